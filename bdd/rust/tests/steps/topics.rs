@@ -37,6 +37,7 @@ pub async fn when_create_topic(
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .expect("Should be able to create topic");
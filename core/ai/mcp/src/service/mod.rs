@@ -152,6 +152,7 @@ impl IggyService {
             replication_factor,
             message_expiry,
             max_size,
+            cleanup_policy,
         }): Parameters<CreateTopic>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_create()?;
@@ -162,6 +163,9 @@ impl IggyService {
             .and_then(|me| me.parse().ok())
             .unwrap_or_default();
         let max_size = max_size.and_then(|ms| ms.parse().ok()).unwrap_or_default();
+        let cleanup_policy = cleanup_policy
+            .and_then(|cp| cp.parse().ok())
+            .unwrap_or_default();
         request(
             self.client
                 .create_topic(
@@ -172,6 +176,7 @@ impl IggyService {
                     replication_factor,
                     message_expiry,
                     max_size,
+                    cleanup_policy,
                 )
                 .await,
         )
@@ -188,6 +193,7 @@ impl IggyService {
             replication_factor,
             message_expiry,
             max_size,
+            cleanup_policy,
         }): Parameters<UpdateTopic>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_update()?;
@@ -198,6 +204,9 @@ impl IggyService {
             .and_then(|me| me.parse().ok())
             .unwrap_or_default();
         let max_size = max_size.and_then(|ms| ms.parse().ok()).unwrap_or_default();
+        let cleanup_policy = cleanup_policy
+            .and_then(|cp| cp.parse().ok())
+            .unwrap_or_default();
         request(
             self.client
                 .update_topic(
@@ -208,6 +217,7 @@ impl IggyService {
                     replication_factor,
                     message_expiry,
                     max_size,
+                    cleanup_policy,
                 )
                 .await,
         )
@@ -508,7 +518,7 @@ impl IggyService {
         self.permissions.ensure_create()?;
         request(
             self.client
-                .create_consumer_group(&id(&stream_id)?, &id(&topic_id)?, &name)
+                .create_consumer_group(&id(&stream_id)?, &id(&topic_id)?, &name, None, None)
                 .await,
         )
     }
@@ -80,7 +80,9 @@ pub struct CreateTopic {
     #[schemars(description = "partitions count (required, must be greater than 0)")]
     pub partitions_count: u32,
 
-    #[schemars(description = "compression algorithm (optional, can be one of 'none', 'gzip')")]
+    #[schemars(
+        description = "compression algorithm (optional, can be one of 'none', 'gzip', 'lz4', 'zstd', 'snappy')"
+    )]
     pub compression_algorithm: Option<String>,
 
     #[schemars(description = "replication factor (optional, must be greater than 0)")]
@@ -91,6 +93,9 @@ pub struct CreateTopic {
 
     #[schemars(description = "maximum size (optional)")]
     pub max_size: Option<String>,
+
+    #[schemars(description = "cleanup policy (optional, can be one of 'delete', 'compact')")]
+    pub cleanup_policy: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -104,7 +109,9 @@ pub struct UpdateTopic {
     #[schemars(description = "name (required, must be unique)")]
     pub name: String,
 
-    #[schemars(description = "compression algorithm (optional, can be one of 'none', 'gzip')")]
+    #[schemars(
+        description = "compression algorithm (optional, can be one of 'none', 'gzip', 'lz4', 'zstd', 'snappy')"
+    )]
     pub compression_algorithm: Option<String>,
 
     #[schemars(description = "replication factor (optional, must be greater than 0)")]
@@ -115,6 +122,9 @@ pub struct UpdateTopic {
 
     #[schemars(description = "maximum size (optional)")]
     pub max_size: Option<String>,
+
+    #[schemars(description = "cleanup policy (optional, can be one of 'delete', 'compact')")]
+    pub cleanup_policy: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -499,6 +509,7 @@ impl From<Permissions> for prelude::Permissions {
                     .map(|(id, stream)| (id, stream.into()))
                     .collect()
             }),
+            stream_patterns: None,
         }
     }
 }
@@ -546,6 +557,7 @@ impl From<TopicPermissions> for prelude::TopicPermissions {
             read_topic: permissions.read_topic.unwrap_or_default(),
             poll_messages: permissions.poll_messages.unwrap_or_default(),
             send_messages: permissions.send_messages.unwrap_or_default(),
+            consumer_groups: None,
         }
     }
 }
@@ -146,6 +146,7 @@ pub trait Benchmarkable: Send {
                     None,
                     message_expiry,
                     max_topic_size,
+                    CleanupPolicy::Delete,
                 )
                 .await?;
         }
@@ -93,7 +93,7 @@ pub async fn init_consumer_groups(
             consumer_group_name, stream_name, topic_id
         );
         if let Err(err) = client
-            .create_consumer_group(&stream_id, &topic_id, &consumer_group_name)
+            .create_consumer_group(&stream_id, &topic_id, &consumer_group_name, None, None)
             .await
         {
             error!("Error when creating consumer group {consumer_group_id}: {err}");
@@ -25,6 +25,7 @@ pub const PING_CODE: u32 = 1;
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_SNAPSHOT_FILE_CODE: u32 = 11;
 pub const GET_CLUSTER_METADATA_CODE: u32 = 12;
+pub const GET_AUDIT_LOG_CODE: u32 = 13;
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT_CODE: u32 = 21;
 pub const GET_CLIENTS_CODE: u32 = 22;
@@ -41,6 +42,20 @@ pub const LOGIN_USER_CODE: u32 = 38;
 pub const LOGOUT_USER_CODE: u32 = 39;
 pub const LOGIN_REGISTER_CODE: u32 = 40;
 pub const LOGIN_REGISTER_WITH_PAT_CODE: u32 = 45;
+pub const LOGIN_SCRAM_START_CODE: u32 = 46;
+pub const LOGIN_SCRAM_FINISH_CODE: u32 = 47;
+pub const LOGIN_WITH_TOKEN_CODE: u32 = 48;
+pub const SET_USER_QUOTA_CODE: u32 = 49;
+pub const GET_USER_QUOTAS_CODE: u32 = 50;
+
+// -- Roles --
+pub const GET_ROLE_CODE: u32 = 51;
+pub const GET_ROLES_CODE: u32 = 52;
+pub const CREATE_ROLE_CODE: u32 = 53;
+pub const DELETE_ROLE_CODE: u32 = 54;
+pub const UPDATE_ROLE_CODE: u32 = 55;
+pub const ASSIGN_ROLE_CODE: u32 = 56;
+pub const UNASSIGN_ROLE_CODE: u32 = 57;
 
 // -- Personal Access Tokens --
 pub const GET_PERSONAL_ACCESS_TOKENS_CODE: u32 = 41;
@@ -52,6 +67,7 @@ pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE: u32 = 44;
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES_CODE: u32 = 101;
 pub const FLUSH_UNSAVED_BUFFER_CODE: u32 = 102;
+pub const INIT_PRODUCER_CODE: u32 = 103;
 
 // -- Consumer Offsets --
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
@@ -60,6 +76,11 @@ pub const DELETE_CONSUMER_OFFSET_CODE: u32 = 122;
 pub const STORE_CONSUMER_OFFSET_2_CODE: u32 = 123;
 pub const DELETE_CONSUMER_OFFSET_2_CODE: u32 = 124;
 
+// -- Transactions --
+pub const BEGIN_TRANSACTION_CODE: u32 = 130;
+pub const COMMIT_TRANSACTION_CODE: u32 = 131;
+pub const ABORT_TRANSACTION_CODE: u32 = 132;
+
 // -- Streams --
 pub const GET_STREAM_CODE: u32 = 200;
 pub const GET_STREAMS_CODE: u32 = 201;
@@ -75,6 +96,7 @@ pub const CREATE_TOPIC_CODE: u32 = 302;
 pub const DELETE_TOPIC_CODE: u32 = 303;
 pub const UPDATE_TOPIC_CODE: u32 = 304;
 pub const PURGE_TOPIC_CODE: u32 = 305;
+pub const ROTATE_TOPIC_KEY_CODE: u32 = 306;
 
 // -- Partitions --
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
@@ -90,6 +112,9 @@ pub const CREATE_CONSUMER_GROUP_CODE: u32 = 602;
 pub const DELETE_CONSUMER_GROUP_CODE: u32 = 603;
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const NACK_MESSAGE_CODE: u32 = 606;
+pub const ACK_MESSAGES_CODE: u32 = 607;
+pub const GET_CONSUMER_GROUP_LAG_CODE: u32 = 608;
 
 /// Lookup the human-readable name for a command code.
 ///
@@ -111,6 +136,7 @@ mod tests {
         GET_STATS_CODE,
         GET_SNAPSHOT_FILE_CODE,
         GET_CLUSTER_METADATA_CODE,
+        GET_AUDIT_LOG_CODE,
         GET_ME_CODE,
         GET_CLIENT_CODE,
         GET_CLIENTS_CODE,
@@ -125,6 +151,18 @@ mod tests {
         LOGOUT_USER_CODE,
         LOGIN_REGISTER_CODE,
         LOGIN_REGISTER_WITH_PAT_CODE,
+        LOGIN_SCRAM_START_CODE,
+        LOGIN_SCRAM_FINISH_CODE,
+        LOGIN_WITH_TOKEN_CODE,
+        SET_USER_QUOTA_CODE,
+        GET_USER_QUOTAS_CODE,
+        GET_ROLE_CODE,
+        GET_ROLES_CODE,
+        CREATE_ROLE_CODE,
+        DELETE_ROLE_CODE,
+        UPDATE_ROLE_CODE,
+        ASSIGN_ROLE_CODE,
+        UNASSIGN_ROLE_CODE,
         GET_PERSONAL_ACCESS_TOKENS_CODE,
         CREATE_PERSONAL_ACCESS_TOKEN_CODE,
         DELETE_PERSONAL_ACCESS_TOKEN_CODE,
@@ -132,11 +170,15 @@ mod tests {
         POLL_MESSAGES_CODE,
         SEND_MESSAGES_CODE,
         FLUSH_UNSAVED_BUFFER_CODE,
+        INIT_PRODUCER_CODE,
         GET_CONSUMER_OFFSET_CODE,
         STORE_CONSUMER_OFFSET_CODE,
         DELETE_CONSUMER_OFFSET_CODE,
         STORE_CONSUMER_OFFSET_2_CODE,
         DELETE_CONSUMER_OFFSET_2_CODE,
+        BEGIN_TRANSACTION_CODE,
+        COMMIT_TRANSACTION_CODE,
+        ABORT_TRANSACTION_CODE,
         GET_STREAM_CODE,
         GET_STREAMS_CODE,
         CREATE_STREAM_CODE,
@@ -149,6 +191,7 @@ mod tests {
         DELETE_TOPIC_CODE,
         UPDATE_TOPIC_CODE,
         PURGE_TOPIC_CODE,
+        ROTATE_TOPIC_KEY_CODE,
         CREATE_PARTITIONS_CODE,
         DELETE_PARTITIONS_CODE,
         DELETE_SEGMENTS_CODE,
@@ -158,6 +201,9 @@ mod tests {
         DELETE_CONSUMER_GROUP_CODE,
         JOIN_CONSUMER_GROUP_CODE,
         LEAVE_CONSUMER_GROUP_CODE,
+        NACK_MESSAGE_CODE,
+        ACK_MESSAGES_CODE,
+        GET_CONSUMER_GROUP_LAG_CODE,
     ];
 
     #[test]
@@ -60,6 +60,11 @@ pub enum Operation {
     UpdatePermissions = 145,
     CreatePersonalAccessToken = 146,
     DeletePersonalAccessToken = 147,
+    CreateRole = 148,
+    UpdateRole = 149,
+    DeleteRole = 150,
+    AssignRole = 151,
+    UnassignRole = 152,
 
     // Partition operations (routed by namespace)
     SendMessages = 160,
@@ -111,6 +116,11 @@ impl Operation {
                 | Self::UpdatePermissions
                 | Self::CreatePersonalAccessToken
                 | Self::DeletePersonalAccessToken
+                | Self::CreateRole
+                | Self::UpdateRole
+                | Self::DeleteRole
+                | Self::AssignRole
+                | Self::UnassignRole
         )
     }
 
@@ -166,6 +176,11 @@ impl Operation {
             | Self::UpdatePermissions
             | Self::CreatePersonalAccessToken
             | Self::DeletePersonalAccessToken
+            | Self::CreateRole
+            | Self::UpdateRole
+            | Self::DeleteRole
+            | Self::AssignRole
+            | Self::UnassignRole
             | Self::SendMessages
             | Self::StoreConsumerOffset
             | Self::DeleteConsumerOffset
@@ -216,6 +231,11 @@ mod tests {
             Operation::UpdatePermissions,
             Operation::CreatePersonalAccessToken,
             Operation::DeletePersonalAccessToken,
+            Operation::CreateRole,
+            Operation::UpdateRole,
+            Operation::DeleteRole,
+            Operation::AssignRole,
+            Operation::UnassignRole,
             Operation::SendMessages,
             Operation::StoreConsumerOffset,
             Operation::DeleteConsumerOffset,
@@ -273,6 +293,8 @@ mod tests {
         assert!(Operation::CreateStream.is_metadata());
         assert!(!Operation::CreateStream.is_partition());
         assert!(Operation::CreateStream.is_client_allowed());
+        assert!(Operation::AssignRole.is_metadata());
+        assert!(!Operation::AssignRole.is_partition());
         assert!(Operation::SendMessages.is_partition());
         assert!(!Operation::SendMessages.is_metadata());
         assert!(Operation::DeleteSegments.is_partition());
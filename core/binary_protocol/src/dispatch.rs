@@ -184,6 +184,38 @@ pub const COMMAND_TABLE: &[CommandMeta] = &[
     CommandMeta::non_replicated(LEAVE_CONSUMER_GROUP_CODE, "consumer_group.leave"),
     // Login + Register (PAT - Personal Access Token variant)
     CommandMeta::non_replicated(LOGIN_REGISTER_WITH_PAT_CODE, "user.login_register_with_pat"),
+    // Transactions
+    CommandMeta::non_replicated(BEGIN_TRANSACTION_CODE, "transaction.begin"),
+    CommandMeta::non_replicated(COMMIT_TRANSACTION_CODE, "transaction.commit"),
+    CommandMeta::non_replicated(ABORT_TRANSACTION_CODE, "transaction.abort"),
+    // Idempotent producer
+    CommandMeta::non_replicated(INIT_PRODUCER_CODE, "message.init_producer"),
+    // Dead-letter
+    CommandMeta::non_replicated(NACK_MESSAGE_CODE, "consumer_group.nack_message"),
+    // Shared subscription
+    CommandMeta::non_replicated(ACK_MESSAGES_CODE, "consumer_group.ack_messages"),
+    // Consumer lag
+    CommandMeta::non_replicated(GET_CONSUMER_GROUP_LAG_CODE, "consumer_group.lag"),
+    // SCRAM-SHA-256 login
+    CommandMeta::non_replicated(LOGIN_SCRAM_START_CODE, "user.login_scram_start"),
+    CommandMeta::non_replicated(LOGIN_SCRAM_FINISH_CODE, "user.login_scram_finish"),
+    // OAuth2/OIDC token login
+    CommandMeta::non_replicated(LOGIN_WITH_TOKEN_CODE, "user.login_with_token"),
+    // Quotas
+    CommandMeta::non_replicated(SET_USER_QUOTA_CODE, "user.set_quota"),
+    CommandMeta::non_replicated(GET_USER_QUOTAS_CODE, "user.get_quotas"),
+    // Audit log
+    CommandMeta::non_replicated(GET_AUDIT_LOG_CODE, "audit.list"),
+    // Roles
+    CommandMeta::non_replicated(GET_ROLE_CODE, "role.get"),
+    CommandMeta::non_replicated(GET_ROLES_CODE, "role.list"),
+    CommandMeta::replicated(CREATE_ROLE_CODE, "role.create", Operation::CreateRole),
+    CommandMeta::replicated(DELETE_ROLE_CODE, "role.delete", Operation::DeleteRole),
+    CommandMeta::replicated(UPDATE_ROLE_CODE, "role.update", Operation::UpdateRole),
+    CommandMeta::replicated(ASSIGN_ROLE_CODE, "role.assign", Operation::AssignRole),
+    CommandMeta::replicated(UNASSIGN_ROLE_CODE, "role.unassign", Operation::UnassignRole),
+    // Encryption keys
+    CommandMeta::non_replicated(ROTATE_TOPIC_KEY_CODE, "topic.rotate_key"),
 ];
 
 /// Lookup command metadata by command code.
@@ -246,6 +278,27 @@ pub const fn lookup_command(code: u32) -> Option<&'static CommandMeta> {
         JOIN_CONSUMER_GROUP_CODE => 48,
         LEAVE_CONSUMER_GROUP_CODE => 49,
         LOGIN_REGISTER_WITH_PAT_CODE => 50,
+        BEGIN_TRANSACTION_CODE => 51,
+        COMMIT_TRANSACTION_CODE => 52,
+        ABORT_TRANSACTION_CODE => 53,
+        INIT_PRODUCER_CODE => 54,
+        NACK_MESSAGE_CODE => 55,
+        ACK_MESSAGES_CODE => 56,
+        GET_CONSUMER_GROUP_LAG_CODE => 57,
+        LOGIN_SCRAM_START_CODE => 58,
+        LOGIN_SCRAM_FINISH_CODE => 59,
+        LOGIN_WITH_TOKEN_CODE => 60,
+        SET_USER_QUOTA_CODE => 61,
+        GET_USER_QUOTAS_CODE => 62,
+        GET_AUDIT_LOG_CODE => 63,
+        GET_ROLE_CODE => 64,
+        GET_ROLES_CODE => 65,
+        CREATE_ROLE_CODE => 66,
+        DELETE_ROLE_CODE => 67,
+        UPDATE_ROLE_CODE => 68,
+        ASSIGN_ROLE_CODE => 69,
+        UNASSIGN_ROLE_CODE => 70,
+        ROTATE_TOPIC_KEY_CODE => 71,
         _ => return None,
     };
     Some(&COMMAND_TABLE[idx])
@@ -279,6 +332,11 @@ pub const fn lookup_by_operation(op: Operation) -> Option<&'static CommandMeta>
         Operation::UpdatePermissions => 12,
         Operation::CreatePersonalAccessToken => 18,
         Operation::DeletePersonalAccessToken => 19,
+        Operation::CreateRole => 66,
+        Operation::DeleteRole => 67,
+        Operation::UpdateRole => 68,
+        Operation::AssignRole => 69,
+        Operation::UnassignRole => 70,
         Operation::SendMessages => 22,
         Operation::StoreConsumerOffset => 25,
         Operation::DeleteConsumerOffset => 26,
@@ -303,6 +361,7 @@ mod tests {
             GET_STATS_CODE,
             GET_SNAPSHOT_FILE_CODE,
             GET_CLUSTER_METADATA_CODE,
+            GET_AUDIT_LOG_CODE,
             GET_ME_CODE,
             GET_CLIENT_CODE,
             GET_CLIENTS_CODE,
@@ -317,6 +376,18 @@ mod tests {
             LOGOUT_USER_CODE,
             LOGIN_REGISTER_CODE,
             LOGIN_REGISTER_WITH_PAT_CODE,
+            LOGIN_SCRAM_START_CODE,
+            LOGIN_SCRAM_FINISH_CODE,
+            LOGIN_WITH_TOKEN_CODE,
+            SET_USER_QUOTA_CODE,
+            GET_USER_QUOTAS_CODE,
+            GET_ROLE_CODE,
+            GET_ROLES_CODE,
+            CREATE_ROLE_CODE,
+            DELETE_ROLE_CODE,
+            UPDATE_ROLE_CODE,
+            ASSIGN_ROLE_CODE,
+            UNASSIGN_ROLE_CODE,
             GET_PERSONAL_ACCESS_TOKENS_CODE,
             CREATE_PERSONAL_ACCESS_TOKEN_CODE,
             DELETE_PERSONAL_ACCESS_TOKEN_CODE,
@@ -329,6 +400,10 @@ mod tests {
             DELETE_CONSUMER_OFFSET_CODE,
             STORE_CONSUMER_OFFSET_2_CODE,
             DELETE_CONSUMER_OFFSET_2_CODE,
+            BEGIN_TRANSACTION_CODE,
+            COMMIT_TRANSACTION_CODE,
+            ABORT_TRANSACTION_CODE,
+            INIT_PRODUCER_CODE,
             GET_STREAM_CODE,
             GET_STREAMS_CODE,
             CREATE_STREAM_CODE,
@@ -341,6 +416,7 @@ mod tests {
             DELETE_TOPIC_CODE,
             UPDATE_TOPIC_CODE,
             PURGE_TOPIC_CODE,
+            ROTATE_TOPIC_KEY_CODE,
             CREATE_PARTITIONS_CODE,
             DELETE_PARTITIONS_CODE,
             DELETE_SEGMENTS_CODE,
@@ -350,6 +426,9 @@ mod tests {
             DELETE_CONSUMER_GROUP_CODE,
             JOIN_CONSUMER_GROUP_CODE,
             LEAVE_CONSUMER_GROUP_CODE,
+            NACK_MESSAGE_CODE,
+            ACK_MESSAGES_CODE,
+            GET_CONSUMER_GROUP_LAG_CODE,
         ];
         for code in all_codes {
             assert!(
@@ -407,6 +486,11 @@ mod tests {
             Operation::UpdatePermissions,
             Operation::CreatePersonalAccessToken,
             Operation::DeletePersonalAccessToken,
+            Operation::CreateRole,
+            Operation::UpdateRole,
+            Operation::DeleteRole,
+            Operation::AssignRole,
+            Operation::UnassignRole,
             Operation::SendMessages,
             Operation::StoreConsumerOffset,
             Operation::DeleteConsumerOffset,
@@ -39,6 +39,12 @@
 //! [status:4 bytes, u32 LE][length:4 bytes, u32 LE][payload:N bytes]
 //! ```
 //!
+//! Error responses (non-zero status) have no payload, except for `Throttled`
+//! (status 56), which carries the time to wait before retrying as
+//! `[retry_after_ms:4 bytes, u32 LE]`. Clients have to read `length` bytes
+//! after any status to stay in sync; the ones which don't can only receive
+//! `Throttled` when quotas are configured for their users.
+//!
 //! All multi-byte integers are little-endian. Strings are length-prefixed
 //! (u8 length for names, u32 length for longer strings).
 //!
@@ -86,9 +92,12 @@ pub use primitives::identifier::{MAX_WIRE_NAME_LENGTH, WireIdentifier, WireName}
 pub use primitives::partition_assignment::CreatedPartitionAssignment;
 pub use primitives::partitioning::{MAX_MESSAGES_KEY_LENGTH, WirePartitioning};
 pub use primitives::permissions::{
-    WireGlobalPermissions, WirePermissions, WireStreamPermissions, WireTopicPermissions,
+    WireConsumerGroupPermissions, WireGlobalPermissions, WirePermissions,
+    WireStreamPatternPermissions, WireStreamPermissions, WireTopicPatternPermissions,
+    WireTopicPermissions,
 };
 pub use primitives::polling_strategy::WirePollingStrategy;
+pub use primitives::quota::{WireQuota, WireQuotaScope};
 pub use primitives::user_headers::{
     WireHeaderKind, WireUserHeaderEntry, WireUserHeaderIterator, WireUserHeaders,
     encode_user_headers, user_headers_encoded_size, validate_user_headers,
@@ -24,4 +24,5 @@ pub mod partition_assignment;
 pub mod partitioning;
 pub mod permissions;
 pub mod polling_strategy;
+pub mod quota;
 pub mod user_headers;
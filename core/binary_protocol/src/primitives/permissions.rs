@@ -15,8 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::WireError;
 use crate::codec::{WireDecode, WireEncode, read_u8, read_u32_le};
+use crate::{WireError, WireName};
 use bytes::{BufMut, BytesMut};
 
 const HAS_NEXT: u8 = 1;
@@ -41,13 +41,31 @@ fn read_bool(buf: &[u8], offset: usize) -> Result<bool, WireError> {
 ///     loop topics:
 ///       [topic_id:u32_le][4 x bool: topic perms][has_next_topic:1]
 ///     [has_next_stream:1]
+/// [has_stream_patterns:1]?
+///   loop stream patterns:
+///     [pattern:WireName][6 x bool: stream perms][has_topics:1]
+///     loop topic patterns:
+///       [pattern:WireName][4 x bool: topic perms][has_next_topic:1]
+///     [has_next_stream:1]
+/// [has_consumer_groups:1]?
+///   loop consumer groups:
+///     [stream_id:u32_le][topic_id:u32_le][group_id:u32_le][4 x bool: group perms]
+///     [has_next_group:1]
 /// ```
 ///
 /// Streams and topics are stored as `Vec` sorted by ID for deterministic encoding.
+/// The consumer groups of all the topics are flattened into a single `Vec` sorted by
+/// stream, topic and group ID.
+/// The trailing sections are only present when there is at least one stream pattern or
+/// consumer group, so that the encoding of the permissions without them is unchanged.
+/// Hence, the decoder must be given exactly the encoded permissions, which are always
+/// length-prefixed by the callers.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WirePermissions {
     pub global: WireGlobalPermissions,
     pub streams: Vec<WireStreamPermissions>,
+    pub stream_patterns: Vec<WireStreamPatternPermissions>,
+    pub consumer_groups: Vec<WireConsumerGroupPermissions>,
 }
 
 #[allow(clippy::struct_excessive_bools)]
@@ -88,6 +106,41 @@ pub struct WireTopicPermissions {
     pub send_messages: bool,
 }
 
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WireConsumerGroupPermissions {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub group_id: u32,
+    pub join_group: bool,
+    pub commit_offsets: bool,
+    pub delete_offsets: bool,
+    pub manage_group: bool,
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WireStreamPatternPermissions {
+    pub pattern: WireName,
+    pub manage_stream: bool,
+    pub read_stream: bool,
+    pub manage_topics: bool,
+    pub read_topics: bool,
+    pub poll_messages: bool,
+    pub send_messages: bool,
+    pub topics: Vec<WireTopicPatternPermissions>,
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WireTopicPatternPermissions {
+    pub pattern: WireName,
+    pub manage_topic: bool,
+    pub read_topic: bool,
+    pub poll_messages: bool,
+    pub send_messages: bool,
+}
+
 impl WireGlobalPermissions {
     fn encode_into(&self, buf: &mut BytesMut) {
         buf.put_u8(bool_to_u8(self.manage_servers));
@@ -263,9 +316,174 @@ impl WireStreamPermissions {
     }
 }
 
+impl WireTopicPatternPermissions {
+    fn encoded_size(&self) -> usize {
+        // pattern + 4 bools + has_next_topic
+        self.pattern.encoded_size() + 4 + 1
+    }
+
+    fn encode_into(&self, buf: &mut BytesMut) {
+        self.pattern.encode(buf);
+        buf.put_u8(bool_to_u8(self.manage_topic));
+        buf.put_u8(bool_to_u8(self.read_topic));
+        buf.put_u8(bool_to_u8(self.poll_messages));
+        buf.put_u8(bool_to_u8(self.send_messages));
+    }
+
+    fn decode_at(buf: &[u8], pos: usize) -> Result<(Self, usize), WireError> {
+        let (pattern, consumed) = WireName::decode(&buf[pos..])?;
+        let mut p = pos + consumed;
+        let manage_topic = read_bool(buf, p)?;
+        p += 1;
+        let read_topic = read_bool(buf, p)?;
+        p += 1;
+        let poll_messages = read_bool(buf, p)?;
+        p += 1;
+        let send_messages = read_bool(buf, p)?;
+        p += 1;
+
+        Ok((
+            Self {
+                pattern,
+                manage_topic,
+                read_topic,
+                poll_messages,
+                send_messages,
+            },
+            p,
+        ))
+    }
+}
+
+impl WireStreamPatternPermissions {
+    fn encoded_size(&self) -> usize {
+        // pattern + 6 bools(6) + has_topics(1) + topics + has_next_stream(1)
+        let topics_size: usize = self
+            .topics
+            .iter()
+            .map(WireTopicPatternPermissions::encoded_size)
+            .sum();
+        self.pattern.encoded_size() + 6 + 1 + topics_size + 1
+    }
+
+    fn encode_into(&self, buf: &mut BytesMut) {
+        self.pattern.encode(buf);
+        buf.put_u8(bool_to_u8(self.manage_stream));
+        buf.put_u8(bool_to_u8(self.read_stream));
+        buf.put_u8(bool_to_u8(self.manage_topics));
+        buf.put_u8(bool_to_u8(self.read_topics));
+        buf.put_u8(bool_to_u8(self.poll_messages));
+        buf.put_u8(bool_to_u8(self.send_messages));
+
+        if self.topics.is_empty() {
+            buf.put_u8(NO_NEXT);
+        } else {
+            buf.put_u8(HAS_NEXT);
+            for (i, topic) in self.topics.iter().enumerate() {
+                topic.encode_into(buf);
+                let is_last = i == self.topics.len() - 1;
+                buf.put_u8(if is_last { NO_NEXT } else { HAS_NEXT });
+            }
+        }
+    }
+
+    fn decode_at(buf: &[u8], pos: usize) -> Result<(Self, usize), WireError> {
+        let (pattern, consumed) = WireName::decode(&buf[pos..])?;
+        let mut p = pos + consumed;
+        let manage_stream = read_bool(buf, p)?;
+        p += 1;
+        let read_stream = read_bool(buf, p)?;
+        p += 1;
+        let manage_topics = read_bool(buf, p)?;
+        p += 1;
+        let read_topics = read_bool(buf, p)?;
+        p += 1;
+        let poll_messages = read_bool(buf, p)?;
+        p += 1;
+        let send_messages = read_bool(buf, p)?;
+        p += 1;
+
+        let has_topics = read_bool(buf, p)?;
+        p += 1;
+
+        let mut topics = Vec::new();
+        if has_topics {
+            loop {
+                let (topic, next_p) = WireTopicPatternPermissions::decode_at(buf, p)?;
+                p = next_p;
+                topics.push(topic);
+                let has_next = read_bool(buf, p)?;
+                p += 1;
+                if !has_next {
+                    break;
+                }
+            }
+        }
+
+        Ok((
+            Self {
+                pattern,
+                manage_stream,
+                read_stream,
+                manage_topics,
+                read_topics,
+                poll_messages,
+                send_messages,
+                topics,
+            },
+            p,
+        ))
+    }
+}
+
+impl WireConsumerGroupPermissions {
+    // stream_id(4) + topic_id(4) + group_id(4) + 4 bools(4) + has_next_group(1)
+    const ENCODED_SIZE: usize = 4 + 4 + 4 + 4 + 1;
+
+    fn encode_into(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.stream_id);
+        buf.put_u32_le(self.topic_id);
+        buf.put_u32_le(self.group_id);
+        buf.put_u8(bool_to_u8(self.join_group));
+        buf.put_u8(bool_to_u8(self.commit_offsets));
+        buf.put_u8(bool_to_u8(self.delete_offsets));
+        buf.put_u8(bool_to_u8(self.manage_group));
+    }
+
+    fn decode_at(buf: &[u8], pos: usize) -> Result<(Self, usize), WireError> {
+        let stream_id = read_u32_le(buf, pos)?;
+        let topic_id = read_u32_le(buf, pos + 4)?;
+        let group_id = read_u32_le(buf, pos + 8)?;
+        let mut p = pos + 12;
+        let join_group = read_bool(buf, p)?;
+        p += 1;
+        let commit_offsets = read_bool(buf, p)?;
+        p += 1;
+        let delete_offsets = read_bool(buf, p)?;
+        p += 1;
+        let manage_group = read_bool(buf, p)?;
+        p += 1;
+
+        Ok((
+            Self {
+                stream_id,
+                topic_id,
+                group_id,
+                join_group,
+                commit_offsets,
+                delete_offsets,
+                manage_group,
+            },
+            p,
+        ))
+    }
+}
+
 impl WireEncode for WirePermissions {
     fn encoded_size(&self) -> usize {
         // global(10) + has_streams(1) + streams
+        //   + [has_stream_patterns(1) + stream patterns]
+        //   + [has_consumer_groups(1) + consumer groups]
         let streams_size: usize = if self.streams.is_empty() {
             0
         } else {
@@ -274,7 +492,22 @@ impl WireEncode for WirePermissions {
                 .map(WireStreamPermissions::encoded_size)
                 .sum()
         };
-        10 + 1 + streams_size
+        let stream_patterns_size: usize =
+            if self.stream_patterns.is_empty() && self.consumer_groups.is_empty() {
+                0
+            } else {
+                1 + self
+                    .stream_patterns
+                    .iter()
+                    .map(WireStreamPatternPermissions::encoded_size)
+                    .sum::<usize>()
+            };
+        let consumer_groups_size: usize = if self.consumer_groups.is_empty() {
+            0
+        } else {
+            1 + self.consumer_groups.len() * WireConsumerGroupPermissions::ENCODED_SIZE
+        };
+        10 + 1 + streams_size + stream_patterns_size + consumer_groups_size
     }
 
     fn encode(&self, buf: &mut BytesMut) {
@@ -290,6 +523,30 @@ impl WireEncode for WirePermissions {
                 buf.put_u8(if is_last { NO_NEXT } else { HAS_NEXT });
             }
         }
+
+        if self.stream_patterns.is_empty() && self.consumer_groups.is_empty() {
+            return;
+        }
+
+        if self.stream_patterns.is_empty() {
+            buf.put_u8(NO_NEXT);
+        } else {
+            buf.put_u8(HAS_NEXT);
+            for (i, stream) in self.stream_patterns.iter().enumerate() {
+                stream.encode_into(buf);
+                let is_last = i == self.stream_patterns.len() - 1;
+                buf.put_u8(if is_last { NO_NEXT } else { HAS_NEXT });
+            }
+        }
+
+        if !self.consumer_groups.is_empty() {
+            buf.put_u8(HAS_NEXT);
+            for (i, group) in self.consumer_groups.iter().enumerate() {
+                group.encode_into(buf);
+                let is_last = i == self.consumer_groups.len() - 1;
+                buf.put_u8(if is_last { NO_NEXT } else { HAS_NEXT });
+            }
+        }
     }
 }
 
@@ -314,7 +571,48 @@ impl WireDecode for WirePermissions {
             }
         }
 
-        Ok((Self { global, streams }, p))
+        let mut stream_patterns = Vec::new();
+        if p < buf.len() {
+            let has_stream_patterns = read_bool(buf, p)?;
+            p += 1;
+            if has_stream_patterns {
+                loop {
+                    let (stream, next_p) = WireStreamPatternPermissions::decode_at(buf, p)?;
+                    p = next_p;
+                    stream_patterns.push(stream);
+                    let has_next = read_bool(buf, p)?;
+                    p += 1;
+                    if !has_next {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let mut consumer_groups = Vec::new();
+        if p < buf.len() && read_bool(buf, p)? {
+            p += 1;
+            loop {
+                let (group, next_p) = WireConsumerGroupPermissions::decode_at(buf, p)?;
+                p = next_p;
+                consumer_groups.push(group);
+                let has_next = read_bool(buf, p)?;
+                p += 1;
+                if !has_next {
+                    break;
+                }
+            }
+        }
+
+        Ok((
+            Self {
+                global,
+                streams,
+                stream_patterns,
+                consumer_groups,
+            },
+            p,
+        ))
     }
 }
 
@@ -360,11 +658,55 @@ mod tests {
         }
     }
 
+    fn make_stream_pattern(
+        pattern: &str,
+        topics: Vec<WireTopicPatternPermissions>,
+    ) -> WireStreamPatternPermissions {
+        WireStreamPatternPermissions {
+            pattern: WireName::new(pattern).unwrap(),
+            manage_stream: false,
+            read_stream: true,
+            manage_topics: true,
+            read_topics: true,
+            poll_messages: false,
+            send_messages: true,
+            topics,
+        }
+    }
+
+    fn make_consumer_group(
+        stream_id: u32,
+        topic_id: u32,
+        group_id: u32,
+    ) -> WireConsumerGroupPermissions {
+        WireConsumerGroupPermissions {
+            stream_id,
+            topic_id,
+            group_id,
+            join_group: true,
+            commit_offsets: true,
+            delete_offsets: false,
+            manage_group: false,
+        }
+    }
+
+    fn make_topic_pattern(pattern: &str) -> WireTopicPatternPermissions {
+        WireTopicPatternPermissions {
+            pattern: WireName::new(pattern).unwrap(),
+            manage_topic: false,
+            read_topic: true,
+            poll_messages: true,
+            send_messages: false,
+        }
+    }
+
     #[test]
     fn roundtrip_global_only() {
         let perms = WirePermissions {
             global: make_global(true),
             streams: vec![],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let bytes = perms.to_bytes();
         assert_eq!(bytes.len(), 11); // 10 global + 1 has_streams=0
@@ -378,6 +720,8 @@ mod tests {
         let perms = WirePermissions {
             global: make_global(false),
             streams: vec![make_stream(1, vec![]), make_stream(2, vec![])],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let bytes = perms.to_bytes();
         let (decoded, consumed) = WirePermissions::decode(&bytes).unwrap();
@@ -393,6 +737,8 @@ mod tests {
                 make_stream(1, vec![make_topic(10), make_topic(20)]),
                 make_stream(2, vec![make_topic(30)]),
             ],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let bytes = perms.to_bytes();
         let (decoded, consumed) = WirePermissions::decode(&bytes).unwrap();
@@ -405,6 +751,8 @@ mod tests {
         let perms = WirePermissions {
             global: make_global(false),
             streams: vec![make_stream(42, vec![make_topic(7)])],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let bytes = perms.to_bytes();
         let (decoded, consumed) = WirePermissions::decode(&bytes).unwrap();
@@ -417,6 +765,8 @@ mod tests {
         let perms = WirePermissions {
             global: make_global(false),
             streams: vec![],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let bytes = perms.to_bytes();
         // First 10 bytes should all be 0
@@ -432,6 +782,8 @@ mod tests {
         let perms = WirePermissions {
             global: make_global(true),
             streams: vec![],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let bytes = perms.to_bytes();
         for byte in &bytes[..10] {
@@ -444,6 +796,8 @@ mod tests {
         let perms = WirePermissions {
             global: make_global(true),
             streams: vec![],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let bytes = perms.to_bytes();
         for i in 0..bytes.len() {
@@ -459,6 +813,8 @@ mod tests {
         let perms = WirePermissions {
             global: make_global(true),
             streams: vec![make_stream(1, vec![make_topic(10)])],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let bytes = perms.to_bytes();
         for i in 0..bytes.len() {
@@ -468,4 +824,104 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn roundtrip_with_stream_patterns() {
+        let perms = WirePermissions {
+            global: make_global(false),
+            streams: vec![make_stream(1, vec![make_topic(10)])],
+            stream_patterns: vec![
+                make_stream_pattern("orders-*", vec![make_topic_pattern("events-?")]),
+                make_stream_pattern("payments", vec![]),
+            ],
+            consumer_groups: vec![],
+        };
+        let bytes = perms.to_bytes();
+        assert_eq!(bytes.len(), perms.encoded_size());
+        let (decoded, consumed) = WirePermissions::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, perms);
+    }
+
+    #[test]
+    fn encoding_without_stream_patterns_has_no_patterns_section() {
+        let perms = WirePermissions {
+            global: make_global(true),
+            streams: vec![make_stream(1, vec![])],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
+        };
+        let bytes = perms.to_bytes();
+        assert_eq!(bytes.len(), 11 + perms.streams[0].encoded_size());
+    }
+
+    #[test]
+    fn truncated_buffer_within_stream_patterns() {
+        let perms = WirePermissions {
+            global: make_global(true),
+            streams: vec![],
+            stream_patterns: vec![make_stream_pattern(
+                "orders-*",
+                vec![make_topic_pattern("events-*")],
+            )],
+            consumer_groups: vec![],
+        };
+        let bytes = perms.to_bytes();
+        // Truncation exactly after the streams section is a valid encoding without patterns.
+        for i in 12..bytes.len() {
+            assert!(
+                WirePermissions::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+
+    #[test]
+    fn roundtrip_with_consumer_groups() {
+        let perms = WirePermissions {
+            global: make_global(false),
+            streams: vec![make_stream(1, vec![make_topic(10)])],
+            stream_patterns: vec![],
+            consumer_groups: vec![make_consumer_group(1, 10, 1), make_consumer_group(1, 10, 2)],
+        };
+        let bytes = perms.to_bytes();
+        assert_eq!(bytes.len(), perms.encoded_size());
+        let (decoded, consumed) = WirePermissions::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, perms);
+    }
+
+    #[test]
+    fn roundtrip_with_stream_patterns_and_consumer_groups() {
+        let perms = WirePermissions {
+            global: make_global(false),
+            streams: vec![make_stream(1, vec![make_topic(10)])],
+            stream_patterns: vec![make_stream_pattern("orders-*", vec![])],
+            consumer_groups: vec![make_consumer_group(1, 10, 3)],
+        };
+        let bytes = perms.to_bytes();
+        assert_eq!(bytes.len(), perms.encoded_size());
+        let (decoded, consumed) = WirePermissions::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, perms);
+    }
+
+    #[test]
+    fn truncated_buffer_within_consumer_groups() {
+        let perms = WirePermissions {
+            global: make_global(true),
+            streams: vec![],
+            stream_patterns: vec![],
+            consumer_groups: vec![make_consumer_group(1, 1, 1)],
+        };
+        let bytes = perms.to_bytes();
+        // Truncation exactly after the empty stream patterns section is a valid encoding
+        // without consumer groups.
+        for i in 13..bytes.len() {
+            assert!(
+                WirePermissions::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
 }
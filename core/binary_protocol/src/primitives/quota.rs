@@ -0,0 +1,142 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_u32_le, read_u64_le};
+use bytes::{BufMut, BytesMut};
+
+/// Which traffic a quota applies to.
+///
+/// Wire format: single `u8` discriminant.
+/// - `User(1)`:   shared by all connections authenticated as the user.
+/// - `Client(2)`: applied to each connection of the user separately.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireQuotaScope {
+    User = 1,
+    Client = 2,
+}
+
+impl WireQuotaScope {
+    /// Decode a `WireQuotaScope` from its wire discriminant.
+    ///
+    /// # Errors
+    /// Returns `WireError::UnknownDiscriminant` for unrecognised values.
+    pub const fn from_code(code: u8) -> Result<Self, WireError> {
+        match code {
+            1 => Ok(Self::User),
+            2 => Ok(Self::Client),
+            other => Err(WireError::UnknownDiscriminant {
+                type_name: "WireQuotaScope",
+                value: other,
+                offset: 0,
+            }),
+        }
+    }
+
+    /// Encode this `WireQuotaScope` as its wire discriminant.
+    #[must_use]
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Throughput and request rate limits. A limit of `0` means unlimited.
+///
+/// Wire format:
+/// `[produce_bytes_per_second:u64_le][fetch_bytes_per_second:u64_le][requests_per_second:u32_le]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WireQuota {
+    pub produce_bytes_per_second: u64,
+    pub fetch_bytes_per_second: u64,
+    pub requests_per_second: u32,
+}
+
+impl WireQuota {
+    pub const ENCODED_SIZE: usize = 8 + 8 + 4;
+}
+
+impl WireEncode for WireQuota {
+    fn encoded_size(&self) -> usize {
+        Self::ENCODED_SIZE
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.produce_bytes_per_second);
+        buf.put_u64_le(self.fetch_bytes_per_second);
+        buf.put_u32_le(self.requests_per_second);
+    }
+}
+
+impl WireDecode for WireQuota {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let produce_bytes_per_second = read_u64_le(buf, 0)?;
+        let fetch_bytes_per_second = read_u64_le(buf, 8)?;
+        let requests_per_second = read_u32_le(buf, 16)?;
+        Ok((
+            Self {
+                produce_bytes_per_second,
+                fetch_bytes_per_second,
+                requests_per_second,
+            },
+            Self::ENCODED_SIZE,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_quota() {
+        let quota = WireQuota {
+            produce_bytes_per_second: 1_048_576,
+            fetch_bytes_per_second: 2_097_152,
+            requests_per_second: 500,
+        };
+        let bytes = quota.to_bytes();
+        assert_eq!(bytes.len(), WireQuota::ENCODED_SIZE);
+        let (decoded, consumed) = WireQuota::decode(&bytes).unwrap();
+        assert_eq!(consumed, WireQuota::ENCODED_SIZE);
+        assert_eq!(decoded, quota);
+    }
+
+    #[test]
+    fn truncated_quota_returns_error() {
+        let bytes = WireQuota::default().to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                WireQuota::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+
+    #[test]
+    fn scope_roundtrip() {
+        for scope in [WireQuotaScope::User, WireQuotaScope::Client] {
+            assert_eq!(WireQuotaScope::from_code(scope.as_u8()).unwrap(), scope);
+        }
+    }
+
+    #[test]
+    fn unknown_scope_rejected() {
+        assert!(WireQuotaScope::from_code(0).is_err());
+        assert!(WireQuotaScope::from_code(3).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::WireIdentifier;
+use crate::codec::{WireDecode, WireEncode, read_u32_le, read_u64_le};
+use bytes::{BufMut, BytesMut};
+
+/// Maximum number of the offsets acknowledged by a single request.
+pub const MAX_ACK_OFFSETS_COUNT: usize = 10_000;
+
+/// `AckMessages` request.
+///
+/// Wire format:
+/// `[stream_id][topic_id][group_id][partition_id:u32_le][offsets_count:u32_le][offset:u64_le]*`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AckMessagesRequest {
+    pub stream_id: WireIdentifier,
+    pub topic_id: WireIdentifier,
+    pub group_id: WireIdentifier,
+    pub partition_id: u32,
+    pub offsets: Vec<u64>,
+}
+
+impl WireEncode for AckMessagesRequest {
+    fn encoded_size(&self) -> usize {
+        self.stream_id.encoded_size()
+            + self.topic_id.encoded_size()
+            + self.group_id.encoded_size()
+            + 4
+            + 4
+            + 8 * self.offsets.len()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        self.stream_id.encode(buf);
+        self.topic_id.encode(buf);
+        self.group_id.encode(buf);
+        buf.put_u32_le(self.partition_id);
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u32_le(self.offsets.len() as u32);
+        for offset in &self.offsets {
+            buf.put_u64_le(*offset);
+        }
+    }
+}
+
+impl WireDecode for AckMessagesRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let mut pos = 0;
+        let (stream_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        let (topic_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        let (group_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        let partition_id = read_u32_le(buf, pos)?;
+        pos += 4;
+        let offsets_count = read_u32_le(buf, pos)? as usize;
+        pos += 4;
+        let mut offsets = Vec::with_capacity(offsets_count.min(MAX_ACK_OFFSETS_COUNT));
+        for _ in 0..offsets_count {
+            offsets.push(read_u64_le(buf, pos)?);
+            pos += 8;
+        }
+        Ok((
+            Self {
+                stream_id,
+                topic_id,
+                group_id,
+                partition_id,
+                offsets,
+            },
+            pos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_request() -> AckMessagesRequest {
+        AckMessagesRequest {
+            stream_id: WireIdentifier::numeric(1),
+            topic_id: WireIdentifier::named("orders").unwrap(),
+            group_id: WireIdentifier::numeric(3),
+            partition_id: 2,
+            offsets: vec![4, 7, 42],
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let req = sample_request();
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        let (decoded, consumed) = AckMessagesRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let bytes = sample_request().to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                AckMessagesRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
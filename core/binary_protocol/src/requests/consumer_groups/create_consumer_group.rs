@@ -17,29 +17,146 @@
 
 use crate::WireError;
 use crate::WireIdentifier;
-use crate::codec::{WireDecode, WireEncode};
+use crate::codec::{WireDecode, WireEncode, read_u8, read_u32_le, read_u64_le};
 use crate::primitives::identifier::WireName;
-use bytes::BytesMut;
+use bytes::{BufMut, BytesMut};
 
 /// `CreateConsumerGroup` request.
 ///
-/// Wire format: `[stream_id][topic_id][name_len:1][name:N]`
+/// Wire format:
+/// `[stream_id][topic_id][name_len:1][name:N]
+///  [has_dead_letter:u8][dead_letter:WireDeadLetterPolicy?][has_shared:u8][shared:WireSharedSubscription?]`
+///
+/// The trailing options are written only if any of them is set, requests without them (sent by
+/// older clients or persisted before they existed) create a group with the default delivery.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateConsumerGroupRequest {
     pub stream_id: WireIdentifier,
     pub topic_id: WireIdentifier,
     pub name: WireName,
+    pub dead_letter: Option<WireDeadLetterPolicy>,
+    pub shared: Option<WireSharedSubscription>,
+}
+
+impl CreateConsumerGroupRequest {
+    const fn has_options(&self) -> bool {
+        self.dead_letter.is_some() || self.shared.is_some()
+    }
+}
+
+/// Dead-letter policy of the consumer group.
+///
+/// Wire format: `[max_delivery_count:u32_le][redelivery_timeout_us:u64_le][stream_id][topic_id]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WireDeadLetterPolicy {
+    pub max_delivery_count: u32,
+    pub redelivery_timeout: u64,
+    pub stream_id: WireIdentifier,
+    pub topic_id: WireIdentifier,
+}
+
+impl WireEncode for WireDeadLetterPolicy {
+    fn encoded_size(&self) -> usize {
+        4 + 8 + self.stream_id.encoded_size() + self.topic_id.encoded_size()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.max_delivery_count);
+        buf.put_u64_le(self.redelivery_timeout);
+        self.stream_id.encode(buf);
+        self.topic_id.encode(buf);
+    }
+}
+
+impl WireDecode for WireDeadLetterPolicy {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let max_delivery_count = read_u32_le(buf, 0)?;
+        let redelivery_timeout = read_u64_le(buf, 4)?;
+        let mut pos = 12;
+        let (stream_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        let (topic_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        Ok((
+            Self {
+                max_delivery_count,
+                redelivery_timeout,
+                stream_id,
+                topic_id,
+            },
+            pos,
+        ))
+    }
+}
+
+/// Shared subscription of the consumer group.
+///
+/// Wire format: `[visibility_timeout_us:u64_le]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WireSharedSubscription {
+    pub visibility_timeout: u64,
+}
+
+impl WireEncode for WireSharedSubscription {
+    fn encoded_size(&self) -> usize {
+        8
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.visibility_timeout);
+    }
+}
+
+impl WireDecode for WireSharedSubscription {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let visibility_timeout = read_u64_le(buf, 0)?;
+        Ok((Self { visibility_timeout }, 8))
+    }
 }
 
 impl WireEncode for CreateConsumerGroupRequest {
     fn encoded_size(&self) -> usize {
-        self.stream_id.encoded_size() + self.topic_id.encoded_size() + self.name.encoded_size()
+        let options = if self.has_options() {
+            1 + self
+                .dead_letter
+                .as_ref()
+                .map_or(0, WireEncode::encoded_size)
+                + 1
+                + self.shared.as_ref().map_or(0, WireEncode::encoded_size)
+        } else {
+            0
+        };
+        self.stream_id.encoded_size()
+            + self.topic_id.encoded_size()
+            + self.name.encoded_size()
+            + options
     }
 
     fn encode(&self, buf: &mut BytesMut) {
         self.stream_id.encode(buf);
         self.topic_id.encode(buf);
         self.name.encode(buf);
+        if !self.has_options() {
+            return;
+        }
+        match &self.dead_letter {
+            Some(dead_letter) => {
+                buf.put_u8(1);
+                dead_letter.encode(buf);
+            }
+            None => {
+                buf.put_u8(0);
+            }
+        }
+        match &self.shared {
+            Some(shared) => {
+                buf.put_u8(1);
+                shared.encode(buf);
+            }
+            None => {
+                buf.put_u8(0);
+            }
+        }
     }
 }
 
@@ -52,11 +169,31 @@ impl WireDecode for CreateConsumerGroupRequest {
         pos += n;
         let (name, n) = WireName::decode(&buf[pos..])?;
         pos += n;
+        let mut dead_letter = None;
+        let mut shared = None;
+        if pos < buf.len() {
+            let has_dead_letter = read_u8(buf, pos)?;
+            pos += 1;
+            if has_dead_letter == 1 {
+                let (policy, n) = WireDeadLetterPolicy::decode(&buf[pos..])?;
+                pos += n;
+                dead_letter = Some(policy);
+            }
+            let has_shared = read_u8(buf, pos)?;
+            pos += 1;
+            if has_shared == 1 {
+                let (subscription, n) = WireSharedSubscription::decode(&buf[pos..])?;
+                pos += n;
+                shared = Some(subscription);
+            }
+        }
         Ok((
             Self {
                 stream_id,
                 topic_id,
                 name,
+                dead_letter,
+                shared,
             },
             pos,
         ))
@@ -73,6 +210,8 @@ mod tests {
             stream_id: WireIdentifier::numeric(1),
             topic_id: WireIdentifier::numeric(2),
             name: WireName::new("my-group").unwrap(),
+            dead_letter: None,
+            shared: None,
         };
         let bytes = req.to_bytes();
         let (decoded, consumed) = CreateConsumerGroupRequest::decode(&bytes).unwrap();
@@ -86,6 +225,8 @@ mod tests {
             stream_id: WireIdentifier::named("stream-1").unwrap(),
             topic_id: WireIdentifier::named("topic-1").unwrap(),
             name: WireName::new("consumer-group-1").unwrap(),
+            dead_letter: None,
+            shared: None,
         };
         let bytes = req.to_bytes();
         let (decoded, consumed) = CreateConsumerGroupRequest::decode(&bytes).unwrap();
@@ -93,12 +234,79 @@ mod tests {
         assert_eq!(decoded, req);
     }
 
+    #[test]
+    fn roundtrip_with_dead_letter_policy() {
+        let req = CreateConsumerGroupRequest {
+            stream_id: WireIdentifier::numeric(1),
+            topic_id: WireIdentifier::numeric(2),
+            name: WireName::new("my-group").unwrap(),
+            dead_letter: Some(WireDeadLetterPolicy {
+                max_delivery_count: 5,
+                redelivery_timeout: 30_000_000,
+                stream_id: WireIdentifier::numeric(1),
+                topic_id: WireIdentifier::named("dlq").unwrap(),
+            }),
+            shared: None,
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        let (decoded, consumed) = CreateConsumerGroupRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn roundtrip_with_shared_subscription() {
+        let req = CreateConsumerGroupRequest {
+            stream_id: WireIdentifier::numeric(1),
+            topic_id: WireIdentifier::numeric(2),
+            name: WireName::new("my-group").unwrap(),
+            dead_letter: None,
+            shared: Some(WireSharedSubscription {
+                visibility_timeout: 30_000_000,
+            }),
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        let (decoded, consumed) = CreateConsumerGroupRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_options_return_error() {
+        let req = CreateConsumerGroupRequest {
+            stream_id: WireIdentifier::numeric(1),
+            topic_id: WireIdentifier::numeric(2),
+            name: WireName::new("grp").unwrap(),
+            dead_letter: Some(WireDeadLetterPolicy {
+                max_delivery_count: 3,
+                redelivery_timeout: 1_000_000,
+                stream_id: WireIdentifier::numeric(1),
+                topic_id: WireIdentifier::numeric(3),
+            }),
+            shared: Some(WireSharedSubscription {
+                visibility_timeout: 5_000_000,
+            }),
+        };
+        let bytes = req.to_bytes();
+        let name_end = 16;
+        for i in name_end + 1..bytes.len() {
+            assert!(
+                CreateConsumerGroupRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+
     #[test]
     fn truncated_returns_error() {
         let req = CreateConsumerGroupRequest {
             stream_id: WireIdentifier::numeric(1),
             topic_id: WireIdentifier::numeric(2),
             name: WireName::new("grp").unwrap(),
+            dead_letter: None,
+            shared: None,
         };
         let bytes = req.to_bytes();
         for i in 0..bytes.len() {
@@ -115,6 +323,8 @@ mod tests {
             stream_id: WireIdentifier::numeric(1),
             topic_id: WireIdentifier::numeric(2),
             name: WireName::new("grp").unwrap(),
+            dead_letter: None,
+            shared: None,
         };
         let bytes = req.to_bytes();
         // stream_id: [1,4, 1,0,0,0] + topic_id: [1,4, 2,0,0,0] + name: [3, g,r,p]
@@ -15,16 +15,24 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod ack_messages;
 pub mod create_consumer_group;
 pub mod delete_consumer_group;
 pub mod get_consumer_group;
+pub mod get_consumer_group_lag;
 pub mod get_consumer_groups;
 pub mod join_consumer_group;
 pub mod leave_consumer_group;
+pub mod nack_message;
 
-pub use create_consumer_group::CreateConsumerGroupRequest;
+pub use ack_messages::{AckMessagesRequest, MAX_ACK_OFFSETS_COUNT};
+pub use create_consumer_group::{
+    CreateConsumerGroupRequest, WireDeadLetterPolicy, WireSharedSubscription,
+};
 pub use delete_consumer_group::DeleteConsumerGroupRequest;
 pub use get_consumer_group::GetConsumerGroupRequest;
+pub use get_consumer_group_lag::GetConsumerGroupLagRequest;
 pub use get_consumer_groups::GetConsumerGroupsRequest;
 pub use join_consumer_group::JoinConsumerGroupRequest;
 pub use leave_consumer_group::LeaveConsumerGroupRequest;
+pub use nack_message::{MAX_NACK_REASON_LENGTH, NackMessageRequest};
@@ -0,0 +1,143 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::WireIdentifier;
+use crate::codec::{WireDecode, WireEncode, read_str, read_u8, read_u32_le, read_u64_le};
+use bytes::{BufMut, BytesMut};
+
+/// Maximum length of the negative acknowledgement reason.
+pub const MAX_NACK_REASON_LENGTH: usize = 255;
+
+/// `NackMessage` request.
+///
+/// Wire format:
+/// `[stream_id][topic_id][group_id][partition_id:u32_le][offset:u64_le][reason_len:u8][reason:N]`
+///
+/// The reason may be empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NackMessageRequest {
+    pub stream_id: WireIdentifier,
+    pub topic_id: WireIdentifier,
+    pub group_id: WireIdentifier,
+    pub partition_id: u32,
+    pub offset: u64,
+    pub reason: String,
+}
+
+impl WireEncode for NackMessageRequest {
+    fn encoded_size(&self) -> usize {
+        self.stream_id.encoded_size()
+            + self.topic_id.encoded_size()
+            + self.group_id.encoded_size()
+            + 4
+            + 8
+            + 1
+            + self.reason.len()
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn encode(&self, buf: &mut BytesMut) {
+        self.stream_id.encode(buf);
+        self.topic_id.encode(buf);
+        self.group_id.encode(buf);
+        buf.put_u32_le(self.partition_id);
+        buf.put_u64_le(self.offset);
+        buf.put_u8(self.reason.len() as u8);
+        buf.put_slice(self.reason.as_bytes());
+    }
+}
+
+impl WireDecode for NackMessageRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let mut pos = 0;
+        let (stream_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        let (topic_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        let (group_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        let partition_id = read_u32_le(buf, pos)?;
+        pos += 4;
+        let offset = read_u64_le(buf, pos)?;
+        pos += 8;
+        let reason_len = read_u8(buf, pos)? as usize;
+        pos += 1;
+        let reason = read_str(buf, pos, reason_len)?;
+        pos += reason_len;
+        Ok((
+            Self {
+                stream_id,
+                topic_id,
+                group_id,
+                partition_id,
+                offset,
+                reason,
+            },
+            pos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_request() -> NackMessageRequest {
+        NackMessageRequest {
+            stream_id: WireIdentifier::numeric(1),
+            topic_id: WireIdentifier::named("orders").unwrap(),
+            group_id: WireIdentifier::numeric(3),
+            partition_id: 2,
+            offset: 42,
+            reason: "invalid payload".to_string(),
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let req = sample_request();
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        let (decoded, consumed) = NackMessageRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn roundtrip_empty_reason() {
+        let req = NackMessageRequest {
+            reason: String::new(),
+            ..sample_request()
+        };
+        let bytes = req.to_bytes();
+        let (decoded, consumed) = NackMessageRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let bytes = sample_request().to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                NackMessageRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
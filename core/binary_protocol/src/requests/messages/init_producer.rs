@@ -0,0 +1,74 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_u64_le};
+use bytes::{BufMut, BytesMut};
+
+/// `InitProducer` request.
+///
+/// Wire format (8 bytes fixed):
+/// ```text
+/// [producer_id:8 LE]
+/// ```
+///
+/// A zero `producer_id` requests a new producer ID, any other value resumes the
+/// existing producer with a bumped epoch, fencing off its previous instances.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitProducerRequest {
+    pub producer_id: u64,
+}
+
+impl WireEncode for InitProducerRequest {
+    fn encoded_size(&self) -> usize {
+        8
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.producer_id);
+    }
+}
+
+impl WireDecode for InitProducerRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let producer_id = read_u64_le(buf, 0)?;
+        Ok((Self { producer_id }, 8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let req = InitProducerRequest { producer_id: 42 };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), 8);
+        let (decoded, consumed) = InitProducerRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, 8);
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let bytes = InitProducerRequest { producer_id: 1 }.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(InitProducerRequest::decode(&bytes[..i]).is_err());
+        }
+    }
+}
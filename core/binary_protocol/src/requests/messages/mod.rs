@@ -16,11 +16,14 @@
 // under the License.
 
 pub mod flush_unsaved_buffer;
+pub mod init_producer;
 pub mod poll_messages;
 pub mod send_messages;
 
 pub use flush_unsaved_buffer::FlushUnsavedBufferRequest;
+pub use init_producer::InitProducerRequest;
 pub use poll_messages::PollMessagesRequest;
 pub use send_messages::{
     RawMessage, SendMessagesEncoder, SendMessagesHeader, SendMessagesMetadataEncoder,
+    WireProducerSequence,
 };
@@ -17,7 +17,7 @@
 
 use crate::WireError;
 use crate::WireIdentifier;
-use crate::codec::{WireDecode, WireEncode, read_u8, read_u32_le};
+use crate::codec::{WireDecode, WireEncode, read_str, read_u8, read_u32_le};
 use crate::primitives::consumer::WireConsumer;
 use crate::primitives::polling_strategy::WirePollingStrategy;
 use bytes::{BufMut, BytesMut};
@@ -27,11 +27,19 @@ use bytes::{BufMut, BytesMut};
 /// Wire format:
 /// ```text
 /// [consumer][stream_id][topic_id][partition_flag:1][partition_id:4 LE]
-/// [strategy:9][count:4 LE][auto_commit:1]
+/// [strategy:9][count:4 LE][auto_commit:1][isolation_level:1]?
+/// [filter_length:4 LE][filter:N]?
 /// ```
 ///
 /// `partition_id` encoding: a u8 flag (1=Some, 0=None) followed by 4 bytes
 /// for the u32 value (0 when None).
+///
+/// `isolation_level` is optional: the trailing byte is written only for a
+/// non-default level (1=read committed), and a missing byte decodes as the
+/// default 0 (read uncommitted), so older clients keep working unchanged.
+///
+/// `filter` is optional as well: the UTF-8 expression over the user headers
+/// follows the isolation level byte, which is then always written.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PollMessagesRequest {
     pub consumer: WireConsumer,
@@ -41,6 +49,8 @@ pub struct PollMessagesRequest {
     pub strategy: WirePollingStrategy,
     pub count: u32,
     pub auto_commit: bool,
+    pub isolation_level: u8,
+    pub filter: Option<String>,
 }
 
 const PARTITION_FLAG_SIZE: usize = 1;
@@ -48,6 +58,8 @@ const PARTITION_VALUE_SIZE: usize = 4;
 const STRATEGY_SIZE: usize = 9;
 const COUNT_SIZE: usize = 4;
 const AUTO_COMMIT_SIZE: usize = 1;
+const ISOLATION_LEVEL_SIZE: usize = 1;
+const FILTER_LENGTH_SIZE: usize = 4;
 
 impl WireEncode for PollMessagesRequest {
     fn encoded_size(&self) -> usize {
@@ -59,6 +71,11 @@ impl WireEncode for PollMessagesRequest {
             + STRATEGY_SIZE
             + COUNT_SIZE
             + AUTO_COMMIT_SIZE
+            + match &self.filter {
+                Some(filter) => ISOLATION_LEVEL_SIZE + FILTER_LENGTH_SIZE + filter.len(),
+                None if self.isolation_level != 0 => ISOLATION_LEVEL_SIZE,
+                None => 0,
+            }
     }
 
     fn encode(&self, buf: &mut BytesMut) {
@@ -75,6 +92,14 @@ impl WireEncode for PollMessagesRequest {
         self.strategy.encode(buf);
         buf.put_u32_le(self.count);
         buf.put_u8(u8::from(self.auto_commit));
+        if self.isolation_level != 0 || self.filter.is_some() {
+            buf.put_u8(self.isolation_level);
+        }
+        if let Some(filter) = &self.filter {
+            #[allow(clippy::cast_possible_truncation)]
+            buf.put_u32_le(filter.len() as u32);
+            buf.put_slice(filter.as_bytes());
+        }
     }
 }
 
@@ -104,6 +129,21 @@ impl WireDecode for PollMessagesRequest {
         pos += 4;
         let auto_commit = read_u8(buf, pos)? != 0;
         pos += 1;
+        let isolation_level = if buf.len() > pos {
+            pos += 1;
+            read_u8(buf, pos - 1)?
+        } else {
+            0
+        };
+        let filter = if buf.len() > pos {
+            let length = read_u32_le(buf, pos)? as usize;
+            pos += 4;
+            let filter = read_str(buf, pos, length)?;
+            pos += length;
+            Some(filter)
+        } else {
+            None
+        };
 
         Ok((
             Self {
@@ -114,6 +154,8 @@ impl WireDecode for PollMessagesRequest {
                 strategy,
                 count,
                 auto_commit,
+                isolation_level,
+                filter,
             },
             pos,
         ))
@@ -134,6 +176,8 @@ mod tests {
             strategy: WirePollingStrategy::offset(100),
             count: 50,
             auto_commit: true,
+            isolation_level: 0,
+            filter: None,
         };
         let bytes = req.to_bytes();
         let (decoded, consumed) = PollMessagesRequest::decode(&bytes).unwrap();
@@ -151,6 +195,8 @@ mod tests {
             strategy: WirePollingStrategy::first(),
             count: 10,
             auto_commit: false,
+            isolation_level: 0,
+            filter: None,
         };
         let bytes = req.to_bytes();
         let (decoded, consumed) = PollMessagesRequest::decode(&bytes).unwrap();
@@ -168,6 +214,8 @@ mod tests {
             strategy: WirePollingStrategy::offset(0),
             count: 1,
             auto_commit: false,
+            isolation_level: 0,
+            filter: None,
         };
         let bytes = req.to_bytes();
         let (decoded, consumed) = PollMessagesRequest::decode(&bytes).unwrap();
@@ -185,6 +233,8 @@ mod tests {
             strategy: WirePollingStrategy::first(),
             count: 1,
             auto_commit: false,
+            isolation_level: 0,
+            filter: None,
         };
         let bytes = req.to_bytes();
         // After consumer(7) + stream_id(6) + topic_id(6) = offset 19
@@ -208,6 +258,8 @@ mod tests {
             strategy: WirePollingStrategy::offset(0),
             count: 1,
             auto_commit: false,
+            isolation_level: 0,
+            filter: None,
         };
         let bytes = req.to_bytes();
         for i in 0..bytes.len() {
@@ -217,4 +269,69 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn roundtrip_read_committed() {
+        let req = PollMessagesRequest {
+            consumer: WireConsumer::consumer(WireIdentifier::numeric(1)),
+            stream_id: WireIdentifier::numeric(1),
+            topic_id: WireIdentifier::numeric(1),
+            partition_id: Some(1),
+            strategy: WirePollingStrategy::offset(0),
+            count: 1,
+            auto_commit: true,
+            isolation_level: 1,
+            filter: None,
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        assert_eq!(bytes[bytes.len() - 1], 1);
+        let (decoded, consumed) = PollMessagesRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn default_isolation_level_is_not_encoded() {
+        let mut req = PollMessagesRequest {
+            consumer: WireConsumer::consumer(WireIdentifier::numeric(1)),
+            stream_id: WireIdentifier::numeric(1),
+            topic_id: WireIdentifier::numeric(1),
+            partition_id: Some(1),
+            strategy: WirePollingStrategy::offset(0),
+            count: 1,
+            auto_commit: false,
+            isolation_level: 0,
+            filter: None,
+        };
+        let uncommitted = req.to_bytes();
+        req.isolation_level = 1;
+        let committed = req.to_bytes();
+        assert_eq!(committed.len(), uncommitted.len() + 1);
+        assert_eq!(&committed[..uncommitted.len()], &uncommitted[..]);
+    }
+
+    #[test]
+    fn roundtrip_with_filter() {
+        let req = PollMessagesRequest {
+            consumer: WireConsumer::consumer(WireIdentifier::numeric(1)),
+            stream_id: WireIdentifier::numeric(1),
+            topic_id: WireIdentifier::numeric(1),
+            partition_id: Some(1),
+            strategy: WirePollingStrategy::next(),
+            count: 10,
+            auto_commit: false,
+            isolation_level: 0,
+            filter: Some("tenant == \"acme\" && priority >= 5".to_string()),
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        let (decoded, consumed) = PollMessagesRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+
+        for i in bytes.len() - 5..bytes.len() {
+            assert!(PollMessagesRequest::decode(&bytes[..i]).is_err());
+        }
+    }
 }
@@ -19,7 +19,7 @@
 //!
 //! Messages are written directly to the buffer without intermediate allocation.
 
-use crate::codec::{WireDecode, WireEncode, read_u32_le};
+use crate::codec::{WireDecode, WireEncode, read_u32_le, read_u64_le};
 use crate::error::WireError;
 use crate::message_layout::{WIRE_MESSAGE_HEADER_SIZE, WIRE_MESSAGE_INDEX_SIZE};
 use crate::primitives::identifier::WireIdentifier;
@@ -58,6 +58,51 @@ impl RawMessage<'_> {
     }
 }
 
+/// Identity of an idempotent producer and the sequence number of the first message in the batch.
+///
+/// Wire format (24 bytes fixed), appended to the `SendMessages` metadata:
+/// ```text
+/// [producer_id:8 LE][producer_epoch:8 LE][base_sequence:8 LE]
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WireProducerSequence {
+    pub producer_id: u64,
+    pub producer_epoch: u64,
+    pub base_sequence: u64,
+}
+
+impl WireProducerSequence {
+    pub const SIZE: usize = 8 + 8 + 8;
+}
+
+impl WireEncode for WireProducerSequence {
+    fn encoded_size(&self) -> usize {
+        Self::SIZE
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.producer_id);
+        buf.put_u64_le(self.producer_epoch);
+        buf.put_u64_le(self.base_sequence);
+    }
+}
+
+impl WireDecode for WireProducerSequence {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let producer_id = read_u64_le(buf, 0)?;
+        let producer_epoch = read_u64_le(buf, 8)?;
+        let base_sequence = read_u64_le(buf, 16)?;
+        Ok((
+            Self {
+                producer_id,
+                producer_epoch,
+                base_sequence,
+            },
+            Self::SIZE,
+        ))
+    }
+}
+
 /// Zero-copy encoder for the `SendMessages` command payload.
 ///
 /// Wire layout:
@@ -67,6 +112,7 @@ impl RawMessage<'_> {
 /// [topic_id:variable]
 /// [partitioning:variable]
 /// [messages_count:u32_le]
+/// [producer_sequence:24 bytes, optional]
 /// [index_array: messages_count * 16 bytes]
 /// [message_data: variable]
 /// ```
@@ -80,22 +126,43 @@ impl SendMessagesEncoder {
         partitioning: &WirePartitioning,
         messages: &[RawMessage<'_>],
     ) -> usize {
-        let metadata_inner =
-            stream_id.encoded_size() + topic_id.encoded_size() + partitioning.encoded_size() + 4;
+        Self::encoded_size_with_producer(stream_id, topic_id, partitioning, None, messages)
+    }
+
+    pub fn encode(
+        buf: &mut BytesMut,
+        stream_id: &WireIdentifier,
+        topic_id: &WireIdentifier,
+        partitioning: &WirePartitioning,
+        messages: &[RawMessage<'_>],
+    ) {
+        Self::encode_with_producer(buf, stream_id, topic_id, partitioning, None, messages);
+    }
+
+    #[must_use]
+    pub fn encoded_size_with_producer(
+        stream_id: &WireIdentifier,
+        topic_id: &WireIdentifier,
+        partitioning: &WirePartitioning,
+        producer: Option<&WireProducerSequence>,
+        messages: &[RawMessage<'_>],
+    ) -> usize {
+        let metadata_inner = Self::metadata_length(stream_id, topic_id, partitioning, producer);
         let index_total = messages.len() * WIRE_MESSAGE_INDEX_SIZE;
         let messages_total: usize = messages.iter().map(RawMessage::wire_size).sum();
         4 + metadata_inner + index_total + messages_total
     }
 
-    pub fn encode(
+    /// Encodes the messages sent by an idempotent producer, or regular messages if `producer` is `None`.
+    pub fn encode_with_producer(
         buf: &mut BytesMut,
         stream_id: &WireIdentifier,
         topic_id: &WireIdentifier,
         partitioning: &WirePartitioning,
+        producer: Option<&WireProducerSequence>,
         messages: &[RawMessage<'_>],
     ) {
-        let metadata_inner =
-            stream_id.encoded_size() + topic_id.encoded_size() + partitioning.encoded_size() + 4;
+        let metadata_inner = Self::metadata_length(stream_id, topic_id, partitioning, producer);
 
         #[allow(clippy::cast_possible_truncation)]
         buf.put_u32_le(metadata_inner as u32);
@@ -107,6 +174,10 @@ impl SendMessagesEncoder {
         #[allow(clippy::cast_possible_truncation)]
         buf.put_u32_le(messages.len() as u32);
 
+        if let Some(producer) = producer {
+            producer.encode(buf);
+        }
+
         // Index array: cumulative sizes for each message
         let mut cumulative_size: u32 = 0;
         for msg in messages {
@@ -145,6 +216,21 @@ impl SendMessagesEncoder {
     }
 }
 
+impl SendMessagesEncoder {
+    fn metadata_length(
+        stream_id: &WireIdentifier,
+        topic_id: &WireIdentifier,
+        partitioning: &WirePartitioning,
+        producer: Option<&WireProducerSequence>,
+    ) -> usize {
+        stream_id.encoded_size()
+            + topic_id.encoded_size()
+            + partitioning.encoded_size()
+            + 4
+            + producer.map_or(0, |_| WireProducerSequence::SIZE)
+    }
+}
+
 /// Metadata-only decoder for the `SendMessages` command.
 ///
 /// Parses routing metadata (stream, topic, partitioning, message count)
@@ -366,6 +452,52 @@ mod tests {
         assert_eq!(metadata_len, expected);
     }
 
+    #[test]
+    fn verify_producer_sequence_in_metadata() {
+        let stream_id = numeric_id(1);
+        let topic_id = numeric_id(2);
+        let partitioning = WirePartitioning::PartitionId(0);
+        let producer = WireProducerSequence {
+            producer_id: 7,
+            producer_epoch: 3,
+            base_sequence: 100,
+        };
+        let messages = [RawMessage {
+            id: 1,
+            origin_timestamp: 0,
+            headers: None,
+            payload: b"x",
+        }];
+
+        let size = SendMessagesEncoder::encoded_size_with_producer(
+            &stream_id,
+            &topic_id,
+            &partitioning,
+            Some(&producer),
+            &messages,
+        );
+        let mut buf = BytesMut::with_capacity(size);
+        SendMessagesEncoder::encode_with_producer(
+            &mut buf,
+            &stream_id,
+            &topic_id,
+            &partitioning,
+            Some(&producer),
+            &messages,
+        );
+        assert_eq!(buf.len(), size);
+
+        let metadata_len = u32::from_le_bytes(buf[0..4].try_into().unwrap()) as usize;
+        let header_len =
+            stream_id.encoded_size() + topic_id.encoded_size() + partitioning.encoded_size() + 4;
+        assert_eq!(metadata_len, header_len + WireProducerSequence::SIZE);
+
+        let (header, consumed) = SendMessagesHeader::decode(&buf[4..]).unwrap();
+        assert_eq!(header.messages_count, 1);
+        let (decoded, _) = WireProducerSequence::decode(&buf[4 + consumed..]).unwrap();
+        assert_eq!(decoded, producer);
+    }
+
     #[test]
     fn verify_index_entries() {
         let stream_id = numeric_id(1);
@@ -0,0 +1,166 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_bytes, read_u8, read_u32_le};
+use crate::primitives::identifier::WireName;
+use crate::primitives::permissions::WirePermissions;
+use bytes::{BufMut, BytesMut};
+use std::borrow::Cow;
+
+/// `CreateRole` request.
+///
+/// Wire format:
+/// `[name_len:u8][name:N][has_permissions:u8][permissions_len:u32_le?][permissions:M?]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateRoleRequest {
+    pub name: WireName,
+    pub permissions: Option<WirePermissions>,
+}
+
+impl WireEncode for CreateRoleRequest {
+    fn encoded_size(&self) -> usize {
+        self.name.encoded_size()
+            + 1 // has_permissions
+            + self
+                .permissions
+                .as_ref()
+                .map_or(0, |p| 4 + p.encoded_size())
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        self.name.encode(buf);
+        if let Some(perms) = &self.permissions {
+            buf.put_u8(1);
+            let perm_bytes = perms.to_bytes();
+            #[allow(clippy::cast_possible_truncation)]
+            buf.put_u32_le(perm_bytes.len() as u32);
+            buf.put_slice(&perm_bytes);
+        } else {
+            buf.put_u8(0);
+        }
+    }
+}
+
+impl WireDecode for CreateRoleRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let (name, mut pos) = WireName::decode(buf)?;
+
+        let has_permissions = read_u8(buf, pos)?;
+        pos += 1;
+
+        let permissions = if has_permissions == 1 {
+            let perm_len = read_u32_le(buf, pos)? as usize;
+            pos += 4;
+            let (perms, consumed) = WirePermissions::decode(read_bytes(buf, pos, perm_len)?)?;
+            if consumed != perm_len {
+                return Err(WireError::Validation(Cow::Owned(format!(
+                    "permissions length mismatch: header says {perm_len}, decoded {consumed}"
+                ))));
+            }
+            pos += consumed;
+            Some(perms)
+        } else {
+            None
+        };
+
+        Ok((Self { name, permissions }, pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::permissions::{
+        WireGlobalPermissions, WireStreamPermissions, WireTopicPermissions,
+    };
+
+    fn sample_permissions() -> WirePermissions {
+        WirePermissions {
+            global: WireGlobalPermissions {
+                manage_servers: false,
+                read_servers: false,
+                manage_users: false,
+                read_users: false,
+                manage_streams: false,
+                read_streams: true,
+                manage_topics: false,
+                read_topics: false,
+                poll_messages: false,
+                send_messages: false,
+            },
+            streams: vec![WireStreamPermissions {
+                stream_id: 1,
+                manage_stream: false,
+                read_stream: true,
+                manage_topics: false,
+                read_topics: true,
+                poll_messages: true,
+                send_messages: false,
+                topics: vec![WireTopicPermissions {
+                    topic_id: 2,
+                    manage_topic: false,
+                    read_topic: true,
+                    poll_messages: true,
+                    send_messages: true,
+                }],
+            }],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
+        }
+    }
+
+    #[test]
+    fn roundtrip_with_permissions() {
+        let req = CreateRoleRequest {
+            name: WireName::new("readers").unwrap(),
+            permissions: Some(sample_permissions()),
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        let (decoded, consumed) = CreateRoleRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn roundtrip_without_permissions() {
+        let req = CreateRoleRequest {
+            name: WireName::new("empty").unwrap(),
+            permissions: None,
+        };
+        let bytes = req.to_bytes();
+        let (decoded, consumed) = CreateRoleRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let req = CreateRoleRequest {
+            name: WireName::new("readers").unwrap(),
+            permissions: Some(sample_permissions()),
+        };
+        let bytes = req.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                CreateRoleRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
@@ -0,0 +1,32 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+pub mod assign_role;
+pub mod create_role;
+pub mod delete_role;
+pub mod get_role;
+pub mod get_roles;
+pub mod unassign_role;
+pub mod update_role;
+
+pub use assign_role::AssignRoleRequest;
+pub use create_role::CreateRoleRequest;
+pub use delete_role::DeleteRoleRequest;
+pub use get_role::GetRoleRequest;
+pub use get_roles::GetRolesRequest;
+pub use unassign_role::UnassignRoleRequest;
+pub use update_role::UpdateRoleRequest;
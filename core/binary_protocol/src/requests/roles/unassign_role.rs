@@ -0,0 +1,83 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::WireIdentifier;
+use crate::codec::{WireDecode, WireEncode};
+use bytes::BytesMut;
+
+/// `UnassignRole` request. Revokes the permissions granted to the user by the role.
+///
+/// Wire format: `[user_id:WireIdentifier][role_id:WireIdentifier]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnassignRoleRequest {
+    pub user_id: WireIdentifier,
+    pub role_id: WireIdentifier,
+}
+
+impl WireEncode for UnassignRoleRequest {
+    fn encoded_size(&self) -> usize {
+        self.user_id.encoded_size() + self.role_id.encoded_size()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        self.user_id.encode(buf);
+        self.role_id.encode(buf);
+    }
+}
+
+impl WireDecode for UnassignRoleRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let (user_id, mut pos) = WireIdentifier::decode(buf)?;
+        let (role_id, consumed) = WireIdentifier::decode(&buf[pos..])?;
+        pos += consumed;
+        Ok((Self { user_id, role_id }, pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let req = UnassignRoleRequest {
+            user_id: WireIdentifier::named("service").unwrap(),
+            role_id: WireIdentifier::numeric(2),
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        let (decoded, consumed) = UnassignRoleRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let req = UnassignRoleRequest {
+            user_id: WireIdentifier::numeric(1),
+            role_id: WireIdentifier::named("readers").unwrap(),
+        };
+        let bytes = req.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                UnassignRoleRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
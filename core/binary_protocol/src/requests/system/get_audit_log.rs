@@ -0,0 +1,145 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_str, read_u8, read_u32_le, read_u64_le};
+use bytes::{BufMut, BytesMut};
+
+/// `GetAuditLog` request: the filters of the audit records to return.
+///
+/// Wire format:
+/// ```text
+/// [user_id_flag:1][user_id:4][from:8][to:8][operation_len:1][operation:N][limit:4]
+/// ```
+///
+/// `from` and `to` are timestamps in microseconds, with 0 meaning no bound.
+/// An empty `operation` matches every operation, and `limit` of 0 returns all the records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetAuditLogRequest {
+    pub user_id: Option<u32>,
+    pub from: u64,
+    pub to: u64,
+    pub operation: String,
+    pub limit: u32,
+}
+
+impl GetAuditLogRequest {
+    const FIXED_SIZE: usize = 1 + 4 + 8 + 8 + 1 + 4; // 26
+}
+
+impl WireEncode for GetAuditLogRequest {
+    fn encoded_size(&self) -> usize {
+        Self::FIXED_SIZE + self.operation.len()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        if let Some(user_id) = self.user_id {
+            buf.put_u8(1);
+            buf.put_u32_le(user_id);
+        } else {
+            buf.put_u8(0);
+            buf.put_u32_le(0);
+        }
+        buf.put_u64_le(self.from);
+        buf.put_u64_le(self.to);
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u8(self.operation.len() as u8);
+        buf.put_slice(self.operation.as_bytes());
+        buf.put_u32_le(self.limit);
+    }
+}
+
+impl WireDecode for GetAuditLogRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let user_id_flag = read_u8(buf, 0)?;
+        let user_id_raw = read_u32_le(buf, 1)?;
+        let from = read_u64_le(buf, 5)?;
+        let to = read_u64_le(buf, 13)?;
+        let operation_len = read_u8(buf, 21)? as usize;
+        let operation = read_str(buf, 22, operation_len)?;
+        let limit = read_u32_le(buf, 22 + operation_len)?;
+        let user_id = if user_id_flag == 1 {
+            Some(user_id_raw)
+        } else {
+            None
+        };
+        Ok((
+            Self {
+                user_id,
+                from,
+                to,
+                operation,
+                limit,
+            },
+            Self::FIXED_SIZE + operation_len,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let req = GetAuditLogRequest {
+            user_id: Some(0),
+            from: 1_000_000,
+            to: 2_000_000,
+            operation: "stream.create".to_string(),
+            limit: 100,
+        };
+        let bytes = req.to_bytes();
+        let (decoded, consumed) = GetAuditLogRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn roundtrip_without_filters() {
+        let req = GetAuditLogRequest {
+            user_id: None,
+            from: 0,
+            to: 0,
+            operation: String::new(),
+            limit: 0,
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), GetAuditLogRequest::FIXED_SIZE);
+        let (decoded, consumed) = GetAuditLogRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, GetAuditLogRequest::FIXED_SIZE);
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let req = GetAuditLogRequest {
+            user_id: Some(1),
+            from: 1,
+            to: 2,
+            operation: "user".to_string(),
+            limit: 10,
+        };
+        let bytes = req.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                GetAuditLogRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
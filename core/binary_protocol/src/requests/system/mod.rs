@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod get_audit_log;
 pub mod get_client;
 pub mod get_clients;
 pub mod get_cluster_metadata;
@@ -23,6 +24,7 @@ pub mod get_snapshot;
 pub mod get_stats;
 pub mod ping;
 
+pub use get_audit_log::GetAuditLogRequest;
 pub use get_client::GetClientRequest;
 pub use get_clients::GetClientsRequest;
 pub use get_cluster_metadata::GetClusterMetadataRequest;
@@ -111,6 +111,7 @@ mod tests {
                 max_topic_size: 1024,
                 replication_factor: 1,
                 name: WireName::new("events").unwrap(),
+                cleanup_policy: 1,
             },
             partitions: vec![
                 CreatedPartitionAssignment {
@@ -21,6 +21,7 @@ pub mod delete_topic;
 pub mod get_topic;
 pub mod get_topics;
 pub mod purge_topic;
+pub mod rotate_topic_key;
 pub mod update_topic;
 
 pub use create_topic::CreateTopicRequest;
@@ -29,4 +30,8 @@ pub use delete_topic::DeleteTopicRequest;
 pub use get_topic::GetTopicRequest;
 pub use get_topics::GetTopicsRequest;
 pub use purge_topic::PurgeTopicRequest;
+pub use rotate_topic_key::RotateTopicKeyRequest;
 pub use update_topic::UpdateTopicRequest;
+
+/// Code of the `delete` cleanup policy, used when a topic request doesn't carry one.
+pub const DEFAULT_CLEANUP_POLICY: u8 = 1;
@@ -19,13 +19,18 @@ use crate::WireError;
 use crate::WireIdentifier;
 use crate::codec::{WireDecode, WireEncode, read_u8, read_u64_le};
 use crate::primitives::identifier::WireName;
+use crate::requests::topics::DEFAULT_CLEANUP_POLICY;
 use bytes::{BufMut, BytesMut};
 
 /// `UpdateTopic` request.
 ///
 /// Wire format:
 /// `[stream_id:WireIdentifier][topic_id:WireIdentifier][compression_algorithm:u8]
-///  [message_expiry:u64_le][max_topic_size:u64_le][replication_factor:u8][name_len:u8][name:N]`
+///  [message_expiry:u64_le][max_topic_size:u64_le][replication_factor:u8][name_len:u8][name:N]
+///  [cleanup_policy:u8]`
+///
+/// The trailing `cleanup_policy` is optional on decode, requests without it (sent by older
+/// clients or persisted before it existed) use [`DEFAULT_CLEANUP_POLICY`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UpdateTopicRequest {
     pub stream_id: WireIdentifier,
@@ -35,9 +40,10 @@ pub struct UpdateTopicRequest {
     pub max_topic_size: u64,
     pub replication_factor: u8,
     pub name: WireName,
+    pub cleanup_policy: u8,
 }
 
-const FIXED_FIELDS_SIZE: usize = 1 + 8 + 8 + 1; // 18 bytes
+const FIXED_FIELDS_SIZE: usize = 1 + 8 + 8 + 1 + 1; // 19 bytes
 
 impl WireEncode for UpdateTopicRequest {
     fn encoded_size(&self) -> usize {
@@ -55,6 +61,7 @@ impl WireEncode for UpdateTopicRequest {
         buf.put_u64_le(self.max_topic_size);
         buf.put_u8(self.replication_factor);
         self.name.encode(buf);
+        buf.put_u8(self.cleanup_policy);
     }
 }
 
@@ -73,6 +80,13 @@ impl WireDecode for UpdateTopicRequest {
         pos += 1;
         let (name, name_consumed) = WireName::decode(&buf[pos..])?;
         pos += name_consumed;
+        let cleanup_policy = if pos < buf.len() {
+            let cleanup_policy = read_u8(buf, pos)?;
+            pos += 1;
+            cleanup_policy
+        } else {
+            DEFAULT_CLEANUP_POLICY
+        };
         Ok((
             Self {
                 stream_id,
@@ -82,6 +96,7 @@ impl WireDecode for UpdateTopicRequest {
                 max_topic_size,
                 replication_factor,
                 name,
+                cleanup_policy,
             },
             pos,
         ))
@@ -101,6 +116,7 @@ mod tests {
             max_topic_size: 500_000,
             replication_factor: 2,
             name: WireName::new("updated-topic").unwrap(),
+            cleanup_policy: 2,
         }
     }
 
@@ -123,6 +139,7 @@ mod tests {
             max_topic_size: u64::MAX,
             replication_factor: 1,
             name: WireName::new("new-name").unwrap(),
+            cleanup_policy: DEFAULT_CLEANUP_POLICY,
         };
         let bytes = req.to_bytes();
         let (decoded, consumed) = UpdateTopicRequest::decode(&bytes).unwrap();
@@ -134,7 +151,8 @@ mod tests {
     fn truncated_returns_error() {
         let req = sample_request();
         let bytes = req.to_bytes();
-        for i in 0..bytes.len() {
+        // The trailing cleanup policy is optional, so only shorter prefixes are invalid.
+        for i in 0..bytes.len() - 1 {
             assert!(
                 UpdateTopicRequest::decode(&bytes[..i]).is_err(),
                 "expected error for truncation at byte {i}"
@@ -142,6 +160,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn missing_cleanup_policy_decodes_as_default() {
+        let req = sample_request();
+        let bytes = req.to_bytes();
+        let (decoded, consumed) = UpdateTopicRequest::decode(&bytes[..bytes.len() - 1]).unwrap();
+        assert_eq!(consumed, bytes.len() - 1);
+        assert_eq!(decoded.cleanup_policy, DEFAULT_CLEANUP_POLICY);
+        assert_eq!(decoded.name, req.name);
+    }
+
     #[test]
     fn encoded_size_matches_output() {
         let req = sample_request();
@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_u64_le};
+use bytes::{BufMut, BytesMut};
+
+/// `AbortTransaction` request.
+///
+/// Wire format (8 bytes fixed):
+/// ```text
+/// [transaction_id:8 LE]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbortTransactionRequest {
+    pub transaction_id: u64,
+}
+
+impl WireEncode for AbortTransactionRequest {
+    fn encoded_size(&self) -> usize {
+        8
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.transaction_id);
+    }
+}
+
+impl WireDecode for AbortTransactionRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let transaction_id = read_u64_le(buf, 0)?;
+        Ok((Self { transaction_id }, 8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let req = AbortTransactionRequest {
+            transaction_id: 0x0102_0304_0506_0708,
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), 8);
+        let (decoded, consumed) = AbortTransactionRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, 8);
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let bytes = AbortTransactionRequest { transaction_id: 1 }.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(AbortTransactionRequest::decode(&bytes[..i]).is_err());
+        }
+    }
+}
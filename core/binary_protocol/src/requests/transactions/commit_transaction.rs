@@ -0,0 +1,209 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::WireIdentifier;
+use crate::codec::{WireDecode, WireEncode, read_u8, read_u32_le, read_u64_le};
+use crate::primitives::consumer::WireConsumer;
+use bytes::{BufMut, BytesMut};
+
+/// Consumer offset stored atomically with the commit of a transaction.
+///
+/// Wire format:
+/// ```text
+/// [consumer][stream_id][topic_id][partition_flag:1][partition_id:4 LE][offset:8 LE]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WireTransactionOffset {
+    pub consumer: WireConsumer,
+    pub stream_id: WireIdentifier,
+    pub topic_id: WireIdentifier,
+    pub partition_id: Option<u32>,
+    pub offset: u64,
+}
+
+impl WireEncode for WireTransactionOffset {
+    fn encoded_size(&self) -> usize {
+        self.consumer.encoded_size()
+            + self.stream_id.encoded_size()
+            + self.topic_id.encoded_size()
+            + 1
+            + 4
+            + 8
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        self.consumer.encode(buf);
+        self.stream_id.encode(buf);
+        self.topic_id.encode(buf);
+        if let Some(pid) = self.partition_id {
+            buf.put_u8(1);
+            buf.put_u32_le(pid);
+        } else {
+            buf.put_u8(0);
+            buf.put_u32_le(0);
+        }
+        buf.put_u64_le(self.offset);
+    }
+}
+
+impl WireDecode for WireTransactionOffset {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let mut pos = 0;
+        let (consumer, n) = WireConsumer::decode(buf)?;
+        pos += n;
+        let (stream_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        let (topic_id, n) = WireIdentifier::decode(&buf[pos..])?;
+        pos += n;
+        let partition_flag = read_u8(buf, pos)?;
+        pos += 1;
+        let partition_raw = read_u32_le(buf, pos)?;
+        pos += 4;
+        let partition_id = if partition_flag == 1 {
+            Some(partition_raw)
+        } else {
+            None
+        };
+        let offset = read_u64_le(buf, pos)?;
+        pos += 8;
+        Ok((
+            Self {
+                consumer,
+                stream_id,
+                topic_id,
+                partition_id,
+                offset,
+            },
+            pos,
+        ))
+    }
+}
+
+/// `CommitTransaction` request.
+///
+/// Wire format:
+/// ```text
+/// [transaction_id:8 LE][offsets_count:4 LE][offset]*
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitTransactionRequest {
+    pub transaction_id: u64,
+    pub offsets: Vec<WireTransactionOffset>,
+}
+
+impl WireEncode for CommitTransactionRequest {
+    fn encoded_size(&self) -> usize {
+        8 + 4
+            + self
+                .offsets
+                .iter()
+                .map(WireEncode::encoded_size)
+                .sum::<usize>()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.transaction_id);
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u32_le(self.offsets.len() as u32);
+        for offset in &self.offsets {
+            offset.encode(buf);
+        }
+    }
+}
+
+impl WireDecode for CommitTransactionRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let transaction_id = read_u64_le(buf, 0)?;
+        let offsets_count = read_u32_le(buf, 8)? as usize;
+        let mut pos = 12;
+        let mut offsets = Vec::new();
+        for _ in 0..offsets_count {
+            let (offset, n) = WireTransactionOffset::decode(&buf[pos..])?;
+            pos += n;
+            offsets.push(offset);
+        }
+        Ok((
+            Self {
+                transaction_id,
+                offsets,
+            },
+            pos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> CommitTransactionRequest {
+        CommitTransactionRequest {
+            transaction_id: 42,
+            offsets: vec![
+                WireTransactionOffset {
+                    consumer: WireConsumer::consumer(WireIdentifier::numeric(1)),
+                    stream_id: WireIdentifier::numeric(10),
+                    topic_id: WireIdentifier::numeric(20),
+                    partition_id: Some(5),
+                    offset: 12345,
+                },
+                WireTransactionOffset {
+                    consumer: WireConsumer::consumer_group(WireIdentifier::named("group").unwrap()),
+                    stream_id: WireIdentifier::named("stream-1").unwrap(),
+                    topic_id: WireIdentifier::named("topic-1").unwrap(),
+                    partition_id: None,
+                    offset: u64::MAX,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let req = sample();
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        let (decoded, consumed) = CommitTransactionRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn roundtrip_without_offsets() {
+        let req = CommitTransactionRequest {
+            transaction_id: 7,
+            offsets: vec![],
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), 12);
+        let (decoded, consumed) = CommitTransactionRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, 12);
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let bytes = sample().to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                CommitTransactionRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
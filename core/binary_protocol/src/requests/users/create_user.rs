@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::WireError;
-use crate::codec::{WireDecode, WireEncode, read_str, read_u8, read_u32_le};
+use crate::codec::{WireDecode, WireEncode, read_bytes, read_str, read_u8, read_u32_le};
 use crate::primitives::identifier::WireName;
 use crate::primitives::permissions::WirePermissions;
 use bytes::{BufMut, BytesMut};
@@ -84,7 +84,7 @@ impl WireDecode for CreateUserRequest {
         let permissions = if has_permissions == 1 {
             let perm_len = read_u32_le(buf, pos)? as usize;
             pos += 4;
-            let (perms, consumed) = WirePermissions::decode(&buf[pos..])?;
+            let (perms, consumed) = WirePermissions::decode(read_bytes(buf, pos, perm_len)?)?;
             if consumed != perm_len {
                 return Err(WireError::Validation(Cow::Owned(format!(
                     "permissions length mismatch: header says {perm_len}, decoded {consumed}"
@@ -128,6 +128,8 @@ mod tests {
                 send_messages: true,
             },
             streams: vec![],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         }
     }
 
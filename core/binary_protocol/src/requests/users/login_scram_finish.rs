@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_bytes, read_str, read_u8};
+use bytes::{BufMut, BytesMut};
+
+/// `LoginScramFinish` request, the client-final message of the SCRAM-SHA-256 exchange.
+///
+/// Wire format:
+/// `[nonce_len:u8][nonce:N][client_proof_len:u8][client_proof:N]`
+///
+/// `nonce` is the combined client and server nonce returned by `LoginScramStart`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginScramFinishRequest {
+    pub nonce: String,
+    pub client_proof: Vec<u8>,
+}
+
+impl WireEncode for LoginScramFinishRequest {
+    fn encoded_size(&self) -> usize {
+        1 + self.nonce.len() + 1 + self.client_proof.len()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u8(self.nonce.len() as u8);
+        buf.put_slice(self.nonce.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u8(self.client_proof.len() as u8);
+        buf.put_slice(&self.client_proof);
+    }
+}
+
+impl WireDecode for LoginScramFinishRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let nonce_len = read_u8(buf, 0)? as usize;
+        let mut pos = 1;
+        let nonce = read_str(buf, pos, nonce_len)?;
+        pos += nonce_len;
+        let client_proof_len = read_u8(buf, pos)? as usize;
+        pos += 1;
+        let client_proof = read_bytes(buf, pos, client_proof_len)?.to_vec();
+        pos += client_proof_len;
+        Ok((
+            Self {
+                nonce,
+                client_proof,
+            },
+            pos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let req = LoginScramFinishRequest {
+            nonce: "client-nonce-server-nonce".to_string(),
+            client_proof: vec![7; 32],
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(req.encoded_size(), bytes.len());
+        let (decoded, consumed) = LoginScramFinishRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let req = LoginScramFinishRequest {
+            nonce: "n".to_string(),
+            client_proof: vec![1, 2],
+        };
+        let bytes = req.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                LoginScramFinishRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
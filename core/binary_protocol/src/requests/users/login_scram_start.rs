@@ -0,0 +1,94 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_str, read_u8};
+use crate::primitives::identifier::WireName;
+use bytes::{BufMut, BytesMut};
+
+/// `LoginScramStart` request, the client-first message of the SCRAM-SHA-256 exchange.
+///
+/// Wire format:
+/// `[username_len:u8][username:N][client_nonce_len:u8][client_nonce:N]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginScramStartRequest {
+    pub username: WireName,
+    pub client_nonce: String,
+}
+
+impl WireEncode for LoginScramStartRequest {
+    fn encoded_size(&self) -> usize {
+        self.username.encoded_size() + 1 + self.client_nonce.len()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        self.username.encode(buf);
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u8(self.client_nonce.len() as u8);
+        buf.put_slice(self.client_nonce.as_bytes());
+    }
+}
+
+impl WireDecode for LoginScramStartRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let (username, mut pos) = WireName::decode(buf)?;
+        let client_nonce_len = read_u8(buf, pos)? as usize;
+        pos += 1;
+        let client_nonce = read_str(buf, pos, client_nonce_len)?;
+        pos += client_nonce_len;
+        Ok((
+            Self {
+                username,
+                client_nonce,
+            },
+            pos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let req = LoginScramStartRequest {
+            username: WireName::new("admin").unwrap(),
+            client_nonce: "rOprNGfwEbeRWgbNEkqO".to_string(),
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(req.encoded_size(), bytes.len());
+        let (decoded, consumed) = LoginScramStartRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let req = LoginScramStartRequest {
+            username: WireName::new("u").unwrap(),
+            client_nonce: "n".to_string(),
+        };
+        let bytes = req.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                LoginScramStartRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
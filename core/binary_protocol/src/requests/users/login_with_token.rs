@@ -0,0 +1,81 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_str, read_u32_le};
+use bytes::{BufMut, BytesMut};
+
+/// `LoginWithToken` request, signs in with an OAuth2/OIDC access token issued by a trusted issuer.
+///
+/// Wire format: `[token_len:u32_le][token:N]`
+///
+/// The length is 4 bytes wide, as the signed JWTs easily exceed 255 bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginWithTokenRequest {
+    pub token: String,
+}
+
+impl WireEncode for LoginWithTokenRequest {
+    fn encoded_size(&self) -> usize {
+        4 + self.token.len()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u32_le(self.token.len() as u32);
+        buf.put_slice(self.token.as_bytes());
+    }
+}
+
+impl WireDecode for LoginWithTokenRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let token_len = read_u32_le(buf, 0)? as usize;
+        let token = read_str(buf, 4, token_len)?;
+        Ok((Self { token }, 4 + token_len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let req = LoginWithTokenRequest {
+            token: format!("eyJhbGciOiJSUzI1NiJ9.{}.signature", "a".repeat(512)),
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(req.encoded_size(), bytes.len());
+        let (decoded, consumed) = LoginWithTokenRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let req = LoginWithTokenRequest {
+            token: "header.payload.signature".to_string(),
+        };
+        let bytes = req.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                LoginWithTokenRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
@@ -19,11 +19,16 @@ pub mod change_password;
 pub mod create_user;
 pub mod delete_user;
 pub mod get_user;
+pub mod get_user_quotas;
 pub mod get_users;
 pub mod login_register;
 pub mod login_register_with_pat;
+pub mod login_scram_finish;
+pub mod login_scram_start;
 pub mod login_user;
+pub mod login_with_token;
 pub mod logout_user;
+pub mod set_user_quota;
 pub mod update_permissions;
 pub mod update_user;
 
@@ -31,10 +36,15 @@ pub use change_password::ChangePasswordRequest;
 pub use create_user::CreateUserRequest;
 pub use delete_user::DeleteUserRequest;
 pub use get_user::GetUserRequest;
+pub use get_user_quotas::GetUserQuotasRequest;
 pub use get_users::GetUsersRequest;
 pub use login_register::LoginRegisterRequest;
 pub use login_register_with_pat::LoginRegisterWithPatRequest;
+pub use login_scram_finish::LoginScramFinishRequest;
+pub use login_scram_start::LoginScramStartRequest;
 pub use login_user::LoginUserRequest;
+pub use login_with_token::LoginWithTokenRequest;
 pub use logout_user::LogoutUserRequest;
+pub use set_user_quota::SetUserQuotaRequest;
 pub use update_permissions::UpdatePermissionsRequest;
 pub use update_user::UpdateUserRequest;
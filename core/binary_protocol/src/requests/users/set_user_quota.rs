@@ -0,0 +1,146 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::WireIdentifier;
+use crate::codec::{WireDecode, WireEncode, read_u8};
+use crate::primitives::quota::{WireQuota, WireQuotaScope};
+use bytes::{BufMut, BytesMut};
+
+/// `SetUserQuota` request. A missing quota removes the limits for the scope.
+///
+/// Wire format:
+/// `[user_id:WireIdentifier][scope:u8][has_quota:u8][quota:WireQuota?]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetUserQuotaRequest {
+    pub user_id: WireIdentifier,
+    pub scope: WireQuotaScope,
+    pub quota: Option<WireQuota>,
+}
+
+impl WireEncode for SetUserQuotaRequest {
+    fn encoded_size(&self) -> usize {
+        self.user_id.encoded_size()
+            + 1 // scope
+            + 1 // has_quota
+            + self.quota.map_or(0, |q| q.encoded_size())
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        self.user_id.encode(buf);
+        buf.put_u8(self.scope.as_u8());
+        if let Some(quota) = &self.quota {
+            buf.put_u8(1);
+            quota.encode(buf);
+        } else {
+            buf.put_u8(0);
+        }
+    }
+}
+
+impl WireDecode for SetUserQuotaRequest {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let (user_id, mut pos) = WireIdentifier::decode(buf)?;
+        let scope = WireQuotaScope::from_code(read_u8(buf, pos)?)?;
+        pos += 1;
+        let has_quota = read_u8(buf, pos)?;
+        pos += 1;
+
+        let quota = if has_quota == 1 {
+            let (quota, consumed) = WireQuota::decode(&buf[pos..])?;
+            pos += consumed;
+            Some(quota)
+        } else {
+            None
+        };
+
+        Ok((
+            Self {
+                user_id,
+                scope,
+                quota,
+            },
+            pos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_with_quota() {
+        let req = SetUserQuotaRequest {
+            user_id: WireIdentifier::named("tenant").unwrap(),
+            scope: WireQuotaScope::Client,
+            quota: Some(WireQuota {
+                produce_bytes_per_second: 1000,
+                fetch_bytes_per_second: 2000,
+                requests_per_second: 10,
+            }),
+        };
+        let bytes = req.to_bytes();
+        assert_eq!(bytes.len(), req.encoded_size());
+        let (decoded, consumed) = SetUserQuotaRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn roundtrip_without_quota() {
+        let req = SetUserQuotaRequest {
+            user_id: WireIdentifier::numeric(7),
+            scope: WireQuotaScope::User,
+            quota: None,
+        };
+        let bytes = req.to_bytes();
+        let (decoded, consumed) = SetUserQuotaRequest::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, req);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let req = SetUserQuotaRequest {
+            user_id: WireIdentifier::numeric(7),
+            scope: WireQuotaScope::User,
+            quota: Some(WireQuota::default()),
+        };
+        let bytes = req.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                SetUserQuotaRequest::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_scope_rejected() {
+        let mut bytes = SetUserQuotaRequest {
+            user_id: WireIdentifier::numeric(7),
+            scope: WireQuotaScope::User,
+            quota: None,
+        }
+        .to_bytes()
+        .to_vec();
+        let scope_pos = bytes.len() - 2;
+        bytes[scope_pos] = 9;
+        assert!(SetUserQuotaRequest::decode(&bytes).is_err());
+    }
+}
@@ -17,7 +17,7 @@
 
 use crate::WireError;
 use crate::WireIdentifier;
-use crate::codec::{WireDecode, WireEncode, read_u8, read_u32_le};
+use crate::codec::{WireDecode, WireEncode, read_bytes, read_u8, read_u32_le};
 use crate::primitives::permissions::WirePermissions;
 use bytes::{BufMut, BytesMut};
 use std::borrow::Cow;
@@ -66,7 +66,7 @@ impl WireDecode for UpdatePermissionsRequest {
         let permissions = if has_permissions == 1 {
             let perm_len = read_u32_le(buf, pos)? as usize;
             pos += 4;
-            let (perms, consumed) = WirePermissions::decode(&buf[pos..])?;
+            let (perms, consumed) = WirePermissions::decode(read_bytes(buf, pos, perm_len)?)?;
             if consumed != perm_len {
                 return Err(WireError::Validation(Cow::Owned(format!(
                     "permissions length mismatch: header says {perm_len}, decoded {consumed}"
@@ -108,6 +108,8 @@ mod tests {
                 send_messages: true,
             },
             streams: vec![],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         }
     }
 
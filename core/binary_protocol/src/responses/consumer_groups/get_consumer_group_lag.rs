@@ -0,0 +1,219 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_u8, read_u32_le, read_u64_le};
+use bytes::{BufMut, BytesMut};
+
+/// The lag of the consumer group in a single partition.
+///
+/// Wire format (37 bytes fixed):
+/// ```text
+/// [partition_id:4][committed_flag:1][committed_offset:8][log_end_offset:8][lag:8][lag_time:8]
+/// ```
+///
+/// `committed_offset` encoding: a u8 flag (1=Some, 0=None) followed by 8 bytes
+/// for the u64 value (0 when None). `lag_time` is in microseconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumerGroupPartitionLagResponse {
+    pub partition_id: u32,
+    pub committed_offset: Option<u64>,
+    pub log_end_offset: u64,
+    pub lag: u64,
+    pub lag_time: u64,
+}
+
+impl ConsumerGroupPartitionLagResponse {
+    const FIXED_SIZE: usize = 4 + 1 + 8 + 8 + 8 + 8; // 37
+}
+
+impl WireEncode for ConsumerGroupPartitionLagResponse {
+    fn encoded_size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.partition_id);
+        if let Some(offset) = self.committed_offset {
+            buf.put_u8(1);
+            buf.put_u64_le(offset);
+        } else {
+            buf.put_u8(0);
+            buf.put_u64_le(0);
+        }
+        buf.put_u64_le(self.log_end_offset);
+        buf.put_u64_le(self.lag);
+        buf.put_u64_le(self.lag_time);
+    }
+}
+
+impl WireDecode for ConsumerGroupPartitionLagResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let partition_id = read_u32_le(buf, 0)?;
+        let committed_flag = read_u8(buf, 4)?;
+        let committed_raw = read_u64_le(buf, 5)?;
+        let log_end_offset = read_u64_le(buf, 13)?;
+        let lag = read_u64_le(buf, 21)?;
+        let lag_time = read_u64_le(buf, 29)?;
+        let committed_offset = if committed_flag == 1 {
+            Some(committed_raw)
+        } else {
+            None
+        };
+        Ok((
+            Self {
+                partition_id,
+                committed_offset,
+                log_end_offset,
+                lag,
+                lag_time,
+            },
+            Self::FIXED_SIZE,
+        ))
+    }
+}
+
+/// `GetConsumerGroupLag` response: the consumer group lag in each of its partitions.
+///
+/// Wire format:
+/// ```text
+/// [group_id:4][partitions_count:4][ConsumerGroupPartitionLagResponse]*
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumerGroupLagResponse {
+    pub group_id: u32,
+    pub partitions: Vec<ConsumerGroupPartitionLagResponse>,
+}
+
+impl WireEncode for ConsumerGroupLagResponse {
+    fn encoded_size(&self) -> usize {
+        4 + 4 + self.partitions.len() * ConsumerGroupPartitionLagResponse::FIXED_SIZE
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.group_id);
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u32_le(self.partitions.len() as u32);
+        for partition in &self.partitions {
+            partition.encode(buf);
+        }
+    }
+}
+
+impl WireDecode for ConsumerGroupLagResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let group_id = read_u32_le(buf, 0)?;
+        let partitions_count = read_u32_le(buf, 4)? as usize;
+        let mut partitions = Vec::with_capacity(crate::codec::capped_capacity(
+            partitions_count,
+            buf.len().saturating_sub(8),
+            ConsumerGroupPartitionLagResponse::FIXED_SIZE,
+        ));
+        let mut pos = 8;
+        for _ in 0..partitions_count {
+            let (partition, consumed) = ConsumerGroupPartitionLagResponse::decode(&buf[pos..])?;
+            pos += consumed;
+            partitions.push(partition);
+        }
+        Ok((
+            Self {
+                group_id,
+                partitions,
+            },
+            pos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_partition(
+        partition_id: u32,
+        committed_offset: Option<u64>,
+    ) -> ConsumerGroupPartitionLagResponse {
+        ConsumerGroupPartitionLagResponse {
+            partition_id,
+            committed_offset,
+            log_end_offset: 1000,
+            lag: 1000 - committed_offset.unwrap_or(0),
+            lag_time: 5_000_000,
+        }
+    }
+
+    #[test]
+    fn partition_roundtrip() {
+        for committed_offset in [Some(0), Some(500), None] {
+            let partition = sample_partition(1, committed_offset);
+            let bytes = partition.to_bytes();
+            assert_eq!(bytes.len(), ConsumerGroupPartitionLagResponse::FIXED_SIZE);
+            let (decoded, consumed) = ConsumerGroupPartitionLagResponse::decode(&bytes).unwrap();
+            assert_eq!(consumed, ConsumerGroupPartitionLagResponse::FIXED_SIZE);
+            assert_eq!(decoded, partition);
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let resp = ConsumerGroupLagResponse {
+            group_id: 3,
+            partitions: vec![sample_partition(0, Some(10)), sample_partition(1, None)],
+        };
+        let bytes = resp.to_bytes();
+        let (decoded, consumed) = ConsumerGroupLagResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn roundtrip_no_partitions() {
+        let resp = ConsumerGroupLagResponse {
+            group_id: 1,
+            partitions: vec![],
+        };
+        let bytes = resp.to_bytes();
+        assert_eq!(bytes.len(), 8);
+        let (decoded, consumed) = ConsumerGroupLagResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, 8);
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let resp = ConsumerGroupLagResponse {
+            group_id: 3,
+            partitions: vec![sample_partition(0, Some(10))],
+        };
+        let bytes = resp.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                ConsumerGroupLagResponse::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+
+    #[test]
+    fn bogus_partitions_count_does_not_oom() {
+        let mut buf = BytesMut::new();
+        buf.put_u32_le(1);
+        buf.put_u32_le(u32::MAX);
+        assert!(ConsumerGroupLagResponse::decode(&buf).is_err());
+    }
+}
@@ -19,6 +19,7 @@ pub mod consumer_group_response;
 mod create_consumer_group;
 mod delete_consumer_group;
 pub mod get_consumer_group;
+pub mod get_consumer_group_lag;
 pub mod get_consumer_groups;
 mod join_consumer_group;
 mod leave_consumer_group;
@@ -28,6 +29,7 @@ pub use consumer_group_response::ConsumerGroupResponse;
 pub use create_consumer_group::CreateConsumerGroupResponse;
 pub use delete_consumer_group::DeleteConsumerGroupResponse;
 pub use get_consumer_group::{ConsumerGroupDetailsResponse, ConsumerGroupMemberResponse};
+pub use get_consumer_group_lag::{ConsumerGroupLagResponse, ConsumerGroupPartitionLagResponse};
 pub use get_consumer_groups::GetConsumerGroupsResponse;
 pub use join_consumer_group::JoinConsumerGroupResponse;
 pub use leave_consumer_group::LeaveConsumerGroupResponse;
@@ -0,0 +1,91 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_u64_le};
+use bytes::{BufMut, BytesMut};
+
+/// `InitProducer` response.
+///
+/// Wire format (16 bytes fixed):
+/// ```text
+/// [producer_id:8 LE][producer_epoch:8 LE]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitProducerResponse {
+    pub producer_id: u64,
+    pub producer_epoch: u64,
+}
+
+impl InitProducerResponse {
+    const FIXED_SIZE: usize = 8 + 8;
+}
+
+impl WireEncode for InitProducerResponse {
+    fn encoded_size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.producer_id);
+        buf.put_u64_le(self.producer_epoch);
+    }
+}
+
+impl WireDecode for InitProducerResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let producer_id = read_u64_le(buf, 0)?;
+        let producer_epoch = read_u64_le(buf, 8)?;
+        Ok((
+            Self {
+                producer_id,
+                producer_epoch,
+            },
+            Self::FIXED_SIZE,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let resp = InitProducerResponse {
+            producer_id: 1_700_000_000_000_000,
+            producer_epoch: 1_700_000_000_000_001,
+        };
+        let bytes = resp.to_bytes();
+        assert_eq!(bytes.len(), InitProducerResponse::FIXED_SIZE);
+        let (decoded, consumed) = InitProducerResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, InitProducerResponse::FIXED_SIZE);
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let bytes = InitProducerResponse {
+            producer_id: 1,
+            producer_epoch: 2,
+        }
+        .to_bytes();
+        for i in 0..bytes.len() {
+            assert!(InitProducerResponse::decode(&bytes[..i]).is_err());
+        }
+    }
+}
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod init_producer;
 pub mod poll_messages;
 
+pub use init_producer::InitProducerResponse;
 pub use poll_messages::{PollMessagesResponse, PollMessagesResponseHeader};
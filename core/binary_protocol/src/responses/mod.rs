@@ -20,9 +20,11 @@ pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod messages;
 pub mod personal_access_tokens;
+pub mod roles;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 
 use crate::WireError;
@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, capped_capacity, read_bytes, read_u8, read_u32_le};
+use crate::primitives::permissions::WirePermissions;
+use crate::responses::roles::role_response::RoleResponse;
+use bytes::{BufMut, BytesMut};
+use std::borrow::Cow;
+
+/// `GetRole` response: role header followed by optional permissions and
+/// the IDs of the users holding the role.
+///
+/// Wire format:
+/// ```text
+/// [RoleResponse]
+/// If no permissions:  [0x00 0x00 0x00 0x00]     (4 bytes)
+/// If has permissions:  [0x01][len:4][perms:N]    (1 + 4 + N bytes)
+/// [user_id:4] * users_count
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleDetailsResponse {
+    pub role: RoleResponse,
+    pub permissions: Option<WirePermissions>,
+    pub user_ids: Vec<u32>,
+}
+
+impl WireEncode for RoleDetailsResponse {
+    fn encoded_size(&self) -> usize {
+        self.role.encoded_size()
+            + self
+                .permissions
+                .as_ref()
+                .map_or(4, |perms| 1 + 4 + perms.encoded_size())
+            + 4 * self.user_ids.len()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        self.role.encode(buf);
+        match &self.permissions {
+            Some(perms) => {
+                buf.put_u8(1);
+                let perm_bytes = perms.to_bytes();
+                #[allow(clippy::cast_possible_truncation)]
+                buf.put_u32_le(perm_bytes.len() as u32);
+                buf.put_slice(&perm_bytes);
+            }
+            None => {
+                buf.put_u32_le(0);
+            }
+        }
+        for user_id in &self.user_ids {
+            buf.put_u32_le(*user_id);
+        }
+    }
+}
+
+impl WireDecode for RoleDetailsResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let (role, mut pos) = RoleResponse::decode(buf)?;
+        let flag = read_u8(buf, pos)?;
+        pos += 1;
+
+        let permissions = if flag == 0 {
+            let _ = read_bytes(buf, pos, 3)?;
+            pos += 3;
+            None
+        } else {
+            let perm_len = read_u32_le(buf, pos)? as usize;
+            pos += 4;
+            let perm_buf = read_bytes(buf, pos, perm_len)?;
+            let (perms, consumed) = WirePermissions::decode(perm_buf)?;
+            if consumed != perm_len {
+                return Err(WireError::Validation(Cow::Owned(format!(
+                    "permissions length mismatch: header says {perm_len}, decoded {consumed}"
+                ))));
+            }
+            pos += perm_len;
+            Some(perms)
+        };
+
+        let users_count = role.users_count as usize;
+        let mut user_ids = Vec::with_capacity(capped_capacity(
+            users_count,
+            buf.len().saturating_sub(pos),
+            4,
+        ));
+        for _ in 0..users_count {
+            user_ids.push(read_u32_le(buf, pos)?);
+            pos += 4;
+        }
+
+        Ok((
+            Self {
+                role,
+                permissions,
+                user_ids,
+            },
+            pos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WireName;
+    use crate::primitives::permissions::WireGlobalPermissions;
+
+    fn sample(permissions: Option<WirePermissions>, user_ids: Vec<u32>) -> RoleDetailsResponse {
+        RoleDetailsResponse {
+            role: RoleResponse {
+                id: 1,
+                created_at: 1_710_000_000_000,
+                #[allow(clippy::cast_possible_truncation)]
+                users_count: user_ids.len() as u32,
+                name: WireName::new("readers").unwrap(),
+            },
+            permissions,
+            user_ids,
+        }
+    }
+
+    fn sample_permissions() -> WirePermissions {
+        WirePermissions {
+            global: WireGlobalPermissions {
+                manage_servers: false,
+                read_servers: true,
+                manage_users: false,
+                read_users: true,
+                manage_streams: false,
+                read_streams: true,
+                manage_topics: false,
+                read_topics: true,
+                poll_messages: true,
+                send_messages: false,
+            },
+            streams: vec![],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
+        }
+    }
+
+    #[test]
+    fn roundtrip_no_permissions_no_users() {
+        let resp = sample(None, vec![]);
+        let bytes = resp.to_bytes();
+        assert_eq!(bytes.len(), resp.encoded_size());
+        let (decoded, consumed) = RoleDetailsResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn roundtrip_with_permissions_and_users() {
+        let resp = sample(Some(sample_permissions()), vec![1, 7, 42]);
+        let bytes = resp.to_bytes();
+        assert_eq!(bytes.len(), resp.encoded_size());
+        let (decoded, consumed) = RoleDetailsResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let bytes = sample(Some(sample_permissions()), vec![1, 2]).to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                RoleDetailsResponse::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
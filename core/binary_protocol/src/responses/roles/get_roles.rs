@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode};
+use crate::responses::roles::role_response::RoleResponse;
+use bytes::BytesMut;
+
+/// `GetRoles` response: sequential role headers.
+///
+/// Wire format:
+/// ```text
+/// [RoleResponse]*
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetRolesResponse {
+    pub roles: Vec<RoleResponse>,
+}
+
+impl WireEncode for GetRolesResponse {
+    fn encoded_size(&self) -> usize {
+        self.roles.iter().map(WireEncode::encoded_size).sum()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        for role in &self.roles {
+            role.encode(buf);
+        }
+    }
+}
+
+impl WireDecode for GetRolesResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let mut roles = Vec::new();
+        let mut pos = 0;
+        while pos < buf.len() {
+            let (role, consumed) = RoleResponse::decode(&buf[pos..])?;
+            pos += consumed;
+            roles.push(role);
+        }
+        Ok((Self { roles }, pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WireName;
+
+    #[test]
+    fn roundtrip_empty() {
+        let resp = GetRolesResponse { roles: vec![] };
+        let bytes = resp.to_bytes();
+        assert!(bytes.is_empty());
+        let (decoded, consumed) = GetRolesResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, 0);
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn roundtrip_multiple() {
+        let resp = GetRolesResponse {
+            roles: vec![
+                RoleResponse {
+                    id: 1,
+                    created_at: 100,
+                    users_count: 0,
+                    name: WireName::new("readers").unwrap(),
+                },
+                RoleResponse {
+                    id: 2,
+                    created_at: 200,
+                    users_count: 5,
+                    name: WireName::new("writers").unwrap(),
+                },
+            ],
+        };
+        let bytes = resp.to_bytes();
+        let (decoded, consumed) = GetRolesResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, resp);
+    }
+}
@@ -0,0 +1,107 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_u32_le, read_u64_le};
+use crate::primitives::identifier::WireName;
+use bytes::{BufMut, BytesMut};
+
+/// Role header on the wire. Used in both single-role and multi-role responses.
+///
+/// Wire format (16 + `name_len` bytes):
+/// ```text
+/// [id:4][created_at:8][users_count:4][name_len:1][name:N]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleResponse {
+    pub id: u32,
+    pub created_at: u64,
+    pub users_count: u32,
+    pub name: WireName,
+}
+
+impl RoleResponse {
+    const FIXED_SIZE: usize = 4 + 8 + 4; // 16
+}
+
+impl WireEncode for RoleResponse {
+    fn encoded_size(&self) -> usize {
+        Self::FIXED_SIZE + self.name.encoded_size()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.id);
+        buf.put_u64_le(self.created_at);
+        buf.put_u32_le(self.users_count);
+        self.name.encode(buf);
+    }
+}
+
+impl WireDecode for RoleResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let id = read_u32_le(buf, 0)?;
+        let created_at = read_u64_le(buf, 4)?;
+        let users_count = read_u32_le(buf, 12)?;
+        let (name, name_consumed) = WireName::decode(&buf[Self::FIXED_SIZE..])?;
+        let consumed = Self::FIXED_SIZE + name_consumed;
+
+        Ok((
+            Self {
+                id,
+                created_at,
+                users_count,
+                name,
+            },
+            consumed,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> RoleResponse {
+        RoleResponse {
+            id: 1,
+            created_at: 1_710_000_000_000,
+            users_count: 3,
+            name: WireName::new("readers").unwrap(),
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let resp = sample();
+        let bytes = resp.to_bytes();
+        assert_eq!(bytes.len(), resp.encoded_size());
+        let (decoded, consumed) = RoleResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let bytes = sample().to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                RoleResponse::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
@@ -18,6 +18,7 @@
 use crate::WireError;
 use crate::codec::{WireDecode, WireEncode, read_u8, read_u32_le, read_u64_le};
 use crate::primitives::identifier::WireName;
+use crate::requests::topics::DEFAULT_CLEANUP_POLICY;
 use crate::responses::streams::StreamResponse;
 use bytes::{BufMut, BytesMut};
 use std::borrow::Cow;
@@ -30,6 +31,10 @@ use std::borrow::Cow;
 /// [compression_algorithm:1][max_topic_size:8][replication_factor:1]
 /// [size_bytes:8][messages_count:8][name_len:1][name:N]
 /// ```
+///
+/// The `cleanup_policy` is not part of the header, so that its layout stays the same for the
+/// older clients. It trails the response containing the headers instead, and decodes as
+/// [`DEFAULT_CLEANUP_POLICY`] when missing (sent by older servers).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TopicHeader {
     pub id: u32,
@@ -39,6 +44,7 @@ pub struct TopicHeader {
     pub compression_algorithm: u8,
     pub max_topic_size: u64,
     pub replication_factor: u8,
+    pub cleanup_policy: u8,
     pub size_bytes: u64,
     pub messages_count: u64,
     pub name: WireName,
@@ -46,6 +52,28 @@ pub struct TopicHeader {
 
 impl TopicHeader {
     const FIXED_SIZE: usize = 4 + 8 + 4 + 8 + 1 + 8 + 1 + 8 + 8 + 1; // 51
+
+    /// Encodes the cleanup policies of the topics, one byte per topic in the same order.
+    pub(crate) fn encode_cleanup_policies(topics: &[Self], buf: &mut BytesMut) {
+        for topic in topics {
+            buf.put_u8(topic.cleanup_policy);
+        }
+    }
+
+    /// Decodes the optional trailing cleanup policies of the topics, returning the number of
+    /// consumed bytes. An empty buffer keeps the default cleanup policies.
+    pub(crate) fn decode_cleanup_policies(
+        topics: &mut [Self],
+        buf: &[u8],
+    ) -> Result<usize, WireError> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        for (position, topic) in topics.iter_mut().enumerate() {
+            topic.cleanup_policy = read_u8(buf, position)?;
+        }
+        Ok(topics.len())
+    }
 }
 
 impl WireEncode for TopicHeader {
@@ -90,6 +118,7 @@ impl WireDecode for TopicHeader {
                 compression_algorithm,
                 max_topic_size,
                 replication_factor,
+                cleanup_policy: DEFAULT_CLEANUP_POLICY,
                 size_bytes,
                 messages_count,
                 name,
@@ -99,15 +128,15 @@ impl WireDecode for TopicHeader {
     }
 }
 
-/// `GetStream` response: stream header followed by topic headers.
+/// `GetStream` response: stream header followed by topic headers and their cleanup policies.
 ///
 /// Wire format:
 /// ```text
-/// [StreamResponse][TopicHeader]*
+/// [StreamResponse][TopicHeader]*[cleanup_policy:1]*
 /// ```
 ///
-/// The number of topics is determined by `stream.topics_count` or by
-/// consuming remaining bytes (topics are packed sequentially).
+/// The number of topics is determined by `stream.topics_count` (topics are packed
+/// sequentially). The trailing cleanup policies are optional on decode.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetStreamResponse {
     pub stream: StreamResponse,
@@ -122,6 +151,7 @@ impl WireEncode for GetStreamResponse {
                 .iter()
                 .map(WireEncode::encoded_size)
                 .sum::<usize>()
+            + self.topics.len()
     }
 
     fn encode(&self, buf: &mut BytesMut) {
@@ -129,6 +159,7 @@ impl WireEncode for GetStreamResponse {
         for topic in &self.topics {
             topic.encode(buf);
         }
+        TopicHeader::encode_cleanup_policies(&self.topics, buf);
     }
 }
 
@@ -136,7 +167,7 @@ impl WireDecode for GetStreamResponse {
     fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
         let (stream, mut pos) = StreamResponse::decode(buf)?;
         let mut topics = Vec::new();
-        while pos < buf.len() {
+        while pos < buf.len() && topics.len() < stream.topics_count as usize {
             let (topic, consumed) = TopicHeader::decode(&buf[pos..])?;
             pos += consumed;
             topics.push(topic);
@@ -148,6 +179,14 @@ impl WireDecode for GetStreamResponse {
                 topics.len()
             ))));
         }
+        pos += TopicHeader::decode_cleanup_policies(&mut topics, &buf[pos..])?;
+        if pos != buf.len() {
+            return Err(WireError::Validation(Cow::Owned(format!(
+                "unexpected {} trailing bytes after {} topics",
+                buf.len() - pos,
+                topics.len()
+            ))));
+        }
         Ok((Self { stream, topics }, pos))
     }
 }
@@ -176,6 +215,7 @@ mod tests {
             compression_algorithm: 1,
             max_topic_size: 0,
             replication_factor: 1,
+            cleanup_policy: 2,
             size_bytes: 1024,
             messages_count: 100,
             name: WireName::new(name).unwrap(),
@@ -209,6 +249,24 @@ mod tests {
         assert_eq!(decoded, resp);
     }
 
+    #[test]
+    fn missing_cleanup_policies_decode_as_default() {
+        let resp = GetStreamResponse {
+            stream: sample_stream(),
+            topics: vec![sample_topic(1, "topic-a"), sample_topic(2, "topic-b")],
+        };
+        let bytes = resp.to_bytes();
+        let (decoded, consumed) = GetStreamResponse::decode(&bytes[..bytes.len() - 2]).unwrap();
+        assert_eq!(consumed, bytes.len() - 2);
+        assert!(
+            decoded
+                .topics
+                .iter()
+                .all(|topic| topic.cleanup_policy == DEFAULT_CLEANUP_POLICY)
+        );
+        assert!(GetStreamResponse::decode(&bytes[..bytes.len() - 1]).is_err());
+    }
+
     #[test]
     fn topic_header_roundtrip() {
         let topic = sample_topic(5, "events");
@@ -216,7 +274,13 @@ mod tests {
         assert_eq!(bytes.len(), TopicHeader::FIXED_SIZE + 6);
         let (decoded, consumed) = TopicHeader::decode(&bytes).unwrap();
         assert_eq!(consumed, bytes.len());
-        assert_eq!(decoded, topic);
+        assert_eq!(
+            decoded,
+            TopicHeader {
+                cleanup_policy: DEFAULT_CLEANUP_POLICY,
+                ..topic
+            }
+        );
     }
 
     #[test]
@@ -0,0 +1,276 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_str, read_u8, read_u32_le, read_u64_le};
+use bytes::{BufMut, BytesMut};
+
+/// A single record of the audit log.
+///
+/// Wire format:
+/// ```text
+/// [id:8][timestamp:8][user_id_flag:1][user_id:4][client_id:4][outcome:1]
+/// [username_len:1][username:N][address_len:1][address:N][operation_len:1][operation:N]
+/// [details_len:4][details:N][error_len:4][error:N]
+/// [previous_hash_len:1][previous_hash:N][hash_len:1][hash:N]
+/// ```
+///
+/// `client_id` of 0 and empty `username`, `address`, `details` or `error` mean
+/// that the value is not known for the record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditRecordResponse {
+    pub id: u64,
+    pub timestamp: u64,
+    pub user_id: Option<u32>,
+    pub client_id: u32,
+    pub outcome: u8,
+    pub username: String,
+    pub address: String,
+    pub operation: String,
+    pub details: String,
+    pub error: String,
+    pub previous_hash: String,
+    pub hash: String,
+}
+
+impl AuditRecordResponse {
+    const FIXED_SIZE: usize = 8 + 8 + 1 + 4 + 4 + 1 + 1 + 1 + 1 + 4 + 4 + 1 + 1; // 39
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn put_short_str(buf: &mut BytesMut, value: &str) {
+    buf.put_u8(value.len() as u8);
+    buf.put_slice(value.as_bytes());
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn put_long_str(buf: &mut BytesMut, value: &str) {
+    buf.put_u32_le(value.len() as u32);
+    buf.put_slice(value.as_bytes());
+}
+
+fn read_short_str(buf: &[u8], pos: &mut usize) -> Result<String, WireError> {
+    let len = read_u8(buf, *pos)? as usize;
+    let value = read_str(buf, *pos + 1, len)?;
+    *pos += 1 + len;
+    Ok(value)
+}
+
+fn read_long_str(buf: &[u8], pos: &mut usize) -> Result<String, WireError> {
+    let len = read_u32_le(buf, *pos)? as usize;
+    let value = read_str(buf, *pos + 4, len)?;
+    *pos += 4 + len;
+    Ok(value)
+}
+
+impl WireEncode for AuditRecordResponse {
+    fn encoded_size(&self) -> usize {
+        Self::FIXED_SIZE
+            + self.username.len()
+            + self.address.len()
+            + self.operation.len()
+            + self.details.len()
+            + self.error.len()
+            + self.previous_hash.len()
+            + self.hash.len()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.id);
+        buf.put_u64_le(self.timestamp);
+        if let Some(user_id) = self.user_id {
+            buf.put_u8(1);
+            buf.put_u32_le(user_id);
+        } else {
+            buf.put_u8(0);
+            buf.put_u32_le(0);
+        }
+        buf.put_u32_le(self.client_id);
+        buf.put_u8(self.outcome);
+        put_short_str(buf, &self.username);
+        put_short_str(buf, &self.address);
+        put_short_str(buf, &self.operation);
+        put_long_str(buf, &self.details);
+        put_long_str(buf, &self.error);
+        put_short_str(buf, &self.previous_hash);
+        put_short_str(buf, &self.hash);
+    }
+}
+
+impl WireDecode for AuditRecordResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let id = read_u64_le(buf, 0)?;
+        let timestamp = read_u64_le(buf, 8)?;
+        let user_id_flag = read_u8(buf, 16)?;
+        let user_id_raw = read_u32_le(buf, 17)?;
+        let client_id = read_u32_le(buf, 21)?;
+        let outcome = read_u8(buf, 25)?;
+        let mut pos = 26;
+        let username = read_short_str(buf, &mut pos)?;
+        let address = read_short_str(buf, &mut pos)?;
+        let operation = read_short_str(buf, &mut pos)?;
+        let details = read_long_str(buf, &mut pos)?;
+        let error = read_long_str(buf, &mut pos)?;
+        let previous_hash = read_short_str(buf, &mut pos)?;
+        let hash = read_short_str(buf, &mut pos)?;
+        let user_id = if user_id_flag == 1 {
+            Some(user_id_raw)
+        } else {
+            None
+        };
+        Ok((
+            Self {
+                id,
+                timestamp,
+                user_id,
+                client_id,
+                outcome,
+                username,
+                address,
+                operation,
+                details,
+                error,
+                previous_hash,
+                hash,
+            },
+            pos,
+        ))
+    }
+}
+
+/// `GetAuditLog` response: the matching audit records, oldest first.
+///
+/// Wire format:
+/// ```text
+/// [records_count:4][AuditRecordResponse]*
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditLogResponse {
+    pub records: Vec<AuditRecordResponse>,
+}
+
+impl WireEncode for AuditLogResponse {
+    fn encoded_size(&self) -> usize {
+        4 + self
+            .records
+            .iter()
+            .map(WireEncode::encoded_size)
+            .sum::<usize>()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u32_le(self.records.len() as u32);
+        for record in &self.records {
+            record.encode(buf);
+        }
+    }
+}
+
+impl WireDecode for AuditLogResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let records_count = read_u32_le(buf, 0)? as usize;
+        let mut records = Vec::with_capacity(crate::codec::capped_capacity(
+            records_count,
+            buf.len().saturating_sub(4),
+            AuditRecordResponse::FIXED_SIZE,
+        ));
+        let mut pos = 4;
+        for _ in 0..records_count {
+            let (record, consumed) = AuditRecordResponse::decode(&buf[pos..])?;
+            pos += consumed;
+            records.push(record);
+        }
+        Ok((Self { records }, pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_record(id: u64, user_id: Option<u32>) -> AuditRecordResponse {
+        AuditRecordResponse {
+            id,
+            timestamp: 1_700_000_000_000_000 + id,
+            user_id,
+            client_id: 7,
+            outcome: 1,
+            username: "iggy".to_string(),
+            address: "127.0.0.1:50000".to_string(),
+            operation: "stream.create".to_string(),
+            details: "name: orders".to_string(),
+            error: String::new(),
+            previous_hash: "a".repeat(64),
+            hash: "b".repeat(64),
+        }
+    }
+
+    #[test]
+    fn record_roundtrip() {
+        for user_id in [Some(0), Some(5), None] {
+            let record = sample_record(1, user_id);
+            let bytes = record.to_bytes();
+            assert_eq!(bytes.len(), record.encoded_size());
+            let (decoded, consumed) = AuditRecordResponse::decode(&bytes).unwrap();
+            assert_eq!(consumed, bytes.len());
+            assert_eq!(decoded, record);
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let resp = AuditLogResponse {
+            records: vec![sample_record(1, Some(0)), sample_record(2, None)],
+        };
+        let bytes = resp.to_bytes();
+        let (decoded, consumed) = AuditLogResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn roundtrip_no_records() {
+        let resp = AuditLogResponse { records: vec![] };
+        let bytes = resp.to_bytes();
+        assert_eq!(bytes.len(), 4);
+        let (decoded, consumed) = AuditLogResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, 4);
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let resp = AuditLogResponse {
+            records: vec![sample_record(1, Some(0))],
+        };
+        let bytes = resp.to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                AuditLogResponse::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+
+    #[test]
+    fn bogus_records_count_does_not_oom() {
+        let mut buf = BytesMut::new();
+        buf.put_u32_le(u32::MAX);
+        assert!(AuditLogResponse::decode(&buf).is_err());
+    }
+}
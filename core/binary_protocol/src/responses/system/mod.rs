@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod get_audit_log;
 pub mod get_cluster_metadata;
 pub mod get_me;
 pub mod get_snapshot;
@@ -22,6 +23,7 @@ pub mod get_stats;
 mod ping;
 
 pub use super::EmptyResponse;
+pub use get_audit_log::{AuditLogResponse, AuditRecordResponse};
 pub use get_cluster_metadata::{ClusterMetadataResponse, ClusterNodeResponse};
 pub use get_me::GetMeResponse;
 pub use get_snapshot::GetSnapshotResponse;
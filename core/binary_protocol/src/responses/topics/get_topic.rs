@@ -79,14 +79,15 @@ impl WireDecode for PartitionResponse {
     }
 }
 
-/// `GetTopic` response: topic header followed by partition details.
+/// `GetTopic` response: topic header followed by partition details and the cleanup policy.
 ///
 /// Wire format:
 /// ```text
-/// [TopicHeader][PartitionResponse]*
+/// [TopicHeader][PartitionResponse]*[cleanup_policy:1]
 /// ```
 ///
-/// The number of partitions must match `topic.partitions_count`.
+/// The number of partitions must match `topic.partitions_count`. The trailing
+/// `cleanup_policy` is optional on decode.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTopicResponse {
     pub topic: TopicHeader,
@@ -101,6 +102,7 @@ impl WireEncode for GetTopicResponse {
                 .iter()
                 .map(WireEncode::encoded_size)
                 .sum::<usize>()
+            + 1
     }
 
     fn encode(&self, buf: &mut BytesMut) {
@@ -108,14 +110,15 @@ impl WireEncode for GetTopicResponse {
         for partition in &self.partitions {
             partition.encode(buf);
         }
+        TopicHeader::encode_cleanup_policies(std::slice::from_ref(&self.topic), buf);
     }
 }
 
 impl WireDecode for GetTopicResponse {
     fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
-        let (topic, mut pos) = TopicHeader::decode(buf)?;
+        let (mut topic, mut pos) = TopicHeader::decode(buf)?;
         let mut partitions = Vec::new();
-        while pos < buf.len() {
+        while pos < buf.len() && partitions.len() < topic.partitions_count as usize {
             let (partition, consumed) = PartitionResponse::decode(&buf[pos..])?;
             pos += consumed;
             partitions.push(partition);
@@ -127,6 +130,14 @@ impl WireDecode for GetTopicResponse {
                 partitions.len()
             ))));
         }
+        pos += TopicHeader::decode_cleanup_policies(std::slice::from_mut(&mut topic), &buf[pos..])?;
+        if pos != buf.len() {
+            return Err(WireError::Validation(Cow::Owned(format!(
+                "unexpected {} trailing bytes after {} partitions",
+                buf.len() - pos,
+                partitions.len()
+            ))));
+        }
         Ok((Self { topic, partitions }, pos))
     }
 }
@@ -135,6 +146,7 @@ impl WireDecode for GetTopicResponse {
 mod tests {
     use super::*;
     use crate::WireName;
+    use crate::requests::topics::DEFAULT_CLEANUP_POLICY;
 
     fn sample_topic(partitions_count: u32) -> TopicHeader {
         TopicHeader {
@@ -145,6 +157,7 @@ mod tests {
             compression_algorithm: 1,
             max_topic_size: 0,
             replication_factor: 1,
+            cleanup_policy: 2,
             size_bytes: 2048,
             messages_count: 200,
             name: WireName::new("my-topic").unwrap(),
@@ -208,6 +221,19 @@ mod tests {
         assert_eq!(decoded, resp);
     }
 
+    #[test]
+    fn missing_cleanup_policy_decodes_as_default() {
+        let resp = GetTopicResponse {
+            topic: sample_topic(1),
+            partitions: vec![sample_partition(1)],
+        };
+        let bytes = resp.to_bytes();
+        let (decoded, consumed) = GetTopicResponse::decode(&bytes[..bytes.len() - 1]).unwrap();
+        assert_eq!(consumed, bytes.len() - 1);
+        assert_eq!(decoded.topic.cleanup_policy, DEFAULT_CLEANUP_POLICY);
+        assert_eq!(decoded.partitions, resp.partitions);
+    }
+
     #[test]
     fn partition_count_mismatch_returns_error() {
         let resp = GetTopicResponse {
@@ -225,7 +251,8 @@ mod tests {
             partitions: vec![sample_partition(1)],
         };
         let bytes = resp.to_bytes();
-        for i in 0..bytes.len() {
+        // Without the trailing cleanup policy, the rest is a valid response.
+        for i in 0..bytes.len() - 1 {
             assert!(
                 GetTopicResponse::decode(&bytes[..i]).is_err(),
                 "expected error for truncation at byte {i}"
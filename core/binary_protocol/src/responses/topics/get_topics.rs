@@ -20,14 +20,16 @@ use crate::codec::{WireDecode, WireEncode};
 use crate::responses::streams::get_stream::TopicHeader;
 use bytes::BytesMut;
 
-/// `GetTopics` response: sequential topic headers.
+/// `GetTopics` response: sequential topic headers followed by their cleanup policies.
 ///
 /// Wire format:
 /// ```text
-/// [TopicHeader]*
+/// [TopicHeader]*[cleanup_policy:1]*
 /// ```
 ///
-/// Empty payload means zero topics.
+/// Empty payload means zero topics. The headers are decoded until the remaining bytes hold
+/// exactly one cleanup policy per decoded topic, while the trailing cleanup policies are
+/// optional on decode.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTopicsResponse {
     pub topics: Vec<TopicHeader>,
@@ -35,13 +37,18 @@ pub struct GetTopicsResponse {
 
 impl WireEncode for GetTopicsResponse {
     fn encoded_size(&self) -> usize {
-        self.topics.iter().map(WireEncode::encoded_size).sum()
+        self.topics
+            .iter()
+            .map(WireEncode::encoded_size)
+            .sum::<usize>()
+            + self.topics.len()
     }
 
     fn encode(&self, buf: &mut BytesMut) {
         for topic in &self.topics {
             topic.encode(buf);
         }
+        TopicHeader::encode_cleanup_policies(&self.topics, buf);
     }
 }
 
@@ -49,11 +56,12 @@ impl WireDecode for GetTopicsResponse {
     fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
         let mut topics = Vec::new();
         let mut pos = 0;
-        while pos < buf.len() {
+        while pos < buf.len() && buf.len() - pos != topics.len() {
             let (topic, consumed) = TopicHeader::decode(&buf[pos..])?;
             pos += consumed;
             topics.push(topic);
         }
+        pos += TopicHeader::decode_cleanup_policies(&mut topics, &buf[pos..])?;
         Ok((Self { topics }, pos))
     }
 }
@@ -62,6 +70,7 @@ impl WireDecode for GetTopicsResponse {
 mod tests {
     use super::*;
     use crate::WireName;
+    use crate::requests::topics::DEFAULT_CLEANUP_POLICY;
 
     fn sample_topic(id: u32, name: &str) -> TopicHeader {
         TopicHeader {
@@ -72,6 +81,7 @@ mod tests {
             compression_algorithm: 1,
             max_topic_size: 0,
             replication_factor: 1,
+            cleanup_policy: 2,
             size_bytes: 1024,
             messages_count: 100,
             name: WireName::new(name).unwrap(),
@@ -99,13 +109,31 @@ mod tests {
         assert_eq!(decoded, resp);
     }
 
+    #[test]
+    fn missing_cleanup_policies_decode_as_default() {
+        let resp = GetTopicsResponse {
+            topics: vec![sample_topic(1, "events"), sample_topic(2, "logs")],
+        };
+        let bytes = resp.to_bytes();
+        let (decoded, consumed) = GetTopicsResponse::decode(&bytes[..bytes.len() - 2]).unwrap();
+        assert_eq!(consumed, bytes.len() - 2);
+        assert!(
+            decoded
+                .topics
+                .iter()
+                .all(|topic| topic.cleanup_policy == DEFAULT_CLEANUP_POLICY)
+        );
+        assert!(GetTopicsResponse::decode(&bytes[..bytes.len() - 1]).is_err());
+    }
+
     #[test]
     fn truncated_returns_error() {
         let resp = GetTopicsResponse {
             topics: vec![sample_topic(1, "t")],
         };
         let bytes = resp.to_bytes();
-        for i in 1..bytes.len() {
+        // Without the trailing cleanup policy, the header alone is a valid response.
+        for i in 1..bytes.len() - 1 {
             assert!(
                 GetTopicsResponse::decode(&bytes[..i]).is_err(),
                 "expected error for truncation at byte {i}"
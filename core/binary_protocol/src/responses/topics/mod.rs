@@ -20,6 +20,7 @@ mod delete_topic;
 pub mod get_topic;
 pub mod get_topics;
 mod purge_topic;
+mod rotate_topic_key;
 mod update_topic;
 
 pub use super::EmptyResponse;
@@ -28,4 +29,5 @@ pub use delete_topic::DeleteTopicResponse;
 pub use get_topic::{GetTopicResponse, PartitionResponse};
 pub use get_topics::GetTopicsResponse;
 pub use purge_topic::PurgeTopicResponse;
+pub use rotate_topic_key::RotateTopicKeyResponse;
 pub use update_topic::UpdateTopicResponse;
@@ -150,6 +150,8 @@ mod tests {
                     send_messages: true,
                 }],
             }],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let resp = UserDetailsResponse {
             user: sample_user(),
@@ -178,6 +180,8 @@ mod tests {
         let perms = WirePermissions {
             global: make_global(false),
             streams: vec![],
+            stream_patterns: vec![],
+            consumer_groups: vec![],
         };
         let resp = UserDetailsResponse {
             user: sample_user(),
@@ -0,0 +1,133 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_u8, read_u32_le};
+use crate::primitives::quota::WireQuota;
+use bytes::{BufMut, BytesMut};
+
+/// `GetUserQuotas` response.
+///
+/// Wire format:
+/// `[user_id:u32_le][has_user_quota:u8][user_quota:WireQuota?][has_client_quota:u8][client_quota:WireQuota?]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserQuotasResponse {
+    pub user_id: u32,
+    pub user: Option<WireQuota>,
+    pub client: Option<WireQuota>,
+}
+
+fn optional_quota_size(quota: Option<&WireQuota>) -> usize {
+    1 + quota.map_or(0, WireEncode::encoded_size)
+}
+
+fn encode_optional_quota(quota: Option<&WireQuota>, buf: &mut BytesMut) {
+    if let Some(quota) = quota {
+        buf.put_u8(1);
+        quota.encode(buf);
+    } else {
+        buf.put_u8(0);
+    }
+}
+
+fn decode_optional_quota(buf: &[u8], pos: usize) -> Result<(Option<WireQuota>, usize), WireError> {
+    if read_u8(buf, pos)? == 1 {
+        let (quota, consumed) = WireQuota::decode(&buf[pos + 1..])?;
+        Ok((Some(quota), pos + 1 + consumed))
+    } else {
+        Ok((None, pos + 1))
+    }
+}
+
+impl WireEncode for UserQuotasResponse {
+    fn encoded_size(&self) -> usize {
+        4 + optional_quota_size(self.user.as_ref()) + optional_quota_size(self.client.as_ref())
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.user_id);
+        encode_optional_quota(self.user.as_ref(), buf);
+        encode_optional_quota(self.client.as_ref(), buf);
+    }
+}
+
+impl WireDecode for UserQuotasResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let user_id = read_u32_le(buf, 0)?;
+        let (user, pos) = decode_optional_quota(buf, 4)?;
+        let (client, pos) = decode_optional_quota(buf, pos)?;
+        Ok((
+            Self {
+                user_id,
+                user,
+                client,
+            },
+            pos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> UserQuotasResponse {
+        UserQuotasResponse {
+            user_id: 3,
+            user: Some(WireQuota {
+                produce_bytes_per_second: 10_000,
+                fetch_bytes_per_second: 0,
+                requests_per_second: 100,
+            }),
+            client: None,
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let resp = sample();
+        let bytes = resp.to_bytes();
+        assert_eq!(bytes.len(), resp.encoded_size());
+        let (decoded, consumed) = UserQuotasResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn roundtrip_empty() {
+        let resp = UserQuotasResponse {
+            user_id: 1,
+            user: None,
+            client: None,
+        };
+        let bytes = resp.to_bytes();
+        assert_eq!(bytes.len(), 6);
+        let (decoded, _) = UserQuotasResponse::decode(&bytes).unwrap();
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let bytes = sample().to_bytes();
+        for i in 0..bytes.len() {
+            assert!(
+                UserQuotasResponse::decode(&bytes[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
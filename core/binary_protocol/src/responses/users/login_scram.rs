@@ -0,0 +1,173 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::WireError;
+use crate::codec::{WireDecode, WireEncode, read_bytes, read_str, read_u8, read_u32_le};
+use bytes::{BufMut, BytesMut};
+
+/// Server-first message of the SCRAM-SHA-256 exchange.
+///
+/// Wire format:
+/// ```text
+/// [nonce_len:1][nonce:N][salt_len:1][salt:N][iterations:4]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginScramStartResponse {
+    pub nonce: String,
+    pub salt: Vec<u8>,
+    pub iterations: u32,
+}
+
+impl WireEncode for LoginScramStartResponse {
+    fn encoded_size(&self) -> usize {
+        1 + self.nonce.len() + 1 + self.salt.len() + 4
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u8(self.nonce.len() as u8);
+        buf.put_slice(self.nonce.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u8(self.salt.len() as u8);
+        buf.put_slice(&self.salt);
+        buf.put_u32_le(self.iterations);
+    }
+}
+
+impl WireDecode for LoginScramStartResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let nonce_len = read_u8(buf, 0)? as usize;
+        let mut pos = 1;
+        let nonce = read_str(buf, pos, nonce_len)?;
+        pos += nonce_len;
+        let salt_len = read_u8(buf, pos)? as usize;
+        pos += 1;
+        let salt = read_bytes(buf, pos, salt_len)?.to_vec();
+        pos += salt_len;
+        let iterations = read_u32_le(buf, pos)?;
+        pos += 4;
+        Ok((
+            Self {
+                nonce,
+                salt,
+                iterations,
+            },
+            pos,
+        ))
+    }
+}
+
+/// Server-final message of the SCRAM-SHA-256 exchange, carrying the
+/// authenticated user's ID and the server signature the client verifies.
+///
+/// Wire format:
+/// ```text
+/// [user_id:4][server_signature_len:1][server_signature:N]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginScramFinishResponse {
+    pub user_id: u32,
+    pub server_signature: Vec<u8>,
+}
+
+impl WireEncode for LoginScramFinishResponse {
+    fn encoded_size(&self) -> usize {
+        4 + 1 + self.server_signature.len()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.user_id);
+        #[allow(clippy::cast_possible_truncation)]
+        buf.put_u8(self.server_signature.len() as u8);
+        buf.put_slice(&self.server_signature);
+    }
+}
+
+impl WireDecode for LoginScramFinishResponse {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), WireError> {
+        let user_id = read_u32_le(buf, 0)?;
+        let server_signature_len = read_u8(buf, 4)? as usize;
+        let server_signature = read_bytes(buf, 5, server_signature_len)?.to_vec();
+        Ok((
+            Self {
+                user_id,
+                server_signature,
+            },
+            5 + server_signature_len,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_roundtrip() {
+        let resp = LoginScramStartResponse {
+            nonce: "client-nonce-server-nonce".to_string(),
+            salt: vec![1; 16],
+            iterations: 4096,
+        };
+        let bytes = resp.to_bytes();
+        assert_eq!(resp.encoded_size(), bytes.len());
+        let (decoded, consumed) = LoginScramStartResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn finish_roundtrip() {
+        let resp = LoginScramFinishResponse {
+            user_id: 42,
+            server_signature: vec![9; 32],
+        };
+        let bytes = resp.to_bytes();
+        assert_eq!(resp.encoded_size(), bytes.len());
+        let (decoded, consumed) = LoginScramFinishResponse::decode(&bytes).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn truncated_returns_error() {
+        let start = LoginScramStartResponse {
+            nonce: "n".to_string(),
+            salt: vec![1],
+            iterations: 1,
+        }
+        .to_bytes();
+        for i in 0..start.len() {
+            assert!(
+                LoginScramStartResponse::decode(&start[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+
+        let finish = LoginScramFinishResponse {
+            user_id: 1,
+            server_signature: vec![1],
+        }
+        .to_bytes();
+        for i in 0..finish.len() {
+            assert!(
+                LoginScramFinishResponse::decode(&finish[..i]).is_err(),
+                "expected error for truncation at byte {i}"
+            );
+        }
+    }
+}
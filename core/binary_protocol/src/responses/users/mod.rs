@@ -19,8 +19,10 @@ mod change_password;
 mod create_user;
 mod delete_user;
 pub mod get_user;
+pub mod get_user_quotas;
 pub mod get_users;
 pub mod login_register;
+pub mod login_scram;
 pub mod login_user;
 mod logout_user;
 mod update_permissions;
@@ -32,8 +34,10 @@ pub use change_password::ChangePasswordResponse;
 pub use create_user::CreateUserResponse;
 pub use delete_user::DeleteUserResponse;
 pub use get_user::UserDetailsResponse;
+pub use get_user_quotas::UserQuotasResponse;
 pub use get_users::GetUsersResponse;
 pub use login_register::LoginRegisterResponse;
+pub use login_scram::{LoginScramFinishResponse, LoginScramStartResponse};
 pub use login_user::IdentityResponse;
 pub use logout_user::LogoutUserResponse;
 pub use update_permissions::UpdatePermissionsResponse;
@@ -22,6 +22,7 @@ use iggy_cli::commands::binary_cluster::get_cluster_metadata::GetClusterMetadata
 use iggy_cli::commands::binary_consumer_groups::get_consumer_groups::GetConsumerGroupsOutput;
 use iggy_cli::commands::binary_context::get_contexts::GetContextsOutput;
 use iggy_cli::commands::binary_personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokensOutput;
+use iggy_cli::commands::binary_roles::get_roles::GetRolesOutput;
 use iggy_cli::commands::binary_streams::get_streams::GetStreamsOutput;
 use iggy_cli::commands::binary_system::stats::GetStatsOutput;
 use iggy_cli::commands::binary_topics::get_topics::GetTopicsOutput;
@@ -78,6 +79,15 @@ impl From<ListMode> for GetUsersOutput {
     }
 }
 
+impl From<ListMode> for GetRolesOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetRolesOutput::Table,
+            ListMode::List => GetRolesOutput::List,
+        }
+    }
+}
+
 impl From<ListMode> for GetClientsOutput {
     fn from(mode: ListMode) -> Self {
         match mode {
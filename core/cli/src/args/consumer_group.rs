@@ -81,6 +81,21 @@ pub(crate) enum ConsumerGroupAction {
     ///  iggy consumer-group list production sensor -l table
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(ConsumerGroupListArgs),
+    /// Get lag of a consumer group with given ID for given stream ID and topic ID
+    ///
+    /// For each partition, the lag is the count of messages not yet consumed by the consumer group,
+    /// and the lag time is the age of the oldest of them.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Consumer group ID can be specified as a consumer group name or ID
+    ///
+    /// Examples:
+    ///  iggy consumer-group lag 1 2 3
+    ///  iggy consumer-group lag stream 2 3
+    ///  iggy consumer-group lag stream topic group
+    #[clap(verbatim_doc_comment)]
+    Lag(ConsumerGroupLagArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -156,3 +171,22 @@ pub(crate) struct ConsumerGroupListArgs {
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ConsumerGroupLagArgs {
+    /// Stream ID to get consumer group lag
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to get consumer group lag
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Consumer group ID to get lag
+    ///
+    /// Consumer group ID can be specified as a consumer group name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) group_id: Identifier,
+}
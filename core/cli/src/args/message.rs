@@ -33,6 +33,7 @@ pub(crate) enum MessageAction {
     ///  iggy message send stream 2 "long message"
     ///  iggy message send 1 topic message1 message2 message3
     ///  iggy message send stream topic "long message with spaces"
+    ///  iggy message send --delay 30s stream topic "delayed message"
     #[clap(verbatim_doc_comment, visible_alias = "s")]
     Send(SendMessagesArgs),
     /// Poll messages from given topic ID and given stream ID
@@ -45,6 +46,7 @@ pub(crate) enum MessageAction {
     ///  iggy message poll --offset 0 stream 2 1
     ///  iggy message poll --offset 0 1 topic 1
     ///  iggy message poll --offset 0 stream topic 1
+    ///  iggy message poll --offset 0 --filter 'tenant == "acme"' stream topic 1
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Poll(PollMessagesArgs),
     /// Flush messages from given topic ID and given stream ID
@@ -116,6 +118,15 @@ pub(crate) struct SendMessagesArgs {
     #[clap(verbatim_doc_comment)]
     #[clap(long, value_parser = NonEmptyStringValueParser::new(), group = "input_messages")]
     pub(crate) input_file: Option<String>,
+    /// Delay after which the messages become visible to the consumers
+    ///
+    /// Delay is a human readable duration, e.g. 30s, 5m or "1h 30m". Messages
+    /// are appended to the partition right away, but polling stops before them
+    /// until the delay elapses. Messages read from the input file which already
+    /// have the delivery time set are sent as is.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_parser = clap::value_parser!(IggyDuration))]
+    pub(crate) delay: Option<IggyDuration>,
 }
 
 /// Parse Header Key, Kind and Value from the string separated by a ':'
@@ -251,6 +262,14 @@ pub(crate) struct PollMessagesArgs {
     #[clap(verbatim_doc_comment)]
     #[clap(short, long, default_value_t = false)]
     pub(crate) show_headers: bool,
+    /// Poll only the messages with the user headers matching the filter
+    ///
+    /// Filter is evaluated by the server, the headers are compared
+    /// with the string, integer, float or boolean literals,
+    /// e.g. 'tenant == "acme" && (priority >= 5 || urgent)'.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_parser = clap::value_parser!(MessageFilter))]
+    pub(crate) filter: Option<MessageFilter>,
     /// Store polled message into file in binary format
     ///
     /// Polled messages will be stored in the file in binary format.
@@ -48,6 +48,7 @@ use crate::args::system::LoginArgs;
 #[cfg(feature = "login-session")]
 use crate::args::session::SessionAction;
 
+use self::role::RoleAction;
 use self::user::UserAction;
 
 pub(crate) mod client;
@@ -60,6 +61,7 @@ pub(crate) mod message;
 pub(crate) mod partition;
 pub(crate) mod permissions;
 pub(crate) mod personal_access_token;
+pub(crate) mod role;
 pub(crate) mod segment;
 pub(crate) mod stream;
 pub(crate) mod system;
@@ -108,6 +110,14 @@ pub(crate) struct CliOptions {
     #[clap(short, long, verbatim_doc_comment)]
     pub(crate) password: Option<String>,
 
+    /// Use SCRAM-SHA-256 challenge-response login
+    ///
+    /// The password is not sent to the server, which allows to
+    /// authenticate securely over plain TCP, QUIC or WebSocket connections.
+    /// Not supported by the HTTP transport.
+    #[clap(long, default_value_t = false, verbatim_doc_comment)]
+    pub(crate) scram: bool,
+
     /// Iggy server personal access token
     #[clap(short, long, group = "credentials")]
     pub(crate) token: Option<String>,
@@ -178,6 +188,9 @@ pub(crate) enum Command {
     /// user operations
     #[command(subcommand, visible_alias = "u")]
     User(UserAction),
+    /// role operations
+    #[command(subcommand, visible_alias = "r")]
+    Role(RoleAction),
     /// client operations
     #[command(subcommand, visible_alias = "c")]
     Client(ClientAction),
@@ -265,6 +278,7 @@ impl IggyMergedConsoleArgs {
             debug: args.cli.debug,
             username: args.cli.username.or(context.username),
             password: args.cli.password.or(context.password),
+            scram: args.cli.scram,
             token: args.cli.token.or(context.token),
             #[cfg(feature = "login-session")]
             token_name: args.cli.token_name.or(context.token_name),
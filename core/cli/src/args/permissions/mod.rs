@@ -16,29 +16,36 @@
  * under the License.
  */
 
-use self::{global::GlobalPermissionsArg, stream::StreamPermissionsArg};
+use self::{
+    global::GlobalPermissionsArg, pattern::StreamPatternPermissionsArg,
+    stream::StreamPermissionsArg,
+};
 use clap::ValueEnum;
-use iggy::prelude::{Permissions, StreamPermissions, UserStatus};
+use iggy::prelude::{Permissions, StreamPatternPermissions, StreamPermissions, UserStatus};
 use std::collections::BTreeMap;
 
 pub(crate) mod constants;
 pub(crate) mod global;
+pub(crate) mod pattern;
 pub(crate) mod stream;
 pub(crate) mod topic;
 
 pub(crate) struct PermissionsArgs {
     global: Option<GlobalPermissionsArg>,
     stream: Vec<StreamPermissionsArg>,
+    stream_pattern: Vec<StreamPatternPermissionsArg>,
 }
 
 impl PermissionsArgs {
     pub(crate) fn new(
         global: Option<GlobalPermissionsArg>,
         stream: Option<Vec<StreamPermissionsArg>>,
+        stream_pattern: Option<Vec<StreamPatternPermissionsArg>>,
     ) -> Self {
         Self {
             global,
             stream: stream.unwrap_or_default(),
+            stream_pattern: stream_pattern.unwrap_or_default(),
         }
     }
 }
@@ -51,21 +58,21 @@ impl From<PermissionsArgs> for Option<Permissions> {
             .map(|s| (s.stream_id, s.into()))
             .collect::<BTreeMap<usize, StreamPermissions>>();
 
-        match (value.global, stream_permissions.is_empty()) {
-            (Some(global), true) => Some(Permissions {
-                global: global.into(),
-                streams: None,
-            }),
-            (Some(global), false) => Some(Permissions {
-                global: global.into(),
-                streams: Some(stream_permissions),
-            }),
-            (None, true) => None,
-            (None, false) => Some(Permissions {
-                global: Default::default(),
-                streams: Some(stream_permissions),
-            }),
+        let stream_patterns = value
+            .stream_pattern
+            .into_iter()
+            .map(StreamPatternPermissions::from)
+            .collect::<Vec<StreamPatternPermissions>>();
+
+        if value.global.is_none() && stream_permissions.is_empty() && stream_patterns.is_empty() {
+            return None;
         }
+
+        Some(Permissions {
+            global: value.global.map(Into::into).unwrap_or_default(),
+            streams: (!stream_permissions.is_empty()).then_some(stream_permissions),
+            stream_patterns: (!stream_patterns.is_empty()).then_some(stream_patterns),
+        })
     }
 }
 
@@ -89,10 +96,11 @@ impl From<UserStatusArg> for UserStatus {
 mod tests {
     use super::*;
     use crate::args::permissions::global::GlobalPermission;
+    use std::str::FromStr;
 
     #[test]
     fn should_convert_empty_permissions_args() {
-        let permissions: Option<Permissions> = Option::from(PermissionsArgs::new(None, None));
+        let permissions: Option<Permissions> = Option::from(PermissionsArgs::new(None, None, None));
         assert_eq!(permissions, None);
     }
 
@@ -100,7 +108,7 @@ mod tests {
     fn should_convert_only_global_permissions_args() {
         let global = GlobalPermissionsArg::new(vec![GlobalPermission::ManageServers]);
         let permissions_args: Option<Permissions> =
-            Option::from(PermissionsArgs::new(Some(global), None));
+            Option::from(PermissionsArgs::new(Some(global), None, None));
 
         let mut permissions = Permissions::default();
         permissions.global.manage_servers = true;
@@ -111,7 +119,7 @@ mod tests {
     fn should_convert_only_stream_permissions_args() {
         let stream = StreamPermissionsArg::new(1, vec![], vec![]);
         let permissions_args: Option<Permissions> =
-            Option::from(PermissionsArgs::new(None, Some(vec![stream])));
+            Option::from(PermissionsArgs::new(None, Some(vec![stream]), None));
 
         let permissions = Permissions {
             streams: Some(BTreeMap::from([(1, StreamPermissions::default())])),
@@ -125,7 +133,7 @@ mod tests {
         let global = GlobalPermissionsArg::new(vec![GlobalPermission::ManageTopics]);
         let stream = StreamPermissionsArg::new(1, vec![], vec![]);
         let permissions_args: Option<Permissions> =
-            Option::from(PermissionsArgs::new(Some(global), Some(vec![stream])));
+            Option::from(PermissionsArgs::new(Some(global), Some(vec![stream]), None));
 
         let mut permissions = Permissions {
             streams: Some(BTreeMap::from([(1, StreamPermissions::default())])),
@@ -135,6 +143,23 @@ mod tests {
         assert_eq!(permissions_args, Some(permissions));
     }
 
+    #[test]
+    fn should_convert_only_stream_pattern_permissions_args() {
+        let stream_pattern = StreamPatternPermissionsArg::from_str("orders-*:r_str").unwrap();
+        let permissions_args: Option<Permissions> =
+            Option::from(PermissionsArgs::new(None, None, Some(vec![stream_pattern])));
+
+        let permissions = Permissions {
+            stream_patterns: Some(vec![StreamPatternPermissions {
+                pattern: "orders-*".to_string(),
+                read_stream: true,
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        assert_eq!(permissions_args, Some(permissions));
+    }
+
     #[test]
     fn should_deserialize_user_status() {
         assert_eq!(
@@ -0,0 +1,190 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::stream::{StreamPermission, StreamPermissionsArg};
+use super::topic::{TopicPermission, TopicPermissionsArg};
+use iggy::prelude::{StreamPatternPermissions, TopicPatternPermissions};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct StreamPatternPermissionsArg {
+    pub(crate) permissions: StreamPatternPermissions,
+}
+
+impl From<StreamPatternPermissionsArg> for StreamPatternPermissions {
+    fn from(cmd: StreamPatternPermissionsArg) -> Self {
+        cmd.permissions
+    }
+}
+
+/// Splits the `pattern[:permission,...]` part into the pattern and the parsed permissions.
+fn parse_pattern_part<P: FromStr<Err = E>, E>(
+    part: &str,
+    kind: &str,
+    error_value: impl Fn(E) -> String,
+) -> Result<(String, Vec<P>), String> {
+    let (pattern, permissions_str) = match part.split_once(':') {
+        Some((pattern, permissions_str)) => (pattern, Some(permissions_str)),
+        None => (part, None),
+    };
+
+    if pattern.is_empty() {
+        return Err(format!("Missing {kind} pattern"));
+    }
+
+    let Some(permissions_str) = permissions_str else {
+        return Ok((pattern.to_owned(), vec![]));
+    };
+
+    let (values, errors): (Vec<_>, Vec<_>) = permissions_str
+        .split(',')
+        .map(|s| s.parse::<P>())
+        .partition(Result::is_ok);
+
+    if !errors.is_empty() {
+        let errors = errors
+            .into_iter()
+            .map(|e| format!("\"{}\"", error_value(e.err().unwrap())))
+            .collect::<Vec<String>>();
+
+        return Err(format!(
+            "Unknown permission{} {} for {kind} pattern: {pattern}",
+            match errors.len() {
+                1 => "",
+                _ => "s",
+            },
+            errors.join(", "),
+        ));
+    }
+
+    Ok((
+        pattern.to_owned(),
+        values.into_iter().map(|p| p.ok().unwrap()).collect(),
+    ))
+}
+
+impl FromStr for StreamPatternPermissionsArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('#');
+        let stream_part = parts.next().unwrap_or_default();
+        let (pattern, stream_permissions) =
+            parse_pattern_part::<StreamPermission, _>(stream_part, "stream", |e| e.0)?;
+
+        let mut topics = vec![];
+        let mut topic_errors = vec![];
+        for topic_part in parts {
+            match parse_pattern_part::<TopicPermission, _>(topic_part, "topic", |e| e.0) {
+                Ok((pattern, permissions)) => {
+                    let topic = TopicPermissionsArg::new(0, permissions).permissions;
+                    topics.push(TopicPatternPermissions {
+                        pattern,
+                        manage_topic: topic.manage_topic,
+                        read_topic: topic.read_topic,
+                        poll_messages: topic.poll_messages,
+                        send_messages: topic.send_messages,
+                    });
+                }
+                Err(error) => topic_errors.push(error),
+            }
+        }
+
+        if !topic_errors.is_empty() {
+            return Err(topic_errors.join("; "));
+        }
+
+        let stream = StreamPermissionsArg::new(0, stream_permissions, vec![]).permissions;
+        Ok(StreamPatternPermissionsArg {
+            permissions: StreamPatternPermissions {
+                pattern,
+                manage_stream: stream.manage_stream,
+                read_stream: stream.read_stream,
+                manage_topics: stream.manage_topics,
+                read_topics: stream.read_topics,
+                poll_messages: stream.poll_messages,
+                send_messages: stream.send_messages,
+                topics: if topics.is_empty() {
+                    None
+                } else {
+                    Some(topics)
+                },
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_deserialize_stream_pattern_without_permissions() {
+        let arg = StreamPatternPermissionsArg::from_str("orders-*").unwrap();
+        assert_eq!(
+            arg.permissions,
+            StreamPatternPermissions {
+                pattern: "orders-*".to_string(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn should_deserialize_stream_and_topic_patterns() {
+        let arg =
+            StreamPatternPermissionsArg::from_str("orders-*:r_str,p_msg#events-*:s_msg,read_topic")
+                .unwrap();
+        assert_eq!(
+            arg.permissions,
+            StreamPatternPermissions {
+                pattern: "orders-*".to_string(),
+                read_stream: true,
+                poll_messages: true,
+                topics: Some(vec![TopicPatternPermissions {
+                    pattern: "events-*".to_string(),
+                    read_topic: true,
+                    send_messages: true,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_unknown_permissions() {
+        let error = StreamPatternPermissionsArg::from_str("orders-*:r_str,xyz").unwrap_err();
+        assert_eq!(
+            error,
+            "Unknown permission \"xyz\" for stream pattern: orders-*"
+        );
+        let error =
+            StreamPatternPermissionsArg::from_str("orders-*#events-*:abc,m_str").unwrap_err();
+        assert_eq!(
+            error,
+            "Unknown permissions \"abc\", \"m_str\" for topic pattern: events-*"
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_empty_pattern() {
+        let error = StreamPatternPermissionsArg::from_str(":r_str").unwrap_err();
+        assert_eq!(error, "Missing stream pattern");
+    }
+}
@@ -37,7 +37,7 @@ pub(super) enum StreamPermission {
 }
 
 #[derive(Clone, Debug, PartialEq)]
-pub(crate) struct StreamPermissionError(String);
+pub(crate) struct StreamPermissionError(pub(super) String);
 
 impl FromStr for StreamPermission {
     type Err = StreamPermissionError;
@@ -390,6 +390,7 @@ mod tests {
                                 read_topic: false,
                                 poll_messages: false,
                                 send_messages: false,
+                                consumer_groups: None,
                             }
                         ),
                         (
@@ -399,6 +400,7 @@ mod tests {
                                 read_topic: false,
                                 poll_messages: false,
                                 send_messages: false,
+                                consumer_groups: None,
                             }
                         )
                     ])),
@@ -425,6 +427,7 @@ mod tests {
                                 read_topic: false,
                                 poll_messages: false,
                                 send_messages: false,
+                                consumer_groups: None,
                             }
                         ),
                         (
@@ -434,6 +437,7 @@ mod tests {
                                 read_topic: false,
                                 poll_messages: false,
                                 send_messages: false,
+                                consumer_groups: None,
                             }
                         )
                     ])),
@@ -523,6 +527,7 @@ mod tests {
                                 read_topic: false,
                                 poll_messages: false,
                                 send_messages: false,
+                                consumer_groups: None,
                             }
                         ),
                         (
@@ -532,6 +537,7 @@ mod tests {
                                 read_topic: false,
                                 poll_messages: false,
                                 send_messages: false,
+                                consumer_groups: None,
                             }
                         )
                     ])),
@@ -25,7 +25,7 @@ use super::constants::{
 };
 
 #[derive(Clone, Debug, PartialEq)]
-enum TopicPermission {
+pub(super) enum TopicPermission {
     ManageTopic,
     ReadTopic,
     PollMessages,
@@ -33,7 +33,7 @@ enum TopicPermission {
 }
 
 #[derive(Clone, Debug, PartialEq)]
-pub(crate) struct TopicPermissionError(String);
+pub(crate) struct TopicPermissionError(pub(super) String);
 
 impl FromStr for TopicPermission {
     type Err = TopicPermissionError;
@@ -63,7 +63,7 @@ impl From<TopicPermissionsArg> for TopicPermissions {
 }
 
 impl TopicPermissionsArg {
-    fn new(topic_id: usize, topic_permissions: Vec<TopicPermission>) -> Self {
+    pub(super) fn new(topic_id: usize, topic_permissions: Vec<TopicPermission>) -> Self {
         let mut result = Self {
             topic_id,
             permissions: TopicPermissions::default(),
@@ -220,6 +220,7 @@ mod tests {
                     read_topic: true,
                     poll_messages: true,
                     send_messages: true,
+                    consumer_groups: None,
                 }
             }
         );
@@ -232,6 +233,7 @@ mod tests {
                     read_topic: true,
                     poll_messages: false,
                     send_messages: false,
+                    consumer_groups: None,
                 }
             }
         );
@@ -244,6 +246,7 @@ mod tests {
                     read_topic: true,
                     poll_messages: false,
                     send_messages: true,
+                    consumer_groups: None,
                 }
             }
         );
@@ -256,6 +259,7 @@ mod tests {
                     read_topic: false,
                     poll_messages: false,
                     send_messages: false,
+                    consumer_groups: None,
                 }
             }
         );
@@ -268,6 +272,7 @@ mod tests {
                     read_topic: false,
                     poll_messages: false,
                     send_messages: true,
+                    consumer_groups: None,
                 }
             }
         );
@@ -284,6 +289,7 @@ mod tests {
                     read_topic: true,
                     poll_messages: true,
                     send_messages: true,
+                    consumer_groups: None,
                 }
             }
         );
@@ -296,6 +302,7 @@ mod tests {
                     read_topic: true,
                     poll_messages: false,
                     send_messages: false,
+                    consumer_groups: None,
                 }
             }
         );
@@ -308,6 +315,7 @@ mod tests {
                     read_topic: true,
                     poll_messages: false,
                     send_messages: true,
+                    consumer_groups: None,
                 }
             }
         );
@@ -320,6 +328,7 @@ mod tests {
                     read_topic: false,
                     poll_messages: false,
                     send_messages: true,
+                    consumer_groups: None,
                 }
             }
         );
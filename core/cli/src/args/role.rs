@@ -0,0 +1,200 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::common::ListMode;
+use crate::args::permissions::global::GlobalPermissionsArg;
+use crate::args::permissions::pattern::StreamPatternPermissionsArg;
+use crate::args::permissions::stream::StreamPermissionsArg;
+use clap::{Args, Subcommand};
+use iggy::prelude::Identifier;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum RoleAction {
+    /// Create role with given name and permissions
+    ///
+    /// Examples
+    ///  iggy role create readers -g r_str,r_top,p_msg
+    ///  iggy role create producers --stream-permissions 1:s_msg
+    #[clap(verbatim_doc_comment, visible_alias = "c")]
+    Create(RoleCreateArgs),
+    /// Delete role with given ID
+    ///
+    /// The role ID can be specified as either a role name or an ID.
+    /// The role is unassigned from all of its users.
+    ///
+    /// Examples:
+    ///  iggy role delete 1
+    ///  iggy role delete readers
+    #[clap(verbatim_doc_comment, visible_alias = "d")]
+    Delete(RoleDeleteArgs),
+    /// Get details of a single role with given ID
+    ///
+    /// The role ID can be specified as either a role name or an ID
+    ///
+    /// Examples:
+    ///  iggy role get 1
+    ///  iggy role get readers
+    #[clap(verbatim_doc_comment, visible_alias = "g")]
+    Get(RoleGetArgs),
+    /// List all roles
+    ///
+    /// Examples:
+    ///  iggy role list
+    ///  iggy role list --list-mode table
+    ///  iggy role list -l table
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(RoleListArgs),
+    /// Set permissions for role with given ID
+    ///
+    /// The role ID can be specified as either a role name or an ID. Permissions
+    /// are configured based on the options provided with this command. If no
+    /// options are set, the default behavior is to remove permissions for the
+    /// specified role. The change applies to all users holding the role.
+    ///
+    /// Examples:
+    ///  iggy role permissions 1 -g r_str
+    ///  iggy role permissions readers
+    #[clap(verbatim_doc_comment, visible_alias = "p")]
+    Permissions(RolePermissionsArgs),
+    /// Assign role with given ID to user with given ID
+    ///
+    /// Both the user ID and the role ID can be specified as either a name or an ID
+    ///
+    /// Examples:
+    ///  iggy role assign testuser readers
+    ///  iggy role assign 2 1
+    #[clap(verbatim_doc_comment, visible_alias = "a")]
+    Assign(RoleAssignmentArgs),
+    /// Unassign role with given ID from user with given ID
+    ///
+    /// Both the user ID and the role ID can be specified as either a name or an ID
+    ///
+    /// Examples:
+    ///  iggy role unassign testuser readers
+    ///  iggy role unassign 2 1
+    #[clap(verbatim_doc_comment, visible_alias = "u")]
+    Unassign(RoleAssignmentArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleCreateArgs {
+    /// Role name
+    ///
+    /// Unique name of the role on iggy server,
+    /// must be between 3 and 50 characters long.
+    #[clap(verbatim_doc_comment)]
+    pub(crate) name: String,
+    /// Set global permissions for created role
+    ///
+    /// All global permissions by default are set to false and this command line option
+    /// allows to set each permission individually. Permissions are separated by comma,
+    /// using the same names as for the user permissions.
+    ///
+    /// Available permissions (long and short versions):  manage_servers / m_srv,
+    /// read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
+    /// manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
+    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+    ///
+    /// Examples:
+    ///  iggy role create clients --global-permissions p_msg,s_msg
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(GlobalPermissionsArg))]
+    pub(crate) global_permissions: Option<GlobalPermissionsArg>,
+    /// Set stream permissions for created role
+    ///
+    /// Stream permissions use the same format as for the user permissions:
+    /// STREAM_ID\[:STREAM_PERMISSIONS\]\[#TOPIC_ID\[:TOPIC_PERMISSIONS\]\]
+    ///
+    /// Examples:
+    ///  iggy role create topic_admins -s 1:manage_topics,read_topics
+    ///  iggy role create senders -s 3#1:s_msg#2:s_msg
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
+    pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+    /// Set stream pattern permissions for created role
+    ///
+    /// Stream pattern permissions use the same format as for the user permissions:
+    /// STREAM_PATTERN\[:STREAM_PERMISSIONS\]\[#TOPIC_PATTERN\[:TOPIC_PERMISSIONS\]\]
+    ///
+    /// Examples:
+    ///  iggy role create orders_team -S orders-*:m_top,r_top,p_msg,s_msg
+    #[clap(short = 'S', long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(StreamPatternPermissionsArg))]
+    pub(crate) stream_pattern_permissions: Option<Vec<StreamPatternPermissionsArg>>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleDeleteArgs {
+    /// Role ID to delete
+    ///
+    /// The role ID can be specified as either a role name or an ID
+    pub(crate) role_id: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleGetArgs {
+    /// Role ID to get
+    ///
+    /// The role ID can be specified as either a role name or an ID
+    pub(crate) role_id: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleListArgs {
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RolePermissionsArgs {
+    /// Role ID to update
+    ///
+    /// The role ID can be specified as either a role name or an ID
+    pub(crate) role_id: Identifier,
+    /// Set global permissions for the role
+    ///
+    /// Uses the same format as the global permissions of the role create command.
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(GlobalPermissionsArg))]
+    pub(crate) global_permissions: Option<GlobalPermissionsArg>,
+    /// Set stream permissions for the role
+    ///
+    /// Uses the same format as the stream permissions of the role create command.
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
+    pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+    /// Set stream pattern permissions for the role
+    ///
+    /// Uses the same format as the stream pattern permissions of the role create command.
+    #[clap(short = 'S', long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(StreamPatternPermissionsArg))]
+    pub(crate) stream_pattern_permissions: Option<Vec<StreamPatternPermissionsArg>>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleAssignmentArgs {
+    /// User ID
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+    /// Role ID
+    ///
+    /// The role ID can be specified as either a role name or an ID
+    pub(crate) role_id: Identifier,
+}
@@ -18,7 +18,7 @@
 
 use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
-use iggy::prelude::{CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
+use iggy::prelude::{CleanupPolicy, CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum TopicAction {
@@ -121,6 +121,11 @@ pub(crate) struct TopicCreateArgs {
     /// Replication factor for the topic
     #[arg(short, long, default_value = "1")]
     pub(crate) replication_factor: u8,
+    /// Cleanup policy for the topic, either "delete" or "compact"
+    ///
+    /// "compact" keeps only the latest message for each message key in sealed segments
+    #[arg(long, default_value = "delete", value_parser = clap::value_parser!(CleanupPolicy), verbatim_doc_comment)]
+    pub(crate) cleanup_policy: CleanupPolicy,
     /// Message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
     ///
     /// "server_default" or skipping parameter makes CLI to use server default (from current server config) expiry time
@@ -168,6 +173,9 @@ pub(crate) struct TopicUpdateArgs {
     #[arg(short, long, default_value = "1")]
     /// New replication factor for the topic
     pub(crate) replication_factor: u8,
+    /// New cleanup policy for the topic, either "delete" or "compact"
+    #[arg(long, default_value = "delete", value_parser = clap::value_parser!(CleanupPolicy), verbatim_doc_comment)]
+    pub(crate) cleanup_policy: CleanupPolicy,
     /// New message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
     ///
     /// "server_default" or skipping parameter makes CLI to use server default (from current server config) expiry time
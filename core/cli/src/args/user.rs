@@ -18,6 +18,7 @@
 
 use crate::args::common::ListMode;
 use crate::args::permissions::UserStatusArg;
+use crate::args::permissions::pattern::StreamPatternPermissionsArg;
 use crate::args::permissions::stream::StreamPermissionsArg;
 use clap::{Args, Subcommand};
 use iggy::prelude::Identifier;
@@ -171,6 +172,22 @@ pub(crate) struct UserCreateArgs {
     #[clap(short, long, verbatim_doc_comment)]
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+    /// Set stream pattern permissions for created user
+    ///
+    /// Stream pattern permissions are applied to all the streams whose names match the
+    /// pattern, including the streams created later on. The pattern is a glob, where
+    /// asterisk (*) matches any sequence of characters and question mark (?) matches
+    /// a single character. Permissions use the same names and format as the stream
+    /// permissions, with the stream and topic IDs replaced by the name patterns.
+    ///
+    /// Permissions format: STREAM_PATTERN\[:STREAM_PERMISSIONS\]\[#TOPIC_PATTERN\[:TOPIC_PERMISSIONS\]\]
+    ///
+    /// Examples:
+    ///  iggy user create orders_service s3cr3t -S orders-*:m_top,r_top,p_msg,s_msg
+    ///  iggy user create audit s3cr3t --stream-pattern-permissions *:r_str#audit-*:p_msg
+    #[clap(short = 'S', long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(StreamPatternPermissionsArg))]
+    pub(crate) stream_pattern_permissions: Option<Vec<StreamPatternPermissionsArg>>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -302,4 +319,20 @@ pub(crate) struct UserPermissionsArgs {
     #[clap(short, long, verbatim_doc_comment)]
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+    /// Set stream pattern permissions for user
+    ///
+    /// Stream pattern permissions are applied to all the streams whose names match the
+    /// pattern, including the streams created later on. The pattern is a glob, where
+    /// asterisk (*) matches any sequence of characters and question mark (?) matches
+    /// a single character. Permissions use the same names and format as the stream
+    /// permissions, with the stream and topic IDs replaced by the name patterns.
+    ///
+    /// Permissions format: STREAM_PATTERN\[:STREAM_PERMISSIONS\]\[#TOPIC_PATTERN\[:TOPIC_PERMISSIONS\]\]
+    ///
+    /// Examples:
+    ///  iggy user permissions orders_service -S orders-*:m_top,r_top,p_msg,s_msg
+    ///  iggy user permissions audit --stream-pattern-permissions *:r_str#audit-*:p_msg
+    #[clap(short = 'S', long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(StreamPatternPermissionsArg))]
+    pub(crate) stream_pattern_permissions: Option<Vec<StreamPatternPermissionsArg>>,
 }
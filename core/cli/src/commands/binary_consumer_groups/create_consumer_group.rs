@@ -35,6 +35,8 @@ impl CreateConsumerGroupCmd {
                 stream_id,
                 topic_id,
                 name,
+                dead_letter: None,
+                shared: None,
             },
         }
     }
@@ -58,7 +60,7 @@ impl CliCommand for CreateConsumerGroupCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .create_consumer_group(&self.create_consumer_group.stream_id, &self.create_consumer_group.topic_id, &self.create_consumer_group.name)
+            .create_consumer_group(&self.create_consumer_group.stream_id, &self.create_consumer_group.topic_id, &self.create_consumer_group.name, self.create_consumer_group.dead_letter.clone(), self.create_consumer_group.shared)
             .await
             .with_context(|| {
                 format!(
@@ -0,0 +1,99 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::commands::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use iggy_common::Client;
+use iggy_common::Identifier;
+use tracing::{Level, event};
+
+pub struct GetConsumerGroupLagCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    group_id: Identifier,
+}
+
+impl GetConsumerGroupLagCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, consumer_group_id: Identifier) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            group_id: consumer_group_id,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetConsumerGroupLagCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get lag of consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.group_id, self.topic_id, self.stream_id,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let lag = client
+            .get_consumer_group_lag(&self.stream_id, &self.topic_id, &self.group_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting lag of consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.group_id, self.topic_id, self.stream_id
+                )
+            })?;
+
+        let Some(lag) = lag else {
+            event!(target: PRINT_TARGET, Level::INFO, "Consumer group with ID: {} was not found", self.group_id);
+            return Ok(());
+        };
+
+        let mut table = Table::new();
+        table.set_header(vec![
+            "Partition",
+            "Committed offset",
+            "Log end offset",
+            "Lag",
+            "Lag time",
+        ]);
+        for partition in &lag.partitions {
+            table.add_row(vec![
+                format!("{}", partition.partition_id),
+                partition
+                    .committed_offset
+                    .map_or("-".to_string(), |offset| format!("{offset}")),
+                format!("{}", partition.log_end_offset),
+                format!("{}", partition.lag),
+                format!("{}", partition.lag_time),
+            ]);
+        }
+        table.add_row(vec![
+            "Total".to_string(),
+            String::new(),
+            String::new(),
+            format!("{}", lag.total_lag()),
+            String::new(),
+        ]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
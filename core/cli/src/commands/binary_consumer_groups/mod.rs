@@ -19,4 +19,5 @@
 pub mod create_consumer_group;
 pub mod delete_consumer_group;
 pub mod get_consumer_group;
+pub mod get_consumer_group_lag;
 pub mod get_consumer_groups;
@@ -24,7 +24,7 @@ use iggy_binary_protocol::WireUserHeaders;
 use iggy_common::Client;
 use iggy_common::{
     Consumer, HeaderKey, HeaderKind, Identifier, IggyByteSize, IggyDuration, IggyMessage,
-    IggyTimestamp, PollMessages, PollingStrategy, Sizeable,
+    IggyTimestamp, MessageFilter, PollMessages, PollingStrategy, Sizeable,
     wire_conversions::user_headers_from_wire,
 };
 use std::collections::HashSet;
@@ -51,6 +51,7 @@ impl PollMessagesCmd {
         next: bool,
         consumer: Identifier,
         show_headers: bool,
+        filter: Option<MessageFilter>,
         output_file: Option<String>,
     ) -> Self {
         let strategy = match (offset, first, last, next) {
@@ -69,6 +70,7 @@ impl PollMessagesCmd {
                 strategy,
                 count: message_count,
                 auto_commit,
+                filter,
             },
             show_headers,
             output_file,
@@ -176,23 +178,41 @@ impl CliCommand for PollMessagesCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let start = std::time::Instant::now();
-        let polled_messages = client
-            .poll_messages(
-                &self.poll_messages.stream_id,
-                &self.poll_messages.topic_id,
-                self.poll_messages.partition_id,
-                &self.poll_messages.consumer,
-                &self.poll_messages.strategy,
-                self.poll_messages.count,
-                self.poll_messages.auto_commit,
+        let polled_messages = match &self.poll_messages.filter {
+            Some(filter) => {
+                client
+                    .poll_filtered_messages(
+                        &self.poll_messages.stream_id,
+                        &self.poll_messages.topic_id,
+                        self.poll_messages.partition_id,
+                        &self.poll_messages.consumer,
+                        &self.poll_messages.strategy,
+                        self.poll_messages.count,
+                        self.poll_messages.auto_commit,
+                        filter,
+                    )
+                    .await
+            }
+            None => {
+                client
+                    .poll_messages(
+                        &self.poll_messages.stream_id,
+                        &self.poll_messages.topic_id,
+                        self.poll_messages.partition_id,
+                        &self.poll_messages.consumer,
+                        &self.poll_messages.strategy,
+                        self.poll_messages.count,
+                        self.poll_messages.auto_commit,
+                    )
+                    .await
+            }
+        }
+        .with_context(|| {
+            format!(
+                "Problem polling messages to topic with ID: {} and stream with ID: {}",
+                self.poll_messages.topic_id, self.poll_messages.stream_id
             )
-            .await
-            .with_context(|| {
-                format!(
-                    "Problem polling messages to topic with ID: {} and stream with ID: {}",
-                    self.poll_messages.topic_id, self.poll_messages.stream_id
-                )
-            })?;
+        })?;
         let elapsed = IggyDuration::new(start.elapsed());
 
         event!(target: PRINT_TARGET, Level::INFO,
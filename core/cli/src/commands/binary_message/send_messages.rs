@@ -21,7 +21,10 @@ use anyhow::Context;
 use async_trait::async_trait;
 use bytes::Bytes;
 use iggy_common::Client;
-use iggy_common::{HeaderKey, HeaderValue, Identifier, IggyMessage, Partitioning, Sizeable};
+use iggy_common::{
+    HeaderKey, HeaderValue, Identifier, IggyDuration, IggyMessage, IggyTimestamp, Partitioning,
+    Sizeable,
+};
 use std::collections::BTreeMap;
 use std::io::{self, Read};
 use tokio::io::AsyncReadExt;
@@ -34,9 +37,11 @@ pub struct SendMessagesCmd {
     messages: Option<Vec<String>>,
     headers: Vec<(HeaderKey, HeaderValue)>,
     input_file: Option<String>,
+    delay: Option<IggyDuration>,
 }
 
 impl SendMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: Identifier,
@@ -45,6 +50,7 @@ impl SendMessagesCmd {
         messages: Option<Vec<String>>,
         headers: Vec<(HeaderKey, HeaderValue)>,
         input_file: Option<String>,
+        delay: Option<IggyDuration>,
     ) -> Self {
         let partitioning = match (partition_id, message_key) {
             (Some(_), Some(_)) => unreachable!(),
@@ -62,6 +68,7 @@ impl SendMessagesCmd {
             messages,
             headers,
             input_file,
+            delay,
         }
     }
 
@@ -162,6 +169,16 @@ impl CliCommand for SendMessagesCmd {
             }
         };
 
+        if let Some(delay) = self.delay {
+            let deliver_at =
+                IggyTimestamp::from(IggyTimestamp::now().as_micros() + delay.as_micros());
+            for message in messages.iter_mut() {
+                if message.deliver_at()?.is_none() {
+                    message.set_deliver_at(deliver_at)?;
+                }
+            }
+        }
+
         client
             .send_messages(
                 &self.stream_id,
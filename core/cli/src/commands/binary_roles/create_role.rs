@@ -0,0 +1,58 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::commands::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::Client;
+use iggy_common::Permissions;
+use iggy_common::create_role::CreateRole;
+use tracing::{Level, event};
+
+pub struct CreateRoleCmd {
+    create_role: CreateRole,
+}
+
+impl CreateRoleCmd {
+    pub fn new(name: String, permissions: Option<Permissions>) -> Self {
+        Self {
+            create_role: CreateRole { name, permissions },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateRoleCmd {
+    fn explain(&self) -> String {
+        format!("create role with name: {}", self.create_role.name)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .create_role(&self.create_role.name, self.create_role.permissions.clone())
+            .await
+            .with_context(|| format!("Problem creating role (name: {})", self.create_role.name))?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Role with name: {} created",
+            self.create_role.name
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::commands::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use iggy_common::Client;
+use iggy_common::Identifier;
+use tracing::{Level, event};
+
+pub struct GetRoleCmd {
+    role_id: Identifier,
+}
+
+impl GetRoleCmd {
+    pub fn new(role_id: Identifier) -> Self {
+        Self { role_id }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetRoleCmd {
+    fn explain(&self) -> String {
+        format!("get role with ID: {}", self.role_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let role = client
+            .get_role(&self.role_id)
+            .await
+            .with_context(|| format!("Problem getting role with ID: {}", self.role_id))?;
+
+        if role.is_none() {
+            event!(
+                target: PRINT_TARGET,
+                Level::INFO,
+                "Role with ID: {} was not found",
+                self.role_id
+            );
+            return Ok(());
+        }
+
+        let role = role.unwrap();
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["Role ID", format!("{}", role.id).as_str()]);
+        table.add_row(vec![
+            "Created",
+            role.created_at
+                .to_local_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec!["Name", role.name.as_str()]);
+        table.add_row(vec![
+            "Users",
+            role.users
+                .iter()
+                .map(|user_id| user_id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+                .as_str(),
+        ]);
+
+        if let Some(permissions) = role.permissions {
+            let global_permissions: Table = permissions.global.into();
+            table.add_row(vec!["Global", format!("{global_permissions}").as_str()]);
+
+            if let Some(streams) = permissions.streams {
+                streams.iter().for_each(|(stream_id, stream_permissions)| {
+                    let stream_permissions: Table = stream_permissions.into();
+                    table.add_row(vec![
+                        format!("Stream: {stream_id}").as_str(),
+                        format!("{stream_permissions}").as_str(),
+                    ]);
+                });
+            }
+
+            if let Some(stream_patterns) = permissions.stream_patterns {
+                stream_patterns.iter().for_each(|stream_pattern| {
+                    let stream_permissions: Table = stream_pattern.into();
+                    table.add_row(vec![
+                        format!("Stream: {}", stream_pattern.pattern).as_str(),
+                        format!("{stream_permissions}").as_str(),
+                    ]);
+                });
+            }
+        };
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::commands::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use iggy_common::Client;
+use tracing::{Level, event};
+
+pub enum GetRolesOutput {
+    Table,
+    List,
+}
+
+pub struct GetRolesCmd {
+    output: GetRolesOutput,
+}
+
+impl GetRolesCmd {
+    pub fn new(output: GetRolesOutput) -> Self {
+        GetRolesCmd { output }
+    }
+}
+
+impl Default for GetRolesCmd {
+    fn default() -> Self {
+        GetRolesCmd {
+            output: GetRolesOutput::Table,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetRolesCmd {
+    fn explain(&self) -> String {
+        let mode = match self.output {
+            GetRolesOutput::Table => "table",
+            GetRolesOutput::List => "list",
+        };
+        format!("list roles in {mode} mode")
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let roles = client
+            .get_roles()
+            .await
+            .with_context(|| String::from("Problem getting list of roles"))?;
+
+        if roles.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "No roles found!");
+            return Ok(());
+        }
+
+        match self.output {
+            GetRolesOutput::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["ID", "Created", "Name", "Users Count"]);
+
+                roles.iter().for_each(|role| {
+                    table.add_row(vec![
+                        format!("{}", role.id),
+                        role.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        role.name.clone(),
+                        format!("{}", role.users_count),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetRolesOutput::List => {
+                roles.iter().for_each(|role| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}",
+                        role.id,
+                        role.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        role.name,
+                        role.users_count,
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
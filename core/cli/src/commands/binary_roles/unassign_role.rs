@@ -0,0 +1,65 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::commands::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::Client;
+use iggy_common::Identifier;
+use tracing::{Level, event};
+
+pub struct UnassignRoleCmd {
+    user_id: Identifier,
+    role_id: Identifier,
+}
+
+impl UnassignRoleCmd {
+    pub fn new(user_id: Identifier, role_id: Identifier) -> Self {
+        Self { user_id, role_id }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UnassignRoleCmd {
+    fn explain(&self) -> String {
+        format!(
+            "unassign role with ID: {} from user with ID: {}",
+            self.role_id, self.user_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .unassign_role(&self.user_id, &self.role_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem unassigning role with ID: {} from user with ID: {}",
+                    self.role_id, self.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Role with ID: {} unassigned from user with ID: {}",
+            self.role_id,
+            self.user_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::commands::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::Client;
+use iggy_common::update_role::UpdateRole;
+use iggy_common::{Identifier, Permissions};
+use tracing::{Level, event};
+
+pub struct UpdateRoleCmd {
+    update_role: UpdateRole,
+}
+
+impl UpdateRoleCmd {
+    pub fn new(role_id: Identifier, permissions: Option<Permissions>) -> Self {
+        Self {
+            update_role: UpdateRole {
+                role_id,
+                permissions,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateRoleCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update permissions for role with ID: {}",
+            self.update_role.role_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_role(
+                &self.update_role.role_id,
+                self.update_role.permissions.clone(),
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating permissions for role with ID: {}",
+                    self.update_role.role_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Permissions for role with ID: {} updated",
+            self.update_role.role_id
+        );
+
+        Ok(())
+    }
+}
@@ -22,7 +22,7 @@ use async_trait::async_trait;
 use core::fmt;
 use iggy_common::Client;
 use iggy_common::create_topic::CreateTopic;
-use iggy_common::{CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
+use iggy_common::{CleanupPolicy, CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
 use tracing::{Level, event};
 
 pub struct CreateTopicCmd {
@@ -42,6 +42,7 @@ impl CreateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
     ) -> Self {
         Self {
             create_topic: CreateTopic {
@@ -52,6 +53,7 @@ impl CreateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                cleanup_policy,
             },
             message_expiry,
             max_topic_size,
@@ -76,6 +78,7 @@ impl CliCommand for CreateTopicCmd {
                 self.create_topic.replication_factor,
                 self.create_topic.message_expiry,
                 self.create_topic.max_topic_size,
+                self.create_topic.cleanup_policy,
             )
             .await
             .with_context(|| {
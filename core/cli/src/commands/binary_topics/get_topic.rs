@@ -92,6 +92,10 @@ impl CliCommand for GetTopicCmd {
             "Max topic size",
             format!("{}", topic.max_topic_size).as_str(),
         ]);
+        table.add_row(vec![
+            "Cleanup policy",
+            topic.cleanup_policy.to_string().as_str(),
+        ]);
         table.add_row(vec![
             "Topic message count",
             format!("{}", topic.messages_count).as_str(),
@@ -22,7 +22,7 @@ use async_trait::async_trait;
 use core::fmt;
 use iggy_common::Client;
 use iggy_common::update_topic::UpdateTopic;
-use iggy_common::{CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
+use iggy_common::{CleanupPolicy, CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
 use tracing::{Level, event};
 
 pub struct UpdateTopicCmd {
@@ -33,6 +33,7 @@ pub struct UpdateTopicCmd {
 }
 
 impl UpdateTopicCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: Identifier,
@@ -41,6 +42,7 @@ impl UpdateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
     ) -> Self {
         Self {
             update_topic: UpdateTopic {
@@ -51,6 +53,7 @@ impl UpdateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                cleanup_policy,
             },
             message_expiry,
             max_topic_size,
@@ -67,7 +70,7 @@ impl CliCommand for UpdateTopicCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry, self.max_topic_size)
+            .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry, self.max_topic_size, self.update_topic.cleanup_policy)
             .await
             .with_context(|| {
                 format!(
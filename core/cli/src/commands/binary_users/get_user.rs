@@ -83,6 +83,16 @@ impl CliCommand for GetUserCmd {
                     ]);
                 });
             }
+
+            if let Some(stream_patterns) = permissions.stream_patterns {
+                stream_patterns.iter().for_each(|stream_pattern| {
+                    let stream_permissions: Table = stream_pattern.into();
+                    table.add_row(vec![
+                        format!("Stream: {}", stream_pattern.pattern).as_str(),
+                        format!("{stream_permissions}").as_str(),
+                    ]);
+                });
+            }
         };
 
         event!(target: PRINT_TARGET, Level::INFO, "{table}");
@@ -42,6 +42,7 @@ static ENV_IGGY_PASSWORD: &str = "IGGY_PASSWORD";
 struct IggyUserClient {
     username: String,
     password: SecretString,
+    scram: bool,
 }
 
 enum Credentials {
@@ -133,6 +134,7 @@ impl<'a> IggyCredentials<'a> {
                 credentials: Some(Credentials::UserNameAndPassword(IggyUserClient {
                     username: username.clone(),
                     password,
+                    scram: cli_options.scram,
                 })),
                 iggy_client: None,
                 login_required,
@@ -142,6 +144,7 @@ impl<'a> IggyCredentials<'a> {
                 credentials: Some(Credentials::UserNameAndPassword(IggyUserClient {
                     username: var(ENV_IGGY_USERNAME)?,
                     password: SecretString::from(var(ENV_IGGY_PASSWORD)?),
+                    scram: cli_options.scram,
                 })),
                 iggy_client: None,
                 login_required,
@@ -162,18 +165,16 @@ impl<'a> IggyCredentials<'a> {
             let credentials = self.credentials.as_ref().unwrap();
             match credentials {
                 Credentials::UserNameAndPassword(username_and_password) => {
-                    let _ = client
-                        .login_user(
-                            &username_and_password.username,
-                            username_and_password.password.expose_secret(),
-                        )
-                        .await
-                        .with_context(|| {
-                            format!(
-                                "Problem with server login for username: {}",
-                                &username_and_password.username
-                            )
-                        })?;
+                    let username = &username_and_password.username;
+                    let password = username_and_password.password.expose_secret();
+                    let login_result = if username_and_password.scram {
+                        client.login_user_scram(username, password).await
+                    } else {
+                        client.login_user(username, password).await
+                    };
+                    let _ = login_result.with_context(|| {
+                        format!("Problem with server login for username: {username}")
+                    })?;
                 }
                 Credentials::PersonalAccessToken(token_value) => {
                     let _ = client
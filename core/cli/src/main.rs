@@ -33,6 +33,7 @@ use crate::logging::Logging;
 use args::context::ContextAction;
 use args::message::MessageAction;
 use args::partition::PartitionAction;
+use args::role::RoleAction;
 use args::segment::SegmentAction;
 use args::user::UserAction;
 use args::{CliOptions, IggyMergedConsoleArgs};
@@ -54,7 +55,7 @@ use iggy_cli::commands::{
     binary_consumer_groups::{
         create_consumer_group::CreateConsumerGroupCmd,
         delete_consumer_group::DeleteConsumerGroupCmd, get_consumer_group::GetConsumerGroupCmd,
-        get_consumer_groups::GetConsumerGroupsCmd,
+        get_consumer_group_lag::GetConsumerGroupLagCmd, get_consumer_groups::GetConsumerGroupsCmd,
     },
     binary_consumer_offsets::{
         get_consumer_offset::GetConsumerOffsetCmd, set_consumer_offset::SetConsumerOffsetCmd,
@@ -72,6 +73,11 @@ use iggy_cli::commands::{
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
         get_personal_access_tokens::GetPersonalAccessTokensCmd,
     },
+    binary_roles::{
+        assign_role::AssignRoleCmd, create_role::CreateRoleCmd, delete_role::DeleteRoleCmd,
+        get_role::GetRoleCmd, get_roles::GetRolesCmd, unassign_role::UnassignRoleCmd,
+        update_role::UpdateRoleCmd,
+    },
     binary_streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
         get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
@@ -132,6 +138,7 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.cleanup_policy,
             )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
@@ -145,6 +152,7 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.cleanup_policy,
             )),
             TopicAction::Get(args) => Box::new(GetTopicCmd::new(
                 args.stream_id.clone(),
@@ -215,6 +223,7 @@ fn get_command(
                 PermissionsArgs::new(
                     create_args.global_permissions.clone(),
                     create_args.stream_permissions.clone(),
+                    create_args.stream_pattern_permissions.clone(),
                 )
                 .into(),
             )),
@@ -241,10 +250,44 @@ fn get_command(
                 PermissionsArgs::new(
                     permissions_args.global_permissions.clone(),
                     permissions_args.stream_permissions.clone(),
+                    permissions_args.stream_pattern_permissions.clone(),
                 )
                 .into(),
             )),
         },
+        Command::Role(command) => match command {
+            RoleAction::Create(create_args) => Box::new(CreateRoleCmd::new(
+                create_args.name.clone(),
+                PermissionsArgs::new(
+                    create_args.global_permissions.clone(),
+                    create_args.stream_permissions.clone(),
+                    create_args.stream_pattern_permissions.clone(),
+                )
+                .into(),
+            )),
+            RoleAction::Delete(delete_args) => {
+                Box::new(DeleteRoleCmd::new(delete_args.role_id.clone()))
+            }
+            RoleAction::Get(get_args) => Box::new(GetRoleCmd::new(get_args.role_id.clone())),
+            RoleAction::List(list_args) => Box::new(GetRolesCmd::new(list_args.list_mode.into())),
+            RoleAction::Permissions(permissions_args) => Box::new(UpdateRoleCmd::new(
+                permissions_args.role_id.clone(),
+                PermissionsArgs::new(
+                    permissions_args.global_permissions.clone(),
+                    permissions_args.stream_permissions.clone(),
+                    permissions_args.stream_pattern_permissions.clone(),
+                )
+                .into(),
+            )),
+            RoleAction::Assign(assign_args) => Box::new(AssignRoleCmd::new(
+                assign_args.user_id.clone(),
+                assign_args.role_id.clone(),
+            )),
+            RoleAction::Unassign(unassign_args) => Box::new(UnassignRoleCmd::new(
+                unassign_args.user_id.clone(),
+                unassign_args.role_id.clone(),
+            )),
+        },
         Command::Client(command) => match command {
             ClientAction::Get(get_args) => Box::new(GetClientCmd::new(get_args.client_id)),
             ClientAction::List(list_args) => {
@@ -277,6 +320,11 @@ fn get_command(
                 list_args.topic_id.clone(),
                 list_args.list_mode.into(),
             )),
+            ConsumerGroupAction::Lag(lag_args) => Box::new(GetConsumerGroupLagCmd::new(
+                lag_args.stream_id.clone(),
+                lag_args.topic_id.clone(),
+                lag_args.group_id.clone(),
+            )),
         },
         Command::Message(command) => match command {
             MessageAction::Send(send_args) => Box::new(SendMessagesCmd::new(
@@ -287,6 +335,7 @@ fn get_command(
                 send_args.messages.clone(),
                 send_args.headers.clone(),
                 send_args.input_file.clone(),
+                send_args.delay,
             )),
             MessageAction::Poll(poll_args) => Box::new(PollMessagesCmd::new(
                 poll_args.stream_id.clone(),
@@ -300,6 +349,7 @@ fn get_command(
                 poll_args.next,
                 poll_args.consumer.clone(),
                 poll_args.show_headers,
+                poll_args.filter.clone(),
                 poll_args.output_file.clone(),
             )),
             MessageAction::Flush(flush_args) => Box::new(FlushMessagesCmd::new(
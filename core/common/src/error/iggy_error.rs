@@ -120,6 +120,14 @@ pub enum IggyError {
     PersonalAccessTokenExpired(String, u32) = 54,
     #[error("Users limit reached.")]
     UsersLimitReached = 55,
+    #[error("Quota exceeded, retry after {0} ms")]
+    Throttled(u32) = 56,
+    #[error("Invalid role name")]
+    InvalidRoleName = 57,
+    #[error("Role already exists")]
+    RoleAlreadyExists = 58,
+    #[error("Invalid permission pattern")]
+    InvalidPermissionPattern = 59,
     #[error("Not connected")]
     NotConnected = 61,
     #[error("Client shutdown")]
@@ -132,6 +140,8 @@ pub enum IggyError {
     InvalidTlsCertificate = 66,
     #[error("Failed to add certificate")]
     FailedToAddCertificate = 67,
+    #[error("Server-side encryption is disabled")]
+    EncryptionDisabled = 68,
     #[error("Invalid encryption key")]
     InvalidEncryptionKey = 70,
     #[error("Cannot encrypt data")]
@@ -162,6 +172,10 @@ pub enum IggyError {
     InvalidBooleanValue = 83,
     #[error("Invalid number value")]
     InvalidNumberValue = 84,
+    #[error("Cannot compress data")]
+    CannotCompressData = 85,
+    #[error("Cannot decompress data")]
+    CannotDecompressData = 86,
     #[error("Client with ID: {0} was not found.")]
     ClientNotFound(u32) = 100,
     #[error("Invalid client ID")]
@@ -419,6 +433,8 @@ pub enum IggyError {
     InvalidOffset(u64) = 4100,
     #[error("Invalid reserved field value: {0}, expected: 0")]
     InvalidReservedField(u64) = 4101,
+    #[error("Invalid message filter: {0}")]
+    InvalidMessageFilter(String) = 4102,
     #[error("Consumer group with ID: {0} for topic with ID: {1} was not found.")]
     ConsumerGroupIdNotFound(Identifier, Identifier) = 5000,
     #[error("Invalid consumer group ID")]
@@ -441,6 +457,28 @@ pub enum IggyError {
         "Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}."
     )]
     CannotDeleteConsumerGroupInfo(usize, Identifier, Identifier) = 5008,
+    #[error(
+        "Invalid dead-letter policy, delivery count and redelivery timeout must be greater than zero."
+    )]
+    InvalidDeadLetterPolicy = 5009,
+    #[error("Message with offset: {0} has not been delivered to the consumer group.")]
+    MessageNotDelivered(u64) = 5010,
+    #[error("Invalid shared subscription, visibility timeout must be greater than zero.")]
+    InvalidSharedSubscription = 5011,
+    #[error("Consumer group with ID: {0} for topic with ID: {1} is not shared.")]
+    ConsumerGroupNotShared(Identifier, Identifier) = 5012,
+    #[error("Transaction with ID: {0} is already in progress for this client.")]
+    TransactionAlreadyInProgress(u64) = 5100,
+    #[error("Transaction with ID: {0} was not found.")]
+    TransactionNotFound(u64) = 5101,
+    #[error("Producer with ID: {0} and epoch: {1} has been fenced by a newer epoch: {2}.")]
+    ProducerFenced(u64, u64, u64) = 5200,
+    #[error(
+        "Out of order sequence number: {2} for producer with ID: {0}, expected sequence number: {1}."
+    )]
+    OutOfOrderSequence(u64, u64, u64) = 5201,
+    #[error("Idempotent producer requires the messages to be sent to a specific partition.")]
+    InvalidIdempotentPartitioning = 5202,
     #[error("Base offset is missing")]
     MissingBaseOffsetRetainedMessageBatch = 6000,
     #[error("Last offset delta is missing")]
@@ -524,6 +562,27 @@ impl IggyError {
         IggyError::from_repr(code).unwrap_or(IggyError::Error)
     }
 
+    /// Returns the payload sent after the status of an error response.
+    /// Only `Throttled` carries one: the throttle time in milliseconds as `u32_le`,
+    /// every other error is sent without a payload.
+    pub fn response_payload(&self) -> Vec<u8> {
+        match self {
+            IggyError::Throttled(throttle_time_ms) => throttle_time_ms.to_le_bytes().to_vec(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Rebuilds the error from the status and the payload of an error response.
+    pub fn from_response(code: u32, payload: &[u8]) -> Self {
+        if code == IggyErrorDiscriminants::Throttled as u32
+            && let Some(bytes) = payload.get(..4)
+        {
+            return IggyError::Throttled(u32::from_le_bytes(bytes.try_into().unwrap()));
+        }
+
+        IggyError::from_code(code)
+    }
+
     pub fn from_code_as_string(code: u32) -> &'static str {
         IggyErrorDiscriminants::from_repr(code)
             .map(|discriminant| discriminant.into())
@@ -570,4 +629,21 @@ mod tests {
             IggyError::from_code_as_string(GROUP_NAME_ERROR_CODE)
         )
     }
+
+    #[test]
+    fn throttled_error_roundtrips_through_response() {
+        let error = IggyError::Throttled(250);
+        let rebuilt = IggyError::from_response(error.as_code(), &error.response_payload());
+        assert!(matches!(rebuilt, IggyError::Throttled(250)));
+    }
+
+    #[test]
+    fn error_without_payload_is_rebuilt_from_code() {
+        let error = IggyError::Unauthorized;
+        assert!(error.response_payload().is_empty());
+        assert!(matches!(
+            IggyError::from_response(error.as_code(), &[]),
+            IggyError::Unauthorized
+        ));
+    }
 }
@@ -17,7 +17,9 @@
  */
 
 use super::MAX_NAME_LENGTH;
+use crate::DeadLetterPolicy;
 use crate::Identifier;
+use crate::SharedSubscription;
 use crate::Validatable;
 use crate::error::IggyError;
 use serde::{Deserialize, Serialize};
@@ -27,6 +29,8 @@ use serde::{Deserialize, Serialize};
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `name` - unique consumer group name, max length is 255 characters.
+/// - `dead_letter` - optional dead-letter policy of the consumer group.
+/// - `shared` - optional shared subscription of the consumer group.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct CreateConsumerGroup {
     /// Unique stream ID (numeric or name).
@@ -37,6 +41,12 @@ pub struct CreateConsumerGroup {
     pub topic_id: Identifier,
     /// Unique consumer group name, max length is 255 characters.
     pub name: String,
+    /// Optional dead-letter policy of the consumer group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dead_letter: Option<DeadLetterPolicy>,
+    /// Optional shared subscription of the consumer group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<SharedSubscription>,
 }
 
 impl Default for CreateConsumerGroup {
@@ -45,6 +55,8 @@ impl Default for CreateConsumerGroup {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             name: "consumer_group_1".to_string(),
+            dead_letter: None,
+            shared: None,
         }
     }
 }
@@ -55,6 +67,14 @@ impl Validatable<IggyError> for CreateConsumerGroup {
             return Err(IggyError::InvalidConsumerGroupName);
         }
 
+        if let Some(dead_letter) = &self.dead_letter {
+            dead_letter.validate()?;
+        }
+
+        if let Some(shared) = &self.shared {
+            shared.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -18,7 +18,7 @@
 
 use crate::Consumer;
 use crate::error::IggyError;
-use crate::{Identifier, PollingStrategy, Validatable};
+use crate::{Identifier, MessageFilter, PollingStrategy, Validatable};
 use serde::{Deserialize, Serialize};
 
 pub const DEFAULT_PARTITION_ID: u32 = 0;
@@ -33,6 +33,7 @@ pub const DEFAULT_NUMBER_OF_MESSAGES_TO_POLL: u32 = 10;
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `filter` - optional filter over the user headers, so only the matching messages are returned.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -56,6 +57,9 @@ pub struct PollMessages {
     /// Whether to commit offset on the server automatically after polling the messages.
     #[serde(default)]
     pub auto_commit: bool,
+    /// Optional filter over the user headers, so only the matching messages are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<MessageFilter>,
 }
 
 impl PollMessages {
@@ -78,6 +82,7 @@ impl Default for PollMessages {
             strategy: PollingStrategy::default(),
             count: PollMessages::default_number_of_messages_to_poll(),
             auto_commit: false,
+            filter: None,
         }
     }
 }
@@ -20,6 +20,7 @@ pub(crate) mod consumer_offsets;
 pub(crate) mod messages;
 pub(crate) mod partitions;
 pub(crate) mod personal_access_tokens;
+pub(crate) mod roles;
 pub(crate) mod segments;
 pub(crate) mod streams;
 pub(crate) mod system;
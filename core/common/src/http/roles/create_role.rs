@@ -0,0 +1,86 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Permissions;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::http::users::defaults::{MAX_ROLE_NAME_LENGTH, MIN_ROLE_NAME_LENGTH};
+use serde::{Deserialize, Serialize};
+
+/// `CreateRole` command is used to create a new role.
+/// It has additional payload:
+/// - `name` - unique name of the role, must be between 3 and 50 characters long.
+/// - `permissions` - optional permissions granted by the role to all of its users.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct CreateRole {
+    /// Unique name of the role, must be between 3 and 50 characters long.
+    pub name: String,
+    /// Optional permissions granted by the role to all of its users.
+    pub permissions: Option<Permissions>,
+}
+
+impl Default for CreateRole {
+    fn default() -> Self {
+        CreateRole {
+            name: "role".to_string(),
+            permissions: None,
+        }
+    }
+}
+
+impl Validatable<IggyError> for CreateRole {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.len() > MAX_ROLE_NAME_LENGTH || self.name.len() < MIN_ROLE_NAME_LENGTH {
+            return Err(IggyError::InvalidRoleName);
+        }
+
+        if let Some(permissions) = &self.permissions {
+            permissions.validate_patterns()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_valid_given_name_within_bounds() {
+        let command = CreateRole {
+            name: "readers".to_string(),
+            permissions: None,
+        };
+        assert!(command.validate().is_ok());
+    }
+
+    #[test]
+    fn should_be_invalid_given_too_short_or_too_long_name() {
+        for name in ["ab".to_string(), "a".repeat(MAX_ROLE_NAME_LENGTH + 1)] {
+            let command = CreateRole {
+                name,
+                permissions: None,
+            };
+            assert!(matches!(
+                command.validate(),
+                Err(IggyError::InvalidRoleName)
+            ));
+        }
+    }
+}
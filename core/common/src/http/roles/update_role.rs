@@ -0,0 +1,46 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Identifier;
+use crate::Permissions;
+use crate::Validatable;
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+
+/// `UpdateRole` command is used to update the permissions of a role.
+/// It has additional payload:
+/// - `role_id` - unique role ID (numeric or name).
+/// - `permissions` - new permissions (optional)
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+pub struct UpdateRole {
+    /// Unique role ID (numeric or name).
+    #[serde(skip)]
+    pub role_id: Identifier,
+    /// New permissions if `None` is provided, then the role won't grant any permissions.
+    pub permissions: Option<Permissions>,
+}
+
+impl Validatable<IggyError> for UpdateRole {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(permissions) = &self.permissions {
+            permissions.validate_patterns()?;
+        }
+
+        Ok(())
+    }
+}
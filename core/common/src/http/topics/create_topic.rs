@@ -17,6 +17,7 @@
  */
 
 use super::{MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
+use crate::CleanupPolicy;
 use crate::CompressionAlgorithm;
 use crate::Identifier;
 use crate::Validatable;
@@ -33,6 +34,7 @@ use serde::{Deserialize, Serialize};
 /// - `max_topic_size` - maximum size of the topic, if `Unlimited` then topic size is unlimited.
 ///   Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
+/// - `cleanup_policy` - cleanup policy of the topic, `Compact` keeps only the latest message per key.
 /// - `name` - unique topic name, max length is 255 characters.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct CreateTopic {
@@ -52,6 +54,9 @@ pub struct CreateTopic {
     pub replication_factor: Option<u8>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Cleanup policy of the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
 }
 
 impl Default for CreateTopic {
@@ -64,6 +69,7 @@ impl Default for CreateTopic {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: None,
             name: "topic".to_string(),
+            cleanup_policy: CleanupPolicy::Delete,
         }
     }
 }
@@ -71,6 +71,10 @@ impl Validatable<IggyError> for CreateUser {
             return Err(IggyError::InvalidPassword);
         }
 
+        if let Some(permissions) = &self.permissions {
+            permissions.validate_patterns()?;
+        }
+
         Ok(())
     }
 }
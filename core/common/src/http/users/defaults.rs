@@ -20,6 +20,9 @@ pub const MAX_USERNAME_LENGTH: usize = 50;
 pub const MIN_USERNAME_LENGTH: usize = 3;
 pub const MAX_PASSWORD_LENGTH: usize = 100;
 pub const MIN_PASSWORD_LENGTH: usize = 3;
+pub const MAX_ROLE_NAME_LENGTH: usize = 50;
+pub const MIN_ROLE_NAME_LENGTH: usize = 3;
+pub const MAX_PERMISSION_PATTERN_LENGTH: usize = 255;
 pub const MAX_PAT_LENGTH: usize = 100;
 pub const MAX_PERSONAL_ACCESS_TOKEN_NAME_LENGTH: usize = 30;
 pub const MIN_PERSONAL_ACCESS_TOKEN_NAME_LENGTH: usize = 3;
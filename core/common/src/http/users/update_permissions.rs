@@ -37,6 +37,10 @@ pub struct UpdatePermissions {
 
 impl Validatable<IggyError> for UpdatePermissions {
     fn validate(&self) -> Result<(), IggyError> {
+        if let Some(permissions) = &self.permissions {
+            permissions.validate_patterns()?;
+        }
+
         Ok(())
     }
 }
@@ -44,6 +44,7 @@ pub use http::consumer_offsets::*;
 pub use http::messages::*;
 pub use http::partitions::*;
 pub use http::personal_access_tokens::*;
+pub use http::roles::*;
 pub use http::segments::*;
 pub use http::streams::*;
 pub use http::system::*;
@@ -62,18 +63,24 @@ pub use traits::message_client::MessageClient;
 pub use traits::partition_client::PartitionClient;
 pub use traits::partitioner::Partitioner;
 pub use traits::personal_access_token_client::PersonalAccessTokenClient;
+pub use traits::producer_client::ProducerClient;
+pub use traits::role_client::RoleClient;
 pub use traits::segment_client::SegmentClient;
 pub use traits::sizeable::Sizeable;
 pub use traits::stream_client::StreamClient;
 pub use traits::system_client::SystemClient;
 pub use traits::topic_client::TopicClient;
+pub use traits::transaction_client::TransactionClient;
 pub use traits::user_client::UserClient;
 pub use traits::validatable::Validatable;
 pub use types::args::*;
+pub use types::audit::audit_log_query::*;
+pub use types::audit::audit_record::*;
 pub use types::client::client_info::*;
 pub use types::client_state::ClientState;
 pub use types::cluster::*;
 pub use types::compression::compression_algorithm::*;
+pub use types::configuration::auth_config::access_token_provider::*;
 pub use types::configuration::auth_config::auto_login::*;
 pub use types::configuration::auth_config::connection_string::*;
 pub use types::configuration::auth_config::connection_string_options::*;
@@ -96,11 +103,14 @@ pub use types::configuration::websocket_config::websocket_client_reconnection_co
 pub use types::configuration::websocket_config::websocket_connection_string_options::*;
 pub use types::consumer::consumer_group::*;
 pub use types::consumer::consumer_group_id::*;
+pub use types::consumer::consumer_group_lag::*;
 pub use types::consumer::consumer_group_offsets::*;
 pub use types::consumer::consumer_kind::*;
 pub use types::consumer::consumer_offset::*;
 pub use types::consumer::consumer_offset_info::*;
 pub use types::consumer::consumer_offsets::*;
+pub use types::consumer::dead_letter_policy::*;
+pub use types::consumer::shared_subscription::*;
 pub use types::diagnostic::diagnostic_event::DiagnosticEvent;
 pub use types::either::Either;
 pub use types::identifier::*;
@@ -109,6 +119,8 @@ pub use types::partition::*;
 pub use types::permissions::permissions_global::*;
 pub use types::permissions::personal_access_token::*;
 pub use types::personal_access_tokens::*;
+pub use types::producer::*;
+pub use types::role::role_info::*;
 pub use types::segment::Segment;
 pub use types::segment_storage::*;
 pub use types::send_messages2;
@@ -117,8 +129,10 @@ pub use types::stats::*;
 pub use types::stream::*;
 pub use types::streaming_stats::*;
 pub use types::topic::*;
+pub use types::transaction::*;
 pub use types::user::user_identity_info::*;
 pub use types::user::user_info::*;
+pub use types::user::user_quota::*;
 pub use types::user::user_status::*;
 pub use utils::byte_size::IggyByteSize;
 pub use utils::checksum::*;
@@ -126,10 +140,12 @@ pub use utils::crypto::*;
 pub use utils::duration::{IggyDuration, SEC_IN_MICRO};
 pub use utils::expiry::IggyExpiry;
 pub use utils::hash::*;
+pub use utils::key_provider::*;
 pub use utils::net::validate_api_url;
 pub use utils::net::validate_server_address;
 pub use utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 pub use utils::random_id;
+pub use utils::scram;
 pub use utils::serde_secret;
 pub use utils::text;
 pub use utils::timestamp::*;
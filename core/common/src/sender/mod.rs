@@ -204,7 +204,14 @@ pub(crate) async fn send_error_response<T>(
 where
     T: AsyncReadExt + AsyncWriteExt + Unpin,
 {
-    send_response(stream, &error.as_code().to_le_bytes(), &[]).await
+    // Only `Throttled` has a payload, so the clients which don't read the payload of an error
+    // response stay in sync for all the other errors.
+    send_response(
+        stream,
+        &error.as_code().to_le_bytes(),
+        &error.response_payload(),
+    )
+    .await
 }
 
 pub(crate) async fn send_response<T>(
@@ -256,3 +263,71 @@ where
     debug!("Sent response with status: {:?}", resp_status);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Reads the status of the next response like the clients predating the `Throttled` payload,
+    /// which skip the payload of the successful responses only.
+    fn read_status_ignoring_error_payload(buffer: &[u8], position: &mut usize) -> u32 {
+        let status = u32::from_le_bytes(buffer[*position..*position + 4].try_into().unwrap());
+        let length = u32::from_le_bytes(buffer[*position + 4..*position + 8].try_into().unwrap());
+        *position += 8;
+        if status == 0 {
+            *position += length as usize;
+        }
+        status
+    }
+
+    #[compio::test]
+    async fn legacy_reader_should_stay_in_sync_after_non_throttle_error() {
+        let mut stream = Cursor::new(Vec::new());
+        send_error_response(&mut stream, IggyError::Unauthorized)
+            .await
+            .unwrap();
+        send_error_response(
+            &mut stream,
+            IggyError::PersonalAccessTokenExpired("token".to_owned(), 1),
+        )
+        .await
+        .unwrap();
+        send_ok_response(&mut stream, b"payload").await.unwrap();
+        let buffer = stream.into_inner();
+
+        let mut position = 0;
+        assert_eq!(
+            read_status_ignoring_error_payload(&buffer, &mut position),
+            IggyError::Unauthorized.as_code()
+        );
+        assert_eq!(
+            read_status_ignoring_error_payload(&buffer, &mut position),
+            IggyError::PersonalAccessTokenExpired("token".to_owned(), 1).as_code()
+        );
+        assert_eq!(
+            read_status_ignoring_error_payload(&buffer, &mut position),
+            0
+        );
+        assert_eq!(position, buffer.len());
+    }
+
+    #[compio::test]
+    async fn throttled_error_should_carry_retry_after_payload() {
+        let mut stream = Cursor::new(Vec::new());
+        send_error_response(&mut stream, IggyError::Throttled(250))
+            .await
+            .unwrap();
+        let buffer = stream.into_inner();
+
+        assert_eq!(buffer.len(), 12);
+        assert_eq!(u32::from_le_bytes(buffer[4..8].try_into().unwrap()), 4);
+        assert!(matches!(
+            IggyError::from_response(
+                u32::from_le_bytes(buffer[..4].try_into().unwrap()),
+                &buffer[8..]
+            ),
+            IggyError::Throttled(250)
+        ));
+    }
+}
@@ -145,14 +145,16 @@ impl Sender for WebSocketSender {
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
         let status = &error.as_code().to_le_bytes();
         debug!("Sending WebSocket error response with status: {:?}", status);
-        let length = 0u32.to_le_bytes();
-        let total_size = status.len() + length.len();
+        let payload = error.response_payload();
+        let length = (payload.len() as u32).to_le_bytes();
+        let total_size = status.len() + length.len() + payload.len();
 
         if self.write_buffer.len() + total_size > self.write_buffer.capacity() {
             self.flush_write_buffer().await?;
         }
         self.write_buffer.put_slice(status);
         self.write_buffer.put_slice(&length);
+        self.write_buffer.put_slice(&payload);
         self.flush_write_buffer().await
     }
 
@@ -17,21 +17,29 @@
  */
 
 use crate::traits::binary_auth::fail_if_not_authenticated;
-use crate::wire_conversions::{consumer_groups_from_wire, identifier_to_wire};
+use crate::wire_conversions::{
+    consumer_groups_from_wire, dead_letter_policy_to_wire, identifier_to_wire,
+    shared_subscription_to_wire,
+};
 use crate::{
-    BinaryClient, ConsumerGroup, ConsumerGroupClient, ConsumerGroupDetails, Identifier, IggyError,
+    BinaryClient, ConsumerGroup, ConsumerGroupClient, ConsumerGroupDetails, ConsumerGroupLag,
+    DeadLetterPolicy, Identifier, IggyError, SharedSubscription,
 };
 use iggy_binary_protocol::WireName;
 use iggy_binary_protocol::codec::WireEncode;
 use iggy_binary_protocol::codes::{
-    CREATE_CONSUMER_GROUP_CODE, DELETE_CONSUMER_GROUP_CODE, GET_CONSUMER_GROUP_CODE,
-    GET_CONSUMER_GROUPS_CODE, JOIN_CONSUMER_GROUP_CODE, LEAVE_CONSUMER_GROUP_CODE,
+    ACK_MESSAGES_CODE, CREATE_CONSUMER_GROUP_CODE, DELETE_CONSUMER_GROUP_CODE,
+    GET_CONSUMER_GROUP_CODE, GET_CONSUMER_GROUP_LAG_CODE, GET_CONSUMER_GROUPS_CODE,
+    JOIN_CONSUMER_GROUP_CODE, LEAVE_CONSUMER_GROUP_CODE, NACK_MESSAGE_CODE,
 };
 use iggy_binary_protocol::requests::consumer_groups::{
-    CreateConsumerGroupRequest, DeleteConsumerGroupRequest, GetConsumerGroupRequest,
-    GetConsumerGroupsRequest, JoinConsumerGroupRequest, LeaveConsumerGroupRequest,
+    AckMessagesRequest, CreateConsumerGroupRequest, DeleteConsumerGroupRequest,
+    GetConsumerGroupLagRequest, GetConsumerGroupRequest, GetConsumerGroupsRequest,
+    JoinConsumerGroupRequest, LeaveConsumerGroupRequest, MAX_ACK_OFFSETS_COUNT,
+    MAX_NACK_REASON_LENGTH, NackMessageRequest,
 };
 use iggy_binary_protocol::responses::consumer_groups::get_consumer_group::ConsumerGroupDetailsResponse;
+use iggy_binary_protocol::responses::consumer_groups::get_consumer_group_lag::ConsumerGroupLagResponse;
 use iggy_binary_protocol::responses::consumer_groups::get_consumer_groups::GetConsumerGroupsResponse;
 
 #[async_trait::async_trait]
@@ -89,16 +97,50 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         Ok(consumer_groups_from_wire(wire_resp))
     }
 
+    async fn get_consumer_group_lag(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<Option<ConsumerGroupLag>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let wire_stream_id = identifier_to_wire(stream_id)?;
+        let wire_topic_id = identifier_to_wire(topic_id)?;
+        let wire_group_id = identifier_to_wire(group_id)?;
+        let response = self
+            .send_raw_with_response(
+                GET_CONSUMER_GROUP_LAG_CODE,
+                GetConsumerGroupLagRequest {
+                    stream_id: wire_stream_id,
+                    topic_id: wire_topic_id,
+                    group_id: wire_group_id,
+                }
+                .to_bytes(),
+            )
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+        let wire_resp = super::decode_response::<ConsumerGroupLagResponse>(&response)?;
+        Ok(Some(ConsumerGroupLag::from(wire_resp)))
+    }
+
     async fn create_consumer_group(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         name: &str,
+        dead_letter: Option<DeadLetterPolicy>,
+        shared: Option<SharedSubscription>,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let wire_stream_id = identifier_to_wire(stream_id)?;
         let wire_topic_id = identifier_to_wire(topic_id)?;
         let wire_name = WireName::new(name).map_err(|_| IggyError::InvalidFormat)?;
+        let wire_dead_letter = dead_letter
+            .as_ref()
+            .map(dead_letter_policy_to_wire)
+            .transpose()?;
         let response = self
             .send_raw_with_response(
                 CREATE_CONSUMER_GROUP_CODE,
@@ -106,6 +148,8 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
                     stream_id: wire_stream_id,
                     topic_id: wire_topic_id,
                     name: wire_name,
+                    dead_letter: wire_dead_letter,
+                    shared: shared.as_ref().map(shared_subscription_to_wire),
                 }
                 .to_bytes(),
             )
@@ -182,4 +226,66 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         .await?;
         Ok(())
     }
+
+    async fn nack_message(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        if reason.len() > MAX_NACK_REASON_LENGTH {
+            return Err(IggyError::InvalidFormat);
+        }
+        let wire_stream_id = identifier_to_wire(stream_id)?;
+        let wire_topic_id = identifier_to_wire(topic_id)?;
+        let wire_group_id = identifier_to_wire(group_id)?;
+        self.send_raw_with_response(
+            NACK_MESSAGE_CODE,
+            NackMessageRequest {
+                stream_id: wire_stream_id,
+                topic_id: wire_topic_id,
+                group_id: wire_group_id,
+                partition_id,
+                offset,
+                reason: reason.to_string(),
+            }
+            .to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        if offsets.is_empty() || offsets.len() > MAX_ACK_OFFSETS_COUNT {
+            return Err(IggyError::InvalidFormat);
+        }
+        let wire_stream_id = identifier_to_wire(stream_id)?;
+        let wire_topic_id = identifier_to_wire(topic_id)?;
+        let wire_group_id = identifier_to_wire(group_id)?;
+        self.send_raw_with_response(
+            ACK_MESSAGES_CODE,
+            AckMessagesRequest {
+                stream_id: wire_stream_id,
+                topic_id: wire_topic_id,
+                group_id: wire_group_id,
+                partition_id,
+                offsets: offsets.to_vec(),
+            }
+            .to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
 }
@@ -21,8 +21,8 @@ use crate::wire_conversions::{
     consumer_to_wire, identifier_to_wire, partitioning_to_wire, polling_strategy_to_wire,
 };
 use crate::{
-    Consumer, Identifier, IggyError, IggyMessage, MessageClient, Partitioning, PolledMessages,
-    PollingStrategy,
+    Consumer, Identifier, IggyError, IggyMessage, MessageClient, MessageFilter, Partitioning,
+    PolledMessages, PollingStrategy,
 };
 use bytes::BytesMut;
 use iggy_binary_protocol::codec::WireEncode;
@@ -45,20 +45,43 @@ impl<B: BinaryClient> MessageClient for B {
         count: u32,
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError> {
-        fail_if_not_authenticated(self).await?;
-        let req = PollMessagesRequest {
-            consumer: consumer_to_wire(consumer)?,
-            stream_id: identifier_to_wire(stream_id)?,
-            topic_id: identifier_to_wire(topic_id)?,
+        poll_messages(
+            self,
+            stream_id,
+            topic_id,
             partition_id,
-            strategy: polling_strategy_to_wire(strategy),
+            consumer,
+            strategy,
             count,
             auto_commit,
-        };
-        let response = self
-            .send_raw_with_response(POLL_MESSAGES_CODE, req.to_bytes())
-            .await?;
-        PolledMessages::from_bytes(response)
+            None,
+        )
+        .await
+    }
+
+    async fn poll_filtered_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: &MessageFilter,
+    ) -> Result<PolledMessages, IggyError> {
+        poll_messages(
+            self,
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            Some(filter),
+        )
+        .await
     }
 
     async fn send_messages(
@@ -119,3 +142,33 @@ impl<B: BinaryClient> MessageClient for B {
         Ok(())
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+async fn poll_messages<B: BinaryClient>(
+    client: &B,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    partition_id: Option<u32>,
+    consumer: &Consumer,
+    strategy: &PollingStrategy,
+    count: u32,
+    auto_commit: bool,
+    filter: Option<&MessageFilter>,
+) -> Result<PolledMessages, IggyError> {
+    fail_if_not_authenticated(client).await?;
+    let req = PollMessagesRequest {
+        consumer: consumer_to_wire(consumer)?,
+        stream_id: identifier_to_wire(stream_id)?,
+        topic_id: identifier_to_wire(topic_id)?,
+        partition_id,
+        strategy: polling_strategy_to_wire(strategy),
+        count,
+        auto_commit,
+        isolation_level: strategy.isolation_level.as_code(),
+        filter: filter.map(|filter| filter.to_string()),
+    };
+    let response = client
+        .send_raw_with_response(POLL_MESSAGES_CODE, req.to_bytes())
+        .await?;
+    PolledMessages::from_bytes(response)
+}
@@ -21,10 +21,13 @@ mod consumer_offsets;
 mod messages;
 mod partitions;
 mod personal_access_tokens;
+mod producers;
+mod roles;
 mod segments;
 mod streams;
 mod system;
 mod topics;
+mod transactions;
 mod users;
 
 use crate::IggyError;
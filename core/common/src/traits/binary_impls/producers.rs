@@ -0,0 +1,95 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::traits::binary_auth::fail_if_not_authenticated;
+use crate::wire_conversions::identifier_to_wire;
+use crate::{BinaryClient, Identifier, IggyError, IggyMessage, ProducerClient, ProducerIdentity};
+use bytes::BytesMut;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::codes::{INIT_PRODUCER_CODE, SEND_MESSAGES_CODE};
+use iggy_binary_protocol::primitives::partitioning::WirePartitioning;
+use iggy_binary_protocol::requests::messages::{
+    InitProducerRequest, RawMessage, SendMessagesEncoder, WireProducerSequence,
+};
+use iggy_binary_protocol::responses::messages::InitProducerResponse;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> ProducerClient for B {
+    async fn init_producer(&self, producer_id: Option<u64>) -> Result<ProducerIdentity, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let req = InitProducerRequest {
+            producer_id: producer_id.unwrap_or_default(),
+        };
+        let response = self
+            .send_raw_with_response(INIT_PRODUCER_CODE, req.to_bytes())
+            .await?;
+        let wire_resp = super::decode_response::<InitProducerResponse>(&response)?;
+        Ok(ProducerIdentity::new(
+            wire_resp.producer_id,
+            wire_resp.producer_epoch,
+        ))
+    }
+
+    async fn send_idempotent_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        producer: &ProducerIdentity,
+        base_sequence: u64,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let wire_stream_id = identifier_to_wire(stream_id)?;
+        let wire_topic_id = identifier_to_wire(topic_id)?;
+        let wire_partitioning = WirePartitioning::PartitionId(partition_id);
+        let wire_producer = WireProducerSequence {
+            producer_id: producer.id,
+            producer_epoch: producer.epoch,
+            base_sequence,
+        };
+        let raw_messages: Vec<RawMessage<'_>> = messages
+            .iter()
+            .map(|m| RawMessage {
+                id: m.header.id,
+                origin_timestamp: m.header.origin_timestamp,
+                headers: m.user_headers.as_deref(),
+                payload: &m.payload,
+            })
+            .collect();
+        let size = SendMessagesEncoder::encoded_size_with_producer(
+            &wire_stream_id,
+            &wire_topic_id,
+            &wire_partitioning,
+            Some(&wire_producer),
+            &raw_messages,
+        );
+        let mut buf = BytesMut::with_capacity(size);
+        SendMessagesEncoder::encode_with_producer(
+            &mut buf,
+            &wire_stream_id,
+            &wire_topic_id,
+            &wire_partitioning,
+            Some(&wire_producer),
+            &raw_messages,
+        );
+        self.send_raw_with_response(SEND_MESSAGES_CODE, buf.freeze())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::traits::binary_auth::fail_if_not_authenticated;
+use crate::wire_conversions::{identifier_to_wire, permissions_to_wire, roles_from_wire};
+use crate::{
+    BinaryClient, Identifier, IggyError, Permissions, RoleClient, RoleInfo, RoleInfoDetails,
+};
+use iggy_binary_protocol::WireName;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::codes::{
+    ASSIGN_ROLE_CODE, CREATE_ROLE_CODE, DELETE_ROLE_CODE, GET_ROLE_CODE, GET_ROLES_CODE,
+    UNASSIGN_ROLE_CODE, UPDATE_ROLE_CODE,
+};
+use iggy_binary_protocol::requests::roles::{
+    AssignRoleRequest, CreateRoleRequest, DeleteRoleRequest, GetRoleRequest, GetRolesRequest,
+    UnassignRoleRequest, UpdateRoleRequest,
+};
+use iggy_binary_protocol::responses::roles::{GetRolesResponse, RoleDetailsResponse};
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> RoleClient for B {
+    async fn get_role(&self, role_id: &Identifier) -> Result<Option<RoleInfoDetails>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let wire_id = identifier_to_wire(role_id)?;
+        let response = self
+            .send_raw_with_response(
+                GET_ROLE_CODE,
+                GetRoleRequest { role_id: wire_id }.to_bytes(),
+            )
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+        let wire_resp = super::decode_response::<RoleDetailsResponse>(&response)?;
+        Ok(Some(RoleInfoDetails::from(wire_resp)))
+    }
+
+    async fn get_roles(&self) -> Result<Vec<RoleInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(GET_ROLES_CODE, GetRolesRequest.to_bytes())
+            .await?;
+        if response.is_empty() {
+            return Ok(Vec::new());
+        }
+        let wire_resp = super::decode_response::<GetRolesResponse>(&response)?;
+        Ok(roles_from_wire(wire_resp))
+    }
+
+    async fn create_role(
+        &self,
+        name: &str,
+        permissions: Option<Permissions>,
+    ) -> Result<RoleInfoDetails, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let wire_name = WireName::new(name).map_err(|_| IggyError::InvalidFormat)?;
+        if let Some(permissions) = &permissions {
+            permissions.validate_patterns()?;
+        }
+        let response = self
+            .send_raw_with_response(
+                CREATE_ROLE_CODE,
+                CreateRoleRequest {
+                    name: wire_name,
+                    permissions: permissions.as_ref().map(permissions_to_wire),
+                }
+                .to_bytes(),
+            )
+            .await?;
+        let wire_resp = super::decode_response::<RoleDetailsResponse>(&response)?;
+        Ok(RoleInfoDetails::from(wire_resp))
+    }
+
+    async fn delete_role(&self, role_id: &Identifier) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let wire_id = identifier_to_wire(role_id)?;
+        self.send_raw_with_response(
+            DELETE_ROLE_CODE,
+            DeleteRoleRequest { role_id: wire_id }.to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_role(
+        &self,
+        role_id: &Identifier,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let wire_id = identifier_to_wire(role_id)?;
+        if let Some(permissions) = &permissions {
+            permissions.validate_patterns()?;
+        }
+        self.send_raw_with_response(
+            UPDATE_ROLE_CODE,
+            UpdateRoleRequest {
+                role_id: wire_id,
+                permissions: permissions.as_ref().map(permissions_to_wire),
+            }
+            .to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn assign_role(
+        &self,
+        user_id: &Identifier,
+        role_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_raw_with_response(
+            ASSIGN_ROLE_CODE,
+            AssignRoleRequest {
+                user_id: identifier_to_wire(user_id)?,
+                role_id: identifier_to_wire(role_id)?,
+            }
+            .to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn unassign_role(
+        &self,
+        user_id: &Identifier,
+        role_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_raw_with_response(
+            UNASSIGN_ROLE_CODE,
+            UnassignRoleRequest {
+                user_id: identifier_to_wire(user_id)?,
+                role_id: identifier_to_wire(role_id)?,
+            }
+            .to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
+}
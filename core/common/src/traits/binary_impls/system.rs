@@ -17,15 +17,15 @@
  */
 
 use crate::traits::binary_auth::fail_if_not_authenticated;
-use crate::wire_conversions::clients_from_wire;
+use crate::wire_conversions::{audit_log_from_wire, audit_log_query_to_wire, clients_from_wire};
 use crate::{
-    BinaryClient, ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot,
-    SnapshotCompression, Stats, SystemClient, SystemSnapshotType,
+    AuditLogQuery, AuditRecord, BinaryClient, ClientInfo, ClientInfoDetails, IggyDuration,
+    IggyError, Snapshot, SnapshotCompression, Stats, SystemClient, SystemSnapshotType,
 };
 use iggy_binary_protocol::codec::WireEncode;
 use iggy_binary_protocol::codes::{
-    GET_CLIENT_CODE, GET_CLIENTS_CODE, GET_ME_CODE, GET_SNAPSHOT_FILE_CODE, GET_STATS_CODE,
-    PING_CODE,
+    GET_AUDIT_LOG_CODE, GET_CLIENT_CODE, GET_CLIENTS_CODE, GET_ME_CODE, GET_SNAPSHOT_FILE_CODE,
+    GET_STATS_CODE, PING_CODE,
 };
 use iggy_binary_protocol::requests::system::{
     GetClientRequest, GetClientsRequest, GetMeRequest, GetSnapshotRequest, GetStatsRequest,
@@ -33,6 +33,7 @@ use iggy_binary_protocol::requests::system::{
 };
 use iggy_binary_protocol::responses::clients::get_client::ClientDetailsResponse;
 use iggy_binary_protocol::responses::clients::get_clients::GetClientsResponse;
+use iggy_binary_protocol::responses::system::get_audit_log::AuditLogResponse;
 use iggy_binary_protocol::responses::system::get_stats::StatsResponse;
 
 #[async_trait::async_trait]
@@ -110,4 +111,17 @@ impl<B: BinaryClient> SystemClient for B {
         let snapshot = Snapshot::new(response.to_vec());
         Ok(snapshot)
     }
+
+    async fn get_audit_log(&self, query: &AuditLogQuery) -> Result<Vec<AuditRecord>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let request = audit_log_query_to_wire(query)?;
+        let response = self
+            .send_raw_with_response(GET_AUDIT_LOG_CODE, request.to_bytes())
+            .await?;
+        if response.is_empty() {
+            return Ok(Vec::new());
+        }
+        let wire_resp = super::decode_response::<AuditLogResponse>(&response)?;
+        audit_log_from_wire(wire_resp)
+    }
 }
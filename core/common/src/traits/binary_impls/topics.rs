@@ -19,18 +19,18 @@
 use crate::traits::binary_auth::fail_if_not_authenticated;
 use crate::wire_conversions::{identifier_to_wire, topics_from_wire};
 use crate::{
-    BinaryClient, CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic,
-    TopicClient, TopicDetails,
+    BinaryClient, CleanupPolicy, CompressionAlgorithm, Identifier, IggyError, IggyExpiry,
+    MaxTopicSize, Topic, TopicClient, TopicDetails,
 };
 use iggy_binary_protocol::WireName;
 use iggy_binary_protocol::codec::WireEncode;
 use iggy_binary_protocol::codes::{
     CREATE_TOPIC_CODE, DELETE_TOPIC_CODE, GET_TOPIC_CODE, GET_TOPICS_CODE, PURGE_TOPIC_CODE,
-    UPDATE_TOPIC_CODE,
+    ROTATE_TOPIC_KEY_CODE, UPDATE_TOPIC_CODE,
 };
 use iggy_binary_protocol::requests::topics::{
     CreateTopicRequest, DeleteTopicRequest, GetTopicRequest, GetTopicsRequest, PurgeTopicRequest,
-    UpdateTopicRequest,
+    RotateTopicKeyRequest, UpdateTopicRequest,
 };
 use iggy_binary_protocol::responses::topics::get_topic::GetTopicResponse;
 use iggy_binary_protocol::responses::topics::get_topics::GetTopicsResponse;
@@ -90,6 +90,7 @@ impl<B: BinaryClient> TopicClient for B {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<TopicDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let wire_stream_id = identifier_to_wire(stream_id)?;
@@ -105,6 +106,7 @@ impl<B: BinaryClient> TopicClient for B {
                     max_topic_size: u64::from(max_topic_size),
                     replication_factor: replication_factor.unwrap_or(0),
                     name: wire_name,
+                    cleanup_policy: cleanup_policy.as_code(),
                 }
                 .to_bytes(),
             )
@@ -122,6 +124,7 @@ impl<B: BinaryClient> TopicClient for B {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         let wire_stream_id = identifier_to_wire(stream_id)?;
@@ -137,6 +140,7 @@ impl<B: BinaryClient> TopicClient for B {
                 max_topic_size: u64::from(max_topic_size),
                 replication_factor: replication_factor.unwrap_or(0),
                 name: wire_name,
+                cleanup_policy: cleanup_policy.as_code(),
             }
             .to_bytes(),
         )
@@ -183,4 +187,24 @@ impl<B: BinaryClient> TopicClient for B {
         .await?;
         Ok(())
     }
+
+    async fn rotate_topic_key(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let wire_stream_id = identifier_to_wire(stream_id)?;
+        let wire_topic_id = identifier_to_wire(topic_id)?;
+        self.send_raw_with_response(
+            ROTATE_TOPIC_KEY_CODE,
+            RotateTopicKeyRequest {
+                stream_id: wire_stream_id,
+                topic_id: wire_topic_id,
+            }
+            .to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,82 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::traits::binary_auth::fail_if_not_authenticated;
+use crate::wire_conversions::{consumer_to_wire, identifier_to_wire};
+use crate::{BinaryClient, IggyError, TransactionClient, TransactionOffset};
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::codes::{
+    ABORT_TRANSACTION_CODE, BEGIN_TRANSACTION_CODE, COMMIT_TRANSACTION_CODE,
+};
+use iggy_binary_protocol::requests::transactions::{
+    AbortTransactionRequest, BeginTransactionRequest, CommitTransactionRequest,
+    WireTransactionOffset,
+};
+use iggy_binary_protocol::responses::transactions::BeginTransactionResponse;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> TransactionClient for B {
+    async fn begin_transaction(&self) -> Result<u64, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(BEGIN_TRANSACTION_CODE, BeginTransactionRequest.to_bytes())
+            .await?;
+        let wire_resp = super::decode_response::<BeginTransactionResponse>(&response)?;
+        Ok(wire_resp.transaction_id)
+    }
+
+    async fn commit_transaction(
+        &self,
+        transaction_id: u64,
+        offsets: &[TransactionOffset],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let offsets = offsets
+            .iter()
+            .map(|offset| {
+                Ok(WireTransactionOffset {
+                    consumer: consumer_to_wire(&offset.consumer)?,
+                    stream_id: identifier_to_wire(&offset.stream_id)?,
+                    topic_id: identifier_to_wire(&offset.topic_id)?,
+                    partition_id: offset.partition_id,
+                    offset: offset.offset,
+                })
+            })
+            .collect::<Result<Vec<_>, IggyError>>()?;
+        self.send_raw_with_response(
+            COMMIT_TRANSACTION_CODE,
+            CommitTransactionRequest {
+                transaction_id,
+                offsets,
+            }
+            .to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_raw_with_response(
+            ABORT_TRANSACTION_CODE,
+            AbortTransactionRequest { transaction_id }.to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -17,23 +17,32 @@
  */
 
 use crate::traits::binary_auth::fail_if_not_authenticated;
-use crate::wire_conversions::{identifier_to_wire, permissions_to_wire, users_from_wire};
+use crate::wire_conversions::{
+    identifier_to_wire, permissions_to_wire, quota_scope_to_wire, quota_to_wire, users_from_wire,
+};
 use crate::{
     BinaryClient, ClientState, DiagnosticEvent, Identifier, IdentityInfo, IggyError, Permissions,
-    UserClient, UserInfo, UserInfoDetails, UserStatus,
+    Quota, QuotaScope, UserClient, UserInfo, UserInfoDetails, UserQuotas, UserStatus, scram,
 };
 use iggy_binary_protocol::WireName;
 use iggy_binary_protocol::codec::WireEncode;
 use iggy_binary_protocol::codes::{
-    CHANGE_PASSWORD_CODE, CREATE_USER_CODE, DELETE_USER_CODE, GET_USER_CODE, GET_USERS_CODE,
-    LOGIN_USER_CODE, LOGOUT_USER_CODE, UPDATE_PERMISSIONS_CODE, UPDATE_USER_CODE,
+    CHANGE_PASSWORD_CODE, CREATE_USER_CODE, DELETE_USER_CODE, GET_USER_CODE, GET_USER_QUOTAS_CODE,
+    GET_USERS_CODE, LOGIN_SCRAM_FINISH_CODE, LOGIN_SCRAM_START_CODE, LOGIN_USER_CODE,
+    LOGIN_WITH_TOKEN_CODE, LOGOUT_USER_CODE, SET_USER_QUOTA_CODE, UPDATE_PERMISSIONS_CODE,
+    UPDATE_USER_CODE,
 };
 use iggy_binary_protocol::requests::users::{
-    ChangePasswordRequest, CreateUserRequest, DeleteUserRequest, GetUserRequest, GetUsersRequest,
-    LoginUserRequest, LogoutUserRequest, UpdatePermissionsRequest, UpdateUserRequest,
+    ChangePasswordRequest, CreateUserRequest, DeleteUserRequest, GetUserQuotasRequest,
+    GetUserRequest, GetUsersRequest, LoginScramFinishRequest, LoginScramStartRequest,
+    LoginUserRequest, LoginWithTokenRequest, LogoutUserRequest, SetUserQuotaRequest,
+    UpdatePermissionsRequest, UpdateUserRequest,
 };
 use iggy_binary_protocol::responses::users::login_user::IdentityResponse;
-use iggy_binary_protocol::responses::users::{GetUsersResponse, UserDetailsResponse};
+use iggy_binary_protocol::responses::users::{
+    GetUsersResponse, LoginScramFinishResponse, LoginScramStartResponse, UserDetailsResponse,
+    UserQuotasResponse,
+};
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> UserClient for B {
@@ -74,6 +83,9 @@ impl<B: BinaryClient> UserClient for B {
     ) -> Result<UserInfoDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let wire_name = WireName::new(username).map_err(|_| IggyError::InvalidFormat)?;
+        if let Some(permissions) = &permissions {
+            permissions.validate_patterns()?;
+        }
         let wire_perms = permissions.as_ref().map(permissions_to_wire);
         let response = self
             .send_raw_with_response(
@@ -134,6 +146,9 @@ impl<B: BinaryClient> UserClient for B {
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         let wire_id = identifier_to_wire(user_id)?;
+        if let Some(permissions) = &permissions {
+            permissions.validate_patterns()?;
+        }
         let wire_perms = permissions.as_ref().map(permissions_to_wire);
         self.send_raw_with_response(
             UPDATE_PERMISSIONS_CODE,
@@ -147,6 +162,40 @@ impl<B: BinaryClient> UserClient for B {
         Ok(())
     }
 
+    async fn set_user_quota(
+        &self,
+        user_id: &Identifier,
+        scope: QuotaScope,
+        quota: Option<Quota>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let wire_id = identifier_to_wire(user_id)?;
+        self.send_raw_with_response(
+            SET_USER_QUOTA_CODE,
+            SetUserQuotaRequest {
+                user_id: wire_id,
+                scope: quota_scope_to_wire(scope),
+                quota: quota.as_ref().map(quota_to_wire),
+            }
+            .to_bytes(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_user_quotas(&self, user_id: &Identifier) -> Result<UserQuotas, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let wire_id = identifier_to_wire(user_id)?;
+        let response = self
+            .send_raw_with_response(
+                GET_USER_QUOTAS_CODE,
+                GetUserQuotasRequest { user_id: wire_id }.to_bytes(),
+            )
+            .await?;
+        let wire_resp = super::decode_response::<UserQuotasResponse>(&response)?;
+        Ok(UserQuotas::from(wire_resp))
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -188,6 +237,80 @@ impl<B: BinaryClient> UserClient for B {
         Ok(IdentityInfo::from(wire_resp))
     }
 
+    async fn login_user_scram(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<IdentityInfo, IggyError> {
+        let wire_name = WireName::new(username).map_err(|_| IggyError::InvalidFormat)?;
+        let client_nonce = scram::generate_nonce();
+        let response = self
+            .send_raw_with_response(
+                LOGIN_SCRAM_START_CODE,
+                LoginScramStartRequest {
+                    username: wire_name,
+                    client_nonce: client_nonce.clone(),
+                }
+                .to_bytes(),
+            )
+            .await?;
+        let challenge = super::decode_response::<LoginScramStartResponse>(&response)?;
+        if !challenge.nonce.starts_with(&client_nonce) || challenge.nonce == client_nonce {
+            return Err(IggyError::InvalidCredentials);
+        }
+
+        let auth_message = scram::auth_message(
+            username,
+            &client_nonce,
+            &challenge.nonce,
+            &challenge.salt,
+            challenge.iterations,
+        );
+        let proof = scram::ScramClientProof::new(
+            password,
+            &challenge.salt,
+            challenge.iterations,
+            &auth_message,
+        )?;
+        let response = self
+            .send_raw_with_response(
+                LOGIN_SCRAM_FINISH_CODE,
+                LoginScramFinishRequest {
+                    nonce: challenge.nonce,
+                    client_proof: proof.proof.to_vec(),
+                }
+                .to_bytes(),
+            )
+            .await?;
+        let wire_resp = super::decode_response::<LoginScramFinishResponse>(&response)?;
+        if !proof.verify_server_signature(&wire_resp.server_signature) {
+            return Err(IggyError::InvalidCredentials);
+        }
+
+        self.set_state(ClientState::Authenticated).await;
+        self.publish_event(DiagnosticEvent::SignedIn).await;
+        Ok(IdentityInfo {
+            user_id: wire_resp.user_id,
+            access_token: None,
+        })
+    }
+
+    async fn login_with_token(&self, token: &str) -> Result<IdentityInfo, IggyError> {
+        let response = self
+            .send_raw_with_response(
+                LOGIN_WITH_TOKEN_CODE,
+                LoginWithTokenRequest {
+                    token: token.to_string(),
+                }
+                .to_bytes(),
+            )
+            .await?;
+        self.set_state(ClientState::Authenticated).await;
+        self.publish_event(DiagnosticEvent::SignedIn).await;
+        let wire_resp = super::decode_response::<IdentityResponse>(&response)?;
+        Ok(IdentityInfo::from(wire_resp))
+    }
+
     async fn logout_user(&self) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_raw_with_response(LOGOUT_USER_CODE, LogoutUserRequest.to_bytes())
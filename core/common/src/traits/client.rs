@@ -18,7 +18,8 @@
 
 use crate::{
     ClusterClient, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, SegmentClient, StreamClient, SystemClient, TopicClient, UserClient,
+    PersonalAccessTokenClient, ProducerClient, RoleClient, SegmentClient, StreamClient,
+    SystemClient, TopicClient, TransactionClient, UserClient,
 };
 use crate::{DiagnosticEvent, IggyError};
 use async_broadcast::Receiver;
@@ -33,6 +34,7 @@ pub trait Client:
     ClusterClient
     + SystemClient
     + UserClient
+    + RoleClient
     + PersonalAccessTokenClient
     + StreamClient
     + TopicClient
@@ -41,6 +43,8 @@ pub trait Client:
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
+    + TransactionClient
+    + ProducerClient
     + Sync
     + Send
     + Debug
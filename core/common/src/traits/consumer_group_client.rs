@@ -16,7 +16,10 @@
  * under the License.
  */
 
-use crate::{ConsumerGroup, ConsumerGroupDetails, Identifier, IggyError};
+use crate::{
+    ConsumerGroup, ConsumerGroupDetails, ConsumerGroupLag, DeadLetterPolicy, Identifier, IggyError,
+    SharedSubscription,
+};
 use async_trait::async_trait;
 
 /// This trait defines the methods to interact with the consumer group module.
@@ -39,14 +42,30 @@ pub trait ConsumerGroupClient {
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<Vec<ConsumerGroup>, IggyError>;
+    /// Get the lag of a specific consumer group by unique ID or name in each of its partitions for the given stream and topic by unique IDs or names.
+    ///
+    /// The lag is the number of messages not yet consumed by the consumer group, along with the age of the oldest of them.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn get_consumer_group_lag(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<Option<ConsumerGroupLag>, IggyError>;
     /// Create a new consumer group for the given stream and topic by unique IDs or names.
     ///
+    /// The optional dead-letter policy moves the messages exceeding the maximum delivery count to the dead-letter topic.
+    /// The optional shared subscription lets every member poll any partition and acknowledge the messages individually.
+    ///
     /// Authentication is required, and the permission to manage the streams or topics.
     async fn create_consumer_group(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         name: &str,
+        dead_letter: Option<DeadLetterPolicy>,
+        shared: Option<SharedSubscription>,
     ) -> Result<ConsumerGroupDetails, IggyError>;
     /// Delete a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
     ///
@@ -75,4 +94,32 @@ pub trait ConsumerGroupClient {
         topic_id: &Identifier,
         group_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Negatively acknowledge the message delivered to the consumer group, so it counts as a failed delivery.
+    ///
+    /// The message is redelivered on the next poll, or moved to the dead-letter topic once it exceeds the maximum delivery count.
+    /// The consumer group must have a dead-letter policy or a shared subscription.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn nack_message(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        reason: &str,
+    ) -> Result<(), IggyError>;
+    /// Acknowledge the messages delivered to the shared consumer group, so they're never redelivered.
+    ///
+    /// The consumer group offset advances once all the messages preceding the acknowledged ones are acknowledged as well.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError>;
 }
@@ -16,7 +16,8 @@
  * under the License.
  */
 use crate::{
-    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PolledMessages, PollingStrategy,
+    Consumer, Identifier, IggyError, IggyMessage, MessageFilter, Partitioning, PolledMessages,
+    PollingStrategy,
 };
 use async_trait::async_trait;
 
@@ -38,6 +39,25 @@ pub trait MessageClient {
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError>;
 
+    /// Poll given amount of messages matching the filter over their user headers, which is evaluated by the server.
+    ///
+    /// The non-matching messages are skipped, and the consumer offset is committed only up to the last returned message.
+    /// The filter is not supported for the consumer groups with the shared subscription.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn poll_filtered_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: &MessageFilter,
+    ) -> Result<PolledMessages, IggyError>;
+
     /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to send the messages.
@@ -27,10 +27,13 @@ pub(crate) mod message_client;
 pub(crate) mod partition_client;
 pub(crate) mod partitioner;
 pub(crate) mod personal_access_token_client;
+pub(crate) mod producer_client;
+pub(crate) mod role_client;
 pub(crate) mod segment_client;
 pub(crate) mod sizeable;
 pub(crate) mod stream_client;
 pub(crate) mod system_client;
 pub(crate) mod topic_client;
+pub(crate) mod transaction_client;
 pub(crate) mod user_client;
 pub(crate) mod validatable;
@@ -0,0 +1,46 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{Identifier, IggyError, IggyMessage, ProducerIdentity};
+use async_trait::async_trait;
+
+/// This trait defines the methods to interact with the idempotent producer module.
+#[async_trait]
+pub trait ProducerClient {
+    /// Initialize an idempotent producer and return its identity.
+    /// If the `producer_id` is provided, the existing producer is resumed with a new epoch,
+    /// and its previous instances are fenced off, otherwise a new producer ID is assigned.
+    ///
+    /// Authentication is required.
+    async fn init_producer(&self, producer_id: Option<u64>) -> Result<ProducerIdentity, IggyError>;
+    /// Send the messages to the given partition as an idempotent producer.
+    /// The `base_sequence` is the sequence number of the first message, and each next message increments it.
+    /// Sequence numbers are tracked per partition, so the batch which was already appended is acknowledged
+    /// without being appended again, while a gap in the sequence numbers is rejected.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_idempotent_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        producer: &ProducerIdentity,
+        base_sequence: u64,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError>;
+}
@@ -0,0 +1,73 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{Identifier, IggyError, Permissions, RoleInfo, RoleInfoDetails};
+use async_trait::async_trait;
+
+/// This trait defines the methods to interact with the roles, the named sets of permissions
+/// which can be assigned to any number of users.
+///
+/// The effective permissions of a user are the union of its own permissions and the permissions
+/// of all the roles it holds.
+#[async_trait]
+pub trait RoleClient {
+    /// Get the info about a specific role by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    async fn get_role(&self, role_id: &Identifier) -> Result<Option<RoleInfoDetails>, IggyError>;
+    /// Get the info about all the roles.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    async fn get_roles(&self) -> Result<Vec<RoleInfo>, IggyError>;
+    /// Create a new role.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn create_role(
+        &self,
+        name: &str,
+        permissions: Option<Permissions>,
+    ) -> Result<RoleInfoDetails, IggyError>;
+    /// Delete a role by unique ID or name. The role is unassigned from all of its users.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn delete_role(&self, role_id: &Identifier) -> Result<(), IggyError>;
+    /// Update the permissions of a role by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_role(
+        &self,
+        role_id: &Identifier,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError>;
+    /// Assign a role to a user, both by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn assign_role(
+        &self,
+        user_id: &Identifier,
+        role_id: &Identifier,
+    ) -> Result<(), IggyError>;
+    /// Unassign a role from a user, both by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn unassign_role(
+        &self,
+        user_id: &Identifier,
+        role_id: &Identifier,
+    ) -> Result<(), IggyError>;
+}
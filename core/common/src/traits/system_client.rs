@@ -17,8 +17,8 @@
  */
 
 use crate::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    AuditLogQuery, AuditRecord, ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot,
+    SnapshotCompression, Stats, SystemSnapshotType,
 };
 use async_trait::async_trait;
 
@@ -52,4 +52,8 @@ pub trait SystemClient {
         compression: SnapshotCompression,
         snapshot_types: Vec<SystemSnapshotType>,
     ) -> Result<Snapshot, IggyError>;
+    /// Get the records of the audit log matching the query, from the oldest to the newest.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn get_audit_log(&self, query: &AuditLogQuery) -> Result<Vec<AuditRecord>, IggyError>;
 }
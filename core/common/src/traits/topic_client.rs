@@ -17,7 +17,8 @@
  */
 
 use crate::{
-    CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic, TopicDetails,
+    CleanupPolicy, CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic,
+    TopicDetails,
 };
 use async_trait::async_trait;
 
@@ -50,10 +51,12 @@ pub trait TopicClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<TopicDetails, IggyError>;
     /// Update a topic by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the topics.
+    #[allow(clippy::too_many_arguments)]
     async fn update_topic(
         &self,
         stream_id: &Identifier,
@@ -63,6 +66,7 @@ pub trait TopicClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<(), IggyError>;
     /// Delete a topic by unique ID or name.
     ///
@@ -80,4 +84,13 @@ pub trait TopicClient {
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Rotate the data encryption key of a topic by unique ID or name.
+    /// The new messages are encrypted with the new key, while the already stored ones remain readable.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn rotate_topic_key(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError>;
 }
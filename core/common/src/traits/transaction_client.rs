@@ -0,0 +1,43 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{IggyError, TransactionOffset};
+use async_trait::async_trait;
+
+/// This trait defines the methods to interact with the transaction module.
+#[async_trait]
+pub trait TransactionClient {
+    /// Begin a new transaction for the current client and return its ID.
+    /// All the messages sent by the client until the transaction is committed or aborted become part of it.
+    ///
+    /// Authentication is required.
+    async fn begin_transaction(&self) -> Result<u64, IggyError>;
+    /// Commit the transaction, making its messages visible to the `read_committed` consumers
+    /// and storing the provided consumer offsets atomically with the messages.
+    ///
+    /// Authentication is required, and the permission to poll the messages for each of the stored offsets.
+    async fn commit_transaction(
+        &self,
+        transaction_id: u64,
+        offsets: &[TransactionOffset],
+    ) -> Result<(), IggyError>;
+    /// Abort the transaction, its messages will never be visible to the `read_committed` consumers.
+    ///
+    /// Authentication is required.
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError>;
+}
@@ -17,7 +17,8 @@
  */
 
 use crate::{
-    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserStatus,
+    Identifier, IdentityInfo, IggyError, Permissions, Quota, QuotaScope, UserInfo, UserInfoDetails,
+    UserQuotas, UserStatus,
 };
 use async_trait::async_trait;
 
@@ -63,6 +64,22 @@ pub trait UserClient {
         user_id: &Identifier,
         permissions: Option<Permissions>,
     ) -> Result<(), IggyError>;
+    /// Set the produce/fetch throughput and request rate quota of a user by unique ID or username.
+    ///
+    /// The `User` scope limits the traffic of all the user's connections together, the `Client` scope limits each connection separately.
+    /// Passing `None` removes the quota for the scope.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn set_user_quota(
+        &self,
+        user_id: &Identifier,
+        scope: QuotaScope,
+        quota: Option<Quota>,
+    ) -> Result<(), IggyError>;
+    /// Get the quotas of a user by unique ID or username.
+    ///
+    /// Authentication is required, and the permission to read the users, unless the provided user ID is the same as the authenticated user.
+    async fn get_user_quotas(&self, user_id: &Identifier) -> Result<UserQuotas, IggyError>;
     /// Change the password of a user by unique ID or username.
     ///
     /// Authentication is required, and the permission to manage the users, unless the provided user ID is the same as the authenticated user.
@@ -74,6 +91,18 @@ pub trait UserClient {
     ) -> Result<(), IggyError>;
     /// Login a user by username and password.
     async fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError>;
+    /// Login a user by username and password using the SCRAM-SHA-256 challenge-response exchange.
+    ///
+    /// The password never leaves the client, and the server proves it knows the user's credentials too.
+    async fn login_user_scram(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<IdentityInfo, IggyError>;
+    /// Login a user with an OAuth2/OIDC access token issued by one of the server's trusted issuers.
+    ///
+    /// The token claims are mapped to the user, which might be provisioned on the first sign in.
+    async fn login_with_token(&self, token: &str) -> Result<IdentityInfo, IggyError>;
     /// Logout the currently authenticated user.
     async fn logout_user(&self) -> Result<(), IggyError>;
 }
@@ -0,0 +1,153 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{AuditRecord, IggyTimestamp, UserId};
+use serde::{Deserialize, Serialize};
+
+/// `AuditLogQuery` represents the filters of the audit records to return.
+/// It consists of the following fields:
+/// - `user_id`: return only the records of the operations performed by the user.
+/// - `from`: return only the records not older than the timestamp.
+/// - `to`: return only the records not newer than the timestamp.
+/// - `operation`: return only the records of the operation, e.g. `stream.create`,
+///   or of the whole group of operations, e.g. `stream`.
+/// - `limit`: return at most the given number of the most recent matching records.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone)]
+pub struct AuditLogQuery {
+    /// Return only the records of the operations performed by the user.
+    #[serde(default)]
+    pub user_id: Option<UserId>,
+    /// Return only the records not older than the timestamp.
+    #[serde(default)]
+    pub from: Option<IggyTimestamp>,
+    /// Return only the records not newer than the timestamp.
+    #[serde(default)]
+    pub to: Option<IggyTimestamp>,
+    /// Return only the records of the operation or the group of operations.
+    #[serde(default)]
+    pub operation: Option<String>,
+    /// Return at most the given number of the most recent matching records.
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl AuditLogQuery {
+    /// Returns `true` if the record matches all the filters of the query.
+    pub fn matches(&self, record: &AuditRecord) -> bool {
+        if self.user_id.is_some() && record.user_id != self.user_id {
+            return false;
+        }
+
+        if let Some(from) = self.from
+            && record.timestamp.as_micros() < from.as_micros()
+        {
+            return false;
+        }
+
+        if let Some(to) = self.to
+            && record.timestamp.as_micros() > to.as_micros()
+        {
+            return false;
+        }
+
+        if let Some(operation) = &self.operation {
+            let matches_group = record
+                .operation
+                .strip_prefix(operation.as_str())
+                .is_some_and(|rest| rest.starts_with('.'));
+            if record.operation != *operation && !matches_group {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AuditOutcome;
+
+    fn record(user_id: Option<UserId>, timestamp: u64, operation: &str) -> AuditRecord {
+        AuditRecord {
+            id: 1,
+            timestamp: IggyTimestamp::from(timestamp),
+            user_id,
+            username: None,
+            client_id: None,
+            address: None,
+            operation: operation.to_string(),
+            details: None,
+            outcome: AuditOutcome::Success,
+            error: None,
+            previous_hash: String::new(),
+            hash: String::new(),
+        }
+    }
+
+    #[test]
+    fn empty_query_should_match_every_record() {
+        let query = AuditLogQuery::default();
+        assert!(query.matches(&record(None, 1, "user.login")));
+        assert!(query.matches(&record(Some(0), 2, "stream.create")));
+    }
+
+    #[test]
+    fn query_should_filter_by_user() {
+        let query = AuditLogQuery {
+            user_id: Some(0),
+            ..Default::default()
+        };
+        assert!(query.matches(&record(Some(0), 1, "stream.create")));
+        assert!(!query.matches(&record(Some(1), 1, "stream.create")));
+        assert!(!query.matches(&record(None, 1, "user.login")));
+    }
+
+    #[test]
+    fn query_should_filter_by_time_range() {
+        let query = AuditLogQuery {
+            from: Some(IggyTimestamp::from(10)),
+            to: Some(IggyTimestamp::from(20)),
+            ..Default::default()
+        };
+        assert!(!query.matches(&record(None, 9, "user.login")));
+        assert!(query.matches(&record(None, 10, "user.login")));
+        assert!(query.matches(&record(None, 20, "user.login")));
+        assert!(!query.matches(&record(None, 21, "user.login")));
+    }
+
+    #[test]
+    fn query_should_filter_by_operation_or_group() {
+        let query = AuditLogQuery {
+            operation: Some("stream".to_string()),
+            ..Default::default()
+        };
+        assert!(query.matches(&record(None, 1, "stream.create")));
+        assert!(query.matches(&record(None, 1, "stream.delete")));
+        assert!(!query.matches(&record(None, 1, "streams.create")));
+        assert!(!query.matches(&record(None, 1, "topic.create")));
+
+        let query = AuditLogQuery {
+            operation: Some("stream.create".to_string()),
+            ..Default::default()
+        };
+        assert!(query.matches(&record(None, 1, "stream.create")));
+        assert!(!query.matches(&record(None, 1, "stream.delete")));
+    }
+}
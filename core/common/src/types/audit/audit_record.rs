@@ -0,0 +1,220 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::hash;
+use crate::{IggyError, IggyTimestamp, UserId};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `AuditOutcome` represents the result of the audited operation.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The operation has succeeded.
+    #[default]
+    Success,
+    /// The operation has been rejected or has failed.
+    Failure,
+}
+
+impl AuditOutcome {
+    /// Returns the code of the outcome.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AuditOutcome::Success => 1,
+            AuditOutcome::Failure => 2,
+        }
+    }
+
+    /// Returns the outcome from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(AuditOutcome::Success),
+            2 => Ok(AuditOutcome::Failure),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for AuditOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "success" => Ok(AuditOutcome::Success),
+            "failure" => Ok(AuditOutcome::Failure),
+            _ => Err(format!("Invalid audit outcome: {s}")),
+        }
+    }
+}
+
+impl Display for AuditOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditOutcome::Success => write!(f, "success"),
+            AuditOutcome::Failure => write!(f, "failure"),
+        }
+    }
+}
+
+/// `AuditRecord` represents a single entry of the audit log of the administrative
+/// and security-relevant operations, such as the changes of the streams, topics and users,
+/// or the authentication attempts.
+///
+/// The records are chained: each of them contains the hash of the previous one, and its own hash
+/// is calculated over all of its fields, so any modification or removal of a record in the middle
+/// of the log can be detected with [`AuditRecord::follows`].
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AuditRecord {
+    /// The sequence number of the record, starting from 1.
+    pub id: u64,
+    /// The time at which the operation has been performed.
+    pub timestamp: IggyTimestamp,
+    /// The user who has performed the operation, if authenticated.
+    pub user_id: Option<UserId>,
+    /// The name of the user who has performed or attempted the operation, if known.
+    pub username: Option<String>,
+    /// The client which has sent the request, if the transport is stateful.
+    pub client_id: Option<u32>,
+    /// The address of the client which has sent the request.
+    pub address: Option<String>,
+    /// The name of the operation, e.g. `stream.create` or `user.login`.
+    pub operation: String,
+    /// The resources affected by the operation, never containing any secrets.
+    pub details: Option<String>,
+    /// The result of the operation.
+    pub outcome: AuditOutcome,
+    /// The reason of the failure, if the operation has failed.
+    pub error: Option<String>,
+    /// The hash of the previous record, empty for the first one.
+    pub previous_hash: String,
+    /// The hash of this record.
+    pub hash: String,
+}
+
+impl AuditRecord {
+    /// Calculates the hash of the record from all of its fields except the hash itself.
+    pub fn calculate_hash(&self) -> String {
+        let mut data = Vec::with_capacity(256);
+        data.extend_from_slice(&self.id.to_le_bytes());
+        data.extend_from_slice(&self.timestamp.as_micros().to_le_bytes());
+        put_optional(&mut data, self.user_id.map(|id| id.to_le_bytes()).as_ref());
+        put_optional(&mut data, self.username.as_ref().map(String::as_bytes));
+        put_optional(
+            &mut data,
+            self.client_id.map(|id| id.to_le_bytes()).as_ref(),
+        );
+        put_optional(&mut data, self.address.as_ref().map(String::as_bytes));
+        put_bytes(&mut data, self.operation.as_bytes());
+        put_optional(&mut data, self.details.as_ref().map(String::as_bytes));
+        data.push(self.outcome.as_code());
+        put_optional(&mut data, self.error.as_ref().map(String::as_bytes));
+        put_bytes(&mut data, self.previous_hash.as_bytes());
+        hash::calculate_256(&data)
+    }
+
+    /// Returns `true` if the hash of the record matches its content.
+    pub fn is_intact(&self) -> bool {
+        self.hash == self.calculate_hash()
+    }
+
+    /// Returns `true` if the record is intact and directly follows the given one in the log.
+    pub fn follows(&self, previous: &AuditRecord) -> bool {
+        self.is_intact() && self.id == previous.id + 1 && self.previous_hash == previous.hash
+    }
+}
+
+fn put_bytes(data: &mut Vec<u8>, value: &[u8]) {
+    data.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    data.extend_from_slice(value);
+}
+
+fn put_optional<T: AsRef<[u8]>>(data: &mut Vec<u8>, value: Option<T>) {
+    match value {
+        Some(value) => {
+            data.push(1);
+            put_bytes(data, value.as_ref());
+        }
+        None => data.push(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: u64, previous_hash: &str) -> AuditRecord {
+        let mut record = AuditRecord {
+            id,
+            timestamp: IggyTimestamp::from(1_700_000_000_000_000 + id),
+            user_id: Some(0),
+            username: Some("iggy".to_string()),
+            client_id: Some(1),
+            address: Some("127.0.0.1:50000".to_string()),
+            operation: "stream.create".to_string(),
+            details: Some("name: orders".to_string()),
+            outcome: AuditOutcome::Success,
+            error: None,
+            previous_hash: previous_hash.to_string(),
+            hash: String::new(),
+        };
+        record.hash = record.calculate_hash();
+        record
+    }
+
+    #[test]
+    fn chained_records_should_follow_each_other() {
+        let first = record(1, "");
+        let second = record(2, &first.hash);
+        assert!(first.is_intact());
+        assert!(second.follows(&first));
+        assert!(!first.follows(&second));
+    }
+
+    #[test]
+    fn modified_record_should_not_be_intact() {
+        let mut record = record(1, "");
+        record.outcome = AuditOutcome::Failure;
+        assert!(!record.is_intact());
+
+        let mut other = self::record(1, "");
+        other.details = None;
+        assert!(!other.is_intact());
+    }
+
+    #[test]
+    fn record_should_not_follow_when_one_in_between_is_removed() {
+        let first = record(1, "");
+        let second = record(2, &first.hash);
+        let third = record(3, &second.hash);
+        assert!(!third.follows(&first));
+    }
+
+    #[test]
+    fn outcome_should_be_parsed_from_code_and_string() {
+        for outcome in [AuditOutcome::Success, AuditOutcome::Failure] {
+            assert_eq!(AuditOutcome::from_code(outcome.as_code()).unwrap(), outcome);
+            assert_eq!(
+                AuditOutcome::from_str(&outcome.to_string()).unwrap(),
+                outcome
+            );
+        }
+        assert!(AuditOutcome::from_code(0).is_err());
+    }
+}
@@ -16,19 +16,31 @@
  * under the License.
  */
 
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
 use serde::{
     Deserialize, Serialize, Serializer,
     de::{self, Deserializer, Visitor},
 };
 use std::{
     fmt::{Display, Formatter},
+    io::{Read, Write},
     str::FromStr,
 };
 
+use crate::MAX_PAYLOAD_SIZE;
 use crate::error::IggyError;
 
-// for now only those, in the future will add snappy, lz4, zstd (same as in confluent kafka) in addition to that
-// we should consider brotli as well.
+/// The user header key used to mark a message payload as compressed.
+///
+/// The value is the `u8` code of the [`CompressionAlgorithm`] that was used,
+/// which allows consumers to decode messages without knowing the topic configuration.
+pub const COMPRESSION_HEADER_KEY: &str = "iggy-compression";
+
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+
+// same set as in confluent kafka, we should consider brotli as well.
 /// Supported compression algorithms
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub enum CompressionAlgorithm {
@@ -37,6 +49,12 @@ pub enum CompressionAlgorithm {
     None,
     // Gzip compression algorithm
     Gzip,
+    // LZ4 block compression algorithm
+    Lz4,
+    // Zstandard compression algorithm
+    Zstd,
+    // Snappy (raw format) compression algorithm
+    Snappy,
 }
 
 impl FromStr for CompressionAlgorithm {
@@ -45,6 +63,9 @@ impl FromStr for CompressionAlgorithm {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "gzip" => Ok(CompressionAlgorithm::Gzip),
+            "lz4" => Ok(CompressionAlgorithm::Lz4),
+            "zstd" => Ok(CompressionAlgorithm::Zstd),
+            "snappy" => Ok(CompressionAlgorithm::Snappy),
             "none" => Ok(CompressionAlgorithm::None),
             _ => Err(format!("Unknown compression type: {s}")),
         }
@@ -56,6 +77,9 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => 1,
             CompressionAlgorithm::Gzip => 2,
+            CompressionAlgorithm::Lz4 => 3,
+            CompressionAlgorithm::Zstd => 4,
+            CompressionAlgorithm::Snappy => 5,
         }
     }
 
@@ -63,9 +87,75 @@ impl CompressionAlgorithm {
         match code {
             1 => Ok(CompressionAlgorithm::None),
             2 => Ok(CompressionAlgorithm::Gzip),
+            3 => Ok(CompressionAlgorithm::Lz4),
+            4 => Ok(CompressionAlgorithm::Zstd),
+            5 => Ok(CompressionAlgorithm::Snappy),
             _ => Err(IggyError::InvalidCommand),
         }
     }
+
+    /// Compresses the provided data, for `None` the data is returned as is.
+    pub fn compress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        match self {
+            CompressionAlgorithm::None => Ok(data.to_vec()),
+            CompressionAlgorithm::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder
+                    .write_all(data)
+                    .map_err(|_| IggyError::CannotCompressData)?;
+                encoder.finish().map_err(|_| IggyError::CannotCompressData)
+            }
+            CompressionAlgorithm::Lz4 => Ok(lz4_flex::compress_prepend_size(data)),
+            CompressionAlgorithm::Zstd => zstd::bulk::compress(data, ZSTD_COMPRESSION_LEVEL)
+                .map_err(|_| IggyError::CannotCompressData),
+            CompressionAlgorithm::Snappy => snap::raw::Encoder::new()
+                .compress_vec(data)
+                .map_err(|_| IggyError::CannotCompressData),
+        }
+    }
+
+    /// Decompresses the provided data, for `None` the data is returned as is.
+    ///
+    /// The decompressed size is capped at [`MAX_PAYLOAD_SIZE`], as no valid message payload can be larger.
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let max_size = MAX_PAYLOAD_SIZE as usize;
+        match self {
+            CompressionAlgorithm::None => Ok(data.to_vec()),
+            CompressionAlgorithm::Gzip => {
+                let mut decompressed = Vec::new();
+                GzDecoder::new(data)
+                    .take(max_size as u64 + 1)
+                    .read_to_end(&mut decompressed)
+                    .map_err(|_| IggyError::CannotDecompressData)?;
+                if decompressed.len() > max_size {
+                    return Err(IggyError::CannotDecompressData);
+                }
+                Ok(decompressed)
+            }
+            CompressionAlgorithm::Lz4 => {
+                let (size, _) = lz4_flex::block::uncompressed_size(data)
+                    .map_err(|_| IggyError::CannotDecompressData)?;
+                if size > max_size {
+                    return Err(IggyError::CannotDecompressData);
+                }
+                lz4_flex::decompress_size_prepended(data)
+                    .map_err(|_| IggyError::CannotDecompressData)
+            }
+            CompressionAlgorithm::Zstd => {
+                zstd::bulk::decompress(data, max_size).map_err(|_| IggyError::CannotDecompressData)
+            }
+            CompressionAlgorithm::Snappy => {
+                let size =
+                    snap::raw::decompress_len(data).map_err(|_| IggyError::CannotDecompressData)?;
+                if size > max_size {
+                    return Err(IggyError::CannotDecompressData);
+                }
+                snap::raw::Decoder::new()
+                    .decompress_vec(data)
+                    .map_err(|_| IggyError::CannotDecompressData)
+            }
+        }
+    }
 }
 
 impl Display for CompressionAlgorithm {
@@ -73,6 +163,9 @@ impl Display for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => write!(f, "none"),
             CompressionAlgorithm::Gzip => write!(f, "gzip"),
+            CompressionAlgorithm::Lz4 => write!(f, "lz4"),
+            CompressionAlgorithm::Zstd => write!(f, "zstd"),
+            CompressionAlgorithm::Snappy => write!(f, "snappy"),
         }
     }
 }
@@ -85,6 +178,9 @@ impl Serialize for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => serializer.serialize_str("none"),
             CompressionAlgorithm::Gzip => serializer.serialize_str("gzip"),
+            CompressionAlgorithm::Lz4 => serializer.serialize_str("lz4"),
+            CompressionAlgorithm::Zstd => serializer.serialize_str("zstd"),
+            CompressionAlgorithm::Snappy => serializer.serialize_str("snappy"),
         }
     }
 }
@@ -94,6 +190,9 @@ impl From<CompressionAlgorithm> for String {
         match value {
             CompressionAlgorithm::None => "none".to_string(),
             CompressionAlgorithm::Gzip => "gzip".to_string(),
+            CompressionAlgorithm::Lz4 => "lz4".to_string(),
+            CompressionAlgorithm::Zstd => "zstd".to_string(),
+            CompressionAlgorithm::Snappy => "snappy".to_string(),
         }
     }
 }
@@ -144,6 +243,18 @@ mod tests {
         let gzip_alg = CompressionAlgorithm::from_str("Gzip");
         assert!(gzip_alg.is_ok());
         assert_eq!(gzip_alg.unwrap(), CompressionAlgorithm::Gzip);
+
+        let lz4_alg = CompressionAlgorithm::from_str("LZ4");
+        assert!(lz4_alg.is_ok());
+        assert_eq!(lz4_alg.unwrap(), CompressionAlgorithm::Lz4);
+
+        let zstd_alg = CompressionAlgorithm::from_str("zstd");
+        assert!(zstd_alg.is_ok());
+        assert_eq!(zstd_alg.unwrap(), CompressionAlgorithm::Zstd);
+
+        let snappy_alg = CompressionAlgorithm::from_str("Snappy");
+        assert!(snappy_alg.is_ok());
+        assert_eq!(snappy_alg.unwrap(), CompressionAlgorithm::Snappy);
     }
 
     #[test]
@@ -166,6 +277,15 @@ mod tests {
         let gzip_string: String = gzip.into();
 
         assert_eq!(gzip_string, "gzip".to_string());
+
+        let lz4_string: String = CompressionAlgorithm::Lz4.into();
+        assert_eq!(lz4_string, "lz4".to_string());
+
+        let zstd_string: String = CompressionAlgorithm::Zstd.into();
+        assert_eq!(zstd_string, "zstd".to_string());
+
+        let snappy_string: String = CompressionAlgorithm::Snappy.into();
+        assert_eq!(snappy_string, "snappy".to_string());
     }
     #[test]
     fn test_as_code() {
@@ -176,6 +296,10 @@ mod tests {
         let gzip = CompressionAlgorithm::Gzip;
         let gzip_code = gzip.as_code();
         assert_eq!(gzip_code, 2);
+
+        assert_eq!(CompressionAlgorithm::Lz4.as_code(), 3);
+        assert_eq!(CompressionAlgorithm::Zstd.as_code(), 4);
+        assert_eq!(CompressionAlgorithm::Snappy.as_code(), 5);
     }
     #[test]
     fn test_from_code() {
@@ -186,6 +310,19 @@ mod tests {
         let gzip = CompressionAlgorithm::from_code(2);
         assert!(gzip.is_ok());
         assert_eq!(gzip.unwrap(), CompressionAlgorithm::Gzip);
+
+        assert_eq!(
+            CompressionAlgorithm::from_code(3).unwrap(),
+            CompressionAlgorithm::Lz4
+        );
+        assert_eq!(
+            CompressionAlgorithm::from_code(4).unwrap(),
+            CompressionAlgorithm::Zstd
+        );
+        assert_eq!(
+            CompressionAlgorithm::from_code(5).unwrap(),
+            CompressionAlgorithm::Snappy
+        );
     }
     #[test]
     fn test_from_code_invalid_input() {
@@ -198,4 +335,90 @@ mod tests {
         let invalid_compression_kind = CompressionAlgorithm::from_code(255);
         assert!(invalid_compression_kind.is_err());
     }
+
+    #[test]
+    fn test_code_round_trip() {
+        for algorithm in ALL_ALGORITHMS {
+            let code = algorithm.as_code();
+            assert_eq!(CompressionAlgorithm::from_code(code).unwrap(), algorithm);
+            assert_eq!(
+                CompressionAlgorithm::from_str(&algorithm.to_string()).unwrap(),
+                algorithm
+            );
+        }
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        for algorithm in ALL_ALGORITHMS {
+            let json = serde_json::to_string(&algorithm).unwrap();
+            assert_eq!(json, format!("\"{algorithm}\""));
+            let deserialized: CompressionAlgorithm = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, algorithm);
+        }
+    }
+
+    #[test]
+    fn given_compressible_data_all_algorithms_should_round_trip() {
+        let data = br#"{"id":1,"name":"order","status":"created","amount":100}"#.repeat(100);
+        for algorithm in ALL_ALGORITHMS {
+            let compressed = algorithm.compress(&data).unwrap();
+            if algorithm != CompressionAlgorithm::None {
+                assert!(
+                    compressed.len() < data.len(),
+                    "{algorithm} did not compress the data"
+                );
+            }
+            let decompressed = algorithm.decompress(&compressed).unwrap();
+            assert_eq!(decompressed, data, "{algorithm} round trip failed");
+        }
+    }
+
+    #[test]
+    fn given_empty_data_all_algorithms_should_round_trip() {
+        for algorithm in ALL_ALGORITHMS {
+            let compressed = algorithm.compress(&[]).unwrap();
+            let decompressed = algorithm.decompress(&compressed).unwrap();
+            assert!(decompressed.is_empty(), "{algorithm} round trip failed");
+        }
+    }
+
+    #[test]
+    fn given_corrupted_data_decompression_should_fail() {
+        let data = b"definitely not compressed with any of the supported algorithms";
+        for algorithm in ALL_ALGORITHMS {
+            if algorithm == CompressionAlgorithm::None {
+                continue;
+            }
+            assert_eq!(
+                algorithm.decompress(data),
+                Err(IggyError::CannotDecompressData),
+                "{algorithm} accepted corrupted data"
+            );
+        }
+    }
+
+    #[test]
+    fn given_data_exceeding_max_payload_size_decompression_should_fail() {
+        let data = vec![0u8; MAX_PAYLOAD_SIZE as usize + 1];
+        for algorithm in ALL_ALGORITHMS {
+            if algorithm == CompressionAlgorithm::None {
+                continue;
+            }
+            let compressed = algorithm.compress(&data).unwrap();
+            assert_eq!(
+                algorithm.decompress(&compressed),
+                Err(IggyError::CannotDecompressData),
+                "{algorithm} exceeded the max payload size"
+            );
+        }
+    }
+
+    const ALL_ALGORITHMS: [CompressionAlgorithm; 5] = [
+        CompressionAlgorithm::None,
+        CompressionAlgorithm::Gzip,
+        CompressionAlgorithm::Lz4,
+        CompressionAlgorithm::Zstd,
+        CompressionAlgorithm::Snappy,
+    ];
 }
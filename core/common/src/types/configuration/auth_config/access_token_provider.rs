@@ -0,0 +1,75 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::IggyError;
+use async_trait::async_trait;
+use secrecy::SecretString;
+use std::future::Future;
+
+/// Supplies the OAuth2/OIDC access token used to sign in with `Credentials::AccessToken`.
+///
+/// The provider is asked for the token on every sign in, including the automatic one after
+/// reconnecting, so it should refresh the token once the previous one is about to expire.
+/// Any async closure returning `Result<SecretString, IggyError>` can be used as the provider.
+#[async_trait]
+pub trait AccessTokenProvider: Send + Sync {
+    async fn access_token(&self) -> Result<SecretString, IggyError>;
+}
+
+#[async_trait]
+impl<F, Fut> AccessTokenProvider for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<SecretString, IggyError>> + Send,
+{
+    async fn access_token(&self) -> Result<SecretString, IggyError> {
+        self().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::ExposeSecret;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn closure_should_be_asked_for_fresh_token_on_every_call() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let provider = {
+            let counter = counter.clone();
+            move || {
+                let counter = counter.clone();
+                async move {
+                    let id = counter.fetch_add(1, Ordering::SeqCst);
+                    Ok(SecretString::from(format!("token-{id}")))
+                }
+            }
+        };
+
+        let provider: Arc<dyn AccessTokenProvider> = Arc::new(provider);
+        assert_eq!(
+            provider.access_token().await.unwrap().expose_secret(),
+            "token-0"
+        );
+        assert_eq!(
+            provider.access_token().await.unwrap().expose_secret(),
+            "token-1"
+        );
+    }
+}
@@ -15,13 +15,19 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::AccessTokenProvider;
 use secrecy::SecretString;
 use std::fmt;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub enum Credentials {
     UsernamePassword(String, SecretString),
+    /// Username and password authenticated with the SCRAM-SHA-256 exchange (binary transports only).
+    UsernamePasswordScram(String, SecretString),
     PersonalAccessToken(SecretString),
+    /// OAuth2/OIDC access token fetched from the provider on every sign in (binary transports only).
+    AccessToken(Arc<dyn AccessTokenProvider>),
 }
 
 impl fmt::Debug for Credentials {
@@ -32,10 +38,18 @@ impl fmt::Debug for Credentials {
                 .field(username)
                 .field(&"[REDACTED]")
                 .finish(),
+            Credentials::UsernamePasswordScram(username, _) => f
+                .debug_tuple("UsernamePasswordScram")
+                .field(username)
+                .field(&"[REDACTED]")
+                .finish(),
             Credentials::PersonalAccessToken(_) => f
                 .debug_tuple("PersonalAccessToken")
                 .field(&"[REDACTED]")
                 .finish(),
+            Credentials::AccessToken(_) => {
+                f.debug_tuple("AccessToken").field(&"[PROVIDER]").finish()
+            }
         }
     }
 }
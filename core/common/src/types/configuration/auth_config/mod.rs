@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub(crate) mod access_token_provider;
 pub(crate) mod auto_login;
 pub(crate) mod connection_string;
 pub(crate) mod connection_string_options;
@@ -53,6 +53,10 @@ pub struct QuicClientConfig {
     pub max_idle_timeout: u64,
     /// Whether to validate the server certificate.
     pub validate_certificate: bool,
+    /// The path to the client certificate presented to the server for mutual TLS.
+    pub client_cert_file: Option<String>,
+    /// The path to the private key of the client certificate.
+    pub client_key_file: Option<String>,
     /// Interval of heartbeats sent by the client
     pub heartbeat_interval: IggyDuration,
 }
@@ -75,6 +79,8 @@ impl Default for QuicClientConfig {
             keep_alive_interval: 5000,
             max_idle_timeout: 10000,
             validate_certificate: false,
+            client_cert_file: None,
+            client_key_file: None,
         }
     }
 }
@@ -96,6 +102,8 @@ impl From<ConnectionString<QuicConnectionStringOptions>> for QuicClientConfig {
             keep_alive_interval: connection_string.options().keep_alive_interval(),
             max_idle_timeout: connection_string.options().max_idle_timeout(),
             validate_certificate: connection_string.options().validate_certificate(),
+            client_cert_file: None,
+            client_key_file: None,
             heartbeat_interval: connection_string.options().heartbeat_interval(),
         }
     }
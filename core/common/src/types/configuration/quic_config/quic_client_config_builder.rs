@@ -35,6 +35,7 @@ use crate::{AutoLogin, IggyDuration, IggyError, QuicClientConfig, validate_serve
 /// - `keep_alive_interval`: Default is 5000 milliseconds.
 /// - `max_idle_timeout`: Default is 10,000 milliseconds.
 /// - `validate_certificate`: Default is false (certificate validation is disabled).
+/// - `client_cert_file` and `client_key_file`: Default is None (no client certificate).
 #[derive(Debug, Default)]
 pub struct QuicClientConfigBuilder {
     config: QuicClientConfig,
@@ -147,6 +148,13 @@ impl QuicClientConfigBuilder {
         self
     }
 
+    /// Sets the client certificate and private key presented to the server for mutual TLS.
+    pub fn with_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config.client_cert_file = Some(cert_file);
+        self.config.client_key_file = Some(key_file);
+        self
+    }
+
     /// Sets the heartbeat interval. Defaults to 5000ms.
     pub fn with_heartbeat_interval(mut self, interval: IggyDuration) -> Self {
         self.config.heartbeat_interval = interval;
@@ -33,6 +33,10 @@ pub struct TcpClientConfig {
     pub tls_domain: String,
     /// The path to the CA file for TLS.
     pub tls_ca_file: Option<String>,
+    /// The path to the client certificate presented to the server for mutual TLS.
+    pub tls_client_cert_file: Option<String>,
+    /// The path to the private key of the client certificate.
+    pub tls_client_key_file: Option<String>,
     /// Whether to validate the TLS certificate.
     pub tls_validate_certificate: bool,
     /// Whether to automatically login user after establishing connection.
@@ -52,6 +56,8 @@ impl Default for TcpClientConfig {
             tls_enabled: false,
             tls_domain: "".to_string(),
             tls_ca_file: None,
+            tls_client_cert_file: None,
+            tls_client_key_file: None,
             tls_validate_certificate: true,
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
             auto_login: AutoLogin::Disabled,
@@ -69,6 +75,11 @@ impl From<ConnectionString<TcpConnectionStringOptions>> for TcpClientConfig {
             tls_enabled: connection_string.options().tls_enabled(),
             tls_domain: connection_string.options().tls_domain().into(),
             tls_ca_file: connection_string.options().tls_ca_file().to_owned(),
+            tls_client_cert_file: connection_string
+                .options()
+                .tls_client_cert_file()
+                .to_owned(),
+            tls_client_key_file: connection_string.options().tls_client_key_file().to_owned(),
             // Always validate TLS certificate for connection strings, we don't want to allow self-signed certificates for connection strings
             tls_validate_certificate: true,
             reconnection: connection_string.options().reconnection().to_owned(),
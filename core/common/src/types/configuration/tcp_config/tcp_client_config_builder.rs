@@ -25,6 +25,7 @@ use crate::{AutoLogin, IggyDuration, IggyError, TcpClientConfig, validate_server
 /// - `tls_enabled`: Default is false.
 /// - `tls_domain`: Default is "" (auto-detected from server_address).
 /// - `tls_ca_file`: Default is None.
+/// - `tls_client_cert_file` and `tls_client_key_file`: Default is None (no client certificate).
 #[derive(Debug, Default)]
 pub struct TcpClientConfigBuilder {
     config: TcpClientConfig,
@@ -89,6 +90,13 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Sets the client certificate and private key presented to the server for mutual TLS.
+    pub fn with_tls_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config.tls_client_cert_file = Some(cert_file);
+        self.config.tls_client_key_file = Some(key_file);
+        self
+    }
+
     /// Sets whether to validate the TLS certificate.
     pub fn with_tls_validate_certificate(mut self, tls_validate_certificate: bool) -> Self {
         self.config.tls_validate_certificate = tls_validate_certificate;
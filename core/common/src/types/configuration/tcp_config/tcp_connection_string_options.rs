@@ -24,6 +24,8 @@ pub struct TcpConnectionStringOptions {
     tls_enabled: bool,
     tls_domain: String,
     tls_ca_file: Option<String>,
+    tls_client_cert_file: Option<String>,
+    tls_client_key_file: Option<String>,
     reconnection: TcpClientReconnectionConfig,
     heartbeat_interval: IggyDuration,
     nodelay: bool,
@@ -42,6 +44,14 @@ impl TcpConnectionStringOptions {
         &self.tls_ca_file
     }
 
+    pub fn tls_client_cert_file(&self) -> &Option<String> {
+        &self.tls_client_cert_file
+    }
+
+    pub fn tls_client_key_file(&self) -> &Option<String> {
+        &self.tls_client_key_file
+    }
+
     pub fn reconnection(&self) -> &TcpClientReconnectionConfig {
         &self.reconnection
     }
@@ -65,6 +75,8 @@ impl ConnectionStringOptions for TcpConnectionStringOptions {
         let mut tls_enabled = false;
         let mut tls_domain = "".to_string();
         let mut tls_ca_file = None;
+        let mut tls_client_cert_file = None;
+        let mut tls_client_key_file = None;
         let mut reconnection_retries = "unlimited".to_owned();
         let mut reconnection_interval = "1s".to_owned();
         let mut reestablish_after = "5s".to_owned();
@@ -86,6 +98,12 @@ impl ConnectionStringOptions for TcpConnectionStringOptions {
                 "tls_ca_file" => {
                     tls_ca_file = Some(option_parts[1].to_string());
                 }
+                "tls_client_cert_file" => {
+                    tls_client_cert_file = Some(option_parts[1].to_string());
+                }
+                "tls_client_key_file" => {
+                    tls_client_key_file = Some(option_parts[1].to_string());
+                }
                 "reconnection_retries" => {
                     reconnection_retries = option_parts[1].to_string();
                 }
@@ -130,6 +148,8 @@ impl ConnectionStringOptions for TcpConnectionStringOptions {
             tls_enabled,
             tls_domain,
             tls_ca_file,
+            tls_client_cert_file,
+            tls_client_key_file,
             reconnection,
             heartbeat_interval,
             nodelay,
@@ -140,10 +160,13 @@ impl ConnectionStringOptions for TcpConnectionStringOptions {
 }
 
 impl TcpConnectionStringOptions {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         tls_enabled: bool,
         tls_domain: String,
         tls_ca_file: Option<String>,
+        tls_client_cert_file: Option<String>,
+        tls_client_key_file: Option<String>,
         reconnection: TcpClientReconnectionConfig,
         heartbeat_interval: IggyDuration,
         nodelay: bool,
@@ -152,6 +175,8 @@ impl TcpConnectionStringOptions {
             tls_enabled,
             tls_domain,
             tls_ca_file,
+            tls_client_cert_file,
+            tls_client_key_file,
             reconnection,
             heartbeat_interval,
             nodelay,
@@ -165,6 +190,8 @@ impl Default for TcpConnectionStringOptions {
             tls_enabled: false,
             tls_domain: "".to_string(),
             tls_ca_file: None,
+            tls_client_cert_file: None,
+            tls_client_key_file: None,
             reconnection: Default::default(),
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
             nodelay: false,
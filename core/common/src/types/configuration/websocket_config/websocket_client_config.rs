@@ -42,6 +42,10 @@ pub struct WebSocketClientConfig {
     pub tls_domain: String,
     /// The path to the CA file for TLS
     pub tls_ca_file: Option<String>,
+    /// The path to the client certificate presented to the server for mutual TLS
+    pub tls_client_cert_file: Option<String>,
+    /// The path to the private key of the client certificate
+    pub tls_client_key_file: Option<String>,
     /// Whether to validate the TLS certificate
     pub tls_validate_certificate: bool,
 }
@@ -75,6 +79,8 @@ impl Default for WebSocketClientConfig {
             tls_enabled: false,
             tls_domain: "localhost".to_string(),
             tls_ca_file: None,
+            tls_client_cert_file: None,
+            tls_client_key_file: None,
             tls_validate_certificate: false,
         }
     }
@@ -161,6 +167,8 @@ impl From<ConnectionString<WebSocketConnectionStringOptions>> for WebSocketClien
             tls_enabled: options.tls_enabled(),
             tls_domain: options.tls_domain().into(),
             tls_ca_file: options.tls_ca_file().map(|s| s.to_string()),
+            tls_client_cert_file: options.tls_client_cert_file().map(|s| s.to_string()),
+            tls_client_key_file: options.tls_client_key_file().map(|s| s.to_string()),
             tls_validate_certificate: options.tls_validate_certificate(),
         }
     }
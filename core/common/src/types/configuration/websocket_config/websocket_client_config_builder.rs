@@ -131,6 +131,13 @@ impl WebSocketClientConfigBuilder {
         self
     }
 
+    /// Sets the client certificate and private key presented to the server for mutual TLS.
+    pub fn with_tls_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config.tls_client_cert_file = Some(cert_file);
+        self.config.tls_client_key_file = Some(key_file);
+        self
+    }
+
     /// Sets whether to validate the TLS certificate.
     pub fn with_tls_validate_certificate(mut self, tls_validate_certificate: bool) -> Self {
         self.config.tls_validate_certificate = tls_validate_certificate;
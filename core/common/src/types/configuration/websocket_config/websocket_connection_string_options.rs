@@ -34,6 +34,8 @@ pub struct WebSocketConnectionStringOptions {
     tls_enabled: bool,
     tls_domain: String,
     tls_ca_file: Option<String>,
+    tls_client_cert_file: Option<String>,
+    tls_client_key_file: Option<String>,
     tls_validate_certificate: bool,
 }
 
@@ -82,6 +84,14 @@ impl WebSocketConnectionStringOptions {
         self.tls_ca_file.as_deref()
     }
 
+    pub fn tls_client_cert_file(&self) -> Option<&str> {
+        self.tls_client_cert_file.as_deref()
+    }
+
+    pub fn tls_client_key_file(&self) -> Option<&str> {
+        self.tls_client_key_file.as_deref()
+    }
+
     pub fn tls_validate_certificate(&self) -> bool {
         self.tls_validate_certificate
     }
@@ -178,6 +188,12 @@ impl ConnectionStringOptions for WebSocketConnectionStringOptions {
                 "tls_ca_file" => {
                     parsed_options.tls_ca_file = Some(parts[1].to_string());
                 }
+                "tls_client_cert_file" => {
+                    parsed_options.tls_client_cert_file = Some(parts[1].to_string());
+                }
+                "tls_client_key_file" => {
+                    parsed_options.tls_client_key_file = Some(parts[1].to_string());
+                }
                 "tls_validate_certificate" => {
                     parsed_options.tls_validate_certificate = parts[1] == "true";
                 }
@@ -203,6 +219,8 @@ impl Default for WebSocketConnectionStringOptions {
             tls_enabled: false,
             tls_domain: "".to_string(),
             tls_ca_file: None,
+            tls_client_cert_file: None,
+            tls_client_key_file: None,
             tls_validate_certificate: false,
         }
     }
@@ -0,0 +1,60 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::IggyDuration;
+use serde::{Deserialize, Serialize};
+
+/// `ConsumerGroupLag` represents how far behind a consumer group is in each of its partitions.
+/// It consists of the following fields:
+/// - `group_id`: the unique identifier (numeric) of the consumer group.
+/// - `partitions`: the lag of the consumer group in each of its partitions.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsumerGroupLag {
+    /// The unique identifier (numeric) of the consumer group.
+    pub group_id: u32,
+    /// The lag of the consumer group in each of its partitions.
+    pub partitions: Vec<ConsumerGroupPartitionLag>,
+}
+
+impl ConsumerGroupLag {
+    /// Returns the total lag in messages across all the partitions.
+    pub fn total_lag(&self) -> u64 {
+        self.partitions.iter().map(|partition| partition.lag).sum()
+    }
+}
+
+/// `ConsumerGroupPartitionLag` represents the lag of a consumer group in a single partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier (numeric) of the partition.
+/// - `committed_offset`: the offset committed by the consumer group, if any.
+/// - `log_end_offset`: the offset of the last message appended to the partition.
+/// - `lag`: the number of messages not yet consumed by the consumer group.
+/// - `lag_time`: the age of the oldest message not yet consumed by the consumer group.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsumerGroupPartitionLag {
+    /// The unique identifier (numeric) of the partition.
+    pub partition_id: u32,
+    /// The offset committed by the consumer group, if any.
+    pub committed_offset: Option<u64>,
+    /// The offset of the last message appended to the partition.
+    pub log_end_offset: u64,
+    /// The number of messages not yet consumed by the consumer group.
+    pub lag: u64,
+    /// The age of the oldest message not yet consumed by the consumer group.
+    pub lag_time: IggyDuration,
+}
@@ -0,0 +1,113 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::{Identifier, IggyDuration, Validatable};
+use serde::{Deserialize, Serialize};
+
+/// User header with the stream ID (numeric) of the dead-lettered message.
+pub const DEAD_LETTER_ORIGIN_STREAM_HEADER_KEY: &str = "iggy-dlq-origin-stream";
+/// User header with the topic ID (numeric) of the dead-lettered message.
+pub const DEAD_LETTER_ORIGIN_TOPIC_HEADER_KEY: &str = "iggy-dlq-origin-topic";
+/// User header with the partition ID of the dead-lettered message.
+pub const DEAD_LETTER_ORIGIN_PARTITION_HEADER_KEY: &str = "iggy-dlq-origin-partition";
+/// User header with the offset of the dead-lettered message in its partition.
+pub const DEAD_LETTER_ORIGIN_OFFSET_HEADER_KEY: &str = "iggy-dlq-origin-offset";
+/// User header with the name of the consumer group which failed to process the message.
+pub const DEAD_LETTER_CONSUMER_GROUP_HEADER_KEY: &str = "iggy-dlq-consumer-group";
+/// User header with the number of times the message was delivered to the consumer group.
+pub const DEAD_LETTER_DELIVERY_COUNT_HEADER_KEY: &str = "iggy-dlq-delivery-count";
+/// User header with the reason of the last failed delivery.
+pub const DEAD_LETTER_REASON_HEADER_KEY: &str = "iggy-dlq-reason";
+
+/// `DeadLetterPolicy` moves the messages which repeatedly failed to be processed by the consumer group
+/// to the dead-letter topic, so they no longer block the partition.
+/// It consists of the following fields:
+/// - `max_delivery_count`: the maximum number of times the message is delivered to the consumer group.
+/// - `redelivery_timeout`: the time within which the delivered message must be committed, otherwise its next delivery counts as a failed one.
+/// - `stream_id`: the unique stream ID (numeric or name) of the dead-letter topic.
+/// - `topic_id`: the unique topic ID (numeric or name) of the dead-letter topic.
+///
+/// The delivery fails when it's negatively acknowledged, or when the consumer offset
+/// was not stored past the message within the redelivery timeout.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct DeadLetterPolicy {
+    /// The maximum number of times the message is delivered to the consumer group.
+    pub max_delivery_count: u32,
+    /// The time within which the delivered message must be committed.
+    pub redelivery_timeout: IggyDuration,
+    /// Unique stream ID (numeric or name) of the dead-letter topic.
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name) of the dead-letter topic.
+    pub topic_id: Identifier,
+}
+
+impl DeadLetterPolicy {
+    /// Creates a new dead-letter policy.
+    pub fn new(
+        max_delivery_count: u32,
+        redelivery_timeout: IggyDuration,
+        stream_id: Identifier,
+        topic_id: Identifier,
+    ) -> Self {
+        Self {
+            max_delivery_count,
+            redelivery_timeout,
+            stream_id,
+            topic_id,
+        }
+    }
+}
+
+impl Validatable<IggyError> for DeadLetterPolicy {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.max_delivery_count == 0 || self.redelivery_timeout.is_zero() {
+            return Err(IggyError::InvalidDeadLetterPolicy);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_require_delivery_count_and_redelivery_timeout() {
+        let stream_id = Identifier::named("stream").unwrap();
+        let topic_id = Identifier::named("dlq").unwrap();
+        let timeout = IggyDuration::from(1_000_000);
+
+        assert!(
+            DeadLetterPolicy::new(3, timeout, stream_id.clone(), topic_id.clone())
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            DeadLetterPolicy::new(0, timeout, stream_id.clone(), topic_id.clone())
+                .validate()
+                .is_err()
+        );
+        assert!(
+            DeadLetterPolicy::new(3, IggyDuration::from(0), stream_id, topic_id)
+                .validate()
+                .is_err()
+        );
+    }
+}
@@ -18,8 +18,11 @@
 
 pub(crate) mod consumer_group;
 pub(crate) mod consumer_group_id;
+pub(crate) mod consumer_group_lag;
 pub(crate) mod consumer_group_offsets;
 pub(crate) mod consumer_kind;
 pub(crate) mod consumer_offset;
 pub(crate) mod consumer_offset_info;
 pub(crate) mod consumer_offsets;
+pub(crate) mod dead_letter_policy;
+pub(crate) mod shared_subscription;
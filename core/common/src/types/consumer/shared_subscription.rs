@@ -0,0 +1,51 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::{IggyDuration, Validatable};
+use serde::{Deserialize, Serialize};
+
+/// `SharedSubscription` switches the consumer group from the partition ownership to the queue semantics.
+/// It consists of the following fields:
+/// - `visibility_timeout`: the time for which the polled message is hidden from the other members of the consumer group.
+///
+/// Every member of the shared consumer group polls the messages from any partition, and each message is
+/// acknowledged individually. The message which wasn't acknowledged within the visibility timeout,
+/// or was negatively acknowledged, is redelivered to any member of the consumer group.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct SharedSubscription {
+    /// The time for which the polled message is hidden from the other members of the consumer group.
+    pub visibility_timeout: IggyDuration,
+}
+
+impl SharedSubscription {
+    /// Creates a new shared subscription.
+    pub fn new(visibility_timeout: IggyDuration) -> Self {
+        Self { visibility_timeout }
+    }
+}
+
+impl Validatable<IggyError> for SharedSubscription {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.visibility_timeout.is_zero() {
+            return Err(IggyError::InvalidSharedSubscription);
+        }
+
+        Ok(())
+    }
+}
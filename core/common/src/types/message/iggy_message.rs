@@ -22,7 +22,7 @@ use crate::error::IggyError;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::timestamp::IggyTimestamp;
 use crate::wire_conversions::{user_headers_from_wire, user_headers_to_wire};
-use crate::{HeaderKey, HeaderValue};
+use crate::{COMPRESSION_HEADER_KEY, CompressionAlgorithm, EncryptorKind, HeaderKey, HeaderValue};
 use bon::bon;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy_binary_protocol::WireUserHeaders;
@@ -58,6 +58,19 @@ pub const MAX_PAYLOAD_SIZE: u32 = 64 * 1000 * 1000;
 ///
 pub const MAX_USER_HEADERS_SIZE: u32 = 100 * 1000;
 
+/// The user header key holding the time at which the message becomes visible to the consumers.
+///
+/// The value is a `u64` UNIX timestamp in microseconds. Until then, polling the partition stops
+/// right before the message, so the partition order is preserved, except for the consumer groups
+/// with a shared subscription, which skip the message and receive it once it's due.
+pub const DELIVER_AT_HEADER_KEY: &str = "iggy-deliver-at";
+
+/// The user header key holding the ID of the key the message was encrypted with by the client.
+///
+/// The value is a string, which the consumers pass to their key provider to get the key
+/// for decrypting the message.
+pub const ENCRYPTION_KEY_ID_HEADER_KEY: &str = "iggy-encryption-key-id";
+
 /// A message stored in the Iggy messaging system.
 ///
 /// `IggyMessage` represents a single message that can be sent to or received from
@@ -335,6 +348,38 @@ impl IggyMessage {
             .is_some_and(|map| map.contains_key(key)))
     }
 
+    /// Gets the time at which the message becomes visible to the consumers, if it's delayed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iggy_common::*;
+    ///
+    /// let mut message = IggyMessage::builder()
+    ///     .payload("Reminder".into())
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(message.deliver_at().unwrap(), None);
+    ///
+    /// let deliver_at = IggyTimestamp::from(1_700_000_000_000_000);
+    /// message.set_deliver_at(deliver_at).unwrap();
+    /// assert_eq!(message.deliver_at().unwrap(), Some(deliver_at));
+    /// ```
+    pub fn deliver_at(&self) -> Result<Option<IggyTimestamp>, IggyError> {
+        let deliver_at_key = HeaderKey::try_from(DELIVER_AT_HEADER_KEY)?;
+        self.get_user_header(&deliver_at_key)?
+            .map(|value| value.as_uint64().map(IggyTimestamp::from))
+            .transpose()
+    }
+
+    /// Delays the delivery of the message until the provided time, by setting the [`DELIVER_AT_HEADER_KEY`] user header.
+    pub fn set_deliver_at(&mut self, deliver_at: IggyTimestamp) -> Result<(), IggyError> {
+        let deliver_at_key = HeaderKey::try_from(DELIVER_AT_HEADER_KEY)?;
+        let mut user_headers = self.user_headers_map()?.unwrap_or_default();
+        user_headers.insert(deliver_at_key, HeaderValue::from(deliver_at.as_micros()));
+        self.set_user_headers(user_headers)
+    }
+
     /// Gets the payload as a UTF-8 string, if valid.
     ///
     /// # Returns
@@ -358,6 +403,185 @@ impl IggyMessage {
         String::from_utf8(self.payload.to_vec()).map_err(|_| IggyError::InvalidUtf8)
     }
 
+    /// Compresses the payload using the provided algorithm.
+    ///
+    /// The algorithm is recorded in the [`COMPRESSION_HEADER_KEY`] user header, so that
+    /// [`IggyMessage::decompress`] can restore the original payload. The payload is left untouched
+    /// when the algorithm is `None`, when the message is already compressed, or when compression
+    /// would not make the payload any smaller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iggy_common::*;
+    ///
+    /// let payload = "Hello world! ".repeat(100);
+    /// let mut message = IggyMessage::builder()
+    ///     .payload(payload.clone().into())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// message.compress(CompressionAlgorithm::Zstd).unwrap();
+    /// assert!(message.payload.len() < payload.len());
+    ///
+    /// message.decompress().unwrap();
+    /// assert_eq!(message.payload_as_string().unwrap(), payload);
+    /// ```
+    pub fn compress(&mut self, algorithm: CompressionAlgorithm) -> Result<(), IggyError> {
+        if algorithm == CompressionAlgorithm::None {
+            return Ok(());
+        }
+
+        let compression_key = HeaderKey::try_from(COMPRESSION_HEADER_KEY)?;
+        let mut user_headers = self.user_headers_map()?.unwrap_or_default();
+        if user_headers.contains_key(&compression_key) {
+            return Ok(());
+        }
+
+        let compressed = algorithm.compress(&self.payload)?;
+        if compressed.len() >= self.payload.len() {
+            return Ok(());
+        }
+
+        user_headers.insert(compression_key, HeaderValue::from(algorithm.as_code()));
+        self.set_user_headers(user_headers)?;
+        self.payload = Bytes::from(compressed);
+        self.header.payload_length = self.payload.len() as u32;
+        Ok(())
+    }
+
+    /// Decompresses the payload if it was compressed with [`IggyMessage::compress`].
+    ///
+    /// The [`COMPRESSION_HEADER_KEY`] user header is removed, so the message looks exactly
+    /// as it did before compression. Messages without that header are left untouched.
+    pub fn decompress(&mut self) -> Result<(), IggyError> {
+        let compression_key = HeaderKey::try_from(COMPRESSION_HEADER_KEY)?;
+        let Some(mut user_headers) = self.user_headers_map()? else {
+            return Ok(());
+        };
+        let Some(compression) = user_headers.remove(&compression_key) else {
+            return Ok(());
+        };
+
+        let algorithm = CompressionAlgorithm::from_code(compression.as_uint8()?)
+            .map_err(|_| IggyError::CannotDecompressData)?;
+        self.payload = Bytes::from(algorithm.decompress(&self.payload)?);
+        self.header.payload_length = self.payload.len() as u32;
+        self.set_user_headers(user_headers)
+    }
+
+    /// Encrypts the payload along with the user headers using the key identified by `key_id`.
+    ///
+    /// The user headers are moved into the encrypted payload, and replaced with the
+    /// [`ENCRYPTION_KEY_ID_HEADER_KEY`] one, which [`IggyMessage::decrypt`] relies on to restore
+    /// the original message. Only the [`DELIVER_AT_HEADER_KEY`] user header stays visible, since
+    /// the server needs it to delay the delivery. Already encrypted messages are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iggy_common::*;
+    ///
+    /// let encryptor = EncryptorKind::Aes256Gcm(Aes256GcmEncryptor::new(&[1; 32]).unwrap());
+    /// let mut message = IggyMessage::builder()
+    ///     .payload("Hello world!".into())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// message.encrypt("key-1", &encryptor).unwrap();
+    /// assert_eq!(message.encryption_key_id().unwrap().as_deref(), Some("key-1"));
+    ///
+    /// message.decrypt(&encryptor).unwrap();
+    /// assert_eq!(message.payload_as_string().unwrap(), "Hello world!");
+    /// ```
+    pub fn encrypt(&mut self, key_id: &str, encryptor: &EncryptorKind) -> Result<(), IggyError> {
+        let key_id_key = HeaderKey::try_from(ENCRYPTION_KEY_ID_HEADER_KEY)?;
+        let deliver_at_key = HeaderKey::try_from(DELIVER_AT_HEADER_KEY)?;
+        let mut user_headers = self.user_headers_map()?.unwrap_or_default();
+        if user_headers.contains_key(&key_id_key) {
+            return Ok(());
+        }
+
+        let deliver_at = user_headers.remove(&deliver_at_key);
+        let encoded_headers = if user_headers.is_empty() {
+            Bytes::new()
+        } else {
+            user_headers_to_wire(&user_headers).into_bytes()
+        };
+        let mut data = Vec::with_capacity(4 + encoded_headers.len() + self.payload.len());
+        data.extend_from_slice(&(encoded_headers.len() as u32).to_le_bytes());
+        data.extend_from_slice(&encoded_headers);
+        data.extend_from_slice(&self.payload);
+        self.payload = Bytes::from(encryptor.encrypt(&data)?);
+        self.header.payload_length = self.payload.len() as u32;
+
+        let mut visible_headers = BTreeMap::from([(key_id_key, HeaderValue::from_str(key_id)?)]);
+        if let Some(deliver_at) = deliver_at {
+            visible_headers.insert(deliver_at_key, deliver_at);
+        }
+        self.set_user_headers(visible_headers)
+    }
+
+    /// Decrypts the payload and the user headers encrypted with [`IggyMessage::encrypt`].
+    ///
+    /// The [`ENCRYPTION_KEY_ID_HEADER_KEY`] user header is removed, so the message looks exactly
+    /// as it did before encryption. Messages without that header are left untouched.
+    pub fn decrypt(&mut self, encryptor: &EncryptorKind) -> Result<(), IggyError> {
+        let key_id_key = HeaderKey::try_from(ENCRYPTION_KEY_ID_HEADER_KEY)?;
+        let Some(mut user_headers) = self.user_headers_map()? else {
+            return Ok(());
+        };
+        if user_headers.remove(&key_id_key).is_none() {
+            return Ok(());
+        }
+
+        let data = Bytes::from(encryptor.decrypt(&self.payload)?);
+        if data.len() < 4 {
+            return Err(IggyError::CannotDecryptData);
+        }
+        let headers_length = u32::from_le_bytes(data[..4].try_into().unwrap()) as usize;
+        if data.len() < 4 + headers_length {
+            return Err(IggyError::CannotDecryptData);
+        }
+        if headers_length > 0 {
+            let wire = WireUserHeaders::from_bytes(data.slice(4..4 + headers_length))
+                .map_err(|_| IggyError::CannotDecryptData)?;
+            user_headers.extend(user_headers_from_wire(&wire)?);
+        }
+
+        self.payload = data.slice(4 + headers_length..);
+        self.header.payload_length = self.payload.len() as u32;
+        self.set_user_headers(user_headers)
+    }
+
+    /// Returns the ID of the key the message was encrypted with by [`IggyMessage::encrypt`].
+    pub fn encryption_key_id(&self) -> Result<Option<String>, IggyError> {
+        let key_id_key = HeaderKey::try_from(ENCRYPTION_KEY_ID_HEADER_KEY)?;
+        self.get_user_header(&key_id_key)?
+            .map(|value| value.as_str().map(ToOwned::to_owned))
+            .transpose()
+    }
+
+    fn set_user_headers(
+        &mut self,
+        user_headers: BTreeMap<HeaderKey, HeaderValue>,
+    ) -> Result<(), IggyError> {
+        if user_headers.is_empty() {
+            self.user_headers = None;
+            self.header.user_headers_length = 0;
+            return Ok(());
+        }
+
+        let user_headers = user_headers_to_wire(&user_headers).into_bytes();
+        if user_headers.len() > MAX_USER_HEADERS_SIZE as usize {
+            return Err(IggyError::TooBigUserHeaders);
+        }
+
+        self.header.user_headers_length = user_headers.len() as u32;
+        self.user_headers = Some(user_headers);
+        Ok(())
+    }
+
     pub fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::with_capacity(self.get_size_bytes().as_bytes_usize());
         let message_header = self.header.to_bytes();
@@ -845,4 +1069,159 @@ mod tests {
             deserialized_map.get(&HeaderKey::try_from("correlation-id").unwrap())
         );
     }
+
+    #[test]
+    fn given_compressible_payload_compress_and_decompress_should_restore_message() {
+        let mut headers = BTreeMap::new();
+        headers.insert(
+            HeaderKey::try_from("content-type").unwrap(),
+            HeaderValue::try_from("application/json").unwrap(),
+        );
+        let payload = Bytes::from(r#"{"order_id":1,"status":"created"}"#.repeat(50));
+
+        for algorithm in [
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Snappy,
+        ] {
+            let original = IggyMessage::builder()
+                .id(1)
+                .payload(payload.clone())
+                .user_headers(headers.clone())
+                .build()
+                .unwrap();
+            let mut message = IggyMessage::from_bytes(original.to_bytes()).unwrap();
+
+            message.compress(algorithm).unwrap();
+            assert!(message.payload.len() < payload.len());
+            assert_eq!(
+                message.header.payload_length as usize,
+                message.payload.len()
+            );
+            let compression = message
+                .get_user_header(&HeaderKey::try_from(COMPRESSION_HEADER_KEY).unwrap())
+                .unwrap()
+                .unwrap();
+            assert_eq!(compression.as_uint8().unwrap(), algorithm.as_code());
+
+            let mut message = IggyMessage::from_bytes(message.to_bytes()).unwrap();
+            message.decompress().unwrap();
+            assert_eq!(message, original, "{algorithm} round trip failed");
+        }
+    }
+
+    #[test]
+    fn given_message_without_headers_decompress_should_remove_compression_header() {
+        let payload = Bytes::from("compress me ".repeat(50));
+        let mut message = IggyMessage::builder()
+            .payload(payload.clone())
+            .build()
+            .unwrap();
+
+        message.compress(CompressionAlgorithm::Lz4).unwrap();
+        assert!(message.user_headers.is_some());
+
+        message.decompress().unwrap();
+        assert_eq!(message.payload, payload);
+        assert!(message.user_headers.is_none());
+        assert_eq!(message.header.user_headers_length, 0);
+    }
+
+    #[test]
+    fn given_already_compressed_message_compress_should_not_compress_again() {
+        let mut message = IggyMessage::builder()
+            .payload(Bytes::from("compress me ".repeat(50)))
+            .build()
+            .unwrap();
+
+        message.compress(CompressionAlgorithm::Zstd).unwrap();
+        let compressed = message.payload.clone();
+        message.compress(CompressionAlgorithm::Gzip).unwrap();
+        assert_eq!(message.payload, compressed);
+    }
+
+    #[test]
+    fn given_incompressible_payload_compress_should_leave_message_untouched() {
+        let original = IggyMessage::builder()
+            .payload(Bytes::from("tiny"))
+            .build()
+            .unwrap();
+        let mut message = IggyMessage::from_bytes(original.to_bytes()).unwrap();
+
+        message.compress(CompressionAlgorithm::Gzip).unwrap();
+        assert_eq!(message, original);
+    }
+
+    #[test]
+    fn given_uncompressed_message_decompress_should_leave_message_untouched() {
+        let mut headers = BTreeMap::new();
+        headers.insert(
+            HeaderKey::try_from("content-type").unwrap(),
+            HeaderValue::try_from("text/plain").unwrap(),
+        );
+        let original = IggyMessage::builder()
+            .payload(Bytes::from("plain message"))
+            .user_headers(headers)
+            .build()
+            .unwrap();
+        let mut message = IggyMessage::from_bytes(original.to_bytes()).unwrap();
+
+        message.decompress().unwrap();
+        assert_eq!(message, original);
+    }
+
+    #[test]
+    fn given_message_with_headers_encrypt_and_decrypt_should_restore_message() {
+        let encryptor = EncryptorKind::Aes256Gcm(crate::Aes256GcmEncryptor::new(&[7; 32]).unwrap());
+        let mut headers = BTreeMap::new();
+        headers.insert(
+            HeaderKey::try_from("content-type").unwrap(),
+            HeaderValue::try_from("text/plain").unwrap(),
+        );
+        let mut original = IggyMessage::builder()
+            .id(1)
+            .payload(Bytes::from("secret message"))
+            .user_headers(headers)
+            .build()
+            .unwrap();
+        original
+            .set_deliver_at(IggyTimestamp::from(1_000_000))
+            .unwrap();
+        let mut message = IggyMessage::from_bytes(original.to_bytes()).unwrap();
+
+        message.encrypt("key-1", &encryptor).unwrap();
+        let visible_headers = message.user_headers_map().unwrap().unwrap();
+        assert_eq!(visible_headers.len(), 2);
+        assert_eq!(
+            message.deliver_at().unwrap(),
+            Some(IggyTimestamp::from(1_000_000))
+        );
+        assert_eq!(
+            message.encryption_key_id().unwrap().as_deref(),
+            Some("key-1")
+        );
+        assert_eq!(
+            message.header.payload_length as usize,
+            message.payload.len()
+        );
+
+        let mut message = IggyMessage::from_bytes(message.to_bytes()).unwrap();
+        message.decrypt(&encryptor).unwrap();
+        assert_eq!(message, original);
+    }
+
+    #[test]
+    fn given_message_encrypted_with_other_key_decrypt_should_fail() {
+        let encryptor = EncryptorKind::Aes256Gcm(crate::Aes256GcmEncryptor::new(&[7; 32]).unwrap());
+        let other_encryptor =
+            EncryptorKind::Aes256Gcm(crate::Aes256GcmEncryptor::new(&[8; 32]).unwrap());
+        let mut message = IggyMessage::builder()
+            .payload(Bytes::from("secret message"))
+            .build()
+            .unwrap();
+
+        message.encrypt("key-1", &encryptor).unwrap();
+        assert!(message.decrypt(&other_encryptor).is_err());
+    }
 }
@@ -240,6 +240,31 @@ impl IggyIndexesMut {
         self.saved_count = self.count();
     }
 
+    /// Finds the position of the first index whose offset is not lower than the target.
+    ///
+    /// The position equals the relative offset unless the segment was compacted, in which case
+    /// removed messages leave gaps in the offsets and the position is found with a binary search.
+    pub fn position_for_offset(&self, relative_offset: u32) -> Option<u32> {
+        if let Some(index) = self.get(relative_offset)
+            && index.offset() == relative_offset
+        {
+            return Some(relative_offset);
+        }
+
+        let mut low = 0;
+        let mut high = self.count();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.get(mid)?.offset() < relative_offset {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        (low < self.count()).then_some(low)
+    }
+
     /// Slices the container to return a view of a specific range of indexes
     pub fn slice_by_offset(
         &self,
@@ -383,3 +408,57 @@ impl fmt::Debug for IggyIndexesMut {
         write!(f, "}}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IggyByteSize, MemoryPool, MemoryPoolConfigOther};
+
+    fn init_memory_pool() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            let config = MemoryPoolConfigOther {
+                enabled: false,
+                size: IggyByteSize::from(64 * 1024 * 1024u64),
+                bucket_capacity: 256,
+            };
+            MemoryPool::init_pool(&config);
+        });
+    }
+
+    fn create_indexes(offsets: &[u32]) -> IggyIndexesMut {
+        init_memory_pool();
+        let mut indexes = IggyIndexesMut::with_capacity(offsets.len(), 0);
+        for (i, &offset) in offsets.iter().enumerate() {
+            indexes.insert(offset, (i as u32 + 1) * 100, offset as u64);
+        }
+        indexes
+    }
+
+    #[test]
+    fn position_for_offset_should_match_offset_without_gaps() {
+        let indexes = create_indexes(&[0, 1, 2, 3]);
+        for offset in 0..4 {
+            assert_eq!(indexes.position_for_offset(offset), Some(offset));
+        }
+        assert_eq!(indexes.position_for_offset(4), None);
+    }
+
+    #[test]
+    fn position_for_offset_should_skip_compacted_gaps() {
+        let indexes = create_indexes(&[1, 4, 5, 9]);
+        assert_eq!(indexes.position_for_offset(0), Some(0));
+        assert_eq!(indexes.position_for_offset(1), Some(0));
+        assert_eq!(indexes.position_for_offset(2), Some(1));
+        assert_eq!(indexes.position_for_offset(5), Some(2));
+        assert_eq!(indexes.position_for_offset(6), Some(3));
+        assert_eq!(indexes.position_for_offset(9), Some(3));
+        assert_eq!(indexes.position_for_offset(10), None);
+    }
+
+    #[test]
+    fn position_for_offset_should_return_none_for_empty_indexes() {
+        let indexes = create_indexes(&[]);
+        assert_eq!(indexes.position_for_offset(0), None);
+    }
+}
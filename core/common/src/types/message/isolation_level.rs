@@ -0,0 +1,73 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `IsolationLevel` controls the visibility of transactional messages when polling and is used by `PollingStrategy`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum IsolationLevel {
+    #[default]
+    /// Return all messages, including the ones written by open or aborted transactions.
+    ReadUncommitted,
+    /// Return only the messages written outside of transactions or by committed transactions.
+    /// Polling stops before the first message of any transaction that is still open.
+    ReadCommitted,
+}
+
+impl IsolationLevel {
+    /// Returns code of the isolation level.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            IsolationLevel::ReadUncommitted => 0,
+            IsolationLevel::ReadCommitted => 1,
+        }
+    }
+
+    /// Returns isolation level from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            0 => Ok(IsolationLevel::ReadUncommitted),
+            1 => Ok(IsolationLevel::ReadCommitted),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for IsolationLevel {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "ru" | "read_uncommitted" => Ok(IsolationLevel::ReadUncommitted),
+            "rc" | "read_committed" => Ok(IsolationLevel::ReadCommitted),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for IsolationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsolationLevel::ReadUncommitted => write!(f, "read_uncommitted"),
+            IsolationLevel::ReadCommitted => write!(f, "read_committed"),
+        }
+    }
+}
@@ -0,0 +1,569 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::iggy_error::IggyError;
+use crate::types::message::user_headers::{HeaderKey, HeaderKind, HeaderValue, UserHeaders};
+use crate::wire_conversions::user_headers_from_wire;
+use iggy_binary_protocol::WireUserHeaders;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The maximum length of the filter expression, in bytes.
+pub const MAX_MESSAGE_FILTER_LENGTH: usize = 4096;
+
+const MAX_NESTING_DEPTH: usize = 32;
+
+/// `MessageFilter` is an expression over the user headers, evaluated by the server when polling
+/// the messages, so only the matching ones are returned to the consumer.
+///
+/// The expression compares the header values with the literals of the following kinds:
+/// - `String` - double-quoted text, e.g. `tenant == "acme"`, with `\"` and `\\` escapes.
+/// - `Int64` - integer, e.g. `priority >= 5`, compared with the headers of any integer kind.
+/// - `Float64` - decimal number, e.g. `score < 0.5`, compared with the headers of any numeric kind.
+/// - `Bool` - `true` or `false`, e.g. `urgent == true`.
+///
+/// The supported operators are `==`, `!=`, `<`, `<=`, `>` and `>=`, while the header key alone
+/// checks if the header exists. The conditions are combined with `&&`, `||`, `!` and parentheses.
+/// A condition on the missing header or the header of a different kind never matches.
+///
+/// # Examples
+///
+/// ```
+/// use iggy_common::MessageFilter;
+/// use std::str::FromStr;
+///
+/// let filter = MessageFilter::from_str(r#"tenant == "acme" && (priority >= 5 || urgent)"#).unwrap();
+/// assert_eq!(filter.to_string(), r#"tenant == "acme" && (priority >= 5 || urgent)"#);
+/// ```
+#[derive(Debug, Clone, PartialEq, SerializeDisplay, DeserializeFromStr)]
+pub enum MessageFilter {
+    /// Matches the messages having the header with the given key.
+    Exists(HeaderKey),
+    /// Matches the messages having the header with the given key and the value satisfying the comparison.
+    Compare {
+        key: HeaderKey,
+        operator: FilterOperator,
+        value: HeaderValue,
+    },
+    /// Matches the messages not matching the inner filter.
+    Not(Box<MessageFilter>),
+    /// Matches the messages matching both filters.
+    And(Box<MessageFilter>, Box<MessageFilter>),
+    /// Matches the messages matching any of the filters.
+    Or(Box<MessageFilter>, Box<MessageFilter>),
+}
+
+/// The comparison operator of the [`MessageFilter`] condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl FilterOperator {
+    fn matches(&self, ordering: Ordering) -> bool {
+        match self {
+            FilterOperator::Equal => ordering.is_eq(),
+            FilterOperator::NotEqual => ordering.is_ne(),
+            FilterOperator::Less => ordering.is_lt(),
+            FilterOperator::LessOrEqual => ordering.is_le(),
+            FilterOperator::Greater => ordering.is_gt(),
+            FilterOperator::GreaterOrEqual => ordering.is_ge(),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            FilterOperator::Equal => "==",
+            FilterOperator::NotEqual => "!=",
+            FilterOperator::Less => "<",
+            FilterOperator::LessOrEqual => "<=",
+            FilterOperator::Greater => ">",
+            FilterOperator::GreaterOrEqual => ">=",
+        }
+    }
+}
+
+impl MessageFilter {
+    /// Creates the filter matching the messages having the header with the given key.
+    pub fn exists(key: &str) -> Result<Self, IggyError> {
+        Ok(MessageFilter::Exists(HeaderKey::from_str(key)?))
+    }
+
+    /// Creates the filter comparing the header with the given key to the value,
+    /// which must be of the `String`, `Int64`, `Float64` or `Bool` kind.
+    pub fn compare(
+        key: &str,
+        operator: FilterOperator,
+        value: impl Into<HeaderValue>,
+    ) -> Result<Self, IggyError> {
+        let value = value.into();
+        if !matches!(
+            value.kind(),
+            HeaderKind::String | HeaderKind::Int64 | HeaderKind::Float64 | HeaderKind::Bool
+        ) {
+            return Err(IggyError::InvalidMessageFilter(format!(
+                "unsupported value kind: {}",
+                value.kind()
+            )));
+        }
+        Ok(MessageFilter::Compare {
+            key: HeaderKey::from_str(key)?,
+            operator,
+            value,
+        })
+    }
+
+    /// Combines the filters, so both must match.
+    pub fn and(self, other: MessageFilter) -> Self {
+        MessageFilter::And(Box::new(self), Box::new(other))
+    }
+
+    /// Combines the filters, so any of them must match.
+    pub fn or(self, other: MessageFilter) -> Self {
+        MessageFilter::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Negates the filter.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        MessageFilter::Not(Box::new(self))
+    }
+
+    /// Returns `true` if the message with the given user headers matches the filter.
+    pub fn matches(&self, headers: Option<&UserHeaders>) -> bool {
+        match self {
+            MessageFilter::Exists(key) => headers.is_some_and(|headers| headers.contains_key(key)),
+            MessageFilter::Compare {
+                key,
+                operator,
+                value,
+            } => headers
+                .and_then(|headers| headers.get(key))
+                .and_then(|header| compare(header, value))
+                .is_some_and(|ordering| operator.matches(ordering)),
+            MessageFilter::Not(filter) => !filter.matches(headers),
+            MessageFilter::And(left, right) => left.matches(headers) && right.matches(headers),
+            MessageFilter::Or(left, right) => left.matches(headers) || right.matches(headers),
+        }
+    }
+
+    /// Returns `true` if the message with the given serialized user headers matches the filter.
+    /// The headers which can't be parsed are treated as missing.
+    pub fn matches_raw(&self, user_headers: Option<&[u8]>) -> bool {
+        let headers = user_headers
+            .and_then(|bytes| WireUserHeaders::from_slice(bytes).ok())
+            .and_then(|wire| user_headers_from_wire(&wire).ok());
+        self.matches(headers.as_ref())
+    }
+}
+
+enum Number {
+    Integer(i128),
+    Float(f64),
+}
+
+fn as_number(value: &HeaderValue) -> Option<Number> {
+    let number = match value.kind() {
+        HeaderKind::Int8 => Number::Integer(value.as_int8().ok()?.into()),
+        HeaderKind::Int16 => Number::Integer(value.as_int16().ok()?.into()),
+        HeaderKind::Int32 => Number::Integer(value.as_int32().ok()?.into()),
+        HeaderKind::Int64 => Number::Integer(value.as_int64().ok()?.into()),
+        HeaderKind::Int128 => Number::Integer(value.as_int128().ok()?),
+        HeaderKind::Uint8 => Number::Integer(value.as_uint8().ok()?.into()),
+        HeaderKind::Uint16 => Number::Integer(value.as_uint16().ok()?.into()),
+        HeaderKind::Uint32 => Number::Integer(value.as_uint32().ok()?.into()),
+        HeaderKind::Uint64 => Number::Integer(value.as_uint64().ok()?.into()),
+        HeaderKind::Uint128 => {
+            let value = value.as_uint128().ok()?;
+            i128::try_from(value).map_or(Number::Float(value as f64), Number::Integer)
+        }
+        HeaderKind::Float32 => Number::Float(value.as_float32().ok()?.into()),
+        HeaderKind::Float64 => Number::Float(value.as_float64().ok()?),
+        _ => return None,
+    };
+    Some(number)
+}
+
+fn compare(header: &HeaderValue, literal: &HeaderValue) -> Option<Ordering> {
+    match literal.kind() {
+        HeaderKind::String if header.kind() == HeaderKind::String => {
+            Some(header.as_str().ok()?.cmp(literal.as_str().ok()?))
+        }
+        HeaderKind::Bool if header.kind() == HeaderKind::Bool => {
+            Some(header.as_bool().ok()?.cmp(&literal.as_bool().ok()?))
+        }
+        HeaderKind::Int64 | HeaderKind::Float64 => {
+            match (as_number(header)?, as_number(literal)?) {
+                (Number::Integer(header), Number::Integer(literal)) => Some(header.cmp(&literal)),
+                (Number::Integer(header), Number::Float(literal)) => {
+                    (header as f64).partial_cmp(&literal)
+                }
+                (Number::Float(header), Number::Integer(literal)) => {
+                    header.partial_cmp(&(literal as f64))
+                }
+                (Number::Float(header), Number::Float(literal)) => header.partial_cmp(&literal),
+            }
+        }
+        _ => None,
+    }
+}
+
+impl Display for MessageFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessageFilter::Exists(key) => write!(f, "{}", key.to_string_value()),
+            MessageFilter::Compare {
+                key,
+                operator,
+                value,
+            } => {
+                write!(f, "{} {} ", key.to_string_value(), operator.as_str())?;
+                match value.kind() {
+                    HeaderKind::String => {
+                        let text = value.as_str().unwrap_or_default();
+                        write!(f, "\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+                    }
+                    HeaderKind::Float64 => {
+                        write!(f, "{:?}", value.as_float64().unwrap_or_default())
+                    }
+                    _ => write!(f, "{}", value.to_string_value()),
+                }
+            }
+            MessageFilter::Not(filter) => match filter.as_ref() {
+                MessageFilter::Exists(_) | MessageFilter::Not(_) => write!(f, "!{filter}"),
+                _ => write!(f, "!({filter})"),
+            },
+            MessageFilter::And(left, right) => {
+                write_operand(f, left, false)?;
+                write!(f, " && ")?;
+                write_operand(f, right, false)
+            }
+            MessageFilter::Or(left, right) => {
+                write_operand(f, left, true)?;
+                write!(f, " || ")?;
+                write_operand(f, right, true)
+            }
+        }
+    }
+}
+
+fn write_operand(f: &mut Formatter<'_>, operand: &MessageFilter, in_or: bool) -> std::fmt::Result {
+    match operand {
+        MessageFilter::Or(_, _) => write!(f, "({operand})"),
+        MessageFilter::And(_, _) if !in_or => write!(f, "({operand})"),
+        _ => write!(f, "{operand}"),
+    }
+}
+
+impl FromStr for MessageFilter {
+    type Err = IggyError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.len() > MAX_MESSAGE_FILTER_LENGTH {
+            return Err(IggyError::InvalidMessageFilter(format!(
+                "expression is longer than {MAX_MESSAGE_FILTER_LENGTH} bytes"
+            )));
+        }
+
+        let mut parser = Parser {
+            input,
+            position: 0,
+            depth: 0,
+        };
+        let filter = parser.parse_or()?;
+        parser.skip_whitespace();
+        if parser.position < input.len() {
+            return Err(parser.error("unexpected input"));
+        }
+        Ok(filter)
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn parse_or(&mut self) -> Result<MessageFilter, IggyError> {
+        let mut filter = self.parse_and()?;
+        while self.consume("||") {
+            filter = filter.or(self.parse_and()?);
+        }
+        Ok(filter)
+    }
+
+    fn parse_and(&mut self) -> Result<MessageFilter, IggyError> {
+        let mut filter = self.parse_unary()?;
+        while self.consume("&&") {
+            filter = filter.and(self.parse_unary()?);
+        }
+        Ok(filter)
+    }
+
+    fn parse_unary(&mut self) -> Result<MessageFilter, IggyError> {
+        self.depth += 1;
+        if self.depth > MAX_NESTING_DEPTH {
+            return Err(self.error("expression is nested too deeply"));
+        }
+
+        let filter = if self.consume("!") {
+            self.parse_unary()?.not()
+        } else if self.consume("(") {
+            let filter = self.parse_or()?;
+            if !self.consume(")") {
+                return Err(self.error("expected ')'"));
+            }
+            filter
+        } else {
+            self.parse_condition()?
+        };
+        self.depth -= 1;
+        Ok(filter)
+    }
+
+    fn parse_condition(&mut self) -> Result<MessageFilter, IggyError> {
+        self.skip_whitespace();
+        let rest = &self.input[self.position..];
+        let length = rest
+            .find(|c: char| c.is_whitespace() || "=!<>()&|\"".contains(c))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error("expected header key"));
+        }
+        let key =
+            HeaderKey::from_str(&rest[..length]).map_err(|_| self.error("invalid header key"))?;
+        self.position += length;
+
+        let operator = if self.consume("==") {
+            FilterOperator::Equal
+        } else if self.consume("!=") {
+            FilterOperator::NotEqual
+        } else if self.consume("<=") {
+            FilterOperator::LessOrEqual
+        } else if self.consume(">=") {
+            FilterOperator::GreaterOrEqual
+        } else if self.consume("<") {
+            FilterOperator::Less
+        } else if self.consume(">") {
+            FilterOperator::Greater
+        } else {
+            return Ok(MessageFilter::Exists(key));
+        };
+
+        let value = self.parse_value()?;
+        Ok(MessageFilter::Compare {
+            key,
+            operator,
+            value,
+        })
+    }
+
+    fn parse_value(&mut self) -> Result<HeaderValue, IggyError> {
+        self.skip_whitespace();
+        let rest = &self.input[self.position..];
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let mut text = String::new();
+            let mut chars = quoted.char_indices();
+            while let Some((index, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        self.position += index + 2;
+                        return HeaderValue::try_from(text.as_str())
+                            .map_err(|_| self.error("invalid string value"));
+                    }
+                    '\\' => match chars.next() {
+                        Some((_, escaped @ ('"' | '\\'))) => text.push(escaped),
+                        _ => return Err(self.error("invalid escape sequence")),
+                    },
+                    _ => text.push(c),
+                }
+            }
+            return Err(self.error("unterminated string"));
+        }
+
+        let length = rest
+            .find(|c: char| c.is_whitespace() || "()&|".contains(c))
+            .unwrap_or(rest.len());
+        let literal = &rest[..length];
+        let value = match literal {
+            "true" => HeaderValue::from(true),
+            "false" => HeaderValue::from(false),
+            _ if literal.contains(['.', 'e', 'E']) => literal
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(HeaderValue::from)
+                .ok_or_else(|| self.error("invalid value"))?,
+            _ => literal
+                .parse::<i64>()
+                .map(HeaderValue::from)
+                .map_err(|_| self.error("invalid value"))?,
+        };
+        self.position += length;
+        Ok(value)
+    }
+
+    fn consume(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.input[self.position..].starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn error(&self, reason: &str) -> IggyError {
+        IggyError::InvalidMessageFilter(format!("{reason} at position {}", self.position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn headers() -> UserHeaders {
+        BTreeMap::from([
+            (
+                HeaderKey::from_str("tenant").unwrap(),
+                HeaderValue::from_str("acme").unwrap(),
+            ),
+            (
+                HeaderKey::from_str("priority").unwrap(),
+                HeaderValue::from(7u8),
+            ),
+            (
+                HeaderKey::from_str("score").unwrap(),
+                HeaderValue::from(0.25f32),
+            ),
+            (
+                HeaderKey::from_str("urgent").unwrap(),
+                HeaderValue::from(false),
+            ),
+        ])
+    }
+
+    fn matches(expression: &str) -> bool {
+        MessageFilter::from_str(expression)
+            .unwrap()
+            .matches(Some(&headers()))
+    }
+
+    #[test]
+    fn should_compare_typed_headers() {
+        assert!(matches(r#"tenant == "acme""#));
+        assert!(!matches(r#"tenant != "acme""#));
+        assert!(matches(r#"tenant < "acne""#));
+        assert!(matches("priority > 5"));
+        assert!(matches("priority <= 7"));
+        assert!(!matches("priority < -1"));
+        assert!(matches("score < 0.5"));
+        assert!(matches("score >= 0"));
+        assert!(matches("urgent == false"));
+        assert!(!matches("urgent != false"));
+    }
+
+    #[test]
+    fn should_not_match_missing_headers_or_different_kinds() {
+        assert!(!matches(r#"region == "eu""#));
+        assert!(matches(r#"!(region == "eu")"#));
+        assert!(!matches(r#"priority == "7""#));
+        assert!(!matches("tenant == 1"));
+        assert!(!matches("urgent == 0"));
+        assert!(!MessageFilter::from_str("tenant").unwrap().matches(None));
+    }
+
+    #[test]
+    fn should_combine_conditions() {
+        assert!(matches(r#"tenant == "acme" && priority >= 5"#));
+        assert!(!matches(r#"tenant == "acme" && urgent == true"#));
+        assert!(matches(r#"urgent == true || tenant == "acme""#));
+        assert!(matches("!region && (urgent == true || priority > 5)"));
+        assert!(!matches("!(tenant && priority)"));
+    }
+
+    #[test]
+    fn should_format_parsed_expression() {
+        for expression in [
+            r#"tenant == "acme" && (priority >= 5 || urgent)"#,
+            r#"a == 1 || b == 2 && c == 3"#,
+            r#"(a || b) && !(c && d)"#,
+            r#"!!a && name == "quoted \" and \\ chars""#,
+            "score < 0.5 && delta > -1.0",
+        ] {
+            let filter = MessageFilter::from_str(expression).unwrap();
+            assert_eq!(filter.to_string(), expression);
+            assert_eq!(
+                MessageFilter::from_str(&filter.to_string()).unwrap(),
+                filter
+            );
+        }
+        assert_eq!(
+            MessageFilter::from_str("(a==1)&&( b>2 )")
+                .unwrap()
+                .to_string(),
+            "a == 1 && b > 2"
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_expressions() {
+        for expression in [
+            "",
+            "tenant ==",
+            r#"tenant == "acme"#,
+            "tenant == acme",
+            "(tenant",
+            "tenant) ",
+            "a && || b",
+            "priority > 1.5.2",
+            r#"name == "\n""#,
+        ] {
+            assert!(
+                MessageFilter::from_str(expression).is_err(),
+                "expression: {expression}"
+            );
+        }
+        let nested = format!("{}a{}", "(".repeat(64), ")".repeat(64));
+        assert!(MessageFilter::from_str(&nested).is_err());
+    }
+
+    #[test]
+    fn should_match_serialized_headers() {
+        let filter = MessageFilter::from_str("priority == 7").unwrap();
+        let wire = crate::wire_conversions::user_headers_to_wire(&headers());
+        assert!(filter.matches_raw(Some(&wire.into_bytes())));
+        assert!(!filter.matches_raw(Some(b"invalid")));
+        assert!(!filter.matches_raw(None));
+    }
+}
@@ -436,9 +436,12 @@ impl IggyMessagesBatchMut {
     /// Validates that all messages have correct checksums and offsets.
     /// This function should be called after messages have been read from disk.
     ///
+    /// Offsets must be strictly increasing and not lower than the requested start offset.
+    /// They don't have to be contiguous, as log compaction removes superseded messages.
+    ///
     /// # Arguments
     ///
-    /// * `absolute_start_offset` - The absolute offset of the first message in the batch.
+    /// * `absolute_start_offset` - The absolute offset requested for the first message in the batch.
     ///
     /// # Returns
     ///
@@ -448,11 +451,12 @@ impl IggyMessagesBatchMut {
         &self,
         absolute_start_offset: u64,
     ) -> Result<(), IggyError> {
-        for (current_offset, message) in (absolute_start_offset..).zip(self.iter()) {
+        let mut min_offset = absolute_start_offset;
+        for message in self.iter() {
             let calculated_checksum = message.calculate_checksum();
             let actual_checksum = message.header().checksum();
             let offset = message.header().offset();
-            if offset != current_offset {
+            if offset < min_offset {
                 return Err(IggyError::InvalidOffset(offset));
             }
             if calculated_checksum != actual_checksum {
@@ -462,6 +466,7 @@ impl IggyMessagesBatchMut {
                     offset,
                 ));
             }
+            min_offset = offset + 1;
         }
         Ok(())
     }
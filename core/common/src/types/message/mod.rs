@@ -22,7 +22,9 @@ mod index;
 mod index_view;
 mod indexes;
 mod indexes_mut;
+pub mod isolation_level;
 mod message_boundaries;
+mod message_filter;
 mod message_header;
 mod message_header_view;
 mod message_header_view_mut;
@@ -43,12 +45,17 @@ pub const INDEX_SIZE: usize = 16;
 
 pub use crate::http::messages::poll_messages::PollMessages;
 pub use crate::http::messages::send_messages::SendMessages;
-pub use iggy_message::{IggyMessage, MAX_PAYLOAD_SIZE, MAX_USER_HEADERS_SIZE};
+pub use iggy_message::{
+    DELIVER_AT_HEADER_KEY, ENCRYPTION_KEY_ID_HEADER_KEY, IggyMessage, MAX_PAYLOAD_SIZE,
+    MAX_USER_HEADERS_SIZE,
+};
 pub use in_flight::IggyMessagesBatchSetInFlight;
 pub use index::IggyIndex;
 pub use index_view::IggyIndexView;
 pub use indexes::IggyIndexes;
 pub use indexes_mut::IggyIndexesMut;
+pub use isolation_level::IsolationLevel;
+pub use message_filter::{FilterOperator, MAX_MESSAGE_FILTER_LENGTH, MessageFilter};
 pub use message_header::{
     IGGY_MESSAGE_CHECKSUM_OFFSET_RANGE, IGGY_MESSAGE_HEADER_RANGE, IGGY_MESSAGE_HEADER_SIZE,
     IGGY_MESSAGE_HEADERS_LENGTH_OFFSET_RANGE, IGGY_MESSAGE_ID_OFFSET_RANGE,
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::types::message::isolation_level::IsolationLevel;
 use crate::types::message::polling_kind::PollingKind;
 use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
@@ -43,6 +44,10 @@ pub struct PollingStrategy {
     #[serde_as(as = "DisplayFromStr")]
     #[serde(default = "PollingStrategy::default_value")]
     pub value: u64,
+    /// Visibility of transactional messages, defaults to `ReadUncommitted`.
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub isolation_level: IsolationLevel,
 }
 
 impl Default for PollingStrategy {
@@ -50,6 +55,7 @@ impl Default for PollingStrategy {
         Self {
             kind: PollingKind::Offset,
             value: 0,
+            isolation_level: IsolationLevel::ReadUncommitted,
         }
     }
 }
@@ -66,6 +72,7 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::Offset,
             value,
+            isolation_level: IsolationLevel::ReadUncommitted,
         }
     }
 
@@ -74,6 +81,7 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::Timestamp,
             value: value.into(),
+            isolation_level: IsolationLevel::ReadUncommitted,
         }
     }
 
@@ -82,6 +90,7 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::First,
             value: 0,
+            isolation_level: IsolationLevel::ReadUncommitted,
         }
     }
 
@@ -90,6 +99,7 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::Last,
             value: 0,
+            isolation_level: IsolationLevel::ReadUncommitted,
         }
     }
 
@@ -98,9 +108,21 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::Next,
             value: 0,
+            isolation_level: IsolationLevel::ReadUncommitted,
         }
     }
 
+    /// Poll only the messages which are not part of an open or aborted transaction.
+    pub fn read_committed(self) -> Self {
+        self.isolation_level(IsolationLevel::ReadCommitted)
+    }
+
+    /// Set the isolation level of the polling strategy.
+    pub fn isolation_level(mut self, isolation_level: IsolationLevel) -> Self {
+        self.isolation_level = isolation_level;
+        self
+    }
+
     /// Change the value of the polling strategy, affects only `Offset` and `Timestamp` kinds.
     pub fn set_value(&mut self, value: u64) {
         if self.kind == PollingKind::Offset || self.kind == PollingKind::Timestamp {
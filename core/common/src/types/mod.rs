@@ -16,6 +16,7 @@
 // under the License.
 
 pub(crate) mod args;
+pub(crate) mod audit;
 pub(crate) mod client;
 pub(crate) mod client_state;
 pub(crate) mod cluster;
@@ -29,6 +30,8 @@ pub(crate) mod message;
 pub(crate) mod partition;
 pub(crate) mod permissions;
 pub(crate) mod personal_access_tokens;
+pub(crate) mod producer;
+pub(crate) mod role;
 pub(crate) mod segment;
 pub(crate) mod segment_storage;
 pub mod send_messages2;
@@ -37,4 +40,5 @@ pub(crate) mod stats;
 pub(crate) mod stream;
 pub(crate) mod streaming_stats;
 pub(crate) mod topic;
+pub(crate) mod transaction;
 pub(crate) mod user;
@@ -16,16 +16,20 @@
  * under the License.
  */
 
+use crate::IggyError;
+use crate::http::users::defaults::MAX_PERMISSION_PATTERN_LENGTH;
 use comfy_table::Table;
 use comfy_table::presets::ASCII_NO_BORDERS;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
 use std::fmt::Display;
 
 /// `Permissions` is used to define the permissions of a user.
-/// It consists of global permissions and stream permissions.
+/// It consists of global permissions, stream permissions and stream pattern permissions.
 /// Global permissions are applied to all streams.
 /// Stream permissions are applied to a specific stream.
+/// Stream pattern permissions are applied to all the streams with a matching name.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct Permissions {
     /// Global permissions are applied to all streams.
@@ -33,6 +37,11 @@ pub struct Permissions {
 
     /// Stream permissions are applied to a specific stream.
     pub streams: Option<BTreeMap<usize, StreamPermissions>>,
+
+    /// Stream pattern permissions are applied to all the streams whose names match the pattern,
+    /// including the streams created after the permissions were granted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_patterns: Option<Vec<StreamPatternPermissions>>,
 }
 
 /// `GlobalPermissions` are applied to all streams without a need to specify them one by one in the `streams` field.
@@ -156,6 +165,83 @@ pub struct TopicPermissions {
 
     /// `send_messages` permission allows to send messages to the topic.
     pub send_messages: bool,
+
+    /// The `consumer_groups` field allows to restrict the consumer group operations on the topic
+    /// to the specific consumer groups. When at least one consumer group is defined, joining a group,
+    /// committing or deleting its offsets and deleting the group are only allowed for the listed
+    /// groups with the corresponding permission, unless the user can manage the topic.
+    /// Otherwise, these operations are governed by the permissions above.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consumer_groups: Option<BTreeMap<usize, ConsumerGroupPermissions>>,
+}
+
+/// `ConsumerGroupPermissions` are applied to a specific consumer group of a topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct ConsumerGroupPermissions {
+    /// `join_group` permission allows to join the consumer group.
+    pub join_group: bool,
+
+    /// `commit_offsets` permission allows to store the offsets of the consumer group.
+    pub commit_offsets: bool,
+
+    /// `delete_offsets` permission allows to delete the offsets of the consumer group.
+    pub delete_offsets: bool,
+
+    /// `manage_group` permission allows to delete the consumer group and includes all the other permissions.
+    pub manage_group: bool,
+}
+
+/// `StreamPatternPermissions` are applied to every stream whose name matches the `pattern`, and they
+/// extend the permissions granted by the ID-based rules in the same way as `StreamPermissions` do.
+///
+/// The pattern is a glob, where `*` matches any sequence of characters and `?` matches a single
+/// character, e.g. `orders-*` matches all the streams with the `orders-` prefix.
+/// A pattern without the wildcards matches the stream with exactly the same name.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct StreamPatternPermissions {
+    /// The glob matched against the stream names.
+    pub pattern: String,
+
+    /// `manage_stream` permission for the matching streams, see `StreamPermissions::manage_stream`.
+    pub manage_stream: bool,
+
+    /// `read_stream` permission for the matching streams, see `StreamPermissions::read_stream`.
+    pub read_stream: bool,
+
+    /// `manage_topics` permission for the matching streams, see `StreamPermissions::manage_topics`.
+    pub manage_topics: bool,
+
+    /// `read_topics` permission for the matching streams, see `StreamPermissions::read_topics`.
+    pub read_topics: bool,
+
+    /// `poll_messages` permission allows to poll messages from the matching streams and their topics.
+    pub poll_messages: bool,
+
+    /// `send_messages` permission allows to send messages to the matching streams and their topics.
+    pub send_messages: bool,
+
+    /// The `topics` field allows to define the permissions for the topics of the matching streams by the topic name.
+    pub topics: Option<Vec<TopicPatternPermissions>>,
+}
+
+/// `TopicPatternPermissions` are applied to every topic whose name matches the `pattern`,
+/// within the streams matching the parent `StreamPatternPermissions`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct TopicPatternPermissions {
+    /// The glob matched against the topic names.
+    pub pattern: String,
+
+    /// `manage_topic` permission for the matching topics, see `TopicPermissions::manage_topic`.
+    pub manage_topic: bool,
+
+    /// `read_topic` permission for the matching topics, see `TopicPermissions::read_topic`.
+    pub read_topic: bool,
+
+    /// `poll_messages` permission allows to poll messages from the matching topics.
+    pub poll_messages: bool,
+
+    /// `send_messages` permission allows to send messages to the matching topics.
+    pub send_messages: bool,
 }
 
 impl Permissions {
@@ -174,7 +260,209 @@ impl Permissions {
                 send_messages: true,
             },
             streams: None,
+            stream_patterns: None,
+        }
+    }
+
+    /// Extends the permissions with the ones granted by `other`, so that the result allows
+    /// everything that is allowed by either of them.
+    pub fn merge(&mut self, other: &Permissions) {
+        self.global.merge(&other.global);
+        if let Some(other_patterns) = &other.stream_patterns {
+            self.stream_patterns
+                .get_or_insert_with(Vec::new)
+                .extend(other_patterns.iter().cloned());
+        }
+
+        let Some(other_streams) = &other.streams else {
+            return;
+        };
+
+        let streams = self.streams.get_or_insert_with(BTreeMap::new);
+        for (stream_id, other_stream) in other_streams {
+            streams.entry(*stream_id).or_default().merge(other_stream);
+        }
+    }
+
+    /// Returns `true` if any of the stream pattern permissions is defined.
+    pub fn has_patterns(&self) -> bool {
+        self.stream_patterns
+            .as_ref()
+            .is_some_and(|patterns| !patterns.is_empty())
+    }
+
+    /// Validates that all the patterns are non-empty and at most `MAX_PERMISSION_PATTERN_LENGTH` bytes long.
+    pub fn validate_patterns(&self) -> Result<(), IggyError> {
+        let Some(stream_patterns) = &self.stream_patterns else {
+            return Ok(());
+        };
+
+        let is_valid =
+            |pattern: &str| !pattern.is_empty() && pattern.len() <= MAX_PERMISSION_PATTERN_LENGTH;
+        for stream_pattern in stream_patterns {
+            if !is_valid(&stream_pattern.pattern) {
+                return Err(IggyError::InvalidPermissionPattern);
+            }
+
+            if let Some(topic_patterns) = &stream_pattern.topics
+                && topic_patterns.iter().any(|topic| !is_valid(&topic.pattern))
+            {
+                return Err(IggyError::InvalidPermissionPattern);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StreamPatternPermissions {
+    /// Returns `true` if the stream name matches the pattern.
+    pub fn matches(&self, stream_name: &str) -> bool {
+        glob_matches(&self.pattern, stream_name)
+    }
+
+    /// Returns the stream-level part of the permissions, to be applied to a single matching stream.
+    pub fn to_stream_permissions(&self) -> StreamPermissions {
+        StreamPermissions {
+            manage_stream: self.manage_stream,
+            read_stream: self.read_stream,
+            manage_topics: self.manage_topics,
+            read_topics: self.read_topics,
+            poll_messages: self.poll_messages,
+            send_messages: self.send_messages,
+            topics: None,
+        }
+    }
+}
+
+impl TopicPatternPermissions {
+    /// Returns `true` if the topic name matches the pattern.
+    pub fn matches(&self, topic_name: &str) -> bool {
+        glob_matches(&self.pattern, topic_name)
+    }
+
+    /// Returns the permissions to be applied to a single matching topic.
+    pub fn to_topic_permissions(&self) -> TopicPermissions {
+        TopicPermissions {
+            manage_topic: self.manage_topic,
+            read_topic: self.read_topic,
+            poll_messages: self.poll_messages,
+            send_messages: self.send_messages,
+            consumer_groups: None,
+        }
+    }
+}
+
+/// Matches the value against the glob, where `*` matches any sequence of characters (including
+/// an empty one) and `?` matches exactly one character. Backtracks only to the last `*`.
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+    let (mut p, mut v) = (0, 0);
+    let mut last_star: Option<(usize, usize)> = None;
+    while v < value.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == value[v]) {
+            p += 1;
+            v += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            last_star = Some((p, v));
+            p += 1;
+        } else if let Some((star_p, star_v)) = last_star {
+            p = star_p + 1;
+            v = star_v + 1;
+            last_star = Some((star_p, star_v + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+impl GlobalPermissions {
+    /// Extends the permissions with the ones granted by `other`.
+    pub fn merge(&mut self, other: &GlobalPermissions) {
+        self.manage_servers |= other.manage_servers;
+        self.read_servers |= other.read_servers;
+        self.manage_users |= other.manage_users;
+        self.read_users |= other.read_users;
+        self.manage_streams |= other.manage_streams;
+        self.read_streams |= other.read_streams;
+        self.manage_topics |= other.manage_topics;
+        self.read_topics |= other.read_topics;
+        self.poll_messages |= other.poll_messages;
+        self.send_messages |= other.send_messages;
+    }
+}
+
+impl StreamPermissions {
+    /// Extends the permissions with the ones granted by `other`, including the topic permissions.
+    pub fn merge(&mut self, other: &StreamPermissions) {
+        self.manage_stream |= other.manage_stream;
+        self.read_stream |= other.read_stream;
+        self.manage_topics |= other.manage_topics;
+        self.read_topics |= other.read_topics;
+        self.poll_messages |= other.poll_messages;
+        self.send_messages |= other.send_messages;
+        let Some(other_topics) = &other.topics else {
+            return;
+        };
+
+        let topics = self.topics.get_or_insert_with(BTreeMap::new);
+        for (topic_id, other_topic) in other_topics {
+            match topics.entry(*topic_id) {
+                Entry::Vacant(entry) => {
+                    entry.insert(other_topic.clone());
+                }
+                Entry::Occupied(mut entry) => entry.get_mut().merge(other_topic),
+            }
+        }
+    }
+}
+
+impl TopicPermissions {
+    /// Extends the permissions with the ones granted by `other`.
+    /// The consumer groups remain restricted only if both of the permissions restrict them.
+    pub fn merge(&mut self, other: &TopicPermissions) {
+        self.manage_topic |= other.manage_topic;
+        self.read_topic |= other.read_topic;
+        self.poll_messages |= other.poll_messages;
+        self.send_messages |= other.send_messages;
+        if !other.restricts_consumer_groups() {
+            self.consumer_groups = None;
+            return;
         }
+
+        if !self.restricts_consumer_groups() {
+            return;
+        }
+
+        let Some(consumer_groups) = self.consumer_groups.as_mut() else {
+            return;
+        };
+
+        for (group_id, other_group) in other.consumer_groups.iter().flatten() {
+            consumer_groups
+                .entry(*group_id)
+                .or_default()
+                .merge(other_group);
+        }
+    }
+
+    /// Returns `true` if the consumer group operations on the topic are restricted to the listed groups.
+    pub fn restricts_consumer_groups(&self) -> bool {
+        self.consumer_groups
+            .as_ref()
+            .is_some_and(|groups| !groups.is_empty())
+    }
+}
+
+impl ConsumerGroupPermissions {
+    /// Extends the permissions with the ones granted by `other`.
+    pub fn merge(&mut self, other: &ConsumerGroupPermissions) {
+        self.join_group |= other.join_group;
+        self.commit_offsets |= other.commit_offsets;
+        self.delete_offsets |= other.delete_offsets;
+        self.manage_group |= other.manage_group;
     }
 }
 
@@ -207,6 +495,41 @@ impl Display for Permissions {
                         result.push_str(&format!("read_topic: {}\n", topic.read_topic));
                         result.push_str(&format!("poll_messages: {}\n", topic.poll_messages));
                         result.push_str(&format!("send_messages: {}\n", topic.send_messages));
+                        if let Some(consumer_groups) = &topic.consumer_groups {
+                            for (group_id, group) in consumer_groups {
+                                result.push_str(&format!("consumer_group_id: {group_id}\n"));
+                                result.push_str(&format!("join_group: {}\n", group.join_group));
+                                result.push_str(&format!(
+                                    "commit_offsets: {}\n",
+                                    group.commit_offsets
+                                ));
+                                result.push_str(&format!(
+                                    "delete_offsets: {}\n",
+                                    group.delete_offsets
+                                ));
+                                result.push_str(&format!("manage_group: {}\n", group.manage_group));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(stream_patterns) = &self.stream_patterns {
+            for stream in stream_patterns {
+                result.push_str(&format!("stream_pattern: {}\n", stream.pattern));
+                result.push_str(&format!("manage_stream: {}\n", stream.manage_stream));
+                result.push_str(&format!("read_stream: {}\n", stream.read_stream));
+                result.push_str(&format!("manage_topics: {}\n", stream.manage_topics));
+                result.push_str(&format!("read_topics: {}\n", stream.read_topics));
+                result.push_str(&format!("poll_messages: {}\n", stream.poll_messages));
+                result.push_str(&format!("send_messages: {}\n", stream.send_messages));
+                if let Some(topics) = &stream.topics {
+                    for topic in topics {
+                        result.push_str(&format!("topic_pattern: {}\n", topic.pattern));
+                        result.push_str(&format!("manage_topic: {}\n", topic.manage_topic));
+                        result.push_str(&format!("read_topic: {}\n", topic.read_topic));
+                        result.push_str(&format!("poll_messages: {}\n", topic.poll_messages));
+                        result.push_str(&format!("send_messages: {}\n", topic.send_messages));
                     }
                 }
             }
@@ -281,6 +604,42 @@ impl From<&TopicPermissions> for Table {
             value.send_messages.to_string().as_str(),
         ]);
 
+        if let Some(consumer_groups) = &value.consumer_groups {
+            consumer_groups
+                .iter()
+                .for_each(|(group_id, group_permissions)| {
+                    let group_table: Table = group_permissions.into();
+                    table.add_row(vec![
+                        format!("Consumer Group: {group_id}").as_str(),
+                        format!("{group_table}").as_str(),
+                    ]);
+                });
+        }
+
+        table
+    }
+}
+
+impl From<&ConsumerGroupPermissions> for Table {
+    fn from(value: &ConsumerGroupPermissions) -> Self {
+        let mut table = Self::new();
+
+        table.load_preset(ASCII_NO_BORDERS);
+        table.set_header(vec!["Permission", "Value"]);
+        table.add_row(vec!["Join Group", value.join_group.to_string().as_str()]);
+        table.add_row(vec![
+            "Commit Offsets",
+            value.commit_offsets.to_string().as_str(),
+        ]);
+        table.add_row(vec![
+            "Delete Offsets",
+            value.delete_offsets.to_string().as_str(),
+        ]);
+        table.add_row(vec![
+            "Manage Group",
+            value.manage_group.to_string().as_str(),
+        ]);
+
         table
     }
 }
@@ -323,3 +682,247 @@ impl From<&StreamPermissions> for Table {
         table
     }
 }
+
+impl From<&StreamPatternPermissions> for Table {
+    fn from(value: &StreamPatternPermissions) -> Self {
+        let mut table: Table = (&value.to_stream_permissions()).into();
+
+        if let Some(topics) = &value.topics {
+            topics.iter().for_each(|topic| {
+                let topic_table: Table = (&topic.to_topic_permissions()).into();
+                table.add_row(vec![
+                    format!("Topic: {}", topic.pattern).as_str(),
+                    format!("{topic_table}").as_str(),
+                ]);
+            });
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream_permissions(
+        read_stream: bool,
+        topics: Option<BTreeMap<usize, TopicPermissions>>,
+    ) -> StreamPermissions {
+        StreamPermissions {
+            read_stream,
+            topics,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn merge_should_union_global_permissions() {
+        let mut permissions = Permissions {
+            global: GlobalPermissions {
+                read_users: true,
+                ..Default::default()
+            },
+            streams: None,
+            stream_patterns: None,
+        };
+        permissions.merge(&Permissions {
+            global: GlobalPermissions {
+                poll_messages: true,
+                ..Default::default()
+            },
+            streams: None,
+            stream_patterns: None,
+        });
+
+        assert!(permissions.global.read_users);
+        assert!(permissions.global.poll_messages);
+        assert!(!permissions.global.manage_users);
+        assert!(permissions.streams.is_none());
+    }
+
+    #[test]
+    fn merge_should_union_stream_and_topic_permissions() {
+        let mut permissions = Permissions {
+            global: GlobalPermissions::default(),
+            streams: Some(BTreeMap::from([(
+                1,
+                stream_permissions(
+                    true,
+                    Some(BTreeMap::from([(
+                        1,
+                        TopicPermissions {
+                            poll_messages: true,
+                            ..Default::default()
+                        },
+                    )])),
+                ),
+            )])),
+            stream_patterns: None,
+        };
+        permissions.merge(&Permissions {
+            global: GlobalPermissions::default(),
+            streams: Some(BTreeMap::from([
+                (
+                    1,
+                    stream_permissions(
+                        false,
+                        Some(BTreeMap::from([(
+                            1,
+                            TopicPermissions {
+                                send_messages: true,
+                                ..Default::default()
+                            },
+                        )])),
+                    ),
+                ),
+                (2, stream_permissions(true, None)),
+            ])),
+            stream_patterns: None,
+        });
+
+        let streams = permissions.streams.unwrap();
+        assert_eq!(streams.len(), 2);
+        let stream = &streams[&1];
+        assert!(stream.read_stream);
+        let topic = &stream.topics.as_ref().unwrap()[&1];
+        assert!(topic.poll_messages);
+        assert!(topic.send_messages);
+        assert!(streams[&2].read_stream);
+    }
+
+    #[test]
+    fn merge_should_restrict_consumer_groups_only_if_both_restrict_them() {
+        let restricted = |group_id: usize, group: ConsumerGroupPermissions| TopicPermissions {
+            poll_messages: true,
+            consumer_groups: Some(BTreeMap::from([(group_id, group)])),
+            ..Default::default()
+        };
+        let join = ConsumerGroupPermissions {
+            join_group: true,
+            ..Default::default()
+        };
+        let commit = ConsumerGroupPermissions {
+            commit_offsets: true,
+            ..Default::default()
+        };
+
+        let mut topic = restricted(1, join.clone());
+        topic.merge(&restricted(1, commit.clone()));
+        topic.merge(&restricted(2, join.clone()));
+        assert!(topic.restricts_consumer_groups());
+        let groups = topic.consumer_groups.as_ref().unwrap();
+        assert!(groups[&1].join_group && groups[&1].commit_offsets);
+        assert!(groups[&2].join_group && !groups[&2].commit_offsets);
+
+        topic.merge(&TopicPermissions {
+            poll_messages: true,
+            ..Default::default()
+        });
+        assert!(!topic.restricts_consumer_groups());
+
+        let mut topic = TopicPermissions::default();
+        topic.merge(&restricted(1, join));
+        assert!(!topic.restricts_consumer_groups());
+
+        let mut permissions = Permissions::default();
+        permissions.merge(&Permissions {
+            streams: Some(BTreeMap::from([(
+                1,
+                stream_permissions(false, Some(BTreeMap::from([(1, restricted(1, commit))]))),
+            )])),
+            ..Default::default()
+        });
+        let streams = permissions.streams.unwrap();
+        assert!(streams[&1].topics.as_ref().unwrap()[&1].restricts_consumer_groups());
+    }
+
+    #[test]
+    fn glob_should_match_prefix_and_wildcards() {
+        assert!(glob_matches("orders-*", "orders-"));
+        assert!(glob_matches("orders-*", "orders-eu"));
+        assert!(!glob_matches("orders-*", "order"));
+        assert!(!glob_matches("orders-*", "payments-eu"));
+        assert!(glob_matches("*-events", "orders-events"));
+        assert!(glob_matches("orders-?", "orders-1"));
+        assert!(!glob_matches("orders-?", "orders-12"));
+        assert!(glob_matches("*-*-*", "a-b-c"));
+        assert!(glob_matches("a*b*c", "aXXbYYbZc"));
+        assert!(!glob_matches("a*b*c", "aXXbYY"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("orders", "orders"));
+        assert!(!glob_matches("orders", "orders-eu"));
+    }
+
+    #[test]
+    fn merge_should_append_stream_patterns() {
+        let orders = StreamPatternPermissions {
+            pattern: "orders-*".to_string(),
+            read_stream: true,
+            ..Default::default()
+        };
+        let payments = StreamPatternPermissions {
+            pattern: "payments-*".to_string(),
+            send_messages: true,
+            ..Default::default()
+        };
+        let mut permissions = Permissions {
+            stream_patterns: Some(vec![orders.clone()]),
+            ..Default::default()
+        };
+        permissions.merge(&Permissions {
+            stream_patterns: Some(vec![payments.clone()]),
+            ..Default::default()
+        });
+
+        assert!(permissions.has_patterns());
+        assert_eq!(permissions.stream_patterns, Some(vec![orders, payments]));
+    }
+
+    #[test]
+    fn validate_patterns_should_reject_empty_and_too_long_patterns() {
+        let with_stream_pattern = |pattern: String| Permissions {
+            stream_patterns: Some(vec![StreamPatternPermissions {
+                pattern,
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let with_topic_pattern = |pattern: String| Permissions {
+            stream_patterns: Some(vec![StreamPatternPermissions {
+                pattern: "orders-*".to_string(),
+                topics: Some(vec![TopicPatternPermissions {
+                    pattern,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let too_long = "a".repeat(MAX_PERMISSION_PATTERN_LENGTH + 1);
+
+        assert!(Permissions::default().validate_patterns().is_ok());
+        assert!(
+            with_stream_pattern("orders-*".to_string())
+                .validate_patterns()
+                .is_ok()
+        );
+        assert!(
+            with_topic_pattern("events-*".to_string())
+                .validate_patterns()
+                .is_ok()
+        );
+        assert!(matches!(
+            with_stream_pattern(String::new()).validate_patterns(),
+            Err(IggyError::InvalidPermissionPattern)
+        ));
+        assert!(matches!(
+            with_stream_pattern(too_long.clone()).validate_patterns(),
+            Err(IggyError::InvalidPermissionPattern)
+        ));
+        assert!(matches!(
+            with_topic_pattern(too_long).validate_patterns(),
+            Err(IggyError::InvalidPermissionPattern)
+        ));
+    }
+}
@@ -0,0 +1,45 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `ProducerIdentity` identifies an idempotent producer on the server.
+/// It consists of the following fields:
+/// - `id`: the unique producer ID, which remains the same when the producer is resumed.
+/// - `epoch`: the producer epoch, bumped each time the producer is initialized, which fences off its previous instances.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub struct ProducerIdentity {
+    /// Unique producer ID.
+    pub id: u64,
+    /// Producer epoch.
+    pub epoch: u64,
+}
+
+impl ProducerIdentity {
+    /// Creates a new producer identity.
+    pub fn new(id: u64, epoch: u64) -> Self {
+        Self { id, epoch }
+    }
+}
+
+impl Display for ProducerIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.id, self.epoch)
+    }
+}
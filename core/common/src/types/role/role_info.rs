@@ -0,0 +1,64 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Permissions;
+use crate::types::user::user_info::UserId;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `RoleId` represents the unique identifier (numeric) of the role.
+pub type RoleId = u32;
+
+/// `RoleInfo` represents the basic information about the role.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the role.
+/// - `created_at`: the timestamp when the role was created.
+/// - `name`: the unique name of the role.
+/// - `users_count`: the number of users holding the role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleInfo {
+    /// The unique identifier (numeric) of the role.
+    pub id: RoleId,
+    /// The timestamp when the role was created.
+    pub created_at: IggyTimestamp,
+    /// The unique name of the role.
+    pub name: String,
+    /// The number of users holding the role.
+    pub users_count: u32,
+}
+
+/// `RoleInfoDetails` represents the detailed information about the role.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the role.
+/// - `created_at`: the timestamp when the role was created.
+/// - `name`: the unique name of the role.
+/// - `permissions`: the optional permissions granted by the role.
+/// - `users`: the identifiers of the users holding the role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleInfoDetails {
+    /// The unique identifier (numeric) of the role.
+    pub id: RoleId,
+    /// The timestamp when the role was created.
+    pub created_at: IggyTimestamp,
+    /// The unique name of the role.
+    pub name: String,
+    /// The optional permissions granted by the role.
+    pub permissions: Option<Permissions>,
+    /// The identifiers of the users holding the role.
+    pub users: Vec<UserId>,
+}
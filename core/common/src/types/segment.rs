@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use crate::{IggyByteSize, IggyExpiry, IggyTimestamp};
+use crate::{IggyByteSize, IggyDuration, IggyExpiry, IggyTimestamp};
 use std::fmt::Display;
 
 #[derive(Default, Debug, Clone)]
@@ -29,14 +29,17 @@ pub struct Segment {
     pub end_offset: u64,
     pub size: IggyByteSize,
     pub max_size: IggyByteSize,
+    /// Whether the messages of this (sealed) segment were offloaded to the archive.
+    pub offloaded: bool,
 }
 
 impl Display for Segment {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Segment {{ sealed: {}, max_size: {}, start_timestamp: {}, end_timestamp: {}, start_offset: {}, end_offset: {}, size: {} }}",
+            "Segment {{ sealed: {}, offloaded: {}, max_size: {}, start_timestamp: {}, end_timestamp: {}, start_offset: {}, end_offset: {}, size: {} }}",
             self.sealed,
+            self.offloaded,
             self.max_size,
             self.start_timestamp,
             self.end_timestamp,
@@ -58,6 +61,7 @@ impl Segment {
             end_offset: start_offset,
             size: IggyByteSize::default(),
             current_position: 0,
+            offloaded: false,
         }
     }
 
@@ -78,12 +82,20 @@ impl Segment {
             }
         }
     }
+
+    /// Whether the sealed segment is old enough to be offloaded to the archive.
+    pub fn should_offload(&self, now: IggyTimestamp, offload_after: IggyDuration) -> bool {
+        if !self.sealed || self.offloaded || self.end_timestamp == 0 {
+            return false;
+        }
+
+        self.end_timestamp + offload_after.as_micros() <= now.as_micros()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{IggyDuration, IggyTimestamp};
     use std::time::Duration;
 
     #[test]
@@ -105,4 +117,26 @@ mod tests {
             "BUG: segment with end_timestamp=0 appears instantly expired"
         );
     }
+
+    #[test]
+    fn only_old_enough_sealed_segment_should_be_offloaded() {
+        let offload_after = IggyDuration::from(Duration::from_secs(600));
+        let now = IggyTimestamp::now();
+        let mut seg = Segment::new(0, IggyByteSize::from(1024u64));
+        seg.end_timestamp = now.as_micros() - offload_after.as_micros();
+        assert!(!seg.should_offload(now, offload_after));
+
+        seg.sealed = true;
+        assert!(seg.should_offload(now, offload_after));
+
+        seg.end_timestamp += 1;
+        assert!(!seg.should_offload(now, offload_after));
+
+        seg.end_timestamp = 0;
+        assert!(!seg.should_offload(now, offload_after));
+
+        seg.end_timestamp = 1;
+        seg.offloaded = true;
+        assert!(!seg.should_offload(now, offload_after));
+    }
 }
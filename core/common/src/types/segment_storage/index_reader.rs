@@ -65,6 +65,11 @@ impl IndexReader {
         self.file_path.clone()
     }
 
+    /// Returns the number of indexes (and thus messages) persisted in the index file.
+    pub fn indexes_count(&self) -> u32 {
+        self.file_size() / INDEX_SIZE as u32
+    }
+
     /// Loads all indexes from the index file into the optimized binary format.
     /// Note that this function does not use the pool, as the messages are not cached.
     /// This is expected - this method is called at startup and we want to preserve
@@ -104,8 +109,8 @@ impl IndexReader {
 
     /// Loads a specific range of indexes from disk based on offset.
     ///
-    /// Returns a slice of indexes starting at the relative_start_offset with the specified count,
-    /// or None if the requested range is not available.
+    /// Returns a slice of indexes starting at the first index whose offset is not lower than
+    /// relative_start_offset with the specified count, or None if the requested range is not available.
     pub async fn load_from_disk_by_offset(
         &self,
         relative_start_offset: u32,
@@ -122,15 +127,18 @@ impl IndexReader {
             return Ok(None);
         }
 
-        if relative_start_offset >= total_indexes {
+        let Some(start_index_pos) = self
+            .position_for_offset_async(relative_start_offset)
+            .await?
+        else {
             trace!(
                 "Start offset {} is out of bounds. Total indexes: {}",
                 relative_start_offset, total_indexes
             );
             return Ok(None);
-        }
+        };
 
-        let available_count = total_indexes.saturating_sub(relative_start_offset);
+        let available_count = total_indexes.saturating_sub(start_index_pos);
         let actual_count = std::cmp::min(count, available_count);
 
         if actual_count == 0 {
@@ -141,7 +149,7 @@ impl IndexReader {
             return Ok(None);
         }
 
-        let start_byte = relative_start_offset as usize * INDEX_SIZE;
+        let start_byte = start_index_pos as usize * INDEX_SIZE;
         let end_byte = start_byte + (actual_count as usize * INDEX_SIZE);
 
         let indexes_bytes = match self
@@ -155,20 +163,20 @@ impl IndexReader {
             }
             Err(e) => {
                 error!(
-                    "Error reading {actual_count} indexes at position {relative_start_offset} in file {} of size {file_size}: {e}",
+                    "Error reading {actual_count} indexes at position {start_index_pos} in file {} of size {file_size}: {e}",
                     self.file_path
                 );
                 return Err(IggyError::CannotReadFile);
             }
         };
 
-        let base_position = if relative_start_offset > 0 {
-            match self.load_nth_index(relative_start_offset - 1).await? {
+        let base_position = if start_index_pos > 0 {
+            match self.load_nth_index(start_index_pos - 1).await? {
                 Some(prev_index) => prev_index.position,
                 None => {
                     trace!(
                         "Failed to load previous index at position {}",
-                        relative_start_offset - 1
+                        start_index_pos - 1
                     );
                     0
                 }
@@ -325,6 +333,42 @@ impl IndexReader {
         Ok(Some(low))
     }
 
+    /// Finds the position of the first index whose offset is not lower than the target.
+    ///
+    /// The position equals the relative offset unless the segment was compacted, in which case
+    /// removed messages leave gaps in the offsets and the position is found with a binary search.
+    async fn position_for_offset_async(
+        &self,
+        relative_offset: u32,
+    ) -> Result<Option<u32>, IggyError> {
+        let total_indexes = self.file_size() / INDEX_SIZE as u32;
+        if total_indexes == 0 {
+            return Ok(None);
+        }
+
+        if let Some(index) = self.load_nth_index(relative_offset).await?
+            && index.offset == relative_offset
+        {
+            return Ok(Some(relative_offset));
+        }
+
+        let mut low = 0;
+        let mut high = total_indexes;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let Some(mid_index) = self.load_nth_index(mid).await? else {
+                return Ok(None);
+            };
+            if mid_index.offset < relative_offset {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        Ok((low < total_indexes).then_some(low))
+    }
+
     /// Returns the size of the index file in bytes.
     fn file_size(&self) -> u32 {
         self.index_size_bytes.load(Ordering::Acquire) as u32
@@ -123,6 +123,19 @@ impl SegmentStorage {
         })
     }
 
+    /// Opens the storage of a segment whose messages were offloaded to the archive, only its
+    /// index is kept on the local disk.
+    pub async fn offloaded(index_path: &str, indexes_size: u64) -> Result<Self, IggyError> {
+        let indexes_size = Rc::new(std::sync::atomic::AtomicU64::new(indexes_size));
+        let index_reader = Rc::new(IndexReader::new(index_path, indexes_size).await?);
+        Ok(Self {
+            messages_writer: None,
+            messages_reader: None,
+            index_writer: None,
+            index_reader: Some(index_reader),
+        })
+    }
+
     pub fn shutdown(&mut self) -> (Option<Rc<MessagesWriter>>, Option<Rc<IndexWriter>>) {
         let messages_writer = self.messages_writer.take();
         let index_writer = self.index_writer.take();
@@ -0,0 +1,126 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The user header key holding the message key used by log compaction.
+///
+/// Messages sharing the same key value (compared by both kind and raw bytes) belong to the same
+/// key, and in a compacted topic only the latest of them is retained. Messages without this
+/// header are never removed by the compaction.
+pub const MESSAGE_KEY_HEADER_KEY: &str = "iggy-key";
+
+/// The user header key marking a keyed message as a tombstone.
+///
+/// A tombstone deletes all previous messages with the same key during compaction, and is
+/// removed itself once it's older than the configured tombstone retention.
+pub const TOMBSTONE_HEADER_KEY: &str = "iggy-tombstone";
+
+/// Cleanup policy of the topic, deciding how the sealed segments are reclaimed.
+/// - `Delete`: segments are deleted as a whole, based on the message expiry and the max topic size.
+/// - `Compact`: additionally, sealed segments are rewritten to keep only the latest message per key.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupPolicy {
+    #[default]
+    Delete,
+    Compact,
+}
+
+impl CleanupPolicy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            CleanupPolicy::Delete => 1,
+            CleanupPolicy::Compact => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(CleanupPolicy::Delete),
+            2 => Ok(CleanupPolicy::Compact),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    pub fn is_compact(&self) -> bool {
+        matches!(self, CleanupPolicy::Compact)
+    }
+}
+
+impl FromStr for CleanupPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "delete" => Ok(CleanupPolicy::Delete),
+            "compact" => Ok(CleanupPolicy::Compact),
+            _ => Err(format!("Unknown cleanup policy: {s}")),
+        }
+    }
+}
+
+impl Display for CleanupPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanupPolicy::Delete => write!(f, "delete"),
+            CleanupPolicy::Compact => write!(f, "compact"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_cleanup_policy_ignoring_case() {
+        assert_eq!(
+            CleanupPolicy::from_str("delete").unwrap(),
+            CleanupPolicy::Delete
+        );
+        assert_eq!(
+            CleanupPolicy::from_str("Compact").unwrap(),
+            CleanupPolicy::Compact
+        );
+        assert!(CleanupPolicy::from_str("compact,delete").is_err());
+    }
+
+    #[test]
+    fn should_round_trip_cleanup_policy_code() {
+        for policy in [CleanupPolicy::Delete, CleanupPolicy::Compact] {
+            assert_eq!(CleanupPolicy::from_code(policy.as_code()).unwrap(), policy);
+            assert_eq!(
+                CleanupPolicy::from_str(&policy.to_string()).unwrap(),
+                policy
+            );
+        }
+        assert!(CleanupPolicy::from_code(0).is_err());
+    }
+
+    #[test]
+    fn should_serialize_cleanup_policy_as_string() {
+        let json = serde_json::to_string(&CleanupPolicy::Compact).unwrap();
+        assert_eq!(json, "\"compact\"");
+        let policy: CleanupPolicy = serde_json::from_str(&json).unwrap();
+        assert_eq!(policy, CleanupPolicy::Compact);
+    }
+}
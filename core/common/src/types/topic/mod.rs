@@ -16,6 +16,10 @@
  * under the License.
  */
 
+mod cleanup_policy;
+
+pub use cleanup_policy::*;
+
 use crate::CompressionAlgorithm;
 use crate::Partition;
 use crate::utils::byte_size::IggyByteSize;
@@ -32,6 +36,7 @@ use serde::{Deserialize, Serialize};
 /// - `size`: the total size of the topic in bytes.
 /// - `message_expiry`: the expiry of the messages in the topic.
 /// - `max_topic_size`: the maximum size of the topic.
+/// - `cleanup_policy`: the cleanup policy of the topic.
 /// - `replication_factor`: replication factor for the topic.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
@@ -52,6 +57,9 @@ pub struct Topic {
     /// The optional maximum size of the topic.
     /// Can't be lower than segment size in the config.
     pub max_topic_size: MaxTopicSize,
+    /// Cleanup policy of the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
     /// Replication factor for the topic.
     pub replication_factor: u8,
     /// The total number of messages in the topic.
@@ -68,6 +76,7 @@ pub struct Topic {
 /// - `size`: the total size of the topic.
 /// - `message_expiry`: the expiry of the messages in the topic.
 /// - `max_topic_size`: the maximum size of the topic.
+/// - `cleanup_policy`: the cleanup policy of the topic.
 /// - `replication_factor`: replication factor for the topic.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
@@ -89,6 +98,9 @@ pub struct TopicDetails {
     /// The optional maximum size of the topic.
     /// Can't be lower than segment size in the config.
     pub max_topic_size: MaxTopicSize,
+    /// Cleanup policy of the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
     /// Replication factor for the topic.
     pub replication_factor: u8,
     /// The total number of messages in the topic.
@@ -0,0 +1,60 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{Consumer, Identifier};
+use serde::{Deserialize, Serialize};
+
+/// `TransactionOffset` is a consumer offset which is stored atomically with the commit of a transaction.
+/// It consists of the following fields:
+/// - `consumer`: the consumer or consumer group for which the offset is stored.
+/// - `stream_id`: the unique stream ID (numeric or name).
+/// - `topic_id`: the unique topic ID (numeric or name).
+/// - `partition_id`: the partition ID, required for the standalone consumer.
+/// - `offset`: the offset to store.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct TransactionOffset {
+    /// The consumer or consumer group for which the offset is stored.
+    pub consumer: Consumer,
+    /// Unique stream ID (numeric or name).
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    pub topic_id: Identifier,
+    /// Partition ID on which the offset is stored.
+    pub partition_id: Option<u32>,
+    /// Offset to store.
+    pub offset: u64,
+}
+
+impl TransactionOffset {
+    /// Creates a new transaction offset.
+    pub fn new(
+        consumer: Consumer,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Self {
+        Self {
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+            offset,
+        }
+    }
+}
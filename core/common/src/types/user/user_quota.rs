@@ -0,0 +1,147 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::{IggyByteSize, UserId};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `QuotaScope` selects which traffic a quota applies to.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaScope {
+    /// The limits are shared by all connections authenticated as the user.
+    #[default]
+    User,
+    /// The limits apply to each connection of the user separately.
+    Client,
+}
+
+impl FromStr for QuotaScope {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "user" => Ok(QuotaScope::User),
+            "client" => Ok(QuotaScope::Client),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for QuotaScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuotaScope::User => write!(f, "user"),
+            QuotaScope::Client => write!(f, "client"),
+        }
+    }
+}
+
+/// `Quota` limits the produce and fetch throughput and the request rate.
+/// A missing limit means the traffic is not restricted.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct Quota {
+    /// The maximum number of message bytes sent per second.
+    pub produce_bytes_per_second: Option<IggyByteSize>,
+    /// The maximum number of message bytes polled per second.
+    pub fetch_bytes_per_second: Option<IggyByteSize>,
+    /// The maximum number of requests per second.
+    pub requests_per_second: Option<u32>,
+}
+
+impl Quota {
+    /// Returns true if none of the limits is set.
+    pub fn is_unlimited(&self) -> bool {
+        self.produce_bytes_per_second.is_none()
+            && self.fetch_bytes_per_second.is_none()
+            && self.requests_per_second.is_none()
+    }
+}
+
+/// `UserQuotas` represents the quotas assigned to a user.
+/// It consists of the following fields:
+/// - `user_id`: the unique identifier (numeric) of the user.
+/// - `user`: the quota shared by all connections of the user.
+/// - `client`: the quota applied to each connection of the user.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+pub struct UserQuotas {
+    /// The unique identifier (numeric) of the user.
+    pub user_id: UserId,
+    /// The quota shared by all connections of the user.
+    pub user: Option<Quota>,
+    /// The quota applied to each connection of the user.
+    pub client: Option<Quota>,
+}
+
+impl UserQuotas {
+    /// Returns the quota for the given scope.
+    pub fn get(&self, scope: QuotaScope) -> Option<&Quota> {
+        match scope {
+            QuotaScope::User => self.user.as_ref(),
+            QuotaScope::Client => self.client.as_ref(),
+        }
+    }
+
+    /// Replaces the quota for the given scope, `None` removes it.
+    pub fn set(&mut self, scope: QuotaScope, quota: Option<Quota>) {
+        let quota = quota.filter(|quota| !quota.is_unlimited());
+        match scope {
+            QuotaScope::User => self.user = quota,
+            QuotaScope::Client => self.client = quota,
+        }
+    }
+
+    /// Returns true if no quota is assigned.
+    pub fn is_empty(&self) -> bool {
+        self.user.is_none() && self.client.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scope_should_roundtrip_through_string() {
+        for scope in [QuotaScope::User, QuotaScope::Client] {
+            assert_eq!(scope.to_string().parse::<QuotaScope>().unwrap(), scope);
+        }
+        assert!("tenant".parse::<QuotaScope>().is_err());
+    }
+
+    #[test]
+    fn setting_unlimited_quota_should_remove_it() {
+        let mut quotas = UserQuotas::default();
+        quotas.set(
+            QuotaScope::Client,
+            Some(Quota {
+                requests_per_second: Some(10),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(
+            quotas.get(QuotaScope::Client).unwrap().requests_per_second,
+            Some(10)
+        );
+        assert!(quotas.get(QuotaScope::User).is_none());
+
+        quotas.set(QuotaScope::Client, Some(Quota::default()));
+        assert!(quotas.is_empty());
+    }
+}
@@ -20,22 +20,33 @@ use crate::IggyError;
 use crate::text;
 use aes_gcm::aead::{Aead, OsRng};
 use aes_gcm::{AeadCore, Aes256Gcm, KeyInit};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::sync::Arc;
+
+/// Marks the data encrypted with one of the keys of an [`EnvelopeEncryptor`].
+const ENVELOPE_MAGIC: [u8; 4] = *b"IGK1";
+const ENVELOPE_HEADER_SIZE: usize = ENVELOPE_MAGIC.len() + 4;
+const KEY_SIZE: usize = 32;
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum EncryptorKind {
     Aes256Gcm(Aes256GcmEncryptor),
+    Envelope(Arc<EnvelopeEncryptor>),
 }
 
 impl EncryptorKind {
     pub fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
         match self {
             EncryptorKind::Aes256Gcm(e) => e.encrypt(data),
+            EncryptorKind::Envelope(e) => e.encrypt(data),
         }
     }
     pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
         match self {
             EncryptorKind::Aes256Gcm(e) => e.decrypt(data),
+            EncryptorKind::Envelope(e) => e.decrypt(data),
         }
     }
 }
@@ -83,6 +94,9 @@ impl Encryptor for Aes256GcmEncryptor {
     }
 
     fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        if data.len() < 12 {
+            return Err(IggyError::CannotDecryptData);
+        }
         let nonce = (&data[0..12]).into();
         let payload = self.cipher.decrypt(nonce, &data[12..]);
         if payload.is_err() {
@@ -92,6 +106,133 @@ impl Encryptor for Aes256GcmEncryptor {
     }
 }
 
+/// Encrypts the data with the current data encryption key, prefixing it with the key ID,
+/// and decrypts it with the key it was encrypted with. The data without the key ID
+/// (encrypted before the first key rotation) is decrypted with the default key.
+#[derive(Clone)]
+pub struct EnvelopeEncryptor {
+    current_key_id: u32,
+    keys: BTreeMap<u32, Aes256GcmEncryptor>,
+    default: Aes256GcmEncryptor,
+}
+
+impl Debug for EnvelopeEncryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EnvelopeEncryptor")
+            .field("current_key_id", &self.current_key_id)
+            .field("key_ids", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl EnvelopeEncryptor {
+    /// Creates the encryptor using the key with the highest ID for the encryption.
+    pub fn new(
+        keys: BTreeMap<u32, Vec<u8>>,
+        default: Aes256GcmEncryptor,
+    ) -> Result<Self, IggyError> {
+        let Some(current_key_id) = keys.keys().next_back().copied() else {
+            return Err(IggyError::InvalidEncryptionKey);
+        };
+        let keys = keys
+            .into_iter()
+            .map(|(key_id, key)| Ok((key_id, Aes256GcmEncryptor::new(&key)?)))
+            .collect::<Result<_, IggyError>>()?;
+        Ok(Self {
+            current_key_id,
+            keys,
+            default,
+        })
+    }
+
+    pub fn current_key_id(&self) -> u32 {
+        self.current_key_id
+    }
+
+    /// Returns the ID of the key the data was encrypted with, if it was encrypted by an envelope encryptor.
+    pub fn key_id(data: &[u8]) -> Option<u32> {
+        if data.len() < ENVELOPE_HEADER_SIZE || data[..ENVELOPE_MAGIC.len()] != ENVELOPE_MAGIC {
+            return None;
+        }
+        Some(u32::from_le_bytes(
+            data[ENVELOPE_MAGIC.len()..ENVELOPE_HEADER_SIZE]
+                .try_into()
+                .unwrap(),
+        ))
+    }
+}
+
+impl Encryptor for EnvelopeEncryptor {
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let encrypted_data = self.keys[&self.current_key_id].encrypt(data)?;
+        let mut payload = Vec::with_capacity(ENVELOPE_HEADER_SIZE + encrypted_data.len());
+        payload.extend_from_slice(&ENVELOPE_MAGIC);
+        payload.extend_from_slice(&self.current_key_id.to_le_bytes());
+        payload.extend_from_slice(&encrypted_data);
+        Ok(payload)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        // The nonce of the data encrypted with the default key might start with the magic bytes.
+        if let Some(key) = Self::key_id(data).and_then(|key_id| self.keys.get(&key_id))
+            && let Ok(payload) = key.decrypt(&data[ENVELOPE_HEADER_SIZE..])
+        {
+            return Ok(payload);
+        }
+        self.default.decrypt(data)
+    }
+}
+
+/// Wraps the data encryption keys with the master key, so that only the wrapped keys are stored.
+/// The KMS backed wrappers can be added as the next variants.
+#[derive(Debug, Clone)]
+pub enum KeyWrapperKind {
+    Local(LocalKeyWrapper),
+}
+
+impl KeyWrapperKind {
+    pub fn wrap_key(&self, key: &[u8]) -> Result<Vec<u8>, IggyError> {
+        match self {
+            KeyWrapperKind::Local(p) => p.wrap_key(key),
+        }
+    }
+
+    pub fn unwrap_key(&self, wrapped_key: &[u8]) -> Result<Vec<u8>, IggyError> {
+        match self {
+            KeyWrapperKind::Local(p) => p.unwrap_key(wrapped_key),
+        }
+    }
+}
+
+/// Key wrapper keeping the master key in the server configuration or a local file.
+#[derive(Debug, Clone)]
+pub struct LocalKeyWrapper {
+    master_key: Aes256GcmEncryptor,
+}
+
+impl LocalKeyWrapper {
+    pub fn new(master_key: Aes256GcmEncryptor) -> Self {
+        Self { master_key }
+    }
+
+    pub fn wrap_key(&self, key: &[u8]) -> Result<Vec<u8>, IggyError> {
+        self.master_key.encrypt(key)
+    }
+
+    pub fn unwrap_key(&self, wrapped_key: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let key = self.master_key.decrypt(wrapped_key)?;
+        if key.len() != KEY_SIZE {
+            return Err(IggyError::InvalidEncryptionKey);
+        }
+        Ok(key)
+    }
+}
+
+/// Generates a random 256-bit data encryption key.
+pub fn generate_encryption_key() -> Vec<u8> {
+    Aes256Gcm::generate_key(&mut OsRng).to_vec()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,4 +336,49 @@ mod tests {
             "test-message"
         );
     }
+
+    #[test]
+    fn envelope_encryptor_should_decrypt_data_encrypted_with_previous_keys() {
+        let default = Aes256GcmEncryptor::new(&[1; 32]).unwrap();
+        let legacy_data = default.encrypt(b"legacy").unwrap();
+
+        let mut keys = BTreeMap::new();
+        keys.insert(1, generate_encryption_key());
+        let first = EnvelopeEncryptor::new(keys.clone(), default.clone()).unwrap();
+        let first_data = first.encrypt(b"first").unwrap();
+        assert_eq!(EnvelopeEncryptor::key_id(&first_data), Some(1));
+
+        keys.insert(2, generate_encryption_key());
+        let second = EnvelopeEncryptor::new(keys, default).unwrap();
+        assert_eq!(second.current_key_id(), 2);
+        let second_data = second.encrypt(b"second").unwrap();
+        assert_eq!(EnvelopeEncryptor::key_id(&second_data), Some(2));
+
+        assert_eq!(second.decrypt(&legacy_data).unwrap(), b"legacy");
+        assert_eq!(second.decrypt(&first_data).unwrap(), b"first");
+        assert_eq!(second.decrypt(&second_data).unwrap(), b"second");
+        assert!(first.decrypt(&second_data).is_err());
+    }
+
+    #[test]
+    fn envelope_encryptor_should_require_at_least_one_key() {
+        let default = Aes256GcmEncryptor::new(&[1; 32]).unwrap();
+        let result = EnvelopeEncryptor::new(BTreeMap::new(), default);
+        assert_eq!(
+            result.err().unwrap().as_code(),
+            IggyError::InvalidEncryptionKey.as_code()
+        );
+    }
+
+    #[test]
+    fn local_key_provider_should_wrap_and_unwrap_key() {
+        let provider = LocalKeyWrapper::new(Aes256GcmEncryptor::new(&[1; 32]).unwrap());
+        let key = generate_encryption_key();
+        let wrapped_key = provider.wrap_key(&key).unwrap();
+        assert_ne!(wrapped_key, key);
+        assert_eq!(provider.unwrap_key(&wrapped_key).unwrap(), key);
+
+        let other_provider = LocalKeyWrapper::new(Aes256GcmEncryptor::new(&[2; 32]).unwrap());
+        assert!(other_provider.unwrap_key(&wrapped_key).is_err());
+    }
 }
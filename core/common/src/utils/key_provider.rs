@@ -0,0 +1,238 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{Aes256GcmEncryptor, EncryptorKind, Identifier, IggyDuration, IggyError};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::error;
+
+const KEYS_DIRECTORY: &str = "keys";
+const CURRENT_KEY_ID_FILE: &str = "current";
+
+/// Provides the keys for the client-side encryption of the messages, identified by their IDs.
+///
+/// The producers encrypt the messages with the current key of the topic and record its ID
+/// in the messages, so that the consumers can get the same key to decrypt them. As the previous
+/// keys remain available by their IDs, the keys can be rotated without restarting the clients.
+pub trait KeyProvider: Debug + Send + Sync {
+    /// Returns the ID of the key to encrypt the messages sent to the topic with.
+    fn current_key_id(&self, stream: &Identifier, topic: &Identifier) -> Result<String, IggyError>;
+
+    /// Returns the encryptor using the key with the provided ID.
+    fn encryptor(&self, key_id: &str) -> Result<Arc<EncryptorKind>, IggyError>;
+}
+
+/// Key provider reading the keys from the files in a directory:
+///
+/// * `keys/<key_id>` - the base64 encoded 32 bytes length key,
+/// * `<stream>/<topic>/current` - the ID of the current key of the topic,
+/// * `current` - the ID of the current key of the topics without their own one.
+///
+/// The stream and topic directories are named after the identifiers used by the producers.
+/// The keys are read once, as they never change, while the current key IDs are read again
+/// after the refresh interval, so that a new key can be rolled out by adding its file
+/// and then updating the `current` one.
+#[derive(Debug)]
+pub struct FileKeyProvider {
+    directory: PathBuf,
+    refresh_interval: IggyDuration,
+    encryptors: Mutex<HashMap<String, Arc<EncryptorKind>>>,
+    current_key_ids: Mutex<HashMap<(String, String), (String, Instant)>>,
+}
+
+impl FileKeyProvider {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            refresh_interval: IggyDuration::ONE_SECOND,
+            encryptors: Mutex::new(HashMap::new()),
+            current_key_ids: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how long the current key ID of a topic is cached before reading it again.
+    pub fn with_refresh_interval(mut self, refresh_interval: IggyDuration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    fn read_current_key_id(&self, stream: &str, topic: &str) -> Result<String, IggyError> {
+        let topic_file = self
+            .directory
+            .join(stream)
+            .join(topic)
+            .join(CURRENT_KEY_ID_FILE);
+        let path = if topic_file.exists() {
+            topic_file
+        } else {
+            self.directory.join(CURRENT_KEY_ID_FILE)
+        };
+        let key_id = read_file(&path)?;
+        validate_key_id(&key_id)?;
+        Ok(key_id)
+    }
+}
+
+impl KeyProvider for FileKeyProvider {
+    fn current_key_id(&self, stream: &Identifier, topic: &Identifier) -> Result<String, IggyError> {
+        let topic_key = (stream.to_string(), topic.to_string());
+        if let Some((key_id, read_at)) = self.current_key_ids.lock().unwrap().get(&topic_key)
+            && read_at.elapsed() < self.refresh_interval.get_duration()
+        {
+            return Ok(key_id.clone());
+        }
+
+        let key_id = self.read_current_key_id(&topic_key.0, &topic_key.1)?;
+        self.current_key_ids
+            .lock()
+            .unwrap()
+            .insert(topic_key, (key_id.clone(), Instant::now()));
+        Ok(key_id)
+    }
+
+    fn encryptor(&self, key_id: &str) -> Result<Arc<EncryptorKind>, IggyError> {
+        if let Some(encryptor) = self.encryptors.lock().unwrap().get(key_id) {
+            return Ok(encryptor.clone());
+        }
+
+        // The key ID comes from the message, so it must not point outside the keys directory.
+        validate_key_id(key_id)?;
+        let key = read_file(&self.directory.join(KEYS_DIRECTORY).join(key_id))?;
+        let encryptor = Arc::new(EncryptorKind::Aes256Gcm(
+            Aes256GcmEncryptor::from_base64_key(&key)?,
+        ));
+        self.encryptors
+            .lock()
+            .unwrap()
+            .insert(key_id.to_owned(), encryptor.clone());
+        Ok(encryptor)
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, IggyError> {
+    std::fs::read_to_string(path)
+        .map(|content| content.trim().to_owned())
+        .map_err(|error| {
+            error!(
+                "Cannot read the encryption key file: {}. {error}",
+                path.display()
+            );
+            IggyError::CannotReadFile
+        })
+}
+
+fn validate_key_id(key_id: &str) -> Result<(), IggyError> {
+    let is_valid = !key_id.is_empty()
+        && !key_id.starts_with('.')
+        && key_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !is_valid {
+        error!("Invalid encryption key ID: {key_id}");
+        return Err(IggyError::InvalidEncryptionKey);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IggyMessage;
+
+    const FIRST_KEY: &str = "/rvT1xP4V8u1EAhk4xDdqzqM2UOPXyy9XYkl4uRShgE=";
+    const SECOND_KEY: &str = "9zyjHMYYoP2hNmKHnHvP1A5Wm8nJPuVbx0m3wZaIq4Y=";
+
+    fn write(directory: &Path, path: &str, content: &str) {
+        let path = directory.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn should_use_topic_key_and_fall_back_to_default_one() {
+        let directory = tempfile::tempdir().unwrap();
+        write(directory.path(), "current", "key-1\n");
+        write(directory.path(), "stream/topic/current", "key-2");
+        let provider = FileKeyProvider::new(directory.path());
+
+        let stream = Identifier::named("stream").unwrap();
+        let topic = Identifier::named("topic").unwrap();
+        let other_topic = Identifier::named("other-topic").unwrap();
+        assert_eq!(provider.current_key_id(&stream, &topic).unwrap(), "key-2");
+        assert_eq!(
+            provider.current_key_id(&stream, &other_topic).unwrap(),
+            "key-1"
+        );
+    }
+
+    #[test]
+    fn should_decrypt_messages_encrypted_with_previous_key_after_rotation() {
+        let directory = tempfile::tempdir().unwrap();
+        write(directory.path(), "keys/key-1", FIRST_KEY);
+        write(directory.path(), "current", "key-1");
+        let provider =
+            FileKeyProvider::new(directory.path()).with_refresh_interval(IggyDuration::from(0));
+        let stream = Identifier::numeric(1).unwrap();
+        let topic = Identifier::numeric(1).unwrap();
+
+        let mut messages = Vec::new();
+        for rotation in 0..2 {
+            if rotation == 1 {
+                write(directory.path(), "keys/key-2", SECOND_KEY);
+                write(directory.path(), "current", "key-2");
+            }
+            let key_id = provider.current_key_id(&stream, &topic).unwrap();
+            let mut message = IggyMessage::builder()
+                .payload(format!("message {rotation}").into())
+                .build()
+                .unwrap();
+            message
+                .encrypt(&key_id, &provider.encryptor(&key_id).unwrap())
+                .unwrap();
+            messages.push(message);
+        }
+
+        for (index, mut message) in messages.into_iter().enumerate() {
+            let key_id = message.encryption_key_id().unwrap().unwrap();
+            assert_eq!(key_id, format!("key-{}", index + 1));
+            message
+                .decrypt(&provider.encryptor(&key_id).unwrap())
+                .unwrap();
+            assert_eq!(
+                message.payload_as_string().unwrap(),
+                format!("message {index}")
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_key_id_outside_keys_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        write(directory.path(), "keys/key-1", FIRST_KEY);
+        write(directory.path(), "key-2", SECOND_KEY);
+        let provider = FileKeyProvider::new(directory.path());
+
+        assert!(matches!(
+            provider.encryptor("../key-2"),
+            Err(IggyError::InvalidEncryptionKey)
+        ));
+    }
+}
@@ -23,9 +23,11 @@ pub(crate) mod crypto;
 pub(crate) mod duration;
 pub(crate) mod expiry;
 pub(crate) mod hash;
+pub(crate) mod key_provider;
 pub(crate) mod net;
 pub(crate) mod personal_access_token_expiry;
 pub mod random_id;
+pub mod scram;
 pub mod serde_secret;
 pub mod text;
 pub(crate) mod timestamp;
@@ -0,0 +1,359 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! SCRAM-SHA-256 (RFC 5802 / RFC 7677) primitives shared by the SDK and the server.
+//!
+//! The binary protocol carries the exchange as structured fields rather than the
+//! textual SASL messages, but the auth message is rebuilt in the RFC format on both
+//! sides, so the proofs and signatures are interoperable with other implementations.
+
+use crate::IggyError;
+use crate::text;
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::{digest, hmac, pbkdf2};
+use std::fmt::{Debug, Display, Formatter};
+use std::num::NonZeroU32;
+use std::str::FromStr;
+
+pub const SCRAM_MECHANISM: &str = "SCRAM-SHA-256";
+pub const SCRAM_DEFAULT_ITERATIONS: u32 = 4096;
+pub const SCRAM_SALT_LENGTH: usize = 16;
+pub const SCRAM_NONCE_LENGTH: usize = 18;
+pub const SCRAM_KEY_LENGTH: usize = 32;
+
+pub type ScramKey = [u8; SCRAM_KEY_LENGTH];
+
+/// Salted SCRAM credentials stored by the server next to the password hash.
+///
+/// Serialized as `SCRAM-SHA-256$<iterations>:<salt>$<stored_key>:<server_key>`
+/// (base64 fields), the same layout PostgreSQL uses.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ScramCredentials {
+    pub salt: Vec<u8>,
+    pub iterations: u32,
+    pub stored_key: ScramKey,
+    pub server_key: ScramKey,
+}
+
+impl ScramCredentials {
+    /// Derives the credentials for the password using a random salt and the default iterations count.
+    pub fn new(password: &str) -> Self {
+        let mut salt = vec![0; SCRAM_SALT_LENGTH];
+        fill_random(&mut salt);
+        Self::with_salt(password, salt, SCRAM_DEFAULT_ITERATIONS)
+            .expect("Default SCRAM iterations count must be valid")
+    }
+
+    pub fn with_salt(password: &str, salt: Vec<u8>, iterations: u32) -> Result<Self, IggyError> {
+        let salted_password = salted_password(password, &salt, iterations)?;
+        let client_key = hmac_sha256(&salted_password, b"Client Key");
+        Ok(Self {
+            stored_key: sha256(&client_key),
+            server_key: hmac_sha256(&salted_password, b"Server Key"),
+            salt,
+            iterations,
+        })
+    }
+
+    /// Checks that the proof was produced from the password these credentials were derived from.
+    pub fn verify_client_proof(&self, auth_message: &str, client_proof: &[u8]) -> bool {
+        if client_proof.len() != SCRAM_KEY_LENGTH {
+            return false;
+        }
+
+        let client_signature = hmac_sha256(&self.stored_key, auth_message.as_bytes());
+        let mut client_key = [0; SCRAM_KEY_LENGTH];
+        for (index, byte) in client_key.iter_mut().enumerate() {
+            *byte = client_proof[index] ^ client_signature[index];
+        }
+        constant_time_eq(&sha256(&client_key), &self.stored_key)
+    }
+
+    pub fn server_signature(&self, auth_message: &str) -> ScramKey {
+        hmac_sha256(&self.server_key, auth_message.as_bytes())
+    }
+}
+
+impl Debug for ScramCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScramCredentials")
+            .field("iterations", &self.iterations)
+            .field("stored_key", &"[REDACTED]")
+            .field("server_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl Display for ScramCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{SCRAM_MECHANISM}${}:{}${}:{}",
+            self.iterations,
+            text::as_base64(&self.salt),
+            text::as_base64(&self.stored_key),
+            text::as_base64(&self.server_key)
+        )
+    }
+}
+
+impl FromStr for ScramCredentials {
+    type Err = IggyError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split('$');
+        if parts.next() != Some(SCRAM_MECHANISM) {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        let (iterations, salt) = parts
+            .next()
+            .and_then(|part| part.split_once(':'))
+            .ok_or(IggyError::InvalidFormat)?;
+        let (stored_key, server_key) = parts
+            .next()
+            .and_then(|part| part.split_once(':'))
+            .ok_or(IggyError::InvalidFormat)?;
+        if parts.next().is_some() {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        let iterations = iterations
+            .parse::<u32>()
+            .map_err(|_| IggyError::InvalidFormat)?;
+        if iterations == 0 {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        Ok(Self {
+            salt: text::from_base64_as_bytes(salt)?,
+            iterations,
+            stored_key: decode_key(stored_key)?,
+            server_key: decode_key(server_key)?,
+        })
+    }
+}
+
+/// Client side of the exchange: the proof to send and the server signature expected back.
+#[derive(Clone)]
+pub struct ScramClientProof {
+    pub proof: ScramKey,
+    pub server_signature: ScramKey,
+}
+
+impl ScramClientProof {
+    pub fn new(
+        password: &str,
+        salt: &[u8],
+        iterations: u32,
+        auth_message: &str,
+    ) -> Result<Self, IggyError> {
+        let salted_password = salted_password(password, salt, iterations)?;
+        let client_key = hmac_sha256(&salted_password, b"Client Key");
+        let stored_key = sha256(&client_key);
+        let client_signature = hmac_sha256(&stored_key, auth_message.as_bytes());
+        let mut proof = [0; SCRAM_KEY_LENGTH];
+        for (index, byte) in proof.iter_mut().enumerate() {
+            *byte = client_key[index] ^ client_signature[index];
+        }
+        let server_key = hmac_sha256(&salted_password, b"Server Key");
+        Ok(Self {
+            proof,
+            server_signature: hmac_sha256(&server_key, auth_message.as_bytes()),
+        })
+    }
+
+    pub fn verify_server_signature(&self, server_signature: &[u8]) -> bool {
+        constant_time_eq(&self.server_signature, server_signature)
+    }
+}
+
+/// Generates a random, printable nonce (never containing `,`).
+pub fn generate_nonce() -> String {
+    let mut nonce = [0; SCRAM_NONCE_LENGTH];
+    fill_random(&mut nonce);
+    text::as_base64(&nonce)
+}
+
+/// Builds the RFC 5802 auth message signed by both parties:
+/// `client-first-message-bare,server-first-message,client-final-message-without-proof`.
+pub fn auth_message(
+    username: &str,
+    client_nonce: &str,
+    nonce: &str,
+    salt: &[u8],
+    iterations: u32,
+) -> String {
+    let username = username.replace('=', "=3D").replace(',', "=2C");
+    format!(
+        "n={username},r={client_nonce},r={nonce},s={},i={iterations},c=biws,r={nonce}",
+        text::as_base64(salt)
+    )
+}
+
+fn salted_password(password: &str, salt: &[u8], iterations: u32) -> Result<ScramKey, IggyError> {
+    let iterations = NonZeroU32::new(iterations).ok_or(IggyError::InvalidFormat)?;
+    let mut salted_password = [0; SCRAM_KEY_LENGTH];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        password.as_bytes(),
+        &mut salted_password,
+    );
+    Ok(salted_password)
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> ScramKey {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key);
+    let mut output = [0; SCRAM_KEY_LENGTH];
+    output.copy_from_slice(hmac::sign(&key, data).as_ref());
+    output
+}
+
+fn sha256(data: &[u8]) -> ScramKey {
+    let mut output = [0; SCRAM_KEY_LENGTH];
+    output.copy_from_slice(digest::digest(&digest::SHA256, data).as_ref());
+    output
+}
+
+fn decode_key(value: &str) -> Result<ScramKey, IggyError> {
+    text::from_base64_as_bytes(value)?
+        .try_into()
+        .map_err(|_| IggyError::InvalidFormat)
+}
+
+fn fill_random(buffer: &mut [u8]) {
+    SystemRandom::new()
+        .fill(buffer)
+        .expect("Failed to generate random bytes");
+}
+
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .fold(0, |acc, (left, right)| acc | (left ^ right))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vector from RFC 7677, section 3.
+    const USERNAME: &str = "user";
+    const PASSWORD: &str = "pencil";
+    const CLIENT_NONCE: &str = "rOprNGfwEbeRWgbNEkqO";
+    const NONCE: &str = "rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0";
+    const SALT: &str = "W22ZaJ0SNY7soEsUEjb6gQ==";
+    const CLIENT_PROOF: &str = "dHzbZapWIk4jUhN+Ute9ytag9zjfMHgsqmmiz7AndVQ=";
+    const SERVER_SIGNATURE: &str = "6rriTRBi23WpRR/wtup+mMhUZUn/dB5nLTJRsjl95G4=";
+
+    fn rfc_auth_message() -> String {
+        let salt = text::from_base64_as_bytes(SALT).unwrap();
+        auth_message(USERNAME, CLIENT_NONCE, NONCE, &salt, 4096)
+    }
+
+    #[test]
+    fn client_proof_should_match_rfc_test_vector() {
+        let salt = text::from_base64_as_bytes(SALT).unwrap();
+        let proof = ScramClientProof::new(PASSWORD, &salt, 4096, &rfc_auth_message()).unwrap();
+        assert_eq!(text::as_base64(&proof.proof), CLIENT_PROOF);
+        assert_eq!(text::as_base64(&proof.server_signature), SERVER_SIGNATURE);
+    }
+
+    #[test]
+    fn server_should_verify_rfc_client_proof_and_sign() {
+        let salt = text::from_base64_as_bytes(SALT).unwrap();
+        let credentials = ScramCredentials::with_salt(PASSWORD, salt, 4096).unwrap();
+        let auth_message = rfc_auth_message();
+        let client_proof = text::from_base64_as_bytes(CLIENT_PROOF).unwrap();
+        assert!(credentials.verify_client_proof(&auth_message, &client_proof));
+        assert_eq!(
+            text::as_base64(&credentials.server_signature(&auth_message)),
+            SERVER_SIGNATURE
+        );
+    }
+
+    #[test]
+    fn server_should_reject_proof_for_invalid_password() {
+        let credentials = ScramCredentials::new(PASSWORD);
+        let auth_message = auth_message(
+            USERNAME,
+            CLIENT_NONCE,
+            NONCE,
+            &credentials.salt,
+            credentials.iterations,
+        );
+        let proof = ScramClientProof::new(
+            "invalid",
+            &credentials.salt,
+            credentials.iterations,
+            &auth_message,
+        )
+        .unwrap();
+        assert!(!credentials.verify_client_proof(&auth_message, &proof.proof));
+        assert!(!credentials.verify_client_proof(&auth_message, &proof.proof[1..]));
+    }
+
+    #[test]
+    fn credentials_should_roundtrip_through_string() {
+        let credentials = ScramCredentials::new(PASSWORD);
+        let serialized = credentials.to_string();
+        assert!(serialized.starts_with("SCRAM-SHA-256$4096:"));
+        let parsed = serialized.parse::<ScramCredentials>().unwrap();
+        assert_eq!(parsed, credentials);
+    }
+
+    #[test]
+    fn invalid_credentials_string_should_not_be_parsed() {
+        assert!("".parse::<ScramCredentials>().is_err());
+        assert!(
+            "SCRAM-SHA-1$4096:c2FsdA==$a:b"
+                .parse::<ScramCredentials>()
+                .is_err()
+        );
+        assert!(
+            "SCRAM-SHA-256$0:c2FsdA==$a:b"
+                .parse::<ScramCredentials>()
+                .is_err()
+        );
+        assert!(
+            "SCRAM-SHA-256$4096:c2FsdA==$a"
+                .parse::<ScramCredentials>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn auth_message_should_escape_username() {
+        let message = auth_message("a=b,c", "x", "xy", b"salt", 1);
+        assert!(message.starts_with("n=a=3Db=2Cc,r=x,"));
+    }
+
+    #[test]
+    fn generated_nonces_should_be_unique_and_printable() {
+        let first = generate_nonce();
+        let second = generate_nonce();
+        assert_ne!(first, second);
+        assert!(!first.contains(','));
+        assert_eq!(first.len(), 24);
+    }
+}
@@ -23,18 +23,23 @@
 //! since neither the container nor the wire type is local.
 
 use crate::{
-    CacheMetrics, CacheMetricsKey, ClientInfo, ClientInfoDetails, ClusterMetadata, ClusterNode,
-    ClusterNodeRole, ClusterNodeStatus, CompressionAlgorithm, Consumer, ConsumerGroup,
-    ConsumerGroupDetails, ConsumerGroupInfo, ConsumerGroupMember, ConsumerOffsetInfo,
-    GlobalPermissions, HeaderKey, HeaderKind, HeaderValue, IdKind, IdentityInfo, IggyByteSize,
-    IggyError, IggyExpiry, MaxTopicSize, Partition, Permissions, PersonalAccessTokenInfo,
-    RawPersonalAccessToken, Stats, Stream, StreamDetails, StreamPermissions, Topic, TopicDetails,
-    TopicPermissions, TransportEndpoints, UserInfo, UserInfoDetails, UserStatus,
+    AuditLogQuery, AuditOutcome, AuditRecord, CacheMetrics, CacheMetricsKey, CleanupPolicy,
+    ClientInfo, ClientInfoDetails, ClusterMetadata, ClusterNode, ClusterNodeRole,
+    ClusterNodeStatus, CompressionAlgorithm, Consumer, ConsumerGroup, ConsumerGroupDetails,
+    ConsumerGroupInfo, ConsumerGroupLag, ConsumerGroupMember, ConsumerGroupPartitionLag,
+    ConsumerGroupPermissions, ConsumerOffsetInfo, GlobalPermissions, HeaderKey, HeaderKind,
+    HeaderValue, IdKind, IdentityInfo, IggyByteSize, IggyError, IggyExpiry, MaxTopicSize,
+    Partition, Permissions, PersonalAccessTokenInfo, Quota, QuotaScope, RawPersonalAccessToken,
+    RoleInfo, RoleInfoDetails, Stats, Stream, StreamDetails, StreamPatternPermissions,
+    StreamPermissions, Topic, TopicDetails, TopicPatternPermissions, TopicPermissions,
+    TransportEndpoints, UserInfo, UserInfoDetails, UserQuotas, UserStatus,
 };
-use iggy_binary_protocol::WireConsumer;
 use iggy_binary_protocol::primitives::permissions::{
-    WireGlobalPermissions, WirePermissions, WireStreamPermissions, WireTopicPermissions,
+    WireConsumerGroupPermissions, WireGlobalPermissions, WirePermissions,
+    WireStreamPatternPermissions, WireStreamPermissions, WireTopicPatternPermissions,
+    WireTopicPermissions,
 };
+use iggy_binary_protocol::requests::system::GetAuditLogRequest;
 use iggy_binary_protocol::responses::clients::client_response::{
     ClientResponse, ConsumerGroupInfoResponse,
 };
@@ -44,15 +49,22 @@ use iggy_binary_protocol::responses::consumer_groups::consumer_group_response::C
 use iggy_binary_protocol::responses::consumer_groups::get_consumer_group::{
     ConsumerGroupDetailsResponse, ConsumerGroupMemberResponse,
 };
+use iggy_binary_protocol::responses::consumer_groups::get_consumer_group_lag::{
+    ConsumerGroupLagResponse, ConsumerGroupPartitionLagResponse,
+};
 use iggy_binary_protocol::responses::consumer_groups::get_consumer_groups::GetConsumerGroupsResponse;
 use iggy_binary_protocol::responses::consumer_offsets::get_consumer_offset::ConsumerOffsetResponse;
 use iggy_binary_protocol::responses::personal_access_tokens::create_personal_access_token::RawPersonalAccessTokenResponse;
 use iggy_binary_protocol::responses::personal_access_tokens::get_personal_access_tokens::{
     GetPersonalAccessTokensResponse, PersonalAccessTokenResponse,
 };
+use iggy_binary_protocol::responses::roles::{GetRolesResponse, RoleDetailsResponse, RoleResponse};
 use iggy_binary_protocol::responses::streams::StreamResponse;
 use iggy_binary_protocol::responses::streams::get_stream::{GetStreamResponse, TopicHeader};
 use iggy_binary_protocol::responses::streams::get_streams::GetStreamsResponse;
+use iggy_binary_protocol::responses::system::get_audit_log::{
+    AuditLogResponse, AuditRecordResponse,
+};
 use iggy_binary_protocol::responses::system::get_cluster_metadata::{
     ClusterMetadataResponse, ClusterNodeResponse,
 };
@@ -61,7 +73,10 @@ use iggy_binary_protocol::responses::topics::get_topic::{GetTopicResponse, Parti
 use iggy_binary_protocol::responses::topics::get_topics::GetTopicsResponse;
 use iggy_binary_protocol::responses::users::login_user::IdentityResponse;
 use iggy_binary_protocol::responses::users::user_response::UserResponse;
-use iggy_binary_protocol::responses::users::{GetUsersResponse, UserDetailsResponse};
+use iggy_binary_protocol::responses::users::{
+    GetUsersResponse, UserDetailsResponse, UserQuotasResponse,
+};
+use iggy_binary_protocol::{WireConsumer, WireName, WireQuota, WireQuotaScope};
 use std::collections::{BTreeMap, HashMap};
 
 /// Sentinel value in the wire protocol indicating no authenticated user.
@@ -135,6 +150,7 @@ impl TryFrom<TopicHeader> for Topic {
             message_expiry,
             compression_algorithm: CompressionAlgorithm::from_code(w.compression_algorithm)?,
             max_topic_size,
+            cleanup_policy: CleanupPolicy::from_code(w.cleanup_policy)?,
             replication_factor: w.replication_factor,
         })
     }
@@ -170,6 +186,7 @@ impl TryFrom<GetTopicResponse> for TopicDetails {
             message_expiry: topic.message_expiry,
             compression_algorithm: topic.compression_algorithm,
             max_topic_size: topic.max_topic_size,
+            cleanup_policy: topic.cleanup_policy,
             replication_factor: topic.replication_factor,
             partitions_count: topic.partitions_count,
             partitions,
@@ -230,6 +247,36 @@ pub fn users_from_wire(w: GetUsersResponse) -> Result<Vec<UserInfo>, IggyError>
     Ok(users)
 }
 
+impl From<RoleResponse> for RoleInfo {
+    fn from(w: RoleResponse) -> Self {
+        Self {
+            id: w.id,
+            created_at: w.created_at.into(),
+            name: w.name.to_string(),
+            users_count: w.users_count,
+        }
+    }
+}
+
+impl From<RoleDetailsResponse> for RoleInfoDetails {
+    fn from(w: RoleDetailsResponse) -> Self {
+        let role = RoleInfo::from(w.role);
+        Self {
+            id: role.id,
+            created_at: role.created_at,
+            name: role.name,
+            permissions: w.permissions.map(Permissions::from),
+            users: w.user_ids,
+        }
+    }
+}
+
+pub fn roles_from_wire(w: GetRolesResponse) -> Vec<RoleInfo> {
+    let mut roles: Vec<RoleInfo> = w.roles.into_iter().map(RoleInfo::from).collect();
+    roles.sort_by_key(|r| r.id);
+    roles
+}
+
 impl From<IdentityResponse> for IdentityInfo {
     fn from(w: IdentityResponse) -> Self {
         Self {
@@ -239,6 +286,57 @@ impl From<IdentityResponse> for IdentityInfo {
     }
 }
 
+impl From<WireQuotaScope> for QuotaScope {
+    fn from(w: WireQuotaScope) -> Self {
+        match w {
+            WireQuotaScope::User => Self::User,
+            WireQuotaScope::Client => Self::Client,
+        }
+    }
+}
+
+pub fn quota_scope_to_wire(scope: QuotaScope) -> WireQuotaScope {
+    match scope {
+        QuotaScope::User => WireQuotaScope::User,
+        QuotaScope::Client => WireQuotaScope::Client,
+    }
+}
+
+// A zero limit on the wire means the traffic is not restricted.
+impl From<WireQuota> for Quota {
+    fn from(w: WireQuota) -> Self {
+        Self {
+            produce_bytes_per_second: (w.produce_bytes_per_second > 0)
+                .then(|| IggyByteSize::from(w.produce_bytes_per_second)),
+            fetch_bytes_per_second: (w.fetch_bytes_per_second > 0)
+                .then(|| IggyByteSize::from(w.fetch_bytes_per_second)),
+            requests_per_second: (w.requests_per_second > 0).then_some(w.requests_per_second),
+        }
+    }
+}
+
+pub fn quota_to_wire(quota: &Quota) -> WireQuota {
+    WireQuota {
+        produce_bytes_per_second: quota
+            .produce_bytes_per_second
+            .map_or(0, |size| size.as_bytes_u64()),
+        fetch_bytes_per_second: quota
+            .fetch_bytes_per_second
+            .map_or(0, |size| size.as_bytes_u64()),
+        requests_per_second: quota.requests_per_second.unwrap_or(0),
+    }
+}
+
+impl From<UserQuotasResponse> for UserQuotas {
+    fn from(w: UserQuotasResponse) -> Self {
+        Self {
+            user_id: w.user_id,
+            user: w.user.map(Quota::from),
+            client: w.client.map(Quota::from),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Clients
 // ---------------------------------------------------------------------------
@@ -347,6 +445,33 @@ impl From<ConsumerGroupDetailsResponse> for ConsumerGroupDetails {
     }
 }
 
+impl From<ConsumerGroupPartitionLagResponse> for ConsumerGroupPartitionLag {
+    fn from(w: ConsumerGroupPartitionLagResponse) -> Self {
+        Self {
+            partition_id: w.partition_id,
+            committed_offset: w.committed_offset,
+            log_end_offset: w.log_end_offset,
+            lag: w.lag,
+            lag_time: w.lag_time.into(),
+        }
+    }
+}
+
+impl From<ConsumerGroupLagResponse> for ConsumerGroupLag {
+    fn from(w: ConsumerGroupLagResponse) -> Self {
+        let mut partitions: Vec<ConsumerGroupPartitionLag> = w
+            .partitions
+            .into_iter()
+            .map(ConsumerGroupPartitionLag::from)
+            .collect();
+        partitions.sort_by_key(|p| p.partition_id);
+        Self {
+            group_id: w.group_id,
+            partitions,
+        }
+    }
+}
+
 pub fn consumer_groups_from_wire(w: GetConsumerGroupsResponse) -> Vec<ConsumerGroup> {
     let mut groups: Vec<ConsumerGroup> = w.groups.into_iter().map(ConsumerGroup::from).collect();
     groups.sort_by_key(|g| g.id);
@@ -467,6 +592,77 @@ impl From<StatsResponse> for Stats {
 // System - Cluster Metadata
 // ---------------------------------------------------------------------------
 
+// Empty strings and a zero client ID on the wire mean the value is not known.
+impl TryFrom<AuditRecordResponse> for AuditRecord {
+    type Error = IggyError;
+
+    fn try_from(w: AuditRecordResponse) -> Result<Self, Self::Error> {
+        let non_empty = |value: String| (!value.is_empty()).then_some(value);
+        Ok(Self {
+            id: w.id,
+            timestamp: w.timestamp.into(),
+            user_id: w.user_id,
+            username: non_empty(w.username),
+            client_id: (w.client_id > 0).then_some(w.client_id),
+            address: non_empty(w.address),
+            operation: w.operation,
+            details: non_empty(w.details),
+            outcome: AuditOutcome::from_code(w.outcome)?,
+            error: non_empty(w.error),
+            previous_hash: w.previous_hash,
+            hash: w.hash,
+        })
+    }
+}
+
+pub fn audit_log_from_wire(w: AuditLogResponse) -> Result<Vec<AuditRecord>, IggyError> {
+    w.records.into_iter().map(AuditRecord::try_from).collect()
+}
+
+pub fn audit_record_to_wire(record: &AuditRecord) -> AuditRecordResponse {
+    AuditRecordResponse {
+        id: record.id,
+        timestamp: record.timestamp.as_micros(),
+        user_id: record.user_id,
+        client_id: record.client_id.unwrap_or(0),
+        outcome: record.outcome.as_code(),
+        username: record.username.clone().unwrap_or_default(),
+        address: record.address.clone().unwrap_or_default(),
+        operation: record.operation.clone(),
+        details: record.details.clone().unwrap_or_default(),
+        error: record.error.clone().unwrap_or_default(),
+        previous_hash: record.previous_hash.clone(),
+        hash: record.hash.clone(),
+    }
+}
+
+pub fn audit_log_query_to_wire(query: &AuditLogQuery) -> Result<GetAuditLogRequest, IggyError> {
+    let operation = query.operation.clone().unwrap_or_default();
+    if operation.len() > u8::MAX as usize {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(GetAuditLogRequest {
+        user_id: query.user_id,
+        from: query.from.map_or(0, |from| from.as_micros()),
+        to: query.to.map_or(0, |to| to.as_micros()),
+        operation,
+        limit: query.limit.unwrap_or(0),
+    })
+}
+
+impl From<GetAuditLogRequest> for AuditLogQuery {
+    fn from(w: GetAuditLogRequest) -> Self {
+        Self {
+            user_id: w.user_id,
+            from: (w.from > 0).then(|| w.from.into()),
+            to: (w.to > 0).then(|| w.to.into()),
+            operation: (!w.operation.is_empty()).then_some(w.operation),
+            limit: (w.limit > 0).then_some(w.limit),
+        }
+    }
+}
+
 impl TryFrom<ClusterNodeResponse> for ClusterNode {
     type Error = IggyError;
 
@@ -531,6 +727,29 @@ pub fn identifier_to_wire(
     }
 }
 
+/// Convert a domain `DeadLetterPolicy` to `WireDeadLetterPolicy`.
+pub fn dead_letter_policy_to_wire(
+    policy: &crate::DeadLetterPolicy,
+) -> Result<iggy_binary_protocol::requests::consumer_groups::WireDeadLetterPolicy, IggyError> {
+    Ok(
+        iggy_binary_protocol::requests::consumer_groups::WireDeadLetterPolicy {
+            max_delivery_count: policy.max_delivery_count,
+            redelivery_timeout: policy.redelivery_timeout.as_micros(),
+            stream_id: identifier_to_wire(&policy.stream_id)?,
+            topic_id: identifier_to_wire(&policy.topic_id)?,
+        },
+    )
+}
+
+/// Convert a domain `SharedSubscription` to `WireSharedSubscription`.
+pub fn shared_subscription_to_wire(
+    subscription: &crate::SharedSubscription,
+) -> iggy_binary_protocol::requests::consumer_groups::WireSharedSubscription {
+    iggy_binary_protocol::requests::consumer_groups::WireSharedSubscription {
+        visibility_timeout: subscription.visibility_timeout.as_micros(),
+    }
+}
+
 /// Convert a domain `Consumer` to `WireConsumer`.
 pub fn consumer_to_wire(consumer: &Consumer) -> Result<WireConsumer, IggyError> {
     let wire_id = identifier_to_wire(&consumer.id)?;
@@ -600,10 +819,38 @@ fn wire_topic_permissions_to_domain(w: WireTopicPermissions) -> (usize, TopicPer
             read_topic: w.read_topic,
             poll_messages: w.poll_messages,
             send_messages: w.send_messages,
+            consumer_groups: None,
         },
     )
 }
 
+/// Attaches the flattened consumer group permissions to their topic permissions.
+fn attach_wire_consumer_group_permissions(
+    streams: &mut BTreeMap<usize, StreamPermissions>,
+    consumer_groups: Vec<WireConsumerGroupPermissions>,
+) {
+    for w in consumer_groups {
+        streams
+            .entry(w.stream_id as usize)
+            .or_default()
+            .topics
+            .get_or_insert_with(BTreeMap::new)
+            .entry(w.topic_id as usize)
+            .or_default()
+            .consumer_groups
+            .get_or_insert_with(BTreeMap::new)
+            .insert(
+                w.group_id as usize,
+                ConsumerGroupPermissions {
+                    join_group: w.join_group,
+                    commit_offsets: w.commit_offsets,
+                    delete_offsets: w.delete_offsets,
+                    manage_group: w.manage_group,
+                },
+            );
+    }
+}
+
 fn wire_stream_permissions_to_domain(w: WireStreamPermissions) -> (usize, StreamPermissions) {
     let topics: Option<BTreeMap<usize, TopicPermissions>> = if w.topics.is_empty() {
         None
@@ -629,21 +876,65 @@ fn wire_stream_permissions_to_domain(w: WireStreamPermissions) -> (usize, Stream
     )
 }
 
+fn wire_stream_pattern_permissions_to_domain(
+    w: WireStreamPatternPermissions,
+) -> StreamPatternPermissions {
+    let topics: Option<Vec<TopicPatternPermissions>> = if w.topics.is_empty() {
+        None
+    } else {
+        Some(
+            w.topics
+                .into_iter()
+                .map(|topic| TopicPatternPermissions {
+                    pattern: topic.pattern.to_string(),
+                    manage_topic: topic.manage_topic,
+                    read_topic: topic.read_topic,
+                    poll_messages: topic.poll_messages,
+                    send_messages: topic.send_messages,
+                })
+                .collect(),
+        )
+    };
+    StreamPatternPermissions {
+        pattern: w.pattern.to_string(),
+        manage_stream: w.manage_stream,
+        read_stream: w.read_stream,
+        manage_topics: w.manage_topics,
+        read_topics: w.read_topics,
+        poll_messages: w.poll_messages,
+        send_messages: w.send_messages,
+        topics,
+    }
+}
+
 impl From<WirePermissions> for Permissions {
     fn from(w: WirePermissions) -> Self {
-        let streams: Option<BTreeMap<usize, StreamPermissions>> = if w.streams.is_empty() {
+        let mut streams: BTreeMap<usize, StreamPermissions> = w
+            .streams
+            .into_iter()
+            .map(wire_stream_permissions_to_domain)
+            .collect();
+        attach_wire_consumer_group_permissions(&mut streams, w.consumer_groups);
+        let streams = if streams.is_empty() {
+            None
+        } else {
+            Some(streams)
+        };
+        let stream_patterns: Option<Vec<StreamPatternPermissions>> = if w.stream_patterns.is_empty()
+        {
             None
         } else {
             Some(
-                w.streams
+                w.stream_patterns
                     .into_iter()
-                    .map(wire_stream_permissions_to_domain)
+                    .map(wire_stream_pattern_permissions_to_domain)
                     .collect(),
             )
         };
         Self {
             global: GlobalPermissions::from(w.global),
             streams,
+            stream_patterns,
         }
     }
 }
@@ -658,6 +949,9 @@ pub fn wire_permissions_to_permissions(wp: &WirePermissions) -> Permissions {
 // ---------------------------------------------------------------------------
 
 /// Convert domain `Permissions` to `WirePermissions`.
+///
+/// The patterns which cannot be represented on the wire are skipped, thus the permissions
+/// should be checked with `Permissions::validate_patterns` beforehand.
 pub fn permissions_to_wire(perms: &Permissions) -> WirePermissions {
     let streams: Vec<WireStreamPermissions> = perms
         .streams
@@ -682,7 +976,72 @@ pub fn permissions_to_wire(perms: &Permissions) -> WirePermissions {
             send_messages: perms.global.send_messages,
         },
         streams,
+        stream_patterns: perms
+            .stream_patterns
+            .as_ref()
+            .map(|patterns| {
+                patterns
+                    .iter()
+                    .filter_map(stream_pattern_permissions_to_wire)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        consumer_groups: consumer_group_permissions_to_wire(perms),
+    }
+}
+
+/// Flattens the consumer group permissions of all the topics, sorted by stream, topic and group ID.
+fn consumer_group_permissions_to_wire(perms: &Permissions) -> Vec<WireConsumerGroupPermissions> {
+    let mut consumer_groups = Vec::new();
+    for (&stream_id, stream) in perms.streams.iter().flatten() {
+        for (&topic_id, topic) in stream.topics.iter().flatten() {
+            for (&group_id, group) in topic.consumer_groups.iter().flatten() {
+                consumer_groups.push(WireConsumerGroupPermissions {
+                    stream_id: stream_id as u32,
+                    topic_id: topic_id as u32,
+                    group_id: group_id as u32,
+                    join_group: group.join_group,
+                    commit_offsets: group.commit_offsets,
+                    delete_offsets: group.delete_offsets,
+                    manage_group: group.manage_group,
+                });
+            }
+        }
     }
+    consumer_groups
+}
+
+fn stream_pattern_permissions_to_wire(
+    sp: &StreamPatternPermissions,
+) -> Option<WireStreamPatternPermissions> {
+    let topics: Vec<WireTopicPatternPermissions> = sp
+        .topics
+        .as_ref()
+        .map(|topics| {
+            topics
+                .iter()
+                .filter_map(|tp| {
+                    Some(WireTopicPatternPermissions {
+                        pattern: WireName::new(tp.pattern.as_str()).ok()?,
+                        manage_topic: tp.manage_topic,
+                        read_topic: tp.read_topic,
+                        poll_messages: tp.poll_messages,
+                        send_messages: tp.send_messages,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(WireStreamPatternPermissions {
+        pattern: WireName::new(sp.pattern.as_str()).ok()?,
+        manage_stream: sp.manage_stream,
+        read_stream: sp.read_stream,
+        manage_topics: sp.manage_topics,
+        read_topics: sp.read_topics,
+        poll_messages: sp.poll_messages,
+        send_messages: sp.send_messages,
+        topics,
+    })
 }
 
 fn stream_permissions_to_wire(stream_id: usize, sp: &StreamPermissions) -> WireStreamPermissions {
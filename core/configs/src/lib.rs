@@ -29,7 +29,7 @@ pub use configs_impl::{
 };
 pub use server_config::{
     COMPONENT, cache_indexes, cluster, defaults, displays, http, quic, server, sharding, system,
-    tcp, validators, websocket,
+    tcp, tls, validators, websocket,
 };
 pub use server_ng_config::{
     COMPONENT_NG, message_bus, quic as ng_quic, server_ng, tcp as ng_tcp, websocket as ng_websocket,
@@ -20,19 +20,20 @@ use super::cluster::{ClusterConfig, ClusterNodeConfig, TransportPorts};
 use super::http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig};
 use super::quic::{QuicCertificateConfig, QuicConfig, QuicSocketConfig};
 use super::server::{
-    ConsumerGroupConfig, DataMaintenanceConfig, HeartbeatConfig, MemoryPoolConfig,
-    MessageSaverConfig, MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig,
-    PersonalAccessTokenConfig, ServerConfig, TelemetryConfig, TelemetryLogsConfig,
-    TelemetryTracesConfig,
+    ArchiverConfig, CompactionMaintenanceConfig, ConsumerGroupConfig, DataMaintenanceConfig,
+    DiskArchiverConfig, HeartbeatConfig, MemoryPoolConfig, MessageSaverConfig,
+    MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig,
+    S3ArchiverConfig, ServerConfig, TelemetryConfig, TelemetryLogsConfig, TelemetryTracesConfig,
 };
 use super::sharding::ShardingConfig;
 use super::system::{
-    BackupConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig, LoggingConfig,
-    MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig, SegmentConfig,
-    StateConfig, StreamConfig, SystemConfig, TopicConfig,
+    AuditConfig, BackupConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig,
+    LoggingConfig, MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig,
+    SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
 };
 use super::tcp::TcpSocketConfig;
 use super::tcp::{TcpConfig, TcpTlsConfig};
+use super::tls::TlsClientAuthConfig;
 use super::websocket::{WebSocketConfig, WebSocketTlsConfig};
 use iggy_common::IggyByteSize;
 use iggy_common::IggyDuration;
@@ -77,6 +78,117 @@ impl Default for MessagesMaintenanceConfig {
     }
 }
 
+impl Default for CompactionMaintenanceConfig {
+    fn default() -> CompactionMaintenanceConfig {
+        CompactionMaintenanceConfig {
+            enabled: SERVER_CONFIG.data_maintenance.compaction.enabled,
+            interval: SERVER_CONFIG
+                .data_maintenance
+                .compaction
+                .interval
+                .parse()
+                .unwrap(),
+            tombstone_retention: SERVER_CONFIG
+                .data_maintenance
+                .compaction
+                .tombstone_retention
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for ArchiverConfig {
+    fn default() -> ArchiverConfig {
+        ArchiverConfig {
+            enabled: SERVER_CONFIG.data_maintenance.archiver.enabled,
+            interval: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .interval
+                .parse()
+                .unwrap(),
+            offload_after: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .offload_after
+                .parse()
+                .unwrap(),
+            kind: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .kind
+                .parse()
+                .unwrap(),
+            disk: DiskArchiverConfig::default(),
+            s3: S3ArchiverConfig::default(),
+        }
+    }
+}
+
+impl Default for DiskArchiverConfig {
+    fn default() -> DiskArchiverConfig {
+        DiskArchiverConfig {
+            path: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .disk
+                .path
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for S3ArchiverConfig {
+    fn default() -> S3ArchiverConfig {
+        S3ArchiverConfig {
+            key_id: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .s_3
+                .key_id
+                .parse()
+                .unwrap(),
+            key_secret: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .s_3
+                .key_secret
+                .parse()
+                .unwrap(),
+            bucket: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .s_3
+                .bucket
+                .parse()
+                .unwrap(),
+            endpoint: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .s_3
+                .endpoint
+                .parse()
+                .unwrap(),
+            region: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .s_3
+                .region
+                .parse()
+                .unwrap(),
+            prefix: SERVER_CONFIG
+                .data_maintenance
+                .archiver
+                .s_3
+                .prefix
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
 impl Default for QuicConfig {
     fn default() -> QuicConfig {
         QuicConfig {
@@ -116,6 +228,24 @@ impl Default for QuicCertificateConfig {
             self_signed: SERVER_CONFIG.quic.certificate.self_signed,
             cert_file: SERVER_CONFIG.quic.certificate.cert_file.parse().unwrap(),
             key_file: SERVER_CONFIG.quic.certificate.key_file.parse().unwrap(),
+            client_auth: TlsClientAuthConfig {
+                enabled: SERVER_CONFIG.quic.certificate.client_auth.enabled,
+                required: SERVER_CONFIG.quic.certificate.client_auth.required,
+                ca_file: SERVER_CONFIG
+                    .quic
+                    .certificate
+                    .client_auth
+                    .ca_file
+                    .parse()
+                    .unwrap(),
+                principal: SERVER_CONFIG
+                    .quic
+                    .certificate
+                    .client_auth
+                    .principal
+                    .parse()
+                    .unwrap(),
+            },
         }
     }
 }
@@ -140,6 +270,12 @@ impl Default for TcpTlsConfig {
             self_signed: SERVER_CONFIG.tcp.tls.self_signed,
             cert_file: SERVER_CONFIG.tcp.tls.cert_file.parse().unwrap(),
             key_file: SERVER_CONFIG.tcp.tls.key_file.parse().unwrap(),
+            client_auth: TlsClientAuthConfig {
+                enabled: SERVER_CONFIG.tcp.tls.client_auth.enabled,
+                required: SERVER_CONFIG.tcp.tls.client_auth.required,
+                ca_file: SERVER_CONFIG.tcp.tls.client_auth.ca_file.parse().unwrap(),
+                principal: SERVER_CONFIG.tcp.tls.client_auth.principal.parse().unwrap(),
+            },
         }
     }
 }
@@ -180,6 +316,24 @@ impl Default for WebSocketTlsConfig {
             self_signed: SERVER_CONFIG.websocket.tls.self_signed,
             cert_file: SERVER_CONFIG.websocket.tls.cert_file.parse().unwrap(),
             key_file: SERVER_CONFIG.websocket.tls.key_file.parse().unwrap(),
+            client_auth: TlsClientAuthConfig {
+                enabled: SERVER_CONFIG.websocket.tls.client_auth.enabled,
+                required: SERVER_CONFIG.websocket.tls.client_auth.required,
+                ca_file: SERVER_CONFIG
+                    .websocket
+                    .tls
+                    .client_auth
+                    .ca_file
+                    .parse()
+                    .unwrap(),
+                principal: SERVER_CONFIG
+                    .websocket
+                    .tls
+                    .client_auth
+                    .principal
+                    .parse()
+                    .unwrap(),
+            },
         }
     }
 }
@@ -327,6 +481,7 @@ impl Default for SystemConfig {
             backup: BackupConfig::default(),
             runtime: RuntimeConfig::default(),
             logging: LoggingConfig::default(),
+            audit: AuditConfig::default(),
             stream: StreamConfig::default(),
             encryption: EncryptionConfig::default(),
             topic: TopicConfig::default(),
@@ -438,11 +593,28 @@ impl Default for LoggingConfig {
     }
 }
 
+impl Default for AuditConfig {
+    fn default() -> AuditConfig {
+        AuditConfig {
+            enabled: SERVER_CONFIG.system.audit.enabled,
+            path: SERVER_CONFIG.system.audit.path.parse().unwrap(),
+            max_file_size: SERVER_CONFIG.system.audit.max_file_size.parse().unwrap(),
+            max_files: SERVER_CONFIG.system.audit.max_files as u32,
+        }
+    }
+}
+
 impl Default for EncryptionConfig {
     fn default() -> EncryptionConfig {
         EncryptionConfig {
             enabled: SERVER_CONFIG.system.encryption.enabled,
             key: SERVER_CONFIG.system.encryption.key.parse().unwrap(),
+            master_key_path: SERVER_CONFIG
+                .system
+                .encryption
+                .master_key_path
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -515,6 +687,12 @@ impl Default for MessageDeduplicationConfig {
                 .expiry
                 .parse()
                 .unwrap(),
+            producer_expiry: SERVER_CONFIG
+                .system
+                .message_deduplication
+                .producer_expiry
+                .parse()
+                .unwrap(),
         }
     }
 }
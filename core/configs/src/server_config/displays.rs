@@ -19,18 +19,20 @@
 
 use super::quic::{QuicCertificateConfig, QuicConfig};
 use super::server::{
-    ConsumerGroupConfig, DataMaintenanceConfig, HeartbeatConfig, MessagesMaintenanceConfig,
-    TelemetryConfig, TelemetryLogsConfig, TelemetryTracesConfig,
+    ArchiverConfig, CompactionMaintenanceConfig, ConsumerGroupConfig, DataMaintenanceConfig,
+    HeartbeatConfig, MessagesMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig,
+    TelemetryTracesConfig,
 };
 use super::system::MessageDeduplicationConfig;
 use super::{
     http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
     server::{MessageSaverConfig, ServerConfig},
     system::{
-        CompressionConfig, EncryptionConfig, LoggingConfig, PartitionConfig, SegmentConfig,
-        StateConfig, StreamConfig, SystemConfig, TopicConfig,
+        AuditConfig, CompressionConfig, EncryptionConfig, LoggingConfig, PartitionConfig,
+        SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
     },
     tcp::{TcpConfig, TcpSocketConfig, TcpTlsConfig},
+    tls::TlsClientAuthConfig,
 };
 use std::fmt::{Display, Formatter};
 
@@ -120,8 +122,8 @@ impl Display for QuicCertificateConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ self_signed: {}, cert_file: {}, key_file: {} }}",
-            self.self_signed, self.cert_file, self.key_file
+            "{{ self_signed: {}, cert_file: {}, key_file: {}, client_auth: {} }}",
+            self.self_signed, self.cert_file, self.key_file, self.client_auth
         )
     }
 }
@@ -138,7 +140,39 @@ impl Display for CompressionConfig {
 
 impl Display for DataMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ messages: {} }}", self.messages)
+        write!(
+            f,
+            "{{ messages: {}, compaction: {}, archiver: {} }}",
+            self.messages, self.compaction, self.archiver
+        )
+    }
+}
+
+impl Display for CompactionMaintenanceConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, tombstone_retention: {} }}",
+            self.enabled, self.interval, self.tombstone_retention
+        )
+    }
+}
+
+impl Display for ArchiverConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, offload_after: {}, kind: {}, disk_path: {}, s3_bucket: {}, s3_endpoint: {}, s3_region: {}, s3_prefix: {} }}",
+            self.enabled,
+            self.interval,
+            self.offload_after,
+            self.kind,
+            self.disk.path,
+            self.s3.bucket,
+            self.s3.endpoint,
+            self.s3.region,
+            self.s3.prefix
+        )
     }
 }
 
@@ -200,9 +234,23 @@ impl Display for HeartbeatConfig {
     }
 }
 
+impl Display for AuditConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, path: {}, max_file_size: {}, max_files: {} }}",
+            self.enabled, self.path, self.max_file_size, self.max_files
+        )
+    }
+}
+
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ enabled: {} }}", self.enabled)
+        write!(
+            f,
+            "{{ enabled: {}, master_key_path: {} }}",
+            self.enabled, self.master_key_path
+        )
     }
 }
 
@@ -240,8 +288,8 @@ impl Display for MessageDeduplicationConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, max_entries: {:?}, expiry: {:?} }}",
-            self.enabled, self.max_entries, self.expiry
+            "{{ enabled: {}, max_entries: {:?}, expiry: {:?}, producer_expiry: {:?} }}",
+            self.enabled, self.max_entries, self.expiry, self.producer_expiry
         )
     }
 }
@@ -286,8 +334,18 @@ impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, self_signed: {}, cert_file: {}, key_file: {} }}",
-            self.enabled, self.self_signed, self.cert_file, self.key_file
+            "{{ enabled: {}, self_signed: {}, cert_file: {}, key_file: {}, client_auth: {} }}",
+            self.enabled, self.self_signed, self.cert_file, self.key_file, self.client_auth
+        )
+    }
+}
+
+impl Display for TlsClientAuthConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, required: {}, ca_file: {}, principal: {} }}",
+            self.enabled, self.required, self.ca_file, self.principal
         )
     }
 }
@@ -351,9 +409,10 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, logging: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {} }}",
+            "{{ path: {}, logging: {}, audit: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {} }}",
             self.path,
             self.logging,
+            self.audit,
             self.stream,
             self.topic,
             self.partition,
@@ -33,6 +33,16 @@ pub struct TrustedIssuerConfig {
     pub jwks_url: String,
     #[serde(default)]
     pub user_id: u32,
+    /// Claim holding the username the binary token login maps to, when `user_id` is not set.
+    #[serde(default)]
+    pub username_claim: Option<String>,
+    /// Create the user mapped by `username_claim` on the first binary token login.
+    #[serde(default)]
+    pub auto_provision: bool,
+    /// Claim listing the global permissions granted to the auto-provisioned user,
+    /// either as an array or a space-separated string (e.g. `"read_streams poll_messages"`).
+    #[serde(default)]
+    pub permissions_claim: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, ConfigEnv)]
@@ -27,6 +27,7 @@ pub mod server;
 pub mod sharding;
 pub mod system;
 pub mod tcp;
+pub mod tls;
 pub mod validators;
 pub mod websocket;
 
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use super::tls::TlsClientAuthConfig;
 use configs::ConfigEnv;
 use iggy_common::IggyByteSize;
 use iggy_common::IggyDuration;
@@ -62,4 +63,5 @@ pub struct QuicCertificateConfig {
     pub self_signed: bool,
     pub cert_file: String,
     pub key_file: String,
+    pub client_auth: TlsClientAuthConfig,
 }
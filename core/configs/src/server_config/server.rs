@@ -80,6 +80,8 @@ impl MemoryPoolConfig {
 #[derive(Debug, Default, Deserialize, Serialize, Clone, ConfigEnv)]
 pub struct DataMaintenanceConfig {
     pub messages: MessagesMaintenanceConfig,
+    pub compaction: CompactionMaintenanceConfig,
+    pub archiver: ArchiverConfig,
 }
 
 #[serde_as]
@@ -91,6 +93,70 @@ pub struct MessagesMaintenanceConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone, ConfigEnv)]
+pub struct CompactionMaintenanceConfig {
+    pub enabled: bool,
+    #[config_env(leaf)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    #[config_env(leaf)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub tombstone_retention: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone, ConfigEnv)]
+pub struct ArchiverConfig {
+    pub enabled: bool,
+    #[config_env(leaf)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    #[config_env(leaf)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub offload_after: IggyDuration,
+    #[config_env(leaf)]
+    pub kind: ArchiverType,
+    pub disk: DiskArchiverConfig,
+    pub s3: S3ArchiverConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchiverType {
+    #[display("disk")]
+    Disk,
+    #[display("s3")]
+    S3,
+}
+
+impl FromStr for ArchiverType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "disk" => Ok(ArchiverType::Disk),
+            "s3" => Ok(ArchiverType::S3),
+            _ => Err(format!("Invalid archiver type: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ConfigEnv)]
+pub struct DiskArchiverConfig {
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ConfigEnv)]
+pub struct S3ArchiverConfig {
+    pub key_id: String,
+    #[config_env(secret)]
+    pub key_secret: String,
+    pub bucket: String,
+    pub endpoint: String,
+    pub region: String,
+    pub prefix: String,
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone, ConfigEnv)]
 pub struct MessageSaverConfig {
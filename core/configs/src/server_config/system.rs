@@ -31,6 +31,7 @@ use serde_with::serde_as;
 
 pub const INDEX_EXTENSION: &str = "index";
 pub const LOG_EXTENSION: &str = "log";
+pub const OFFLOADED_EXTENSION: &str = "offloaded";
 
 #[derive(Debug, Deserialize, Serialize, ConfigEnv)]
 pub struct SystemConfig {
@@ -39,6 +40,7 @@ pub struct SystemConfig {
     pub state: StateConfig,
     pub runtime: RuntimeConfig,
     pub logging: LoggingConfig,
+    pub audit: AuditConfig,
     pub stream: StreamConfig,
     pub topic: TopicConfig,
     pub partition: PartitionConfig,
@@ -100,11 +102,21 @@ pub struct LoggingConfig {
     pub sysinfo_print_interval: IggyDuration,
 }
 
+#[derive(Debug, Deserialize, Serialize, ConfigEnv)]
+pub struct AuditConfig {
+    pub enabled: bool,
+    pub path: String,
+    #[config_env(leaf)]
+    pub max_file_size: IggyByteSize,
+    pub max_files: u32,
+}
+
 #[derive(Debug, Deserialize, Serialize, ConfigEnv)]
 pub struct EncryptionConfig {
     pub enabled: bool,
     #[config_env(secret)]
     pub key: String,
+    pub master_key_path: String,
 }
 
 #[derive(Debug, Deserialize, Serialize, ConfigEnv)]
@@ -142,6 +154,9 @@ pub struct MessageDeduplicationConfig {
     #[config_env(leaf)]
     #[serde_as(as = "DisplayFromStr")]
     pub expiry: IggyDuration,
+    #[config_env(leaf)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub producer_expiry: IggyDuration,
 }
 
 #[derive(Debug, Deserialize, Serialize, ConfigEnv)]
@@ -189,10 +204,19 @@ impl SystemConfig {
         format!("{}/tokens", self.get_state_path())
     }
 
+    /// Directory holding the commit decisions of transactions which are not yet fully applied.
+    pub fn get_state_transactions_path(&self) -> String {
+        format!("{}/transactions", self.get_state_path())
+    }
+
     pub fn get_backup_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.backup.path)
     }
 
+    pub fn get_audit_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.audit.path)
+    }
+
     pub fn get_runtime_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.runtime.path)
     }
@@ -246,6 +270,42 @@ impl SystemConfig {
         )
     }
 
+    pub fn get_partition_transactions_path(
+        &self,
+        stream_id: usize,
+        topic_id: usize,
+        partition_id: usize,
+    ) -> String {
+        format!(
+            "{}/transactions",
+            self.get_partition_path(stream_id, topic_id, partition_id)
+        )
+    }
+
+    pub fn get_partition_producers_path(
+        &self,
+        stream_id: usize,
+        topic_id: usize,
+        partition_id: usize,
+    ) -> String {
+        format!(
+            "{}/producers",
+            self.get_partition_path(stream_id, topic_id, partition_id)
+        )
+    }
+
+    pub fn get_partition_schedule_path(
+        &self,
+        stream_id: usize,
+        topic_id: usize,
+        partition_id: usize,
+    ) -> String {
+        format!(
+            "{}/schedule",
+            self.get_partition_path(stream_id, topic_id, partition_id)
+        )
+    }
+
     pub fn get_consumer_offsets_path(
         &self,
         stream_id: usize,
@@ -270,6 +330,18 @@ impl SystemConfig {
         )
     }
 
+    pub fn get_consumer_group_acks_path(
+        &self,
+        stream_id: usize,
+        topic_id: usize,
+        partition_id: usize,
+    ) -> String {
+        format!(
+            "{}/acks",
+            self.get_offsets_path(stream_id, topic_id, partition_id)
+        )
+    }
+
     pub fn get_segment_path(
         &self,
         stream_id: usize,
@@ -306,6 +378,18 @@ impl SystemConfig {
         format!("{path}.{INDEX_EXTENSION}")
     }
 
+    /// Path of the empty marker file left in place of the messages file of an offloaded segment.
+    pub fn get_offloaded_marker_path(
+        &self,
+        stream_id: usize,
+        topic_id: usize,
+        partition_id: usize,
+        start_offset: u64,
+    ) -> String {
+        let path = self.get_segment_path(stream_id, topic_id, partition_id, start_offset);
+        format!("{path}.{OFFLOADED_EXTENSION}")
+    }
+
     pub fn resolve_max_topic_size(
         &self,
         max_topic_size: MaxTopicSize,
@@ -331,4 +415,15 @@ impl SystemConfig {
             _ => message_expiry,
         }
     }
+
+    pub fn resolve_compression_algorithm(
+        &self,
+        compression_algorithm: CompressionAlgorithm,
+    ) -> CompressionAlgorithm {
+        if self.compression.allow_override {
+            compression_algorithm
+        } else {
+            self.compression.default_algorithm
+        }
+    }
 }
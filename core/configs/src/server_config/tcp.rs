@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use super::tls::TlsClientAuthConfig;
 use configs::ConfigEnv;
 use iggy_common::{IggyByteSize, IggyDuration};
 use serde::{Deserialize, Serialize};
@@ -38,6 +39,7 @@ pub struct TcpTlsConfig {
     pub self_signed: bool,
     pub cert_file: String,
     pub key_file: String,
+    pub client_auth: TlsClientAuthConfig,
 }
 
 #[serde_as]
@@ -0,0 +1,56 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use configs::ConfigEnv;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Mutual TLS settings shared by the TCP, WebSocket and QUIC listeners.
+///
+/// When enabled, clients may present a certificate signed by `ca_file`; the
+/// configured principal is read from the certificate and mapped to an existing
+/// user, so the connection is authenticated without an explicit login.
+#[derive(Debug, Deserialize, Serialize, Clone, ConfigEnv)]
+pub struct TlsClientAuthConfig {
+    pub enabled: bool,
+    pub required: bool,
+    pub ca_file: String,
+    #[config_env(leaf)]
+    pub principal: ClientCertificatePrincipal,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientCertificatePrincipal {
+    #[display("common_name")]
+    CommonName,
+    #[display("subject_alt_name")]
+    SubjectAltName,
+}
+
+impl FromStr for ClientCertificatePrincipal {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "common_name" => Ok(ClientCertificatePrincipal::CommonName),
+            "subject_alt_name" => Ok(ClientCertificatePrincipal::SubjectAltName),
+            _ => Err(format!("Invalid client certificate principal: {s}")),
+        }
+    }
+}
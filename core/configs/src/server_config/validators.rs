@@ -20,12 +20,14 @@
 use super::COMPONENT;
 use super::cluster::ClusterConfig;
 use super::server::{
-    DataMaintenanceConfig, MessageSaverConfig, MessagesMaintenanceConfig, TelemetryConfig,
+    ArchiverConfig, ArchiverType, CompactionMaintenanceConfig, DataMaintenanceConfig,
+    MessageSaverConfig, MessagesMaintenanceConfig, TelemetryConfig,
 };
 use super::server::{MemoryPoolConfig, PersonalAccessTokenConfig, ServerConfig};
 use super::sharding::{CpuAllocation, ShardingConfig};
 use super::system::SegmentConfig;
-use super::system::{CompressionConfig, LoggingConfig, PartitionConfig};
+use super::system::{AuditConfig, CompressionConfig, LoggingConfig, PartitionConfig};
+use super::tls::TlsClientAuthConfig;
 use crate::ConfigurationError;
 use err_trail::ErrContext;
 use iggy_common::CompressionAlgorithm;
@@ -33,7 +35,7 @@ use iggy_common::IggyExpiry;
 use iggy_common::MaxTopicSize;
 use iggy_common::Validatable;
 use std::thread::available_parallelism;
-use tracing::warn;
+use tracing::{info, warn};
 
 /// 1 GiB max segment size. Canonical definition; re-exported by core/server streaming.
 pub const SEGMENT_MAX_SIZE_BYTES: u64 = 1024 * 1024 * 1024;
@@ -123,6 +125,30 @@ impl Validatable<ConfigurationError> for ServerConfig {
             );
         }
 
+        self.tcp
+            .tls
+            .client_auth
+            .validate()
+            .error(|e: &ConfigurationError| {
+                format!("{COMPONENT} (error: {e}) - failed to validate TCP TLS client auth config")
+            })?;
+        self.quic
+            .certificate
+            .client_auth
+            .validate()
+            .error(|e: &ConfigurationError| {
+                format!("{COMPONENT} (error: {e}) - failed to validate QUIC client auth config")
+            })?;
+        self.websocket
+            .tls
+            .client_auth
+            .validate()
+            .error(|e: &ConfigurationError| {
+                format!(
+                    "{COMPONENT} (error: {e}) - failed to validate WebSocket TLS client auth config"
+                )
+            })?;
+
         self.system
             .logging
             .validate()
@@ -130,6 +156,13 @@ impl Validatable<ConfigurationError> for ServerConfig {
                 format!("{COMPONENT} (error: {e}) - failed to validate logging config")
             })?;
 
+        self.system
+            .audit
+            .validate()
+            .error(|e: &ConfigurationError| {
+                format!("{COMPONENT} (error: {e}) - failed to validate audit config")
+            })?;
+
         let topic_size = match self.system.topic.max_size {
             MaxTopicSize::Custom(size) => Ok(size.as_bytes_u64()),
             MaxTopicSize::Unlimited => Ok(u64::MAX),
@@ -166,11 +199,10 @@ impl Validatable<ConfigurationError> for ServerConfig {
 
 impl Validatable<ConfigurationError> for CompressionConfig {
     fn validate(&self) -> Result<(), ConfigurationError> {
-        let compression_alg = &self.default_algorithm;
-        if *compression_alg != CompressionAlgorithm::None {
-            // TODO(numinex): Change this message once server side compression is fully developed.
-            warn!(
-                "Server started with server-side compression enabled, using algorithm: {compression_alg}, this feature is not implemented yet!"
+        if !self.allow_override && self.default_algorithm != CompressionAlgorithm::None {
+            info!(
+                "Compression override is disabled, all topics will use the default algorithm: {}",
+                self.default_algorithm
             );
         }
 
@@ -253,6 +285,12 @@ impl Validatable<ConfigurationError> for DataMaintenanceConfig {
         self.messages.validate().error(|e: &ConfigurationError| {
             format!("{COMPONENT} (error: {e}) - failed to validate messages maintenance config")
         })?;
+        self.compaction.validate().error(|e: &ConfigurationError| {
+            format!("{COMPONENT} (error: {e}) - failed to validate compaction maintenance config")
+        })?;
+        self.archiver.validate().error(|e: &ConfigurationError| {
+            format!("{COMPONENT} (error: {e}) - failed to validate archiver config")
+        })?;
         Ok(())
     }
 }
@@ -268,6 +306,74 @@ impl Validatable<ConfigurationError> for MessagesMaintenanceConfig {
     }
 }
 
+impl Validatable<ConfigurationError> for CompactionMaintenanceConfig {
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        if self.enabled && self.interval.is_zero() {
+            eprintln!(
+                "data_maintenance.compaction.interval cannot be zero when compaction is enabled"
+            );
+            return Err(ConfigurationError::InvalidConfigurationValue);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigurationError> for ArchiverConfig {
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.interval.is_zero() {
+            eprintln!("data_maintenance.archiver.interval cannot be zero when archiver is enabled");
+            return Err(ConfigurationError::InvalidConfigurationValue);
+        }
+
+        match self.kind {
+            ArchiverType::Disk => {
+                if self.disk.path.is_empty() {
+                    eprintln!("data_maintenance.archiver.disk.path cannot be empty");
+                    return Err(ConfigurationError::InvalidConfigurationValue);
+                }
+            }
+            ArchiverType::S3 => {
+                if self.s3.bucket.is_empty() {
+                    eprintln!("data_maintenance.archiver.s3.bucket cannot be empty");
+                    return Err(ConfigurationError::InvalidConfigurationValue);
+                }
+                if self.s3.region.is_empty() {
+                    eprintln!("data_maintenance.archiver.s3.region cannot be empty");
+                    return Err(ConfigurationError::InvalidConfigurationValue);
+                }
+                if self.s3.key_id.is_empty() || self.s3.key_secret.is_empty() {
+                    eprintln!(
+                        "data_maintenance.archiver.s3.key_id and key_secret must be set for the S3 archiver"
+                    );
+                    return Err(ConfigurationError::InvalidConfigurationValue);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigurationError> for TlsClientAuthConfig {
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.ca_file.is_empty() {
+            eprintln!("client_auth.ca_file cannot be empty when client authentication is enabled");
+            return Err(ConfigurationError::InvalidConfigurationValue);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigurationError> for PersonalAccessTokenConfig {
     fn validate(&self) -> Result<(), ConfigurationError> {
         if self.max_tokens_per_user == 0 {
@@ -324,6 +430,26 @@ impl Validatable<ConfigurationError> for LoggingConfig {
     }
 }
 
+impl Validatable<ConfigurationError> for AuditConfig {
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.max_file_size.as_bytes_u64() == 0 {
+            eprintln!("Configured system.audit.max_file_size cannot be 0");
+            return Err(ConfigurationError::InvalidConfigurationValue);
+        }
+
+        if self.max_files == 0 {
+            eprintln!("Configured system.audit.max_files cannot be 0");
+            return Err(ConfigurationError::InvalidConfigurationValue);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigurationError> for MemoryPoolConfig {
     fn validate(&self) -> Result<(), ConfigurationError> {
         if self.enabled && self.size == 0 {
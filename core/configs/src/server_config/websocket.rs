@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use super::tls::TlsClientAuthConfig;
 use configs::ConfigEnv;
 use iggy_common::IggyByteSize;
 use serde::{Deserialize, Serialize};
@@ -48,6 +49,7 @@ pub struct WebSocketTlsConfig {
     pub self_signed: bool,
     pub cert_file: String,
     pub key_file: String,
+    pub client_auth: TlsClientAuthConfig,
 }
 
 impl WebSocketConfig {
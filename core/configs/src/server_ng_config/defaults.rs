@@ -41,6 +41,7 @@ use crate::server_config::server::{
     PersonalAccessTokenConfig, TelemetryConfig,
 };
 use crate::server_config::system::SystemConfig;
+use crate::server_config::tls::TlsClientAuthConfig;
 use std::sync::Arc;
 
 static_toml::static_toml! {
@@ -118,6 +119,24 @@ impl Default for QuicCertificateConfig {
             self_signed: SERVER_NG_CONFIG.quic.certificate.self_signed,
             cert_file: SERVER_NG_CONFIG.quic.certificate.cert_file.parse().unwrap(),
             key_file: SERVER_NG_CONFIG.quic.certificate.key_file.parse().unwrap(),
+            client_auth: TlsClientAuthConfig {
+                enabled: SERVER_NG_CONFIG.quic.certificate.client_auth.enabled,
+                required: SERVER_NG_CONFIG.quic.certificate.client_auth.required,
+                ca_file: SERVER_NG_CONFIG
+                    .quic
+                    .certificate
+                    .client_auth
+                    .ca_file
+                    .parse()
+                    .unwrap(),
+                principal: SERVER_NG_CONFIG
+                    .quic
+                    .certificate
+                    .client_auth
+                    .principal
+                    .parse()
+                    .unwrap(),
+            },
         }
     }
 }
@@ -142,6 +161,24 @@ impl Default for TcpTlsConfig {
             self_signed: SERVER_NG_CONFIG.tcp.tls.self_signed,
             cert_file: SERVER_NG_CONFIG.tcp.tls.cert_file.parse().unwrap(),
             key_file: SERVER_NG_CONFIG.tcp.tls.key_file.parse().unwrap(),
+            client_auth: TlsClientAuthConfig {
+                enabled: SERVER_NG_CONFIG.tcp.tls.client_auth.enabled,
+                required: SERVER_NG_CONFIG.tcp.tls.client_auth.required,
+                ca_file: SERVER_NG_CONFIG
+                    .tcp
+                    .tls
+                    .client_auth
+                    .ca_file
+                    .parse()
+                    .unwrap(),
+                principal: SERVER_NG_CONFIG
+                    .tcp
+                    .tls
+                    .client_auth
+                    .principal
+                    .parse()
+                    .unwrap(),
+            },
         }
     }
 }
@@ -192,6 +229,24 @@ impl Default for WebSocketTlsConfig {
             self_signed: SERVER_NG_CONFIG.websocket.tls.self_signed,
             cert_file: SERVER_NG_CONFIG.websocket.tls.cert_file.parse().unwrap(),
             key_file: SERVER_NG_CONFIG.websocket.tls.key_file.parse().unwrap(),
+            client_auth: TlsClientAuthConfig {
+                enabled: SERVER_NG_CONFIG.websocket.tls.client_auth.enabled,
+                required: SERVER_NG_CONFIG.websocket.tls.client_auth.required,
+                ca_file: SERVER_NG_CONFIG
+                    .websocket
+                    .tls
+                    .client_auth
+                    .ca_file
+                    .parse()
+                    .unwrap(),
+                principal: SERVER_NG_CONFIG
+                    .websocket
+                    .tls
+                    .client_auth
+                    .principal
+                    .parse()
+                    .unwrap(),
+            },
         }
     }
 }
@@ -106,8 +106,8 @@ impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, self_signed: {}, cert_file: {}, key_file: {} }}",
-            self.enabled, self.self_signed, self.cert_file, self.key_file
+            "{{ enabled: {}, self_signed: {}, cert_file: {}, key_file: {}, client_auth: {} }}",
+            self.enabled, self.self_signed, self.cert_file, self.key_file, self.client_auth
         )
     }
 }
@@ -150,8 +150,8 @@ impl Display for QuicCertificateConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ self_signed: {}, cert_file: {}, key_file: {} }}",
-            self.self_signed, self.cert_file, self.key_file
+            "{{ self_signed: {}, cert_file: {}, key_file: {}, client_auth: {} }}",
+            self.self_signed, self.cert_file, self.key_file, self.client_auth
         )
     }
 }
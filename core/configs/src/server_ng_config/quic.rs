@@ -25,6 +25,7 @@
 
 use super::COMPONENT_NG;
 use crate::ConfigurationError;
+use crate::server_config::tls::TlsClientAuthConfig;
 use configs::ConfigEnv;
 use iggy_common::{IggyByteSize, IggyDuration, Validatable};
 use serde::{Deserialize, Serialize};
@@ -70,6 +71,7 @@ pub struct QuicCertificateConfig {
     pub self_signed: bool,
     pub cert_file: String,
     pub key_file: String,
+    pub client_auth: TlsClientAuthConfig,
 }
 
 /// Validates the field range constraints the runtime conversion in
@@ -141,6 +143,7 @@ impl Validatable<ConfigurationError> for QuicConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::server_config::tls::ClientCertificatePrincipal;
 
     fn baseline() -> QuicConfig {
         QuicConfig {
@@ -157,6 +160,12 @@ mod tests {
                 self_signed: false,
                 cert_file: String::new(),
                 key_file: String::new(),
+                client_auth: TlsClientAuthConfig {
+                    enabled: false,
+                    required: false,
+                    ca_file: String::new(),
+                    principal: ClientCertificatePrincipal::CommonName,
+                },
             },
             socket: QuicSocketConfig {
                 override_defaults: false,
@@ -23,6 +23,7 @@
 //! its TCP/TLS surface (additional knobs, removed knobs) independently
 //! of the legacy server. No semantic change at fork time.
 
+use crate::server_config::tls::TlsClientAuthConfig;
 use configs::ConfigEnv;
 use iggy_common::{IggyByteSize, IggyDuration};
 use serde::{Deserialize, Serialize};
@@ -45,6 +46,7 @@ pub struct TcpTlsConfig {
     pub self_signed: bool,
     pub cert_file: String,
     pub key_file: String,
+    pub client_auth: TlsClientAuthConfig,
 }
 
 #[serde_as]
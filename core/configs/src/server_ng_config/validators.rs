@@ -133,6 +133,32 @@ impl Validatable<ConfigurationError> for ServerNgConfig {
             format!("{COMPONENT_NG} (error: {e}) - failed to validate quic config")
         })?;
 
+        self.tcp
+            .tls
+            .client_auth
+            .validate()
+            .error(|e: &ConfigurationError| {
+                format!(
+                    "{COMPONENT_NG} (error: {e}) - failed to validate TCP TLS client auth config"
+                )
+            })?;
+        self.quic
+            .certificate
+            .client_auth
+            .validate()
+            .error(|e: &ConfigurationError| {
+                format!("{COMPONENT_NG} (error: {e}) - failed to validate QUIC client auth config")
+            })?;
+        self.websocket
+            .tls
+            .client_auth
+            .validate()
+            .error(|e: &ConfigurationError| {
+                format!(
+                    "{COMPONENT_NG} (error: {e}) - failed to validate WebSocket TLS client auth config"
+                )
+            })?;
+
         Ok(())
     }
 }
@@ -26,6 +26,7 @@
 //! (e.g. tighten the type to `Option<IggyByteSize>`) independently of
 //! the legacy server.
 
+use crate::server_config::tls::TlsClientAuthConfig;
 use configs::ConfigEnv;
 use iggy_common::IggyByteSize;
 use serde::{Deserialize, Serialize};
@@ -58,6 +59,7 @@ pub struct WebSocketTlsConfig {
     pub self_signed: bool,
     pub cert_file: String,
     pub key_file: String,
+    pub client_auth: TlsClientAuthConfig,
 }
 
 impl WebSocketConfig {
@@ -30,8 +30,8 @@ use iggy::prelude::{Client, IggyConsumer, IggyProducer};
 use iggy_connector_sdk::{
     StreamDecoder, StreamEncoder,
     api::ConnectorStatus,
-    sink::ConsumeCallback,
-    source::{HandleCallback, SendCallback},
+    sink::{ConsumeCallback, StoredOffsetCallback},
+    source::{AcknowledgeCallback, HandleCallback, SendCallback},
     transforms::Transform,
 };
 use mimalloc::MiMalloc;
@@ -81,6 +81,7 @@ pub(crate) struct SourceApi {
         log_callback: iggy_connector_sdk::LogCallback,
     ) -> i32,
     iggy_source_handle: extern "C" fn(id: u32, callback: SendCallback) -> i32,
+    iggy_source_acknowledge: Option<extern "C" fn(id: u32, delivered: usize) -> i32>,
     iggy_source_close: extern "C" fn(id: u32) -> i32,
     iggy_source_version: extern "C" fn() -> *const std::ffi::c_char,
 }
@@ -103,6 +104,16 @@ pub(crate) struct SinkApi {
         messages_ptr: *const u8,
         messages_len: usize,
     ) -> i32,
+    iggy_sink_buffers_messages: Option<extern "C" fn(id: u32) -> bool>,
+    iggy_sink_stored_offset: Option<
+        extern "C" fn(
+            id: u32,
+            topic_meta_ptr: *const u8,
+            topic_meta_len: usize,
+            partition_id: u32,
+            offset: *mut u64,
+        ) -> i32,
+    >,
     iggy_sink_close: extern "C" fn(id: u32) -> i32,
     iggy_sink_version: extern "C" fn() -> *const std::ffi::c_char,
 }
@@ -185,11 +196,13 @@ async fn main() -> Result<(), RuntimeError> {
     for (_path, source) in sources {
         let container = Arc::new(source.container);
         let callback = container.iggy_source_handle;
+        let acknowledge = container.iggy_source_acknowledge;
         for plugin in &source.plugins {
             source_containers_by_key.insert(plugin.key.clone(), container.clone());
         }
         source_wrappers.push(SourceConnectorWrapper {
             callback,
+            acknowledge,
             plugins: source.plugins,
         });
     }
@@ -414,6 +427,7 @@ struct SinkConnectorPlugin {
     version: String,
     config_format: Option<ConfigFormat>,
     consumers: Vec<SinkConnectorConsumer>,
+    stored_offset: Option<StoredOffsetCallback>,
     error: Option<String>,
     verbose: bool,
 }
@@ -456,6 +470,7 @@ struct SourceConnectorProducer {
 
 struct SourceConnectorWrapper {
     callback: HandleCallback,
+    acknowledge: Option<AcknowledgeCallback>,
     plugins: Vec<SourceConnectorPlugin>,
 }
 
@@ -205,7 +205,9 @@ impl SinkManager {
         )?;
         info!("Sink connector with ID: {plugin_id} for plugin: {key} initialized successfully.");
 
-        let consumers = sink::setup_sink_consumers(key, config, iggy_client).await?;
+        let stored_offset = sink::get_stored_offset_callback(&container, plugin_id);
+        let consumers =
+            sink::setup_sink_consumers(key, config, iggy_client, stored_offset.is_some()).await?;
 
         let callback = container.iggy_sink_consume;
         let (shutdown_tx, task_handles) = sink::spawn_consume_tasks(
@@ -213,6 +215,7 @@ impl SinkManager {
             key,
             consumers,
             callback,
+            stored_offset,
             config.verbose,
             metrics,
             context.clone(),
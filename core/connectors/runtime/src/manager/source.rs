@@ -223,6 +223,7 @@ impl SourceManager {
             transforms,
             state_storage,
             callback,
+            container.iggy_source_acknowledge,
             context.clone(),
         );
 
@@ -28,24 +28,29 @@ use crate::{
 use dlopen2::wrapper::Container;
 use futures::StreamExt;
 use iggy::prelude::{
-    AutoCommit, AutoCommitWhen, IggyClient, IggyConsumer, IggyDuration, IggyMessage,
-    PollingStrategy,
+    AutoCommit, AutoCommitWhen, Consumer, ConsumerOffsetClient, Identifier, IggyClient,
+    IggyConsumer, IggyDuration, IggyMessage, PollingStrategy, TopicClient,
 };
 use iggy_connector_sdk::decoders::avro::{AvroConfig, AvroStreamDecoder};
 use iggy_connector_sdk::{
     DecodedMessage, MessagesMetadata, RawMessage, RawMessages, ReceivedMessage, Schema,
-    StreamDecoder, TopicMetadata, sink::ConsumeCallback, transforms::Transform,
+    StreamDecoder, TopicMetadata,
+    sink::{ConsumeCallback, StoredOffsetCallback},
+    transforms::Transform,
 };
 use std::{
     collections::HashMap,
     str::FromStr,
     sync::{Arc, atomic::Ordering},
-    time::Instant,
+    time::{Duration, Instant},
 };
 use tokio::sync::watch;
 use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 
+/// How often the offsets stored by the sinks buffering the messages are committed between the batches.
+const STORED_OFFSETS_COMMIT_INTERVAL: Duration = Duration::from_secs(1);
+
 pub async fn init(
     sink_configs: HashMap<String, SinkConfig>,
     iggy_client: &IggyClient,
@@ -75,6 +80,7 @@ pub async fn init(
             )
             .err()
             .map(|error| error.to_string());
+            let stored_offset = get_stored_offset_callback(&container.container, plugin_id);
             container.plugins.push(SinkConnectorPlugin {
                 id: plugin_id,
                 key: key.clone(),
@@ -83,6 +89,7 @@ pub async fn init(
                 version,
                 config_format: config.plugin_config_format,
                 consumers: vec![],
+                stored_offset,
                 error: init_error.clone(),
                 verbose: config.verbose,
             });
@@ -103,6 +110,7 @@ pub async fn init(
             )
             .err()
             .map(|error| error.to_string());
+            let stored_offset = get_stored_offset_callback(&container, plugin_id);
             sink_connectors.insert(
                 path.clone(),
                 SinkConnector {
@@ -115,6 +123,7 @@ pub async fn init(
                         version,
                         config_format: config.plugin_config_format,
                         consumers: vec![],
+                        stored_offset,
                         error: init_error.clone(),
                         verbose: config.verbose,
                     }],
@@ -131,7 +140,6 @@ pub async fn init(
             );
         }
 
-        let consumers = setup_sink_consumers(&key, &config, iggy_client).await?;
         let connector = sink_connectors.get_mut(&path).ok_or_else(|| {
             RuntimeError::InvalidConfiguration(format!("Sink connector not found for path: {path}"))
         })?;
@@ -144,6 +152,8 @@ pub async fn init(
                     "Sink plugin not found for ID: {plugin_id}"
                 ))
             })?;
+        let buffered = plugin.stored_offset.is_some();
+        let consumers = setup_sink_consumers(&key, &config, iggy_client, buffered).await?;
         for (consumer, decoder, batch_size, transforms) in consumers {
             plugin.consumers.push(SinkConnectorConsumer {
                 consumer,
@@ -182,6 +192,7 @@ pub fn consume(
                 &plugin.key,
                 consumers,
                 sink.callback,
+                plugin.stored_offset,
                 plugin.verbose,
                 &context.metrics,
                 context.clone(),
@@ -192,7 +203,7 @@ pub fn consume(
     handles
 }
 
-#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(crate) fn spawn_consume_tasks(
     plugin_id: u32,
     plugin_key: &str,
@@ -203,6 +214,7 @@ pub(crate) fn spawn_consume_tasks(
         Vec<Arc<dyn Transform>>,
     )>,
     callback: ConsumeCallback,
+    stored_offset: Option<StoredOffsetCallback>,
     verbose: bool,
     metrics: &Arc<Metrics>,
     context: Arc<RuntimeContext>,
@@ -220,6 +232,8 @@ pub(crate) fn spawn_consume_tasks(
                 decoder,
                 batch_size,
                 callback,
+                stored_offset,
+                &context.iggy_clients.consumer,
                 transforms,
                 consumer,
                 verbose,
@@ -249,6 +263,8 @@ pub(crate) async fn consume_messages(
     decoder: Arc<dyn StreamDecoder>,
     batch_size: u32,
     consume: ConsumeCallback,
+    stored_offset: Option<StoredOffsetCallback>,
+    iggy_client: &IggyClient,
     transforms: Vec<Arc<dyn Transform>>,
     mut consumer: IggyConsumer,
     verbose: bool,
@@ -263,6 +279,12 @@ pub(crate) async fn consume_messages(
         stream: consumer.stream().to_string(),
         topic: consumer.topic().to_string(),
     };
+    let mut stored_offsets = stored_offset
+        .map(|callback| {
+            StoredOffsets::new(plugin_id, callback, iggy_client, &consumer, &topic_metadata)
+        })
+        .transpose()?;
+    let mut commit_interval = tokio::time::interval(STORED_OFFSETS_COMMIT_INTERVAL);
 
     loop {
         let message = tokio::select! {
@@ -270,6 +292,12 @@ pub(crate) async fn consume_messages(
                 info!("Sink connector with ID: {plugin_id} received shutdown signal");
                 break;
             }
+            _ = commit_interval.tick(), if stored_offsets.is_some() => {
+                if let Some(stored_offsets) = stored_offsets.as_mut() {
+                    stored_offsets.commit().await;
+                }
+                continue;
+            }
             msg = consumer.next() => msg,
         };
 
@@ -330,6 +358,10 @@ pub(crate) async fn consume_messages(
         };
 
         metrics.increment_messages_processed(plugin_key, processed_count as u64);
+        if let Some(stored_offsets) = stored_offsets.as_mut() {
+            stored_offsets.track(partition_id);
+            stored_offsets.commit().await;
+        }
         let elapsed = start.elapsed();
         if verbose {
             info!(
@@ -343,10 +375,180 @@ pub(crate) async fn consume_messages(
             );
         }
     }
+    if let Some(stored_offsets) = stored_offsets.as_mut() {
+        stored_offsets.commit().await;
+    }
     info!("Stopped consuming messages for sink connector with ID: {plugin_id}");
     Ok(())
 }
 
+/// Commits the offsets of the messages durably stored by a sink buffering them. The offsets of
+/// the consumer group are committed once the messages are polled, so the stored ones are kept by
+/// a standalone consumer, to which the consumer group is rewound on start.
+struct StoredOffsets<'a> {
+    plugin_id: u32,
+    callback: StoredOffsetCallback,
+    iggy_client: &'a IggyClient,
+    consumer: Consumer,
+    stream: Identifier,
+    topic: Identifier,
+    topic_metadata: Vec<u8>,
+    committed_offsets: HashMap<u32, Option<u64>>,
+}
+
+impl<'a> StoredOffsets<'a> {
+    fn new(
+        plugin_id: u32,
+        callback: StoredOffsetCallback,
+        iggy_client: &'a IggyClient,
+        consumer: &IggyConsumer,
+        topic_metadata: &TopicMetadata,
+    ) -> Result<Self, RuntimeError> {
+        let topic_metadata = postcard::to_allocvec(topic_metadata).map_err(|error| {
+            error!(
+                "Failed to serialize topic metadata for sink connector with ID: {plugin_id}. {error}"
+            );
+            RuntimeError::FailedToSerializeTopicMetadata
+        })?;
+        Ok(Self {
+            plugin_id,
+            callback,
+            iggy_client,
+            consumer: stored_offsets_consumer(consumer.name())?,
+            stream: consumer.stream().clone(),
+            topic: consumer.topic().clone(),
+            topic_metadata,
+            committed_offsets: HashMap::new(),
+        })
+    }
+
+    fn track(&mut self, partition_id: u32) {
+        self.committed_offsets.entry(partition_id).or_default();
+    }
+
+    async fn commit(&mut self) {
+        let plugin_id = self.plugin_id;
+        for (partition_id, committed_offset) in self.committed_offsets.iter_mut() {
+            let mut offset = 0;
+            let result = (self.callback)(
+                plugin_id,
+                self.topic_metadata.as_ptr(),
+                self.topic_metadata.len(),
+                *partition_id,
+                &mut offset,
+            );
+            if result < 0 {
+                warn!(
+                    "Failed to get the stored offset in partition: {partition_id} for sink connector with ID: {plugin_id}"
+                );
+                continue;
+            }
+
+            if result > 0 || committed_offset.is_some_and(|committed| committed >= offset) {
+                continue;
+            }
+
+            match self
+                .iggy_client
+                .store_consumer_offset(
+                    &self.consumer,
+                    &self.stream,
+                    &self.topic,
+                    Some(*partition_id),
+                    offset,
+                )
+                .await
+            {
+                Ok(()) => {
+                    debug!(
+                        "Committed the stored offset: {offset} in partition: {partition_id} for sink connector with ID: {plugin_id}"
+                    );
+                    *committed_offset = Some(offset);
+                }
+                Err(error) => {
+                    error!(
+                        "Failed to commit the stored offset: {offset} in partition: {partition_id} for sink connector with ID: {plugin_id}. {error}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn stored_offsets_consumer(consumer_group: &str) -> Result<Consumer, RuntimeError> {
+    Ok(Consumer::new(Identifier::named(&format!(
+        "{consumer_group}-stored"
+    ))?))
+}
+
+/// Rewinds the consumer group to the offsets stored by the sink buffering the messages, so that the
+/// messages polled but not stored before the restart are consumed again. Without the stored offsets
+/// (e.g. on the first start), the current offsets of the consumer group are stored instead.
+async fn restore_stored_offsets(
+    iggy_client: &IggyClient,
+    consumer: &IggyConsumer,
+) -> Result<(), RuntimeError> {
+    let stored_consumer = stored_offsets_consumer(consumer.name())?;
+    let group_consumer = Consumer::group(Identifier::named(consumer.name())?);
+    let stream = consumer.stream();
+    let topic = consumer.topic();
+    let Some(topic_details) = iggy_client.get_topic(stream, topic).await? else {
+        return Ok(());
+    };
+
+    for partition in topic_details.partitions {
+        let partition_id = Some(partition.id);
+        let stored = iggy_client
+            .get_consumer_offset(&stored_consumer, stream, topic, partition_id)
+            .await?
+            .map(|offset| offset.stored_offset);
+        let polled = iggy_client
+            .get_consumer_offset(&group_consumer, stream, topic, partition_id)
+            .await?
+            .map(|offset| offset.stored_offset);
+        let result = match (stored, polled) {
+            (Some(stored), polled) if polled.is_none_or(|polled| polled > stored) => {
+                info!(
+                    "Rewinding consumer group: {} to the stored offset: {stored} in partition: {}",
+                    consumer.name(),
+                    partition.id
+                );
+                iggy_client
+                    .store_consumer_offset(&group_consumer, stream, topic, partition_id, stored)
+                    .await
+            }
+            (None, Some(polled)) => {
+                iggy_client
+                    .store_consumer_offset(&stored_consumer, stream, topic, partition_id, polled)
+                    .await
+            }
+            _ => Ok(()),
+        };
+        // Only the partitions assigned to the consumer group member can be rewound.
+        if let Err(error) = result {
+            warn!(
+                "Failed to restore the stored offset of consumer group: {} in partition: {}. {error}",
+                consumer.name(),
+                partition.id
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Returns the stored offset callback of the plugin if it buffers the consumed messages.
+pub(crate) fn get_stored_offset_callback(
+    container: &Container<SinkApi>,
+    id: u32,
+) -> Option<StoredOffsetCallback> {
+    let buffers_messages = container.iggy_sink_buffers_messages?;
+    if buffers_messages(id) {
+        container.iggy_sink_stored_offset
+    } else {
+        None
+    }
+}
+
 fn get_plugin_version(container: &Container<SinkApi>) -> String {
     unsafe {
         let version_ptr = (container.iggy_sink_version)();
@@ -381,6 +583,7 @@ pub(crate) async fn setup_sink_consumers(
     key: &str,
     config: &SinkConfig,
     iggy_client: &IggyClient,
+    buffered: bool,
 ) -> Result<
     Vec<(
         IggyConsumer,
@@ -427,6 +630,9 @@ pub(crate) async fn setup_sink_consumers(
                 .batch_length(batch_length)
                 .build();
             consumer.init().await?;
+            if buffered {
+                restore_stored_offsets(iggy_client, &consumer).await?;
+            }
             let decoder: Arc<dyn StreamDecoder> = match stream.schema {
                 Schema::Avro => {
                     let config = AvroConfig {
@@ -21,13 +21,15 @@ use dashmap::DashMap;
 use dlopen2::wrapper::Container;
 use flume::{Receiver, Sender};
 use iggy::prelude::{
-    DirectConfig, HeaderKey, HeaderValue, IggyClient, IggyDuration, IggyError, IggyMessage,
-    IggyProducer,
+    DirectConfig, HeaderKey, HeaderValue, Identifier, IggyClient, IggyDuration, IggyError,
+    IggyMessage, IggyProducer, Partitioning,
 };
 use iggy_connector_sdk::encoders::avro::{AvroEncoderConfig, AvroStreamEncoder};
 use iggy_connector_sdk::{
-    ConnectorState, DecodedMessage, Error, ProducedMessages, Schema, StreamEncoder, TopicMetadata,
-    source::HandleCallback, transforms::Transform,
+    ConnectorState, DecodedMessage, Error, MessageRoute, ProducedMessages, Schema, StreamEncoder,
+    TopicMetadata,
+    source::{AcknowledgeCallback, HandleCallback},
+    transforms::Transform,
 };
 use once_cell::sync::Lazy;
 use std::{
@@ -50,7 +52,10 @@ use crate::{
 use iggy_connector_sdk::api::ConnectorStatus;
 use tokio::task::JoinHandle;
 
-pub static SOURCE_SENDERS: Lazy<DashMap<u32, Sender<ProducedMessages>>> = Lazy::new(DashMap::new);
+/// Produced messages are `None` when they could not be decoded, so that the batch is still
+/// acknowledged as not delivered.
+pub static SOURCE_SENDERS: Lazy<DashMap<u32, Sender<Option<ProducedMessages>>>> =
+    Lazy::new(DashMap::new);
 
 pub fn cleanup_sender(plugin_id: u32) {
     SOURCE_SENDERS.remove(&plugin_id);
@@ -299,7 +304,8 @@ pub(crate) async fn source_forwarding_loop(
     encoder: Arc<dyn StreamEncoder>,
     transforms: Vec<Arc<dyn Transform>>,
     state_storage: StateStorage,
-    receiver: Receiver<ProducedMessages>,
+    receiver: Receiver<Option<ProducedMessages>>,
+    acknowledge: Option<AcknowledgeCallback>,
     context: Arc<RuntimeContext>,
 ) {
     info!("Source connector with ID: {plugin_id} started.");
@@ -319,6 +325,11 @@ pub(crate) async fn source_forwarding_loop(
     };
 
     while let Ok(produced_messages) = receiver.recv_async().await {
+        let Some(produced_messages) = produced_messages else {
+            acknowledge_messages(plugin_id, acknowledge, 0);
+            continue;
+        };
+
         let count = produced_messages.messages.len();
         context
             .metrics
@@ -329,8 +340,9 @@ pub(crate) async fn source_forwarding_loop(
             debug!("Source connector with ID: {plugin_id} received {count} messages");
         }
         let schema = produced_messages.schema;
-        let mut messages: Vec<DecodedMessage> = Vec::with_capacity(count);
-        for message in produced_messages.messages {
+        let mut messages: Vec<(usize, Option<MessageRoute>, DecodedMessage)> =
+            Vec::with_capacity(count);
+        for (index, mut message) in produced_messages.messages.into_iter().enumerate() {
             let Ok(payload) = schema.try_into_payload(message.payload) else {
                 error!(
                     "Failed to decode message payload with schema: {schema} for source connector with ID: {plugin_id}",
@@ -341,40 +353,44 @@ pub(crate) async fn source_forwarding_loop(
             debug!(
                 "Source connector with ID: {plugin_id}] received message: {number} | schema: {schema} | payload: {payload}"
             );
-            messages.push(DecodedMessage {
-                id: message.id,
-                offset: None,
-                headers: message.headers,
-                checksum: message.checksum,
-                timestamp: message.timestamp,
-                origin_timestamp: message.origin_timestamp,
-                payload,
-            });
+            let route = MessageRoute::take(&mut message.headers);
+            messages.push((
+                index,
+                route,
+                DecodedMessage {
+                    id: message.id,
+                    offset: None,
+                    headers: message.headers,
+                    checksum: message.checksum,
+                    timestamp: message.timestamp,
+                    origin_timestamp: message.origin_timestamp,
+                    payload,
+                },
+            ));
             number += 1;
         }
 
-        let Ok(iggy_messages) =
-            process_messages(plugin_id, &encoder, &topic_metadata, messages, &transforms)
-        else {
-            let error_msg = format!(
-                "Failed to process {count} messages by source connector with ID: {plugin_id} before sending them to stream: {}, topic: {}.",
-                producer.stream(),
-                producer.topic()
-            );
-            error!("{error_msg}");
+        let (delivered, error) = send_groups(group_by_route(messages), count, |route, messages| {
+            send_messages(
+                plugin_id,
+                verbose,
+                &producer,
+                &encoder,
+                &transforms,
+                &topic_metadata,
+                route,
+                messages,
+            )
+        })
+        .await;
+        if delivered > 0 {
             context
                 .metrics
-                .increment_errors(&plugin_key, ConnectorType::Source);
-            context.sources.set_error(&plugin_key, &error_msg).await;
-            continue;
-        };
+                .increment_messages_sent(&plugin_key, delivered as u64);
+        }
+        acknowledge_messages(plugin_id, acknowledge, delivered);
 
-        if let Err(error) = producer.send(iggy_messages).await {
-            let error_msg = format!(
-                "Failed to send {count} messages to stream: {}, topic: {} by source connector with ID: {plugin_id}. {error}",
-                producer.stream(),
-                producer.topic(),
-            );
+        if let Some(error_msg) = error {
             error!("{error_msg}");
             context
                 .metrics
@@ -383,24 +399,6 @@ pub(crate) async fn source_forwarding_loop(
             continue;
         }
 
-        context
-            .metrics
-            .increment_messages_sent(&plugin_key, count as u64);
-
-        if verbose {
-            info!(
-                "Sent {count} messages to stream: {}, topic: {} by source connector with ID: {plugin_id}",
-                producer.stream(),
-                producer.topic()
-            );
-        } else {
-            debug!(
-                "Sent {count} messages to stream: {}, topic: {} by source connector with ID: {plugin_id}",
-                producer.stream(),
-                producer.topic()
-            );
-        }
-
         let Some(state) = produced_messages.state else {
             debug!("No state provided for source connector with ID: {plugin_id}");
             continue;
@@ -432,6 +430,144 @@ pub(crate) async fn source_forwarding_loop(
         .await;
 }
 
+/// A group of consecutive messages sharing the same route, along with the index of its first
+/// message in the produced batch.
+type RouteGroup = (usize, Option<MessageRoute>, Vec<DecodedMessage>);
+
+/// Splits the indexed messages into consecutive groups sharing the same route, preserving
+/// their order.
+fn group_by_route(messages: Vec<(usize, Option<MessageRoute>, DecodedMessage)>) -> Vec<RouteGroup> {
+    let mut groups: Vec<RouteGroup> = Vec::new();
+    for (index, route, message) in messages {
+        match groups.last_mut() {
+            Some((_, last_route, group)) if *last_route == route => group.push(message),
+            _ => groups.push((index, route, vec![message])),
+        }
+    }
+    groups
+}
+
+/// Sends the groups in order, stopping at the first one which could not be sent. Returns the
+/// number of the leading messages of the batch handled before the failed group (or all of
+/// them), so that only the remaining ones are acknowledged as not delivered.
+async fn send_groups<F, Fut>(
+    groups: Vec<RouteGroup>,
+    count: usize,
+    mut send: F,
+) -> (usize, Option<String>)
+where
+    F: FnMut(Option<MessageRoute>, Vec<DecodedMessage>) -> Fut,
+    Fut: Future<Output = Result<(), String>>,
+{
+    for (index, route, messages) in groups {
+        if let Err(error) = send(route, messages).await {
+            return (index, Some(error));
+        }
+    }
+    (count, None)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn send_messages(
+    plugin_id: u32,
+    verbose: bool,
+    producer: &IggyProducer,
+    encoder: &Arc<dyn StreamEncoder>,
+    transforms: &Vec<Arc<dyn Transform>>,
+    topic_metadata: &TopicMetadata,
+    route: Option<MessageRoute>,
+    messages: Vec<DecodedMessage>,
+) -> Result<(), String> {
+    let count = messages.len();
+    let route = route.unwrap_or_default();
+    let topic_metadata = TopicMetadata {
+        stream: route
+            .stream
+            .clone()
+            .unwrap_or_else(|| topic_metadata.stream.clone()),
+        topic: route
+            .topic
+            .clone()
+            .unwrap_or_else(|| topic_metadata.topic.clone()),
+    };
+
+    let Ok(iggy_messages) =
+        process_messages(plugin_id, encoder, &topic_metadata, messages, transforms)
+    else {
+        return Err(format!(
+            "Failed to process {count} messages by source connector with ID: {plugin_id} before sending them to stream: {}, topic: {}.",
+            topic_metadata.stream, topic_metadata.topic
+        ));
+    };
+
+    let result = if route == MessageRoute::default() {
+        producer.send(iggy_messages).await
+    } else {
+        match resolve_route(producer, route) {
+            Ok((stream, topic, partitioning)) => {
+                producer
+                    .send_to(stream, topic, iggy_messages, partitioning)
+                    .await
+            }
+            Err(error) => Err(error),
+        }
+    };
+
+    if let Err(error) = result {
+        return Err(format!(
+            "Failed to send {count} messages to stream: {}, topic: {} by source connector with ID: {plugin_id}. {error}",
+            topic_metadata.stream, topic_metadata.topic
+        ));
+    }
+
+    if verbose {
+        info!(
+            "Sent {count} messages to stream: {}, topic: {} by source connector with ID: {plugin_id}",
+            topic_metadata.stream, topic_metadata.topic
+        );
+    } else {
+        debug!(
+            "Sent {count} messages to stream: {}, topic: {} by source connector with ID: {plugin_id}",
+            topic_metadata.stream, topic_metadata.topic
+        );
+    }
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+fn resolve_route(
+    producer: &IggyProducer,
+    route: MessageRoute,
+) -> Result<(Arc<Identifier>, Arc<Identifier>, Option<Arc<Partitioning>>), IggyError> {
+    let stream = match route.stream {
+        Some(stream) => Identifier::from_str_value(&stream)?,
+        None => producer.stream().clone(),
+    };
+    let topic = match route.topic {
+        Some(topic) => Identifier::from_str_value(&topic)?,
+        None => producer.topic().clone(),
+    };
+    let partitioning = match route.partition_key {
+        Some(key) => Some(Arc::new(Partitioning::messages_key(&key)?)),
+        None => None,
+    };
+    Ok((Arc::new(stream), Arc::new(topic), partitioning))
+}
+
+fn acknowledge_messages(
+    plugin_id: u32,
+    acknowledge: Option<AcknowledgeCallback>,
+    delivered: usize,
+) {
+    let Some(acknowledge) = acknowledge else {
+        return;
+    };
+
+    if acknowledge(plugin_id, delivered) != 0 {
+        warn!("Failed to acknowledge messages for source connector with ID: {plugin_id}");
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn spawn_source_handler(
     plugin_id: u32,
@@ -442,6 +578,7 @@ pub(crate) fn spawn_source_handler(
     transforms: Vec<Arc<dyn Transform>>,
     state_storage: StateStorage,
     callback: HandleCallback,
+    acknowledge: Option<AcknowledgeCallback>,
     context: Arc<RuntimeContext>,
 ) -> Vec<JoinHandle<()>> {
     let (sender, receiver) = flume::unbounded();
@@ -462,6 +599,7 @@ pub(crate) fn spawn_source_handler(
             transforms,
             state_storage,
             receiver,
+            acknowledge,
             context,
         )
         .await;
@@ -502,6 +640,7 @@ pub fn handle(
                 plugin.transforms,
                 plugin.state_storage,
                 source.callback,
+                source.acknowledge,
                 context.clone(),
             );
 
@@ -563,19 +702,19 @@ pub(crate) extern "C" fn handle_produced_messages(
     unsafe {
         if let Some(sender) = SOURCE_SENDERS.get(&plugin_id) {
             let messages = std::slice::from_raw_parts(messages_ptr, messages_len);
-            match postcard::from_bytes::<ProducedMessages>(messages) {
-                Ok(messages) => {
-                    if let Err(send_error) = sender.send(messages) {
-                        error!(
-                            "Failed to send messages for source connector with ID: {plugin_id}. Channel closed: {send_error}"
-                        );
-                    }
-                }
+            let messages = match postcard::from_bytes::<ProducedMessages>(messages) {
+                Ok(messages) => Some(messages),
                 Err(err) => {
                     error!(
                         "Failed to deserialize produced messages for source connector with ID: {plugin_id}. {err}"
                     );
+                    None
                 }
+            };
+            if let Err(send_error) = sender.send(messages) {
+                error!(
+                    "Failed to send messages for source connector with ID: {plugin_id}. Channel closed: {send_error}"
+                );
             }
         }
     }
@@ -603,3 +742,139 @@ fn build_iggy_message(
         (None, None) => IggyMessage::builder().payload(payload.into()).build(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_connector_sdk::{Payload, ProducedMessage};
+
+    fn message(id: u128) -> DecodedMessage {
+        DecodedMessage {
+            id: Some(id),
+            offset: None,
+            checksum: None,
+            timestamp: None,
+            origin_timestamp: None,
+            headers: None,
+            payload: Payload::Raw(vec![]),
+        }
+    }
+
+    fn route(topic: &str) -> Option<MessageRoute> {
+        Some(MessageRoute {
+            topic: Some(topic.to_string()),
+            ..MessageRoute::default()
+        })
+    }
+
+    fn ids(messages: &[DecodedMessage]) -> Vec<u128> {
+        messages.iter().filter_map(|message| message.id).collect()
+    }
+
+    #[test]
+    fn test_group_by_route_keeps_consecutive_messages_together() {
+        let groups = group_by_route(vec![
+            (0, None, message(1)),
+            (1, None, message(2)),
+            (2, route("a"), message(3)),
+            (4, route("a"), message(4)),
+            (5, route("b"), message(5)),
+            (6, None, message(6)),
+        ]);
+
+        let groups: Vec<(usize, Option<MessageRoute>, Vec<u128>)> = groups
+            .into_iter()
+            .map(|(index, route, messages)| (index, route, ids(&messages)))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                (0, None, vec![1, 2]),
+                (2, route("a"), vec![3, 4]),
+                (5, route("b"), vec![5]),
+                (6, None, vec![6]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_route_with_no_messages() {
+        assert!(group_by_route(vec![]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_groups_delivers_all_messages() {
+        let groups = group_by_route(vec![(0, None, message(1)), (1, route("a"), message(2))]);
+
+        let mut sent = Vec::new();
+        let result = send_groups(groups, 3, |_, messages| {
+            sent.push(ids(&messages));
+            async { Ok(()) }
+        })
+        .await;
+
+        assert_eq!(result, (3, None));
+        assert_eq!(sent, vec![vec![1], vec![2]]);
+    }
+
+    #[tokio::test]
+    async fn test_send_groups_stops_at_failed_group() {
+        let groups = group_by_route(vec![
+            (0, None, message(1)),
+            (1, None, message(2)),
+            (2, route("a"), message(3)),
+            (3, route("b"), message(4)),
+        ]);
+
+        let mut attempts = Vec::new();
+        let result = send_groups(groups, 4, |route, messages| {
+            attempts.push(ids(&messages));
+            let failed = route.is_some();
+            async move {
+                if failed {
+                    Err("failed".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result, (2, Some("failed".to_string())));
+        assert_eq!(attempts, vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_route_is_taken_from_message_headers() {
+        let mut message = ProducedMessage {
+            id: None,
+            checksum: None,
+            timestamp: None,
+            origin_timestamp: None,
+            headers: None,
+            payload: vec![],
+        };
+        let expected = MessageRoute {
+            stream: Some("stream".to_string()),
+            topic: None,
+            partition_key: Some(b"key".to_vec()),
+        };
+        expected.apply(&mut message).unwrap();
+        message.headers.as_mut().unwrap().insert(
+            HeaderKey::try_from("key").unwrap(),
+            HeaderValue::try_from("value").unwrap(),
+        );
+
+        assert_eq!(MessageRoute::take(&mut message.headers), Some(expected));
+        let headers = message.headers.unwrap();
+        assert_eq!(headers.len(), 1);
+        assert!(headers.contains_key(&HeaderKey::try_from("key").unwrap()));
+    }
+
+    #[test]
+    fn test_message_without_route_headers_has_no_route() {
+        let mut headers = None;
+        assert_eq!(MessageRoute::take(&mut headers), None);
+        assert!(headers.is_none());
+    }
+}
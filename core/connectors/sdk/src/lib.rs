@@ -114,6 +114,14 @@ pub trait Source: Send + Sync {
     /// Invoked every time a batch of messages is produced to the configured stream and topic.
     async fn poll(&self) -> Result<ProducedMessages, Error>;
 
+    /// Invoked once for every batch returned by `poll`, in the same order, after the runtime
+    /// has tried to send its messages. `delivered` is the number of the leading messages of
+    /// the batch which were handled (sent or skipped), while the remaining ones could not be
+    /// sent, allowing the source to e.g. acknowledge the data upstream only once it's stored.
+    async fn acknowledge(&self, _delivered: usize) -> Result<(), Error> {
+        Ok(())
+    }
+
     /// Invoked when the source is closed, allowing it to perform any necessary cleanup.
     async fn close(&mut self) -> Result<(), Error>;
 }
@@ -133,6 +141,23 @@ pub trait Sink: Send + Sync {
         messages: Vec<ConsumedMessage>,
     ) -> Result<(), Error>;
 
+    /// Whether the sink buffers the consumed messages across the batches (e.g. to write larger files).
+    /// The offsets of such a sink are committed only up to the ones returned by `stored_offset`,
+    /// instead of as soon as the messages are polled.
+    fn buffers_messages(&self) -> bool {
+        false
+    }
+
+    /// Returns the offset of the last message consumed from the partition that is durably stored,
+    /// if any. Invoked after every batch and periodically, only if the sink buffers the messages.
+    async fn stored_offset(
+        &self,
+        _topic_metadata: &TopicMetadata,
+        _partition_id: u32,
+    ) -> Option<u64> {
+        None
+    }
+
     /// Invoked when the sink is closed, allowing it to perform any necessary cleanup.
     async fn close(&mut self) -> Result<(), Error>;
 }
@@ -324,6 +349,82 @@ pub struct ProducedMessage {
     pub payload: Vec<u8>,
 }
 
+/// Overrides where a produced message is sent. The stream and topic default to the ones
+/// configured for the source, while the messages without a partition key are balanced.
+///
+/// The route is carried in the reserved headers of the message, so that the layout of
+/// `ProducedMessage` shared with the runtime stays the same. The runtime removes these
+/// headers before sending the message, while the older runtimes keep them as they are
+/// and send the message to the configured stream and topic.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct MessageRoute {
+    pub stream: Option<String>,
+    pub topic: Option<String>,
+    pub partition_key: Option<Vec<u8>>,
+}
+
+impl MessageRoute {
+    pub const STREAM_HEADER: &'static str = "iggy_route_v1_stream";
+    pub const TOPIC_HEADER: &'static str = "iggy_route_v1_topic";
+    pub const PARTITION_KEY_HEADER: &'static str = "iggy_route_v1_partition_key";
+
+    /// Sets the route of the message by storing it in its reserved headers.
+    pub fn apply(&self, message: &mut ProducedMessage) -> Result<(), Error> {
+        let mut entries = Vec::with_capacity(3);
+        if let Some(stream) = self.stream.as_deref() {
+            entries.push((Self::STREAM_HEADER, HeaderValue::try_from(stream)));
+        }
+        if let Some(topic) = self.topic.as_deref() {
+            entries.push((Self::TOPIC_HEADER, HeaderValue::try_from(topic)));
+        }
+        if let Some(partition_key) = self.partition_key.as_deref() {
+            entries.push((
+                Self::PARTITION_KEY_HEADER,
+                HeaderValue::try_from(partition_key),
+            ));
+        }
+
+        for (name, value) in entries {
+            let value = value.map_err(|error| {
+                Error::InvalidRecordValue(format!("Invalid route header: {name}. {error}"))
+            })?;
+            let key = HeaderKey::try_from(name).map_err(|error| {
+                Error::InvalidRecordValue(format!("Invalid route header: {name}. {error}"))
+            })?;
+            message
+                .headers
+                .get_or_insert_with(BTreeMap::new)
+                .insert(key, value);
+        }
+        Ok(())
+    }
+
+    /// Removes the reserved route headers, returning the route they describe, if any.
+    pub fn take(headers: &mut Option<BTreeMap<HeaderKey, HeaderValue>>) -> Option<Self> {
+        let entries = headers.as_mut()?;
+        let mut remove = |name: &str| {
+            let key = HeaderKey::try_from(name).ok()?;
+            entries.remove(&key)
+        };
+        let stream =
+            remove(Self::STREAM_HEADER).and_then(|value| value.as_str().ok().map(str::to_owned));
+        let topic =
+            remove(Self::TOPIC_HEADER).and_then(|value| value.as_str().ok().map(str::to_owned));
+        let partition_key =
+            remove(Self::PARTITION_KEY_HEADER).map(|value| value.as_bytes().to_vec());
+        if entries.is_empty() {
+            *headers = None;
+        }
+
+        let route = Self {
+            stream,
+            topic,
+            partition_key,
+        };
+        (route != Self::default()).then_some(route)
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DecodedMessage {
@@ -35,6 +35,14 @@ pub type ConsumeCallback = extern "C" fn(
     messages_len: usize,
 ) -> i32;
 
+pub type StoredOffsetCallback = extern "C" fn(
+    plugin_id: u32,
+    topic_meta_ptr: *const u8,
+    topic_meta_len: usize,
+    partition_id: u32,
+    offset: *mut u64,
+) -> i32;
+
 #[derive(Debug)]
 pub struct SinkContainer<T: Sink + std::fmt::Debug> {
     id: u32,
@@ -212,6 +220,53 @@ impl<T: Sink + std::fmt::Debug> SinkContainer<T> {
             if result.is_ok() { 0 } else { 1 }
         }
     }
+
+    pub fn buffers_messages(&self) -> bool {
+        self.sink
+            .as_ref()
+            .is_some_and(|sink| sink.buffers_messages())
+    }
+
+    /// Writes the offset of the last message of the partition durably stored by the sink.
+    /// Returns 0 if the offset is written, 1 if no message is stored yet, or -1 on error.
+    ///
+    /// # Safety
+    /// Do not copy the pointer to the topic metadata, the offset pointer must be valid for writes.
+    pub unsafe fn stored_offset(
+        &self,
+        topic_meta_ptr: *const u8,
+        topic_meta_len: usize,
+        partition_id: u32,
+        offset: *mut u64,
+    ) -> i32 {
+        unsafe {
+            let Some(sink) = self.sink.as_ref() else {
+                error!(
+                    "Sink connector with ID: {} is not initialized - cannot return stored offset.",
+                    self.id
+                );
+                return -1;
+            };
+
+            let topic_meta_slice = std::slice::from_raw_parts(topic_meta_ptr, topic_meta_len);
+            let Ok(topic_metadata) = postcard::from_bytes::<TopicMetadata>(topic_meta_slice) else {
+                error!(
+                    "Failed to decode topic metadata by sink connector with ID: {}",
+                    self.id
+                );
+                return -1;
+            };
+
+            let runtime = get_runtime();
+            match runtime.block_on(sink.stored_offset(&topic_metadata, partition_id)) {
+                Some(stored_offset) => {
+                    *offset = stored_offset;
+                    0
+                }
+                None => 1,
+            }
+        }
+    }
 }
 
 #[macro_export]
@@ -276,6 +331,32 @@ macro_rules! sink_connector {
             )
         }
 
+        #[cfg(not(test))]
+        #[unsafe(no_mangle)]
+        extern "C" fn iggy_sink_buffers_messages(id: u32) -> bool {
+            INSTANCES
+                .get(&id)
+                .is_some_and(|instance| instance.buffers_messages())
+        }
+
+        #[cfg(not(test))]
+        #[unsafe(no_mangle)]
+        unsafe extern "C" fn iggy_sink_stored_offset(
+            id: u32,
+            topic_meta_ptr: *const u8,
+            topic_meta_len: usize,
+            partition_id: u32,
+            offset: *mut u64,
+        ) -> i32 {
+            let Some(instance) = INSTANCES.get(&id) else {
+                tracing::error!(
+                    "Sink connector with ID: {id} was not found and stored offset cannot be returned."
+                );
+                return -1;
+            };
+            instance.stored_offset(topic_meta_ptr, topic_meta_len, partition_id, offset)
+        }
+
         #[cfg(not(test))]
         #[unsafe(no_mangle)]
         unsafe extern "C" fn iggy_sink_close(id: u32) -> i32 {
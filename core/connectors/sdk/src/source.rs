@@ -21,7 +21,8 @@ use crate::log::{CallbackLayer, LogCallback};
 use crate::{ConnectorState, Error, Source, get_runtime};
 use serde::de::DeserializeOwned;
 use std::sync::Arc;
-use tokio::{sync::watch, task::JoinHandle};
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
 use tracing::{error, info};
 use tracing_subscriber::{EnvFilter, Registry, layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -38,12 +39,16 @@ pub type HandleCallback = extern "C" fn(plugin_id: u32, callback: SendCallback)
 
 pub type SendCallback = extern "C" fn(plugin_id: u32, messages_ptr: *const u8, messages_len: usize);
 
+pub type AcknowledgeCallback = extern "C" fn(plugin_id: u32, delivered: usize) -> i32;
+
 #[derive(Debug)]
 pub struct SourceContainer<T: Source + std::fmt::Debug> {
     id: u32,
     source: Option<Arc<T>>,
     shutdown: Option<watch::Sender<()>>,
     task: Option<JoinHandle<()>>,
+    acknowledgements: Option<mpsc::UnboundedSender<usize>>,
+    acknowledge_task: Option<JoinHandle<()>>,
 }
 
 impl<T: Source + std::fmt::Debug + 'static> SourceContainer<T> {
@@ -53,6 +58,8 @@ impl<T: Source + std::fmt::Debug + 'static> SourceContainer<T> {
             source: None,
             shutdown: None,
             task: None,
+            acknowledgements: None,
+            acknowledge_task: None,
         }
     }
 
@@ -126,6 +133,10 @@ impl<T: Source + std::fmt::Debug + 'static> SourceContainer<T> {
         if let Some(handle) = self.task.take() {
             let _ = runtime.block_on(handle);
         }
+        self.acknowledgements = None;
+        if let Some(handle) = self.acknowledge_task.take() {
+            let _ = runtime.block_on(handle);
+        }
 
         let Ok(mut source) = Arc::try_unwrap(source) else {
             error!("Source connector with ID: {} was already closed.", self.id);
@@ -159,16 +170,59 @@ impl<T: Source + std::fmt::Debug + 'static> SourceContainer<T> {
         let (shutdown_tx, shutdown_rx) = watch::channel(());
         let plugin_id = self.id;
         let source = Arc::clone(source);
+        let (acknowledgements_tx, acknowledgements_rx) = mpsc::unbounded_channel();
+        let acknowledge_handle = runtime.spawn(acknowledge_messages(
+            plugin_id,
+            source.clone(),
+            acknowledgements_rx,
+        ));
         let handle = runtime.spawn(async move {
             let _ = handle_messages(plugin_id, source, callback, shutdown_rx).await;
         });
 
         self.shutdown = Some(shutdown_tx);
         self.task = Some(handle);
+        self.acknowledgements = Some(acknowledgements_tx);
+        self.acknowledge_task = Some(acknowledge_handle);
+        0
+    }
+
+    /// Queues the acknowledgement of the oldest batch of messages handed over to the runtime,
+    /// so that the acknowledgements reach the source in the same order as the batches.
+    pub fn acknowledge(&self, delivered: usize) -> i32 {
+        let Some(acknowledgements) = self.acknowledgements.as_ref() else {
+            error!(
+                "Source connector with ID: {} is not handled - cannot acknowledge.",
+                self.id
+            );
+            return -1;
+        };
+
+        if acknowledgements.send(delivered).is_err() {
+            error!(
+                "Source connector with ID: {} is closed - cannot acknowledge.",
+                self.id
+            );
+            return -1;
+        }
         0
     }
 }
 
+async fn acknowledge_messages<T: Source>(
+    plugin_id: u32,
+    source: Arc<T>,
+    mut acknowledgements: mpsc::UnboundedReceiver<usize>,
+) {
+    while let Some(delivered) = acknowledgements.recv().await {
+        if let Err(error) = source.acknowledge(delivered).await {
+            error!(
+                "Failed to acknowledge messages for source connector with ID: {plugin_id}. {error}"
+            );
+        }
+    }
+}
+
 async fn handle_messages<T: Source>(
     plugin_id: u32,
     source: Arc<T>,
@@ -189,6 +243,9 @@ async fn handle_messages<T: Source>(
 
                 let Ok(messages) = postcard::to_allocvec(&messages) else {
                     error!("Failed to serialize messages for source connector with ID: {plugin_id}");
+                    // An empty buffer cannot be decoded, so the runtime still acknowledges
+                    // the batch as not delivered, keeping the acknowledgements in order.
+                    callback(plugin_id, [].as_ptr(), 0);
                     continue;
                 };
 
@@ -271,6 +328,18 @@ macro_rules! source_connector {
             instance.1.close()
         }
 
+        #[cfg(not(test))]
+        #[unsafe(no_mangle)]
+        extern "C" fn iggy_source_acknowledge(id: u32, delivered: usize) -> i32 {
+            let Some(instance) = INSTANCES.get(&id) else {
+                tracing::error!(
+                    "Source connector with ID: {id} was not found and cannot be acknowledged."
+                );
+                return -1;
+            };
+            instance.acknowledge(delivered)
+        }
+
         #[cfg(not(test))]
         #[unsafe(no_mangle)]
         extern "C" fn iggy_source_version() -> *const std::ffi::c_char {
@@ -0,0 +1,437 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use humantime::Duration as HumanDuration;
+use iggy_common::{HeaderKind, HeaderValue};
+use iggy_connector_sdk::{
+    ConsumedMessage, Error, MessagesMetadata, Sink, TopicMetadata, sink_connector,
+};
+use rdkafka::ClientConfig;
+use rdkafka::message::{Header, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord, Producer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{debug, error, info, warn};
+
+sink_connector!(KafkaSink);
+
+const CONNECTOR_NAME: &str = "Kafka sink";
+const DEFAULT_KEY_HEADER: &str = "kafka_key";
+const DEFAULT_SEND_TIMEOUT: &str = "5s";
+const METADATA_TIMEOUT: Duration = Duration::from_secs(10);
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Headers added by the Kafka source to describe the original record, which must not be
+/// written back as the Kafka headers when bridging the messages back to Kafka.
+const SOURCE_METADATA_HEADERS: [&str; 4] = [
+    "kafka_topic",
+    "kafka_partition",
+    "kafka_offset",
+    "kafka_timestamp",
+];
+
+pub struct KafkaSink {
+    id: u32,
+    config: KafkaSinkConfig,
+    producer: Option<FutureProducer>,
+    key_header: String,
+    include_headers: bool,
+    include_metadata: bool,
+    send_timeout: Duration,
+    state: Mutex<State>,
+}
+
+impl std::fmt::Debug for KafkaSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KafkaSink")
+            .field("id", &self.id)
+            .field("config", &self.config)
+            .field("key_header", &self.key_header)
+            .field("include_headers", &self.include_headers)
+            .field("include_metadata", &self.include_metadata)
+            .field("send_timeout", &self.send_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KafkaSinkConfig {
+    pub bootstrap_servers: String,
+    pub topic: Option<String>,
+    pub key_header: Option<String>,
+    pub include_headers: Option<bool>,
+    pub include_metadata: Option<bool>,
+    pub send_timeout: Option<String>,
+    pub properties: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    messages_sent: u64,
+    errors_count: u64,
+}
+
+impl KafkaSink {
+    pub fn new(id: u32, config: KafkaSinkConfig) -> Self {
+        let send_timeout = config
+            .send_timeout
+            .as_deref()
+            .unwrap_or(DEFAULT_SEND_TIMEOUT);
+        let send_timeout = HumanDuration::from_str(send_timeout)
+            .map(|duration| duration.into())
+            .unwrap_or_else(|_| Duration::from_secs(5));
+
+        KafkaSink {
+            id,
+            key_header: config
+                .key_header
+                .clone()
+                .unwrap_or_else(|| DEFAULT_KEY_HEADER.to_owned()),
+            include_headers: config.include_headers.unwrap_or(true),
+            include_metadata: config.include_metadata.unwrap_or(false),
+            send_timeout,
+            config,
+            producer: None,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    fn client_config(&self) -> ClientConfig {
+        let mut client_config = ClientConfig::new();
+        client_config.set("bootstrap.servers", &self.config.bootstrap_servers);
+        if let Some(properties) = &self.config.properties {
+            for (key, value) in properties {
+                client_config.set(key, value);
+            }
+        }
+        client_config
+    }
+
+    /// Splits the message headers into the Kafka record key and the Kafka headers.
+    fn build_record_parts(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: &MessagesMetadata,
+        message: &ConsumedMessage,
+    ) -> (Option<Vec<u8>>, OwnedHeaders) {
+        let mut key = None;
+        let mut headers = OwnedHeaders::new();
+        if let Some(message_headers) = &message.headers {
+            for (name, value) in message_headers {
+                let name = name.to_string_value();
+                if name == self.key_header {
+                    key = Some(header_value_bytes(value));
+                } else if self.include_headers && !SOURCE_METADATA_HEADERS.contains(&name.as_str())
+                {
+                    headers = headers.insert(Header {
+                        key: &name,
+                        value: Some(&header_value_bytes(value)),
+                    });
+                }
+            }
+        }
+
+        if self.include_metadata {
+            let metadata = [
+                ("iggy_stream", topic_metadata.stream.clone()),
+                ("iggy_topic", topic_metadata.topic.clone()),
+                (
+                    "iggy_partition_id",
+                    messages_metadata.partition_id.to_string(),
+                ),
+                ("iggy_offset", message.offset.to_string()),
+                ("iggy_id", message.id.to_string()),
+            ];
+            for (name, value) in metadata {
+                headers = headers.insert(Header {
+                    key: name,
+                    value: Some(&value),
+                });
+            }
+        }
+        (key, headers)
+    }
+}
+
+#[async_trait]
+impl Sink for KafkaSink {
+    async fn open(&mut self) -> Result<(), Error> {
+        info!(
+            "Opening {CONNECTOR_NAME} connector with ID: {}. Bootstrap servers: {}, topic: {:?}",
+            self.id, self.config.bootstrap_servers, self.config.topic
+        );
+
+        let producer: FutureProducer = self.client_config().create().map_err(|error| {
+            Error::InitError(format!("Failed to create Kafka producer: {error}"))
+        })?;
+        producer
+            .client()
+            .fetch_metadata(self.config.topic.as_deref(), METADATA_TIMEOUT)
+            .map_err(|error| Error::Connection(format!("Failed to connect to Kafka: {error}")))?;
+
+        self.producer = Some(producer);
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} opened successfully",
+            self.id
+        );
+        Ok(())
+    }
+
+    async fn consume(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: MessagesMetadata,
+        messages: Vec<ConsumedMessage>,
+    ) -> Result<(), Error> {
+        let Some(producer) = self.producer.as_ref() else {
+            error!(
+                "{CONNECTOR_NAME} connector with ID: {} is not opened",
+                self.id
+            );
+            return Err(Error::InvalidState);
+        };
+
+        let topic = self
+            .config
+            .topic
+            .as_deref()
+            .unwrap_or(&topic_metadata.topic);
+        let mut records = Vec::with_capacity(messages.len());
+        for message in &messages {
+            let payload = match message.payload.try_to_bytes() {
+                Ok(payload) => payload,
+                Err(error) => {
+                    warn!(
+                        "{CONNECTOR_NAME} connector with ID: {} skipped the message with offset: {}. {error}",
+                        self.id, message.offset
+                    );
+                    continue;
+                }
+            };
+            let (key, headers) =
+                self.build_record_parts(topic_metadata, &messages_metadata, message);
+            records.push((payload, key, headers, message.origin_timestamp));
+        }
+
+        // The records are enqueued in order, so they keep their order within a Kafka partition.
+        let deliveries = records.iter().map(|(payload, key, headers, timestamp)| {
+            let mut record = FutureRecord::to(topic)
+                .payload(payload)
+                .headers(headers.clone());
+            if let Some(key) = key {
+                record = record.key(key);
+            }
+            if *timestamp > 0 {
+                record = record.timestamp((*timestamp / 1000) as i64);
+            }
+            producer.send(record, self.send_timeout)
+        });
+        let results = join_all(deliveries).await;
+
+        let mut failed = 0;
+        for result in results {
+            if let Err((error, _)) = result {
+                failed += 1;
+                error!(
+                    "{CONNECTOR_NAME} connector with ID: {} failed to send a message to Kafka topic: {topic}. {error}",
+                    self.id
+                );
+            }
+        }
+
+        let mut state = self.state.lock().await;
+        state.messages_sent += (records.len() - failed) as u64;
+        state.errors_count += failed as u64;
+        debug!(
+            "{CONNECTOR_NAME} connector with ID: {} sent {} messages to Kafka topic: {topic} from stream: {}, topic: {}, partition: {}, offset: {}",
+            self.id,
+            records.len() - failed,
+            topic_metadata.stream,
+            topic_metadata.topic,
+            messages_metadata.partition_id,
+            messages_metadata.current_offset
+        );
+
+        if failed > 0 {
+            return Err(Error::CannotStoreData(format!(
+                "Failed to send {failed} of {} messages to Kafka topic: {topic}",
+                records.len()
+            )));
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<(), Error> {
+        if let Some(producer) = self.producer.take()
+            && let Err(error) = producer.flush(FLUSH_TIMEOUT)
+        {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} failed to flush the Kafka producer. {error}",
+                self.id
+            );
+        }
+        let state = self.state.lock().await;
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} closed. Messages sent: {}, errors: {}",
+            self.id, state.messages_sent, state.errors_count
+        );
+        Ok(())
+    }
+}
+
+/// Returns the bytes of the raw header values and the text representation of the other ones.
+fn header_value_bytes(value: &HeaderValue) -> Vec<u8> {
+    match value.kind() {
+        HeaderKind::Raw | HeaderKind::String => value.as_bytes().to_vec(),
+        _ => value.to_string_value().into_bytes(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::HeaderKey;
+    use iggy_connector_sdk::{Payload, Schema};
+    use rdkafka::message::Headers;
+    use std::collections::BTreeMap;
+
+    fn test_config() -> KafkaSinkConfig {
+        KafkaSinkConfig {
+            bootstrap_servers: "localhost:9092".to_owned(),
+            topic: None,
+            key_header: None,
+            include_headers: None,
+            include_metadata: None,
+            send_timeout: None,
+            properties: None,
+        }
+    }
+
+    fn test_message(headers: BTreeMap<HeaderKey, HeaderValue>) -> ConsumedMessage {
+        ConsumedMessage {
+            id: 1,
+            offset: 5,
+            checksum: 0,
+            timestamp: 0,
+            origin_timestamp: 0,
+            headers: Some(headers),
+            payload: Payload::Raw(b"payload".to_vec()),
+        }
+    }
+
+    fn record_headers(headers: &OwnedHeaders) -> Vec<(String, Vec<u8>)> {
+        headers
+            .iter()
+            .map(|header| {
+                (
+                    header.key.to_owned(),
+                    header.value.unwrap_or_default().to_vec(),
+                )
+            })
+            .collect()
+    }
+
+    fn topic_metadata() -> TopicMetadata {
+        TopicMetadata {
+            stream: "stream".to_owned(),
+            topic: "topic".to_owned(),
+        }
+    }
+
+    fn messages_metadata() -> MessagesMetadata {
+        MessagesMetadata {
+            partition_id: 1,
+            current_offset: 5,
+            schema: Schema::Raw,
+        }
+    }
+
+    #[test]
+    fn should_use_key_header_as_record_key_and_skip_source_metadata() {
+        let sink = KafkaSink::new(1, test_config());
+        let headers = BTreeMap::from([
+            (
+                HeaderKey::try_from("kafka_key").unwrap(),
+                HeaderValue::try_from(b"order-1".as_slice()).unwrap(),
+            ),
+            (
+                HeaderKey::try_from("kafka_offset").unwrap(),
+                HeaderValue::from(7i64),
+            ),
+            (
+                HeaderKey::try_from("trace_id").unwrap(),
+                HeaderValue::try_from("abc").unwrap(),
+            ),
+            (
+                HeaderKey::try_from("retries").unwrap(),
+                HeaderValue::from(3u32),
+            ),
+        ]);
+
+        let (key, headers) = sink.build_record_parts(
+            &topic_metadata(),
+            &messages_metadata(),
+            &test_message(headers),
+        );
+
+        assert_eq!(key.as_deref(), Some(b"order-1".as_slice()));
+        assert_eq!(
+            record_headers(&headers),
+            vec![
+                ("retries".to_owned(), b"3".to_vec()),
+                ("trace_id".to_owned(), b"abc".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_add_iggy_metadata_headers_when_enabled() {
+        let mut config = test_config();
+        config.include_headers = Some(false);
+        config.include_metadata = Some(true);
+        let sink = KafkaSink::new(1, config);
+        let headers = BTreeMap::from([(
+            HeaderKey::try_from("trace_id").unwrap(),
+            HeaderValue::try_from("abc").unwrap(),
+        )]);
+
+        let (key, headers) = sink.build_record_parts(
+            &topic_metadata(),
+            &messages_metadata(),
+            &test_message(headers),
+        );
+
+        assert!(key.is_none());
+        assert_eq!(
+            record_headers(&headers),
+            vec![
+                ("iggy_stream".to_owned(), b"stream".to_vec()),
+                ("iggy_topic".to_owned(), b"topic".to_vec()),
+                ("iggy_partition_id".to_owned(), b"1".to_vec()),
+                ("iggy_offset".to_owned(), b"5".to_vec()),
+                ("iggy_id".to_owned(), b"1".to_vec()),
+            ]
+        );
+    }
+}
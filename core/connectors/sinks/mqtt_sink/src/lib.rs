@@ -0,0 +1,611 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use humantime::Duration as HumanDuration;
+use iggy_common::{HeaderKind, HeaderValue};
+use iggy_connector_sdk::{
+    ConsumedMessage, Error, MessagesMetadata, Sink, TopicMetadata, sink_connector,
+};
+use rumqttc::v5::mqttbytes::v5::{
+    Packet, PubAckReason, PubCompReason, PubRecReason, PublishProperties,
+};
+use rumqttc::v5::mqttbytes::{QoS, qos};
+use rumqttc::v5::{AsyncClient, Event, EventLoop, MqttOptions};
+use rumqttc::{Outgoing, Transport};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::{Mutex, mpsc};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, sleep, timeout, timeout_at};
+use tracing::{debug, error, info, warn};
+
+sink_connector!(MqttSink);
+
+const CONNECTOR_NAME: &str = "MQTT sink";
+const DEFAULT_PORT: u16 = 1883;
+const DEFAULT_QOS: u8 = 1;
+const DEFAULT_TOPIC: &str = "{stream}/{topic}";
+const DEFAULT_TOPIC_HEADER: &str = "mqtt_topic";
+const DEFAULT_KEEP_ALIVE: &str = "30s";
+const DEFAULT_ACK_TIMEOUT: &str = "5s";
+const CHANNEL_CAPACITY: usize = 1000;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Headers mapped to the MQTT properties of the same meaning.
+const CONTENT_TYPE_HEADER: &str = "mqtt_content_type";
+const RESPONSE_TOPIC_HEADER: &str = "mqtt_response_topic";
+const CORRELATION_DATA_HEADER: &str = "mqtt_correlation_data";
+
+/// Headers added by the MQTT source to describe the original message, which must not be
+/// published back as the user properties when bridging the messages back to MQTT.
+const SOURCE_METADATA_HEADERS: [&str; 3] = ["mqtt_topic", "mqtt_qos", "mqtt_retain"];
+
+#[derive(Debug)]
+pub struct MqttSink {
+    id: u32,
+    config: MqttSinkConfig,
+    qos: QoS,
+    topic: String,
+    topic_header: String,
+    include_headers: bool,
+    include_metadata: bool,
+    ack_timeout: Duration,
+    client: Option<AsyncClient>,
+    event_loop: Option<JoinHandle<()>>,
+    /// The outcomes of the published messages, also serializing the batches being published.
+    deliveries: Mutex<Option<mpsc::UnboundedReceiver<Result<(), String>>>>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MqttSinkConfig {
+    pub host: String,
+    pub port: Option<u16>,
+    pub client_id: Option<String>,
+    pub username: Option<String>,
+    #[serde(serialize_with = "iggy_common::serde_secret::serialize_optional_secret")]
+    pub password: Option<SecretString>,
+    pub tls: Option<bool>,
+    pub topic: Option<String>,
+    pub topic_header: Option<String>,
+    pub qos: Option<u8>,
+    pub retain: Option<bool>,
+    pub include_headers: Option<bool>,
+    pub include_metadata: Option<bool>,
+    pub keep_alive: Option<String>,
+    pub ack_timeout: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    messages_sent: u64,
+    errors_count: u64,
+}
+
+impl MqttSink {
+    pub fn new(id: u32, config: MqttSinkConfig) -> Self {
+        MqttSink {
+            id,
+            qos: QoS::AtLeastOnce,
+            topic: config
+                .topic
+                .clone()
+                .unwrap_or_else(|| DEFAULT_TOPIC.to_owned()),
+            topic_header: config
+                .topic_header
+                .clone()
+                .unwrap_or_else(|| DEFAULT_TOPIC_HEADER.to_owned()),
+            include_headers: config.include_headers.unwrap_or(true),
+            include_metadata: config.include_metadata.unwrap_or(false),
+            ack_timeout: parse_duration(config.ack_timeout.as_deref(), DEFAULT_ACK_TIMEOUT),
+            config,
+            client: None,
+            event_loop: None,
+            deliveries: Mutex::new(None),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    fn mqtt_options(&self) -> MqttOptions {
+        let client_id = self
+            .config
+            .client_id
+            .clone()
+            .unwrap_or_else(|| format!("iggy-mqtt-sink-{}", self.id));
+        let mut options = MqttOptions::new(
+            client_id,
+            &self.config.host,
+            self.config.port.unwrap_or(DEFAULT_PORT),
+        );
+        options.set_keep_alive(parse_duration(
+            self.config.keep_alive.as_deref(),
+            DEFAULT_KEEP_ALIVE,
+        ));
+        if let Some(username) = &self.config.username {
+            let password = self
+                .config
+                .password
+                .as_ref()
+                .map(|password| password.expose_secret().to_owned())
+                .unwrap_or_default();
+            options.set_credentials(username, password);
+        }
+        if self.config.tls.unwrap_or(false) {
+            // The TLS configuration requires a process-wide crypto provider, which may be
+            // already installed by another connector.
+            let _ = rustls::crypto::ring::default_provider().install_default();
+            options.set_transport(Transport::tls_with_default_config());
+        }
+        options
+    }
+
+    /// Resolves the MQTT topic from the topic header, or from the configured template,
+    /// where `{stream}`, `{topic}` and `{partition}` describe the Iggy origin of the message.
+    fn resolve_topic(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: &MessagesMetadata,
+        message: &ConsumedMessage,
+    ) -> String {
+        let header_topic = message.headers.as_ref().and_then(|headers| {
+            headers
+                .iter()
+                .find(|(name, _)| name.to_string_value() == self.topic_header)
+                .map(|(_, value)| header_value_string(value))
+        });
+        match header_topic {
+            Some(topic) if !topic.is_empty() => topic,
+            _ => self
+                .topic
+                .replace("{stream}", &topic_metadata.stream)
+                .replace("{topic}", &topic_metadata.topic)
+                .replace("{partition}", &messages_metadata.partition_id.to_string()),
+        }
+    }
+
+    /// Maps the message headers to the MQTT properties, with the remaining headers
+    /// and optionally the Iggy metadata published as the user properties.
+    fn build_properties(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: &MessagesMetadata,
+        message: &ConsumedMessage,
+    ) -> PublishProperties {
+        let mut properties = PublishProperties::default();
+        if let Some(headers) = &message.headers {
+            for (name, value) in headers {
+                let name = name.to_string_value();
+                match name.as_str() {
+                    CONTENT_TYPE_HEADER => {
+                        properties.content_type = Some(header_value_string(value))
+                    }
+                    RESPONSE_TOPIC_HEADER => {
+                        properties.response_topic = Some(header_value_string(value))
+                    }
+                    CORRELATION_DATA_HEADER => {
+                        properties.correlation_data = Some(value.as_bytes().to_vec().into())
+                    }
+                    name if name == self.topic_header
+                        || SOURCE_METADATA_HEADERS.contains(&name) => {}
+                    _ if self.include_headers => properties
+                        .user_properties
+                        .push((name, header_value_string(value))),
+                    _ => {}
+                }
+            }
+        }
+
+        if self.include_metadata {
+            let metadata = [
+                ("iggy_stream", topic_metadata.stream.clone()),
+                ("iggy_topic", topic_metadata.topic.clone()),
+                (
+                    "iggy_partition_id",
+                    messages_metadata.partition_id.to_string(),
+                ),
+                ("iggy_offset", message.offset.to_string()),
+                ("iggy_id", message.id.to_string()),
+            ];
+            for (name, value) in metadata {
+                properties.user_properties.push((name.to_owned(), value));
+            }
+        }
+        properties
+    }
+}
+
+#[async_trait]
+impl Sink for MqttSink {
+    async fn open(&mut self) -> Result<(), Error> {
+        info!(
+            "Opening {CONNECTOR_NAME} connector with ID: {}. Broker: {}:{}, topic: {}",
+            self.id,
+            self.config.host,
+            self.config.port.unwrap_or(DEFAULT_PORT),
+            self.topic
+        );
+        let qos_value = self.config.qos.unwrap_or(DEFAULT_QOS);
+        let Some(qos) = qos(qos_value) else {
+            return Err(Error::InvalidConfigValue(format!(
+                "Invalid MQTT QoS: {qos_value}, expected 0, 1 or 2"
+            )));
+        };
+
+        let (client, mut event_loop) = AsyncClient::new(self.mqtt_options(), CHANNEL_CAPACITY);
+        timeout(CONNECT_TIMEOUT, wait_for_connack(&mut event_loop))
+            .await
+            .map_err(|_| {
+                Error::Connection(format!(
+                    "Timed out connecting to the MQTT broker: {}",
+                    self.config.host
+                ))
+            })??;
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.event_loop = Some(tokio::spawn(run_event_loop(self.id, event_loop, sender)));
+        *self.deliveries.lock().await = Some(receiver);
+        self.client = Some(client);
+        self.qos = qos;
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} opened successfully",
+            self.id
+        );
+        Ok(())
+    }
+
+    async fn consume(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: MessagesMetadata,
+        messages: Vec<ConsumedMessage>,
+    ) -> Result<(), Error> {
+        let Some(client) = self.client.as_ref() else {
+            error!(
+                "{CONNECTOR_NAME} connector with ID: {} is not opened",
+                self.id
+            );
+            return Err(Error::InvalidState);
+        };
+        let mut deliveries = self.deliveries.lock().await;
+        let Some(deliveries) = deliveries.as_mut() else {
+            return Err(Error::InvalidState);
+        };
+        // Discard the late outcomes of the messages from the previous batches which timed out.
+        while deliveries.try_recv().is_ok() {}
+
+        let retain = self.config.retain.unwrap_or(false);
+        let mut published = 0;
+        let mut failed = 0;
+        for message in &messages {
+            let payload = match message.payload.try_to_bytes() {
+                Ok(payload) => payload,
+                Err(error) => {
+                    warn!(
+                        "{CONNECTOR_NAME} connector with ID: {} skipped the message with offset: {}. {error}",
+                        self.id, message.offset
+                    );
+                    continue;
+                }
+            };
+            let topic = self.resolve_topic(topic_metadata, &messages_metadata, message);
+            if topic.is_empty() || topic.contains(['+', '#']) {
+                warn!(
+                    "{CONNECTOR_NAME} connector with ID: {} skipped the message with offset: {} and invalid MQTT topic: {topic}",
+                    self.id, message.offset
+                );
+                continue;
+            }
+
+            let properties = self.build_properties(topic_metadata, &messages_metadata, message);
+            match client
+                .publish_with_properties(topic, self.qos, retain, payload, properties)
+                .await
+            {
+                Ok(()) => published += 1,
+                Err(error) => {
+                    failed += 1;
+                    error!(
+                        "{CONNECTOR_NAME} connector with ID: {} failed to publish a message. {error}",
+                        self.id
+                    );
+                }
+            }
+        }
+
+        // The outcomes are not matched with the messages, as only their count matters.
+        let deadline = Instant::now() + self.ack_timeout;
+        let mut delivered = 0;
+        let mut rejected = 0;
+        while delivered + rejected < published {
+            match timeout_at(deadline, deliveries.recv()).await {
+                Ok(Some(Ok(()))) => delivered += 1,
+                Ok(Some(Err(reason))) => {
+                    rejected += 1;
+                    error!(
+                        "{CONNECTOR_NAME} connector with ID: {} had a message rejected by the MQTT broker. {reason}",
+                        self.id
+                    );
+                }
+                Ok(None) | Err(_) => {
+                    error!(
+                        "{CONNECTOR_NAME} connector with ID: {} did not receive {} MQTT acknowledgements in time",
+                        self.id,
+                        published - delivered - rejected
+                    );
+                    break;
+                }
+            }
+        }
+        failed += published - delivered;
+
+        let mut state = self.state.lock().await;
+        state.messages_sent += delivered as u64;
+        state.errors_count += failed as u64;
+        debug!(
+            "{CONNECTOR_NAME} connector with ID: {} published {delivered} messages from stream: {}, topic: {}, partition: {}, offset: {}",
+            self.id,
+            topic_metadata.stream,
+            topic_metadata.topic,
+            messages_metadata.partition_id,
+            messages_metadata.current_offset
+        );
+
+        if failed > 0 {
+            return Err(Error::CannotStoreData(format!(
+                "Failed to publish {failed} of {} messages to the MQTT broker",
+                delivered + failed
+            )));
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<(), Error> {
+        if let Some(client) = self.client.take()
+            && let Err(error) = client.disconnect().await
+        {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} failed to disconnect from the MQTT broker. {error}",
+                self.id
+            );
+        }
+        if let Some(mut event_loop) = self.event_loop.take()
+            && timeout(CLOSE_TIMEOUT, &mut event_loop).await.is_err()
+        {
+            event_loop.abort();
+        }
+        let state = self.state.lock().await;
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} closed. Messages sent: {}, errors: {}",
+            self.id, state.messages_sent, state.errors_count
+        );
+        Ok(())
+    }
+}
+
+async fn wait_for_connack(event_loop: &mut EventLoop) -> Result<(), Error> {
+    loop {
+        match event_loop.poll().await {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => return Ok(()),
+            Ok(_) => {}
+            Err(error) => {
+                return Err(Error::Connection(format!(
+                    "Failed to connect to the MQTT broker: {error}"
+                )));
+            }
+        }
+    }
+}
+
+/// Drives the MQTT connection, reporting the outcome of every published message: written
+/// to the socket for QoS 0, acknowledged by the broker for QoS 1 and completed for QoS 2.
+async fn run_event_loop(
+    id: u32,
+    mut event_loop: EventLoop,
+    deliveries: mpsc::UnboundedSender<Result<(), String>>,
+) {
+    loop {
+        let delivery = match event_loop.poll().await {
+            Ok(Event::Outgoing(Outgoing::Publish(0))) => Ok(()),
+            Ok(Event::Incoming(Packet::PubAck(ack))) => match ack.reason {
+                PubAckReason::Success | PubAckReason::NoMatchingSubscribers => Ok(()),
+                reason => Err(format!("{reason:?}")),
+            },
+            Ok(Event::Incoming(Packet::PubRec(rec))) => match rec.reason {
+                PubRecReason::Success | PubRecReason::NoMatchingSubscribers => continue,
+                reason => Err(format!("{reason:?}")),
+            },
+            Ok(Event::Incoming(Packet::PubComp(comp))) => match comp.reason {
+                PubCompReason::Success => Ok(()),
+                reason => Err(format!("{reason:?}")),
+            },
+            Ok(Event::Outgoing(Outgoing::Disconnect)) => break,
+            Ok(_) => continue,
+            Err(error) => {
+                warn!(
+                    "{CONNECTOR_NAME} connector with ID: {id} lost the connection to the MQTT broker, reconnecting. {error}"
+                );
+                sleep(RECONNECT_DELAY).await;
+                continue;
+            }
+        };
+        if deliveries.send(delivery).is_err() {
+            break;
+        }
+    }
+}
+
+fn parse_duration(value: Option<&str>, default: &str) -> Duration {
+    HumanDuration::from_str(value.unwrap_or(default))
+        .or_else(|_| HumanDuration::from_str(default))
+        .map(|duration| duration.into())
+        .unwrap_or_default()
+}
+
+/// Returns the text of the raw and string header values, and the text representation of the other ones.
+fn header_value_string(value: &HeaderValue) -> String {
+    match value.kind() {
+        HeaderKind::Raw | HeaderKind::String => {
+            String::from_utf8_lossy(value.as_bytes()).into_owned()
+        }
+        _ => value.to_string_value(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::HeaderKey;
+    use iggy_connector_sdk::{Payload, Schema};
+    use std::collections::BTreeMap;
+
+    fn test_config() -> MqttSinkConfig {
+        MqttSinkConfig {
+            host: "localhost".to_owned(),
+            port: None,
+            client_id: None,
+            username: None,
+            password: None,
+            tls: None,
+            topic: None,
+            topic_header: None,
+            qos: None,
+            retain: None,
+            include_headers: None,
+            include_metadata: None,
+            keep_alive: None,
+            ack_timeout: None,
+        }
+    }
+
+    fn test_message(headers: Vec<(&str, HeaderValue)>) -> ConsumedMessage {
+        ConsumedMessage {
+            id: 1,
+            offset: 5,
+            checksum: 0,
+            timestamp: 0,
+            origin_timestamp: 0,
+            headers: Some(
+                headers
+                    .into_iter()
+                    .map(|(name, value)| (HeaderKey::try_from(name).unwrap(), value))
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+            payload: Payload::Raw(b"payload".to_vec()),
+        }
+    }
+
+    fn topic_metadata() -> TopicMetadata {
+        TopicMetadata {
+            stream: "stream".to_owned(),
+            topic: "topic".to_owned(),
+        }
+    }
+
+    fn messages_metadata() -> MessagesMetadata {
+        MessagesMetadata {
+            partition_id: 1,
+            current_offset: 5,
+            schema: Schema::Raw,
+        }
+    }
+
+    #[test]
+    fn should_resolve_topic_from_header_or_template() {
+        let mut config = test_config();
+        config.topic = Some("iggy/{stream}/{topic}/{partition}".to_owned());
+        let sink = MqttSink::new(1, config);
+
+        let message = test_message(vec![(
+            "mqtt_topic",
+            HeaderValue::try_from("sensors/1").unwrap(),
+        )]);
+        assert_eq!(
+            sink.resolve_topic(&topic_metadata(), &messages_metadata(), &message),
+            "sensors/1"
+        );
+
+        let message = test_message(vec![]);
+        assert_eq!(
+            sink.resolve_topic(&topic_metadata(), &messages_metadata(), &message),
+            "iggy/stream/topic/1"
+        );
+    }
+
+    #[test]
+    fn should_map_headers_to_properties_and_skip_source_metadata() {
+        let sink = MqttSink::new(1, test_config());
+        let message = test_message(vec![
+            ("mqtt_topic", HeaderValue::try_from("sensors/1").unwrap()),
+            ("mqtt_qos", HeaderValue::from(1u8)),
+            (
+                "mqtt_content_type",
+                HeaderValue::try_from("text/plain").unwrap(),
+            ),
+            (
+                "mqtt_correlation_data",
+                HeaderValue::try_from(b"request-1".as_slice()).unwrap(),
+            ),
+            ("unit", HeaderValue::try_from("celsius").unwrap()),
+            ("retries", HeaderValue::from(3u32)),
+        ]);
+
+        let properties = sink.build_properties(&topic_metadata(), &messages_metadata(), &message);
+
+        assert_eq!(properties.content_type.as_deref(), Some("text/plain"));
+        assert_eq!(
+            properties.correlation_data.as_deref(),
+            Some(b"request-1".as_slice())
+        );
+        assert_eq!(properties.response_topic, None);
+        assert_eq!(
+            properties.user_properties,
+            vec![
+                ("retries".to_owned(), "3".to_owned()),
+                ("unit".to_owned(), "celsius".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_add_iggy_metadata_to_user_properties() {
+        let mut config = test_config();
+        config.include_headers = Some(false);
+        config.include_metadata = Some(true);
+        let sink = MqttSink::new(1, config);
+        let message = test_message(vec![("unit", HeaderValue::try_from("celsius").unwrap())]);
+
+        let properties = sink.build_properties(&topic_metadata(), &messages_metadata(), &message);
+
+        assert_eq!(
+            properties.user_properties,
+            vec![
+                ("iggy_stream".to_owned(), "stream".to_owned()),
+                ("iggy_topic".to_owned(), "topic".to_owned()),
+                ("iggy_partition_id".to_owned(), "1".to_owned()),
+                ("iggy_offset".to_owned(), "5".to_owned()),
+                ("iggy_id".to_owned(), "1".to_owned()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,469 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use iggy_common::{HeaderKind, HeaderValue};
+use iggy_connector_sdk::{
+    ConsumedMessage, Error, MessagesMetadata, Payload, Sink, TopicMetadata, sink_connector,
+};
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use redis::streams::StreamMaxlen;
+use redis::{Client, RedisError};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+use simd_json::OwnedValue;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{debug, error, info, warn};
+
+sink_connector!(RedisSink);
+
+const CONNECTOR_NAME: &str = "Redis sink";
+const DEFAULT_TARGET: &str = "{stream}.{topic}";
+const DEFAULT_PAYLOAD_FIELD: &str = "payload";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Headers added by the Redis source to describe the original entry or message, which must
+/// not be written back as the entry fields when bridging the messages back to Redis.
+const SOURCE_METADATA_HEADERS: [&str; 4] = [
+    "redis_stream",
+    "redis_entry_id",
+    "redis_channel",
+    "redis_pattern",
+];
+
+#[derive(Debug)]
+pub struct RedisSink {
+    id: u32,
+    config: RedisSinkConfig,
+    mode: Mode,
+    target: String,
+    payload_field: String,
+    expand_json: bool,
+    include_headers: bool,
+    include_metadata: bool,
+    connection: Option<ConnectionManager>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedisSinkConfig {
+    #[serde(serialize_with = "iggy_common::serde_secret::serialize_secret")]
+    pub connection_string: SecretString,
+    pub mode: Option<Mode>,
+    pub target: Option<String>,
+    pub target_header: Option<String>,
+    pub max_len: Option<usize>,
+    pub payload_field: Option<String>,
+    pub expand_json: Option<bool>,
+    pub include_headers: Option<bool>,
+    pub include_metadata: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// Adds the messages as the entries of Redis Streams.
+    #[default]
+    Streams,
+    /// Publishes the messages to Redis channels.
+    PubSub,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    messages_sent: u64,
+    errors_count: u64,
+}
+
+impl RedisSink {
+    pub fn new(id: u32, config: RedisSinkConfig) -> Self {
+        RedisSink {
+            id,
+            mode: config.mode.unwrap_or_default(),
+            target: config
+                .target
+                .clone()
+                .unwrap_or_else(|| DEFAULT_TARGET.to_owned()),
+            payload_field: config
+                .payload_field
+                .clone()
+                .unwrap_or_else(|| DEFAULT_PAYLOAD_FIELD.to_owned()),
+            expand_json: config.expand_json.unwrap_or(true),
+            include_headers: config.include_headers.unwrap_or(true),
+            include_metadata: config.include_metadata.unwrap_or(false),
+            config,
+            connection: None,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Resolves the Redis stream or channel from the target header, or from the configured
+    /// template, where `{stream}`, `{topic}` and `{partition}` describe the Iggy origin of
+    /// the message.
+    fn resolve_target(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: &MessagesMetadata,
+        message: &ConsumedMessage,
+    ) -> String {
+        let header_target = self
+            .config
+            .target_header
+            .as_deref()
+            .and_then(|target_header| {
+                message.headers.as_ref()?.iter().find_map(|(name, value)| {
+                    (name.to_string_value() == target_header).then(|| header_value_bytes(value))
+                })
+            });
+        match header_target.map(String::from_utf8) {
+            Some(Ok(target)) if !target.is_empty() => target,
+            _ => self
+                .target
+                .replace("{stream}", &topic_metadata.stream)
+                .replace("{topic}", &topic_metadata.topic)
+                .replace("{partition}", &messages_metadata.partition_id.to_string()),
+        }
+    }
+
+    /// Maps the message to the fields of a stream entry: the fields of a JSON object
+    /// payload, or the payload field, followed by the headers and optionally the Iggy
+    /// metadata.
+    fn build_fields(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: &MessagesMetadata,
+        message: &ConsumedMessage,
+    ) -> Result<Vec<(String, Vec<u8>)>, Error> {
+        let mut fields = Vec::new();
+        match &message.payload {
+            Payload::Json(OwnedValue::Object(object)) if self.expand_json => {
+                for (name, value) in object.iter() {
+                    let value = match value {
+                        OwnedValue::String(text) => text.as_bytes().to_vec(),
+                        value => simd_json::to_vec(value).map_err(|_| Error::InvalidJsonPayload)?,
+                    };
+                    fields.push((name.to_string(), value));
+                }
+            }
+            payload => fields.push((self.payload_field.clone(), payload.try_to_bytes()?)),
+        }
+
+        if self.include_headers
+            && let Some(headers) = &message.headers
+        {
+            for (name, value) in headers {
+                let name = name.to_string_value();
+                if SOURCE_METADATA_HEADERS.contains(&name.as_str())
+                    || self.config.target_header.as_deref() == Some(name.as_str())
+                {
+                    continue;
+                }
+                fields.push((name, header_value_bytes(value)));
+            }
+        }
+
+        if self.include_metadata {
+            let metadata = [
+                ("iggy_stream", topic_metadata.stream.clone()),
+                ("iggy_topic", topic_metadata.topic.clone()),
+                (
+                    "iggy_partition_id",
+                    messages_metadata.partition_id.to_string(),
+                ),
+                ("iggy_offset", message.offset.to_string()),
+                ("iggy_id", message.id.to_string()),
+            ];
+            for (name, value) in metadata {
+                fields.push((name.to_owned(), value.into_bytes()));
+            }
+        }
+        Ok(fields)
+    }
+}
+
+#[async_trait]
+impl Sink for RedisSink {
+    async fn open(&mut self) -> Result<(), Error> {
+        info!(
+            "Opening {CONNECTOR_NAME} connector with ID: {}. Mode: {:?}, target: {}",
+            self.id, self.mode, self.target
+        );
+        let client =
+            Client::open(self.config.connection_string.expose_secret()).map_err(|error| {
+                Error::InvalidConfigValue(format!("Invalid Redis connection string: {error}"))
+            })?;
+        let connection_config = ConnectionManagerConfig::new()
+            .set_connection_timeout(Some(CONNECT_TIMEOUT))
+            .set_response_timeout(Some(RESPONSE_TIMEOUT));
+        let connection = client
+            .get_connection_manager_with_config(connection_config)
+            .await
+            .map_err(|error| Error::Connection(format!("Failed to connect to Redis: {error}")))?;
+        self.connection = Some(connection);
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} opened successfully",
+            self.id
+        );
+        Ok(())
+    }
+
+    async fn consume(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: MessagesMetadata,
+        messages: Vec<ConsumedMessage>,
+    ) -> Result<(), Error> {
+        let Some(connection) = self.connection.as_ref() else {
+            error!(
+                "{CONNECTOR_NAME} connector with ID: {} is not opened",
+                self.id
+            );
+            return Err(Error::InvalidState);
+        };
+
+        // The whole batch is sent in a single round trip.
+        let mut pipeline = redis::pipe();
+        let mut count = 0;
+        for message in &messages {
+            let target = self.resolve_target(topic_metadata, &messages_metadata, message);
+            let result = match self.mode {
+                Mode::Streams => self
+                    .build_fields(topic_metadata, &messages_metadata, message)
+                    .map(|fields| match self.config.max_len {
+                        Some(max_len) => pipeline.xadd_maxlen(
+                            &target,
+                            StreamMaxlen::Approx(max_len),
+                            "*",
+                            &fields,
+                        ),
+                        None => pipeline.xadd(&target, "*", &fields),
+                    }),
+                Mode::PubSub => message
+                    .payload
+                    .try_to_bytes()
+                    .map(|payload| pipeline.publish(&target, payload)),
+            };
+            match result {
+                Ok(command) => {
+                    command.ignore();
+                    count += 1;
+                }
+                Err(error) => warn!(
+                    "{CONNECTOR_NAME} connector with ID: {} skipped the message with offset: {}. {error}",
+                    self.id, message.offset
+                ),
+            }
+        }
+        let skipped = messages.len() - count;
+
+        let result: Result<(), RedisError> = if count > 0 {
+            pipeline.query_async(&mut connection.clone()).await
+        } else {
+            Ok(())
+        };
+        let mut state = self.state.lock().await;
+        state.errors_count += skipped as u64;
+        if let Err(error) = result {
+            state.errors_count += count as u64;
+            error!(
+                "{CONNECTOR_NAME} connector with ID: {} failed to write {count} messages to Redis. {error}",
+                self.id
+            );
+            return Err(Error::CannotStoreData(format!(
+                "Failed to write {count} messages to Redis: {error}"
+            )));
+        }
+
+        state.messages_sent += count as u64;
+        debug!(
+            "{CONNECTOR_NAME} connector with ID: {} wrote {count} messages from stream: {}, topic: {}, partition: {}, offset: {}",
+            self.id,
+            topic_metadata.stream,
+            topic_metadata.topic,
+            messages_metadata.partition_id,
+            messages_metadata.current_offset
+        );
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<(), Error> {
+        self.connection.take();
+        let state = self.state.lock().await;
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} closed. Messages sent: {}, errors: {}",
+            self.id, state.messages_sent, state.errors_count
+        );
+        Ok(())
+    }
+}
+
+/// Returns the bytes of the raw and string header values, and the text representation
+/// of the other ones.
+fn header_value_bytes(value: &HeaderValue) -> Vec<u8> {
+    match value.kind() {
+        HeaderKind::Raw | HeaderKind::String => value.as_bytes().to_vec(),
+        _ => value.to_string_value().into_bytes(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::HeaderKey;
+    use iggy_connector_sdk::Schema;
+    use std::collections::BTreeMap;
+
+    fn test_config() -> RedisSinkConfig {
+        RedisSinkConfig {
+            connection_string: SecretString::from("redis://localhost:6379"),
+            mode: None,
+            target: None,
+            target_header: None,
+            max_len: None,
+            payload_field: None,
+            expand_json: None,
+            include_headers: None,
+            include_metadata: None,
+        }
+    }
+
+    fn test_message(payload: Payload, headers: Vec<(&str, HeaderValue)>) -> ConsumedMessage {
+        ConsumedMessage {
+            id: 1,
+            offset: 5,
+            checksum: 0,
+            timestamp: 0,
+            origin_timestamp: 0,
+            headers: Some(
+                headers
+                    .into_iter()
+                    .map(|(name, value)| (HeaderKey::try_from(name).unwrap(), value))
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+            payload,
+        }
+    }
+
+    fn topic_metadata() -> TopicMetadata {
+        TopicMetadata {
+            stream: "stream".to_owned(),
+            topic: "topic".to_owned(),
+        }
+    }
+
+    fn messages_metadata() -> MessagesMetadata {
+        MessagesMetadata {
+            partition_id: 1,
+            current_offset: 5,
+            schema: Schema::Raw,
+        }
+    }
+
+    fn fields(fields: Vec<(String, Vec<u8>)>) -> Vec<(String, String)> {
+        let mut fields: Vec<(String, String)> = fields
+            .into_iter()
+            .map(|(name, value)| (name, String::from_utf8(value).unwrap()))
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    #[test]
+    fn should_resolve_target_from_header_or_template() {
+        let mut config = test_config();
+        config.target = Some("iggy:{stream}:{topic}:{partition}".to_owned());
+        config.target_header = Some("redis_target".to_owned());
+        let sink = RedisSink::new(1, config);
+
+        let message = test_message(
+            Payload::Raw(b"payload".to_vec()),
+            vec![("redis_target", HeaderValue::try_from("orders").unwrap())],
+        );
+        assert_eq!(
+            sink.resolve_target(&topic_metadata(), &messages_metadata(), &message),
+            "orders"
+        );
+
+        let message = test_message(Payload::Raw(b"payload".to_vec()), vec![]);
+        assert_eq!(
+            sink.resolve_target(&topic_metadata(), &messages_metadata(), &message),
+            "iggy:stream:topic:1"
+        );
+    }
+
+    #[test]
+    fn should_expand_json_object_into_fields() {
+        let sink = RedisSink::new(1, test_config());
+        let message = test_message(
+            Payload::Json(simd_json::json!({"id": 7, "status": "paid", "items": ["a"]})),
+            vec![("tenant", HeaderValue::try_from("acme").unwrap())],
+        );
+
+        let fields = fields(
+            sink.build_fields(&topic_metadata(), &messages_metadata(), &message)
+                .unwrap(),
+        );
+
+        assert_eq!(
+            fields,
+            vec![
+                ("id".to_owned(), "7".to_owned()),
+                ("items".to_owned(), r#"["a"]"#.to_owned()),
+                ("status".to_owned(), "paid".to_owned()),
+                ("tenant".to_owned(), "acme".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_write_payload_field_and_skip_source_metadata_headers() {
+        let mut config = test_config();
+        config.expand_json = Some(false);
+        config.include_metadata = Some(true);
+        let sink = RedisSink::new(1, config);
+        let message = test_message(
+            Payload::Json(simd_json::json!({"id": 7})),
+            vec![
+                ("redis_stream", HeaderValue::try_from("orders").unwrap()),
+                ("redis_entry_id", HeaderValue::try_from("1-0").unwrap()),
+                ("retries", HeaderValue::from(3u32)),
+            ],
+        );
+
+        let fields = fields(
+            sink.build_fields(&topic_metadata(), &messages_metadata(), &message)
+                .unwrap(),
+        );
+
+        assert_eq!(
+            fields,
+            vec![
+                ("iggy_id".to_owned(), "1".to_owned()),
+                ("iggy_offset".to_owned(), "5".to_owned()),
+                ("iggy_partition_id".to_owned(), "1".to_owned()),
+                ("iggy_stream".to_owned(), "stream".to_owned()),
+                ("iggy_topic".to_owned(), "topic".to_owned()),
+                ("payload".to_owned(), r#"{"id":7}"#.to_owned()),
+                ("retries".to_owned(), "3".to_owned()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{Compression, Format};
+use arrow_array::{
+    ArrayRef, BinaryArray, RecordBatch, StringArray, TimestampMicrosecondArray, UInt32Array,
+    UInt64Array,
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use base64::Engine;
+use base64::engine::general_purpose;
+use flate2::write::GzEncoder;
+use iggy_common::{HeaderKey, HeaderValue};
+use iggy_connector_sdk::{ConsumedMessage, Error, Payload, owned_value_to_serde_json};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{GzipLevel, ZstdLevel};
+use parquet::file::properties::WriterProperties;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Approximate size of the metadata of a message buffered for a Parquet file.
+const RECORD_OVERHEAD: u64 = 48;
+
+/// Messages of a single partition buffered until the file is rolled and uploaded.
+#[derive(Debug)]
+pub(crate) struct FileBuffer {
+    pub first_offset: u64,
+    pub last_offset: u64,
+    pub messages_count: usize,
+    pub size: u64,
+    pub opened_at: Instant,
+    contents: Contents,
+}
+
+#[derive(Debug)]
+enum Contents {
+    /// The encoded lines of the file, compressed only once the file is rolled.
+    Jsonl(Vec<u8>),
+    Parquet(Vec<Record>),
+}
+
+#[derive(Debug)]
+struct Record {
+    id: u128,
+    offset: u64,
+    timestamp: u64,
+    origin_timestamp: u64,
+    headers: Option<String>,
+    payload: RecordPayload,
+}
+
+#[derive(Debug)]
+enum RecordPayload {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+/// Fields of the written messages, shared by the JSONL lines and the Parquet columns.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Fields {
+    pub include_metadata: bool,
+    pub include_headers: bool,
+}
+
+impl FileBuffer {
+    pub fn new(format: Format, first_offset: u64) -> Self {
+        FileBuffer {
+            first_offset,
+            last_offset: first_offset,
+            messages_count: 0,
+            size: 0,
+            opened_at: Instant::now(),
+            contents: match format {
+                Format::Jsonl => Contents::Jsonl(Vec::new()),
+                Format::Parquet => Contents::Parquet(Vec::new()),
+            },
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        stream: &str,
+        topic: &str,
+        partition_id: u32,
+        message: ConsumedMessage,
+        fields: Fields,
+    ) -> Result<(), Error> {
+        let offset = message.offset;
+        let size = match &mut self.contents {
+            Contents::Jsonl(lines) => {
+                let mut line =
+                    serde_json::to_vec(&jsonl_line(stream, topic, partition_id, message, fields))
+                        .map_err(|error| Error::Serialization(format!("JSONL line: {error}")))?;
+                line.push(b'\n');
+                lines.extend_from_slice(&line);
+                line.len() as u64
+            }
+            Contents::Parquet(records) => {
+                let headers = if fields.include_headers {
+                    headers_to_json(message.headers.as_ref())
+                        .map(|headers| Value::Object(headers).to_string())
+                } else {
+                    None
+                };
+                let payload = match message.payload {
+                    Payload::Json(value) => RecordPayload::Text(
+                        simd_json::to_string(&value).map_err(|_| Error::InvalidJsonPayload)?,
+                    ),
+                    Payload::Text(text) | Payload::Proto(text) => RecordPayload::Text(text),
+                    Payload::Raw(bytes) | Payload::FlatBuffer(bytes) | Payload::Avro(bytes) => {
+                        RecordPayload::Bytes(bytes)
+                    }
+                };
+                let size = RECORD_OVERHEAD
+                    + headers.as_ref().map_or(0, |headers| headers.len() as u64)
+                    + match &payload {
+                        RecordPayload::Text(text) => text.len() as u64,
+                        RecordPayload::Bytes(bytes) => bytes.len() as u64,
+                    };
+                records.push(Record {
+                    id: message.id,
+                    offset,
+                    timestamp: message.timestamp,
+                    origin_timestamp: message.origin_timestamp,
+                    headers,
+                    payload,
+                });
+                size
+            }
+        };
+        self.last_offset = offset;
+        self.messages_count += 1;
+        self.size += size;
+        Ok(())
+    }
+
+    /// Encodes the file, returning its contents and the extension of its name.
+    pub fn encode(
+        &self,
+        partition_id: u32,
+        compression: Compression,
+        fields: Fields,
+    ) -> Result<(Vec<u8>, &'static str), Error> {
+        match &self.contents {
+            Contents::Jsonl(lines) => match compression {
+                Compression::None => Ok((lines.clone(), "jsonl")),
+                Compression::Gzip => {
+                    let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+                    encoder
+                        .write_all(lines)
+                        .and_then(|_| encoder.finish())
+                        .map(|data| (data, "jsonl.gz"))
+                        .map_err(|error| Error::Serialization(format!("gzip: {error}")))
+                }
+                Compression::Zstd => zstd::encode_all(lines.as_slice(), 0)
+                    .map(|data| (data, "jsonl.zst"))
+                    .map_err(|error| Error::Serialization(format!("zstd: {error}"))),
+            },
+            Contents::Parquet(records) => {
+                encode_parquet(records, partition_id, compression, fields)
+                    .map(|data| (data, "parquet"))
+            }
+        }
+    }
+}
+
+fn jsonl_line(
+    stream: &str,
+    topic: &str,
+    partition_id: u32,
+    message: ConsumedMessage,
+    fields: Fields,
+) -> Value {
+    let payload = match message.payload {
+        Payload::Json(value) => owned_value_to_serde_json(&value),
+        Payload::Text(text) | Payload::Proto(text) => Value::String(text),
+        Payload::Raw(bytes) | Payload::FlatBuffer(bytes) | Payload::Avro(bytes) => {
+            Value::String(general_purpose::STANDARD.encode(bytes))
+        }
+    };
+    if !fields.include_metadata && !fields.include_headers {
+        return payload;
+    }
+
+    let mut line = Map::new();
+    if fields.include_metadata {
+        line.insert("stream".to_owned(), stream.into());
+        line.insert("topic".to_owned(), topic.into());
+        line.insert("partition_id".to_owned(), partition_id.into());
+        line.insert("offset".to_owned(), message.offset.into());
+        line.insert("id".to_owned(), message.id.to_string().into());
+        line.insert("timestamp".to_owned(), message.timestamp.into());
+        line.insert(
+            "origin_timestamp".to_owned(),
+            message.origin_timestamp.into(),
+        );
+    }
+    if fields.include_headers
+        && let Some(headers) = headers_to_json(message.headers.as_ref())
+    {
+        line.insert("headers".to_owned(), Value::Object(headers));
+    }
+    line.insert("payload".to_owned(), payload);
+    Value::Object(line)
+}
+
+/// Converts the headers to a JSON object, with the raw values encoded as base64.
+fn headers_to_json(
+    headers: Option<&BTreeMap<HeaderKey, HeaderValue>>,
+) -> Option<Map<String, Value>> {
+    let headers = headers.filter(|headers| !headers.is_empty())?;
+    Some(
+        headers
+            .iter()
+            .map(|(key, value)| {
+                let value = match value.as_raw() {
+                    Ok(raw) => general_purpose::STANDARD.encode(raw),
+                    Err(_) => value.to_string_value(),
+                };
+                (key.to_string_value(), Value::String(value))
+            })
+            .collect(),
+    )
+}
+
+fn encode_parquet(
+    records: &[Record],
+    partition_id: u32,
+    compression: Compression,
+    fields: Fields,
+) -> Result<Vec<u8>, Error> {
+    let mut schema_fields = Vec::new();
+    let mut columns: Vec<ArrayRef> = Vec::new();
+    if fields.include_metadata {
+        let timestamp_type = DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()));
+        schema_fields.extend([
+            Field::new("partition_id", DataType::UInt32, false),
+            Field::new("offset", DataType::UInt64, false),
+            Field::new("id", DataType::Utf8, false),
+            Field::new("timestamp", timestamp_type.clone(), false),
+            Field::new("origin_timestamp", timestamp_type, false),
+        ]);
+        columns.extend([
+            Arc::new(UInt32Array::from(vec![partition_id; records.len()])) as ArrayRef,
+            Arc::new(UInt64Array::from_iter_values(
+                records.iter().map(|record| record.offset),
+            )),
+            Arc::new(StringArray::from_iter_values(
+                records.iter().map(|record| record.id.to_string()),
+            )),
+            Arc::new(
+                TimestampMicrosecondArray::from_iter_values(
+                    records.iter().map(|record| record.timestamp as i64),
+                )
+                .with_timezone("UTC"),
+            ),
+            Arc::new(
+                TimestampMicrosecondArray::from_iter_values(
+                    records.iter().map(|record| record.origin_timestamp as i64),
+                )
+                .with_timezone("UTC"),
+            ),
+        ]);
+    }
+    if fields.include_headers {
+        schema_fields.push(Field::new("headers", DataType::Utf8, true));
+        columns.push(Arc::new(StringArray::from_iter(
+            records.iter().map(|record| record.headers.as_deref()),
+        )));
+    }
+
+    // The payloads are written as strings unless any of them is binary.
+    let binary = records
+        .iter()
+        .any(|record| matches!(record.payload, RecordPayload::Bytes(_)));
+    if binary {
+        schema_fields.push(Field::new("payload", DataType::Binary, false));
+        columns.push(Arc::new(BinaryArray::from_iter_values(records.iter().map(
+            |record| match &record.payload {
+                RecordPayload::Text(text) => text.as_bytes(),
+                RecordPayload::Bytes(bytes) => bytes.as_slice(),
+            },
+        ))));
+    } else {
+        schema_fields.push(Field::new("payload", DataType::Utf8, false));
+        columns.push(Arc::new(StringArray::from_iter_values(records.iter().map(
+            |record| match &record.payload {
+                RecordPayload::Text(text) => text.as_str(),
+                RecordPayload::Bytes(_) => "",
+            },
+        ))));
+    }
+
+    let schema = Arc::new(Schema::new(schema_fields));
+    let batch = RecordBatch::try_new(schema.clone(), columns)
+        .map_err(|error| Error::Serialization(format!("Parquet record batch: {error}")))?;
+    let compression = match compression {
+        Compression::None => parquet::basic::Compression::UNCOMPRESSED,
+        Compression::Gzip => parquet::basic::Compression::GZIP(GzipLevel::default()),
+        Compression::Zstd => parquet::basic::Compression::ZSTD(ZstdLevel::default()),
+    };
+    let properties = WriterProperties::builder()
+        .set_compression(compression)
+        .build();
+    let mut writer = ArrowWriter::try_new(Vec::new(), schema, Some(properties))
+        .map_err(|error| Error::Serialization(format!("Parquet writer: {error}")))?;
+    writer
+        .write(&batch)
+        .map_err(|error| Error::Serialization(format!("Parquet write: {error}")))?;
+    writer
+        .into_inner()
+        .map_err(|error| Error::Serialization(format!("Parquet close: {error}")))
+}
@@ -0,0 +1,725 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use files::{Fields, FileBuffer};
+use humantime::Duration as HumanDuration;
+use iggy_common::IggyByteSize;
+use iggy_connector_sdk::{
+    ConsumedMessage, Error, MessagesMetadata, Sink, TopicMetadata, sink_connector,
+};
+use object_store::aws::AmazonS3Builder;
+use object_store::local::LocalFileSystem;
+use object_store::path::Path;
+use object_store::{ObjectStore, ObjectStoreExt, PutPayload};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info};
+
+mod files;
+
+sink_connector!(S3Sink);
+
+const CONNECTOR_NAME: &str = "S3 sink";
+const DEFAULT_PATH_TEMPLATE: &str = "{stream}/{topic}/dt={date}/hour={hour}";
+const DEFAULT_REGION: &str = "us-east-1";
+const DEFAULT_MAX_FILE_SIZE: &str = "64 MB";
+const DEFAULT_MAX_FILE_MESSAGES: usize = 100_000;
+const DEFAULT_MAX_FILE_AGE: &str = "5m";
+/// How often the open files are checked for being older than the maximum age.
+const ROLL_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub struct S3Sink {
+    id: u32,
+    config: S3SinkConfig,
+    path_template: String,
+    time_source: TimeSource,
+    writer: Option<Arc<Writer>>,
+    state: Arc<Mutex<State>>,
+    roll_task: Option<JoinHandle<()>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S3SinkConfig {
+    pub storage: Option<Storage>,
+    pub bucket: Option<String>,
+    pub region: Option<String>,
+    pub endpoint: Option<String>,
+    pub access_key_id: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "iggy_common::serde_secret::serialize_optional_secret"
+    )]
+    pub secret_access_key: Option<SecretString>,
+    pub path: Option<String>,
+    pub prefix: Option<String>,
+    pub path_template: Option<String>,
+    pub time_source: Option<TimeSource>,
+    pub format: Option<Format>,
+    pub compression: Option<Compression>,
+    pub max_file_size: Option<String>,
+    pub max_file_messages: Option<usize>,
+    pub max_file_age: Option<String>,
+    pub include_metadata: Option<bool>,
+    pub include_headers: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Storage {
+    /// S3 or S3-compatible object storage, such as MinIO.
+    #[default]
+    S3,
+    /// Directory of the local filesystem.
+    Local,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// One JSON object per line.
+    #[default]
+    Jsonl,
+    Parquet,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeSource {
+    /// The timestamp of the message, assigned when it was appended to the Iggy partition.
+    #[default]
+    Message,
+    /// The time the message is consumed by the sink.
+    Processing,
+}
+
+/// Key of an open file, which holds the messages of a single partition under a single prefix.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct FileKey {
+    stream: String,
+    topic: String,
+    partition_id: u32,
+    prefix: String,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    files: BTreeMap<FileKey, FileBuffer>,
+    consumed_offsets: HashMap<(String, String, u32), u64>,
+    messages_written: u64,
+    files_written: u64,
+    errors_count: u64,
+}
+
+/// Rolls the open files, shared with the task rolling them once they are too old.
+#[derive(Debug)]
+struct Writer {
+    id: u32,
+    store: Arc<dyn ObjectStore>,
+    prefix: String,
+    format: Format,
+    compression: Compression,
+    fields: Fields,
+    max_file_size: u64,
+    max_file_messages: usize,
+    max_file_age: Duration,
+}
+
+impl S3Sink {
+    pub fn new(id: u32, config: S3SinkConfig) -> Self {
+        S3Sink {
+            id,
+            path_template: config
+                .path_template
+                .clone()
+                .unwrap_or_else(|| DEFAULT_PATH_TEMPLATE.to_owned()),
+            time_source: config.time_source.unwrap_or_default(),
+            config,
+            writer: None,
+            state: Arc::new(Mutex::new(State::default())),
+            roll_task: None,
+        }
+    }
+
+    fn create_store(&self) -> Result<Arc<dyn ObjectStore>, Error> {
+        match self.config.storage.unwrap_or_default() {
+            Storage::Local => {
+                let path = self.config.path.as_deref().ok_or_else(|| {
+                    Error::InvalidConfigValue("path is required for local storage".to_owned())
+                })?;
+                std::fs::create_dir_all(path).map_err(|error| {
+                    Error::InitError(format!("Failed to create directory: {path}. {error}"))
+                })?;
+                let store = LocalFileSystem::new_with_prefix(path).map_err(|error| {
+                    Error::InitError(format!("Failed to open directory: {path}. {error}"))
+                })?;
+                Ok(Arc::new(store))
+            }
+            Storage::S3 => {
+                let bucket = self.config.bucket.as_deref().ok_or_else(|| {
+                    Error::InvalidConfigValue("bucket is required for S3 storage".to_owned())
+                })?;
+                // The credentials and settings not configured are read from the AWS_* variables.
+                let mut builder = AmazonS3Builder::from_env()
+                    .with_bucket_name(bucket)
+                    .with_region(self.config.region.as_deref().unwrap_or(DEFAULT_REGION));
+                if let Some(endpoint) = self.config.endpoint.as_deref() {
+                    builder = builder
+                        .with_endpoint(endpoint)
+                        .with_allow_http(endpoint.starts_with("http://"));
+                }
+                if let Some(access_key_id) = self.config.access_key_id.as_deref() {
+                    builder = builder.with_access_key_id(access_key_id);
+                }
+                if let Some(secret_access_key) = self.config.secret_access_key.as_ref() {
+                    builder = builder.with_secret_access_key(secret_access_key.expose_secret());
+                }
+                let store = builder.build().map_err(|error| {
+                    Error::InitError(format!("Failed to create S3 client. {error}"))
+                })?;
+                Ok(Arc::new(store))
+            }
+        }
+    }
+
+    /// Renders the prefix of the file from the path template, where `{stream}`, `{topic}` and
+    /// `{partition}` describe the Iggy origin of the message, while `{date}` (`YYYY-MM-DD`),
+    /// `{year}`, `{month}`, `{day}`, `{hour}` and `{minute}` its time in UTC.
+    fn render_prefix(
+        &self,
+        topic_metadata: &TopicMetadata,
+        partition_id: u32,
+        time: DateTime<Utc>,
+    ) -> String {
+        self.path_template
+            .replace("{stream}", &topic_metadata.stream)
+            .replace("{topic}", &topic_metadata.topic)
+            .replace("{partition}", &partition_id.to_string())
+            .replace("{date}", &time.format("%Y-%m-%d").to_string())
+            .replace("{year}", &time.format("%Y").to_string())
+            .replace("{month}", &time.format("%m").to_string())
+            .replace("{day}", &time.format("%d").to_string())
+            .replace("{hour}", &time.format("%H").to_string())
+            .replace("{minute}", &time.format("%M").to_string())
+    }
+
+    fn message_time(&self, message: &ConsumedMessage) -> DateTime<Utc> {
+        match self.time_source {
+            TimeSource::Message => {
+                DateTime::from_timestamp_micros(message.timestamp as i64).unwrap_or_default()
+            }
+            TimeSource::Processing => Utc::now(),
+        }
+    }
+}
+
+impl Writer {
+    fn should_roll(&self, file: &FileBuffer) -> bool {
+        file.messages_count >= self.max_file_messages
+            || file.size >= self.max_file_size
+            || file.opened_at.elapsed() >= self.max_file_age
+    }
+
+    /// Uploads the files to roll, or all of them if forced. The files which failed to upload
+    /// are kept open and retried on the next roll, returning the last error.
+    async fn roll(&self, state: &mut State, force: bool) -> Result<(), Error> {
+        let keys = state
+            .files
+            .iter()
+            .filter(|(_, file)| force || self.should_roll(file))
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        let mut result = Ok(());
+        for key in keys {
+            let Some(file) = state.files.get(&key) else {
+                continue;
+            };
+            match self.upload(&key, file).await {
+                Ok(path) => {
+                    info!(
+                        "Uploaded file: {path} with {} messages (offsets {}-{}) by {CONNECTOR_NAME} connector with ID: {}",
+                        file.messages_count, file.first_offset, file.last_offset, self.id
+                    );
+                    state.messages_written += file.messages_count as u64;
+                    state.files_written += 1;
+                    state.files.remove(&key);
+                }
+                Err(error) => {
+                    error!(
+                        "Failed to upload file with offsets {}-{} of partition: {} by {CONNECTOR_NAME} connector with ID: {}. {error}",
+                        file.first_offset, file.last_offset, key.partition_id, self.id
+                    );
+                    state.errors_count += 1;
+                    result = Err(error);
+                }
+            }
+        }
+        result
+    }
+
+    async fn upload(&self, key: &FileKey, file: &FileBuffer) -> Result<Path, Error> {
+        let (data, extension) = file.encode(key.partition_id, self.compression, self.fields)?;
+        let name = format!("{}-{:020}.{extension}", key.partition_id, file.first_offset);
+        let path = [self.prefix.as_str(), key.prefix.trim_matches('/'), &name]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("/");
+        let path = Path::parse(&path).map_err(|error| {
+            Error::InvalidConfigValue(format!("Invalid file path: {path}. {error}"))
+        })?;
+        self.store
+            .put(&path, PutPayload::from(data))
+            .await
+            .map_err(|error| Error::Storage(format!("Failed to upload file: {path}. {error}")))?;
+        Ok(path)
+    }
+}
+
+async fn roll_files(writer: Arc<Writer>, state: Arc<Mutex<State>>) {
+    let mut interval = tokio::time::interval(ROLL_CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        let mut state = state.lock().await;
+        // The errors are logged, the files are retried on the next tick.
+        let _ = writer.roll(&mut state, false).await;
+    }
+}
+
+#[async_trait]
+impl Sink for S3Sink {
+    async fn open(&mut self) -> Result<(), Error> {
+        let max_file_size = IggyByteSize::from_str(
+            self.config
+                .max_file_size
+                .as_deref()
+                .unwrap_or(DEFAULT_MAX_FILE_SIZE),
+        )
+        .map_err(|error| Error::InvalidConfigValue(format!("Invalid max_file_size. {error}")))?
+        .as_bytes_u64();
+        let max_file_messages = self
+            .config
+            .max_file_messages
+            .unwrap_or(DEFAULT_MAX_FILE_MESSAGES)
+            .max(1);
+        let max_file_age =
+            parse_duration(self.config.max_file_age.as_deref(), DEFAULT_MAX_FILE_AGE);
+        let store = self.create_store()?;
+        let prefix = self
+            .config
+            .prefix
+            .as_deref()
+            .unwrap_or_default()
+            .trim_matches('/')
+            .to_owned();
+
+        // Verifies the bucket and the credentials before consuming any messages.
+        let list_prefix = (!prefix.is_empty()).then(|| Path::from(prefix.as_str()));
+        store
+            .list_with_delimiter(list_prefix.as_ref())
+            .await
+            .map_err(|error| Error::Connection(format!("Failed to access the storage. {error}")))?;
+
+        let writer = Arc::new(Writer {
+            id: self.id,
+            store,
+            prefix,
+            format: self.config.format.unwrap_or_default(),
+            compression: self.config.compression.unwrap_or_default(),
+            fields: Fields {
+                include_metadata: self.config.include_metadata.unwrap_or(true),
+                include_headers: self.config.include_headers.unwrap_or(true),
+            },
+            max_file_size,
+            max_file_messages,
+            max_file_age,
+        });
+        self.roll_task = Some(tokio::spawn(roll_files(writer.clone(), self.state.clone())));
+        info!(
+            "Opened {CONNECTOR_NAME} connector with ID: {} writing {:?} files ({:?} compression) to {:?} storage, rolled after {max_file_messages} messages, {} or {}",
+            self.id,
+            writer.format,
+            writer.compression,
+            self.config.storage.unwrap_or_default(),
+            IggyByteSize::from(max_file_size),
+            HumanDuration::from(max_file_age),
+        );
+        self.writer = Some(writer);
+        Ok(())
+    }
+
+    async fn consume(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: MessagesMetadata,
+        messages: Vec<ConsumedMessage>,
+    ) -> Result<(), Error> {
+        let Some(writer) = self.writer.as_ref() else {
+            return Err(Error::InitError(format!(
+                "{CONNECTOR_NAME} connector with ID: {} is not opened",
+                self.id
+            )));
+        };
+
+        let partition_id = messages_metadata.partition_id;
+        let messages_count = messages.len();
+        let mut state = self.state.lock().await;
+        let mut result = Ok(());
+        for message in messages {
+            let offset = message.offset;
+            let key = FileKey {
+                stream: topic_metadata.stream.clone(),
+                topic: topic_metadata.topic.clone(),
+                partition_id,
+                prefix: self.render_prefix(
+                    topic_metadata,
+                    partition_id,
+                    self.message_time(&message),
+                ),
+            };
+            let file = state
+                .files
+                .entry(key.clone())
+                .or_insert_with(|| FileBuffer::new(writer.format, offset));
+            let pushed = file.push(
+                &topic_metadata.stream,
+                &topic_metadata.topic,
+                partition_id,
+                message,
+                writer.fields,
+            );
+            let roll = writer.should_roll(file);
+            let empty = file.messages_count == 0;
+            // The messages which cannot be encoded are skipped, not to block the partition.
+            if let Err(error) = pushed {
+                error!(
+                    "Failed to encode message with offset: {offset} by {CONNECTOR_NAME} connector with ID: {}. {error}",
+                    self.id
+                );
+                state.errors_count += 1;
+                if empty {
+                    state.files.remove(&key);
+                }
+            }
+            state.consumed_offsets.insert(
+                (
+                    topic_metadata.stream.clone(),
+                    topic_metadata.topic.clone(),
+                    partition_id,
+                ),
+                offset,
+            );
+            // After a failed upload, the files are retried by the rolling task instead.
+            if roll && result.is_ok() {
+                result = writer.roll(&mut state, false).await;
+            }
+        }
+        debug!(
+            "Buffered {messages_count} messages from stream: {}, topic: {}, partition: {partition_id} by {CONNECTOR_NAME} connector with ID: {}",
+            topic_metadata.stream, topic_metadata.topic, self.id
+        );
+        result
+    }
+
+    fn buffers_messages(&self) -> bool {
+        true
+    }
+
+    async fn stored_offset(
+        &self,
+        topic_metadata: &TopicMetadata,
+        partition_id: u32,
+    ) -> Option<u64> {
+        let state = self.state.lock().await;
+        stored_offset(&state, topic_metadata, partition_id)
+    }
+
+    async fn close(&mut self) -> Result<(), Error> {
+        if let Some(roll_task) = self.roll_task.take() {
+            roll_task.abort();
+        }
+        let mut state = self.state.lock().await;
+        if let Some(writer) = self.writer.as_ref()
+            && let Err(error) = writer.roll(&mut state, true).await
+        {
+            error!(
+                "Failed to upload the open files on close of {CONNECTOR_NAME} connector with ID: {}. {error}",
+                self.id
+            );
+        }
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} closed. Messages written: {}, files written: {}, errors: {}",
+            self.id, state.messages_written, state.files_written, state.errors_count
+        );
+        Ok(())
+    }
+}
+
+/// Returns the offset preceding the first message of the partition still buffered in the open
+/// files, or the offset of the last message consumed from it if all of them are uploaded.
+fn stored_offset(state: &State, topic_metadata: &TopicMetadata, partition_id: u32) -> Option<u64> {
+    let first_buffered_offset = state
+        .files
+        .iter()
+        .filter(|(key, _)| {
+            key.partition_id == partition_id
+                && key.stream == topic_metadata.stream
+                && key.topic == topic_metadata.topic
+        })
+        .map(|(_, file)| file.first_offset)
+        .min();
+    match first_buffered_offset {
+        Some(offset) => offset.checked_sub(1),
+        None => state
+            .consumed_offsets
+            .get(&(
+                topic_metadata.stream.clone(),
+                topic_metadata.topic.clone(),
+                partition_id,
+            ))
+            .copied(),
+    }
+}
+
+fn parse_duration(value: Option<&str>, default: &str) -> Duration {
+    HumanDuration::from_str(value.unwrap_or(default))
+        .or_else(|_| HumanDuration::from_str(default))
+        .map(|duration| duration.into())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_connector_sdk::{Payload, Schema};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use std::io::Read;
+    use tempfile::TempDir;
+
+    fn test_config(path: &str) -> S3SinkConfig {
+        S3SinkConfig {
+            storage: Some(Storage::Local),
+            bucket: None,
+            region: None,
+            endpoint: None,
+            access_key_id: None,
+            secret_access_key: None,
+            path: Some(path.to_owned()),
+            prefix: None,
+            path_template: None,
+            time_source: None,
+            format: None,
+            compression: None,
+            max_file_size: None,
+            max_file_messages: None,
+            max_file_age: None,
+            include_metadata: None,
+            include_headers: None,
+        }
+    }
+
+    fn test_message(offset: u64) -> ConsumedMessage {
+        ConsumedMessage {
+            id: offset as u128,
+            offset,
+            checksum: 0,
+            // 2024-03-10T10:00:00Z
+            timestamp: 1_710_064_800_000_000,
+            origin_timestamp: 0,
+            headers: None,
+            payload: Payload::Json(simd_json::json!({ "offset": offset })),
+        }
+    }
+
+    fn topic_metadata() -> TopicMetadata {
+        TopicMetadata {
+            stream: "stream".to_owned(),
+            topic: "topic".to_owned(),
+        }
+    }
+
+    fn messages_metadata() -> MessagesMetadata {
+        MessagesMetadata {
+            partition_id: 1,
+            current_offset: 2,
+            schema: Schema::Json,
+        }
+    }
+
+    fn list_files(path: &std::path::Path) -> Vec<std::path::PathBuf> {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(path).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                files.extend(list_files(&path));
+            } else {
+                files.push(path);
+            }
+        }
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn given_default_template_should_render_time_based_prefix() {
+        let sink = S3Sink::new(1, test_config("unused"));
+        let time = DateTime::from_timestamp_micros(1_710_064_800_000_000).unwrap();
+
+        let prefix = sink.render_prefix(&topic_metadata(), 1, time);
+
+        assert_eq!(prefix, "stream/topic/dt=2024-03-10/hour=10");
+    }
+
+    #[tokio::test]
+    async fn given_file_rolled_by_count_should_upload_it_and_store_its_offsets() {
+        let dir = TempDir::new().unwrap();
+        let mut config = test_config(dir.path().to_str().unwrap());
+        config.max_file_messages = Some(2);
+        let mut sink = S3Sink::new(1, config);
+        sink.open().await.unwrap();
+
+        sink.consume(
+            &topic_metadata(),
+            messages_metadata(),
+            (0..3).map(test_message).collect(),
+        )
+        .await
+        .unwrap();
+
+        let files = list_files(dir.path());
+        assert_eq!(files.len(), 1);
+        assert!(
+            files[0].ends_with("stream/topic/dt=2024-03-10/hour=10/1-00000000000000000000.jsonl")
+        );
+        let lines = std::fs::read_to_string(&files[0]).unwrap();
+        let lines = lines
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["offset"], 1);
+        assert_eq!(lines[1]["partition_id"], 1);
+        assert_eq!(lines[1]["payload"]["offset"], 1);
+        // The third message is still buffered, so only the first two are stored.
+        assert_eq!(sink.stored_offset(&topic_metadata(), 1).await, Some(1));
+
+        sink.close().await.unwrap();
+        assert_eq!(list_files(dir.path()).len(), 2);
+        assert_eq!(sink.stored_offset(&topic_metadata(), 1).await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn given_no_uploaded_file_should_not_return_stored_offset() {
+        let dir = TempDir::new().unwrap();
+        let mut sink = S3Sink::new(1, test_config(dir.path().to_str().unwrap()));
+        sink.open().await.unwrap();
+
+        sink.consume(
+            &topic_metadata(),
+            messages_metadata(),
+            vec![test_message(0)],
+        )
+        .await
+        .unwrap();
+
+        assert!(list_files(dir.path()).is_empty());
+        assert_eq!(sink.stored_offset(&topic_metadata(), 1).await, None);
+    }
+
+    #[test]
+    fn given_gzip_compression_should_encode_jsonl_file() {
+        let fields = Fields {
+            include_metadata: false,
+            include_headers: false,
+        };
+        let mut file = FileBuffer::new(Format::Jsonl, 0);
+        file.push("stream", "topic", 1, test_message(0), fields)
+            .unwrap();
+
+        let (data, extension) = file.encode(1, Compression::Gzip, fields).unwrap();
+
+        let mut lines = String::new();
+        flate2::read::GzDecoder::new(data.as_slice())
+            .read_to_string(&mut lines)
+            .unwrap();
+        assert_eq!(extension, "jsonl.gz");
+        assert_eq!(lines, "{\"offset\":0}\n");
+    }
+
+    #[test]
+    fn given_parquet_format_should_encode_messages_as_rows() {
+        let fields = Fields {
+            include_metadata: true,
+            include_headers: true,
+        };
+        let mut file = FileBuffer::new(Format::Parquet, 0);
+        for offset in 0..3 {
+            file.push("stream", "topic", 1, test_message(offset), fields)
+                .unwrap();
+        }
+
+        let (data, extension) = file.encode(1, Compression::Zstd, fields).unwrap();
+
+        let batches = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(data))
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(extension, "parquet");
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 3);
+        let columns = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns,
+            [
+                "partition_id",
+                "offset",
+                "id",
+                "timestamp",
+                "origin_timestamp",
+                "headers",
+                "payload"
+            ]
+        );
+    }
+}
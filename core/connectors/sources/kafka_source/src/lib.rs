@@ -0,0 +1,514 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use humantime::Duration as HumanDuration;
+use iggy_common::{HeaderKey, HeaderValue};
+use iggy_connector_sdk::{
+    ConnectorState, Error, ProducedMessage, ProducedMessages, Schema, Source, source_connector,
+};
+use rdkafka::consumer::{Consumer, StreamConsumer};
+use rdkafka::message::{BorrowedMessage, Headers};
+use rdkafka::{ClientConfig, Message, Offset, TopicPartitionList};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::{Instant, timeout_at};
+use tracing::{debug, error, info, warn};
+
+source_connector!(KafkaSource);
+
+const CONNECTOR_NAME: &str = "Kafka source";
+const DEFAULT_BATCH_SIZE: usize = 500;
+const DEFAULT_POLL_TIMEOUT: &str = "100ms";
+const METADATA_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Header holding the key of the Kafka record.
+pub const KEY_HEADER: &str = "kafka_key";
+/// Header holding the name of the Kafka topic the record was read from.
+pub const TOPIC_HEADER: &str = "kafka_topic";
+/// Header holding the Kafka partition the record was read from.
+pub const PARTITION_HEADER: &str = "kafka_partition";
+/// Header holding the offset of the record in its Kafka partition.
+pub const OFFSET_HEADER: &str = "kafka_offset";
+/// Header holding the Kafka timestamp of the record, in milliseconds.
+pub const TIMESTAMP_HEADER: &str = "kafka_timestamp";
+
+pub struct KafkaSource {
+    id: u32,
+    config: KafkaSourceConfig,
+    consumer: Option<StreamConsumer>,
+    batch_size: usize,
+    poll_timeout: Duration,
+    include_metadata: bool,
+    state: Mutex<State>,
+}
+
+impl std::fmt::Debug for KafkaSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KafkaSource")
+            .field("id", &self.id)
+            .field("config", &self.config)
+            .field("batch_size", &self.batch_size)
+            .field("poll_timeout", &self.poll_timeout)
+            .field("include_metadata", &self.include_metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KafkaSourceConfig {
+    pub bootstrap_servers: String,
+    pub topics: Vec<String>,
+    pub group_id: Option<String>,
+    pub initial_offset: Option<InitialOffset>,
+    pub batch_size: Option<usize>,
+    pub poll_timeout: Option<String>,
+    pub payload_format: Option<Schema>,
+    pub include_metadata: Option<bool>,
+    pub properties: Option<HashMap<String, String>>,
+}
+
+/// Where to start reading the partitions without a checkpointed offset.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InitialOffset {
+    #[default]
+    Earliest,
+    Latest,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct State {
+    /// The next offset to read, per Kafka topic and partition.
+    offsets: BTreeMap<String, BTreeMap<i32, i64>>,
+    messages_produced: u64,
+}
+
+impl KafkaSource {
+    pub fn new(id: u32, config: KafkaSourceConfig, state: Option<ConnectorState>) -> Self {
+        let restored_state = state
+            .and_then(|s| s.deserialize::<State>(CONNECTOR_NAME, id))
+            .inspect(|s| {
+                info!(
+                    "Restored state for {CONNECTOR_NAME} connector with ID: {id}. \
+                     Offsets: {:?}, messages produced: {}",
+                    s.offsets, s.messages_produced
+                );
+            });
+
+        let poll_timeout = config
+            .poll_timeout
+            .as_deref()
+            .unwrap_or(DEFAULT_POLL_TIMEOUT);
+        let poll_timeout = HumanDuration::from_str(poll_timeout)
+            .map(|duration| duration.into())
+            .unwrap_or_else(|_| Duration::from_millis(100));
+
+        KafkaSource {
+            id,
+            batch_size: config.batch_size.unwrap_or(DEFAULT_BATCH_SIZE).max(1),
+            poll_timeout,
+            include_metadata: config.include_metadata.unwrap_or(true),
+            config,
+            consumer: None,
+            state: Mutex::new(restored_state.unwrap_or_default()),
+        }
+    }
+
+    fn serialize_state(&self, state: &State) -> Option<ConnectorState> {
+        ConnectorState::serialize(state, CONNECTOR_NAME, self.id)
+    }
+
+    fn client_config(&self) -> ClientConfig {
+        let group_id = self
+            .config
+            .group_id
+            .clone()
+            .unwrap_or_else(|| format!("iggy-connector-kafka-source-{}", self.id));
+        let mut client_config = ClientConfig::new();
+        client_config
+            .set("bootstrap.servers", &self.config.bootstrap_servers)
+            .set("group.id", group_id)
+            .set("enable.auto.commit", "false")
+            .set("enable.auto.offset.store", "false");
+        if let Some(properties) = &self.config.properties {
+            for (key, value) in properties {
+                client_config.set(key, value);
+            }
+        }
+        client_config
+    }
+
+    /// Builds the assignment of all the partitions of the configured topics, starting from
+    /// the checkpointed offsets, or from the initial offset for the partitions never read.
+    fn build_assignment(
+        &self,
+        consumer: &StreamConsumer,
+        state: &State,
+    ) -> Result<TopicPartitionList, Error> {
+        let mut assignment = TopicPartitionList::new();
+        for topic in &self.config.topics {
+            let metadata = consumer
+                .fetch_metadata(Some(topic), METADATA_TIMEOUT)
+                .map_err(|error| {
+                    Error::InitError(format!(
+                        "Failed to fetch metadata of Kafka topic: {topic}. {error}"
+                    ))
+                })?;
+            let Some(topic_metadata) = metadata.topics().iter().find(|t| t.name() == topic) else {
+                return Err(Error::InitError(format!(
+                    "Kafka topic: {topic} was not found"
+                )));
+            };
+            if let Some(error) = topic_metadata.error() {
+                return Err(Error::InitError(format!(
+                    "Kafka topic: {topic} is not available. {error:?}"
+                )));
+            }
+
+            for partition in topic_metadata.partitions() {
+                let offset = self.starting_offset(state, topic, partition.id());
+                assignment
+                    .add_partition_offset(topic, partition.id(), offset)
+                    .map_err(|error| {
+                        Error::InitError(format!(
+                            "Failed to assign Kafka topic: {topic}, partition: {}. {error}",
+                            partition.id()
+                        ))
+                    })?;
+            }
+        }
+        Ok(assignment)
+    }
+
+    fn starting_offset(&self, state: &State, topic: &str, partition: i32) -> Offset {
+        match state
+            .offsets
+            .get(topic)
+            .and_then(|partitions| partitions.get(&partition))
+        {
+            Some(offset) => Offset::Offset(*offset),
+            None => match self.config.initial_offset.unwrap_or_default() {
+                InitialOffset::Earliest => Offset::Beginning,
+                InitialOffset::Latest => Offset::End,
+            },
+        }
+    }
+
+    fn map_message(&self, message: &BorrowedMessage<'_>) -> Option<ProducedMessage> {
+        let Some(payload) = message.payload().filter(|payload| !payload.is_empty()) else {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} skipped the record without payload from topic: {}, partition: {}, offset: {}",
+                self.id,
+                message.topic(),
+                message.partition(),
+                message.offset()
+            );
+            return None;
+        };
+
+        let kafka_headers = message.headers().map(|headers| {
+            headers
+                .iter()
+                .filter_map(|header| header.value.map(|value| (header.key, value)))
+                .collect::<Vec<_>>()
+        });
+        let timestamp = message.timestamp().to_millis();
+        let metadata = self.include_metadata.then_some(RecordMetadata {
+            topic: message.topic(),
+            partition: message.partition(),
+            offset: message.offset(),
+            timestamp,
+        });
+        let headers = build_headers(
+            self.id,
+            message.key(),
+            kafka_headers.unwrap_or_default(),
+            metadata,
+        );
+
+        Some(ProducedMessage {
+            id: None,
+            checksum: None,
+            timestamp: None,
+            origin_timestamp: timestamp
+                .filter(|timestamp| *timestamp > 0)
+                .map(|timestamp| timestamp as u64 * 1000),
+            headers: (!headers.is_empty()).then_some(headers),
+            payload: payload.to_vec(),
+        })
+    }
+}
+
+#[async_trait]
+impl Source for KafkaSource {
+    async fn open(&mut self) -> Result<(), Error> {
+        info!(
+            "Opening {CONNECTOR_NAME} connector with ID: {}. Bootstrap servers: {}, topics: {:?}",
+            self.id, self.config.bootstrap_servers, self.config.topics
+        );
+        if self.config.topics.is_empty() {
+            return Err(Error::InvalidConfigValue(
+                "At least one Kafka topic must be configured".to_owned(),
+            ));
+        }
+
+        let consumer: StreamConsumer = self.client_config().create().map_err(|error| {
+            Error::InitError(format!("Failed to create Kafka consumer: {error}"))
+        })?;
+        let state = self.state.lock().await;
+        let assignment = self.build_assignment(&consumer, &state)?;
+        drop(state);
+        consumer.assign(&assignment).map_err(|error| {
+            Error::InitError(format!("Failed to assign Kafka partitions: {error}"))
+        })?;
+
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} opened with {} assigned partitions",
+            self.id,
+            assignment.count()
+        );
+        self.consumer = Some(consumer);
+        Ok(())
+    }
+
+    async fn poll(&self) -> Result<ProducedMessages, Error> {
+        let Some(consumer) = self.consumer.as_ref() else {
+            error!(
+                "{CONNECTOR_NAME} connector with ID: {} is not opened",
+                self.id
+            );
+            return Err(Error::InvalidState);
+        };
+
+        let mut messages = Vec::new();
+        let mut offsets = Vec::new();
+        let deadline = Instant::now() + self.poll_timeout;
+        while offsets.len() < self.batch_size {
+            let message = match timeout_at(deadline, consumer.recv()).await {
+                Err(_) => break,
+                Ok(Ok(message)) => message,
+                Ok(Err(error)) if offsets.is_empty() => {
+                    error!(
+                        "{CONNECTOR_NAME} connector with ID: {} failed to read from Kafka. {error}",
+                        self.id
+                    );
+                    return Err(Error::Connection(error.to_string()));
+                }
+                Ok(Err(error)) => {
+                    // Hand over the records read so far, the next poll will report the error.
+                    warn!(
+                        "{CONNECTOR_NAME} connector with ID: {} failed to read from Kafka. {error}",
+                        self.id
+                    );
+                    break;
+                }
+            };
+
+            offsets.push((
+                message.topic().to_owned(),
+                message.partition(),
+                message.offset(),
+            ));
+            if let Some(message) = self.map_message(&message) {
+                messages.push(message);
+            }
+        }
+
+        let mut state = self.state.lock().await;
+        for (topic, partition, offset) in offsets {
+            state
+                .offsets
+                .entry(topic)
+                .or_default()
+                .insert(partition, offset + 1);
+        }
+        state.messages_produced += messages.len() as u64;
+        debug!(
+            "{CONNECTOR_NAME} connector with ID: {} produced {} messages. Total produced: {}",
+            self.id,
+            messages.len(),
+            state.messages_produced
+        );
+
+        Ok(ProducedMessages {
+            schema: self.config.payload_format.unwrap_or(Schema::Raw),
+            messages,
+            state: self.serialize_state(&state),
+        })
+    }
+
+    async fn close(&mut self) -> Result<(), Error> {
+        if let Some(consumer) = self.consumer.take() {
+            consumer.unassign().ok();
+        }
+        let state = self.state.lock().await;
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} closed. Total messages produced: {}",
+            self.id, state.messages_produced
+        );
+        Ok(())
+    }
+}
+
+struct RecordMetadata<'a> {
+    topic: &'a str,
+    partition: i32,
+    offset: i64,
+    timestamp: Option<i64>,
+}
+
+/// Maps the key, the headers and optionally the metadata of a Kafka record to the message headers.
+/// The entries which cannot be represented as a header (e.g. longer than 255 bytes) are skipped.
+fn build_headers(
+    id: u32,
+    key: Option<&[u8]>,
+    kafka_headers: Vec<(&str, &[u8])>,
+    metadata: Option<RecordMetadata<'_>>,
+) -> BTreeMap<HeaderKey, HeaderValue> {
+    let mut headers = BTreeMap::new();
+    for (name, value) in key
+        .map(|key| (KEY_HEADER, key))
+        .into_iter()
+        .chain(kafka_headers)
+    {
+        match (HeaderKey::try_from(name), HeaderValue::try_from(value)) {
+            (Ok(name), Ok(value)) => {
+                headers.insert(name, value);
+            }
+            _ => warn!(
+                "{CONNECTOR_NAME} connector with ID: {id} skipped the Kafka header: {name} with value of {} bytes",
+                value.len()
+            ),
+        }
+    }
+
+    if let Some(metadata) = metadata {
+        if let Ok(topic) = HeaderValue::try_from(metadata.topic) {
+            headers.insert(header_key(TOPIC_HEADER), topic);
+        }
+        headers.insert(header_key(PARTITION_HEADER), metadata.partition.into());
+        headers.insert(header_key(OFFSET_HEADER), metadata.offset.into());
+        if let Some(timestamp) = metadata.timestamp {
+            headers.insert(header_key(TIMESTAMP_HEADER), timestamp.into());
+        }
+    }
+    headers
+}
+
+fn header_key(name: &str) -> HeaderKey {
+    HeaderKey::try_from(name).expect("Kafka header name must be valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> KafkaSourceConfig {
+        KafkaSourceConfig {
+            bootstrap_servers: "localhost:9092".to_owned(),
+            topics: vec!["orders".to_owned()],
+            group_id: None,
+            initial_offset: Some(InitialOffset::Latest),
+            batch_size: Some(10),
+            poll_timeout: Some("50ms".to_owned()),
+            payload_format: None,
+            include_metadata: None,
+            properties: None,
+        }
+    }
+
+    #[test]
+    fn given_persisted_state_should_resume_from_checkpointed_offsets() {
+        let state = State {
+            offsets: BTreeMap::from([("orders".to_owned(), BTreeMap::from([(1, 42)]))]),
+            messages_produced: 42,
+        };
+        let connector_state = ConnectorState(rmp_serde::to_vec(&state).unwrap());
+        let source = KafkaSource::new(1, test_config(), Some(connector_state));
+
+        let state = source.state.blocking_lock();
+        assert_eq!(state.messages_produced, 42);
+        assert_eq!(
+            source.starting_offset(&state, "orders", 1),
+            Offset::Offset(42)
+        );
+        assert_eq!(source.starting_offset(&state, "orders", 0), Offset::End);
+        assert_eq!(source.starting_offset(&state, "payments", 1), Offset::End);
+    }
+
+    #[test]
+    fn given_invalid_state_should_start_from_initial_offset() {
+        let mut config = test_config();
+        config.initial_offset = None;
+        let source = KafkaSource::new(1, config, Some(ConnectorState(b"invalid".to_vec())));
+
+        let state = source.state.blocking_lock();
+        assert_eq!(state.messages_produced, 0);
+        assert_eq!(
+            source.starting_offset(&state, "orders", 0),
+            Offset::Beginning
+        );
+    }
+
+    #[test]
+    fn should_map_key_headers_and_metadata_to_message_headers() {
+        let headers = build_headers(
+            1,
+            Some(b"order-1"),
+            vec![("trace_id", b"abc".as_slice())],
+            Some(RecordMetadata {
+                topic: "orders",
+                partition: 2,
+                offset: 7,
+                timestamp: Some(1_700_000_000_000),
+            }),
+        );
+
+        assert_eq!(
+            headers[&header_key(KEY_HEADER)].as_raw().unwrap(),
+            b"order-1"
+        );
+        assert_eq!(headers[&header_key("trace_id")].as_raw().unwrap(), b"abc");
+        assert_eq!(
+            headers[&header_key(TOPIC_HEADER)].as_str().unwrap(),
+            "orders"
+        );
+        assert_eq!(
+            headers[&header_key(PARTITION_HEADER)].as_int32().unwrap(),
+            2
+        );
+        assert_eq!(headers[&header_key(OFFSET_HEADER)].as_int64().unwrap(), 7);
+        assert_eq!(
+            headers[&header_key(TIMESTAMP_HEADER)].as_int64().unwrap(),
+            1_700_000_000_000
+        );
+    }
+
+    #[test]
+    fn should_skip_headers_which_cannot_be_represented() {
+        let long_key = vec![1u8; 256];
+        let headers = build_headers(1, Some(&long_key), vec![("empty", b"".as_slice())], None);
+
+        assert!(headers.is_empty());
+    }
+}
@@ -0,0 +1,758 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use humantime::Duration as HumanDuration;
+use iggy_common::{HeaderKey, HeaderValue};
+use iggy_connector_sdk::{
+    ConnectorState, Error, MessageRoute, ProducedMessage, ProducedMessages, Schema, Source,
+    source_connector,
+};
+use rumqttc::v5::mqttbytes::v5::{ConnAck, Filter, Packet, Publish, SubscribeReasonCode};
+use rumqttc::v5::mqttbytes::{QoS, qos};
+use rumqttc::v5::{AsyncClient, Event, EventLoop, MqttOptions};
+use rumqttc::{Outgoing, Transport};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::{Mutex, mpsc};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, sleep, timeout, timeout_at};
+use tracing::{debug, error, info, warn};
+
+source_connector!(MqttSource);
+
+const CONNECTOR_NAME: &str = "MQTT source";
+const DEFAULT_PORT: u16 = 1883;
+const DEFAULT_QOS: u8 = 1;
+const DEFAULT_BATCH_SIZE: usize = 100;
+const DEFAULT_POLL_TIMEOUT: &str = "100ms";
+const DEFAULT_KEEP_ALIVE: &str = "30s";
+const DEFAULT_SESSION_EXPIRY: &str = "1h";
+const CHANNEL_CAPACITY: usize = 1000;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Header holding the MQTT topic the message was published to.
+pub const TOPIC_HEADER: &str = "mqtt_topic";
+/// Header holding the QoS the message was received with.
+pub const QOS_HEADER: &str = "mqtt_qos";
+/// Header holding the retain flag of the message.
+pub const RETAIN_HEADER: &str = "mqtt_retain";
+/// Header holding the content type property of the message.
+pub const CONTENT_TYPE_HEADER: &str = "mqtt_content_type";
+/// Header holding the response topic property of the message.
+pub const RESPONSE_TOPIC_HEADER: &str = "mqtt_response_topic";
+/// Header holding the correlation data property of the message.
+pub const CORRELATION_DATA_HEADER: &str = "mqtt_correlation_data";
+
+#[derive(Debug)]
+pub struct MqttSource {
+    id: u32,
+    config: MqttSourceConfig,
+    batch_size: usize,
+    poll_timeout: Duration,
+    include_metadata: bool,
+    client: Option<AsyncClient>,
+    event_loop: Option<JoinHandle<()>>,
+    receiver: Mutex<Option<mpsc::Receiver<Publish>>>,
+    /// The batches awaiting the acknowledgement.
+    pending: Mutex<VecDeque<PendingBatch>>,
+    /// The messages which could not be delivered, produced again by the next poll.
+    redelivered: Mutex<VecDeque<Publish>>,
+    messages_produced: AtomicU64,
+}
+
+/// The MQTT messages of a polled batch, split into the ones mapped to the produced messages
+/// (in the same order) and the skipped ones.
+#[derive(Debug, Default)]
+struct PendingBatch {
+    produced: Vec<Publish>,
+    skipped: Vec<Publish>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MqttSourceConfig {
+    pub host: String,
+    pub port: Option<u16>,
+    pub client_id: Option<String>,
+    pub username: Option<String>,
+    #[serde(serialize_with = "iggy_common::serde_secret::serialize_optional_secret")]
+    pub password: Option<SecretString>,
+    pub tls: Option<bool>,
+    pub topics: Vec<String>,
+    pub qos: Option<u8>,
+    pub clean_start: Option<bool>,
+    pub session_expiry: Option<String>,
+    pub keep_alive: Option<String>,
+    pub batch_size: Option<usize>,
+    pub poll_timeout: Option<String>,
+    pub payload_format: Option<Schema>,
+    pub include_metadata: Option<bool>,
+    pub routes: Option<Vec<RouteConfig>>,
+}
+
+/// Routes the messages published to the topics matching the filter. The stream, topic and
+/// partition key are templates, where `{topic}` is replaced with the MQTT topic and `{N}`
+/// with its N-th level, counting from 0.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteConfig {
+    pub filter: String,
+    pub stream: Option<String>,
+    pub topic: Option<String>,
+    pub partition_key: Option<String>,
+}
+
+impl MqttSource {
+    pub fn new(id: u32, config: MqttSourceConfig, _state: Option<ConnectorState>) -> Self {
+        let poll_timeout = parse_duration(config.poll_timeout.as_deref(), DEFAULT_POLL_TIMEOUT);
+        MqttSource {
+            id,
+            batch_size: config.batch_size.unwrap_or(DEFAULT_BATCH_SIZE).max(1),
+            poll_timeout,
+            include_metadata: config.include_metadata.unwrap_or(true),
+            config,
+            client: None,
+            event_loop: None,
+            receiver: Mutex::new(None),
+            pending: Mutex::new(VecDeque::new()),
+            redelivered: Mutex::new(VecDeque::new()),
+            messages_produced: AtomicU64::new(0),
+        }
+    }
+
+    fn mqtt_options(&self) -> MqttOptions {
+        let client_id = self
+            .config
+            .client_id
+            .clone()
+            .unwrap_or_else(|| format!("iggy-mqtt-source-{}", self.id));
+        let session_expiry = parse_duration(
+            self.config.session_expiry.as_deref(),
+            DEFAULT_SESSION_EXPIRY,
+        );
+        let mut options = MqttOptions::new(
+            client_id,
+            &self.config.host,
+            self.config.port.unwrap_or(DEFAULT_PORT),
+        );
+        options
+            .set_manual_acks(true)
+            .set_clean_start(self.config.clean_start.unwrap_or(false))
+            .set_session_expiry_interval(Some(session_expiry.as_secs().min(u32::MAX as u64) as u32))
+            .set_keep_alive(parse_duration(
+                self.config.keep_alive.as_deref(),
+                DEFAULT_KEEP_ALIVE,
+            ));
+        if let Some(username) = &self.config.username {
+            let password = self
+                .config
+                .password
+                .as_ref()
+                .map(|password| password.expose_secret().to_owned())
+                .unwrap_or_default();
+            options.set_credentials(username, password);
+        }
+        if self.config.tls.unwrap_or(false) {
+            // The TLS configuration requires a process-wide crypto provider, which may be
+            // already installed by another connector.
+            let _ = rustls::crypto::ring::default_provider().install_default();
+            options.set_transport(Transport::tls_with_default_config());
+        }
+        options
+    }
+
+    fn map_message(&self, publish: &Publish) -> Option<ProducedMessage> {
+        let Ok(topic) = std::str::from_utf8(&publish.topic) else {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} skipped the message with invalid topic name",
+                self.id
+            );
+            return None;
+        };
+        if publish.payload.is_empty() {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} skipped the message without payload from topic: {topic}",
+                self.id
+            );
+            return None;
+        }
+
+        let headers = build_headers(self.id, topic, publish, self.include_metadata);
+        let mut message = ProducedMessage {
+            id: None,
+            checksum: None,
+            timestamp: None,
+            origin_timestamp: None,
+            headers: (!headers.is_empty()).then_some(headers),
+            payload: publish.payload.to_vec(),
+        };
+        if let Some(route) = resolve_route(self.config.routes.as_deref().unwrap_or_default(), topic)
+            && let Err(error) = route.apply(&mut message)
+        {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} skipped the message with invalid route from topic: {topic}. {error}",
+                self.id
+            );
+            return None;
+        }
+        Some(message)
+    }
+}
+
+#[async_trait]
+impl Source for MqttSource {
+    async fn open(&mut self) -> Result<(), Error> {
+        info!(
+            "Opening {CONNECTOR_NAME} connector with ID: {}. Broker: {}:{}, topics: {:?}",
+            self.id,
+            self.config.host,
+            self.config.port.unwrap_or(DEFAULT_PORT),
+            self.config.topics
+        );
+        if self.config.topics.is_empty() {
+            return Err(Error::InvalidConfigValue(
+                "At least one MQTT topic filter must be configured".to_owned(),
+            ));
+        }
+        let qos_value = self.config.qos.unwrap_or(DEFAULT_QOS);
+        let Some(qos) = qos(qos_value) else {
+            return Err(Error::InvalidConfigValue(format!(
+                "Invalid MQTT QoS: {qos_value}, expected 0, 1 or 2"
+            )));
+        };
+
+        let (client, mut event_loop) = AsyncClient::new(self.mqtt_options(), CHANNEL_CAPACITY);
+        let connack = timeout(CONNECT_TIMEOUT, wait_for_connack(&mut event_loop))
+            .await
+            .map_err(|_| {
+                Error::Connection(format!(
+                    "Timed out connecting to the MQTT broker: {}",
+                    self.config.host
+                ))
+            })??;
+
+        let filters: Vec<Filter> = self
+            .config
+            .topics
+            .iter()
+            .map(|topic| Filter::new(topic, qos))
+            .collect();
+        if !connack.session_present {
+            client
+                .subscribe_many(filters.clone())
+                .await
+                .map_err(|error| {
+                    Error::InitError(format!("Failed to subscribe to MQTT topics: {error}"))
+                })?;
+        }
+
+        let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+        self.event_loop = Some(tokio::spawn(run_event_loop(
+            self.id,
+            event_loop,
+            client.clone(),
+            filters,
+            sender,
+        )));
+        *self.receiver.lock().await = Some(receiver);
+        self.client = Some(client);
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} opened, session present: {}",
+            self.id, connack.session_present
+        );
+        Ok(())
+    }
+
+    async fn poll(&self) -> Result<ProducedMessages, Error> {
+        let mut receiver = self.receiver.lock().await;
+        let Some(receiver) = receiver.as_mut() else {
+            error!(
+                "{CONNECTOR_NAME} connector with ID: {} is not opened",
+                self.id
+            );
+            return Err(Error::InvalidState);
+        };
+
+        let mut publishes: Vec<Publish> = {
+            let mut redelivered = self.redelivered.lock().await;
+            let count = redelivered.len().min(self.batch_size);
+            redelivered.drain(..count).collect()
+        };
+        let deadline = Instant::now() + self.poll_timeout;
+        while publishes.len() < self.batch_size {
+            match timeout_at(deadline, receiver.recv()).await {
+                Err(_) => break,
+                Ok(Some(publish)) => publishes.push(publish),
+                Ok(None) if publishes.is_empty() => {
+                    error!(
+                        "{CONNECTOR_NAME} connector with ID: {} lost the MQTT event loop",
+                        self.id
+                    );
+                    return Err(Error::Connection("MQTT event loop has stopped".to_owned()));
+                }
+                Ok(None) => break,
+            }
+        }
+
+        let mut messages = Vec::with_capacity(publishes.len());
+        let mut batch = PendingBatch::default();
+        for publish in publishes {
+            match self.map_message(&publish) {
+                Some(message) => {
+                    messages.push(message);
+                    batch.produced.push(publish);
+                }
+                None => batch.skipped.push(publish),
+            }
+        }
+        // Every batch is acknowledged by the runtime, including the empty ones.
+        self.pending.lock().await.push_back(batch);
+
+        let produced = self
+            .messages_produced
+            .fetch_add(messages.len() as u64, Ordering::Relaxed)
+            + messages.len() as u64;
+        debug!(
+            "{CONNECTOR_NAME} connector with ID: {} produced {} messages. Total produced: {produced}",
+            self.id,
+            messages.len()
+        );
+
+        Ok(ProducedMessages {
+            schema: self.config.payload_format.unwrap_or(Schema::Raw),
+            messages,
+            state: None,
+        })
+    }
+
+    async fn acknowledge(&self, delivered: usize) -> Result<(), Error> {
+        let Some(mut batch) = self.pending.lock().await.pop_front() else {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} received an acknowledgement without pending messages",
+                self.id
+            );
+            return Ok(());
+        };
+
+        // Only the messages received with QoS 1 or 2 are acknowledged or produced again.
+        let undelivered: Vec<Publish> = batch
+            .produced
+            .split_off(delivered.min(batch.produced.len()))
+            .into_iter()
+            .filter(|publish| publish.qos != QoS::AtMostOnce)
+            .collect();
+        if !undelivered.is_empty() {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} failed to deliver {} messages, they will be produced again",
+                self.id,
+                undelivered.len()
+            );
+            self.redelivered.lock().await.extend(undelivered);
+        }
+
+        let publishes: Vec<Publish> = batch
+            .skipped
+            .into_iter()
+            .chain(batch.produced)
+            .filter(|publish| publish.qos != QoS::AtMostOnce)
+            .collect();
+        if publishes.is_empty() {
+            return Ok(());
+        }
+
+        let Some(client) = self.client.as_ref() else {
+            return Err(Error::InvalidState);
+        };
+        for publish in &publishes {
+            client.ack(publish).await.map_err(|error| {
+                Error::Connection(format!("Failed to acknowledge MQTT message: {error}"))
+            })?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<(), Error> {
+        if let Some(client) = self.client.take()
+            && let Err(error) = client.disconnect().await
+        {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} failed to disconnect from the MQTT broker. {error}",
+                self.id
+            );
+        }
+        if let Some(mut event_loop) = self.event_loop.take()
+            && timeout(CLOSE_TIMEOUT, &mut event_loop).await.is_err()
+        {
+            event_loop.abort();
+        }
+        self.receiver.lock().await.take();
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} closed. Total messages produced: {}",
+            self.id,
+            self.messages_produced.load(Ordering::Relaxed)
+        );
+        Ok(())
+    }
+}
+
+async fn wait_for_connack(event_loop: &mut EventLoop) -> Result<ConnAck, Error> {
+    loop {
+        match event_loop.poll().await {
+            Ok(Event::Incoming(Packet::ConnAck(connack))) => return Ok(connack),
+            Ok(_) => {}
+            Err(error) => {
+                return Err(Error::Connection(format!(
+                    "Failed to connect to the MQTT broker: {error}"
+                )));
+            }
+        }
+    }
+}
+
+/// Drives the MQTT connection, forwarding the received messages and subscribing again
+/// whenever the broker has not kept the session after reconnecting.
+async fn run_event_loop(
+    id: u32,
+    mut event_loop: EventLoop,
+    client: AsyncClient,
+    filters: Vec<Filter>,
+    sender: mpsc::Sender<Publish>,
+) {
+    loop {
+        match event_loop.poll().await {
+            Ok(Event::Incoming(Packet::Publish(publish))) => {
+                if sender.send(publish).await.is_err() {
+                    break;
+                }
+            }
+            Ok(Event::Incoming(Packet::ConnAck(connack))) => {
+                info!(
+                    "{CONNECTOR_NAME} connector with ID: {id} reconnected to the MQTT broker, session present: {}",
+                    connack.session_present
+                );
+                if !connack.session_present
+                    && let Err(error) = client.try_subscribe_many(filters.clone())
+                {
+                    error!(
+                        "{CONNECTOR_NAME} connector with ID: {id} failed to subscribe to MQTT topics. {error}"
+                    );
+                }
+            }
+            Ok(Event::Incoming(Packet::SubAck(suback))) => {
+                for (filter, code) in filters.iter().zip(&suback.return_codes) {
+                    if !matches!(code, SubscribeReasonCode::Success(_)) {
+                        error!(
+                            "{CONNECTOR_NAME} connector with ID: {id} failed to subscribe to MQTT topic: {}. {code:?}",
+                            filter.path
+                        );
+                    }
+                }
+            }
+            Ok(Event::Outgoing(Outgoing::Disconnect)) => break,
+            Ok(_) => {}
+            Err(error) => {
+                warn!(
+                    "{CONNECTOR_NAME} connector with ID: {id} lost the connection to the MQTT broker, reconnecting. {error}"
+                );
+                sleep(RECONNECT_DELAY).await;
+            }
+        }
+    }
+}
+
+fn parse_duration(value: Option<&str>, default: &str) -> Duration {
+    HumanDuration::from_str(value.unwrap_or(default))
+        .or_else(|_| HumanDuration::from_str(default))
+        .map(|duration| duration.into())
+        .unwrap_or_default()
+}
+
+/// Maps the metadata and the properties of an MQTT message to the message headers.
+/// The entries which cannot be represented as a header (e.g. longer than 255 bytes) are skipped.
+fn build_headers(
+    id: u32,
+    topic: &str,
+    publish: &Publish,
+    include_metadata: bool,
+) -> BTreeMap<HeaderKey, HeaderValue> {
+    let mut headers = BTreeMap::new();
+    if include_metadata {
+        insert_header(id, &mut headers, TOPIC_HEADER, HeaderValue::try_from(topic));
+        headers.insert(header_key(QOS_HEADER), (publish.qos as u8).into());
+        headers.insert(header_key(RETAIN_HEADER), publish.retain.into());
+    }
+
+    let Some(properties) = &publish.properties else {
+        return headers;
+    };
+    for (name, value) in &properties.user_properties {
+        insert_header(
+            id,
+            &mut headers,
+            name,
+            HeaderValue::try_from(value.as_str()),
+        );
+    }
+    if let Some(content_type) = &properties.content_type {
+        insert_header(
+            id,
+            &mut headers,
+            CONTENT_TYPE_HEADER,
+            HeaderValue::try_from(content_type.as_str()),
+        );
+    }
+    if let Some(response_topic) = &properties.response_topic {
+        insert_header(
+            id,
+            &mut headers,
+            RESPONSE_TOPIC_HEADER,
+            HeaderValue::try_from(response_topic.as_str()),
+        );
+    }
+    if let Some(correlation_data) = &properties.correlation_data {
+        insert_header(
+            id,
+            &mut headers,
+            CORRELATION_DATA_HEADER,
+            HeaderValue::try_from(correlation_data.as_ref()),
+        );
+    }
+    headers
+}
+
+fn insert_header<E>(
+    id: u32,
+    headers: &mut BTreeMap<HeaderKey, HeaderValue>,
+    name: &str,
+    value: Result<HeaderValue, E>,
+) {
+    match (HeaderKey::try_from(name), value) {
+        (Ok(name), Ok(value)) => {
+            headers.insert(name, value);
+        }
+        _ => warn!("{CONNECTOR_NAME} connector with ID: {id} skipped the MQTT property: {name}"),
+    }
+}
+
+fn header_key(name: &str) -> HeaderKey {
+    HeaderKey::try_from(name).expect("MQTT header name must be valid")
+}
+
+/// Resolves the route of the first route config whose filter matches the MQTT topic.
+fn resolve_route(routes: &[RouteConfig], topic: &str) -> Option<MessageRoute> {
+    let route = routes
+        .iter()
+        .find(|route| matches_filter(&route.filter, topic))?;
+    let levels: Vec<&str> = topic.split('/').collect();
+    let render = |template: &Option<String>| {
+        template
+            .as_deref()
+            .map(|template| render_template(template, topic, &levels))
+            .filter(|value| !value.is_empty())
+    };
+    Some(MessageRoute {
+        stream: render(&route.stream),
+        topic: render(&route.topic),
+        partition_key: render(&route.partition_key).map(String::into_bytes),
+    })
+}
+
+/// Replaces `{topic}` with the MQTT topic and `{N}` with its N-th level. The missing levels
+/// are replaced with an empty string, while the other placeholders are kept as they are.
+fn render_template(template: &str, topic: &str, levels: &[&str]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let placeholder = &rest[start + 1..start + end];
+        if placeholder == "topic" {
+            rendered.push_str(topic);
+        } else if let Ok(level) = placeholder.parse::<usize>() {
+            rendered.push_str(levels.get(level).copied().unwrap_or_default());
+        } else {
+            rendered.push_str(&rest[start..=start + end]);
+        }
+        rest = &rest[start + end + 1..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// Checks if the MQTT topic matches the filter, supporting the `+` and `#` wildcards
+/// and the shared subscriptions (`$share/<group>/<filter>`).
+fn matches_filter(filter: &str, topic: &str) -> bool {
+    let filter = filter
+        .strip_prefix("$share/")
+        .and_then(|shared| shared.split_once('/'))
+        .map_or(filter, |(_, filter)| filter);
+    // The wildcards at the first level do not match the topics starting with `$`.
+    if topic.starts_with('$') && (filter.starts_with('+') || filter.starts_with('#')) {
+        return false;
+    }
+
+    let mut topic_levels = topic.split('/');
+    for level in filter.split('/') {
+        match level {
+            "#" => return true,
+            "+" => {
+                if topic_levels.next().is_none() {
+                    return false;
+                }
+            }
+            level => {
+                if topic_levels.next() != Some(level) {
+                    return false;
+                }
+            }
+        }
+    }
+    topic_levels.next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumqttc::v5::mqttbytes::v5::PublishProperties;
+
+    fn route(
+        filter: &str,
+        stream: Option<&str>,
+        topic: Option<&str>,
+        key: Option<&str>,
+    ) -> RouteConfig {
+        RouteConfig {
+            filter: filter.to_owned(),
+            stream: stream.map(str::to_owned),
+            topic: topic.map(str::to_owned),
+            partition_key: key.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn should_match_topic_filters_with_wildcards() {
+        assert!(matches_filter("sensors/temperature", "sensors/temperature"));
+        assert!(matches_filter(
+            "sensors/+/temperature",
+            "sensors/1/temperature"
+        ));
+        assert!(matches_filter("sensors/#", "sensors"));
+        assert!(matches_filter("sensors/#", "sensors/1/temperature"));
+        assert!(matches_filter("#", "sensors/1"));
+        assert!(matches_filter("$share/group/sensors/+", "sensors/1"));
+        assert!(!matches_filter("sensors/+", "sensors/1/temperature"));
+        assert!(!matches_filter(
+            "sensors/+/temperature",
+            "sensors/temperature"
+        ));
+        assert!(!matches_filter("sensors/temperature", "sensors/humidity"));
+        assert!(!matches_filter("#", "$SYS/uptime"));
+        assert!(matches_filter("$SYS/#", "$SYS/uptime"));
+    }
+
+    #[test]
+    fn should_resolve_route_of_first_matching_filter() {
+        let routes = vec![
+            route("alerts/#", Some("alerts"), None, None),
+            route("sensors/+/+", Some("sensors"), Some("{2}"), Some("{1}")),
+            route("sensors/#", None, Some("other"), Some("{topic}")),
+        ];
+
+        assert_eq!(
+            resolve_route(&routes, "sensors/device-1/temperature"),
+            Some(MessageRoute {
+                stream: Some("sensors".to_owned()),
+                topic: Some("temperature".to_owned()),
+                partition_key: Some(b"device-1".to_vec()),
+            })
+        );
+        assert_eq!(
+            resolve_route(&routes, "sensors/device-1"),
+            Some(MessageRoute {
+                stream: None,
+                topic: Some("other".to_owned()),
+                partition_key: Some(b"sensors/device-1".to_vec()),
+            })
+        );
+        assert_eq!(resolve_route(&routes, "commands/device-1"), None);
+    }
+
+    #[test]
+    fn should_render_templates_with_topic_levels() {
+        let levels = ["a", "b"];
+        assert_eq!(render_template("{topic}", "a/b", &levels), "a/b");
+        assert_eq!(render_template("x-{1}-{0}", "a/b", &levels), "x-b-a");
+        assert_eq!(render_template("{5}", "a/b", &levels), "");
+        assert_eq!(render_template("{other}-{", "a/b", &levels), "{other}-{");
+    }
+
+    #[test]
+    fn should_map_metadata_and_properties_to_message_headers() {
+        let mut publish = Publish::new("sensors/1", QoS::AtLeastOnce, "21.5", None);
+        publish.retain = true;
+        publish.properties = Some(PublishProperties {
+            user_properties: vec![("unit".to_owned(), "celsius".to_owned())],
+            content_type: Some("text/plain".to_owned()),
+            response_topic: Some("replies/1".to_owned()),
+            correlation_data: Some(b"request-1".to_vec().into()),
+            ..PublishProperties::default()
+        });
+
+        let headers = build_headers(1, "sensors/1", &publish, true);
+
+        assert_eq!(
+            headers[&header_key(TOPIC_HEADER)].as_str().unwrap(),
+            "sensors/1"
+        );
+        assert_eq!(headers[&header_key(QOS_HEADER)].as_uint8().unwrap(), 1);
+        assert!(headers[&header_key(RETAIN_HEADER)].as_bool().unwrap());
+        assert_eq!(headers[&header_key("unit")].as_str().unwrap(), "celsius");
+        assert_eq!(
+            headers[&header_key(CONTENT_TYPE_HEADER)].as_str().unwrap(),
+            "text/plain"
+        );
+        assert_eq!(
+            headers[&header_key(RESPONSE_TOPIC_HEADER)]
+                .as_str()
+                .unwrap(),
+            "replies/1"
+        );
+        assert_eq!(
+            headers[&header_key(CORRELATION_DATA_HEADER)]
+                .as_raw()
+                .unwrap(),
+            b"request-1"
+        );
+    }
+
+    #[test]
+    fn should_skip_metadata_headers_when_disabled() {
+        let publish = Publish::new("sensors/1", QoS::AtMostOnce, "21.5", None);
+
+        assert!(build_headers(1, "sensors/1", &publish, false).is_empty());
+    }
+}
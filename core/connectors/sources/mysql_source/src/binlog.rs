@@ -0,0 +1,472 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::gtid::{Gtid, GtidSet};
+use crate::value::binlog_row_to_json;
+use crate::{
+    ChangeRecord, Flavor, MySqlSource, Operation, SourceMetadata, State, TableName,
+    TransactionMetadata,
+};
+use futures::StreamExt;
+use iggy_connector_sdk::{Error, ProducedMessage};
+use mysql_async::binlog::events::{Event, EventData, RowsEventData};
+use mysql_async::binlog::row::BinlogRow;
+use mysql_async::prelude::Queryable;
+use mysql_async::{BinlogStream, BinlogStreamRequest, Conn};
+use std::collections::HashMap;
+use std::fmt;
+use tokio::time::{Instant, sleep_until, timeout_at};
+use tracing::{error, info, warn};
+
+/// MariaDB specific event starting a transaction, not decoded by `mysql_async`.
+const MARIADB_GTID_EVENT: u8 = 162;
+/// Lets MariaDB send its GTID events instead of the `BEGIN` queries.
+const MARIADB_SLAVE_CAPABILITY_GTID: u8 = 4;
+
+/// The binlog stream with the transaction being read, whose changes are produced once it
+/// is committed.
+pub(crate) struct BinlogReader {
+    stream: BinlogStream,
+    file: String,
+    /// Tracked only if GTIDs are enabled and the set was checkpointed before.
+    gtid_set: Option<GtidSet>,
+    transaction: Option<PendingTransaction>,
+    /// The column names read from `information_schema` when missing in the binlog.
+    column_names: HashMap<TableName, Vec<String>>,
+}
+
+impl fmt::Debug for BinlogReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinlogReader")
+            .field("file", &self.file)
+            .field("gtid_set", &self.gtid_set)
+            .field("transaction", &self.transaction)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+struct PendingTransaction {
+    id: String,
+    gtid: Option<Gtid>,
+    records: Vec<ChangeRecord>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum QueryKind {
+    Begin,
+    Commit,
+    Rollback,
+    Savepoint,
+    /// DDL and other statements committed on their own.
+    Statement,
+}
+
+impl QueryKind {
+    fn of(query: &str) -> Self {
+        let query = query.trim_start().to_uppercase();
+        if query.starts_with("BEGIN") || query.starts_with("XA START") {
+            QueryKind::Begin
+        } else if query.starts_with("COMMIT") {
+            QueryKind::Commit
+        } else if query.starts_with("ROLLBACK TO") || query.starts_with("SAVEPOINT") {
+            QueryKind::Savepoint
+        } else if query.starts_with("ROLLBACK") {
+            QueryKind::Rollback
+        } else {
+            QueryKind::Statement
+        }
+    }
+}
+
+impl BinlogReader {
+    pub(crate) async fn close(self) {
+        if let Err(error) = self.stream.close().await {
+            warn!("Failed to close binlog stream: {error}");
+        }
+    }
+
+    fn begin(&mut self, gtid: Option<Gtid>, position: u64) {
+        let id = match &gtid {
+            Some(gtid) => gtid.to_string(),
+            None => format!("{}:{position}", self.file),
+        };
+        self.transaction = Some(PendingTransaction {
+            id,
+            gtid,
+            records: Vec::new(),
+        });
+    }
+}
+
+impl MySqlSource {
+    async fn connect_binlog(&self, state: &State) -> Result<BinlogReader, Error> {
+        let mut conn = Conn::new(self.opts()?)
+            .await
+            .map_err(|error| Error::Connection(format!("Failed to connect to MySQL: {error}")))?;
+
+        let gtid_set = match (&state.gtid_set, self.use_gtid) {
+            (Some(gtid_set), true) => {
+                Some(GtidSet::parse(self.flavor, gtid_set).map_err(|error| {
+                    error!(
+                        "Invalid GTID set in the state of connector ID: {}: {error}",
+                        self.id
+                    );
+                    Error::InvalidState
+                })?)
+            }
+            (None, true) => {
+                warn!(
+                    "GTID set was not checkpointed for connector ID: {}, \
+                     streaming by binlog position only",
+                    self.id
+                );
+                None
+            }
+            _ => None,
+        };
+        let file = state.binlog_file.clone().unwrap_or_default();
+        let resume_by_gtid = gtid_set.as_ref().is_some_and(|set| !set.is_empty());
+
+        let mut request = BinlogStreamRequest::new(self.server_id);
+        let mut setup = Vec::new();
+        if self.flavor == Flavor::MariaDb {
+            setup.push(format!(
+                "SET @mariadb_slave_capability = {MARIADB_SLAVE_CAPABILITY_GTID}"
+            ));
+        }
+        match &gtid_set {
+            Some(gtid_set) if resume_by_gtid && self.flavor == Flavor::MariaDb => {
+                setup.push(format!("SET @slave_connect_state = '{gtid_set}'"));
+                setup.push("SET @slave_gtid_strict_mode = 0".to_string());
+                setup.push("SET @slave_gtid_ignore_duplicates = 0".to_string());
+            }
+            Some(gtid_set) if resume_by_gtid => {
+                request = request.with_gtid().with_gtid_set(gtid_set.sids());
+            }
+            _ => {
+                request = request
+                    .with_filename(file.as_bytes())
+                    .with_pos(state.binlog_position.max(4));
+            }
+        }
+        for statement in setup {
+            conn.query_drop(statement).await.map_err(|error| {
+                Error::Connection(format!("Failed to prepare binlog stream: {error}"))
+            })?;
+        }
+
+        let stream = conn.get_binlog_stream(request).await.map_err(|error| {
+            Error::Connection(format!("Failed to request binlog stream: {error}"))
+        })?;
+
+        if resume_by_gtid {
+            info!(
+                "Streaming binlog for connector ID: {} after GTID set: {}",
+                self.id,
+                state.gtid_set.as_deref().unwrap_or_default()
+            );
+        } else {
+            info!(
+                "Streaming binlog for connector ID: {} from {file}:{}",
+                self.id, state.binlog_position
+            );
+        }
+
+        Ok(BinlogReader {
+            stream,
+            file,
+            gtid_set,
+            transaction: None,
+            column_names: HashMap::new(),
+        })
+    }
+
+    /// Reads the binlog until the batch is full or the poll interval elapses, producing the
+    /// changes of the committed transactions only, so that the checkpointed position is
+    /// always at a transaction boundary.
+    pub(crate) async fn poll_binlog(
+        &self,
+        state: &mut State,
+    ) -> Result<Vec<ProducedMessage>, Error> {
+        let deadline = Instant::now() + self.poll_interval;
+        let mut guard = self.reader.lock().await;
+        let reader = match guard.as_mut() {
+            Some(reader) => reader,
+            None => match self.connect_binlog(state).await {
+                Ok(reader) => guard.insert(reader),
+                Err(error) => {
+                    sleep_until(deadline).await;
+                    return Err(error);
+                }
+            },
+        };
+
+        let mut messages = Vec::new();
+        let mut failed = false;
+        while messages.len() < self.batch_size {
+            let event = match timeout_at(deadline, reader.stream.next()).await {
+                Err(_) => break,
+                Ok(Some(Ok(event))) => event,
+                Ok(Some(Err(error))) => {
+                    error!(
+                        "Failed to read binlog for connector ID: {}: {error}",
+                        self.id
+                    );
+                    failed = true;
+                    break;
+                }
+                Ok(None) => {
+                    warn!("Binlog stream closed for connector ID: {}", self.id);
+                    failed = true;
+                    break;
+                }
+            };
+            if let Err(error) = self.handle_event(reader, event, state, &mut messages).await {
+                error!("{error}, connector ID: {}", self.id);
+                failed = true;
+                break;
+            }
+        }
+
+        // Reconnecting resumes from the last committed transaction, dropping the pending one.
+        if failed {
+            if let Some(reader) = guard.take() {
+                reader.close().await;
+            }
+            sleep_until(deadline).await;
+        }
+        Ok(messages)
+    }
+
+    async fn handle_event(
+        &self,
+        reader: &mut BinlogReader,
+        event: Event,
+        state: &mut State,
+        messages: &mut Vec<ProducedMessage>,
+    ) -> Result<(), String> {
+        let header = event.header();
+        let position = u64::from(header.log_pos());
+
+        if header.event_type_raw() == MARIADB_GTID_EVENT {
+            let gtid = Gtid::mariadb(header.server_id(), event.data())
+                .ok_or_else(|| "Invalid MariaDB GTID event".to_string())?;
+            reader.begin(Some(gtid), position);
+            return Ok(());
+        }
+
+        let data = event
+            .read_data()
+            .map_err(|error| format!("Failed to decode binlog event: {error}"))?;
+        match data {
+            Some(EventData::RotateEvent(rotate)) => {
+                reader.file = rotate.name().into_owned();
+            }
+            Some(EventData::GtidEvent(gtid)) => {
+                reader.begin(Some(Gtid::mysql(gtid.sid(), gtid.gno())), position);
+            }
+            Some(EventData::AnonymousGtidEvent(_)) => reader.begin(None, position),
+            Some(EventData::QueryEvent(query)) => match QueryKind::of(&query.query()) {
+                QueryKind::Begin => {
+                    if reader.transaction.is_none() {
+                        reader.begin(None, position);
+                    }
+                }
+                QueryKind::Savepoint => {}
+                QueryKind::Commit => self.commit(reader, None, position, state, messages)?,
+                QueryKind::Rollback => {
+                    if let Some(transaction) = reader.transaction.as_mut() {
+                        transaction.records.clear();
+                    }
+                    self.commit(reader, None, position, state, messages)?;
+                }
+                QueryKind::Statement => {
+                    // The schema may have changed, so the column names are read again.
+                    reader.column_names.clear();
+                    self.commit(reader, None, position, state, messages)?;
+                }
+            },
+            Some(EventData::XidEvent(xid)) => {
+                self.commit(reader, Some(xid.xid), position, state, messages)?;
+            }
+            Some(EventData::RowsEvent(rows)) => {
+                let source = SourceMetadata {
+                    server_id: Some(header.server_id()),
+                    file: reader.file.clone(),
+                    position,
+                    gtid: None,
+                    timestamp: u64::from(header.timestamp()) * 1000,
+                    snapshot: false,
+                };
+                self.handle_rows(reader, &rows, source).await?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_rows(
+        &self,
+        reader: &mut BinlogReader,
+        rows: &RowsEventData<'_>,
+        mut source: SourceMetadata,
+    ) -> Result<(), String> {
+        let operation = match rows {
+            RowsEventData::WriteRowsEventV1(_) | RowsEventData::WriteRowsEvent(_) => {
+                Operation::Insert
+            }
+            RowsEventData::UpdateRowsEventV1(_)
+            | RowsEventData::UpdateRowsEvent(_)
+            | RowsEventData::PartialUpdateRowsEvent(_) => Operation::Update,
+            RowsEventData::DeleteRowsEventV1(_) | RowsEventData::DeleteRowsEvent(_) => {
+                Operation::Delete
+            }
+        };
+
+        let table_id = rows.table_id();
+        let table_map = reader
+            .stream
+            .get_tme(table_id)
+            .ok_or_else(|| format!("Missing table map event for table ID: {table_id}"))?;
+        let table = TableName::new(table_map.database_name(), table_map.table_name());
+        if !self.is_captured(&table) || !self.operations.contains(&operation) {
+            return Ok(());
+        }
+        let images = rows
+            .rows(table_map)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| format!("Failed to decode rows of table {table}: {error}"))?;
+
+        let column_names = self.column_names(reader, &table, &images).await?;
+        if reader.transaction.is_none() {
+            reader.begin(None, source.position);
+        }
+        let Some(transaction) = reader.transaction.as_mut() else {
+            return Ok(());
+        };
+        source.gtid = transaction.gtid.as_ref().map(|gtid| gtid.to_string());
+
+        for (before, after) in images {
+            transaction.records.push(ChangeRecord {
+                operation,
+                database: table.database.clone(),
+                table: table.table.clone(),
+                before: before.map(|row| binlog_row_to_json(row, column_names.as_deref())),
+                after: after.map(|row| binlog_row_to_json(row, column_names.as_deref())),
+                source: source.clone(),
+                transaction: None,
+            });
+        }
+        Ok(())
+    }
+
+    /// Reads the column names from `information_schema` if the binlog does not contain them,
+    /// which happens unless `binlog_row_metadata` is set to `FULL`.
+    async fn column_names(
+        &self,
+        reader: &mut BinlogReader,
+        table: &TableName,
+        images: &[(Option<BinlogRow>, Option<BinlogRow>)],
+    ) -> Result<Option<Vec<String>>, String> {
+        let unnamed = images
+            .iter()
+            .flat_map(|(before, after)| before.iter().chain(after.iter()))
+            .any(|row| {
+                row.columns_ref()
+                    .iter()
+                    .any(|column| column.name_ref().starts_with(b"@"))
+            });
+        if !unnamed {
+            return Ok(None);
+        }
+        if let Some(names) = reader.column_names.get(table) {
+            return Ok(Some(names.clone()));
+        }
+
+        let mut conn = self
+            .pool()
+            .map_err(|error| error.to_string())?
+            .get_conn()
+            .await
+            .map_err(|error| format!("Failed to connect to MySQL: {error}"))?;
+        let names: Vec<String> = conn
+            .exec(
+                "SELECT COLUMN_NAME FROM information_schema.COLUMNS \
+                 WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? ORDER BY ORDINAL_POSITION",
+                (&table.database, &table.table),
+            )
+            .await
+            .map_err(|error| format!("Failed to read columns of table {table}: {error}"))?;
+        reader.column_names.insert(table.clone(), names.clone());
+        Ok(Some(names))
+    }
+
+    /// Produces the changes of the transaction and checkpoints the position after it.
+    fn commit(
+        &self,
+        reader: &mut BinlogReader,
+        xid: Option<u64>,
+        position: u64,
+        state: &mut State,
+        messages: &mut Vec<ProducedMessage>,
+    ) -> Result<(), String> {
+        if let Some(transaction) = reader.transaction.take() {
+            let total = transaction.records.len() as u64;
+            for (sequence, mut record) in transaction.records.into_iter().enumerate() {
+                record.transaction = Some(TransactionMetadata {
+                    id: transaction.id.clone(),
+                    xid,
+                    sequence: sequence as u64,
+                    total,
+                });
+                messages.push(record.into_message().map_err(|error| error.to_string())?);
+            }
+            if let (Some(gtid), Some(gtid_set)) = (&transaction.gtid, reader.gtid_set.as_mut()) {
+                gtid_set.add(gtid);
+            }
+        }
+
+        state.binlog_file = Some(reader.file.clone());
+        state.binlog_position = position;
+        if let Some(gtid_set) = &reader.gtid_set {
+            state.gtid_set = Some(gtid_set.to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queries_should_be_classified_by_transaction_boundary() {
+        assert_eq!(QueryKind::of("BEGIN"), QueryKind::Begin);
+        assert_eq!(QueryKind::of("XA START X'01',X'',1"), QueryKind::Begin);
+        assert_eq!(QueryKind::of("COMMIT"), QueryKind::Commit);
+        assert_eq!(QueryKind::of("ROLLBACK"), QueryKind::Rollback);
+        assert_eq!(QueryKind::of("ROLLBACK TO `sp`"), QueryKind::Savepoint);
+        assert_eq!(QueryKind::of("savepoint sp"), QueryKind::Savepoint);
+        assert_eq!(
+            QueryKind::of("ALTER TABLE orders ADD COLUMN note TEXT"),
+            QueryKind::Statement
+        );
+    }
+}
@@ -0,0 +1,301 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Flavor;
+use mysql_async::{GnoInterval, Sid};
+use std::collections::BTreeMap;
+use std::fmt;
+use uuid::Uuid;
+
+/// Identifies a single transaction written to the binlog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Gtid {
+    /// MySQL GTID, formatted as `source_uuid:transaction_id`.
+    MySql { uuid: String, gno: u64 },
+    /// MariaDB GTID, formatted as `domain_id-server_id-sequence`.
+    MariaDb {
+        domain: u32,
+        server: u32,
+        sequence: u64,
+    },
+}
+
+impl Gtid {
+    pub(crate) fn mysql(sid: [u8; 16], gno: u64) -> Self {
+        Gtid::MySql {
+            uuid: Uuid::from_bytes(sid).to_string(),
+            gno,
+        }
+    }
+
+    /// Parses the post-header of the MariaDB GTID event: the 8 bytes of the sequence followed
+    /// by the 4 bytes of the domain ID, both little endian.
+    pub(crate) fn mariadb(server: u32, data: &[u8]) -> Option<Self> {
+        let sequence = u64::from_le_bytes(data.get(0..8)?.try_into().ok()?);
+        let domain = u32::from_le_bytes(data.get(8..12)?.try_into().ok()?);
+        Some(Gtid::MariaDb {
+            domain,
+            server,
+            sequence,
+        })
+    }
+}
+
+impl fmt::Display for Gtid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Gtid::MySql { uuid, gno } => write!(f, "{uuid}:{gno}"),
+            Gtid::MariaDb {
+                domain,
+                server,
+                sequence,
+            } => write!(f, "{domain}-{server}-{sequence}"),
+        }
+    }
+}
+
+/// The GTIDs of the transactions processed so far, checkpointed in the connector state to
+/// resume the replication after them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum GtidSet {
+    /// The inclusive ranges of the transaction IDs per source UUID, as in `gtid_executed`.
+    MySql(BTreeMap<String, Vec<(u64, u64)>>),
+    /// The last `(server_id, sequence)` per replication domain, as in `gtid_binlog_pos`.
+    MariaDb(BTreeMap<u32, (u32, u64)>),
+}
+
+impl GtidSet {
+    pub(crate) fn empty(flavor: Flavor) -> Self {
+        match flavor {
+            Flavor::MySql => GtidSet::MySql(BTreeMap::new()),
+            Flavor::MariaDb => GtidSet::MariaDb(BTreeMap::new()),
+        }
+    }
+
+    pub(crate) fn parse(flavor: Flavor, value: &str) -> Result<Self, String> {
+        let mut set = GtidSet::empty(flavor);
+        let entries = value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty());
+        for entry in entries {
+            match &mut set {
+                GtidSet::MySql(sources) => {
+                    let (uuid, intervals) = entry
+                        .split_once(':')
+                        .ok_or_else(|| format!("Invalid GTID set entry: '{entry}'"))?;
+                    let uuid = Uuid::parse_str(uuid)
+                        .map_err(|error| format!("Invalid GTID set entry: '{entry}': {error}"))?
+                        .to_string();
+                    let ranges = sources.entry(uuid).or_default();
+                    for interval in intervals.split(':') {
+                        let (start, end) = interval.split_once('-').unwrap_or((interval, interval));
+                        let start = parse_number(start, entry)?;
+                        let end = parse_number(end, entry)?;
+                        if start == 0 || start > end {
+                            return Err(format!("Invalid GTID interval in entry: '{entry}'"));
+                        }
+                        add_range(ranges, start, end);
+                    }
+                }
+                GtidSet::MariaDb(domains) => {
+                    let mut parts = entry.splitn(3, '-');
+                    let (Some(domain), Some(server), Some(sequence)) =
+                        (parts.next(), parts.next(), parts.next())
+                    else {
+                        return Err(format!("Invalid GTID position entry: '{entry}'"));
+                    };
+                    domains.insert(
+                        parse_number(domain, entry)?,
+                        (parse_number(server, entry)?, parse_number(sequence, entry)?),
+                    );
+                }
+            }
+        }
+        Ok(set)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        match self {
+            GtidSet::MySql(sources) => sources.is_empty(),
+            GtidSet::MariaDb(domains) => domains.is_empty(),
+        }
+    }
+
+    pub(crate) fn add(&mut self, gtid: &Gtid) {
+        match (self, gtid) {
+            (GtidSet::MySql(sources), Gtid::MySql { uuid, gno }) => {
+                add_range(sources.entry(uuid.clone()).or_default(), *gno, *gno);
+            }
+            (
+                GtidSet::MariaDb(domains),
+                Gtid::MariaDb {
+                    domain,
+                    server,
+                    sequence,
+                },
+            ) => {
+                domains.insert(*domain, (*server, *sequence));
+            }
+            _ => {}
+        }
+    }
+
+    /// The GTID set in the form expected by `COM_BINLOG_DUMP_GTID`, empty for MariaDB which
+    /// receives its position through the `@slave_connect_state` variable instead.
+    pub(crate) fn sids(&self) -> Vec<Sid<'static>> {
+        let GtidSet::MySql(sources) = self else {
+            return Vec::new();
+        };
+        sources
+            .iter()
+            .filter_map(|(uuid, ranges)| {
+                let uuid = Uuid::parse_str(uuid).ok()?;
+                let intervals = ranges
+                    .iter()
+                    .map(|(start, end)| GnoInterval::new(*start, end + 1))
+                    .collect();
+                Some(Sid::new(*uuid.as_bytes()).with_intervals(intervals))
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for GtidSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: Vec<String> = match self {
+            GtidSet::MySql(sources) => sources
+                .iter()
+                .filter(|(_, ranges)| !ranges.is_empty())
+                .map(|(uuid, ranges)| {
+                    let intervals = ranges
+                        .iter()
+                        .map(|(start, end)| {
+                            if start == end {
+                                start.to_string()
+                            } else {
+                                format!("{start}-{end}")
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(":");
+                    format!("{uuid}:{intervals}")
+                })
+                .collect(),
+            GtidSet::MariaDb(domains) => domains
+                .iter()
+                .map(|(domain, (server, sequence))| format!("{domain}-{server}-{sequence}"))
+                .collect(),
+        };
+        write!(f, "{}", entries.join(","))
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, entry: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid number '{value}' in GTID entry: '{entry}'"))
+}
+
+/// Adds the inclusive range, merging it with the overlapping and adjacent ones.
+fn add_range(ranges: &mut Vec<(u64, u64)>, start: u64, end: u64) {
+    ranges.push((start, end));
+    ranges.sort_unstable();
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for &(start, end) in ranges.iter() {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    *ranges = merged;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UUID: &str = "3e11fa47-71ca-11e1-9e33-c80aa9429562";
+
+    #[test]
+    fn mysql_gtid_set_should_merge_added_transactions() {
+        let mut set = GtidSet::parse(Flavor::MySql, &format!("{UUID}:1-5:8")).unwrap();
+        set.add(&Gtid::MySql {
+            uuid: UUID.to_string(),
+            gno: 6,
+        });
+        assert_eq!(set.to_string(), format!("{UUID}:1-6:8"));
+
+        set.add(&Gtid::MySql {
+            uuid: UUID.to_string(),
+            gno: 7,
+        });
+        assert_eq!(set.to_string(), format!("{UUID}:1-8"));
+    }
+
+    #[test]
+    fn mysql_gtid_set_should_parse_executed_gtid_set_output() {
+        let other = "4c2a2b6e-71ca-11e1-9e33-c80aa9429562";
+        let value = format!("{}:1-3,\n{other}:1-10:12", UUID.to_uppercase());
+        let set = GtidSet::parse(Flavor::MySql, &value).unwrap();
+
+        assert_eq!(set.to_string(), format!("{UUID}:1-3,{other}:1-10:12"));
+        let sids = set.sids();
+        assert_eq!(sids.len(), 2);
+        assert_eq!(
+            sids[1].intervals(),
+            &[GnoInterval::new(1, 11), GnoInterval::new(12, 13)]
+        );
+    }
+
+    #[test]
+    fn mysql_gtid_set_should_reject_invalid_entries() {
+        assert!(GtidSet::parse(Flavor::MySql, "invalid").is_err());
+        assert!(GtidSet::parse(Flavor::MySql, &format!("{UUID}:5-1")).is_err());
+        assert!(GtidSet::parse(Flavor::MySql, "").unwrap().is_empty());
+    }
+
+    #[test]
+    fn mariadb_gtid_position_should_keep_last_sequence_per_domain() {
+        let mut set = GtidSet::parse(Flavor::MariaDb, "0-1-100,1-2-5").unwrap();
+        set.add(&Gtid::MariaDb {
+            domain: 0,
+            server: 3,
+            sequence: 101,
+        });
+        set.add(&Gtid::MariaDb {
+            domain: 2,
+            server: 1,
+            sequence: 1,
+        });
+        assert_eq!(set.to_string(), "0-3-101,1-2-5,2-1-1");
+        assert!(set.sids().is_empty());
+    }
+
+    #[test]
+    fn mariadb_gtid_should_be_parsed_from_event_data() {
+        let mut data = 42u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&7u32.to_le_bytes());
+        data.push(0);
+        let gtid = Gtid::mariadb(3, &data).unwrap();
+        assert_eq!(gtid.to_string(), "7-3-42");
+        assert!(Gtid::mariadb(3, &data[..10]).is_none());
+    }
+}
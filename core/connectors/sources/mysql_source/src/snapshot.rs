@@ -0,0 +1,326 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::value::row_to_json;
+use crate::{
+    BinlogPosition, ChangeRecord, MySqlSource, Operation, SYSTEM_DATABASES, SourceMetadata, State,
+    TableName, quote_identifier,
+};
+use iggy_common::Utc;
+use iggy_connector_sdk::{Error, ProducedMessage};
+use mysql_async::prelude::Queryable;
+use mysql_async::{Conn, Params, Row, Value};
+use std::collections::VecDeque;
+use tracing::{error, info, warn};
+
+/// The consistent snapshot in progress, read in batches by the consecutive polls within a
+/// single `REPEATABLE READ` transaction started at the binlog position the streaming resumes
+/// from once it is completed.
+#[derive(Debug)]
+pub(crate) struct Snapshot {
+    conn: Conn,
+    position: BinlogPosition,
+    tables: VecDeque<TableSnapshot>,
+}
+
+#[derive(Debug)]
+struct TableSnapshot {
+    name: TableName,
+    primary_key: Option<Vec<String>>,
+    /// The primary key of the last row read, the next batch starts after it.
+    last_key: Option<Vec<Value>>,
+    /// The number of rows read, used to page through the tables without a primary key.
+    offset: u64,
+}
+
+impl Snapshot {
+    pub(crate) async fn close(mut self) {
+        if let Err(error) = self.conn.query_drop("ROLLBACK").await {
+            warn!("Failed to roll back the snapshot transaction: {error}");
+        }
+    }
+}
+
+impl MySqlSource {
+    pub(crate) async fn start_snapshot(&self) -> Result<Snapshot, Error> {
+        let mut conn =
+            self.pool()?.get_conn().await.map_err(|error| {
+                Error::InitError(format!("Failed to connect to MySQL: {error}"))
+            })?;
+        let locking = self.config.snapshot_locking.unwrap_or(true);
+
+        for statement in [
+            "SET SESSION time_zone = '+00:00'",
+            "SET SESSION TRANSACTION ISOLATION LEVEL REPEATABLE READ",
+        ] {
+            conn.query_drop(statement).await.map_err(|error| {
+                Error::InitError(format!("Failed to prepare the snapshot: {error}"))
+            })?;
+        }
+
+        // The global read lock blocks the writes for the short moment between starting the
+        // transaction and reading the binlog position, so that both match exactly.
+        if locking {
+            conn.query_drop("FLUSH TABLES WITH READ LOCK")
+                .await
+                .map_err(|error| {
+                    Error::InitError(format!(
+                        "Failed to lock the tables for the snapshot, grant the RELOAD privilege \
+                         or disable snapshot_locking: {error}"
+                    ))
+                })?;
+        }
+        let position = match conn
+            .query_drop("START TRANSACTION WITH CONSISTENT SNAPSHOT")
+            .await
+        {
+            Ok(()) => self.read_binlog_position(&mut conn).await,
+            Err(error) => Err(Error::InitError(format!(
+                "Failed to start the snapshot transaction: {error}"
+            ))),
+        };
+        if locking && let Err(error) = conn.query_drop("UNLOCK TABLES").await {
+            error!("Failed to unlock the tables after starting the snapshot: {error}");
+        }
+        let position = position?;
+
+        let tables = self.snapshot_tables(&mut conn).await?;
+        info!(
+            "Started snapshot of {} tables for connector ID: {} at {}:{}",
+            tables.len(),
+            self.id,
+            position.file,
+            position.position
+        );
+
+        Ok(Snapshot {
+            conn,
+            position,
+            tables: tables
+                .into_iter()
+                .map(|name| TableSnapshot {
+                    name,
+                    primary_key: None,
+                    last_key: None,
+                    offset: 0,
+                })
+                .collect(),
+        })
+    }
+
+    async fn snapshot_tables(&self, conn: &mut Conn) -> Result<Vec<TableName>, Error> {
+        if !self.tables.is_empty() {
+            return Ok(self.tables.clone());
+        }
+
+        let query = format!(
+            "SELECT TABLE_SCHEMA, TABLE_NAME FROM information_schema.TABLES \
+             WHERE TABLE_TYPE = 'BASE TABLE' AND TABLE_SCHEMA NOT IN ({}) \
+             ORDER BY TABLE_SCHEMA, TABLE_NAME",
+            SYSTEM_DATABASES
+                .map(|database| format!("'{database}'"))
+                .join(", ")
+        );
+        let tables: Vec<(String, String)> = conn
+            .query(query)
+            .await
+            .map_err(|error| Error::Storage(format!("Failed to list the tables: {error}")))?;
+        Ok(tables
+            .into_iter()
+            .map(|(database, table)| TableName::new(database, table))
+            .filter(|table| self.is_captured(table))
+            .collect())
+    }
+
+    pub(crate) async fn poll_snapshot(
+        &self,
+        state: &mut State,
+    ) -> Result<Vec<ProducedMessage>, Error> {
+        let mut guard = self.snapshot.lock().await;
+        let snapshot = match guard.as_mut() {
+            Some(snapshot) => snapshot,
+            None => guard.insert(self.start_snapshot().await?),
+        };
+
+        let mut messages = Vec::new();
+        while messages.len() < self.batch_size {
+            let Some(table) = snapshot.tables.front_mut() else {
+                break;
+            };
+            let limit = self.batch_size - messages.len();
+            let rows = match read_page(&mut snapshot.conn, table, limit).await {
+                Ok(rows) => rows,
+                Err(error) => {
+                    // The consistent view is lost with the connection, so the snapshot is
+                    // started over by the next poll, producing the rows read again.
+                    error!("Failed to read snapshot of table {}: {error}", table.name);
+                    if let Some(snapshot) = guard.take() {
+                        snapshot.close().await;
+                    }
+                    return if messages.is_empty() {
+                        Err(error)
+                    } else {
+                        Ok(messages)
+                    };
+                }
+            };
+
+            let completed = rows.len() < limit;
+            let timestamp = Utc::now().timestamp_millis() as u64;
+            for row in rows {
+                let record = ChangeRecord {
+                    operation: Operation::Snapshot,
+                    database: table.name.database.clone(),
+                    table: table.name.table.clone(),
+                    before: None,
+                    after: Some(row_to_json(row)),
+                    source: SourceMetadata {
+                        server_id: None,
+                        file: snapshot.position.file.clone(),
+                        position: snapshot.position.position,
+                        gtid: None,
+                        timestamp,
+                        snapshot: true,
+                    },
+                    transaction: None,
+                };
+                messages.push(record.into_message()?);
+            }
+
+            if completed {
+                info!(
+                    "Completed snapshot of table {} for connector ID: {}, read {} rows",
+                    table.name, self.id, table.offset
+                );
+                snapshot.tables.pop_front();
+            }
+        }
+
+        if snapshot.tables.is_empty()
+            && let Some(mut snapshot) = guard.take()
+        {
+            if let Err(error) = snapshot.conn.query_drop("COMMIT").await {
+                warn!("Failed to commit the snapshot transaction: {error}");
+            }
+            info!(
+                "Snapshot completed for connector ID: {}, streaming binlog from {}:{}",
+                self.id, snapshot.position.file, snapshot.position.position
+            );
+            state.binlog_file = Some(snapshot.position.file);
+            state.binlog_position = snapshot.position.position;
+            state.gtid_set = snapshot.position.gtid_set;
+            state.snapshot_completed = true;
+        }
+
+        Ok(messages)
+    }
+}
+
+/// Reads the next rows of the table, ordered by the primary key and continuing after the last
+/// one read, or by offset if the table has no primary key.
+async fn read_page(
+    conn: &mut Conn,
+    table: &mut TableSnapshot,
+    limit: usize,
+) -> Result<Vec<Row>, Error> {
+    let primary_key = match &table.primary_key {
+        Some(primary_key) => primary_key.clone(),
+        None => {
+            let primary_key = read_primary_key(conn, &table.name).await?;
+            if primary_key.is_empty() {
+                warn!(
+                    "Table {} has no primary key, its snapshot is paged by offset",
+                    table.name
+                );
+            }
+            table.primary_key.insert(primary_key).clone()
+        }
+    };
+
+    let (query, params) = if primary_key.is_empty() {
+        let query = format!(
+            "SELECT * FROM {} LIMIT {limit} OFFSET {}",
+            table.name.quoted(),
+            table.offset
+        );
+        (query, Params::Empty)
+    } else {
+        let columns = primary_key
+            .iter()
+            .map(|column| quote_identifier(column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match &table.last_key {
+            None => (
+                format!(
+                    "SELECT * FROM {} ORDER BY {columns} LIMIT {limit}",
+                    table.name.quoted()
+                ),
+                Params::Empty,
+            ),
+            Some(last_key) => (
+                format!(
+                    "SELECT * FROM {} WHERE ({columns}) > ({}) ORDER BY {columns} LIMIT {limit}",
+                    table.name.quoted(),
+                    vec!["?"; last_key.len()].join(", ")
+                ),
+                Params::Positional(last_key.clone()),
+            ),
+        }
+    };
+
+    let rows: Vec<Row> = conn.exec(query, params).await.map_err(|error| {
+        Error::Storage(format!(
+            "Failed to read rows of table {}: {error}",
+            table.name
+        ))
+    })?;
+
+    if let Some(last) = rows.last()
+        && !primary_key.is_empty()
+    {
+        let columns = last.columns_ref();
+        table.last_key = primary_key
+            .iter()
+            .map(|name| {
+                columns
+                    .iter()
+                    .position(|column| column.name_str() == name.as_str())
+                    .and_then(|index| last.as_ref(index).cloned())
+            })
+            .collect();
+    }
+    table.offset += rows.len() as u64;
+    Ok(rows)
+}
+
+async fn read_primary_key(conn: &mut Conn, table: &TableName) -> Result<Vec<String>, Error> {
+    conn.exec(
+        "SELECT COLUMN_NAME FROM information_schema.KEY_COLUMN_USAGE \
+         WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND CONSTRAINT_NAME = 'PRIMARY' \
+         ORDER BY ORDINAL_POSITION",
+        (&table.database, &table.table),
+    )
+    .await
+    .map_err(|error| {
+        Error::Storage(format!(
+            "Failed to read primary key of table {table}: {error}"
+        ))
+    })
+}
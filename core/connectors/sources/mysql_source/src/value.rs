@@ -0,0 +1,268 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use iggy_common::{DateTime, Utc};
+use mysql_async::binlog::row::BinlogRow;
+use mysql_async::binlog::value::BinlogValue;
+use mysql_async::consts::ColumnType;
+use mysql_async::{Column, Row, Value};
+use serde_json::{Map, Number, Value as JsonValue};
+use tracing::warn;
+
+/// The character set used by MySQL for the binary strings and the numeric columns.
+const BINARY_CHARSET: u16 = 63;
+
+/// Converts the row read during the snapshot into a JSON object keyed by the column names.
+pub(crate) fn row_to_json(mut row: Row) -> Map<String, JsonValue> {
+    let columns = row.columns();
+    columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let value = row
+                .take::<Value, _>(index)
+                .map(|value| value_to_json(value, column))
+                .unwrap_or(JsonValue::Null);
+            (column.name_str().into_owned(), value)
+        })
+        .collect()
+}
+
+/// Converts the row image read from the binlog into a JSON object keyed by the column names,
+/// looking up the ones not logged, named by their position as `@N`, in the given names.
+pub(crate) fn binlog_row_to_json(
+    mut row: BinlogRow,
+    column_names: Option<&[String]>,
+) -> Map<String, JsonValue> {
+    let columns = row.columns();
+    columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let name = column.name_str();
+            let name = name
+                .strip_prefix('@')
+                .and_then(|position| position.parse::<usize>().ok())
+                .and_then(|position| column_names?.get(position).cloned())
+                .unwrap_or_else(|| name.into_owned());
+            let value = row
+                .take(index)
+                .map(|value| binlog_value_to_json(value, column))
+                .unwrap_or(JsonValue::Null);
+            (name, value)
+        })
+        .collect()
+}
+
+fn binlog_value_to_json(value: BinlogValue<'_>, column: &Column) -> JsonValue {
+    match value {
+        BinlogValue::Value(value) => value_to_json(value, column),
+        BinlogValue::Jsonb(value) => JsonValue::try_from(value).unwrap_or_else(|error| {
+            warn!(
+                "Failed to decode JSON value of column '{}': {error}",
+                column.name_str()
+            );
+            JsonValue::Null
+        }),
+        BinlogValue::JsonDiff(_) => {
+            warn!(
+                "Partial JSON update of column '{}' cannot be captured, \
+                 disable 'binlog_row_value_options' to log the full values",
+                column.name_str()
+            );
+            JsonValue::Null
+        }
+    }
+}
+
+/// Converts the value into JSON, the same way whether it was read from the binary protocol
+/// during the snapshot or decoded from the binlog. Dates and times are rendered as strings in
+/// UTC, decimals as strings to keep their precision, and binary data as base64.
+pub(crate) fn value_to_json(value: Value, column: &Column) -> JsonValue {
+    match value {
+        Value::NULL => JsonValue::Null,
+        Value::Int(value) => value.into(),
+        Value::UInt(value) => value.into(),
+        Value::Float(value) => float_to_json(value.to_string().parse().unwrap_or(value as f64)),
+        Value::Double(value) => float_to_json(value),
+        Value::Date(year, month, day, hour, minute, second, micros) => {
+            if matches!(
+                column.column_type(),
+                ColumnType::MYSQL_TYPE_DATE | ColumnType::MYSQL_TYPE_NEWDATE
+            ) {
+                format!("{year:04}-{month:02}-{day:02}").into()
+            } else {
+                let datetime =
+                    format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}");
+                with_micros(datetime, micros).into()
+            }
+        }
+        Value::Time(negative, days, hours, minutes, seconds, micros) => {
+            let sign = if negative { "-" } else { "" };
+            let hours = days * 24 + u32::from(hours);
+            with_micros(
+                format!("{sign}{hours:02}:{minutes:02}:{seconds:02}"),
+                micros,
+            )
+            .into()
+        }
+        Value::Bytes(bytes) => bytes_to_json(bytes, column),
+    }
+}
+
+fn bytes_to_json(bytes: Vec<u8>, column: &Column) -> JsonValue {
+    match column.column_type() {
+        ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL => {
+            String::from_utf8_lossy(&bytes).into_owned().into()
+        }
+        ColumnType::MYSQL_TYPE_JSON => serde_json::from_slice(&bytes)
+            .unwrap_or_else(|_| String::from_utf8_lossy(&bytes).into_owned().into()),
+        // The binlog stores the timestamps as the seconds since the epoch.
+        ColumnType::MYSQL_TYPE_TIMESTAMP | ColumnType::MYSQL_TYPE_TIMESTAMP2 => {
+            let text = String::from_utf8_lossy(&bytes);
+            format_epoch(&text)
+                .unwrap_or_else(|| text.into_owned())
+                .into()
+        }
+        ColumnType::MYSQL_TYPE_BIT
+        | ColumnType::MYSQL_TYPE_GEOMETRY
+        | ColumnType::MYSQL_TYPE_VECTOR => STANDARD.encode(bytes).into(),
+        _ if column.character_set() == BINARY_CHARSET => STANDARD.encode(bytes).into(),
+        _ => match String::from_utf8(bytes) {
+            Ok(text) => text.into(),
+            Err(error) => STANDARD.encode(error.into_bytes()).into(),
+        },
+    }
+}
+
+fn float_to_json(value: f64) -> JsonValue {
+    Number::from_f64(value)
+        .map(JsonValue::Number)
+        .unwrap_or(JsonValue::Null)
+}
+
+fn with_micros(value: String, micros: u32) -> String {
+    if micros == 0 {
+        value
+    } else {
+        format!("{value}.{micros:06}")
+    }
+}
+
+fn format_epoch(value: &str) -> Option<String> {
+    let (seconds, micros) = value.split_once('.').unwrap_or((value, "0"));
+    let micros: u32 = micros.parse().ok()?;
+    let datetime = DateTime::<Utc>::from_timestamp(seconds.parse().ok()?, micros * 1000)?;
+    Some(with_micros(
+        datetime.format("%Y-%m-%d %H:%M:%S").to_string(),
+        micros,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mysql_async::consts::ColumnFlags;
+
+    fn column(column_type: ColumnType, character_set: u16) -> Column {
+        Column::new(column_type)
+            .with_name(b"value")
+            .with_flags(ColumnFlags::empty())
+            .with_character_set(character_set)
+    }
+
+    #[test]
+    fn temporal_values_should_be_rendered_as_strings() {
+        let date = column(ColumnType::MYSQL_TYPE_DATE, BINARY_CHARSET);
+        let datetime = column(ColumnType::MYSQL_TYPE_DATETIME2, BINARY_CHARSET);
+        let time = column(ColumnType::MYSQL_TYPE_TIME2, BINARY_CHARSET);
+
+        assert_eq!(
+            value_to_json(Value::Date(2024, 2, 29, 0, 0, 0, 0), &date),
+            "2024-02-29"
+        );
+        assert_eq!(
+            value_to_json(Value::Date(2024, 2, 29, 13, 5, 9, 1500), &datetime),
+            "2024-02-29 13:05:09.001500"
+        );
+        assert_eq!(
+            value_to_json(Value::Time(true, 1, 2, 3, 4, 0), &time),
+            "-26:03:04"
+        );
+    }
+
+    #[test]
+    fn binlog_timestamps_should_be_rendered_in_utc() {
+        let timestamp = column(ColumnType::MYSQL_TYPE_TIMESTAMP2, BINARY_CHARSET);
+
+        assert_eq!(
+            value_to_json(Value::Bytes(b"1700000000".to_vec()), &timestamp),
+            "2023-11-14 22:13:20"
+        );
+        assert_eq!(
+            value_to_json(Value::Bytes(b"1700000000.000250".to_vec()), &timestamp),
+            "2023-11-14 22:13:20.000250"
+        );
+    }
+
+    #[test]
+    fn bytes_should_be_converted_according_to_column_type() {
+        let decimal = column(ColumnType::MYSQL_TYPE_NEWDECIMAL, BINARY_CHARSET);
+        let json = column(ColumnType::MYSQL_TYPE_JSON, BINARY_CHARSET);
+        let varbinary = column(ColumnType::MYSQL_TYPE_VARCHAR, BINARY_CHARSET);
+        let varchar = column(ColumnType::MYSQL_TYPE_VARCHAR, 255);
+
+        assert_eq!(
+            value_to_json(Value::Bytes(b"12.50".to_vec()), &decimal),
+            "12.50"
+        );
+        assert_eq!(
+            value_to_json(Value::Bytes(br#"{"a":[1,2]}"#.to_vec()), &json),
+            serde_json::json!({"a": [1, 2]})
+        );
+        assert_eq!(
+            value_to_json(Value::Bytes(vec![0, 1, 255]), &varbinary),
+            "AAH/"
+        );
+        assert_eq!(
+            value_to_json(Value::Bytes("zażółć".as_bytes().to_vec()), &varchar),
+            "zażółć"
+        );
+        assert_eq!(
+            value_to_json(Value::Bytes(vec![0xff, 0xfe]), &varchar),
+            "//4="
+        );
+    }
+
+    #[test]
+    fn numeric_values_should_be_converted_to_numbers() {
+        let numeric = column(ColumnType::MYSQL_TYPE_FLOAT, BINARY_CHARSET);
+
+        assert_eq!(value_to_json(Value::Int(-5), &numeric), -5);
+        assert_eq!(value_to_json(Value::UInt(u64::MAX), &numeric), u64::MAX);
+        assert_eq!(value_to_json(Value::Float(0.1), &numeric), 0.1);
+        assert_eq!(
+            value_to_json(Value::Double(f64::NAN), &numeric),
+            JsonValue::Null
+        );
+        assert_eq!(value_to_json(Value::NULL, &numeric), JsonValue::Null);
+    }
+}
@@ -0,0 +1,680 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+mod pubsub;
+mod streams;
+
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use humantime::Duration as HumanDuration;
+use iggy_common::{HeaderKey, HeaderValue};
+use iggy_connector_sdk::{
+    ConnectorState, Error, ProducedMessage, ProducedMessages, Schema, Source, source_connector,
+};
+use redis::{Client, Msg, Value};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+use std::collections::{BTreeMap, VecDeque};
+use std::str::FromStr;
+use std::time::Duration;
+use streams::{StreamEntry, StreamReader};
+use tokio::sync::{Mutex, mpsc};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, sleep, timeout, timeout_at};
+use tracing::{debug, error, info, warn};
+
+source_connector!(RedisSource);
+
+const CONNECTOR_NAME: &str = "Redis source";
+const DEFAULT_BATCH_SIZE: usize = 100;
+const DEFAULT_POLL_TIMEOUT: &str = "100ms";
+const DEFAULT_START_ID: &str = "$";
+const CHANNEL_CAPACITY: usize = 1000;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Header holding the Redis stream the entry was read from.
+pub const STREAM_HEADER: &str = "redis_stream";
+/// Header holding the ID of the stream entry.
+pub const ENTRY_ID_HEADER: &str = "redis_entry_id";
+/// Header holding the channel the message was published to.
+pub const CHANNEL_HEADER: &str = "redis_channel";
+/// Header holding the channel pattern the message was received by.
+pub const PATTERN_HEADER: &str = "redis_pattern";
+
+#[derive(Debug)]
+pub struct RedisSource {
+    id: u32,
+    config: RedisSourceConfig,
+    mode: Mode,
+    batch_size: usize,
+    poll_timeout: Duration,
+    include_metadata: bool,
+    reader: Mutex<Option<StreamReader>>,
+    subscriber: Option<JoinHandle<()>>,
+    receiver: Mutex<Option<mpsc::Receiver<Msg>>>,
+    /// The stream entries read, per batch awaiting the acknowledgement.
+    pending: Mutex<VecDeque<PendingBatch>>,
+    /// The stream entries which could not be delivered, produced again by the next poll.
+    redelivered: Mutex<VecDeque<StreamEntry>>,
+    state: Mutex<State>,
+}
+
+/// The stream entries of a polled batch, split into the ones mapped to the produced messages
+/// (in the same order) and the skipped ones.
+#[derive(Debug, Default)]
+struct PendingBatch {
+    produced: Vec<StreamEntry>,
+    skipped: Vec<StreamEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedisSourceConfig {
+    #[serde(serialize_with = "iggy_common::serde_secret::serialize_secret")]
+    pub connection_string: SecretString,
+    pub mode: Option<Mode>,
+    pub streams: Option<Vec<String>>,
+    pub consumer_group: Option<String>,
+    pub consumer_name: Option<String>,
+    pub start_id: Option<String>,
+    pub channels: Option<Vec<String>>,
+    pub patterns: Option<Vec<String>>,
+    pub payload_field: Option<String>,
+    pub payload_format: Option<Schema>,
+    pub include_metadata: Option<bool>,
+    pub batch_size: Option<usize>,
+    pub poll_timeout: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// Reads the entries of Redis Streams, in a consumer group if configured.
+    #[default]
+    Streams,
+    /// Receives the messages published to Redis channels, at most once.
+    PubSub,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct State {
+    /// The ID of the last entry read, per Redis stream.
+    last_ids: BTreeMap<String, String>,
+    messages_produced: u64,
+}
+
+impl RedisSource {
+    pub fn new(id: u32, config: RedisSourceConfig, state: Option<ConnectorState>) -> Self {
+        let restored_state = state
+            .and_then(|s| s.deserialize::<State>(CONNECTOR_NAME, id))
+            .inspect(|s| {
+                info!(
+                    "Restored state for {CONNECTOR_NAME} connector with ID: {id}. \
+                     Last entry IDs: {:?}, messages produced: {}",
+                    s.last_ids, s.messages_produced
+                );
+            });
+
+        RedisSource {
+            id,
+            mode: config.mode.unwrap_or_default(),
+            batch_size: config.batch_size.unwrap_or(DEFAULT_BATCH_SIZE).max(1),
+            poll_timeout: parse_duration(config.poll_timeout.as_deref(), DEFAULT_POLL_TIMEOUT),
+            include_metadata: config.include_metadata.unwrap_or(true),
+            config,
+            reader: Mutex::new(None),
+            subscriber: None,
+            receiver: Mutex::new(None),
+            pending: Mutex::new(VecDeque::new()),
+            redelivered: Mutex::new(VecDeque::new()),
+            state: Mutex::new(restored_state.unwrap_or_default()),
+        }
+    }
+
+    fn serialize_state(&self, state: &State) -> Option<ConnectorState> {
+        ConnectorState::serialize(state, CONNECTOR_NAME, self.id)
+    }
+
+    /// The entries are produced as JSON objects of their fields, unless a single field
+    /// holds the payload.
+    fn schema(&self) -> Schema {
+        match (self.mode, &self.config.payload_field) {
+            (Mode::Streams, None) => Schema::Json,
+            _ => self.config.payload_format.unwrap_or(Schema::Raw),
+        }
+    }
+
+    /// Maps the stream entry to a message, either with all the fields in a JSON object,
+    /// or with the payload field as the payload and the remaining fields as the headers.
+    fn map_entry(&self, entry: &StreamEntry) -> Option<ProducedMessage> {
+        if entry.fields.is_empty() {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} skipped the deleted entry: {} of Redis stream: {}",
+                self.id, entry.id, entry.stream
+            );
+            return None;
+        }
+
+        let mut headers = BTreeMap::new();
+        if self.include_metadata {
+            insert_header(
+                self.id,
+                &mut headers,
+                STREAM_HEADER,
+                HeaderValue::try_from(entry.stream.as_str()),
+            );
+            insert_header(
+                self.id,
+                &mut headers,
+                ENTRY_ID_HEADER,
+                HeaderValue::try_from(entry.id.as_str()),
+            );
+        }
+
+        let payload = match &self.config.payload_field {
+            None => {
+                let fields: Map<String, JsonValue> = entry
+                    .fields
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value_to_json(value)))
+                    .collect();
+                serde_json::to_vec(&fields).ok()?
+            }
+            Some(payload_field) => {
+                let Some(payload) = entry.fields.get(payload_field).and_then(value_to_bytes) else {
+                    warn!(
+                        "{CONNECTOR_NAME} connector with ID: {} skipped the entry: {} of Redis stream: {} without payload field: {payload_field}",
+                        self.id, entry.id, entry.stream
+                    );
+                    return None;
+                };
+                for (name, value) in &entry.fields {
+                    if name == payload_field {
+                        continue;
+                    }
+                    let value = value_to_bytes(value).unwrap_or_default();
+                    let value = match std::str::from_utf8(&value) {
+                        Ok(value) => HeaderValue::try_from(value),
+                        Err(_) => HeaderValue::try_from(value.as_slice()),
+                    };
+                    insert_header(self.id, &mut headers, name, value);
+                }
+                payload
+            }
+        };
+
+        Some(ProducedMessage {
+            id: None,
+            checksum: None,
+            timestamp: None,
+            origin_timestamp: entry_timestamp(&entry.id),
+            headers: (!headers.is_empty()).then_some(headers),
+            payload,
+        })
+    }
+
+    fn map_message(&self, message: &Msg) -> Option<ProducedMessage> {
+        let channel = message.get_channel_name();
+        let payload = message.get_payload_bytes();
+        if payload.is_empty() {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} skipped the message without payload from channel: {channel}",
+                self.id
+            );
+            return None;
+        }
+
+        let mut headers = BTreeMap::new();
+        if self.include_metadata {
+            insert_header(
+                self.id,
+                &mut headers,
+                CHANNEL_HEADER,
+                HeaderValue::try_from(channel),
+            );
+            if message.from_pattern()
+                && let Ok(pattern) = message.get_pattern::<String>()
+            {
+                insert_header(
+                    self.id,
+                    &mut headers,
+                    PATTERN_HEADER,
+                    HeaderValue::try_from(pattern.as_str()),
+                );
+            }
+        }
+        Some(ProducedMessage {
+            id: None,
+            checksum: None,
+            timestamp: None,
+            origin_timestamp: None,
+            headers: (!headers.is_empty()).then_some(headers),
+            payload: payload.to_vec(),
+        })
+    }
+
+    async fn poll_streams(&self) -> Result<(Vec<ProducedMessage>, PendingBatch), Error> {
+        let mut entries: Vec<StreamEntry> = {
+            let mut redelivered = self.redelivered.lock().await;
+            let count = redelivered.len().min(self.batch_size);
+            redelivered.drain(..count).collect()
+        };
+
+        if entries.is_empty() {
+            let mut reader = self.reader.lock().await;
+            let Some(reader) = reader.as_mut() else {
+                error!(
+                    "{CONNECTOR_NAME} connector with ID: {} is not opened",
+                    self.id
+                );
+                return Err(Error::InvalidState);
+            };
+            let mut state = self.state.lock().await;
+            entries = match reader
+                .read(self.id, &state, self.batch_size, self.poll_timeout)
+                .await
+            {
+                Ok(entries) => entries,
+                Err(error) => {
+                    error!(
+                        "{CONNECTOR_NAME} connector with ID: {} failed to read Redis streams. {error}",
+                        self.id
+                    );
+                    // The connection is restored by the next read.
+                    sleep(RECONNECT_DELAY).await;
+                    return Err(error);
+                }
+            };
+            for entry in &entries {
+                state
+                    .last_ids
+                    .insert(entry.stream.clone(), entry.id.clone());
+            }
+        }
+
+        let mut messages = Vec::with_capacity(entries.len());
+        let mut batch = PendingBatch::default();
+        for entry in entries {
+            match self.map_entry(&entry) {
+                Some(message) => {
+                    messages.push(message);
+                    batch.produced.push(entry);
+                }
+                None => batch.skipped.push(entry),
+            }
+        }
+        Ok((messages, batch))
+    }
+
+    async fn poll_pubsub(&self) -> Result<Vec<ProducedMessage>, Error> {
+        let mut receiver = self.receiver.lock().await;
+        let Some(receiver) = receiver.as_mut() else {
+            error!(
+                "{CONNECTOR_NAME} connector with ID: {} is not opened",
+                self.id
+            );
+            return Err(Error::InvalidState);
+        };
+
+        let mut messages = Vec::new();
+        let deadline = Instant::now() + self.poll_timeout;
+        while messages.len() < self.batch_size {
+            match timeout_at(deadline, receiver.recv()).await {
+                Err(_) => break,
+                Ok(Some(message)) => messages.extend(self.map_message(&message)),
+                Ok(None) => {
+                    error!(
+                        "{CONNECTOR_NAME} connector with ID: {} lost the Redis subscriber",
+                        self.id
+                    );
+                    return Err(Error::Connection("Redis subscriber has stopped".to_owned()));
+                }
+            }
+        }
+        Ok(messages)
+    }
+}
+
+#[async_trait]
+impl Source for RedisSource {
+    async fn open(&mut self) -> Result<(), Error> {
+        info!(
+            "Opening {CONNECTOR_NAME} connector with ID: {}. Mode: {:?}",
+            self.id, self.mode
+        );
+        let client =
+            Client::open(self.config.connection_string.expose_secret()).map_err(|error| {
+                Error::InvalidConfigValue(format!("Invalid Redis connection string: {error}"))
+            })?;
+
+        match self.mode {
+            Mode::Streams => {
+                let streams = self.config.streams.as_deref().unwrap_or_default();
+                if streams.is_empty() {
+                    return Err(Error::InvalidConfigValue(
+                        "At least one Redis stream must be configured".to_owned(),
+                    ));
+                }
+                let state = self.state.lock().await;
+                let reader = StreamReader::connect(
+                    self.id,
+                    &client,
+                    &self.config,
+                    &state,
+                    self.poll_timeout,
+                )
+                .await?;
+                drop(state);
+                info!(
+                    "{CONNECTOR_NAME} connector with ID: {} opened. Streams: {streams:?}, consumer group: {:?}",
+                    self.id, self.config.consumer_group
+                );
+                *self.reader.lock().await = Some(reader);
+            }
+            Mode::PubSub => {
+                let channels = self.config.channels.clone().unwrap_or_default();
+                let patterns = self.config.patterns.clone().unwrap_or_default();
+                if channels.is_empty() && patterns.is_empty() {
+                    return Err(Error::InvalidConfigValue(
+                        "At least one Redis channel or pattern must be configured".to_owned(),
+                    ));
+                }
+                let pubsub = timeout(
+                    CONNECT_TIMEOUT,
+                    pubsub::subscribe(&client, &channels, &patterns),
+                )
+                .await
+                .map_err(|_| Error::Connection("Timed out connecting to Redis".to_owned()))?
+                .map_err(|error| {
+                    Error::Connection(format!("Failed to subscribe to Redis channels: {error}"))
+                })?;
+                info!(
+                    "{CONNECTOR_NAME} connector with ID: {} opened. Channels: {channels:?}, patterns: {patterns:?}",
+                    self.id
+                );
+
+                let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+                self.subscriber = Some(tokio::spawn(pubsub::run_subscriber(
+                    self.id, client, channels, patterns, pubsub, sender,
+                )));
+                *self.receiver.lock().await = Some(receiver);
+            }
+        }
+        Ok(())
+    }
+
+    async fn poll(&self) -> Result<ProducedMessages, Error> {
+        let (messages, batch) = match self.mode {
+            Mode::Streams => self.poll_streams().await?,
+            Mode::PubSub => (self.poll_pubsub().await?, PendingBatch::default()),
+        };
+        // Every batch is acknowledged by the runtime, including the empty ones.
+        self.pending.lock().await.push_back(batch);
+
+        let mut state = self.state.lock().await;
+        state.messages_produced += messages.len() as u64;
+        debug!(
+            "{CONNECTOR_NAME} connector with ID: {} produced {} messages. Total produced: {}",
+            self.id,
+            messages.len(),
+            state.messages_produced
+        );
+
+        Ok(ProducedMessages {
+            schema: self.schema(),
+            messages,
+            state: self.serialize_state(&state),
+        })
+    }
+
+    async fn acknowledge(&self, delivered: usize) -> Result<(), Error> {
+        let Some(mut batch) = self.pending.lock().await.pop_front() else {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} received an acknowledgement without pending messages",
+                self.id
+            );
+            return Ok(());
+        };
+
+        let undelivered = batch
+            .produced
+            .split_off(delivered.min(batch.produced.len()));
+        if !undelivered.is_empty() {
+            warn!(
+                "{CONNECTOR_NAME} connector with ID: {} failed to deliver {} entries, they will be produced again",
+                self.id,
+                undelivered.len()
+            );
+            self.redelivered.lock().await.extend(undelivered);
+        }
+
+        let entries: Vec<StreamEntry> = batch.skipped.into_iter().chain(batch.produced).collect();
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut reader = self.reader.lock().await;
+        let Some(reader) = reader.as_mut() else {
+            return Err(Error::InvalidState);
+        };
+        reader.acknowledge(&entries).await
+    }
+
+    async fn close(&mut self) -> Result<(), Error> {
+        self.reader.lock().await.take();
+        self.receiver.lock().await.take();
+        if let Some(subscriber) = self.subscriber.take() {
+            subscriber.abort();
+        }
+        let state = self.state.lock().await;
+        info!(
+            "{CONNECTOR_NAME} connector with ID: {} closed. Total messages produced: {}",
+            self.id, state.messages_produced
+        );
+        Ok(())
+    }
+}
+
+fn parse_duration(value: Option<&str>, default: &str) -> Duration {
+    HumanDuration::from_str(value.unwrap_or(default))
+        .or_else(|_| HumanDuration::from_str(default))
+        .map(|duration| duration.into())
+        .unwrap_or_default()
+}
+
+/// Converts the value of an entry field, which is UTF-8 text or binary data encoded
+/// as base64.
+fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::BulkString(bytes) => match std::str::from_utf8(bytes) {
+            Ok(text) => text.into(),
+            Err(_) => STANDARD.encode(bytes).into(),
+        },
+        Value::SimpleString(text) => text.as_str().into(),
+        Value::Int(value) => (*value).into(),
+        _ => JsonValue::Null,
+    }
+}
+
+fn value_to_bytes(value: &Value) -> Option<Vec<u8>> {
+    match value {
+        Value::BulkString(bytes) => Some(bytes.clone()),
+        Value::SimpleString(text) => Some(text.clone().into_bytes()),
+        Value::Int(value) => Some(value.to_string().into_bytes()),
+        _ => None,
+    }
+}
+
+/// The stream entry IDs start with the milliseconds since the epoch they were added at.
+fn entry_timestamp(id: &str) -> Option<u64> {
+    let (millis, _) = id.split_once('-')?;
+    millis.parse::<u64>().ok().map(|millis| millis * 1000)
+}
+
+fn insert_header<E>(
+    id: u32,
+    headers: &mut BTreeMap<HeaderKey, HeaderValue>,
+    name: &str,
+    value: Result<HeaderValue, E>,
+) {
+    match (HeaderKey::try_from(name), value) {
+        (Ok(name), Ok(value)) => {
+            headers.insert(name, value);
+        }
+        _ => warn!("{CONNECTOR_NAME} connector with ID: {id} skipped the Redis field: {name}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn test_config() -> RedisSourceConfig {
+        RedisSourceConfig {
+            connection_string: SecretString::from("redis://localhost:6379"),
+            mode: None,
+            streams: Some(vec!["orders".to_owned()]),
+            consumer_group: Some("iggy".to_owned()),
+            consumer_name: None,
+            start_id: None,
+            channels: None,
+            patterns: None,
+            payload_field: None,
+            payload_format: None,
+            include_metadata: None,
+            batch_size: None,
+            poll_timeout: None,
+        }
+    }
+
+    fn entry(fields: &[(&str, &[u8])]) -> StreamEntry {
+        StreamEntry {
+            stream: "orders".to_owned(),
+            id: "1700000000123-4".to_owned(),
+            fields: fields
+                .iter()
+                .map(|(name, value)| ((*name).to_owned(), Value::BulkString(value.to_vec())))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    fn header(message: &ProducedMessage, name: &str) -> Option<HeaderValue> {
+        message
+            .headers
+            .as_ref()?
+            .get(&HeaderKey::try_from(name).unwrap())
+            .cloned()
+    }
+
+    #[test]
+    fn should_map_entry_fields_to_json_payload() {
+        let source = RedisSource::new(1, test_config(), None);
+        let message = source
+            .map_entry(&entry(&[
+                ("id", b"7"),
+                ("status", b"paid"),
+                ("raw", &[0, 255]),
+            ]))
+            .unwrap();
+
+        assert_eq!(source.schema(), Schema::Json);
+        let payload: JsonValue = serde_json::from_slice(&message.payload).unwrap();
+        assert_eq!(
+            payload,
+            serde_json::json!({"id": "7", "status": "paid", "raw": "AP8="})
+        );
+        assert_eq!(message.origin_timestamp, Some(1_700_000_000_123_000));
+        assert_eq!(
+            header(&message, STREAM_HEADER).unwrap().as_str().unwrap(),
+            "orders"
+        );
+        assert_eq!(
+            header(&message, ENTRY_ID_HEADER).unwrap().as_str().unwrap(),
+            "1700000000123-4"
+        );
+    }
+
+    #[test]
+    fn should_map_payload_field_and_remaining_fields_to_headers() {
+        let mut config = test_config();
+        config.payload_field = Some("payload".to_owned());
+        config.include_metadata = Some(false);
+        let source = RedisSource::new(1, config, None);
+        let message = source
+            .map_entry(&entry(&[("payload", br#"{"id":7}"#), ("tenant", b"acme")]))
+            .unwrap();
+
+        assert_eq!(source.schema(), Schema::Raw);
+        assert_eq!(message.payload, br#"{"id":7}"#);
+        let headers = message.headers.as_ref().unwrap();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(
+            header(&message, "tenant").unwrap().as_str().unwrap(),
+            "acme"
+        );
+    }
+
+    #[test]
+    fn should_skip_entries_without_payload() {
+        let mut config = test_config();
+        config.payload_field = Some("payload".to_owned());
+        let source = RedisSource::new(1, config, None);
+
+        assert!(source.map_entry(&entry(&[("other", b"value")])).is_none());
+        assert!(source.map_entry(&entry(&[])).is_none());
+    }
+
+    #[test]
+    fn should_parse_entry_timestamps() {
+        assert_eq!(
+            entry_timestamp("1700000000000-0"),
+            Some(1_700_000_000_000_000)
+        );
+        assert_eq!(entry_timestamp("invalid"), None);
+    }
+
+    #[test]
+    fn should_deserialize_pubsub_mode() {
+        let config: RedisSourceConfig = serde_json::from_value(serde_json::json!({
+            "connection_string": "redis://localhost:6379",
+            "mode": "pubsub",
+            "patterns": ["events.*"],
+        }))
+        .unwrap();
+        assert_eq!(config.mode, Some(Mode::PubSub));
+    }
+
+    #[test]
+    fn given_persisted_state_should_restore_last_entry_ids() {
+        let state = State {
+            last_ids: BTreeMap::from([("orders".to_owned(), "1700000000123-4".to_owned())]),
+            messages_produced: 42,
+        };
+        let connector_state = ConnectorState(rmp_serde::to_vec(&state).unwrap());
+        let source = RedisSource::new(1, test_config(), Some(connector_state));
+
+        let state = source.state.blocking_lock();
+        assert_eq!(state.messages_produced, 42);
+        assert_eq!(state.last_ids["orders"], "1700000000123-4");
+    }
+}
@@ -0,0 +1,79 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{CONNECTOR_NAME, RECONNECT_DELAY};
+use futures::StreamExt;
+use redis::aio::PubSub;
+use redis::{Client, Msg, RedisResult};
+use tokio::sync::mpsc;
+use tokio::time::sleep;
+use tracing::{info, warn};
+
+/// Subscribes to the channels and the channel patterns.
+pub(crate) async fn subscribe(
+    client: &Client,
+    channels: &[String],
+    patterns: &[String],
+) -> RedisResult<PubSub> {
+    let mut pubsub = client.get_async_pubsub().await?;
+    if !channels.is_empty() {
+        pubsub.subscribe(channels).await?;
+    }
+    if !patterns.is_empty() {
+        pubsub.psubscribe(patterns).await?;
+    }
+    Ok(pubsub)
+}
+
+/// Forwards the published messages, subscribing again whenever the connection is lost.
+/// The messages published while disconnected are not delivered by Redis.
+pub(crate) async fn run_subscriber(
+    id: u32,
+    client: Client,
+    channels: Vec<String>,
+    patterns: Vec<String>,
+    mut pubsub: PubSub,
+    sender: mpsc::Sender<Msg>,
+) {
+    loop {
+        let mut messages = pubsub.into_on_message();
+        while let Some(message) = messages.next().await {
+            if sender.send(message).await.is_err() {
+                return;
+            }
+        }
+
+        warn!(
+            "{CONNECTOR_NAME} connector with ID: {id} lost the connection to Redis, subscribing again"
+        );
+        pubsub = loop {
+            sleep(RECONNECT_DELAY).await;
+            if sender.is_closed() {
+                return;
+            }
+            match subscribe(&client, &channels, &patterns).await {
+                Ok(pubsub) => break pubsub,
+                Err(error) => warn!(
+                    "{CONNECTOR_NAME} connector with ID: {id} failed to subscribe to Redis channels. {error}"
+                ),
+            }
+        };
+        info!("{CONNECTOR_NAME} connector with ID: {id} subscribed to Redis channels again");
+    }
+}
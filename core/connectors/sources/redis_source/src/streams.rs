@@ -0,0 +1,254 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{CONNECTOR_NAME, DEFAULT_START_ID, RESPONSE_TIMEOUT, RedisSourceConfig, State};
+use iggy_connector_sdk::Error;
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use redis::streams::{StreamRangeReply, StreamReadOptions, StreamReadReply};
+use redis::{AsyncCommands, Client, RedisError, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Reading the new entries of a stream in a consumer group.
+const NEW_ENTRIES_ID: &str = ">";
+/// Reading the pending entries of the consumer from the beginning.
+const PENDING_ENTRIES_ID: &str = "0";
+const EMPTY_STREAM_ID: &str = "0-0";
+
+/// An entry read from a Redis stream.
+#[derive(Debug, Clone)]
+pub(crate) struct StreamEntry {
+    pub stream: String,
+    pub id: String,
+    pub fields: HashMap<String, Value>,
+}
+
+/// Reads the configured streams with `XREADGROUP` in a consumer group, or with `XREAD`
+/// from the last entry produced otherwise.
+pub(crate) struct StreamReader {
+    connection: ConnectionManager,
+    keys: Vec<String>,
+    /// The consumer group and the consumer name.
+    group: Option<(String, String)>,
+    /// The ID each stream is read after. In a consumer group, the pending entries of the
+    /// consumer, delivered before the restart but never acknowledged, are read first.
+    cursors: Vec<String>,
+    start_id: String,
+}
+
+impl fmt::Debug for StreamReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamReader")
+            .field("keys", &self.keys)
+            .field("group", &self.group)
+            .field("cursors", &self.cursors)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StreamReader {
+    pub async fn connect(
+        id: u32,
+        client: &Client,
+        config: &RedisSourceConfig,
+        state: &State,
+        poll_timeout: Duration,
+    ) -> Result<Self, Error> {
+        let connection_config = ConnectionManagerConfig::new()
+            .set_connection_timeout(Some(crate::CONNECT_TIMEOUT))
+            // The blocking reads wait for the poll timeout before responding.
+            .set_response_timeout(Some(poll_timeout + RESPONSE_TIMEOUT));
+        let connection = client
+            .get_connection_manager_with_config(connection_config)
+            .await
+            .map_err(|error| Error::Connection(format!("Failed to connect to Redis: {error}")))?;
+
+        let keys = config.streams.clone().unwrap_or_default();
+        let group = config.consumer_group.clone().map(|group| {
+            let consumer = config
+                .consumer_name
+                .clone()
+                .unwrap_or_else(|| format!("iggy-redis-source-{id}"));
+            (group, consumer)
+        });
+        let mut reader = StreamReader {
+            connection,
+            cursors: Vec::with_capacity(keys.len()),
+            keys,
+            group,
+            start_id: config
+                .start_id
+                .clone()
+                .unwrap_or_else(|| DEFAULT_START_ID.to_owned()),
+        };
+
+        if reader.group.is_some() {
+            reader.create_groups(id, state).await?;
+            reader.cursors = vec![PENDING_ENTRIES_ID.to_owned(); reader.keys.len()];
+        } else {
+            for key in reader.keys.clone() {
+                let cursor = match state.last_ids.get(&key) {
+                    Some(last_id) => last_id.clone(),
+                    None => reader.resolve_start_id(&key).await?,
+                };
+                info!(
+                    "{CONNECTOR_NAME} connector with ID: {id} reads Redis stream: {key} after entry: {cursor}"
+                );
+                reader.cursors.push(cursor);
+            }
+        }
+        Ok(reader)
+    }
+
+    /// Creates the consumer group of every stream, together with the stream if it doesn't
+    /// exist. A new group starts after the last entry produced, if any, or the start ID.
+    async fn create_groups(&mut self, id: u32, state: &State) -> Result<(), Error> {
+        let Some((group, _)) = self.group.clone() else {
+            return Ok(());
+        };
+        for key in &self.keys {
+            let start_id = state.last_ids.get(key).unwrap_or(&self.start_id);
+            let result: Result<(), RedisError> = self
+                .connection
+                .xgroup_create_mkstream(key, &group, start_id)
+                .await;
+            match result {
+                Ok(()) => info!(
+                    "{CONNECTOR_NAME} connector with ID: {id} created consumer group: {group} of Redis stream: {key} starting after entry: {start_id}"
+                ),
+                Err(error) if error.code() == Some("BUSYGROUP") => {}
+                Err(error) => {
+                    return Err(Error::InitError(format!(
+                        "Failed to create consumer group: {group} of Redis stream: {key}. {error}"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves `$` to the ID of the last entry of the stream, so that the entries added
+    /// between the polls are not missed.
+    async fn resolve_start_id(&mut self, key: &str) -> Result<String, Error> {
+        if self.start_id != "$" {
+            return Ok(self.start_id.clone());
+        }
+        let reply: StreamRangeReply = self
+            .connection
+            .xrevrange_count(key, "+", "-", 1)
+            .await
+            .map_err(|error| {
+                Error::InitError(format!(
+                    "Failed to read the last entry of Redis stream: {key}. {error}"
+                ))
+            })?;
+        Ok(reply
+            .ids
+            .into_iter()
+            .next()
+            .map(|entry| entry.id)
+            .unwrap_or_else(|| EMPTY_STREAM_ID.to_owned()))
+    }
+
+    /// Reads up to `count` entries, blocking for up to `block` if there are none.
+    pub async fn read(
+        &mut self,
+        id: u32,
+        state: &State,
+        count: usize,
+        block: Duration,
+    ) -> Result<Vec<StreamEntry>, Error> {
+        let mut options = StreamReadOptions::default()
+            .count(count)
+            // Blocking for 0 milliseconds would block forever.
+            .block((block.as_millis() as usize).max(1));
+        if let Some((group, consumer)) = &self.group {
+            options = options.group(group, consumer);
+        }
+
+        let result: Result<Option<StreamReadReply>, RedisError> = self
+            .connection
+            .xread_options(&self.keys, &self.cursors, &options)
+            .await;
+        let reply = match result {
+            Ok(reply) => reply.unwrap_or_default(),
+            Err(error) if error.code() == Some("NOGROUP") => {
+                warn!(
+                    "{CONNECTOR_NAME} connector with ID: {id} lost the consumer group of a Redis stream, creating it again. {error}"
+                );
+                self.create_groups(id, state).await?;
+                return Ok(Vec::new());
+            }
+            Err(error) => {
+                return Err(Error::Connection(format!(
+                    "Failed to read Redis streams: {error}"
+                )));
+            }
+        };
+
+        let mut entries = Vec::new();
+        for stream in reply.keys {
+            let Some(index) = self.keys.iter().position(|key| *key == stream.key) else {
+                continue;
+            };
+            if let Some(last) = stream.ids.last() {
+                if self.group.is_none() || self.cursors[index] != NEW_ENTRIES_ID {
+                    self.cursors[index] = last.id.clone();
+                }
+            } else if self.group.is_some() {
+                info!(
+                    "{CONNECTOR_NAME} connector with ID: {id} read the pending entries of Redis stream: {}",
+                    stream.key
+                );
+                self.cursors[index] = NEW_ENTRIES_ID.to_owned();
+            }
+            entries.extend(stream.ids.into_iter().map(|entry| StreamEntry {
+                stream: stream.key.clone(),
+                id: entry.id,
+                fields: entry.map,
+            }));
+        }
+        Ok(entries)
+    }
+
+    /// Acknowledges the entries in the consumer group, removing them from the pending ones.
+    pub async fn acknowledge(&mut self, entries: &[StreamEntry]) -> Result<(), Error> {
+        let Some((group, _)) = &self.group else {
+            return Ok(());
+        };
+        let mut ids: HashMap<&str, Vec<&str>> = HashMap::new();
+        for entry in entries {
+            ids.entry(&entry.stream).or_default().push(&entry.id);
+        }
+        for (key, ids) in ids {
+            let _: usize = self
+                .connection
+                .xack(key, group, &ids)
+                .await
+                .map_err(|error| {
+                    Error::Connection(format!(
+                        "Failed to acknowledge the entries of Redis stream: {key}. {error}"
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+}
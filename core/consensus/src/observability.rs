@@ -640,6 +640,11 @@ pub const fn operation_as_str(operation: Operation) -> &'static str {
         Operation::UpdatePermissions => "update_permissions",
         Operation::CreatePersonalAccessToken => "create_personal_access_token",
         Operation::DeletePersonalAccessToken => "delete_personal_access_token",
+        Operation::CreateRole => "create_role",
+        Operation::UpdateRole => "update_role",
+        Operation::DeleteRole => "delete_role",
+        Operation::AssignRole => "assign_role",
+        Operation::UnassignRole => "unassign_role",
         Operation::Register => "register",
         Operation::SendMessages => "send_messages",
         Operation::StoreConsumerOffset => "store_consumer_offset",
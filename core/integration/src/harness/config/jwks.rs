@@ -25,4 +25,6 @@ pub struct JwksConfig {
     pub issuer_url: Option<String>,
     #[builder(into)]
     pub store_path: Option<String>,
+    /// User the trusted issuer maps every token to, `0` maps the tokens by their claims (default: 1).
+    pub user_id: Option<u32>,
 }
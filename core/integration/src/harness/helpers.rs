@@ -93,6 +93,7 @@ fn full_permissions() -> Permissions {
             send_messages: true,
         },
         streams: None,
+        stream_patterns: None,
     }
 }
 
@@ -134,6 +134,7 @@ impl TestHarness {
                 .issuer_url
                 .as_deref()
                 .unwrap_or("https://test-issuer.com");
+            let user_id = jwks_config.user_id.unwrap_or(1).to_string();
 
             for server in &mut self.servers {
                 server.add_env("IGGY_HTTP_JWT_TRUSTED_ISSUERS_0_ISSUER", issuer);
@@ -142,7 +143,7 @@ impl TestHarness {
                     jwks_url.as_str(),
                 );
                 server.add_env("IGGY_HTTP_JWT_TRUSTED_ISSUERS_0_AUDIENCE", "iggy");
-                server.add_env("IGGY_HTTP_JWT_TRUSTED_ISSUERS_0_USER_ID", "1");
+                server.add_env("IGGY_HTTP_JWT_TRUSTED_ISSUERS_0_USER_ID", user_id.as_str());
             }
 
             self.jwks_server = Some(mock_server);
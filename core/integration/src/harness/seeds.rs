@@ -19,8 +19,8 @@
 
 use iggy::prelude::{IggyClient, StreamClient, TopicClient};
 use iggy_common::{
-    CompressionAlgorithm, Consumer, Identifier, IggyExpiry, IggyMessage, MaxTopicSize,
-    Partitioning, PersonalAccessTokenExpiry, UserStatus,
+    CleanupPolicy, CompressionAlgorithm, Consumer, Identifier, IggyExpiry, IggyMessage,
+    MaxTopicSize, Partitioning, PersonalAccessTokenExpiry, UserStatus,
 };
 use iggy_common::{
     ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PersonalAccessTokenClient, UserClient,
@@ -62,6 +62,7 @@ pub async fn stream_with_topic(client: &IggyClient) -> Result<(), SeedError> {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::Unlimited,
+            CleanupPolicy::Delete,
         )
         .await?;
     Ok(())
@@ -82,6 +83,7 @@ pub async fn connector_stream(client: &IggyClient) -> Result<(), SeedError> {
             None,
             IggyExpiry::ServerDefault,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await?;
 
@@ -105,6 +107,7 @@ pub async fn connector_multi_topic_stream(client: &IggyClient) -> Result<(), See
             None,
             IggyExpiry::ServerDefault,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await?;
 
@@ -117,6 +120,7 @@ pub async fn connector_multi_topic_stream(client: &IggyClient) -> Result<(), See
             None,
             IggyExpiry::ServerDefault,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await?;
 
@@ -139,6 +143,7 @@ pub async fn mcp_standard(client: &IggyClient) -> Result<(), SeedError> {
             None,
             IggyExpiry::ServerDefault,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await?;
 
@@ -163,7 +168,7 @@ pub async fn mcp_standard(client: &IggyClient) -> Result<(), SeedError> {
         .await?;
 
     client
-        .create_consumer_group(&stream_id, &topic_id, names::CONSUMER_GROUP)
+        .create_consumer_group(&stream_id, &topic_id, names::CONSUMER_GROUP, None, None)
         .await?;
 
     client
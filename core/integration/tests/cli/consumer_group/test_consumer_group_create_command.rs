@@ -22,7 +22,7 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
-use iggy::prelude::{Client, IggyExpiry, MaxTopicSize};
+use iggy::prelude::{CleanupPolicy, Client, IggyExpiry, MaxTopicSize};
 use predicates::str::diff;
 use serial_test::parallel;
 
@@ -100,6 +100,7 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
@@ -24,7 +24,7 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::prelude::Client;
 use iggy::prelude::IggyExpiry;
-use iggy::prelude::MaxTopicSize;
+use iggy::prelude::{CleanupPolicy, MaxTopicSize};
 use predicates::str::diff;
 use serial_test::parallel;
 
@@ -103,6 +103,7 @@ impl IggyCmdTestCase for TestConsumerGroupDeleteCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
@@ -114,6 +115,8 @@ impl IggyCmdTestCase for TestConsumerGroupDeleteCmd {
                 &self.stream_id.try_into().unwrap(),
                 &self.topic_id.try_into().unwrap(),
                 &self.group_name,
+                None,
+                None,
             )
             .await;
         assert!(consumer_group.is_ok());
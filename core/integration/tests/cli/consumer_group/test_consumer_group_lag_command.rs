@@ -0,0 +1,228 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    CLAP_INDENT, IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::*;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestConsumerGroupLagCmd {
+    stream_name: String,
+    topic_name: String,
+    group_name: String,
+    messages_count: u32,
+    stored_offset: u64,
+}
+
+impl TestConsumerGroupLagCmd {
+    fn new(stream_name: String, topic_name: String, group_name: String) -> Self {
+        Self {
+            stream_name,
+            topic_name,
+            group_name,
+            messages_count: 100,
+            stored_offset: 66,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerGroupLagCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream_id = Identifier::named(&self.stream_name).unwrap();
+        let topic_id = Identifier::named(&self.topic_name).unwrap();
+        let group_id = Identifier::named(&self.group_name).unwrap();
+
+        let stream = client.create_stream(&self.stream_name).await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &stream_id,
+                &self.topic_name,
+                1,
+                Default::default(),
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let consumer_group = client
+            .create_consumer_group(&stream_id, &topic_id, &self.group_name, None, None)
+            .await;
+        assert!(consumer_group.is_ok());
+
+        let mut messages = (1..=self.messages_count)
+            .filter_map(|id| IggyMessage::from_str(format!("Test message {id}").as_str()).ok())
+            .collect::<Vec<_>>();
+        let send_status = client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(0),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+
+        let offset = client
+            .store_consumer_offset(
+                &Consumer::group(group_id),
+                &stream_id,
+                &topic_id,
+                Some(0),
+                self.stored_offset,
+            )
+            .await;
+        assert!(offset.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("consumer-group")
+            .arg("lag")
+            .arg(self.stream_name.clone())
+            .arg(self.topic_name.clone())
+            .arg(self.group_name.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let start_message = format!(
+            "Executing get lag of consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.group_name, self.topic_name, self.stream_name
+        );
+        let lag = self.messages_count as u64 - 1 - self.stored_offset;
+
+        command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(contains(format!(
+                "| 0         | {}               | {}             | {lag}  |",
+                self.stored_offset,
+                self.messages_count - 1
+            )))
+            .stdout(contains("| Total     |"));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .delete_stream(&Identifier::named(&self.stream_name).unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupLagCmd::new(
+            String::from("lag-stream"),
+            String::from("lag-topic"),
+            String::from("lag-group"),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "lag", "--help"],
+            format!(
+                r#"Get lag of a consumer group with given ID for given stream ID and topic ID
+
+For each partition, the lag is the count of messages not yet consumed by the consumer group,
+and the lag time is the age of the oldest of them.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+Consumer group ID can be specified as a consumer group name or ID
+
+Examples:
+ iggy consumer-group lag 1 2 3
+ iggy consumer-group lag stream 2 3
+ iggy consumer-group lag stream topic group
+
+{USAGE_PREFIX} consumer-group lag <STREAM_ID> <TOPIC_ID> <GROUP_ID>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to get consumer group lag
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to get consumer group lag
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <GROUP_ID>
+          Consumer group ID to get lag
+{CLAP_INDENT}
+          Consumer group ID can be specified as a consumer group name or ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "lag", "-h"],
+            format!(
+                r#"Get lag of a consumer group with given ID for given stream ID and topic ID
+
+{USAGE_PREFIX} consumer-group lag <STREAM_ID> <TOPIC_ID> <GROUP_ID>
+
+Arguments:
+  <STREAM_ID>  Stream ID to get consumer group lag
+  <TOPIC_ID>   Topic ID to get consumer group lag
+  <GROUP_ID>   Consumer group ID to get lag
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -24,7 +24,7 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::prelude::Client;
 use iggy::prelude::IggyExpiry;
-use iggy::prelude::MaxTopicSize;
+use iggy::prelude::{CleanupPolicy, MaxTopicSize};
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
 
@@ -100,6 +100,7 @@ impl IggyCmdTestCase for TestConsumerGroupListCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await
             .expect("Failed to create topic");
@@ -111,6 +112,8 @@ impl IggyCmdTestCase for TestConsumerGroupListCmd {
                 &stream.id.try_into().unwrap(),
                 &topic.id.try_into().unwrap(),
                 &self.consumer_group_name,
+                None,
+                None,
             )
             .await
             .expect("Failed to create consumer group");
@@ -110,6 +110,7 @@ impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await
             .expect("Failed to create topic");
@@ -111,6 +111,7 @@ impl IggyCmdTestCase for TestConsumerOffsetSetCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
@@ -43,6 +43,7 @@ Commands:
   snapshot         collect iggy server troubleshooting data
   pat              personal access token operations
   user             user operations [aliases: u]
+  role             role operations [aliases: r]
   client           client operations [aliases: c]
   cluster          cluster operations [aliases: cl]
   consumer-group   consumer group operations [aliases: g]
@@ -207,6 +208,13 @@ Options:
           If not provided, user will be prompted interactively to enter the
           password securely.
 
+      --scram
+          Use SCRAM-SHA-256 challenge-response login
+{CLAP_INDENT}
+          The password is not sent to the server, which allows to
+          authenticate securely over plain TCP, QUIC or WebSocket connections.
+          Not supported by the HTTP transport.
+
   -t, --token <TOKEN>
           Iggy server personal access token
 
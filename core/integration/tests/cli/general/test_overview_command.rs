@@ -54,6 +54,7 @@ Commands:
   snapshot         collect iggy server troubleshooting data
   pat              personal access token operations
   user             user operations [aliases: u]
+  role             role operations [aliases: r]
   client           client operations [aliases: c]
   cluster          cluster operations [aliases: cl]
   consumer-group   consumer group operations [aliases: g]
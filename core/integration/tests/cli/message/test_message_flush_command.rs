@@ -24,7 +24,7 @@ use async_trait::async_trait;
 use iggy::prelude::Client;
 use iggy::prelude::Identifier;
 use iggy::prelude::IggyExpiry;
-use iggy::prelude::MaxTopicSize;
+use iggy::prelude::{CleanupPolicy, MaxTopicSize};
 use predicates::str::diff;
 use serial_test::parallel;
 use std::str::FromStr;
@@ -106,6 +106,7 @@ impl IggyCmdTestCase for TestMessageFetchCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await
             .expect("Failed to create topic");
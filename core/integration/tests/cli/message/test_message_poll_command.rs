@@ -130,6 +130,7 @@ impl IggyCmdTestCase for TestMessagePollCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
@@ -320,6 +321,7 @@ Examples:
  iggy message poll --offset 0 stream 2 1
  iggy message poll --offset 0 1 topic 1
  iggy message poll --offset 0 stream topic 1
+ iggy message poll --offset 0 --filter 'tenant == "acme"' stream topic 1
 
 {USAGE_PREFIX} message poll [OPTIONS] <--offset <OFFSET>|--first|--last|--next> <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
 
@@ -379,6 +381,13 @@ Options:
           Flag indicates whether to include headers in the output
           after polling the messages.
 
+      --filter <FILTER>
+          Poll only the messages with the user headers matching the filter
+{CLAP_INDENT}
+          Filter is evaluated by the server, the headers are compared
+          with the string, integer, float or boolean literals,
+          e.g. 'tenant == "acme" && (priority >= 5 || urgent)'.
+
       --output-file <OUTPUT_FILE>
           Store polled message into file in binary format
 {CLAP_INDENT}
@@ -424,6 +433,7 @@ Options:
   -n, --next                           Polling strategy - start polling from the next message
   -c, --consumer <CONSUMER>            Regular consumer which will poll messages [default: 0]
   -s, --show-headers                   Include the message headers in the output
+      --filter <FILTER>                Poll only the messages with the user headers matching the filter
       --output-file <OUTPUT_FILE>      Store polled message into file in binary format
   -h, --help                           Print help (see more with '--help')
 "#,
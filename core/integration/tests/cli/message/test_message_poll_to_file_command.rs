@@ -123,6 +123,7 @@ impl IggyCmdTestCase for TestMessagePollToFileCmd<'_> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
@@ -168,6 +168,7 @@ impl IggyCmdTestCase for TestMessageSendCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await
             .expect("Failed to create topic");
@@ -376,6 +377,7 @@ Examples
  iggy message send stream 2 "long message"
  iggy message send 1 topic message1 message2 message3
  iggy message send stream topic "long message with spaces"
+ iggy message send --delay 30s stream topic "delayed message"
 
 {USAGE_PREFIX} message send [OPTIONS] <STREAM_ID> <TOPIC_ID> [MESSAGES]...
 
@@ -425,6 +427,14 @@ Options:
           will be read from the file and sent as is. Option cannot be used
           with the messages option (messages given as command line arguments).
 
+      --delay <DELAY>
+          Delay after which the messages become visible to the consumers
+{CLAP_INDENT}
+          Delay is a human readable duration, e.g. 30s, 5m or "1h 30m". Messages
+          are appended to the partition right away, but polling stops before them
+          until the delay elapses. Messages read from the input file which already
+          have the delivery time set are sent as is.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -456,6 +466,7 @@ Options:
   -m, --message-key <MESSAGE_KEY>    Messages key which will be used to partition the messages
   -H, --headers <HEADERS>            Comma separated list of key:kind:value, sent as header with the message
       --input-file <INPUT_FILE>      Input file with messages to be sent
+      --delay <DELAY>                Delay after which the messages become visible to the consumers
   -h, --help                         Print help (see more with '--help')
 "#,
             ),
@@ -111,6 +111,7 @@ impl IggyCmdTestCase for TestMessageSendFromFileCmd<'_> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await
             .expect("Failed to create topic");
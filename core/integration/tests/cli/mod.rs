@@ -25,6 +25,7 @@ mod general;
 mod message;
 mod partition;
 mod personal_access_token;
+mod role;
 mod stream;
 mod system;
 mod topic;
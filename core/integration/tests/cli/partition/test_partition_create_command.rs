@@ -24,7 +24,7 @@ use async_trait::async_trait;
 use iggy::prelude::Client;
 use iggy::prelude::CompressionAlgorithm;
 use iggy::prelude::IggyExpiry;
-use iggy::prelude::MaxTopicSize;
+use iggy::prelude::{CleanupPolicy, MaxTopicSize};
 use predicates::str::diff;
 use serial_test::parallel;
 
@@ -97,6 +97,7 @@ impl IggyCmdTestCase for TestPartitionCreateCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
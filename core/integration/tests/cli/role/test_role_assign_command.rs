@@ -0,0 +1,168 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    CLAP_INDENT, IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::Client;
+use iggy::prelude::{Identifier, UserStatus};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestRoleAssignCmd {
+    username: String,
+    role_name: String,
+    user_id: Option<u32>,
+}
+
+impl TestRoleAssignCmd {
+    fn new(username: String, role_name: String) -> Self {
+        Self {
+            username,
+            role_name,
+            user_id: None,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestRoleAssignCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let user = client
+            .create_user(&self.username, "secret", UserStatus::Active, None)
+            .await;
+        assert!(user.is_ok());
+        self.user_id = Some(user.unwrap().id);
+        let role = client.create_role(&self.role_name, None).await;
+        assert!(role.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("role")
+            .arg("assign")
+            .arg(self.username.clone())
+            .arg(self.role_name.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(diff(format!(
+            "Executing assign role with ID: {} to user with ID: {}\nRole with ID: {} assigned to user with ID: {}\n",
+            self.role_name, self.username, self.role_name, self.username
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let role = client
+            .get_role(&self.role_name.as_str().try_into().unwrap())
+            .await;
+        assert!(role.is_ok());
+        let role = role.unwrap().expect("Role not found");
+        assert_eq!(role.users, vec![self.user_id.unwrap()]);
+
+        let deleted = client
+            .delete_role(&Identifier::numeric(role.id).unwrap())
+            .await;
+        assert!(deleted.is_ok());
+        let deleted = client
+            .delete_user(&self.username.as_str().try_into().unwrap())
+            .await;
+        assert!(deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestRoleAssignCmd::new(
+            String::from("role_user"),
+            String::from("readers"),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["role", "assign", "--help"],
+            format!(
+                r"Assign role with given ID to user with given ID
+
+Both the user ID and the role ID can be specified as either a name or an ID
+
+Examples:
+ iggy role assign testuser readers
+ iggy role assign 2 1
+
+{USAGE_PREFIX} role assign <USER_ID> <ROLE_ID>
+
+Arguments:
+  <USER_ID>
+          User ID
+{CLAP_INDENT}
+          The user ID can be specified as either a username or an ID
+
+  <ROLE_ID>
+          Role ID
+{CLAP_INDENT}
+          The role ID can be specified as either a role name or an ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+",
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["role", "assign", "-h"],
+            format!(
+                r#"Assign role with given ID to user with given ID
+
+{USAGE_PREFIX} role assign <USER_ID> <ROLE_ID>
+
+Arguments:
+  <USER_ID>  User ID
+  <ROLE_ID>  Role ID
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
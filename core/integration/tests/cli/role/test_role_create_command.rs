@@ -0,0 +1,147 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::Client;
+use iggy::prelude::{GlobalPermissions, Identifier, Permissions};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestRoleCreateCmd {
+    name: String,
+    global_permissions: Option<String>,
+    expected_permissions: Option<Permissions>,
+}
+
+impl TestRoleCreateCmd {
+    fn new(
+        name: String,
+        global_permissions: Option<String>,
+        expected_permissions: Option<Permissions>,
+    ) -> Self {
+        Self {
+            name,
+            global_permissions,
+            expected_permissions,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = vec![self.name.clone()];
+        if let Some(global_permissions) = &self.global_permissions {
+            args.push(String::from("--global-permissions"));
+            args.push(global_permissions.clone());
+        }
+
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestRoleCreateCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("role")
+            .arg("create")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(diff(format!(
+            "Executing create role with name: {}\nRole with name: {} created\n",
+            self.name, self.name
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let role = client
+            .get_role(&self.name.as_str().try_into().unwrap())
+            .await;
+        assert!(role.is_ok());
+        let role = role.unwrap().expect("Role not found");
+        assert_eq!(role.name, self.name);
+        assert_eq!(role.permissions, self.expected_permissions);
+        assert!(role.users.is_empty());
+
+        let deleted = client
+            .delete_role(&Identifier::numeric(role.id).unwrap())
+            .await;
+        assert!(deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestRoleCreateCmd::new(
+            String::from("empty_role"),
+            None,
+            None,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestRoleCreateCmd::new(
+            String::from("clients"),
+            Some(String::from("p_msg,s_msg")),
+            Some(Permissions {
+                global: GlobalPermissions {
+                    poll_messages: true,
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+                stream_patterns: None,
+            }),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["role", "create", "-h"],
+            format!(
+                r#"Create role with given name and permissions
+
+{USAGE_PREFIX} role create [OPTIONS] <NAME>
+
+Arguments:
+  <NAME>  Role name
+
+Options:
+  -g, --global-permissions <GLOBAL_PERMISSIONS>  Set global permissions for created role
+  -s, --stream-permissions <STREAM_PERMISSIONS>  Set stream permissions for created role
+  -h, --help                                     Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
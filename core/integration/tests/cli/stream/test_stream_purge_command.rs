@@ -67,6 +67,7 @@ impl IggyCmdTestCase for TestStreamPurgeCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
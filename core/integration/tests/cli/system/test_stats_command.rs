@@ -25,8 +25,8 @@ use iggy::prelude::Client;
 use iggy::prelude::Identifier;
 use iggy::prelude::IggyExpiry;
 use iggy::prelude::IggyMessage;
-use iggy::prelude::MaxTopicSize;
 use iggy::prelude::Partitioning;
+use iggy::prelude::{CleanupPolicy, MaxTopicSize};
 use iggy_cli::commands::binary_system::stats::GetStatsOutput;
 use iggy_common::Stats;
 use predicates::str::{contains, starts_with};
@@ -80,6 +80,7 @@ impl IggyCmdTestCase for TestStatsCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
@@ -190,6 +191,7 @@ impl IggyCmdTestCase for TestStatsCmdWithMessages {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
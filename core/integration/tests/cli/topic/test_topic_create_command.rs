@@ -301,6 +301,13 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --cleanup-policy <CLEANUP_POLICY>
+          Cleanup policy for the topic, either "delete" or "compact"
+{CLAP_INDENT}
+          "compact" keeps only the latest message for each message key in sealed segments
+{CLAP_INDENT}
+          [default: delete]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -332,6 +339,7 @@ Options:
   -t, --topic-id <TOPIC_ID>                      Topic ID to create
   -m, --max-topic-size <MAX_TOPIC_SIZE>          Max topic size in human-readable format like "unlimited" or "15GB" [default: server_default]
   -r, --replication-factor <REPLICATION_FACTOR>  Replication factor for the topic [default: 1]
+      --cleanup-policy <CLEANUP_POLICY>          Cleanup policy for the topic, either "delete" or "compact" [default: delete]
   -h, --help                                     Print help (see more with '--help')
 "#,
             ),
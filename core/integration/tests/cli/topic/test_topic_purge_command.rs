@@ -85,6 +85,7 @@ impl IggyCmdTestCase for TestTopicPurgeCmd {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
@@ -27,7 +27,7 @@ use iggy::prelude::Client;
 use iggy::prelude::CompressionAlgorithm;
 use iggy::prelude::IggyByteSize;
 use iggy::prelude::IggyExpiry;
-use iggy::prelude::MaxTopicSize;
+use iggy::prelude::{CleanupPolicy, MaxTopicSize};
 use predicates::str::diff;
 use serial_test::parallel;
 use std::str::FromStr;
@@ -144,6 +144,7 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
                 Some(self.replication_factor),
                 message_expiry,
                 self.max_topic_size,
+                CleanupPolicy::Delete,
             )
             .await;
         assert!(topic.is_ok());
@@ -344,6 +345,11 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --cleanup-policy <CLEANUP_POLICY>
+          New cleanup policy for the topic, either "delete" or "compact"
+{CLAP_INDENT}
+          [default: delete]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -375,6 +381,7 @@ Arguments:
 Options:
   -m, --max-topic-size <MAX_TOPIC_SIZE>          New max topic size in human-readable format like "unlimited" or "15GB" [default: server_default]
   -r, --replication-factor <REPLICATION_FACTOR>  New replication factor for the topic [default: 1]
+      --cleanup-policy <CLEANUP_POLICY>          New cleanup policy for the topic, either "delete" or "compact" [default: delete]
   -h, --help                                     Print help (see more with '--help')
 "#,
             ),
@@ -174,6 +174,7 @@ pub async fn should_be_successful() {
                         send_messages: false,
                     },
                     streams: None,
+                    stream_patterns: None,
                 }),
             ),
         ))
@@ -189,6 +190,7 @@ pub async fn should_be_successful() {
                 Some(Permissions {
                     global: GlobalPermissions::default(),
                     streams: Some(BTreeMap::from([(3usize, StreamPermissions::default())])),
+                    stream_patterns: None,
                 }),
             ),
         ))
@@ -213,11 +215,13 @@ pub async fn should_be_successful() {
                                     read_topic: true,
                                     poll_messages: true,
                                     send_messages: true,
+                                    consumer_groups: None,
                                 },
                             )])),
                             ..Default::default()
                         },
                     )])),
+                    stream_patterns: None,
                 }),
             ),
         ))
@@ -253,11 +257,13 @@ pub async fn should_be_successful() {
                                     read_topic: false,
                                     poll_messages: true,
                                     send_messages: true,
+                                    consumer_groups: None,
                                 },
                             )])),
                             ..Default::default()
                         },
                     )])),
+                    stream_patterns: None,
                 }),
             ),
         ))
@@ -146,6 +146,7 @@ pub async fn should_be_successful() {
                         send_messages: true,
                     },
                     streams: None,
+                    stream_patterns: None,
                 }),
             ),
             TestUserId::Named,
@@ -160,6 +161,7 @@ pub async fn should_be_successful() {
                 Some(Permissions {
                     global: GlobalPermissions::default(),
                     streams: Some(BTreeMap::from([(3usize, StreamPermissions::default())])),
+                    stream_patterns: None,
                 }),
             ),
             TestUserId::Numeric,
@@ -183,11 +185,13 @@ pub async fn should_be_successful() {
                                     read_topic: true,
                                     poll_messages: true,
                                     send_messages: true,
+                                    consumer_groups: None,
                                 },
                             )])),
                             ..Default::default()
                         },
                     )])),
+                    stream_patterns: None,
                 }),
             ),
             TestUserId::Named,
@@ -222,11 +226,13 @@ pub async fn should_be_successful() {
                                     read_topic: false,
                                     poll_messages: true,
                                     send_messages: true,
+                                    consumer_groups: None,
                                 },
                             )])),
                             ..Default::default()
                         },
                     )])),
+                    stream_patterns: None,
                 }),
             ),
             TestUserId::Named,
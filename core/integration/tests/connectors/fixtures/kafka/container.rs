@@ -0,0 +1,261 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use integration::harness::TestBinaryError;
+use rdkafka::admin::{AdminClient, AdminOptions, NewTopic, TopicReplication};
+use rdkafka::client::DefaultClientContext;
+use rdkafka::consumer::{Consumer, StreamConsumer};
+use rdkafka::message::{Header, Headers, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::{ClientConfig, Message, Offset, TopicPartitionList};
+use std::net::TcpListener;
+use std::time::Duration;
+use testcontainers_modules::testcontainers::core::{IntoContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use testcontainers_modules::testcontainers::{ContainerAsync, GenericImage, ImageExt};
+use tokio::time::timeout;
+use tracing::info;
+
+const REDPANDA_IMAGE: &str = "redpandadata/redpanda";
+const REDPANDA_TAG: &str = "v24.2.4";
+const REDPANDA_KAFKA_PORT: u16 = 9092;
+const REDPANDA_READY_MSG: &str = "Successfully started Redpanda!";
+const KAFKA_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub(super) const DEFAULT_TEST_STREAM: &str = "test_stream";
+pub(super) const DEFAULT_TEST_TOPIC: &str = "test_topic";
+pub(super) const DEFAULT_KAFKA_TOPIC: &str = "iggy_kafka_topic";
+
+// Source env vars
+pub(super) const ENV_SOURCE_BOOTSTRAP_SERVERS: &str =
+    "IGGY_CONNECTORS_SOURCE_KAFKA_PLUGIN_CONFIG_BOOTSTRAP_SERVERS";
+pub(super) const ENV_SOURCE_TOPICS: &str = "IGGY_CONNECTORS_SOURCE_KAFKA_PLUGIN_CONFIG_TOPICS";
+pub(super) const ENV_SOURCE_INITIAL_OFFSET: &str =
+    "IGGY_CONNECTORS_SOURCE_KAFKA_PLUGIN_CONFIG_INITIAL_OFFSET";
+pub(super) const ENV_SOURCE_POLL_TIMEOUT: &str =
+    "IGGY_CONNECTORS_SOURCE_KAFKA_PLUGIN_CONFIG_POLL_TIMEOUT";
+pub(super) const ENV_SOURCE_STREAMS_0_STREAM: &str =
+    "IGGY_CONNECTORS_SOURCE_KAFKA_STREAMS_0_STREAM";
+pub(super) const ENV_SOURCE_STREAMS_0_TOPIC: &str = "IGGY_CONNECTORS_SOURCE_KAFKA_STREAMS_0_TOPIC";
+pub(super) const ENV_SOURCE_STREAMS_0_SCHEMA: &str =
+    "IGGY_CONNECTORS_SOURCE_KAFKA_STREAMS_0_SCHEMA";
+pub(super) const ENV_SOURCE_PATH: &str = "IGGY_CONNECTORS_SOURCE_KAFKA_PATH";
+
+// Sink env vars
+pub(super) const ENV_SINK_BOOTSTRAP_SERVERS: &str =
+    "IGGY_CONNECTORS_SINK_KAFKA_PLUGIN_CONFIG_BOOTSTRAP_SERVERS";
+pub(super) const ENV_SINK_TOPIC: &str = "IGGY_CONNECTORS_SINK_KAFKA_PLUGIN_CONFIG_TOPIC";
+pub(super) const ENV_SINK_STREAMS_0_STREAM: &str = "IGGY_CONNECTORS_SINK_KAFKA_STREAMS_0_STREAM";
+pub(super) const ENV_SINK_STREAMS_0_TOPICS: &str = "IGGY_CONNECTORS_SINK_KAFKA_STREAMS_0_TOPICS";
+pub(super) const ENV_SINK_STREAMS_0_SCHEMA: &str = "IGGY_CONNECTORS_SINK_KAFKA_STREAMS_0_SCHEMA";
+pub(super) const ENV_SINK_STREAMS_0_CONSUMER_GROUP: &str =
+    "IGGY_CONNECTORS_SINK_KAFKA_STREAMS_0_CONSUMER_GROUP";
+pub(super) const ENV_SINK_PATH: &str = "IGGY_CONNECTORS_SINK_KAFKA_PATH";
+
+/// Kafka record as produced to or consumed from the Redpanda container.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KafkaRecord {
+    pub key: Option<Vec<u8>>,
+    pub headers: Vec<(String, Vec<u8>)>,
+    pub payload: Vec<u8>,
+}
+
+/// Base container management for the Kafka fixtures, using Redpanda as the broker.
+pub struct KafkaContainer {
+    #[allow(dead_code)]
+    container: ContainerAsync<GenericImage>,
+    pub(super) bootstrap_servers: String,
+}
+
+impl KafkaContainer {
+    pub(super) async fn start() -> Result<Self, TestBinaryError> {
+        // Kafka clients connect to the advertised address, so the host port must be known
+        // before the broker starts.
+        let host_port = TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .map(|address| address.port())
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "KafkaContainer".to_string(),
+                message: format!("Failed to find a free port: {e}"),
+            })?;
+        let bootstrap_servers = format!("localhost:{host_port}");
+
+        let container = GenericImage::new(REDPANDA_IMAGE, REDPANDA_TAG)
+            .with_exposed_port(REDPANDA_KAFKA_PORT.tcp())
+            .with_wait_for(WaitFor::message_on_stderr(REDPANDA_READY_MSG))
+            .with_mapped_port(host_port, REDPANDA_KAFKA_PORT.tcp())
+            .with_cmd([
+                "redpanda".to_string(),
+                "start".to_string(),
+                "--mode=dev-container".to_string(),
+                "--smp=1".to_string(),
+                format!("--kafka-addr=PLAINTEXT://0.0.0.0:{REDPANDA_KAFKA_PORT}"),
+                format!("--advertise-kafka-addr=PLAINTEXT://{bootstrap_servers}"),
+            ])
+            .start()
+            .await
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "KafkaContainer".to_string(),
+                message: format!("Failed to start container: {e}"),
+            })?;
+
+        info!("Redpanda container available at {bootstrap_servers}");
+
+        Ok(Self {
+            container,
+            bootstrap_servers,
+        })
+    }
+
+    fn client_config(&self) -> ClientConfig {
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", &self.bootstrap_servers);
+        config
+    }
+
+    pub(super) async fn create_topic(&self, topic: &str) -> Result<(), TestBinaryError> {
+        let admin: AdminClient<DefaultClientContext> =
+            self.client_config()
+                .create()
+                .map_err(|e| TestBinaryError::FixtureSetup {
+                    fixture_type: "KafkaContainer".to_string(),
+                    message: format!("Failed to create admin client: {e}"),
+                })?;
+        let results = admin
+            .create_topics(
+                &[NewTopic::new(topic, 1, TopicReplication::Fixed(1))],
+                &AdminOptions::new().operation_timeout(Some(KAFKA_TIMEOUT)),
+            )
+            .await
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "KafkaContainer".to_string(),
+                message: format!("Failed to create topic {topic}: {e}"),
+            })?;
+        for result in results {
+            result.map_err(|(topic, e)| TestBinaryError::FixtureSetup {
+                fixture_type: "KafkaContainer".to_string(),
+                message: format!("Failed to create topic {topic}: {e}"),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Common Kafka operations for fixtures.
+pub trait KafkaOps: Sync {
+    fn container(&self) -> &KafkaContainer;
+
+    fn produce_records(
+        &self,
+        topic: &str,
+        records: &[KafkaRecord],
+    ) -> impl std::future::Future<Output = Result<(), TestBinaryError>> + Send {
+        async move {
+            let producer: FutureProducer =
+                self.container().client_config().create().map_err(|e| {
+                    TestBinaryError::InvalidState {
+                        message: format!("Failed to create Kafka producer: {e}"),
+                    }
+                })?;
+            for record in records {
+                let mut headers = OwnedHeaders::new();
+                for (key, value) in &record.headers {
+                    headers = headers.insert(Header {
+                        key,
+                        value: Some(value),
+                    });
+                }
+                let mut kafka_record = FutureRecord::to(topic)
+                    .payload(&record.payload)
+                    .headers(headers);
+                if let Some(key) = &record.key {
+                    kafka_record = kafka_record.key(key);
+                }
+                producer
+                    .send(kafka_record, KAFKA_TIMEOUT)
+                    .await
+                    .map_err(|(e, _)| TestBinaryError::InvalidState {
+                        message: format!("Failed to produce Kafka record: {e}"),
+                    })?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Consume records from the beginning of the topic until the expected count is reached.
+    fn consume_records(
+        &self,
+        topic: &str,
+        expected: usize,
+    ) -> impl std::future::Future<Output = Result<Vec<KafkaRecord>, TestBinaryError>> + Send {
+        async move {
+            let consumer: StreamConsumer = self
+                .container()
+                .client_config()
+                .set("group.id", "iggy-integration-tests")
+                .set("enable.auto.commit", "false")
+                .create()
+                .map_err(|e| TestBinaryError::InvalidState {
+                    message: format!("Failed to create Kafka consumer: {e}"),
+                })?;
+            let mut assignment = TopicPartitionList::new();
+            assignment
+                .add_partition_offset(topic, 0, Offset::Beginning)
+                .and_then(|_| consumer.assign(&assignment))
+                .map_err(|e| TestBinaryError::InvalidState {
+                    message: format!("Failed to assign Kafka topic {topic}: {e}"),
+                })?;
+
+            let mut records = Vec::new();
+            while records.len() < expected {
+                let message = timeout(KAFKA_TIMEOUT, consumer.recv())
+                    .await
+                    .map_err(|_| TestBinaryError::InvalidState {
+                        message: format!(
+                            "Expected {expected} Kafka records in {topic}, got {}",
+                            records.len()
+                        ),
+                    })?
+                    .map_err(|e| TestBinaryError::InvalidState {
+                        message: format!("Failed to consume Kafka record: {e}"),
+                    })?;
+                let headers = message
+                    .headers()
+                    .map(|headers| {
+                        headers
+                            .iter()
+                            .map(|header| {
+                                (
+                                    header.key.to_string(),
+                                    header.value.unwrap_or_default().to_vec(),
+                                )
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                records.push(KafkaRecord {
+                    key: message.key().map(|key| key.to_vec()),
+                    headers,
+                    payload: message.payload().unwrap_or_default().to_vec(),
+                });
+            }
+            Ok(records)
+        }
+    }
+}
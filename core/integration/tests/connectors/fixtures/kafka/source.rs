@@ -0,0 +1,86 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::container::{
+    DEFAULT_KAFKA_TOPIC, DEFAULT_TEST_STREAM, DEFAULT_TEST_TOPIC, ENV_SOURCE_BOOTSTRAP_SERVERS,
+    ENV_SOURCE_INITIAL_OFFSET, ENV_SOURCE_PATH, ENV_SOURCE_POLL_TIMEOUT,
+    ENV_SOURCE_STREAMS_0_SCHEMA, ENV_SOURCE_STREAMS_0_STREAM, ENV_SOURCE_STREAMS_0_TOPIC,
+    ENV_SOURCE_TOPICS, KafkaContainer, KafkaOps,
+};
+use async_trait::async_trait;
+use integration::harness::{TestBinaryError, TestFixture};
+use std::collections::HashMap;
+
+/// Kafka source connector fixture mirroring a pre-created Kafka topic.
+pub struct KafkaSourceFixture {
+    container: KafkaContainer,
+}
+
+impl KafkaOps for KafkaSourceFixture {
+    fn container(&self) -> &KafkaContainer {
+        &self.container
+    }
+}
+
+impl KafkaSourceFixture {
+    pub fn kafka_topic(&self) -> &str {
+        DEFAULT_KAFKA_TOPIC
+    }
+}
+
+#[async_trait]
+impl TestFixture for KafkaSourceFixture {
+    async fn setup() -> Result<Self, TestBinaryError> {
+        let container = KafkaContainer::start().await?;
+        // The source assigns the partitions of the topics on open, so they must exist upfront.
+        container.create_topic(DEFAULT_KAFKA_TOPIC).await?;
+        Ok(Self { container })
+    }
+
+    fn connectors_runtime_envs(&self) -> HashMap<String, String> {
+        let mut envs = HashMap::new();
+        envs.insert(
+            ENV_SOURCE_BOOTSTRAP_SERVERS.to_string(),
+            self.container.bootstrap_servers.clone(),
+        );
+        envs.insert(
+            ENV_SOURCE_TOPICS.to_string(),
+            format!("[{DEFAULT_KAFKA_TOPIC}]"),
+        );
+        envs.insert(
+            ENV_SOURCE_INITIAL_OFFSET.to_string(),
+            "earliest".to_string(),
+        );
+        envs.insert(ENV_SOURCE_POLL_TIMEOUT.to_string(), "100ms".to_string());
+        envs.insert(
+            ENV_SOURCE_STREAMS_0_STREAM.to_string(),
+            DEFAULT_TEST_STREAM.to_string(),
+        );
+        envs.insert(
+            ENV_SOURCE_STREAMS_0_TOPIC.to_string(),
+            DEFAULT_TEST_TOPIC.to_string(),
+        );
+        envs.insert(ENV_SOURCE_STREAMS_0_SCHEMA.to_string(), "raw".to_string());
+        envs.insert(
+            ENV_SOURCE_PATH.to_string(),
+            "../../target/debug/libiggy_connector_kafka_source".to_string(),
+        );
+        envs
+    }
+}
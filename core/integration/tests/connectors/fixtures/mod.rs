@@ -22,9 +22,14 @@ mod elasticsearch;
 mod http;
 mod iceberg;
 mod influxdb;
+mod kafka;
 mod mongodb;
+mod mqtt;
+mod mysql;
 mod postgres;
 mod quickwit;
+mod redis;
+mod s3;
 mod wiremock;
 
 pub use delta::{DeltaFixture, DeltaS3Fixture};
@@ -41,14 +46,19 @@ pub use influxdb::{
     InfluxDbSinkNsPrecisionFixture, InfluxDbSinkTextFixture, InfluxDbSourceFixture,
     InfluxDbSourceRawFixture, InfluxDbSourceTextFixture,
 };
+pub use kafka::{KafkaOps, KafkaRecord, KafkaSinkFixture, KafkaSourceFixture};
 pub use mongodb::{
     MongoDbOps, MongoDbSinkAutoCreateFixture, MongoDbSinkBatchFixture, MongoDbSinkFailpointFixture,
     MongoDbSinkFixture, MongoDbSinkJsonFixture, MongoDbSinkWriteConcernFixture,
 };
+pub use mqtt::{MqttMessage, MqttOps, MqttSinkFixture, MqttSourceFixture};
+pub use mysql::{MySqlOps, MySqlSourceFixture};
 pub use postgres::{
     PostgresOps, PostgresSinkByteaFixture, PostgresSinkFixture, PostgresSinkJsonFixture,
     PostgresSourceByteaFixture, PostgresSourceDeleteFixture, PostgresSourceJsonFixture,
     PostgresSourceJsonbFixture, PostgresSourceMarkFixture, PostgresSourceOps,
 };
 pub use quickwit::{QuickwitFixture, QuickwitOps, QuickwitPreCreatedFixture};
+pub use redis::{RedisOps, RedisSinkFixture, RedisSourceFixture};
+pub use s3::{S3Ops, S3SinkLocalFixture, S3SinkMinioFixture};
 pub use wiremock::{WireMockDirectFixture, WireMockWrappedFixture};
@@ -0,0 +1,248 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use integration::harness::TestBinaryError;
+use rumqttc::v5::mqttbytes::QoS;
+use rumqttc::v5::mqttbytes::v5::{Packet, PublishProperties};
+use rumqttc::v5::{AsyncClient, Event, EventLoop, MqttOptions};
+use std::time::Duration;
+use testcontainers_modules::testcontainers::core::{IntoContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use testcontainers_modules::testcontainers::{ContainerAsync, GenericImage, ImageExt};
+use tokio::time::{Instant, timeout, timeout_at};
+use tracing::info;
+
+const MOSQUITTO_IMAGE: &str = "eclipse-mosquitto";
+const MOSQUITTO_TAG: &str = "2.0.20";
+const MOSQUITTO_PORT: u16 = 1883;
+const MOSQUITTO_READY_MSG: &str = "running";
+const MQTT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub(super) const DEFAULT_TEST_STREAM: &str = "test_stream";
+pub(super) const DEFAULT_TEST_TOPIC: &str = "test_topic";
+
+// Source env vars
+pub(super) const ENV_SOURCE_HOST: &str = "IGGY_CONNECTORS_SOURCE_MQTT_PLUGIN_CONFIG_HOST";
+pub(super) const ENV_SOURCE_PORT: &str = "IGGY_CONNECTORS_SOURCE_MQTT_PLUGIN_CONFIG_PORT";
+pub(super) const ENV_SOURCE_PATH: &str = "IGGY_CONNECTORS_SOURCE_MQTT_PATH";
+
+// Sink env vars
+pub(super) const ENV_SINK_HOST: &str = "IGGY_CONNECTORS_SINK_MQTT_PLUGIN_CONFIG_HOST";
+pub(super) const ENV_SINK_PORT: &str = "IGGY_CONNECTORS_SINK_MQTT_PLUGIN_CONFIG_PORT";
+pub(super) const ENV_SINK_TOPIC: &str = "IGGY_CONNECTORS_SINK_MQTT_PLUGIN_CONFIG_TOPIC";
+pub(super) const ENV_SINK_STREAMS_0_STREAM: &str = "IGGY_CONNECTORS_SINK_MQTT_STREAMS_0_STREAM";
+pub(super) const ENV_SINK_STREAMS_0_TOPICS: &str = "IGGY_CONNECTORS_SINK_MQTT_STREAMS_0_TOPICS";
+pub(super) const ENV_SINK_STREAMS_0_SCHEMA: &str = "IGGY_CONNECTORS_SINK_MQTT_STREAMS_0_SCHEMA";
+pub(super) const ENV_SINK_STREAMS_0_CONSUMER_GROUP: &str =
+    "IGGY_CONNECTORS_SINK_MQTT_STREAMS_0_CONSUMER_GROUP";
+pub(super) const ENV_SINK_PATH: &str = "IGGY_CONNECTORS_SINK_MQTT_PATH";
+
+/// MQTT message as published to or received from the Mosquitto container.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MqttMessage {
+    pub topic: String,
+    pub user_properties: Vec<(String, String)>,
+    pub payload: Vec<u8>,
+}
+
+/// Base container management for the MQTT fixtures, using Mosquitto as the broker.
+pub struct MqttContainer {
+    #[allow(dead_code)]
+    container: ContainerAsync<GenericImage>,
+    pub(super) port: u16,
+}
+
+impl MqttContainer {
+    pub(super) async fn start() -> Result<Self, TestBinaryError> {
+        let container = GenericImage::new(MOSQUITTO_IMAGE, MOSQUITTO_TAG)
+            .with_exposed_port(MOSQUITTO_PORT.tcp())
+            .with_wait_for(WaitFor::message_on_stderr(MOSQUITTO_READY_MSG))
+            .with_mapped_port(0, MOSQUITTO_PORT.tcp())
+            .with_cmd(["mosquitto", "-c", "/mosquitto-no-auth.conf"])
+            .start()
+            .await
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "MqttContainer".to_string(),
+                message: format!("Failed to start container: {e}"),
+            })?;
+
+        let port = container
+            .ports()
+            .await
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "MqttContainer".to_string(),
+                message: format!("Failed to get ports: {e}"),
+            })?
+            .map_to_host_port_ipv4(MOSQUITTO_PORT)
+            .ok_or_else(|| TestBinaryError::FixtureSetup {
+                fixture_type: "MqttContainer".to_string(),
+                message: "No mapping for MQTT port".to_string(),
+            })?;
+
+        info!("Mosquitto container available at localhost:{port}");
+
+        Ok(Self { container, port })
+    }
+
+    async fn connect(&self, client_id: &str) -> Result<(AsyncClient, EventLoop), TestBinaryError> {
+        let options = MqttOptions::new(client_id, "localhost", self.port);
+        let (client, mut event_loop) = AsyncClient::new(options, 100);
+        timeout(MQTT_TIMEOUT, async {
+            loop {
+                match event_loop.poll().await {
+                    Ok(Event::Incoming(Packet::ConnAck(_))) => return Ok(()),
+                    Ok(_) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        })
+        .await
+        .map_err(|_| TestBinaryError::InvalidState {
+            message: "Timed out connecting to MQTT broker".to_string(),
+        })?
+        .map_err(|e| TestBinaryError::InvalidState {
+            message: format!("Failed to connect to MQTT broker: {e}"),
+        })?;
+        Ok((client, event_loop))
+    }
+}
+
+/// Subscription to the MQTT broker, collecting the messages published to the filter.
+pub struct MqttSubscriber {
+    #[allow(dead_code)]
+    client: AsyncClient,
+    event_loop: EventLoop,
+}
+
+impl MqttSubscriber {
+    /// Receive the messages until the expected count is reached.
+    pub async fn receive(&mut self, expected: usize) -> Result<Vec<MqttMessage>, TestBinaryError> {
+        let deadline = Instant::now() + MQTT_TIMEOUT;
+        let mut messages = Vec::new();
+        while messages.len() < expected {
+            let event = timeout_at(deadline, self.event_loop.poll())
+                .await
+                .map_err(|_| TestBinaryError::InvalidState {
+                    message: format!("Expected {expected} MQTT messages, got {}", messages.len()),
+                })?
+                .map_err(|e| TestBinaryError::InvalidState {
+                    message: format!("Failed to receive MQTT message: {e}"),
+                })?;
+            if let Event::Incoming(Packet::Publish(publish)) = event {
+                messages.push(MqttMessage {
+                    topic: String::from_utf8_lossy(&publish.topic).into_owned(),
+                    user_properties: publish
+                        .properties
+                        .map(|properties| properties.user_properties)
+                        .unwrap_or_default(),
+                    payload: publish.payload.to_vec(),
+                });
+            }
+        }
+        Ok(messages)
+    }
+}
+
+/// Common MQTT operations for fixtures.
+pub trait MqttOps: Sync {
+    fn container(&self) -> &MqttContainer;
+
+    fn publish_messages(
+        &self,
+        messages: &[MqttMessage],
+    ) -> impl std::future::Future<Output = Result<(), TestBinaryError>> + Send {
+        async move {
+            let (client, mut event_loop) = self.container().connect("iggy-test-publisher").await?;
+            for message in messages {
+                let properties = PublishProperties {
+                    user_properties: message.user_properties.clone(),
+                    ..PublishProperties::default()
+                };
+                client
+                    .publish_with_properties(
+                        message.topic.clone(),
+                        QoS::AtLeastOnce,
+                        false,
+                        message.payload.clone(),
+                        properties,
+                    )
+                    .await
+                    .map_err(|e| TestBinaryError::InvalidState {
+                        message: format!("Failed to publish MQTT message: {e}"),
+                    })?;
+            }
+
+            // Drive the connection until the broker acknowledges all the messages.
+            let mut acknowledged = 0;
+            timeout(MQTT_TIMEOUT, async {
+                while acknowledged < messages.len() {
+                    match event_loop.poll().await {
+                        Ok(Event::Incoming(Packet::PubAck(_))) => acknowledged += 1,
+                        Ok(_) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                Ok(())
+            })
+            .await
+            .map_err(|_| TestBinaryError::InvalidState {
+                message: format!(
+                    "Expected {} MQTT acknowledgements, got {acknowledged}",
+                    messages.len()
+                ),
+            })?
+            .map_err(|e| TestBinaryError::InvalidState {
+                message: format!("Failed to publish MQTT messages: {e}"),
+            })?;
+            Ok(())
+        }
+    }
+
+    fn subscribe(
+        &self,
+        filter: &str,
+    ) -> impl std::future::Future<Output = Result<MqttSubscriber, TestBinaryError>> + Send {
+        async move {
+            let (client, mut event_loop) = self.container().connect("iggy-test-subscriber").await?;
+            client
+                .subscribe(filter, QoS::AtLeastOnce)
+                .await
+                .map_err(|e| TestBinaryError::InvalidState {
+                    message: format!("Failed to subscribe to MQTT topic {filter}: {e}"),
+                })?;
+            timeout(MQTT_TIMEOUT, async {
+                loop {
+                    match event_loop.poll().await {
+                        Ok(Event::Incoming(Packet::SubAck(_))) => return Ok(()),
+                        Ok(_) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+            })
+            .await
+            .map_err(|_| TestBinaryError::InvalidState {
+                message: format!("Timed out subscribing to MQTT topic {filter}"),
+            })?
+            .map_err(|e| TestBinaryError::InvalidState {
+                message: format!("Failed to subscribe to MQTT topic {filter}: {e}"),
+            })?;
+            Ok(MqttSubscriber { client, event_loop })
+        }
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::container::{
+    DEFAULT_TEST_STREAM, DEFAULT_TEST_TOPIC, ENV_SINK_HOST, ENV_SINK_PATH, ENV_SINK_PORT,
+    ENV_SINK_STREAMS_0_CONSUMER_GROUP, ENV_SINK_STREAMS_0_SCHEMA, ENV_SINK_STREAMS_0_STREAM,
+    ENV_SINK_STREAMS_0_TOPICS, ENV_SINK_TOPIC, MqttContainer, MqttOps,
+};
+use async_trait::async_trait;
+use integration::harness::{TestBinaryError, TestFixture};
+use std::collections::HashMap;
+
+/// MQTT sink connector fixture publishing to the topics under `iggy/`.
+pub struct MqttSinkFixture {
+    container: MqttContainer,
+}
+
+impl MqttOps for MqttSinkFixture {
+    fn container(&self) -> &MqttContainer {
+        &self.container
+    }
+}
+
+#[async_trait]
+impl TestFixture for MqttSinkFixture {
+    async fn setup() -> Result<Self, TestBinaryError> {
+        let container = MqttContainer::start().await?;
+        Ok(Self { container })
+    }
+
+    fn connectors_runtime_envs(&self) -> HashMap<String, String> {
+        let mut envs = HashMap::new();
+        envs.insert(ENV_SINK_HOST.to_string(), "localhost".to_string());
+        envs.insert(ENV_SINK_PORT.to_string(), self.container.port.to_string());
+        envs.insert(
+            ENV_SINK_TOPIC.to_string(),
+            "iggy/{stream}/{topic}".to_string(),
+        );
+        envs.insert(
+            ENV_SINK_STREAMS_0_STREAM.to_string(),
+            DEFAULT_TEST_STREAM.to_string(),
+        );
+        envs.insert(
+            ENV_SINK_STREAMS_0_TOPICS.to_string(),
+            format!("[{DEFAULT_TEST_TOPIC}]"),
+        );
+        envs.insert(ENV_SINK_STREAMS_0_SCHEMA.to_string(), "raw".to_string());
+        envs.insert(
+            ENV_SINK_STREAMS_0_CONSUMER_GROUP.to_string(),
+            "mqtt_sink_cg".to_string(),
+        );
+        envs.insert(
+            ENV_SINK_PATH.to_string(),
+            "../../target/debug/libiggy_connector_mqtt_sink".to_string(),
+        );
+        envs
+    }
+}
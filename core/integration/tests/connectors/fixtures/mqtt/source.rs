@@ -0,0 +1,54 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::container::{ENV_SOURCE_HOST, ENV_SOURCE_PATH, ENV_SOURCE_PORT, MqttContainer, MqttOps};
+use async_trait::async_trait;
+use integration::harness::{TestBinaryError, TestFixture};
+use std::collections::HashMap;
+
+/// MQTT source connector fixture, routing the messages as configured in
+/// `tests/connectors/mqtt/source_config/config.toml`.
+pub struct MqttSourceFixture {
+    container: MqttContainer,
+}
+
+impl MqttOps for MqttSourceFixture {
+    fn container(&self) -> &MqttContainer {
+        &self.container
+    }
+}
+
+#[async_trait]
+impl TestFixture for MqttSourceFixture {
+    async fn setup() -> Result<Self, TestBinaryError> {
+        let container = MqttContainer::start().await?;
+        Ok(Self { container })
+    }
+
+    fn connectors_runtime_envs(&self) -> HashMap<String, String> {
+        let mut envs = HashMap::new();
+        envs.insert(ENV_SOURCE_HOST.to_string(), "localhost".to_string());
+        envs.insert(ENV_SOURCE_PORT.to_string(), self.container.port.to_string());
+        envs.insert(
+            ENV_SOURCE_PATH.to_string(),
+            "../../target/debug/libiggy_connector_mqtt_source".to_string(),
+        );
+        envs
+    }
+}
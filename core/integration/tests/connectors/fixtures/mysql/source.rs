@@ -0,0 +1,90 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::container::{
+    DEFAULT_TEST_STREAM, DEFAULT_TEST_TOPIC, ENV_SOURCE_CONNECTION_STRING, ENV_SOURCE_PATH,
+    ENV_SOURCE_POLL_INTERVAL, ENV_SOURCE_STREAMS_0_SCHEMA, ENV_SOURCE_STREAMS_0_STREAM,
+    ENV_SOURCE_STREAMS_0_TOPIC, ENV_SOURCE_TABLES, MYSQL_DATABASE, MySqlContainer, MySqlOps,
+};
+use async_trait::async_trait;
+use integration::harness::{TestBinaryError, TestFixture};
+use std::collections::HashMap;
+
+/// MySQL source fixture, creating a table with the rows read by the snapshot before the
+/// connector starts.
+pub struct MySqlSourceFixture {
+    container: MySqlContainer,
+}
+
+impl MySqlOps for MySqlSourceFixture {
+    fn container(&self) -> &MySqlContainer {
+        &self.container
+    }
+}
+
+impl MySqlSourceFixture {
+    pub const TABLE: &'static str = "customers";
+    pub const SNAPSHOT_ROWS: usize = 2;
+}
+
+#[async_trait]
+impl TestFixture for MySqlSourceFixture {
+    async fn setup() -> Result<Self, TestBinaryError> {
+        let container = MySqlContainer::start().await?;
+        let fixture = Self { container };
+        fixture
+            .execute(&[
+                "CREATE TABLE customers (
+                    id INT PRIMARY KEY,
+                    name VARCHAR(255) NOT NULL,
+                    balance DECIMAL(10, 2) NOT NULL
+                )",
+                "INSERT INTO customers VALUES (1, 'alice', 10.50), (2, 'bob', 20.00)",
+            ])
+            .await?;
+        Ok(fixture)
+    }
+
+    fn connectors_runtime_envs(&self) -> HashMap<String, String> {
+        let mut envs = HashMap::new();
+        envs.insert(
+            ENV_SOURCE_CONNECTION_STRING.to_string(),
+            self.container.connection_string.clone(),
+        );
+        envs.insert(
+            ENV_SOURCE_TABLES.to_string(),
+            format!("[{MYSQL_DATABASE}.{}]", Self::TABLE),
+        );
+        envs.insert(ENV_SOURCE_POLL_INTERVAL.to_string(), "100ms".to_string());
+        envs.insert(
+            ENV_SOURCE_STREAMS_0_STREAM.to_string(),
+            DEFAULT_TEST_STREAM.to_string(),
+        );
+        envs.insert(
+            ENV_SOURCE_STREAMS_0_TOPIC.to_string(),
+            DEFAULT_TEST_TOPIC.to_string(),
+        );
+        envs.insert(ENV_SOURCE_STREAMS_0_SCHEMA.to_string(), "json".to_string());
+        envs.insert(
+            ENV_SOURCE_PATH.to_string(),
+            "../../target/debug/libiggy_connector_mysql_source".to_string(),
+        );
+        envs
+    }
+}
@@ -0,0 +1,191 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use integration::harness::TestBinaryError;
+use redis::aio::MultiplexedConnection;
+use redis::streams::{StreamPendingReply, StreamRangeReply};
+use redis::{AsyncCommands, Client};
+use std::collections::HashMap;
+use std::time::Duration;
+use testcontainers_modules::testcontainers::core::{IntoContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use testcontainers_modules::testcontainers::{ContainerAsync, GenericImage, ImageExt};
+use tokio::time::sleep;
+use tracing::info;
+
+const REDIS_IMAGE: &str = "redis";
+const REDIS_TAG: &str = "7.4-alpine";
+const REDIS_PORT: u16 = 6379;
+const REDIS_READY_MSG: &str = "Ready to accept connections";
+const POLL_ATTEMPTS: usize = 100;
+const POLL_INTERVAL_MS: u64 = 50;
+
+pub(super) const DEFAULT_TEST_STREAM: &str = "test_stream";
+pub(super) const DEFAULT_TEST_TOPIC: &str = "test_topic";
+
+// Source env vars
+pub(super) const ENV_SOURCE_CONNECTION_STRING: &str =
+    "IGGY_CONNECTORS_SOURCE_REDIS_PLUGIN_CONFIG_CONNECTION_STRING";
+pub(super) const ENV_SOURCE_STREAMS: &str = "IGGY_CONNECTORS_SOURCE_REDIS_PLUGIN_CONFIG_STREAMS";
+pub(super) const ENV_SOURCE_CONSUMER_GROUP: &str =
+    "IGGY_CONNECTORS_SOURCE_REDIS_PLUGIN_CONFIG_CONSUMER_GROUP";
+pub(super) const ENV_SOURCE_START_ID: &str = "IGGY_CONNECTORS_SOURCE_REDIS_PLUGIN_CONFIG_START_ID";
+pub(super) const ENV_SOURCE_STREAMS_0_STREAM: &str =
+    "IGGY_CONNECTORS_SOURCE_REDIS_STREAMS_0_STREAM";
+pub(super) const ENV_SOURCE_STREAMS_0_TOPIC: &str = "IGGY_CONNECTORS_SOURCE_REDIS_STREAMS_0_TOPIC";
+pub(super) const ENV_SOURCE_STREAMS_0_SCHEMA: &str =
+    "IGGY_CONNECTORS_SOURCE_REDIS_STREAMS_0_SCHEMA";
+pub(super) const ENV_SOURCE_PATH: &str = "IGGY_CONNECTORS_SOURCE_REDIS_PATH";
+
+// Sink env vars
+pub(super) const ENV_SINK_CONNECTION_STRING: &str =
+    "IGGY_CONNECTORS_SINK_REDIS_PLUGIN_CONFIG_CONNECTION_STRING";
+pub(super) const ENV_SINK_TARGET: &str = "IGGY_CONNECTORS_SINK_REDIS_PLUGIN_CONFIG_TARGET";
+pub(super) const ENV_SINK_STREAMS_0_STREAM: &str = "IGGY_CONNECTORS_SINK_REDIS_STREAMS_0_STREAM";
+pub(super) const ENV_SINK_STREAMS_0_TOPICS: &str = "IGGY_CONNECTORS_SINK_REDIS_STREAMS_0_TOPICS";
+pub(super) const ENV_SINK_STREAMS_0_SCHEMA: &str = "IGGY_CONNECTORS_SINK_REDIS_STREAMS_0_SCHEMA";
+pub(super) const ENV_SINK_STREAMS_0_CONSUMER_GROUP: &str =
+    "IGGY_CONNECTORS_SINK_REDIS_STREAMS_0_CONSUMER_GROUP";
+pub(super) const ENV_SINK_PATH: &str = "IGGY_CONNECTORS_SINK_REDIS_PATH";
+
+/// Base container management for the Redis fixtures.
+pub struct RedisContainer {
+    #[allow(dead_code)]
+    container: ContainerAsync<GenericImage>,
+    pub(super) connection_string: String,
+}
+
+impl RedisContainer {
+    pub(super) async fn start() -> Result<Self, TestBinaryError> {
+        let container = GenericImage::new(REDIS_IMAGE, REDIS_TAG)
+            .with_exposed_port(REDIS_PORT.tcp())
+            .with_wait_for(WaitFor::message_on_stdout(REDIS_READY_MSG))
+            .with_mapped_port(0, REDIS_PORT.tcp())
+            .start()
+            .await
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "RedisContainer".to_string(),
+                message: format!("Failed to start container: {e}"),
+            })?;
+
+        let port = container
+            .get_host_port_ipv4(REDIS_PORT)
+            .await
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "RedisContainer".to_string(),
+                message: format!("Failed to get port: {e}"),
+            })?;
+
+        info!("Redis container available at localhost:{port}");
+
+        Ok(Self {
+            container,
+            connection_string: format!("redis://localhost:{port}"),
+        })
+    }
+
+    async fn connect(&self) -> Result<MultiplexedConnection, TestBinaryError> {
+        Client::open(self.connection_string.as_str())
+            .map_err(|e| TestBinaryError::InvalidState {
+                message: format!("Invalid Redis connection string: {e}"),
+            })?
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| TestBinaryError::InvalidState {
+                message: format!("Failed to connect to Redis: {e}"),
+            })
+    }
+}
+
+/// Common Redis operations for fixtures.
+pub trait RedisOps: Sync {
+    fn container(&self) -> &RedisContainer;
+
+    fn add_entries(
+        &self,
+        stream: &str,
+        entries: &[Vec<(&str, &str)>],
+    ) -> impl std::future::Future<Output = Result<(), TestBinaryError>> + Send {
+        async move {
+            let mut connection = self.container().connect().await?;
+            for fields in entries {
+                let _: String = connection.xadd(stream, "*", fields).await.map_err(|e| {
+                    TestBinaryError::InvalidState {
+                        message: format!("Failed to add Redis stream entry: {e}"),
+                    }
+                })?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Polls the stream until it has the expected number of entries, returning their fields.
+    fn read_entries(
+        &self,
+        stream: &str,
+        expected: usize,
+    ) -> impl std::future::Future<Output = Result<Vec<HashMap<String, String>>, TestBinaryError>> + Send
+    {
+        async move {
+            let mut connection = self.container().connect().await?;
+            let mut entries = Vec::new();
+            for _ in 0..POLL_ATTEMPTS {
+                let reply: StreamRangeReply = connection.xrange_all(stream).await.map_err(|e| {
+                    TestBinaryError::InvalidState {
+                        message: format!("Failed to read Redis stream entries: {e}"),
+                    }
+                })?;
+                entries = reply
+                    .ids
+                    .into_iter()
+                    .map(|entry| {
+                        entry
+                            .map
+                            .keys()
+                            .map(|name| (name.clone(), entry.get(name).unwrap_or_default()))
+                            .collect()
+                    })
+                    .collect();
+                if entries.len() >= expected {
+                    break;
+                }
+                sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+            }
+            Ok(entries)
+        }
+    }
+
+    /// Returns the number of entries delivered in the consumer group but not acknowledged.
+    fn pending_count(
+        &self,
+        stream: &str,
+        group: &str,
+    ) -> impl std::future::Future<Output = Result<usize, TestBinaryError>> + Send {
+        async move {
+            let mut connection = self.container().connect().await?;
+            let reply: StreamPendingReply =
+                connection.xpending(stream, group).await.map_err(|e| {
+                    TestBinaryError::InvalidState {
+                        message: format!("Failed to read Redis pending entries: {e}"),
+                    }
+                })?;
+            Ok(reply.count())
+        }
+    }
+}
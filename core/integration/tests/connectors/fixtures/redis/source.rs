@@ -0,0 +1,83 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::container::{
+    DEFAULT_TEST_STREAM, DEFAULT_TEST_TOPIC, ENV_SOURCE_CONNECTION_STRING,
+    ENV_SOURCE_CONSUMER_GROUP, ENV_SOURCE_PATH, ENV_SOURCE_START_ID, ENV_SOURCE_STREAMS,
+    ENV_SOURCE_STREAMS_0_SCHEMA, ENV_SOURCE_STREAMS_0_STREAM, ENV_SOURCE_STREAMS_0_TOPIC,
+    RedisContainer, RedisOps,
+};
+use async_trait::async_trait;
+use integration::harness::{TestBinaryError, TestFixture};
+use std::collections::HashMap;
+
+/// Redis source connector fixture, reading a stream in a consumer group from its beginning.
+pub struct RedisSourceFixture {
+    container: RedisContainer,
+}
+
+impl RedisOps for RedisSourceFixture {
+    fn container(&self) -> &RedisContainer {
+        &self.container
+    }
+}
+
+impl RedisSourceFixture {
+    pub const STREAM: &'static str = "orders";
+    pub const CONSUMER_GROUP: &'static str = "iggy";
+}
+
+#[async_trait]
+impl TestFixture for RedisSourceFixture {
+    async fn setup() -> Result<Self, TestBinaryError> {
+        let container = RedisContainer::start().await?;
+        Ok(Self { container })
+    }
+
+    fn connectors_runtime_envs(&self) -> HashMap<String, String> {
+        let mut envs = HashMap::new();
+        envs.insert(
+            ENV_SOURCE_CONNECTION_STRING.to_string(),
+            self.container.connection_string.clone(),
+        );
+        envs.insert(
+            ENV_SOURCE_STREAMS.to_string(),
+            format!("[{}]", Self::STREAM),
+        );
+        envs.insert(
+            ENV_SOURCE_CONSUMER_GROUP.to_string(),
+            Self::CONSUMER_GROUP.to_string(),
+        );
+        envs.insert(ENV_SOURCE_START_ID.to_string(), "0".to_string());
+        envs.insert(
+            ENV_SOURCE_STREAMS_0_STREAM.to_string(),
+            DEFAULT_TEST_STREAM.to_string(),
+        );
+        envs.insert(
+            ENV_SOURCE_STREAMS_0_TOPIC.to_string(),
+            DEFAULT_TEST_TOPIC.to_string(),
+        );
+        envs.insert(ENV_SOURCE_STREAMS_0_SCHEMA.to_string(), "json".to_string());
+        envs.insert(
+            ENV_SOURCE_PATH.to_string(),
+            "../../target/debug/libiggy_connector_redis_source".to_string(),
+        );
+        envs
+    }
+}
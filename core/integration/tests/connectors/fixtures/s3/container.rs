@@ -0,0 +1,219 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use futures::TryStreamExt;
+use integration::harness::TestBinaryError;
+use object_store::aws::AmazonS3Builder;
+use object_store::{ObjectStore, ObjectStoreExt};
+use std::sync::Arc;
+use std::time::Duration;
+use testcontainers_modules::testcontainers::core::{IntoContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use testcontainers_modules::testcontainers::{ContainerAsync, GenericImage, ImageExt};
+use tokio::process::Command;
+use tokio::time::sleep;
+use tracing::info;
+
+const MINIO_IMAGE: &str = "minio/minio";
+const MINIO_TAG: &str = "RELEASE.2025-09-07T16-13-09Z";
+const MINIO_PORT: u16 = 9000;
+const MINIO_READY_MSG: &str = "API:";
+const POLL_ATTEMPTS: usize = 200;
+const POLL_INTERVAL_MS: u64 = 50;
+
+pub(super) const MINIO_ACCESS_KEY: &str = "admin";
+pub(super) const MINIO_SECRET_KEY: &str = "password";
+pub(super) const MINIO_BUCKET: &str = "iggy-messages";
+pub(super) const MINIO_REGION: &str = "us-east-1";
+
+pub(super) const DEFAULT_TEST_STREAM: &str = "test_stream";
+pub(super) const DEFAULT_TEST_TOPIC: &str = "test_topic";
+
+// Sink env vars
+pub(super) const ENV_SINK_STORAGE: &str = "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_STORAGE";
+pub(super) const ENV_SINK_BUCKET: &str = "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_BUCKET";
+pub(super) const ENV_SINK_REGION: &str = "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_REGION";
+pub(super) const ENV_SINK_ENDPOINT: &str = "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_ENDPOINT";
+pub(super) const ENV_SINK_ACCESS_KEY_ID: &str =
+    "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_ACCESS_KEY_ID";
+pub(super) const ENV_SINK_SECRET_ACCESS_KEY: &str =
+    "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_SECRET_ACCESS_KEY";
+pub(super) const ENV_SINK_DIRECTORY: &str = "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_PATH";
+pub(super) const ENV_SINK_FORMAT: &str = "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_FORMAT";
+pub(super) const ENV_SINK_COMPRESSION: &str = "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_COMPRESSION";
+pub(super) const ENV_SINK_MAX_FILE_MESSAGES: &str =
+    "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_MAX_FILE_MESSAGES";
+pub(super) const ENV_SINK_MAX_FILE_AGE: &str = "IGGY_CONNECTORS_SINK_S3_PLUGIN_CONFIG_MAX_FILE_AGE";
+pub(super) const ENV_SINK_STREAMS_0_STREAM: &str = "IGGY_CONNECTORS_SINK_S3_STREAMS_0_STREAM";
+pub(super) const ENV_SINK_STREAMS_0_TOPICS: &str = "IGGY_CONNECTORS_SINK_S3_STREAMS_0_TOPICS";
+pub(super) const ENV_SINK_STREAMS_0_SCHEMA: &str = "IGGY_CONNECTORS_SINK_S3_STREAMS_0_SCHEMA";
+pub(super) const ENV_SINK_STREAMS_0_CONSUMER_GROUP: &str =
+    "IGGY_CONNECTORS_SINK_S3_STREAMS_0_CONSUMER_GROUP";
+pub(super) const ENV_SINK_PATH: &str = "IGGY_CONNECTORS_SINK_S3_PATH";
+
+/// File written by the S3 sink, with its path relative to the bucket or directory.
+#[derive(Debug)]
+pub struct S3File {
+    pub path: String,
+    pub contents: Bytes,
+}
+
+/// Base container management for the MinIO fixtures.
+pub struct MinioContainer {
+    #[allow(dead_code)]
+    container: ContainerAsync<GenericImage>,
+    pub(super) endpoint: String,
+}
+
+impl MinioContainer {
+    pub(super) async fn start() -> Result<Self, TestBinaryError> {
+        let container = GenericImage::new(MINIO_IMAGE, MINIO_TAG)
+            .with_exposed_port(MINIO_PORT.tcp())
+            .with_wait_for(WaitFor::message_on_stderr(MINIO_READY_MSG))
+            .with_env_var("MINIO_ROOT_USER", MINIO_ACCESS_KEY)
+            .with_env_var("MINIO_ROOT_PASSWORD", MINIO_SECRET_KEY)
+            .with_cmd(vec!["server", "/data"])
+            .with_mapped_port(0, MINIO_PORT.tcp())
+            .start()
+            .await
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "MinioContainer".to_string(),
+                message: format!("Failed to start container: {e}"),
+            })?;
+
+        let port = container
+            .get_host_port_ipv4(MINIO_PORT)
+            .await
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "MinioContainer".to_string(),
+                message: format!("Failed to get port: {e}"),
+            })?;
+
+        let endpoint = format!("http://localhost:{port}");
+        info!("MinIO container available at {endpoint}");
+
+        let minio = Self {
+            container,
+            endpoint,
+        };
+        minio.create_bucket().await?;
+        Ok(minio)
+    }
+
+    async fn create_bucket(&self) -> Result<(), TestBinaryError> {
+        let host = self.endpoint.trim_start_matches("http://");
+        let output = Command::new("docker")
+            .args([
+                "run",
+                "--rm",
+                "--network=host",
+                "-e",
+                &format!("MC_HOST_minio=http://{MINIO_ACCESS_KEY}:{MINIO_SECRET_KEY}@{host}"),
+                "minio/mc",
+                "mb",
+                "--ignore-existing",
+                &format!("minio/{MINIO_BUCKET}"),
+            ])
+            .output()
+            .await
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "MinioContainer".to_string(),
+                message: format!("Failed to run mc command: {e}"),
+            })?;
+
+        if !output.status.success() {
+            return Err(TestBinaryError::FixtureSetup {
+                fixture_type: "MinioContainer".to_string(),
+                message: format!(
+                    "Failed to create bucket: {}",
+                    String::from_utf8_lossy(&output.stderr)
+                ),
+            });
+        }
+
+        info!("Created MinIO bucket: {MINIO_BUCKET}");
+        Ok(())
+    }
+
+    pub(super) fn store(&self) -> Result<Arc<dyn ObjectStore>, TestBinaryError> {
+        let store = AmazonS3Builder::new()
+            .with_bucket_name(MINIO_BUCKET)
+            .with_region(MINIO_REGION)
+            .with_endpoint(&self.endpoint)
+            .with_allow_http(true)
+            .with_access_key_id(MINIO_ACCESS_KEY)
+            .with_secret_access_key(MINIO_SECRET_KEY)
+            .build()
+            .map_err(|e| TestBinaryError::FixtureSetup {
+                fixture_type: "MinioContainer".to_string(),
+                message: format!("Failed to create S3 client: {e}"),
+            })?;
+        Ok(Arc::new(store))
+    }
+}
+
+/// Common object storage operations for the S3 fixtures.
+pub trait S3Ops: Sync {
+    fn store(&self) -> &Arc<dyn ObjectStore>;
+
+    /// Polls the storage until it holds the expected number of files, returning them ordered by path.
+    fn read_files(
+        &self,
+        expected: usize,
+    ) -> impl std::future::Future<Output = Result<Vec<S3File>, TestBinaryError>> + Send {
+        async move {
+            let store = self.store();
+            let mut paths = Vec::new();
+            for _ in 0..POLL_ATTEMPTS {
+                let objects: Vec<_> = store.list(None).try_collect().await.map_err(|e| {
+                    TestBinaryError::InvalidState {
+                        message: format!("Failed to list files: {e}"),
+                    }
+                })?;
+                paths = objects.into_iter().map(|object| object.location).collect();
+                if paths.len() >= expected {
+                    break;
+                }
+                sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+            }
+
+            paths.sort();
+            let mut files = Vec::with_capacity(paths.len());
+            for path in paths {
+                let contents = store
+                    .get(&path)
+                    .await
+                    .map_err(|e| TestBinaryError::InvalidState {
+                        message: format!("Failed to get file {path}: {e}"),
+                    })?
+                    .bytes()
+                    .await
+                    .map_err(|e| TestBinaryError::InvalidState {
+                        message: format!("Failed to read file {path}: {e}"),
+                    })?;
+                files.push(S3File {
+                    path: path.to_string(),
+                    contents,
+                });
+            }
+            Ok(files)
+        }
+    }
+}
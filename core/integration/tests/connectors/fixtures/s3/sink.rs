@@ -0,0 +1,153 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::container::{
+    DEFAULT_TEST_STREAM, DEFAULT_TEST_TOPIC, ENV_SINK_ACCESS_KEY_ID, ENV_SINK_BUCKET,
+    ENV_SINK_COMPRESSION, ENV_SINK_DIRECTORY, ENV_SINK_ENDPOINT, ENV_SINK_FORMAT,
+    ENV_SINK_MAX_FILE_AGE, ENV_SINK_MAX_FILE_MESSAGES, ENV_SINK_PATH, ENV_SINK_REGION,
+    ENV_SINK_SECRET_ACCESS_KEY, ENV_SINK_STORAGE, ENV_SINK_STREAMS_0_CONSUMER_GROUP,
+    ENV_SINK_STREAMS_0_SCHEMA, ENV_SINK_STREAMS_0_STREAM, ENV_SINK_STREAMS_0_TOPICS,
+    MINIO_ACCESS_KEY, MINIO_BUCKET, MINIO_REGION, MINIO_SECRET_KEY, MinioContainer, S3Ops,
+};
+use async_trait::async_trait;
+use integration::harness::{TestBinaryError, TestFixture};
+use object_store::ObjectStore;
+use object_store::local::LocalFileSystem;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn streams_envs(envs: &mut HashMap<String, String>) {
+    envs.insert(
+        ENV_SINK_STREAMS_0_STREAM.to_string(),
+        DEFAULT_TEST_STREAM.to_string(),
+    );
+    envs.insert(
+        ENV_SINK_STREAMS_0_TOPICS.to_string(),
+        format!("[{DEFAULT_TEST_TOPIC}]"),
+    );
+    envs.insert(ENV_SINK_STREAMS_0_SCHEMA.to_string(), "json".to_string());
+    envs.insert(
+        ENV_SINK_STREAMS_0_CONSUMER_GROUP.to_string(),
+        "s3_sink_cg".to_string(),
+    );
+    envs.insert(
+        ENV_SINK_PATH.to_string(),
+        "../../target/debug/libiggy_connector_s3_sink".to_string(),
+    );
+}
+
+/// S3 sink connector fixture writing the uncompressed JSONL files rolled by the message count
+/// to a local directory.
+pub struct S3SinkLocalFixture {
+    directory: TempDir,
+    store: Arc<dyn ObjectStore>,
+}
+
+impl S3Ops for S3SinkLocalFixture {
+    fn store(&self) -> &Arc<dyn ObjectStore> {
+        &self.store
+    }
+}
+
+impl S3SinkLocalFixture {
+    pub const MAX_FILE_MESSAGES: usize = 3;
+}
+
+#[async_trait]
+impl TestFixture for S3SinkLocalFixture {
+    async fn setup() -> Result<Self, TestBinaryError> {
+        let directory = TempDir::new().map_err(|e| TestBinaryError::FixtureSetup {
+            fixture_type: "S3SinkLocalFixture".to_string(),
+            message: format!("Failed to create temp directory: {e}"),
+        })?;
+        let store = LocalFileSystem::new_with_prefix(directory.path()).map_err(|e| {
+            TestBinaryError::FixtureSetup {
+                fixture_type: "S3SinkLocalFixture".to_string(),
+                message: format!("Failed to open temp directory: {e}"),
+            }
+        })?;
+        Ok(Self {
+            directory,
+            store: Arc::new(store),
+        })
+    }
+
+    fn connectors_runtime_envs(&self) -> HashMap<String, String> {
+        let mut envs = HashMap::new();
+        envs.insert(ENV_SINK_STORAGE.to_string(), "local".to_string());
+        envs.insert(
+            ENV_SINK_DIRECTORY.to_string(),
+            self.directory.path().display().to_string(),
+        );
+        envs.insert(ENV_SINK_FORMAT.to_string(), "jsonl".to_string());
+        envs.insert(ENV_SINK_COMPRESSION.to_string(), "none".to_string());
+        envs.insert(
+            ENV_SINK_MAX_FILE_MESSAGES.to_string(),
+            Self::MAX_FILE_MESSAGES.to_string(),
+        );
+        streams_envs(&mut envs);
+        envs
+    }
+}
+
+/// S3 sink connector fixture writing the ZSTD-compressed Parquet files rolled by age to MinIO.
+pub struct S3SinkMinioFixture {
+    container: MinioContainer,
+    store: Arc<dyn ObjectStore>,
+}
+
+impl S3Ops for S3SinkMinioFixture {
+    fn store(&self) -> &Arc<dyn ObjectStore> {
+        &self.store
+    }
+}
+
+#[async_trait]
+impl TestFixture for S3SinkMinioFixture {
+    async fn setup() -> Result<Self, TestBinaryError> {
+        let container = MinioContainer::start().await?;
+        let store = container.store()?;
+        Ok(Self { container, store })
+    }
+
+    fn connectors_runtime_envs(&self) -> HashMap<String, String> {
+        let mut envs = HashMap::new();
+        envs.insert(ENV_SINK_STORAGE.to_string(), "s3".to_string());
+        envs.insert(ENV_SINK_BUCKET.to_string(), MINIO_BUCKET.to_string());
+        envs.insert(ENV_SINK_REGION.to_string(), MINIO_REGION.to_string());
+        envs.insert(
+            ENV_SINK_ENDPOINT.to_string(),
+            self.container.endpoint.clone(),
+        );
+        envs.insert(
+            ENV_SINK_ACCESS_KEY_ID.to_string(),
+            MINIO_ACCESS_KEY.to_string(),
+        );
+        envs.insert(
+            ENV_SINK_SECRET_ACCESS_KEY.to_string(),
+            MINIO_SECRET_KEY.to_string(),
+        );
+        envs.insert(ENV_SINK_FORMAT.to_string(), "parquet".to_string());
+        envs.insert(ENV_SINK_COMPRESSION.to_string(), "zstd".to_string());
+        envs.insert(ENV_SINK_MAX_FILE_AGE.to_string(), "1s".to_string());
+        streams_envs(&mut envs);
+        envs
+    }
+}
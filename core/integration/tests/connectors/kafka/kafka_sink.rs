@@ -0,0 +1,86 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::TEST_MESSAGE_COUNT;
+use crate::connectors::fixtures::{KafkaOps, KafkaSinkFixture};
+use bytes::Bytes;
+use iggy::prelude::{HeaderKey, HeaderValue, IggyMessage, Partitioning};
+use iggy_common::{Identifier, MessageClient};
+use integration::harness::seeds;
+use integration::iggy_harness;
+use std::collections::BTreeMap;
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/kafka/sink.toml")),
+    seed = seeds::connector_stream
+)]
+async fn kafka_sink_produces_records_with_keys_and_headers(
+    harness: &TestHarness,
+    fixture: KafkaSinkFixture,
+) {
+    let client = harness.root_client().await.unwrap();
+
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = seeds::names::TOPIC.try_into().unwrap();
+
+    let mut messages: Vec<IggyMessage> = (0..TEST_MESSAGE_COUNT)
+        .map(|i| {
+            let headers = BTreeMap::from([
+                (
+                    HeaderKey::try_from("kafka_key").unwrap(),
+                    HeaderValue::try_from(format!("order-{i}").as_bytes()).unwrap(),
+                ),
+                (
+                    HeaderKey::try_from("trace_id").unwrap(),
+                    HeaderValue::try_from(format!("trace-{i}").as_str()).unwrap(),
+                ),
+            ]);
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message {i}")))
+                .user_headers(headers)
+                .build()
+                .expect("Failed to build message")
+        })
+        .collect();
+
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(0),
+            &mut messages,
+        )
+        .await
+        .expect("Failed to send messages");
+
+    let records = fixture
+        .consume_records(fixture.kafka_topic(), TEST_MESSAGE_COUNT)
+        .await
+        .expect("Records did not appear in Kafka");
+
+    assert_eq!(records.len(), TEST_MESSAGE_COUNT);
+    for (i, record) in records.iter().enumerate() {
+        assert_eq!(record.payload, format!("message {i}").into_bytes());
+        assert_eq!(record.key, Some(format!("order-{i}").into_bytes()));
+        assert_eq!(
+            record.headers,
+            vec![("trace_id".to_string(), format!("trace-{i}").into_bytes())]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::{POLL_ATTEMPTS, POLL_INTERVAL_MS, TEST_MESSAGE_COUNT};
+use crate::connectors::fixtures::{KafkaOps, KafkaRecord, KafkaSourceFixture};
+use iggy_common::{Consumer, HeaderKey, Identifier, IggyMessage, MessageClient, PollingStrategy};
+use integration::harness::seeds;
+use integration::iggy_harness;
+use std::time::Duration;
+use tokio::time::sleep;
+
+fn header(message: &IggyMessage, key: &str) -> Option<Vec<u8>> {
+    message
+        .user_headers_map()
+        .unwrap()
+        .and_then(|headers| headers.get(&HeaderKey::try_from(key).unwrap()).cloned())
+        .map(|value| value.as_bytes().to_vec())
+}
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/kafka/source.toml")),
+    seed = seeds::connector_stream
+)]
+async fn kafka_source_mirrors_records_with_keys_and_headers(
+    harness: &TestHarness,
+    fixture: KafkaSourceFixture,
+) {
+    let client = harness.root_client().await.unwrap();
+
+    let records: Vec<KafkaRecord> = (0..TEST_MESSAGE_COUNT)
+        .map(|i| KafkaRecord {
+            key: Some(format!("order-{i}").into_bytes()),
+            headers: vec![("trace_id".to_string(), format!("trace-{i}").into_bytes())],
+            payload: format!("record {i}").into_bytes(),
+        })
+        .collect();
+    fixture
+        .produce_records(fixture.kafka_topic(), &records)
+        .await
+        .expect("Failed to produce Kafka records");
+
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = seeds::names::TOPIC.try_into().unwrap();
+    let consumer_id: Identifier = "test_consumer".try_into().unwrap();
+
+    let mut received: Vec<IggyMessage> = Vec::new();
+    for _ in 0..POLL_ATTEMPTS {
+        if let Ok(polled) = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                None,
+                &Consumer::new(consumer_id.clone()),
+                &PollingStrategy::next(),
+                10,
+                true,
+            )
+            .await
+        {
+            received.extend(polled.messages);
+            if received.len() >= TEST_MESSAGE_COUNT {
+                break;
+            }
+        }
+        sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+    }
+
+    assert_eq!(
+        received.len(),
+        TEST_MESSAGE_COUNT,
+        "Expected {TEST_MESSAGE_COUNT} mirrored messages"
+    );
+    for (i, (message, record)) in received.iter().zip(&records).enumerate() {
+        assert_eq!(message.payload.as_ref(), record.payload.as_slice());
+        assert_eq!(
+            header(message, "kafka_key"),
+            record.key,
+            "Key mismatch at {i}"
+        );
+        assert_eq!(
+            header(message, "trace_id").as_deref(),
+            Some(record.headers[0].1.as_slice()),
+            "Header mismatch at {i}"
+        );
+        assert_eq!(
+            message
+                .user_headers_map()
+                .unwrap()
+                .unwrap()
+                .get(&HeaderKey::try_from("kafka_offset").unwrap())
+                .unwrap()
+                .as_int64()
+                .unwrap(),
+            i as i64
+        );
+    }
+}
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/kafka/source.toml")),
+    seed = seeds::connector_stream
+)]
+async fn kafka_source_handles_empty_topic(harness: &TestHarness, _fixture: KafkaSourceFixture) {
+    let client = harness.root_client().await.unwrap();
+
+    sleep(Duration::from_millis(POLL_INTERVAL_MS * 10)).await;
+
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = seeds::names::TOPIC.try_into().unwrap();
+    let polled = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            None,
+            &Consumer::new("test_consumer".try_into().unwrap()),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+        )
+        .await
+        .expect("Failed to poll messages");
+
+    assert!(
+        polled.messages.is_empty(),
+        "Expected no messages for an empty Kafka topic"
+    );
+}
@@ -25,10 +25,15 @@ mod http;
 mod http_config_provider;
 mod iceberg;
 mod influxdb;
+mod kafka;
 mod mongodb;
+mod mqtt;
+mod mysql;
 mod postgres;
 mod quickwit;
 mod random;
+mod redis;
+mod s3;
 mod stdout;
 
 use iggy_common::IggyTimestamp;
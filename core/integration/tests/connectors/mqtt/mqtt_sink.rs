@@ -0,0 +1,84 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::TEST_MESSAGE_COUNT;
+use crate::connectors::fixtures::{MqttOps, MqttSinkFixture};
+use bytes::Bytes;
+use iggy::prelude::{HeaderKey, HeaderValue, IggyMessage, Partitioning};
+use iggy_common::{Identifier, MessageClient};
+use integration::harness::seeds;
+use integration::iggy_harness;
+use std::collections::BTreeMap;
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/mqtt/sink.toml")),
+    seed = seeds::connector_stream
+)]
+async fn mqtt_sink_publishes_messages_with_user_properties(
+    harness: &TestHarness,
+    fixture: MqttSinkFixture,
+) {
+    let client = harness.root_client().await.unwrap();
+    let mut subscriber = fixture
+        .subscribe("iggy/#")
+        .await
+        .expect("Failed to subscribe to MQTT topics");
+
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = seeds::names::TOPIC.try_into().unwrap();
+
+    let mut messages: Vec<IggyMessage> = (0..TEST_MESSAGE_COUNT)
+        .map(|i| {
+            let headers = BTreeMap::from([(
+                HeaderKey::try_from("trace_id").unwrap(),
+                HeaderValue::try_from(format!("trace-{i}").as_str()).unwrap(),
+            )]);
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message {i}")))
+                .user_headers(headers)
+                .build()
+                .expect("Failed to build message")
+        })
+        .collect();
+
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(0),
+            &mut messages,
+        )
+        .await
+        .expect("Failed to send messages");
+
+    let received = subscriber
+        .receive(TEST_MESSAGE_COUNT)
+        .await
+        .expect("Messages did not appear in MQTT");
+
+    assert_eq!(received.len(), TEST_MESSAGE_COUNT);
+    for (i, message) in received.iter().enumerate() {
+        assert_eq!(message.topic, "iggy/test_stream/test_topic");
+        assert_eq!(message.payload, format!("message {i}").into_bytes());
+        assert_eq!(
+            message.user_properties,
+            vec![("trace_id".to_string(), format!("trace-{i}"))]
+        );
+    }
+}
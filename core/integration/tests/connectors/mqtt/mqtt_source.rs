@@ -0,0 +1,124 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::{POLL_ATTEMPTS, POLL_INTERVAL_MS, TEST_MESSAGE_COUNT};
+use crate::connectors::fixtures::{MqttMessage, MqttOps, MqttSourceFixture};
+use iggy::prelude::IggyClient;
+use iggy_common::{Consumer, HeaderKey, Identifier, IggyMessage, MessageClient, PollingStrategy};
+use integration::harness::seeds;
+use integration::iggy_harness;
+use std::time::Duration;
+use tokio::time::sleep;
+
+fn header(message: &IggyMessage, key: &str) -> Option<String> {
+    message
+        .user_headers_map()
+        .unwrap()
+        .and_then(|headers| headers.get(&HeaderKey::try_from(key).unwrap()).cloned())
+        .map(|value| value.as_str().unwrap().to_string())
+}
+
+async fn poll_topic(client: &IggyClient, topic: &str, expected: usize) -> Vec<IggyMessage> {
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = topic.try_into().unwrap();
+    let consumer_id: Identifier = "test_consumer".try_into().unwrap();
+
+    let mut received: Vec<IggyMessage> = Vec::new();
+    for _ in 0..POLL_ATTEMPTS {
+        if let Ok(polled) = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                None,
+                &Consumer::new(consumer_id.clone()),
+                &PollingStrategy::next(),
+                10,
+                true,
+            )
+            .await
+        {
+            received.extend(polled.messages);
+            if received.len() >= expected {
+                break;
+            }
+        }
+        sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+    }
+    received
+}
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/mqtt/source.toml")),
+    seed = seeds::connector_multi_topic_stream
+)]
+async fn mqtt_source_routes_messages_with_properties(
+    harness: &TestHarness,
+    fixture: MqttSourceFixture,
+) {
+    let client = harness.root_client().await.unwrap();
+
+    let mut messages: Vec<MqttMessage> = (0..TEST_MESSAGE_COUNT)
+        .map(|i| MqttMessage {
+            topic: format!("sensors/device-{i}/temperature"),
+            user_properties: vec![("unit".to_string(), "celsius".to_string())],
+            payload: format!("{i}.5").into_bytes(),
+        })
+        .collect();
+    messages.push(MqttMessage {
+        topic: "alerts/fire".to_string(),
+        user_properties: vec![],
+        payload: b"evacuate".to_vec(),
+    });
+    fixture
+        .publish_messages(&messages)
+        .await
+        .expect("Failed to publish MQTT messages");
+
+    let received = poll_topic(&client, seeds::names::TOPIC, TEST_MESSAGE_COUNT).await;
+    assert_eq!(
+        received.len(),
+        TEST_MESSAGE_COUNT,
+        "Expected {TEST_MESSAGE_COUNT} sensor messages"
+    );
+    for (i, (message, published)) in received.iter().zip(&messages).enumerate() {
+        assert_eq!(message.payload.as_ref(), published.payload.as_slice());
+        assert_eq!(
+            header(message, "mqtt_topic").as_deref(),
+            Some(published.topic.as_str()),
+            "Topic mismatch at {i}"
+        );
+        assert_eq!(
+            header(message, "unit").as_deref(),
+            Some("celsius"),
+            "User property mismatch at {i}"
+        );
+    }
+
+    let alerts = poll_topic(&client, seeds::names::TOPIC_2, 1).await;
+    assert_eq!(
+        alerts.len(),
+        1,
+        "Expected the alert routed to the other topic"
+    );
+    assert_eq!(alerts[0].payload.as_ref(), b"evacuate");
+    assert_eq!(
+        header(&alerts[0], "mqtt_topic").as_deref(),
+        Some("alerts/fire")
+    );
+}
@@ -0,0 +1,130 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::{POLL_ATTEMPTS, POLL_INTERVAL_MS};
+use crate::connectors::fixtures::{MySqlOps, MySqlSourceFixture};
+use iggy::prelude::IggyClient;
+use iggy_common::{Consumer, Identifier, MessageClient, PollingStrategy};
+use integration::harness::seeds;
+use integration::iggy_harness;
+use serde_json::{Value, json};
+use std::time::Duration;
+use tokio::time::sleep;
+
+async fn poll_changes(client: &IggyClient, expected: usize) -> Vec<Value> {
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = seeds::names::TOPIC.try_into().unwrap();
+    let consumer_id: Identifier = "test_consumer".try_into().unwrap();
+
+    let mut received: Vec<Value> = Vec::new();
+    for _ in 0..POLL_ATTEMPTS {
+        if let Ok(polled) = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                None,
+                &Consumer::new(consumer_id.clone()),
+                &PollingStrategy::next(),
+                10,
+                true,
+            )
+            .await
+        {
+            for message in polled.messages {
+                received.push(serde_json::from_slice(&message.payload).unwrap());
+            }
+            if received.len() >= expected {
+                break;
+            }
+        }
+        sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+    }
+    received
+}
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/mysql/source.toml")),
+    seed = seeds::connector_stream
+)]
+async fn mysql_source_captures_snapshot_and_binlog_changes(
+    harness: &TestHarness,
+    fixture: MySqlSourceFixture,
+) {
+    let client = harness.root_client().await.unwrap();
+
+    let snapshot = poll_changes(&client, MySqlSourceFixture::SNAPSHOT_ROWS).await;
+    assert_eq!(
+        snapshot.len(),
+        MySqlSourceFixture::SNAPSHOT_ROWS,
+        "Expected the snapshot of the existing rows"
+    );
+    for record in &snapshot {
+        assert_eq!(record["operation"], "snapshot");
+        assert_eq!(record["table"], MySqlSourceFixture::TABLE);
+        assert_eq!(record["source"]["snapshot"], true);
+    }
+    assert_eq!(
+        snapshot[0]["after"],
+        json!({ "id": 1, "name": "alice", "balance": "10.50" })
+    );
+    assert_eq!(
+        snapshot[1]["after"],
+        json!({ "id": 2, "name": "bob", "balance": "20.00" })
+    );
+
+    fixture
+        .execute(&[
+            "INSERT INTO customers VALUES (3, 'carol', 30.25)",
+            "START TRANSACTION",
+            "UPDATE customers SET balance = 15.00 WHERE id = 1",
+            "DELETE FROM customers WHERE id = 2",
+            "COMMIT",
+        ])
+        .await
+        .expect("Failed to change MySQL rows");
+
+    let changes = poll_changes(&client, 3).await;
+    assert_eq!(changes.len(), 3, "Expected the captured changes");
+
+    assert_eq!(changes[0]["operation"], "insert");
+    assert_eq!(changes[0]["before"], Value::Null);
+    assert_eq!(
+        changes[0]["after"],
+        json!({ "id": 3, "name": "carol", "balance": "30.25" })
+    );
+
+    assert_eq!(changes[1]["operation"], "update");
+    assert_eq!(changes[1]["before"]["balance"], "10.50");
+    assert_eq!(changes[1]["after"]["balance"], "15.00");
+
+    assert_eq!(changes[2]["operation"], "delete");
+    assert_eq!(changes[2]["before"]["id"], 2);
+    assert_eq!(changes[2]["after"], Value::Null);
+
+    // Both changes of the explicit transaction share its GTID.
+    assert_eq!(changes[1]["transaction"]["total"], 2);
+    assert_eq!(
+        changes[1]["transaction"]["id"],
+        changes[2]["transaction"]["id"]
+    );
+    assert_ne!(
+        changes[0]["transaction"]["id"],
+        changes[1]["transaction"]["id"]
+    );
+}
@@ -0,0 +1,82 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::TEST_MESSAGE_COUNT;
+use crate::connectors::fixtures::{RedisOps, RedisSinkFixture};
+use bytes::Bytes;
+use iggy::prelude::{HeaderKey, HeaderValue, IggyMessage, Partitioning};
+use iggy_common::{Identifier, MessageClient};
+use integration::harness::seeds;
+use integration::iggy_harness;
+use std::collections::{BTreeMap, HashMap};
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/redis/sink.toml")),
+    seed = seeds::connector_stream
+)]
+async fn redis_sink_adds_json_fields_and_headers_to_stream(
+    harness: &TestHarness,
+    fixture: RedisSinkFixture,
+) {
+    let client = harness.root_client().await.unwrap();
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = seeds::names::TOPIC.try_into().unwrap();
+
+    let mut messages: Vec<IggyMessage> = (0..TEST_MESSAGE_COUNT)
+        .map(|i| {
+            let headers = BTreeMap::from([(
+                HeaderKey::try_from("trace_id").unwrap(),
+                HeaderValue::try_from(format!("trace-{i}").as_str()).unwrap(),
+            )]);
+            IggyMessage::builder()
+                .payload(Bytes::from(format!(
+                    r#"{{"id":{i},"name":"user-{i}","tags":["a"]}}"#
+                )))
+                .user_headers(headers)
+                .build()
+                .expect("Failed to build message")
+        })
+        .collect();
+
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(0),
+            &mut messages,
+        )
+        .await
+        .expect("Failed to send messages");
+
+    let entries = fixture
+        .read_entries(RedisSinkFixture::STREAM, TEST_MESSAGE_COUNT)
+        .await
+        .expect("Failed to read Redis stream entries");
+
+    assert_eq!(entries.len(), TEST_MESSAGE_COUNT);
+    for (i, entry) in entries.iter().enumerate() {
+        let expected = HashMap::from([
+            ("id".to_string(), i.to_string()),
+            ("name".to_string(), format!("user-{i}")),
+            ("tags".to_string(), r#"["a"]"#.to_string()),
+            ("trace_id".to_string(), format!("trace-{i}")),
+        ]);
+        assert_eq!(entry, &expected, "Entry mismatch at {i}");
+    }
+}
@@ -0,0 +1,124 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::{POLL_ATTEMPTS, POLL_INTERVAL_MS, TEST_MESSAGE_COUNT};
+use crate::connectors::fixtures::{RedisOps, RedisSourceFixture};
+use iggy_common::{Consumer, HeaderKey, Identifier, IggyMessage, MessageClient, PollingStrategy};
+use integration::harness::seeds;
+use integration::iggy_harness;
+use serde_json::{Value, json};
+use std::time::Duration;
+use tokio::time::sleep;
+
+fn header(message: &IggyMessage, key: &str) -> Option<String> {
+    message
+        .user_headers_map()
+        .unwrap()
+        .and_then(|headers| headers.get(&HeaderKey::try_from(key).unwrap()).cloned())
+        .map(|value| value.as_str().unwrap().to_string())
+}
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/redis/source.toml")),
+    seed = seeds::connector_stream
+)]
+async fn redis_source_produces_stream_entries_and_acknowledges_them(
+    harness: &TestHarness,
+    fixture: RedisSourceFixture,
+) {
+    let client = harness.root_client().await.unwrap();
+
+    let entries: Vec<Vec<(&str, String)>> = (0..TEST_MESSAGE_COUNT)
+        .map(|i| vec![("id", i.to_string()), ("status", format!("status-{i}"))])
+        .collect();
+    let entries: Vec<Vec<(&str, &str)>> = entries
+        .iter()
+        .map(|fields| {
+            fields
+                .iter()
+                .map(|(name, value)| (*name, value.as_str()))
+                .collect()
+        })
+        .collect();
+    fixture
+        .add_entries(RedisSourceFixture::STREAM, &entries)
+        .await
+        .expect("Failed to add Redis stream entries");
+
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = seeds::names::TOPIC.try_into().unwrap();
+    let consumer_id: Identifier = "test_consumer".try_into().unwrap();
+
+    let mut received: Vec<IggyMessage> = Vec::new();
+    for _ in 0..POLL_ATTEMPTS {
+        if let Ok(polled) = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                None,
+                &Consumer::new(consumer_id.clone()),
+                &PollingStrategy::next(),
+                10,
+                true,
+            )
+            .await
+        {
+            received.extend(polled.messages);
+            if received.len() >= TEST_MESSAGE_COUNT {
+                break;
+            }
+        }
+        sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+    }
+
+    assert_eq!(
+        received.len(),
+        TEST_MESSAGE_COUNT,
+        "Expected {TEST_MESSAGE_COUNT} messages"
+    );
+    for (i, message) in received.iter().enumerate() {
+        let payload: Value = serde_json::from_slice(&message.payload).unwrap();
+        assert_eq!(
+            payload,
+            json!({ "id": i.to_string(), "status": format!("status-{i}") }),
+            "Payload mismatch at {i}"
+        );
+        assert_eq!(
+            header(message, "redis_stream").as_deref(),
+            Some(RedisSourceFixture::STREAM)
+        );
+        assert!(header(message, "redis_entry_id").is_some());
+    }
+
+    let mut pending = usize::MAX;
+    for _ in 0..POLL_ATTEMPTS {
+        pending = fixture
+            .pending_count(
+                RedisSourceFixture::STREAM,
+                RedisSourceFixture::CONSUMER_GROUP,
+            )
+            .await
+            .expect("Failed to read the pending entries");
+        if pending == 0 {
+            break;
+        }
+        sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+    }
+    assert_eq!(pending, 0, "Expected the entries to be acknowledged");
+}
@@ -0,0 +1,182 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::TEST_MESSAGE_COUNT;
+use crate::connectors::fixtures::{S3Ops, S3SinkLocalFixture, S3SinkMinioFixture};
+use arrow_array::cast::AsArray;
+use arrow_array::types::UInt64Type;
+use bytes::Bytes;
+use iggy::prelude::{HeaderKey, HeaderValue, IggyMessage, Partitioning};
+use iggy_common::{Identifier, MessageClient};
+use integration::harness::seeds;
+use integration::iggy_harness;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+
+fn build_messages() -> Vec<IggyMessage> {
+    (0..TEST_MESSAGE_COUNT)
+        .map(|i| {
+            let headers = BTreeMap::from([(
+                HeaderKey::try_from("trace_id").unwrap(),
+                HeaderValue::try_from(format!("trace-{i}").as_str()).unwrap(),
+            )]);
+            IggyMessage::builder()
+                .payload(Bytes::from(format!(r#"{{"id":{i},"name":"user-{i}"}}"#)))
+                .user_headers(headers)
+                .build()
+                .expect("Failed to build message")
+        })
+        .collect()
+}
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/s3/sink.toml")),
+    seed = seeds::connector_stream
+)]
+async fn s3_sink_writes_jsonl_files_rolled_by_message_count(
+    harness: &TestHarness,
+    fixture: S3SinkLocalFixture,
+) {
+    let client = harness.root_client().await.unwrap();
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = seeds::names::TOPIC.try_into().unwrap();
+
+    let mut messages = build_messages();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(0),
+            &mut messages,
+        )
+        .await
+        .expect("Failed to send messages");
+
+    let expected_files = TEST_MESSAGE_COUNT / S3SinkLocalFixture::MAX_FILE_MESSAGES;
+    let files = fixture
+        .read_files(expected_files)
+        .await
+        .expect("Failed to read files");
+
+    assert_eq!(files.len(), expected_files);
+    let prefix = format!("iggy/{}/{}/dt=", seeds::names::STREAM, seeds::names::TOPIC);
+    for (i, file) in files.iter().enumerate() {
+        let first_offset = i * S3SinkLocalFixture::MAX_FILE_MESSAGES;
+        assert!(
+            file.path.starts_with(&prefix),
+            "Unexpected path {}",
+            file.path
+        );
+        assert!(
+            file.path.ends_with(&format!("/0-{first_offset:020}.jsonl")),
+            "Unexpected file name {}",
+            file.path
+        );
+    }
+
+    let lines: Vec<Value> = files
+        .iter()
+        .flat_map(|file| {
+            std::str::from_utf8(&file.contents)
+                .expect("File is not UTF-8")
+                .lines()
+                .map(|line| serde_json::from_str(line).expect("Line is not JSON"))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    assert_eq!(lines.len(), TEST_MESSAGE_COUNT);
+    for (i, line) in lines.iter().enumerate() {
+        assert_eq!(line["stream"], seeds::names::STREAM);
+        assert_eq!(line["topic"], seeds::names::TOPIC);
+        assert_eq!(line["partition_id"], 0);
+        assert_eq!(line["offset"], i as u64);
+        assert_eq!(line["headers"], json!({ "trace_id": format!("trace-{i}") }));
+        assert_eq!(
+            line["payload"],
+            json!({ "id": i, "name": format!("user-{i}") })
+        );
+    }
+}
+
+#[iggy_harness(
+    server(connectors_runtime(config_path = "tests/connectors/s3/sink.toml")),
+    seed = seeds::connector_stream
+)]
+async fn s3_sink_writes_parquet_files_rolled_by_age_to_minio(
+    harness: &TestHarness,
+    fixture: S3SinkMinioFixture,
+) {
+    let client = harness.root_client().await.unwrap();
+    let stream_id: Identifier = seeds::names::STREAM.try_into().unwrap();
+    let topic_id: Identifier = seeds::names::TOPIC.try_into().unwrap();
+
+    let mut messages = build_messages();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(0),
+            &mut messages,
+        )
+        .await
+        .expect("Failed to send messages");
+
+    let files = fixture.read_files(1).await.expect("Failed to read files");
+
+    assert_eq!(files.len(), 1);
+    let file = &files[0];
+    assert!(
+        file.path.ends_with(&format!("/0-{:020}.parquet", 0)),
+        "Unexpected file name {}",
+        file.path
+    );
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file.contents.clone())
+        .expect("Failed to read Parquet metadata")
+        .build()
+        .expect("Failed to create Parquet reader");
+
+    let mut payloads = Vec::new();
+    let mut offsets = Vec::new();
+    for batch in reader {
+        let batch = batch.expect("Failed to read record batch");
+        let payload = batch
+            .column_by_name("payload")
+            .expect("Missing payload column")
+            .as_string::<i32>();
+        payloads.extend(
+            payload
+                .iter()
+                .map(|value| serde_json::from_str::<Value>(value.unwrap()).unwrap()),
+        );
+        let offset = batch
+            .column_by_name("offset")
+            .expect("Missing offset column")
+            .as_primitive::<UInt64Type>();
+        offsets.extend(offset.iter().flatten());
+    }
+
+    assert_eq!(payloads.len(), TEST_MESSAGE_COUNT);
+    for (i, payload) in payloads.iter().enumerate() {
+        assert_eq!(offsets[i], i as u64);
+        assert_eq!(payload, &json!({ "id": i, "name": format!("user-{i}") }));
+    }
+}
@@ -94,7 +94,7 @@ async fn should_fill_data_and_verify_after_restart(cache_setting: &'static str)
     for (idx, cg_name) in consumer_group_names.iter().enumerate() {
         let stream_id = Identifier::numeric(idx as u32).unwrap();
         client
-            .create_consumer_group(&stream_id, &topic_id, cg_name)
+            .create_consumer_group(&stream_id, &topic_id, cg_name, None, None)
             .await
             .unwrap();
     }
@@ -328,6 +328,7 @@ async fn should_handle_resource_deletion_and_restart() {
                     None,
                     IggyExpiry::NeverExpire,
                     MaxTopicSize::Unlimited,
+                    CleanupPolicy::Delete,
                 )
                 .await
                 .unwrap();
@@ -338,7 +339,13 @@ async fn should_handle_resource_deletion_and_restart() {
             // Create 3 consumer groups per topic
             for cg_idx in 0..3 {
                 client
-                    .create_consumer_group(&stream_ident, &topic_ident, &format!("cg-{}", cg_idx))
+                    .create_consumer_group(
+                        &stream_ident,
+                        &topic_ident,
+                        &format!("cg-{}", cg_idx),
+                        None,
+                        None,
+                    )
                     .await
                     .unwrap();
             }
@@ -408,6 +415,7 @@ async fn should_handle_resource_deletion_and_restart() {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::Unlimited,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
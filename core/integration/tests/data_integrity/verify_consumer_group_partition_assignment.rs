@@ -81,6 +81,7 @@ async fn should_not_duplicate_partition_assignments_after_stale_client_cleanup(
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -89,6 +90,8 @@ async fn should_not_duplicate_partition_assignments_after_stale_client_cleanup(
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -737,6 +740,7 @@ async fn should_handle_partition_delete_while_multiple_consumers_polling(harness
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -745,6 +749,8 @@ async fn should_handle_partition_delete_while_multiple_consumers_polling(harness
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -874,6 +880,7 @@ async fn should_reach_even_distribution_after_multiple_joins(harness: &TestHarne
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -882,6 +889,8 @@ async fn should_reach_even_distribution_after_multiple_joins(harness: &TestHarne
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -1278,6 +1287,7 @@ async fn should_handle_delete_partitions_with_uncommitted_work(harness: &TestHar
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -1286,6 +1296,8 @@ async fn should_handle_delete_partitions_with_uncommitted_work(harness: &TestHar
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -1622,6 +1634,7 @@ async fn should_rebalance_after_deleting_partitions(harness: &TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -1630,6 +1643,8 @@ async fn should_rebalance_after_deleting_partitions(harness: &TestHarness) {
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -1906,6 +1921,7 @@ async fn should_not_duplicate_after_reconnect_without_heartbeat(harness: &TestHa
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -1914,6 +1930,8 @@ async fn should_not_duplicate_after_reconnect_without_heartbeat(harness: &TestHa
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -2048,6 +2066,7 @@ async fn should_not_duplicate_partition_assignments_after_client_reconnect(harne
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -2056,6 +2075,8 @@ async fn should_not_duplicate_partition_assignments_after_client_reconnect(harne
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -2223,6 +2244,7 @@ async fn setup_stream_topic_cg(client: &IggyClient) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -2231,6 +2253,8 @@ async fn setup_stream_topic_cg(client: &IggyClient) {
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
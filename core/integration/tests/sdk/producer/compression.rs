@@ -0,0 +1,257 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::sdk::producer::{PARTITION_ID, STREAM_NAME, TOPIC_NAME, cleanup};
+use bytes::Bytes;
+use futures::StreamExt;
+use iggy::clients::client::IggyClient;
+use iggy::prelude::*;
+use integration::iggy_harness;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+const MESSAGES_COUNT: u32 = 100;
+
+fn create_json_payload(offset: u64) -> Bytes {
+    let item = format!(r#"{{"offset":{offset},"status":"created","amount":100}}"#);
+    Bytes::from(format!("[{}]", vec![item; 20].join(",")))
+}
+
+fn create_user_headers(offset: u64) -> BTreeMap<HeaderKey, HeaderValue> {
+    BTreeMap::from([
+        (
+            HeaderKey::try_from("content-type").unwrap(),
+            HeaderValue::try_from("application/json").unwrap(),
+        ),
+        (HeaderKey::try_from("offset").unwrap(), offset.into()),
+    ])
+}
+
+fn create_messages() -> Vec<IggyMessage> {
+    (0..MESSAGES_COUNT as u64)
+        .map(|offset| {
+            IggyMessage::builder()
+                .id(offset as u128 + 1)
+                .payload(create_json_payload(offset))
+                .user_headers(create_user_headers(offset))
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+async fn create_topic(client: &IggyClient, compression_algorithm: CompressionAlgorithm) {
+    client.create_stream(STREAM_NAME).await.unwrap();
+    client
+        .create_topic(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            TOPIC_NAME,
+            1,
+            compression_algorithm,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+}
+
+async fn poll_raw_messages(client: &IggyClient) -> Vec<IggyMessage> {
+    client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+}
+
+async fn consume_messages(client: &IggyClient) -> Vec<IggyMessage> {
+    let mut consumer = client
+        .consumer(
+            "compression-consumer",
+            STREAM_NAME,
+            TOPIC_NAME,
+            PARTITION_ID,
+        )
+        .unwrap()
+        .polling_strategy(PollingStrategy::offset(0))
+        .auto_commit(AutoCommit::Disabled)
+        .batch_length(MESSAGES_COUNT)
+        .build();
+    consumer.init().await.unwrap();
+
+    let mut messages = Vec::new();
+    while messages.len() < MESSAGES_COUNT as usize {
+        let message = consumer.next().await.unwrap().unwrap();
+        messages.push(message.message);
+    }
+    messages
+}
+
+fn assert_original_messages(messages: &[IggyMessage]) {
+    let compression_key = HeaderKey::from_str(COMPRESSION_HEADER_KEY).unwrap();
+    assert_eq!(messages.len() as u32, MESSAGES_COUNT);
+    for (offset, message) in messages.iter().enumerate() {
+        let offset = offset as u64;
+        assert_eq!(message.header.offset, offset);
+        assert_eq!(message.payload, create_json_payload(offset));
+        assert_eq!(
+            message.header.payload_length as usize,
+            message.payload.len()
+        );
+        assert!(!message.has_user_header(&compression_key).unwrap());
+        assert_eq!(
+            message.user_headers_map().unwrap().unwrap(),
+            create_user_headers(offset)
+        );
+    }
+}
+
+async fn assert_compressed_round_trip(
+    client: &IggyClient,
+    compression_algorithm: CompressionAlgorithm,
+) {
+    let compression_key = HeaderKey::from_str(COMPRESSION_HEADER_KEY).unwrap();
+    let raw_messages = poll_raw_messages(client).await;
+    assert_eq!(raw_messages.len() as u32, MESSAGES_COUNT);
+    for (offset, message) in raw_messages.iter().enumerate() {
+        assert!(message.payload.len() < create_json_payload(offset as u64).len());
+        let compression = message.get_user_header(&compression_key).unwrap().unwrap();
+        assert_eq!(
+            compression.as_uint8().unwrap(),
+            compression_algorithm.as_code()
+        );
+    }
+
+    assert_original_messages(&consume_messages(client).await);
+}
+
+#[iggy_harness]
+async fn should_compress_messages_using_topic_compression_algorithm(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    for compression_algorithm in [
+        CompressionAlgorithm::Gzip,
+        CompressionAlgorithm::Lz4,
+        CompressionAlgorithm::Zstd,
+        CompressionAlgorithm::Snappy,
+    ] {
+        create_topic(&client, compression_algorithm).await;
+        let producer = client
+            .producer(STREAM_NAME, TOPIC_NAME)
+            .unwrap()
+            .partitioning(Partitioning::partition_id(PARTITION_ID))
+            .build();
+        producer.init().await.unwrap();
+        producer.send(create_messages()).await.unwrap();
+
+        assert_compressed_round_trip(&client, compression_algorithm).await;
+        cleanup(&client).await;
+    }
+}
+
+#[iggy_harness]
+async fn should_compress_messages_using_producer_compression_algorithm(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    create_topic(&client, CompressionAlgorithm::None).await;
+    let producer = client
+        .producer(STREAM_NAME, TOPIC_NAME)
+        .unwrap()
+        .partitioning(Partitioning::partition_id(PARTITION_ID))
+        .compression(CompressionAlgorithm::Lz4)
+        .background(BackgroundConfig::builder().build())
+        .build();
+    producer.init().await.unwrap();
+    producer.send(create_messages()).await.unwrap();
+    producer.shutdown().await;
+
+    assert_compressed_round_trip(&client, CompressionAlgorithm::Lz4).await;
+    cleanup(&client).await;
+}
+
+#[iggy_harness]
+async fn should_consume_uncompressed_messages_from_compressed_topic(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    create_topic(&client, CompressionAlgorithm::Zstd).await;
+    let mut messages = create_messages();
+    client
+        .send_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    assert_original_messages(&poll_raw_messages(&client).await);
+    assert_original_messages(&consume_messages(&client).await);
+    cleanup(&client).await;
+}
+
+#[iggy_harness(server(
+    compression.allow_override = false,
+    compression.default_algorithm = "snappy"
+))]
+async fn should_use_default_compression_algorithm_when_override_is_disabled(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    create_topic(&client, CompressionAlgorithm::Gzip).await;
+    let topic = client
+        .get_topic(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(topic.compression_algorithm, CompressionAlgorithm::Snappy);
+
+    let producer = client
+        .producer(STREAM_NAME, TOPIC_NAME)
+        .unwrap()
+        .partitioning(Partitioning::partition_id(PARTITION_ID))
+        .build();
+    producer.init().await.unwrap();
+    producer.send(create_messages()).await.unwrap();
+
+    assert_compressed_round_trip(&client, CompressionAlgorithm::Snappy).await;
+    cleanup(&client).await;
+}
+
+#[iggy_harness(server(compression.default_algorithm = "snappy"))]
+async fn should_keep_explicit_none_compression_when_override_is_enabled(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    create_topic(&client, CompressionAlgorithm::None).await;
+    let topic = client
+        .get_topic(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(topic.compression_algorithm, CompressionAlgorithm::None);
+    cleanup(&client).await;
+}
@@ -0,0 +1,152 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::sdk::producer::{PARTITION_ID, STREAM_NAME, TOPIC_NAME, cleanup, init_system};
+use bytes::Bytes;
+use futures::StreamExt;
+use iggy::prelude::*;
+use integration::iggy_harness;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+const MESSAGES_PER_KEY: u64 = 10;
+const FIRST_KEY: &str = "/rvT1xP4V8u1EAhk4xDdqzqM2UOPXyy9XYkl4uRShgE=";
+const SECOND_KEY: &str = "9zyjHMYYoP2hNmKHnHvP1A5Wm8nJPuVbx0m3wZaIq4Y=";
+
+fn write_key_file(directory: &Path, path: &str, content: &str) {
+    let path = directory.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn create_user_headers(offset: u64) -> BTreeMap<HeaderKey, HeaderValue> {
+    BTreeMap::from([(HeaderKey::try_from("offset").unwrap(), offset.into())])
+}
+
+fn create_messages(offsets: std::ops::Range<u64>) -> Vec<IggyMessage> {
+    offsets
+        .map(|offset| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("secret message {offset}")))
+                .user_headers(create_user_headers(offset))
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+#[iggy_harness]
+async fn should_encrypt_messages_with_rotated_topic_keys(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    init_system(&client).await;
+
+    let keys_directory = tempfile::tempdir().unwrap();
+    write_key_file(keys_directory.path(), "keys/key-1", FIRST_KEY);
+    write_key_file(
+        keys_directory.path(),
+        &format!("{STREAM_NAME}/{TOPIC_NAME}/current"),
+        "key-1",
+    );
+    let producer_key_provider = Arc::new(
+        FileKeyProvider::new(keys_directory.path()).with_refresh_interval(IggyDuration::from(0)),
+    );
+
+    let producer = client
+        .producer(STREAM_NAME, TOPIC_NAME)
+        .unwrap()
+        .partitioning(Partitioning::partition_id(PARTITION_ID))
+        .key_provider(producer_key_provider)
+        .build();
+    producer.init().await.unwrap();
+    producer
+        .send(create_messages(0..MESSAGES_PER_KEY))
+        .await
+        .unwrap();
+
+    // Rotate the key without restarting the producer.
+    write_key_file(keys_directory.path(), "keys/key-2", SECOND_KEY);
+    write_key_file(
+        keys_directory.path(),
+        &format!("{STREAM_NAME}/{TOPIC_NAME}/current"),
+        "key-2",
+    );
+    producer
+        .send(create_messages(MESSAGES_PER_KEY..MESSAGES_PER_KEY * 2))
+        .await
+        .unwrap();
+
+    let raw_messages = client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            (MESSAGES_PER_KEY * 2) as u32,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages;
+    assert_eq!(raw_messages.len() as u64, MESSAGES_PER_KEY * 2);
+    for (offset, message) in raw_messages.iter().enumerate() {
+        let expected_key_id = if (offset as u64) < MESSAGES_PER_KEY {
+            "key-1"
+        } else {
+            "key-2"
+        };
+        assert_eq!(
+            message.encryption_key_id().unwrap().as_deref(),
+            Some(expected_key_id)
+        );
+        assert!(
+            !message
+                .has_user_header(&HeaderKey::try_from("offset").unwrap())
+                .unwrap()
+        );
+        assert_ne!(
+            message.payload,
+            Bytes::from(format!("secret message {offset}"))
+        );
+    }
+
+    let mut consumer = client
+        .consumer("encryption-consumer", STREAM_NAME, TOPIC_NAME, PARTITION_ID)
+        .unwrap()
+        .polling_strategy(PollingStrategy::offset(0))
+        .auto_commit(AutoCommit::Disabled)
+        .key_provider(Arc::new(FileKeyProvider::new(keys_directory.path())))
+        .build();
+    consumer.init().await.unwrap();
+
+    for offset in 0..MESSAGES_PER_KEY * 2 {
+        let message = consumer.next().await.unwrap().unwrap().message;
+        assert_eq!(message.header.offset, offset);
+        assert_eq!(
+            message.payload,
+            Bytes::from(format!("secret message {offset}"))
+        );
+        assert_eq!(
+            message.user_headers_map().unwrap().unwrap(),
+            create_user_headers(offset)
+        );
+    }
+
+    cleanup(&client).await;
+}
@@ -17,6 +17,8 @@
  */
 
 mod background;
+mod compression;
+mod encryption;
 
 use bytes::Bytes;
 use iggy::clients::client::IggyClient;
@@ -45,6 +47,7 @@ async fn init_system(client: &IggyClient) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -42,6 +42,7 @@ async fn seed_a2a_user(
             ..GlobalPermissions::default()
         },
         streams: None,
+        stream_patterns: None,
     };
 
     match client
@@ -18,3 +18,4 @@
  */
 
 mod jwt_tests;
+mod token_login_tests;
@@ -0,0 +1,203 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::harness::{JwksConfig, TestHarness, TestServerConfig};
+use integration::iggy_harness;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use secrecy::SecretString;
+use serde_json::{Value, json};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+const TEST_ISSUER: &str = "https://test-issuer.com";
+const TEST_AUDIENCE: &str = "iggy";
+const TEST_KEY_ID: &str = "iggy-jwt-key-1";
+const TEST_PRIVATE_KEY: &[u8] = include_bytes!("../../../../certs/iggy_key.pem");
+const JWKS_STORE_PATH: &str = "tests/server/a2a_jwt/wiremock/__files/jwks.json";
+const MAPPED_USERNAME: &str = "token-mapped-user";
+const PROVISIONED_USERNAME: &str = "token-provisioned-user";
+
+/// Creates the user with ID 1, which the trusted issuer maps all of its tokens to.
+async fn seed_mapped_user(
+    client: &IggyClient,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    client
+        .create_user(MAPPED_USERNAME, "mapped-password", UserStatus::Active, None)
+        .await?;
+    Ok(())
+}
+
+fn now_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn create_token(username: &str, permissions: &str, exp: u64) -> String {
+    let now = now_timestamp();
+    let claims = json!({
+        "jti": uuid::Uuid::now_v7().to_string(),
+        "iss": TEST_ISSUER,
+        "aud": TEST_AUDIENCE,
+        "sub": format!("external-{username}"),
+        "preferred_username": username,
+        "iggy_permissions": permissions,
+        "exp": exp,
+        "iat": now,
+        "nbf": now,
+    });
+    sign(&claims)
+}
+
+fn sign(claims: &Value) -> String {
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(TEST_KEY_ID.to_string());
+    let encoding_key = EncodingKey::from_rsa_pem(TEST_PRIVATE_KEY).unwrap();
+    encode(&header, claims, &encoding_key).unwrap()
+}
+
+/// Test that the token of the trusted issuer signs in the binary client as the configured user.
+#[iggy_harness(
+    jwks_server(store_path = "tests/server/a2a_jwt/wiremock/__files/jwks.json"),
+    seed = seed_mapped_user
+)]
+async fn test_token_login_maps_token_to_trusted_issuer_user(harness: &TestHarness) {
+    let client = harness
+        .new_client_for(TransportProtocol::Tcp)
+        .await
+        .unwrap();
+    let token = create_token("anyone", "", now_timestamp() + 3600);
+
+    let identity = client.login_with_token(&token).await.unwrap();
+
+    assert_eq!(identity.user_id, 1);
+    client.get_streams().await.unwrap();
+
+    client.logout_user().await.unwrap();
+    let expired_token = create_token("anyone", "", now_timestamp().saturating_sub(3600));
+    let result = client.login_with_token(&expired_token).await;
+    assert!(matches!(result, Err(IggyError::Unauthenticated)));
+    assert!(matches!(
+        client.login_with_token("not-a-token").await,
+        Err(IggyError::InvalidAccessToken)
+    ));
+}
+
+/// Test that the user named by the token claims is created on the first sign in with the
+/// permissions from the claims, that the creation is audited, and that the token provider
+/// is asked for the token on connect.
+#[tokio::test]
+#[parallel]
+async fn test_token_login_auto_provisions_user_from_claims() {
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_HTTP_JWT_TRUSTED_ISSUERS_0_USERNAME_CLAIM".to_string(),
+            "preferred_username".to_string(),
+        ),
+        (
+            "IGGY_HTTP_JWT_TRUSTED_ISSUERS_0_AUTO_PROVISION".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_HTTP_JWT_TRUSTED_ISSUERS_0_PERMISSIONS_CLAIM".to_string(),
+            "iggy_permissions".to_string(),
+        ),
+        ("IGGY_SYSTEM_AUDIT_ENABLED".to_string(), "true".to_string()),
+    ]);
+    let mut harness = TestHarness::builder()
+        .server(TestServerConfig::builder().extra_envs(extra_envs).build())
+        .jwks(
+            JwksConfig::builder()
+                .enabled(true)
+                .store_path(JWKS_STORE_PATH)
+                .user_id(0)
+                .build(),
+        )
+        .build()
+        .unwrap();
+    harness.start().await.unwrap();
+    let root_client = harness.tcp_root_client().await.unwrap();
+    let server_address = harness.server().tcp_addr().unwrap().to_string();
+
+    let client = IggyClient::builder()
+        .with_tcp()
+        .with_server_address(server_address.clone())
+        .with_access_token_provider(|| async {
+            Ok(SecretString::from(create_token(
+                PROVISIONED_USERNAME,
+                "read_streams read_topics",
+                now_timestamp() + 3600,
+            )))
+        })
+        .build()
+        .unwrap();
+    client.connect().await.unwrap();
+
+    let me = client.get_me().await.unwrap();
+    let user = root_client
+        .get_user(&Identifier::named(PROVISIONED_USERNAME).unwrap())
+        .await
+        .unwrap()
+        .expect("user should be provisioned");
+    assert_eq!(me.user_id, Some(user.id));
+    let global = user
+        .permissions
+        .expect("permissions should be granted")
+        .global;
+    assert!(global.read_streams);
+    assert!(global.read_topics);
+    assert!(!global.manage_streams);
+    client.get_streams().await.unwrap();
+    assert!(matches!(
+        client.create_stream("token-stream").await,
+        Err(IggyError::Unauthorized)
+    ));
+
+    // The next sign in is mapped to the already provisioned user.
+    let other_client = harness
+        .new_client_for(TransportProtocol::Tcp)
+        .await
+        .unwrap();
+    let token = create_token(PROVISIONED_USERNAME, "", now_timestamp() + 3600);
+    let identity = other_client.login_with_token(&token).await.unwrap();
+    assert_eq!(identity.user_id, user.id);
+
+    let root_token = create_token(DEFAULT_ROOT_USERNAME, "", now_timestamp() + 3600);
+    assert!(matches!(
+        other_client.login_with_token(&root_token).await,
+        Err(IggyError::InvalidCredentials)
+    ));
+
+    let created = root_client
+        .get_audit_log(&AuditLogQuery {
+            operation: Some("user.create".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let provisioned = created
+        .iter()
+        .filter(|record| record.username.as_deref() == Some(PROVISIONED_USERNAME))
+        .collect::<Vec<_>>();
+    assert_eq!(provisioned.len(), 1);
+    assert_eq!(provisioned[0].outcome, AuditOutcome::Success);
+    assert_eq!(provisioned[0].user_id, Some(user.id));
+}
@@ -0,0 +1,163 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+
+const USERNAME: &str = "audit-user";
+const PASSWORD: &str = "audit-password";
+const STREAM_NAME: &str = "audit-stream";
+const TOPIC_NAME: &str = "audit-topic";
+
+/// Tests that the administrative operations and the login attempts made with both
+/// the binary and HTTP transports are recorded as a single chain of records, that
+/// the records can be filtered, that only the users managing the servers can read them,
+/// and that the chain continues after the restart.
+pub async fn run(harness: &mut TestHarness) {
+    let root_client = harness.tcp_root_client().await.unwrap();
+    root_client.create_stream(STREAM_NAME).await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let user = root_client
+        .create_user(USERNAME, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+
+    let client = harness
+        .new_client_for(TransportProtocol::Tcp)
+        .await
+        .unwrap();
+    let result = client.login_user(USERNAME, "invalid-password").await;
+    assert!(result.is_err());
+    client.login_user(USERNAME, PASSWORD).await.unwrap();
+    let result = client.get_audit_log(&AuditLogQuery::default()).await;
+    assert!(matches!(result, Err(IggyError::Unauthorized)));
+
+    let http_client = harness.http_root_client().await.unwrap();
+    http_client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+
+    let records = root_client
+        .get_audit_log(&AuditLogQuery::default())
+        .await
+        .unwrap();
+    assert_chain(&records);
+    let stream_created = find(&records, "stream.create");
+    assert_eq!(stream_created.user_id, Some(DEFAULT_ROOT_USER_ID));
+    assert_eq!(stream_created.outcome, AuditOutcome::Success);
+    assert!(stream_created.address.is_some());
+    assert!(
+        stream_created
+            .details
+            .as_deref()
+            .is_some_and(|details| details.contains(STREAM_NAME))
+    );
+    let user_created = find(&records, "user.create");
+    assert!(
+        user_created
+            .details
+            .as_deref()
+            .is_some_and(|details| !details.contains(PASSWORD))
+    );
+
+    let logins = root_client
+        .get_audit_log(&AuditLogQuery {
+            operation: Some("user.login".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let user_logins = logins
+        .iter()
+        .filter(|record| record.username.as_deref() == Some(USERNAME))
+        .collect::<Vec<_>>();
+    assert_eq!(user_logins.len(), 2);
+    assert_eq!(user_logins[0].outcome, AuditOutcome::Failure);
+    assert!(user_logins[0].user_id.is_none());
+    assert!(user_logins[0].error.is_some());
+    assert_eq!(user_logins[1].outcome, AuditOutcome::Success);
+    assert_eq!(user_logins[1].user_id, Some(user.id));
+
+    let user_records = root_client
+        .get_audit_log(&AuditLogQuery {
+            user_id: Some(user.id),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(
+        user_records
+            .iter()
+            .all(|record| record.user_id == Some(user.id))
+    );
+
+    let topic_records = http_client
+        .get_audit_log(&AuditLogQuery {
+            operation: Some("topic".to_string()),
+            limit: Some(1),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic_records.len(), 1);
+    assert_eq!(topic_records[0].operation, "topic.create");
+    assert_eq!(topic_records[0].user_id, Some(DEFAULT_ROOT_USER_ID));
+    assert_eq!(topic_records[0].outcome, AuditOutcome::Success);
+    let last_id = records.last().unwrap().id;
+    drop(client);
+    drop(http_client);
+    drop(root_client);
+
+    harness.restart_server().await.unwrap();
+
+    let root_client = harness.tcp_root_client().await.unwrap();
+    root_client.delete_stream(&stream_id).await.unwrap();
+    let records = root_client
+        .get_audit_log(&AuditLogQuery::default())
+        .await
+        .unwrap();
+    assert_chain(&records);
+    assert!(records.last().unwrap().id > last_id);
+    let stream_deleted = find(&records, "stream.delete");
+    assert_eq!(stream_deleted.outcome, AuditOutcome::Success);
+}
+
+fn assert_chain(records: &[AuditRecord]) {
+    assert!(!records.is_empty());
+    assert!(records[0].is_intact());
+    for pair in records.windows(2) {
+        assert!(pair[1].follows(&pair[0]));
+    }
+}
+
+fn find<'a>(records: &'a [AuditRecord], operation: &str) -> &'a AuditRecord {
+    records
+        .iter()
+        .find(|record| record.operation == operation)
+        .unwrap_or_else(|| panic!("missing audit record of: {operation}"))
+}
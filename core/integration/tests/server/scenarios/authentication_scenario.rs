@@ -40,6 +40,7 @@ struct TestContext {
     stream_id: Identifier,
     topic_id: Identifier,
     user_id: Identifier,
+    role_id: Identifier,
     group_id: Identifier,
     consumer: Consumer,
 }
@@ -50,6 +51,7 @@ impl TestContext {
             stream_id: Identifier::named(STREAM_NAME).unwrap(),
             topic_id: Identifier::named(TOPIC_NAME).unwrap(),
             user_id: Identifier::numeric(1).unwrap(),
+            role_id: Identifier::named("test-role").unwrap(),
             group_id: Identifier::named("test-group").unwrap(),
             consumer: Consumer::default(),
         }
@@ -125,6 +127,9 @@ async fn test_all_commands_require_auth(client: &IggyClient) {
                 | LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE
                 | LOGIN_REGISTER_CODE
                 | LOGIN_REGISTER_WITH_PAT_CODE
+                | LOGIN_SCRAM_START_CODE
+                | LOGIN_SCRAM_FINISH_CODE
+                | LOGIN_WITH_TOKEN_CODE
         ) {
             continue;
         }
@@ -160,6 +165,10 @@ async fn test_all_commands_require_auth(client: &IggyClient) {
             GET_CLIENT_CODE => client.get_client(1).await.map(|_| ()),
             GET_CLIENTS_CODE => client.get_clients().await.map(|_| ()),
             GET_CLUSTER_METADATA_CODE => client.get_cluster_metadata().await.map(|_| ()),
+            GET_AUDIT_LOG_CODE => client
+                .get_audit_log(&AuditLogQuery::default())
+                .await
+                .map(|_| ()),
 
             // Users
             GET_USER_CODE => client.get_user(&ctx.user_id).await.map(|_| ()),
@@ -172,6 +181,21 @@ async fn test_all_commands_require_auth(client: &IggyClient) {
             UPDATE_USER_CODE => client.update_user(&ctx.user_id, Some("x"), None).await,
             UPDATE_PERMISSIONS_CODE => client.update_permissions(&ctx.user_id, None).await,
             CHANGE_PASSWORD_CODE => client.change_password(&ctx.user_id, "old", "new").await,
+            SET_USER_QUOTA_CODE => {
+                client
+                    .set_user_quota(&ctx.user_id, QuotaScope::User, None)
+                    .await
+            }
+            GET_USER_QUOTAS_CODE => client.get_user_quotas(&ctx.user_id).await.map(|_| ()),
+
+            // Roles
+            GET_ROLE_CODE => client.get_role(&ctx.role_id).await.map(|_| ()),
+            GET_ROLES_CODE => client.get_roles().await.map(|_| ()),
+            CREATE_ROLE_CODE => client.create_role("test", None).await.map(|_| ()),
+            DELETE_ROLE_CODE => client.delete_role(&ctx.role_id).await,
+            UPDATE_ROLE_CODE => client.update_role(&ctx.role_id, None).await,
+            ASSIGN_ROLE_CODE => client.assign_role(&ctx.user_id, &ctx.role_id).await,
+            UNASSIGN_ROLE_CODE => client.unassign_role(&ctx.user_id, &ctx.role_id).await,
 
             // PAT
             GET_PERSONAL_ACCESS_TOKENS_CODE => {
@@ -206,6 +230,7 @@ async fn test_all_commands_require_auth(client: &IggyClient) {
                     None,
                     IggyExpiry::NeverExpire,
                     MaxTopicSize::ServerDefault,
+                    CleanupPolicy::Delete,
                 )
                 .await
                 .map(|_| ()),
@@ -220,6 +245,7 @@ async fn test_all_commands_require_auth(client: &IggyClient) {
                         None,
                         IggyExpiry::NeverExpire,
                         MaxTopicSize::ServerDefault,
+                        CleanupPolicy::Delete,
                     )
                     .await
             }
@@ -271,6 +297,7 @@ async fn test_all_commands_require_auth(client: &IggyClient) {
                     .flush_unsaved_buffer(&ctx.stream_id, &ctx.topic_id, 0, false)
                     .await
             }
+            INIT_PRODUCER_CODE => client.init_producer(None).await.map(|_| ()),
 
             // Consumer Offsets
             GET_CONSUMER_OFFSET_CODE => client
@@ -297,8 +324,12 @@ async fn test_all_commands_require_auth(client: &IggyClient) {
                 .get_consumer_groups(&ctx.stream_id, &ctx.topic_id)
                 .await
                 .map(|_| ()),
+            GET_CONSUMER_GROUP_LAG_CODE => client
+                .get_consumer_group_lag(&ctx.stream_id, &ctx.topic_id, &ctx.group_id)
+                .await
+                .map(|_| ()),
             CREATE_CONSUMER_GROUP_CODE => client
-                .create_consumer_group(&ctx.stream_id, &ctx.topic_id, "x")
+                .create_consumer_group(&ctx.stream_id, &ctx.topic_id, "x", None, None)
                 .await
                 .map(|_| ()),
             DELETE_CONSUMER_GROUP_CODE => {
@@ -306,6 +337,21 @@ async fn test_all_commands_require_auth(client: &IggyClient) {
                     .delete_consumer_group(&ctx.stream_id, &ctx.topic_id, &ctx.group_id)
                     .await
             }
+            NACK_MESSAGE_CODE => {
+                client
+                    .nack_message(&ctx.stream_id, &ctx.topic_id, &ctx.group_id, 0, 0, "")
+                    .await
+            }
+            ACK_MESSAGES_CODE => {
+                client
+                    .ack_messages(&ctx.stream_id, &ctx.topic_id, &ctx.group_id, 0, &[0])
+                    .await
+            }
+
+            // Transactions
+            BEGIN_TRANSACTION_CODE => client.begin_transaction().await.map(|_| ()),
+            COMMIT_TRANSACTION_CODE => client.commit_transaction(1, &[]).await,
+            ABORT_TRANSACTION_CODE => client.abort_transaction(1).await,
 
             _ => panic!("Unhandled command code {code} ({name}) in auth test"),
         };
@@ -398,6 +444,7 @@ async fn setup_test_resources(client: &IggyClient) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .expect("create topic");
@@ -71,6 +71,7 @@ async fn concurrent_produce_consume_no_offset_skip(harness: &TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
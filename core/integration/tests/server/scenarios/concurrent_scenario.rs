@@ -91,6 +91,7 @@ pub async fn run(
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await
             .unwrap();
@@ -280,6 +281,7 @@ async fn execute_topics_hot(
                     None,
                     IggyExpiry::NeverExpire,
                     MaxTopicSize::ServerDefault,
+                    CleanupPolicy::Delete,
                 )
                 .await
                 .map(|_| ())
@@ -320,6 +322,7 @@ async fn execute_topics_cold(
                     None,
                     IggyExpiry::NeverExpire,
                     MaxTopicSize::ServerDefault,
+                    CleanupPolicy::Delete,
                 )
                 .await
                 .map(|_| ())
@@ -385,7 +388,7 @@ async fn execute_consumer_groups_hot(
             let topic_id = Identifier::named(TEST_TOPIC_NAME).unwrap();
             let group_name = format!("race-consumer-group-{}", client_id);
             client
-                .create_consumer_group(&stream_id, &topic_id, &group_name)
+                .create_consumer_group(&stream_id, &topic_id, &group_name, None, None)
                 .await
                 .map(|_| ())
         }));
@@ -418,7 +421,7 @@ async fn execute_consumer_groups_cold(
             let stream_id = Identifier::named(TEST_STREAM_NAME).unwrap();
             let topic_id = Identifier::named(TEST_TOPIC_NAME).unwrap();
             client
-                .create_consumer_group(&stream_id, &topic_id, DUPLICATE_CONSUMER_GROUP)
+                .create_consumer_group(&stream_id, &topic_id, DUPLICATE_CONSUMER_GROUP, None, None)
                 .await
                 .map(|_| ())
         }));
@@ -50,6 +50,7 @@ async fn init_system(client: &IggyClient) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -60,6 +61,8 @@ async fn init_system(client: &IggyClient) {
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -26,7 +26,7 @@ use iggy::prelude::CompressionAlgorithm;
 use iggy::prelude::ConsumerGroupDetails;
 use iggy::prelude::Identifier;
 use iggy::prelude::IggyExpiry;
-use iggy::prelude::MaxTopicSize;
+use iggy::prelude::{CleanupPolicy, MaxTopicSize};
 use iggy::prelude::{ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
 use integration::harness::{TestHarness, assert_clean_system, create_user, login_user};
 
@@ -55,6 +55,7 @@ pub async fn run(harness: &TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -67,6 +68,8 @@ pub async fn run(harness: &TestHarness) {
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -0,0 +1,176 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+
+const STREAM_NAME: &str = "consumer-group-lag-stream";
+const TOPIC_NAME: &str = "consumer-group-lag-topic";
+const CONSUMER_GROUP_NAME: &str = "consumer-group-lag-group";
+const PARTITIONS_COUNT: u32 = 2;
+const MESSAGES_COUNT: u32 = 10;
+const STORED_OFFSET: u64 = 3;
+
+/// Tests that the consumer group lag follows the committed offset in each partition,
+/// for both the binary and HTTP transports, and that it's exposed as the Prometheus gauges.
+pub async fn run(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    let http_client = harness.http_root_client().await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+    let group_id = Identifier::named(CONSUMER_GROUP_NAME).unwrap();
+
+    client.create_stream(STREAM_NAME).await.unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(&stream_id, &topic_id, CONSUMER_GROUP_NAME, None, None)
+        .await
+        .unwrap();
+
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|id| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(0),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    // Nothing is consumed yet, so all the messages are lagging.
+    let lag = get_lag(&client).await;
+    assert_eq!(lag.partitions.len(), PARTITIONS_COUNT as usize);
+    assert_eq!(lag.total_lag(), MESSAGES_COUNT as u64);
+    let partition = &lag.partitions[0];
+    assert_eq!(partition.committed_offset, None);
+    assert_eq!(partition.log_end_offset, MESSAGES_COUNT as u64 - 1);
+    assert_eq!(partition.lag, MESSAGES_COUNT as u64);
+    assert!(partition.lag_time.as_micros() > 0);
+    let partition = &lag.partitions[1];
+    assert_eq!(partition.committed_offset, None);
+    assert_eq!(partition.lag, 0);
+    assert_eq!(partition.lag_time.as_micros(), 0);
+
+    // The lag follows the committed offset.
+    client
+        .store_consumer_offset(
+            &Consumer::group(group_id.clone()),
+            &stream_id,
+            &topic_id,
+            Some(0),
+            STORED_OFFSET,
+        )
+        .await
+        .unwrap();
+    let expected_lag = MESSAGES_COUNT as u64 - 1 - STORED_OFFSET;
+    for client in [&client, &http_client] {
+        let lag = get_lag(client).await;
+        let partition = &lag.partitions[0];
+        assert_eq!(partition.committed_offset, Some(STORED_OFFSET));
+        assert_eq!(partition.log_end_offset, MESSAGES_COUNT as u64 - 1);
+        assert_eq!(partition.lag, expected_lag);
+        assert!(partition.lag_time.as_micros() > 0);
+    }
+
+    // The same values are exposed as the Prometheus gauges.
+    let metrics_url = format!("http://{}/metrics", harness.server().http_addr().unwrap());
+    let metrics = reqwest::get(metrics_url)
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    let labels = format!(
+        r#"{{stream="{STREAM_NAME}",topic="{TOPIC_NAME}",consumer_group="{CONSUMER_GROUP_NAME}",partition="0"}}"#
+    );
+    assert!(metrics.contains(&format!("consumer_group_lag{labels} {expected_lag}")));
+    assert!(metrics.contains(&format!(
+        "consumer_group_committed_offset{labels} {STORED_OFFSET}"
+    )));
+    assert!(metrics.contains(&format!(
+        "consumer_group_log_end_offset{labels} {}",
+        MESSAGES_COUNT - 1
+    )));
+    assert!(metrics.contains(&format!("consumer_group_lag_seconds{labels} ")));
+
+    // Consuming the remaining messages clears the lag.
+    client
+        .join_consumer_group(&stream_id, &topic_id, &group_id)
+        .await
+        .unwrap();
+    let polled = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(0),
+            &Consumer::group(group_id.clone()),
+            &PollingStrategy::next(),
+            MESSAGES_COUNT,
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled.messages.len() as u64, expected_lag);
+    let lag = get_lag(&client).await;
+    assert_eq!(lag.total_lag(), 0);
+    assert_eq!(lag.partitions[0].lag_time.as_micros(), 0);
+
+    // The lag of the unknown consumer group is not found.
+    assert!(
+        client
+            .get_consumer_group_lag(&stream_id, &topic_id, &Identifier::numeric(100).unwrap())
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    client.delete_stream(&stream_id).await.unwrap();
+}
+
+async fn get_lag(client: &IggyClient) -> ConsumerGroupLag {
+    client
+        .get_consumer_group_lag(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            &Identifier::named(CONSUMER_GROUP_NAME).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+}
@@ -47,6 +47,7 @@ async fn init_system(client: &IggyClient) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -56,6 +57,8 @@ async fn init_system(client: &IggyClient) {
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -55,6 +55,7 @@ async fn init_system(client: &IggyClient) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -80,6 +81,8 @@ async fn test_consumer_group_lifecycle(client: &IggyClient, consumer_group_name:
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             consumer_group_name,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -61,6 +61,7 @@ async fn init_system(
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -71,6 +72,8 @@ async fn init_system(
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -0,0 +1,258 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+use std::str::FromStr;
+use std::time::Duration;
+
+const STREAM_NAME: &str = "dead-letter-stream";
+const TOPIC_NAME: &str = "dead-letter-topic";
+const DEAD_LETTER_TOPIC_NAME: &str = "dead-letter-topic-dlq";
+const CONSUMER_GROUP_NAME: &str = "dead-letter-group";
+const PARTITION_ID: u32 = 0;
+const MAX_DELIVERY_COUNT: u32 = 2;
+const REDELIVERY_TIMEOUT: &str = "2s";
+const MESSAGES_COUNT: u32 = 3;
+
+/// Tests that the messages negatively acknowledged or not committed within the redelivery timeout
+/// more times than the maximum delivery count are moved to the dead-letter topic, along with
+/// the headers describing their origin, and are no longer delivered to the consumer group.
+pub async fn run(harness: &mut TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+    let dead_letter_topic_id = Identifier::named(DEAD_LETTER_TOPIC_NAME).unwrap();
+    let group_id = Identifier::named(CONSUMER_GROUP_NAME).unwrap();
+
+    client.create_stream(STREAM_NAME).await.unwrap();
+    for name in [TOPIC_NAME, DEAD_LETTER_TOPIC_NAME] {
+        client
+            .create_topic(
+                &stream_id,
+                name,
+                1,
+                CompressionAlgorithm::None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+            )
+            .await
+            .unwrap();
+    }
+
+    // The source topic can't be its own dead-letter topic.
+    let error = client
+        .create_consumer_group(
+            &stream_id,
+            &topic_id,
+            CONSUMER_GROUP_NAME,
+            Some(policy(topic_id.clone())),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::InvalidDeadLetterPolicy.as_code()
+    );
+
+    client
+        .create_consumer_group(
+            &stream_id,
+            &topic_id,
+            CONSUMER_GROUP_NAME,
+            Some(policy(dead_letter_topic_id.clone())),
+            None,
+        )
+        .await
+        .unwrap();
+    client
+        .join_consumer_group(&stream_id, &topic_id, &group_id)
+        .await
+        .unwrap();
+
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|id| {
+            IggyMessage::builder()
+                .id(id as u128 + 1)
+                .payload(Bytes::from(format!("message-{id}")))
+                .user_headers(
+                    [(
+                        HeaderKey::from_str("origin").unwrap(),
+                        HeaderValue::from_str("source").unwrap(),
+                    )]
+                    .into(),
+                )
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(poll_offsets(&client).await, vec![0, 1, 2]);
+
+    // The message can't be negatively acknowledged before it's delivered.
+    let error = client
+        .nack_message(&stream_id, &topic_id, &group_id, PARTITION_ID, 10, "")
+        .await
+        .unwrap_err();
+    assert_eq!(error.as_code(), IggyError::MessageNotDelivered(0).as_code());
+
+    // The redelivery of the negatively acknowledged message counts as the failed one,
+    // while the remaining messages are still within the redelivery timeout.
+    client
+        .nack_message(
+            &stream_id,
+            &topic_id,
+            &group_id,
+            PARTITION_ID,
+            0,
+            "invalid payload",
+        )
+        .await
+        .unwrap();
+    assert_eq!(poll_offsets(&client).await, vec![0, 1, 2]);
+
+    client
+        .nack_message(
+            &stream_id,
+            &topic_id,
+            &group_id,
+            PARTITION_ID,
+            0,
+            "invalid payload",
+        )
+        .await
+        .unwrap();
+    assert_eq!(poll_offsets(&client).await, vec![1, 2]);
+
+    let dead_letters = poll_dead_letters(&client).await;
+    assert_eq!(dead_letters.len(), 1);
+    assert_dead_letter(&dead_letters[0], 0, "invalid payload");
+
+    // The messages not committed within the redelivery timeout are dead-lettered as well.
+    let timeout = IggyDuration::from_str(REDELIVERY_TIMEOUT)
+        .unwrap()
+        .get_duration();
+    tokio::time::sleep(timeout + Duration::from_millis(500)).await;
+    assert_eq!(poll_offsets(&client).await, vec![1, 2]);
+    tokio::time::sleep(timeout + Duration::from_millis(500)).await;
+    assert!(poll_offsets(&client).await.is_empty());
+
+    let dead_letters = poll_dead_letters(&client).await;
+    assert_eq!(dead_letters.len(), 3);
+    assert_dead_letter(&dead_letters[1], 1, "redelivery timeout expired");
+    assert_dead_letter(&dead_letters[2], 2, "redelivery timeout expired");
+
+    client.delete_stream(&stream_id).await.unwrap();
+}
+
+fn policy(topic_id: Identifier) -> DeadLetterPolicy {
+    DeadLetterPolicy::new(
+        MAX_DELIVERY_COUNT,
+        IggyDuration::from_str(REDELIVERY_TIMEOUT).unwrap(),
+        Identifier::named(STREAM_NAME).unwrap(),
+        topic_id,
+    )
+}
+
+fn assert_dead_letter(message: &IggyMessage, origin_offset: u64, reason: &str) {
+    assert_eq!(message.header.id, origin_offset as u128 + 1);
+    assert_eq!(
+        message.payload,
+        Bytes::from(format!("message-{origin_offset}"))
+    );
+
+    let headers = message.user_headers_map().unwrap().unwrap();
+    let header = |key: &str| &headers[&HeaderKey::from_str(key).unwrap()];
+    assert_eq!(header("origin").as_str().unwrap(), "source");
+    assert_eq!(
+        header(DEAD_LETTER_ORIGIN_PARTITION_HEADER_KEY)
+            .as_uint32()
+            .unwrap(),
+        PARTITION_ID
+    );
+    assert_eq!(
+        header(DEAD_LETTER_ORIGIN_OFFSET_HEADER_KEY)
+            .as_uint64()
+            .unwrap(),
+        origin_offset
+    );
+    assert_eq!(
+        header(DEAD_LETTER_CONSUMER_GROUP_HEADER_KEY)
+            .as_str()
+            .unwrap(),
+        CONSUMER_GROUP_NAME
+    );
+    assert_eq!(
+        header(DEAD_LETTER_DELIVERY_COUNT_HEADER_KEY)
+            .as_uint32()
+            .unwrap(),
+        MAX_DELIVERY_COUNT
+    );
+    assert_eq!(
+        header(DEAD_LETTER_REASON_HEADER_KEY).as_str().unwrap(),
+        reason
+    );
+}
+
+async fn poll_offsets(client: &IggyClient) -> Vec<u64> {
+    client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            None,
+            &Consumer::group(Identifier::named(CONSUMER_GROUP_NAME).unwrap()),
+            &PollingStrategy::next(),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| message.header.offset)
+        .collect()
+}
+
+async fn poll_dead_letters(client: &IggyClient) -> Vec<IggyMessage> {
+    client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(DEAD_LETTER_TOPIC_NAME).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+}
@@ -0,0 +1,233 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+use std::str::FromStr;
+use std::time::Duration;
+
+const STREAM_NAME: &str = "delayed-delivery-stream";
+const TOPIC_NAME: &str = "delayed-delivery-topic";
+const CONSUMER_GROUP_NAME: &str = "delayed-delivery-group";
+const PARTITION_ID: u32 = 0;
+const DELAY: &str = "10s";
+const MESSAGES_COUNT: u32 = 10;
+
+/// Tests that the delayed message is hidden until its delivery time, so polling stops right
+/// before it, except for the shared subscription which skips it, and that its delivery time
+/// is kept after the server restart.
+pub async fn run(harness: &mut TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+    let group_id = Identifier::named(CONSUMER_GROUP_NAME).unwrap();
+
+    client.create_stream(STREAM_NAME).await.unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(
+            &stream_id,
+            &topic_id,
+            CONSUMER_GROUP_NAME,
+            None,
+            Some(SharedSubscription::new(
+                IggyDuration::from_str("30s").unwrap(),
+            )),
+        )
+        .await
+        .unwrap();
+
+    // The message with the past delivery time is visible right away.
+    let deliver_at = IggyTimestamp::from(IggyTimestamp::now().as_micros() + delay().as_micros());
+    let mut sent = messages(5);
+    sent[0].set_deliver_at(IggyTimestamp::from(1)).unwrap();
+    sent[2].set_deliver_at(deliver_at).unwrap();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut sent,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        poll_offsets(&client, PollingStrategy::offset(0)).await,
+        vec![0, 1]
+    );
+    assert_eq!(
+        poll_offsets(&client, PollingStrategy::timestamp(IggyTimestamp::from(0))).await,
+        vec![0, 1, 3, 4]
+    );
+    assert!(
+        poll_offsets(&client, PollingStrategy::offset(2))
+            .await
+            .is_empty()
+    );
+    assert_eq!(poll_shared_offsets(&client).await, vec![0, 1, 3, 4]);
+    client
+        .ack_messages(
+            &stream_id,
+            &topic_id,
+            &group_id,
+            PARTITION_ID,
+            &[0, 1, 3, 4],
+        )
+        .await
+        .unwrap();
+
+    // The delivery time is persisted along with the messages.
+    client
+        .flush_unsaved_buffer(&stream_id, &topic_id, PARTITION_ID, true)
+        .await
+        .unwrap();
+    drop(client);
+    harness.restart_server().await.unwrap();
+    let client = harness.tcp_root_client().await.unwrap();
+    client
+        .join_consumer_group(&stream_id, &topic_id, &group_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        poll_offsets(&client, PollingStrategy::offset(0)).await,
+        vec![0, 1]
+    );
+
+    // Once due, the message is delivered in the partition order.
+    wait_until(deliver_at).await;
+    assert_eq!(
+        poll_offsets(&client, PollingStrategy::offset(0)).await,
+        vec![0, 1, 2, 3, 4]
+    );
+    assert_eq!(poll_shared_offsets(&client).await, vec![2]);
+
+    // The producer delays all the messages it sends.
+    let producer = client
+        .producer(STREAM_NAME, TOPIC_NAME)
+        .unwrap()
+        .partitioning(Partitioning::partition_id(PARTITION_ID))
+        .delivery_delay(IggyDuration::from_str(DELAY).unwrap())
+        .build();
+    producer.init().await.unwrap();
+    producer.send(messages(2)).await.unwrap();
+    let deliver_at = IggyTimestamp::from(IggyTimestamp::now().as_micros() + delay().as_micros());
+    assert_eq!(
+        poll_offsets(&client, PollingStrategy::offset(0)).await,
+        vec![0, 1, 2, 3, 4]
+    );
+    wait_until(deliver_at).await;
+    let polled = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &consumer(),
+            &PollingStrategy::offset(5),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled.messages.len(), 2);
+    assert!(
+        polled
+            .messages
+            .iter()
+            .all(|message| message.deliver_at().unwrap().is_some())
+    );
+
+    client.delete_stream(&stream_id).await.unwrap();
+}
+
+fn delay() -> IggyDuration {
+    IggyDuration::from_str(DELAY).unwrap()
+}
+
+fn consumer() -> Consumer {
+    Consumer::new(Identifier::named("delayed-delivery-consumer").unwrap())
+}
+
+fn messages(count: u32) -> Vec<IggyMessage> {
+    (0..count)
+        .map(|id| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+async fn wait_until(deliver_at: IggyTimestamp) {
+    let remaining = deliver_at
+        .as_micros()
+        .saturating_sub(IggyTimestamp::now().as_micros());
+    tokio::time::sleep(Duration::from_micros(remaining) + Duration::from_millis(200)).await;
+}
+
+async fn poll_offsets(client: &IggyClient, strategy: PollingStrategy) -> Vec<u64> {
+    client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(PARTITION_ID),
+            &consumer(),
+            &strategy,
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| message.header.offset)
+        .collect()
+}
+
+async fn poll_shared_offsets(client: &IggyClient) -> Vec<u64> {
+    client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::group(Identifier::named(CONSUMER_GROUP_NAME).unwrap()),
+            &PollingStrategy::next(),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| message.header.offset)
+        .collect()
+}
@@ -55,6 +55,7 @@ async fn should_fill_data_with_headers_and_verify_after_restart_using_api(encryp
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -362,6 +363,7 @@ async fn should_encrypt_and_decrypt_headers_with_client_side_encryption(
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -480,6 +482,175 @@ async fn should_encrypt_and_decrypt_headers_with_client_side_encryption(
     }
 }
 
+#[tokio::test]
+#[parallel]
+async fn should_rotate_topic_key_and_read_messages_encrypted_with_previous_keys() {
+    let mut harness = TestHarness::builder()
+        .server(build_server_config(true))
+        .build()
+        .unwrap();
+
+    harness.start().await.unwrap();
+
+    let client = harness.tcp_root_client().await.unwrap();
+    let stream_id = Identifier::named("test-stream-rotation").unwrap();
+    let topic_id = Identifier::named("test-topic-rotation").unwrap();
+
+    client.create_stream("test-stream-rotation").await.unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            "test-topic-rotation",
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+
+    // The first batch uses the default key, each next one a newly rotated topic key.
+    let messages_per_batch = 10;
+    for batch in 0..3u64 {
+        if batch > 0 {
+            client
+                .rotate_topic_key(&stream_id, &topic_id)
+                .await
+                .unwrap();
+        }
+        send_rotation_batch(&client, &stream_id, &topic_id, batch, messages_per_batch).await;
+    }
+
+    assert_rotation_batches_polled(&client, &stream_id, &topic_id, 3, messages_per_batch).await;
+
+    harness.restart_server().await.unwrap();
+
+    let client = harness.tcp_root_client().await.unwrap();
+    assert_rotation_batches_polled(&client, &stream_id, &topic_id, 3, messages_per_batch).await;
+
+    client
+        .rotate_topic_key(&stream_id, &topic_id)
+        .await
+        .unwrap();
+    send_rotation_batch(&client, &stream_id, &topic_id, 3, messages_per_batch).await;
+    assert_rotation_batches_polled(&client, &stream_id, &topic_id, 4, messages_per_batch).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn should_fail_to_rotate_topic_key_when_encryption_is_disabled() {
+    let mut harness = TestHarness::builder()
+        .server(build_server_config(false))
+        .build()
+        .unwrap();
+
+    harness.start().await.unwrap();
+
+    let client = harness.tcp_root_client().await.unwrap();
+    let stream_id = Identifier::named("test-stream-no-rotation").unwrap();
+
+    client
+        .create_stream("test-stream-no-rotation")
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            "test-topic-no-rotation",
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+
+    let result = client
+        .rotate_topic_key(
+            &stream_id,
+            &Identifier::named("test-topic-no-rotation").unwrap(),
+        )
+        .await;
+    assert!(matches!(result, Err(IggyError::EncryptionDisabled)));
+}
+
+async fn send_rotation_batch(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    batch: u64,
+    messages_count: u64,
+) {
+    let mut messages = (0..messages_count)
+        .map(|i| {
+            let mut headers = BTreeMap::new();
+            headers.insert(HeaderKey::try_from("batch").unwrap(), batch.into());
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("Message batch {batch} index {i}")))
+                .user_headers(headers)
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    client
+        .send_messages(
+            stream_id,
+            topic_id,
+            &Partitioning::partition_id(0),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn assert_rotation_batches_polled(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    batches_count: u64,
+    messages_per_batch: u64,
+) {
+    let polled = client
+        .poll_messages(
+            stream_id,
+            topic_id,
+            Some(0),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            (batches_count * messages_per_batch) as u32,
+            false,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        polled.messages.len() as u64,
+        batches_count * messages_per_batch
+    );
+    for (position, message) in polled.messages.iter().enumerate() {
+        let batch = position as u64 / messages_per_batch;
+        let index = position as u64 % messages_per_batch;
+        assert_eq!(
+            message.payload,
+            Bytes::from(format!("Message batch {batch} index {index}"))
+        );
+        let headers = message.user_headers_map().unwrap().unwrap();
+        assert_eq!(
+            headers
+                .get(&HeaderKey::try_from("batch").unwrap())
+                .unwrap()
+                .as_uint64()
+                .unwrap(),
+            batch
+        );
+    }
+}
+
 fn encryption_enabled() -> bool {
     true
 }
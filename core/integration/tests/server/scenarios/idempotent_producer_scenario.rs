@@ -0,0 +1,150 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+
+const STREAM_NAME: &str = "idempotent-producer-stream";
+const TOPIC_NAME: &str = "idempotent-producer-topic";
+const PARTITION_ID: u32 = 0;
+const POLL_BATCH_SIZE: u32 = 100;
+
+/// Tests that the batches of an idempotent producer are appended exactly once per sequence,
+/// that gaps in the sequence numbers are rejected, that the previous epoch of the producer
+/// is fenced once it's initialized again, and that the sequences survive the server restart.
+pub async fn run(harness: &mut TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+
+    client.create_stream(STREAM_NAME).await.unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+
+    let producer = client.init_producer(None).await.unwrap();
+    send(&client, &producer, 0, 3).await.unwrap();
+    send(&client, &producer, 3, 2).await.unwrap();
+
+    // The retried batch is acknowledged, but not appended again, even though the message IDs differ.
+    send(&client, &producer, 3, 2).await.unwrap();
+    assert_eq!(poll_offsets(&client).await, vec![0, 1, 2, 3, 4]);
+
+    let error = send(&client, &producer, 7, 1).await.unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::OutOfOrderSequence(0, 0, 0).as_code()
+    );
+
+    // Resuming the producer bumps its epoch and restarts the sequence, fencing off the old epoch.
+    let resumed = client.init_producer(Some(producer.id)).await.unwrap();
+    assert_eq!(resumed.id, producer.id);
+    assert!(resumed.epoch > producer.epoch);
+    send(&client, &resumed, 0, 1).await.unwrap();
+    let error = send(&client, &producer, 5, 1).await.unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::ProducerFenced(0, 0, 0).as_code()
+    );
+    assert_eq!(poll_offsets(&client).await, vec![0, 1, 2, 3, 4, 5]);
+
+    // The sequences are persisted along with the messages saved on disk.
+    client
+        .flush_unsaved_buffer(&stream_id, &topic_id, PARTITION_ID, true)
+        .await
+        .unwrap();
+    drop(client);
+    harness.restart_server().await.unwrap();
+    let client = harness.tcp_root_client().await.unwrap();
+    send(&client, &resumed, 0, 1).await.unwrap();
+    assert_eq!(poll_offsets(&client).await, vec![0, 1, 2, 3, 4, 5]);
+
+    // The producer tracks the sequences of its batches on its own.
+    let iggy_producer = client
+        .producer(STREAM_NAME, TOPIC_NAME)
+        .unwrap()
+        .partitioning(Partitioning::partition_id(PARTITION_ID))
+        .enable_idempotence()
+        .build();
+    iggy_producer.init().await.unwrap();
+    assert!(iggy_producer.producer_identity().is_some());
+    iggy_producer.send(messages(0, 2)).await.unwrap();
+    iggy_producer.send(messages(0, 1)).await.unwrap();
+    assert_eq!(poll_offsets(&client).await, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+    client.delete_stream(&stream_id).await.unwrap();
+}
+
+fn messages(first_id: u64, count: u64) -> Vec<IggyMessage> {
+    (first_id..first_id + count)
+        .map(|id| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+async fn send(
+    client: &IggyClient,
+    producer: &ProducerIdentity,
+    base_sequence: u64,
+    count: u64,
+) -> Result<(), IggyError> {
+    client
+        .send_idempotent_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            PARTITION_ID,
+            producer,
+            base_sequence,
+            &mut messages(base_sequence, count),
+        )
+        .await
+}
+
+async fn poll_offsets(client: &IggyClient) -> Vec<u64> {
+    client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            POLL_BATCH_SIZE,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| message.header.offset)
+        .collect()
+}
@@ -98,12 +98,13 @@ async fn initialize(client: &IggyClient, stream: &Identifier, topic: &Identifier
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::Unlimited,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
 
     client
-        .create_consumer_group(stream, topic, CONSUMER_GROUP_NAME)
+        .create_consumer_group(stream, topic, CONSUMER_GROUP_NAME, None, None)
         .await
         .unwrap();
 
@@ -0,0 +1,190 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::time::Duration;
+
+const STREAM_NAME: &str = "log-compaction-stream";
+const TOPIC_NAME: &str = "log-compaction-topic";
+const PARTITION_ID: u32 = 0;
+const KEYS_COUNT: u64 = 5;
+const MESSAGES_COUNT: u64 = 100;
+const POLL_BATCH_SIZE: u32 = 1000;
+
+/// Buffer time for the compactor to rewrite the sealed segments.
+const COMPACTOR_BUFFER: Duration = Duration::from_millis(500);
+
+fn create_message(offset: u64, key: Option<u64>, tombstone: bool) -> IggyMessage {
+    let mut headers = BTreeMap::new();
+    if let Some(key) = key {
+        headers.insert(
+            HeaderKey::from_str(MESSAGE_KEY_HEADER_KEY).unwrap(),
+            HeaderValue::try_from(format!("key-{key}").as_str()).unwrap(),
+        );
+    }
+    if tombstone {
+        headers.insert(
+            HeaderKey::from_str(TOMBSTONE_HEADER_KEY).unwrap(),
+            true.into(),
+        );
+    }
+
+    let builder = IggyMessage::builder()
+        .id(offset as u128 + 1)
+        .payload(Bytes::from(format!("message-{offset}")));
+    if headers.is_empty() {
+        builder.build().unwrap()
+    } else {
+        builder.user_headers(headers).build().unwrap()
+    }
+}
+
+/// Every third message is unkeyed, the others cycle through the keys.
+fn message_key(offset: u64) -> Option<u64> {
+    (!offset.is_multiple_of(3)).then_some(offset % KEYS_COUNT)
+}
+
+/// Tests that the sealed segments of a compacted topic retain only the latest message per key,
+/// along with all the unkeyed messages, while the offsets of the retained messages are preserved.
+/// The last message of the first key is a tombstone, retained until the tombstone retention passes.
+pub async fn run(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+
+    client.create_stream(STREAM_NAME).await.unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Compact,
+        )
+        .await
+        .unwrap();
+
+    let topic = client
+        .get_topic(&stream_id, &topic_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(topic.cleanup_policy, CleanupPolicy::Compact);
+
+    let tombstone_offset = (0..MESSAGES_COUNT)
+        .rev()
+        .find(|&offset| message_key(offset) == Some(0))
+        .unwrap();
+    let mut latest_offsets = HashMap::new();
+    for offset in 0..MESSAGES_COUNT {
+        let key = message_key(offset);
+        if let Some(key) = key {
+            latest_offsets.insert(key, offset);
+        }
+        let mut messages = vec![create_message(offset, key, offset == tombstone_offset)];
+        client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(PARTITION_ID),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+    }
+
+    tokio::time::sleep(COMPACTOR_BUFFER).await;
+
+    let polled = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            POLL_BATCH_SIZE,
+            false,
+        )
+        .await
+        .unwrap();
+
+    let offsets: Vec<u64> = polled.messages.iter().map(|m| m.header.offset).collect();
+    assert!(
+        offsets.len() < MESSAGES_COUNT as usize,
+        "Expected the compacted segments to have fewer messages, got: {}",
+        offsets.len()
+    );
+    assert!(
+        offsets.windows(2).all(|w| w[0] < w[1]),
+        "Offsets must be strictly increasing: {offsets:?}"
+    );
+    assert_eq!(offsets.last(), Some(&(MESSAGES_COUNT - 1)));
+
+    for message in &polled.messages {
+        let offset = message.header.offset;
+        assert_eq!(message.payload, Bytes::from(format!("message-{offset}")));
+    }
+
+    for offset in (0..MESSAGES_COUNT).filter(|&offset| message_key(offset).is_none()) {
+        assert!(
+            offsets.contains(&offset),
+            "Unkeyed message at offset {offset} must be retained"
+        );
+    }
+
+    for (key, latest_offset) in latest_offsets {
+        assert!(
+            offsets.contains(&latest_offset),
+            "Latest message of key {key} at offset {latest_offset} must be retained"
+        );
+    }
+
+    // Polling from an offset removed by the compaction continues from the next retained one.
+    let removed_offset = (0..MESSAGES_COUNT)
+        .find(|offset| !offsets.contains(offset))
+        .unwrap();
+    let polled = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(removed_offset),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    let next_offset = offsets
+        .iter()
+        .find(|&&offset| offset > removed_offset)
+        .copied();
+    assert_eq!(
+        polled.messages.first().map(|m| m.header.offset),
+        next_offset
+    );
+
+    client.delete_stream(&stream_id).await.unwrap();
+}
@@ -203,6 +203,7 @@ async fn generate_enough_logs(client: &IggyClient) -> Result<(), String> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::Unlimited,
+                CleanupPolicy::Delete,
             )
             .await
             .map_err(|e| format!("Failed to create topic {topic_name}: {e}"))?;
@@ -58,6 +58,7 @@ pub async fn run_expiry_after_rotation(client: &IggyClient, data_path: &Path) {
             None,
             IggyExpiry::ExpireDuration(IggyDuration::from(expiry)),
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -174,6 +175,7 @@ pub async fn run_active_segment_protection(client: &IggyClient, data_path: &Path
             None,
             IggyExpiry::ExpireDuration(IggyDuration::from(expiry)),
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -239,6 +241,7 @@ pub async fn run_size_based_retention(client: &IggyClient, data_path: &Path) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::Custom(IggyByteSize::from(max_size_bytes)),
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -333,6 +336,7 @@ pub async fn run_combined_retention(client: &IggyClient, data_path: &Path) {
             None,
             IggyExpiry::ExpireDuration(IggyDuration::from(expiry)),
             MaxTopicSize::Custom(IggyByteSize::from(500 * 1024)), // 500KB (won't trigger)
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -406,6 +410,7 @@ pub async fn run_expiry_with_multiple_partitions(client: &IggyClient, data_path:
             None,
             IggyExpiry::ExpireDuration(IggyDuration::from(expiry)),
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -519,6 +524,7 @@ pub async fn run_fair_size_based_cleanup_multipartition(client: &IggyClient, dat
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::Custom(IggyByteSize::from(max_size_bytes)),
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -604,6 +610,7 @@ pub async fn run_expiry_respects_consumer_offset(client: &IggyClient, data_path:
             None,
             IggyExpiry::ExpireDuration(IggyDuration::from(expiry)),
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
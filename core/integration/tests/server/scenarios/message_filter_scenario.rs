@@ -0,0 +1,221 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+const STREAM_NAME: &str = "message-filter-stream";
+const TOPIC_NAME: &str = "message-filter-topic";
+const CONSUMER_GROUP_NAME: &str = "message-filter-group";
+const PARTITION_ID: u32 = 0;
+const MESSAGES_COUNT: u32 = 30;
+
+/// Tests that only the messages with the user headers matching the filter are polled,
+/// for each polling strategy and both the binary and HTTP transports, that the consumer
+/// offset is committed up to the last matching message and that the filter is rejected
+/// for the shared subscription.
+pub async fn run(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+
+    client.create_stream(STREAM_NAME).await.unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+    let mut sent = messages();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut sent,
+        )
+        .await
+        .unwrap();
+
+    let acme = (0..MESSAGES_COUNT as u64).step_by(3).collect::<Vec<_>>();
+    assert_eq!(
+        poll_offsets(
+            &client,
+            PollingStrategy::offset(0),
+            100,
+            r#"tenant == "acme""#
+        )
+        .await,
+        acme
+    );
+    assert_eq!(
+        poll_offsets(&client, PollingStrategy::first(), 100, "urgent").await,
+        (0..MESSAGES_COUNT as u64).step_by(5).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        poll_offsets(
+            &client,
+            PollingStrategy::offset(0),
+            2,
+            r#"tenant == "acme" && priority >= 10"#
+        )
+        .await,
+        vec![12, 15]
+    );
+    assert_eq!(
+        poll_offsets(
+            &client,
+            PollingStrategy::timestamp(IggyTimestamp::from(0)),
+            100,
+            r#"tenant != "acme" && !urgent && priority < 8"#
+        )
+        .await,
+        vec![1, 2, 4, 7]
+    );
+    assert!(
+        poll_offsets(&client, PollingStrategy::offset(0), 100, "priority > 100")
+            .await
+            .is_empty()
+    );
+
+    // The offset is committed up to the last matching message.
+    assert_eq!(
+        poll_offsets(
+            &client,
+            PollingStrategy::next(),
+            3,
+            "priority > 20 && urgent"
+        )
+        .await,
+        vec![25]
+    );
+    assert_eq!(
+        poll_offsets(&client, PollingStrategy::next(), 3, r#"tenant == "acme""#).await,
+        vec![27]
+    );
+    assert!(
+        poll_offsets(&client, PollingStrategy::next(), 3, "urgent")
+            .await
+            .is_empty()
+    );
+
+    // The HTTP endpoint accepts the same filter.
+    let http_client = harness.http_root_client().await.unwrap();
+    assert_eq!(
+        poll_offsets(
+            &http_client,
+            PollingStrategy::offset(0),
+            100,
+            r#"tenant == "acme""#
+        )
+        .await,
+        acme
+    );
+
+    // The messages skipped by the filter would never be acknowledged.
+    let group_id = Identifier::named(CONSUMER_GROUP_NAME).unwrap();
+    client
+        .create_consumer_group(
+            &stream_id,
+            &topic_id,
+            CONSUMER_GROUP_NAME,
+            None,
+            Some(SharedSubscription::new(
+                IggyDuration::from_str("30s").unwrap(),
+            )),
+        )
+        .await
+        .unwrap();
+    client
+        .join_consumer_group(&stream_id, &topic_id, &group_id)
+        .await
+        .unwrap();
+    let error = client
+        .poll_filtered_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::group(group_id),
+            &PollingStrategy::next(),
+            MESSAGES_COUNT,
+            false,
+            &MessageFilter::from_str("urgent").unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(error, IggyError::InvalidMessageFilter(_)));
+
+    client.delete_stream(&stream_id).await.unwrap();
+}
+
+fn messages() -> Vec<IggyMessage> {
+    (0..MESSAGES_COUNT)
+        .map(|id| {
+            let mut headers = BTreeMap::new();
+            let tenant = if id % 3 == 0 { "acme" } else { "other" };
+            headers.insert(
+                HeaderKey::try_from("tenant").unwrap(),
+                HeaderValue::try_from(tenant).unwrap(),
+            );
+            headers.insert(HeaderKey::try_from("priority").unwrap(), (id as i64).into());
+            if id % 5 == 0 {
+                headers.insert(HeaderKey::try_from("urgent").unwrap(), true.into());
+            }
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message-{id}")))
+                .user_headers(headers)
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+async fn poll_offsets(
+    client: &IggyClient,
+    strategy: PollingStrategy,
+    count: u32,
+    filter: &str,
+) -> Vec<u64> {
+    client
+        .poll_filtered_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::new(Identifier::named("message-filter-consumer").unwrap()),
+            &strategy,
+            count,
+            strategy.kind == PollingKind::Next,
+            &MessageFilter::from_str(filter).unwrap(),
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| message.header.offset)
+        .collect()
+}
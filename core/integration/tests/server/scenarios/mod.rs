@@ -16,12 +16,14 @@
  * under the License.
  */
 
+pub mod audit_log_scenario;
 pub mod authentication_scenario;
 pub mod bench_scenario;
 pub mod concurrent_produce_consume_scenario;
 pub mod concurrent_scenario;
 pub mod consumer_group_auto_commit_reconnection_scenario;
 pub mod consumer_group_join_scenario;
+pub mod consumer_group_lag_scenario;
 pub mod consumer_group_new_messages_after_restart_scenario;
 pub mod consumer_group_offset_cleanup_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
@@ -29,26 +31,38 @@ pub mod consumer_group_with_single_client_polling_messages_scenario;
 pub mod consumer_timestamp_polling_scenario;
 pub mod create_message_payload;
 pub mod cross_protocol_pat_scenario;
+pub mod dead_letter_scenario;
+pub mod delayed_delivery_scenario;
 pub mod encryption_scenario;
+pub mod idempotent_producer_scenario;
 pub mod invalid_consumer_offset_scenario;
+pub mod log_compaction_scenario;
 pub mod log_rotation_scenario;
 pub mod message_cleanup_scenario;
+pub mod message_filter_scenario;
 pub mod message_headers_scenario;
 pub mod message_size_scenario;
+pub mod mutual_tls_scenario;
 pub mod offset_scenario;
 pub mod permissions_scenario;
 pub mod purge_delete_scenario;
 pub mod read_during_persistence_scenario;
 pub mod reconnect_after_restart_scenario;
 pub mod restart_offset_skip_scenario;
+pub mod role_scenario;
+pub mod scram_login_scenario;
 pub mod segment_rotation_race_scenario;
+pub mod shared_subscription_scenario;
 pub mod single_message_per_batch_scenario;
 pub mod snapshot_scenario;
 pub mod stale_client_consumer_group_scenario;
 pub mod stream_size_validation_scenario;
 pub mod system_scenario;
 pub mod tcp_tls_scenario;
+pub mod tiered_storage_scenario;
 pub mod timestamp_scenario;
+pub mod transactions_scenario;
+pub mod user_quota_scenario;
 pub mod user_scenario;
 pub mod websocket_tls_scenario;
 
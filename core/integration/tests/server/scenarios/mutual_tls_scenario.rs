@@ -0,0 +1,170 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::harness::{TestHarness, TestServerConfig, TlsConfig};
+use rcgen::{
+    BasicConstraints, CertificateParams, DnType, ExtendedKeyUsagePurpose, IsCa, Issuer, KeyPair,
+};
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+const USERNAME: &str = "mtls-user";
+const PASSWORD: &str = "secret-password";
+const UNKNOWN_USERNAME: &str = "mtls-unknown-user";
+
+#[tokio::test]
+#[parallel]
+async fn should_authenticate_tcp_tls_client_using_certificate_mapped_to_user() {
+    let cert_dir = tempfile::tempdir().unwrap();
+    let ca = generate_ca(cert_dir.path());
+    let (user_cert_file, user_key_file) =
+        generate_client_certificate(cert_dir.path(), &ca, USERNAME);
+    let (unknown_cert_file, unknown_key_file) =
+        generate_client_certificate(cert_dir.path(), &ca, UNKNOWN_USERNAME);
+
+    let mut harness = TestHarness::builder()
+        .server(build_server_config(&cert_dir.path().join("ca.pem")))
+        .build()
+        .unwrap();
+    harness.start().await.unwrap();
+
+    let root_client = harness.tcp_root_client().await.unwrap();
+    let user = root_client
+        .create_user(USERNAME, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+    let server_address = harness.server().tcp_addr().unwrap().to_string();
+
+    let client = create_client(&server_address, Some((&user_cert_file, &user_key_file))).await;
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.user_id, Some(user.id));
+    client.get_streams().await.unwrap();
+
+    let unknown_client = create_client(
+        &server_address,
+        Some((&unknown_cert_file, &unknown_key_file)),
+    )
+    .await;
+    let result = unknown_client.get_streams().await;
+    assert!(matches!(result, Err(IggyError::Unauthenticated)));
+
+    let anonymous_client = create_client(&server_address, None).await;
+    let result = anonymous_client.get_streams().await;
+    assert!(matches!(result, Err(IggyError::Unauthenticated)));
+
+    anonymous_client
+        .login_user(USERNAME, PASSWORD)
+        .await
+        .unwrap();
+    anonymous_client.get_streams().await.unwrap();
+
+    let logins = root_client
+        .get_audit_log(&AuditLogQuery {
+            operation: Some("user.login_with_certificate".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let user_login = logins
+        .iter()
+        .find(|record| record.username.as_deref() == Some(USERNAME))
+        .expect("missing audit record of the certificate login");
+    assert_eq!(user_login.outcome, AuditOutcome::Success);
+    assert_eq!(user_login.user_id, Some(user.id));
+    let unknown_login = logins
+        .iter()
+        .find(|record| record.username.as_deref() == Some(UNKNOWN_USERNAME))
+        .expect("missing audit record of the rejected certificate login");
+    assert_eq!(unknown_login.outcome, AuditOutcome::Failure);
+    assert!(unknown_login.user_id.is_none());
+    assert!(unknown_login.error.is_some());
+}
+
+async fn create_client(server_address: &str, certificate: Option<(&str, &str)>) -> IggyClient {
+    let config = TcpClientConfig {
+        server_address: server_address.to_string(),
+        tls_enabled: true,
+        tls_domain: "localhost".to_string(),
+        tls_validate_certificate: false,
+        tls_client_cert_file: certificate.map(|(cert_file, _)| cert_file.to_string()),
+        tls_client_key_file: certificate.map(|(_, key_file)| key_file.to_string()),
+        ..TcpClientConfig::default()
+    };
+    let client = TcpClient::create(Arc::new(config)).unwrap();
+    Client::connect(&client).await.unwrap();
+    IggyClient::create(ClientWrapper::Tcp(client), None, None)
+}
+
+fn build_server_config(ca_file: &Path) -> TestServerConfig {
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_TCP_TLS_CLIENT_AUTH_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_TCP_TLS_CLIENT_AUTH_CA_FILE".to_string(),
+            ca_file.display().to_string(),
+        ),
+        (
+            "IGGY_TCP_TLS_CLIENT_AUTH_PRINCIPAL".to_string(),
+            "common_name".to_string(),
+        ),
+        ("IGGY_SYSTEM_AUDIT_ENABLED".to_string(), "true".to_string()),
+    ]);
+
+    TestServerConfig::builder()
+        .tls(TlsConfig::self_signed())
+        .extra_envs(extra_envs)
+        .build()
+}
+
+fn generate_ca(dir: &Path) -> Issuer<'static, KeyPair> {
+    let key = KeyPair::generate().unwrap();
+    let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+    params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    params
+        .distinguished_name
+        .push(DnType::CommonName, "iggy-test-ca");
+    let certificate = params.self_signed(&key).unwrap();
+    std::fs::write(dir.join("ca.pem"), certificate.pem()).unwrap();
+    Issuer::new(params, key)
+}
+
+fn generate_client_certificate(
+    dir: &Path,
+    ca: &Issuer<'static, KeyPair>,
+    username: &str,
+) -> (String, String) {
+    let key = KeyPair::generate().unwrap();
+    let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+    params.distinguished_name.push(DnType::CommonName, username);
+    params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ClientAuth];
+    let certificate = params.signed_by(&key, ca).unwrap();
+
+    let cert_file = dir.join(format!("{username}_cert.pem"));
+    let key_file = dir.join(format!("{username}_key.pem"));
+    std::fs::write(&cert_file, certificate.pem()).unwrap();
+    std::fs::write(&key_file, key.serialize_pem()).unwrap();
+    (
+        cert_file.display().to_string(),
+        key_file.display().to_string(),
+    )
+}
@@ -125,6 +125,7 @@ async fn init_system(client: &IggyClient, stream_name: &str, topic_name: &str) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -22,6 +22,8 @@
 //! - Global permissions (servers, users, streams, topics, messages)
 //! - Stream-specific permissions
 //! - Topic-specific permissions
+//! - Stream and topic name-pattern permissions
+//! - Consumer-group-level permissions
 //! - Permission inheritance hierarchy
 //! - Positive cases (permission grants access)
 //! - Negative cases (missing permission denies access)
@@ -55,6 +57,7 @@ pub async fn run(harness: &TestHarness) {
     test_message_permissions(harness, &root_client).await;
     test_stream_specific_permissions(harness, &root_client).await;
     test_topic_specific_permissions(harness, &root_client).await;
+    test_pattern_permissions(harness, &root_client).await;
 
     // Permission inheritance/implication tests
     test_global_permission_inheritance(harness, &root_client).await;
@@ -63,6 +66,7 @@ pub async fn run(harness: &TestHarness) {
 
     // Consumer group operations matrix
     test_consumer_group_operations(harness, &root_client).await;
+    test_consumer_group_permissions(harness, &root_client).await;
 
     // Union semantics tests
     test_union_semantics(harness, &root_client).await;
@@ -96,6 +100,7 @@ async fn setup_test_resources(root_client: &IggyClient) {
                     None,
                     IggyExpiry::NeverExpire,
                     MaxTopicSize::ServerDefault,
+                    CleanupPolicy::Delete,
                 )
                 .await
                 .expect("create topic");
@@ -149,6 +154,7 @@ fn no_permissions() -> Permissions {
     Permissions {
         global: GlobalPermissions::default(),
         streams: None,
+        stream_patterns: None,
     }
 }
 
@@ -183,6 +189,7 @@ async fn test_no_permissions(harness: &TestHarness, root_client: &IggyClient) {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await,
         "create_topic",
@@ -234,6 +241,7 @@ async fn test_system_permissions(harness: &TestHarness, root_client: &IggyClient
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -265,6 +273,7 @@ async fn test_system_permissions(harness: &TestHarness, root_client: &IggyClient
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -298,6 +307,7 @@ async fn test_user_permissions(harness: &TestHarness, root_client: &IggyClient)
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -344,6 +354,7 @@ async fn test_user_permissions(harness: &TestHarness, root_client: &IggyClient)
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -396,6 +407,7 @@ async fn test_stream_permissions(harness: &TestHarness, root_client: &IggyClient
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -437,6 +449,7 @@ async fn test_stream_permissions(harness: &TestHarness, root_client: &IggyClient
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -496,6 +509,7 @@ async fn test_topic_permissions(harness: &TestHarness, root_client: &IggyClient)
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -527,6 +541,7 @@ async fn test_topic_permissions(harness: &TestHarness, root_client: &IggyClient)
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await,
         "read_topics: create_topic",
@@ -541,6 +556,7 @@ async fn test_topic_permissions(harness: &TestHarness, root_client: &IggyClient)
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await,
         "read_topics: update_topic",
@@ -559,6 +575,7 @@ async fn test_topic_permissions(harness: &TestHarness, root_client: &IggyClient)
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -575,6 +592,7 @@ async fn test_topic_permissions(harness: &TestHarness, root_client: &IggyClient)
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .expect("manage_topics: create_topic should work");
@@ -589,6 +607,7 @@ async fn test_topic_permissions(harness: &TestHarness, root_client: &IggyClient)
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .expect("manage_topics: update_topic should work");
@@ -627,6 +646,7 @@ async fn test_message_permissions(harness: &TestHarness, root_client: &IggyClien
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -677,6 +697,7 @@ async fn test_message_permissions(harness: &TestHarness, root_client: &IggyClien
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -725,6 +746,7 @@ async fn test_message_permissions(harness: &TestHarness, root_client: &IggyClien
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -792,6 +814,7 @@ async fn test_stream_specific_permissions(harness: &TestHarness, root_client: &I
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -933,6 +956,7 @@ async fn test_topic_specific_permissions(harness: &TestHarness, root_client: &Ig
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1012,6 +1036,102 @@ async fn test_topic_specific_permissions(harness: &TestHarness, root_client: &Ig
     delete_test_user(root_client, USER).await;
 }
 
+// =============================================================================
+// Test: Name-pattern permissions
+// Patterns are resolved against the stream and topic names, including the
+// streams created after the permissions were granted.
+// =============================================================================
+
+async fn test_pattern_permissions(harness: &TestHarness, root_client: &IggyClient) {
+    const USER: &str = "pattern-user";
+    const LATE_STREAM: &str = "perm-stream-late";
+    let stream1_id = Identifier::named(STREAM_1).unwrap();
+    let topic1_id = Identifier::named(TOPIC_1).unwrap();
+    let topic2_id = Identifier::named(TOPIC_2).unwrap();
+
+    create_test_user(
+        root_client,
+        USER,
+        Some(Permissions {
+            global: GlobalPermissions::default(),
+            streams: None,
+            stream_patterns: Some(vec![
+                StreamPatternPermissions {
+                    pattern: "perm-stream-*".to_string(),
+                    topics: Some(vec![TopicPatternPermissions {
+                        pattern: "*-1".to_string(),
+                        read_topic: true,
+                        send_messages: true,
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+                StreamPatternPermissions {
+                    pattern: "*-late".to_string(),
+                    read_stream: true,
+                    ..Default::default()
+                },
+            ]),
+        }),
+    )
+    .await;
+
+    let client = login_user(harness, USER).await;
+
+    assert_unauthorized(
+        client.get_stream(&stream1_id).await,
+        "pattern: get_stream 1",
+    );
+    client
+        .get_topic(&stream1_id, &topic1_id)
+        .await
+        .expect("pattern: get_topic 1 should work");
+
+    let mut msgs = test_messages();
+    client
+        .send_messages(
+            &stream1_id,
+            &topic1_id,
+            &Partitioning::partition_id(0),
+            &mut msgs,
+        )
+        .await
+        .expect("pattern: send_messages 1 should work");
+
+    assert_unauthorized(
+        client.get_topic(&stream1_id, &topic2_id).await,
+        "pattern: get_topic 2",
+    );
+    assert_unauthorized(
+        client
+            .poll_messages(
+                &stream1_id,
+                &topic1_id,
+                Some(0),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                1,
+                false,
+            )
+            .await,
+        "pattern: poll_messages 1",
+    );
+
+    // A stream created after the permissions were granted is matched as well.
+    root_client
+        .create_stream(LATE_STREAM)
+        .await
+        .expect("create late stream");
+    let late_stream_id = Identifier::named(LATE_STREAM).unwrap();
+    client
+        .get_stream(&late_stream_id)
+        .await
+        .expect("pattern: get_stream late should work");
+
+    let _ = root_client.delete_stream(&late_stream_id).await;
+    delete_test_user(root_client, USER).await;
+}
+
 // =============================================================================
 // Test: Global permission inheritance/implication
 // Tests that higher-level permissions imply lower-level ones WITHOUT explicitly
@@ -1035,6 +1155,7 @@ async fn test_global_permission_inheritance(harness: &TestHarness, root_client:
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1100,6 +1221,7 @@ async fn test_global_permission_inheritance(harness: &TestHarness, root_client:
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await,
         "read_streams does NOT imply manage_topics",
@@ -1120,6 +1242,7 @@ async fn test_global_permission_inheritance(harness: &TestHarness, root_client:
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1146,6 +1269,7 @@ async fn test_global_permission_inheritance(harness: &TestHarness, root_client:
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .expect("manage_streams → manage_topics: create_topic");
@@ -1211,6 +1335,7 @@ async fn test_global_permission_inheritance(harness: &TestHarness, root_client:
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1267,6 +1392,7 @@ async fn test_global_permission_inheritance(harness: &TestHarness, root_client:
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1317,6 +1443,7 @@ async fn test_global_permission_inheritance(harness: &TestHarness, root_client:
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1347,6 +1474,7 @@ async fn test_global_permission_inheritance(harness: &TestHarness, root_client:
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1399,6 +1527,7 @@ async fn test_stream_permission_inheritance(harness: &TestHarness, root_client:
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1466,6 +1595,7 @@ async fn test_stream_permission_inheritance(harness: &TestHarness, root_client:
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await,
         "stream.read_stream does NOT imply manage_topics",
@@ -1489,6 +1619,7 @@ async fn test_stream_permission_inheritance(harness: &TestHarness, root_client:
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1511,6 +1642,7 @@ async fn test_stream_permission_inheritance(harness: &TestHarness, root_client:
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .expect("stream.manage_stream → manage_topics: create_topic");
@@ -1523,6 +1655,7 @@ async fn test_stream_permission_inheritance(harness: &TestHarness, root_client:
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .expect("stream.manage_stream → manage_topics: update_topic");
@@ -1595,6 +1728,7 @@ async fn test_stream_permission_inheritance(harness: &TestHarness, root_client:
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1611,6 +1745,7 @@ async fn test_stream_permission_inheritance(harness: &TestHarness, root_client:
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .expect("stream.manage_topics: create_topic should work");
@@ -1696,6 +1831,7 @@ async fn test_topic_permission_inheritance(harness: &TestHarness, root_client: &
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1765,6 +1901,7 @@ async fn test_topic_permission_inheritance(harness: &TestHarness, root_client: &
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1821,6 +1958,7 @@ async fn test_consumer_group_operations(harness: &TestHarness, root_client: &Igg
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1854,7 +1992,7 @@ async fn test_consumer_group_operations(harness: &TestHarness, root_client: &Igg
     );
     assert_unauthorized(
         client
-            .create_consumer_group(&stream_id, &topic_id, "test-cg")
+            .create_consumer_group(&stream_id, &topic_id, "test-cg", None, None)
             .await,
         "poll_messages only: create_consumer_group should be denied",
     );
@@ -1872,6 +2010,7 @@ async fn test_consumer_group_operations(harness: &TestHarness, root_client: &Igg
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1885,7 +2024,7 @@ async fn test_consumer_group_operations(harness: &TestHarness, root_client: &Igg
         .expect("read_topics: get_consumer_groups should work");
 
     let cg = client
-        .create_consumer_group(&stream_id, &topic_id, "test-cg-read-topics")
+        .create_consumer_group(&stream_id, &topic_id, "test-cg-read-topics", None, None)
         .await
         .expect("read_topics: create_consumer_group should work");
 
@@ -1954,6 +2093,7 @@ async fn test_consumer_group_operations(harness: &TestHarness, root_client: &Igg
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -1967,7 +2107,7 @@ async fn test_consumer_group_operations(harness: &TestHarness, root_client: &Igg
         .expect("topic.read_topic: get_consumer_groups should work");
 
     let cg = client
-        .create_consumer_group(&stream_id, &topic_id, "test-cg-read-topic")
+        .create_consumer_group(&stream_id, &topic_id, "test-cg-read-topic", None, None)
         .await
         .expect("topic.read_topic: create_consumer_group should work");
 
@@ -2002,6 +2142,163 @@ async fn test_consumer_group_operations(harness: &TestHarness, root_client: &Igg
     delete_test_user(root_client, READ_TOPIC_CG_USER).await;
 }
 
+// =============================================================================
+// Test: Consumer-group-level permissions
+// Listing consumer groups in the topic permissions restricts the group
+// operations to these groups, unless the user can manage the topic.
+// =============================================================================
+
+async fn test_consumer_group_permissions(harness: &TestHarness, root_client: &IggyClient) {
+    let stream_id = Identifier::named(STREAM_1).unwrap();
+    let topic_id = Identifier::named(TOPIC_1).unwrap();
+
+    let allowed_group = root_client
+        .create_consumer_group(&stream_id, &topic_id, "cg-perm-allowed", None, None)
+        .await
+        .expect("create allowed consumer group");
+    let other_group = root_client
+        .create_consumer_group(&stream_id, &topic_id, "cg-perm-other", None, None)
+        .await
+        .expect("create other consumer group");
+    let allowed_group_id = Identifier::numeric(allowed_group.id).unwrap();
+    let other_group_id = Identifier::numeric(other_group.id).unwrap();
+
+    let stream = root_client
+        .get_stream(&stream_id)
+        .await
+        .expect("get stream")
+        .expect("stream 1 should exist");
+    let topic = root_client
+        .get_topic(&stream_id, &topic_id)
+        .await
+        .expect("get topic")
+        .expect("topic 1 should exist");
+
+    const CG_SCOPED_USER: &str = "cg-scoped-user";
+    create_test_user(
+        root_client,
+        CG_SCOPED_USER,
+        Some(Permissions {
+            global: GlobalPermissions::default(),
+            streams: Some(BTreeMap::from([(
+                stream.id as usize,
+                StreamPermissions {
+                    topics: Some(BTreeMap::from([(
+                        topic.id as usize,
+                        TopicPermissions {
+                            read_topic: true,
+                            poll_messages: true,
+                            consumer_groups: Some(BTreeMap::from([(
+                                allowed_group.id as usize,
+                                ConsumerGroupPermissions {
+                                    join_group: true,
+                                    commit_offsets: true,
+                                    ..Default::default()
+                                },
+                            )])),
+                            ..Default::default()
+                        },
+                    )])),
+                    ..Default::default()
+                },
+            )])),
+            stream_patterns: None,
+        }),
+    )
+    .await;
+
+    let client = login_user(harness, CG_SCOPED_USER).await;
+
+    assert_ok_or_feature_unavailable(
+        client
+            .join_consumer_group(&stream_id, &topic_id, &allowed_group_id)
+            .await,
+        "consumer_groups: join_consumer_group on listed group should work",
+    );
+    assert_unauthorized(
+        client
+            .join_consumer_group(&stream_id, &topic_id, &other_group_id)
+            .await,
+        "consumer_groups: join_consumer_group on other group should be denied",
+    );
+
+    let result = client
+        .store_consumer_offset(
+            &Consumer::group(allowed_group_id.clone()),
+            &stream_id,
+            &topic_id,
+            Some(0),
+            0,
+        )
+        .await;
+    assert!(
+        !matches!(result, Err(IggyError::Unauthorized)),
+        "consumer_groups: store_consumer_offset on listed group should be authorized, got {result:?}"
+    );
+    assert_unauthorized(
+        client
+            .store_consumer_offset(
+                &Consumer::group(other_group_id.clone()),
+                &stream_id,
+                &topic_id,
+                Some(0),
+                0,
+            )
+            .await,
+        "consumer_groups: store_consumer_offset on other group should be denied",
+    );
+    assert_unauthorized(
+        client
+            .delete_consumer_offset(
+                &Consumer::group(allowed_group_id.clone()),
+                &stream_id,
+                &topic_id,
+                Some(0),
+            )
+            .await,
+        "consumer_groups: delete_consumer_offset without delete_offsets should be denied",
+    );
+    assert_unauthorized(
+        client
+            .delete_consumer_group(&stream_id, &topic_id, &allowed_group_id)
+            .await,
+        "consumer_groups: delete_consumer_group without manage_group should be denied",
+    );
+    assert_unauthorized(
+        client
+            .ack_messages(&stream_id, &topic_id, &other_group_id, 0, &[0])
+            .await,
+        "consumer_groups: ack_messages on other group should be denied",
+    );
+    assert_unauthorized(
+        client
+            .nack_message(&stream_id, &topic_id, &other_group_id, 0, 0, "")
+            .await,
+        "consumer_groups: nack_message on other group should be denied",
+    );
+
+    // Offsets of individual consumers are not affected by the restriction.
+    let result = client
+        .store_consumer_offset(&Consumer::default(), &stream_id, &topic_id, Some(0), 0)
+        .await;
+    assert!(
+        !matches!(result, Err(IggyError::Unauthorized)),
+        "consumer_groups: store_consumer_offset for consumer should be authorized, got {result:?}"
+    );
+
+    let _ = client
+        .leave_consumer_group(&stream_id, &topic_id, &allowed_group_id)
+        .await;
+    delete_test_user(root_client, CG_SCOPED_USER).await;
+
+    for group_id in [allowed_group_id, other_group_id] {
+        root_client
+            .delete_consumer_group(&stream_id, &topic_id, &group_id)
+            .await
+            .expect("delete consumer group");
+    }
+}
+
 // =============================================================================
 // Test: Union semantics (global + scoped should be OR, not AND)
 // Tests that stream permissions extend rather than restrict global permissions.
@@ -2047,6 +2344,7 @@ async fn test_union_semantics(harness: &TestHarness, root_client: &IggyClient) {
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -2096,6 +2394,7 @@ async fn test_union_semantics(harness: &TestHarness, root_client: &IggyClient) {
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -2165,6 +2464,7 @@ async fn test_union_semantics(harness: &TestHarness, root_client: &IggyClient) {
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -2263,6 +2563,7 @@ async fn test_missing_resource_behavior(harness: &TestHarness, root_client: &Igg
                 ..Default::default()
             },
             streams: None,
+            stream_patterns: None,
         }),
     )
     .await;
@@ -2389,6 +2690,7 @@ async fn test_missing_resource_behavior(harness: &TestHarness, root_client: &Igg
                     ..Default::default()
                 },
             )])),
+            stream_patterns: None,
         }),
     )
     .await;
@@ -67,6 +67,7 @@ pub async fn run(harness: &mut TestHarness, restart_server: bool) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -337,6 +338,7 @@ pub async fn run_no_consumers(harness: &mut TestHarness, restart_server: bool) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -437,6 +439,7 @@ pub async fn run_consumer_group_barrier(client: &IggyClient, data_path: &Path) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -545,6 +548,7 @@ pub async fn run_multi_consumer_barrier(harness: &mut TestHarness, restart_serve
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -743,6 +747,7 @@ pub async fn run_purge_topic(harness: &mut TestHarness, restart_server: bool) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -57,6 +57,7 @@ async fn should_read_messages_during_background_saver_flush(
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -119,6 +119,7 @@ pub async fn run_consumer(harness: &mut TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .expect("Failed to create topic");
@@ -273,6 +274,7 @@ pub async fn run_single_message_offset_zero_restart(harness: &mut TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -394,6 +396,7 @@ pub async fn run_consumer_offset_ahead_after_crash(harness: &mut TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
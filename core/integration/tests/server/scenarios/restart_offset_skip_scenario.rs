@@ -68,12 +68,13 @@ pub async fn run(harness: &mut TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
 
     setup_client
-        .create_consumer_group(&stream_id, &topic_id, CONSUMER_GROUP_NAME)
+        .create_consumer_group(&stream_id, &topic_id, CONSUMER_GROUP_NAME, None, None)
         .await
         .unwrap();
 
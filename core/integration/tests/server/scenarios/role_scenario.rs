@@ -0,0 +1,122 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+
+const USERNAME: &str = "role-user";
+const PASSWORD: &str = "role-password";
+const ROLE_NAME: &str = "user-readers";
+
+/// Tests that the permissions granted by a role are effective for its holders, that updating,
+/// unassigning and deleting the role revokes them, and that the roles and the assignments
+/// survive the restart.
+pub async fn run(harness: &mut TestHarness) {
+    let root_client = harness.tcp_root_client().await.unwrap();
+    let user = root_client
+        .create_user(USERNAME, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+    let user_id = Identifier::numeric(user.id).unwrap();
+
+    let client = harness
+        .new_client_for(TransportProtocol::Tcp)
+        .await
+        .unwrap();
+    client.login_user(USERNAME, PASSWORD).await.unwrap();
+    assert_unauthorized(&client).await;
+
+    let result = root_client.create_role("ab", None).await;
+    assert!(matches!(result, Err(IggyError::InvalidRoleName)));
+
+    let role = root_client
+        .create_role(ROLE_NAME, Some(read_users_permissions()))
+        .await
+        .unwrap();
+    assert_eq!(role.name, ROLE_NAME);
+    assert_eq!(role.permissions, Some(read_users_permissions()));
+    assert!(role.users.is_empty());
+    let role_id = Identifier::named(ROLE_NAME).unwrap();
+
+    let result = root_client.create_role(ROLE_NAME, None).await;
+    assert!(matches!(result, Err(IggyError::RoleAlreadyExists)));
+
+    let result = client.assign_role(&user_id, &role_id).await;
+    assert!(matches!(result, Err(IggyError::Unauthorized)));
+
+    root_client.assign_role(&user_id, &role_id).await.unwrap();
+    root_client.assign_role(&user_id, &role_id).await.unwrap();
+    assert!(client.get_users().await.is_ok());
+
+    let role = root_client.get_role(&role_id).await.unwrap().unwrap();
+    assert_eq!(role.users, vec![user.id]);
+    let roles = root_client.get_roles().await.unwrap();
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0].users_count, 1);
+
+    root_client.update_role(&role_id, None).await.unwrap();
+    assert_unauthorized(&client).await;
+
+    root_client
+        .update_role(&role_id, Some(read_users_permissions()))
+        .await
+        .unwrap();
+    assert!(client.get_users().await.is_ok());
+
+    root_client.unassign_role(&user_id, &role_id).await.unwrap();
+    assert_unauthorized(&client).await;
+
+    root_client.assign_role(&user_id, &role_id).await.unwrap();
+    drop(client);
+    drop(root_client);
+
+    harness.restart_server().await.unwrap();
+
+    let root_client = harness.tcp_root_client().await.unwrap();
+    let role = root_client.get_role(&role_id).await.unwrap().unwrap();
+    assert_eq!(role.permissions, Some(read_users_permissions()));
+    assert_eq!(role.users, vec![user.id]);
+
+    let client = harness
+        .new_client_for(TransportProtocol::Tcp)
+        .await
+        .unwrap();
+    client.login_user(USERNAME, PASSWORD).await.unwrap();
+    assert!(client.get_users().await.is_ok());
+
+    root_client.delete_role(&role_id).await.unwrap();
+    assert_unauthorized(&client).await;
+    assert!(root_client.get_role(&role_id).await.unwrap().is_none());
+    assert!(root_client.get_roles().await.unwrap().is_empty());
+}
+
+async fn assert_unauthorized(client: &IggyClient) {
+    let result = client.get_users().await;
+    assert!(matches!(result, Err(IggyError::Unauthorized)));
+}
+
+fn read_users_permissions() -> Permissions {
+    Permissions {
+        global: GlobalPermissions {
+            read_users: true,
+            ..Default::default()
+        },
+        streams: None,
+        stream_patterns: None,
+    }
+}
@@ -0,0 +1,83 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+
+const USERNAME: &str = "scram-user";
+const PASSWORD: &str = "scram-password";
+const NEW_PASSWORD: &str = "scram-new-password";
+
+/// Tests that the user can sign in with the SCRAM-SHA-256 exchange, that the wrong password
+/// is rejected, and that the credentials follow the password change and survive the restart.
+pub async fn run(harness: &mut TestHarness) {
+    let root_client = harness.tcp_root_client().await.unwrap();
+    let user = root_client
+        .create_user(USERNAME, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+
+    let client = harness
+        .new_client_for(TransportProtocol::Tcp)
+        .await
+        .unwrap();
+    let identity = client.login_user_scram(USERNAME, PASSWORD).await.unwrap();
+    assert_eq!(identity.user_id, user.id);
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.user_id, Some(user.id));
+    client.logout_user().await.unwrap();
+
+    let result = client.login_user_scram(USERNAME, NEW_PASSWORD).await;
+    assert!(matches!(result, Err(IggyError::InvalidCredentials)));
+    assert!(matches!(
+        client.get_streams().await,
+        Err(IggyError::Unauthenticated)
+    ));
+
+    let result = client
+        .login_user_scram("scram-unknown-user", PASSWORD)
+        .await;
+    assert!(matches!(result, Err(IggyError::InvalidCredentials)));
+
+    let user_id = Identifier::numeric(user.id).unwrap();
+    root_client
+        .change_password(&user_id, PASSWORD, NEW_PASSWORD)
+        .await
+        .unwrap();
+    let result = client.login_user_scram(USERNAME, PASSWORD).await;
+    assert!(matches!(result, Err(IggyError::InvalidCredentials)));
+    client
+        .login_user_scram(USERNAME, NEW_PASSWORD)
+        .await
+        .unwrap();
+    drop(client);
+    drop(root_client);
+
+    harness.restart_server().await.unwrap();
+
+    let client = harness
+        .new_client_for(TransportProtocol::Tcp)
+        .await
+        .unwrap();
+    let identity = client
+        .login_user_scram(USERNAME, NEW_PASSWORD)
+        .await
+        .unwrap();
+    assert_eq!(identity.user_id, user.id);
+    client.get_streams().await.unwrap();
+}
@@ -168,6 +168,7 @@ async fn init_system(client: &IggyClient, total_producers: usize) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -0,0 +1,299 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use futures::StreamExt;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+use std::str::FromStr;
+use std::time::Duration;
+
+const STREAM_NAME: &str = "shared-subscription-stream";
+const TOPIC_NAME: &str = "shared-subscription-topic";
+const CONSUMER_GROUP_NAME: &str = "shared-subscription-group";
+const EXCLUSIVE_CONSUMER_GROUP_NAME: &str = "exclusive-group";
+const PARTITIONS_COUNT: u32 = 2;
+const VISIBILITY_TIMEOUT: &str = "2s";
+const MESSAGES_COUNT: u32 = 3;
+
+/// Tests that the members of the consumer group with the shared subscription poll the messages
+/// of any partition, the messages in flight are not delivered to the other members until they
+/// are negatively acknowledged or their visibility timeout expires, and the acknowledged ones
+/// are never redelivered, even after the server restart.
+pub async fn run(harness: &mut TestHarness) {
+    let clients = harness.tcp_root_clients(2).await.unwrap();
+    let (first, second) = (&clients[0], &clients[1]);
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+    let group_id = Identifier::named(CONSUMER_GROUP_NAME).unwrap();
+
+    first.create_stream(STREAM_NAME).await.unwrap();
+    first
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+
+    // The shared subscription requires the visibility timeout.
+    let error = first
+        .create_consumer_group(
+            &stream_id,
+            &topic_id,
+            CONSUMER_GROUP_NAME,
+            None,
+            Some(SharedSubscription::new(IggyDuration::from(0))),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::InvalidSharedSubscription.as_code()
+    );
+
+    // Only the messages delivered to the shared subscription can be acknowledged.
+    first
+        .create_consumer_group(
+            &stream_id,
+            &topic_id,
+            EXCLUSIVE_CONSUMER_GROUP_NAME,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let error = first
+        .ack_messages(
+            &stream_id,
+            &topic_id,
+            &Identifier::named(EXCLUSIVE_CONSUMER_GROUP_NAME).unwrap(),
+            0,
+            &[0],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::ConsumerGroupNotShared(group_id.clone(), topic_id.clone()).as_code()
+    );
+
+    first
+        .create_consumer_group(
+            &stream_id,
+            &topic_id,
+            CONSUMER_GROUP_NAME,
+            None,
+            Some(subscription()),
+        )
+        .await
+        .unwrap();
+    join(&clients).await;
+
+    for partition_id in 0..PARTITIONS_COUNT {
+        send(first, partition_id).await;
+    }
+
+    // Any member polls any partition, while the messages in flight are skipped by the others.
+    assert_eq!(poll_offsets(first, 0, false).await, vec![0, 1, 2]);
+    assert!(poll_offsets(second, 0, false).await.is_empty());
+
+    // The clients which are not the members of the group can't settle its messages.
+    let outsider = harness.tcp_root_client().await.unwrap();
+    let error = outsider
+        .ack_messages(&stream_id, &topic_id, &group_id, 0, &[0])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::ConsumerGroupMemberNotFound(0, group_id.clone(), topic_id.clone()).as_code()
+    );
+    let error = outsider
+        .nack_message(&stream_id, &topic_id, &group_id, 0, 0, "")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::ConsumerGroupMemberNotFound(0, group_id.clone(), topic_id.clone()).as_code()
+    );
+    drop(outsider);
+
+    let error = first
+        .ack_messages(&stream_id, &topic_id, &group_id, 0, &[0, 5])
+        .await
+        .unwrap_err();
+    assert_eq!(error.as_code(), IggyError::MessageNotDelivered(5).as_code());
+
+    first
+        .ack_messages(&stream_id, &topic_id, &group_id, 0, &[0, 2])
+        .await
+        .unwrap();
+    assert_eq!(stored_offset(first, 0).await, Some(0));
+
+    // The negatively acknowledged message is redelivered to any member right away.
+    second
+        .nack_message(&stream_id, &topic_id, &group_id, 0, 1, "")
+        .await
+        .unwrap();
+    assert_eq!(poll_offsets(second, 0, false).await, vec![1]);
+    second
+        .ack_messages(&stream_id, &topic_id, &group_id, 0, &[1])
+        .await
+        .unwrap();
+    assert!(poll_offsets(first, 0, false).await.is_empty());
+    assert_eq!(stored_offset(first, 0).await, Some(2));
+
+    // The unacknowledged messages are redelivered once their visibility timeout expires,
+    // while polling with the auto commit acknowledges them right away.
+    assert_eq!(poll_offsets(first, 1, false).await, vec![0, 1, 2]);
+    assert!(poll_offsets(second, 1, true).await.is_empty());
+    tokio::time::sleep(visibility_timeout()).await;
+    assert_eq!(poll_offsets(second, 1, true).await, vec![0, 1, 2]);
+    tokio::time::sleep(visibility_timeout()).await;
+    assert!(poll_offsets(first, 1, false).await.is_empty());
+    assert_eq!(stored_offset(first, 1).await, Some(2));
+
+    // The acknowledged messages are persisted and never redelivered after the server restart.
+    send(first, 0).await;
+    assert_eq!(poll_offsets(first, 0, false).await, vec![3, 4, 5]);
+    first
+        .ack_messages(&stream_id, &topic_id, &group_id, 0, &[4])
+        .await
+        .unwrap();
+    first
+        .flush_unsaved_buffer(&stream_id, &topic_id, 0, true)
+        .await
+        .unwrap();
+    drop(clients);
+    harness.restart_server().await.unwrap();
+
+    let clients = harness.tcp_root_clients(2).await.unwrap();
+    join(&clients).await;
+    assert_eq!(poll_offsets(&clients[0], 0, false).await, vec![3, 5]);
+    clients[0]
+        .ack_messages(&stream_id, &topic_id, &group_id, 0, &[3, 5])
+        .await
+        .unwrap();
+    assert_eq!(stored_offset(&clients[0], 0).await, Some(5));
+
+    // The consumer acknowledges each consumed message.
+    send(&clients[0], 1).await;
+    let consumer_client = harness.tcp_root_client().await.unwrap();
+    let mut consumer = consumer_client
+        .consumer_group(CONSUMER_GROUP_NAME, STREAM_NAME, TOPIC_NAME)
+        .unwrap()
+        .shared_subscription(subscription())
+        .batch_length(MESSAGES_COUNT)
+        .poll_interval(IggyDuration::from_str("100ms").unwrap())
+        .polling_strategy(PollingStrategy::next())
+        .auto_join_consumer_group()
+        .auto_commit(AutoCommit::When(AutoCommitWhen::ConsumingEachMessage))
+        .build();
+    consumer.init().await.unwrap();
+    for _ in 0..MESSAGES_COUNT {
+        let message = consumer.next().await.unwrap().unwrap();
+        assert_eq!(message.partition_id, 1);
+    }
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(stored_offset(&clients[0], 1).await, Some(5));
+    consumer.shutdown().await.unwrap();
+
+    clients[0].delete_stream(&stream_id).await.unwrap();
+}
+
+fn subscription() -> SharedSubscription {
+    SharedSubscription::new(IggyDuration::from_str(VISIBILITY_TIMEOUT).unwrap())
+}
+
+fn visibility_timeout() -> Duration {
+    IggyDuration::from_str(VISIBILITY_TIMEOUT)
+        .unwrap()
+        .get_duration()
+        + Duration::from_millis(500)
+}
+
+async fn join(clients: &[IggyClient]) {
+    for client in clients {
+        client
+            .join_consumer_group(
+                &Identifier::named(STREAM_NAME).unwrap(),
+                &Identifier::named(TOPIC_NAME).unwrap(),
+                &Identifier::named(CONSUMER_GROUP_NAME).unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+}
+
+async fn send(client: &IggyClient, partition_id: u32) {
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|id| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn poll_offsets(client: &IggyClient, partition_id: u32, auto_commit: bool) -> Vec<u64> {
+    client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(partition_id),
+            &Consumer::group(Identifier::named(CONSUMER_GROUP_NAME).unwrap()),
+            &PollingStrategy::next(),
+            MESSAGES_COUNT,
+            auto_commit,
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| message.header.offset)
+        .collect()
+}
+
+async fn stored_offset(client: &IggyClient, partition_id: u32) -> Option<u64> {
+    client
+        .get_consumer_offset(
+            &Consumer::group(Identifier::named(CONSUMER_GROUP_NAME).unwrap()),
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(partition_id),
+        )
+        .await
+        .unwrap()
+        .map(|offset| offset.stored_offset)
+}
@@ -56,6 +56,7 @@ pub async fn run(harness: &TestHarness, duration_secs: u64) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -159,6 +159,7 @@ async fn create_topic_assert_empty(client: &IggyClient, stream_name: &str, topic
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -97,6 +97,7 @@ pub async fn run(harness: &TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -184,6 +185,7 @@ pub async fn run(harness: &TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await;
     assert!(create_topic_result.is_err());
@@ -410,6 +412,8 @@ pub async fn run(harness: &TestHarness) {
             &Identifier::named(STREAM_NAME).unwrap(),
             &Identifier::named(TOPIC_NAME).unwrap(),
             CONSUMER_GROUP_NAME,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -574,6 +578,7 @@ pub async fn run(harness: &TestHarness) {
             Some(updated_replication_factor),
             IggyExpiry::ExpireDuration(message_expiry_duration),
             updated_max_topic_size,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
@@ -711,6 +716,7 @@ pub async fn run(harness: &TestHarness) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
         .unwrap();
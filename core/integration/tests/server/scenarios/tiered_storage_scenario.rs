@@ -0,0 +1,178 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+use std::fs::read_dir;
+use std::path::Path;
+use std::time::Duration;
+
+const STREAM_NAME: &str = "tiered-storage-stream";
+const TOPIC_NAME: &str = "tiered-storage-topic";
+const PARTITION_ID: u32 = 0;
+const MESSAGES_COUNT: u64 = 100;
+const POLL_BATCH_SIZE: u32 = 1000;
+const LOG_EXTENSION: &str = "log";
+const OFFLOADED_EXTENSION: &str = "offloaded";
+
+/// Maximum time for the archiver to offload the sealed segments.
+const ARCHIVER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Tests that the sealed segments are offloaded to the archive, leaving only the markers and
+/// the indexes on the local disk, while all the messages can still be polled by offset and by
+/// timestamp, also after the server restart.
+pub async fn run(harness: &mut TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    let stream = client.create_stream(STREAM_NAME).await.unwrap();
+    let topic = client
+        .create_topic(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+    let partition_path = harness.server().data_path().join(format!(
+        "streams/{}/topics/{}/partitions/{PARTITION_ID}",
+        stream.id, topic.id
+    ));
+
+    let start_timestamp = IggyTimestamp::now().as_micros();
+    for offset in 0..MESSAGES_COUNT {
+        let mut messages = vec![
+            IggyMessage::builder()
+                .id(offset as u128 + 1)
+                .payload(Bytes::from(format!("message-{offset:0>100}")))
+                .build()
+                .unwrap(),
+        ];
+        client
+            .send_messages(
+                &Identifier::named(STREAM_NAME).unwrap(),
+                &Identifier::named(TOPIC_NAME).unwrap(),
+                &Partitioning::partition_id(PARTITION_ID),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+    }
+
+    let offloaded_segments = wait_for_offloaded_segments(&partition_path).await;
+    assert!(
+        offloaded_segments > 0,
+        "Expected the sealed segments to be offloaded"
+    );
+    assert_eq!(
+        count_files(&partition_path, LOG_EXTENSION),
+        1,
+        "Only the messages file of the active segment should remain on the local disk"
+    );
+
+    assert_all_messages_polled(&client, start_timestamp).await;
+    drop(client);
+
+    harness.restart_server().await.unwrap();
+    let client = harness.tcp_root_client().await.unwrap();
+    assert_eq!(
+        count_files(&partition_path, OFFLOADED_EXTENSION),
+        offloaded_segments
+    );
+    assert_all_messages_polled(&client, start_timestamp).await;
+
+    client
+        .delete_stream(&Identifier::named(STREAM_NAME).unwrap())
+        .await
+        .unwrap();
+}
+
+async fn assert_all_messages_polled(client: &IggyClient, start_timestamp: u64) {
+    for strategy in [
+        PollingStrategy::offset(0),
+        PollingStrategy::timestamp(start_timestamp.into()),
+    ] {
+        let polled = client
+            .poll_messages(
+                &Identifier::named(STREAM_NAME).unwrap(),
+                &Identifier::named(TOPIC_NAME).unwrap(),
+                Some(PARTITION_ID),
+                &Consumer::default(),
+                &strategy,
+                POLL_BATCH_SIZE,
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(polled.messages.len() as u64, MESSAGES_COUNT);
+        for (offset, message) in polled.messages.iter().enumerate() {
+            assert_eq!(message.header.offset, offset as u64);
+            assert_eq!(
+                message.payload,
+                Bytes::from(format!("message-{offset:0>100}"))
+            );
+        }
+    }
+
+    // Polling from the middle of an offloaded segment fetches only the requested range.
+    let polled = client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(5),
+            3,
+            false,
+        )
+        .await
+        .unwrap();
+    let offsets: Vec<u64> = polled.messages.iter().map(|m| m.header.offset).collect();
+    assert_eq!(offsets, vec![5, 6, 7]);
+}
+
+/// Waits until every sealed segment was offloaded, returns the number of offloaded segments.
+async fn wait_for_offloaded_segments(partition_path: &Path) -> usize {
+    let deadline = tokio::time::Instant::now() + ARCHIVER_TIMEOUT;
+    loop {
+        let offloaded = count_files(partition_path, OFFLOADED_EXTENSION);
+        if offloaded > 0 && count_files(partition_path, LOG_EXTENSION) == 1 {
+            return offloaded;
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return offloaded;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+fn count_files(partition_path: &Path, extension: &str) -> usize {
+    read_dir(partition_path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().extension().is_some_and(|ext| ext == extension))
+                .count()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,171 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+
+const STREAM_NAME: &str = "transactions-stream";
+const TOPIC_NAME: &str = "transactions-topic";
+const CONSUMER_NAME: &str = "transactions-consumer";
+const PARTITIONS_COUNT: u32 = 2;
+const MESSAGES_PER_PARTITION: u64 = 3;
+const POLL_BATCH_SIZE: u32 = 100;
+
+/// Tests that the messages of a transaction spanning multiple partitions become visible
+/// to read_committed consumers only once committed, that the consumer offsets passed
+/// to the commit are stored, and that aborted messages are hidden from them.
+/// Transaction markers are never returned to any consumer.
+pub async fn run(harness: &TestHarness) {
+    let client = harness.tcp_root_client().await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+    let consumer = Consumer::new(Identifier::named(CONSUMER_NAME).unwrap());
+
+    client.create_stream(STREAM_NAME).await.unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+
+    // Messages of an open transaction are visible only to read_uncommitted consumers.
+    let transaction_id = client.begin_transaction().await.unwrap();
+    for partition_id in 0..PARTITIONS_COUNT {
+        send(&client, partition_id, 0..MESSAGES_PER_PARTITION).await;
+    }
+    for partition_id in 0..PARTITIONS_COUNT {
+        assert!(
+            poll_offsets(&client, partition_id, IsolationLevel::ReadCommitted)
+                .await
+                .is_empty()
+        );
+        assert_eq!(
+            poll_offsets(&client, partition_id, IsolationLevel::ReadUncommitted).await,
+            vec![0, 1, 2]
+        );
+    }
+
+    // A second transaction cannot be started by the same client.
+    assert!(client.begin_transaction().await.is_err());
+
+    let offsets = [TransactionOffset::new(
+        consumer.clone(),
+        stream_id.clone(),
+        topic_id.clone(),
+        Some(0),
+        MESSAGES_PER_PARTITION - 1,
+    )];
+    client
+        .commit_transaction(transaction_id, &offsets)
+        .await
+        .unwrap();
+
+    // The commit marker at offset 3 is hidden from both isolation levels.
+    for partition_id in 0..PARTITIONS_COUNT {
+        for isolation_level in [
+            IsolationLevel::ReadCommitted,
+            IsolationLevel::ReadUncommitted,
+        ] {
+            assert_eq!(
+                poll_offsets(&client, partition_id, isolation_level).await,
+                vec![0, 1, 2]
+            );
+        }
+    }
+
+    let stored_offset = client
+        .get_consumer_offset(&consumer, &stream_id, &topic_id, Some(0))
+        .await
+        .unwrap()
+        .expect("Consumer offset must be stored on commit");
+    assert_eq!(stored_offset.stored_offset, MESSAGES_PER_PARTITION - 1);
+
+    // The committed transaction can no longer be completed.
+    assert!(client.abort_transaction(transaction_id).await.is_err());
+
+    // Aborted messages (4, 5) and the abort marker (6) are hidden from read_committed consumers,
+    // the non-transactional message appended afterwards (7) is visible to everyone.
+    let transaction_id = client.begin_transaction().await.unwrap();
+    send(&client, 0, 100..102).await;
+    client.abort_transaction(transaction_id).await.unwrap();
+    send(&client, 0, 200..201).await;
+
+    assert_eq!(
+        poll_offsets(&client, 0, IsolationLevel::ReadCommitted).await,
+        vec![0, 1, 2, 7]
+    );
+    assert_eq!(
+        poll_offsets(&client, 0, IsolationLevel::ReadUncommitted).await,
+        vec![0, 1, 2, 4, 5, 7]
+    );
+
+    client.delete_stream(&stream_id).await.unwrap();
+}
+
+async fn send(client: &IggyClient, partition_id: u32, ids: std::ops::Range<u64>) {
+    let mut messages = ids
+        .map(|id| {
+            IggyMessage::builder()
+                .id(id as u128 + 1)
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn poll_offsets(
+    client: &IggyClient,
+    partition_id: u32,
+    isolation_level: IsolationLevel,
+) -> Vec<u64> {
+    client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(partition_id),
+            &Consumer::default(),
+            &PollingStrategy::offset(0).isolation_level(isolation_level),
+            POLL_BATCH_SIZE,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| message.header.offset)
+        .collect()
+}
@@ -0,0 +1,153 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::harness::TestHarness;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+const USERNAME: &str = "quota-user";
+const PASSWORD: &str = "quota-password";
+const STREAM_NAME: &str = "quota-stream";
+const TOPIC_NAME: &str = "quota-topic";
+const PRODUCE_BYTES_PER_SECOND: u64 = 4 * 1024;
+
+/// Tests that the quotas can be set, read back and cleared, that the user without
+/// the permission cannot manage them, that exceeding the produce quota delays the client
+/// instead of failing the request, and that the quotas survive the restart.
+pub async fn run(harness: &mut TestHarness) {
+    let root_client = harness.tcp_root_client().await.unwrap();
+    let user = root_client
+        .create_user(
+            USERNAME,
+            PASSWORD,
+            UserStatus::Active,
+            Some(producer_permissions()),
+        )
+        .await
+        .unwrap();
+    let user_id = Identifier::numeric(user.id).unwrap();
+    root_client.create_stream(STREAM_NAME).await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    root_client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+        )
+        .await
+        .unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+
+    let quotas = root_client.get_user_quotas(&user_id).await.unwrap();
+    assert_eq!(quotas.user_id, user.id);
+    assert!(quotas.is_empty());
+
+    let produce_quota = Quota {
+        produce_bytes_per_second: Some(IggyByteSize::from(PRODUCE_BYTES_PER_SECOND)),
+        ..Default::default()
+    };
+    let client_quota = Quota {
+        requests_per_second: Some(1000),
+        ..Default::default()
+    };
+    root_client
+        .set_user_quota(&user_id, QuotaScope::User, Some(produce_quota))
+        .await
+        .unwrap();
+    root_client
+        .set_user_quota(&user_id, QuotaScope::Client, Some(client_quota))
+        .await
+        .unwrap();
+    let quotas = root_client.get_user_quotas(&user_id).await.unwrap();
+    assert_eq!(quotas.user, Some(produce_quota));
+    assert_eq!(quotas.client, Some(client_quota));
+
+    let client = harness
+        .new_client_for(TransportProtocol::Tcp)
+        .await
+        .unwrap();
+    client.login_user(USERNAME, PASSWORD).await.unwrap();
+    let own_quotas = client.get_user_quotas(&user_id).await.unwrap();
+    assert_eq!(own_quotas, quotas);
+    let result = client
+        .set_user_quota(&user_id, QuotaScope::User, None)
+        .await;
+    assert!(matches!(result, Err(IggyError::Unauthorized)));
+
+    // The first two batches drain the budget and put the user into debt, so the third one
+    // is throttled by the server and transparently retried by the client once it is paid off.
+    let payload = Bytes::from(vec![0u8; PRODUCE_BYTES_PER_SECOND as usize]);
+    let started_at = Instant::now();
+    for _ in 0..3 {
+        let mut messages = vec![
+            IggyMessage::builder()
+                .payload(payload.clone())
+                .build()
+                .unwrap(),
+        ];
+        client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(0),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+    }
+    assert!(started_at.elapsed() >= Duration::from_millis(500));
+    drop(client);
+
+    root_client
+        .set_user_quota(&user_id, QuotaScope::Client, None)
+        .await
+        .unwrap();
+    drop(root_client);
+
+    harness.restart_server().await.unwrap();
+
+    let root_client = harness.tcp_root_client().await.unwrap();
+    let quotas = root_client.get_user_quotas(&user_id).await.unwrap();
+    assert_eq!(quotas.user, Some(produce_quota));
+    assert_eq!(quotas.client, None);
+
+    root_client
+        .set_user_quota(&user_id, QuotaScope::from_str("user").unwrap(), None)
+        .await
+        .unwrap();
+    let quotas = root_client.get_user_quotas(&user_id).await.unwrap();
+    assert!(quotas.is_empty());
+}
+
+fn producer_permissions() -> Permissions {
+    Permissions {
+        global: GlobalPermissions {
+            send_messages: true,
+            ..Default::default()
+        },
+        streams: None,
+        stream_patterns: None,
+    }
+}
@@ -84,6 +84,7 @@ pub async fn run(harness: &TestHarness) {
                     send_messages: true,
                 },
                 streams: None,
+                stream_patterns: None,
             }),
         )
         .await
@@ -232,6 +233,7 @@ pub async fn run(harness: &TestHarness) {
                     send_messages: true,
                 },
                 streams: None,
+                stream_patterns: None,
             }),
         )
         .await
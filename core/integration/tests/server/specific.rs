@@ -18,9 +18,13 @@
  */
 
 use crate::server::scenarios::{
-    message_size_scenario, reconnect_after_restart_scenario, restart_offset_skip_scenario,
-    segment_rotation_race_scenario, single_message_per_batch_scenario, tcp_tls_scenario,
-    websocket_tls_scenario,
+    audit_log_scenario, consumer_group_lag_scenario, dead_letter_scenario,
+    delayed_delivery_scenario, idempotent_producer_scenario, log_compaction_scenario,
+    message_filter_scenario, message_size_scenario, reconnect_after_restart_scenario,
+    restart_offset_skip_scenario, role_scenario, scram_login_scenario,
+    segment_rotation_race_scenario, shared_subscription_scenario,
+    single_message_per_batch_scenario, tcp_tls_scenario, tiered_storage_scenario,
+    transactions_scenario, user_quota_scenario, websocket_tls_scenario,
 };
 use integration::iggy_harness;
 
@@ -147,3 +151,82 @@ async fn restart_offset_skip(harness: &mut TestHarness) {
 async fn segment_rotation_scenario(harness: &TestHarness) {
     segment_rotation_race_scenario::run(harness).await;
 }
+
+#[iggy_harness(server(
+    segment.size = "2KiB",
+    partition.messages_required_to_save = "1",
+    partition.enforce_fsync = true,
+    data_maintenance.compaction.enabled = true,
+    data_maintenance.compaction.interval = "100ms"
+))]
+async fn log_compaction_scenario(harness: &TestHarness) {
+    log_compaction_scenario::run(harness).await;
+}
+
+#[iggy_harness(server(
+    segment.size = "2KiB",
+    partition.messages_required_to_save = "1",
+    partition.enforce_fsync = true,
+    data_maintenance.archiver.enabled = true,
+    data_maintenance.archiver.interval = "100ms",
+    data_maintenance.archiver.offload_after = "0s",
+    data_maintenance.archiver.kind = "disk"
+))]
+async fn tiered_storage_scenario(harness: &mut TestHarness) {
+    tiered_storage_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn transactions_scenario(harness: &TestHarness) {
+    transactions_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn idempotent_producer_scenario(harness: &mut TestHarness) {
+    idempotent_producer_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn dead_letter_scenario(harness: &mut TestHarness) {
+    dead_letter_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn shared_subscription_scenario(harness: &mut TestHarness) {
+    shared_subscription_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn delayed_delivery_scenario(harness: &mut TestHarness) {
+    delayed_delivery_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn message_filter_scenario(harness: &TestHarness) {
+    message_filter_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn consumer_group_lag_scenario(harness: &TestHarness) {
+    consumer_group_lag_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn scram_login_scenario(harness: &mut TestHarness) {
+    scram_login_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn user_quota_scenario(harness: &mut TestHarness) {
+    user_quota_scenario::run(harness).await;
+}
+
+#[iggy_harness]
+async fn role_scenario(harness: &mut TestHarness) {
+    role_scenario::run(harness).await;
+}
+
+#[iggy_harness(server(audit.enabled = true))]
+async fn audit_log_scenario(harness: &mut TestHarness) {
+    audit_log_scenario::run(harness).await;
+}
@@ -49,6 +49,7 @@ async fn should_apply_single_entry() {
             status: 1,
             permissions: None,
         },
+        scram_credentials: None,
     });
     let command_bytes = command.to_bytes();
 
@@ -75,6 +76,7 @@ async fn should_apply_encrypted_entry() {
             status: 1,
             permissions: None,
         },
+        scram_credentials: None,
     });
     let command_bytes = command.to_bytes();
 
@@ -107,6 +109,7 @@ async fn should_apply_multiple_entries() {
             status: 1,
             permissions: None,
         },
+        scram_credentials: None,
     });
     let create_user_bytes = create_user.to_bytes();
 
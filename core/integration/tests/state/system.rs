@@ -23,6 +23,7 @@ use iggy_binary_protocol::requests::{
     streams::DeleteStreamRequest, topics::CreateTopicRequest, users::CreateUserRequest,
 };
 use iggy_binary_protocol::{WireIdentifier, WireName};
+use iggy_common::scram::ScramCredentials;
 use server::state::command::EntryCommand;
 use server::state::models::{
     CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash, CreateStreamWithId,
@@ -43,6 +44,7 @@ async fn should_be_initialized_based_on_state_entries() {
         status: 1, // Active
         permissions: None,
     };
+    let scram_credentials = ScramCredentials::new("secret");
 
     let stream1_id = 1u32;
     let create_stream1 = CreateStreamRequest {
@@ -58,6 +60,7 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: 0,        // ServerDefault
         replication_factor: 0,    // None
         name: WireName::new("topic1").unwrap(),
+        cleanup_policy: 1, // Delete
     };
 
     let stream2_id = 2u32;
@@ -74,6 +77,7 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: 0,
         replication_factor: 0,
         name: WireName::new("topic2").unwrap(),
+        cleanup_policy: 1,
     };
 
     let create_partitions = CreatePartitionsRequest {
@@ -98,6 +102,8 @@ async fn should_be_initialized_based_on_state_entries() {
         stream_id: WireIdentifier::numeric(stream1_id),
         topic_id: WireIdentifier::numeric(topic1_id),
         name: WireName::new("test").unwrap(),
+        dead_letter: None,
+        shared: None,
     };
 
     let group_id = 1u32;
@@ -108,6 +114,7 @@ async fn should_be_initialized_based_on_state_entries() {
             &EntryCommand::CreateUser(CreateUserWithId {
                 user_id,
                 command: create_user,
+                scram_credentials: Some(scram_credentials.to_string()),
             }),
         )
         .await
@@ -188,6 +195,7 @@ async fn should_be_initialized_based_on_state_entries() {
     assert_eq!(user.id, user_id);
     assert_eq!(user.username, "user");
     assert_eq!(user.password_hash, "secret");
+    assert_eq!(user.scram_credentials, Some(scram_credentials));
     assert_eq!(user.personal_access_tokens.len(), 1);
 
     let personal_access_token = user.personal_access_tokens.remove("test").unwrap();
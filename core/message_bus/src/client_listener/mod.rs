@@ -65,15 +65,19 @@
 //!   socket can complete the framing handshake and submit a `LOGIN`
 //!   attempt. Operators that need link-level authentication must
 //!   front the listener with mTLS or a network policy boundary.
-//! - **TCP-TLS / WSS** — server-side TLS only. The listener accepts
-//!   `TlsServerCredentials` (cert chain plus key) and builds a
-//!   `rustls::ServerConfig` internally with `with_no_client_auth`;
-//!   client-cert mTLS is not exposed through the current `bind` API.
-//!   Operators needing mTLS must front the listener with a TLS
-//!   terminator that enforces it, or extend the bus to accept a
-//!   pre-built `Arc<rustls::ServerConfig>`.
+//! - **TCP-TLS / WSS** — server-side TLS, optionally mutual. The
+//!   listener accepts `TlsServerCredentials` (cert chain, key, and
+//!   client certificate verifier) and builds a `rustls::ServerConfig`
+//!   internally. The verifier comes from the listener's `client_auth`
+//!   config section (see
+//!   [`crate::transports::tls::client_cert_verifier`]): disabled, no
+//!   client certificate is requested; enabled, a presented certificate
+//!   must chain to `ca_file`; `required` additionally rejects the
+//!   handshake when the client presents none. `LOGIN` is still
+//!   required after the handshake.
 //! - **QUIC** — TLS 1.3 handshake gated by a `rustls::ServerConfig`
-//!   built the same way as TCP-TLS / WSS (no client auth). No ALPN is
+//!   built the same way as TCP-TLS / WSS, including the client
+//!   certificate verifier. No ALPN is
 //!   advertised; protocol-version validation lives in the
 //!   application-level `LOGIN` command on the caller. 0-RTT data is
 //!   structurally rejected by the transport layer (see
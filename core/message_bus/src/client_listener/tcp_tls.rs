@@ -53,7 +53,10 @@ use tracing::{debug, error, info};
 /// The returned config is cloned into every accepted connection so each
 /// install path drives its own `UnbufferedServerConnection` against the
 /// same key material. A single `ServerConfig` allocation is shared across
-/// all clients.
+/// all clients. Client certificates are checked by
+/// `credentials.client_verifier`, so a listener configured with a
+/// required `client_auth` fails the handshake of a client that presents
+/// no certificate.
 ///
 /// Like the WS pre-upgrade listener, `TCP_NODELAY` is applied at bind
 /// time; the install path re-applies on every accepted stream because
@@ -76,7 +79,7 @@ pub async fn bind(
 ) -> Result<(TcpListener, Arc<rustls::ServerConfig>, SocketAddr), IggyError> {
     install_default_crypto_provider();
     let mut cfg = rustls::ServerConfig::builder()
-        .with_no_client_auth()
+        .with_client_cert_verifier(credentials.client_verifier)
         .with_single_cert(credentials.cert_chain, credentials.key_der)
         .map_err(|e| IggyError::IoError(format!("TCP-TLS server config build failed: {e}")))?;
     // Defense-in-depth alongside the quic plane: TLS 1.3 0-RTT is off by
@@ -71,7 +71,7 @@ pub async fn bind(
 ) -> Result<(TcpListener, Arc<rustls::ServerConfig>, SocketAddr), IggyError> {
     install_default_crypto_provider();
     let mut cfg = rustls::ServerConfig::builder()
-        .with_no_client_auth()
+        .with_client_cert_verifier(credentials.client_verifier)
         .with_single_cert(credentials.cert_chain, credentials.key_der)
         .map_err(|e| IggyError::IoError(format!("WSS server config build failed: {e}")))?;
     cfg.max_early_data_size = 0;
@@ -36,14 +36,16 @@
 
 use std::net::SocketAddr;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::time::Duration;
 
 use iggy_common::IggyError;
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::danger::ClientCertVerifier;
 
 use crate::connector::start as start_connector;
 use crate::replica::listener::{bind as bind_replica_listener, run as run_replica_listener};
-use crate::transports::quic::server_config_with_cert;
+use crate::transports::quic::server_config_with_client_auth;
 use crate::transports::tls::{TlsServerCredentials, install_default_crypto_provider};
 use crate::{
     AcceptedClientFn, AcceptedQuicClientFn, AcceptedReplicaFn, AcceptedTlsClientFn,
@@ -55,10 +57,13 @@ use crate::{
 /// The cert chain is the leaf-first sequence rustls expects; the key
 /// is the server's private key in DER form. Tests use rcgen to mint a
 /// throwaway pair; production callers load real PKI material via
-/// `core/server-ng`'s `[quic.certificate]` config section.
+/// `core/server-ng`'s `[quic.certificate]` config section, whose
+/// `client_auth` subsection yields `client_verifier` via
+/// [`crate::transports::tls::client_cert_verifier`].
 pub struct QuicServerCredentials {
     pub cert_chain: Vec<CertificateDer<'static>>,
     pub key_der: PrivateKeyDer<'static>,
+    pub client_verifier: Arc<dyn ClientCertVerifier>,
 }
 
 /// Bound addresses returned to shard 0 after the listeners come up.
@@ -252,11 +257,13 @@ pub async fn start_on_shard_zero(
 
     let quic_bound = match (quic_listen_addr, quic_credentials, on_accepted_quic_client) {
         (Some(addr), Some(creds), Some(on_accepted_quic)) => {
-            let server_config =
-                server_config_with_cert(creds.cert_chain, creds.key_der, &bus.config().quic)
-                    .map_err(|e| {
-                        IggyError::IoError(format!("QUIC server config build failed: {e}"))
-                    })?;
+            let server_config = server_config_with_client_auth(
+                creds.cert_chain,
+                creds.key_der,
+                creds.client_verifier,
+                &bus.config().quic,
+            )
+            .map_err(|e| IggyError::IoError(format!("QUIC server config build failed: {e}")))?;
             let (endpoint, quic_bound) = client_listener::quic::bind(addr, server_config).await?;
             let token_for_quic = bus.token();
             let handshake_grace = bus.config().handshake_grace;
@@ -64,6 +64,8 @@ use compio_quic::{
 };
 use futures::FutureExt;
 use iggy_binary_protocol::{GenericHeader, Message};
+use rustls::server::WebPkiClientVerifier;
+use rustls::server::danger::ClientCertVerifier;
 use std::io;
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -497,9 +499,30 @@ pub fn server_config_with_cert(
     cert_chain: Vec<rustls::pki_types::CertificateDer<'static>>,
     key_der: rustls::pki_types::PrivateKeyDer<'static>,
     tuning: &QuicTuning,
+) -> Result<compio_quic::ServerConfig, rustls::Error> {
+    server_config_with_client_auth(
+        cert_chain,
+        key_der,
+        WebPkiClientVerifier::no_client_auth(),
+        tuning,
+    )
+}
+
+/// Same as [`server_config_with_cert`], but verifies client
+/// certificates with `client_verifier` during the handshake (see
+/// [`crate::transports::tls::client_cert_verifier`]).
+///
+/// # Errors
+///
+/// Returns the underlying `rustls::Error` on cert/key import failure.
+pub fn server_config_with_client_auth(
+    cert_chain: Vec<rustls::pki_types::CertificateDer<'static>>,
+    key_der: rustls::pki_types::PrivateKeyDer<'static>,
+    client_verifier: Arc<dyn ClientCertVerifier>,
+    tuning: &QuicTuning,
 ) -> Result<compio_quic::ServerConfig, rustls::Error> {
     let mut rustls_cfg = rustls::ServerConfig::builder()
-        .with_no_client_auth()
+        .with_client_cert_verifier(client_verifier)
         .with_single_cert(cert_chain, key_der)?;
     rustls_cfg.max_early_data_size = 0; // 0-RTT off by default
     let crypto = QuicServerConfig::try_from(rustls_cfg)
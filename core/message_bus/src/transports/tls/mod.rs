@@ -17,8 +17,9 @@
 
 //! Shared TLS plumbing for the in-process TCP-TLS and WSS transports.
 //!
-//! Hosts the credentials newtypes, PEM loaders, and the idempotent
-//! rustls/ring crypto provider initializer. The hand-rolled
+//! Hosts the credentials newtypes, PEM loaders, the client certificate
+//! verifier builder, and the idempotent rustls/ring crypto provider
+//! initializer. The hand-rolled
 //! `UnbufferedConnection` driver was retired in favour of `compio-tls`;
 //! the per-transport handshake + steady-state pump now lives directly
 //! in `transports/tcp_tls.rs` and `transports/wss.rs`.
@@ -33,7 +34,11 @@
 //! install it; the wrapper here is idempotent and safe under races
 //! between concurrent first-use sites.
 
+use configs::tls::TlsClientAuthConfig;
+use rustls::RootCertStore;
 use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use rustls::server::WebPkiClientVerifier;
+use rustls::server::danger::ClientCertVerifier;
 use std::io::{self, BufReader};
 use std::path::Path;
 use std::sync::Arc;
@@ -59,10 +64,13 @@ pub enum TlsRole {
 }
 
 /// Server-side TLS credentials: a certificate chain and the matching
-/// private key, both in DER form.
+/// private key, both in DER form, plus the verifier applied to client
+/// certificates during the handshake.
 ///
 /// Construct via [`load_pem`] for production deployments or
-/// [`self_signed_for_loopback`] for tests / local development.
+/// [`self_signed_for_loopback`] for tests / local development. Both
+/// start without client authentication; chain
+/// [`TlsServerCredentials::with_client_auth`] to enable mTLS.
 pub struct TlsServerCredentials {
     /// Leaf certificate first, intermediates in order, root LAST or
     /// omitted (matches the on-wire order rustls expects).
@@ -70,6 +78,24 @@ pub struct TlsServerCredentials {
     /// PKCS#8, RSA, or SEC1-encoded private key matching the leaf
     /// certificate's public key.
     pub key_der: PrivateKeyDer<'static>,
+    /// Client certificate verifier installed on the rustls
+    /// `ServerConfig`. [`WebPkiClientVerifier::no_client_auth`] unless
+    /// replaced via [`TlsServerCredentials::with_client_auth`].
+    pub client_verifier: Arc<dyn ClientCertVerifier>,
+}
+
+impl TlsServerCredentials {
+    /// Replace the client certificate verifier with one built from the
+    /// `client_auth` section of the listener config.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] under the same conditions as
+    /// [`client_cert_verifier`].
+    pub fn with_client_auth(mut self, config: &TlsClientAuthConfig) -> io::Result<Self> {
+        self.client_verifier = client_cert_verifier(config)?;
+        Ok(self)
+    }
 }
 
 impl std::fmt::Debug for TlsServerCredentials {
@@ -79,6 +105,7 @@ impl std::fmt::Debug for TlsServerCredentials {
         f.debug_struct("TlsServerCredentials")
             .field("cert_chain_len", &self.cert_chain.len())
             .field("key_der", &"<redacted>")
+            .field("client_auth", &self.client_verifier.offer_client_auth())
             .finish()
     }
 }
@@ -119,6 +146,7 @@ pub fn load_pem(cert_path: &Path, key_path: &Path) -> io::Result<TlsServerCreden
     Ok(TlsServerCredentials {
         cert_chain,
         key_der,
+        client_verifier: WebPkiClientVerifier::no_client_auth(),
     })
 }
 
@@ -140,7 +168,54 @@ pub fn self_signed_for_loopback() -> TlsServerCredentials {
     TlsServerCredentials {
         cert_chain,
         key_der,
+        client_verifier: WebPkiClientVerifier::no_client_auth(),
+    }
+}
+
+/// Build the client certificate verifier for a TLS-family listener
+/// (TCP-TLS, WSS, QUIC) from its `client_auth` config section.
+///
+/// Mirrors the legacy server's listeners: when client authentication is
+/// disabled, clients are not asked for a certificate; when enabled, the
+/// presented chain must be signed by a CA from `ca_file`, and with
+/// `required` set a handshake without a certificate is rejected.
+///
+/// # Errors
+///
+/// Returns [`io::Error`] when `ca_file` cannot be read, contains no
+/// certificates, or the verifier cannot be built from them.
+pub fn client_cert_verifier(
+    config: &TlsClientAuthConfig,
+) -> io::Result<Arc<dyn ClientCertVerifier>> {
+    if !config.enabled {
+        return Ok(WebPkiClientVerifier::no_client_auth());
+    }
+
+    install_default_crypto_provider();
+    let ca_file = std::fs::File::open(&config.ca_file)?;
+    let mut ca_reader = BufReader::new(ca_file);
+    let mut roots = RootCertStore::empty();
+    for cert in rustls_pemfile::certs(&mut ca_reader) {
+        roots
+            .add(cert?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    }
+    if roots.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("no CA certificates found in {}", config.ca_file),
+        ));
     }
+
+    let builder = WebPkiClientVerifier::builder(Arc::new(roots));
+    let builder = if config.required {
+        builder
+    } else {
+        builder.allow_unauthenticated()
+    };
+    builder
+        .build()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
 
 /// Install the rustls/ring crypto provider as the process-wide
@@ -231,6 +306,48 @@ mod tests {
         let _ = rustls::ServerConfig::builder().with_no_client_auth();
     }
 
+    fn client_auth_config(ca_file: &Path, required: bool) -> TlsClientAuthConfig {
+        TlsClientAuthConfig {
+            enabled: true,
+            required,
+            ca_file: ca_file.display().to_string(),
+            principal: configs::tls::ClientCertificatePrincipal::CommonName,
+        }
+    }
+
+    #[test]
+    fn client_cert_verifier_honours_client_auth_config() {
+        let ca = rcgen::generate_simple_self_signed(vec!["iggy-ca".to_owned()]).expect("rcgen");
+        let ca_file = tempfile::NamedTempFile::new().expect("tempfile ca");
+        std::fs::File::create(ca_file.path())
+            .unwrap()
+            .write_all(ca.cert.pem().as_bytes())
+            .unwrap();
+
+        let mut disabled = client_auth_config(ca_file.path(), true);
+        disabled.enabled = false;
+        let verifier = client_cert_verifier(&disabled).expect("disabled verifier");
+        assert!(!verifier.offer_client_auth());
+
+        let verifier =
+            client_cert_verifier(&client_auth_config(ca_file.path(), true)).expect("verifier");
+        assert!(verifier.offer_client_auth());
+        assert!(verifier.client_auth_mandatory());
+
+        let verifier =
+            client_cert_verifier(&client_auth_config(ca_file.path(), false)).expect("verifier");
+        assert!(verifier.offer_client_auth());
+        assert!(!verifier.client_auth_mandatory());
+    }
+
+    #[test]
+    fn client_cert_verifier_rejects_empty_ca_file() {
+        let ca_file = tempfile::NamedTempFile::new().expect("tempfile ca");
+        let err =
+            client_cert_verifier(&client_auth_config(ca_file.path(), true)).expect_err("must fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
     #[test]
     fn load_pem_rejects_empty_cert_file() {
         let cert_file = tempfile::NamedTempFile::new().expect("tempfile cert");
@@ -33,6 +33,7 @@ use message_bus::replica::listener::{MessageHandler, bind, run};
 use message_bus::transports::tls::self_signed_for_loopback;
 use message_bus::{IggyMessageBus, TlsServerCredentials};
 use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use rustls::server::WebPkiClientVerifier;
 use std::net::SocketAddr;
 use std::rc::Rc;
 use std::time::Duration;
@@ -207,6 +208,7 @@ async fn shard_zero_binds_all_six_planes_when_configured() {
     let quic_creds = QuicServerCredentials {
         cert_chain: vec![cert],
         key_der: key,
+        client_verifier: WebPkiClientVerifier::no_client_auth(),
     };
     let tls_creds = self_signed_for_loopback();
     let wss_creds = self_signed_for_loopback();
@@ -21,12 +21,15 @@
 //! `install_client_tcp_tls`, which drives the rustls handshake on its
 //! own task before installing reader / writer tasks. The handler echoes
 //! a Reply back via `bus.send_to_client`, the client reads the Reply.
+//! With a required `client_auth`, a client presenting no certificate
+//! must fail the handshake and never reach the handler.
 
 mod common;
 
 use async_channel::bounded;
 use common::{header_only, install_tls_clients_locally, loopback};
 use compio::net::TcpStream;
+use configs::tls::{ClientCertificatePrincipal, TlsClientAuthConfig};
 use iggy_binary_protocol::Command2;
 use iggy_binary_protocol::consensus::MESSAGE_ALIGN;
 use iggy_binary_protocol::consensus::iobuf::Frozen;
@@ -39,6 +42,7 @@ use message_bus::transports::{ActorContext, TransportConn};
 use message_bus::{FusedShutdown, IggyMessageBus, MessageBus, MessageBusConfig, Shutdown, framing};
 use rustls::RootCertStore;
 use rustls::pki_types::ServerName;
+use std::io::Write;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -191,3 +195,109 @@ async fn slow_tls_handshake_evicts_registry() {
         "graceful shutdown should not force-cancel"
     );
 }
+
+#[compio::test]
+async fn tcp_tls_client_listener_rejects_client_without_certificate_when_required() {
+    install_default_crypto_provider();
+
+    let bus = Rc::new(IggyMessageBus::new(0));
+
+    // Echo like a real dispatcher would, so an accepted client would
+    // observe a Reply instead of a torn-down connection.
+    let bus_for_handler = Rc::clone(&bus);
+    let on_request: RequestHandler = Rc::new(move |client_id, _| {
+        let bus = Rc::clone(&bus_for_handler);
+        compio::runtime::spawn(async move {
+            let reply = header_only(Command2::Reply, 42, 0).into_frozen();
+            let _ = bus.send_to_client(client_id, reply).await;
+        })
+        .detach();
+    });
+
+    let ca = rcgen::generate_simple_self_signed(vec!["iggy-ca".to_owned()]).expect("rcgen");
+    let mut ca_file = tempfile::NamedTempFile::new().expect("tempfile ca");
+    ca_file
+        .write_all(ca.cert.pem().as_bytes())
+        .expect("write ca");
+    let client_auth = TlsClientAuthConfig {
+        enabled: true,
+        required: true,
+        ca_file: ca_file.path().display().to_string(),
+        principal: ClientCertificatePrincipal::CommonName,
+    };
+
+    let creds = self_signed_for_loopback()
+        .with_client_auth(&client_auth)
+        .expect("client auth verifier");
+    let cert_chain = creds.cert_chain.clone();
+    let (listener, server_cfg, server_addr) =
+        bind(loopback(), creds).await.expect("tls listener bind");
+    let token = bus.token();
+    let on_accepted = install_tls_clients_locally(Rc::clone(&bus), on_request);
+    let accept_handle = compio::runtime::spawn(async move {
+        run(listener, server_cfg, token, on_accepted).await;
+    });
+    bus.track_background(accept_handle);
+
+    // The client trusts the server but has no certificate of its own.
+    let mut roots = RootCertStore::empty();
+    for cert in cert_chain {
+        roots.add(cert).expect("trust self-signed cert");
+    }
+    let client_cfg: Arc<rustls::ClientConfig> = Arc::new(
+        rustls::ClientConfig::builder()
+            .with_root_certificates(Arc::new(roots))
+            .with_no_client_auth(),
+    );
+    let server_name: ServerName<'static> = "localhost".try_into().expect("server name");
+
+    let client_tcp = TcpStream::connect(server_addr).await.expect("client dial");
+    let conn = TcpTlsTransportConn::new_client(client_tcp, client_cfg, server_name);
+
+    let (out_tx, out_rx) = bounded::<Frozen<MESSAGE_ALIGN>>(8);
+    let (in_tx, in_rx) = bounded::<Message<GenericHeader>>(8);
+    let (client_shutdown, client_token) = Shutdown::new();
+    let ctx = ActorContext {
+        in_tx,
+        rx: out_rx,
+        shutdown: FusedShutdown::single(client_token),
+        conn_shutdown: client_shutdown.clone(),
+        max_batch: 16,
+        max_message_size: framing::MAX_MESSAGE_SIZE,
+        label: "test-client",
+        peer: "test-client".to_owned(),
+    };
+    let client_handle = compio::runtime::spawn(async move { conn.run(ctx).await });
+
+    let request = header_only(Command2::Request, 42, 0).into_frozen();
+    let _ = out_tx.send(request).await;
+
+    // The server aborts the handshake, so the client connection ends
+    // without ever delivering a frame.
+    let received = compio::time::timeout(Duration::from_secs(5), in_rx.recv())
+        .await
+        .expect("client connection must be torn down within 5 s");
+    assert!(
+        received.is_err(),
+        "client without certificate must not receive a reply"
+    );
+
+    let evict_deadline = Instant::now() + Duration::from_secs(2);
+    while !bus.clients().is_empty() {
+        assert!(
+            Instant::now() < evict_deadline,
+            "rejected TLS client not evicted within deadline; len = {}",
+            bus.clients().len()
+        );
+        compio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    client_shutdown.trigger();
+    let _ = compio::time::timeout(Duration::from_secs(5), client_handle).await;
+
+    let outcome = bus.shutdown(Duration::from_secs(5)).await;
+    assert_eq!(
+        outcome.force, 0,
+        "graceful shutdown should not force-cancel"
+    );
+}
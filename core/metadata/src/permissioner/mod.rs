@@ -18,7 +18,10 @@
 pub mod permissioner_rules;
 
 use ahash::{AHashMap, AHashSet};
-use iggy_common::{GlobalPermissions, Permissions, StreamPermissions, UserId};
+use iggy_common::{
+    GlobalPermissions, Permissions, RoleId, StreamPermissions, TopicPermissions, UserId,
+};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Default, Clone)]
 pub struct Permissioner {
@@ -28,6 +31,13 @@ pub struct Permissioner {
     pub users_that_can_send_messages_to_all_streams: AHashSet<UserId>,
     pub users_that_can_poll_messages_from_specific_streams: AHashSet<(UserId, usize)>,
     pub users_that_can_send_messages_to_specific_streams: AHashSet<(UserId, usize)>,
+    /// Permissions assigned directly to the users, before the roles are applied.
+    pub users_assigned_permissions: AHashMap<UserId, Permissions>,
+    pub roles_permissions: AHashMap<RoleId, Permissions>,
+    pub users_roles: AHashSet<(UserId, RoleId)>,
+    /// Stream and topic names, used to resolve the stream pattern permissions.
+    pub streams_names: AHashMap<usize, String>,
+    pub topics_names: AHashMap<(usize, usize), String>,
 }
 
 impl Permissioner {
@@ -37,7 +47,181 @@ impl Permissioner {
     }
 
     pub fn init_permissions_for_user(&mut self, user_id: UserId, permissions: Option<Permissions>) {
-        let Some(permissions) = permissions else {
+        match permissions {
+            Some(permissions) => {
+                self.users_assigned_permissions.insert(user_id, permissions);
+            }
+            None => {
+                self.users_assigned_permissions.remove(&user_id);
+            }
+        }
+        self.refresh_user(user_id);
+    }
+
+    pub fn update_permissions_for_user(
+        &mut self,
+        user_id: UserId,
+        permissions: Option<Permissions>,
+    ) {
+        self.init_permissions_for_user(user_id, permissions);
+    }
+
+    pub fn delete_permissions_for_user(&mut self, user_id: UserId) {
+        self.users_assigned_permissions.remove(&user_id);
+        self.users_roles.retain(|(id, _)| *id != user_id);
+        self.clear_user(user_id);
+    }
+
+    pub fn set_role_permissions(&mut self, role_id: RoleId, permissions: Option<Permissions>) {
+        match permissions {
+            Some(permissions) => {
+                self.roles_permissions.insert(role_id, permissions);
+            }
+            None => {
+                self.roles_permissions.remove(&role_id);
+            }
+        }
+        for user_id in self.role_holders(role_id) {
+            self.refresh_user(user_id);
+        }
+    }
+
+    pub fn delete_role(&mut self, role_id: RoleId) {
+        let holders = self.role_holders(role_id);
+        self.roles_permissions.remove(&role_id);
+        self.users_roles.retain(|(_, id)| *id != role_id);
+        for user_id in holders {
+            self.refresh_user(user_id);
+        }
+    }
+
+    pub fn assign_role(&mut self, user_id: UserId, role_id: RoleId) {
+        if self.users_roles.insert((user_id, role_id)) {
+            self.refresh_user(user_id);
+        }
+    }
+
+    pub fn unassign_role(&mut self, user_id: UserId, role_id: RoleId) {
+        if self.users_roles.remove(&(user_id, role_id)) {
+            self.refresh_user(user_id);
+        }
+    }
+
+    /// Returns the permissions assigned to the user, extended with the ones granted by its roles.
+    #[must_use]
+    pub fn effective_permissions(&self, user_id: UserId) -> Option<Permissions> {
+        let mut effective = self.users_assigned_permissions.get(&user_id).cloned();
+        for (_, role_id) in self.users_roles.iter().filter(|(id, _)| *id == user_id) {
+            let Some(role_permissions) = self.roles_permissions.get(role_id) else {
+                continue;
+            };
+
+            match effective.as_mut() {
+                Some(permissions) => permissions.merge(role_permissions),
+                None => effective = Some(role_permissions.clone()),
+            }
+        }
+        effective
+    }
+
+    /// Registers the created or renamed stream and applies the matching stream pattern permissions.
+    pub fn index_stream(&mut self, stream_id: usize, name: &str) {
+        self.streams_names.insert(stream_id, name.to_owned());
+        self.refresh_users_with_patterns();
+    }
+
+    pub fn unindex_stream(&mut self, stream_id: usize) {
+        self.streams_names.remove(&stream_id);
+        self.topics_names.retain(|(id, _), _| *id != stream_id);
+        self.refresh_users_with_patterns();
+    }
+
+    /// Registers the created or renamed topic and applies the matching topic pattern permissions.
+    pub fn index_topic(&mut self, stream_id: usize, topic_id: usize, name: &str) {
+        self.topics_names
+            .insert((stream_id, topic_id), name.to_owned());
+        self.refresh_users_with_patterns();
+    }
+
+    pub fn unindex_topic(&mut self, stream_id: usize, topic_id: usize) {
+        self.topics_names.remove(&(stream_id, topic_id));
+        self.refresh_users_with_patterns();
+    }
+
+    /// Stream pattern permissions depend on the stream and topic names, so they have to be
+    /// resolved again whenever a stream or topic is created, renamed or deleted.
+    fn refresh_users_with_patterns(&mut self) {
+        let mut user_ids: Vec<UserId> = self.users_assigned_permissions.keys().copied().collect();
+        user_ids.extend(self.users_roles.iter().map(|(user_id, _)| *user_id));
+        user_ids.sort_unstable();
+        user_ids.dedup();
+        for user_id in user_ids {
+            if self
+                .effective_permissions(user_id)
+                .is_some_and(|permissions| permissions.has_patterns())
+            {
+                self.refresh_user(user_id);
+            }
+        }
+    }
+
+    fn resolve_stream_permissions(
+        &self,
+        permissions: &Permissions,
+    ) -> BTreeMap<usize, StreamPermissions> {
+        let mut streams = permissions.streams.clone().unwrap_or_default();
+        let Some(stream_patterns) = &permissions.stream_patterns else {
+            return streams;
+        };
+
+        for stream_pattern in stream_patterns {
+            for (&stream_id, stream_name) in &self.streams_names {
+                if !stream_pattern.matches(stream_name) {
+                    continue;
+                }
+
+                let mut stream_permissions = stream_pattern.to_stream_permissions();
+                if let Some(topic_patterns) = &stream_pattern.topics {
+                    let mut topics = BTreeMap::new();
+                    for topic_pattern in topic_patterns {
+                        for (&(_, topic_id), topic_name) in self
+                            .topics_names
+                            .iter()
+                            .filter(|((id, _), _)| *id == stream_id)
+                        {
+                            if topic_pattern.matches(topic_name) {
+                                topics
+                                    .entry(topic_id)
+                                    .or_insert_with(TopicPermissions::default)
+                                    .merge(&topic_pattern.to_topic_permissions());
+                            }
+                        }
+                    }
+                    if !topics.is_empty() {
+                        stream_permissions.topics = Some(topics);
+                    }
+                }
+
+                streams
+                    .entry(stream_id)
+                    .or_default()
+                    .merge(&stream_permissions);
+            }
+        }
+        streams
+    }
+
+    fn role_holders(&self, role_id: RoleId) -> Vec<UserId> {
+        self.users_roles
+            .iter()
+            .filter(|(_, id)| *id == role_id)
+            .map(|(user_id, _)| *user_id)
+            .collect()
+    }
+
+    fn refresh_user(&mut self, user_id: UserId) {
+        self.clear_user(user_id);
+        let Some(permissions) = self.effective_permissions(user_id) else {
             return;
         };
         if permissions.global.poll_messages {
@@ -50,10 +234,8 @@ impl Permissioner {
                 .insert(user_id);
         }
 
+        let streams = self.resolve_stream_permissions(&permissions);
         self.users_permissions.insert(user_id, permissions.global);
-        let Some(streams) = permissions.streams else {
-            return;
-        };
         for (stream_id, stream) in streams {
             if stream.poll_messages {
                 self.users_that_can_poll_messages_from_specific_streams
@@ -70,16 +252,7 @@ impl Permissioner {
         }
     }
 
-    pub fn update_permissions_for_user(
-        &mut self,
-        user_id: UserId,
-        permissions: Option<Permissions>,
-    ) {
-        self.delete_permissions_for_user(user_id);
-        self.init_permissions_for_user(user_id, permissions);
-    }
-
-    pub fn delete_permissions_for_user(&mut self, user_id: UserId) {
+    fn clear_user(&mut self, user_id: UserId) {
         self.users_permissions.remove(&user_id);
         self.users_that_can_poll_messages_from_all_streams
             .remove(&user_id);
@@ -93,3 +266,289 @@ impl Permissioner {
             .retain(|(id, _)| *id != user_id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::{
+        ConsumerGroupPermissions, StreamPatternPermissions, TopicPatternPermissions,
+    };
+
+    fn stream_permissions(stream_id: usize, stream: StreamPermissions) -> Permissions {
+        Permissions {
+            streams: Some(BTreeMap::from([(stream_id, stream)])),
+            ..Permissions::default()
+        }
+    }
+
+    fn poll_stream(stream_id: usize) -> Permissions {
+        stream_permissions(
+            stream_id,
+            StreamPermissions {
+                poll_messages: true,
+                ..StreamPermissions::default()
+            },
+        )
+    }
+
+    fn send_stream(stream_id: usize) -> Permissions {
+        stream_permissions(
+            stream_id,
+            StreamPermissions {
+                send_messages: true,
+                ..StreamPermissions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn role_permissions_should_apply_to_users_holding_the_role() {
+        let mut permissioner = Permissioner::new();
+        permissioner.init_permissions_for_user(1, None);
+        permissioner.set_role_permissions(10, Some(poll_stream(1)));
+        assert!(permissioner.poll_messages(1, 1, 1).is_err());
+
+        permissioner.assign_role(1, 10);
+        assert!(permissioner.poll_messages(1, 1, 1).is_ok());
+        assert!(permissioner.poll_messages(1, 2, 1).is_err());
+        assert!(permissioner.append_messages(1, 1, 1).is_err());
+
+        permissioner.unassign_role(1, 10);
+        assert!(permissioner.poll_messages(1, 1, 1).is_err());
+    }
+
+    #[test]
+    fn role_permissions_should_be_unioned_with_user_permissions() {
+        let mut permissioner = Permissioner::new();
+        permissioner.init_permissions_for_user(1, Some(poll_stream(1)));
+        permissioner.set_role_permissions(10, Some(send_stream(1)));
+        permissioner.set_role_permissions(20, Some(poll_stream(2)));
+        permissioner.assign_role(1, 10);
+        permissioner.assign_role(1, 20);
+
+        assert!(permissioner.poll_messages(1, 1, 1).is_ok());
+        assert!(permissioner.append_messages(1, 1, 1).is_ok());
+        assert!(permissioner.poll_messages(1, 2, 1).is_ok());
+        assert!(permissioner.append_messages(1, 2, 1).is_err());
+
+        permissioner.update_permissions_for_user(1, None);
+        assert!(permissioner.poll_messages(1, 1, 1).is_err());
+        assert!(permissioner.append_messages(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn changing_role_permissions_should_refresh_role_holders() {
+        let mut permissioner = Permissioner::new();
+        permissioner.set_role_permissions(10, Some(poll_stream(1)));
+        permissioner.assign_role(1, 10);
+        permissioner.assign_role(2, 10);
+
+        permissioner.set_role_permissions(10, Some(send_stream(1)));
+        for user_id in [1, 2] {
+            assert!(permissioner.poll_messages(user_id, 1, 1).is_err());
+            assert!(permissioner.append_messages(user_id, 1, 1).is_ok());
+        }
+
+        permissioner.delete_role(10);
+        for user_id in [1, 2] {
+            assert!(permissioner.append_messages(user_id, 1, 1).is_err());
+            assert!(permissioner.effective_permissions(user_id).is_none());
+        }
+        assert!(permissioner.users_roles.is_empty());
+    }
+
+    #[test]
+    fn global_role_permissions_should_apply_to_all_streams() {
+        let mut permissioner = Permissioner::new();
+        permissioner.set_role_permissions(
+            10,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    ..GlobalPermissions::default()
+                },
+                ..Permissions::default()
+            }),
+        );
+        permissioner.assign_role(1, 10);
+
+        assert!(permissioner.get_stream(1, 1).is_ok());
+        assert!(permissioner.get_topic(1, 7, 3).is_ok());
+        assert!(permissioner.create_stream(1).is_err());
+
+        permissioner.delete_permissions_for_user(1);
+        assert!(permissioner.get_stream(1, 1).is_err());
+    }
+
+    fn stream_pattern(pattern: StreamPatternPermissions) -> Permissions {
+        Permissions {
+            stream_patterns: Some(vec![pattern]),
+            ..Permissions::default()
+        }
+    }
+
+    #[test]
+    fn stream_pattern_should_cover_streams_created_after_the_grant() {
+        let mut permissioner = Permissioner::new();
+        permissioner.index_stream(1, "orders-eu");
+        permissioner.init_permissions_for_user(
+            1,
+            Some(stream_pattern(StreamPatternPermissions {
+                pattern: "orders-*".to_string(),
+                poll_messages: true,
+                ..StreamPatternPermissions::default()
+            })),
+        );
+        assert!(permissioner.poll_messages(1, 1, 1).is_ok());
+        assert!(permissioner.poll_messages(1, 2, 1).is_err());
+
+        permissioner.index_stream(2, "orders-us");
+        permissioner.index_stream(3, "payments");
+        assert!(permissioner.poll_messages(1, 2, 1).is_ok());
+        assert!(permissioner.poll_messages(1, 3, 1).is_err());
+        assert!(permissioner.append_messages(1, 2, 1).is_err());
+
+        permissioner.index_stream(2, "archive");
+        assert!(permissioner.poll_messages(1, 2, 1).is_err());
+
+        permissioner.unindex_stream(1);
+        assert!(permissioner.poll_messages(1, 1, 1).is_err());
+    }
+
+    #[test]
+    fn topic_pattern_should_cover_topics_created_after_the_grant() {
+        let mut permissioner = Permissioner::new();
+        permissioner.index_stream(1, "orders");
+        permissioner.set_role_permissions(
+            10,
+            Some(stream_pattern(StreamPatternPermissions {
+                pattern: "orders".to_string(),
+                topics: Some(vec![TopicPatternPermissions {
+                    pattern: "events-*".to_string(),
+                    send_messages: true,
+                    ..TopicPatternPermissions::default()
+                }]),
+                ..StreamPatternPermissions::default()
+            })),
+        );
+        permissioner.assign_role(1, 10);
+        assert!(permissioner.append_messages(1, 1, 1).is_err());
+
+        permissioner.index_topic(1, 1, "events-created");
+        permissioner.index_topic(1, 2, "audit");
+        assert!(permissioner.append_messages(1, 1, 1).is_ok());
+        assert!(permissioner.append_messages(1, 1, 2).is_err());
+        assert!(permissioner.poll_messages(1, 1, 1).is_err());
+
+        permissioner.unindex_topic(1, 1);
+        assert!(permissioner.append_messages(1, 1, 1).is_err());
+    }
+
+    fn topic_with_groups(
+        consumer_groups: BTreeMap<usize, ConsumerGroupPermissions>,
+    ) -> Permissions {
+        stream_permissions(
+            1,
+            StreamPermissions {
+                topics: Some(BTreeMap::from([(
+                    1,
+                    TopicPermissions {
+                        read_topic: true,
+                        poll_messages: true,
+                        consumer_groups: Some(consumer_groups),
+                        ..TopicPermissions::default()
+                    },
+                )])),
+                ..StreamPermissions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn consumer_group_permissions_should_restrict_the_listed_groups() {
+        let mut permissioner = Permissioner::new();
+        permissioner.init_permissions_for_user(
+            1,
+            Some(topic_with_groups(BTreeMap::from([
+                (
+                    1,
+                    ConsumerGroupPermissions {
+                        join_group: true,
+                        ..ConsumerGroupPermissions::default()
+                    },
+                ),
+                (
+                    2,
+                    ConsumerGroupPermissions {
+                        commit_offsets: true,
+                        ..ConsumerGroupPermissions::default()
+                    },
+                ),
+            ]))),
+        );
+
+        assert!(permissioner.join_consumer_group(1, 1, 1, 1).is_ok());
+        assert!(permissioner.join_consumer_group(1, 1, 1, 2).is_err());
+        assert!(permissioner.join_consumer_group(1, 1, 1, 3).is_err());
+
+        assert!(
+            permissioner
+                .store_consumer_offset(1, 1, 1, Some(1))
+                .is_err()
+        );
+        assert!(permissioner.store_consumer_offset(1, 1, 1, Some(2)).is_ok());
+        assert!(permissioner.store_consumer_offset(1, 1, 1, None).is_ok());
+
+        assert!(
+            permissioner
+                .delete_consumer_offset(1, 1, 1, Some(1))
+                .is_err()
+        );
+        assert!(
+            permissioner
+                .delete_consumer_offset(1, 1, 1, Some(2))
+                .is_err()
+        );
+        assert!(permissioner.delete_consumer_offset(1, 1, 1, None).is_ok());
+
+        assert!(permissioner.delete_consumer_group(1, 1, 1, 1).is_err());
+    }
+
+    #[test]
+    fn consumer_group_permissions_should_fall_back_to_topic_permissions() {
+        let mut permissioner = Permissioner::new();
+        permissioner.init_permissions_for_user(1, Some(topic_with_groups(BTreeMap::new())));
+        assert!(permissioner.join_consumer_group(1, 1, 1, 3).is_ok());
+        assert!(permissioner.store_consumer_offset(1, 1, 1, Some(3)).is_ok());
+        assert!(
+            permissioner
+                .delete_consumer_offset(1, 1, 1, Some(3))
+                .is_ok()
+        );
+
+        permissioner.init_permissions_for_user(2, Some(poll_stream(2)));
+        assert!(permissioner.join_consumer_group(2, 1, 1, 3).is_err());
+        assert!(
+            permissioner
+                .store_consumer_offset(2, 1, 1, Some(3))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn managing_the_topic_should_allow_every_consumer_group() {
+        let mut permissioner = Permissioner::new();
+        let mut permissions =
+            topic_with_groups(BTreeMap::from([(1, ConsumerGroupPermissions::default())]));
+        permissions.global.manage_topics = true;
+        permissioner.init_permissions_for_user(1, Some(permissions));
+
+        assert!(permissioner.join_consumer_group(1, 1, 1, 1).is_ok());
+        assert!(permissioner.delete_consumer_group(1, 1, 1, 2).is_ok());
+        assert!(
+            permissioner
+                .delete_consumer_offset(1, 1, 1, Some(1))
+                .is_ok()
+        );
+    }
+}
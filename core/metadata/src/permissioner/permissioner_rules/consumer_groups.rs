@@ -17,7 +17,7 @@
  */
 
 use crate::permissioner::Permissioner;
-use iggy_common::IggyError;
+use iggy_common::{ConsumerGroupPermissions, IggyError};
 
 #[allow(clippy::missing_errors_doc)]
 impl Permissioner {
@@ -35,8 +35,16 @@ impl Permissioner {
         user_id: u32,
         stream_id: usize,
         topic_id: usize,
+        group_id: usize,
     ) -> Result<(), IggyError> {
-        self.get_topic(user_id, stream_id, topic_id)
+        self.consumer_group(
+            user_id,
+            stream_id,
+            topic_id,
+            group_id,
+            |group| group.manage_group,
+            Self::get_topic,
+        )
     }
 
     pub fn get_consumer_group(
@@ -62,8 +70,16 @@ impl Permissioner {
         user_id: u32,
         stream_id: usize,
         topic_id: usize,
+        group_id: usize,
     ) -> Result<(), IggyError> {
-        self.get_topic(user_id, stream_id, topic_id)
+        self.consumer_group(
+            user_id,
+            stream_id,
+            topic_id,
+            group_id,
+            |group| group.join_group,
+            Self::get_topic,
+        )
     }
 
     pub fn leave_consumer_group(
@@ -74,4 +90,37 @@ impl Permissioner {
     ) -> Result<(), IggyError> {
         self.get_topic(user_id, stream_id, topic_id)
     }
+
+    /// When the topic permissions list the consumer groups, only those groups are allowed,
+    /// otherwise the `fallback` topic-level check applies. Managing the topic allows every group.
+    pub(super) fn consumer_group(
+        &self,
+        user_id: u32,
+        stream_id: usize,
+        topic_id: usize,
+        group_id: usize,
+        allows: fn(&ConsumerGroupPermissions) -> bool,
+        fallback: fn(&Self, u32, usize, usize) -> Result<(), IggyError>,
+    ) -> Result<(), IggyError> {
+        if self.manage_topic(user_id, stream_id, topic_id).is_ok() {
+            return Ok(());
+        }
+
+        let consumer_groups = self
+            .users_streams_permissions
+            .get(&(user_id, stream_id))
+            .and_then(|stream_permissions| stream_permissions.topics.as_ref())
+            .and_then(|topics| topics.get(&topic_id))
+            .filter(|topic_permissions| topic_permissions.restricts_consumer_groups())
+            .and_then(|topic_permissions| topic_permissions.consumer_groups.as_ref());
+
+        let Some(consumer_groups) = consumer_groups else {
+            return fallback(self, user_id, stream_id, topic_id);
+        };
+
+        match consumer_groups.get(&group_id) {
+            Some(group) if group.manage_group || allows(group) => Ok(()),
+            _ => Err(IggyError::Unauthorized),
+        }
+    }
 }
@@ -30,21 +30,45 @@ impl Permissioner {
         self.poll_messages(user_id, stream_id, topic_id)
     }
 
+    /// With a consumer group, the group listed in the topic permissions has to allow `commit_offsets`.
     pub fn store_consumer_offset(
         &self,
         user_id: u32,
         stream_id: usize,
         topic_id: usize,
+        group_id: Option<usize>,
     ) -> Result<(), IggyError> {
-        self.poll_messages(user_id, stream_id, topic_id)
+        let Some(group_id) = group_id else {
+            return self.get_consumer_offset(user_id, stream_id, topic_id);
+        };
+        self.consumer_group(
+            user_id,
+            stream_id,
+            topic_id,
+            group_id,
+            |group| group.commit_offsets,
+            Self::get_consumer_offset,
+        )
     }
 
+    /// With a consumer group, the group listed in the topic permissions has to allow `delete_offsets`.
     pub fn delete_consumer_offset(
         &self,
         user_id: u32,
         stream_id: usize,
         topic_id: usize,
+        group_id: Option<usize>,
     ) -> Result<(), IggyError> {
-        self.poll_messages(user_id, stream_id, topic_id)
+        let Some(group_id) = group_id else {
+            return self.get_consumer_offset(user_id, stream_id, topic_id);
+        };
+        self.consumer_group(
+            user_id,
+            stream_id,
+            topic_id,
+            group_id,
+            |group| group.delete_offsets,
+            Self::get_consumer_offset,
+        )
     }
 }
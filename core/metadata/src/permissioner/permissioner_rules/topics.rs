@@ -125,7 +125,7 @@ impl Permissioner {
     }
 
     /// Inheritance: `manage_streams` -> `manage_topics`
-    fn manage_topic(
+    pub(super) fn manage_topic(
         &self,
         user_id: u32,
         stream_id: usize,
@@ -297,7 +297,9 @@ macro_rules! impl_fill_restore {
 mod tests {
     use super::*;
     use crate::stm::stream::{PartitionSnapshot, StatsSnapshot, StreamSnapshot, TopicSnapshot};
-    use iggy_common::{CompressionAlgorithm, IggyExpiry, IggyTimestamp, MaxTopicSize};
+    use iggy_common::{
+        CleanupPolicy, CompressionAlgorithm, IggyExpiry, IggyTimestamp, MaxTopicSize,
+    };
 
     #[test]
     fn test_metadata_snapshot_roundtrip() {
@@ -339,6 +341,7 @@ mod tests {
                             message_expiry: IggyExpiry::default(),
                             compression_algorithm: CompressionAlgorithm::default(),
                             max_topic_size: MaxTopicSize::default(),
+                            cleanup_policy: CleanupPolicy::default(),
                             stats: StatsSnapshot {
                                 size_bytes: 256,
                                 messages_count: 12,
@@ -413,14 +416,8 @@ mod tests {
                 ),
             ],
             personal_access_tokens: vec![],
-            permissioner: PermissionerSnapshot {
-                users_permissions: vec![],
-                users_streams_permissions: vec![],
-                users_that_can_poll_messages_from_all_streams: vec![],
-                users_that_can_send_messages_to_all_streams: vec![],
-                users_that_can_poll_messages_from_specific_streams: vec![],
-                users_that_can_send_messages_to_specific_streams: vec![],
-            },
+            roles: vec![],
+            permissioner: PermissionerSnapshot::default(),
         };
 
         let streams_snap = StreamsSnapshot {
@@ -31,7 +31,8 @@ use iggy_binary_protocol::requests::topics::{
     CreateTopicWithAssignmentsRequest, DeleteTopicRequest, PurgeTopicRequest, UpdateTopicRequest,
 };
 use iggy_common::{
-    CompressionAlgorithm, IggyExpiry, IggyTimestamp, MaxTopicSize, StreamStats, TopicStats,
+    CleanupPolicy, CompressionAlgorithm, IggyExpiry, IggyTimestamp, MaxTopicSize, StreamStats,
+    TopicStats,
 };
 use serde::{Deserialize, Serialize};
 use slab::Slab;
@@ -82,6 +83,8 @@ pub struct TopicSnapshot {
     pub message_expiry: IggyExpiry,
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
     pub stats: StatsSnapshot,
     pub partitions: Vec<PartitionSnapshot>,
     pub round_robin_counter: usize,
@@ -96,6 +99,7 @@ pub struct Topic {
     pub message_expiry: IggyExpiry,
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
+    pub cleanup_policy: CleanupPolicy,
 
     pub stats: Arc<TopicStats>,
     pub partitions: Vec<Partition>,
@@ -112,6 +116,7 @@ impl Default for Topic {
             message_expiry: IggyExpiry::default(),
             compression_algorithm: CompressionAlgorithm::default(),
             max_topic_size: MaxTopicSize::default(),
+            cleanup_policy: CleanupPolicy::default(),
             stats: Arc::new(TopicStats::default()),
             partitions: Vec::new(),
             round_robin_counter: Arc::new(AtomicUsize::new(0)),
@@ -120,6 +125,7 @@ impl Default for Topic {
 }
 
 impl Topic {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: Arc<str>,
         created_at: IggyTimestamp,
@@ -127,6 +133,7 @@ impl Topic {
         message_expiry: IggyExpiry,
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
         stream_stats: Arc<StreamStats>,
     ) -> Self {
         Self {
@@ -137,6 +144,7 @@ impl Topic {
             message_expiry,
             compression_algorithm,
             max_topic_size,
+            cleanup_policy,
             stats: Arc::new(TopicStats::new(stream_stats)),
             partitions: Vec::new(),
             round_robin_counter: Arc::new(AtomicUsize::new(0)),
@@ -436,6 +444,8 @@ impl StateHandler for CreateTopicWithAssignmentsRequest {
             )
             .unwrap_or_default(),
             max_topic_size: MaxTopicSize::from(self.request.max_topic_size),
+            cleanup_policy: CleanupPolicy::from_code(self.request.cleanup_policy)
+                .unwrap_or_default(),
             stats: Arc::new(TopicStats::new(stream.stats.clone())),
             partitions: Vec::new(),
             round_robin_counter: Arc::new(AtomicUsize::new(0)),
@@ -490,6 +500,7 @@ impl StateHandler for UpdateTopicRequest {
             CompressionAlgorithm::from_code(self.compression_algorithm).unwrap_or_default();
         topic.message_expiry = IggyExpiry::from(self.message_expiry);
         topic.max_topic_size = MaxTopicSize::from(self.max_topic_size);
+        topic.cleanup_policy = CleanupPolicy::from_code(self.cleanup_policy).unwrap_or_default();
         if self.replication_factor != 0 {
             topic.replication_factor = self.replication_factor;
         }
@@ -635,6 +646,7 @@ impl Snapshotable for Streams {
                                     message_expiry: topic.message_expiry,
                                     compression_algorithm: topic.compression_algorithm,
                                     max_topic_size: topic.max_topic_size,
+                                    cleanup_policy: topic.cleanup_policy,
                                     stats: StatsSnapshot {
                                         size_bytes: t_size,
                                         messages_count: t_msgs,
@@ -709,6 +721,7 @@ impl Snapshotable for Streams {
                     message_expiry: topic_snap.message_expiry,
                     compression_algorithm: topic_snap.compression_algorithm,
                     max_topic_size: topic_snap.max_topic_size,
+                    cleanup_policy: topic_snap.cleanup_policy,
                     stats: topic_stats,
                     partitions: topic_snap
                         .partitions
@@ -786,6 +799,7 @@ mod tests {
             max_topic_size: 0,
             replication_factor: 1,
             name: WireName::new(name).unwrap(),
+            cleanup_policy: 1,
         }
     }
 
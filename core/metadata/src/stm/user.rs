@@ -25,12 +25,15 @@ use iggy_binary_protocol::WireIdentifier;
 use iggy_binary_protocol::requests::personal_access_tokens::{
     CreatePersonalAccessTokenRequest, DeletePersonalAccessTokenRequest,
 };
+use iggy_binary_protocol::requests::roles::{
+    AssignRoleRequest, CreateRoleRequest, DeleteRoleRequest, UnassignRoleRequest, UpdateRoleRequest,
+};
 use iggy_binary_protocol::requests::users::{
     ChangePasswordRequest, CreateUserRequest, DeleteUserRequest, UpdatePermissionsRequest,
     UpdateUserRequest,
 };
 use iggy_common::{
-    GlobalPermissions, IggyExpiry, IggyTimestamp, Permissions, PersonalAccessToken,
+    GlobalPermissions, IggyExpiry, IggyTimestamp, Permissions, PersonalAccessToken, RoleId,
     StreamPermissions, UserId, UserStatus,
 };
 use serde::{Deserialize, Serialize};
@@ -84,11 +87,25 @@ impl User {
     }
 }
 
+// ============================================================================
+// Role Entity
+// ============================================================================
+
+#[derive(Debug, Clone, Default)]
+pub struct Role {
+    pub id: RoleId,
+    pub name: Arc<str>,
+    pub created_at: IggyTimestamp,
+    pub permissions: Option<Arc<Permissions>>,
+}
+
 define_state! {
     Users {
         index: AHashMap<Arc<str>, UserId>,
         items: Slab<User>,
         personal_access_tokens: AHashMap<UserId, AHashMap<Arc<str>, PersonalAccessToken>>,
+        roles_index: AHashMap<Arc<str>, RoleId>,
+        roles: Slab<Role>,
         permissioner: Permissioner,
     }
 }
@@ -102,6 +119,11 @@ collect_handlers! {
         UpdatePermissions,
         CreatePersonalAccessToken,
         DeletePersonalAccessToken,
+        CreateRole,
+        UpdateRole,
+        DeleteRole,
+        AssignRole,
+        UnassignRole,
     }
 }
 
@@ -119,6 +141,22 @@ impl UsersInner {
             WireIdentifier::String(name) => self.index.get(name.as_str()).map(|&id| id as usize),
         }
     }
+
+    fn resolve_role_id(&self, identifier: &WireIdentifier) -> Option<usize> {
+        match identifier {
+            WireIdentifier::Numeric(id) => {
+                let id = *id as usize;
+                if self.roles.contains(id) {
+                    Some(id)
+                } else {
+                    None
+                }
+            }
+            WireIdentifier::String(name) => {
+                self.roles_index.get(name.as_str()).map(|&id| id as usize)
+            }
+        }
+    }
 }
 
 // TODO(hubcio): Serialize proper reply (e.g. assigned user ID) instead of empty Bytes.
@@ -155,6 +193,12 @@ impl StateHandler for CreateUserRequest {
         state
             .personal_access_tokens
             .insert(id as UserId, AHashMap::default());
+        state.permissioner.init_permissions_for_user(
+            id as UserId,
+            self.permissions
+                .as_ref()
+                .map(|p| Permissions::from(p.clone())),
+        );
         Bytes::new()
     }
 }
@@ -206,6 +250,9 @@ impl StateHandler for DeleteUserRequest {
             state.items.remove(user_id);
             state.index.remove(&username);
             state.personal_access_tokens.remove(&(user_id as UserId));
+            state
+                .permissioner
+                .delete_permissions_for_user(user_id as UserId);
         }
         Bytes::new()
     }
@@ -233,10 +280,14 @@ impl StateHandler for UpdatePermissionsRequest {
         };
 
         if let Some(user) = state.items.get_mut(user_id) {
-            user.permissions = self
+            let permissions = self
                 .permissions
                 .as_ref()
-                .map(|p| Arc::new(Permissions::from(p.clone())));
+                .map(|p| Permissions::from(p.clone()));
+            user.permissions = permissions.clone().map(Arc::new);
+            state
+                .permissioner
+                .update_permissions_for_user(user.id, permissions);
         }
         Bytes::new()
     }
@@ -283,6 +334,111 @@ impl StateHandler for DeletePersonalAccessTokenRequest {
     }
 }
 
+impl StateHandler for CreateRoleRequest {
+    type State = UsersInner;
+    #[allow(clippy::cast_possible_truncation)]
+    fn apply(&self, state: &mut UsersInner) -> Bytes {
+        let name_arc: Arc<str> = Arc::from(self.name.as_str());
+        if state.roles_index.contains_key(&name_arc) {
+            return Bytes::new();
+        }
+
+        let permissions = self
+            .permissions
+            .as_ref()
+            .map(|p| Permissions::from(p.clone()));
+        let role = Role {
+            id: 0,
+            name: name_arc.clone(),
+            created_at: IggyTimestamp::now(),
+            permissions: permissions.clone().map(Arc::new),
+        };
+
+        let id = state.roles.insert(role);
+        if let Some(role) = state.roles.get_mut(id) {
+            role.id = id as RoleId;
+        }
+
+        state.roles_index.insert(name_arc, id as RoleId);
+        state
+            .permissioner
+            .set_role_permissions(id as RoleId, permissions);
+        Bytes::new()
+    }
+}
+
+impl StateHandler for UpdateRoleRequest {
+    type State = UsersInner;
+    fn apply(&self, state: &mut UsersInner) -> Bytes {
+        let Some(role_id) = state.resolve_role_id(&self.role_id) else {
+            return Bytes::new();
+        };
+
+        if let Some(role) = state.roles.get_mut(role_id) {
+            let permissions = self
+                .permissions
+                .as_ref()
+                .map(|p| Permissions::from(p.clone()));
+            role.permissions = permissions.clone().map(Arc::new);
+            state
+                .permissioner
+                .set_role_permissions(role.id, permissions);
+        }
+        Bytes::new()
+    }
+}
+
+impl StateHandler for DeleteRoleRequest {
+    type State = UsersInner;
+    #[allow(clippy::cast_possible_truncation)]
+    fn apply(&self, state: &mut UsersInner) -> Bytes {
+        let Some(role_id) = state.resolve_role_id(&self.role_id) else {
+            return Bytes::new();
+        };
+
+        let role = state.roles.remove(role_id);
+        state.roles_index.remove(&role.name);
+        state.permissioner.delete_role(role_id as RoleId);
+        Bytes::new()
+    }
+}
+
+impl StateHandler for AssignRoleRequest {
+    type State = UsersInner;
+    #[allow(clippy::cast_possible_truncation)]
+    fn apply(&self, state: &mut UsersInner) -> Bytes {
+        let (Some(user_id), Some(role_id)) = (
+            state.resolve_user_id(&self.user_id),
+            state.resolve_role_id(&self.role_id),
+        ) else {
+            return Bytes::new();
+        };
+
+        state
+            .permissioner
+            .assign_role(user_id as UserId, role_id as RoleId);
+        Bytes::new()
+    }
+}
+
+impl StateHandler for UnassignRoleRequest {
+    type State = UsersInner;
+    #[allow(clippy::cast_possible_truncation)]
+    fn apply(&self, state: &mut UsersInner) -> Bytes {
+        let (Some(user_id), Some(role_id)) = (
+            state.resolve_user_id(&self.user_id),
+            state.resolve_role_id(&self.role_id),
+        ) else {
+            return Bytes::new();
+        };
+
+        state
+            .permissioner
+            .unassign_role(user_id as UserId, role_id as RoleId);
+        Bytes::new()
+    }
+}
+
 /// User snapshot representation for serialization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserSnapshot {
@@ -303,8 +459,17 @@ pub struct PersonalAccessTokenSnapshot {
     pub expiry_at: Option<IggyTimestamp>,
 }
 
-/// Permissioner snapshot representation for serialization.
+/// Role snapshot representation for serialization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleSnapshot {
+    pub id: RoleId,
+    pub name: String,
+    pub created_at: IggyTimestamp,
+    pub permissions: Option<Permissions>,
+}
+
+/// Permissioner snapshot representation for serialization.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct PermissionerSnapshot {
     pub users_permissions: Vec<(UserId, GlobalPermissions)>,
     pub users_streams_permissions: Vec<((UserId, usize), StreamPermissions)>,
@@ -312,6 +477,16 @@ pub struct PermissionerSnapshot {
     pub users_that_can_send_messages_to_all_streams: Vec<UserId>,
     pub users_that_can_poll_messages_from_specific_streams: Vec<(UserId, usize)>,
     pub users_that_can_send_messages_to_specific_streams: Vec<(UserId, usize)>,
+    #[serde(default)]
+    pub users_assigned_permissions: Vec<(UserId, Permissions)>,
+    #[serde(default)]
+    pub roles_permissions: Vec<(RoleId, Permissions)>,
+    #[serde(default)]
+    pub users_roles: Vec<(UserId, RoleId)>,
+    #[serde(default)]
+    pub streams_names: Vec<(usize, String)>,
+    #[serde(default)]
+    pub topics_names: Vec<((usize, usize), String)>,
 }
 
 /// Snapshot representation for the Users state machine.
@@ -319,12 +494,15 @@ pub struct PermissionerSnapshot {
 pub struct UsersSnapshot {
     pub items: Vec<(usize, UserSnapshot)>,
     pub personal_access_tokens: Vec<(UserId, Vec<(String, PersonalAccessTokenSnapshot)>)>,
+    #[serde(default)]
+    pub roles: Vec<(usize, RoleSnapshot)>,
     pub permissioner: PermissionerSnapshot,
 }
 
 impl Snapshotable for Users {
     type Snapshot = UsersSnapshot;
 
+    #[allow(clippy::too_many_lines)]
     fn to_snapshot(&self) -> Self::Snapshot {
         self.inner.read(|inner| {
             let items: Vec<(usize, UserSnapshot)> = inner
@@ -368,6 +546,22 @@ impl Snapshotable for Users {
                     })
                     .collect();
 
+            let roles: Vec<(usize, RoleSnapshot)> = inner
+                .roles
+                .iter()
+                .map(|(role_id, role)| {
+                    (
+                        role_id,
+                        RoleSnapshot {
+                            id: role.id,
+                            name: role.name.to_string(),
+                            created_at: role.created_at,
+                            permissions: role.permissions.as_ref().map(|p| (**p).clone()),
+                        },
+                    )
+                })
+                .collect();
+
             let permissioner = PermissionerSnapshot {
                 users_permissions: inner
                     .permissioner
@@ -405,11 +599,37 @@ impl Snapshotable for Users {
                     .iter()
                     .copied()
                     .collect(),
+                users_assigned_permissions: inner
+                    .permissioner
+                    .users_assigned_permissions
+                    .iter()
+                    .map(|(&k, v)| (k, v.clone()))
+                    .collect(),
+                roles_permissions: inner
+                    .permissioner
+                    .roles_permissions
+                    .iter()
+                    .map(|(&k, v)| (k, v.clone()))
+                    .collect(),
+                users_roles: inner.permissioner.users_roles.iter().copied().collect(),
+                streams_names: inner
+                    .permissioner
+                    .streams_names
+                    .iter()
+                    .map(|(&k, v)| (k, v.clone()))
+                    .collect(),
+                topics_names: inner
+                    .permissioner
+                    .topics_names
+                    .iter()
+                    .map(|(&k, v)| (k, v.clone()))
+                    .collect(),
             };
 
             UsersSnapshot {
                 items,
                 personal_access_tokens,
+                roles,
                 permissioner,
             }
         })
@@ -455,6 +675,22 @@ impl Snapshotable for Users {
             personal_access_tokens.insert(user_id, token_map);
         }
 
+        let mut roles_index: AHashMap<Arc<str>, RoleId> = AHashMap::new();
+        let mut role_entries: Vec<(usize, Role)> = Vec::new();
+        for (slab_key, role_snap) in snapshot.roles {
+            let name: Arc<str> = Arc::from(role_snap.name.as_str());
+            let role = Role {
+                id: role_snap.id,
+                name: name.clone(),
+                created_at: role_snap.created_at,
+                permissions: role_snap.permissions.map(Arc::new),
+            };
+
+            roles_index.insert(name, slab_key as RoleId);
+            role_entries.push((slab_key, role));
+        }
+        let roles: Slab<Role> = role_entries.into_iter().collect();
+
         let permissioner = Permissioner {
             users_permissions: snapshot
                 .permissioner
@@ -486,12 +722,27 @@ impl Snapshotable for Users {
                 .users_that_can_send_messages_to_specific_streams
                 .into_iter()
                 .collect(),
+            users_assigned_permissions: snapshot
+                .permissioner
+                .users_assigned_permissions
+                .into_iter()
+                .collect(),
+            roles_permissions: snapshot
+                .permissioner
+                .roles_permissions
+                .into_iter()
+                .collect(),
+            users_roles: snapshot.permissioner.users_roles.into_iter().collect(),
+            streams_names: snapshot.permissioner.streams_names.into_iter().collect(),
+            topics_names: snapshot.permissioner.topics_names.into_iter().collect(),
         };
 
         let inner = UsersInner {
             index,
             items,
             personal_access_tokens,
+            roles_index,
+            roles,
             permissioner,
             last_result: None,
         };
@@ -500,3 +751,101 @@ impl Snapshotable for Users {
 }
 
 impl_fill_restore!(Users, users);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_binary_protocol::WireName;
+    use iggy_common::wire_conversions::permissions_to_wire;
+    use std::collections::BTreeMap;
+
+    fn poll_stream(stream_id: usize) -> Permissions {
+        Permissions {
+            streams: Some(BTreeMap::from([(
+                stream_id,
+                StreamPermissions {
+                    poll_messages: true,
+                    ..StreamPermissions::default()
+                },
+            )])),
+            ..Permissions::default()
+        }
+    }
+
+    fn create_user(inner: &mut UsersInner, username: &str) {
+        let request = CreateUserRequest {
+            username: WireName::new(username).unwrap(),
+            password: "secret".to_string(),
+            status: 1,
+            permissions: None,
+        };
+        let _ = StateHandler::apply(&request, inner);
+    }
+
+    fn create_role(inner: &mut UsersInner, name: &str, permissions: &Permissions) {
+        let request = CreateRoleRequest {
+            name: WireName::new(name).unwrap(),
+            permissions: Some(permissions_to_wire(permissions)),
+        };
+        let _ = StateHandler::apply(&request, inner);
+    }
+
+    fn role_request(username: &str, role: &str) -> AssignRoleRequest {
+        AssignRoleRequest {
+            user_id: WireIdentifier::named(username).unwrap(),
+            role_id: WireIdentifier::named(role).unwrap(),
+        }
+    }
+
+    #[test]
+    fn role_commands_should_resolve_permissions_of_role_holders() {
+        let mut inner = UsersInner::new();
+        create_user(&mut inner, "alice");
+        create_role(&mut inner, "readers", &poll_stream(1));
+        assert!(inner.permissioner.poll_messages(0, 1, 1).is_err());
+
+        let _ = StateHandler::apply(&role_request("alice", "readers"), &mut inner);
+        assert!(inner.permissioner.poll_messages(0, 1, 1).is_ok());
+
+        let update = UpdateRoleRequest {
+            role_id: WireIdentifier::named("readers").unwrap(),
+            permissions: Some(permissions_to_wire(&poll_stream(2))),
+        };
+        let _ = StateHandler::apply(&update, &mut inner);
+        assert!(inner.permissioner.poll_messages(0, 1, 1).is_err());
+        assert!(inner.permissioner.poll_messages(0, 2, 1).is_ok());
+
+        let assign = role_request("alice", "readers");
+        let unassign = UnassignRoleRequest {
+            user_id: assign.user_id,
+            role_id: assign.role_id,
+        };
+        let _ = StateHandler::apply(&unassign, &mut inner);
+        assert!(inner.permissioner.poll_messages(0, 2, 1).is_err());
+
+        let _ = StateHandler::apply(&role_request("alice", "readers"), &mut inner);
+        let delete = DeleteRoleRequest {
+            role_id: WireIdentifier::named("readers").unwrap(),
+        };
+        let _ = StateHandler::apply(&delete, &mut inner);
+        assert!(inner.permissioner.poll_messages(0, 2, 1).is_err());
+        assert!(inner.roles.is_empty());
+        assert!(inner.roles_index.is_empty());
+    }
+
+    #[test]
+    fn roles_should_survive_the_snapshot_roundtrip() {
+        let mut inner = UsersInner::new();
+        create_user(&mut inner, "alice");
+        create_role(&mut inner, "readers", &poll_stream(1));
+        let _ = StateHandler::apply(&role_request("alice", "readers"), &mut inner);
+        let users: Users = inner.into();
+
+        let restored = Users::from_snapshot(users.to_snapshot()).unwrap();
+        restored.inner.read(|inner| {
+            assert_eq!(inner.roles_index.get("readers"), Some(&0));
+            assert_eq!(&*inner.roles[0].name, "readers");
+            assert!(inner.permissioner.poll_messages(0, 1, 1).is_ok());
+        });
+    }
+}
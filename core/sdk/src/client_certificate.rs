@@ -0,0 +1,110 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::IggyError;
+use rustls::client::WantsClientCert;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::{ClientConfig, ConfigBuilder};
+use tracing::error;
+
+/// Completes the TLS client configuration, presenting the client certificate
+/// to the server for mutual TLS when both the certificate and key are set.
+pub(crate) fn with_client_certificate(
+    builder: ConfigBuilder<ClientConfig, WantsClientCert>,
+    cert_file: Option<&str>,
+    key_file: Option<&str>,
+) -> Result<ClientConfig, IggyError> {
+    let (cert_file, key_file) = match (cert_file, key_file) {
+        (None, None) => return Ok(builder.with_no_client_auth()),
+        (Some(cert_file), Some(key_file)) => (cert_file, key_file),
+        _ => {
+            error!(
+                "Both the client certificate and the client key file must be set for mutual TLS."
+            );
+            return Err(IggyError::InvalidConfiguration);
+        }
+    };
+
+    let certificates = CertificateDer::pem_file_iter(cert_file)
+        .map_err(|error| {
+            error!("Failed to read the client certificate file: {cert_file}. {error}");
+            IggyError::InvalidTlsCertificatePath
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| {
+            error!("Failed to read the client certificate from: {cert_file}. {error}");
+            IggyError::InvalidTlsCertificate
+        })?;
+    let key = PrivateKeyDer::from_pem_file(key_file).map_err(|error| {
+        error!("Failed to read the client key file: {key_file}. {error}");
+        IggyError::InvalidTlsCertificatePath
+    })?;
+
+    builder
+        .with_client_auth_cert(certificates, key)
+        .map_err(|error| {
+            error!("Failed to use the client certificate for mutual TLS. {error}");
+            IggyError::InvalidTlsCertificate
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tcp::tcp_tls_verifier::NoServerVerification;
+    use std::sync::Arc;
+
+    const CERT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../certs/iggy_cert.pem");
+    const KEY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../certs/iggy_key.pem");
+
+    fn builder() -> ConfigBuilder<ClientConfig, WantsClientCert> {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        ClientConfig::builder()
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(NoServerVerification))
+    }
+
+    #[test]
+    fn should_not_present_certificate_when_none_is_configured() {
+        let config = with_client_certificate(builder(), None, None).unwrap();
+
+        assert!(!config.client_auth_cert_resolver.has_certs());
+    }
+
+    #[test]
+    fn should_present_configured_client_certificate() {
+        let config = with_client_certificate(builder(), Some(CERT_FILE), Some(KEY_FILE)).unwrap();
+
+        assert!(config.client_auth_cert_resolver.has_certs());
+    }
+
+    #[test]
+    fn should_fail_when_client_key_is_missing() {
+        let result = with_client_certificate(builder(), Some(CERT_FILE), None);
+
+        assert!(matches!(result, Err(IggyError::InvalidConfiguration)));
+    }
+
+    #[test]
+    fn should_fail_when_client_certificate_file_does_not_exist() {
+        let result = with_client_certificate(builder(), Some("missing.pem"), Some(KEY_FILE));
+
+        assert!(matches!(result, Err(IggyError::InvalidTlsCertificatePath)));
+    }
+}
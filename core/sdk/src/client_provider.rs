@@ -121,6 +121,8 @@ impl ClientProviderConfig {
                     keep_alive_interval: args.quic_keep_alive_interval,
                     max_idle_timeout: args.quic_max_idle_timeout,
                     validate_certificate: args.quic_validate_certificate,
+                    client_cert_file: None,
+                    client_key_file: None,
                 }));
             }
             TransportProtocol::Http => {
@@ -136,6 +138,8 @@ impl ClientProviderConfig {
                     tls_enabled: args.tcp_tls_enabled,
                     tls_domain: args.tcp_tls_domain,
                     tls_ca_file: args.tcp_tls_ca_file,
+                    tls_client_cert_file: None,
+                    tls_client_key_file: None,
                     tls_validate_certificate: true,
                     nodelay: args.tcp_nodelay,
                     heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
@@ -186,6 +190,8 @@ impl ClientProviderConfig {
                     tls_enabled: args.websocket_tls_enabled,
                     tls_domain: args.websocket_tls_domain,
                     tls_ca_file: args.websocket_tls_ca_file,
+                    tls_client_cert_file: None,
+                    tls_client_key_file: None,
                     tls_validate_certificate: args.websocket_tls_validate_certificate,
                 }));
             }
@@ -19,7 +19,10 @@
 use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_dropper::AsyncDrop;
 use async_trait::async_trait;
-use iggy_common::{ConsumerGroup, ConsumerGroupDetails, Identifier, IggyError};
+use iggy_common::{
+    ConsumerGroup, ConsumerGroupDetails, ConsumerGroupLag, DeadLetterPolicy, Identifier, IggyError,
+    SharedSubscription,
+};
 use iggy_common::{ConsumerGroupClient, UserClient};
 
 #[async_trait]
@@ -75,36 +78,73 @@ impl ConsumerGroupClient for ClientWrapper {
         }
     }
 
+    async fn get_consumer_group_lag(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<Option<ConsumerGroupLag>, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => {
+                client
+                    .get_consumer_group_lag(stream_id, topic_id, group_id)
+                    .await
+            }
+            ClientWrapper::Http(client) => {
+                client
+                    .get_consumer_group_lag(stream_id, topic_id, group_id)
+                    .await
+            }
+            ClientWrapper::Tcp(client) => {
+                client
+                    .get_consumer_group_lag(stream_id, topic_id, group_id)
+                    .await
+            }
+            ClientWrapper::Quic(client) => {
+                client
+                    .get_consumer_group_lag(stream_id, topic_id, group_id)
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .get_consumer_group_lag(stream_id, topic_id, group_id)
+                    .await
+            }
+        }
+    }
+
     async fn create_consumer_group(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         name: &str,
+        dead_letter: Option<DeadLetterPolicy>,
+        shared: Option<SharedSubscription>,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         match self {
             ClientWrapper::Iggy(client) => {
                 client
-                    .create_consumer_group(stream_id, topic_id, name)
+                    .create_consumer_group(stream_id, topic_id, name, dead_letter, shared)
                     .await
             }
             ClientWrapper::Http(client) => {
                 client
-                    .create_consumer_group(stream_id, topic_id, name)
+                    .create_consumer_group(stream_id, topic_id, name, dead_letter, shared)
                     .await
             }
             ClientWrapper::Tcp(client) => {
                 client
-                    .create_consumer_group(stream_id, topic_id, name)
+                    .create_consumer_group(stream_id, topic_id, name, dead_letter, shared)
                     .await
             }
             ClientWrapper::Quic(client) => {
                 client
-                    .create_consumer_group(stream_id, topic_id, name)
+                    .create_consumer_group(stream_id, topic_id, name, dead_letter, shared)
                     .await
             }
             ClientWrapper::WebSocket(client) => {
                 client
-                    .create_consumer_group(stream_id, topic_id, name)
+                    .create_consumer_group(stream_id, topic_id, name, dead_letter, shared)
                     .await
             }
         }
@@ -214,6 +254,81 @@ impl ConsumerGroupClient for ClientWrapper {
             }
         }
     }
+
+    async fn nack_message(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => {
+                client
+                    .nack_message(stream_id, topic_id, group_id, partition_id, offset, reason)
+                    .await
+            }
+            ClientWrapper::Http(client) => {
+                client
+                    .nack_message(stream_id, topic_id, group_id, partition_id, offset, reason)
+                    .await
+            }
+            ClientWrapper::Tcp(client) => {
+                client
+                    .nack_message(stream_id, topic_id, group_id, partition_id, offset, reason)
+                    .await
+            }
+            ClientWrapper::Quic(client) => {
+                client
+                    .nack_message(stream_id, topic_id, group_id, partition_id, offset, reason)
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .nack_message(stream_id, topic_id, group_id, partition_id, offset, reason)
+                    .await
+            }
+        }
+    }
+
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => {
+                client
+                    .ack_messages(stream_id, topic_id, group_id, partition_id, offsets)
+                    .await
+            }
+            ClientWrapper::Http(client) => {
+                client
+                    .ack_messages(stream_id, topic_id, group_id, partition_id, offsets)
+                    .await
+            }
+            ClientWrapper::Tcp(client) => {
+                client
+                    .ack_messages(stream_id, topic_id, group_id, partition_id, offsets)
+                    .await
+            }
+            ClientWrapper::Quic(client) => {
+                client
+                    .ack_messages(stream_id, topic_id, group_id, partition_id, offsets)
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .ack_messages(stream_id, topic_id, group_id, partition_id, offsets)
+                    .await
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -20,7 +20,8 @@ use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_common::MessageClient;
 use iggy_common::{
-    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PolledMessages, PollingStrategy,
+    Consumer, Identifier, IggyError, IggyMessage, MessageFilter, Partitioning, PolledMessages,
+    PollingStrategy,
 };
 
 #[async_trait]
@@ -104,6 +105,91 @@ impl MessageClient for ClientWrapper {
         }
     }
 
+    async fn poll_filtered_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: &MessageFilter,
+    ) -> Result<PolledMessages, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => {
+                client
+                    .poll_filtered_messages(
+                        stream_id,
+                        topic_id,
+                        partition_id,
+                        consumer,
+                        strategy,
+                        count,
+                        auto_commit,
+                        filter,
+                    )
+                    .await
+            }
+            ClientWrapper::Http(client) => {
+                client
+                    .poll_filtered_messages(
+                        stream_id,
+                        topic_id,
+                        partition_id,
+                        consumer,
+                        strategy,
+                        count,
+                        auto_commit,
+                        filter,
+                    )
+                    .await
+            }
+            ClientWrapper::Tcp(client) => {
+                client
+                    .poll_filtered_messages(
+                        stream_id,
+                        topic_id,
+                        partition_id,
+                        consumer,
+                        strategy,
+                        count,
+                        auto_commit,
+                        filter,
+                    )
+                    .await
+            }
+            ClientWrapper::Quic(client) => {
+                client
+                    .poll_filtered_messages(
+                        stream_id,
+                        topic_id,
+                        partition_id,
+                        consumer,
+                        strategy,
+                        count,
+                        auto_commit,
+                        filter,
+                    )
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .poll_filtered_messages(
+                        stream_id,
+                        topic_id,
+                        partition_id,
+                        consumer,
+                        strategy,
+                        count,
+                        auto_commit,
+                        filter,
+                    )
+                    .await
+            }
+        }
+    }
+
     async fn send_messages(
         &self,
         stream_id: &Identifier,
@@ -0,0 +1,111 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client_wrappers::client_wrapper::ClientWrapper;
+use async_trait::async_trait;
+use iggy_common::RoleClient;
+use iggy_common::{Identifier, IggyError, Permissions, RoleInfo, RoleInfoDetails};
+
+#[async_trait]
+impl RoleClient for ClientWrapper {
+    async fn get_role(&self, role_id: &Identifier) -> Result<Option<RoleInfoDetails>, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.get_role(role_id).await,
+            ClientWrapper::Http(client) => client.get_role(role_id).await,
+            ClientWrapper::Tcp(client) => client.get_role(role_id).await,
+            ClientWrapper::Quic(client) => client.get_role(role_id).await,
+            ClientWrapper::WebSocket(client) => client.get_role(role_id).await,
+        }
+    }
+
+    async fn get_roles(&self) -> Result<Vec<RoleInfo>, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.get_roles().await,
+            ClientWrapper::Http(client) => client.get_roles().await,
+            ClientWrapper::Tcp(client) => client.get_roles().await,
+            ClientWrapper::Quic(client) => client.get_roles().await,
+            ClientWrapper::WebSocket(client) => client.get_roles().await,
+        }
+    }
+
+    async fn create_role(
+        &self,
+        name: &str,
+        permissions: Option<Permissions>,
+    ) -> Result<RoleInfoDetails, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.create_role(name, permissions).await,
+            ClientWrapper::Http(client) => client.create_role(name, permissions).await,
+            ClientWrapper::Tcp(client) => client.create_role(name, permissions).await,
+            ClientWrapper::Quic(client) => client.create_role(name, permissions).await,
+            ClientWrapper::WebSocket(client) => client.create_role(name, permissions).await,
+        }
+    }
+
+    async fn delete_role(&self, role_id: &Identifier) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.delete_role(role_id).await,
+            ClientWrapper::Http(client) => client.delete_role(role_id).await,
+            ClientWrapper::Tcp(client) => client.delete_role(role_id).await,
+            ClientWrapper::Quic(client) => client.delete_role(role_id).await,
+            ClientWrapper::WebSocket(client) => client.delete_role(role_id).await,
+        }
+    }
+
+    async fn update_role(
+        &self,
+        role_id: &Identifier,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.update_role(role_id, permissions).await,
+            ClientWrapper::Http(client) => client.update_role(role_id, permissions).await,
+            ClientWrapper::Tcp(client) => client.update_role(role_id, permissions).await,
+            ClientWrapper::Quic(client) => client.update_role(role_id, permissions).await,
+            ClientWrapper::WebSocket(client) => client.update_role(role_id, permissions).await,
+        }
+    }
+
+    async fn assign_role(
+        &self,
+        user_id: &Identifier,
+        role_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.assign_role(user_id, role_id).await,
+            ClientWrapper::Http(client) => client.assign_role(user_id, role_id).await,
+            ClientWrapper::Tcp(client) => client.assign_role(user_id, role_id).await,
+            ClientWrapper::Quic(client) => client.assign_role(user_id, role_id).await,
+            ClientWrapper::WebSocket(client) => client.assign_role(user_id, role_id).await,
+        }
+    }
+
+    async fn unassign_role(
+        &self,
+        user_id: &Identifier,
+        role_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.unassign_role(user_id, role_id).await,
+            ClientWrapper::Http(client) => client.unassign_role(user_id, role_id).await,
+            ClientWrapper::Tcp(client) => client.unassign_role(user_id, role_id).await,
+            ClientWrapper::Quic(client) => client.unassign_role(user_id, role_id).await,
+            ClientWrapper::WebSocket(client) => client.unassign_role(user_id, role_id).await,
+        }
+    }
+}
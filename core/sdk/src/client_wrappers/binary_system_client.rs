@@ -20,8 +20,8 @@ use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_common::SystemClient;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    AuditLogQuery, AuditRecord, ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot,
+    SnapshotCompression, Stats, SystemSnapshotType,
 };
 
 #[async_trait]
@@ -99,4 +99,14 @@ impl SystemClient for ClientWrapper {
             ClientWrapper::WebSocket(client) => client.snapshot(compression, snapshot_types).await,
         }
     }
+
+    async fn get_audit_log(&self, query: &AuditLogQuery) -> Result<Vec<AuditRecord>, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.get_audit_log(query).await,
+            ClientWrapper::Http(client) => client.get_audit_log(query).await,
+            ClientWrapper::Tcp(client) => client.get_audit_log(query).await,
+            ClientWrapper::Quic(client) => client.get_audit_log(query).await,
+            ClientWrapper::WebSocket(client) => client.get_audit_log(query).await,
+        }
+    }
 }
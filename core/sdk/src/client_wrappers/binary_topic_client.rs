@@ -20,7 +20,8 @@ use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_common::TopicClient;
 use iggy_common::{
-    CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic, TopicDetails,
+    CleanupPolicy, CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic,
+    TopicDetails,
 };
 
 #[async_trait]
@@ -58,6 +59,7 @@ impl TopicClient for ClientWrapper {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<TopicDetails, IggyError> {
         match self {
             ClientWrapper::Iggy(client) => {
@@ -70,6 +72,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -83,6 +86,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -96,6 +100,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -109,6 +114,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -122,6 +128,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -137,6 +144,7 @@ impl TopicClient for ClientWrapper {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<(), IggyError> {
         match self {
             ClientWrapper::Iggy(client) => {
@@ -149,6 +157,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -162,6 +171,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -175,6 +185,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -188,6 +199,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -201,6 +213,7 @@ impl TopicClient for ClientWrapper {
                         replication_factor,
                         message_expiry,
                         max_topic_size,
+                        cleanup_policy,
                     )
                     .await
             }
@@ -234,4 +247,18 @@ impl TopicClient for ClientWrapper {
             ClientWrapper::WebSocket(client) => client.purge_topic(stream_id, topic_id).await,
         }
     }
+
+    async fn rotate_topic_key(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.rotate_topic_key(stream_id, topic_id).await,
+            ClientWrapper::Http(client) => client.rotate_topic_key(stream_id, topic_id).await,
+            ClientWrapper::Tcp(client) => client.rotate_topic_key(stream_id, topic_id).await,
+            ClientWrapper::Quic(client) => client.rotate_topic_key(stream_id, topic_id).await,
+            ClientWrapper::WebSocket(client) => client.rotate_topic_key(stream_id, topic_id).await,
+        }
+    }
 }
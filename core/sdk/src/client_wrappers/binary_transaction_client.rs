@@ -0,0 +1,61 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client_wrappers::client_wrapper::ClientWrapper;
+use async_trait::async_trait;
+use iggy_common::TransactionClient;
+use iggy_common::{IggyError, TransactionOffset};
+
+#[async_trait]
+impl TransactionClient for ClientWrapper {
+    async fn begin_transaction(&self) -> Result<u64, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.begin_transaction().await,
+            ClientWrapper::Http(client) => client.begin_transaction().await,
+            ClientWrapper::Tcp(client) => client.begin_transaction().await,
+            ClientWrapper::Quic(client) => client.begin_transaction().await,
+            ClientWrapper::WebSocket(client) => client.begin_transaction().await,
+        }
+    }
+
+    async fn commit_transaction(
+        &self,
+        transaction_id: u64,
+        offsets: &[TransactionOffset],
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.commit_transaction(transaction_id, offsets).await,
+            ClientWrapper::Http(client) => client.commit_transaction(transaction_id, offsets).await,
+            ClientWrapper::Tcp(client) => client.commit_transaction(transaction_id, offsets).await,
+            ClientWrapper::Quic(client) => client.commit_transaction(transaction_id, offsets).await,
+            ClientWrapper::WebSocket(client) => {
+                client.commit_transaction(transaction_id, offsets).await
+            }
+        }
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.abort_transaction(transaction_id).await,
+            ClientWrapper::Http(client) => client.abort_transaction(transaction_id).await,
+            ClientWrapper::Tcp(client) => client.abort_transaction(transaction_id).await,
+            ClientWrapper::Quic(client) => client.abort_transaction(transaction_id).await,
+            ClientWrapper::WebSocket(client) => client.abort_transaction(transaction_id).await,
+        }
+    }
+}
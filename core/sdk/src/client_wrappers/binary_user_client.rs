@@ -20,7 +20,8 @@ use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_common::UserClient;
 use iggy_common::{
-    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserStatus,
+    Identifier, IdentityInfo, IggyError, Permissions, Quota, QuotaScope, UserInfo, UserInfoDetails,
+    UserQuotas, UserStatus,
 };
 
 #[async_trait]
@@ -122,6 +123,31 @@ impl UserClient for ClientWrapper {
         }
     }
 
+    async fn set_user_quota(
+        &self,
+        user_id: &Identifier,
+        scope: QuotaScope,
+        quota: Option<Quota>,
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.set_user_quota(user_id, scope, quota).await,
+            ClientWrapper::Http(client) => client.set_user_quota(user_id, scope, quota).await,
+            ClientWrapper::Tcp(client) => client.set_user_quota(user_id, scope, quota).await,
+            ClientWrapper::Quic(client) => client.set_user_quota(user_id, scope, quota).await,
+            ClientWrapper::WebSocket(client) => client.set_user_quota(user_id, scope, quota).await,
+        }
+    }
+
+    async fn get_user_quotas(&self, user_id: &Identifier) -> Result<UserQuotas, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.get_user_quotas(user_id).await,
+            ClientWrapper::Http(client) => client.get_user_quotas(user_id).await,
+            ClientWrapper::Tcp(client) => client.get_user_quotas(user_id).await,
+            ClientWrapper::Quic(client) => client.get_user_quotas(user_id).await,
+            ClientWrapper::WebSocket(client) => client.get_user_quotas(user_id).await,
+        }
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -167,6 +193,30 @@ impl UserClient for ClientWrapper {
         }
     }
 
+    async fn login_user_scram(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<IdentityInfo, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.login_user_scram(username, password).await,
+            ClientWrapper::Http(client) => client.login_user_scram(username, password).await,
+            ClientWrapper::Tcp(client) => client.login_user_scram(username, password).await,
+            ClientWrapper::Quic(client) => client.login_user_scram(username, password).await,
+            ClientWrapper::WebSocket(client) => client.login_user_scram(username, password).await,
+        }
+    }
+
+    async fn login_with_token(&self, token: &str) -> Result<IdentityInfo, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.login_with_token(token).await,
+            ClientWrapper::Http(client) => client.login_with_token(token).await,
+            ClientWrapper::Tcp(client) => client.login_with_token(token).await,
+            ClientWrapper::Quic(client) => client.login_with_token(token).await,
+            ClientWrapper::WebSocket(client) => client.login_with_token(token).await,
+        }
+    }
+
     async fn logout_user(&self) -> Result<(), IggyError> {
         match self {
             ClientWrapper::Iggy(client) => client.logout_user().await,
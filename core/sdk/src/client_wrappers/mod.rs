@@ -23,10 +23,13 @@ mod binary_consumer_offset_client;
 mod binary_message_client;
 mod binary_partition_client;
 mod binary_personal_access_token_client;
+mod binary_producer_client;
+mod binary_role_client;
 mod binary_segment_client;
 mod binary_stream_client;
 mod binary_system_client;
 mod binary_topic_client;
+mod binary_transaction_client;
 mod binary_user_client;
 pub mod client_wrapper;
 pub mod connection_info;
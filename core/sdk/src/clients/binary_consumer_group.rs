@@ -20,7 +20,8 @@ use crate::prelude::IggyClient;
 use async_dropper::AsyncDrop;
 use async_trait::async_trait;
 use iggy_common::{
-    ConsumerGroup, ConsumerGroupDetails, Identifier, IggyError, locking::IggyRwLockFn,
+    ConsumerGroup, ConsumerGroupDetails, ConsumerGroupLag, DeadLetterPolicy, Identifier, IggyError,
+    SharedSubscription, locking::IggyRwLockFn,
 };
 use iggy_common::{ConsumerGroupClient, UserClient};
 
@@ -51,16 +52,31 @@ impl ConsumerGroupClient for IggyClient {
             .await
     }
 
+    async fn get_consumer_group_lag(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<Option<ConsumerGroupLag>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_consumer_group_lag(stream_id, topic_id, group_id)
+            .await
+    }
+
     async fn create_consumer_group(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         name: &str,
+        dead_letter: Option<DeadLetterPolicy>,
+        shared: Option<SharedSubscription>,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         self.client
             .read()
             .await
-            .create_consumer_group(stream_id, topic_id, name)
+            .create_consumer_group(stream_id, topic_id, name, dead_letter, shared)
             .await
     }
 
@@ -102,6 +118,37 @@ impl ConsumerGroupClient for IggyClient {
             .leave_consumer_group(stream_id, topic_id, group_id)
             .await
     }
+
+    async fn nack_message(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .nack_message(stream_id, topic_id, group_id, partition_id, offset, reason)
+            .await
+    }
+
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .ack_messages(stream_id, topic_id, group_id, partition_id, offsets)
+            .await
+    }
 }
 
 #[async_trait]
@@ -22,7 +22,8 @@ use bytes::Bytes;
 use iggy_common::MessageClient;
 use iggy_common::locking::IggyRwLockFn;
 use iggy_common::{
-    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PolledMessages, PollingStrategy,
+    Consumer, Identifier, IggyError, IggyMessage, MessageFilter, Partitioning, PolledMessages,
+    PollingStrategy,
 };
 
 #[async_trait]
@@ -41,7 +42,7 @@ impl MessageClient for IggyClient {
             return Err(IggyError::InvalidMessagesCount);
         }
 
-        let mut polled_messages = self
+        let polled_messages = self
             .client
             .read()
             .await
@@ -55,22 +56,40 @@ impl MessageClient for IggyClient {
                 auto_commit,
             )
             .await?;
+        self.decrypt_polled_messages(polled_messages)
+    }
 
-        if let Some(ref encryptor) = self.encryptor {
-            for message in &mut polled_messages.messages {
-                let payload = encryptor.decrypt(&message.payload)?;
-                message.payload = Bytes::from(payload);
-                message.header.payload_length = message.payload.len() as u32;
-
-                if let Some(ref user_headers) = message.user_headers {
-                    let decrypted_headers = encryptor.decrypt(user_headers)?;
-                    message.header.user_headers_length = decrypted_headers.len() as u32;
-                    message.user_headers = Some(Bytes::from(decrypted_headers));
-                }
-            }
+    async fn poll_filtered_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: &MessageFilter,
+    ) -> Result<PolledMessages, IggyError> {
+        if count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
         }
 
-        Ok(polled_messages)
+        let polled_messages = self
+            .client
+            .read()
+            .await
+            .poll_filtered_messages(
+                stream_id,
+                topic_id,
+                partition_id,
+                consumer,
+                strategy,
+                count,
+                auto_commit,
+                filter,
+            )
+            .await?;
+        self.decrypt_polled_messages(polled_messages)
     }
 
     async fn send_messages(
@@ -118,3 +137,26 @@ impl MessageClient for IggyClient {
             .await
     }
 }
+
+impl IggyClient {
+    fn decrypt_polled_messages(
+        &self,
+        mut polled_messages: PolledMessages,
+    ) -> Result<PolledMessages, IggyError> {
+        if let Some(ref encryptor) = self.encryptor {
+            for message in &mut polled_messages.messages {
+                let payload = encryptor.decrypt(&message.payload)?;
+                message.payload = Bytes::from(payload);
+                message.header.payload_length = message.payload.len() as u32;
+
+                if let Some(ref user_headers) = message.user_headers {
+                    let decrypted_headers = encryptor.decrypt(user_headers)?;
+                    message.header.user_headers_length = decrypted_headers.len() as u32;
+                    message.user_headers = Some(Bytes::from(decrypted_headers));
+                }
+            }
+        }
+
+        Ok(polled_messages)
+    }
+}
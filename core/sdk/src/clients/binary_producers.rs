@@ -0,0 +1,53 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::prelude::IggyClient;
+use async_trait::async_trait;
+use iggy_common::ProducerClient;
+use iggy_common::locking::IggyRwLockFn;
+use iggy_common::{Identifier, IggyError, IggyMessage, ProducerIdentity};
+
+#[async_trait]
+impl ProducerClient for IggyClient {
+    async fn init_producer(&self, producer_id: Option<u64>) -> Result<ProducerIdentity, IggyError> {
+        self.client.read().await.init_producer(producer_id).await
+    }
+
+    async fn send_idempotent_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        producer: &ProducerIdentity,
+        base_sequence: u64,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .send_idempotent_messages(
+                stream_id,
+                topic_id,
+                partition_id,
+                producer,
+                base_sequence,
+                messages,
+            )
+            .await
+    }
+}
@@ -21,8 +21,8 @@ use async_trait::async_trait;
 use iggy_common::SystemClient;
 use iggy_common::locking::IggyRwLockFn;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    AuditLogQuery, AuditRecord, ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot,
+    SnapshotCompression, Stats, SystemSnapshotType,
 };
 
 #[async_trait]
@@ -62,4 +62,8 @@ impl SystemClient for IggyClient {
             .snapshot(compression, snapshot_types)
             .await
     }
+
+    async fn get_audit_log(&self, query: &AuditLogQuery) -> Result<Vec<AuditRecord>, IggyError> {
+        self.client.read().await.get_audit_log(query).await
+    }
 }
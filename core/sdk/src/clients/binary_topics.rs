@@ -21,7 +21,8 @@ use async_trait::async_trait;
 use iggy_common::TopicClient;
 use iggy_common::locking::IggyRwLockFn;
 use iggy_common::{
-    CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic, TopicDetails,
+    CleanupPolicy, CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic,
+    TopicDetails,
 };
 
 #[async_trait]
@@ -51,6 +52,7 @@ impl TopicClient for IggyClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<TopicDetails, IggyError> {
         self.client
             .read()
@@ -63,6 +65,7 @@ impl TopicClient for IggyClient {
                 replication_factor,
                 message_expiry,
                 max_topic_size,
+                cleanup_policy,
             )
             .await
     }
@@ -76,6 +79,7 @@ impl TopicClient for IggyClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<(), IggyError> {
         self.client
             .read()
@@ -88,6 +92,7 @@ impl TopicClient for IggyClient {
                 replication_factor,
                 message_expiry,
                 max_topic_size,
+                cleanup_policy,
             )
             .await
     }
@@ -115,4 +120,16 @@ impl TopicClient for IggyClient {
             .purge_topic(stream_id, topic_id)
             .await
     }
+
+    async fn rotate_topic_key(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .rotate_topic_key(stream_id, topic_id)
+            .await
+    }
 }
@@ -0,0 +1,50 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::prelude::IggyClient;
+use async_trait::async_trait;
+use iggy_common::TransactionClient;
+use iggy_common::locking::IggyRwLockFn;
+use iggy_common::{IggyError, TransactionOffset};
+
+#[async_trait]
+impl TransactionClient for IggyClient {
+    async fn begin_transaction(&self) -> Result<u64, IggyError> {
+        self.client.read().await.begin_transaction().await
+    }
+
+    async fn commit_transaction(
+        &self,
+        transaction_id: u64,
+        offsets: &[TransactionOffset],
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .commit_transaction(transaction_id, offsets)
+            .await
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .abort_transaction(transaction_id)
+            .await
+    }
+}
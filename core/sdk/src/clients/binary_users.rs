@@ -22,7 +22,8 @@ use async_trait::async_trait;
 use iggy_common::locking::IggyRwLockFn;
 use iggy_common::{Client, UserClient};
 use iggy_common::{
-    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserStatus,
+    Identifier, IdentityInfo, IggyError, Permissions, Quota, QuotaScope, UserInfo, UserInfoDetails,
+    UserQuotas, UserStatus,
 };
 use tracing::info;
 
@@ -79,6 +80,23 @@ impl UserClient for IggyClient {
             .await
     }
 
+    async fn set_user_quota(
+        &self,
+        user_id: &Identifier,
+        scope: QuotaScope,
+        quota: Option<Quota>,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .set_user_quota(user_id, scope, quota)
+            .await
+    }
+
+    async fn get_user_quotas(&self, user_id: &Identifier) -> Result<UserQuotas, IggyError> {
+        self.client.read().await.get_user_quotas(user_id).await
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -121,6 +139,63 @@ impl UserClient for IggyClient {
         }
     }
 
+    async fn login_user_scram(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<IdentityInfo, IggyError> {
+        let identity = self
+            .client
+            .read()
+            .await
+            .login_user_scram(username, password)
+            .await?;
+
+        let should_redirect = {
+            let client = self.client.read().await;
+            match &*client {
+                ClientWrapper::Tcp(tcp_client) => tcp_client.handle_leader_redirection().await?,
+                ClientWrapper::Quic(quic_client) => quic_client.handle_leader_redirection().await?,
+                ClientWrapper::WebSocket(ws_client) => {
+                    ws_client.handle_leader_redirection().await?
+                }
+                _ => false,
+            }
+        };
+
+        if should_redirect {
+            info!("Redirected to leader, reconnecting and re-authenticating");
+            self.connect().await?;
+            self.login_user_scram(username, password).await
+        } else {
+            Ok(identity)
+        }
+    }
+
+    async fn login_with_token(&self, token: &str) -> Result<IdentityInfo, IggyError> {
+        let identity = self.client.read().await.login_with_token(token).await?;
+
+        let should_redirect = {
+            let client = self.client.read().await;
+            match &*client {
+                ClientWrapper::Tcp(tcp_client) => tcp_client.handle_leader_redirection().await?,
+                ClientWrapper::Quic(quic_client) => quic_client.handle_leader_redirection().await?,
+                ClientWrapper::WebSocket(ws_client) => {
+                    ws_client.handle_leader_redirection().await?
+                }
+                _ => false,
+            }
+        };
+
+        if should_redirect {
+            info!("Redirected to leader, reconnecting and re-authenticating");
+            self.connect().await?;
+            self.login_with_token(token).await
+        } else {
+            Ok(identity)
+        }
+    }
+
     async fn logout_user(&self) -> Result<(), IggyError> {
         self.client.read().await.logout_user().await
     }
@@ -20,8 +20,9 @@ use crate::client_wrappers::client_wrapper::ClientWrapper;
 use crate::clients::client::IggyClient;
 use crate::http::http_client::HttpClient;
 use crate::prelude::{
-    AutoLogin, EncryptorKind, HttpClientConfigBuilder, IggyDuration, IggyError, Partitioner,
-    QuicClientConfigBuilder, TcpClientConfigBuilder, WebSocketClientConfigBuilder,
+    AccessTokenProvider, AutoLogin, Credentials, EncryptorKind, HttpClientConfigBuilder,
+    IggyDuration, IggyError, Partitioner, QuicClientConfigBuilder, TcpClientConfigBuilder,
+    WebSocketClientConfigBuilder,
 };
 use crate::quic::quic_client::QuicClient;
 use crate::tcp::tcp_client::TcpClient;
@@ -166,6 +167,19 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Sets the OAuth2/OIDC access token provider used to sign in during every connection.
+    pub fn with_access_token_provider(
+        mut self,
+        provider: impl AccessTokenProvider + 'static,
+    ) -> Self {
+        self.config = self
+            .config
+            .with_auto_sign_in(AutoLogin::Enabled(Credentials::AccessToken(Arc::new(
+                provider,
+            ))));
+        self
+    }
+
     /// Sets the number of max retries when connecting to the server.
     pub fn with_reconnection_max_retries(mut self, reconnection_retries: Option<u32>) -> Self {
         self.config = self
@@ -206,6 +220,12 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Sets the client certificate and private key presented to the server for mutual TLS.
+    pub fn with_tls_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config = self.config.with_tls_client_certificate(cert_file, key_file);
+        self
+    }
+
     /// Sets whether to validate the TLS certificate.
     pub fn with_tls_validate_certificate(mut self, tls_validate_certificate: bool) -> Self {
         self.config = self
@@ -250,6 +270,19 @@ impl QuicClientBuilder {
         self
     }
 
+    /// Sets the OAuth2/OIDC access token provider used to sign in during every connection.
+    pub fn with_access_token_provider(
+        mut self,
+        provider: impl AccessTokenProvider + 'static,
+    ) -> Self {
+        self.config = self
+            .config
+            .with_auto_sign_in(AutoLogin::Enabled(Credentials::AccessToken(Arc::new(
+                provider,
+            ))));
+        self
+    }
+
     /// Sets the number of retries when connecting to the server.
     pub fn with_reconnection_max_retries(mut self, reconnection_retries: Option<u32>) -> Self {
         self.config = self
@@ -278,6 +311,12 @@ impl QuicClientBuilder {
         self
     }
 
+    /// Sets the client certificate and private key presented to the server for mutual TLS.
+    pub fn with_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config = self.config.with_client_certificate(cert_file, key_file);
+        self
+    }
+
     /// Builds the parent `IggyClient` with QUIC configuration.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let client = QuicClient::create(Arc::new(self.config.build()?))?;
@@ -343,6 +382,19 @@ impl WebSocketClientBuilder {
         self
     }
 
+    /// Sets the OAuth2/OIDC access token provider used to sign in during every connection.
+    pub fn with_access_token_provider(
+        mut self,
+        provider: impl AccessTokenProvider + 'static,
+    ) -> Self {
+        self.config = self
+            .config
+            .with_auto_sign_in(AutoLogin::Enabled(Credentials::AccessToken(Arc::new(
+                provider,
+            ))));
+        self
+    }
+
     /// Sets the number of retries when connecting to the server.
     pub fn with_reconnection_max_retries(mut self, reconnection_retries: Option<u32>) -> Self {
         self.config = self
@@ -383,6 +435,12 @@ impl WebSocketClientBuilder {
         self
     }
 
+    /// Sets the client certificate and private key presented to the server for mutual TLS.
+    pub fn with_tls_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config = self.config.with_tls_client_certificate(cert_file, key_file);
+        self
+    }
+
     /// Sets whether to validate the TLS certificate.
     pub fn with_tls_validate_certificate(mut self, tls_validate_certificate: bool) -> Self {
         self.config = self
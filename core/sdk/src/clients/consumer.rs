@@ -26,10 +26,11 @@ use iggy_common::{
     Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, StreamClient, TopicClient,
 };
 use iggy_common::{
-    Consumer, ConsumerKind, DiagnosticEvent, EncryptorKind, IdKind, Identifier, IggyDuration,
-    IggyError, IggyMessage, IggyTimestamp, PolledMessages, PollingKind, PollingStrategy,
+    Consumer, ConsumerKind, DeadLetterPolicy, DiagnosticEvent, EncryptorKind, IdKind, Identifier,
+    IggyDuration, IggyError, IggyMessage, IggyTimestamp, KeyProvider, MessageFilter,
+    PolledMessages, PollingKind, PollingStrategy, SharedSubscription,
 };
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -118,12 +119,17 @@ pub struct IggyConsumer {
     auto_commit_after_polling: bool,
     auto_join_consumer_group: bool,
     create_consumer_group_if_not_exists: bool,
+    dead_letter_policy: Option<DeadLetterPolicy>,
+    shared_subscription: Option<SharedSubscription>,
+    filter: Option<Arc<MessageFilter>>,
+    ack_consumed_messages: bool,
     last_stored_offsets: Arc<DashMap<u32, AtomicU64>>,
     last_consumed_offsets: Arc<DashMap<u32, AtomicU64>>,
     current_offsets: Arc<DashMap<u32, AtomicU64>>,
     poll_future: Option<PollMessagesFuture>,
     buffered_messages: VecDeque<IggyMessage>,
     encryptor: Option<Arc<EncryptorKind>>,
+    key_provider: Option<Arc<dyn KeyProvider>>,
     store_offset_sender: flume::Sender<(u32, u64)>,
     store_offset_after_each_message: bool,
     store_offset_after_all_messages: bool,
@@ -151,13 +157,27 @@ impl IggyConsumer {
         auto_commit: AutoCommit,
         auto_join_consumer_group: bool,
         create_consumer_group_if_not_exists: bool,
+        dead_letter_policy: Option<DeadLetterPolicy>,
+        shared_subscription: Option<SharedSubscription>,
+        filter: Option<MessageFilter>,
         encryptor: Option<Arc<EncryptorKind>>,
+        key_provider: Option<Arc<dyn KeyProvider>>,
         reconnection_retry_interval: IggyDuration,
         init_retries: Option<u32>,
         init_retry_interval: IggyDuration,
         allow_replay: bool,
     ) -> Self {
         let (store_offset_sender, _) = flume::unbounded();
+        // Shared subscriptions redeliver unacknowledged messages to any member, so the offsets
+        // may go backwards and the consumed messages are acknowledged instead of committed.
+        let is_shared = shared_subscription.is_some();
+        let ack_consumed_messages = is_shared
+            && !matches!(
+                auto_commit,
+                AutoCommit::Disabled
+                    | AutoCommit::When(AutoCommitWhen::PollingMessages)
+                    | AutoCommit::IntervalOrWhen(_, AutoCommitWhen::PollingMessages)
+            );
         Self {
             initialized: false,
             shutdown: Arc::new(AtomicBool::new(false)),
@@ -185,22 +205,35 @@ impl IggyConsumer {
             ),
             auto_join_consumer_group,
             create_consumer_group_if_not_exists,
+            dead_letter_policy,
+            shared_subscription,
+            filter: filter.map(Arc::new),
+            ack_consumed_messages,
             buffered_messages: VecDeque::new(),
             encryptor,
+            key_provider,
             store_offset_sender,
-            store_offset_after_each_message: matches!(
-                auto_commit,
-                AutoCommit::When(AutoCommitWhen::ConsumingEachMessage)
-                    | AutoCommit::IntervalOrWhen(_, AutoCommitWhen::ConsumingEachMessage)
-            ),
-            store_offset_after_all_messages: matches!(
-                auto_commit,
-                AutoCommit::When(AutoCommitWhen::ConsumingAllMessages)
-                    | AutoCommit::IntervalOrWhen(_, AutoCommitWhen::ConsumingAllMessages)
-            ),
+            store_offset_after_each_message: (ack_consumed_messages
+                && !matches!(
+                    auto_commit,
+                    AutoCommit::After(_) | AutoCommit::IntervalOrAfter(_, _)
+                ))
+                || matches!(
+                    auto_commit,
+                    AutoCommit::When(AutoCommitWhen::ConsumingEachMessage)
+                        | AutoCommit::IntervalOrWhen(_, AutoCommitWhen::ConsumingEachMessage)
+                ),
+            store_offset_after_all_messages: !is_shared
+                && matches!(
+                    auto_commit,
+                    AutoCommit::When(AutoCommitWhen::ConsumingAllMessages)
+                        | AutoCommit::IntervalOrWhen(_, AutoCommitWhen::ConsumingAllMessages)
+                ),
             store_after_every_nth_message: match auto_commit {
                 AutoCommit::When(AutoCommitWhen::ConsumingEveryNthMessage(n))
-                | AutoCommit::IntervalOrWhen(_, AutoCommitWhen::ConsumingEveryNthMessage(n)) => {
+                | AutoCommit::IntervalOrWhen(_, AutoCommitWhen::ConsumingEveryNthMessage(n))
+                    if !is_shared =>
+                {
                     n as u64
                 }
                 _ => 0,
@@ -210,7 +243,7 @@ impl IggyConsumer {
             reconnection_retry_interval,
             init_retries,
             init_retry_interval,
-            allow_replay,
+            allow_replay: allow_replay || is_shared,
         }
     }
 
@@ -218,6 +251,10 @@ impl IggyConsumer {
         self.auto_commit
     }
 
+    pub(crate) fn is_shared(&self) -> bool {
+        self.shared_subscription.is_some()
+    }
+
     /// Returns the name of the consumer.
     pub fn name(&self) -> &str {
         &self.consumer_name
@@ -262,6 +299,64 @@ impl IggyConsumer {
         .await
     }
 
+    /// Negatively acknowledges the message with the given offset either for the current partition or the provided partition ID,
+    /// so it counts as a failed delivery and gets redelivered or moved to the dead-letter topic of the consumer group.
+    pub async fn nack_message(
+        &self,
+        offset: u64,
+        partition_id: Option<u32>,
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        if !self.is_consumer_group {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = if let Some(partition_id) = partition_id {
+            partition_id
+        } else {
+            self.current_partition_id.load(ORDERING)
+        };
+        let client = self.client.read().await;
+        client
+            .nack_message(
+                &self.stream_id,
+                &self.topic_id,
+                &self.consumer.id,
+                partition_id,
+                offset,
+                reason,
+            )
+            .await
+    }
+
+    /// Acknowledges the messages with the given offsets either for the current partition or the provided partition ID.
+    /// Available only for the consumer groups with a shared subscription, the acknowledged messages are never redelivered.
+    pub async fn ack_messages(
+        &self,
+        offsets: &[u64],
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        if !self.is_consumer_group || self.shared_subscription.is_none() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = if let Some(partition_id) = partition_id {
+            partition_id
+        } else {
+            self.current_partition_id.load(ORDERING)
+        };
+        let client = self.client.read().await;
+        client
+            .ack_messages(
+                &self.stream_id,
+                &self.topic_id,
+                &self.consumer.id,
+                partition_id,
+                offsets,
+            )
+            .await
+    }
+
     /// Retrieves the last consumed offset for the specified partition ID.
     /// To get the current partition ID use `partition_id()`
     pub fn get_last_consumed_offset(&self, partition_id: u32) -> Option<u64> {
@@ -373,6 +468,7 @@ impl IggyConsumer {
         self.init_consumer_group().await?;
 
         match self.auto_commit {
+            _ if self.shared_subscription.is_some() => {}
             AutoCommit::Interval(interval) => self.store_offsets_in_background(interval),
             AutoCommit::IntervalOrWhen(interval, _) => self.store_offsets_in_background(interval),
             AutoCommit::IntervalOrAfter(interval, _) => self.store_offsets_in_background(interval),
@@ -387,6 +483,35 @@ impl IggyConsumer {
         let (store_offset_sender, store_offset_receiver) = flume::unbounded();
         self.store_offset_sender = store_offset_sender;
 
+        if self.ack_consumed_messages {
+            tokio::spawn(async move {
+                while let Ok((partition_id, offset)) = store_offset_receiver.recv_async().await {
+                    let mut offsets = BTreeMap::from([(partition_id, vec![offset])]);
+                    for (partition_id, offset) in store_offset_receiver.drain() {
+                        offsets.entry(partition_id).or_default().push(offset);
+                    }
+                    for (partition_id, offsets) in offsets {
+                        Self::ack_consumed_messages(
+                            &client,
+                            &consumer,
+                            &stream_id,
+                            &topic_id,
+                            partition_id,
+                            &offsets,
+                        )
+                        .await;
+                    }
+                }
+            });
+
+            self.initialized = true;
+            info!(
+                "Consumer: {consumer_name} has been initialized for stream: {}, topic: {}.",
+                self.stream_id, self.topic_id
+            );
+            return Ok(());
+        }
+
         tokio::spawn(async move {
             while let Ok((partition_id, offset)) = store_offset_receiver.recv_async().await {
                 trace!(
@@ -464,6 +589,29 @@ impl IggyConsumer {
         Ok(())
     }
 
+    async fn ack_consumed_messages(
+        client: &IggyRwLock<ClientWrapper>,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offsets: &[u64],
+    ) {
+        trace!(
+            "Acknowledging {} message(s) for consumer: {consumer}, partition ID: {partition_id}, topic: {topic_id}, stream: {stream_id}...",
+            offsets.len()
+        );
+        let client = client.read().await;
+        if let Err(error) = client
+            .ack_messages(stream_id, topic_id, &consumer.id, partition_id, offsets)
+            .await
+        {
+            error!(
+                "Failed to acknowledge messages with offsets: {offsets:?} for consumer: {consumer}, partition ID: {partition_id}, topic: {topic_id}, stream: {stream_id}. {error}"
+            );
+        }
+    }
+
     fn store_offsets_in_background(&self, interval: IggyDuration) {
         let client = self.client.clone();
         let consumer = self.consumer.clone();
@@ -525,6 +673,8 @@ impl IggyConsumer {
         Self::initialize_consumer_group(
             self.client.clone(),
             self.create_consumer_group_if_not_exists,
+            self.dead_letter_policy.clone(),
+            self.shared_subscription,
             self.stream_id.clone(),
             self.topic_id.clone(),
             self.consumer.clone(),
@@ -546,6 +696,8 @@ impl IggyConsumer {
         let can_join_consumer_group = is_consumer_group && self.auto_join_consumer_group;
         let client = self.client.clone();
         let create_consumer_group_if_not_exists = self.create_consumer_group_if_not_exists;
+        let dead_letter_policy = self.dead_letter_policy.clone();
+        let shared_subscription = self.shared_subscription;
         let stream_id = self.stream_id.clone();
         let topic_id = self.topic_id.clone();
         let consumer = self.consumer.clone();
@@ -612,6 +764,8 @@ impl IggyConsumer {
                         if let Err(error) = Self::initialize_consumer_group(
                             client.clone(),
                             create_consumer_group_if_not_exists,
+                            dead_letter_policy.clone(),
+                            shared_subscription,
                             stream_id.clone(),
                             topic_id.clone(),
                             consumer.clone(),
@@ -647,6 +801,7 @@ impl IggyConsumer {
         let partition_id = self.partition_id;
         let consumer = self.consumer.clone();
         let polling_strategy = self.polling_strategy;
+        let filter = self.filter.clone();
         let client = self.client.clone();
         let count = self.batch_length;
         let auto_commit_after_polling = self.auto_commit_after_polling;
@@ -679,19 +834,38 @@ impl IggyConsumer {
 
             trace!("Sending poll messages request");
             last_polled_at.store(IggyTimestamp::now().into(), ORDERING);
-            let polled_messages = client
-                .read()
-                .await
-                .poll_messages(
-                    &stream_id,
-                    &topic_id,
-                    partition_id,
-                    &consumer,
-                    &polling_strategy,
-                    count,
-                    auto_commit_after_polling,
-                )
-                .await;
+            let polled_messages = {
+                let client = client.read().await;
+                match filter.as_deref() {
+                    Some(filter) => {
+                        client
+                            .poll_filtered_messages(
+                                &stream_id,
+                                &topic_id,
+                                partition_id,
+                                &consumer,
+                                &polling_strategy,
+                                count,
+                                auto_commit_after_polling,
+                                filter,
+                            )
+                            .await
+                    }
+                    None => {
+                        client
+                            .poll_messages(
+                                &stream_id,
+                                &topic_id,
+                                partition_id,
+                                &consumer,
+                                &polling_strategy,
+                                count,
+                                auto_commit_after_polling,
+                            )
+                            .await
+                    }
+                }
+            };
 
             if let Ok(mut polled_messages) = polled_messages {
                 if polled_messages.messages.is_empty() {
@@ -828,9 +1002,12 @@ impl IggyConsumer {
         sleep(Duration::from_micros(remaining)).await;
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn initialize_consumer_group(
         client: IggyRwLock<ClientWrapper>,
         create_consumer_group_if_not_exists: bool,
+        dead_letter_policy: Option<DeadLetterPolicy>,
+        shared_subscription: Option<SharedSubscription>,
         stream_id: Arc<Identifier>,
         topic_id: Arc<Identifier>,
         consumer: Arc<Consumer>,
@@ -869,7 +1046,13 @@ impl IggyConsumer {
                 "Creating consumer group: {consumer_group_id} for topic: {topic_id}, stream: {stream_id}"
             );
             match client
-                .create_consumer_group(&stream_id, &topic_id, &name)
+                .create_consumer_group(
+                    &stream_id,
+                    &topic_id,
+                    &name,
+                    dead_letter_policy,
+                    shared_subscription,
+                )
                 .await
             {
                 Ok(_) => {}
@@ -947,7 +1130,8 @@ impl Stream for IggyConsumer {
 
             if self.buffered_messages.is_empty() {
                 if self.polling_strategy.kind != PollingKind::Next {
-                    self.polling_strategy = PollingStrategy::offset(message.header.offset + 1);
+                    self.polling_strategy = PollingStrategy::offset(message.header.offset + 1)
+                        .isolation_level(self.polling_strategy.isolation_level);
                 }
 
                 if self.store_offset_after_all_messages {
@@ -982,7 +1166,25 @@ impl Stream for IggyConsumer {
                     if polled_messages.messages.is_empty() {
                         self.poll_future = Some(Box::pin(self.create_poll_messages_future()));
                     } else {
-                        if let Some(ref encryptor) = self.encryptor {
+                        if let Some(ref key_provider) = self.key_provider {
+                            for message in &mut polled_messages.messages {
+                                let decrypted =
+                                    message.encryption_key_id().and_then(|key_id| match key_id {
+                                        Some(key_id) => {
+                                            message.decrypt(&*key_provider.encryptor(&key_id)?)
+                                        }
+                                        None => Ok(()),
+                                    });
+                                if let Err(error) = decrypted {
+                                    let offset = message.header.offset;
+                                    self.poll_future = None;
+                                    error!(
+                                        "Failed to decrypt the message at offset: {offset}, partition ID: {partition_id}",
+                                    );
+                                    return Poll::Ready(Some(Err(error)));
+                                }
+                            }
+                        } else if let Some(ref encryptor) = self.encryptor {
                             for message in &mut polled_messages.messages {
                                 let offset = message.header.offset;
                                 let payload = encryptor.decrypt(&message.payload);
@@ -1015,6 +1217,17 @@ impl Stream for IggyConsumer {
                             }
                         }
 
+                        for message in &mut polled_messages.messages {
+                            if let Err(error) = message.decompress() {
+                                let offset = message.header.offset;
+                                self.poll_future = None;
+                                error!(
+                                    "Failed to decompress the message payload at offset: {offset}, partition ID: {partition_id}",
+                                );
+                                return Poll::Ready(Some(Err(error)));
+                            }
+                        }
+
                         if let Some(current_offset_entry) = self.current_offsets.get(&partition_id)
                         {
                             current_offset_entry.store(polled_messages.current_offset, ORDERING);
@@ -1030,7 +1243,8 @@ impl Stream for IggyConsumer {
 
                         if self.polling_strategy.kind != PollingKind::Next {
                             self.polling_strategy =
-                                PollingStrategy::offset(message.header.offset + 1);
+                                PollingStrategy::offset(message.header.offset + 1)
+                                    .isolation_level(self.polling_strategy.isolation_level);
                         }
 
                         if let Some(last_consumed_offset_entry) =
@@ -1082,7 +1296,9 @@ impl IggyConsumer {
 
         info!("Shutting down consumer: {}...", self.consumer_name);
 
-        for entry in self.last_consumed_offsets.iter() {
+        // Shared subscriptions commit the offsets on the server as the messages get acknowledged.
+        let is_shared = self.shared_subscription.is_some();
+        for entry in self.last_consumed_offsets.iter().filter(|_| !is_shared) {
             let partition_id = *entry.key();
             let consumed_offset = entry.load(ORDERING);
 
@@ -19,7 +19,10 @@
 use crate::client_wrappers::client_wrapper::ClientWrapper;
 use crate::prelude::{AutoCommit, AutoCommitWhen, IggyConsumer};
 use iggy_common::locking::IggyRwLock;
-use iggy_common::{Consumer, EncryptorKind, Identifier, IggyDuration, PollingStrategy};
+use iggy_common::{
+    Consumer, DeadLetterPolicy, EncryptorKind, Identifier, IggyDuration, KeyProvider,
+    MessageFilter, PollingStrategy, SharedSubscription,
+};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -36,7 +39,11 @@ pub struct IggyConsumerBuilder {
     auto_commit: AutoCommit,
     auto_join_consumer_group: bool,
     create_consumer_group_if_not_exists: bool,
+    dead_letter_policy: Option<DeadLetterPolicy>,
+    shared_subscription: Option<SharedSubscription>,
+    filter: Option<MessageFilter>,
     encryptor: Option<Arc<EncryptorKind>>,
+    key_provider: Option<Arc<dyn KeyProvider>>,
     polling_retry_interval: IggyDuration,
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
@@ -70,7 +77,11 @@ impl IggyConsumerBuilder {
             ),
             auto_join_consumer_group: true,
             create_consumer_group_if_not_exists: true,
+            dead_letter_policy: None,
+            shared_subscription: None,
+            filter: None,
             encryptor,
+            key_provider: None,
             polling_interval,
             polling_retry_interval: IggyDuration::ONE_SECOND,
             init_retries: None,
@@ -157,6 +168,35 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the dead-letter policy used when the consumer group is automatically created.
+    pub fn dead_letter_policy(self, dead_letter_policy: DeadLetterPolicy) -> Self {
+        Self {
+            dead_letter_policy: Some(dead_letter_policy),
+            ..self
+        }
+    }
+
+    /// Sets the shared subscription used when the consumer group is automatically created.
+    ///
+    /// The consumer acknowledges the messages individually instead of storing the offsets, so the consumer group must be shared.
+    /// Unless the auto commit is disabled, each consumed message is acknowledged, otherwise use `ack_messages()` and `nack_message()`.
+    pub fn shared_subscription(self, shared_subscription: SharedSubscription) -> Self {
+        Self {
+            shared_subscription: Some(shared_subscription),
+            ..self
+        }
+    }
+
+    /// Sets the filter evaluated by the server against the messages' user headers, only the matching messages are polled.
+    ///
+    /// The filter is not supported for shared subscriptions and can't match the headers encrypted by the client.
+    pub fn filter(self, filter: MessageFilter) -> Self {
+        Self {
+            filter: Some(filter),
+            ..self
+        }
+    }
+
     /// Sets the polling interval for messages.
     pub fn poll_interval(self, interval: IggyDuration) -> Self {
         Self {
@@ -189,6 +229,23 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the key provider for decrypting the messages with the keys identified in their user headers,
+    /// taking precedence over the encryptor. The messages without the key ID are left untouched.
+    pub fn key_provider(self, key_provider: Arc<dyn KeyProvider>) -> Self {
+        Self {
+            key_provider: Some(key_provider),
+            ..self
+        }
+    }
+
+    /// Clears the key provider for decrypting the messages.
+    pub fn without_key_provider(self) -> Self {
+        Self {
+            key_provider: None,
+            ..self
+        }
+    }
+
     /// Sets the polling retry interval in case of server disconnection.
     pub fn polling_retry_interval(self, interval: IggyDuration) -> Self {
         Self {
@@ -233,7 +290,11 @@ impl IggyConsumerBuilder {
             self.auto_commit,
             self.auto_join_consumer_group,
             self.create_consumer_group_if_not_exists,
+            self.dead_letter_policy,
+            self.shared_subscription,
+            self.filter,
             self.encryptor,
+            self.key_provider,
             self.polling_retry_interval,
             self.init_retries,
             self.init_retry_interval,
@@ -22,10 +22,13 @@ mod binary_consumer_offset;
 mod binary_message;
 mod binary_partitions;
 mod binary_personal_access_tokens;
+mod binary_producers;
+mod binary_roles;
 mod binary_segments;
 mod binary_streams;
 mod binary_system;
 mod binary_topics;
+mod binary_transactions;
 mod binary_users;
 pub mod client;
 pub mod client_builder;
@@ -36,6 +39,7 @@ pub mod producer_builder;
 pub mod producer_config;
 pub mod producer_dispatcher;
 pub mod producer_error_callback;
+mod producer_idempotence;
 pub mod producer_sharding;
 
 const ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::SeqCst;
@@ -21,17 +21,21 @@ use crate::clients::MAX_BATCH_LENGTH;
 use crate::clients::producer_builder::SendMode;
 use crate::clients::producer_config::DirectConfig;
 use crate::clients::producer_dispatcher::ProducerDispatcher;
+use crate::clients::producer_idempotence::IdempotentProducer;
 use bytes::Bytes;
 use futures_util::StreamExt;
 use iggy_common::locking::{IggyRwLock, IggyRwLockFn};
-use iggy_common::{Client, MessageClient, StreamClient, TopicClient};
 use iggy_common::{
-    CompressionAlgorithm, DiagnosticEvent, EncryptorKind, IdKind, Identifier, IggyDuration,
-    IggyError, IggyExpiry, IggyMessage, IggyTimestamp, MaxTopicSize, Partitioner, Partitioning,
+    CleanupPolicy, CompressionAlgorithm, DiagnosticEvent, EncryptorKind, IdKind, Identifier,
+    IggyDuration, IggyError, IggyExpiry, IggyMessage, IggyTimestamp, KeyProvider, MaxTopicSize,
+    Partitioner, Partitioning, ProducerIdentity, TransactionOffset,
+};
+use iggy_common::{
+    Client, MessageClient, ProducerClient, StreamClient, TopicClient, TransactionClient,
 };
-use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 use tokio::time::{Interval, sleep};
 use tracing::{error, info, trace, warn};
@@ -60,6 +64,9 @@ pub struct ProducerCore {
     topic_name: String,
     partitioning: Option<Arc<Partitioning>>,
     encryptor: Option<Arc<EncryptorKind>>,
+    key_provider: Option<Arc<dyn KeyProvider>>,
+    compression: OnceLock<CompressionAlgorithm>,
+    delivery_delay: Option<IggyDuration>,
     partitioner: Option<Arc<dyn Partitioner>>,
     create_stream_if_not_exists: bool,
     create_topic_if_not_exists: bool,
@@ -72,6 +79,7 @@ pub struct ProducerCore {
     send_retries_count: Option<u32>,
     send_retries_interval: Option<IggyDuration>,
     direct_config: Option<DirectConfig>,
+    idempotence: Option<IdempotentProducer>,
 }
 
 impl ProducerCore {
@@ -103,7 +111,9 @@ impl ProducerCore {
             client.create_stream(&name).await?;
         }
 
-        if client.get_topic(&stream_id, &topic_id).await?.is_none() {
+        if let Some(topic) = client.get_topic(&stream_id, &topic_id).await? {
+            let _ = self.compression.set(topic.compression_algorithm);
+        } else {
             if !self.create_topic_if_not_exists {
                 error!("Topic does not exist and auto-creation is disabled.");
                 return Err(IggyError::TopicNameNotFound(
@@ -120,17 +130,27 @@ impl ProducerCore {
                 IdKind::String => (self.topic_id.get_string_value()?, None),
             };
             info!("Creating topic: {name} for stream: {}", self.stream_name);
-            client
+            let topic = client
                 .create_topic(
                     &self.stream_id,
                     &self.topic_name,
                     self.topic_partitions_count,
-                    CompressionAlgorithm::None,
+                    self.compression.get().copied().unwrap_or_default(),
                     self.topic_replication_factor,
                     self.topic_message_expiry,
                     self.topic_max_size,
+                    CleanupPolicy::Delete,
                 )
                 .await?;
+            let _ = self.compression.set(topic.compression_algorithm);
+        }
+
+        if let Some(compression) = self.compression.get() {
+            info!("Producer will compress messages using algorithm: {compression}");
+        }
+
+        if let Some(idempotence) = &self.idempotence {
+            idempotence.identity(&client).await?;
         }
 
         let _ = self
@@ -185,22 +205,60 @@ impl ProducerCore {
         messages: &mut [IggyMessage],
     ) -> Result<(), IggyError> {
         let client = self.client.read().await;
-
-        let Some(max_retries) = self.send_retries_count else {
-            return client
-                .send_messages(stream, topic, partitioning, messages)
+        let Some(idempotence) = &self.idempotence else {
+            return self
+                .send_with_retries(&client, stream, topic, partitioning, None, messages)
                 .await;
         };
 
-        if max_retries == 0 {
+        let partition_id = idempotence
+            .resolve_partition_id(&client, stream, topic, partitioning)
+            .await?;
+        let sequence = idempotence.partition_sequence(stream, topic, partition_id);
+        let mut sequence = sequence.lock().await;
+        let producer = idempotence.identity(&client).await?;
+        let base_sequence = sequence.base_sequence(&producer);
+        let result = self
+            .send_with_retries(
+                &client,
+                stream,
+                topic,
+                partitioning,
+                Some((partition_id, &producer, base_sequence)),
+                messages,
+            )
+            .await;
+        match &result {
+            Ok(()) => sequence.advance(messages.len() as u64),
+            Err(error) => idempotence.reset(&producer, error),
+        }
+        result
+    }
+
+    /// Sends the messages once, as an idempotent producer if the partition, producer identity and base sequence are provided.
+    async fn send_once(
+        client: &ClientWrapper,
+        stream: &Identifier,
+        topic: &Identifier,
+        partitioning: &Partitioning,
+        idempotent: Option<(u32, &ProducerIdentity, u64)>,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError> {
+        let Some((partition_id, producer, base_sequence)) = idempotent else {
             return client
                 .send_messages(stream, topic, partitioning, messages)
                 .await;
-        }
+        };
 
-        self.wait_until_connected(max_retries, stream, topic)
-            .await?;
-        self.send_with_retries(&client, max_retries, stream, topic, partitioning, messages)
+        client
+            .send_idempotent_messages(
+                stream,
+                topic,
+                partition_id,
+                producer,
+                base_sequence,
+                messages,
+            )
             .await
     }
 
@@ -244,20 +302,28 @@ impl ProducerCore {
     async fn send_with_retries(
         &self,
         client: &ClientWrapper,
-        max_retries: u32,
         stream: &Identifier,
         topic: &Identifier,
         partitioning: &Arc<Partitioning>,
+        idempotent: Option<(u32, &ProducerIdentity, u64)>,
         messages: &mut [IggyMessage],
     ) -> Result<(), IggyError> {
+        let max_retries = match self.send_retries_count {
+            Some(max_retries) if max_retries > 0 => max_retries,
+            _ => {
+                return Self::send_once(client, stream, topic, partitioning, idempotent, messages)
+                    .await;
+            }
+        };
+
+        self.wait_until_connected(max_retries, stream, topic)
+            .await?;
+
         let mut retries = 0;
         let mut timer: Option<Interval> = None;
 
         loop {
-            match client
-                .send_messages(stream, topic, partitioning, messages)
-                .await
-            {
+            match Self::send_once(client, stream, topic, partitioning, idempotent, messages).await {
                 Ok(_) => return Ok(()),
                 Err(error) => {
                     retries += 1;
@@ -288,8 +354,41 @@ impl ProducerCore {
         }
     }
 
-    fn encrypt_messages(&self, messages: &mut [IggyMessage]) -> Result<(), IggyError> {
-        if let Some(encryptor) = &self.encryptor {
+    fn delay_messages(&self, messages: &mut [IggyMessage]) -> Result<(), IggyError> {
+        if let Some(delay) = self.delivery_delay {
+            let deliver_at =
+                IggyTimestamp::from(IggyTimestamp::now().as_micros() + delay.as_micros());
+            for message in messages {
+                if message.deliver_at()?.is_none() {
+                    message.set_deliver_at(deliver_at)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn compress_messages(&self, messages: &mut [IggyMessage]) -> Result<(), IggyError> {
+        if let Some(&compression) = self.compression.get() {
+            for message in messages {
+                message.compress(compression)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn encrypt_messages(
+        &self,
+        stream: &Identifier,
+        topic: &Identifier,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError> {
+        if let Some(key_provider) = &self.key_provider {
+            let key_id = key_provider.current_key_id(stream, topic)?;
+            let encryptor = key_provider.encryptor(&key_id)?;
+            for message in messages {
+                message.encrypt(&key_id, &encryptor)?;
+            }
+        } else if let Some(encryptor) = &self.encryptor {
             for message in messages {
                 message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
                 message.header.payload_length = message.payload.len() as u32;
@@ -366,7 +465,15 @@ impl ProducerCoreBackend for ProducerCore {
             return Ok(());
         }
 
-        if let Err(err) = self.encrypt_messages(&mut msgs) {
+        if let Err(err) = self.delay_messages(&mut msgs) {
+            return Err(self.make_failed_error(err, msgs));
+        }
+
+        if let Err(err) = self.compress_messages(&mut msgs) {
+            return Err(self.make_failed_error(err, msgs));
+        }
+
+        if let Err(err) = self.encrypt_messages(stream, topic, &mut msgs) {
             return Err(self.make_failed_error(err, msgs));
         }
 
@@ -424,6 +531,7 @@ unsafe impl Sync for IggyProducer {}
 pub struct IggyProducer {
     core: Arc<ProducerCore>,
     dispatcher: Option<ProducerDispatcher>,
+    transaction_id: AtomicU64,
 }
 
 impl IggyProducer {
@@ -436,6 +544,9 @@ impl IggyProducer {
         topic_name: String,
         partitioning: Option<Partitioning>,
         encryptor: Option<Arc<EncryptorKind>>,
+        key_provider: Option<Arc<dyn KeyProvider>>,
+        compression: Option<CompressionAlgorithm>,
+        delivery_delay: Option<IggyDuration>,
         partitioner: Option<Arc<dyn Partitioner>>,
         create_stream_if_not_exists: bool,
         create_topic_if_not_exists: bool,
@@ -446,6 +557,7 @@ impl IggyProducer {
         send_retries_count: Option<u32>,
         send_retries_interval: Option<IggyDuration>,
         mode: SendMode,
+        idempotence: bool,
     ) -> Self {
         let core = Arc::new(ProducerCore {
             initialized: AtomicBool::new(false),
@@ -457,6 +569,9 @@ impl IggyProducer {
             topic_name,
             partitioning: partitioning.map(Arc::new),
             encryptor,
+            key_provider,
+            compression: compression.map(OnceLock::from).unwrap_or_default(),
+            delivery_delay,
             partitioner,
             create_stream_if_not_exists,
             create_topic_if_not_exists,
@@ -472,13 +587,18 @@ impl IggyProducer {
                 SendMode::Direct(ref cfg) => Some(cfg.clone()),
                 _ => None,
             },
+            idempotence: idempotence.then(IdempotentProducer::default),
         });
         let dispatcher = match mode {
             SendMode::Background(cfg) => Some(ProducerDispatcher::new(core.clone(), cfg)),
             _ => None,
         };
 
-        Self { core, dispatcher }
+        Self {
+            core,
+            dispatcher,
+            transaction_id: AtomicU64::new(0),
+        }
     }
 
     pub fn stream(&self) -> &Identifier {
@@ -567,6 +687,68 @@ impl IggyProducer {
         }
     }
 
+    /// Returns the identity of the idempotent producer, if the idempotence is enabled and the producer was initialized.
+    pub fn producer_identity(&self) -> Option<ProducerIdentity> {
+        self.core
+            .idempotence
+            .as_ref()
+            .and_then(IdempotentProducer::current_identity)
+    }
+
+    /// Begins a new transaction, all the messages sent until it's committed or aborted become part of it.
+    ///
+    /// Transactions are available only in the direct send mode, as the background mode buffers the messages.
+    pub async fn begin_transaction(&self) -> Result<u64, IggyError> {
+        if self.dispatcher.is_some() {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let current = self.transaction_id.load(ORDERING);
+        if current != 0 {
+            return Err(IggyError::TransactionAlreadyInProgress(current));
+        }
+
+        let transaction_id = self.core.client.read().await.begin_transaction().await?;
+        self.transaction_id.store(transaction_id, ORDERING);
+        trace!("Began transaction with ID: {transaction_id}");
+        Ok(transaction_id)
+    }
+
+    /// Commits the current transaction and atomically stores the provided consumer offsets.
+    pub async fn commit_transaction(&self, offsets: &[TransactionOffset]) -> Result<(), IggyError> {
+        let transaction_id = self.current_transaction_id()?;
+        self.core
+            .client
+            .read()
+            .await
+            .commit_transaction(transaction_id, offsets)
+            .await?;
+        self.transaction_id.store(0, ORDERING);
+        trace!("Committed transaction with ID: {transaction_id}");
+        Ok(())
+    }
+
+    /// Aborts the current transaction, its messages will never be visible to the `read_committed` consumers.
+    pub async fn abort_transaction(&self) -> Result<(), IggyError> {
+        let transaction_id = self.current_transaction_id()?;
+        self.core
+            .client
+            .read()
+            .await
+            .abort_transaction(transaction_id)
+            .await?;
+        self.transaction_id.store(0, ORDERING);
+        trace!("Aborted transaction with ID: {transaction_id}");
+        Ok(())
+    }
+
+    fn current_transaction_id(&self) -> Result<u64, IggyError> {
+        match self.transaction_id.load(ORDERING) {
+            0 => Err(IggyError::TransactionNotFound(0)),
+            transaction_id => Ok(transaction_id),
+        }
+    }
+
     pub async fn shutdown(self) {
         if let Some(dispatcher) = self.dispatcher {
             dispatcher.shutdown().await;
@@ -20,7 +20,8 @@ use crate::clients::producer_config::{BackgroundConfig, DirectConfig};
 use crate::prelude::IggyProducer;
 use iggy_common::locking::IggyRwLock;
 use iggy_common::{
-    EncryptorKind, Identifier, IggyDuration, IggyExpiry, MaxTopicSize, Partitioner, Partitioning,
+    CompressionAlgorithm, EncryptorKind, Identifier, IggyDuration, IggyExpiry, KeyProvider,
+    MaxTopicSize, Partitioner, Partitioning,
 };
 use std::sync::Arc;
 
@@ -42,6 +43,9 @@ pub struct IggyProducerBuilder {
     topic: Identifier,
     topic_name: String,
     encryptor: Option<Arc<EncryptorKind>>,
+    key_provider: Option<Arc<dyn KeyProvider>>,
+    compression: Option<CompressionAlgorithm>,
+    delivery_delay: Option<IggyDuration>,
     partitioner: Option<Arc<dyn Partitioner>>,
     create_stream_if_not_exists: bool,
     create_topic_if_not_exists: bool,
@@ -53,6 +57,7 @@ pub struct IggyProducerBuilder {
     topic_max_size: MaxTopicSize,
     partitioning: Option<Partitioning>,
     mode: SendMode,
+    idempotence: bool,
 }
 
 impl IggyProducerBuilder {
@@ -74,6 +79,9 @@ impl IggyProducerBuilder {
             topic_name,
             partitioning: None,
             encryptor,
+            key_provider: None,
+            compression: None,
+            delivery_delay: None,
             partitioner,
             create_stream_if_not_exists: true,
             create_topic_if_not_exists: true,
@@ -84,6 +92,7 @@ impl IggyProducerBuilder {
             send_retries_count: Some(3),
             send_retries_interval: Some(IggyDuration::ONE_SECOND),
             mode: SendMode::default(),
+            idempotence: false,
         }
     }
 
@@ -113,6 +122,45 @@ impl IggyProducerBuilder {
         }
     }
 
+    /// Sets the key provider for encrypting the messages with the current key of the topic,
+    /// taking precedence over the encryptor. The key ID is sent in the messages' user headers,
+    /// which are encrypted along with the payloads.
+    pub fn key_provider(self, key_provider: Arc<dyn KeyProvider>) -> Self {
+        Self {
+            key_provider: Some(key_provider),
+            ..self
+        }
+    }
+
+    /// Clears the key provider for encrypting the messages.
+    pub fn without_key_provider(self) -> Self {
+        Self {
+            key_provider: None,
+            ..self
+        }
+    }
+
+    /// Sets the compression algorithm for the messages' payloads, overriding the one configured for the topic.
+    /// When not set, the topic's compression algorithm is used, which is also the algorithm applied
+    /// when the topic is created by the producer. Consumers decompress the payloads automatically.
+    pub fn compression(self, compression: CompressionAlgorithm) -> Self {
+        Self {
+            compression: Some(compression),
+            ..self
+        }
+    }
+
+    /// Delays the delivery of the sent messages, so they become visible to the consumers only after the given duration.
+    /// The messages which already have the delivery time set are left intact. It has no effect along with the encryptor,
+    /// as the delivery time is kept in the user headers, which must be readable by the server. The key provider
+    /// leaves the delivery time unencrypted, so both can be used together.
+    pub fn delivery_delay(self, delay: IggyDuration) -> Self {
+        Self {
+            delivery_delay: Some(delay),
+            ..self
+        }
+    }
+
     /// Sets the partitioning strategy for messages.
     pub fn partitioning(self, partitioning: Partitioning) -> Self {
         Self {
@@ -198,6 +246,25 @@ impl IggyProducerBuilder {
         }
     }
 
+    /// Enables the idempotent producer, which is assigned an ID and epoch by the server,
+    /// and tracks the sequence numbers of the messages per partition, so the retried batches
+    /// are never appended twice, regardless of the message IDs.
+    /// The partition is resolved on the client side, based on the partitioning strategy.
+    pub fn enable_idempotence(self) -> Self {
+        Self {
+            idempotence: true,
+            ..self
+        }
+    }
+
+    /// Disables the idempotent producer (default).
+    pub fn disable_idempotence(self) -> Self {
+        Self {
+            idempotence: false,
+            ..self
+        }
+    }
+
     /// Sets the producer to use direct message sending.
     /// This mode ensures that messages are sent immediately to the server
     /// without being buffered or delayed.
@@ -222,6 +289,9 @@ impl IggyProducerBuilder {
             self.topic_name,
             self.partitioning,
             self.encryptor,
+            self.key_provider,
+            self.compression,
+            self.delivery_delay,
             self.partitioner,
             self.create_stream_if_not_exists,
             self.create_topic_if_not_exists,
@@ -232,6 +302,7 @@ impl IggyProducerBuilder {
             self.send_retries_count,
             self.send_retries_interval,
             self.mode,
+            self.idempotence,
         )
     }
 }
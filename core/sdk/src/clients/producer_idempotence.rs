@@ -0,0 +1,184 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client_wrappers::client_wrapper::ClientWrapper;
+use iggy_common::{
+    Identifier, IggyError, Partitioning, PartitioningKind, ProducerClient, ProducerIdentity,
+    TopicClient, calculate_32,
+};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+/// The next sequence number of the idempotent producer for a single partition.
+#[derive(Debug, Default)]
+pub(crate) struct PartitionSequence {
+    epoch: u64,
+    next: u64,
+}
+
+impl PartitionSequence {
+    /// Returns the sequence number of the first message in the next batch,
+    /// which starts from zero whenever the producer epoch changes.
+    pub(crate) fn base_sequence(&mut self, producer: &ProducerIdentity) -> u64 {
+        if self.epoch != producer.epoch {
+            self.epoch = producer.epoch;
+            self.next = 0;
+        }
+        self.next
+    }
+
+    pub(crate) fn advance(&mut self, count: u64) {
+        self.next += count;
+    }
+}
+
+type PartitionKey = (Identifier, Identifier, u32);
+
+/// State of the idempotent producer, shared by the direct and the background send modes.
+///
+/// Sequence numbers are tracked per partition, so the partition is resolved on the client side.
+/// Sending to a partition holds its sequence lock until the batch is acknowledged (including the retries),
+/// which keeps the batches in order and makes the retries reuse the same sequence numbers.
+#[derive(Debug, Default)]
+pub(crate) struct IdempotentProducer {
+    identity: Mutex<Option<ProducerIdentity>>,
+    producer_id: AtomicU64,
+    sequences: Mutex<HashMap<PartitionKey, Arc<tokio::sync::Mutex<PartitionSequence>>>>,
+    partitions_counts: Mutex<HashMap<(Identifier, Identifier), u32>>,
+    balanced_counter: AtomicU64,
+}
+
+impl IdempotentProducer {
+    /// Returns the producer identity, initializing the producer on the server if needed.
+    /// Once the producer was reset, it's resumed with the same ID and a new epoch.
+    pub(crate) async fn identity(
+        &self,
+        client: &ClientWrapper,
+    ) -> Result<ProducerIdentity, IggyError> {
+        if let Some(identity) = *self.identity.lock().unwrap() {
+            return Ok(identity);
+        }
+
+        let producer_id = match self.producer_id.load(Ordering::SeqCst) {
+            0 => None,
+            producer_id => Some(producer_id),
+        };
+        let identity = client.init_producer(producer_id).await?;
+        self.producer_id.store(identity.id, Ordering::SeqCst);
+        let mut current = self.identity.lock().unwrap();
+        match *current {
+            Some(current) if current.epoch > identity.epoch => Ok(current),
+            _ => {
+                info!("Initialized idempotent producer: {identity}");
+                *current = Some(identity);
+                Ok(identity)
+            }
+        }
+    }
+
+    pub(crate) fn current_identity(&self) -> Option<ProducerIdentity> {
+        *self.identity.lock().unwrap()
+    }
+
+    /// Drops the current epoch after a batch could not be delivered, as its sequence numbers
+    /// might have been appended or not. The next batch bumps the epoch and starts from zero.
+    /// A fenced producer is never resumed, as it has been replaced by its newer instance.
+    pub(crate) fn reset(&self, producer: &ProducerIdentity, error: &IggyError) {
+        if matches!(error, IggyError::ProducerFenced(..)) {
+            warn!("Idempotent producer: {producer} has been fenced.");
+            return;
+        }
+
+        let mut current = self.identity.lock().unwrap();
+        if current.is_some_and(|current| current.epoch == producer.epoch) {
+            warn!("Resetting the epoch of idempotent producer: {producer} after error: {error}");
+            *current = None;
+        }
+    }
+
+    pub(crate) fn partition_sequence(
+        &self,
+        stream: &Identifier,
+        topic: &Identifier,
+        partition_id: u32,
+    ) -> Arc<tokio::sync::Mutex<PartitionSequence>> {
+        self.sequences
+            .lock()
+            .unwrap()
+            .entry((stream.clone(), topic.clone(), partition_id))
+            .or_default()
+            .clone()
+    }
+
+    /// Resolves the partition the same way the server does for the given partitioning.
+    pub(crate) async fn resolve_partition_id(
+        &self,
+        client: &ClientWrapper,
+        stream: &Identifier,
+        topic: &Identifier,
+        partitioning: &Partitioning,
+    ) -> Result<u32, IggyError> {
+        match partitioning.kind {
+            PartitioningKind::PartitionId => Ok(u32::from_le_bytes(
+                partitioning
+                    .value
+                    .get(..4)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            )),
+            PartitioningKind::Balanced => {
+                let partitions_count = self.partitions_count(client, stream, topic).await?;
+                let counter = self.balanced_counter.fetch_add(1, Ordering::Relaxed);
+                Ok((counter % partitions_count as u64) as u32)
+            }
+            PartitioningKind::MessagesKey => {
+                let partitions_count = self.partitions_count(client, stream, topic).await?;
+                Ok(calculate_32(&partitioning.value) % partitions_count)
+            }
+        }
+    }
+
+    async fn partitions_count(
+        &self,
+        client: &ClientWrapper,
+        stream: &Identifier,
+        topic: &Identifier,
+    ) -> Result<u32, IggyError> {
+        let key = (stream.clone(), topic.clone());
+        if let Some(partitions_count) = self.partitions_counts.lock().unwrap().get(&key) {
+            return Ok(*partitions_count);
+        }
+
+        let topic_details = client
+            .get_topic(stream, topic)
+            .await?
+            .ok_or_else(|| IggyError::TopicIdNotFound(topic.clone(), stream.clone()))?;
+        if topic_details.partitions_count == 0 {
+            return Err(IggyError::InvalidIdempotentPartitioning);
+        }
+
+        self.partitions_counts
+            .lock()
+            .unwrap()
+            .insert(key, topic_details.partitions_count);
+        Ok(topic_details.partitions_count)
+    }
+}
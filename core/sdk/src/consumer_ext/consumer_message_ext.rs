@@ -54,19 +54,27 @@ impl<'a> IggyConsumerMessageExt<'a> for IggyConsumer {
         P: MessageConsumer + Sync,
     {
         let auto_commit = self.auto_commit();
+        // The shared subscription acknowledges each consumed message instead of storing the offset.
+        let is_shared = self.is_shared();
         let store_offset_after_each_message = matches!(
             auto_commit,
             AutoCommit::After(AutoCommitAfter::ConsumingEachMessage)
                 | AutoCommit::IntervalOrAfter(_, AutoCommitAfter::ConsumingEachMessage)
-        );
+        ) || (is_shared
+            && matches!(
+                auto_commit,
+                AutoCommit::After(_) | AutoCommit::IntervalOrAfter(_, _)
+            ));
 
-        let store_offset_after_all_messages = matches!(
-            auto_commit,
-            AutoCommit::After(AutoCommitAfter::ConsumingAllMessages)
-                | AutoCommit::IntervalOrAfter(_, AutoCommitAfter::ConsumingAllMessages)
-        );
+        let store_offset_after_all_messages = !is_shared
+            && matches!(
+                auto_commit,
+                AutoCommit::After(AutoCommitAfter::ConsumingAllMessages)
+                    | AutoCommit::IntervalOrAfter(_, AutoCommitAfter::ConsumingAllMessages)
+            );
 
         let store_after_every_nth_message = match auto_commit {
+            _ if is_shared => 0,
             AutoCommit::After(AutoCommitAfter::ConsumingEveryNthMessage(n))
             | AutoCommit::IntervalOrAfter(_, AutoCommitAfter::ConsumingEveryNthMessage(n)) => {
                 n as u64
@@ -23,7 +23,9 @@ use async_trait::async_trait;
 use iggy_common::ConsumerGroupClient;
 use iggy_common::Identifier;
 use iggy_common::create_consumer_group::CreateConsumerGroup;
-use iggy_common::{ConsumerGroup, ConsumerGroupDetails};
+use iggy_common::{
+    ConsumerGroup, ConsumerGroupDetails, ConsumerGroupLag, DeadLetterPolicy, SharedSubscription,
+};
 
 #[async_trait]
 impl ConsumerGroupClient for HttpClient {
@@ -70,11 +72,41 @@ impl ConsumerGroupClient for HttpClient {
         Ok(consumer_groups)
     }
 
+    async fn get_consumer_group_lag(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<Option<ConsumerGroupLag>, IggyError> {
+        let response = self
+            .get(&format!(
+                "{}/{}/lag",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                group_id
+            ))
+            .await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let lag = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(lag))
+    }
+
     async fn create_consumer_group(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         name: &str,
+        dead_letter: Option<DeadLetterPolicy>,
+        shared: Option<SharedSubscription>,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         let response = self
             .post(
@@ -83,6 +115,8 @@ impl ConsumerGroupClient for HttpClient {
                     stream_id: stream_id.clone(),
                     topic_id: topic_id.clone(),
                     name: name.to_string(),
+                    dead_letter,
+                    shared,
                 },
             )
             .await?;
@@ -125,6 +159,29 @@ impl ConsumerGroupClient for HttpClient {
     ) -> Result<(), IggyError> {
         Err(IggyError::FeatureUnavailable)
     }
+
+    async fn nack_message(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+        _: u32,
+        _: u64,
+        _: &str,
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn ack_messages(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+        _: u32,
+        _: &[u64],
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
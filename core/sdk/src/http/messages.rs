@@ -19,8 +19,8 @@
 use crate::http::http_client::HttpClient;
 use crate::http::http_transport::HttpTransport;
 use crate::prelude::{
-    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PollMessages, PolledMessages,
-    PollingStrategy, SendMessages,
+    Consumer, Identifier, IggyError, IggyMessage, MessageFilter, Partitioning, PollMessages,
+    PolledMessages, PollingStrategy, SendMessages,
 };
 use async_trait::async_trait;
 use iggy_common::IggyMessagesBatch;
@@ -39,25 +39,41 @@ impl MessageClient for HttpClient {
         count: u32,
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError> {
-        let response = self
-            .get_with_query(
-                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
-                &PollMessages {
-                    stream_id: stream_id.clone(),
-                    topic_id: topic_id.clone(),
-                    partition_id,
-                    consumer: consumer.clone(),
-                    strategy: *strategy,
-                    count,
-                    auto_commit,
-                },
-            )
-            .await?;
-        let messages = response
-            .json()
-            .await
-            .map_err(|_| IggyError::InvalidJsonResponse)?;
-        Ok(messages)
+        self.poll(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            None,
+        )
+        .await
+    }
+
+    async fn poll_filtered_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: &MessageFilter,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            Some(filter.clone()),
+        )
+        .await
     }
 
     async fn send_messages(
@@ -107,6 +123,42 @@ impl MessageClient for HttpClient {
     }
 }
 
+impl HttpClient {
+    #[allow(clippy::too_many_arguments)]
+    async fn poll(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: Option<MessageFilter>,
+    ) -> Result<PolledMessages, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &PollMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    consumer: consumer.clone(),
+                    strategy: *strategy,
+                    count,
+                    auto_commit,
+                    filter,
+                },
+            )
+            .await?;
+        let messages = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(messages)
+    }
+}
+
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/messages")
 }
@@ -24,8 +24,11 @@ mod http_transport;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod producers;
+pub mod roles;
 pub mod segments;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
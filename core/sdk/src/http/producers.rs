@@ -0,0 +1,44 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::http_client::HttpClient;
+use async_trait::async_trait;
+use iggy_common::ProducerClient;
+use iggy_common::{Identifier, IggyError, IggyMessage, ProducerIdentity};
+
+#[async_trait]
+impl ProducerClient for HttpClient {
+    async fn init_producer(
+        &self,
+        _producer_id: Option<u64>,
+    ) -> Result<ProducerIdentity, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn send_idempotent_messages(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        _partition_id: u32,
+        _producer: &ProducerIdentity,
+        _base_sequence: u64,
+        _messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+}
@@ -0,0 +1,132 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::http_client::HttpClient;
+use crate::http::http_transport::HttpTransport;
+use crate::prelude::{Identifier, IggyError};
+use async_trait::async_trait;
+use iggy_common::RoleClient;
+use iggy_common::create_role::CreateRole;
+use iggy_common::update_role::UpdateRole;
+use iggy_common::{Permissions, RoleInfo, RoleInfoDetails};
+
+const PATH: &str = "/roles";
+const USERS_PATH: &str = "/users";
+
+#[async_trait]
+impl RoleClient for HttpClient {
+    async fn get_role(&self, role_id: &Identifier) -> Result<Option<RoleInfoDetails>, IggyError> {
+        let response = self.get(&format!("{PATH}/{role_id}")).await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let role = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(role))
+    }
+
+    async fn get_roles(&self) -> Result<Vec<RoleInfo>, IggyError> {
+        let response = self.get(PATH).await?;
+        let roles = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(roles)
+    }
+
+    async fn create_role(
+        &self,
+        name: &str,
+        permissions: Option<Permissions>,
+    ) -> Result<RoleInfoDetails, IggyError> {
+        let response = self
+            .post(
+                PATH,
+                &CreateRole {
+                    name: name.to_string(),
+                    permissions,
+                },
+            )
+            .await?;
+        let role = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(role)
+    }
+
+    async fn delete_role(&self, role_id: &Identifier) -> Result<(), IggyError> {
+        self.delete(&format!("{PATH}/{}", &role_id.as_cow_str()))
+            .await?;
+        Ok(())
+    }
+
+    async fn update_role(
+        &self,
+        role_id: &Identifier,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("{PATH}/{}", &role_id.as_cow_str()),
+            &UpdateRole {
+                role_id: role_id.clone(),
+                permissions,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn assign_role(
+        &self,
+        user_id: &Identifier,
+        role_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{USERS_PATH}/{}{PATH}/{}",
+                &user_id.as_cow_str(),
+                &role_id.as_cow_str()
+            ),
+            &(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn unassign_role(
+        &self,
+        user_id: &Identifier,
+        role_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.delete(&format!(
+            "{USERS_PATH}/{}{PATH}/{}",
+            &user_id.as_cow_str(),
+            &role_id.as_cow_str()
+        ))
+        .await?;
+        Ok(())
+    }
+}
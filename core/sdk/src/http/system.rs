@@ -24,6 +24,7 @@ use iggy_common::Snapshot;
 use iggy_common::Stats;
 use iggy_common::SystemClient;
 use iggy_common::get_snapshot::GetSnapshot;
+use iggy_common::{AuditLogQuery, AuditRecord};
 use iggy_common::{ClientInfo, ClientInfoDetails};
 use iggy_common::{SnapshotCompression, SystemSnapshotType};
 
@@ -31,6 +32,7 @@ const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
 const SNAPSHOT: &str = "/snapshot";
+const AUDIT: &str = "/audit";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -103,4 +105,13 @@ impl SystemClient for HttpClient {
         let snapshot = Snapshot::new(file.to_vec());
         Ok(snapshot)
     }
+
+    async fn get_audit_log(&self, query: &AuditLogQuery) -> Result<Vec<AuditRecord>, IggyError> {
+        let response = self.get_with_query(AUDIT, query).await?;
+        let records = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(records)
+    }
 }
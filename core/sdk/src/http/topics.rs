@@ -18,7 +18,9 @@
 
 use crate::http::http_client::HttpClient;
 use crate::http::http_transport::HttpTransport;
-use crate::prelude::{CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize};
+use crate::prelude::{
+    CleanupPolicy, CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize,
+};
 use async_trait::async_trait;
 use iggy_common::TopicClient;
 use iggy_common::create_topic::CreateTopic;
@@ -71,6 +73,7 @@ impl TopicClient for HttpClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<TopicDetails, IggyError> {
         let response = self
             .post(
@@ -83,6 +86,7 @@ impl TopicClient for HttpClient {
                     replication_factor,
                     message_expiry,
                     max_topic_size,
+                    cleanup_policy,
                 },
             )
             .await?;
@@ -102,6 +106,7 @@ impl TopicClient for HttpClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<(), IggyError> {
         self.put(
             &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
@@ -113,6 +118,7 @@ impl TopicClient for HttpClient {
                 replication_factor,
                 message_expiry,
                 max_topic_size,
+                cleanup_policy,
             },
         )
         .await?;
@@ -144,6 +150,22 @@ impl TopicClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn rotate_topic_key(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.post(
+            &format!(
+                "{}/rotate-key",
+                &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &(),
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str) -> String {
@@ -26,7 +26,9 @@ use iggy_common::create_user::CreateUser;
 use iggy_common::login_user::LoginUser;
 use iggy_common::update_permissions::UpdatePermissions;
 use iggy_common::update_user::UpdateUser;
-use iggy_common::{IdentityInfo, Permissions, UserInfo, UserInfoDetails, UserStatus};
+use iggy_common::{
+    IdentityInfo, Permissions, Quota, QuotaScope, UserInfo, UserInfoDetails, UserQuotas, UserStatus,
+};
 use secrecy::SecretString;
 
 const PATH: &str = "/users";
@@ -124,6 +126,20 @@ impl UserClient for HttpClient {
         Ok(())
     }
 
+    async fn set_user_quota(
+        &self,
+        _: &Identifier,
+        _: QuotaScope,
+        _: Option<Quota>,
+    ) -> Result<(), IggyError> {
+        // Quotas are enforced by the binary transports only.
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn get_user_quotas(&self, _: &Identifier) -> Result<UserQuotas, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -162,6 +178,15 @@ impl UserClient for HttpClient {
         Ok(identity_info)
     }
 
+    async fn login_user_scram(&self, _: &str, _: &str) -> Result<IdentityInfo, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn login_with_token(&self, _: &str) -> Result<IdentityInfo, IggyError> {
+        // The HTTP API accepts the trusted issuer tokens directly, see `HttpClientBuilder::with_jwt`.
+        Err(IggyError::FeatureUnavailable)
+    }
+
     async fn logout_user(&self) -> Result<(), IggyError> {
         self.delete(&format!("{PATH}/logout")).await?;
         self.set_access_token(None).await;
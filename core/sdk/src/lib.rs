@@ -17,6 +17,7 @@
  */
 
 pub mod binary;
+mod client_certificate;
 pub mod client_provider;
 pub mod client_wrappers;
 pub mod clients;
@@ -28,4 +29,5 @@ pub mod quic;
 pub mod session;
 pub mod stream_builder;
 pub mod tcp;
+mod throttling;
 pub mod websocket;
@@ -49,25 +49,35 @@ pub use crate::stream_builder::{IggyStream, IggyStreamConfig};
 pub use crate::tcp::tcp_client::TcpClient;
 pub use crate::websocket::websocket_client::WebSocketClient;
 pub use iggy_common::{
-    Aes256GcmEncryptor, Args, ArgsOptional, AutoLogin, CacheMetrics, CacheMetricsKey, ClientError,
-    ClientInfoDetails, ClusterMetadata, ClusterNode, ClusterNodeRole, ClusterNodeStatus,
-    CompressionAlgorithm, Consumer, ConsumerGroupDetails, ConsumerKind, EncryptorKind,
+    AccessTokenProvider, Aes256GcmEncryptor, Args, ArgsOptional, AuditLogQuery, AuditOutcome,
+    AuditRecord, AutoLogin, COMPRESSION_HEADER_KEY, CacheMetrics, CacheMetricsKey, CleanupPolicy,
+    ClientError, ClientInfoDetails, ClusterMetadata, ClusterNode, ClusterNodeRole,
+    ClusterNodeStatus, CompressionAlgorithm, Consumer, ConsumerGroupDetails, ConsumerGroupLag,
+    ConsumerGroupPermissions, ConsumerKind, Credentials, DEAD_LETTER_CONSUMER_GROUP_HEADER_KEY,
+    DEAD_LETTER_DELIVERY_COUNT_HEADER_KEY, DEAD_LETTER_ORIGIN_OFFSET_HEADER_KEY,
+    DEAD_LETTER_ORIGIN_PARTITION_HEADER_KEY, DEAD_LETTER_ORIGIN_STREAM_HEADER_KEY,
+    DEAD_LETTER_ORIGIN_TOPIC_HEADER_KEY, DEAD_LETTER_REASON_HEADER_KEY, DELIVER_AT_HEADER_KEY,
+    DeadLetterPolicy, ENCRYPTION_KEY_ID_HEADER_KEY, EncryptorKind, FileKeyProvider, FilterOperator,
     GlobalPermissions, HeaderKey, HeaderKind, HeaderValue, HttpClientConfig,
     HttpClientConfigBuilder, IdKind, Identifier, IdentityInfo, IggyByteSize, IggyDuration,
     IggyError, IggyExpiry, IggyIndexView, IggyMessage, IggyMessageHeader, IggyMessageHeaderView,
-    IggyMessageView, IggyMessageViewIterator, IggyTimestamp, MaxTopicSize, Partition, Partitioner,
-    Partitioning, Permissions, PersonalAccessTokenExpiry, PollMessages, PolledMessages,
-    PollingKind, PollingStrategy, QuicClientConfig, QuicClientConfigBuilder,
-    QuicClientReconnectionConfig, SendMessages, Sizeable, SnapshotCompression, Stats, Stream,
-    StreamDetails, StreamPermissions, SystemSnapshotType, TcpClientConfig, TcpClientConfigBuilder,
-    TcpClientReconnectionConfig, Topic, TopicDetails, TopicPermissions, TransportEndpoints,
-    TransportProtocol, UserId, UserStatus, Validatable, WebSocketClientConfig,
-    WebSocketClientConfigBuilder, WebSocketClientReconnectionConfig, defaults, locking,
+    IggyMessageView, IggyMessageViewIterator, IggyTimestamp, IsolationLevel, KeyProvider,
+    MESSAGE_KEY_HEADER_KEY, MaxTopicSize, MessageFilter, Partition, Partitioner, Partitioning,
+    Permissions, PersonalAccessTokenExpiry, PollMessages, PolledMessages, PollingKind,
+    PollingStrategy, ProducerIdentity, QuicClientConfig, QuicClientConfigBuilder,
+    QuicClientReconnectionConfig, Quota, QuotaScope, RoleId, RoleInfo, RoleInfoDetails,
+    SendMessages, SharedSubscription, Sizeable, SnapshotCompression, Stats, Stream, StreamDetails,
+    StreamPatternPermissions, StreamPermissions, SystemSnapshotType, TOMBSTONE_HEADER_KEY,
+    TRANSACTION_ID_HEADER_KEY, TcpClientConfig, TcpClientConfigBuilder,
+    TcpClientReconnectionConfig, Topic, TopicDetails, TopicPatternPermissions, TopicPermissions,
+    TransactionOffset, TransportEndpoints, TransportProtocol, UserId, UserQuotas, UserStatus,
+    Validatable, WebSocketClientConfig, WebSocketClientConfigBuilder,
+    WebSocketClientReconnectionConfig, defaults, locking,
 };
 pub use iggy_common::{
     Client, ClusterClient, ConsumerGroupClient, ConsumerOffsetClient, MessageClient,
-    PartitionClient, PersonalAccessTokenClient, SegmentClient, StreamClient, SystemClient,
-    TopicClient, UserClient,
+    PartitionClient, PersonalAccessTokenClient, ProducerClient, RoleClient, SegmentClient,
+    StreamClient, SystemClient, TopicClient, TransactionClient, UserClient,
 };
 pub use iggy_common::{
     IGGY_MESSAGE_CHECKSUM_OFFSET_RANGE, IGGY_MESSAGE_HEADER_SIZE,
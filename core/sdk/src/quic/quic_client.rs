@@ -16,8 +16,10 @@
  * under the License.
  */
 
+use crate::client_certificate::with_client_certificate;
 use crate::leader_aware::{LeaderRedirectionState, check_and_redirect_to_leader};
 use crate::prelude::AutoLogin;
+use crate::throttling::send_with_throttle_backoff;
 use iggy_common::{BinaryClient, BinaryTransport, Client, PersonalAccessTokenClient, UserClient};
 
 use crate::prelude::{IggyDuration, IggyError, IggyTimestamp, QuicClientConfig};
@@ -32,6 +34,7 @@ use iggy_common::{
 use quinn::crypto::rustls::QuicClientConfig as QuinnQuicClientConfig;
 use quinn::{ClientConfig, Connection, Endpoint, IdleTimeout, RecvStream, VarInt};
 use rustls::crypto::CryptoProvider;
+use rustls_platform_verifier::BuilderVerifierExt;
 use secrecy::ExposeSecret;
 use std::net::{SocketAddr, ToSocketAddrs};
 use std::str::FromStr;
@@ -103,7 +106,7 @@ impl BinaryTransport for QuicClient {
     }
 
     async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
-        let result = self.send_raw(code, payload.clone()).await;
+        let result = send_with_throttle_backoff(|| self.send_raw(code, payload.clone())).await;
         if result.is_ok() {
             return result;
         }
@@ -246,7 +249,12 @@ impl QuicClient {
                 IggyError::from_code_as_string(status)
             );
 
-            return Err(IggyError::from_code(status));
+            return Err(IggyError::from_response(
+                status,
+                buffer
+                    .get(RESPONSE_INITIAL_BYTES_LENGTH..)
+                    .unwrap_or_default(),
+            ));
         }
 
         let length = u32::from_le_bytes(
@@ -397,6 +405,15 @@ impl QuicClient {
                                 self.config.client_address
                             );
                         }
+                        Credentials::UsernamePasswordScram(username, password) => {
+                            self.login_user_scram(username, password.expose_secret())
+                                .await?;
+                            self.publish_event(DiagnosticEvent::SignedIn).await;
+                            info!(
+                                "{NAME} client: {} has signed in with the SCRAM user credentials, username: {username}",
+                                self.config.client_address
+                            );
+                        }
                         Credentials::PersonalAccessToken(token) => {
                             self.login_with_personal_access_token(token.expose_secret())
                                 .await?;
@@ -406,6 +423,15 @@ impl QuicClient {
                                 self.config.client_address
                             );
                         }
+                        Credentials::AccessToken(provider) => {
+                            let token = provider.access_token().await?;
+                            self.login_with_token(token.expose_secret()).await?;
+                            self.publish_event(DiagnosticEvent::SignedIn).await;
+                            info!(
+                                "{NAME} client: {} has signed in with an access token.",
+                                self.config.client_address
+                            );
+                        }
                     }
 
                     self.handle_leader_redirection().await?
@@ -607,24 +633,27 @@ fn configure(config: &QuicClientConfig) -> Result<ClientConfig, IggyError> {
             e
         );
     }
-    let mut client_config = match config.validate_certificate {
-        true => ClientConfig::try_with_platform_verifier().map_err(|error| {
+    let builder = match config.validate_certificate {
+        true => rustls::ClientConfig::builder()
+            .with_platform_verifier()
+            .map_err(|error| {
+                error!("Failed to create QUIC client configuration: {error}");
+                IggyError::InvalidConfiguration
+            })?,
+        false => rustls::ClientConfig::builder()
+            .dangerous()
+            .with_custom_certificate_verifier(SkipServerVerification::new()),
+    };
+    let tls_config = with_client_certificate(
+        builder,
+        config.client_cert_file.as_deref(),
+        config.client_key_file.as_deref(),
+    )?;
+    let mut client_config = match QuinnQuicClientConfig::try_from(tls_config) {
+        Ok(config) => ClientConfig::new(Arc::new(config)),
+        Err(error) => {
             error!("Failed to create QUIC client configuration: {error}");
-            IggyError::InvalidConfiguration
-        })?,
-        false => {
-            match QuinnQuicClientConfig::try_from(
-                rustls::ClientConfig::builder()
-                    .dangerous()
-                    .with_custom_certificate_verifier(SkipServerVerification::new())
-                    .with_no_client_auth(),
-            ) {
-                Ok(config) => ClientConfig::new(Arc::new(config)),
-                Err(error) => {
-                    error!("Failed to create QUIC client configuration: {error}");
-                    return Err(IggyError::InvalidConfiguration);
-                }
-            }
+            return Err(IggyError::InvalidConfiguration);
         }
     };
     client_config.transport_config(Arc::new(transport));
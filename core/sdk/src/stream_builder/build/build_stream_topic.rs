@@ -17,8 +17,8 @@
  */
 
 use crate::prelude::{
-    CompressionAlgorithm, IdKind, Identifier, IggyClient, IggyError, IggyExpiry, MaxTopicSize,
-    StreamClient, TopicClient,
+    CleanupPolicy, CompressionAlgorithm, IdKind, Identifier, IggyClient, IggyError, IggyExpiry,
+    MaxTopicSize, StreamClient, TopicClient,
 };
 
 use crate::stream_builder::IggyConsumerConfig;
@@ -96,6 +96,7 @@ pub(crate) async fn build_iggy_stream_topic_if_not_exists(
                 topic_replication_factor,
                 IggyExpiry::ServerDefault,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await?;
     }
@@ -16,12 +16,14 @@
  * under the License.
  */
 
+use crate::client_certificate::with_client_certificate;
 use crate::leader_aware::{LeaderRedirectionState, check_and_redirect_to_leader};
 use crate::prelude::Client;
 use crate::prelude::TcpClientConfig;
 use crate::tcp::tcp_connection_stream::TcpConnectionStream;
 use crate::tcp::tcp_connection_stream_kind::ConnectionStreamKind;
 use crate::tcp::tcp_tls_connection_stream::TcpTlsConnectionStream;
+use crate::throttling::send_with_throttle_backoff;
 use async_broadcast::{Receiver, Sender, broadcast};
 use async_trait::async_trait;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -103,7 +105,7 @@ impl BinaryTransport for TcpClient {
     }
 
     async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
-        let result = self.send_raw(code, payload.clone()).await;
+        let result = send_with_throttle_backoff(|| self.send_raw(code, payload.clone())).await;
         if result.is_ok() {
             return result;
         }
@@ -212,6 +214,11 @@ impl TcpClient {
         stream: &mut ConnectionStreamKind,
     ) -> Result<Bytes, IggyError> {
         if status != 0 {
+            let mut error_payload = vec![0u8; length as usize];
+            if length > 0 {
+                stream.read(&mut error_payload).await?;
+            }
+
             // TEMP: See https://github.com/apache/iggy/pull/604 for context.
             if status == IggyErrorDiscriminants::TopicNameAlreadyExists as u32
                 || status == IggyErrorDiscriminants::StreamNameAlreadyExists as u32
@@ -232,7 +239,7 @@ impl TcpClient {
                 );
             }
 
-            return Err(IggyError::from_code(status));
+            return Err(IggyError::from_response(status, &error_payload));
         }
 
         trace!("Status: OK. Response length: {}", length);
@@ -357,7 +364,7 @@ impl TcpClient {
 
                 let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
 
-                let config = if self.config.tls_validate_certificate {
+                let builder = if self.config.tls_validate_certificate {
                     let mut root_cert_store = rustls::RootCertStore::empty();
                     if let Some(certificate_path) = &self.config.tls_ca_file {
                         for cert in
@@ -383,16 +390,18 @@ impl TcpClient {
                         root_cert_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
                     }
 
-                    rustls::ClientConfig::builder()
-                        .with_root_certificates(root_cert_store)
-                        .with_no_client_auth()
+                    rustls::ClientConfig::builder().with_root_certificates(root_cert_store)
                 } else {
                     use crate::tcp::tcp_tls_verifier::NoServerVerification;
                     rustls::ClientConfig::builder()
                         .dangerous()
                         .with_custom_certificate_verifier(Arc::new(NoServerVerification))
-                        .with_no_client_auth()
                 };
+                let config = with_client_certificate(
+                    builder,
+                    self.config.tls_client_cert_file.as_deref(),
+                    self.config.tls_client_key_file.as_deref(),
+                )?;
                 let connector = TlsConnector::from(Arc::new(config));
                 let tls_domain = if self.config.tls_domain.is_empty() {
                     // Extract hostname/IP from server_address when tls_domain is not specified
@@ -443,6 +452,13 @@ impl TcpClient {
                                 "{NAME} client: {client_address} has signed in with the user credentials, username: {username}",
                             );
                         }
+                        Credentials::UsernamePasswordScram(username, password) => {
+                            self.login_user_scram(username, password.expose_secret())
+                                .await?;
+                            info!(
+                                "{NAME} client: {client_address} has signed in with the SCRAM user credentials, username: {username}",
+                            );
+                        }
                         Credentials::PersonalAccessToken(token) => {
                             self.login_with_personal_access_token(token.expose_secret())
                                 .await?;
@@ -450,6 +466,13 @@ impl TcpClient {
                                 "{NAME} client: {client_address} has signed in with a personal access token.",
                             );
                         }
+                        Credentials::AccessToken(provider) => {
+                            let token = provider.access_token().await?;
+                            self.login_with_token(token.expose_secret()).await?;
+                            info!(
+                                "{NAME} client: {client_address} has signed in with an access token.",
+                            );
+                        }
                     }
 
                     self.handle_leader_redirection().await?
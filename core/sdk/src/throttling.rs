@@ -0,0 +1,118 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy_common::IggyError;
+use std::future::Future;
+use std::time::Duration;
+use tracing::warn;
+
+/// How many times a request rejected by a server quota is sent again before the error is returned.
+const MAX_THROTTLE_RETRIES: u32 = 5;
+/// Upper bound for a single wait, so a misbehaving server cannot stall the client indefinitely.
+const MAX_THROTTLE_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Sends the request, waiting for the throttle time reported by the server and retrying
+/// whenever a quota rejects it. The server rejects throttled requests before executing them,
+/// so sending them again is safe.
+pub(crate) async fn send_with_throttle_backoff<F, Fut>(mut send: F) -> Result<Bytes, IggyError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Bytes, IggyError>>,
+{
+    let mut retries = 0;
+    loop {
+        match send().await {
+            Err(IggyError::Throttled(throttle_time_ms)) if retries < MAX_THROTTLE_RETRIES => {
+                retries += 1;
+                let backoff = throttle_backoff(throttle_time_ms, retries);
+                warn!(
+                    "Request was throttled by the server quota, retrying in {} ms ({retries}/{MAX_THROTTLE_RETRIES})...",
+                    backoff.as_millis()
+                );
+                tokio::time::sleep(backoff).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Waits at least the hinted throttle time, doubling it with every retry.
+fn throttle_backoff(throttle_time_ms: u32, retry: u32) -> Duration {
+    let throttle_time = Duration::from_millis(u64::from(throttle_time_ms.max(1)));
+    throttle_time
+        .saturating_mul(1 << (retry - 1).min(16))
+        .min(MAX_THROTTLE_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn backoff_should_grow_from_hint_and_be_capped() {
+        assert_eq!(throttle_backoff(100, 1), Duration::from_millis(100));
+        assert_eq!(throttle_backoff(100, 2), Duration::from_millis(200));
+        assert_eq!(throttle_backoff(100, 3), Duration::from_millis(400));
+        assert_eq!(throttle_backoff(0, 1), Duration::from_millis(1));
+        assert_eq!(throttle_backoff(u32::MAX, 5), MAX_THROTTLE_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn throttled_request_should_be_retried_until_it_succeeds() {
+        let attempts = AtomicU32::new(0);
+        let result = send_with_throttle_backoff(|| async {
+            if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(IggyError::Throttled(1))
+            } else {
+                Ok(Bytes::from_static(b"ok"))
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), Bytes::from_static(b"ok"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn throttled_error_should_be_returned_when_retries_are_exhausted() {
+        let attempts = AtomicU32::new(0);
+        let result = send_with_throttle_backoff(|| async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(IggyError::Throttled(1))
+        })
+        .await;
+
+        assert!(matches!(result, Err(IggyError::Throttled(1))));
+        assert_eq!(attempts.load(Ordering::SeqCst), MAX_THROTTLE_RETRIES + 1);
+    }
+
+    #[tokio::test]
+    async fn other_errors_should_not_be_retried() {
+        let attempts = AtomicU32::new(0);
+        let result = send_with_throttle_backoff(|| async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(IggyError::Unauthorized)
+        })
+        .await;
+
+        assert!(matches!(result, Err(IggyError::Unauthorized)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
@@ -16,7 +16,9 @@
  * under the License.
  */
 
+use crate::client_certificate::with_client_certificate;
 use crate::leader_aware::{LeaderRedirectionState, check_and_redirect_to_leader};
+use crate::throttling::send_with_throttle_backoff;
 use crate::websocket::websocket_connection_stream::WebSocketConnectionStream;
 use crate::websocket::websocket_stream_kind::WebSocketStreamKind;
 use crate::websocket::websocket_tls_connection_stream::WebSocketTlsConnectionStream;
@@ -102,7 +104,7 @@ impl BinaryTransport for WebSocketClient {
     }
 
     async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
-        let result = self.send_raw(code, payload.clone()).await;
+        let result = send_with_throttle_backoff(|| self.send_raw(code, payload.clone())).await;
         if result.is_ok() {
             return result;
         }
@@ -361,7 +363,7 @@ impl WebSocketClient {
             let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
         }
 
-        let config = if self.config.tls_validate_certificate {
+        let builder = if self.config.tls_validate_certificate {
             let mut root_cert_store = rustls::RootCertStore::empty();
 
             if let Some(certificate_path) = &self.config.tls_ca_file {
@@ -387,19 +389,20 @@ impl WebSocketClient {
                 root_cert_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
             }
 
-            rustls::ClientConfig::builder()
-                .with_root_certificates(root_cert_store)
-                .with_no_client_auth()
+            rustls::ClientConfig::builder().with_root_certificates(root_cert_store)
         } else {
             // skip certificate validation (development/self-signed certs)
             use crate::tcp::tcp_tls_verifier::NoServerVerification;
             rustls::ClientConfig::builder()
                 .dangerous()
                 .with_custom_certificate_verifier(Arc::new(NoServerVerification))
-                .with_no_client_auth()
         };
 
-        Ok(config)
+        with_client_certificate(
+            builder,
+            self.config.tls_client_cert_file.as_deref(),
+            self.config.tls_client_key_file.as_deref(),
+        )
     }
 
     async fn handle_connection_error<T>(&self, retry_count: &mut u32) -> Result<T, IggyError> {
@@ -497,6 +500,14 @@ impl WebSocketClient {
                         );
                         Ok(())
                     }
+                    Credentials::UsernamePasswordScram(username, password) => {
+                        self.login_user_scram(username, password.expose_secret())
+                            .await?;
+                        info!(
+                            "{NAME} client: {client_address} has signed in with the SCRAM user credentials, username: {username}",
+                        );
+                        Ok(())
+                    }
                     Credentials::PersonalAccessToken(token) => {
                         self.login_with_personal_access_token(token.expose_secret())
                             .await?;
@@ -505,6 +516,14 @@ impl WebSocketClient {
                         );
                         Ok(())
                     }
+                    Credentials::AccessToken(provider) => {
+                        let token = provider.access_token().await?;
+                        self.login_with_token(token.expose_secret()).await?;
+                        info!(
+                            "{NAME} client: {client_address} has signed in with an access token.",
+                        );
+                        Ok(())
+                    }
                 }
             }
         }
@@ -609,6 +628,11 @@ impl WebSocketClient {
         );
 
         if status != 0 {
+            let mut error_payload = vec![0u8; length];
+            if length > 0 {
+                stream.read(&mut error_payload).await?;
+            }
+
             // TEMP: See https://github.com/apache/iggy/pull/604 for context.
             if status == IggyErrorDiscriminants::TopicNameAlreadyExists as u32
                 || status == IggyErrorDiscriminants::StreamNameAlreadyExists as u32
@@ -629,7 +653,7 @@ impl WebSocketClient {
                 );
             }
 
-            return Err(IggyError::from_code(status));
+            return Err(IggyError::from_response(status, &error_payload));
         }
 
         if length == 0 {
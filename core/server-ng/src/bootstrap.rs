@@ -36,9 +36,10 @@ use message_bus::installer::ConnectionInstaller;
 use message_bus::installer::conn_info::{ClientConnMeta, ClientTransportKind};
 use message_bus::replica::io as replica_io;
 use message_bus::replica::listener::{self as replica_listener, MessageHandler};
-use message_bus::transports::quic::server_config_with_cert;
+use message_bus::transports::quic::server_config_with_client_auth;
 use message_bus::transports::tls::{
-    TlsServerCredentials, install_default_crypto_provider, load_pem, self_signed_for_loopback,
+    TlsServerCredentials, client_cert_verifier, install_default_crypto_provider, load_pem,
+    self_signed_for_loopback,
 };
 use message_bus::{
     AcceptedClientFn, AcceptedQuicClientFn, AcceptedReplicaFn, AcceptedTlsClientFn,
@@ -1449,9 +1450,10 @@ async fn start_client_listeners(
     if let Some(quic_addr) = topology.quic_listen_addr {
         install_default_crypto_provider();
         let credentials = load_quic_server_credentials(config)?;
-        let server_config = server_config_with_cert(
+        let server_config = server_config_with_client_auth(
             credentials.cert_chain,
             credentials.key_der,
+            credentials.client_verifier,
             &shard.bus.config().quic,
         )
         .map_err(|e| {
@@ -1505,22 +1507,35 @@ fn load_tcp_tls_server_credentials(
     config: &ServerNgConfig,
 ) -> Result<TlsServerCredentials, ServerNgError> {
     let tls = &config.tcp.tls;
-    if tls.self_signed && !Path::new(&tls.cert_file).exists() {
-        return Ok(self_signed_for_loopback());
-    }
+    let credentials = if tls.self_signed && !Path::new(&tls.cert_file).exists() {
+        self_signed_for_loopback()
+    } else {
+        load_pem(Path::new(&tls.cert_file), Path::new(&tls.key_file)).map_err(|source| {
+            ServerNgError::ListenerCredentials {
+                transport: "tcp.tls",
+                source,
+            }
+        })?
+    };
 
-    load_pem(Path::new(&tls.cert_file), Path::new(&tls.key_file)).map_err(|source| {
-        ServerNgError::ListenerCredentials {
+    credentials
+        .with_client_auth(&tls.client_auth)
+        .map_err(|source| ServerNgError::ListenerCredentials {
             transport: "tcp.tls",
             source,
-        }
-    })
+        })
 }
 
 fn load_quic_server_credentials(
     config: &ServerNgConfig,
 ) -> Result<replica_io::QuicServerCredentials, ServerNgError> {
     let certificate = &config.quic.certificate;
+    let client_verifier = client_cert_verifier(&certificate.client_auth).map_err(|source| {
+        ServerNgError::ListenerCredentials {
+            transport: "quic",
+            source,
+        }
+    })?;
     if certificate.self_signed {
         let (cert_chain, key_der) = iggy_common::generate_self_signed_certificate("localhost")
             .map_err(|error| ServerNgError::ListenerCredentials {
@@ -1530,6 +1545,7 @@ fn load_quic_server_credentials(
         return Ok(replica_io::QuicServerCredentials {
             cert_chain,
             key_der,
+            client_verifier,
         });
     }
 
@@ -1544,6 +1560,7 @@ fn load_quic_server_credentials(
     Ok(replica_io::QuicServerCredentials {
         cert_chain: credentials.cert_chain,
         key_der: credentials.key_der,
+        client_verifier,
     })
 }
 
@@ -0,0 +1,182 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::Archiver;
+use crate::server_error::ArchiverError;
+use crate::streaming::utils::file;
+use compio::buf::{IntoInner, IoBuf};
+use compio::fs::create_dir_all;
+use compio::io::{AsyncReadAtExt, AsyncWriteAtExt};
+use iggy_common::PooledBuffer;
+use std::io::ErrorKind;
+use std::path::Path;
+use tracing::{debug, error};
+
+/// The size of the chunks in which the files are copied to the archive.
+const COPY_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+/// Archives the files in a local (or mounted) directory.
+#[derive(Debug, Clone)]
+pub struct DiskArchiver {
+    path: String,
+}
+
+impl DiskArchiver {
+    pub fn new(path: String) -> Self {
+        Self { path }
+    }
+
+    fn object_path(&self, key: &str) -> String {
+        format!("{}/{key}", self.path)
+    }
+}
+
+impl Archiver for DiskArchiver {
+    /// Copies the file next to its destination first and renames it afterwards, so a partially
+    /// copied file is never visible under the key.
+    async fn archive(&self, path: &str, key: &str) -> Result<(), ArchiverError> {
+        let source = match file::open(path).await {
+            Ok(source) => source,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Err(ArchiverError::FileToArchiveNotFound {
+                    file_path: path.to_owned(),
+                });
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let size = source.metadata().await?.len();
+
+        let destination_path = self.object_path(key);
+        if let Some(directory) = Path::new(&destination_path).parent() {
+            create_dir_all(directory).await?;
+        }
+        let temporary_path = format!("{destination_path}.tmp");
+        let mut destination = file::overwrite(&temporary_path).await?;
+        destination.set_len(size).await?;
+
+        let mut position = 0;
+        while position < size {
+            let length = COPY_CHUNK_SIZE.min(size - position) as usize;
+            let (result, chunk) = source
+                .read_exact_at(Vec::with_capacity(length), position)
+                .await
+                .into();
+            result?;
+            let (result, _) = destination.write_all_at(chunk, position).await.into();
+            result?;
+            position += length as u64;
+        }
+
+        destination.sync_all().await.map_err(|e| {
+            error!("Failed to sync archived file: {temporary_path}. {e}");
+            ArchiverError::CannotArchiveFile {
+                file_path: path.to_owned(),
+            }
+        })?;
+        drop(destination);
+        file::rename(&temporary_path, &destination_path).await?;
+        debug!("Archived file: {path} as: {destination_path}");
+        Ok(())
+    }
+
+    async fn read(
+        &self,
+        key: &str,
+        position: u64,
+        length: u64,
+    ) -> Result<PooledBuffer, ArchiverError> {
+        let path = self.object_path(key);
+        let file = file::open(&path).await?;
+        let buffer = PooledBuffer::with_capacity(length as usize);
+        let (result, buffer) = file
+            .read_exact_at(buffer.slice(..length as usize), position)
+            .await
+            .into();
+        result?;
+        Ok(buffer.into_inner())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), ArchiverError> {
+        match file::remove(&self.object_path(key)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::{IggyByteSize, MemoryPool, MemoryPoolConfigOther};
+
+    fn init_memory_pool() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            let config = MemoryPoolConfigOther {
+                enabled: false,
+                size: IggyByteSize::from(64 * 1024 * 1024u64),
+                bucket_capacity: 256,
+            };
+            MemoryPool::init_pool(&config);
+        });
+    }
+
+    #[compio::test]
+    async fn should_archive_read_and_delete_file() {
+        init_memory_pool();
+        let directory = tempfile::tempdir().unwrap();
+        let source_path = directory.path().join("00000000000000000000.log");
+        let content: Vec<u8> = (0..COPY_CHUNK_SIZE as usize + 1000)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        std::fs::write(&source_path, &content).unwrap();
+
+        let archiver = DiskArchiver::new(directory.path().join("archive").display().to_string());
+        let key = "streams/1/topics/1/partitions/1/00000000000000000000.log";
+        archiver
+            .archive(source_path.to_str().unwrap(), key)
+            .await
+            .unwrap();
+
+        let archived = std::fs::read(archiver.object_path(key)).unwrap();
+        assert_eq!(archived, content);
+
+        let position = COPY_CHUNK_SIZE - 10;
+        let range = archiver.read(key, position, 100).await.unwrap();
+        assert_eq!(
+            range.as_ref(),
+            &content[position as usize..position as usize + 100]
+        );
+
+        archiver.delete(key).await.unwrap();
+        archiver.delete(key).await.unwrap();
+        assert!(!Path::new(&archiver.object_path(key)).exists());
+    }
+
+    #[compio::test]
+    async fn should_fail_to_archive_missing_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let archiver = DiskArchiver::new(directory.path().display().to_string());
+        let result = archiver.archive("missing.log", "missing.log").await;
+        assert!(matches!(
+            result,
+            Err(ArchiverError::FileToArchiveNotFound { .. })
+        ));
+    }
+}
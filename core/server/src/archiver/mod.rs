@@ -0,0 +1,176 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Tiered storage of sealed segments.
+//!
+//! The archiver offloads the `.log` and `.index` files of sealed segments to an archive and
+//! removes the local messages file afterwards. The index stays on the local disk, so the byte
+//! range of the requested messages is known up front and only that range is fetched from the
+//! archive when an offloaded segment is polled.
+
+mod disk;
+mod s3;
+
+pub use disk::DiskArchiver;
+pub use s3::S3Archiver;
+
+use crate::configs::server::{ArchiverType, ServerConfig};
+use crate::configs::system::{INDEX_EXTENSION, LOG_EXTENSION};
+use crate::server_error::ArchiverError;
+use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut};
+use iggy_common::sharding::IggyNamespace;
+use iggy_common::{IggyError, PooledBuffer};
+use std::future::Future;
+use std::path::Path;
+use tracing::error;
+
+/// Storage backend of the archived segment files, addressed by object keys.
+pub trait Archiver {
+    /// Copies the local file at `path` to the archive under the given `key`.
+    fn archive(&self, path: &str, key: &str) -> impl Future<Output = Result<(), ArchiverError>>;
+
+    /// Reads `length` bytes starting at `position` of the archived object.
+    fn read(
+        &self,
+        key: &str,
+        position: u64,
+        length: u64,
+    ) -> impl Future<Output = Result<PooledBuffer, ArchiverError>>;
+
+    /// Deletes the archived object, deleting a missing object is not an error.
+    fn delete(&self, key: &str) -> impl Future<Output = Result<(), ArchiverError>>;
+}
+
+#[derive(Debug, Clone)]
+pub enum ArchiverKind {
+    Disk(DiskArchiver),
+    S3(S3Archiver),
+}
+
+impl ArchiverKind {
+    /// Creates the archiver configured in `data_maintenance.archiver`.
+    pub fn from_config(config: &ServerConfig) -> Result<Self, ArchiverError> {
+        let archiver = &config.data_maintenance.archiver;
+        match archiver.kind {
+            ArchiverType::Disk => {
+                let path = if Path::new(&archiver.disk.path).is_absolute() {
+                    archiver.disk.path.clone()
+                } else {
+                    format!("{}/{}", config.system.get_system_path(), archiver.disk.path)
+                };
+                Ok(ArchiverKind::Disk(DiskArchiver::new(path)))
+            }
+            ArchiverType::S3 => Ok(ArchiverKind::S3(S3Archiver::new(&archiver.s3)?)),
+        }
+    }
+
+    pub async fn archive(&self, path: &str, key: &str) -> Result<(), ArchiverError> {
+        match self {
+            ArchiverKind::Disk(archiver) => archiver.archive(path, key).await,
+            ArchiverKind::S3(archiver) => archiver.archive(path, key).await,
+        }
+    }
+
+    pub async fn read(
+        &self,
+        key: &str,
+        position: u64,
+        length: u64,
+    ) -> Result<PooledBuffer, ArchiverError> {
+        match self {
+            ArchiverKind::Disk(archiver) => archiver.read(key, position, length).await,
+            ArchiverKind::S3(archiver) => archiver.read(key, position, length).await,
+        }
+    }
+
+    pub async fn delete(&self, key: &str) -> Result<(), ArchiverError> {
+        match self {
+            ArchiverKind::Disk(archiver) => archiver.delete(key).await,
+            ArchiverKind::S3(archiver) => archiver.delete(key).await,
+        }
+    }
+
+    /// Archives the messages and index files of a sealed segment.
+    pub async fn archive_segment(
+        &self,
+        namespace: &IggyNamespace,
+        start_offset: u64,
+        messages_path: &str,
+        index_path: &str,
+    ) -> Result<(), ArchiverError> {
+        self.archive(
+            messages_path,
+            &segment_key(namespace, start_offset, LOG_EXTENSION),
+        )
+        .await?;
+        self.archive(
+            index_path,
+            &segment_key(namespace, start_offset, INDEX_EXTENSION),
+        )
+        .await
+    }
+
+    /// Deletes the archived files of a segment.
+    pub async fn delete_segment(
+        &self,
+        namespace: &IggyNamespace,
+        start_offset: u64,
+    ) -> Result<(), ArchiverError> {
+        self.delete(&segment_key(namespace, start_offset, LOG_EXTENSION))
+            .await?;
+        self.delete(&segment_key(namespace, start_offset, INDEX_EXTENSION))
+            .await
+    }
+
+    /// Fetches the messages pointed by `indexes` from the archived messages file of a segment.
+    pub async fn load_segment_messages(
+        &self,
+        namespace: &IggyNamespace,
+        start_offset: u64,
+        indexes: IggyIndexesMut,
+    ) -> Result<IggyMessagesBatchMut, IggyError> {
+        if indexes.is_empty() {
+            return Ok(IggyMessagesBatchMut::empty());
+        }
+
+        let key = segment_key(namespace, start_offset, LOG_EXTENSION);
+        let position = indexes.base_position() as u64;
+        let length = indexes.messages_size() as u64;
+        let messages = self.read(&key, position, length).await.map_err(|e| {
+            error!(
+                "Failed to read {length} bytes at position {position} of archived segment: {key}. {e}"
+            );
+            IggyError::CannotReadMessage
+        })?;
+
+        Ok(IggyMessagesBatchMut::from_indexes_and_messages(
+            indexes, messages,
+        ))
+    }
+}
+
+/// Object key of an archived segment file, it doesn't depend on the local data path.
+pub fn segment_key(namespace: &IggyNamespace, start_offset: u64, extension: &str) -> String {
+    format!(
+        "streams/{}/topics/{}/partitions/{}/{:0>20}.{extension}",
+        namespace.stream_id(),
+        namespace.topic_id(),
+        namespace.partition_id(),
+        start_offset
+    )
+}
@@ -0,0 +1,141 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+mod request;
+mod writer;
+
+pub use request::{AuditedRequest, describe_request, is_audited};
+
+use crate::configs::system::AuditConfig;
+use iggy_common::{AuditLogQuery, AuditOutcome, AuditRecord, IggyError, UserId};
+use std::thread::JoinHandle;
+use tracing::{debug, error, info};
+use writer::AuditWriter;
+
+/// A single audited operation, which becomes an [`AuditRecord`] once appended to the log.
+#[derive(Debug, Clone, Default)]
+pub struct AuditEvent {
+    pub user_id: Option<UserId>,
+    pub username: Option<String>,
+    pub client_id: Option<u32>,
+    pub address: Option<String>,
+    pub operation: String,
+    pub details: Option<String>,
+    pub outcome: AuditOutcome,
+    pub error: Option<String>,
+}
+
+impl AuditEvent {
+    pub fn new(operation: &str, result: Result<(), &IggyError>) -> Self {
+        let (outcome, error) = match result {
+            Ok(()) => (AuditOutcome::Success, None),
+            Err(error) => (AuditOutcome::Failure, Some(error.to_string())),
+        };
+        Self {
+            operation: operation.to_string(),
+            outcome,
+            error,
+            ..Default::default()
+        }
+    }
+}
+
+enum AuditCommand {
+    Append(AuditEvent),
+    Query(
+        AuditLogQuery,
+        flume::Sender<Result<Vec<AuditRecord>, IggyError>>,
+    ),
+}
+
+/// Handle to the audit log shared by all the shards.
+///
+/// The records are written by a dedicated thread, so that all the shards append to a single
+/// chain of records without blocking their executors on the file I/O.
+#[derive(Debug, Clone, Default)]
+pub struct AuditLog {
+    sender: Option<flume::Sender<AuditCommand>>,
+}
+
+impl AuditLog {
+    /// Returns a handle which discards all the events, used when the audit log is disabled.
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    pub fn start(config: &AuditConfig, path: &str) -> Result<(Self, JoinHandle<()>), IggyError> {
+        let mut writer =
+            AuditWriter::open(path, config.max_file_size.as_bytes_u64(), config.max_files)?;
+        let (sender, receiver) = flume::unbounded::<AuditCommand>();
+        let handle = std::thread::Builder::new()
+            .name("audit-log".to_string())
+            .spawn(move || {
+                while let Ok(command) = receiver.recv() {
+                    match command {
+                        AuditCommand::Append(event) => {
+                            if let Err(error) = writer.append(event) {
+                                error!("Failed to append the audit log record: {error}");
+                            }
+                        }
+                        AuditCommand::Query(query, response) => {
+                            let _ = response.send(writer.query(&query));
+                        }
+                    }
+                }
+                debug!("Audit log thread exited gracefully");
+            })
+            .expect("Failed to spawn audit log thread");
+        info!("Audit log is enabled, the records are stored at: {path}");
+        Ok((
+            Self {
+                sender: Some(sender),
+            },
+            handle,
+        ))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.sender.is_some()
+    }
+
+    pub fn record(&self, event: AuditEvent) {
+        let Some(sender) = &self.sender else {
+            return;
+        };
+
+        if sender.send(AuditCommand::Append(event)).is_err() {
+            error!("Failed to record the audit event, the audit log thread has stopped.");
+        }
+    }
+
+    pub async fn query(&self, query: AuditLogQuery) -> Result<Vec<AuditRecord>, IggyError> {
+        let Some(sender) = &self.sender else {
+            return Err(IggyError::FeatureUnavailable);
+        };
+
+        let (response_sender, response_receiver) = flume::bounded(1);
+        sender
+            .send_async(AuditCommand::Query(query, response_sender))
+            .await
+            .map_err(|_| IggyError::FeatureUnavailable)?;
+        response_receiver
+            .recv_async()
+            .await
+            .map_err(|_| IggyError::FeatureUnavailable)?
+    }
+}
@@ -0,0 +1,309 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_binary_protocol::codec::WireDecode;
+use iggy_binary_protocol::codes::*;
+use iggy_binary_protocol::requests::consumer_groups::*;
+use iggy_binary_protocol::requests::partitions::*;
+use iggy_binary_protocol::requests::personal_access_tokens::*;
+use iggy_binary_protocol::requests::roles::*;
+use iggy_binary_protocol::requests::segments::*;
+use iggy_binary_protocol::requests::streams::*;
+use iggy_binary_protocol::requests::topics::*;
+use iggy_binary_protocol::requests::users::*;
+use iggy_binary_protocol::{WireIdentifier, WireQuotaScope};
+
+/// The audited details of a binary request, decoded before it's handled.
+#[derive(Debug, Default)]
+pub struct AuditedRequest {
+    /// The name of the user attempting to authenticate, if sent in the request.
+    pub username: Option<String>,
+    /// The identifiers and names of the affected resources, never containing any secrets.
+    pub details: Option<String>,
+}
+
+/// Returns `true` if the command changes the server state or authenticates the client.
+pub fn is_audited(code: u32) -> bool {
+    matches!(
+        code,
+        CREATE_STREAM_CODE
+            | UPDATE_STREAM_CODE
+            | DELETE_STREAM_CODE
+            | PURGE_STREAM_CODE
+            | CREATE_TOPIC_CODE
+            | UPDATE_TOPIC_CODE
+            | DELETE_TOPIC_CODE
+            | PURGE_TOPIC_CODE
+            | ROTATE_TOPIC_KEY_CODE
+            | CREATE_PARTITIONS_CODE
+            | DELETE_PARTITIONS_CODE
+            | DELETE_SEGMENTS_CODE
+            | CREATE_CONSUMER_GROUP_CODE
+            | DELETE_CONSUMER_GROUP_CODE
+            | CREATE_USER_CODE
+            | UPDATE_USER_CODE
+            | DELETE_USER_CODE
+            | CHANGE_PASSWORD_CODE
+            | UPDATE_PERMISSIONS_CODE
+            | SET_USER_QUOTA_CODE
+            | CREATE_ROLE_CODE
+            | UPDATE_ROLE_CODE
+            | DELETE_ROLE_CODE
+            | ASSIGN_ROLE_CODE
+            | UNASSIGN_ROLE_CODE
+            | CREATE_PERSONAL_ACCESS_TOKEN_CODE
+            | DELETE_PERSONAL_ACCESS_TOKEN_CODE
+            | LOGIN_USER_CODE
+            | LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE
+            | LOGIN_SCRAM_START_CODE
+            | LOGIN_SCRAM_FINISH_CODE
+            | LOGIN_WITH_TOKEN_CODE
+            | LOGOUT_USER_CODE
+    )
+}
+
+/// Decodes the audited request, skipping the passwords, tokens and other secrets.
+/// A request which cannot be decoded is rejected by its handler, so it's described
+/// only by the operation name and the outcome.
+pub fn describe_request(code: u32, payload: &[u8]) -> AuditedRequest {
+    let details = |details: String| AuditedRequest {
+        username: None,
+        details: Some(details),
+    };
+    let described = match code {
+        CREATE_STREAM_CODE => decode(payload, |r: CreateStreamRequest| {
+            details(format!("name: {}", r.name))
+        }),
+        UPDATE_STREAM_CODE => decode(payload, |r: UpdateStreamRequest| {
+            details(format!("stream: {}, name: {}", r.stream_id, r.name))
+        }),
+        DELETE_STREAM_CODE => decode(payload, |r: DeleteStreamRequest| {
+            details(format!("stream: {}", r.stream_id))
+        }),
+        PURGE_STREAM_CODE => decode(payload, |r: PurgeStreamRequest| {
+            details(format!("stream: {}", r.stream_id))
+        }),
+        CREATE_TOPIC_CODE => decode(payload, |r: CreateTopicRequest| {
+            details(format!(
+                "stream: {}, name: {}, partitions: {}",
+                r.stream_id, r.name, r.partitions_count
+            ))
+        }),
+        UPDATE_TOPIC_CODE => decode(payload, |r: UpdateTopicRequest| {
+            details(format!(
+                "{}, name: {}",
+                topic(&r.stream_id, &r.topic_id),
+                r.name
+            ))
+        }),
+        DELETE_TOPIC_CODE => decode(payload, |r: DeleteTopicRequest| {
+            details(topic(&r.stream_id, &r.topic_id))
+        }),
+        PURGE_TOPIC_CODE => decode(payload, |r: PurgeTopicRequest| {
+            details(topic(&r.stream_id, &r.topic_id))
+        }),
+        ROTATE_TOPIC_KEY_CODE => decode(payload, |r: RotateTopicKeyRequest| {
+            details(topic(&r.stream_id, &r.topic_id))
+        }),
+        CREATE_PARTITIONS_CODE => decode(payload, |r: CreatePartitionsRequest| {
+            details(format!(
+                "{}, partitions: {}",
+                topic(&r.stream_id, &r.topic_id),
+                r.partitions_count
+            ))
+        }),
+        DELETE_PARTITIONS_CODE => decode(payload, |r: DeletePartitionsRequest| {
+            details(format!(
+                "{}, partitions: {}",
+                topic(&r.stream_id, &r.topic_id),
+                r.partitions_count
+            ))
+        }),
+        DELETE_SEGMENTS_CODE => decode(payload, |r: DeleteSegmentsRequest| {
+            details(format!(
+                "{}, partition: {}, segments: {}",
+                topic(&r.stream_id, &r.topic_id),
+                r.partition_id,
+                r.segments_count
+            ))
+        }),
+        CREATE_CONSUMER_GROUP_CODE => decode(payload, |r: CreateConsumerGroupRequest| {
+            details(format!(
+                "{}, name: {}",
+                topic(&r.stream_id, &r.topic_id),
+                r.name
+            ))
+        }),
+        DELETE_CONSUMER_GROUP_CODE => decode(payload, |r: DeleteConsumerGroupRequest| {
+            details(format!(
+                "{}, group: {}",
+                topic(&r.stream_id, &r.topic_id),
+                r.group_id
+            ))
+        }),
+        CREATE_USER_CODE => decode(payload, |r: CreateUserRequest| {
+            details(format!(
+                "username: {}, permissions: {}",
+                r.username,
+                r.permissions.is_some()
+            ))
+        }),
+        UPDATE_USER_CODE => decode(payload, |r: UpdateUserRequest| {
+            let mut description = format!("user: {}", r.user_id);
+            if let Some(username) = &r.username {
+                description.push_str(&format!(", username: {username}"));
+            }
+            if let Some(status) = r.status {
+                description.push_str(&format!(", status: {status}"));
+            }
+            details(description)
+        }),
+        DELETE_USER_CODE => decode(payload, |r: DeleteUserRequest| {
+            details(format!("user: {}", r.user_id))
+        }),
+        CHANGE_PASSWORD_CODE => decode(payload, |r: ChangePasswordRequest| {
+            details(format!("user: {}", r.user_id))
+        }),
+        UPDATE_PERMISSIONS_CODE => decode(payload, |r: UpdatePermissionsRequest| {
+            details(format!(
+                "user: {}, permissions: {}",
+                r.user_id,
+                r.permissions.is_some()
+            ))
+        }),
+        SET_USER_QUOTA_CODE => decode(payload, |r: SetUserQuotaRequest| {
+            let scope = match r.scope {
+                WireQuotaScope::User => "user",
+                WireQuotaScope::Client => "client",
+            };
+            details(format!(
+                "user: {}, scope: {scope}, quota: {}",
+                r.user_id,
+                r.quota.is_some()
+            ))
+        }),
+        CREATE_ROLE_CODE => decode(payload, |r: CreateRoleRequest| {
+            details(format!(
+                "name: {}, permissions: {}",
+                r.name,
+                r.permissions.is_some()
+            ))
+        }),
+        UPDATE_ROLE_CODE => decode(payload, |r: UpdateRoleRequest| {
+            details(format!(
+                "role: {}, permissions: {}",
+                r.role_id,
+                r.permissions.is_some()
+            ))
+        }),
+        DELETE_ROLE_CODE => decode(payload, |r: DeleteRoleRequest| {
+            details(format!("role: {}", r.role_id))
+        }),
+        ASSIGN_ROLE_CODE => decode(payload, |r: AssignRoleRequest| {
+            details(format!("user: {}, role: {}", r.user_id, r.role_id))
+        }),
+        UNASSIGN_ROLE_CODE => decode(payload, |r: UnassignRoleRequest| {
+            details(format!("user: {}, role: {}", r.user_id, r.role_id))
+        }),
+        CREATE_PERSONAL_ACCESS_TOKEN_CODE => {
+            decode(payload, |r: CreatePersonalAccessTokenRequest| {
+                details(format!("name: {}", r.name))
+            })
+        }
+        DELETE_PERSONAL_ACCESS_TOKEN_CODE => {
+            decode(payload, |r: DeletePersonalAccessTokenRequest| {
+                details(format!("name: {}", r.name))
+            })
+        }
+        LOGIN_USER_CODE => decode(payload, |r: LoginUserRequest| AuditedRequest {
+            username: Some(r.username.to_string()),
+            details: None,
+        }),
+        LOGIN_SCRAM_START_CODE => decode(payload, |r: LoginScramStartRequest| AuditedRequest {
+            username: Some(r.username.to_string()),
+            details: None,
+        }),
+        _ => None,
+    };
+    described.unwrap_or_default()
+}
+
+fn decode<T: WireDecode>(
+    payload: &[u8],
+    describe: impl FnOnce(T) -> AuditedRequest,
+) -> Option<AuditedRequest> {
+    T::decode_from(payload).ok().map(describe)
+}
+
+fn topic(stream_id: &WireIdentifier, topic_id: &WireIdentifier) -> String {
+    format!("stream: {stream_id}, topic: {topic_id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_binary_protocol::WireName;
+    use iggy_binary_protocol::codec::WireEncode;
+
+    #[test]
+    fn should_describe_resources_of_the_request() {
+        let request = CreateTopicRequest {
+            stream_id: WireIdentifier::numeric(1),
+            partitions_count: 3,
+            compression_algorithm: 1,
+            message_expiry: 0,
+            max_topic_size: 0,
+            replication_factor: 1,
+            name: WireName::new("orders").unwrap(),
+            cleanup_policy: 0,
+        };
+
+        let described = describe_request(CREATE_TOPIC_CODE, &request.to_bytes());
+
+        assert_eq!(
+            described.details.as_deref(),
+            Some("stream: 1, name: orders, partitions: 3")
+        );
+        assert!(described.username.is_none());
+    }
+
+    #[test]
+    fn should_describe_login_without_the_password() {
+        let request = LoginUserRequest {
+            username: WireName::new("admin").unwrap(),
+            password: "secret".to_string(),
+            version: None,
+            context: None,
+        };
+
+        let described = describe_request(LOGIN_USER_CODE, &request.to_bytes());
+
+        assert_eq!(described.username.as_deref(), Some("admin"));
+        assert!(described.details.is_none());
+    }
+
+    #[test]
+    fn should_not_describe_invalid_request() {
+        let described = describe_request(DELETE_STREAM_CODE, &[1, 2]);
+
+        assert!(described.username.is_none());
+        assert!(described.details.is_none());
+        assert!(is_audited(DELETE_STREAM_CODE));
+        assert!(!is_audited(GET_STREAMS_CODE));
+    }
+}
@@ -0,0 +1,356 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::AuditEvent;
+use iggy_common::{AuditLogQuery, AuditRecord, IggyError, IggyTimestamp};
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+const FILE_PREFIX: &str = "audit-";
+const FILE_EXTENSION: &str = "jsonl";
+
+/// Appends the audit records as JSON lines to the rotating files in a single directory,
+/// chaining each record to the previous one, including the records written before the restart.
+#[derive(Debug)]
+pub(crate) struct AuditWriter {
+    path: PathBuf,
+    max_file_size: u64,
+    max_files: usize,
+    file: File,
+    file_index: u64,
+    file_size: u64,
+    next_id: u64,
+    last_hash: String,
+}
+
+impl AuditWriter {
+    pub fn open(path: &str, max_file_size: u64, max_files: u32) -> Result<Self, IggyError> {
+        let path = PathBuf::from(path);
+        fs::create_dir_all(&path)
+            .map_err(|_| IggyError::CannotCreateBaseDirectory(path.display().to_string()))?;
+
+        let mut next_id = 1;
+        let mut last_hash = String::new();
+        let indexes = list_file_indexes(&path)?;
+        for index in indexes.iter().rev() {
+            if let Some(record) = read_last_record(&file_path(&path, *index))? {
+                next_id = record.id + 1;
+                last_hash = record.hash;
+                break;
+            }
+        }
+
+        let file_index = indexes.last().copied().unwrap_or(1);
+        let (file, file_size) = open_file(&path, file_index)?;
+        if next_id > 1 {
+            info!(
+                "Opened the audit log at: {}, the next record ID: {next_id}.",
+                path.display()
+            );
+        }
+
+        Ok(Self {
+            path,
+            max_file_size,
+            max_files: max_files.max(1) as usize,
+            file,
+            file_index,
+            file_size,
+            next_id,
+            last_hash,
+        })
+    }
+
+    pub fn append(&mut self, event: AuditEvent) -> Result<AuditRecord, IggyError> {
+        let mut record = AuditRecord {
+            id: self.next_id,
+            timestamp: IggyTimestamp::now(),
+            user_id: event.user_id,
+            username: event.username,
+            client_id: event.client_id,
+            address: event.address,
+            operation: event.operation,
+            details: event.details,
+            outcome: event.outcome,
+            error: event.error,
+            previous_hash: self.last_hash.clone(),
+            hash: String::new(),
+        };
+        record.hash = record.calculate_hash();
+
+        let mut line =
+            serde_json::to_vec(&record).map_err(|_| IggyError::CannotSerializeResource)?;
+        line.push(b'\n');
+        if self.file_size > 0 && self.file_size + line.len() as u64 > self.max_file_size {
+            self.rotate()?;
+        }
+
+        self.file
+            .write_all(&line)
+            .map_err(|_| IggyError::CannotAppendToFile)?;
+        self.file_size += line.len() as u64;
+        self.next_id += 1;
+        self.last_hash.clone_from(&record.hash);
+        Ok(record)
+    }
+
+    /// Returns the matching records from the oldest to the newest, limited to the most recent ones.
+    pub fn query(&self, query: &AuditLogQuery) -> Result<Vec<AuditRecord>, IggyError> {
+        let limit = query.limit.map(|limit| limit as usize);
+        let mut records = VecDeque::new();
+        for index in list_file_indexes(&self.path)? {
+            let file =
+                File::open(file_path(&self.path, index)).map_err(|_| IggyError::CannotReadFile)?;
+            for line in BufReader::new(file).lines() {
+                let line = line.map_err(|_| IggyError::CannotReadFile)?;
+                let Some(record) = parse_record(&line) else {
+                    continue;
+                };
+                if !query.matches(&record) {
+                    continue;
+                }
+
+                records.push_back(record);
+                if limit.is_some_and(|limit| records.len() > limit) {
+                    records.pop_front();
+                }
+            }
+        }
+
+        Ok(records.into())
+    }
+
+    fn rotate(&mut self) -> Result<(), IggyError> {
+        self.file
+            .sync_all()
+            .map_err(|_| IggyError::CannotSyncFile)?;
+        self.file_index += 1;
+        let (file, file_size) = open_file(&self.path, self.file_index)?;
+        self.file = file;
+        self.file_size = file_size;
+
+        let indexes = list_file_indexes(&self.path)?;
+        let excess = indexes.len().saturating_sub(self.max_files);
+        for index in &indexes[..excess] {
+            fs::remove_file(file_path(&self.path, *index))
+                .map_err(|_| IggyError::CannotDeleteFile)?;
+        }
+        Ok(())
+    }
+}
+
+fn file_path(path: &Path, index: u64) -> PathBuf {
+    path.join(format!("{FILE_PREFIX}{index:020}.{FILE_EXTENSION}"))
+}
+
+fn list_file_indexes(path: &Path) -> Result<Vec<u64>, IggyError> {
+    let entries = fs::read_dir(path).map_err(|_| IggyError::CannotReadFile)?;
+    let mut indexes = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()?
+                .strip_prefix(FILE_PREFIX)?
+                .strip_suffix(FILE_EXTENSION)?
+                .strip_suffix('.')?
+                .parse::<u64>()
+                .ok()
+        })
+        .collect::<Vec<_>>();
+    indexes.sort_unstable();
+    Ok(indexes)
+}
+
+fn open_file(path: &Path, index: u64) -> Result<(File, u64), IggyError> {
+    let file_path = file_path(path, index);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(&file_path)
+        .map_err(|_| IggyError::CannotAppendToFile)?;
+    let size = truncate_incomplete_line(&mut file, &file_path)?;
+    Ok((file, size))
+}
+
+/// A crash in the middle of writing may leave an incomplete last line,
+/// which has to be removed, so that the next record starts on its own line.
+fn truncate_incomplete_line(file: &mut File, file_path: &Path) -> Result<u64, IggyError> {
+    let mut content = Vec::new();
+    file.seek(SeekFrom::Start(0))
+        .map_err(|_| IggyError::CannotSeekFile)?;
+    file.read_to_end(&mut content)
+        .map_err(|_| IggyError::CannotReadFile)?;
+    if content.is_empty() || content.ends_with(b"\n") {
+        return Ok(content.len() as u64);
+    }
+
+    let size = content
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |position| position + 1) as u64;
+    warn!(
+        "Removing the incomplete last record of the audit log file: {}",
+        file_path.display()
+    );
+    file.set_len(size)
+        .map_err(|_| IggyError::CannotOverwriteFile)?;
+    Ok(size)
+}
+
+fn read_last_record(file_path: &Path) -> Result<Option<AuditRecord>, IggyError> {
+    let file = File::open(file_path).map_err(|_| IggyError::CannotReadFile)?;
+    let mut last_record = None;
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|_| IggyError::CannotReadFile)?;
+        if let Some(record) = parse_record(&line) {
+            last_record = Some(record);
+        }
+    }
+    Ok(last_record)
+}
+
+fn parse_record(line: &str) -> Option<AuditRecord> {
+    if line.trim().is_empty() {
+        return None;
+    }
+
+    match serde_json::from_str(line) {
+        Ok(record) => Some(record),
+        Err(error) => {
+            warn!("Skipping an invalid audit log record: {error}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::AuditOutcome;
+
+    fn event(user_id: u32, operation: &str) -> AuditEvent {
+        AuditEvent {
+            user_id: Some(user_id),
+            username: None,
+            client_id: Some(1),
+            address: Some("127.0.0.1:1234".to_string()),
+            operation: operation.to_string(),
+            details: None,
+            outcome: AuditOutcome::Success,
+            error: None,
+        }
+    }
+
+    fn open(dir: &tempfile::TempDir, max_file_size: u64, max_files: u32) -> AuditWriter {
+        AuditWriter::open(dir.path().to_str().unwrap(), max_file_size, max_files).unwrap()
+    }
+
+    #[test]
+    fn appended_records_should_form_a_chain() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = open(&dir, 1024 * 1024, 10);
+        writer.append(event(0, "stream.create")).unwrap();
+        writer.append(event(0, "topic.create")).unwrap();
+        writer.append(event(1, "user.login")).unwrap();
+
+        let records = writer.query(&AuditLogQuery::default()).unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].id, 1);
+        assert!(records[0].is_intact());
+        assert!(records[0].previous_hash.is_empty());
+        assert!(records[1].follows(&records[0]));
+        assert!(records[2].follows(&records[1]));
+    }
+
+    #[test]
+    fn chain_should_continue_after_reopening() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = open(&dir, 1024 * 1024, 10);
+        writer.append(event(0, "stream.create")).unwrap();
+        drop(writer);
+
+        let mut writer = open(&dir, 1024 * 1024, 10);
+        writer.append(event(0, "stream.delete")).unwrap();
+
+        let records = writer.query(&AuditLogQuery::default()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(records[1].follows(&records[0]));
+    }
+
+    #[test]
+    fn incomplete_last_line_should_be_removed_on_reopening() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = open(&dir, 1024 * 1024, 10);
+        writer.append(event(0, "stream.create")).unwrap();
+        drop(writer);
+
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(file_path(dir.path(), 1))
+            .unwrap();
+        file.write_all(b"{\"id\":2,").unwrap();
+
+        let mut writer = open(&dir, 1024 * 1024, 10);
+        writer.append(event(0, "stream.delete")).unwrap();
+
+        let records = writer.query(&AuditLogQuery::default()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(records[1].follows(&records[0]));
+    }
+
+    #[test]
+    fn files_should_be_rotated_and_the_oldest_ones_deleted() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = open(&dir, 1, 2);
+        for _ in 0..4 {
+            writer.append(event(0, "stream.create")).unwrap();
+        }
+
+        assert_eq!(list_file_indexes(dir.path()).unwrap(), vec![3, 4]);
+        let records = writer.query(&AuditLogQuery::default()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].id, 3);
+        assert!(records[1].follows(&records[0]));
+    }
+
+    #[test]
+    fn query_should_return_the_most_recent_matching_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = open(&dir, 1024 * 1024, 10);
+        for user_id in [0, 1, 0, 1, 0] {
+            writer.append(event(user_id, "stream.create")).unwrap();
+        }
+
+        let records = writer
+            .query(&AuditLogQuery {
+                user_id: Some(0),
+                limit: Some(2),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(
+            records.iter().map(|record| record.id).collect::<Vec<_>>(),
+            vec![3, 5]
+        );
+    }
+}
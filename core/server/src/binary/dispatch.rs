@@ -16,9 +16,11 @@
  * under the License.
  */
 
+use crate::audit::{self, AuditEvent};
 use crate::binary::handlers;
 use crate::shard::IggyShard;
 use crate::streaming::session::Session;
+use crate::streaming::users::quota_manager::QuotaUsage;
 use bytes::BytesMut;
 use iggy_binary_protocol::RequestFrame;
 use iggy_binary_protocol::codec::WireDecode;
@@ -28,13 +30,16 @@ use iggy_binary_protocol::requests::consumer_offsets::*;
 use iggy_binary_protocol::requests::messages::*;
 use iggy_binary_protocol::requests::partitions::*;
 use iggy_binary_protocol::requests::personal_access_tokens::*;
+use iggy_binary_protocol::requests::roles::*;
 use iggy_binary_protocol::requests::segments::*;
 use iggy_binary_protocol::requests::streams::*;
 use iggy_binary_protocol::requests::system::*;
 use iggy_binary_protocol::requests::topics::*;
+use iggy_binary_protocol::requests::transactions::*;
 use iggy_binary_protocol::requests::users::*;
 use iggy_common::{
-    Consumer, ConsumerKind, Identifier, IggyError, PollingKind, PollingStrategy, SenderKind,
+    Consumer, ConsumerKind, DeadLetterPolicy, Identifier, IggyDuration, IggyError, IsolationLevel,
+    PollingKind, PollingStrategy, SenderKind, SharedSubscription,
 };
 use std::rc::Rc;
 use tracing::{error, warn};
@@ -66,6 +71,18 @@ pub async fn read_payload(sender: &mut SenderKind, length: u32) -> Result<BytesM
     Ok(buffer)
 }
 
+/// Read and drop the payload of a rejected request in bounded chunks.
+async fn discard_payload(sender: &mut SenderKind, length: u32) -> Result<(), IggyError> {
+    const CHUNK_SIZE: u32 = 64 * 1024;
+    let mut remaining = length;
+    while remaining > 0 {
+        let chunk = remaining.min(CHUNK_SIZE);
+        read_payload(sender, chunk).await?;
+        remaining -= chunk;
+    }
+    Ok(())
+}
+
 fn decode<T: WireDecode>(payload: &[u8]) -> Result<T, IggyError> {
     use iggy_binary_protocol::error::WireError;
 
@@ -97,6 +114,25 @@ pub fn wire_id_to_identifier(
     }
 }
 
+/// Convert a `WireDeadLetterPolicy` to the domain `DeadLetterPolicy`.
+pub fn wire_dead_letter_policy_to_policy(
+    wire: &WireDeadLetterPolicy,
+) -> Result<DeadLetterPolicy, IggyError> {
+    Ok(DeadLetterPolicy::new(
+        wire.max_delivery_count,
+        IggyDuration::from(wire.redelivery_timeout),
+        wire_id_to_identifier(&wire.stream_id)?,
+        wire_id_to_identifier(&wire.topic_id)?,
+    ))
+}
+
+/// Convert a `WireSharedSubscription` to the domain `SharedSubscription`.
+pub fn wire_shared_subscription_to_subscription(
+    wire: &WireSharedSubscription,
+) -> SharedSubscription {
+    SharedSubscription::new(IggyDuration::from(wire.visibility_timeout))
+}
+
 /// Convert a `WireConsumer` to the domain `Consumer`.
 pub fn wire_consumer_to_consumer(
     wire: &iggy_binary_protocol::WireConsumer,
@@ -113,6 +149,7 @@ pub fn wire_polling_to_strategy(
     Ok(PollingStrategy {
         kind: PollingKind::from_code(wire.kind)?,
         value: wire.value,
+        isolation_level: IsolationLevel::default(),
     })
 }
 
@@ -132,6 +169,14 @@ pub async fn dispatch_send_messages(
     session: &Session,
     shard: &Rc<IggyShard>,
 ) -> Result<HandlerResult, IggyError> {
+    if let Err(error) = shard.acquire_quota(session, QuotaUsage::Produce(u64::from(payload_length)))
+    {
+        // The messages of a throttled request are not read by the handler, so they have to be
+        // drained to keep the connection in sync.
+        discard_payload(sender, payload_length).await?;
+        return Err(error);
+    }
+
     handlers::messages::send_messages_handler::handle_send_messages(
         sender,
         payload_length,
@@ -148,12 +193,61 @@ pub async fn dispatch_send_messages(
 /// `RequestFrame::from_parts(code, frame.payload)`, and pass it here.
 ///
 /// SendMessages is handled separately via `dispatch_send_messages()`.
-#[allow(clippy::too_many_lines)]
+/// The commands changing the server state and the authentication attempts
+/// are recorded in the audit log, if it's enabled.
 pub async fn dispatch(
     frame: RequestFrame<'_>,
     sender: &mut SenderKind,
     session: &Session,
     shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    // Heartbeats are not throttled, so a client waiting for its quota isn't considered stale.
+    if frame.code != PING_CODE {
+        let usage = if frame.code == POLL_MESSAGES_CODE {
+            QuotaUsage::Fetch
+        } else {
+            QuotaUsage::Request
+        };
+        shard.acquire_quota(session, usage)?;
+    }
+
+    if !shard.audit_log.is_enabled() || !audit::is_audited(frame.code) {
+        return dispatch_command(frame, sender, session, shard).await;
+    }
+
+    let code = frame.code;
+    let request = audit::describe_request(code, frame.payload);
+    // The user is known before the logout and only after the login, so both are captured.
+    let user_id = session.is_authenticated().then(|| session.get_user_id());
+    let scram_username = session.scram_username();
+    let result = dispatch_command(frame, sender, session, shard).await;
+    let user_id = session
+        .is_authenticated()
+        .then(|| session.get_user_id())
+        .or(user_id);
+    let username = user_id
+        .and_then(|id| shard.metadata.get_user(id))
+        .map(|user| user.username.to_string())
+        .or(request.username)
+        .or(scram_username);
+    let operation = command_name(code).unwrap_or("unknown");
+    shard.audit_log.record(AuditEvent {
+        user_id,
+        username,
+        client_id: (session.client_id > 0).then_some(session.client_id),
+        address: Some(session.ip_address.to_string()),
+        details: request.details,
+        ..AuditEvent::new(operation, result.as_ref().map(|_| ()))
+    });
+    result
+}
+
+#[allow(clippy::too_many_lines)]
+async fn dispatch_command(
+    frame: RequestFrame<'_>,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
 ) -> Result<HandlerResult, IggyError> {
     match frame.code {
         // System
@@ -187,6 +281,13 @@ pub async fn dispatch(
             )
             .await
         }
+        GET_AUDIT_LOG_CODE => {
+            let req: GetAuditLogRequest = decode(frame.payload)?;
+            handlers::system::get_audit_log_handler::handle_get_audit_log(
+                req, sender, session, shard,
+            )
+            .await
+        }
 
         // Streams
         GET_STREAM_CODE => {
@@ -266,6 +367,13 @@ pub async fn dispatch(
             )
             .await
         }
+        ROTATE_TOPIC_KEY_CODE => {
+            let req: RotateTopicKeyRequest = decode(frame.payload)?;
+            handlers::topics::rotate_topic_key_handler::handle_rotate_topic_key(
+                req, sender, session, shard,
+            )
+            .await
+        }
 
         // Partitions
         CREATE_PARTITIONS_CODE => {
@@ -307,6 +415,13 @@ pub async fn dispatch(
             )
             .await
         }
+        INIT_PRODUCER_CODE => {
+            let req: InitProducerRequest = decode(frame.payload)?;
+            handlers::messages::init_producer_handler::handle_init_producer(
+                req, sender, session, shard,
+            )
+            .await
+        }
 
         // Consumer Offsets
         GET_CONSUMER_OFFSET_CODE => {
@@ -331,6 +446,29 @@ pub async fn dispatch(
             .await
         }
 
+        // Transactions
+        BEGIN_TRANSACTION_CODE => {
+            let req: BeginTransactionRequest = decode(frame.payload)?;
+            handlers::transactions::begin_transaction_handler::handle_begin_transaction(
+                req, sender, session, shard,
+            )
+            .await
+        }
+        COMMIT_TRANSACTION_CODE => {
+            let req: CommitTransactionRequest = decode(frame.payload)?;
+            handlers::transactions::commit_transaction_handler::handle_commit_transaction(
+                req, sender, session, shard,
+            )
+            .await
+        }
+        ABORT_TRANSACTION_CODE => {
+            let req: AbortTransactionRequest = decode(frame.payload)?;
+            handlers::transactions::abort_transaction_handler::handle_abort_transaction(
+                req, sender, session, shard,
+            )
+            .await
+        }
+
         // Consumer Groups
         GET_CONSUMER_GROUP_CODE => {
             let req: GetConsumerGroupRequest = decode(frame.payload)?;
@@ -346,6 +484,13 @@ pub async fn dispatch(
             )
             .await
         }
+        GET_CONSUMER_GROUP_LAG_CODE => {
+            let req: GetConsumerGroupLagRequest = decode(frame.payload)?;
+            handlers::consumer_groups::get_consumer_group_lag_handler::handle_get_consumer_group_lag(
+                req, sender, session, shard,
+            )
+            .await
+        }
         CREATE_CONSUMER_GROUP_CODE => {
             let req: CreateConsumerGroupRequest = decode(frame.payload)?;
             handlers::consumer_groups::create_consumer_group_handler::handle_create_consumer_group(
@@ -374,6 +519,20 @@ pub async fn dispatch(
             )
             .await
         }
+        NACK_MESSAGE_CODE => {
+            let req: NackMessageRequest = decode(frame.payload)?;
+            handlers::consumer_groups::nack_message_handler::handle_nack_message(
+                req, sender, session, shard,
+            )
+            .await
+        }
+        ACK_MESSAGES_CODE => {
+            let req: AckMessagesRequest = decode(frame.payload)?;
+            handlers::consumer_groups::ack_messages_handler::handle_ack_messages(
+                req, sender, session, shard,
+            )
+            .await
+        }
 
         // Users
         GET_USER_CODE => {
@@ -420,6 +579,77 @@ pub async fn dispatch(
         LOGOUT_USER_CODE => {
             handlers::users::logout_user_handler::handle_logout_user(sender, session, shard).await
         }
+        LOGIN_SCRAM_START_CODE => {
+            let req: LoginScramStartRequest = decode(frame.payload)?;
+            handlers::users::login_scram_start_handler::handle_login_scram_start(
+                req, sender, session, shard,
+            )
+            .await
+        }
+        LOGIN_SCRAM_FINISH_CODE => {
+            let req: LoginScramFinishRequest = decode(frame.payload)?;
+            handlers::users::login_scram_finish_handler::handle_login_scram_finish(
+                req, sender, session, shard,
+            )
+            .await
+        }
+        LOGIN_WITH_TOKEN_CODE => {
+            let req: LoginWithTokenRequest = decode(frame.payload)?;
+            handlers::users::login_with_token_handler::handle_login_with_token(
+                req, sender, session, shard,
+            )
+            .await
+        }
+        SET_USER_QUOTA_CODE => {
+            let req: SetUserQuotaRequest = decode(frame.payload)?;
+            handlers::users::set_user_quota_handler::handle_set_user_quota(
+                req, sender, session, shard,
+            )
+            .await
+        }
+        GET_USER_QUOTAS_CODE => {
+            let req: GetUserQuotasRequest = decode(frame.payload)?;
+            handlers::users::get_user_quotas_handler::handle_get_user_quotas(
+                req, sender, session, shard,
+            )
+            .await
+        }
+
+        // Roles
+        GET_ROLE_CODE => {
+            let req: GetRoleRequest = decode(frame.payload)?;
+            handlers::roles::get_role_handler::handle_get_role(req, sender, session, shard).await
+        }
+        GET_ROLES_CODE => {
+            handlers::roles::get_roles_handler::handle_get_roles(sender, session, shard).await
+        }
+        CREATE_ROLE_CODE => {
+            let req: CreateRoleRequest = decode(frame.payload)?;
+            handlers::roles::create_role_handler::handle_create_role(req, sender, session, shard)
+                .await
+        }
+        DELETE_ROLE_CODE => {
+            let req: DeleteRoleRequest = decode(frame.payload)?;
+            handlers::roles::delete_role_handler::handle_delete_role(req, sender, session, shard)
+                .await
+        }
+        UPDATE_ROLE_CODE => {
+            let req: UpdateRoleRequest = decode(frame.payload)?;
+            handlers::roles::update_role_handler::handle_update_role(req, sender, session, shard)
+                .await
+        }
+        ASSIGN_ROLE_CODE => {
+            let req: AssignRoleRequest = decode(frame.payload)?;
+            handlers::roles::assign_role_handler::handle_assign_role(req, sender, session, shard)
+                .await
+        }
+        UNASSIGN_ROLE_CODE => {
+            let req: UnassignRoleRequest = decode(frame.payload)?;
+            handlers::roles::unassign_role_handler::handle_unassign_role(
+                req, sender, session, shard,
+            )
+            .await
+        }
 
         // Personal Access Tokens
         GET_PERSONAL_ACCESS_TOKENS_CODE => {
@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::{HandlerResult, wire_id_to_identifier};
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::requests::consumer_groups::GetConsumerGroupLagRequest;
+use iggy_binary_protocol::responses::consumer_groups::{
+    ConsumerGroupLagResponse, ConsumerGroupPartitionLagResponse,
+};
+use iggy_common::IggyError;
+use iggy_common::SenderKind;
+use std::rc::Rc;
+use tracing::debug;
+
+pub async fn handle_get_consumer_group_lag(
+    req: GetConsumerGroupLagRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    let stream_id = wire_id_to_identifier(&req.stream_id)?;
+    let topic_id = wire_id_to_identifier(&req.topic_id)?;
+    let group_id = wire_id_to_identifier(&req.group_id)?;
+    debug!(
+        "session: {session}, command: get_consumer_group_lag, stream_id: {stream_id}, topic_id: {topic_id}, group_id: {group_id}"
+    );
+    shard.ensure_authenticated(session)?;
+
+    let Ok(group) = shard.resolve_consumer_group(&stream_id, &topic_id, &group_id) else {
+        sender.send_empty_ok_response().await?;
+        return Ok(HandlerResult::Finished);
+    };
+    let Some(lag) = shard
+        .get_consumer_group_lag(session.get_user_id(), group)
+        .await?
+    else {
+        sender.send_empty_ok_response().await?;
+        return Ok(HandlerResult::Finished);
+    };
+
+    let response = ConsumerGroupLagResponse {
+        group_id: lag.group_id,
+        partitions: lag
+            .partitions
+            .iter()
+            .map(|partition| ConsumerGroupPartitionLagResponse {
+                partition_id: partition.partition_id,
+                committed_offset: partition.committed_offset,
+                log_end_offset: partition.log_end_offset,
+                lag: partition.lag,
+                lag_time: partition.lag_time.as_micros(),
+            })
+            .collect(),
+    };
+    sender.send_ok_response(&response.to_bytes()).await?;
+    Ok(HandlerResult::Finished)
+}
@@ -16,11 +16,14 @@
  * under the License.
  */
 
+pub mod ack_messages_handler;
 pub mod create_consumer_group_handler;
 pub mod delete_consumer_group_handler;
 pub mod get_consumer_group_handler;
+pub mod get_consumer_group_lag_handler;
 pub mod get_consumer_groups_handler;
 pub mod join_consumer_group_handler;
 pub mod leave_consumer_group_handler;
+pub mod nack_message_handler;
 
 pub const COMPONENT: &str = "CONSUMER_GROUP_HANDLER";
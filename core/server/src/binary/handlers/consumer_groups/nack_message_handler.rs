@@ -0,0 +1,56 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::{HandlerResult, wire_id_to_identifier};
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use iggy_binary_protocol::requests::consumer_groups::NackMessageRequest;
+use iggy_common::{IggyError, SenderKind};
+use std::rc::Rc;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_nack_message", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle_nack_message(
+    req: NackMessageRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    let stream_id = wire_id_to_identifier(&req.stream_id)?;
+    let topic_id = wire_id_to_identifier(&req.topic_id)?;
+    let group_id = wire_id_to_identifier(&req.group_id)?;
+    debug!(
+        "session: {session}, command: nack_message, stream_id: {stream_id}, topic_id: {topic_id}, group_id: {group_id}, partition_id: {}, offset: {}",
+        req.partition_id, req.offset
+    );
+    shard.ensure_authenticated(session)?;
+
+    let group = shard.resolve_consumer_group(&stream_id, &topic_id, &group_id)?;
+    shard
+        .nack_message(
+            session.get_user_id(),
+            session.client_id,
+            group,
+            req.partition_id as usize,
+            req.offset,
+            req.reason,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(HandlerResult::Finished)
+}
@@ -42,6 +42,7 @@ pub async fn handle_delete_consumer_offset(
     shard.ensure_authenticated(session)?;
     let topic = shard.resolve_topic_for_delete_consumer_offset(
         session.get_user_id(),
+        &consumer,
         &stream_id,
         &topic_id,
     )?;
@@ -0,0 +1,46 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::HandlerResult;
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::requests::messages::InitProducerRequest;
+use iggy_binary_protocol::responses::messages::InitProducerResponse;
+use iggy_common::IggyError;
+use iggy_common::SenderKind;
+use std::rc::Rc;
+use tracing::debug;
+
+pub async fn handle_init_producer(
+    req: InitProducerRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    debug!("session: {session}, command: init_producer");
+    shard.ensure_authenticated(session)?;
+    let producer_id = (req.producer_id != 0).then_some(req.producer_id);
+    let producer = shard.init_producer(session.client_id, producer_id);
+    let response = InitProducerResponse {
+        producer_id: producer.id,
+        producer_epoch: producer.epoch,
+    };
+    sender.send_ok_response(&response.to_bytes()).await?;
+    Ok(HandlerResult::Finished)
+}
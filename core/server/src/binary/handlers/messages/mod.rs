@@ -17,6 +17,7 @@
  */
 
 pub mod flush_unsaved_buffer_handler;
+pub mod init_producer_handler;
 pub mod poll_messages_handler;
 pub mod send_messages_handler;
 
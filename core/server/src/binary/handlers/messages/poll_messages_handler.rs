@@ -24,8 +24,9 @@ use crate::shard::system::messages::PollingArgs;
 use crate::streaming::session::Session;
 use iggy_binary_protocol::requests::messages::PollMessagesRequest;
 use iggy_common::SenderKind;
-use iggy_common::{IggyError, PooledBuffer};
+use iggy_common::{IggyError, IsolationLevel, MessageFilter, PooledBuffer};
 use std::rc::Rc;
+use std::str::FromStr;
 use tracing::{debug, trace};
 
 pub async fn handle_poll_messages(
@@ -37,17 +38,23 @@ pub async fn handle_poll_messages(
     let consumer = wire_consumer_to_consumer(&req.consumer)?;
     let stream_id = wire_id_to_identifier(&req.stream_id)?;
     let topic_id = wire_id_to_identifier(&req.topic_id)?;
-    let strategy = wire_polling_to_strategy(&req.strategy)?;
+    let strategy = wire_polling_to_strategy(&req.strategy)?
+        .isolation_level(IsolationLevel::from_code(req.isolation_level)?);
     let partition_id = req.partition_id;
     let count = req.count;
     let auto_commit = req.auto_commit;
+    let filter = req
+        .filter
+        .as_deref()
+        .map(MessageFilter::from_str)
+        .transpose()?;
 
     debug!(
         "session: {session}, command: poll_messages, stream_id: {stream_id}, topic_id: {topic_id}, partition_id: {partition_id:?}"
     );
     shard.ensure_authenticated(session)?;
 
-    let args = PollingArgs::new(strategy, count, auto_commit);
+    let args = PollingArgs::new(strategy, count, auto_commit).with_filter(filter);
 
     let user_id = session.get_user_id();
     let client_id = session.client_id;
@@ -56,6 +63,8 @@ pub async fn handle_poll_messages(
         .poll_messages(client_id, topic, consumer, partition_id, args)
         .await?;
 
+    shard.record_fetched_bytes(session, u64::from(batch.size()));
+
     let response_length = 4 + 8 + 4 + batch.size();
     let response_length_bytes = response_length.to_le_bytes();
 
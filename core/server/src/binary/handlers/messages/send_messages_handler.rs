@@ -19,10 +19,13 @@
 use crate::binary::dispatch::HandlerResult;
 use crate::shard::IggyShard;
 use crate::shard::transmission::message::{ResolvedPartition, ShardRequest, ShardRequestPayload};
+use crate::streaming::producers::ProducerSequence;
 use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut};
 use crate::streaming::session::Session;
 use crate::streaming::topics;
 use compio::buf::{IntoInner as _, IoBuf};
+use iggy_binary_protocol::codec::WireDecode;
+use iggy_binary_protocol::requests::messages::WireProducerSequence;
 use iggy_common::Identifier;
 use iggy_common::PooledBuffer;
 use iggy_common::SenderKind;
@@ -95,6 +98,21 @@ pub async fn handle_send_messages(
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
+    element_size += 4;
+
+    // The sequence of an idempotent producer follows the messages count, if present.
+    let producer = match metadata_buf.get(element_size..) {
+        Some(bytes) if bytes.len() >= WireProducerSequence::SIZE => {
+            let producer =
+                WireProducerSequence::decode_from(bytes).map_err(|_| IggyError::InvalidCommand)?;
+            Some(ProducerSequence {
+                producer_id: producer.producer_id,
+                producer_epoch: producer.producer_epoch,
+                base_sequence: producer.base_sequence,
+            })
+        }
+        _ => None,
+    };
     let indexes_size = (messages_count as usize)
         .checked_mul(INDEX_SIZE)
         .ok_or(IggyError::InvalidCommand)?;
@@ -122,6 +140,9 @@ pub async fn handle_send_messages(
     batch.validate()?;
 
     let topic = shard.resolve_topic_for_append(session.get_user_id(), &stream_id, &topic_id)?;
+    if producer.is_some() && partitioning.kind != PartitioningKind::PartitionId {
+        return Err(IggyError::InvalidIdempotentPartitioning);
+    }
 
     let partition_id = match partitioning.kind {
         PartitioningKind::Balanced => shard
@@ -157,8 +178,13 @@ pub async fn handle_send_messages(
         PartitioningKind::Balanced | PartitioningKind::MessagesKey
     );
     let enabled_socket_migration = shard.config.tcp.socket_migration;
+    let transaction_id = shard.client_manager.get_transaction_id(session.client_id);
 
+    // Messages sent within a transaction or by an idempotent producer are not migrated,
+    // as the transfer appends the initial batch without them.
     if enabled_socket_migration
+        && transaction_id.is_none()
+        && producer.is_none()
         && !(session.is_migrated() || unsupported_socket_transfer)
         && let Some(target_shard) = shard.find_shard(&namespace)
         && target_shard.id != shard.id
@@ -197,7 +223,14 @@ pub async fn handle_send_messages(
         topic_id: topic.topic_id,
         partition_id,
     };
-    shard.append_messages(partition, batch).await?;
+    if transaction_id.is_some() {
+        shard
+            .client_manager
+            .add_transaction_partition(session.client_id, namespace)?;
+    }
+    shard
+        .append_messages(partition, batch, transaction_id, producer)
+        .await?;
 
     sender.send_empty_ok_response().await?;
     Ok(HandlerResult::Finished)
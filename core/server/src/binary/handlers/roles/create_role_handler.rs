@@ -0,0 +1,66 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::HandlerResult;
+use crate::binary::handlers::roles::get_role_handler::build_role_details_response;
+use crate::shard::IggyShard;
+use crate::shard::transmission::frame::ShardResponse;
+use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
+use crate::streaming::session::Session;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::requests::roles::CreateRoleRequest;
+use iggy_common::defaults::{MAX_ROLE_NAME_LENGTH, MIN_ROLE_NAME_LENGTH};
+use iggy_common::{IggyError, SenderKind};
+use std::rc::Rc;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_create_role", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle_create_role(
+    req: CreateRoleRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    debug!(
+        "session: {session}, command: create_role, name: {}",
+        req.name.as_str()
+    );
+    shard.ensure_authenticated(session)?;
+    shard.metadata.perm_create_role(session.get_user_id())?;
+
+    let name_len = req.name.as_str().len();
+    if !(MIN_ROLE_NAME_LENGTH..=MAX_ROLE_NAME_LENGTH).contains(&name_len) {
+        return Err(IggyError::InvalidRoleName);
+    }
+
+    let request = ShardRequest::control_plane(ShardRequestPayload::CreateRoleRequest {
+        user_id: session.get_user_id(),
+        command: req,
+    });
+
+    match shard.send_to_control_plane(request).await? {
+        ShardResponse::CreateRoleResponse(role) => {
+            let response = build_role_details_response(&role, Vec::new())?;
+            sender.send_ok_response(&response.to_bytes()).await?;
+        }
+        ShardResponse::ErrorResponse(err) => return Err(err),
+        _ => unreachable!("Expected CreateRoleResponse"),
+    }
+
+    Ok(HandlerResult::Finished)
+}
@@ -0,0 +1,57 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::HandlerResult;
+use crate::shard::IggyShard;
+use crate::shard::transmission::frame::ShardResponse;
+use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
+use crate::streaming::session::Session;
+use iggy_binary_protocol::requests::roles::DeleteRoleRequest;
+use iggy_common::{IggyError, SenderKind};
+use std::rc::Rc;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_delete_role", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle_delete_role(
+    req: DeleteRoleRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    debug!(
+        "session: {session}, command: delete_role, role_id: {:?}",
+        req.role_id
+    );
+    shard.ensure_authenticated(session)?;
+    shard.metadata.perm_delete_role(session.get_user_id())?;
+
+    let request = ShardRequest::control_plane(ShardRequestPayload::DeleteRoleRequest {
+        user_id: session.get_user_id(),
+        command: req,
+    });
+
+    match shard.send_to_control_plane(request).await? {
+        ShardResponse::DeleteRoleResponse => {
+            sender.send_empty_ok_response().await?;
+        }
+        ShardResponse::ErrorResponse(err) => return Err(err),
+        _ => unreachable!("Expected DeleteRoleResponse"),
+    }
+
+    Ok(HandlerResult::Finished)
+}
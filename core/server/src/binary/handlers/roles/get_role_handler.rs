@@ -0,0 +1,79 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::{HandlerResult, wire_id_to_identifier};
+use crate::metadata::{RoleMeta, UserId};
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use iggy_binary_protocol::WireName;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::requests::roles::GetRoleRequest;
+use iggy_binary_protocol::responses::roles::{RoleDetailsResponse, RoleResponse};
+use iggy_common::IggyError;
+use iggy_common::SenderKind;
+use iggy_common::wire_conversions::permissions_to_wire;
+use std::rc::Rc;
+use tracing::debug;
+
+pub async fn handle_get_role(
+    req: GetRoleRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    debug!(
+        "session: {session}, command: get_role, role_id: {:?}",
+        req.role_id
+    );
+    shard.ensure_authenticated(session)?;
+    shard.metadata.perm_get_role(session.get_user_id())?;
+
+    let role_id = wire_id_to_identifier(&req.role_id)?;
+    let Some(role) = shard.find_role(&role_id) else {
+        sender.send_empty_ok_response().await?;
+        return Ok(HandlerResult::Finished);
+    };
+
+    let user_ids = shard.metadata.get_role_user_ids(role.id);
+    let response = build_role_details_response(&role, user_ids)?;
+    sender.send_ok_response(&response.to_bytes()).await?;
+    Ok(HandlerResult::Finished)
+}
+
+pub(crate) fn build_role_response(
+    role: &RoleMeta,
+    users_count: u32,
+) -> Result<RoleResponse, IggyError> {
+    Ok(RoleResponse {
+        id: role.id,
+        created_at: role.created_at.as_micros(),
+        users_count,
+        name: WireName::new(role.name.as_ref()).map_err(|_| IggyError::InvalidCommand)?,
+    })
+}
+
+pub(crate) fn build_role_details_response(
+    role: &RoleMeta,
+    user_ids: Vec<UserId>,
+) -> Result<RoleDetailsResponse, IggyError> {
+    Ok(RoleDetailsResponse {
+        role: build_role_response(role, user_ids.len() as u32)?,
+        permissions: role.permissions.as_deref().map(permissions_to_wire),
+        user_ids,
+    })
+}
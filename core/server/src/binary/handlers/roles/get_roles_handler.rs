@@ -0,0 +1,50 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::HandlerResult;
+use crate::binary::handlers::roles::get_role_handler::build_role_response;
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::responses::roles::{GetRolesResponse, RoleResponse};
+use iggy_common::IggyError;
+use iggy_common::SenderKind;
+use std::rc::Rc;
+use tracing::debug;
+
+pub async fn handle_get_roles(
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    debug!("session: {session}, command: get_roles");
+    shard.ensure_authenticated(session)?;
+    shard.metadata.perm_get_roles(session.get_user_id())?;
+
+    let roles: Vec<RoleResponse> = shard
+        .get_roles()
+        .iter()
+        .map(|role| {
+            let users_count = shard.metadata.get_role_user_ids(role.id).len() as u32;
+            build_role_response(role, users_count)
+        })
+        .collect::<Result<_, IggyError>>()?;
+    let response = GetRolesResponse { roles };
+    sender.send_ok_response(&response.to_bytes()).await?;
+    Ok(HandlerResult::Finished)
+}
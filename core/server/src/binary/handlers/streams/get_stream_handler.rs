@@ -89,6 +89,7 @@ pub(crate) fn build_topic_header(topic: &TopicMeta) -> Result<TopicHeader, IggyE
         compression_algorithm: topic.compression_algorithm.as_code(),
         max_topic_size: topic.max_topic_size.into(),
         replication_factor: topic.replication_factor,
+        cleanup_policy: topic.cleanup_policy.as_code(),
         size_bytes: size,
         messages_count: messages,
         name: WireName::new(topic.name.as_ref()).map_err(|_| IggyError::InvalidCommand)?,
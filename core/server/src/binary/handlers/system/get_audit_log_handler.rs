@@ -0,0 +1,46 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::HandlerResult;
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::requests::system::GetAuditLogRequest;
+use iggy_binary_protocol::responses::system::AuditLogResponse;
+use iggy_common::wire_conversions::audit_record_to_wire;
+use iggy_common::{AuditLogQuery, IggyError, SenderKind};
+use std::rc::Rc;
+use tracing::debug;
+
+pub async fn handle_get_audit_log(
+    req: GetAuditLogRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    debug!("session: {session}, command: get_audit_log");
+    shard.ensure_authenticated(session)?;
+    shard.metadata.perm_get_audit_log(session.get_user_id())?;
+
+    let records = shard.audit_log.query(AuditLogQuery::from(req)).await?;
+    let response = AuditLogResponse {
+        records: records.iter().map(audit_record_to_wire).collect(),
+    };
+    sender.send_ok_response(&response.to_bytes()).await?;
+    Ok(HandlerResult::Finished)
+}
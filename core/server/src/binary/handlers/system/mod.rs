@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod get_audit_log_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
 pub mod get_me_handler;
@@ -21,13 +21,12 @@ use crate::shard::IggyShard;
 use crate::shard::transmission::frame::ShardResponse;
 use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
 use crate::streaming::session::Session;
-use bytes::BytesMut;
 use iggy_binary_protocol::MAX_PARTITIONS_PER_REQUEST;
 use iggy_binary_protocol::WireName;
 use iggy_binary_protocol::codec::WireEncode;
 use iggy_binary_protocol::requests::topics::CreateTopicRequest;
 use iggy_binary_protocol::responses::streams::get_stream::TopicHeader;
-use iggy_binary_protocol::responses::topics::get_topic::PartitionResponse;
+use iggy_binary_protocol::responses::topics::get_topic::{GetTopicResponse, PartitionResponse};
 use iggy_common::{IggyError, SenderKind};
 use std::rc::Rc;
 use tracing::{debug, instrument};
@@ -57,7 +56,7 @@ pub async fn handle_create_topic(
 
     match shard.send_to_control_plane(request).await? {
         ShardResponse::CreateTopicResponse(data) => {
-            let header = TopicHeader {
+            let topic = TopicHeader {
                 id: data.id,
                 created_at: data.created_at.into(),
                 partitions_count: data.partitions.len() as u32,
@@ -65,6 +64,7 @@ pub async fn handle_create_topic(
                 compression_algorithm: data.compression_algorithm.as_code(),
                 max_topic_size: data.max_topic_size.into(),
                 replication_factor: data.replication_factor,
+                cleanup_policy: data.cleanup_policy.as_code(),
                 size_bytes: 0,
                 messages_count: 0,
                 name: WireName::new(data.name.as_ref()).map_err(|_| IggyError::InvalidCommand)?,
@@ -82,18 +82,8 @@ pub async fn handle_create_topic(
                 })
                 .collect();
 
-            let mut buf = BytesMut::with_capacity(
-                header.encoded_size()
-                    + partitions
-                        .iter()
-                        .map(WireEncode::encoded_size)
-                        .sum::<usize>(),
-            );
-            header.encode(&mut buf);
-            for partition in &partitions {
-                partition.encode(&mut buf);
-            }
-            sender.send_ok_response(&buf.freeze()).await?;
+            let response = GetTopicResponse { topic, partitions };
+            sender.send_ok_response(&response.to_bytes()).await?;
         }
         ShardResponse::ErrorResponse(err) => return Err(err),
         _ => unreachable!("Expected CreateTopicResponse"),
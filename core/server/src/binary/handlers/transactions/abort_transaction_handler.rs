@@ -0,0 +1,52 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::HandlerResult;
+use crate::binary::handlers::transactions::COMPONENT;
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use err_trail::ErrContext;
+use iggy_binary_protocol::requests::transactions::AbortTransactionRequest;
+use iggy_common::IggyError;
+use iggy_common::SenderKind;
+use std::rc::Rc;
+use tracing::debug;
+
+pub async fn handle_abort_transaction(
+    req: AbortTransactionRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    debug!(
+        "session: {session}, command: abort_transaction, transaction_id: {}",
+        req.transaction_id
+    );
+    shard.ensure_authenticated(session)?;
+    shard
+        .abort_transaction(session.client_id, req.transaction_id)
+        .await
+        .error(|e: &IggyError| {
+            format!(
+                "{COMPONENT} (error: {e}) - failed to abort transaction with ID: {}, session: {session}",
+                req.transaction_id
+            )
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(HandlerResult::Finished)
+}
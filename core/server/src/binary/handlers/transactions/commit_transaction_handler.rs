@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::{HandlerResult, wire_consumer_to_consumer, wire_id_to_identifier};
+use crate::binary::handlers::transactions::COMPONENT;
+use crate::shard::IggyShard;
+use crate::shard::system::transactions::TransactionOffsetArgs;
+use crate::streaming::session::Session;
+use err_trail::ErrContext;
+use iggy_binary_protocol::requests::transactions::CommitTransactionRequest;
+use iggy_common::IggyError;
+use iggy_common::SenderKind;
+use std::rc::Rc;
+use tracing::debug;
+
+pub async fn handle_commit_transaction(
+    req: CommitTransactionRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    debug!(
+        "session: {session}, command: commit_transaction, transaction_id: {}, offsets: {}",
+        req.transaction_id,
+        req.offsets.len()
+    );
+    shard.ensure_authenticated(session)?;
+
+    let mut offsets = Vec::with_capacity(req.offsets.len());
+    for offset in &req.offsets {
+        let stream_id = wire_id_to_identifier(&offset.stream_id)?;
+        let topic_id = wire_id_to_identifier(&offset.topic_id)?;
+        let consumer = wire_consumer_to_consumer(&offset.consumer)?;
+        let topic = shard.resolve_topic_for_store_consumer_offset(
+            session.get_user_id(),
+            &consumer,
+            &stream_id,
+            &topic_id,
+        )?;
+        offsets.push(TransactionOffsetArgs {
+            consumer,
+            topic,
+            partition_id: offset.partition_id,
+            offset: offset.offset,
+        });
+    }
+
+    shard
+        .commit_transaction(session.client_id, req.transaction_id, offsets)
+        .await
+        .error(|e: &IggyError| {
+            format!(
+                "{COMPONENT} (error: {e}) - failed to commit transaction with ID: {}, session: {session}",
+                req.transaction_id
+            )
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(HandlerResult::Finished)
+}
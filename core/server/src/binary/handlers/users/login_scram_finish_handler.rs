@@ -0,0 +1,62 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::HandlerResult;
+use crate::binary::handlers::users::COMPONENT;
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use err_trail::ErrContext;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::requests::users::LoginScramFinishRequest;
+use iggy_binary_protocol::responses::users::LoginScramFinishResponse;
+use iggy_common::IggyError;
+use iggy_common::SenderKind;
+use std::rc::Rc;
+use tracing::{debug, info, instrument, warn};
+
+#[instrument(skip_all, name = "trace_login_scram_finish", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle_login_scram_finish(
+    req: LoginScramFinishRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    if shard.is_shutting_down() {
+        warn!("Rejecting login request during shutdown");
+        return Err(IggyError::Disconnected);
+    }
+
+    debug!("session: {session}, command: login_scram_finish");
+
+    let (user, server_signature) = shard
+        .finish_scram_login(&req.nonce, &req.client_proof, session)
+        .error(|e: &IggyError| {
+            format!("{COMPONENT} (error: {e}) - failed to finish SCRAM login, session: {session}")
+        })?;
+    info!(
+        "Logged in user: {} with ID: {} using SCRAM.",
+        user.username, user.id
+    );
+
+    let response = LoginScramFinishResponse {
+        user_id: user.id,
+        server_signature: server_signature.to_vec(),
+    };
+    sender.send_ok_response(&response.to_bytes()).await?;
+    Ok(HandlerResult::Finished)
+}
@@ -0,0 +1,77 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::dispatch::HandlerResult;
+use crate::binary::handlers::users::COMPONENT;
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use err_trail::ErrContext;
+use iggy_binary_protocol::codec::WireEncode;
+use iggy_binary_protocol::requests::users::LoginScramStartRequest;
+use iggy_binary_protocol::responses::users::LoginScramStartResponse;
+use iggy_common::IggyError;
+use iggy_common::SenderKind;
+use iggy_common::defaults::{MAX_USERNAME_LENGTH, MIN_USERNAME_LENGTH};
+use std::rc::Rc;
+use tracing::{debug, instrument, warn};
+
+// Leaves room for the server part of the combined nonce, which is sent back with a u8 length.
+const MAX_CLIENT_NONCE_LENGTH: usize = 128;
+
+#[instrument(skip_all, name = "trace_login_scram_start", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle_login_scram_start(
+    req: LoginScramStartRequest,
+    sender: &mut SenderKind,
+    session: &Session,
+    shard: &Rc<IggyShard>,
+) -> Result<HandlerResult, IggyError> {
+    if shard.is_shutting_down() {
+        warn!("Rejecting login request during shutdown");
+        return Err(IggyError::Disconnected);
+    }
+
+    let username = req.username.as_str();
+    let username_len = username.len();
+    if !(MIN_USERNAME_LENGTH..=MAX_USERNAME_LENGTH).contains(&username_len) {
+        return Err(IggyError::InvalidUsername);
+    }
+    if req.client_nonce.is_empty()
+        || req.client_nonce.len() > MAX_CLIENT_NONCE_LENGTH
+        || req.client_nonce.contains(',')
+    {
+        return Err(IggyError::InvalidFormat);
+    }
+
+    debug!("session: {session}, command: login_scram_start, username: {username}");
+
+    let challenge = shard
+        .start_scram_login(username, &req.client_nonce, session)
+        .error(|e: &IggyError| {
+            format!(
+                "{COMPONENT} (error: {e}) - failed to start SCRAM login for user with name: {username}, session: {session}",
+            )
+        })?;
+
+    let response = LoginScramStartResponse {
+        nonce: challenge.nonce,
+        salt: challenge.salt,
+        iterations: challenge.iterations,
+    };
+    sender.send_ok_response(&response.to_bytes()).await?;
+    Ok(HandlerResult::Finished)
+}
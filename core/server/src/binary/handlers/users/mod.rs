@@ -20,9 +20,14 @@ pub mod change_password_handler;
 pub mod create_user_handler;
 pub mod delete_user_handler;
 pub mod get_user_handler;
+pub mod get_user_quotas_handler;
 pub mod get_users_handler;
+pub mod login_scram_finish_handler;
+pub mod login_scram_start_handler;
 pub mod login_user_handler;
+pub mod login_with_token_handler;
 pub mod logout_user_handler;
+pub mod set_user_quota_handler;
 pub mod update_permissions_handler;
 pub mod update_user_handler;
 
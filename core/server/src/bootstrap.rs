@@ -23,10 +23,13 @@ use crate::{
     configs::{
         cache_indexes::CacheIndexesConfig,
         server::ServerConfig,
-        system::{INDEX_EXTENSION, LOG_EXTENSION, SystemConfig},
+        system::{INDEX_EXTENSION, LOG_EXTENSION, OFFLOADED_EXTENSION, SystemConfig},
     },
+    encryption::ServerEncryption,
     io::fs_utils::{self, DirEntry},
-    metadata::{ConsumerGroupMeta, MetadataWriter, PartitionMeta, StreamMeta, TopicMeta, UserMeta},
+    metadata::{
+        ConsumerGroupMeta, MetadataWriter, PartitionMeta, RoleMeta, StreamMeta, TopicMeta, UserMeta,
+    },
     server_error::ServerError,
     shard::{
         system::info::SystemInfo,
@@ -36,14 +39,15 @@ use crate::{
         },
     },
     shard_allocator::ShardInfo,
-    state::system::{StreamState, TopicState, UserState},
+    state::system::{RoleState, StreamState, TopicState, UserState},
     streaming::{
+        deliveries::DeliveryPolicy,
         partitions::{
             consumer_group_offsets::ConsumerGroupOffsets, consumer_offsets::ConsumerOffsets,
             journal::MemoryMessageJournal, log::SegmentedLog,
         },
         persistence::persister::{FilePersister, FileWithSyncPersister, PersisterKind},
-        segments::{Segment, storage::Storage},
+        segments::{Segment, compaction, storage::Storage},
         stats::{PartitionStats, StreamStats, TopicStats},
         storage::SystemStorage,
         users::user::User,
@@ -62,7 +66,7 @@ use iggy_common::{
 };
 use slab::Slab;
 use std::{env, path::Path, sync::Arc};
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
 pub fn create_shard_connections(
     shard_assignment: &[ShardInfo],
@@ -213,6 +217,7 @@ pub async fn update_system_info(
     Ok(())
 }
 
+/// Collects the messages files and the markers of the offloaded segments.
 async fn collect_log_files(partition_path: &str) -> Result<Vec<DirEntry>, IggyError> {
     let dir_entries = fs_utils::walk_dir(&partition_path)
         .await
@@ -224,7 +229,9 @@ async fn collect_log_files(partition_path: &str) -> Result<Vec<DirEntry>, IggyEr
         }
 
         let extension = entry.path.extension();
-        if extension.is_none() || extension.unwrap() != LOG_EXTENSION {
+        if extension.is_none()
+            || (extension.unwrap() != LOG_EXTENSION && extension.unwrap() != OFFLOADED_EXTENSION)
+        {
             continue;
         }
 
@@ -244,6 +251,9 @@ pub async fn load_segments(
 ) -> Result<SegmentedLog<MemoryMessageJournal>, IggyError> {
     let mut log_files = collect_log_files(&partition_path).await?;
     log_files.sort_by(|a, b| a.path.file_name().cmp(&b.path.file_name()));
+    // The messages file is sorted before the marker of the same segment, whose offloading
+    // didn't complete then.
+    log_files.dedup_by(|b, a| a.path.file_stem() == b.path.file_stem());
     let mut log = SegmentedLog::new(MemoryMessageJournal::empty());
     for entry in log_files {
         let log_file_name = entry
@@ -258,6 +268,8 @@ pub async fn load_segments(
         let messages_file_path = format!("{}/{}.{}", partition_path, log_file_name, LOG_EXTENSION);
         let index_file_path = format!("{}/{}.{}", partition_path, log_file_name, INDEX_EXTENSION);
 
+        compaction::recover_interrupted_compaction(&messages_file_path, &index_file_path).await?;
+
         async fn try_exists(path: &str) -> Result<bool, std::io::Error> {
             match compio::fs::metadata(path).await {
                 Ok(_) => Ok(true),
@@ -268,12 +280,35 @@ pub async fn load_segments(
             }
         }
 
+        let marker_file_path = format!(
+            "{}/{}.{}",
+            partition_path, log_file_name, OFFLOADED_EXTENSION
+        );
+        let offloaded = !try_exists(&messages_file_path).await.unwrap();
+        if !offloaded && try_exists(&marker_file_path).await.unwrap() {
+            warn!(
+                "Messages file {} of the offloaded segment still exists, removing the marker...",
+                messages_file_path
+            );
+            compio::fs::remove_file(&marker_file_path)
+                .await
+                .map_err(|_| IggyError::CannotDeleteFile)?;
+        }
+
         let index_path_exists = try_exists(&index_file_path).await.unwrap();
         let index_cache_enabled = matches!(
             config.segment.cache_indexes,
             CacheIndexesConfig::All | CacheIndexesConfig::OpenSegment
         );
 
+        if offloaded && !index_path_exists {
+            error!(
+                "Index at path {} of the offloaded segment does not exist, it cannot be rebuilt locally.",
+                index_file_path
+            );
+            return Err(IggyError::CannotReadPartitions);
+        }
+
         if index_cache_enabled && !index_path_exists {
             warn!(
                 "Index at path {} does not exist, rebuilding it based on {}...",
@@ -298,26 +333,28 @@ pub async fn load_segments(
             );
         }
 
-        let messages_metadata = compio::fs::metadata(&messages_file_path)
-            .await
-            .map_err(|_| IggyError::CannotReadPartitions)?;
-        let messages_size = messages_metadata.len() as u32;
-
         let index_size = match compio::fs::metadata(&index_file_path).await {
             Ok(metadata) => metadata.len() as u32,
             Err(_) => 0, // Default to 0 if index file doesn't exist
         };
 
-        let storage = Storage::new(
-            &messages_file_path,
-            &index_file_path,
-            messages_size as u64,
-            index_size as u64,
-            config.partition.enforce_fsync,
-            config.partition.enforce_fsync,
-            true,
-        )
-        .await?;
+        let storage = if offloaded {
+            Storage::offloaded(&index_file_path, index_size as u64).await?
+        } else {
+            let messages_metadata = compio::fs::metadata(&messages_file_path)
+                .await
+                .map_err(|_| IggyError::CannotReadPartitions)?;
+            Storage::new(
+                &messages_file_path,
+                &index_file_path,
+                messages_metadata.len(),
+                index_size as u64,
+                config.partition.enforce_fsync,
+                config.partition.enforce_fsync,
+                true,
+            )
+            .await?
+        };
 
         let loaded_indexes = {
             storage.
@@ -330,6 +367,13 @@ pub async fn load_segments(
             .map_err(|_| IggyError::CannotReadFile)?
         };
 
+        // The messages of an offloaded segment end at the position of its last index.
+        let messages_size = match &storage.messages_reader {
+            Some(messages_reader) => messages_reader.file_size(),
+            None if loaded_indexes.count() == 0 => 0,
+            None => loaded_indexes.last_position(),
+        };
+
         let end_offset = if loaded_indexes.count() == 0 {
             start_offset
         } else {
@@ -355,8 +399,9 @@ pub async fn load_segments(
         // At segment load, set the current position to the size of the segment (No data is buffered yet).
         segment.current_position = segment.size.as_bytes_u32();
         segment.sealed = true; // Persisted segments are assumed to be sealed
+        segment.offloaded = offloaded;
 
-        if config.partition.validate_checksum {
+        if config.partition.validate_checksum && !offloaded {
             info!(
                 "Validating checksum for segment at offset {} in stream ID: {}, topic ID: {}, partition ID: {}",
                 start_offset, stream_id, topic_id, partition_id
@@ -411,9 +456,8 @@ pub async fn load_segments(
 
         stats.increment_size_bytes(messages_size as u64);
 
-        let messages_count = if end_offset > start_offset {
-            (end_offset - start_offset + 1) as u64
-        } else if messages_size > 0 {
+        // Count the indexes rather than the offset range, compacted segments have offset gaps.
+        let messages_count = if messages_size > 0 {
             loaded_indexes.count() as u64
         } else {
             0
@@ -464,11 +508,13 @@ pub async fn load_segments(
     Ok(log)
 }
 
-/// Builds `InnerMetadata` from persisted user and stream state.
+/// Builds `InnerMetadata` from persisted user, stream and role state.
 pub fn build_inner_metadata(
     users_state: impl IntoIterator<Item = UserState>,
     streams_state: impl IntoIterator<Item = StreamState>,
-) -> crate::metadata::InnerMetadata {
+    roles_state: impl IntoIterator<Item = RoleState>,
+    encryption: Option<&ServerEncryption>,
+) -> Result<crate::metadata::InnerMetadata, IggyError> {
     use crate::metadata::InnerMetadata;
     use std::sync::atomic::AtomicUsize;
 
@@ -485,9 +531,13 @@ pub fn build_inner_metadata(
         id,
         username,
         password_hash,
+        scram_credentials,
         status,
         created_at,
         permissions,
+        user_quota,
+        client_quota,
+        roles,
         personal_access_tokens: user_pats,
     } in users_state
     {
@@ -496,8 +546,12 @@ pub fn build_inner_metadata(
             id,
             username: username_arc.clone(),
             password_hash: Arc::from(password_hash.as_str()),
+            scram_credentials: scram_credentials.map(Arc::new),
             status,
             permissions: permissions.map(Arc::new),
+            roles,
+            user_quota,
+            client_quota,
             created_at,
         };
         user_entries.push((id as usize, user_meta));
@@ -527,6 +581,27 @@ pub fn build_inner_metadata(
         users_count, pats_count
     );
 
+    let mut role_entries = Vec::new();
+    let mut role_index = ahash::AHashMap::default();
+    for RoleState {
+        id,
+        name,
+        permissions,
+        created_at,
+    } in roles_state
+    {
+        let name: Arc<str> = Arc::from(name.as_str());
+        let role_meta = RoleMeta {
+            id,
+            name: name.clone(),
+            permissions: permissions.map(Arc::new),
+            created_at,
+        };
+        role_entries.push((id as usize, role_meta));
+        role_index.insert(name, id);
+    }
+    info!("Building metadata: {} roles", role_entries.len());
+
     let mut stream_entries = Vec::new();
     let mut stream_index = ahash::AHashMap::default();
     let mut streams_count = 0;
@@ -561,8 +636,10 @@ pub fn build_inner_metadata(
             message_expiry,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
             consumer_groups,
             partitions,
+            encryption_keys,
         } in topics.into_values()
         {
             info!("Building topic with ID: {}, name: {} metadata...", id, name);
@@ -609,12 +686,18 @@ pub fn build_inner_metadata(
                     name: group_name.clone(),
                     partitions: partition_ids.clone(),
                     members: Slab::new(),
+                    delivery: DeliveryPolicy::new(cg_state.dead_letter, cg_state.shared)
+                        .map(Arc::new),
                 };
                 cg_entries.push((group_id, cg_meta));
                 cg_index.insert(group_name, group_id);
                 consumer_groups_count += 1;
             }
 
+            let encryptor = match encryption {
+                Some(encryption) => encryption.topic_encryptor(&encryption_keys)?,
+                None => None,
+            };
             let topic_meta = TopicMeta {
                 id: topic_id,
                 name: topic_name.clone(),
@@ -623,11 +706,14 @@ pub fn build_inner_metadata(
                 compression_algorithm,
                 max_topic_size,
                 replication_factor: replication_factor.unwrap_or(1),
+                cleanup_policy,
                 stats: topic_stats,
                 partitions: partition_entries.into_iter().map(|(_, p)| p).collect(),
                 consumer_groups: cg_entries.into_iter().collect(),
                 consumer_group_index: cg_index,
                 round_robin_counter: Arc::new(AtomicUsize::new(0)),
+                encryption_keys,
+                encryptor,
             };
             topic_entries.push((topic_id, topic_meta));
             topic_index.insert(topic_name, topic_id);
@@ -652,11 +738,13 @@ pub fn build_inner_metadata(
         streams_count, topics_count, partitions_count, consumer_groups_count
     );
 
-    InnerMetadata {
+    Ok(InnerMetadata {
         streams: stream_entries.into_iter().collect(),
         users: user_entries.into_iter().collect(),
+        roles: role_entries.into_iter().collect(),
         stream_index,
         user_index,
+        role_index,
         personal_access_tokens,
         users_global_permissions: Default::default(),
         users_stream_permissions: Default::default(),
@@ -664,7 +752,7 @@ pub fn build_inner_metadata(
         users_can_send_all_streams: Default::default(),
         users_can_poll_stream: Default::default(),
         users_can_send_stream: Default::default(),
-    }
+    })
 }
 
 /// Loads all metadata from persisted state into metadata writer.
@@ -672,8 +760,11 @@ pub fn build_inner_metadata(
 pub fn load_metadata(
     users_state: impl IntoIterator<Item = UserState>,
     streams_state: impl IntoIterator<Item = StreamState>,
+    roles_state: impl IntoIterator<Item = RoleState>,
+    encryption: Option<&ServerEncryption>,
     writer: &mut MetadataWriter,
-) {
-    let inner = build_inner_metadata(users_state, streams_state);
+) -> Result<(), IggyError> {
+    let inner = build_inner_metadata(users_state, streams_state, roles_state, encryption)?;
     writer.initialize(inner);
+    Ok(())
 }
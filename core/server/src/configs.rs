@@ -19,5 +19,5 @@
 
 pub use configs::{
     COMPONENT, cache_indexes, cluster, defaults, displays, http, quic, server, sharding, system,
-    tcp, validators, websocket,
+    tcp, tls, validators, websocket,
 };
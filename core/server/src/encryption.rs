@@ -0,0 +1,91 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::EncryptionConfig;
+use iggy_common::{
+    Aes256GcmEncryptor, EncryptorKind, EnvelopeEncryptor, IggyError, KeyWrapperKind,
+    LocalKeyWrapper, generate_encryption_key,
+};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::error;
+
+/// Server-side encryption of the messages and the state commands.
+///
+/// The configured `key` encrypts the state log and the topics which have never had their
+/// data encryption key rotated. Each rotation generates a new per-topic key, which is stored
+/// wrapped by the master key, while the previous keys are kept to read the older messages.
+#[derive(Debug, Clone)]
+pub struct ServerEncryption {
+    default: Aes256GcmEncryptor,
+    key_wrapper: KeyWrapperKind,
+}
+
+impl ServerEncryption {
+    pub fn from_config(config: &EncryptionConfig) -> Result<Option<Self>, IggyError> {
+        if !config.enabled {
+            return Ok(None);
+        }
+
+        let default = Aes256GcmEncryptor::from_base64_key(&config.key)?;
+        let master_key = if config.master_key_path.is_empty() {
+            default.clone()
+        } else {
+            let master_key = std::fs::read_to_string(&config.master_key_path).map_err(|error| {
+                error!(
+                    "Cannot read the master key from file: {}. {error}",
+                    config.master_key_path
+                );
+                IggyError::CannotReadFile
+            })?;
+            Aes256GcmEncryptor::from_base64_key(master_key.trim())?
+        };
+
+        Ok(Some(Self {
+            default,
+            key_wrapper: KeyWrapperKind::Local(LocalKeyWrapper::new(master_key)),
+        }))
+    }
+
+    /// Returns the encryptor used for the state log and the topics without their own keys.
+    pub fn default_encryptor(&self) -> EncryptorKind {
+        EncryptorKind::Aes256Gcm(self.default.clone())
+    }
+
+    /// Builds the encryptor of a topic from its wrapped data encryption keys.
+    pub fn topic_encryptor(
+        &self,
+        wrapped_keys: &BTreeMap<u32, Vec<u8>>,
+    ) -> Result<Option<Arc<EnvelopeEncryptor>>, IggyError> {
+        if wrapped_keys.is_empty() {
+            return Ok(None);
+        }
+
+        let keys = wrapped_keys
+            .iter()
+            .map(|(key_id, wrapped_key)| Ok((*key_id, self.key_wrapper.unwrap_key(wrapped_key)?)))
+            .collect::<Result<BTreeMap<_, _>, IggyError>>()?;
+        let encryptor = EnvelopeEncryptor::new(keys, self.default.clone())?;
+        Ok(Some(Arc::new(encryptor)))
+    }
+
+    /// Generates a new data encryption key, wrapped by the master key.
+    pub fn generate_wrapped_key(&self) -> Result<Vec<u8>, IggyError> {
+        self.key_wrapper.wrap_key(&generate_encryption_key())
+    }
+}
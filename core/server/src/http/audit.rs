@@ -0,0 +1,147 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::audit::AuditEvent;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::{AppState, RequestDetails};
+use axum::body::Body;
+use axum::extract::{MatchedPath, State};
+use axum::http::{Method, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use iggy_binary_protocol::codes::*;
+use iggy_common::{IggyError, UserId};
+use std::sync::Arc;
+
+/// Records the requests changing the server state in the audit log, using the same
+/// operation names as the binary protocol. The logins are recorded by their handlers,
+/// as only they know the name of the user attempting to authenticate.
+pub async fn audit(
+    State(state): State<Arc<AppState>>,
+    matched_path: Option<MatchedPath>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let audit_log = state.shard.shard().audit_log.clone();
+    if !audit_log.is_enabled() {
+        return next.run(request).await;
+    }
+
+    let Some(operation) = matched_path.and_then(|path| operation(request.method(), path.as_str()))
+    else {
+        return next.run(request).await;
+    };
+
+    let user_id = request
+        .extensions()
+        .get::<Identity>()
+        .map(|identity| identity.user_id);
+    let address = request
+        .extensions()
+        .get::<RequestDetails>()
+        .map(|details| details.ip_address.to_string());
+    let details = format!("{} {}", request.method(), request.uri().path());
+    let response = next.run(request).await;
+
+    let error = response
+        .extensions()
+        .get::<IggyError>()
+        .cloned()
+        .or_else(|| (!response.status().is_success()).then_some(IggyError::Error));
+    audit_log.record(AuditEvent {
+        user_id,
+        username: find_username(&state, user_id),
+        address,
+        details: Some(details),
+        ..AuditEvent::new(operation, error.as_ref().map_or(Ok(()), Err))
+    });
+    response
+}
+
+/// Records the login attempt made with the HTTP API.
+pub fn record_login(
+    state: &AppState,
+    request_details: &RequestDetails,
+    code: u32,
+    username: Option<&str>,
+    result: Result<UserId, &IggyError>,
+) {
+    let audit_log = &state.shard.shard().audit_log;
+    if !audit_log.is_enabled() {
+        return;
+    }
+
+    let user_id = result.ok();
+    audit_log.record(AuditEvent {
+        user_id,
+        username: username
+            .map(ToString::to_string)
+            .or_else(|| find_username(state, user_id)),
+        address: Some(request_details.ip_address.to_string()),
+        ..AuditEvent::new(command_name(code).unwrap_or("unknown"), result.map(|_| ()))
+    });
+}
+
+fn find_username(state: &AppState, user_id: Option<UserId>) -> Option<String> {
+    user_id
+        .and_then(|id| state.shard.shard().metadata.get_user(id))
+        .map(|user| user.username.to_string())
+}
+
+fn operation(method: &Method, path: &str) -> Option<&'static str> {
+    const STREAM: &str = "/streams/{stream_id}";
+    const TOPICS: &str = "/streams/{stream_id}/topics";
+    const TOPIC: &str = "/streams/{stream_id}/topics/{topic_id}";
+    const PARTITIONS: &str = "/streams/{stream_id}/topics/{topic_id}/partitions";
+    const PARTITION: &str = "/streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}";
+    const CONSUMER_GROUPS: &str = "/streams/{stream_id}/topics/{topic_id}/consumer-groups";
+    const CONSUMER_GROUP: &str =
+        "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}";
+
+    let code = match (method.as_str(), path) {
+        ("POST", "/streams") => CREATE_STREAM_CODE,
+        ("PUT", STREAM) => UPDATE_STREAM_CODE,
+        ("DELETE", STREAM) => DELETE_STREAM_CODE,
+        ("DELETE", "/streams/{stream_id}/purge") => PURGE_STREAM_CODE,
+        ("POST", TOPICS) => CREATE_TOPIC_CODE,
+        ("PUT", TOPIC) => UPDATE_TOPIC_CODE,
+        ("DELETE", TOPIC) => DELETE_TOPIC_CODE,
+        ("DELETE", "/streams/{stream_id}/topics/{topic_id}/purge") => PURGE_TOPIC_CODE,
+        ("POST", "/streams/{stream_id}/topics/{topic_id}/rotate-key") => ROTATE_TOPIC_KEY_CODE,
+        ("POST", PARTITIONS) => CREATE_PARTITIONS_CODE,
+        ("DELETE", PARTITIONS) => DELETE_PARTITIONS_CODE,
+        ("DELETE", PARTITION) => DELETE_SEGMENTS_CODE,
+        ("POST", CONSUMER_GROUPS) => CREATE_CONSUMER_GROUP_CODE,
+        ("DELETE", CONSUMER_GROUP) => DELETE_CONSUMER_GROUP_CODE,
+        ("POST", "/users") => CREATE_USER_CODE,
+        ("PUT", "/users/{user_id}") => UPDATE_USER_CODE,
+        ("DELETE", "/users/{user_id}") => DELETE_USER_CODE,
+        ("PUT", "/users/{user_id}/permissions") => UPDATE_PERMISSIONS_CODE,
+        ("PUT", "/users/{user_id}/password") => CHANGE_PASSWORD_CODE,
+        ("DELETE", "/users/logout") => LOGOUT_USER_CODE,
+        ("POST", "/roles") => CREATE_ROLE_CODE,
+        ("PUT", "/roles/{role_id}") => UPDATE_ROLE_CODE,
+        ("DELETE", "/roles/{role_id}") => DELETE_ROLE_CODE,
+        ("PUT", "/users/{user_id}/roles/{role_id}") => ASSIGN_ROLE_CODE,
+        ("DELETE", "/users/{user_id}/roles/{role_id}") => UNASSIGN_ROLE_CODE,
+        ("POST", "/personal-access-tokens") => CREATE_PERSONAL_ACCESS_TOKEN_CODE,
+        ("DELETE", "/personal-access-tokens/{name}") => DELETE_PERSONAL_ACCESS_TOKEN_CODE,
+        _ => return None,
+    };
+    command_name(code).ok()
+}
@@ -35,8 +35,11 @@ use iggy_binary_protocol::requests::consumer_groups::{
 use iggy_common::Identifier;
 use iggy_common::Validatable;
 use iggy_common::create_consumer_group::CreateConsumerGroup;
-use iggy_common::wire_conversions::identifier_to_wire;
-use iggy_common::{ConsumerGroup, ConsumerGroupDetails, IggyError};
+use iggy_common::wire_conversions::{
+    dead_letter_policy_to_wire, identifier_to_wire, shared_subscription_to_wire,
+};
+use iggy_common::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupLag, IggyError};
+use send_wrapper::SendWrapper;
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -50,6 +53,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
             get(get_consumer_group).delete(delete_consumer_group),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}/lag",
+            get(get_consumer_group_lag),
+        )
         .with_state(state)
 }
 
@@ -83,6 +90,30 @@ async fn get_consumer_group(
     Ok(Json(consumer_group))
 }
 
+async fn get_consumer_group_lag(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, group_id)): Path<(String, String, String)>,
+) -> Result<Json<ConsumerGroupLag>, CustomError> {
+    let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
+    let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
+    let identifier_group_id = Identifier::from_str_value(&group_id)?;
+
+    let group = state.shard.shard().resolve_consumer_group(
+        &identifier_stream_id,
+        &identifier_topic_id,
+        &identifier_group_id,
+    )?;
+    let lag_future = SendWrapper::new(
+        state
+            .shard
+            .shard()
+            .get_consumer_group_lag(identity.user_id, group),
+    );
+    let lag = lag_future.await?.ok_or(CustomError::ResourceNotFound)?;
+    Ok(Json(lag))
+}
+
 async fn get_consumer_groups(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -126,6 +157,12 @@ async fn create_consumer_group(
         stream_id: identifier_to_wire(&command.stream_id)?,
         topic_id: identifier_to_wire(&command.topic_id)?,
         name: WireName::new(&command.name).map_err(|_| IggyError::InvalidConsumerGroupName)?,
+        dead_letter: command
+            .dead_letter
+            .as_ref()
+            .map(dead_letter_policy_to_wire)
+            .transpose()?,
+        shared: command.shared.as_ref().map(shared_subscription_to_wire),
     };
     let request = ShardRequest::control_plane(ShardRequestPayload::CreateConsumerGroupRequest {
         user_id: identity.user_id,
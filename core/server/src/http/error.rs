@@ -63,7 +63,11 @@ impl IntoResponse for CustomError {
                     IggyError::Unauthorized => StatusCode::FORBIDDEN,
                     _ => StatusCode::BAD_REQUEST,
                 };
-                (status_code, Json(ErrorResponse::from_error(error)))
+                let mut response =
+                    (status_code, Json(ErrorResponse::from_error(error.clone()))).into_response();
+                // The error is exposed to the middlewares, e.g. to be recorded in the audit log.
+                response.extensions_mut().insert(error);
+                response
             }
             CustomError::ResourceNotFound => (
                 StatusCode::NOT_FOUND,
@@ -73,9 +77,9 @@ impl IntoResponse for CustomError {
                     reason: "Resource not found".to_string(),
                     field: None,
                 }),
-            ),
+            )
+                .into_response(),
         }
-        .into_response()
     }
 }
 
@@ -101,6 +105,8 @@ impl ErrorResponse {
                 IggyError::InvalidConsumerGroupId => Some("consumer_group_id".to_string()),
                 IggyError::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 IggyError::UserAlreadyExists => Some("username".to_string()),
+                IggyError::InvalidRoleName => Some("name".to_string()),
+                IggyError::RoleAlreadyExists => Some("name".to_string()),
                 IggyError::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
                 _ => None,
             },
@@ -18,6 +18,7 @@
  */
 
 use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::http::audit::audit;
 use crate::http::diagnostics::request_diagnostics;
 use crate::http::http_shard_wrapper::HttpSafeShard;
 use crate::http::jwt::jwt_manager::JwtManager;
@@ -97,6 +98,7 @@ pub async fn start_http_server(
         .merge(system::router(app_state.clone(), &config.metrics))
         .merge(personal_access_tokens::router(app_state.clone()))
         .merge(users::router(app_state.clone()))
+        .merge(roles::router(app_state.clone()))
         .merge(streams::router(app_state.clone()))
         .merge(topics::router(app_state.clone()))
         .merge(consumer_groups::router(app_state.clone()))
@@ -107,6 +109,7 @@ pub async fn start_http_server(
         .layer(DefaultBodyLimit::max(
             config.max_request_size.as_bytes_u64() as usize,
         ))
+        .layer(middleware::from_fn_with_state(app_state.clone(), audit))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
     if config.cors.enabled {
@@ -204,7 +204,7 @@ impl HttpSafeShard {
             partition_id,
         };
 
-        let future = SendWrapper::new(self.shard().append_messages(partition, batch));
+        let future = SendWrapper::new(self.shard().append_messages(partition, batch, None, None));
         future.await
     }
 
@@ -392,7 +392,7 @@ impl JwtManager {
 /// Normalize issuer URL by lowercasing scheme and host, preserving path case
 ///
 /// Example: "HTTPS://Example.COM/PATH" -> "https://example.com/PATH"
-fn normalize_issuer_url(url: &str) -> String {
+pub(crate) fn normalize_issuer_url(url: &str) -> String {
     match url.split_once("://") {
         Some((scheme, rest)) => {
             let scheme = scheme.to_lowercase();
@@ -17,13 +17,15 @@
  */
 
 use crate::http::jwt::json_web_token::GeneratedToken;
-use crate::metadata::{ConsumerGroupMeta, InnerMetadata, PartitionMeta, StreamMeta, TopicMeta};
+use crate::metadata::{
+    ConsumerGroupMeta, InnerMetadata, PartitionMeta, RoleMeta, StreamMeta, TopicMeta, UserId,
+};
 use crate::streaming::clients::client_manager::Client;
 use crate::streaming::users::user::User;
 use iggy_common::PersonalAccessToken;
 use iggy_common::{ConsumerGroupDetails, ConsumerGroupInfo, ConsumerGroupMember, IggyByteSize};
 use iggy_common::{IdentityInfo, PersonalAccessTokenInfo, TokenInfo, TopicDetails};
-use iggy_common::{UserInfo, UserInfoDetails};
+use iggy_common::{RoleInfo, RoleInfoDetails, UserInfo, UserInfoDetails};
 
 pub fn map_user(user: &User) -> UserInfoDetails {
     UserInfoDetails {
@@ -50,6 +52,31 @@ pub fn map_users(users: &[&User]) -> Vec<UserInfo> {
     users_data
 }
 
+pub fn map_role(role: &RoleMeta, users: Vec<UserId>) -> RoleInfoDetails {
+    RoleInfoDetails {
+        id: role.id,
+        created_at: role.created_at,
+        name: role.name.to_string(),
+        permissions: role.permissions.as_deref().cloned(),
+        users,
+    }
+}
+
+pub fn map_roles(roles: &[(RoleMeta, u32)]) -> Vec<RoleInfo> {
+    let mut roles_data = Vec::with_capacity(roles.len());
+    for (role, users_count) in roles {
+        let role = RoleInfo {
+            id: role.id,
+            created_at: role.created_at,
+            name: role.name.to_string(),
+            users_count: *users_count,
+        };
+        roles_data.push(role);
+    }
+    roles_data.sort_by_key(|r| r.id);
+    roles_data
+}
+
 pub fn map_personal_access_tokens(
     personal_access_tokens: &[PersonalAccessToken],
 ) -> Vec<PersonalAccessTokenInfo> {
@@ -182,6 +209,7 @@ pub fn map_topic_from_metadata(topic_meta: &TopicMeta) -> iggy_common::Topic {
         compression_algorithm: topic_meta.compression_algorithm,
         max_topic_size: topic_meta.max_topic_size,
         replication_factor: topic_meta.replication_factor,
+        cleanup_policy: topic_meta.cleanup_policy,
     }
 }
 
@@ -233,6 +261,7 @@ pub fn map_topic_details_from_metadata(topic_meta: &TopicMeta) -> TopicDetails {
         compression_algorithm: topic_meta.compression_algorithm,
         max_topic_size: topic_meta.max_topic_size,
         replication_factor: topic_meta.replication_factor,
+        cleanup_policy: topic_meta.cleanup_policy,
     }
 }
 
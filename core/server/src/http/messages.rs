@@ -65,15 +65,18 @@ async fn poll_messages(
 
     let session = Session::stateless(identity.user_id, identity.ip_address);
 
-    let poll_future = SendWrapper::new(state.shard.poll_messages(
-        session.client_id,
-        session.get_user_id(),
-        query.0.stream_id,
-        query.0.topic_id,
-        consumer,
-        query.0.partition_id,
-        PollingArgs::new(query.0.strategy, query.0.count, query.0.auto_commit),
-    ));
+    let poll_future = SendWrapper::new(
+        state.shard.poll_messages(
+            session.client_id,
+            session.get_user_id(),
+            query.0.stream_id,
+            query.0.topic_id,
+            consumer,
+            query.0.partition_id,
+            PollingArgs::new(query.0.strategy, query.0.count, query.0.auto_commit)
+                .with_filter(query.0.filter),
+        ),
+    );
 
     let (metadata, messages) = poll_future
         .await
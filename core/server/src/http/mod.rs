@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod audit;
 pub mod diagnostics;
 pub mod error;
 pub mod http_server;
@@ -30,6 +31,7 @@ pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod roles;
 pub mod segments;
 pub mod streams;
 pub mod system;
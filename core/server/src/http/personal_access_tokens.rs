@@ -17,11 +17,12 @@
  */
 
 use crate::http::COMPONENT;
+use crate::http::audit::record_login;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_access_token_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::shard::transmission::frame::ShardResponse;
 use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
 use axum::extract::{Path, State};
@@ -30,6 +31,7 @@ use axum::routing::{delete, get, post};
 use axum::{Extension, Json, Router, debug_handler};
 use err_trail::ErrContext;
 use iggy_binary_protocol::WireName;
+use iggy_binary_protocol::codes::LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE;
 use iggy_binary_protocol::requests::personal_access_tokens::{
     CreatePersonalAccessTokenRequest as WireCreatePat,
     DeletePersonalAccessTokenRequest as WireDeletePat,
@@ -135,15 +137,23 @@ async fn delete_personal_access_token(
 #[instrument(skip_all, name = "trace_login_with_personal_access_token")]
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginWithPersonalAccessToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
-    let user = state
+    let result = state
         .shard
         .shard()
-        .login_with_personal_access_token(command.token.expose_secret(), None)
-        .error(|e: &IggyError| {
-            format!("{COMPONENT} (error: {e}) - failed to login with personal access token")
-        })?;
+        .login_with_personal_access_token(command.token.expose_secret(), None);
+    record_login(
+        &state,
+        &request_details,
+        LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
+        None,
+        result.as_ref().map(|user| user.id),
+    );
+    let user = result.error(|e: &IggyError| {
+        format!("{COMPONENT} (error: {e}) - failed to login with personal access token")
+    })?;
     let tokens = state.jwt_manager.generate(user.id)?;
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
@@ -0,0 +1,221 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::shared::AppState;
+use crate::shard::transmission::frame::ShardResponse;
+use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
+use ::iggy_common::create_role::CreateRole;
+use ::iggy_common::update_role::UpdateRole;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, put};
+use axum::{Extension, Json, Router, debug_handler};
+use iggy_binary_protocol::WireName;
+use iggy_binary_protocol::requests::roles::{
+    AssignRoleRequest as WireAssignRole, CreateRoleRequest as WireCreateRole,
+    DeleteRoleRequest as WireDeleteRole, UnassignRoleRequest as WireUnassignRole,
+    UpdateRoleRequest as WireUpdateRole,
+};
+use iggy_common::Identifier;
+use iggy_common::Validatable;
+use iggy_common::wire_conversions::{identifier_to_wire, permissions_to_wire};
+use iggy_common::{IggyError, RoleInfo, RoleInfoDetails};
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/roles", get(get_roles).post(create_role))
+        .route(
+            "/roles/{role_id}",
+            get(get_role).put(update_role).delete(delete_role),
+        )
+        .route(
+            "/users/{user_id}/roles/{role_id}",
+            put(assign_role).delete(unassign_role),
+        )
+        .with_state(state)
+}
+
+#[debug_handler]
+async fn get_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(role_id): Path<String>,
+) -> Result<Json<RoleInfoDetails>, CustomError> {
+    let shard = state.shard.shard();
+    shard.metadata.perm_get_role(identity.user_id)?;
+
+    let role_id = Identifier::from_str_value(&role_id)?;
+    let Some(role) = shard.find_role(&role_id) else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    let users = shard.metadata.get_role_user_ids(role.id);
+    Ok(Json(mapper::map_role(&role, users)))
+}
+
+#[debug_handler]
+async fn get_roles(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<RoleInfo>>, CustomError> {
+    let shard = state.shard.shard();
+    shard.metadata.perm_get_roles(identity.user_id)?;
+
+    let roles: Vec<_> = shard
+        .get_roles()
+        .into_iter()
+        .map(|role| {
+            let users_count = shard.metadata.get_role_user_ids(role.id).len() as u32;
+            (role, users_count)
+        })
+        .collect();
+    Ok(Json(mapper::map_roles(&roles)))
+}
+
+#[debug_handler]
+#[instrument(skip_all, name = "trace_create_role", fields(iggy_user_id = identity.user_id))]
+async fn create_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateRole>,
+) -> Result<Json<RoleInfoDetails>, CustomError> {
+    command.validate()?;
+
+    let wire_command = WireCreateRole {
+        name: WireName::new(&command.name).map_err(|_| IggyError::InvalidRoleName)?,
+        permissions: command.permissions.as_ref().map(permissions_to_wire),
+    };
+    let request = ShardRequest::control_plane(ShardRequestPayload::CreateRoleRequest {
+        user_id: identity.user_id,
+        command: wire_command,
+    });
+
+    match state.shard.send_to_control_plane(request).await? {
+        ShardResponse::CreateRoleResponse(role) => Ok(Json(mapper::map_role(&role, Vec::new()))),
+        ShardResponse::ErrorResponse(err) => Err(err.into()),
+        _ => unreachable!("Expected CreateRoleResponse"),
+    }
+}
+
+#[debug_handler]
+#[instrument(skip_all, name = "trace_update_role", fields(iggy_user_id = identity.user_id, iggy_updated_role_id = role_id))]
+async fn update_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(role_id): Path<String>,
+    Json(mut command): Json<UpdateRole>,
+) -> Result<StatusCode, CustomError> {
+    command.role_id = Identifier::from_str_value(&role_id)?;
+    command.validate()?;
+
+    let wire_command = WireUpdateRole {
+        role_id: identifier_to_wire(&command.role_id)?,
+        permissions: command.permissions.as_ref().map(permissions_to_wire),
+    };
+    let request = ShardRequest::control_plane(ShardRequestPayload::UpdateRoleRequest {
+        user_id: identity.user_id,
+        command: wire_command,
+    });
+
+    match state.shard.send_to_control_plane(request).await? {
+        ShardResponse::UpdateRoleResponse => Ok(StatusCode::NO_CONTENT),
+        ShardResponse::ErrorResponse(err) => Err(err.into()),
+        _ => unreachable!("Expected UpdateRoleResponse"),
+    }
+}
+
+#[debug_handler]
+#[instrument(skip_all, name = "trace_delete_role", fields(iggy_user_id = identity.user_id, iggy_deleted_role_id = role_id))]
+async fn delete_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(role_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let role_id = Identifier::from_str_value(&role_id)?;
+
+    let wire_command = WireDeleteRole {
+        role_id: identifier_to_wire(&role_id)?,
+    };
+    let request = ShardRequest::control_plane(ShardRequestPayload::DeleteRoleRequest {
+        user_id: identity.user_id,
+        command: wire_command,
+    });
+
+    match state.shard.send_to_control_plane(request).await? {
+        ShardResponse::DeleteRoleResponse => Ok(StatusCode::NO_CONTENT),
+        ShardResponse::ErrorResponse(err) => Err(err.into()),
+        _ => unreachable!("Expected DeleteRoleResponse"),
+    }
+}
+
+#[debug_handler]
+#[instrument(skip_all, name = "trace_assign_role", fields(iggy_user_id = identity.user_id, iggy_assigned_user_id = user_id, iggy_role_id = role_id))]
+async fn assign_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((user_id, role_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let role_id = Identifier::from_str_value(&role_id)?;
+
+    let wire_command = WireAssignRole {
+        user_id: identifier_to_wire(&user_id)?,
+        role_id: identifier_to_wire(&role_id)?,
+    };
+    let request = ShardRequest::control_plane(ShardRequestPayload::AssignRoleRequest {
+        user_id: identity.user_id,
+        command: wire_command,
+    });
+
+    match state.shard.send_to_control_plane(request).await? {
+        ShardResponse::AssignRoleResponse => Ok(StatusCode::NO_CONTENT),
+        ShardResponse::ErrorResponse(err) => Err(err.into()),
+        _ => unreachable!("Expected AssignRoleResponse"),
+    }
+}
+
+#[debug_handler]
+#[instrument(skip_all, name = "trace_unassign_role", fields(iggy_user_id = identity.user_id, iggy_unassigned_user_id = user_id, iggy_role_id = role_id))]
+async fn unassign_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((user_id, role_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let role_id = Identifier::from_str_value(&role_id)?;
+
+    let wire_command = WireUnassignRole {
+        user_id: identifier_to_wire(&user_id)?,
+        role_id: identifier_to_wire(&role_id)?,
+    };
+    let request = ShardRequest::control_plane(ShardRequestPayload::UnassignRoleRequest {
+        user_id: identity.user_id,
+        command: wire_command,
+    });
+
+    match state.shard.send_to_control_plane(request).await? {
+        ShardResponse::UnassignRoleResponse => Ok(StatusCode::NO_CONTENT),
+        ShardResponse::ErrorResponse(err) => Err(err.into()),
+        _ => unreachable!("Expected UnassignRoleResponse"),
+    }
+}
@@ -23,7 +23,7 @@ use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
 use axum::body::Body;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::{HeaderMap, header};
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
@@ -33,7 +33,10 @@ use chrono::Local;
 use err_trail::ErrContext;
 use iggy_common::Stats;
 use iggy_common::get_snapshot::GetSnapshot;
-use iggy_common::{ClientInfo, ClientInfoDetails, ClusterMetadata, IggyError, SystemSnapshotType};
+use iggy_common::{
+    AuditLogQuery, AuditRecord, ClientInfo, ClientInfoDetails, ClusterMetadata, IggyError,
+    SystemSnapshotType,
+};
 use send_wrapper::SendWrapper;
 use std::sync::Arc;
 use tracing::error;
@@ -49,7 +52,8 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
         .route("/cluster/metadata", get(get_cluster_metadata))
         .route("/clients", get(get_clients))
         .route("/clients/{client_id}", get(get_client))
-        .route("/snapshot", post(get_snapshot));
+        .route("/snapshot", post(get_snapshot))
+        .route("/audit", get(get_audit_log));
     if metrics_config.enabled {
         router = router.route(&metrics_config.endpoint, get(get_metrics));
     }
@@ -59,6 +63,7 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
 
 #[debug_handler]
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
+    SendWrapper::new(state.shard.shard().update_consumer_group_lag_metrics()).await;
     let metrics_formatted_output = state.shard.shard().metrics.get_formatted_output();
     Ok(metrics_formatted_output)
 }
@@ -162,3 +167,24 @@ async fn get_snapshot(
     );
     Ok((headers, Body::from(zip_data)))
 }
+
+#[debug_handler]
+async fn get_audit_log(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<AuditLogQuery>,
+) -> Result<Json<Vec<AuditRecord>>, CustomError> {
+    state
+        .shard
+        .shard()
+        .metadata
+        .perm_get_audit_log(identity.user_id)?;
+    let audit_log = state.shard.shard().audit_log.clone();
+    let records = audit_log.query(query).await.error(|e: &IggyError| {
+        format!(
+            "{COMPONENT} (error: {e}) - failed to get audit log, user ID: {}",
+            identity.user_id
+        )
+    })?;
+    Ok(Json(records))
+}
@@ -24,13 +24,14 @@ use crate::shard::transmission::frame::ShardResponse;
 use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, post};
 use axum::{Extension, Json, Router, debug_handler};
 use err_trail::ErrContext;
 use iggy_binary_protocol::WireName;
 use iggy_binary_protocol::requests::topics::{
     CreateTopicRequest as WireCreateTopic, DeleteTopicRequest as WireDeleteTopic,
-    PurgeTopicRequest as WirePurgeTopic, UpdateTopicRequest as WireUpdateTopic,
+    PurgeTopicRequest as WirePurgeTopic, RotateTopicKeyRequest as WireRotateTopicKey,
+    UpdateTopicRequest as WireUpdateTopic,
 };
 use iggy_common::Identifier;
 use iggy_common::Validatable;
@@ -55,6 +56,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/purge",
             delete(purge_topic),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/rotate-key",
+            post(rotate_topic_key),
+        )
         .with_state(state)
 }
 
@@ -158,6 +163,7 @@ async fn create_topic(
         max_topic_size: command.max_topic_size.into(),
         replication_factor: command.replication_factor.unwrap_or(0),
         name: WireName::new(&command.name).map_err(|_| IggyError::InvalidTopicName)?,
+        cleanup_policy: command.cleanup_policy.as_code(),
     };
     let request = ShardRequest::control_plane(ShardRequestPayload::CreateTopicRequest {
         user_id: identity.user_id,
@@ -200,6 +206,7 @@ async fn update_topic(
         max_topic_size: command.max_topic_size.into(),
         replication_factor: command.replication_factor.unwrap_or(0),
         name: WireName::new(&command.name).map_err(|_| IggyError::InvalidTopicName)?,
+        cleanup_policy: command.cleanup_policy.as_code(),
     };
     let request = ShardRequest::control_plane(ShardRequestPayload::UpdateTopicRequest {
         user_id: identity.user_id,
@@ -262,3 +269,28 @@ async fn purge_topic(
         _ => unreachable!("Expected PurgeTopicResponse"),
     }
 }
+
+#[debug_handler]
+#[instrument(skip_all, name = "trace_rotate_topic_key", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn rotate_topic_key(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+
+    let request = ShardRequest::control_plane(ShardRequestPayload::RotateTopicKeyRequest {
+        user_id: identity.user_id,
+        command: WireRotateTopicKey {
+            stream_id: identifier_to_wire(&stream_id)?,
+            topic_id: identifier_to_wire(&topic_id)?,
+        },
+    });
+
+    match state.shard.send_to_control_plane(request).await? {
+        ShardResponse::RotateTopicKeyResponse => Ok(StatusCode::NO_CONTENT),
+        ShardResponse::ErrorResponse(err) => Err(err.into()),
+        _ => unreachable!("Expected RotateTopicKeyResponse"),
+    }
+}
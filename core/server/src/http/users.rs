@@ -17,11 +17,12 @@
  */
 
 use crate::http::COMPONENT;
+use crate::http::audit::record_login;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_access_token_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::shard::transmission::frame::ShardResponse;
 use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
 use crate::streaming::session::Session;
@@ -36,6 +37,7 @@ use axum::routing::{delete, get, post, put};
 use axum::{Extension, Json, Router, debug_handler};
 use err_trail::ErrContext;
 use iggy_binary_protocol::WireName;
+use iggy_binary_protocol::codes::LOGIN_USER_CODE;
 use iggy_binary_protocol::requests::users::{
     ChangePasswordRequest as WireChangePassword, CreateUserRequest as WireCreateUser,
     DeleteUserRequest as WireDeleteUser, UpdatePermissionsRequest as WireUpdatePermissions,
@@ -257,18 +259,27 @@ async fn delete_user(
 #[instrument(skip_all, name = "trace_login_user")]
 async fn login_user(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginUser>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
-    let user = state
-        .shard
-        .shard()
-        .login_user(&command.username, command.password.expose_secret(), None)
-        .error(|e: &IggyError| {
-            format!(
-                "{COMPONENT} (error: {e}) - failed to login, username: {}",
-                command.username
-            )
-        })?;
+    let result =
+        state
+            .shard
+            .shard()
+            .login_user(&command.username, command.password.expose_secret(), None);
+    record_login(
+        &state,
+        &request_details,
+        LOGIN_USER_CODE,
+        Some(&command.username),
+        result.as_ref().map(|user| user.id),
+    );
+    let user = result.error(|e: &IggyError| {
+        format!(
+            "{COMPONENT} (error: {e}) - failed to login, username: {}",
+            command.username
+        )
+    })?;
     let tokens = state.jwt_manager.generate(user.id)?;
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
@@ -29,12 +29,15 @@ static GLOBAL: MiMalloc = MiMalloc;
 #[cfg(windows)]
 compile_error!("iggy-server doesn't support windows.");
 
+pub mod archiver;
 pub mod args;
+pub mod audit;
 pub mod binary;
 pub mod bootstrap;
 pub(crate) mod compat;
 pub mod configs;
 pub mod diagnostics;
+pub mod encryption;
 pub mod http;
 pub mod io;
 pub mod log;
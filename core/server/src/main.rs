@@ -25,9 +25,11 @@ use err_trail::ErrContext;
 use figlet_rs::FIGlet;
 use iggy_common::SemanticVersion;
 use iggy_common::sharding::{IggyNamespace, LocalIdx, PartitionLocation, ShardId};
-use iggy_common::{Aes256GcmEncryptor, EncryptorKind, IggyError, MemoryPool};
+use iggy_common::{IggyError, MemoryPool};
 use server::SEMANTIC_VERSION;
+use server::archiver::ArchiverKind;
 use server::args::Args;
+use server::audit::AuditLog;
 use server::bootstrap::{
     create_directories, create_shard_connections, create_shard_executor, load_config,
     load_metadata, resolve_persister, update_system_info,
@@ -36,6 +38,7 @@ use server::diagnostics::{
     print_invalid_io_uring_args_info, print_io_uring_permission_info,
     print_locked_memory_limit_info,
 };
+use server::encryption::ServerEncryption;
 use server::io::fs_utils;
 use server::log::logger::Logging;
 use server::metadata::{Metadata, create_metadata_handles};
@@ -48,6 +51,7 @@ use server::state::system::SystemState;
 use server::streaming::clients::client_manager::{Client, ClientManager};
 use server::streaming::diagnostics::metrics::Metrics;
 use server::streaming::storage::SystemStorage;
+use server::streaming::users::quota_manager::{QuotaBuckets, QuotaManager};
 use server::streaming::utils::ptr::EternalPtr;
 use std::panic::AssertUnwindSafe;
 use std::rc::Rc;
@@ -256,10 +260,21 @@ fn main() -> Result<(), ServerError> {
                 false => "disabled",
             }
         );
-        let encryptor: Option<EncryptorKind> = match config.system.encryption.enabled {
-            true => Some(EncryptorKind::Aes256Gcm(
-                Aes256GcmEncryptor::from_base64_key(&config.system.encryption.key).unwrap(),
-            )),
+        let encryption = ServerEncryption::from_config(&config.system.encryption)?;
+        let encryptor = encryption
+            .as_ref()
+            .map(|encryption| encryption.default_encryptor());
+
+        // NINTH DISCRETE LOADING STEP.
+        info!(
+            "Tiered storage archiver is {}.",
+            match config.data_maintenance.archiver.enabled {
+                true => "enabled",
+                false => "disabled",
+            }
+        );
+        let archiver: Option<ArchiverKind> = match config.data_maintenance.archiver.enabled {
+            true => Some(ArchiverKind::from_config(&config)?),
             false => None,
         };
 
@@ -280,7 +295,7 @@ fn main() -> Result<(), ServerError> {
             state_term.clone(),
         );
         let state = SystemState::load(state).await?;
-        let (streams_state, users_state) = state.decompose();
+        let (streams_state, users_state, roles_state) = state.decompose();
 
         // Create left-right handles for metadata
         let (mut metadata_writer, metadata_reader) = create_metadata_handles();
@@ -292,8 +307,18 @@ fn main() -> Result<(), ServerError> {
         load_metadata(
             users_state.into_values(),
             streams_state.into_values(),
+            roles_state.into_values(),
+            encryption.as_ref(),
             &mut metadata_writer,
-        );
+        )?;
+
+        // Commit decisions which were persisted but not fully applied before a crash
+        // must be completed before any shard loads its partitions.
+        let recovered_transactions =
+            server::streaming::transactions::recover_pending_commits(&config.system).await?;
+        if recovered_transactions > 0 {
+            info!("Recovered {recovered_transactions} pending transaction commit(s).");
+        }
 
         // ELEVENTH DISCRETE LOADING STEP.
         let shard_allocator = ShardAllocator::new(&config.system.sharding.cpu_allocation)?;
@@ -334,6 +359,24 @@ fn main() -> Result<(), ServerError> {
         let client_manager: EternalPtr<DashMap<u32, Client>> = client_manager.into();
         let client_manager = ClientManager::new(client_manager);
 
+        let user_quota_buckets = Box::new(DashMap::new());
+        let user_quota_buckets = Box::leak(user_quota_buckets);
+        let user_quota_buckets: EternalPtr<DashMap<u32, QuotaBuckets>> = user_quota_buckets.into();
+        let client_quota_buckets = Box::new(DashMap::new());
+        let client_quota_buckets = Box::leak(client_quota_buckets);
+        let client_quota_buckets: EternalPtr<DashMap<u32, QuotaBuckets>> =
+            client_quota_buckets.into();
+        let quota_manager = QuotaManager::new(user_quota_buckets, client_quota_buckets);
+
+        // A single writer thread appends the audit records of all the shards.
+        let (audit_log, audit_log_handle) = if config.system.audit.enabled {
+            let (audit_log, handle) =
+                AuditLog::start(&config.system.audit, &config.system.get_audit_path())?;
+            (audit_log, Some(handle))
+        } else {
+            (AuditLog::disabled(), None)
+        };
+
         // Populate shards_table from SharedMetadata partitions (hierarchical traversal)
         metadata.with_metadata(|metadata| {
             for (stream_id, stream_meta) in metadata.streams.iter() {
@@ -363,7 +406,8 @@ fn main() -> Result<(), ServerError> {
             let shards_table = shards_table.clone();
             let connections = connections.clone();
             let config = config.clone();
-            let encryptor = encryptor.clone();
+            let encryption = encryption.clone();
+            let archiver = archiver.clone();
             let metrics = metrics.clone();
             let current_version = current_version.clone();
             let state_persister = resolve_persister(config.system.state.enforce_fsync);
@@ -378,6 +422,8 @@ fn main() -> Result<(), ServerError> {
                 state_term.clone(),
             );
             let client_manager = client_manager.clone();
+            let quota_manager = quota_manager.clone();
+            let audit_log = audit_log.clone();
             let shard_metadata = metadata.clone();
 
             // Take metadata_writer for shard 0 only
@@ -429,8 +475,11 @@ fn main() -> Result<(), ServerError> {
                                 .shards_table(shards_table)
                                 .connections(connections)
                                 .clients_manager(client_manager)
+                                .quota_manager(quota_manager)
+                                .audit_log(audit_log)
                                 .config(config)
-                                .encryptor(encryptor)
+                                .encryption(encryption)
+                                .archiver(archiver)
                                 .version(current_version)
                                 .metrics(metrics)
                                 .is_follower(is_follower)
@@ -531,6 +580,14 @@ fn main() -> Result<(), ServerError> {
             }
         }
 
+        // Dropping the last handle lets the audit log thread write the pending records and exit.
+        drop(audit_log);
+        if let Some(handle) = audit_log_handle
+            && let Err(e) = handle.join()
+        {
+            warn!("Audit log thread join returned panic: {e:?}");
+        }
+
         let shutdown_duration_msg = {
             let start_time = SHUTDOWN_START_TIME.load(Ordering::SeqCst);
             if start_time > 0 {
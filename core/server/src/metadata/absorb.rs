@@ -18,10 +18,11 @@
 use crate::metadata::ConsumerGroupMemberMeta;
 use crate::metadata::inner::InnerMetadata;
 use crate::metadata::ops::MetadataOp;
-use crate::metadata::{StreamId, UserId};
+use crate::metadata::{RoleId, StreamId, UserId};
 use crate::streaming::polling_consumer::ConsumerGroupId;
-use iggy_common::Permissions;
+use iggy_common::{Permissions, StreamPermissions, TopicPermissions};
 use left_right::Absorb;
+use std::collections::BTreeMap;
 use std::sync::atomic::Ordering;
 
 impl Absorb<MetadataOp> for InnerMetadata {
@@ -61,6 +62,7 @@ fn apply_op(
             let name = meta.name.clone();
             entry.insert(meta);
             metadata.stream_index.insert(name, id);
+            refresh_pattern_permission_indexes(metadata);
         }
 
         MetadataOp::UpdateStream { id, new_name } => {
@@ -69,6 +71,7 @@ fn apply_op(
                 stream.name = new_name.clone();
                 metadata.stream_index.remove(&old_name);
                 metadata.stream_index.insert(new_name.clone(), *id);
+                refresh_pattern_permission_indexes(metadata);
             }
         }
 
@@ -96,6 +99,7 @@ fn apply_op(
                 let name = meta.name.clone();
                 entry.insert(meta);
                 stream.topic_index.insert(name, id);
+                refresh_pattern_permission_indexes(metadata);
             }
         }
 
@@ -107,6 +111,7 @@ fn apply_op(
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
         } => {
             if let Some(stream) = metadata.streams.get_mut(*stream_id)
                 && let Some(topic) = stream.topics.get_mut(*topic_id)
@@ -118,10 +123,12 @@ fn apply_op(
                 topic.compression_algorithm = *compression_algorithm;
                 topic.max_topic_size = *max_topic_size;
                 topic.replication_factor = *replication_factor;
+                topic.cleanup_policy = *cleanup_policy;
 
                 if old_name != *new_name {
                     stream.topic_index.remove(&old_name);
                     stream.topic_index.insert(new_name.clone(), *topic_id);
+                    refresh_pattern_permission_indexes(metadata);
                 }
             }
         }
@@ -138,6 +145,20 @@ fn apply_op(
             }
         }
 
+        MetadataOp::SetTopicEncryptionKeys {
+            stream_id,
+            topic_id,
+            encryption_keys,
+            encryptor,
+        } => {
+            if let Some(stream) = metadata.streams.get_mut(*stream_id)
+                && let Some(topic) = stream.topics.get_mut(*topic_id)
+            {
+                topic.encryption_keys = encryption_keys.clone();
+                topic.encryptor = encryptor.clone();
+            }
+        }
+
         MetadataOp::AddPartitions {
             stream_id,
             topic_id,
@@ -184,10 +205,9 @@ fn apply_op(
             let mut meta = meta.clone();
             meta.id = id as u32;
             let username = meta.username.clone();
-            let permissions = meta.permissions.clone();
             entry.insert(meta);
             metadata.user_index.insert(username, id as u32);
-            update_permission_indexes(metadata, id as u32, permissions.as_deref());
+            refresh_user_permission_indexes(metadata, id as u32);
         }
 
         MetadataOp::UpdateUserMeta { id, meta } => {
@@ -199,9 +219,8 @@ fn apply_op(
                 metadata.user_index.insert(meta.username.clone(), *id);
             }
             if metadata.users.contains(user_id) {
-                let permissions = meta.permissions.clone();
                 metadata.users[user_id] = meta.clone();
-                update_permission_indexes(metadata, *id, permissions.as_deref());
+                refresh_user_permission_indexes(metadata, *id);
             }
         }
 
@@ -214,6 +233,39 @@ fn apply_op(
             metadata.personal_access_tokens.remove(id);
             clear_permission_indexes(metadata, *id);
         }
+        MetadataOp::AddRole { meta, assigned_id } => {
+            let entry = metadata.roles.vacant_entry();
+            let id = entry.key();
+            if populate_ids {
+                assigned_id.store(id, Ordering::Release);
+            }
+            let mut meta = meta.clone();
+            meta.id = id as RoleId;
+            let name = meta.name.clone();
+            entry.insert(meta);
+            metadata.role_index.insert(name, id as RoleId);
+        }
+        MetadataOp::UpdateRole { id, permissions } => {
+            if let Some(role) = metadata.roles.get_mut(*id as usize) {
+                role.permissions = permissions.clone();
+                for user_id in role_holders(metadata, *id) {
+                    refresh_user_permission_indexes(metadata, user_id);
+                }
+            }
+        }
+        MetadataOp::DeleteRole { id } => {
+            let role_id = *id as usize;
+            if metadata.roles.contains(role_id) {
+                let role = metadata.roles.remove(role_id);
+                metadata.role_index.remove(&role.name);
+                for user_id in role_holders(metadata, *id) {
+                    metadata.users[user_id as usize]
+                        .roles
+                        .retain(|assigned| assigned != id);
+                    refresh_user_permission_indexes(metadata, user_id);
+                }
+            }
+        }
 
         MetadataOp::AddPersonalAccessToken { user_id, pat } => {
             metadata
@@ -461,25 +513,64 @@ fn update_permission_indexes(
         .users_global_permissions
         .insert(user_id, permissions.global.clone());
 
-    let Some(streams) = &permissions.streams else {
-        return;
-    };
-
+    let streams = resolve_stream_permissions(metadata, permissions);
     for (stream_id, stream_perm) in streams {
         if stream_perm.poll_messages {
-            metadata.users_can_poll_stream.insert((user_id, *stream_id));
+            metadata.users_can_poll_stream.insert((user_id, stream_id));
         }
 
         if stream_perm.send_messages {
-            metadata.users_can_send_stream.insert((user_id, *stream_id));
+            metadata.users_can_send_stream.insert((user_id, stream_id));
         }
 
         metadata
             .users_stream_permissions
-            .insert((user_id, *stream_id), stream_perm.clone());
+            .insert((user_id, stream_id), stream_perm);
     }
 }
 
+/// Resolves the stream pattern permissions against the names of the existing streams and topics,
+/// and merges them with the ID-based stream permissions, so that the permission checks only
+/// have to look up the stream ID.
+fn resolve_stream_permissions(
+    metadata: &InnerMetadata,
+    permissions: &Permissions,
+) -> BTreeMap<StreamId, StreamPermissions> {
+    let mut streams = permissions.streams.clone().unwrap_or_default();
+    let Some(stream_patterns) = &permissions.stream_patterns else {
+        return streams;
+    };
+
+    for stream_pattern in stream_patterns {
+        for (stream_id, stream) in &metadata.streams {
+            if !stream_pattern.matches(&stream.name) {
+                continue;
+            }
+
+            let mut stream_perm = stream_pattern.to_stream_permissions();
+            if let Some(topic_patterns) = &stream_pattern.topics {
+                let mut topics = BTreeMap::new();
+                for topic_pattern in topic_patterns {
+                    for (topic_id, topic) in &stream.topics {
+                        if topic_pattern.matches(&topic.name) {
+                            topics
+                                .entry(topic_id)
+                                .or_insert_with(TopicPermissions::default)
+                                .merge(&topic_pattern.to_topic_permissions());
+                        }
+                    }
+                }
+                if !topics.is_empty() {
+                    stream_perm.topics = Some(topics);
+                }
+            }
+
+            streams.entry(stream_id).or_default().merge(&stream_perm);
+        }
+    }
+    streams
+}
+
 fn rebuild_all_permission_indexes(metadata: &mut InnerMetadata) {
     metadata.users_global_permissions.clear();
     metadata.users_stream_permissions.clear();
@@ -488,13 +579,61 @@ fn rebuild_all_permission_indexes(metadata: &mut InnerMetadata) {
     metadata.users_can_poll_stream.clear();
     metadata.users_can_send_stream.clear();
 
-    let user_permissions: Vec<_> = metadata
+    let user_ids: Vec<_> = metadata.users.iter().map(|(_, user)| user.id).collect();
+    for user_id in user_ids {
+        refresh_user_permission_indexes(metadata, user_id);
+    }
+}
+
+fn role_holders(metadata: &InnerMetadata, role_id: RoleId) -> Vec<UserId> {
+    metadata
         .users
         .iter()
-        .map(|(_, user)| (user.id, user.permissions.clone()))
-        .collect();
+        .filter(|(_, user)| user.roles.contains(&role_id))
+        .map(|(_, user)| user.id)
+        .collect()
+}
 
-    for (user_id, permissions) in user_permissions {
-        update_permission_indexes(metadata, user_id, permissions.as_deref());
+/// Effective permissions are the union of the user's own permissions and the permissions
+/// of every role assigned to the user.
+fn effective_permissions(metadata: &InnerMetadata, user_id: UserId) -> Option<Permissions> {
+    let user = metadata.users.get(user_id as usize)?;
+    let mut effective = user.permissions.as_deref().cloned();
+    for role_id in &user.roles {
+        let Some(role_permissions) = metadata
+            .roles
+            .get(*role_id as usize)
+            .and_then(|role| role.permissions.as_deref())
+        else {
+            continue;
+        };
+
+        match effective.as_mut() {
+            Some(permissions) => permissions.merge(role_permissions),
+            None => effective = Some(role_permissions.clone()),
+        }
+    }
+    effective
+}
+
+fn refresh_user_permission_indexes(metadata: &mut InnerMetadata, user_id: UserId) {
+    let permissions = effective_permissions(metadata, user_id);
+    update_permission_indexes(metadata, user_id, permissions.as_ref());
+}
+
+/// Stream pattern permissions depend on the stream and topic names, so they have to be resolved
+/// again whenever a stream or topic is created or renamed.
+fn refresh_pattern_permission_indexes(metadata: &mut InnerMetadata) {
+    let user_ids: Vec<_> = metadata
+        .users
+        .iter()
+        .filter(|(_, user)| {
+            effective_permissions(metadata, user.id)
+                .is_some_and(|permissions| permissions.has_patterns())
+        })
+        .map(|(_, user)| user.id)
+        .collect();
+    for user_id in user_ids {
+        refresh_user_permission_indexes(metadata, user_id);
     }
 }
@@ -20,6 +20,7 @@ use crate::metadata::consumer_group_member::{
 };
 use crate::metadata::partition::PartitionMeta;
 use crate::metadata::{ConsumerGroupId, PartitionId};
+use crate::streaming::deliveries::DeliveryPolicy;
 use crate::streaming::polling_consumer::ConsumerGroupId as CgId;
 use iggy_common::IggyTimestamp;
 use slab::Slab;
@@ -34,6 +35,7 @@ pub struct ConsumerGroupMeta {
     pub name: Arc<str>,
     pub partitions: Vec<PartitionId>,
     pub members: Slab<ConsumerGroupMemberMeta>,
+    pub delivery: Option<Arc<DeliveryPolicy>>,
 }
 
 impl ConsumerGroupMeta {
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::metadata::{StreamId, StreamMeta, UserId, UserMeta};
+use crate::metadata::{RoleId, RoleMeta, StreamId, StreamMeta, UserId, UserMeta};
 use ahash::{AHashMap, AHashSet};
 use iggy_common::{GlobalPermissions, PersonalAccessToken, StreamPermissions};
 use slab::Slab;
@@ -29,14 +29,19 @@ pub struct InnerMetadata {
     /// Users indexed by UserId (slab-assigned)
     pub users: Slab<UserMeta>,
 
+    /// Roles indexed by RoleId (slab-assigned)
+    pub roles: Slab<RoleMeta>,
+
     /// Forward indexes (name → ID)
     pub stream_index: AHashMap<Arc<str>, StreamId>,
     pub user_index: AHashMap<Arc<str>, UserId>,
+    pub role_index: AHashMap<Arc<str>, RoleId>,
 
     /// user_id -> (token_hash -> PAT)
     pub personal_access_tokens: AHashMap<UserId, AHashMap<Arc<str>, PersonalAccessToken>>,
 
-    // Permission indexes (auto-maintained by absorb)
+    // Permission indexes (auto-maintained by absorb), built from the user's own
+    // permissions merged with the permissions of all the roles assigned to them
     pub users_global_permissions: AHashMap<UserId, GlobalPermissions>,
     pub users_stream_permissions: AHashMap<(UserId, StreamId), StreamPermissions>,
 
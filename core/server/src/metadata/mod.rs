@@ -24,7 +24,8 @@
 //!
 //! - `InnerMetadata` (inner.rs): Immutable snapshot with all metadata
 //! - `Metadata` (reader.rs): Thread-safe read handle for querying metadata
-//! - Entity types: `StreamMeta`, `TopicMeta`, `PartitionMeta`, `UserMeta`, `ConsumerGroupMeta`
+//! - Entity types: `StreamMeta`, `TopicMeta`, `PartitionMeta`, `UserMeta`, `RoleMeta`,
+//!   `ConsumerGroupMeta`
 //! - Consumer offsets are stored in `PartitionMeta` for cross-shard visibility
 
 mod absorb;
@@ -34,6 +35,7 @@ mod inner;
 pub mod ops;
 mod partition;
 mod reader;
+mod role;
 mod stream;
 mod topic;
 mod user;
@@ -48,6 +50,7 @@ pub use reader::{Metadata, PartitionInitInfo};
 pub(crate) use reader::{
     resolve_consumer_group_id_inner, resolve_stream_id_inner, resolve_topic_id_inner,
 };
+pub use role::RoleMeta;
 pub use stream::StreamMeta;
 pub use topic::TopicMeta;
 pub use user::UserMeta;
@@ -58,6 +61,7 @@ pub type StreamId = usize;
 pub type TopicId = usize;
 pub type PartitionId = usize;
 pub type UserId = u32;
+pub type RoleId = u32;
 pub type ClientId = u32;
 pub type ConsumerGroupId = usize;
 pub type ConsumerGroupMemberId = usize;
@@ -18,10 +18,14 @@
 use crate::metadata::consumer_group_member::CompletableRevocation;
 use crate::metadata::inner::InnerMetadata;
 use crate::metadata::{
-    ConsumerGroupId, ConsumerGroupMeta, PartitionId, PartitionMeta, StreamId, StreamMeta, TopicId,
-    TopicMeta, UserId, UserMeta,
+    ConsumerGroupId, ConsumerGroupMeta, PartitionId, PartitionMeta, RoleId, RoleMeta, StreamId,
+    StreamMeta, TopicId, TopicMeta, UserId, UserMeta,
 };
-use iggy_common::{CompressionAlgorithm, IggyExpiry, MaxTopicSize, PersonalAccessToken};
+use iggy_common::{
+    CleanupPolicy, CompressionAlgorithm, EnvelopeEncryptor, IggyExpiry, MaxTopicSize, Permissions,
+    PersonalAccessToken,
+};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::atomic::AtomicUsize;
@@ -54,11 +58,18 @@ pub enum MetadataOp {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
     },
     DeleteTopic {
         stream_id: StreamId,
         topic_id: TopicId,
     },
+    SetTopicEncryptionKeys {
+        stream_id: StreamId,
+        topic_id: TopicId,
+        encryption_keys: BTreeMap<u32, Vec<u8>>,
+        encryptor: Option<Arc<EnvelopeEncryptor>>,
+    },
     AddPartitions {
         stream_id: StreamId,
         topic_id: TopicId,
@@ -81,6 +92,17 @@ pub enum MetadataOp {
     DeleteUser {
         id: UserId,
     },
+    AddRole {
+        meta: RoleMeta,
+        assigned_id: Arc<AtomicUsize>,
+    },
+    UpdateRole {
+        id: RoleId,
+        permissions: Option<Arc<Permissions>>,
+    },
+    DeleteRole {
+        id: RoleId,
+    },
 
     AddPersonalAccessToken {
         user_id: UserId,
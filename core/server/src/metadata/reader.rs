@@ -17,18 +17,22 @@
 
 use crate::metadata::{
     ConsumerGroupId, ConsumerGroupMeta, InnerMetadata, MetadataReadHandle, PartitionId,
-    PartitionMeta, StreamId, StreamMeta, TopicId, TopicMeta, UserId, UserMeta,
+    PartitionMeta, RoleId, RoleMeta, StreamId, StreamMeta, TopicId, TopicMeta, UserId, UserMeta,
 };
 use crate::shard::transmission::message::{ResolvedPartition, ResolvedTopic};
+use crate::streaming::deliveries::DeliveryPolicy;
 use crate::streaming::partitions::consumer_group_offsets::ConsumerGroupOffsets;
 use crate::streaming::partitions::consumer_offsets::ConsumerOffsets;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::stats::{PartitionStats, StreamStats, TopicStats};
 use iggy_common::sharding::IggyNamespace;
 use iggy_common::{
-    IdKind, Identifier, IggyError, IggyExpiry, IggyTimestamp, MaxTopicSize, PersonalAccessToken,
+    CleanupPolicy, Consumer, ConsumerGroupPermissions, ConsumerKind, EncryptorKind, IdKind,
+    Identifier, IggyError, IggyExpiry, IggyTimestamp, MaxTopicSize, PersonalAccessToken,
+    UserQuotas,
 };
 use left_right::ReadGuard;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
@@ -108,6 +112,20 @@ impl Metadata {
         }
     }
 
+    pub fn get_role_id(&self, identifier: &Identifier) -> Option<RoleId> {
+        let metadata = self.load();
+        match identifier.kind {
+            IdKind::Numeric => {
+                let id = identifier.get_u32_value().ok()? as RoleId;
+                metadata.roles.contains(id as usize).then_some(id)
+            }
+            IdKind::String => {
+                let name = identifier.get_cow_str_value().ok()?;
+                metadata.role_index.get(name.as_ref()).copied()
+            }
+        }
+    }
+
     pub fn get_consumer_group_id(
         &self,
         stream_id: StreamId,
@@ -167,6 +185,10 @@ impl Metadata {
         self.load().users.get(id as usize).is_some()
     }
 
+    pub fn role_exists(&self, id: RoleId) -> bool {
+        self.load().roles.contains(id as usize)
+    }
+
     pub fn consumer_group_exists(
         &self,
         stream_id: StreamId,
@@ -340,6 +362,44 @@ impl Metadata {
                 )
             })?;
 
+        // Shared subscription: any member polls any partition of the group
+        if group
+            .delivery
+            .as_ref()
+            .is_some_and(|delivery| delivery.is_shared())
+        {
+            let partitions = &group.partitions;
+            if let Some(pid) = explicit_partition_id {
+                let pid_usize = pid as usize;
+                if !partitions.contains(&pid_usize) {
+                    return Ok(None);
+                }
+                return Ok(Some((
+                    PollingConsumer::consumer_group(group_id, member_slab_id),
+                    pid_usize,
+                )));
+            }
+
+            let count = partitions.len();
+            if count == 0 {
+                return Ok(None);
+            }
+            let counter = &member.partition_index;
+            let current = if calculate_partition_id {
+                counter
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |c| {
+                        Some((c + 1) % count)
+                    })
+                    .unwrap()
+            } else {
+                counter.load(Ordering::Relaxed)
+            };
+            return Ok(Some((
+                PollingConsumer::consumer_group(group_id, member_slab_id),
+                partitions[current % count],
+            )));
+        }
+
         // Step 3a: If explicit partition_id provided, validate member owns it
         if let Some(pid) = explicit_partition_id {
             let pid_usize = pid as usize;
@@ -463,6 +523,10 @@ impl Metadata {
         self.load().user_index.contains_key(username)
     }
 
+    pub fn role_name_exists(&self, name: &str) -> bool {
+        self.load().role_index.contains_key(name)
+    }
+
     pub fn consumer_groups_count(&self, stream_id: StreamId, topic_id: TopicId) -> usize {
         self.load()
             .streams
@@ -543,10 +607,36 @@ impl Metadata {
         self.load().users.get(id as usize).cloned()
     }
 
+    pub fn get_user_quotas(&self, id: UserId) -> Option<UserQuotas> {
+        self.load().users.get(id as usize).map(|user| UserQuotas {
+            user_id: user.id,
+            user: user.user_quota,
+            client: user.client_quota,
+        })
+    }
+
     pub fn get_all_users(&self) -> Vec<UserMeta> {
         self.load().users.iter().map(|(_, u)| u.clone()).collect()
     }
 
+    pub fn get_role(&self, id: RoleId) -> Option<RoleMeta> {
+        self.load().roles.get(id as usize).cloned()
+    }
+
+    pub fn get_all_roles(&self) -> Vec<RoleMeta> {
+        self.load().roles.iter().map(|(_, r)| r.clone()).collect()
+    }
+
+    /// Returns the IDs of all the users holding the role.
+    pub fn get_role_user_ids(&self, id: RoleId) -> Vec<UserId> {
+        self.load()
+            .users
+            .iter()
+            .filter(|(_, user)| user.roles.contains(&id))
+            .map(|(_, user)| user.id)
+            .collect()
+    }
+
     pub fn get_stream(&self, id: StreamId) -> Option<StreamMeta> {
         self.load().streams.get(id).cloned()
     }
@@ -584,6 +674,20 @@ impl Metadata {
             .and_then(|t| t.consumer_groups.get(group_id).cloned())
     }
 
+    pub fn get_consumer_group_delivery(
+        &self,
+        stream_id: StreamId,
+        topic_id: TopicId,
+        group_id: ConsumerGroupId,
+    ) -> Option<Arc<DeliveryPolicy>> {
+        self.load()
+            .streams
+            .get(stream_id)
+            .and_then(|s| s.topics.get(topic_id))
+            .and_then(|t| t.consumer_groups.get(group_id))
+            .and_then(|cg| cg.delivery.clone())
+    }
+
     pub fn get_user_personal_access_tokens(&self, user_id: UserId) -> Vec<PersonalAccessToken> {
         self.load()
             .personal_access_tokens
@@ -733,6 +837,49 @@ impl Metadata {
         })
     }
 
+    /// Get topic cleanup policy.
+    pub fn get_topic_cleanup_policy(
+        &self,
+        stream_id: StreamId,
+        topic_id: TopicId,
+    ) -> Option<CleanupPolicy> {
+        self.with_metadata(|m| {
+            m.streams
+                .get(stream_id)
+                .and_then(|s| s.topics.get(topic_id))
+                .map(|t| t.cleanup_policy)
+        })
+    }
+
+    /// Get topic data encryption keys, wrapped by the master key.
+    pub fn get_topic_encryption_keys(
+        &self,
+        stream_id: StreamId,
+        topic_id: TopicId,
+    ) -> Option<BTreeMap<u32, Vec<u8>>> {
+        self.with_metadata(|m| {
+            m.streams
+                .get(stream_id)
+                .and_then(|s| s.topics.get(topic_id))
+                .map(|t| t.encryption_keys.clone())
+        })
+    }
+
+    /// Get topic encryptor, available once the topic data encryption key has been rotated.
+    pub fn get_topic_encryptor(
+        &self,
+        stream_id: StreamId,
+        topic_id: TopicId,
+    ) -> Option<EncryptorKind> {
+        self.with_metadata(|m| {
+            m.streams
+                .get(stream_id)
+                .and_then(|s| s.topics.get(topic_id))
+                .and_then(|t| t.encryptor.clone())
+                .map(EncryptorKind::Envelope)
+        })
+    }
+
     /// Get partition initialization info needed for LocalPartition setup.
     pub fn get_partition_init_info(
         &self,
@@ -1085,6 +1232,15 @@ impl Metadata {
         self.perm_manage_topic(user_id, stream_id, topic_id)
     }
 
+    pub fn perm_rotate_topic_key(
+        &self,
+        user_id: u32,
+        stream_id: StreamId,
+        topic_id: TopicId,
+    ) -> Result<(), IggyError> {
+        self.perm_manage_topic(user_id, stream_id, topic_id)
+    }
+
     /// Inheritance: manage_streams → manage_topics
     fn perm_manage_topic(
         &self,
@@ -1159,8 +1315,19 @@ impl Metadata {
         user_id: u32,
         stream_id: StreamId,
         topic_id: TopicId,
+        group_id: ConsumerGroupId,
     ) -> Result<(), IggyError> {
-        self.perm_get_topic(user_id, stream_id, topic_id)
+        self.with_metadata(|m| {
+            perm_consumer_group_inner(
+                m,
+                user_id,
+                stream_id,
+                topic_id,
+                group_id,
+                |group| group.manage_group,
+                perm_get_topic_inner,
+            )
+        })
     }
 
     pub fn perm_get_consumer_group(
@@ -1186,8 +1353,19 @@ impl Metadata {
         user_id: u32,
         stream_id: StreamId,
         topic_id: TopicId,
+        group_id: ConsumerGroupId,
     ) -> Result<(), IggyError> {
-        self.perm_get_topic(user_id, stream_id, topic_id)
+        self.with_metadata(|m| {
+            perm_consumer_group_inner(
+                m,
+                user_id,
+                stream_id,
+                topic_id,
+                group_id,
+                |group| group.join_group,
+                perm_get_topic_inner,
+            )
+        })
     }
 
     pub fn perm_leave_consumer_group(
@@ -1199,6 +1377,38 @@ impl Metadata {
         self.perm_get_topic(user_id, stream_id, topic_id)
     }
 
+    /// Acknowledging the messages settles the group's progress, so it requires the same
+    /// `commit_offsets` permission as storing the group's offset.
+    pub fn perm_ack_messages(
+        &self,
+        user_id: u32,
+        stream_id: StreamId,
+        topic_id: TopicId,
+        group_id: ConsumerGroupId,
+    ) -> Result<(), IggyError> {
+        self.with_metadata(|m| {
+            perm_consumer_group_inner(
+                m,
+                user_id,
+                stream_id,
+                topic_id,
+                group_id,
+                |group| group.commit_offsets,
+                perm_get_consumer_offset_inner,
+            )
+        })
+    }
+
+    pub fn perm_nack_message(
+        &self,
+        user_id: u32,
+        stream_id: StreamId,
+        topic_id: TopicId,
+        group_id: ConsumerGroupId,
+    ) -> Result<(), IggyError> {
+        self.perm_ack_messages(user_id, stream_id, topic_id, group_id)
+    }
+
     pub fn perm_get_consumer_offset(
         &self,
         user_id: u32,
@@ -1250,10 +1460,42 @@ impl Metadata {
         self.perm_manage_users(user_id)
     }
 
+    pub fn perm_set_user_quota(&self, user_id: u32) -> Result<(), IggyError> {
+        self.perm_manage_users(user_id)
+    }
+
     pub fn perm_change_password(&self, user_id: u32) -> Result<(), IggyError> {
         self.perm_manage_users(user_id)
     }
 
+    pub fn perm_get_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.perm_read_users(user_id)
+    }
+
+    pub fn perm_get_roles(&self, user_id: u32) -> Result<(), IggyError> {
+        self.perm_read_users(user_id)
+    }
+
+    pub fn perm_create_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.perm_manage_users(user_id)
+    }
+
+    pub fn perm_update_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.perm_manage_users(user_id)
+    }
+
+    pub fn perm_delete_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.perm_manage_users(user_id)
+    }
+
+    pub fn perm_assign_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.perm_manage_users(user_id)
+    }
+
+    pub fn perm_unassign_role(&self, user_id: u32) -> Result<(), IggyError> {
+        self.perm_manage_users(user_id)
+    }
+
     fn perm_manage_users(&self, user_id: u32) -> Result<(), IggyError> {
         let metadata = self.load();
 
@@ -1290,6 +1532,22 @@ impl Metadata {
         self.perm_get_server_info(user_id)
     }
 
+    pub fn perm_get_audit_log(&self, user_id: u32) -> Result<(), IggyError> {
+        self.perm_manage_servers(user_id)
+    }
+
+    fn perm_manage_servers(&self, user_id: u32) -> Result<(), IggyError> {
+        let metadata = self.load();
+
+        if let Some(global_permissions) = metadata.users_global_permissions.get(&user_id)
+            && global_permissions.manage_servers
+        {
+            return Ok(());
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
     fn perm_get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
         let metadata = self.load();
 
@@ -1514,6 +1772,7 @@ impl Metadata {
     pub fn resolve_for_store_consumer_offset(
         &self,
         user_id: u32,
+        consumer: &Consumer,
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<ResolvedTopic, IggyError> {
@@ -1522,7 +1781,18 @@ impl Metadata {
                 .ok_or_else(|| IggyError::StreamIdNotFound(stream_id.clone()))?;
             let tid = resolve_topic_id_inner(m, sid, topic_id)
                 .ok_or_else(|| IggyError::TopicIdNotFound(stream_id.clone(), topic_id.clone()))?;
-            perm_get_consumer_offset_inner(m, user_id, sid, tid)?;
+            match consumer_group_id_inner(m, sid, tid, consumer) {
+                Some(gid) => perm_consumer_group_inner(
+                    m,
+                    user_id,
+                    sid,
+                    tid,
+                    gid,
+                    |group| group.commit_offsets,
+                    perm_get_consumer_offset_inner,
+                )?,
+                None => perm_get_consumer_offset_inner(m, user_id, sid, tid)?,
+            }
             Ok(ResolvedTopic {
                 stream_id: sid,
                 topic_id: tid,
@@ -1534,6 +1804,7 @@ impl Metadata {
     pub fn resolve_for_delete_consumer_offset(
         &self,
         user_id: u32,
+        consumer: &Consumer,
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<ResolvedTopic, IggyError> {
@@ -1542,7 +1813,18 @@ impl Metadata {
                 .ok_or_else(|| IggyError::StreamIdNotFound(stream_id.clone()))?;
             let tid = resolve_topic_id_inner(m, sid, topic_id)
                 .ok_or_else(|| IggyError::TopicIdNotFound(stream_id.clone(), topic_id.clone()))?;
-            perm_get_consumer_offset_inner(m, user_id, sid, tid)?;
+            match consumer_group_id_inner(m, sid, tid, consumer) {
+                Some(gid) => perm_consumer_group_inner(
+                    m,
+                    user_id,
+                    sid,
+                    tid,
+                    gid,
+                    |group| group.delete_offsets,
+                    perm_get_consumer_offset_inner,
+                )?,
+                None => perm_get_consumer_offset_inner(m, user_id, sid, tid)?,
+            }
             Ok(ResolvedTopic {
                 stream_id: sid,
                 topic_id: tid,
@@ -1665,6 +1947,21 @@ pub(crate) fn resolve_consumer_group_id_inner(
     }
 }
 
+/// Resolves the consumer group ID if the consumer is an existing consumer group.
+fn consumer_group_id_inner(
+    m: &InnerMetadata,
+    stream_id: StreamId,
+    topic_id: TopicId,
+    consumer: &Consumer,
+) -> Option<ConsumerGroupId> {
+    match consumer.kind {
+        ConsumerKind::Consumer => None,
+        ConsumerKind::ConsumerGroup => {
+            resolve_consumer_group_id_inner(m, stream_id, topic_id, &consumer.id)
+        }
+    }
+}
+
 fn resolve_user_id_inner(m: &InnerMetadata, user_id: &Identifier) -> Option<UserId> {
     match user_id.kind {
         IdKind::Numeric => Some(user_id.get_u32_value().ok()?),
@@ -1801,6 +2098,40 @@ fn perm_get_consumer_group_inner(
     perm_get_topic_inner(m, user_id, stream_id, topic_id)
 }
 
+/// Inheritance: manage_topic → all consumer groups. If the user's permissions for the topic
+/// list any consumer groups, the operation is allowed only for the listed groups granting it
+/// (or `manage_group`), otherwise the topic-level `fallback` check applies.
+fn perm_consumer_group_inner(
+    m: &InnerMetadata,
+    user_id: u32,
+    stream_id: StreamId,
+    topic_id: TopicId,
+    group_id: ConsumerGroupId,
+    allows: fn(&ConsumerGroupPermissions) -> bool,
+    fallback: fn(&InnerMetadata, u32, StreamId, TopicId) -> Result<(), IggyError>,
+) -> Result<(), IggyError> {
+    if perm_manage_topic_inner(m, user_id, stream_id, topic_id).is_ok() {
+        return Ok(());
+    }
+
+    let consumer_groups = m
+        .users_stream_permissions
+        .get(&(user_id, stream_id))
+        .and_then(|stream_permissions| stream_permissions.topics.as_ref())
+        .and_then(|topics| topics.get(&topic_id))
+        .filter(|topic_permissions| topic_permissions.restricts_consumer_groups())
+        .and_then(|topic_permissions| topic_permissions.consumer_groups.as_ref());
+
+    let Some(consumer_groups) = consumer_groups else {
+        return fallback(m, user_id, stream_id, topic_id);
+    };
+
+    match consumer_groups.get(&group_id) {
+        Some(group) if group.manage_group || allows(group) => Ok(()),
+        _ => Err(IggyError::Unauthorized),
+    }
+}
+
 fn perm_get_user_inner(m: &InnerMetadata, user_id: u32) -> Result<(), IggyError> {
     if let Some(global) = m.users_global_permissions.get(&user_id)
         && (global.manage_users || global.read_users)
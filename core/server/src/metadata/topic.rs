@@ -20,8 +20,11 @@ use crate::metadata::partition::PartitionMeta;
 use crate::metadata::{ConsumerGroupId, TopicId};
 use crate::streaming::stats::TopicStats;
 use ahash::AHashMap;
-use iggy_common::{CompressionAlgorithm, IggyExpiry, IggyTimestamp, MaxTopicSize};
+use iggy_common::{
+    CleanupPolicy, CompressionAlgorithm, EnvelopeEncryptor, IggyExpiry, IggyTimestamp, MaxTopicSize,
+};
 use slab::Slab;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 
@@ -35,11 +38,16 @@ pub struct TopicMeta {
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: u8,
+    pub cleanup_policy: CleanupPolicy,
     pub stats: Arc<TopicStats>,
     pub partitions: Vec<PartitionMeta>,
     pub consumer_groups: Slab<ConsumerGroupMeta>,
     pub consumer_group_index: AHashMap<Arc<str>, ConsumerGroupId>,
     pub round_robin_counter: Arc<AtomicUsize>,
+    /// Data encryption keys wrapped by the master key, by key ID.
+    pub encryption_keys: BTreeMap<u32, Vec<u8>>,
+    /// Encryptor built from the `encryption_keys`, `None` until the first key rotation.
+    pub encryptor: Option<Arc<EnvelopeEncryptor>>,
 }
 
 impl TopicMeta {
@@ -52,6 +60,7 @@ impl TopicMeta {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
         stats: Arc<TopicStats>,
     ) -> Self {
         Self {
@@ -62,11 +71,14 @@ impl TopicMeta {
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
             stats,
             partitions: Vec::new(),
             consumer_groups: Slab::new(),
             consumer_group_index: AHashMap::default(),
             round_robin_counter: Arc::new(AtomicUsize::new(0)),
+            encryption_keys: BTreeMap::new(),
+            encryptor: None,
         }
     }
 }
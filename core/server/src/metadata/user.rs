@@ -15,8 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::metadata::UserId;
-use iggy_common::{IggyTimestamp, Permissions, UserStatus};
+use crate::metadata::{RoleId, UserId};
+use iggy_common::scram::ScramCredentials;
+use iggy_common::{IggyTimestamp, Permissions, Quota, UserStatus};
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
@@ -24,7 +25,11 @@ pub struct UserMeta {
     pub id: UserId,
     pub username: Arc<str>,
     pub password_hash: Arc<str>,
+    pub scram_credentials: Option<Arc<ScramCredentials>>,
     pub status: UserStatus,
     pub permissions: Option<Arc<Permissions>>,
+    pub roles: Vec<RoleId>,
+    pub user_quota: Option<Quota>,
+    pub client_quota: Option<Quota>,
     pub created_at: IggyTimestamp,
 }
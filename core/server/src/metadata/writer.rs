@@ -20,18 +20,21 @@ use crate::metadata::inner::InnerMetadata;
 use crate::metadata::ops::MetadataOp;
 use crate::metadata::reader::Metadata;
 use crate::metadata::{
-    ConsumerGroupId, ConsumerGroupMeta, PartitionId, PartitionMeta, StreamId, StreamMeta, TopicId,
-    TopicMeta, UserId, UserMeta,
+    ConsumerGroupId, ConsumerGroupMeta, PartitionId, PartitionMeta, RoleId, RoleMeta, StreamId,
+    StreamMeta, TopicId, TopicMeta, UserId, UserMeta,
 };
+use crate::streaming::deliveries::DeliveryPolicy;
 use crate::streaming::partitions::consumer_group_offsets::ConsumerGroupOffsets;
 use crate::streaming::partitions::consumer_offsets::ConsumerOffsets;
 use crate::streaming::stats::{PartitionStats, StreamStats, TopicStats};
+use iggy_common::scram::ScramCredentials;
 use iggy_common::{
-    CompressionAlgorithm, Identifier, IggyError, IggyExpiry, IggyTimestamp, MaxTopicSize,
-    Permissions, PersonalAccessToken, UserStatus,
+    CleanupPolicy, CompressionAlgorithm, EnvelopeEncryptor, Identifier, IggyError, IggyExpiry,
+    IggyTimestamp, MaxTopicSize, Permissions, PersonalAccessToken, UserStatus,
 };
 use left_right::WriteHandle;
 use slab::Slab;
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
@@ -110,6 +113,7 @@ impl MetadataWriter {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
     ) {
         self.append(MetadataOp::UpdateTopic {
             stream_id,
@@ -119,6 +123,7 @@ impl MetadataWriter {
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
         });
         self.publish();
     }
@@ -131,6 +136,22 @@ impl MetadataWriter {
         self.publish();
     }
 
+    pub fn set_topic_encryption_keys(
+        &mut self,
+        stream_id: StreamId,
+        topic_id: TopicId,
+        encryption_keys: BTreeMap<u32, Vec<u8>>,
+        encryptor: Option<Arc<EnvelopeEncryptor>>,
+    ) {
+        self.append(MetadataOp::SetTopicEncryptionKeys {
+            stream_id,
+            topic_id,
+            encryption_keys,
+            encryptor,
+        });
+        self.publish();
+    }
+
     /// Add partitions to a topic. Returns the assigned partition IDs (sequential from current count).
     pub fn add_partitions(
         &mut self,
@@ -195,6 +216,28 @@ impl MetadataWriter {
         self.publish();
     }
 
+    pub fn add_role(&mut self, meta: RoleMeta) -> RoleId {
+        let assigned_id = Arc::new(AtomicUsize::new(usize::MAX));
+        self.append(MetadataOp::AddRole {
+            meta,
+            assigned_id: assigned_id.clone(),
+        });
+        self.publish();
+        let id = assigned_id.load(Ordering::Acquire);
+        debug_assert_ne!(id, usize::MAX, "add_role should always succeed");
+        id as RoleId
+    }
+
+    pub fn update_role(&mut self, id: RoleId, permissions: Option<Arc<Permissions>>) {
+        self.append(MetadataOp::UpdateRole { id, permissions });
+        self.publish();
+    }
+
+    pub fn delete_role(&mut self, id: RoleId) {
+        self.append(MetadataOp::DeleteRole { id });
+        self.publish();
+    }
+
     pub fn add_personal_access_token(&mut self, user_id: UserId, pat: PersonalAccessToken) {
         self.append(MetadataOp::AddPersonalAccessToken { user_id, pat });
         self.publish();
@@ -382,6 +425,7 @@ impl MetadataWriter {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<(TopicId, Arc<TopicStats>), IggyError> {
         let parent_stats = reader.get_stream_stats(stream_id).ok_or_else(|| {
             IggyError::StreamIdNotFound(Identifier::numeric(stream_id as u32).unwrap())
@@ -411,11 +455,14 @@ impl MetadataWriter {
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
             stats: stats.clone(),
             partitions: Vec::new(),
             consumer_groups: Slab::new(),
             consumer_group_index: ahash::AHashMap::default(),
             round_robin_counter: Arc::new(AtomicUsize::new(0)),
+            encryption_keys: Default::default(),
+            encryptor: None,
         };
 
         // change to create_topic
@@ -436,6 +483,7 @@ impl MetadataWriter {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<(), IggyError> {
         let guard = reader.load();
         let Some(stream) = guard.streams.get(stream_id) else {
@@ -470,6 +518,7 @@ impl MetadataWriter {
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
         );
         Ok(())
     }
@@ -511,11 +560,13 @@ impl MetadataWriter {
         ids.into_iter().zip(stats_list).collect()
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_user(
         &mut self,
         reader: &Metadata,
         username: Arc<str>,
         password_hash: Arc<str>,
+        scram_credentials: Option<Arc<ScramCredentials>>,
         status: UserStatus,
         permissions: Option<Arc<Permissions>>,
         max_users: usize,
@@ -532,8 +583,12 @@ impl MetadataWriter {
             id: 0,
             username,
             password_hash,
+            scram_credentials,
             status,
             permissions,
+            roles: Vec::new(),
+            user_quota: None,
+            client_quota: None,
             created_at: IggyTimestamp::now(),
         };
         let id = self.add_user(meta);
@@ -567,6 +622,70 @@ impl MetadataWriter {
         Ok(updated)
     }
 
+    pub fn create_role(
+        &mut self,
+        reader: &Metadata,
+        name: Arc<str>,
+        permissions: Option<Arc<Permissions>>,
+    ) -> Result<RoleId, IggyError> {
+        if reader.role_name_exists(&name) {
+            return Err(IggyError::RoleAlreadyExists);
+        }
+
+        let meta = RoleMeta {
+            id: 0,
+            name,
+            permissions,
+            created_at: IggyTimestamp::now(),
+        };
+        let id = self.add_role(meta);
+        Ok(id)
+    }
+
+    /// Assigns the role to the user, assigning an already held role is a no-op.
+    pub fn assign_role(
+        &mut self,
+        reader: &Metadata,
+        user_id: UserId,
+        role_id: RoleId,
+    ) -> Result<(), IggyError> {
+        let Some(mut meta) = reader.get_user(user_id) else {
+            return Err(IggyError::ResourceNotFound(format!("user:{user_id}")));
+        };
+
+        if !reader.role_exists(role_id) {
+            return Err(IggyError::ResourceNotFound(format!("role:{role_id}")));
+        }
+
+        if meta.roles.contains(&role_id) {
+            return Ok(());
+        }
+
+        meta.roles.push(role_id);
+        self.update_user_meta(user_id, meta);
+        Ok(())
+    }
+
+    /// Removes the role from the user, removing a role which is not held is a no-op.
+    pub fn unassign_role(
+        &mut self,
+        reader: &Metadata,
+        user_id: UserId,
+        role_id: RoleId,
+    ) -> Result<(), IggyError> {
+        let Some(mut meta) = reader.get_user(user_id) else {
+            return Err(IggyError::ResourceNotFound(format!("user:{user_id}")));
+        };
+
+        if !meta.roles.contains(&role_id) {
+            return Ok(());
+        }
+
+        meta.roles.retain(|assigned| *assigned != role_id);
+        self.update_user_meta(user_id, meta);
+        Ok(())
+    }
+
     pub fn create_consumer_group(
         &mut self,
         reader: &Metadata,
@@ -574,6 +693,7 @@ impl MetadataWriter {
         topic_id: TopicId,
         name: Arc<str>,
         partitions_count: u32,
+        delivery: Option<DeliveryPolicy>,
     ) -> Result<ConsumerGroupId, IggyError> {
         let guard = reader.load();
         let Some(stream) = guard.streams.get(stream_id) else {
@@ -602,6 +722,7 @@ impl MetadataWriter {
             name,
             partitions: (0..partitions_count as usize).collect(),
             members: Slab::new(),
+            delivery: delivery.map(Arc::new),
         };
 
         let id = self
@@ -21,6 +21,7 @@ use crate::server_error::ConnectionError;
 use crate::shard::IggyShard;
 use crate::shard::task_registry::ShutdownToken;
 use crate::streaming::session::Session;
+use crate::streaming::utils::client_certificate::authenticate_client_certificate;
 use anyhow::anyhow;
 use compio::io::AsyncReadExt;
 use compio::quic::{Connection, Endpoint, RecvStream, SendStream};
@@ -103,6 +104,20 @@ async fn handle_connection(
         address
     );
 
+    let client_auth = &shard.config.quic.certificate.client_auth;
+    let peer_certificates = connection.peer_identity();
+    if let Err(error) = authenticate_client_certificate(
+        &shard,
+        &session,
+        client_auth,
+        peer_certificates.as_deref().map(Vec::as_slice),
+    ) && client_auth.required
+    {
+        warn!("Rejecting QUIC client: {address} without a valid client certificate user. {error}");
+        shard.delete_client(client_id).await;
+        return Ok(());
+    }
+
     let conn_stop_receiver = shard.task_registry.add_connection(client_id);
 
     loop {
@@ -216,6 +231,11 @@ async fn handle_stream(
                 sender.send_error_response(e).await?;
                 trace!("QUIC error response was sent.");
                 Ok(())
+            } else if matches!(e, IggyError::Throttled(_)) {
+                debug!("Command was throttled, session: {:?}, error: {e}.", session);
+                sender.send_error_response(e).await?;
+                trace!("QUIC error response was sent.");
+                Ok(())
             } else {
                 error!(
                     "Command was not handled successfully, session: {:?}, error: {e}.",
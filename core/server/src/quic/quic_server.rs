@@ -22,6 +22,7 @@ use crate::server_error::QuicError;
 use crate::shard::IggyShard;
 use crate::shard::task_registry::ShutdownToken;
 use crate::shard::transmission::event::ShardEvent;
+use crate::streaming::utils::client_certificate::client_cert_verifier;
 use anyhow::Result;
 use compio::quic::{
     Endpoint, EndpointConfig, IdleTimeout, ServerBuilder, ServerConfig, TransportConfig, VarInt,
@@ -144,11 +145,20 @@ fn configure_quic(config: &QuicConfig) -> Result<ServerConfig, QuicError> {
         false => load_certificates(&config.certificate.cert_file, &config.certificate.key_file)?,
     };
 
-    let builder = ServerBuilder::new_with_single_cert(certificates, private_key)
-        .error(|e: &rustls::Error| {
-            format!("{COMPONENT} (error: {e}) - failed to create QUIC server builder")
-        })
-        .map_err(|_| QuicError::ConfigCreationError)?;
+    let client_cert_verifier =
+        client_cert_verifier(&config.certificate.client_auth).map_err(|e| {
+            error!("{COMPONENT} (error: {e}) - failed to load client auth CA certificates");
+            QuicError::ConfigCreationError
+        })?;
+    let server_config =
+        rustls::ServerConfig::builder_with_protocol_versions(&[&rustls::version::TLS13])
+            .with_client_cert_verifier(client_cert_verifier)
+            .with_single_cert(certificates, private_key)
+            .error(|e: &rustls::Error| {
+                format!("{COMPONENT} (error: {e}) - failed to create QUIC server builder")
+            })
+            .map_err(|_| QuicError::ConfigCreationError)?;
+    let builder = ServerBuilder::new_with_rustls_server_config(server_config);
     let mut transport = TransportConfig::default();
     transport.initial_mtu(config.initial_mtu.as_bytes_u64() as u16);
     transport.send_window(config.send_window.as_bytes_u64());
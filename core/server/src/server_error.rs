@@ -60,6 +60,9 @@ error_set!(
 
         #[display("Cannot archive file: {}", file_path)]
         CannotArchiveFile { file_path: String },
+
+        #[display("Cannot read archived file: {}", file_path)]
+        CannotReadArchivedFile { file_path: String },
     } || IoError
 
     ConnectionError := {
@@ -21,19 +21,23 @@ use super::{
     IggyShard, TaskRegistry, transmission::connector::ShardConnector,
     transmission::frame::ShardFrame,
 };
+use crate::archiver::ArchiverKind;
+use crate::audit::AuditLog;
+use crate::encryption::ServerEncryption;
 use crate::metadata::{Metadata, MetadataWriter};
 use crate::streaming::partitions::local_partitions::LocalPartitions;
 use crate::{
     configs::server::ServerConfig,
     state::file::FileState,
     streaming::{
-        clients::client_manager::ClientManager, diagnostics::metrics::Metrics,
+        clients::client_manager::ClientManager,
+        diagnostics::metrics::Metrics,
+        users::{quota_manager::QuotaManager, token_authenticator::TokenAuthenticator},
         utils::ptr::EternalPtr,
     },
 };
 use ahash::AHashSet;
 use dashmap::DashMap;
-use iggy_common::EncryptorKind;
 use iggy_common::SemanticVersion;
 use iggy_common::sharding::{IggyNamespace, PartitionLocation};
 use std::{
@@ -48,9 +52,12 @@ pub struct IggyShardBuilder {
     shards_table: Option<EternalPtr<DashMap<IggyNamespace, PartitionLocation>>>,
     state: Option<FileState>,
     client_manager: Option<ClientManager>,
+    quota_manager: Option<QuotaManager>,
+    audit_log: Option<AuditLog>,
     connections: Option<Vec<ShardConnector<ShardFrame>>>,
     config: Option<ServerConfig>,
-    encryptor: Option<EncryptorKind>,
+    encryption: Option<ServerEncryption>,
+    archiver: Option<ArchiverKind>,
     version: Option<SemanticVersion>,
     metrics: Option<Metrics>,
     is_follower: bool,
@@ -91,8 +98,23 @@ impl IggyShardBuilder {
         self
     }
 
-    pub fn encryptor(mut self, encryptor: Option<EncryptorKind>) -> Self {
-        self.encryptor = encryptor;
+    pub fn quota_manager(mut self, quota_manager: QuotaManager) -> Self {
+        self.quota_manager = Some(quota_manager);
+        self
+    }
+
+    pub fn audit_log(mut self, audit_log: AuditLog) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    pub fn encryption(mut self, encryption: Option<ServerEncryption>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
+    pub fn archiver(mut self, archiver: Option<ArchiverKind>) -> Self {
+        self.archiver = archiver;
         self
     }
 
@@ -138,8 +160,12 @@ impl IggyShardBuilder {
         let state = self.state.unwrap();
         let config = self.config.unwrap();
         let connections = self.connections.unwrap();
-        let encryptor = self.encryptor;
+        let encryption = self.encryption;
+        let archiver = self.archiver;
         let client_manager = self.client_manager.unwrap();
+        let quota_manager = self.quota_manager.unwrap();
+        let audit_log = self.audit_log.unwrap();
+        let token_authenticator = TokenAuthenticator::new(&config.http.jwt);
         let version = self.version.unwrap();
         let metadata = self.metadata.expect("metadata is required");
         let (stop_receiver, frame_receiver) = connections
@@ -176,7 +202,8 @@ impl IggyShardBuilder {
             metadata_writer: self.metadata_writer.map(RefCell::new),
             local_partitions,
             pending_partition_inits: RefCell::new(AHashSet::new()),
-            encryptor,
+            encryption,
+            archiver,
             config,
             _version: version,
             state,
@@ -194,6 +221,9 @@ impl IggyShardBuilder {
             config_writer_receiver,
             task_registry,
             client_manager,
+            token_authenticator,
+            quota_manager,
+            audit_log,
         }
     }
 }
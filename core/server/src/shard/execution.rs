@@ -15,7 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::binary::dispatch::wire_id_to_identifier;
+use crate::binary::dispatch::{
+    wire_dead_letter_policy_to_policy, wire_id_to_identifier,
+    wire_shared_subscription_to_subscription,
+};
+use crate::metadata::RoleMeta;
+use crate::streaming::deliveries::DeliveryPolicy;
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
 use crate::{
@@ -30,19 +35,21 @@ use crate::{
     state::{
         command::EntryCommand,
         models::{
-            CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash, CreateStreamWithId,
-            CreateTopicWithId, CreateUserWithId,
+            ChangePasswordWithCredentials, CreateConsumerGroupWithId,
+            CreatePersonalAccessTokenWithHash, CreateRoleWithId, CreateStreamWithId,
+            CreateTopicWithId, CreateUserWithId, RotateTopicKeyWithKey,
         },
     },
     streaming::polling_consumer::ConsumerGroupId,
 };
 use iggy_binary_protocol::requests::{
-    consumer_groups::*, partitions::*, personal_access_tokens::*, streams::*, topics::*, users::*,
+    consumer_groups::*, partitions::*, personal_access_tokens::*, roles::*, streams::*, topics::*,
+    users::*,
 };
 use iggy_common::wire_conversions::wire_permissions_to_permissions;
 use iggy_common::{
-    CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, PersonalAccessToken,
-    UserStatus,
+    CleanupPolicy, CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize,
+    PersonalAccessToken, Quota, QuotaScope, UserStatus, Validatable,
 };
 use secrecy::{ExposeSecret, SecretString};
 
@@ -179,12 +186,13 @@ pub async fn execute_purge_stream(
 pub async fn execute_create_topic(
     shard: &IggyShard,
     user_id: u32,
-    wire: CreateTopicRequest,
+    mut wire: CreateTopicRequest,
 ) -> Result<TopicResponseData, IggyError> {
     let stream_id = wire_id_to_identifier(&wire.stream_id)?;
     let compression = CompressionAlgorithm::from_code(wire.compression_algorithm)?;
     let message_expiry = IggyExpiry::from(wire.message_expiry);
     let max_topic_size = MaxTopicSize::from(wire.max_topic_size);
+    let cleanup_policy = CleanupPolicy::from_code(wire.cleanup_policy)?;
     let replication_factor = if wire.replication_factor == 0 {
         None
     } else {
@@ -202,6 +210,7 @@ pub async fn execute_create_topic(
             compression,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
         )
         .await?;
 
@@ -228,8 +237,11 @@ pub async fn execute_create_topic(
             compression_algorithm: topic.compression_algorithm,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
+            cleanup_policy: topic.cleanup_policy,
         }
     });
+    // Persist the algorithm resolved from the compression config, not the requested one.
+    wire.compression_algorithm = response_data.compression_algorithm.as_code();
 
     shard
         .state
@@ -258,13 +270,14 @@ pub async fn execute_create_topic(
 pub async fn execute_update_topic(
     shard: &IggyShard,
     user_id: u32,
-    wire: UpdateTopicRequest,
+    mut wire: UpdateTopicRequest,
 ) -> Result<(), IggyError> {
     let stream_id = wire_id_to_identifier(&wire.stream_id)?;
     let topic_id = wire_id_to_identifier(&wire.topic_id)?;
     let compression = CompressionAlgorithm::from_code(wire.compression_algorithm)?;
     let message_expiry = IggyExpiry::from(wire.message_expiry);
     let max_topic_size = MaxTopicSize::from(wire.max_topic_size);
+    let cleanup_policy = CleanupPolicy::from_code(wire.cleanup_policy)?;
     let replication_factor = if wire.replication_factor == 0 {
         None
     } else {
@@ -283,7 +296,17 @@ pub async fn execute_update_topic(
         compression,
         max_topic_size,
         replication_factor,
+        cleanup_policy,
     )?;
+    // Persist the algorithm resolved from the compression config, not the requested one.
+    wire.compression_algorithm = shard.metadata.with_metadata(|m| {
+        m.streams
+            .get(topic.stream_id)
+            .and_then(|s| s.topics.get(topic.topic_id))
+            .map_or(wire.compression_algorithm, |t| {
+                t.compression_algorithm.as_code()
+            })
+    });
 
     shard
         .state
@@ -366,6 +389,35 @@ pub async fn execute_purge_topic(
     Ok(())
 }
 
+pub async fn execute_rotate_topic_key(
+    shard: &IggyShard,
+    user_id: u32,
+    wire: RotateTopicKeyRequest,
+) -> Result<(), IggyError> {
+    let stream_id = wire_id_to_identifier(&wire.stream_id)?;
+    let topic_id = wire_id_to_identifier(&wire.topic_id)?;
+    let topic = shard.resolve_topic(&stream_id, &topic_id)?;
+    shard
+        .metadata
+        .perm_rotate_topic_key(user_id, topic.stream_id, topic.topic_id)?;
+
+    let (key_id, wrapped_key) = shard.rotate_topic_key(topic)?;
+
+    shard
+        .state
+        .apply(
+            user_id,
+            &EntryCommand::RotateTopicKey(RotateTopicKeyWithKey {
+                key_id,
+                wrapped_key,
+                command: wire,
+            }),
+        )
+        .await?;
+
+    Ok(())
+}
+
 pub async fn execute_create_partitions(
     shard: &IggyShard,
     user_id: u32,
@@ -467,7 +519,40 @@ pub async fn execute_create_consumer_group(
         .metadata
         .perm_create_consumer_group(user_id, topic.stream_id, topic.topic_id)?;
 
-    let group_id = shard.create_consumer_group(topic, wire.name.to_string())?;
+    let dead_letter = wire
+        .dead_letter
+        .as_ref()
+        .map(wire_dead_letter_policy_to_policy)
+        .transpose()?;
+    if let Some(dead_letter) = &dead_letter {
+        dead_letter.validate()?;
+        let dead_letter_topic =
+            shard.resolve_topic(&dead_letter.stream_id, &dead_letter.topic_id)?;
+        if dead_letter_topic.stream_id == topic.stream_id
+            && dead_letter_topic.topic_id == topic.topic_id
+        {
+            return Err(IggyError::InvalidDeadLetterPolicy);
+        }
+        shard.metadata.perm_append_messages(
+            user_id,
+            dead_letter_topic.stream_id,
+            dead_letter_topic.topic_id,
+        )?;
+    }
+
+    let shared = wire
+        .shared
+        .as_ref()
+        .map(wire_shared_subscription_to_subscription);
+    if let Some(shared) = &shared {
+        shared.validate()?;
+    }
+
+    let group_id = shard.create_consumer_group(
+        topic,
+        wire.name.to_string(),
+        DeliveryPolicy::new(dead_letter, shared),
+    )?;
 
     let response_data = shard
         .metadata
@@ -502,9 +587,12 @@ pub async fn execute_delete_consumer_group(
     let topic_id = wire_id_to_identifier(&wire.topic_id)?;
     let group_id = wire_id_to_identifier(&wire.group_id)?;
     let group = shard.resolve_consumer_group(&stream_id, &topic_id, &group_id)?;
-    shard
-        .metadata
-        .perm_delete_consumer_group(user_id, group.stream_id, group.topic_id)?;
+    shard.metadata.perm_delete_consumer_group(
+        user_id,
+        group.stream_id,
+        group.topic_id,
+        group.group_id,
+    )?;
 
     let deleted = shard.delete_consumer_group(group)?;
 
@@ -536,9 +624,12 @@ pub fn execute_join_consumer_group(
     let topic_id = wire_id_to_identifier(&wire.topic_id)?;
     let group_id = wire_id_to_identifier(&wire.group_id)?;
     let group = shard.resolve_consumer_group(&stream_id, &topic_id, &group_id)?;
-    shard
-        .metadata
-        .perm_join_consumer_group(user_id, group.stream_id, group.topic_id)?;
+    shard.metadata.perm_join_consumer_group(
+        user_id,
+        group.stream_id,
+        group.topic_id,
+        group.group_id,
+    )?;
 
     shard.join_consumer_group(client_id, group)?;
 
@@ -592,6 +683,7 @@ pub async fn execute_create_user(
             &EntryCommand::CreateUser(CreateUserWithId {
                 user_id: user.id,
                 command: wal_wire,
+                scram_credentials: user.scram_credentials.as_ref().map(ToString::to_string),
             }),
         )
         .await?;
@@ -649,6 +741,11 @@ pub async fn execute_change_password(
     }
 
     shard.change_password(&target_id, &wire.current_password, &wire.new_password)?;
+    let scram_credentials = shard
+        .get_user(&target_id)?
+        .scram_credentials
+        .as_ref()
+        .map(ToString::to_string);
 
     // Clear current password and hash new password before persisting to WAL
     let wal_wire = ChangePasswordRequest {
@@ -659,7 +756,13 @@ pub async fn execute_change_password(
 
     shard
         .state
-        .apply(user_id, &EntryCommand::ChangePassword(wal_wire))
+        .apply(
+            user_id,
+            &EntryCommand::ChangePassword(ChangePasswordWithCredentials {
+                command: wal_wire,
+                scram_credentials,
+            }),
+        )
         .await?;
 
     Ok(())
@@ -692,6 +795,133 @@ pub async fn execute_update_permissions(
     Ok(())
 }
 
+pub async fn execute_set_user_quota(
+    shard: &IggyShard,
+    user_id: u32,
+    wire: SetUserQuotaRequest,
+) -> Result<(), IggyError> {
+    shard.metadata.perm_set_user_quota(user_id)?;
+
+    let target_id = wire_id_to_identifier(&wire.user_id)?;
+    shard.set_user_quota(
+        &target_id,
+        QuotaScope::from(wire.scope),
+        wire.quota.map(Quota::from),
+    )?;
+
+    shard
+        .state
+        .apply(user_id, &EntryCommand::SetUserQuota(wire))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn execute_create_role(
+    shard: &IggyShard,
+    user_id: u32,
+    wire: CreateRoleRequest,
+) -> Result<RoleMeta, IggyError> {
+    shard.metadata.perm_create_role(user_id)?;
+
+    let permissions = wire
+        .permissions
+        .as_ref()
+        .map(wire_permissions_to_permissions);
+    let role = shard.create_role(wire.name.as_str(), permissions)?;
+
+    shard
+        .state
+        .apply(
+            user_id,
+            &EntryCommand::CreateRole(CreateRoleWithId {
+                role_id: role.id,
+                command: wire,
+            }),
+        )
+        .await?;
+
+    Ok(role)
+}
+
+pub async fn execute_update_role(
+    shard: &IggyShard,
+    user_id: u32,
+    wire: UpdateRoleRequest,
+) -> Result<(), IggyError> {
+    shard.metadata.perm_update_role(user_id)?;
+
+    let role_id = wire_id_to_identifier(&wire.role_id)?;
+    let permissions = wire
+        .permissions
+        .as_ref()
+        .map(wire_permissions_to_permissions);
+    shard.update_role(&role_id, permissions)?;
+
+    shard
+        .state
+        .apply(user_id, &EntryCommand::UpdateRole(wire))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn execute_delete_role(
+    shard: &IggyShard,
+    user_id: u32,
+    wire: DeleteRoleRequest,
+) -> Result<(), IggyError> {
+    shard.metadata.perm_delete_role(user_id)?;
+
+    let role_id = wire_id_to_identifier(&wire.role_id)?;
+    shard.delete_role(&role_id)?;
+
+    shard
+        .state
+        .apply(user_id, &EntryCommand::DeleteRole(wire))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn execute_assign_role(
+    shard: &IggyShard,
+    user_id: u32,
+    wire: AssignRoleRequest,
+) -> Result<(), IggyError> {
+    shard.metadata.perm_assign_role(user_id)?;
+
+    let target_id = wire_id_to_identifier(&wire.user_id)?;
+    let role_id = wire_id_to_identifier(&wire.role_id)?;
+    shard.assign_role(&target_id, &role_id)?;
+
+    shard
+        .state
+        .apply(user_id, &EntryCommand::AssignRole(wire))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn execute_unassign_role(
+    shard: &IggyShard,
+    user_id: u32,
+    wire: UnassignRoleRequest,
+) -> Result<(), IggyError> {
+    shard.metadata.perm_unassign_role(user_id)?;
+
+    let target_id = wire_id_to_identifier(&wire.user_id)?;
+    let role_id = wire_id_to_identifier(&wire.role_id)?;
+    shard.unassign_role(&target_id, &role_id)?;
+
+    shard
+        .state
+        .apply(user_id, &EntryCommand::UnassignRole(wire))
+        .await?;
+
+    Ok(())
+}
+
 pub async fn execute_create_personal_access_token(
     shard: &IggyShard,
     user_id: u32,
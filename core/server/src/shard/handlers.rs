@@ -25,13 +25,20 @@ use crate::{
             message::{ShardMessage, ShardRequest, ShardRequestPayload},
         },
     },
+    streaming::{
+        polling_consumer::{ConsumerGroupId, MemberId, PollingConsumer},
+        scheduled::find_delayed_messages,
+        transactions::TransactionAppend,
+    },
     tcp::{
         connection_handler::{ConnectionAction, handle_connection, handle_error},
         tcp_listener::cleanup_connection,
     },
 };
 use compio::net::TcpStream;
-use iggy_common::{IggyError, SenderKind, TransportProtocol, sharding::IggyNamespace};
+use iggy_common::{
+    IggyError, IggyTimestamp, SenderKind, TransportProtocol, sharding::IggyNamespace,
+};
 use nix::sys::stat::SFlag;
 use std::os::fd::{FromRawFd, IntoRawFd};
 use tracing::info;
@@ -59,22 +66,37 @@ async fn handle_request(
     // Data-plane operations extract namespace from routing
     let namespace = request.routing;
     match request.payload {
-        ShardRequestPayload::SendMessages { batch } => {
-            let batch = shard.maybe_encrypt_messages(batch)?;
-            let messages_count = batch.count();
-
+        ShardRequestPayload::SendMessages {
+            batch,
+            transaction_id,
+            producer,
+        } => {
+            let delayed = find_delayed_messages(&batch, IggyTimestamp::now())?;
             let namespace = namespace.expect("SendMessages requires routing namespace");
+            let batch = shard.maybe_encrypt_messages(&namespace, batch)?;
+            let messages_count = batch.count();
 
             shard.ensure_partition(&namespace).await?;
 
             shard
-                .append_messages_to_local_partition(&namespace, batch, &shard.config.system)
+                .append_messages_to_local_partition(
+                    &namespace,
+                    batch,
+                    transaction_id.map(TransactionAppend::Messages),
+                    producer,
+                    delayed,
+                    &shard.config.system,
+                )
                 .await?;
 
             shard.metrics.increment_messages(messages_count as u64);
             Ok(ShardResponse::SendMessages)
         }
-        ShardRequestPayload::PollMessages { args, consumer } => {
+        ShardRequestPayload::PollMessages {
+            args,
+            consumer,
+            delivery,
+        } => {
             let namespace = namespace.expect("PollMessages requires routing namespace");
 
             if args.count == 0 {
@@ -90,26 +112,110 @@ async fn handle_request(
                         current_offset,
                     ),
                     crate::streaming::segments::IggyMessagesBatchSet::empty(),
+                    Vec::new(),
                 )));
             }
 
             let auto_commit = args.auto_commit;
+            let count = args.count;
+            let is_shared = delivery.as_ref().is_some_and(|policy| policy.is_shared());
 
             shard.ensure_partition(&namespace).await?;
 
-            let (poll_metadata, batches) = shard
+            // The shared subscription skips the acknowledged and in-flight messages following
+            // the stored offset, so poll enough of them to fill the requested count. It also skips
+            // the delayed messages instead of stopping before them, as they're acknowledged one by one.
+            let mut args = args;
+            if let (PollingConsumer::ConsumerGroup(group_id, _), true) = (consumer, is_shared) {
+                args.skip_scheduled = true;
+                let tracked_count = shard
+                    .local_partitions
+                    .borrow()
+                    .get(&namespace)
+                    .map_or(0, |partition| {
+                        partition.deliveries.tracked_count(group_id.0)
+                    });
+                args.count = args.count.saturating_add(tracked_count as u32);
+            }
+
+            let (poll_metadata, mut batches) = shard
                 .poll_messages_from_local_partition(&namespace, consumer, args)
                 .await?;
 
-            if auto_commit && !batches.is_empty() {
-                let offset = batches
-                    .last_offset()
-                    .expect("Batch set should have at least one batch");
+            let (dead_letters, settled_offset) = match (consumer, &delivery) {
+                (PollingConsumer::ConsumerGroup(group_id, _), Some(policy)) => shard
+                    .track_deliveries_in_local_partition(
+                        &namespace,
+                        group_id,
+                        policy,
+                        count,
+                        auto_commit && is_shared,
+                        &mut batches,
+                    ),
+                _ => (Vec::new(), None),
+            };
+
+            let commit_offset = if auto_commit && !is_shared {
+                batches.last_offset().max(settled_offset)
+            } else {
+                settled_offset
+            };
+            if let Some(offset) = commit_offset {
                 shard
                     .auto_commit_consumer_offset_from_local_partition(&namespace, consumer, offset)
                     .await?;
             }
-            Ok(ShardResponse::PollMessages((poll_metadata, batches)))
+            if let PollingConsumer::ConsumerGroup(group_id, _) = consumer
+                && is_shared
+                && (auto_commit || !dead_letters.is_empty())
+            {
+                shard
+                    .persist_consumer_group_acks(&namespace, group_id)
+                    .await?;
+            }
+            Ok(ShardResponse::PollMessages((
+                poll_metadata,
+                batches,
+                dead_letters,
+            )))
+        }
+        ShardRequestPayload::NackMessage {
+            group_id,
+            policy,
+            offset,
+            reason,
+        } => {
+            let namespace = namespace.expect("NackMessage requires routing namespace");
+            shard.ensure_partition(&namespace).await?;
+            shard
+                .nack_message_in_local_partition(&namespace, group_id, &policy, offset, &reason)?;
+            Ok(ShardResponse::NackMessage)
+        }
+        ShardRequestPayload::AckMessages {
+            group_id,
+            policy,
+            offsets,
+        } => {
+            let namespace = namespace.expect("AckMessages requires routing namespace");
+            shard.ensure_partition(&namespace).await?;
+            let group_id = ConsumerGroupId(group_id);
+            if let Some(offset) =
+                shard.ack_messages_in_local_partition(&namespace, group_id, &policy, &offsets)?
+            {
+                // Acknowledgements aren't bound to any member of the shared subscription,
+                // so committing them never completes a pending partition revocation.
+                shard
+                    .auto_commit_consumer_offset_from_local_partition(
+                        &namespace,
+                        PollingConsumer::ConsumerGroup(group_id, MemberId(usize::MAX)),
+                        offset,
+                    )
+                    .await?;
+            }
+            shard
+                .persist_consumer_group_acks(&namespace, group_id)
+                .await?;
+            Ok(ShardResponse::AckMessages)
         }
         ShardRequestPayload::FlushUnsavedBuffer { fsync } => {
             let ns = namespace.expect("FlushUnsavedBuffer requires routing namespace");
@@ -146,6 +252,42 @@ async fn handle_request(
                 deleted_messages,
             })
         }
+        ShardRequestPayload::CompactTopicMessages {
+            stream_id,
+            topic_id,
+            partition_ids,
+        } => {
+            let (compacted_segments, deleted_segments, removed_messages) = shard
+                .compact_topic_messages(stream_id, topic_id, &partition_ids)
+                .await?;
+            Ok(ShardResponse::CompactTopicMessages {
+                compacted_segments,
+                deleted_segments,
+                removed_messages,
+            })
+        }
+        ShardRequestPayload::OffloadSegments {
+            stream_id,
+            topic_id,
+            partition_id,
+            segments,
+        } => {
+            let offloaded_segments = shard
+                .offload_segments(stream_id, topic_id, partition_id, &segments)
+                .await?;
+            Ok(ShardResponse::OffloadSegments { offloaded_segments })
+        }
+        ShardRequestPayload::CompleteTransaction {
+            transaction_id,
+            committed,
+        } => {
+            let ns = namespace.expect("CompleteTransaction requires routing namespace");
+            shard.ensure_partition(&ns).await?;
+            shard
+                .complete_transaction_in_local_partition(&ns, transaction_id, committed)
+                .await?;
+            Ok(ShardResponse::CompleteTransaction)
+        }
         ShardRequestPayload::CreatePartitionsRequest { user_id, command } => {
             assert_eq!(
                 shard.id, 0,
@@ -255,6 +397,54 @@ async fn handle_request(
             execution::execute_change_password(shard, user_id, command).await?;
             Ok(ShardResponse::ChangePasswordResponse)
         }
+        ShardRequestPayload::SetUserQuotaRequest { user_id, command } => {
+            assert_eq!(
+                shard.id, 0,
+                "SetUserQuotaRequest should only be handled by shard0"
+            );
+            execution::execute_set_user_quota(shard, user_id, command).await?;
+            Ok(ShardResponse::SetUserQuotaResponse)
+        }
+        ShardRequestPayload::CreateRoleRequest { user_id, command } => {
+            assert_eq!(
+                shard.id, 0,
+                "CreateRoleRequest should only be handled by shard0"
+            );
+            let role = execution::execute_create_role(shard, user_id, command).await?;
+            Ok(ShardResponse::CreateRoleResponse(role))
+        }
+        ShardRequestPayload::UpdateRoleRequest { user_id, command } => {
+            assert_eq!(
+                shard.id, 0,
+                "UpdateRoleRequest should only be handled by shard0"
+            );
+            execution::execute_update_role(shard, user_id, command).await?;
+            Ok(ShardResponse::UpdateRoleResponse)
+        }
+        ShardRequestPayload::DeleteRoleRequest { user_id, command } => {
+            assert_eq!(
+                shard.id, 0,
+                "DeleteRoleRequest should only be handled by shard0"
+            );
+            execution::execute_delete_role(shard, user_id, command).await?;
+            Ok(ShardResponse::DeleteRoleResponse)
+        }
+        ShardRequestPayload::AssignRoleRequest { user_id, command } => {
+            assert_eq!(
+                shard.id, 0,
+                "AssignRoleRequest should only be handled by shard0"
+            );
+            execution::execute_assign_role(shard, user_id, command).await?;
+            Ok(ShardResponse::AssignRoleResponse)
+        }
+        ShardRequestPayload::UnassignRoleRequest { user_id, command } => {
+            assert_eq!(
+                shard.id, 0,
+                "UnassignRoleRequest should only be handled by shard0"
+            );
+            execution::execute_unassign_role(shard, user_id, command).await?;
+            Ok(ShardResponse::UnassignRoleResponse)
+        }
         ShardRequestPayload::UpdateUserRequest { user_id, command } => {
             assert_eq!(
                 shard.id, 0,
@@ -406,14 +596,21 @@ async fn handle_request(
             let registry = shard.task_registry.clone();
             let registry_clone = registry.clone();
 
-            let batch = shard.maybe_encrypt_messages(initial_data)?;
-            let messages_count = batch.count();
-
+            let delayed = find_delayed_messages(&initial_data, IggyTimestamp::now())?;
             let ns = namespace.expect("SocketTransfer requires routing namespace");
+            let batch = shard.maybe_encrypt_messages(&ns, initial_data)?;
+            let messages_count = batch.count();
             shard.ensure_partition(&ns).await?;
 
             shard
-                .append_messages_to_local_partition(&ns, batch, &shard.config.system)
+                .append_messages_to_local_partition(
+                    &ns,
+                    batch,
+                    None,
+                    None,
+                    delayed,
+                    &shard.config.system,
+                )
                 .await?;
 
             shard.metrics.increment_messages(messages_count as u64);
@@ -471,6 +668,15 @@ async fn handle_request(
             execution::execute_purge_topic(shard, user_id, command).await?;
             Ok(ShardResponse::PurgeTopicResponse)
         }
+        ShardRequestPayload::RotateTopicKeyRequest { user_id, command } => {
+            assert_eq!(
+                shard.id, 0,
+                "RotateTopicKeyRequest should only be handled by shard0"
+            );
+
+            execution::execute_rotate_topic_key(shard, user_id, command).await?;
+            Ok(ShardResponse::RotateTopicKeyResponse)
+        }
     }
 }
 
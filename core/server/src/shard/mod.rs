@@ -19,8 +19,11 @@
 
 use self::tasks::{continuous, periodic};
 use crate::{
+    archiver::ArchiverKind,
+    audit::AuditLog,
     bootstrap::load_segments,
     configs::server::ServerConfig,
+    encryption::ServerEncryption,
     metadata::{Metadata, MetadataWriter},
     shard::{task_registry::TaskRegistry, transmission::frame::ShardFrame},
     state::file::FileState,
@@ -29,6 +32,7 @@ use crate::{
         diagnostics::metrics::Metrics,
         partitions::{local_partition::LocalPartition, local_partitions::LocalPartitions},
         session::Session,
+        users::{quota_manager::QuotaManager, token_authenticator::TokenAuthenticator},
         utils::ptr::EternalPtr,
     },
 };
@@ -37,7 +41,7 @@ use builder::IggyShardBuilder;
 use dashmap::DashMap;
 use iggy_common::SemanticVersion;
 use iggy_common::sharding::{IggyNamespace, PartitionLocation};
-use iggy_common::{EncryptorKind, IggyByteSize, IggyError};
+use iggy_common::{IggyByteSize, IggyError};
 use std::{
     cell::{Cell, RefCell},
     net::SocketAddr,
@@ -83,9 +87,13 @@ pub struct IggyShard {
     pub(crate) shards_table: EternalPtr<DashMap<IggyNamespace, PartitionLocation>>,
     pub(crate) state: FileState,
 
-    pub(crate) encryptor: Option<EncryptorKind>,
+    pub(crate) encryption: Option<ServerEncryption>,
+    pub(crate) archiver: Option<ArchiverKind>,
     pub(crate) config: ServerConfig,
     pub(crate) client_manager: ClientManager,
+    pub(crate) token_authenticator: TokenAuthenticator,
+    pub(crate) quota_manager: QuotaManager,
+    pub(crate) audit_log: AuditLog,
     pub(crate) metrics: Metrics,
     pub(crate) is_follower: bool,
     /// Index into `config.cluster.nodes` that describes this running node.
@@ -163,6 +171,14 @@ impl IggyShard {
             periodic::spawn_message_cleaner(self.clone());
         }
 
+        if self.config.data_maintenance.compaction.enabled {
+            periodic::spawn_message_compactor(self.clone());
+        }
+
+        if self.config.data_maintenance.archiver.enabled {
+            periodic::spawn_segment_archiver(self.clone());
+        }
+
         if self.config.heartbeat.enabled {
             periodic::spawn_heartbeat_verifier(self.clone());
         }
@@ -403,7 +419,7 @@ impl IggyShard {
 
                         let revision_id = init_info.revision_id;
 
-                        let partition = LocalPartition::with_log(
+                        let mut partition = LocalPartition::with_log(
                             loaded_log,
                             stats,
                             Arc::new(AtomicU64::new(current_offset)),
@@ -414,6 +430,21 @@ impl IggyShard {
                             revision_id,
                             should_increment_offset,
                         );
+                        partition.transactions = self
+                            .load_partition_transactions(
+                                namespace,
+                                should_increment_offset.then_some(current_offset),
+                            )
+                            .await?;
+                        partition.producers = self.load_partition_producers(
+                            namespace,
+                            should_increment_offset.then_some(current_offset),
+                        )?;
+                        partition.deliveries = self.load_partition_deliveries(namespace)?;
+                        partition.schedule = self.load_partition_schedule(
+                            namespace,
+                            should_increment_offset.then_some(current_offset),
+                        )?;
 
                         self.local_partitions
                             .borrow_mut()
@@ -34,6 +34,7 @@ impl IggyShard {
 
     pub async fn delete_client(&self, client_id: u32) {
         let consumer_groups: Vec<(u32, u32, u32)>;
+        self.abort_client_transaction(client_id).await;
 
         {
             let client = self.client_manager.try_get_client(client_id);
@@ -81,6 +82,7 @@ impl IggyShard {
             }
         }
         self.client_manager.delete_client(client_id);
+        self.quota_manager.delete_client(client_id);
     }
 
     pub fn get_client(&self, client_id: u32) -> Option<Client> {
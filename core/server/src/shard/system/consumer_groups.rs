@@ -18,11 +18,23 @@
 
 use super::COMPONENT;
 use crate::shard::IggyShard;
-use crate::shard::transmission::message::{ResolvedConsumerGroup, ResolvedTopic};
+use crate::shard::system::messages::PollingArgs;
+use crate::shard::transmission::frame::ShardResponse;
+use crate::shard::transmission::message::{
+    ResolvedConsumerGroup, ResolvedTopic, ShardRequest, ShardRequestPayload,
+};
+use crate::streaming::deliveries::DeliveryPolicy;
+use crate::streaming::diagnostics::metrics::ConsumerGroupLagLabels;
+use crate::streaming::polling_consumer::{ConsumerGroupId, PollingConsumer};
 use err_trail::ErrContext;
-use iggy_common::Identifier;
-use iggy_common::IggyError;
+use iggy_common::sharding::IggyNamespace;
+use iggy_common::{
+    ConsumerGroupLag, ConsumerGroupPartitionLag, Identifier, IggyDuration, IggyError,
+    IggyTimestamp, PollingStrategy,
+};
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use tracing::warn;
 
 pub struct DeletedConsumerGroup {
     pub group_id: usize,
@@ -34,6 +46,7 @@ impl IggyShard {
         &self,
         topic: ResolvedTopic,
         name: String,
+        delivery: Option<DeliveryPolicy>,
     ) -> Result<usize, IggyError> {
         let stream = topic.stream_id;
         let topic_id = topic.topic_id;
@@ -48,6 +61,7 @@ impl IggyShard {
                 topic_id,
                 Arc::from(name.as_str()),
                 partitions_count,
+                delivery,
             )
             .map_err(|e| {
                 if let IggyError::ConsumerGroupNameAlreadyExists(_, _) = &e {
@@ -203,4 +217,141 @@ impl IggyShard {
 
         Ok(())
     }
+
+    /// Returns the lag of the consumer group in each of its partitions.
+    pub async fn get_consumer_group_lag(
+        &self,
+        user_id: u32,
+        group: ResolvedConsumerGroup,
+    ) -> Result<Option<ConsumerGroupLag>, IggyError> {
+        self.metadata
+            .perm_get_consumer_group(user_id, group.stream_id, group.topic_id)?;
+        let Some(consumer_group) =
+            self.metadata
+                .get_consumer_group(group.stream_id, group.topic_id, group.group_id)
+        else {
+            return Ok(None);
+        };
+
+        let mut partitions = Vec::with_capacity(consumer_group.partitions.len());
+        for &partition_id in &consumer_group.partitions {
+            if let Some(lag) = self
+                .get_consumer_group_partition_lag(group, partition_id)
+                .await?
+            {
+                partitions.push(lag);
+            }
+        }
+        Ok(Some(ConsumerGroupLag {
+            group_id: group.group_id as u32,
+            partitions,
+        }))
+    }
+
+    /// Computes the lag of all the consumer groups and exposes it as the Prometheus gauges.
+    pub async fn update_consumer_group_lag_metrics(&self) {
+        self.metrics.clear_consumer_group_lag();
+        for stream_id in self.metadata.get_stream_ids() {
+            let Some(stream) = self.metadata.get_stream(stream_id) else {
+                continue;
+            };
+            for topic_id in self.metadata.get_topic_ids(stream_id) {
+                let Some(topic) = self.metadata.get_topic(stream_id, topic_id) else {
+                    continue;
+                };
+                for consumer_group in self.metadata.get_all_consumer_groups(stream_id, topic_id) {
+                    let group = ResolvedConsumerGroup {
+                        stream_id,
+                        topic_id,
+                        group_id: consumer_group.id,
+                    };
+                    for &partition_id in &consumer_group.partitions {
+                        let lag = match self
+                            .get_consumer_group_partition_lag(group, partition_id)
+                            .await
+                        {
+                            Ok(Some(lag)) => lag,
+                            Ok(None) => continue,
+                            Err(error) => {
+                                warn!(
+                                    "Failed to get lag of consumer group: {} in partition: {partition_id}, topic: {}, stream: {}. {error}",
+                                    consumer_group.name, topic.name, stream.name
+                                );
+                                continue;
+                            }
+                        };
+                        let labels = ConsumerGroupLagLabels {
+                            stream: stream.name.to_string(),
+                            topic: topic.name.to_string(),
+                            consumer_group: consumer_group.name.to_string(),
+                            partition: partition_id as u32,
+                        };
+                        self.metrics.set_consumer_group_lag(&labels, &lag);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The lag is the number of messages following the committed offset (or all of them,
+    /// if there's none), and the lag time is the age of the oldest of these messages.
+    async fn get_consumer_group_partition_lag(
+        &self,
+        group: ResolvedConsumerGroup,
+        partition_id: usize,
+    ) -> Result<Option<ConsumerGroupPartitionLag>, IggyError> {
+        let namespace = IggyNamespace::new(group.stream_id, group.topic_id, partition_id);
+        let Some(stats) = self.metadata.get_partition_stats(&namespace) else {
+            return Ok(None);
+        };
+        let log_end_offset = stats.current_offset();
+        let messages_count = stats.messages_count_inconsistent();
+        let committed_offset = self
+            .metadata
+            .get_partition_consumer_group_offsets(group.stream_id, group.topic_id, partition_id)
+            .and_then(|offsets| {
+                offsets
+                    .pin()
+                    .get(&ConsumerGroupId(group.group_id))
+                    .map(|item| item.offset.load(Ordering::Relaxed))
+            });
+        let lag = match committed_offset {
+            _ if messages_count == 0 => 0,
+            Some(offset) => log_end_offset.saturating_sub(offset).min(messages_count),
+            None => messages_count,
+        };
+
+        let mut lag_time = IggyDuration::default();
+        if lag > 0 {
+            let strategy = match committed_offset {
+                Some(offset) => PollingStrategy::offset(offset + 1),
+                None => PollingStrategy::first(),
+            };
+            let mut args = PollingArgs::new(strategy, 1, false);
+            args.skip_scheduled = true;
+            let payload = ShardRequestPayload::PollMessages {
+                consumer: PollingConsumer::consumer_group(group.group_id, 0),
+                args,
+                delivery: None,
+            };
+            let request = ShardRequest::data_plane(namespace, payload);
+            let batch = match self.send_to_data_plane(request).await? {
+                ShardResponse::PollMessages((_, batch, _)) => batch,
+                ShardResponse::ErrorResponse(err) => return Err(err),
+                _ => unreachable!("Expected PollMessages response"),
+            };
+            if let Some(timestamp) = batch.first_timestamp() {
+                lag_time =
+                    IggyDuration::from(IggyTimestamp::now().as_micros().saturating_sub(timestamp));
+            }
+        }
+
+        Ok(Some(ConsumerGroupPartitionLag {
+            partition_id: partition_id as u32,
+            committed_offset,
+            log_end_offset,
+            lag,
+            lag_time,
+        }))
+    }
 }
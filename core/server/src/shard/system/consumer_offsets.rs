@@ -254,6 +254,22 @@ impl IggyShard {
                         )
                     })?;
             }
+
+            let acks_path = format!(
+                "{}/{}",
+                self.config
+                    .system
+                    .get_consumer_group_acks_path(stream_id, topic_id, partition_id),
+                cg_id.0
+            );
+            self.delete_consumer_offset_from_disk(&acks_path)
+                .await
+                .error(|e: &IggyError| {
+                    format!(
+                        "{COMPONENT} (error: {e}) - failed to delete consumer group acks file for group with ID: {} in partition {} of topic with ID: {} and stream with ID: {}",
+                        cg_id, partition_id, topic_id, stream_id
+                    )
+                })?;
         }
 
         Ok(())
@@ -0,0 +1,514 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::shard::IggyShard;
+use crate::shard::transmission::frame::ShardResponse;
+use crate::shard::transmission::message::{
+    ResolvedConsumerGroup, ResolvedPartition, ResolvedTopic, ShardRequest, ShardRequestPayload,
+};
+use crate::streaming::deliveries::{DeadLetter, Delivery, DeliveryPolicy, PartitionDeliveries};
+use crate::streaming::persistence::persister::FileWithSyncPersister;
+use crate::streaming::polling_consumer::ConsumerGroupId;
+use crate::streaming::segments::{IggyMessagesBatchMut, IggyMessagesBatchSet};
+use bytes::Bytes;
+use iggy_common::sharding::IggyNamespace;
+use iggy_common::{
+    DEAD_LETTER_CONSUMER_GROUP_HEADER_KEY, DEAD_LETTER_DELIVERY_COUNT_HEADER_KEY,
+    DEAD_LETTER_ORIGIN_OFFSET_HEADER_KEY, DEAD_LETTER_ORIGIN_PARTITION_HEADER_KEY,
+    DEAD_LETTER_ORIGIN_STREAM_HEADER_KEY, DEAD_LETTER_ORIGIN_TOPIC_HEADER_KEY,
+    DEAD_LETTER_REASON_HEADER_KEY, DeadLetterPolicy, HeaderKey, HeaderValue, Identifier, IggyError,
+    IggyMessage, IggyTimestamp, Sizeable,
+};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use tracing::{error, info, warn};
+
+impl IggyShard {
+    /// Registers the deliveries of the polled messages to the consumer group with the delivery policy.
+    ///
+    /// The messages exceeding the maximum delivery count are removed from the polled batches and returned
+    /// as dead letters, along with the offset up to which the consumer group can commit the leading
+    /// acknowledged and dead-lettered messages, so they are never polled again. The messages in flight
+    /// and the ones above the `limit` of delivered messages are removed as well, while the delivered ones
+    /// are acknowledged right away if `ack_delivered` is set.
+    pub(crate) fn track_deliveries_in_local_partition(
+        &self,
+        namespace: &IggyNamespace,
+        group_id: ConsumerGroupId,
+        policy: &Arc<DeliveryPolicy>,
+        limit: u32,
+        ack_delivered: bool,
+        batches: &mut IggyMessagesBatchSet,
+    ) -> (Vec<DeadLetter>, Option<u64>) {
+        let mut partitions = self.local_partitions.borrow_mut();
+        let Some(partition) = partitions.get_mut(namespace) else {
+            return (Vec::new(), None);
+        };
+
+        let stored_offset = partition
+            .consumer_group_offsets
+            .pin()
+            .get(&group_id)
+            .map(|item| item.offset.load(Ordering::Relaxed));
+        if let Some(stored_offset) = stored_offset {
+            partition.deliveries.prune(group_id.0, stored_offset);
+        }
+
+        let now = IggyTimestamp::now();
+        let mut dead_letters = Vec::new();
+        let mut delivered_count = 0;
+        let mut delivered = IggyMessagesBatchSet::empty();
+        let polled = std::mem::replace(batches, IggyMessagesBatchSet::empty());
+        for mut batch in polled.into_inner() {
+            let mut removed = Vec::new();
+            for (index, message) in batch.iter().enumerate() {
+                let offset = message.header().offset();
+                if delivered_count >= limit {
+                    removed.push(index as u32);
+                    continue;
+                }
+
+                match partition
+                    .deliveries
+                    .deliver(group_id.0, policy, offset, now)
+                {
+                    Delivery::Delivered => {
+                        delivered_count += 1;
+                        if ack_delivered {
+                            _ = partition
+                                .deliveries
+                                .ack(group_id.0, policy, &[offset], None);
+                        }
+                        continue;
+                    }
+                    Delivery::DeadLettered {
+                        delivery_count,
+                        reason,
+                    } => dead_letters.push(DeadLetter {
+                        offset,
+                        delivery_count,
+                        reason,
+                        message: IggyMessage {
+                            header: message.header().to_header(),
+                            payload: Bytes::copy_from_slice(message.payload()),
+                            user_headers: message.user_headers().map(Bytes::copy_from_slice),
+                        },
+                    }),
+                    Delivery::InFlight | Delivery::Skipped => {}
+                }
+                removed.push(index as u32);
+            }
+
+            if removed.len() == batch.count() as usize {
+                continue;
+            }
+            if !removed.is_empty() {
+                let base_position = batch.indexes().base_position();
+                batch.remove_messages(&removed, base_position);
+            }
+            delivered.add_batch(batch);
+        }
+
+        *batches = delivered;
+        let settled_offset = partition
+            .deliveries
+            .settled_offset(group_id.0, stored_offset);
+        (dead_letters, settled_offset)
+    }
+
+    /// Appends the dead letters of the consumer group to its dead-letter topic.
+    ///
+    /// The dead letters are already removed from the consumer group deliveries,
+    /// so the failure to append them is logged rather than failing the poll.
+    pub(crate) async fn move_to_dead_letter_topic(
+        &self,
+        topic: ResolvedTopic,
+        partition_id: usize,
+        group_id: usize,
+        policy: &DeadLetterPolicy,
+        dead_letters: Vec<DeadLetter>,
+    ) {
+        let count = dead_letters.len();
+        if let Err(error) = self
+            .append_dead_letters(topic, partition_id, group_id, policy, dead_letters)
+            .await
+        {
+            error!(
+                "Failed to move {count} message(s) from partition: {partition_id} of topic with ID: {}, stream with ID: {} to dead-letter topic: {}, stream: {} for consumer group with ID: {group_id}. {error}",
+                topic.topic_id, topic.stream_id, policy.topic_id, policy.stream_id
+            );
+            return;
+        }
+
+        info!(
+            "Moved {count} message(s) from partition: {partition_id} of topic with ID: {}, stream with ID: {} to dead-letter topic: {}, stream: {} for consumer group with ID: {group_id}.",
+            topic.topic_id, topic.stream_id, policy.topic_id, policy.stream_id
+        );
+    }
+
+    async fn append_dead_letters(
+        &self,
+        topic: ResolvedTopic,
+        partition_id: usize,
+        group_id: usize,
+        policy: &DeadLetterPolicy,
+        dead_letters: Vec<DeadLetter>,
+    ) -> Result<(), IggyError> {
+        let dead_letter_topic = self.resolve_topic(&policy.stream_id, &policy.topic_id)?;
+        let dead_letter_partition_id = self
+            .metadata
+            .get_next_partition_id(dead_letter_topic.stream_id, dead_letter_topic.topic_id)
+            .ok_or_else(|| {
+                IggyError::TopicIdNotFound(policy.stream_id.clone(), policy.topic_id.clone())
+            })?;
+        let group_name = self
+            .metadata
+            .get_consumer_group(topic.stream_id, topic.topic_id, group_id)
+            .map(|group| group.name.to_string())
+            .unwrap_or_else(|| group_id.to_string());
+
+        let encryptor = self.topic_encryptor(topic.stream_id, topic.topic_id);
+        let mut messages = Vec::with_capacity(dead_letters.len());
+        let mut messages_size = 0;
+        for dead_letter in dead_letters {
+            let mut message = dead_letter.message;
+            if let Some(encryptor) = &encryptor {
+                message.payload = Bytes::from(encryptor.decrypt(&message.payload)?);
+                message.user_headers = message
+                    .user_headers
+                    .map(|user_headers| encryptor.decrypt(&user_headers).map(Bytes::from))
+                    .transpose()?;
+            }
+
+            let mut headers = message.user_headers_map()?.unwrap_or_default();
+            headers.extend([
+                header(
+                    DEAD_LETTER_ORIGIN_STREAM_HEADER_KEY,
+                    (topic.stream_id as u32).into(),
+                )?,
+                header(
+                    DEAD_LETTER_ORIGIN_TOPIC_HEADER_KEY,
+                    (topic.topic_id as u32).into(),
+                )?,
+                header(
+                    DEAD_LETTER_ORIGIN_PARTITION_HEADER_KEY,
+                    (partition_id as u32).into(),
+                )?,
+                header(
+                    DEAD_LETTER_ORIGIN_OFFSET_HEADER_KEY,
+                    dead_letter.offset.into(),
+                )?,
+                header(
+                    DEAD_LETTER_CONSUMER_GROUP_HEADER_KEY,
+                    HeaderValue::from_str(&group_name)?,
+                )?,
+                header(
+                    DEAD_LETTER_DELIVERY_COUNT_HEADER_KEY,
+                    dead_letter.delivery_count.into(),
+                )?,
+                header(
+                    DEAD_LETTER_REASON_HEADER_KEY,
+                    HeaderValue::from_str(&dead_letter.reason)?,
+                )?,
+            ]);
+
+            let message = IggyMessage::builder()
+                .id(message.header.id)
+                .payload(message.payload)
+                .user_headers(headers)
+                .build()?;
+            messages_size += message.get_size_bytes().as_bytes_u32();
+            messages.push(message);
+        }
+
+        let batch = IggyMessagesBatchMut::from_messages(&messages, messages_size);
+        let batch = self.maybe_encrypt_messages(
+            &IggyNamespace::new(
+                dead_letter_topic.stream_id,
+                dead_letter_topic.topic_id,
+                dead_letter_partition_id,
+            ),
+            batch,
+        )?;
+        self.append_messages(
+            ResolvedPartition {
+                stream_id: dead_letter_topic.stream_id,
+                topic_id: dead_letter_topic.topic_id,
+                partition_id: dead_letter_partition_id,
+            },
+            batch,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Negatively acknowledges the message delivered to the consumer group, so its next delivery
+    /// counts as a failed one.
+    pub async fn nack_message(
+        &self,
+        user_id: u32,
+        client_id: u32,
+        group: ResolvedConsumerGroup,
+        partition_id: usize,
+        offset: u64,
+        reason: String,
+    ) -> Result<(), IggyError> {
+        self.metadata.perm_nack_message(
+            user_id,
+            group.stream_id,
+            group.topic_id,
+            group.group_id,
+        )?;
+        if !self
+            .metadata
+            .partition_exists(group.stream_id, group.topic_id, partition_id)
+        {
+            return Err(IggyError::MessageNotDelivered(offset));
+        }
+        let Some(policy) = self.metadata.get_consumer_group_delivery(
+            group.stream_id,
+            group.topic_id,
+            group.group_id,
+        ) else {
+            warn!(
+                "Cannot negatively acknowledge message with offset: {offset}, consumer group with ID: {} has no delivery policy.",
+                group.group_id
+            );
+            return Err(IggyError::MessageNotDelivered(offset));
+        };
+        self.ensure_consumer_group_member(client_id, &group)?;
+
+        let namespace = IggyNamespace::new(group.stream_id, group.topic_id, partition_id);
+        let payload = ShardRequestPayload::NackMessage {
+            group_id: group.group_id,
+            policy,
+            offset,
+            reason,
+        };
+        let request = ShardRequest::data_plane(namespace, payload);
+        match self.send_to_data_plane(request).await? {
+            ShardResponse::NackMessage => Ok(()),
+            ShardResponse::ErrorResponse(err) => Err(err),
+            _ => unreachable!("Expected NackMessage response"),
+        }
+    }
+
+    pub(crate) fn nack_message_in_local_partition(
+        &self,
+        namespace: &IggyNamespace,
+        group_id: usize,
+        policy: &Arc<DeliveryPolicy>,
+        offset: u64,
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        let mut partitions = self.local_partitions.borrow_mut();
+        let Some(partition) = partitions.get_mut(namespace) else {
+            return Err(IggyError::MessageNotDelivered(offset));
+        };
+        partition.deliveries.nack(group_id, policy, offset, reason)
+    }
+
+    /// Acknowledges the messages delivered to the consumer group with the shared subscription,
+    /// so they are never redelivered to any of its members.
+    pub async fn ack_messages(
+        &self,
+        user_id: u32,
+        client_id: u32,
+        group: ResolvedConsumerGroup,
+        partition_id: usize,
+        offsets: Vec<u64>,
+    ) -> Result<(), IggyError> {
+        self.metadata.perm_ack_messages(
+            user_id,
+            group.stream_id,
+            group.topic_id,
+            group.group_id,
+        )?;
+        if !self
+            .metadata
+            .partition_exists(group.stream_id, group.topic_id, partition_id)
+        {
+            return Err(IggyError::PartitionNotFound(
+                partition_id,
+                Identifier::numeric(group.topic_id as u32).expect("valid topic id"),
+                Identifier::numeric(group.stream_id as u32).expect("valid stream id"),
+            ));
+        }
+        let Some(policy) = self
+            .metadata
+            .get_consumer_group_delivery(group.stream_id, group.topic_id, group.group_id)
+            .filter(|policy| policy.is_shared())
+        else {
+            return Err(IggyError::ConsumerGroupNotShared(
+                Identifier::numeric(group.group_id as u32).expect("valid group id"),
+                Identifier::numeric(group.topic_id as u32).expect("valid topic id"),
+            ));
+        };
+        self.ensure_consumer_group_member(client_id, &group)?;
+
+        let namespace = IggyNamespace::new(group.stream_id, group.topic_id, partition_id);
+        let payload = ShardRequestPayload::AckMessages {
+            group_id: group.group_id,
+            policy,
+            offsets,
+        };
+        let request = ShardRequest::data_plane(namespace, payload);
+        match self.send_to_data_plane(request).await? {
+            ShardResponse::AckMessages => Ok(()),
+            ShardResponse::ErrorResponse(err) => Err(err),
+            _ => unreachable!("Expected AckMessages response"),
+        }
+    }
+
+    /// Only the members of the consumer group settle the messages delivered to it.
+    fn ensure_consumer_group_member(
+        &self,
+        client_id: u32,
+        group: &ResolvedConsumerGroup,
+    ) -> Result<(), IggyError> {
+        if self.metadata.is_consumer_group_member(
+            group.stream_id,
+            group.topic_id,
+            group.group_id,
+            client_id,
+        ) {
+            return Ok(());
+        }
+
+        Err(IggyError::ConsumerGroupMemberNotFound(
+            client_id,
+            Identifier::numeric(group.group_id as u32).expect("valid group id"),
+            Identifier::numeric(group.topic_id as u32).expect("valid topic id"),
+        ))
+    }
+
+    /// Acknowledges the messages and returns the offset up to which the consumer group can commit.
+    pub(crate) fn ack_messages_in_local_partition(
+        &self,
+        namespace: &IggyNamespace,
+        group_id: ConsumerGroupId,
+        policy: &Arc<DeliveryPolicy>,
+        offsets: &[u64],
+    ) -> Result<Option<u64>, IggyError> {
+        let mut partitions = self.local_partitions.borrow_mut();
+        let Some(partition) = partitions.get_mut(namespace) else {
+            return Err(IggyError::MessageNotDelivered(
+                offsets.first().copied().unwrap_or_default(),
+            ));
+        };
+
+        let stored_offset = partition
+            .consumer_group_offsets
+            .pin()
+            .get(&group_id)
+            .map(|item| item.offset.load(Ordering::Relaxed));
+        partition
+            .deliveries
+            .ack(group_id.0, policy, offsets, stored_offset)?;
+        Ok(partition
+            .deliveries
+            .settled_offset(group_id.0, stored_offset))
+    }
+
+    /// Persists the acknowledged messages of the consumer group which are not committed yet,
+    /// so they are not redelivered after the server restart.
+    pub(crate) async fn persist_consumer_group_acks(
+        &self,
+        namespace: &IggyNamespace,
+        group_id: ConsumerGroupId,
+    ) -> Result<(), IggyError> {
+        let offsets = {
+            let mut partitions = self.local_partitions.borrow_mut();
+            let Some(partition) = partitions.get_mut(namespace) else {
+                return Ok(());
+            };
+            let stored_offset = partition
+                .consumer_group_offsets
+                .pin()
+                .get(&group_id)
+                .map(|item| item.offset.load(Ordering::Relaxed));
+            if let Some(stored_offset) = stored_offset {
+                partition.deliveries.prune(group_id.0, stored_offset);
+            }
+            partition.deliveries.settled_offsets(group_id.0)
+        };
+
+        let dir_path = self.config.system.get_consumer_group_acks_path(
+            namespace.stream_id(),
+            namespace.topic_id(),
+            namespace.partition_id(),
+        );
+        if !Path::new(&dir_path).exists() && compio::fs::create_dir_all(&dir_path).await.is_err() {
+            error!("Failed to create consumer group acks directory: {dir_path}");
+            return Err(IggyError::CannotCreateConsumerOffsetsDirectory(dir_path));
+        }
+
+        let bytes = rmp_serde::to_vec(&offsets).map_err(|_| IggyError::CannotSerializeResource)?;
+        FileWithSyncPersister
+            .overwrite(&format!("{dir_path}/{}", group_id.0), bytes)
+            .await
+    }
+
+    /// Loads the acknowledged messages of the consumer groups with the shared subscription.
+    pub(crate) fn load_partition_deliveries(
+        &self,
+        namespace: &IggyNamespace,
+    ) -> Result<PartitionDeliveries, IggyError> {
+        let mut deliveries = PartitionDeliveries::default();
+        let Some(topic) = self
+            .metadata
+            .get_topic(namespace.stream_id(), namespace.topic_id())
+        else {
+            return Ok(deliveries);
+        };
+
+        let dir_path = self.config.system.get_consumer_group_acks_path(
+            namespace.stream_id(),
+            namespace.topic_id(),
+            namespace.partition_id(),
+        );
+        for (group_id, group) in topic.consumer_groups.iter() {
+            let Some(policy) = group.delivery.as_ref().filter(|policy| policy.is_shared()) else {
+                continue;
+            };
+
+            let path = format!("{dir_path}/{group_id}");
+            let bytes = match std::fs::read(&path) {
+                Ok(bytes) => bytes,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    error!("Cannot read consumer group acks file: {path}, error: {e}");
+                    return Err(IggyError::CannotReadFile);
+                }
+            };
+            let offsets: Vec<u64> = rmp_serde::from_slice(&bytes).map_err(|e| {
+                error!("Cannot deserialize consumer group acks file: {path}, error: {e}");
+                IggyError::CannotDeserializeResource
+            })?;
+            deliveries.restore_acks(group_id, policy, &offsets);
+        }
+        Ok(deliveries)
+    }
+}
+
+fn header(key: &str, value: HeaderValue) -> Result<(HeaderKey, HeaderValue), IggyError> {
+    Ok((HeaderKey::from_str(key)?, value))
+}
@@ -24,16 +24,20 @@ use crate::shard::transmission::message::{
 };
 use crate::streaming::partitions::journal::Journal;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::producers::ProducerSequence;
 use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut, IggyMessagesBatchSet};
+use crate::streaming::transactions::TransactionAppend;
 use err_trail::ErrContext;
 use iggy_common::IggyPollMetadata;
 use iggy_common::PooledBuffer;
 use iggy_common::sharding::IggyNamespace;
 use iggy_common::{
-    Consumer, EncryptorKind, IGGY_MESSAGE_HEADER_SIZE, Identifier, IggyError, PollingStrategy,
+    Consumer, EncryptorKind, IGGY_MESSAGE_HEADER_SIZE, Identifier, IggyError, IggyTimestamp,
+    MessageFilter, PollingKind, PollingStrategy,
 };
+use std::collections::HashSet;
 use std::sync::atomic::Ordering;
-use tracing::error;
+use tracing::{debug, error};
 
 impl IggyShard {
     /// Appends messages to partition. Permission must be checked by caller via
@@ -42,6 +46,8 @@ impl IggyShard {
         &self,
         partition: ResolvedPartition,
         batch: IggyMessagesBatchMut,
+        transaction_id: Option<u64>,
+        producer: Option<ProducerSequence>,
     ) -> Result<(), IggyError> {
         if batch.count() == 0 {
             return Ok(());
@@ -53,7 +59,11 @@ impl IggyShard {
             partition.partition_id,
         );
 
-        let payload = ShardRequestPayload::SendMessages { batch };
+        let payload = ShardRequestPayload::SendMessages {
+            batch,
+            transaction_id,
+            producer,
+        };
         let request = ShardRequest::data_plane(namespace, payload);
 
         match self.send_to_data_plane(request).await? {
@@ -86,17 +96,50 @@ impl IggyShard {
 
         let namespace = IggyNamespace::new(topic.stream_id, topic.topic_id, partition_id);
 
-        let payload = ShardRequestPayload::PollMessages { consumer, args };
+        let delivery = match consumer {
+            PollingConsumer::ConsumerGroup(group_id, _)
+                if args.strategy.kind == PollingKind::Next =>
+            {
+                self.metadata.get_consumer_group_delivery(
+                    topic.stream_id,
+                    topic.topic_id,
+                    group_id.0,
+                )
+            }
+            _ => None,
+        };
+        // The messages skipped by the filter would never be acknowledged, blocking the group offset.
+        if args.filter.is_some() && delivery.as_ref().is_some_and(|policy| policy.is_shared()) {
+            return Err(IggyError::InvalidMessageFilter(
+                "filter is not supported for shared subscriptions".to_owned(),
+            ));
+        }
+        let payload = ShardRequestPayload::PollMessages {
+            consumer,
+            args,
+            delivery: delivery.clone(),
+        };
         let request = ShardRequest::data_plane(namespace, payload);
 
-        let (metadata, batch) = match self.send_to_data_plane(request).await? {
+        let (metadata, batch, dead_letters) = match self.send_to_data_plane(request).await? {
             ShardResponse::PollMessages(result) => result,
             ShardResponse::ErrorResponse(err) => return Err(err),
             _ => unreachable!("Expected PollMessages response"),
         };
 
-        let batch = if let Some(encryptor) = &self.encryptor {
-            self.decrypt_messages(batch, encryptor).await?
+        if let (PollingConsumer::ConsumerGroup(group_id, _), Some(policy)) = (
+            &consumer,
+            delivery
+                .as_ref()
+                .and_then(|delivery| delivery.dead_letter.as_ref()),
+        ) && !dead_letters.is_empty()
+        {
+            self.move_to_dead_letter_topic(topic, partition_id, group_id.0, policy, dead_letters)
+                .await;
+        }
+
+        let batch = if let Some(encryptor) = self.topic_encryptor(topic.stream_id, topic.topic_id) {
+            self.decrypt_messages(batch, &encryptor).await?
         } else {
             batch
         };
@@ -314,8 +357,22 @@ impl IggyShard {
         &self,
         namespace: &IggyNamespace,
         mut batch: IggyMessagesBatchMut,
+        transaction: Option<TransactionAppend>,
+        producer: Option<ProducerSequence>,
+        delayed: Vec<(u32, u64)>,
         config: &crate::configs::system::SystemConfig,
     ) -> Result<(), IggyError> {
+        let messages_count = batch.count();
+        if let Some(producer) = &producer
+            && !self.check_producer_sequence(namespace, producer, messages_count)?
+        {
+            debug!(
+                "Skipping duplicated batch of {messages_count} messages with base sequence: {} from producer with ID: {} in partition: {namespace:?}",
+                producer.base_sequence, producer.producer_id
+            );
+            return Ok(());
+        }
+
         let (
             current_offset,
             current_position,
@@ -355,6 +412,27 @@ impl IggyShard {
             )
             .await;
 
+        if let Some(transaction) = transaction
+            && batch.count() > 0
+        {
+            self.record_transaction_append(namespace, transaction, current_offset, batch.count())
+                .await?;
+        }
+
+        // Offsets are assigned before removing the duplicates, so the delayed messages keep
+        // their position in the batch, unless they were removed.
+        let mut delayed = delayed
+            .into_iter()
+            .map(|(index, deliver_at)| (current_offset + index as u64, deliver_at))
+            .collect::<Vec<_>>();
+        if !delayed.is_empty() && batch.count() < messages_count {
+            let offsets = batch
+                .iter()
+                .map(|message| message.header().offset())
+                .collect::<HashSet<_>>();
+            delayed.retain(|(offset, _)| offsets.contains(offset));
+        }
+
         let (journal_messages_count, journal_size, is_full) = {
             let mut partitions = self.local_partitions.borrow_mut();
             let partition = partitions
@@ -382,12 +460,27 @@ impl IggyShard {
 
             let (journal_messages_count, journal_size) =
                 partition.log.journal_mut().append(batch)?;
+            for (offset, deliver_at) in delayed {
+                partition.schedule.schedule(offset, deliver_at);
+            }
 
             let last_offset = if batch_messages_count == 0 {
                 current_offset
             } else {
                 current_offset + batch_messages_count as u64 - 1
             };
+            if let Some(producer) = &producer {
+                // The producer state is kept after a restart only once the partition log
+                // contains this offset.
+                let offset = if batch_messages_count == 0 {
+                    current_offset.checked_sub(1)
+                } else {
+                    Some(last_offset)
+                };
+                partition
+                    .producers
+                    .record(producer, messages_count, offset, IggyTimestamp::now());
+            }
 
             if partition.should_increment_offset {
                 partition.offset.store(last_offset, Ordering::Relaxed);
@@ -480,6 +573,10 @@ impl IggyShard {
             (messages_writer, index_writer)
         };
 
+        // The producers state is persisted before the messages, along with the offsets of the
+        // batches, so that the batches never saved on disk are discarded after a restart.
+        self.persist_partition_producers(namespace).await?;
+
         let saved = messages_writer
             .as_ref()
             .save_frozen_batches(&frozen_batches)
@@ -526,8 +623,13 @@ impl IggyShard {
                 iggy_common::IggyByteSize::from(segment.size.as_bytes_u64() + saved.as_bytes_u64());
 
             partition.log.clear_in_flight();
+            if let Some(saved_offset) = frozen_batches.last().and_then(|batch| batch.last_offset())
+            {
+                partition.producers.mark_saved(saved_offset);
+            }
         }
 
+        self.persist_partition_schedule(namespace).await?;
         Ok(batch_count)
     }
 
@@ -540,8 +642,11 @@ impl IggyShard {
         crate::streaming::partitions::ops::poll_messages(
             &self.local_partitions,
             namespace,
+            self.archiver.as_ref(),
             consumer,
             args,
+            self.topic_encryptor(namespace.stream_id(), namespace.topic_id())
+                .as_ref(),
         )
         .await
     }
@@ -612,9 +717,10 @@ impl IggyShard {
 
     pub fn maybe_encrypt_messages(
         &self,
+        namespace: &IggyNamespace,
         batch: IggyMessagesBatchMut,
     ) -> Result<IggyMessagesBatchMut, IggyError> {
-        let encryptor = match self.encryptor.as_ref() {
+        let encryptor = match self.topic_encryptor(namespace.stream_id(), namespace.topic_id()) {
             Some(encryptor) => encryptor,
             None => return Ok(batch),
         };
@@ -683,6 +789,10 @@ pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
     pub auto_commit: bool,
+    /// Skips the delayed messages which are not due yet, rather than stopping right before them.
+    pub skip_scheduled: bool,
+    /// Returns only the messages with the user headers matching the filter.
+    pub filter: Option<MessageFilter>,
 }
 
 impl PollingArgs {
@@ -691,6 +801,13 @@ impl PollingArgs {
             strategy,
             count,
             auto_commit,
+            skip_scheduled: false,
+            filter: None,
         }
     }
+
+    pub fn with_filter(mut self, filter: Option<MessageFilter>) -> Self {
+        self.filter = filter;
+        self
+    }
 }
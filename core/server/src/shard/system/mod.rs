@@ -20,16 +20,21 @@ pub mod clients;
 pub mod cluster;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod deliveries;
 pub mod info;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod producers;
+pub mod roles;
+pub mod scheduled;
 pub mod segments;
 pub mod snapshot;
 pub mod stats;
 pub mod storage;
 pub mod streams;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
 
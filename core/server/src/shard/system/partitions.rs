@@ -350,7 +350,7 @@ impl IggyShard {
             }
         }
 
-        let partition = LocalPartition::with_log(
+        let mut partition = LocalPartition::with_log(
             loaded_log,
             stats,
             std::sync::Arc::new(std::sync::atomic::AtomicU64::new(current_offset)),
@@ -361,6 +361,14 @@ impl IggyShard {
             revision_id,
             should_increment_offset,
         );
+        partition.transactions = self
+            .load_partition_transactions(ns, should_increment_offset.then_some(current_offset))
+            .await?;
+        partition.producers =
+            self.load_partition_producers(ns, should_increment_offset.then_some(current_offset))?;
+        partition.deliveries = self.load_partition_deliveries(ns)?;
+        partition.schedule =
+            self.load_partition_schedule(ns, should_increment_offset.then_some(current_offset))?;
 
         self.local_partitions.borrow_mut().insert(*ns, partition);
 
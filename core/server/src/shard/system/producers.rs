@@ -0,0 +1,97 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::shard::IggyShard;
+use crate::streaming::producers::{PartitionProducers, ProducerSequence};
+use crate::streaming::utils::timestamp_id::TimestampIdGenerator;
+use iggy_common::sharding::IggyNamespace;
+use iggy_common::{IggyError, IggyTimestamp, ProducerIdentity};
+use tracing::{debug, info};
+
+static PRODUCER_IDS: TimestampIdGenerator = TimestampIdGenerator::new();
+
+impl IggyShard {
+    /// Assigns a new epoch to the idempotent producer, along with a new ID unless it's provided.
+    /// Epochs keep increasing, so the batches sent by the previous instance of the producer are fenced.
+    pub fn init_producer(&self, client_id: u32, producer_id: Option<u64>) -> ProducerIdentity {
+        let id = producer_id.unwrap_or_else(|| PRODUCER_IDS.next());
+        let producer = ProducerIdentity::new(id, PRODUCER_IDS.next());
+        info!("Client with ID: {client_id} initialized idempotent producer: {producer}");
+        producer
+    }
+
+    /// Returns `false` if the batch of the idempotent producer has already been appended to the partition.
+    pub(crate) fn check_producer_sequence(
+        &self,
+        namespace: &IggyNamespace,
+        producer: &ProducerSequence,
+        messages_count: u32,
+    ) -> Result<bool, IggyError> {
+        let partitions = self.local_partitions.borrow();
+        let partition = partitions
+            .get(namespace)
+            .expect("local_partitions: partition must exist");
+        partition.producers.check(producer, messages_count)
+    }
+
+    /// Persists the idempotent producers state of the partition, before its messages are saved on disk.
+    pub(crate) async fn persist_partition_producers(
+        &self,
+        namespace: &IggyNamespace,
+    ) -> Result<(), IggyError> {
+        let producers = {
+            let mut partitions = self.local_partitions.borrow_mut();
+            let partition = partitions
+                .get_mut(namespace)
+                .expect("local_partitions: partition must exist");
+            let expired = partition.producers.expire(
+                IggyTimestamp::now(),
+                self.config.system.message_deduplication.producer_expiry,
+            );
+            if expired > 0 {
+                debug!("Expired {expired} idempotent producer(s) in partition: {namespace:?}");
+            }
+            if !partition.producers.is_dirty() {
+                return Ok(());
+            }
+            partition.producers.take_snapshot()
+        };
+
+        let path = self.config.system.get_partition_producers_path(
+            namespace.stream_id(),
+            namespace.topic_id(),
+            namespace.partition_id(),
+        );
+        producers.persist(&path).await
+    }
+
+    pub(crate) fn load_partition_producers(
+        &self,
+        namespace: &IggyNamespace,
+        current_offset: Option<u64>,
+    ) -> Result<PartitionProducers, IggyError> {
+        let path = self.config.system.get_partition_producers_path(
+            namespace.stream_id(),
+            namespace.topic_id(),
+            namespace.partition_id(),
+        );
+        let mut producers = PartitionProducers::load(&path)?;
+        producers.clamp(current_offset);
+        Ok(producers)
+    }
+}
@@ -0,0 +1,126 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::COMPONENT;
+use crate::metadata::RoleMeta;
+use crate::shard::IggyShard;
+use err_trail::ErrContext;
+use iggy_common::Identifier;
+use iggy_common::IggyError;
+use iggy_common::Permissions;
+use std::sync::Arc;
+use tracing::{error, info};
+
+impl IggyShard {
+    pub fn find_role(&self, role_id: &Identifier) -> Option<RoleMeta> {
+        let role_id = self.metadata.get_role_id(role_id)?;
+        self.metadata.get_role(role_id)
+    }
+
+    pub fn get_role(&self, role_id: &Identifier) -> Result<RoleMeta, IggyError> {
+        self.find_role(role_id)
+            .ok_or(IggyError::ResourceNotFound(role_id.to_string()))
+    }
+
+    pub fn get_roles(&self) -> Vec<RoleMeta> {
+        self.metadata.get_all_roles()
+    }
+
+    pub fn create_role(
+        &self,
+        name: &str,
+        permissions: Option<Permissions>,
+    ) -> Result<RoleMeta, IggyError> {
+        let role_id = self
+            .writer()
+            .create_role(&self.metadata, Arc::from(name), permissions.map(Arc::new))
+            .inspect_err(|e| {
+                if let IggyError::RoleAlreadyExists = e {
+                    error!("Role: {name} already exists.");
+                }
+            })?;
+
+        info!("Created role: {name} with ID: {role_id}.");
+        self.get_role(&role_id.try_into()?).error(|e: &IggyError| {
+            format!("{COMPONENT} (error: {e}) - failed to get role with id: {role_id}")
+        })
+    }
+
+    pub fn update_role(
+        &self,
+        role_id: &Identifier,
+        permissions: Option<Permissions>,
+    ) -> Result<RoleMeta, IggyError> {
+        let role = self.get_role(role_id).error(|e: &IggyError| {
+            format!("{COMPONENT} (error: {e}) - failed to get role with id: {role_id}")
+        })?;
+
+        self.writer()
+            .update_role(role.id, permissions.map(Arc::new));
+        info!("Updated permissions of role with ID: {}.", role.id);
+        Ok(role)
+    }
+
+    pub fn delete_role(&self, role_id: &Identifier) -> Result<RoleMeta, IggyError> {
+        let role = self.get_role(role_id).error(|e: &IggyError| {
+            format!("{COMPONENT} (error: {e}) - failed to get role with id: {role_id}")
+        })?;
+
+        self.writer().delete_role(role.id);
+        info!("Deleted role: {} with ID: {}.", role.name, role.id);
+        Ok(role)
+    }
+
+    pub fn assign_role(&self, user_id: &Identifier, role_id: &Identifier) -> Result<(), IggyError> {
+        let user = self.get_user(user_id).error(|e: &IggyError| {
+            format!("{COMPONENT} (error: {e}) - failed to get user with id: {user_id}")
+        })?;
+        let role = self.get_role(role_id).error(|e: &IggyError| {
+            format!("{COMPONENT} (error: {e}) - failed to get role with id: {role_id}")
+        })?;
+
+        self.writer()
+            .assign_role(&self.metadata, user.id, role.id)?;
+        info!(
+            "Assigned role with ID: {} to user with ID: {}.",
+            role.id, user.id
+        );
+        Ok(())
+    }
+
+    pub fn unassign_role(
+        &self,
+        user_id: &Identifier,
+        role_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        let user = self.get_user(user_id).error(|e: &IggyError| {
+            format!("{COMPONENT} (error: {e}) - failed to get user with id: {user_id}")
+        })?;
+        let role = self.get_role(role_id).error(|e: &IggyError| {
+            format!("{COMPONENT} (error: {e}) - failed to get role with id: {role_id}")
+        })?;
+
+        self.writer()
+            .unassign_role(&self.metadata, user.id, role.id)?;
+        info!(
+            "Unassigned role with ID: {} from user with ID: {}.",
+            role.id, user.id
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::shard::IggyShard;
+use crate::streaming::scheduled::PartitionSchedule;
+use iggy_common::sharding::IggyNamespace;
+use iggy_common::{IggyError, IggyTimestamp};
+use tracing::debug;
+
+impl IggyShard {
+    /// Persists the delayed messages of the partition which are not due yet, once its messages are saved on disk.
+    pub(crate) async fn persist_partition_schedule(
+        &self,
+        namespace: &IggyNamespace,
+    ) -> Result<(), IggyError> {
+        let schedule = {
+            let mut partitions = self.local_partitions.borrow_mut();
+            let partition = partitions
+                .get_mut(namespace)
+                .expect("local_partitions: partition must exist");
+            let released = partition.schedule.release(IggyTimestamp::now());
+            if released > 0 {
+                debug!("Released {released} delayed message(s) in partition: {namespace:?}");
+            }
+            if !partition.schedule.is_dirty() {
+                return Ok(());
+            }
+            partition.schedule.take_snapshot()
+        };
+
+        let path = self.config.system.get_partition_schedule_path(
+            namespace.stream_id(),
+            namespace.topic_id(),
+            namespace.partition_id(),
+        );
+        schedule.persist(&path).await
+    }
+
+    /// Loads the delayed messages of the partition, dropping the ones beyond its current offset.
+    pub(crate) fn load_partition_schedule(
+        &self,
+        namespace: &IggyNamespace,
+        current_offset: Option<u64>,
+    ) -> Result<PartitionSchedule, IggyError> {
+        let path = self.config.system.get_partition_schedule_path(
+            namespace.stream_id(),
+            namespace.topic_id(),
+            namespace.partition_id(),
+        );
+        let mut schedule = PartitionSchedule::load(&path)?;
+        schedule.clamp(current_offset);
+        Ok(schedule)
+    }
+}
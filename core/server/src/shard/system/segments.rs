@@ -18,8 +18,11 @@
 use crate::configs::cache_indexes::CacheIndexesConfig;
 use crate::shard::IggyShard;
 use crate::streaming::segments::Segment;
+use crate::streaming::segments::compaction::SegmentsCompactor;
+use crate::streaming::segments::storage::Storage;
+use crate::streaming::utils::file;
 use iggy_common::sharding::IggyNamespace;
-use iggy_common::{ConsumerKind, IggyError, IggyExpiry, IggyTimestamp, MaxTopicSize};
+use iggy_common::{ConsumerKind, IggyByteSize, IggyError, IggyExpiry, IggyTimestamp, MaxTopicSize};
 
 impl IggyShard {
     /// Performs all cleanup for a topic's partitions: time-based expiry then size-based trimming.
@@ -106,6 +109,227 @@ impl IggyShard {
         Ok((total_segments, total_messages))
     }
 
+    /// Compacts the sealed segments of a topic with the `compact` cleanup policy, retaining only
+    /// the latest message of every key.
+    /// Returns `(compacted_segments, deleted_segments, removed_messages)`.
+    ///
+    /// Runs entirely inside the message pump's serialized loop, like `clean_topic_messages`.
+    pub(crate) async fn compact_topic_messages(
+        &self,
+        stream_id: usize,
+        topic_id: usize,
+        partition_ids: &[usize],
+    ) -> Result<(u64, u64, u64), IggyError> {
+        let cleanup_policy = self
+            .metadata
+            .get_topic_cleanup_policy(stream_id, topic_id)
+            .unwrap_or_default();
+        if !cleanup_policy.is_compact() {
+            return Ok((0, 0, 0));
+        }
+
+        let tombstone_retention = self.config.data_maintenance.compaction.tombstone_retention;
+        let tombstone_deadline = IggyTimestamp::now()
+            .as_micros()
+            .saturating_sub(tombstone_retention.as_micros());
+
+        let mut total_compacted = 0u64;
+        let mut total_deleted = 0u64;
+        let mut total_messages = 0u64;
+        for &partition_id in partition_ids {
+            let (c, d, m) = self
+                .compact_partition_segments(stream_id, topic_id, partition_id, tombstone_deadline)
+                .await?;
+            total_compacted += c;
+            total_deleted += d;
+            total_messages += m;
+        }
+        Ok((total_compacted, total_deleted, total_messages))
+    }
+
+    /// Rewrites the sealed segments of a single partition without the superseded messages.
+    /// Segments left without any message are deleted.
+    async fn compact_partition_segments(
+        &self,
+        stream_id: usize,
+        topic_id: usize,
+        partition_id: usize,
+        tombstone_deadline: u64,
+    ) -> Result<(u64, u64, u64), IggyError> {
+        let ns = IggyNamespace::new(stream_id, topic_id, partition_id);
+
+        // The active segment is only scanned, its messages may supersede the sealed ones.
+        // Offloaded segments are left as they are, their messages are no longer on the local disk.
+        let segments: Vec<(u64, Storage)> = {
+            let partitions = self.local_partitions.borrow();
+            let Some(partition) = partitions.get(&ns) else {
+                return Ok((0, 0, 0));
+            };
+            partition
+                .log
+                .segments()
+                .iter()
+                .zip(partition.log.storages())
+                .filter(|(segment, _)| !segment.offloaded)
+                .map(|(segment, storage)| (segment.start_offset, storage.clone()))
+                .collect()
+        };
+        if segments.len() <= 1 {
+            return Ok((0, 0, 0));
+        }
+
+        let mut compactor = SegmentsCompactor::new(tombstone_deadline);
+        for (_, storage) in &segments {
+            compactor.scan(storage).await?;
+        }
+
+        let fsync = self.config.system.partition.enforce_fsync;
+        let mut compacted_segments = 0u64;
+        let mut removed_messages = 0u64;
+        let mut emptied_offsets = Vec::new();
+        for (start_offset, storage) in &segments[..segments.len() - 1] {
+            let Some(compacted) = compactor.compact(storage, *start_offset, fsync).await? else {
+                continue;
+            };
+
+            let mut partitions = self.local_partitions.borrow_mut();
+            let Some(partition) = partitions.get_mut(&ns) else {
+                break;
+            };
+            let log = &mut partition.log;
+            let Some(idx) = log
+                .segments()
+                .iter()
+                .position(|s| s.start_offset == *start_offset)
+            else {
+                continue;
+            };
+
+            let segment = &mut log.segments_mut()[idx];
+            let removed_size = segment.size.as_bytes_u64() - compacted.messages_size;
+            segment.size = IggyByteSize::from(compacted.messages_size);
+            segment.current_position = segment.size.as_bytes_u32();
+            if let (Some(first), Some(last)) = (compacted.indexes.get(0), compacted.indexes.last())
+            {
+                segment.start_timestamp = first.timestamp();
+                segment.end_timestamp = last.timestamp();
+            } else {
+                emptied_offsets.push(*start_offset);
+            }
+
+            log.storages_mut()[idx] = compacted.storage;
+            if let Some(indexes) = log.indexes_mut()[idx].as_mut() {
+                *indexes = compacted.indexes;
+            }
+
+            partition.stats.decrement_size_bytes(removed_size);
+            partition
+                .stats
+                .decrement_messages_count(compacted.removed_messages);
+            compacted_segments += 1;
+            removed_messages += compacted.removed_messages;
+
+            tracing::info!(
+                "Compacted segment (start: {}, removed messages: {}, removed size: {}) in partition {}",
+                start_offset,
+                compacted.removed_messages,
+                removed_size,
+                partition_id
+            );
+        }
+
+        let mut deleted_segments = 0u64;
+        for offset in emptied_offsets {
+            let (s, _) = self
+                .remove_segment_by_offset(stream_id, topic_id, partition_id, offset)
+                .await?;
+            deleted_segments += s;
+        }
+
+        Ok((compacted_segments, deleted_segments, removed_messages))
+    }
+
+    /// Marks the sealed segments already copied to the archive as offloaded and removes their
+    /// local messages files, the indexes stay on the local disk.
+    /// Segments changed since they were archived (e.g. compacted or deleted) are skipped.
+    ///
+    /// Runs entirely inside the message pump's serialized loop, like `clean_topic_messages`.
+    pub(crate) async fn offload_segments(
+        &self,
+        stream_id: usize,
+        topic_id: usize,
+        partition_id: usize,
+        segments: &[(u64, u64)],
+    ) -> Result<u64, IggyError> {
+        let ns = IggyNamespace::new(stream_id, topic_id, partition_id);
+
+        let mut offloaded_segments = 0u64;
+        for &(start_offset, size) in segments {
+            let messages_path = {
+                let mut partitions = self.local_partitions.borrow_mut();
+                let Some(partition) = partitions.get_mut(&ns) else {
+                    break;
+                };
+
+                let log = &mut partition.log;
+                let last_idx = log.segments().len().saturating_sub(1);
+                let Some(idx) = log
+                    .segments()
+                    .iter()
+                    .position(|s| s.start_offset == start_offset)
+                else {
+                    continue;
+                };
+
+                let segment = &mut log.segments_mut()[idx];
+                if idx == last_idx
+                    || !segment.sealed
+                    || segment.offloaded
+                    || segment.size.as_bytes_u64() != size
+                {
+                    continue;
+                }
+                segment.offloaded = true;
+
+                let storage = &mut log.storages_mut()[idx];
+                let _ = storage.shutdown();
+                let (messages_path, _) = storage.segment_and_index_paths();
+                storage.messages_reader = None;
+                messages_path
+            };
+
+            // The marker is written before the messages file is removed, so the segment is
+            // loaded as offloaded after a restart.
+            let marker_path = self.config.system.get_offloaded_marker_path(
+                stream_id,
+                topic_id,
+                partition_id,
+                start_offset,
+            );
+            if let Err(e) = file::overwrite(&marker_path).await {
+                tracing::error!("Failed to create offloaded segment marker {marker_path}: {e}");
+                continue;
+            }
+
+            if let Some(path) = messages_path
+                && let Err(e) = compio::fs::remove_file(&path).await
+            {
+                tracing::error!("Failed to delete offloaded messages file {}: {}", path, e);
+            }
+
+            offloaded_segments += 1;
+            tracing::info!(
+                "Offloaded segment (start: {}, size: {}) of partition {} (stream {}, topic {})",
+                start_offset,
+                size,
+                partition_id,
+                stream_id,
+                topic_id
+            );
+        }
+        Ok(offloaded_segments)
+    }
+
     /// Deletes all expired sealed segments from a single partition.
     async fn delete_expired_segments_for_partition(
         &self,
@@ -271,10 +495,11 @@ impl IggyShard {
 
         let segment_size = segment.size.as_bytes_u64();
         let end_offset = segment.end_offset;
-        let messages_in_segment = if start_offset == end_offset {
-            0
-        } else {
-            (end_offset - start_offset) + 1
+        // Compacted segments have offset gaps, so prefer the persisted indexes count.
+        let messages_in_segment = match storage.index_reader.as_ref() {
+            Some(index_reader) => index_reader.indexes_count() as u64,
+            None if start_offset == end_offset => 0,
+            None => (end_offset - start_offset) + 1,
         };
 
         let _ = storage.shutdown();
@@ -292,6 +517,10 @@ impl IggyShard {
             tracing::error!("Failed to delete index file {}: {}", path, e);
         }
 
+        if segment.offloaded {
+            self.delete_offloaded_segment(&ns, start_offset).await;
+        }
+
         stats.decrement_size_bytes(segment_size);
         stats.decrement_segments_count(1);
         stats.decrement_messages_count(messages_in_segment);
@@ -474,6 +703,11 @@ impl IggyShard {
                     }
                 }
             }
+
+            if segment.offloaded {
+                self.delete_offloaded_segment(&namespace, start_offset)
+                    .await;
+            }
         }
 
         self.init_log_in_local_partitions(&namespace).await?;
@@ -481,6 +715,38 @@ impl IggyShard {
         Ok(())
     }
 
+    /// Removes the marker and the archived files of an offloaded segment. Failures are only
+    /// logged, the segment is already gone from the partition at this point.
+    async fn delete_offloaded_segment(&self, namespace: &IggyNamespace, start_offset: u64) {
+        let marker_path = self.config.system.get_offloaded_marker_path(
+            namespace.stream_id(),
+            namespace.topic_id(),
+            namespace.partition_id(),
+            start_offset,
+        );
+        if let Err(e) = compio::fs::remove_file(&marker_path).await
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            tracing::error!("Failed to delete offloaded segment marker {marker_path}: {e}");
+        }
+
+        let Some(archiver) = self.archiver.as_ref() else {
+            tracing::warn!(
+                "Archiver is disabled, archived files of segment (start: {start_offset}) \
+                 were not deleted for partition {}",
+                namespace.partition_id()
+            );
+            return;
+        };
+        if let Err(e) = archiver.delete_segment(namespace, start_offset).await {
+            tracing::error!(
+                "Failed to delete archived files of segment (start: {start_offset}) \
+                 for partition {}: {e}",
+                namespace.partition_id()
+            );
+        }
+    }
+
     /// Creates a fresh segment at offset 0 after all segments have been drained.
     ///
     /// The log is momentarily empty between `delete_segments`' drain and this call, which is
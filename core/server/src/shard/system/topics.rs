@@ -22,7 +22,8 @@ use crate::shard::transmission::message::{ResolvedStream, ResolvedTopic};
 use crate::streaming::topics::storage::{create_topic_file_hierarchy, delete_topic_directory};
 use iggy_common::sharding::IggyNamespace;
 use iggy_common::{
-    CompressionAlgorithm, Identifier, IggyError, IggyExpiry, IggyTimestamp, MaxTopicSize,
+    CleanupPolicy, CompressionAlgorithm, EncryptorKind, Identifier, IggyError, IggyExpiry,
+    IggyTimestamp, MaxTopicSize,
 };
 use std::sync::Arc;
 
@@ -43,12 +44,14 @@ impl IggyShard {
         compression: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<usize, IggyError> {
         let stream_id = stream.0;
 
         let config = &self.config.system;
         let message_expiry = config.resolve_message_expiry(message_expiry);
         let max_topic_size = config.resolve_max_topic_size(max_topic_size)?;
+        let compression = config.resolve_compression_algorithm(compression);
 
         let name_arc = Arc::from(name.as_str());
         let parent_stats = self.metadata.get_stream_stats(stream_id).ok_or_else(|| {
@@ -83,11 +86,14 @@ impl IggyShard {
             compression_algorithm: compression,
             max_topic_size,
             replication_factor: replication_factor.unwrap_or(1),
+            cleanup_policy,
             stats,
             partitions: Vec::new(),
             consumer_groups: slab::Slab::new(),
             consumer_group_index: ahash::AHashMap::default(),
             round_robin_counter: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            encryption_keys: Default::default(),
+            encryptor: None,
         };
         let assigned_id = self
             .writer()
@@ -113,7 +119,12 @@ impl IggyShard {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<(), IggyError> {
+        let compression_algorithm = self
+            .config
+            .system
+            .resolve_compression_algorithm(compression_algorithm);
         self.writer().try_update_topic(
             &self.metadata,
             topic.stream_id,
@@ -123,6 +134,7 @@ impl IggyShard {
             compression_algorithm,
             max_topic_size,
             replication_factor.unwrap_or(1),
+            cleanup_policy,
         )
     }
 
@@ -227,6 +239,52 @@ impl IggyShard {
         Ok(())
     }
 
+    /// Generates a new data encryption key, which encrypts the messages appended to the topic from now on.
+    /// The previous keys are kept, so that the already stored messages can still be decrypted.
+    /// Returns the ID of the new key and the key wrapped by the master key.
+    pub fn rotate_topic_key(&self, topic: ResolvedTopic) -> Result<(u32, Vec<u8>), IggyError> {
+        let encryption = self
+            .encryption
+            .as_ref()
+            .ok_or(IggyError::EncryptionDisabled)?;
+        let mut encryption_keys = self
+            .metadata
+            .get_topic_encryption_keys(topic.stream_id, topic.topic_id)
+            .ok_or_else(|| {
+                IggyError::TopicIdNotFound(
+                    Identifier::numeric(topic.stream_id as u32).unwrap(),
+                    Identifier::numeric(topic.topic_id as u32).unwrap(),
+                )
+            })?;
+
+        let key_id = encryption_keys
+            .keys()
+            .next_back()
+            .map_or(1, |key_id| key_id + 1);
+        let wrapped_key = encryption.generate_wrapped_key()?;
+        encryption_keys.insert(key_id, wrapped_key.clone());
+        let encryptor = encryption.topic_encryptor(&encryption_keys)?;
+        self.writer().set_topic_encryption_keys(
+            topic.stream_id,
+            topic.topic_id,
+            encryption_keys,
+            encryptor,
+        );
+        Ok((key_id, wrapped_key))
+    }
+
+    /// Returns the encryptor of the topic messages, or `None` if the encryption is disabled.
+    pub(crate) fn topic_encryptor(
+        &self,
+        stream_id: usize,
+        topic_id: usize,
+    ) -> Option<EncryptorKind> {
+        let encryption = self.encryption.as_ref()?;
+        self.metadata
+            .get_topic_encryptor(stream_id, topic_id)
+            .or_else(|| Some(encryption.default_encryptor()))
+    }
+
     /// Disk cleanup for local partitions: deletes consumer offset files and purges segments.
     /// Called on each shard (including shard 0) after in-memory state is cleared.
     pub(crate) async fn purge_topic_local(&self, topic: ResolvedTopic) -> Result<(), IggyError> {
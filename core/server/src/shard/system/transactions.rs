@@ -0,0 +1,310 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::shard::IggyShard;
+use crate::shard::transmission::frame::ShardResponse;
+use crate::shard::transmission::message::{ResolvedTopic, ShardRequest, ShardRequestPayload};
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::segments::IggyMessagesBatchMut;
+use crate::streaming::transactions::{
+    PartitionTransactions, PendingCommit, PendingOffset, TransactionAppend,
+};
+use bytes::Bytes;
+use iggy_common::sharding::IggyNamespace;
+use iggy_common::{
+    Consumer, ConsumerKind, HeaderKey, IggyError, IggyMessage, Sizeable, TRANSACTION_ID_HEADER_KEY,
+};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use tracing::{error, info, warn};
+
+/// Consumer offset stored atomically with the commit of a transaction.
+#[derive(Debug, Clone)]
+pub struct TransactionOffsetArgs {
+    pub consumer: Consumer,
+    pub topic: ResolvedTopic,
+    pub partition_id: Option<u32>,
+    pub offset: u64,
+}
+
+impl IggyShard {
+    pub fn begin_transaction(&self, client_id: u32) -> Result<u64, IggyError> {
+        let transaction_id = self.client_manager.begin_transaction(client_id)?;
+        info!("Client with ID: {client_id} began transaction with ID: {transaction_id}");
+        Ok(transaction_id)
+    }
+
+    pub async fn commit_transaction(
+        &self,
+        client_id: u32,
+        transaction_id: u64,
+        offsets: Vec<TransactionOffsetArgs>,
+    ) -> Result<(), IggyError> {
+        if self.client_manager.get_transaction_id(client_id) != Some(transaction_id) {
+            return Err(IggyError::TransactionNotFound(transaction_id));
+        }
+
+        // Offsets are validated upfront, so that storing them after the commit cannot fail.
+        let mut pending_offsets = Vec::with_capacity(offsets.len());
+        for args in &offsets {
+            let Some((polling_consumer, partition_id)) = self.resolve_consumer_with_partition_id(
+                args.topic,
+                &args.consumer,
+                client_id,
+                args.partition_id,
+                false,
+            )?
+            else {
+                return Err(IggyError::NotResolvedConsumer(args.consumer.id.clone()));
+            };
+            self.validate_partition_offset(
+                args.topic.stream_id,
+                args.topic.topic_id,
+                partition_id,
+                args.offset,
+            )?;
+            let (kind, consumer_id) = match polling_consumer {
+                PollingConsumer::Consumer(id, _) => (ConsumerKind::Consumer, id),
+                PollingConsumer::ConsumerGroup(group_id, _) => {
+                    (ConsumerKind::ConsumerGroup, group_id.0)
+                }
+            };
+            pending_offsets.push(PendingOffset {
+                stream_id: args.topic.stream_id,
+                topic_id: args.topic.topic_id,
+                partition_id,
+                kind,
+                consumer_id,
+                offset: args.offset,
+            });
+        }
+
+        let transaction = self
+            .client_manager
+            .take_transaction(client_id, transaction_id)?;
+        let commit = PendingCommit {
+            transaction_id,
+            partitions: transaction
+                .partitions
+                .iter()
+                .map(|ns| (ns.stream_id(), ns.topic_id(), ns.partition_id()))
+                .collect(),
+            offsets: pending_offsets,
+        };
+
+        if let Err(error) = commit.persist(&self.config.system).await {
+            error!(
+                "Failed to persist commit decision of transaction with ID: {transaction_id}, aborting it. {error}"
+            );
+            self.complete_transaction(transaction_id, &transaction.partitions, false)
+                .await?;
+            return Err(error);
+        }
+
+        // Once the decision is persisted, the transaction is committed. Partitions which could
+        // not be completed now are completed when the decision is recovered after a restart.
+        self.complete_transaction(transaction_id, &transaction.partitions, true)
+            .await?;
+        for args in offsets {
+            self.store_consumer_offset(
+                client_id,
+                args.consumer,
+                args.topic,
+                args.partition_id,
+                args.offset,
+            )
+            .await?;
+        }
+        PendingCommit::remove(&self.config.system, transaction_id).await?;
+
+        info!(
+            "Client with ID: {client_id} committed transaction with ID: {transaction_id} spanning {} partition(s)",
+            transaction.partitions.len()
+        );
+        Ok(())
+    }
+
+    pub async fn abort_transaction(
+        &self,
+        client_id: u32,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        let transaction = self
+            .client_manager
+            .take_transaction(client_id, transaction_id)?;
+        self.complete_transaction(transaction_id, &transaction.partitions, false)
+            .await?;
+        info!("Client with ID: {client_id} aborted transaction with ID: {transaction_id}");
+        Ok(())
+    }
+
+    async fn complete_transaction(
+        &self,
+        transaction_id: u64,
+        partitions: &[IggyNamespace],
+        committed: bool,
+    ) -> Result<(), IggyError> {
+        for namespace in partitions {
+            let payload = ShardRequestPayload::CompleteTransaction {
+                transaction_id,
+                committed,
+            };
+            let request = ShardRequest::data_plane(*namespace, payload);
+            match self.send_to_data_plane(request).await? {
+                ShardResponse::CompleteTransaction => {}
+                ShardResponse::ErrorResponse(err) => return Err(err),
+                _ => unreachable!("Expected CompleteTransaction response"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends the control marker of the transaction, completing it on the partition.
+    /// Completing a transaction which is not open on the partition is a no-op.
+    pub(crate) async fn complete_transaction_in_local_partition(
+        &self,
+        namespace: &IggyNamespace,
+        transaction_id: u64,
+        committed: bool,
+    ) -> Result<(), IggyError> {
+        let is_open = self
+            .local_partitions
+            .borrow()
+            .get(namespace)
+            .is_some_and(|partition| partition.transactions.is_open(transaction_id));
+        if !is_open {
+            return Ok(());
+        }
+
+        let payload = if committed { "commit" } else { "abort" };
+        let headers = BTreeMap::from([(
+            HeaderKey::from_str(TRANSACTION_ID_HEADER_KEY)?,
+            transaction_id.into(),
+        )]);
+        let marker = IggyMessage::builder()
+            .payload(Bytes::from(payload))
+            .user_headers(headers)
+            .build()?;
+        let marker_size = marker.get_size_bytes().as_bytes_u32();
+        let batch = IggyMessagesBatchMut::from_messages(&[marker], marker_size);
+        let batch = self.maybe_encrypt_messages(namespace, batch)?;
+
+        self.append_messages_to_local_partition(
+            namespace,
+            batch,
+            Some(TransactionAppend::Marker {
+                transaction_id,
+                committed,
+            }),
+            None,
+            Vec::new(),
+            &self.config.system,
+        )
+        .await
+    }
+
+    /// Records the offsets of the batch being appended in the transactional state of the partition
+    /// and persists it, before the batch reaches the journal.
+    pub(crate) async fn record_transaction_append(
+        &self,
+        namespace: &IggyNamespace,
+        transaction: TransactionAppend,
+        first_offset: u64,
+        messages_count: u32,
+    ) -> Result<(), IggyError> {
+        let transactions = {
+            let mut partitions = self.local_partitions.borrow_mut();
+            let partition = partitions
+                .get_mut(namespace)
+                .expect("local_partitions: partition must exist");
+            let last_offset = first_offset + messages_count as u64 - 1;
+            match transaction {
+                TransactionAppend::Messages(transaction_id) => {
+                    partition
+                        .transactions
+                        .add_range(transaction_id, first_offset, last_offset);
+                }
+                TransactionAppend::Marker {
+                    transaction_id,
+                    committed,
+                } => {
+                    partition
+                        .transactions
+                        .complete(transaction_id, committed, Some(first_offset));
+                    if let Some(segment) = partition.log.segments().first() {
+                        partition.transactions.prune(segment.start_offset);
+                    }
+                }
+            }
+            partition.transactions.clone()
+        };
+
+        let path = self.config.system.get_partition_transactions_path(
+            namespace.stream_id(),
+            namespace.topic_id(),
+            namespace.partition_id(),
+        );
+        transactions.persist(&path).await
+    }
+
+    /// Loads the transactional state of the partition. Transactions still open are aborted,
+    /// as the clients which began them did not survive the restart.
+    pub(crate) async fn load_partition_transactions(
+        &self,
+        namespace: &IggyNamespace,
+        current_offset: Option<u64>,
+    ) -> Result<PartitionTransactions, IggyError> {
+        let path = self.config.system.get_partition_transactions_path(
+            namespace.stream_id(),
+            namespace.topic_id(),
+            namespace.partition_id(),
+        );
+        let mut transactions = PartitionTransactions::load(&path)?;
+        if transactions.is_empty() {
+            return Ok(transactions);
+        }
+
+        let loaded = transactions.clone();
+        transactions.clamp(current_offset);
+        let aborted = transactions.abort_open();
+        if aborted > 0 {
+            warn!(
+                "Aborted {aborted} unfinished transaction(s) in partition {} of topic {} in stream {}",
+                namespace.partition_id(),
+                namespace.topic_id(),
+                namespace.stream_id()
+            );
+        }
+        if transactions != loaded {
+            transactions.persist(&path).await?;
+        }
+        Ok(transactions)
+    }
+
+    /// Aborts the open transaction of a disconnected client.
+    pub(crate) async fn abort_client_transaction(&self, client_id: u32) {
+        let Some(transaction_id) = self.client_manager.get_transaction_id(client_id) else {
+            return;
+        };
+        if let Err(error) = self.abort_transaction(client_id, transaction_id).await {
+            warn!(
+                "Failed to abort transaction with ID: {transaction_id} of client with ID: {client_id}: {error}"
+            );
+        }
+    }
+}
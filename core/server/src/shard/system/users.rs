@@ -17,19 +17,31 @@
  */
 
 use super::COMPONENT;
+use crate::audit::AuditEvent;
 use crate::metadata::UserMeta;
 use crate::shard::IggyShard;
-use crate::streaming::session::Session;
+use crate::shard::transmission::frame::ShardResponse;
+use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
+use crate::streaming::session::{ScramChallenge, Session};
+use crate::streaming::users::quota_manager::QuotaUsage;
+use crate::streaming::users::token_authenticator::TokenPrincipal;
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
 use dashmap::DashMap;
 use err_trail::ErrContext;
+use iggy_binary_protocol::WireName;
+use iggy_binary_protocol::codes::{CREATE_USER_CODE, command_name};
+use iggy_binary_protocol::requests::users::CreateUserRequest;
 use iggy_common::Identifier;
 use iggy_common::IggyError;
 use iggy_common::Permissions;
 use iggy_common::UserStatus;
+use iggy_common::defaults::DEFAULT_ROOT_USER_ID;
+use iggy_common::scram::{self, ScramCredentials, ScramKey};
+use iggy_common::wire_conversions::permissions_to_wire;
+use iggy_common::{Quota, QuotaScope};
 use std::sync::Arc;
-use tracing::{error, warn};
+use tracing::{debug, error, info, warn};
 
 const MAX_USERS: usize = u32::MAX as usize;
 
@@ -45,6 +57,7 @@ impl IggyShard {
             status: meta.status,
             username: meta.username.to_string(),
             password: meta.password_hash.to_string(),
+            scram_credentials: meta.scram_credentials.as_deref().cloned(),
             created_at: meta.created_at,
             permissions: meta.permissions.as_ref().map(|p| (**p).clone()),
             personal_access_tokens: pat_map,
@@ -92,6 +105,7 @@ impl IggyShard {
         permissions: Option<Permissions>,
     ) -> Result<User, IggyError> {
         let password_hash = crypto::hash_password(password);
+        let scram_credentials = ScramCredentials::new(password);
 
         let user_id = self
             .writer()
@@ -99,6 +113,7 @@ impl IggyShard {
                 &self.metadata,
                 Arc::from(username),
                 Arc::from(password_hash.as_str()),
+                Some(Arc::new(scram_credentials)),
                 status,
                 permissions.map(Arc::new),
                 MAX_USERS,
@@ -136,6 +151,7 @@ impl IggyShard {
                 )
             })?;
         self.metrics.decrement_users(1);
+        self.quota_manager.delete_user(user_u32_id);
 
         self.writer().delete_user(user_u32_id);
 
@@ -179,8 +195,12 @@ impl IggyShard {
             id: current_meta.id,
             username: current_meta.username,
             password_hash: current_meta.password_hash,
+            scram_credentials: current_meta.scram_credentials,
             status: current_meta.status,
             permissions: permissions.map(Arc::new),
+            roles: current_meta.roles,
+            user_quota: current_meta.user_quota,
+            client_quota: current_meta.client_quota,
             created_at: current_meta.created_at,
         };
 
@@ -189,6 +209,71 @@ impl IggyShard {
         Ok(())
     }
 
+    pub fn set_user_quota(
+        &self,
+        user_id: &Identifier,
+        scope: QuotaScope,
+        quota: Option<Quota>,
+    ) -> Result<(), IggyError> {
+        let user: User = self.get_user(user_id).error(|e: &IggyError| {
+            format!("{COMPONENT} (error: {e}) - failed to get user with id: {user_id}")
+        })?;
+
+        let mut updated_meta = self
+            .metadata
+            .get_user(user.id)
+            .ok_or_else(|| IggyError::ResourceNotFound(user_id.to_string()))?;
+        let quota = quota.filter(|quota| !quota.is_unlimited());
+        match scope {
+            QuotaScope::User => updated_meta.user_quota = quota,
+            QuotaScope::Client => updated_meta.client_quota = quota,
+        }
+
+        self.writer().update_user_meta(user.id, updated_meta);
+        info!("Set {scope} quota for user with ID: {}.", user.id);
+        Ok(())
+    }
+
+    /// Charges the request against the quotas of the session's user, or rejects it
+    /// with the time the client has to wait when one of them is exceeded.
+    pub fn acquire_quota(&self, session: &Session, usage: QuotaUsage) -> Result<(), IggyError> {
+        if !session.is_authenticated() {
+            return Ok(());
+        }
+
+        let Some(quotas) = self.metadata.get_user_quotas(session.get_user_id()) else {
+            return Ok(());
+        };
+        if quotas.is_empty() {
+            return Ok(());
+        }
+
+        self.quota_manager
+            .acquire(&quotas, session.client_id, usage)
+            .map_err(|throttle_time| {
+                let throttle_time_ms =
+                    u32::try_from(throttle_time.as_micros().div_ceil(1000)).unwrap_or(u32::MAX);
+                debug!(
+                    "Request of session: {session} exceeded the quota, throttled for {throttle_time_ms} ms."
+                );
+                IggyError::Throttled(throttle_time_ms)
+            })
+    }
+
+    /// Charges the bytes returned by a poll against the fetch quotas of the session's user.
+    pub fn record_fetched_bytes(&self, session: &Session, bytes: u64) {
+        if bytes == 0 {
+            return;
+        }
+
+        if let Some(quotas) = self.metadata.get_user_quotas(session.get_user_id())
+            && !quotas.is_empty()
+        {
+            self.quota_manager
+                .record_fetch(&quotas, session.client_id, bytes);
+        }
+    }
+
     pub fn change_password(
         &self,
         user_id: &Identifier,
@@ -219,8 +304,12 @@ impl IggyShard {
             id: current_meta.id,
             username: current_meta.username,
             password_hash: Arc::from(new_password_hash.as_str()),
+            scram_credentials: Some(Arc::new(ScramCredentials::new(new_password))),
             status: current_meta.status,
             permissions: current_meta.permissions,
+            roles: current_meta.roles,
+            user_quota: current_meta.user_quota,
+            client_quota: current_meta.client_quota,
             created_at: current_meta.created_at,
         };
 
@@ -292,6 +381,180 @@ impl IggyShard {
         Ok(user)
     }
 
+    pub fn start_scram_login(
+        &self,
+        username: &str,
+        client_nonce: &str,
+        session: &Session,
+    ) -> Result<ScramChallenge, IggyError> {
+        let user = match self.get_user(&username.try_into()?) {
+            Ok(user) => user,
+            Err(_) => {
+                error!("Cannot start SCRAM login for user: {username} (not found).");
+                return Err(IggyError::InvalidCredentials);
+            }
+        };
+
+        if !user.is_active() {
+            warn!("User: {username} with ID: {} is inactive.", user.id);
+            return Err(IggyError::UserInactive);
+        }
+
+        let Some(credentials) = user.scram_credentials else {
+            warn!(
+                "User: {username} with ID: {} has no SCRAM credentials, the password must be changed to enable SCRAM login.",
+                user.id
+            );
+            return Err(IggyError::InvalidCredentials);
+        };
+
+        let nonce = format!("{client_nonce}{}", scram::generate_nonce());
+        let auth_message = scram::auth_message(
+            username,
+            client_nonce,
+            &nonce,
+            &credentials.salt,
+            credentials.iterations,
+        );
+        let challenge = ScramChallenge {
+            username: username.to_owned(),
+            nonce,
+            salt: credentials.salt,
+            iterations: credentials.iterations,
+            auth_message,
+        };
+        session.set_scram_challenge(challenge.clone());
+        Ok(challenge)
+    }
+
+    pub fn finish_scram_login(
+        &self,
+        nonce: &str,
+        client_proof: &[u8],
+        session: &Session,
+    ) -> Result<(User, ScramKey), IggyError> {
+        let Some(challenge) = session.take_scram_challenge() else {
+            warn!("SCRAM login was not started for session: {session}.");
+            return Err(IggyError::InvalidCredentials);
+        };
+
+        if challenge.nonce != nonce {
+            warn!("Invalid SCRAM nonce for session: {session}.");
+            return Err(IggyError::InvalidCredentials);
+        }
+
+        let username = challenge.username.as_str();
+        let user = self.get_user(&username.try_into()?).map_err(|_| {
+            error!("Cannot finish SCRAM login for user: {username} (not found).");
+            IggyError::InvalidCredentials
+        })?;
+        let Some(credentials) = &user.scram_credentials else {
+            return Err(IggyError::InvalidCredentials);
+        };
+
+        if !credentials.verify_client_proof(&challenge.auth_message, client_proof) {
+            warn!(
+                "Invalid SCRAM proof for user: {username} with ID: {}.",
+                user.id
+            );
+            return Err(IggyError::InvalidCredentials);
+        }
+
+        let server_signature = credentials.server_signature(&challenge.auth_message);
+        let user = self.login_user_with_credentials(username, None, Some(session))?;
+        Ok((user, server_signature))
+    }
+
+    pub async fn login_user_with_token(
+        &self,
+        token: &str,
+        session: &Session,
+    ) -> Result<User, IggyError> {
+        let username = match self.token_authenticator.authenticate(token).await? {
+            TokenPrincipal::UserId(user_id) => {
+                self.get_user(&user_id.try_into()?)
+                    .map_err(|_| {
+                        error!("Cannot login with token, user with ID: {user_id} not found.");
+                        IggyError::InvalidCredentials
+                    })?
+                    .username
+            }
+            TokenPrincipal::Username {
+                username,
+                auto_provision,
+                permissions,
+            } => {
+                if self.try_get_user(&username.as_str().try_into()?)?.is_none() {
+                    if !auto_provision {
+                        error!("Cannot login with token, user: {username} not found.");
+                        return Err(IggyError::InvalidCredentials);
+                    }
+                    self.provision_token_user(&username, permissions, session)
+                        .await?;
+                }
+                username
+            }
+        };
+
+        let user = self.get_user(&username.as_str().try_into()?)?;
+        if user.is_root() {
+            warn!("Access token cannot be mapped to the root user.");
+            return Err(IggyError::InvalidCredentials);
+        }
+        self.login_user_with_credentials(&username, None, Some(session))
+    }
+
+    /// Creates the user mapped by the access token claims, with a random password nobody knows,
+    /// so the user can sign in with the tokens only, until the password is changed.
+    /// The user is created on behalf of the root user, so the creation is recorded in the
+    /// audit log here, as it's not a command sent by the client.
+    async fn provision_token_user(
+        &self,
+        username: &str,
+        permissions: Option<Permissions>,
+        session: &Session,
+    ) -> Result<(), IggyError> {
+        let command = CreateUserRequest {
+            username: WireName::new(username).map_err(|_| IggyError::InvalidUsername)?,
+            password: uuid::Uuid::new_v4().to_string(),
+            status: UserStatus::Active.as_code(),
+            permissions: permissions.as_ref().map(permissions_to_wire),
+        };
+        let request = ShardRequest::control_plane(ShardRequestPayload::CreateUserRequest {
+            user_id: DEFAULT_ROOT_USER_ID,
+            command,
+        });
+        let result = match self.send_to_control_plane(request).await {
+            Ok(ShardResponse::CreateUserResponse(user)) => {
+                info!(
+                    "Provisioned user: {username} with ID: {} from the access token.",
+                    user.id
+                );
+                Ok(user.id)
+            }
+            // The user was provisioned by the concurrent login with another token.
+            Ok(ShardResponse::ErrorResponse(IggyError::UserAlreadyExists)) => return Ok(()),
+            Ok(ShardResponse::ErrorResponse(err)) | Err(err) => Err(err),
+            Ok(_) => unreachable!("Expected CreateUserResponse"),
+        };
+
+        self.audit_log.record(AuditEvent {
+            user_id: result.as_ref().ok().copied(),
+            username: Some(username.to_string()),
+            client_id: (session.client_id > 0).then_some(session.client_id),
+            address: Some(session.ip_address.to_string()),
+            details: Some(format!(
+                "username: {username}, permissions: {}, provisioned from the access token",
+                permissions.is_some()
+            )),
+            ..AuditEvent::new(
+                command_name(CREATE_USER_CODE).unwrap_or("unknown"),
+                result.as_ref().map(|_| ()),
+            )
+        });
+        result.map(|_| ())
+    }
+
     pub fn logout_user(&self, session: &Session) -> Result<(), IggyError> {
         let client_id = session.client_id;
         if client_id > 0 {
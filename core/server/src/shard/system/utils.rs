@@ -214,22 +214,24 @@ impl IggyShard {
     pub fn resolve_topic_for_store_consumer_offset(
         &self,
         user_id: u32,
+        consumer: &Consumer,
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<ResolvedTopic, IggyError> {
         self.metadata
-            .resolve_for_store_consumer_offset(user_id, stream_id, topic_id)
+            .resolve_for_store_consumer_offset(user_id, consumer, stream_id, topic_id)
     }
 
     /// Resolves topic and verifies user has permission to delete consumer offset atomically.
     pub fn resolve_topic_for_delete_consumer_offset(
         &self,
         user_id: u32,
+        consumer: &Consumer,
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<ResolvedTopic, IggyError> {
         self.metadata
-            .resolve_for_delete_consumer_offset(user_id, stream_id, topic_id)
+            .resolve_for_delete_consumer_offset(user_id, consumer, stream_id, topic_id)
     }
 
     /// Resolves partition and verifies user has permission to delete segments atomically.
@@ -0,0 +1,122 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::shard::IggyShard;
+use crate::shard::transmission::frame::ShardResponse;
+use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
+use iggy_common::IggyError;
+use iggy_common::sharding::IggyNamespace;
+use std::rc::Rc;
+use tracing::{error, info, trace};
+
+pub fn spawn_message_compactor(shard: Rc<IggyShard>) {
+    if !shard.config.data_maintenance.compaction.enabled {
+        info!("Message compactor is disabled.");
+        return;
+    }
+
+    let period = shard
+        .config
+        .data_maintenance
+        .compaction
+        .interval
+        .get_duration();
+    info!(
+        "Message compactor is enabled, sealed segments of compacted topics will be rewritten every: {:?}",
+        period
+    );
+    let shard_clone = shard.clone();
+    shard
+        .task_registry
+        .periodic("compact_messages")
+        .every(period)
+        .tick(move |_shutdown| compact_messages(shard_clone.clone()))
+        .spawn();
+}
+
+/// Groups namespaces by topic and sends a single `CompactTopicMessages` per topic to the pump.
+/// Topics with the `delete` cleanup policy are skipped inside the pump handler.
+async fn compact_messages(shard: Rc<IggyShard>) -> Result<(), IggyError> {
+    trace!("Compacting messages...");
+
+    let namespaces = shard.get_current_shard_namespaces();
+
+    let mut topics: std::collections::HashMap<(usize, usize), Vec<usize>> =
+        std::collections::HashMap::new();
+
+    for ns in namespaces {
+        topics
+            .entry((ns.stream_id(), ns.topic_id()))
+            .or_default()
+            .push(ns.partition_id());
+    }
+
+    let mut total_compacted_segments = 0u64;
+    let mut total_removed_messages = 0u64;
+
+    for ((stream_id, topic_id), partition_ids) in topics {
+        let ns = IggyNamespace::new(stream_id, topic_id, partition_ids[0]);
+        let payload = ShardRequestPayload::CompactTopicMessages {
+            stream_id,
+            topic_id,
+            partition_ids,
+        };
+        let request = ShardRequest::data_plane(ns, payload);
+
+        match shard.send_to_data_plane(request).await {
+            Ok(ShardResponse::CompactTopicMessages {
+                compacted_segments,
+                deleted_segments,
+                removed_messages,
+            }) => {
+                if compacted_segments > 0 {
+                    info!(
+                        "Compacted {} segments (deleted {} empty ones) and removed {} messages for stream {}, topic {}",
+                        compacted_segments, deleted_segments, removed_messages, stream_id, topic_id
+                    );
+                    shard.metrics.decrement_segments(deleted_segments as u32);
+                    shard.metrics.decrement_messages(removed_messages);
+                    total_compacted_segments += compacted_segments;
+                    total_removed_messages += removed_messages;
+                }
+            }
+            Ok(ShardResponse::ErrorResponse(err)) => {
+                error!(
+                    "Failed to compact messages for stream {}, topic {}: {}",
+                    stream_id, topic_id, err
+                );
+            }
+            Ok(_) => unreachable!("Expected CompactTopicMessages response"),
+            Err(err) => {
+                error!(
+                    "Failed to send CompactTopicMessages for stream {}, topic {}: {}",
+                    stream_id, topic_id, err
+                );
+            }
+        }
+    }
+
+    if total_compacted_segments > 0 {
+        info!(
+            "Total compacted: {} segments and {} messages",
+            total_compacted_segments, total_removed_messages
+        );
+    }
+
+    Ok(())
+}
@@ -19,9 +19,11 @@
 mod heartbeat_verifier;
 mod jwt_token_cleaner;
 mod message_cleaner;
+mod message_compactor;
 mod message_saver;
 mod personal_access_token_cleaner;
 mod revocation_timeout;
+mod segment_archiver;
 mod sysinfo_printer;
 #[cfg(feature = "systemd")]
 mod systemd_watchdog;
@@ -29,9 +31,11 @@ mod systemd_watchdog;
 pub use heartbeat_verifier::spawn_heartbeat_verifier;
 pub use jwt_token_cleaner::spawn_jwt_token_cleaner;
 pub use message_cleaner::spawn_message_cleaner;
+pub use message_compactor::spawn_message_compactor;
 pub use message_saver::spawn_message_saver;
 pub use personal_access_token_cleaner::spawn_personal_access_token_cleaner;
 pub use revocation_timeout::spawn_revocation_timeout_checker;
+pub use segment_archiver::spawn_segment_archiver;
 pub use sysinfo_printer::spawn_sysinfo_printer;
 #[cfg(feature = "systemd")]
 pub use systemd_watchdog::spawn_systemd_watchdog;
@@ -0,0 +1,169 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::shard::IggyShard;
+use crate::shard::transmission::frame::ShardResponse;
+use crate::shard::transmission::message::{ShardRequest, ShardRequestPayload};
+use iggy_common::sharding::IggyNamespace;
+use iggy_common::{IggyError, IggyTimestamp};
+use std::rc::Rc;
+use tracing::{error, info, trace};
+
+struct SegmentToArchive {
+    start_offset: u64,
+    size: u64,
+    messages_path: String,
+    index_path: String,
+}
+
+pub fn spawn_segment_archiver(shard: Rc<IggyShard>) {
+    if shard.archiver.is_none() {
+        info!("Segment archiver is disabled.");
+        return;
+    }
+
+    let config = &shard.config.data_maintenance.archiver;
+    let period = config.interval.get_duration();
+    info!(
+        "Segment archiver is enabled, sealed segments older than: {} will be offloaded to the {} archive every: {:?}",
+        config.offload_after, config.kind, period
+    );
+    let shard_clone = shard.clone();
+    shard
+        .task_registry
+        .periodic("archive_segments")
+        .every(period)
+        .tick(move |_shutdown| archive_segments(shard_clone.clone()))
+        .spawn();
+}
+
+/// Copies the files of the sealed segments to the archive outside of the message pump and sends
+/// a single `OffloadSegments` per partition, which removes the local messages files.
+async fn archive_segments(shard: Rc<IggyShard>) -> Result<(), IggyError> {
+    trace!("Archiving segments...");
+    let Some(archiver) = shard.archiver.as_ref() else {
+        return Ok(());
+    };
+
+    let now = IggyTimestamp::now();
+    let offload_after = shard.config.data_maintenance.archiver.offload_after;
+    let mut total_offloaded_segments = 0u64;
+
+    for ns in shard.get_current_shard_namespaces() {
+        let segments = {
+            let partitions = shard.local_partitions.borrow();
+            let Some(partition) = partitions.get(&ns) else {
+                continue;
+            };
+
+            let segments = partition.log.segments();
+            let last_idx = segments.len().saturating_sub(1);
+            segments
+                .iter()
+                .zip(partition.log.storages())
+                .take(last_idx)
+                .filter(|(segment, _)| segment.should_offload(now, offload_after))
+                .filter_map(|(segment, storage)| {
+                    let (Some(messages_path), Some(index_path)) = storage.segment_and_index_paths()
+                    else {
+                        return None;
+                    };
+                    Some(SegmentToArchive {
+                        start_offset: segment.start_offset,
+                        size: segment.size.as_bytes_u64(),
+                        messages_path,
+                        index_path,
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        if segments.is_empty() {
+            continue;
+        }
+
+        let mut archived_segments = Vec::with_capacity(segments.len());
+        for segment in segments {
+            if let Err(err) = archiver
+                .archive_segment(
+                    &ns,
+                    segment.start_offset,
+                    &segment.messages_path,
+                    &segment.index_path,
+                )
+                .await
+            {
+                error!(
+                    "Failed to archive segment (start: {}) of partition {} (stream {}, topic {}): {}",
+                    segment.start_offset,
+                    ns.partition_id(),
+                    ns.stream_id(),
+                    ns.topic_id(),
+                    err
+                );
+                continue;
+            }
+            archived_segments.push((segment.start_offset, segment.size));
+        }
+        if archived_segments.is_empty() {
+            continue;
+        }
+
+        total_offloaded_segments += offload_segments(&shard, ns, archived_segments).await;
+    }
+
+    if total_offloaded_segments > 0 {
+        info!("Total offloaded: {} segments", total_offloaded_segments);
+    }
+
+    Ok(())
+}
+
+async fn offload_segments(shard: &IggyShard, ns: IggyNamespace, segments: Vec<(u64, u64)>) -> u64 {
+    let payload = ShardRequestPayload::OffloadSegments {
+        stream_id: ns.stream_id(),
+        topic_id: ns.topic_id(),
+        partition_id: ns.partition_id(),
+        segments,
+    };
+    let request = ShardRequest::data_plane(ns, payload);
+
+    match shard.send_to_data_plane(request).await {
+        Ok(ShardResponse::OffloadSegments { offloaded_segments }) => offloaded_segments,
+        Ok(ShardResponse::ErrorResponse(err)) => {
+            error!(
+                "Failed to offload segments of partition {} (stream {}, topic {}): {}",
+                ns.partition_id(),
+                ns.stream_id(),
+                ns.topic_id(),
+                err
+            );
+            0
+        }
+        Ok(_) => unreachable!("Expected OffloadSegments response"),
+        Err(err) => {
+            error!(
+                "Failed to send OffloadSegments for partition {} (stream {}, topic {}): {}",
+                ns.partition_id(),
+                ns.stream_id(),
+                ns.topic_id(),
+                err
+            );
+            0
+        }
+    }
+}
@@ -16,13 +16,14 @@
  * under the License.
  */
 use crate::{
+    metadata::RoleMeta,
     shard::transmission::message::ShardMessage,
-    streaming::{segments::IggyMessagesBatchSet, users::user::User},
+    streaming::{deliveries::DeadLetter, segments::IggyMessagesBatchSet, users::user::User},
 };
 use async_channel::Sender;
 use iggy_common::{
-    CompressionAlgorithm, IggyError, IggyExpiry, IggyPollMetadata, IggyTimestamp, MaxTopicSize,
-    PersonalAccessToken, Stats,
+    CleanupPolicy, CompressionAlgorithm, IggyError, IggyExpiry, IggyPollMetadata, IggyTimestamp,
+    MaxTopicSize, PersonalAccessToken, Stats,
 };
 use std::sync::Arc;
 
@@ -45,6 +46,7 @@ pub struct TopicResponseData {
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: u8,
+    pub cleanup_policy: CleanupPolicy,
 }
 
 /// Data needed to construct a consumer group creation response.
@@ -58,7 +60,9 @@ pub struct ConsumerGroupResponseData {
 // TODO: make nice types in common module so that each command has respective *Response struct, i.e. CreateStream -> CreateStreamResponse
 #[derive(Debug)]
 pub enum ShardResponse {
-    PollMessages((IggyPollMetadata, IggyMessagesBatchSet)),
+    PollMessages((IggyPollMetadata, IggyMessagesBatchSet, Vec<DeadLetter>)),
+    NackMessage,
+    AckMessages,
     SendMessages,
     FlushUnsavedBuffer {
         flushed_count: u32,
@@ -71,6 +75,15 @@ pub enum ShardResponse {
         deleted_segments: u64,
         deleted_messages: u64,
     },
+    CompactTopicMessages {
+        compacted_segments: u64,
+        deleted_segments: u64,
+        removed_messages: u64,
+    },
+    OffloadSegments {
+        offloaded_segments: u64,
+    },
+    CompleteTransaction,
     Event,
     CreateStreamResponse(StreamResponseData),
     DeleteStreamResponse,
@@ -86,6 +99,12 @@ pub enum ShardResponse {
     SocketTransferResponse,
     UpdatePermissionsResponse,
     ChangePasswordResponse,
+    SetUserQuotaResponse,
+    CreateRoleResponse(RoleMeta),
+    UpdateRoleResponse,
+    DeleteRoleResponse,
+    AssignRoleResponse,
+    UnassignRoleResponse,
     UpdateUserResponse(User),
     CreateConsumerGroupResponse(ConsumerGroupResponseData),
     JoinConsumerGroupResponse,
@@ -97,6 +116,7 @@ pub enum ShardResponse {
     CompletePartitionRevocationResponse,
     PurgeStreamResponse,
     PurgeTopicResponse,
+    RotateTopicKeyResponse,
     ErrorResponse(IggyError),
 }
 
@@ -17,14 +17,18 @@
  */
 use crate::{
     shard::{system::messages::PollingArgs, transmission::event::ShardEvent},
-    streaming::{polling_consumer::PollingConsumer, segments::IggyMessagesBatchMut},
+    streaming::{
+        deliveries::DeliveryPolicy, polling_consumer::PollingConsumer, producers::ProducerSequence,
+        segments::IggyMessagesBatchMut,
+    },
 };
 use iggy_binary_protocol::requests::{
-    consumer_groups::*, partitions::*, personal_access_tokens::*, streams::*, topics::*, users::*,
+    consumer_groups::*, partitions::*, personal_access_tokens::*, roles::*, streams::*, topics::*,
+    users::*,
 };
 use iggy_common::sharding::IggyNamespace;
 
-use std::{net::SocketAddr, os::fd::OwnedFd};
+use std::{net::SocketAddr, os::fd::OwnedFd, sync::Arc};
 
 /// Resolved stream ID. Contains only the numeric ID - `Identifier` stays at handler boundary.
 #[derive(Debug, Clone, Copy)]
@@ -97,10 +101,24 @@ pub enum ShardRequestPayload {
     // Data-plane operations: namespace provided via ShardRequest
     SendMessages {
         batch: IggyMessagesBatchMut,
+        transaction_id: Option<u64>,
+        producer: Option<ProducerSequence>,
     },
     PollMessages {
         consumer: PollingConsumer,
         args: PollingArgs,
+        delivery: Option<Arc<DeliveryPolicy>>,
+    },
+    NackMessage {
+        group_id: usize,
+        policy: Arc<DeliveryPolicy>,
+        offset: u64,
+        reason: String,
+    },
+    AckMessages {
+        group_id: usize,
+        policy: Arc<DeliveryPolicy>,
+        offsets: Vec<u64>,
     },
     FlushUnsavedBuffer {
         fsync: bool,
@@ -113,6 +131,22 @@ pub enum ShardRequestPayload {
         topic_id: usize,
         partition_ids: Vec<usize>,
     },
+    CompactTopicMessages {
+        stream_id: usize,
+        topic_id: usize,
+        partition_ids: Vec<usize>,
+    },
+    /// Sealed segments already copied to the archive, as `(start_offset, size)`.
+    OffloadSegments {
+        stream_id: usize,
+        topic_id: usize,
+        partition_id: usize,
+        segments: Vec<(u64, u64)>,
+    },
+    CompleteTransaction {
+        transaction_id: u64,
+        committed: bool,
+    },
     SocketTransfer {
         fd: OwnedFd,
         from_shard: u16,
@@ -157,6 +191,10 @@ pub enum ShardRequestPayload {
         user_id: u32,
         command: PurgeTopicRequest,
     },
+    RotateTopicKeyRequest {
+        user_id: u32,
+        command: RotateTopicKeyRequest,
+    },
 
     // Control-plane: partition operations
     CreatePartitionsRequest {
@@ -189,6 +227,32 @@ pub enum ShardRequestPayload {
         user_id: u32,
         command: ChangePasswordRequest,
     },
+    SetUserQuotaRequest {
+        user_id: u32,
+        command: SetUserQuotaRequest,
+    },
+
+    // Control-plane: role operations
+    CreateRoleRequest {
+        user_id: u32,
+        command: CreateRoleRequest,
+    },
+    UpdateRoleRequest {
+        user_id: u32,
+        command: UpdateRoleRequest,
+    },
+    DeleteRoleRequest {
+        user_id: u32,
+        command: DeleteRoleRequest,
+    },
+    AssignRoleRequest {
+        user_id: u32,
+        command: AssignRoleRequest,
+    },
+    UnassignRoleRequest {
+        user_id: u32,
+        command: UnassignRoleRequest,
+    },
 
     // Control-plane: consumer group operations
     CreateConsumerGroupRequest {
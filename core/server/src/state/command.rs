@@ -17,28 +17,29 @@
  */
 
 use crate::state::models::{
-    CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash, CreateStreamWithId,
-    CreateTopicWithId, CreateUserWithId,
+    ChangePasswordWithCredentials, CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash,
+    CreateRoleWithId, CreateStreamWithId, CreateTopicWithId, CreateUserWithId,
+    RotateTopicKeyWithKey,
 };
 use bytes::{BufMut, BytesMut};
 use iggy_binary_protocol::codes::{
-    CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS_CODE,
-    CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE, CREATE_USER_CODE,
-    DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
-    DELETE_SEGMENTS_CODE, DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE,
-    PURGE_STREAM_CODE, PURGE_TOPIC_CODE, UPDATE_PERMISSIONS_CODE, UPDATE_STREAM_CODE,
-    UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
+    ASSIGN_ROLE_CODE, CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS_CODE,
+    CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_ROLE_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE,
+    CREATE_USER_CODE, DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS_CODE,
+    DELETE_PERSONAL_ACCESS_TOKEN_CODE, DELETE_ROLE_CODE, DELETE_SEGMENTS_CODE, DELETE_STREAM_CODE,
+    DELETE_TOPIC_CODE, DELETE_USER_CODE, PURGE_STREAM_CODE, PURGE_TOPIC_CODE,
+    ROTATE_TOPIC_KEY_CODE, SET_USER_QUOTA_CODE, UNASSIGN_ROLE_CODE, UPDATE_PERMISSIONS_CODE,
+    UPDATE_ROLE_CODE, UPDATE_STREAM_CODE, UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
 };
 use iggy_binary_protocol::requests::{
     consumer_groups::DeleteConsumerGroupRequest,
     partitions::{CreatePartitionsRequest, DeletePartitionsRequest},
     personal_access_tokens::DeletePersonalAccessTokenRequest,
+    roles::{AssignRoleRequest, DeleteRoleRequest, UnassignRoleRequest, UpdateRoleRequest},
     segments::DeleteSegmentsRequest,
     streams::{DeleteStreamRequest, PurgeStreamRequest, UpdateStreamRequest},
     topics::{DeleteTopicRequest, PurgeTopicRequest, UpdateTopicRequest},
-    users::{
-        ChangePasswordRequest, DeleteUserRequest, UpdatePermissionsRequest, UpdateUserRequest,
-    },
+    users::{DeleteUserRequest, SetUserQuotaRequest, UpdatePermissionsRequest, UpdateUserRequest},
 };
 use iggy_binary_protocol::{WireDecode, WireEncode, WireError};
 use std::fmt::{Display, Formatter};
@@ -53,6 +54,7 @@ pub enum EntryCommand {
     UpdateTopic(UpdateTopicRequest),
     DeleteTopic(DeleteTopicRequest),
     PurgeTopic(PurgeTopicRequest),
+    RotateTopicKey(RotateTopicKeyWithKey),
     CreatePartitions(CreatePartitionsRequest),
     DeletePartitions(DeletePartitionsRequest),
     DeleteSegments(DeleteSegmentsRequest),
@@ -61,8 +63,14 @@ pub enum EntryCommand {
     CreateUser(CreateUserWithId),
     UpdateUser(UpdateUserRequest),
     DeleteUser(DeleteUserRequest),
-    ChangePassword(ChangePasswordRequest),
+    ChangePassword(ChangePasswordWithCredentials),
     UpdatePermissions(UpdatePermissionsRequest),
+    SetUserQuota(SetUserQuotaRequest),
+    CreateRole(CreateRoleWithId),
+    UpdateRole(UpdateRoleRequest),
+    DeleteRole(DeleteRoleRequest),
+    AssignRole(AssignRoleRequest),
+    UnassignRole(UnassignRoleRequest),
     CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash),
     DeletePersonalAccessToken(DeletePersonalAccessTokenRequest),
 }
@@ -78,6 +86,7 @@ impl WireEncode for EntryCommand {
             EntryCommand::UpdateTopic(cmd) => cmd.encoded_size(),
             EntryCommand::DeleteTopic(cmd) => cmd.encoded_size(),
             EntryCommand::PurgeTopic(cmd) => cmd.encoded_size(),
+            EntryCommand::RotateTopicKey(cmd) => cmd.encoded_size(),
             EntryCommand::CreatePartitions(cmd) => cmd.encoded_size(),
             EntryCommand::DeletePartitions(cmd) => cmd.encoded_size(),
             EntryCommand::DeleteSegments(cmd) => cmd.encoded_size(),
@@ -88,6 +97,12 @@ impl WireEncode for EntryCommand {
             EntryCommand::DeleteUser(cmd) => cmd.encoded_size(),
             EntryCommand::ChangePassword(cmd) => cmd.encoded_size(),
             EntryCommand::UpdatePermissions(cmd) => cmd.encoded_size(),
+            EntryCommand::SetUserQuota(cmd) => cmd.encoded_size(),
+            EntryCommand::CreateRole(cmd) => cmd.encoded_size(),
+            EntryCommand::UpdateRole(cmd) => cmd.encoded_size(),
+            EntryCommand::DeleteRole(cmd) => cmd.encoded_size(),
+            EntryCommand::AssignRole(cmd) => cmd.encoded_size(),
+            EntryCommand::UnassignRole(cmd) => cmd.encoded_size(),
             EntryCommand::CreatePersonalAccessToken(cmd) => cmd.encoded_size(),
             EntryCommand::DeletePersonalAccessToken(cmd) => cmd.encoded_size(),
         };
@@ -104,6 +119,7 @@ impl WireEncode for EntryCommand {
             EntryCommand::UpdateTopic(cmd) => (UPDATE_TOPIC_CODE, cmd.encoded_size()),
             EntryCommand::DeleteTopic(cmd) => (DELETE_TOPIC_CODE, cmd.encoded_size()),
             EntryCommand::PurgeTopic(cmd) => (PURGE_TOPIC_CODE, cmd.encoded_size()),
+            EntryCommand::RotateTopicKey(cmd) => (ROTATE_TOPIC_KEY_CODE, cmd.encoded_size()),
             EntryCommand::CreatePartitions(cmd) => (CREATE_PARTITIONS_CODE, cmd.encoded_size()),
             EntryCommand::DeletePartitions(cmd) => (DELETE_PARTITIONS_CODE, cmd.encoded_size()),
             EntryCommand::DeleteSegments(cmd) => (DELETE_SEGMENTS_CODE, cmd.encoded_size()),
@@ -118,6 +134,12 @@ impl WireEncode for EntryCommand {
             EntryCommand::DeleteUser(cmd) => (DELETE_USER_CODE, cmd.encoded_size()),
             EntryCommand::ChangePassword(cmd) => (CHANGE_PASSWORD_CODE, cmd.encoded_size()),
             EntryCommand::UpdatePermissions(cmd) => (UPDATE_PERMISSIONS_CODE, cmd.encoded_size()),
+            EntryCommand::SetUserQuota(cmd) => (SET_USER_QUOTA_CODE, cmd.encoded_size()),
+            EntryCommand::CreateRole(cmd) => (CREATE_ROLE_CODE, cmd.encoded_size()),
+            EntryCommand::UpdateRole(cmd) => (UPDATE_ROLE_CODE, cmd.encoded_size()),
+            EntryCommand::DeleteRole(cmd) => (DELETE_ROLE_CODE, cmd.encoded_size()),
+            EntryCommand::AssignRole(cmd) => (ASSIGN_ROLE_CODE, cmd.encoded_size()),
+            EntryCommand::UnassignRole(cmd) => (UNASSIGN_ROLE_CODE, cmd.encoded_size()),
             EntryCommand::CreatePersonalAccessToken(cmd) => {
                 (CREATE_PERSONAL_ACCESS_TOKEN_CODE, cmd.encoded_size())
             }
@@ -136,6 +158,7 @@ impl WireEncode for EntryCommand {
             EntryCommand::UpdateTopic(cmd) => cmd.encode(buf),
             EntryCommand::DeleteTopic(cmd) => cmd.encode(buf),
             EntryCommand::PurgeTopic(cmd) => cmd.encode(buf),
+            EntryCommand::RotateTopicKey(cmd) => cmd.encode(buf),
             EntryCommand::CreatePartitions(cmd) => cmd.encode(buf),
             EntryCommand::DeletePartitions(cmd) => cmd.encode(buf),
             EntryCommand::DeleteSegments(cmd) => cmd.encode(buf),
@@ -146,6 +169,12 @@ impl WireEncode for EntryCommand {
             EntryCommand::DeleteUser(cmd) => cmd.encode(buf),
             EntryCommand::ChangePassword(cmd) => cmd.encode(buf),
             EntryCommand::UpdatePermissions(cmd) => cmd.encode(buf),
+            EntryCommand::SetUserQuota(cmd) => cmd.encode(buf),
+            EntryCommand::CreateRole(cmd) => cmd.encode(buf),
+            EntryCommand::UpdateRole(cmd) => cmd.encode(buf),
+            EntryCommand::DeleteRole(cmd) => cmd.encode(buf),
+            EntryCommand::AssignRole(cmd) => cmd.encode(buf),
+            EntryCommand::UnassignRole(cmd) => cmd.encode(buf),
             EntryCommand::CreatePersonalAccessToken(cmd) => cmd.encode(buf),
             EntryCommand::DeletePersonalAccessToken(cmd) => cmd.encode(buf),
         }
@@ -195,6 +224,9 @@ impl WireDecode for EntryCommand {
                 EntryCommand::DeleteTopic(DeleteTopicRequest::decode_from(payload)?)
             }
             PURGE_TOPIC_CODE => EntryCommand::PurgeTopic(PurgeTopicRequest::decode_from(payload)?),
+            ROTATE_TOPIC_KEY_CODE => {
+                EntryCommand::RotateTopicKey(RotateTopicKeyWithKey::decode_from(payload)?)
+            }
             CREATE_PARTITIONS_CODE => {
                 EntryCommand::CreatePartitions(CreatePartitionsRequest::decode_from(payload)?)
             }
@@ -214,11 +246,21 @@ impl WireDecode for EntryCommand {
             UPDATE_USER_CODE => EntryCommand::UpdateUser(UpdateUserRequest::decode_from(payload)?),
             DELETE_USER_CODE => EntryCommand::DeleteUser(DeleteUserRequest::decode_from(payload)?),
             CHANGE_PASSWORD_CODE => {
-                EntryCommand::ChangePassword(ChangePasswordRequest::decode_from(payload)?)
+                EntryCommand::ChangePassword(ChangePasswordWithCredentials::decode_from(payload)?)
             }
             UPDATE_PERMISSIONS_CODE => {
                 EntryCommand::UpdatePermissions(UpdatePermissionsRequest::decode_from(payload)?)
             }
+            SET_USER_QUOTA_CODE => {
+                EntryCommand::SetUserQuota(SetUserQuotaRequest::decode_from(payload)?)
+            }
+            CREATE_ROLE_CODE => EntryCommand::CreateRole(CreateRoleWithId::decode_from(payload)?),
+            UPDATE_ROLE_CODE => EntryCommand::UpdateRole(UpdateRoleRequest::decode_from(payload)?),
+            DELETE_ROLE_CODE => EntryCommand::DeleteRole(DeleteRoleRequest::decode_from(payload)?),
+            ASSIGN_ROLE_CODE => EntryCommand::AssignRole(AssignRoleRequest::decode_from(payload)?),
+            UNASSIGN_ROLE_CODE => {
+                EntryCommand::UnassignRole(UnassignRoleRequest::decode_from(payload)?)
+            }
             CREATE_PERSONAL_ACCESS_TOKEN_CODE => EntryCommand::CreatePersonalAccessToken(
                 CreatePersonalAccessTokenWithHash::decode_from(payload)?,
             ),
@@ -242,6 +284,7 @@ impl Display for EntryCommand {
             EntryCommand::UpdateTopic(command) => write!(f, "UpdateTopic({command:?})"),
             EntryCommand::DeleteTopic(command) => write!(f, "DeleteTopic({command:?})"),
             EntryCommand::PurgeTopic(command) => write!(f, "PurgeTopic({command:?})"),
+            EntryCommand::RotateTopicKey(command) => write!(f, "RotateTopicKey({command})"),
             EntryCommand::CreatePartitions(command) => write!(f, "CreatePartitions({command:?})"),
             EntryCommand::DeletePartitions(command) => write!(f, "DeletePartitions({command:?})"),
             EntryCommand::DeleteSegments(command) => write!(f, "DeleteSegments({command:?})"),
@@ -254,10 +297,16 @@ impl Display for EntryCommand {
             EntryCommand::CreateUser(command) => write!(f, "CreateUser({command})"),
             EntryCommand::UpdateUser(command) => write!(f, "UpdateUser({command:?})"),
             EntryCommand::DeleteUser(command) => write!(f, "DeleteUser({command:?})"),
-            EntryCommand::ChangePassword(command) => write!(f, "ChangePassword({command:?})"),
+            EntryCommand::ChangePassword(command) => write!(f, "ChangePassword({command})"),
             EntryCommand::UpdatePermissions(command) => {
                 write!(f, "UpdatePermissions({command:?})")
             }
+            EntryCommand::SetUserQuota(command) => write!(f, "SetUserQuota({command:?})"),
+            EntryCommand::CreateRole(command) => write!(f, "CreateRole({command})"),
+            EntryCommand::UpdateRole(command) => write!(f, "UpdateRole({command:?})"),
+            EntryCommand::DeleteRole(command) => write!(f, "DeleteRole({command:?})"),
+            EntryCommand::AssignRole(command) => write!(f, "AssignRole({command:?})"),
+            EntryCommand::UnassignRole(command) => write!(f, "UnassignRole({command:?})"),
             EntryCommand::CreatePersonalAccessToken(command) => {
                 write!(f, "CreatePersonalAccessToken({command})")
             }
@@ -19,8 +19,11 @@
 use bytes::{BufMut, BytesMut};
 use iggy_binary_protocol::requests::{
     consumer_groups::CreateConsumerGroupRequest,
-    personal_access_tokens::CreatePersonalAccessTokenRequest, streams::CreateStreamRequest,
-    topics::CreateTopicRequest, users::CreateUserRequest,
+    personal_access_tokens::CreatePersonalAccessTokenRequest,
+    roles::CreateRoleRequest,
+    streams::CreateStreamRequest,
+    topics::{CreateTopicRequest, RotateTopicKeyRequest},
+    users::{ChangePasswordRequest, CreateUserRequest},
 };
 use iggy_binary_protocol::{WireDecode, WireEncode};
 use std::fmt;
@@ -48,6 +51,19 @@ pub struct CreateConsumerGroupWithId {
 pub struct CreateUserWithId {
     pub user_id: u32,
     pub command: CreateUserRequest,
+    pub scram_credentials: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateRoleWithId {
+    pub role_id: u32,
+    pub command: CreateRoleRequest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangePasswordWithCredentials {
+    pub command: ChangePasswordRequest,
+    pub scram_credentials: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -56,6 +72,14 @@ pub struct CreatePersonalAccessTokenWithHash {
     pub command: CreatePersonalAccessTokenRequest,
 }
 
+/// Stores the data encryption key generated for the topic, wrapped with the master key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotateTopicKeyWithKey {
+    pub key_id: u32,
+    pub wrapped_key: Vec<u8>,
+    pub command: RotateTopicKeyRequest,
+}
+
 impl Display for CreateStreamWithId {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
@@ -96,6 +120,26 @@ impl Display for CreateUserWithId {
     }
 }
 
+impl Display for CreateRoleWithId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CreateRoleWithId {{ name: {}, role_id: {} }}",
+            self.command.name, self.role_id
+        )
+    }
+}
+
+impl Display for ChangePasswordWithCredentials {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ChangePasswordWithCredentials {{ user_id: {}, password: [REDACTED], scram_credentials: [REDACTED] }}",
+            self.command.user_id,
+        )
+    }
+}
+
 impl Display for CreatePersonalAccessTokenWithHash {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
@@ -106,7 +150,45 @@ impl Display for CreatePersonalAccessTokenWithHash {
     }
 }
 
+impl Display for RotateTopicKeyWithKey {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "RotateTopicKeyWithKey {{ stream_id: {:?}, topic_id: {:?}, key_id: {}, wrapped_key: [REDACTED] }}",
+            self.command.stream_id, self.command.topic_id, self.key_id,
+        )
+    }
+}
+
 // Wire format for WithId wrappers: id:u32_le | inner_length:u32_le | inner_bytes
+// User credentials wrappers append an optional trailer: scram_length:u32_le | scram_credentials.
+// Entries written before SCRAM support end right after the inner command.
+
+fn scram_credentials_size(scram_credentials: &Option<String>) -> usize {
+    scram_credentials
+        .as_ref()
+        .map_or(0, |credentials| 4 + credentials.len())
+}
+
+fn encode_scram_credentials(scram_credentials: &Option<String>, buf: &mut BytesMut) {
+    if let Some(credentials) = scram_credentials {
+        buf.put_u32_le(credentials.len() as u32);
+        buf.put_slice(credentials.as_bytes());
+    }
+}
+
+fn decode_scram_credentials(
+    buf: &[u8],
+    pos: usize,
+) -> Result<(Option<String>, usize), iggy_binary_protocol::WireError> {
+    if buf.len() <= pos {
+        return Ok((None, pos));
+    }
+
+    let length = iggy_binary_protocol::codec::read_u32_le(buf, pos)? as usize;
+    let credentials = iggy_binary_protocol::codec::read_str(buf, pos + 4, length)?;
+    Ok((Some(credentials), pos + 4 + length))
+}
 
 impl WireEncode for CreateStreamWithId {
     fn encoded_size(&self) -> usize {
@@ -234,15 +316,58 @@ impl WireDecode for CreateConsumerGroupWithId {
     }
 }
 
-impl WireEncode for CreateUserWithId {
+impl WireEncode for CreateRoleWithId {
     fn encoded_size(&self) -> usize {
         4 + 4 + self.command.encoded_size()
     }
 
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.role_id);
+        buf.put_u32_le(self.command.encoded_size() as u32);
+        self.command.encode(buf);
+    }
+}
+
+impl WireDecode for CreateRoleWithId {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), iggy_binary_protocol::WireError> {
+        if buf.len() < 8 {
+            return Err(iggy_binary_protocol::WireError::UnexpectedEof {
+                offset: 0,
+                need: 8,
+                have: buf.len(),
+            });
+        }
+        let role_id = u32::from_le_bytes(buf[0..4].try_into().unwrap());
+        let command_length = u32::from_le_bytes(buf[4..8].try_into().unwrap()) as usize;
+        let total = 8usize.checked_add(command_length).ok_or(
+            iggy_binary_protocol::WireError::UnexpectedEof {
+                offset: 4,
+                need: command_length,
+                have: buf.len() - 8,
+            },
+        )?;
+        if buf.len() < total {
+            return Err(iggy_binary_protocol::WireError::UnexpectedEof {
+                offset: 8,
+                need: command_length,
+                have: buf.len() - 8,
+            });
+        }
+        let (command, _) = CreateRoleRequest::decode(&buf[8..total])?;
+        Ok((Self { role_id, command }, total))
+    }
+}
+
+impl WireEncode for CreateUserWithId {
+    fn encoded_size(&self) -> usize {
+        4 + 4 + self.command.encoded_size() + scram_credentials_size(&self.scram_credentials)
+    }
+
     fn encode(&self, buf: &mut BytesMut) {
         buf.put_u32_le(self.user_id);
         buf.put_u32_le(self.command.encoded_size() as u32);
         self.command.encode(buf);
+        encode_scram_credentials(&self.scram_credentials, buf);
     }
 }
 
@@ -272,7 +397,40 @@ impl WireDecode for CreateUserWithId {
             });
         }
         let (command, _) = CreateUserRequest::decode(&buf[8..total])?;
-        Ok((Self { user_id, command }, total))
+        let (scram_credentials, total) = decode_scram_credentials(buf, total)?;
+        Ok((
+            Self {
+                user_id,
+                command,
+                scram_credentials,
+            },
+            total,
+        ))
+    }
+}
+
+impl WireEncode for ChangePasswordWithCredentials {
+    fn encoded_size(&self) -> usize {
+        self.command.encoded_size() + scram_credentials_size(&self.scram_credentials)
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        self.command.encode(buf);
+        encode_scram_credentials(&self.scram_credentials, buf);
+    }
+}
+
+impl WireDecode for ChangePasswordWithCredentials {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), iggy_binary_protocol::WireError> {
+        let (command, pos) = ChangePasswordRequest::decode(buf)?;
+        let (scram_credentials, pos) = decode_scram_credentials(buf, pos)?;
+        Ok((
+            Self {
+                command,
+                scram_credentials,
+            },
+            pos,
+        ))
     }
 }
 
@@ -333,3 +491,68 @@ impl WireDecode for CreatePersonalAccessTokenWithHash {
         Ok((Self { hash, command }, pos))
     }
 }
+
+// Wire format: key_id:u32_le | wrapped_key_length:u32_le | wrapped_key | inner_length:u32_le | inner_bytes
+impl WireEncode for RotateTopicKeyWithKey {
+    fn encoded_size(&self) -> usize {
+        4 + 4 + self.wrapped_key.len() + 4 + self.command.encoded_size()
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.key_id);
+        buf.put_u32_le(self.wrapped_key.len() as u32);
+        buf.put_slice(&self.wrapped_key);
+        buf.put_u32_le(self.command.encoded_size() as u32);
+        self.command.encode(buf);
+    }
+}
+
+impl WireDecode for RotateTopicKeyWithKey {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), iggy_binary_protocol::WireError> {
+        if buf.len() < 8 {
+            return Err(iggy_binary_protocol::WireError::UnexpectedEof {
+                offset: 0,
+                need: 8,
+                have: buf.len(),
+            });
+        }
+        let key_id = u32::from_le_bytes(buf[0..4].try_into().unwrap());
+        let wrapped_key_length = u32::from_le_bytes(buf[4..8].try_into().unwrap()) as usize;
+        let mut pos = 8;
+        if buf.len() < pos + wrapped_key_length {
+            return Err(iggy_binary_protocol::WireError::UnexpectedEof {
+                offset: pos,
+                need: wrapped_key_length,
+                have: buf.len() - pos,
+            });
+        }
+        let wrapped_key = buf[pos..pos + wrapped_key_length].to_vec();
+        pos += wrapped_key_length;
+        if buf.len() < pos + 4 {
+            return Err(iggy_binary_protocol::WireError::UnexpectedEof {
+                offset: pos,
+                need: 4,
+                have: buf.len() - pos,
+            });
+        }
+        let command_length = u32::from_le_bytes(buf[pos..pos + 4].try_into().unwrap()) as usize;
+        pos += 4;
+        if buf.len() < pos + command_length {
+            return Err(iggy_binary_protocol::WireError::UnexpectedEof {
+                offset: pos,
+                need: command_length,
+                have: buf.len() - pos,
+            });
+        }
+        let (command, _) = RotateTopicKeyRequest::decode(&buf[pos..pos + command_length])?;
+        pos += command_length;
+        Ok((
+            Self {
+                key_id,
+                wrapped_key,
+                command,
+            },
+            pos,
+        ))
+    }
+}
@@ -16,6 +16,9 @@
  * under the License.
  */
 
+use crate::binary::dispatch::{
+    wire_dead_letter_policy_to_policy, wire_shared_subscription_to_subscription,
+};
 use crate::bootstrap::create_root_user;
 use crate::state::file::FileState;
 use crate::state::models::CreateUserWithId;
@@ -24,15 +27,19 @@ use ahash::AHashMap;
 use err_trail::ErrContext;
 use iggy_binary_protocol::requests::users::CreateUserRequest;
 use iggy_binary_protocol::{WireIdentifier, WireName};
+use iggy_common::CleanupPolicy;
 use iggy_common::CompressionAlgorithm;
+use iggy_common::DeadLetterPolicy;
 use iggy_common::IggyError;
 use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
 use iggy_common::MaxTopicSize;
 use iggy_common::PersonalAccessToken;
+use iggy_common::SharedSubscription;
 use iggy_common::defaults::DEFAULT_ROOT_USER_ID;
+use iggy_common::scram::ScramCredentials;
 use iggy_common::wire_conversions::{permissions_to_wire, wire_permissions_to_permissions};
-use iggy_common::{Permissions, UserStatus};
+use iggy_common::{Permissions, Quota, QuotaScope, UserStatus};
 use std::collections::BTreeMap;
 use std::fmt::Display;
 use tracing::{debug, error, info};
@@ -41,11 +48,18 @@ use tracing::{debug, error, info};
 pub struct SystemState {
     pub streams: BTreeMap<u32, StreamState>,
     pub users: AHashMap<u32, UserState>,
+    pub roles: BTreeMap<u32, RoleState>,
 }
 
 impl SystemState {
-    pub fn decompose(self) -> (BTreeMap<u32, StreamState>, AHashMap<u32, UserState>) {
-        (self.streams, self.users)
+    pub fn decompose(
+        self,
+    ) -> (
+        BTreeMap<u32, StreamState>,
+        AHashMap<u32, UserState>,
+        BTreeMap<u32, RoleState>,
+    ) {
+        (self.streams, self.users, self.roles)
     }
 }
 
@@ -67,6 +81,9 @@ pub struct TopicState {
     pub message_expiry: IggyExpiry,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: Option<u8>,
+    pub cleanup_policy: CleanupPolicy,
+    /// Data encryption keys by their IDs, wrapped with the master key.
+    pub encryption_keys: BTreeMap<u32, Vec<u8>>,
     pub created_at: IggyTimestamp,
 }
 
@@ -100,9 +117,13 @@ pub struct UserState {
     pub id: u32,
     pub username: String,
     pub password_hash: String,
+    pub scram_credentials: Option<ScramCredentials>,
     pub status: UserStatus,
     pub created_at: IggyTimestamp,
     pub permissions: Option<Permissions>,
+    pub user_quota: Option<Quota>,
+    pub client_quota: Option<Quota>,
+    pub roles: Vec<u32>,
     pub personal_access_tokens: AHashMap<String, PersonalAccessTokenState>,
 }
 
@@ -112,18 +133,32 @@ impl std::fmt::Debug for UserState {
             .field("id", &self.id)
             .field("username", &self.username)
             .field("password_hash", &"[REDACTED]")
+            .field("scram_credentials", &self.scram_credentials)
             .field("status", &self.status)
             .field("created_at", &self.created_at)
             .field("permissions", &self.permissions)
+            .field("user_quota", &self.user_quota)
+            .field("client_quota", &self.client_quota)
+            .field("roles", &self.roles)
             .field("personal_access_tokens", &self.personal_access_tokens)
             .finish()
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct RoleState {
+    pub id: u32,
+    pub name: String,
+    pub permissions: Option<Permissions>,
+    pub created_at: IggyTimestamp,
+}
+
 #[derive(Debug, Clone)]
 pub struct ConsumerGroupState {
     pub id: u32,
     pub name: String,
+    pub dead_letter: Option<DeadLetterPolicy>,
+    pub shared: Option<SharedSubscription>,
 }
 
 impl SystemState {
@@ -158,7 +193,8 @@ impl SystemState {
             state
                 .apply(0, &EntryCommand::CreateUser(CreateUserWithId {
                     user_id: root.id,
-                    command
+                    command,
+                    scram_credentials: root.scram_credentials.as_ref().map(ToString::to_string),
                 }))
                 .await
                 .error(|e: &IggyError| {
@@ -181,6 +217,7 @@ impl SystemState {
     pub async fn init(entries: Vec<StateEntry>) -> Result<Self, IggyError> {
         let mut streams = BTreeMap::new();
         let mut users = AHashMap::new();
+        let mut roles = BTreeMap::new();
         for entry in entries {
             debug!("Processing state entry: {entry}",);
             match entry.command().error(|e: &IggyError| {
@@ -237,6 +274,8 @@ impl SystemState {
                         } else {
                             Some(wire.replication_factor)
                         },
+                        cleanup_policy: CleanupPolicy::from_code(wire.cleanup_policy)?,
+                        encryption_keys: BTreeMap::new(),
                         created_at: entry.timestamp,
                         partitions: if wire.partitions_count > 0 {
                             let mut partitions = BTreeMap::new();
@@ -276,6 +315,7 @@ impl SystemState {
                     } else {
                         Some(command.replication_factor)
                     };
+                    topic.cleanup_policy = CleanupPolicy::from_code(command.cleanup_policy)?;
                 }
                 EntryCommand::DeleteTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -296,6 +336,20 @@ impl SystemState {
                         .get(&topic_id)
                         .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
                 }
+                EntryCommand::RotateTopicKey(command) => {
+                    let stream_id = find_stream_id(&streams, &command.command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    topic
+                        .encryption_keys
+                        .insert(command.key_id, command.wrapped_key);
+                }
                 EntryCommand::CreatePartitions(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
                     let stream = streams
@@ -389,6 +443,15 @@ impl SystemState {
                     let consumer_group = ConsumerGroupState {
                         id: consumer_group_id,
                         name: wire.name.to_string(),
+                        dead_letter: wire
+                            .dead_letter
+                            .as_ref()
+                            .map(wire_dead_letter_policy_to_policy)
+                            .transpose()?,
+                        shared: wire
+                            .shared
+                            .as_ref()
+                            .map(wire_shared_subscription_to_subscription),
                     };
                     topic
                         .consumer_groups
@@ -415,12 +478,20 @@ impl SystemState {
                         id: user_id,
                         username: wire.username.to_string(),
                         password_hash: wire.password, // already hashed at write time
+                        scram_credentials: command
+                            .scram_credentials
+                            .as_deref()
+                            .map(str::parse)
+                            .transpose()?,
                         status: UserStatus::from_code(wire.status)?,
                         created_at: entry.timestamp,
                         permissions: wire
                             .permissions
                             .as_ref()
                             .map(wire_permissions_to_permissions),
+                        user_quota: None,
+                        client_quota: None,
+                        roles: Vec::new(),
                         personal_access_tokens: AHashMap::new(),
                     };
                     users.insert(user.id, user);
@@ -442,11 +513,16 @@ impl SystemState {
                     users.remove(&user_id);
                 }
                 EntryCommand::ChangePassword(command) => {
-                    let user_id = find_user_id(&users, &command.user_id);
+                    let user_id = find_user_id(&users, &command.command.user_id);
                     let user = users
                         .get_mut(&user_id)
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
-                    user.password_hash = command.new_password; // already hashed at write time
+                    user.password_hash = command.command.new_password; // already hashed at write time
+                    user.scram_credentials = command
+                        .scram_credentials
+                        .as_deref()
+                        .map(str::parse)
+                        .transpose()?;
                 }
                 EntryCommand::UpdatePermissions(command) => {
                     let user_id = find_user_id(&users, &command.user_id);
@@ -458,6 +534,69 @@ impl SystemState {
                         .as_ref()
                         .map(wire_permissions_to_permissions);
                 }
+                EntryCommand::SetUserQuota(command) => {
+                    let user_id = find_user_id(&users, &command.user_id);
+                    let user = users
+                        .get_mut(&user_id)
+                        .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
+                    let quota = command
+                        .quota
+                        .map(Quota::from)
+                        .filter(|quota| !quota.is_unlimited());
+                    match QuotaScope::from(command.scope) {
+                        QuotaScope::User => user.user_quota = quota,
+                        QuotaScope::Client => user.client_quota = quota,
+                    }
+                }
+                EntryCommand::CreateRole(command) => {
+                    let role_id = command.role_id;
+                    let wire = command.command;
+                    let role = RoleState {
+                        id: role_id,
+                        name: wire.name.to_string(),
+                        permissions: wire
+                            .permissions
+                            .as_ref()
+                            .map(wire_permissions_to_permissions),
+                        created_at: entry.timestamp,
+                    };
+                    roles.insert(role.id, role);
+                }
+                EntryCommand::UpdateRole(command) => {
+                    let role_id = find_role_id(&roles, &command.role_id);
+                    let role = roles
+                        .get_mut(&role_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Role: {role_id} not found")));
+                    role.permissions = command
+                        .permissions
+                        .as_ref()
+                        .map(wire_permissions_to_permissions);
+                }
+                EntryCommand::DeleteRole(command) => {
+                    let role_id = find_role_id(&roles, &command.role_id);
+                    roles.remove(&role_id);
+                    for user in users.values_mut() {
+                        user.roles.retain(|assigned| *assigned != role_id);
+                    }
+                }
+                EntryCommand::AssignRole(command) => {
+                    let role_id = find_role_id(&roles, &command.role_id);
+                    let user_id = find_user_id(&users, &command.user_id);
+                    let user = users
+                        .get_mut(&user_id)
+                        .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
+                    if !user.roles.contains(&role_id) {
+                        user.roles.push(role_id);
+                    }
+                }
+                EntryCommand::UnassignRole(command) => {
+                    let role_id = find_role_id(&roles, &command.role_id);
+                    let user_id = find_user_id(&users, &command.user_id);
+                    let user = users
+                        .get_mut(&user_id)
+                        .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
+                    user.roles.retain(|assigned| *assigned != role_id);
+                }
                 EntryCommand::CreatePersonalAccessToken(command) => {
                     let token_hash = command.hash;
                     let user_id = find_user_id(&users, &WireIdentifier::numeric(entry.user_id));
@@ -495,7 +634,11 @@ impl SystemState {
             }
         }
 
-        let state = SystemState { streams, users };
+        let state = SystemState {
+            streams,
+            users,
+            roles,
+        };
         debug!("+++ State +++");
         debug!("{state}");
         debug!("+++ State +++");
@@ -562,6 +705,20 @@ fn find_user_id(users: &AHashMap<u32, UserState>, user_id: &WireIdentifier) -> u
     }
 }
 
+fn find_role_id(roles: &BTreeMap<u32, RoleState>, role_id: &WireIdentifier) -> u32 {
+    match role_id {
+        WireIdentifier::Numeric(id) => *id,
+        WireIdentifier::String(name) => {
+            let name = name.as_str();
+            let role = roles
+                .values()
+                .find(|r| r.name == name)
+                .unwrap_or_else(|| panic!("Role: {name} not found"));
+            role.id
+        }
+    }
+}
+
 impl Display for SystemState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Streams:")?;
@@ -574,10 +731,30 @@ impl Display for SystemState {
             write!(f, "\n================\n")?;
             write!(f, "{}", user.1)?;
         }
+        write!(f, "Roles:")?;
+        for role in self.roles.iter() {
+            write!(f, "\n================\n")?;
+            write!(f, "{}", role.1)?;
+        }
         Ok(())
     }
 }
 
+impl Display for RoleState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let permissions = if let Some(permissions) = &self.permissions {
+            permissions.to_string()
+        } else {
+            "no_permissions".to_string()
+        };
+        write!(
+            f,
+            "Role -> ID: {}, Name: {}, Permissions: {}",
+            self.id, self.name, permissions
+        )
+    }
+}
+
 impl Display for ConsumerGroupState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "ConsumerGroup -> ID: {}, Name: {}", self.id, self.name)
@@ -593,8 +770,8 @@ impl Display for UserState {
         };
         write!(
             f,
-            "User -> ID: {}, Username: {}, Status: {}, Permissions: {}",
-            self.id, self.username, self.status, permissions
+            "User -> ID: {}, Username: {}, Status: {}, Permissions: {}, Roles: {:?}",
+            self.id, self.username, self.status, permissions, self.roles
         )
     }
 }
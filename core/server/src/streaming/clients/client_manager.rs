@@ -18,13 +18,17 @@
 
 use crate::streaming::session::Session;
 use crate::streaming::utils::ptr::EternalPtr;
+use crate::streaming::utils::timestamp_id::TimestampIdGenerator;
 use dashmap::DashMap;
 use iggy_common::IggyTimestamp;
 use iggy_common::TransportProtocol;
 use iggy_common::UserId;
+use iggy_common::sharding::IggyNamespace;
 use iggy_common::{IggyError, calculate_32};
 use std::net::SocketAddr;
 
+static TRANSACTION_IDS: TimestampIdGenerator = TimestampIdGenerator::new();
+
 pub struct ClientManager {
     clients: EternalPtr<DashMap<u32, Client>>,
 }
@@ -50,6 +54,13 @@ pub struct Client {
     pub transport: TransportProtocol,
     pub consumer_groups: Vec<ConsumerGroup>,
     pub last_heartbeat: IggyTimestamp,
+    pub transaction: Option<Transaction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    pub id: u64,
+    pub partitions: Vec<IggyNamespace>,
 }
 
 #[derive(Debug, Clone)]
@@ -69,6 +80,7 @@ impl ClientManager {
             transport,
             consumer_groups: Vec::new(),
             last_heartbeat: IggyTimestamp::now(),
+            transaction: None,
         };
         self.clients.insert(client_id, client);
         session
@@ -139,6 +151,64 @@ impl ClientManager {
         Ok(())
     }
 
+    pub fn begin_transaction(&self, client_id: u32) -> Result<u64, IggyError> {
+        let mut client = self
+            .clients
+            .get_mut(&client_id)
+            .ok_or(IggyError::StaleClient)?;
+        if let Some(transaction) = &client.transaction {
+            return Err(IggyError::TransactionAlreadyInProgress(transaction.id));
+        }
+
+        let id = TRANSACTION_IDS.next();
+        client.transaction = Some(Transaction {
+            id,
+            partitions: Vec::new(),
+        });
+        Ok(id)
+    }
+
+    pub fn get_transaction_id(&self, client_id: u32) -> Option<u64> {
+        self.clients.get(&client_id).and_then(|client| {
+            client
+                .transaction
+                .as_ref()
+                .map(|transaction| transaction.id)
+        })
+    }
+
+    pub fn add_transaction_partition(
+        &self,
+        client_id: u32,
+        namespace: IggyNamespace,
+    ) -> Result<(), IggyError> {
+        let mut client = self
+            .clients
+            .get_mut(&client_id)
+            .ok_or(IggyError::StaleClient)?;
+        if let Some(transaction) = client.transaction.as_mut()
+            && !transaction.partitions.contains(&namespace)
+        {
+            transaction.partitions.push(namespace);
+        }
+        Ok(())
+    }
+
+    pub fn take_transaction(
+        &self,
+        client_id: u32,
+        transaction_id: u64,
+    ) -> Result<Transaction, IggyError> {
+        let mut client = self
+            .clients
+            .get_mut(&client_id)
+            .ok_or(IggyError::StaleClient)?;
+        client
+            .transaction
+            .take_if(|transaction| transaction.id == transaction_id)
+            .ok_or(IggyError::TransactionNotFound(transaction_id))
+    }
+
     pub fn join_consumer_group(
         &self,
         client_id: u32,
@@ -0,0 +1,471 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::DeliveryPolicy;
+use ahash::AHashMap;
+use iggy_common::{IggyError, IggyMessage, IggyTimestamp};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+const REDELIVERY_TIMEOUT_REASON: &str = "redelivery timeout expired";
+const NACK_REASON: &str = "negatively acknowledged";
+
+/// Message which exceeded the maximum delivery count of the consumer group and must be
+/// moved to its dead-letter topic.
+#[derive(Debug)]
+pub struct DeadLetter {
+    pub offset: u64,
+    pub delivery_count: u32,
+    pub reason: String,
+    pub message: IggyMessage,
+}
+
+/// Outcome of delivering the message to the consumer group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Delivery {
+    /// The message can be returned to the consumer.
+    Delivered,
+    /// The message is leased to the member of the shared subscription until its visibility timeout expires.
+    InFlight,
+    /// The message exceeded the maximum delivery count with the last failure reason.
+    DeadLettered { delivery_count: u32, reason: String },
+    /// The message has already been acknowledged or dead-lettered and must be skipped.
+    Skipped,
+}
+
+#[derive(Debug)]
+struct DeliveryState {
+    count: u32,
+    redeliver_at: u64,
+    reason: Option<String>,
+    acked: bool,
+    dead_lettered: bool,
+}
+
+impl DeliveryState {
+    fn is_settled(&self) -> bool {
+        self.acked || self.dead_lettered
+    }
+}
+
+#[derive(Debug)]
+struct GroupDeliveries {
+    policy: Arc<DeliveryPolicy>,
+    offsets: BTreeMap<u64, DeliveryState>,
+}
+
+/// Deliveries of the messages to the consumer groups with the delivery policy, tracked in memory
+/// by a single partition.
+///
+/// Only the messages above the stored consumer group offset are tracked, the committed ones are pruned.
+/// The policy instance identifies the consumer group, so the deliveries of a deleted group never leak
+/// into the group recreated with the same ID.
+#[derive(Debug, Default)]
+pub struct PartitionDeliveries {
+    groups: AHashMap<usize, GroupDeliveries>,
+}
+
+impl PartitionDeliveries {
+    /// Registers the delivery of the message to the consumer group.
+    ///
+    /// The message delivered again within the redelivery timeout (e.g. polled twice before committing)
+    /// doesn't count as another delivery, only the expired or negatively acknowledged ones do.
+    /// The shared subscription doesn't deliver such a message at all, as it's leased to one of its members.
+    pub fn deliver(
+        &mut self,
+        group_id: usize,
+        policy: &Arc<DeliveryPolicy>,
+        offset: u64,
+        now: IggyTimestamp,
+    ) -> Delivery {
+        let group = self.group_mut(group_id, policy);
+        let now = now.as_micros();
+        let redeliver_at = now + policy.redelivery_timeout();
+        let Some(state) = group.offsets.get_mut(&offset) else {
+            group.offsets.insert(
+                offset,
+                DeliveryState {
+                    count: 1,
+                    redeliver_at,
+                    reason: None,
+                    acked: false,
+                    dead_lettered: false,
+                },
+            );
+            return Delivery::Delivered;
+        };
+
+        if state.is_settled() {
+            return Delivery::Skipped;
+        }
+
+        if state.redeliver_at > now {
+            if policy.is_shared() {
+                return Delivery::InFlight;
+            }
+            return Delivery::Delivered;
+        }
+
+        if let Some(dead_letter) = &policy.dead_letter
+            && state.count >= dead_letter.max_delivery_count
+        {
+            state.dead_lettered = true;
+            return Delivery::DeadLettered {
+                delivery_count: state.count,
+                reason: state
+                    .reason
+                    .take()
+                    .unwrap_or_else(|| REDELIVERY_TIMEOUT_REASON.to_owned()),
+            };
+        }
+
+        state.count += 1;
+        state.redeliver_at = redeliver_at;
+        Delivery::Delivered
+    }
+
+    /// Marks the delivered message as failed, so it can be redelivered immediately and its next delivery
+    /// counts towards the maximum delivery count.
+    pub fn nack(
+        &mut self,
+        group_id: usize,
+        policy: &Arc<DeliveryPolicy>,
+        offset: u64,
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        let group = self.group_mut(group_id, policy);
+        let Some(state) = group
+            .offsets
+            .get_mut(&offset)
+            .filter(|state| !state.is_settled())
+        else {
+            return Err(IggyError::MessageNotDelivered(offset));
+        };
+
+        state.redeliver_at = 0;
+        state.reason = Some(if reason.is_empty() {
+            NACK_REASON.to_owned()
+        } else {
+            reason.to_owned()
+        });
+        Ok(())
+    }
+
+    /// Marks the delivered messages as acknowledged, so they are never delivered again.
+    ///
+    /// The offsets up to the stored consumer group offset are already committed and ignored,
+    /// while acknowledging any message which hasn't been delivered yet fails the whole request.
+    pub fn ack(
+        &mut self,
+        group_id: usize,
+        policy: &Arc<DeliveryPolicy>,
+        offsets: &[u64],
+        stored_offset: Option<u64>,
+    ) -> Result<(), IggyError> {
+        let group = self.group_mut(group_id, policy);
+        let is_committed = |offset: u64| stored_offset.is_some_and(|stored| offset <= stored);
+        if let Some(&offset) = offsets
+            .iter()
+            .find(|&&offset| !is_committed(offset) && !group.offsets.contains_key(&offset))
+        {
+            return Err(IggyError::MessageNotDelivered(offset));
+        }
+
+        for offset in offsets {
+            if let Some(state) = group.offsets.get_mut(offset) {
+                state.acked = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Restores the acknowledged messages of the consumer group, e.g. after the server restart.
+    pub fn restore_acks(&mut self, group_id: usize, policy: &Arc<DeliveryPolicy>, offsets: &[u64]) {
+        let group = self.group_mut(group_id, policy);
+        for &offset in offsets {
+            group.offsets.insert(
+                offset,
+                DeliveryState {
+                    count: 0,
+                    redeliver_at: 0,
+                    reason: None,
+                    acked: true,
+                    dead_lettered: false,
+                },
+            );
+        }
+    }
+
+    /// Returns the offsets of the acknowledged and dead-lettered messages which are not committed yet.
+    pub fn settled_offsets(&self, group_id: usize) -> Vec<u64> {
+        self.groups
+            .get(&group_id)
+            .map(|group| {
+                group
+                    .offsets
+                    .iter()
+                    .filter(|(_, state)| state.is_settled())
+                    .map(|(offset, _)| *offset)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the offset up to which the consumer group can commit, as all the messages following
+    /// the stored consumer group offset are either acknowledged or dead-lettered.
+    pub fn settled_offset(&self, group_id: usize, stored_offset: Option<u64>) -> Option<u64> {
+        let group = self.groups.get(&group_id)?;
+        let mut next_offset = stored_offset.map(|offset| offset + 1);
+        let mut settled_offset = None;
+        for (&offset, state) in group.offsets.range(next_offset.unwrap_or_default()..) {
+            if next_offset.is_some_and(|next_offset| next_offset != offset) || !state.is_settled() {
+                break;
+            }
+            settled_offset = Some(offset);
+            next_offset = Some(offset + 1);
+        }
+        settled_offset
+    }
+
+    /// Returns the number of the messages above the stored consumer group offset which were delivered.
+    pub fn tracked_count(&self, group_id: usize) -> usize {
+        self.groups
+            .get(&group_id)
+            .map_or(0, |group| group.offsets.len())
+    }
+
+    /// Removes the deliveries of the messages up to the stored consumer group offset.
+    pub fn prune(&mut self, group_id: usize, stored_offset: u64) {
+        if let Some(group) = self.groups.get_mut(&group_id) {
+            group.offsets = group.offsets.split_off(&(stored_offset + 1));
+        }
+    }
+
+    fn group_mut(&mut self, group_id: usize, policy: &Arc<DeliveryPolicy>) -> &mut GroupDeliveries {
+        let group = self
+            .groups
+            .entry(group_id)
+            .or_insert_with(|| GroupDeliveries {
+                policy: policy.clone(),
+                offsets: BTreeMap::new(),
+            });
+        if !Arc::ptr_eq(&group.policy, policy) {
+            group.policy = policy.clone();
+            group.offsets.clear();
+        }
+        group
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::{DeadLetterPolicy, Identifier, IggyDuration, SharedSubscription};
+
+    const TIMEOUT: u64 = 1_000_000;
+
+    fn policy(max_delivery_count: u32) -> Arc<DeliveryPolicy> {
+        Arc::new(
+            DeliveryPolicy::new(
+                Some(DeadLetterPolicy::new(
+                    max_delivery_count,
+                    IggyDuration::from(TIMEOUT),
+                    Identifier::numeric(1).unwrap(),
+                    Identifier::numeric(2).unwrap(),
+                )),
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn shared_policy() -> Arc<DeliveryPolicy> {
+        Arc::new(
+            DeliveryPolicy::new(
+                None,
+                Some(SharedSubscription::new(IggyDuration::from(TIMEOUT))),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn should_not_count_redelivery_within_timeout() {
+        let policy = policy(1);
+        let mut deliveries = PartitionDeliveries::default();
+        for now in [0, TIMEOUT / 2, TIMEOUT - 1] {
+            assert_eq!(
+                deliveries.deliver(1, &policy, 5, IggyTimestamp::from(now)),
+                Delivery::Delivered
+            );
+        }
+    }
+
+    #[test]
+    fn should_dead_letter_after_redelivery_timeouts_exceed_max_delivery_count() {
+        let policy = policy(2);
+        let mut deliveries = PartitionDeliveries::default();
+        assert_eq!(
+            deliveries.deliver(1, &policy, 5, IggyTimestamp::from(0)),
+            Delivery::Delivered
+        );
+        assert_eq!(
+            deliveries.deliver(1, &policy, 5, IggyTimestamp::from(TIMEOUT)),
+            Delivery::Delivered
+        );
+        assert_eq!(
+            deliveries.deliver(1, &policy, 5, IggyTimestamp::from(2 * TIMEOUT)),
+            Delivery::DeadLettered {
+                delivery_count: 2,
+                reason: REDELIVERY_TIMEOUT_REASON.to_owned(),
+            }
+        );
+        assert_eq!(
+            deliveries.deliver(1, &policy, 5, IggyTimestamp::from(3 * TIMEOUT)),
+            Delivery::Skipped
+        );
+    }
+
+    #[test]
+    fn should_redeliver_nacked_message_immediately_and_keep_the_reason() {
+        let policy = policy(2);
+        let mut deliveries = PartitionDeliveries::default();
+        assert_eq!(
+            deliveries.nack(1, &policy, 5, "boom"),
+            Err(IggyError::MessageNotDelivered(5))
+        );
+
+        deliveries.deliver(1, &policy, 5, IggyTimestamp::from(0));
+        deliveries.nack(1, &policy, 5, "boom").unwrap();
+        assert_eq!(
+            deliveries.deliver(1, &policy, 5, IggyTimestamp::from(1)),
+            Delivery::Delivered
+        );
+        deliveries.nack(1, &policy, 5, "").unwrap();
+        assert_eq!(
+            deliveries.deliver(1, &policy, 5, IggyTimestamp::from(2)),
+            Delivery::DeadLettered {
+                delivery_count: 2,
+                reason: NACK_REASON.to_owned(),
+            }
+        );
+        assert_eq!(
+            deliveries.nack(1, &policy, 5, "boom"),
+            Err(IggyError::MessageNotDelivered(5))
+        );
+    }
+
+    #[test]
+    fn should_prune_committed_deliveries() {
+        let policy = policy(1);
+        let mut deliveries = PartitionDeliveries::default();
+        for offset in 0..10 {
+            deliveries.deliver(1, &policy, offset, IggyTimestamp::from(0));
+        }
+        deliveries.prune(1, 4);
+        assert_eq!(
+            deliveries.nack(1, &policy, 4, ""),
+            Err(IggyError::MessageNotDelivered(4))
+        );
+        assert!(deliveries.nack(1, &policy, 5, "").is_ok());
+    }
+
+    #[test]
+    fn should_reset_deliveries_of_recreated_group() {
+        let mut deliveries = PartitionDeliveries::default();
+        let deleted_group_policy = policy(1);
+        deliveries.deliver(1, &deleted_group_policy, 5, IggyTimestamp::from(0));
+
+        let recreated_group_policy = policy(1);
+        assert_eq!(
+            deliveries.deliver(1, &recreated_group_policy, 5, IggyTimestamp::from(TIMEOUT)),
+            Delivery::Delivered
+        );
+    }
+
+    #[test]
+    fn should_not_deliver_in_flight_message_of_shared_subscription_until_visibility_timeout_expires()
+     {
+        let policy = shared_policy();
+        let mut deliveries = PartitionDeliveries::default();
+        assert_eq!(
+            deliveries.deliver(1, &policy, 5, IggyTimestamp::from(0)),
+            Delivery::Delivered
+        );
+        assert_eq!(
+            deliveries.deliver(1, &policy, 5, IggyTimestamp::from(TIMEOUT - 1)),
+            Delivery::InFlight
+        );
+        for now in [TIMEOUT, 2 * TIMEOUT, 3 * TIMEOUT] {
+            assert_eq!(
+                deliveries.deliver(1, &policy, 5, IggyTimestamp::from(now)),
+                Delivery::Delivered
+            );
+        }
+
+        deliveries.nack(1, &policy, 5, "").unwrap();
+        assert_eq!(
+            deliveries.deliver(1, &policy, 5, IggyTimestamp::from(3 * TIMEOUT + 1)),
+            Delivery::Delivered
+        );
+    }
+
+    #[test]
+    fn should_skip_acked_messages_and_commit_settled_offsets() {
+        let policy = shared_policy();
+        let mut deliveries = PartitionDeliveries::default();
+        for offset in 5..10 {
+            deliveries.deliver(1, &policy, offset, IggyTimestamp::from(0));
+        }
+        assert_eq!(
+            deliveries.ack(1, &policy, &[6, 10], Some(4)),
+            Err(IggyError::MessageNotDelivered(10))
+        );
+
+        deliveries.ack(1, &policy, &[3, 6, 7], Some(4)).unwrap();
+        assert_eq!(deliveries.settled_offset(1, Some(4)), None);
+        assert_eq!(deliveries.settled_offsets(1), vec![6, 7]);
+        assert_eq!(
+            deliveries.deliver(1, &policy, 6, IggyTimestamp::from(TIMEOUT)),
+            Delivery::Skipped
+        );
+        assert_eq!(
+            deliveries.nack(1, &policy, 6, ""),
+            Err(IggyError::MessageNotDelivered(6))
+        );
+
+        deliveries.ack(1, &policy, &[5], Some(4)).unwrap();
+        assert_eq!(deliveries.settled_offset(1, Some(4)), Some(7));
+        deliveries.prune(1, 7);
+        assert_eq!(deliveries.tracked_count(1), 2);
+        assert!(deliveries.settled_offsets(1).is_empty());
+    }
+
+    #[test]
+    fn should_restore_acked_messages() {
+        let policy = shared_policy();
+        let mut deliveries = PartitionDeliveries::default();
+        deliveries.restore_acks(1, &policy, &[2, 3]);
+        assert_eq!(
+            deliveries.deliver(1, &policy, 2, IggyTimestamp::from(0)),
+            Delivery::Skipped
+        );
+        assert_eq!(deliveries.settled_offset(1, Some(0)), None);
+        assert_eq!(deliveries.settled_offset(1, Some(1)), Some(3));
+    }
+}
@@ -0,0 +1,59 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::{DeadLetterPolicy, SharedSubscription};
+
+/// Delivery policy of the consumer group, tracking the deliveries of its messages
+/// either to move the failing ones to the dead-letter topic, to redeliver the
+/// unacknowledged ones to any member of the shared subscription, or both.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryPolicy {
+    pub dead_letter: Option<DeadLetterPolicy>,
+    pub shared: Option<SharedSubscription>,
+}
+
+impl DeliveryPolicy {
+    /// Returns the delivery policy, unless the consumer group doesn't need to track the deliveries.
+    pub fn new(
+        dead_letter: Option<DeadLetterPolicy>,
+        shared: Option<SharedSubscription>,
+    ) -> Option<Self> {
+        if dead_letter.is_none() && shared.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            dead_letter,
+            shared,
+        })
+    }
+
+    pub fn is_shared(&self) -> bool {
+        self.shared.is_some()
+    }
+
+    /// Returns the time in microseconds after which the delivered message can be delivered again,
+    /// the visibility timeout of the shared subscription takes precedence over the redelivery timeout.
+    pub fn redelivery_timeout(&self) -> u64 {
+        match (&self.shared, &self.dead_letter) {
+            (Some(shared), _) => shared.visibility_timeout.as_micros(),
+            (None, Some(dead_letter)) => dead_letter.redelivery_timeout.as_micros(),
+            (None, None) => 0,
+        }
+    }
+}
@@ -16,13 +16,25 @@
  * under the License.
  */
 
+use iggy_common::ConsumerGroupPartitionLag;
+use prometheus_client::encoding::EncodeLabelSet;
 use prometheus_client::encoding::text::encode;
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
 use prometheus_client::registry::Registry;
 use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
 use tracing::error;
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ConsumerGroupLagLabels {
+    pub stream: String,
+    pub topic: String,
+    pub consumer_group: String,
+    pub partition: u32,
+}
+
 #[derive(Debug, Clone)]
 pub struct Metrics {
     registry: Arc<Registry>,
@@ -34,6 +46,10 @@ pub struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    consumer_group_committed_offset: Family<ConsumerGroupLagLabels, Gauge>,
+    consumer_group_log_end_offset: Family<ConsumerGroupLagLabels, Gauge>,
+    consumer_group_lag: Family<ConsumerGroupLagLabels, Gauge>,
+    consumer_group_lag_seconds: Family<ConsumerGroupLagLabels, Gauge<f64, AtomicU64>>,
 }
 
 impl Metrics {
@@ -48,6 +64,11 @@ impl Metrics {
         let messages = Gauge::default();
         let users = Gauge::default();
         let clients = Gauge::default();
+        let consumer_group_committed_offset = Family::<ConsumerGroupLagLabels, Gauge>::default();
+        let consumer_group_log_end_offset = Family::<ConsumerGroupLagLabels, Gauge>::default();
+        let consumer_group_lag = Family::<ConsumerGroupLagLabels, Gauge>::default();
+        let consumer_group_lag_seconds =
+            Family::<ConsumerGroupLagLabels, Gauge<f64, AtomicU64>>::default();
 
         registry.register(
             "http_requests",
@@ -65,6 +86,26 @@ impl Metrics {
         registry.register("messages", "total count of messages", messages.clone());
         registry.register("users", "total count of users", users.clone());
         registry.register("clients", "total count of clients", clients.clone());
+        registry.register(
+            "consumer_group_committed_offset",
+            "offset committed by the consumer group in the partition",
+            consumer_group_committed_offset.clone(),
+        );
+        registry.register(
+            "consumer_group_log_end_offset",
+            "offset of the last message appended to the partition consumed by the consumer group",
+            consumer_group_log_end_offset.clone(),
+        );
+        registry.register(
+            "consumer_group_lag",
+            "count of messages not yet consumed by the consumer group in the partition",
+            consumer_group_lag.clone(),
+        );
+        registry.register(
+            "consumer_group_lag_seconds",
+            "age of the oldest message not yet consumed by the consumer group in the partition",
+            consumer_group_lag_seconds.clone(),
+        );
 
         let registry = registry.into();
         Self {
@@ -77,6 +118,10 @@ impl Metrics {
             messages,
             users,
             clients,
+            consumer_group_committed_offset,
+            consumer_group_log_end_offset,
+            consumer_group_lag,
+            consumer_group_lag_seconds,
         }
     }
 
@@ -147,4 +192,32 @@ impl Metrics {
     pub fn decrement_clients(&self, count: u32) {
         self.clients.dec_by(count as i64);
     }
+
+    pub fn clear_consumer_group_lag(&self) {
+        self.consumer_group_committed_offset.clear();
+        self.consumer_group_log_end_offset.clear();
+        self.consumer_group_lag.clear();
+        self.consumer_group_lag_seconds.clear();
+    }
+
+    pub fn set_consumer_group_lag(
+        &self,
+        labels: &ConsumerGroupLagLabels,
+        lag: &ConsumerGroupPartitionLag,
+    ) {
+        if let Some(offset) = lag.committed_offset {
+            self.consumer_group_committed_offset
+                .get_or_create(labels)
+                .set(offset as i64);
+        }
+        self.consumer_group_log_end_offset
+            .get_or_create(labels)
+            .set(lag.log_end_offset as i64);
+        self.consumer_group_lag
+            .get_or_create(labels)
+            .set(lag.lag as i64);
+        self.consumer_group_lag_seconds
+            .get_or_create(labels)
+            .set(lag.lag_time.as_secs_f64());
+    }
 }
@@ -18,15 +18,19 @@
 
 pub mod clients;
 pub mod deduplication;
+pub mod deliveries;
 pub mod diagnostics;
 pub mod partitions;
 pub mod persistence;
 pub mod polling_consumer;
+pub mod producers;
+pub mod scheduled;
 pub mod segments;
 pub mod session;
 pub mod stats;
 pub mod storage;
 pub mod streams;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
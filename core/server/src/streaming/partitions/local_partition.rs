@@ -24,7 +24,11 @@ use super::{
     consumer_group_offsets::ConsumerGroupOffsets, consumer_offsets::ConsumerOffsets,
     journal::MemoryMessageJournal, log::SegmentedLog,
 };
-use crate::streaming::{deduplication::MessageDeduplicator, stats::PartitionStats};
+use crate::streaming::{
+    deduplication::MessageDeduplicator, deliveries::PartitionDeliveries,
+    producers::PartitionProducers, scheduled::PartitionSchedule, stats::PartitionStats,
+    transactions::PartitionTransactions,
+};
 use iggy_common::IggyTimestamp;
 use std::sync::{Arc, atomic::AtomicU64};
 
@@ -40,6 +44,10 @@ pub struct LocalPartition {
     pub created_at: IggyTimestamp,
     pub revision_id: u64,
     pub should_increment_offset: bool,
+    pub transactions: PartitionTransactions,
+    pub producers: PartitionProducers,
+    pub deliveries: PartitionDeliveries,
+    pub schedule: PartitionSchedule,
 }
 
 impl LocalPartition {
@@ -67,6 +75,10 @@ impl LocalPartition {
             created_at,
             revision_id,
             should_increment_offset,
+            transactions: PartitionTransactions::default(),
+            producers: PartitionProducers::default(),
+            deliveries: PartitionDeliveries::default(),
+            schedule: PartitionSchedule::default(),
         }
     }
 
@@ -93,6 +105,10 @@ impl LocalPartition {
             created_at,
             revision_id,
             should_increment_offset,
+            transactions: PartitionTransactions::default(),
+            producers: PartitionProducers::default(),
+            deliveries: PartitionDeliveries::default(),
+            schedule: PartitionSchedule::default(),
         }
     }
 }
@@ -32,19 +32,41 @@
 //! The poll + auto_commit sequence in the handler (`handlers.rs`) is likewise
 //! non-atomic but safe for the same reason.
 //!
-//! If the architecture ever moves to multi-threaded shard processing or adds
-//! compaction/message deletion, these invariants must be re-evaluated.
+//! Log compaction rewrites sealed segments inside the same message pump, so it
+//! never interleaves with a read. Compacted segments keep their offset range but
+//! may have gaps in it, so disk reads advance by the last loaded offset rather
+//! than by the number of loaded messages.
+//!
+//! Offloaded segments keep only their indexes on the local disk, their messages are
+//! fetched from the archive, so these functions take the optional archiver.
+//!
+//! Delayed messages stay in the log at their offsets, so polling either stops right
+//! before the first one which is not due yet, or skips them for the shared subscriptions.
+//!
+//! The message filter is evaluated against the user headers while reading, the segments
+//! are scanned in larger chunks until the requested count of matching messages is found.
+//! The user headers encrypted by the server are decrypted just for the evaluation.
+//!
+//! If the architecture ever moves to multi-threaded shard processing, these
+//! invariants must be re-evaluated.
 
 use super::journal::Journal;
 use super::local_partitions::LocalPartitions;
+use crate::archiver::ArchiverKind;
 use crate::shard::system::messages::PollingArgs;
 use crate::streaming::polling_consumer::PollingConsumer;
-use crate::streaming::segments::IggyMessagesBatchSet;
+use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut, IggyMessagesBatchSet};
 use iggy_common::IggyPollMetadata;
 use iggy_common::sharding::IggyNamespace;
-use iggy_common::{IggyError, PollingKind};
+use iggy_common::{
+    EncryptorKind, IggyError, IggyMessageView, IggyTimestamp, MessageFilter, PollingKind,
+};
 use std::cell::RefCell;
 use std::sync::atomic::Ordering;
+use tracing::error;
+
+/// The minimum number of messages read at once while looking for the ones matching the filter.
+const FILTER_READ_CHUNK: u32 = 1000;
 
 /// Poll messages from a partition partitions.
 ///
@@ -52,25 +74,35 @@ use std::sync::atomic::Ordering;
 pub async fn poll_messages(
     local_partitions: &RefCell<LocalPartitions>,
     namespace: &IggyNamespace,
+    archiver: Option<&ArchiverKind>,
     consumer: PollingConsumer,
     args: PollingArgs,
+    encryptor: Option<&EncryptorKind>,
 ) -> Result<(IggyPollMetadata, IggyMessagesBatchSet), IggyError> {
     let partition_id = namespace.partition_id();
     let count = args.count;
     let strategy = args.strategy;
     let value = strategy.value;
+    let filter = args.filter.as_ref();
 
     // Handle timestamp polling separately - it has different logic
     if strategy.kind == PollingKind::Timestamp {
-        return poll_messages_by_timestamp(local_partitions, namespace, value, count).await;
+        let (metadata, batches) =
+            poll_messages_by_timestamp(local_partitions, namespace, archiver, value, count).await?;
+        return Ok((
+            metadata,
+            remove_hidden_messages(local_partitions, namespace, batches, filter, encryptor),
+        ));
     }
 
-    // Phase 1: Extract metadata and determine start offset
-    let (metadata, start_offset) = {
-        let store = local_partitions.borrow();
+    // Phase 1: Extract metadata, determine start offset, transactional visibility
+    // and the delayed messages which are not due yet
+    let (metadata, start_offset, visibility, scheduled_offset, skip_scheduled) = {
+        let mut store = local_partitions.borrow_mut();
         let partition = store
-            .get(namespace)
+            .get_mut(namespace)
             .expect("local_partitions: partition must exist for poll");
+        partition.schedule.release(IggyTimestamp::now());
 
         let current_offset = partition.offset.load(Ordering::Relaxed);
         let metadata = IggyPollMetadata::new(partition_id as u32, current_offset);
@@ -126,18 +158,164 @@ pub async fn poll_messages(
             return Ok((metadata, IggyMessagesBatchSet::empty()));
         }
 
-        (metadata, start_offset)
+        let visibility = partition
+            .transactions
+            .visibility(strategy.isolation_level, start_offset);
+        let first_scheduled = partition.schedule.first_pending(start_offset);
+        if args.skip_scheduled {
+            (
+                metadata,
+                start_offset,
+                visibility,
+                None,
+                first_scheduled.is_some(),
+            )
+        } else {
+            (metadata, start_offset, visibility, first_scheduled, false)
+        }
     };
 
     // Phase 2: Get messages using hybrid disk+journal logic
-    let batches = get_messages_by_offset(local_partitions, namespace, start_offset, count).await?;
-    Ok((metadata, batches))
+    if visibility.is_unrestricted()
+        && scheduled_offset.is_none()
+        && !skip_scheduled
+        && filter.is_none()
+    {
+        let batches =
+            get_messages_by_offset(local_partitions, namespace, archiver, start_offset, count)
+                .await?;
+        return Ok((metadata, batches));
+    }
+
+    // Transaction markers, aborted, skipped delayed and filtered out messages are left out,
+    // so keep reading until either the requested count is reached or the stable offset is hit.
+    let end_offset = visibility
+        .stable_offset
+        .unwrap_or(metadata.current_offset + 1)
+        .min(metadata.current_offset + 1)
+        .min(scheduled_offset.unwrap_or(u64::MAX));
+    let mut combined = IggyMessagesBatchSet::empty();
+    let mut current = start_offset;
+    while combined.count() < count && current < end_offset {
+        let remaining = count - combined.count();
+        let requested = if filter.is_some() {
+            remaining.max(FILTER_READ_CHUNK)
+        } else {
+            remaining
+        };
+        let requested = (requested as u64).min(end_offset - current) as u32;
+        let batches =
+            get_messages_by_offset(local_partitions, namespace, archiver, current, requested)
+                .await?;
+        let Some(last_offset) = batches.last_offset() else {
+            break;
+        };
+        current = last_offset + 1;
+        let store = local_partitions.borrow();
+        let schedule = &store
+            .get(namespace)
+            .expect("local_partitions: partition must exist for poll")
+            .schedule;
+        let mut accepted = 0;
+        for mut batch in batches.into_inner() {
+            let hidden: Vec<u32> = batch
+                .iter()
+                .enumerate()
+                .filter(|(_, message)| {
+                    let offset = message.header().offset();
+                    let hidden = offset >= end_offset
+                        || visibility.is_hidden(offset)
+                        || (skip_scheduled && schedule.is_pending(offset))
+                        || filter.is_some_and(|filter| !matches(filter, message, encryptor));
+                    if hidden || accepted >= remaining {
+                        return true;
+                    }
+                    accepted += 1;
+                    false
+                })
+                .map(|(index, _)| index as u32)
+                .collect();
+            if hidden.len() == batch.count() as usize {
+                continue;
+            }
+            if !hidden.is_empty() {
+                let base_position = batch.indexes().base_position();
+                batch.remove_messages(&hidden, base_position);
+            }
+            if !batch.is_empty() {
+                combined.add_batch(batch);
+            }
+        }
+    }
+    Ok((metadata, combined))
+}
+
+/// Removes the delayed messages which are not due yet and the messages not matching
+/// the filter from the polled batches.
+fn remove_hidden_messages(
+    local_partitions: &RefCell<LocalPartitions>,
+    namespace: &IggyNamespace,
+    batches: IggyMessagesBatchSet,
+    filter: Option<&MessageFilter>,
+    encryptor: Option<&EncryptorKind>,
+) -> IggyMessagesBatchSet {
+    let mut store = local_partitions.borrow_mut();
+    let schedule = &mut store
+        .get_mut(namespace)
+        .expect("local_partitions: partition must exist for poll")
+        .schedule;
+    schedule.release(IggyTimestamp::now());
+    if schedule.is_empty() && filter.is_none() {
+        return batches;
+    }
+
+    let mut result = IggyMessagesBatchSet::empty();
+    for mut batch in batches.into_inner() {
+        let hidden: Vec<u32> = batch
+            .iter()
+            .enumerate()
+            .filter(|(_, message)| {
+                schedule.is_pending(message.header().offset())
+                    || filter.is_some_and(|filter| !matches(filter, message, encryptor))
+            })
+            .map(|(index, _)| index as u32)
+            .collect();
+        if hidden.len() == batch.count() as usize {
+            continue;
+        }
+        if !hidden.is_empty() {
+            let base_position = batch.indexes().base_position();
+            batch.remove_messages(&hidden, base_position);
+        }
+        if !batch.is_empty() {
+            result.add_batch(batch);
+        }
+    }
+    result
+}
+
+/// Checks whether the message user headers match the filter, decrypting them first
+/// if they're encrypted by the server. Headers which can't be read are treated as missing.
+fn matches(
+    filter: &MessageFilter,
+    message: &IggyMessageView,
+    encryptor: Option<&EncryptorKind>,
+) -> bool {
+    let Some(user_headers) = message.user_headers() else {
+        return filter.matches(None);
+    };
+    match encryptor.map(|encryptor| encryptor.decrypt(user_headers)) {
+        Some(Ok(decrypted)) => filter.matches_raw(Some(&decrypted)),
+        Some(Err(_)) => filter.matches(None),
+        None => filter.matches_raw(Some(user_headers)),
+    }
 }
 
 /// Get messages by offset, handling the hybrid disk+journal case.
 pub async fn get_messages_by_offset(
     local_partitions: &RefCell<LocalPartitions>,
     namespace: &IggyNamespace,
+    archiver: Option<&ArchiverKind>,
     start_offset: u64,
     count: u32,
 ) -> Result<IggyMessagesBatchSet, IggyError> {
@@ -184,10 +362,11 @@ pub async fn get_messages_by_offset(
         let disk_count =
             ((in_memory_floor.min(current + remaining as u64) - current) as u32).min(remaining);
         let disk_messages =
-            load_messages_from_disk(local_partitions, namespace, current, disk_count).await?;
+            load_messages_from_disk(local_partitions, namespace, archiver, current, disk_count)
+                .await?;
         let loaded = disk_messages.count();
-        if loaded > 0 {
-            current += loaded as u64;
+        if let Some(last_offset) = disk_messages.last_offset() {
+            current = last_offset + 1;
             remaining = remaining.saturating_sub(loaded);
             combined.add_batch_set(disk_messages);
         }
@@ -248,6 +427,7 @@ pub async fn get_messages_by_offset(
 async fn poll_messages_by_timestamp(
     local_partitions: &RefCell<LocalPartitions>,
     namespace: &IggyNamespace,
+    archiver: Option<&ArchiverKind>,
     timestamp: u64,
     count: u32,
 ) -> Result<(IggyPollMetadata, IggyMessagesBatchSet), IggyError> {
@@ -317,9 +497,14 @@ async fn poll_messages_by_timestamp(
     };
 
     if timestamp < disk_upper_ts && remaining > 0 {
-        let disk_messages =
-            load_messages_from_disk_by_timestamp(local_partitions, namespace, timestamp, remaining)
-                .await?;
+        let disk_messages = load_messages_from_disk_by_timestamp(
+            local_partitions,
+            namespace,
+            archiver,
+            timestamp,
+            remaining,
+        )
+        .await?;
         let loaded = disk_messages.count();
         if loaded > 0 {
             remaining = remaining.saturating_sub(loaded);
@@ -375,6 +560,7 @@ async fn poll_messages_by_timestamp(
 pub async fn load_messages_from_disk(
     local_partitions: &RefCell<LocalPartitions>,
     namespace: &IggyNamespace,
+    archiver: Option<&ArchiverKind>,
     start_offset: u64,
     count: u32,
 ) -> Result<IggyMessagesBatchSet, IggyError> {
@@ -401,6 +587,7 @@ pub async fn load_messages_from_disk(
         let end = segments.len();
         start..end
     };
+    let segments_count = segment_range.end;
 
     let mut remaining_count = count;
     let mut batches = IggyMessagesBatchSet::empty();
@@ -435,6 +622,7 @@ pub async fn load_messages_from_disk(
         let messages = load_segment_messages(
             local_partitions,
             namespace,
+            archiver,
             idx,
             offset,
             end_offset,
@@ -444,10 +632,13 @@ pub async fn load_messages_from_disk(
         .await?;
 
         let loaded_count = messages.count();
-        if loaded_count > 0 {
+        if let Some(last_offset) = messages.last_offset() {
             batches.add_batch_set(messages);
             remaining_count = remaining_count.saturating_sub(loaded_count);
-            current_offset = end_offset + 1;
+            current_offset = last_offset + 1;
+        } else if idx + 1 < segments_count {
+            // The rest of a compacted segment may be gone, continue with the next one.
+            current_offset = segment_end_offset + 1;
         } else {
             break;
         }
@@ -457,9 +648,11 @@ pub async fn load_messages_from_disk(
 }
 
 /// Load messages from a specific segment.
+#[allow(clippy::too_many_arguments)]
 async fn load_segment_messages(
     local_partitions: &RefCell<LocalPartitions>,
     namespace: &IggyNamespace,
+    archiver: Option<&ArchiverKind>,
     idx: usize,
     start_offset: u64,
     end_offset: u64,
@@ -508,18 +701,16 @@ async fn load_segment_messages(
             .as_ref()
             .expect("Index reader not initialized")
             .clone();
-        let messages_reader = storages[idx]
-            .messages_reader
-            .as_ref()
-            .expect("Messages reader not initialized")
-            .clone();
+        // Offloaded segments have no local messages file.
+        let messages_reader = storages[idx].messages_reader.clone();
         let indexes_vec = partition.log.indexes();
         let indexes = indexes_vec
             .get(idx)
             .and_then(|opt| opt.as_ref())
             .map(|indexes| {
                 indexes
-                    .slice_by_offset(relative_start_offset, count)
+                    .position_for_offset(relative_start_offset)
+                    .and_then(|position| indexes.slice_by_offset(position, count))
                     .unwrap_or_default()
             });
         (index_reader, messages_reader, indexes)
@@ -546,10 +737,17 @@ async fn load_segment_messages(
     }
 
     let indexes_to_read = indexes_to_read.unwrap();
-    let batch = messages_reader
-        .as_ref()
-        .load_messages_from_disk(indexes_to_read)
-        .await?;
+    let batch = match messages_reader {
+        Some(messages_reader) => {
+            messages_reader
+                .load_messages_from_disk(indexes_to_read)
+                .await?
+        }
+        None => {
+            load_offloaded_messages(archiver, namespace, segment_start_offset, indexes_to_read)
+                .await?
+        }
+    };
 
     batch.validate_checksums_and_offsets(start_offset)?;
 
@@ -560,6 +758,7 @@ async fn load_segment_messages(
 async fn load_messages_from_disk_by_timestamp(
     local_partitions: &RefCell<LocalPartitions>,
     namespace: &IggyNamespace,
+    archiver: Option<&ArchiverKind>,
     timestamp: u64,
     count: u32,
 ) -> Result<IggyMessagesBatchSet, IggyError> {
@@ -614,6 +813,7 @@ async fn load_messages_from_disk_by_timestamp(
         let messages = load_segment_messages_by_timestamp(
             local_partitions,
             namespace,
+            archiver,
             idx,
             timestamp,
             remaining_count,
@@ -636,6 +836,7 @@ async fn load_messages_from_disk_by_timestamp(
 async fn load_segment_messages_by_timestamp(
     local_partitions: &RefCell<LocalPartitions>,
     namespace: &IggyNamespace,
+    archiver: Option<&ArchiverKind>,
     idx: usize,
     timestamp: u64,
     count: u32,
@@ -668,7 +869,7 @@ async fn load_segment_messages_by_timestamp(
     }
 
     // Load from disk
-    let (index_reader, messages_reader, indexes) = {
+    let (index_reader, messages_reader, indexes, segment_start_offset) = {
         let store = local_partitions.borrow();
         let partition = store
             .get(namespace)
@@ -678,17 +879,15 @@ async fn load_segment_messages_by_timestamp(
         if idx >= storages.len() {
             return Ok(IggyMessagesBatchSet::empty());
         }
+        let segment_start_offset = partition.log.segments()[idx].start_offset;
 
         let index_reader = storages[idx]
             .index_reader
             .as_ref()
             .expect("Index reader not initialized")
             .clone();
-        let messages_reader = storages[idx]
-            .messages_reader
-            .as_ref()
-            .expect("Messages reader not initialized")
-            .clone();
+        // Offloaded segments have no local messages file.
+        let messages_reader = storages[idx].messages_reader.clone();
         let indexes_vec = partition.log.indexes();
         let indexes = indexes_vec
             .get(idx)
@@ -698,7 +897,7 @@ async fn load_segment_messages_by_timestamp(
                     .slice_by_timestamp(timestamp, count)
                     .unwrap_or_default()
             });
-        (index_reader, messages_reader, indexes)
+        (index_reader, messages_reader, indexes, segment_start_offset)
     };
 
     let indexes_to_read = if let Some(indexes) = indexes {
@@ -722,10 +921,37 @@ async fn load_segment_messages_by_timestamp(
     }
 
     let indexes_to_read = indexes_to_read.unwrap();
-    let batch = messages_reader
-        .as_ref()
-        .load_messages_from_disk(indexes_to_read)
-        .await?;
+    let batch = match messages_reader {
+        Some(messages_reader) => {
+            messages_reader
+                .load_messages_from_disk(indexes_to_read)
+                .await?
+        }
+        None => {
+            load_offloaded_messages(archiver, namespace, segment_start_offset, indexes_to_read)
+                .await?
+        }
+    };
 
     Ok(IggyMessagesBatchSet::from(batch))
 }
+
+/// Fetches the messages of an offloaded segment from the archive.
+async fn load_offloaded_messages(
+    archiver: Option<&ArchiverKind>,
+    namespace: &IggyNamespace,
+    segment_start_offset: u64,
+    indexes: IggyIndexesMut,
+) -> Result<IggyMessagesBatchMut, IggyError> {
+    let Some(archiver) = archiver else {
+        error!(
+            "Cannot load messages of offloaded segment (start: {segment_start_offset}) \
+             for partition {}, archiver is disabled",
+            namespace.partition_id()
+        );
+        return Err(IggyError::CannotReadMessage);
+    };
+    archiver
+        .load_segment_messages(namespace, segment_start_offset, indexes)
+        .await
+}
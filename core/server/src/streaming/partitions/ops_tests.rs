@@ -153,7 +153,7 @@ mod tests {
     #[compio::test]
     async fn in_flight_reachable_when_journal_non_empty() {
         let (store, ns) = setup_state_c(10, 5).await;
-        let batches = ops::get_messages_by_offset(&store, &ns, 0, 5)
+        let batches = ops::get_messages_by_offset(&store, &ns, None, 0, 5)
             .await
             .unwrap();
         assert_eq!(batches.count(), 5);
@@ -163,7 +163,7 @@ mod tests {
     #[compio::test]
     async fn spanning_in_flight_and_journal_returns_all_in_order() {
         let (store, ns) = setup_state_c(10, 5).await;
-        let batches = ops::get_messages_by_offset(&store, &ns, 0, 15)
+        let batches = ops::get_messages_by_offset(&store, &ns, None, 0, 15)
             .await
             .unwrap();
         assert_eq!(batches.count(), 15);
@@ -176,7 +176,7 @@ mod tests {
         let consumer = PollingConsumer::Consumer(1, 0);
         let args =
             crate::shard::system::messages::PollingArgs::new(PollingStrategy::next(), 15, false);
-        let (metadata, batches) = ops::poll_messages(&store, &ns, consumer, args)
+        let (metadata, batches) = ops::poll_messages(&store, &ns, None, consumer, args, None)
             .await
             .unwrap();
         assert_eq!(batches.first_offset(), Some(0));
@@ -186,7 +186,7 @@ mod tests {
     #[compio::test]
     async fn single_message_at_in_flight_journal_boundary() {
         let (store, ns) = setup_state_c(10, 5).await;
-        let batches = ops::get_messages_by_offset(&store, &ns, 9, 1)
+        let batches = ops::get_messages_by_offset(&store, &ns, None, 9, 1)
             .await
             .unwrap();
         assert_eq!(batches.count(), 1);
@@ -196,7 +196,7 @@ mod tests {
     #[compio::test]
     async fn single_message_from_in_flight_at_offset_zero() {
         let (store, ns) = setup_state_c(10, 5).await;
-        let batches = ops::get_messages_by_offset(&store, &ns, 0, 1)
+        let batches = ops::get_messages_by_offset(&store, &ns, None, 0, 1)
             .await
             .unwrap();
         assert_eq!(batches.count(), 1);
@@ -230,7 +230,7 @@ mod tests {
         store.insert(ns, partition);
         let store = RefCell::new(store);
 
-        let batches = ops::get_messages_by_offset(&store, &ns, 0, 10)
+        let batches = ops::get_messages_by_offset(&store, &ns, None, 0, 10)
             .await
             .unwrap();
         assert_eq!(batches.count(), 10);
@@ -268,7 +268,7 @@ mod tests {
         store.insert(ns, partition);
         let store = RefCell::new(store);
 
-        let batches = ops::get_messages_by_offset(&store, &ns, 0, 10)
+        let batches = ops::get_messages_by_offset(&store, &ns, None, 0, 10)
             .await
             .unwrap();
         assert_eq!(batches.count(), 10);
@@ -277,7 +277,7 @@ mod tests {
     #[compio::test]
     async fn journal_single_message_at_specific_offset() {
         let (store, ns) = setup_state_c(10, 5).await;
-        let batches = ops::get_messages_by_offset(&store, &ns, 12, 1)
+        let batches = ops::get_messages_by_offset(&store, &ns, None, 12, 1)
             .await
             .unwrap();
         assert_eq!(batches.count(), 1);
@@ -340,7 +340,7 @@ mod tests {
     async fn post_restart_poll_with_correct_journal_init_no_skip() {
         let (store, ns) = setup_state_c(100, 10).await;
 
-        let batches = ops::get_messages_by_offset(&store, &ns, 95, 10)
+        let batches = ops::get_messages_by_offset(&store, &ns, None, 95, 10)
             .await
             .unwrap();
 
@@ -101,6 +101,24 @@ pub async fn create_partition_file_hierarchy(
         ));
     }
 
+    let consumer_group_acks_path =
+        config.get_consumer_group_acks_path(stream_id, topic_id, partition_id);
+    if !Path::new(&consumer_group_acks_path).exists()
+        && create_dir_all(&consumer_group_acks_path).await.is_err()
+    {
+        tracing::error!(
+            "Failed to create consumer group acks directory for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+            partition_id,
+            stream_id,
+            topic_id
+        );
+        return Err(IggyError::CannotCreatePartition(
+            partition_id,
+            stream_id,
+            topic_id,
+        ));
+    }
+
     tracing::info!(
         "Saved partition with start ID: {} for stream with ID: {} and topic with ID: {}, path: {}.",
         partition_id,
@@ -0,0 +1,319 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::persistence::persister::FileWithSyncPersister;
+use iggy_common::{IggyDuration, IggyError, IggyTimestamp};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::error;
+
+/// Identity of an idempotent producer along with the sequence number of the first message in the batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProducerSequence {
+    pub producer_id: u64,
+    pub producer_epoch: u64,
+    pub base_sequence: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct ProducerState {
+    epoch: u64,
+    next_sequence: u64,
+    updated_at: u64,
+}
+
+/// State of the producer after appending the batch, along with the offset of the last message
+/// in the partition at that time, or `None` if the partition was still empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct ProducerBatch {
+    producer_id: u64,
+    state: ProducerState,
+    offset: Option<u64>,
+}
+
+/// Idempotent producers state of a single partition, persisted next to its segments.
+///
+/// The state is persisted before the appended messages are saved on disk, as the state of the
+/// producers up to the saved messages followed by the batches appended since. After a restart,
+/// only the batches which reached the partition log are applied, so the state neither claims
+/// the sequence numbers of lost messages nor misses the ones of saved messages.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartitionProducers {
+    producers: BTreeMap<u64, ProducerState>,
+    #[serde(default)]
+    batches: Vec<ProducerBatch>,
+    #[serde(skip)]
+    current: BTreeMap<u64, ProducerState>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl PartitionProducers {
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Validates the sequence numbers of the batch, returning `false` if it was already appended.
+    ///
+    /// The first batch of an unknown producer or a new epoch is accepted with any base sequence,
+    /// an older epoch is fenced, and within the epoch the batch must continue the sequence.
+    pub fn check(&self, producer: &ProducerSequence, count: u32) -> Result<bool, IggyError> {
+        let Some(state) = self.current.get(&producer.producer_id) else {
+            return Ok(true);
+        };
+
+        if producer.producer_epoch < state.epoch {
+            return Err(IggyError::ProducerFenced(
+                producer.producer_id,
+                producer.producer_epoch,
+                state.epoch,
+            ));
+        }
+
+        if producer.producer_epoch > state.epoch || producer.base_sequence == state.next_sequence {
+            return Ok(true);
+        }
+
+        if producer.base_sequence + count as u64 <= state.next_sequence {
+            return Ok(false);
+        }
+
+        Err(IggyError::OutOfOrderSequence(
+            producer.producer_id,
+            state.next_sequence,
+            producer.base_sequence,
+        ))
+    }
+
+    /// Records the batch appended by the producer, which must have been validated by [`Self::check`].
+    /// The offset is the one of the last message in the partition after appending the batch.
+    pub fn record(
+        &mut self,
+        producer: &ProducerSequence,
+        count: u32,
+        offset: Option<u64>,
+        now: IggyTimestamp,
+    ) {
+        let state = ProducerState {
+            epoch: producer.producer_epoch,
+            next_sequence: producer.base_sequence + count as u64,
+            updated_at: now.as_micros(),
+        };
+        self.current.insert(producer.producer_id, state);
+        self.batches.push(ProducerBatch {
+            producer_id: producer.producer_id,
+            state,
+            offset,
+        });
+        self.dirty = true;
+    }
+
+    /// Applies the batches whose messages are saved on disk up to the given offset.
+    pub fn mark_saved(&mut self, saved_offset: u64) {
+        let count = self
+            .batches
+            .partition_point(|batch| batch.offset <= Some(saved_offset));
+        for batch in self.batches.drain(..count) {
+            self.producers.insert(batch.producer_id, batch.state);
+        }
+    }
+
+    /// Applies the batches which reached the partition log and drops the ones which were lost,
+    /// as their messages were never saved on disk.
+    pub fn clamp(&mut self, current_offset: Option<u64>) {
+        if !self.batches.is_empty() {
+            self.dirty = true;
+        }
+        for batch in self
+            .batches
+            .drain(..)
+            .take_while(|batch| batch.offset <= current_offset)
+        {
+            self.producers.insert(batch.producer_id, batch.state);
+        }
+        self.current = self.producers.clone();
+    }
+
+    /// Removes the producers which didn't append any messages within the expiry.
+    pub fn expire(&mut self, now: IggyTimestamp, expiry: IggyDuration) -> usize {
+        if expiry.is_zero() {
+            return 0;
+        }
+
+        let now = now.as_micros();
+        let expiry = expiry.as_micros();
+        let count = self.current.len();
+        self.current
+            .retain(|_, state| state.updated_at + expiry > now);
+        let expired = count - self.current.len();
+        if expired > 0 {
+            let current = &self.current;
+            self.producers.retain(|id, _| current.contains_key(id));
+            self.batches
+                .retain(|batch| current.contains_key(&batch.producer_id));
+            self.dirty = true;
+        }
+        expired
+    }
+
+    /// Returns the snapshot of the state to persist and marks it as clean.
+    pub fn take_snapshot(&mut self) -> Self {
+        self.dirty = false;
+        self.clone()
+    }
+
+    pub fn load(path: &str) -> Result<Self, IggyError> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                error!("Cannot read partition producers file: {path}, error: {e}");
+                return Err(IggyError::CannotReadFile);
+            }
+        };
+        rmp_serde::from_slice(&bytes).map_err(|e| {
+            error!("Cannot deserialize partition producers file: {path}, error: {e}");
+            IggyError::CannotDeserializeResource
+        })
+    }
+
+    pub async fn persist(&self, path: &str) -> Result<(), IggyError> {
+        let bytes = rmp_serde::to_vec(self).map_err(|_| IggyError::CannotSerializeResource)?;
+        FileWithSyncPersister.overwrite(path, bytes).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(producer_epoch: u64, base_sequence: u64) -> ProducerSequence {
+        ProducerSequence {
+            producer_id: 1,
+            producer_epoch,
+            base_sequence,
+        }
+    }
+
+    #[test]
+    fn should_accept_batches_continuing_the_sequence() {
+        let mut producers = PartitionProducers::default();
+        assert!(producers.check(&sequence(1, 0), 5).unwrap());
+        producers.record(&sequence(1, 0), 5, Some(4), IggyTimestamp::now());
+        assert!(producers.is_dirty());
+
+        assert!(producers.check(&sequence(1, 5), 5).unwrap());
+        producers.record(&sequence(1, 5), 5, Some(9), IggyTimestamp::now());
+        assert!(producers.check(&sequence(1, 10), 1).unwrap());
+    }
+
+    #[test]
+    fn should_detect_duplicated_batches() {
+        let mut producers = PartitionProducers::default();
+        producers.record(&sequence(1, 0), 5, Some(4), IggyTimestamp::now());
+        producers.record(&sequence(1, 5), 5, Some(9), IggyTimestamp::now());
+
+        assert!(!producers.check(&sequence(1, 0), 5).unwrap());
+        assert!(!producers.check(&sequence(1, 5), 5).unwrap());
+    }
+
+    #[test]
+    fn should_reject_out_of_order_batches() {
+        let mut producers = PartitionProducers::default();
+        producers.record(&sequence(1, 0), 5, Some(4), IggyTimestamp::now());
+
+        assert_eq!(
+            producers.check(&sequence(1, 7), 1),
+            Err(IggyError::OutOfOrderSequence(1, 5, 7))
+        );
+        assert_eq!(
+            producers.check(&sequence(1, 3), 5),
+            Err(IggyError::OutOfOrderSequence(1, 5, 3))
+        );
+    }
+
+    #[test]
+    fn should_fence_older_epochs_and_reset_sequence_for_newer_ones() {
+        let mut producers = PartitionProducers::default();
+        producers.record(&sequence(2, 0), 5, Some(4), IggyTimestamp::now());
+
+        assert_eq!(
+            producers.check(&sequence(1, 5), 1),
+            Err(IggyError::ProducerFenced(1, 1, 2))
+        );
+        assert!(producers.check(&sequence(3, 0), 1).unwrap());
+    }
+
+    #[test]
+    fn should_expire_idle_producers() {
+        let mut producers = PartitionProducers::default();
+        producers.record(&sequence(1, 0), 5, Some(4), IggyTimestamp::from(1_000_000));
+        let snapshot = producers.take_snapshot();
+        assert!(!producers.is_dirty());
+        assert_eq!(snapshot.batches.len(), 1);
+
+        let expiry = IggyDuration::from(1_000_000);
+        assert_eq!(producers.expire(IggyTimestamp::from(1_500_000), expiry), 0);
+        assert_eq!(producers.expire(IggyTimestamp::from(2_000_000), expiry), 1);
+        assert!(producers.is_dirty());
+        assert!(producers.check(&sequence(1, 0), 5).unwrap());
+    }
+
+    #[test]
+    fn should_apply_batches_saved_on_disk() {
+        let mut producers = PartitionProducers::default();
+        producers.record(&sequence(1, 0), 5, Some(4), IggyTimestamp::now());
+        producers.record(&sequence(1, 5), 5, Some(9), IggyTimestamp::now());
+
+        producers.mark_saved(4);
+        let snapshot = producers.take_snapshot();
+        assert_eq!(snapshot.producers[&1].next_sequence, 5);
+        assert_eq!(snapshot.batches.len(), 1);
+        assert!(!producers.check(&sequence(1, 5), 5).unwrap());
+    }
+
+    #[test]
+    fn should_clamp_batches_beyond_current_offset() {
+        let mut producers = PartitionProducers::default();
+        producers.record(&sequence(1, 0), 5, Some(4), IggyTimestamp::now());
+        producers.record(&sequence(1, 5), 5, Some(9), IggyTimestamp::now());
+        producers.record(&sequence(1, 10), 5, Some(14), IggyTimestamp::now());
+        let mut loaded = producers.take_snapshot();
+
+        // The messages of the last batch were lost, so it can be appended again.
+        loaded.clamp(Some(9));
+        assert!(loaded.is_dirty());
+        assert!(!loaded.check(&sequence(1, 5), 5).unwrap());
+        assert!(loaded.check(&sequence(1, 10), 5).unwrap());
+
+        // Without any messages in the partition, the producer is unknown again.
+        let mut empty = producers.take_snapshot();
+        empty.clamp(None);
+        assert!(empty.check(&sequence(1, 5), 5).unwrap());
+    }
+
+    #[test]
+    fn should_keep_state_of_batches_without_new_messages() {
+        let mut producers = PartitionProducers::default();
+        producers.record(&sequence(1, 0), 5, None, IggyTimestamp::now());
+        let mut loaded = producers.take_snapshot();
+
+        loaded.clamp(None);
+        assert!(!loaded.check(&sequence(1, 0), 5).unwrap());
+    }
+}
@@ -0,0 +1,231 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::persistence::persister::FileWithSyncPersister;
+use iggy_common::{
+    DELIVER_AT_HEADER_KEY, HeaderKey, IggyError, IggyMessagesBatchMut, IggyTimestamp,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+use tracing::error;
+
+/// Returns the positions of the delayed messages in the batch along with their delivery times,
+/// skipping the ones which are already due.
+///
+/// The batch must be read before it's encrypted by the server, as the delivery time is kept in the user headers.
+pub fn find_delayed_messages(
+    batch: &IggyMessagesBatchMut,
+    now: IggyTimestamp,
+) -> Result<Vec<(u32, u64)>, IggyError> {
+    let deliver_at_key = HeaderKey::from_str(DELIVER_AT_HEADER_KEY)?;
+    let now = now.as_micros();
+    let mut delayed = Vec::new();
+    for (index, message) in batch.iter().enumerate() {
+        // The headers encrypted by the client can't be read, so such messages are never delayed.
+        let Ok(Some(headers)) = message.user_headers_map() else {
+            continue;
+        };
+        let Some(deliver_at) = headers.get(&deliver_at_key) else {
+            continue;
+        };
+        let deliver_at = deliver_at.as_uint64()?;
+        if deliver_at > now {
+            delayed.push((index as u32, deliver_at));
+        }
+    }
+    Ok(delayed)
+}
+
+/// Delayed messages of a single partition which are not due yet, persisted next to its segments.
+///
+/// The messages are indexed by offset, to find the first one hidden from the polling consumer,
+/// and by delivery time, so the due ones are released in order without scanning the whole index.
+/// The state is persisted only once the appended messages are saved on disk.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PartitionSchedule {
+    pending: BTreeMap<u64, u64>,
+    timers: BTreeSet<(u64, u64)>,
+    dirty: bool,
+}
+
+impl PartitionSchedule {
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Hides the message with the given offset until its delivery time.
+    pub fn schedule(&mut self, offset: u64, deliver_at: u64) {
+        if let Some(previous) = self.pending.insert(offset, deliver_at) {
+            self.timers.remove(&(previous, offset));
+        }
+        self.timers.insert((deliver_at, offset));
+        self.dirty = true;
+    }
+
+    /// Releases the messages which are due, returning their count.
+    pub fn release(&mut self, now: IggyTimestamp) -> usize {
+        let now = now.as_micros();
+        let mut released = 0;
+        while let Some(&(deliver_at, offset)) = self.timers.first()
+            && deliver_at <= now
+        {
+            self.timers.pop_first();
+            self.pending.remove(&offset);
+            released += 1;
+        }
+        if released > 0 {
+            self.dirty = true;
+        }
+        released
+    }
+
+    /// Returns the offset of the first message starting from `from` which is not due yet.
+    pub fn first_pending(&self, from: u64) -> Option<u64> {
+        self.pending.range(from..).next().map(|(offset, _)| *offset)
+    }
+
+    pub fn is_pending(&self, offset: u64) -> bool {
+        self.pending.contains_key(&offset)
+    }
+
+    /// Drops the delayed messages which were never persisted in the partition log.
+    pub fn clamp(&mut self, current_offset: Option<u64>) {
+        let count = self.pending.len();
+        match current_offset {
+            Some(current_offset) => {
+                self.pending.retain(|offset, _| *offset <= current_offset);
+                self.timers.retain(|(_, offset)| *offset <= current_offset);
+            }
+            None => {
+                self.pending.clear();
+                self.timers.clear();
+            }
+        }
+        if self.pending.len() != count {
+            self.dirty = true;
+        }
+    }
+
+    /// Returns the snapshot of the state to persist and marks it as clean.
+    pub fn take_snapshot(&mut self) -> Self {
+        self.dirty = false;
+        self.clone()
+    }
+
+    pub fn load(path: &str) -> Result<Self, IggyError> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                error!("Cannot read partition schedule file: {path}, error: {e}");
+                return Err(IggyError::CannotReadFile);
+            }
+        };
+        let pending: BTreeMap<u64, u64> = rmp_serde::from_slice(&bytes).map_err(|e| {
+            error!("Cannot deserialize partition schedule file: {path}, error: {e}");
+            IggyError::CannotDeserializeResource
+        })?;
+        let timers = pending
+            .iter()
+            .map(|(offset, deliver_at)| (*deliver_at, *offset))
+            .collect();
+        Ok(Self {
+            pending,
+            timers,
+            dirty: false,
+        })
+    }
+
+    pub async fn persist(&self, path: &str) -> Result<(), IggyError> {
+        let bytes =
+            rmp_serde::to_vec(&self.pending).map_err(|_| IggyError::CannotSerializeResource)?;
+        FileWithSyncPersister.overwrite(path, bytes).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy_common::{HeaderValue, IggyMessage, Sizeable};
+
+    #[test]
+    fn should_release_due_messages_in_order_of_delivery_time() {
+        let mut schedule = PartitionSchedule::default();
+        schedule.schedule(3, 300);
+        schedule.schedule(5, 100);
+        schedule.schedule(8, 200);
+        assert!(schedule.is_dirty());
+        assert_eq!(schedule.first_pending(0), Some(3));
+        assert_eq!(schedule.first_pending(4), Some(5));
+
+        assert_eq!(schedule.release(IggyTimestamp::from(99)), 0);
+        assert_eq!(schedule.release(IggyTimestamp::from(200)), 2);
+        assert!(!schedule.is_pending(5));
+        assert!(schedule.is_pending(3));
+        assert_eq!(schedule.first_pending(4), None);
+
+        assert_eq!(schedule.release(IggyTimestamp::from(300)), 1);
+        assert!(schedule.is_empty());
+    }
+
+    #[test]
+    fn should_clamp_messages_beyond_current_offset() {
+        let mut schedule = PartitionSchedule::default();
+        schedule.schedule(1, 100);
+        schedule.schedule(7, 100);
+        schedule.take_snapshot();
+
+        schedule.clamp(Some(5));
+        assert!(schedule.is_dirty());
+        assert_eq!(schedule.first_pending(0), Some(1));
+        assert_eq!(schedule.first_pending(2), None);
+        assert_eq!(schedule.release(IggyTimestamp::from(100)), 1);
+
+        schedule.schedule(2, 100);
+        schedule.clamp(None);
+        assert!(schedule.is_empty());
+    }
+
+    #[test]
+    fn should_find_delayed_messages_which_are_not_due() {
+        let deliver_at_key = HeaderKey::from_str(DELIVER_AT_HEADER_KEY).unwrap();
+        let message = |deliver_at: Option<u64>| {
+            IggyMessage::builder()
+                .payload(Bytes::from("message"))
+                .maybe_user_headers(deliver_at.map(|deliver_at| {
+                    BTreeMap::from([(deliver_at_key.clone(), HeaderValue::from(deliver_at))])
+                }))
+                .build()
+                .unwrap()
+        };
+        let messages = [message(Some(500)), message(None), message(Some(1500))];
+        let size = messages
+            .iter()
+            .map(|message| message.get_size_bytes().as_bytes_u32())
+            .sum();
+        let batch = IggyMessagesBatchMut::from_messages(&messages, size);
+
+        let delayed = find_delayed_messages(&batch, IggyTimestamp::from(1000)).unwrap();
+        assert_eq!(delayed, vec![(2, 1500)]);
+    }
+}
@@ -0,0 +1,535 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Key-based compaction of sealed segments.
+//!
+//! The compaction runs in two steps over the sealed segments of a partition. First, all of them
+//! are scanned to find the latest offset of every message key. Then, each segment is rewritten
+//! without the messages superseded by a later message with the same key. The offsets of the
+//! retained messages are preserved, so the compacted segments have gaps in their offsets.
+
+use crate::streaming::segments::storage::Storage;
+use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut};
+use ahash::AHashMap;
+use err_trail::ErrContext;
+use iggy_common::{
+    HeaderKey, HeaderValue, IggyError, IggyMessageView, MESSAGE_KEY_HEADER_KEY,
+    TOMBSTONE_HEADER_KEY,
+};
+use std::path::Path;
+use std::str::FromStr;
+use tracing::warn;
+
+/// The number of messages loaded from disk at once while compacting a segment.
+const COMPACTION_BATCH_COUNT: u32 = 10_000;
+
+/// A sealed segment rewritten by the compaction.
+#[derive(Debug)]
+pub struct CompactedSegment {
+    pub storage: Storage,
+    pub indexes: IggyIndexesMut,
+    pub messages_size: u64,
+    pub removed_messages: u64,
+}
+
+/// Finds the latest message of every key and rewrites the segments without the older ones.
+#[derive(Debug)]
+pub struct SegmentsCompactor {
+    key_header: HeaderKey,
+    tombstone_header: HeaderKey,
+    tombstone_deadline: u64,
+    latest_offsets: AHashMap<HeaderValue, u64>,
+}
+
+impl SegmentsCompactor {
+    /// Creates a compactor removing the tombstones with a timestamp lower than `tombstone_deadline`.
+    pub fn new(tombstone_deadline: u64) -> Self {
+        Self {
+            key_header: HeaderKey::from_str(MESSAGE_KEY_HEADER_KEY)
+                .expect("Message key header must be valid"),
+            tombstone_header: HeaderKey::from_str(TOMBSTONE_HEADER_KEY)
+                .expect("Tombstone header must be valid"),
+            tombstone_deadline,
+            latest_offsets: AHashMap::new(),
+        }
+    }
+
+    /// Records the offset of every keyed message, the segments must be scanned in order.
+    pub async fn scan(&mut self, storage: &Storage) -> Result<(), IggyError> {
+        let indexes = load_indexes(storage).await?;
+        let mut position = 0;
+        while let Some(batch) = load_batch(storage, &indexes, position).await? {
+            position += batch.count();
+            for message in batch.iter() {
+                if let Some((key, _)) = self.message_key(&message)? {
+                    self.latest_offsets.insert(key, message.header().offset());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Rewrites the segment without the superseded messages and expired tombstones.
+    ///
+    /// Returns `None` when there is nothing to remove from the segment, so it's left untouched.
+    /// Otherwise, the compacted files atomically replace the original ones and the returned
+    /// storage is reopened on them.
+    pub async fn compact(
+        &self,
+        storage: &Storage,
+        start_offset: u64,
+        fsync: bool,
+    ) -> Result<Option<CompactedSegment>, IggyError> {
+        let (Some(messages_path), Some(index_path)) = storage.segment_and_index_paths() else {
+            return Ok(None);
+        };
+
+        // Only decide on the removed messages first, to avoid rewriting the unchanged segments.
+        let indexes = load_indexes(storage).await?;
+        let mut removed_by_batch = Vec::new();
+        let mut removed_messages = 0u64;
+        let mut position = 0;
+        while let Some(batch) = load_batch(storage, &indexes, position).await? {
+            position += batch.count();
+            let mut removed = Vec::new();
+            for (index, message) in batch.iter().enumerate() {
+                if !self.retains(&message)? {
+                    removed.push(index as u32);
+                }
+            }
+            removed_messages += removed.len() as u64;
+            removed_by_batch.push(removed);
+        }
+
+        if removed_messages == 0 {
+            return Ok(None);
+        }
+
+        if position < indexes.count() {
+            warn!(
+                "Skipping compaction of segment: {messages_path}, loaded only {position} of {} messages.",
+                indexes.count()
+            );
+            return Ok(None);
+        }
+
+        let compacted_messages_path = compacted_path(&messages_path);
+        let compacted_index_path = compacted_path(&index_path);
+        // Leftovers of an interrupted compaction would not be truncated by the writers.
+        let _ = compio::fs::remove_file(&compacted_messages_path).await;
+        let _ = compio::fs::remove_file(&compacted_index_path).await;
+        let mut compacted_storage = Storage::new(
+            &compacted_messages_path,
+            &compacted_index_path,
+            0,
+            0,
+            false,
+            false,
+            false,
+        )
+        .await?;
+        let messages_writer = compacted_storage
+            .messages_writer
+            .clone()
+            .expect("Messages writer not initialized");
+        let index_writer = compacted_storage
+            .index_writer
+            .clone()
+            .expect("Index writer not initialized");
+
+        let retained_count = indexes.count() as u64 - removed_messages;
+        let mut compacted_indexes = IggyIndexesMut::with_capacity(retained_count as usize, 0);
+        let mut messages_size = 0u32;
+        let mut position = 0;
+        for removed in &removed_by_batch {
+            let Some(mut batch) = load_batch(storage, &indexes, position).await? else {
+                break;
+            };
+            position += batch.count();
+            batch.remove_messages(removed, messages_size);
+            if batch.is_empty() {
+                continue;
+            }
+
+            for message in batch.iter() {
+                let header = message.header();
+                messages_size += message.size() as u32;
+                compacted_indexes.insert(
+                    (header.offset() - start_offset) as u32,
+                    messages_size,
+                    header.timestamp(),
+                );
+            }
+            messages_writer
+                .save_frozen_batches(&[batch.freeze()])
+                .await?;
+        }
+
+        index_writer
+            .save_indexes(compacted_indexes.unsaved_slice())
+            .await?;
+        compacted_indexes.mark_saved();
+        messages_writer.fsync().await?;
+        index_writer.fsync().await?;
+        drop((messages_writer, index_writer));
+        let _ = compacted_storage.shutdown();
+
+        replace_file(&compacted_messages_path, &messages_path).await?;
+        replace_file(&compacted_index_path, &index_path).await?;
+
+        let indexes_size = compacted_indexes.size() as u64;
+        let mut storage = Storage::new(
+            &messages_path,
+            &index_path,
+            messages_size as u64,
+            indexes_size,
+            fsync,
+            fsync,
+            true,
+        )
+        .await?;
+        // The compacted segment is sealed, so the writers are never needed again.
+        let _ = storage.shutdown();
+
+        Ok(Some(CompactedSegment {
+            storage,
+            indexes: compacted_indexes,
+            messages_size: messages_size as u64,
+            removed_messages,
+        }))
+    }
+
+    /// Returns the key of the message and whether it's a tombstone, or `None` for unkeyed ones.
+    fn message_key(
+        &self,
+        message: &IggyMessageView,
+    ) -> Result<Option<(HeaderValue, bool)>, IggyError> {
+        let Some(mut headers) = message.user_headers_map()? else {
+            return Ok(None);
+        };
+        let Some(key) = headers.remove(&self.key_header) else {
+            return Ok(None);
+        };
+        Ok(Some((key, headers.contains_key(&self.tombstone_header))))
+    }
+
+    /// Decides whether the message survives the compaction.
+    fn retains(&self, message: &IggyMessageView) -> Result<bool, IggyError> {
+        let Some((key, tombstone)) = self.message_key(message)? else {
+            return Ok(true);
+        };
+
+        let header = message.header();
+        if self
+            .latest_offsets
+            .get(&key)
+            .is_some_and(|&latest_offset| latest_offset > header.offset())
+        {
+            return Ok(false);
+        }
+        Ok(!tombstone || header.timestamp() >= self.tombstone_deadline)
+    }
+}
+
+async fn load_indexes(storage: &Storage) -> Result<IggyIndexesMut, IggyError> {
+    storage
+        .index_reader
+        .as_ref()
+        .expect("Index reader not initialized")
+        .load_all_indexes_from_disk()
+        .await
+}
+
+/// Loads the next batch of messages starting at the given index position, or `None` at the end.
+async fn load_batch(
+    storage: &Storage,
+    indexes: &IggyIndexesMut,
+    position: u32,
+) -> Result<Option<IggyMessagesBatchMut>, IggyError> {
+    let Some(indexes) = indexes.slice_by_offset(position, COMPACTION_BATCH_COUNT) else {
+        return Ok(None);
+    };
+    let batch = storage
+        .messages_reader
+        .as_ref()
+        .expect("Messages reader not initialized")
+        .load_messages_from_disk(indexes)
+        .await?;
+    Ok((!batch.is_empty()).then_some(batch))
+}
+
+/// Completes or rolls back the compaction of a segment interrupted by a crash.
+///
+/// The compacted messages file replaces the original one before the index does, so a leftover
+/// compacted messages file means that nothing was replaced yet, while a leftover compacted index
+/// alone means that it still has to replace the stale index.
+pub async fn recover_interrupted_compaction(
+    messages_path: &str,
+    index_path: &str,
+) -> Result<(), IggyError> {
+    let compacted_messages_path = compacted_path(messages_path);
+    let compacted_index_path = compacted_path(index_path);
+    if compio::fs::metadata(&compacted_messages_path).await.is_ok() {
+        warn!("Discarding interrupted compaction of segment: {messages_path}");
+        let _ = compio::fs::remove_file(&compacted_messages_path).await;
+        let _ = compio::fs::remove_file(&compacted_index_path).await;
+    } else if compio::fs::metadata(&compacted_index_path).await.is_ok() {
+        warn!("Completing interrupted compaction of segment: {messages_path}");
+        replace_file(&compacted_index_path, index_path).await?;
+    }
+    Ok(())
+}
+
+fn compacted_path(path: &str) -> String {
+    format!("{path}.compacted")
+}
+
+/// Atomically replaces the file at `path` with the one at `source`, making the rename durable.
+async fn replace_file(source: &str, path: &str) -> Result<(), IggyError> {
+    compio::fs::rename(source, path)
+        .await
+        .error(|e: &std::io::Error| format!("Failed to rename file: {source} to: {path}. {e}"))
+        .map_err(|_| IggyError::CannotOverwriteFile)?;
+
+    if let Some(parent) = Path::new(path).parent() {
+        let directory = compio::fs::File::open(parent)
+            .await
+            .error(|e: &std::io::Error| format!("Failed to open directory: {parent:?}. {e}"))
+            .map_err(|_| IggyError::CannotReadFile)?;
+        directory
+            .sync_all()
+            .await
+            .error(|e: &std::io::Error| format!("Failed to fsync directory: {parent:?}. {e}"))
+            .map_err(|_| IggyError::CannotSyncFile)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::{IggyByteSize, IggyMessage, MemoryPool, MemoryPoolConfigOther, Sizeable};
+    use std::collections::BTreeMap;
+
+    fn init_memory_pool() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            let config = MemoryPoolConfigOther {
+                enabled: false,
+                size: IggyByteSize::from(64 * 1024 * 1024u64),
+                bucket_capacity: 256,
+            };
+            MemoryPool::init_pool(&config);
+        });
+    }
+
+    fn create_message(key: Option<&str>, tombstone: bool) -> IggyMessage {
+        let mut headers = BTreeMap::new();
+        if let Some(key) = key {
+            headers.insert(
+                HeaderKey::from_str(MESSAGE_KEY_HEADER_KEY).unwrap(),
+                HeaderValue::try_from(key).unwrap(),
+            );
+        }
+        if tombstone {
+            headers.insert(
+                HeaderKey::from_str(TOMBSTONE_HEADER_KEY).unwrap(),
+                true.into(),
+            );
+        }
+        let builder = IggyMessage::builder().payload(bytes::Bytes::from("payload"));
+        if headers.is_empty() {
+            builder.build().unwrap()
+        } else {
+            builder.user_headers(headers).build().unwrap()
+        }
+    }
+
+    async fn create_segment(
+        directory: &Path,
+        start_offset: u64,
+        messages: &[IggyMessage],
+    ) -> Storage {
+        let messages_path = directory.join(format!("{start_offset:0>20}.log"));
+        let index_path = directory.join(format!("{start_offset:0>20}.index"));
+        let storage = Storage::new(
+            messages_path.to_str().unwrap(),
+            index_path.to_str().unwrap(),
+            0,
+            0,
+            false,
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+
+        let messages_size = messages
+            .iter()
+            .map(|m| m.get_size_bytes().as_bytes_u32())
+            .sum();
+        let mut batch = IggyMessagesBatchMut::from_messages(messages, messages_size);
+        batch
+            .prepare_for_persistence(start_offset, start_offset, 0, None)
+            .await;
+        let indexes = batch.indexes().unsaved_slice();
+        storage
+            .messages_writer
+            .as_ref()
+            .unwrap()
+            .save_frozen_batches(&[batch.freeze()])
+            .await
+            .unwrap();
+        storage
+            .index_writer
+            .as_ref()
+            .unwrap()
+            .save_indexes(indexes)
+            .await
+            .unwrap();
+        storage
+    }
+
+    async fn load_offsets(storage: &Storage) -> Vec<u64> {
+        let indexes = load_indexes(storage).await.unwrap();
+        let Some(batch) = load_batch(storage, &indexes, 0).await.unwrap() else {
+            return Vec::new();
+        };
+        batch.iter().map(|m| m.header().offset()).collect()
+    }
+
+    #[compio::test]
+    async fn should_retain_only_latest_message_per_key() {
+        init_memory_pool();
+        let directory = tempfile::tempdir().unwrap();
+        let first = create_segment(
+            directory.path(),
+            0,
+            &[
+                create_message(Some("a"), false),
+                create_message(Some("b"), false),
+                create_message(None, false),
+                create_message(Some("a"), false),
+            ],
+        )
+        .await;
+        let second = create_segment(
+            directory.path(),
+            4,
+            &[
+                create_message(Some("b"), false),
+                create_message(Some("c"), false),
+            ],
+        )
+        .await;
+
+        let mut compactor = SegmentsCompactor::new(0);
+        compactor.scan(&first).await.unwrap();
+        compactor.scan(&second).await.unwrap();
+
+        let compacted = compactor.compact(&first, 0, false).await.unwrap().unwrap();
+        assert_eq!(compacted.removed_messages, 2);
+        assert_eq!(compacted.indexes.count(), 2);
+        assert_eq!(compacted.indexes.get(0).unwrap().offset(), 2);
+        assert_eq!(compacted.indexes.get(1).unwrap().offset(), 3);
+        assert_eq!(
+            compacted.messages_size,
+            compacted.indexes.last().unwrap().position() as u64
+        );
+        assert_eq!(load_offsets(&compacted.storage).await, vec![2, 3]);
+
+        assert!(
+            compactor
+                .compact(&second, 4, false)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[compio::test]
+    async fn should_remove_tombstones_only_after_retention() {
+        init_memory_pool();
+        let directory = tempfile::tempdir().unwrap();
+        let segment = create_segment(
+            directory.path(),
+            0,
+            &[
+                create_message(Some("a"), false),
+                create_message(Some("a"), true),
+            ],
+        )
+        .await;
+
+        let mut compactor = SegmentsCompactor::new(0);
+        compactor.scan(&segment).await.unwrap();
+        let compacted = compactor
+            .compact(&segment, 0, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(compacted.removed_messages, 1);
+        assert_eq!(load_offsets(&compacted.storage).await, vec![1]);
+
+        let mut compactor = SegmentsCompactor::new(u64::MAX);
+        compactor.scan(&compacted.storage).await.unwrap();
+        let compacted = compactor
+            .compact(&compacted.storage, 0, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(compacted.removed_messages, 1);
+        assert_eq!(compacted.messages_size, 0);
+        assert!(compacted.indexes.is_empty());
+        assert!(load_offsets(&compacted.storage).await.is_empty());
+    }
+
+    #[compio::test]
+    async fn should_recover_interrupted_compaction() {
+        let directory = tempfile::tempdir().unwrap();
+        let messages_path = directory.path().join("0.log");
+        let index_path = directory.path().join("0.index");
+        let messages_path = messages_path.to_str().unwrap();
+        let index_path = index_path.to_str().unwrap();
+        for (path, content) in [
+            (messages_path.to_owned(), "messages"),
+            (index_path.to_owned(), "index"),
+            (compacted_path(index_path), "compacted index"),
+        ] {
+            std::fs::write(path, content).unwrap();
+        }
+
+        recover_interrupted_compaction(messages_path, index_path)
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(index_path).unwrap(),
+            "compacted index"
+        );
+        assert!(!Path::new(&compacted_path(index_path)).exists());
+
+        std::fs::write(compacted_path(messages_path), "compacted messages").unwrap();
+        std::fs::write(compacted_path(index_path), "compacted index").unwrap();
+        recover_interrupted_compaction(messages_path, index_path)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(messages_path).unwrap(), "messages");
+        assert!(!Path::new(&compacted_path(messages_path)).exists());
+        assert!(!Path::new(&compacted_path(index_path)).exists());
+    }
+}
@@ -21,6 +21,7 @@ mod messages;
 mod segment;
 mod types;
 
+pub mod compaction;
 pub mod storage;
 
 pub use indexes::IggyIndexesMut;
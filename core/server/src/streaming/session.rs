@@ -17,7 +17,7 @@
  */
 
 use iggy_common::UserId;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 use std::net::SocketAddr;
 
@@ -29,6 +29,17 @@ pub struct Session {
     active: Cell<bool>,
     pub ip_address: SocketAddr,
     pub migrated: Cell<bool>,
+    scram_challenge: RefCell<Option<ScramChallenge>>,
+}
+
+/// Server-first state of a SCRAM exchange, kept until the client sends its proof.
+#[derive(Debug, Clone)]
+pub struct ScramChallenge {
+    pub username: String,
+    pub nonce: String,
+    pub salt: Vec<u8>,
+    pub iterations: u32,
+    pub auth_message: String,
 }
 
 impl Session {
@@ -39,6 +50,7 @@ impl Session {
             active: Cell::new(true),
             migrated: Cell::new(false),
             ip_address,
+            scram_challenge: RefCell::new(None),
         }
     }
 
@@ -73,6 +85,22 @@ impl Session {
         self.migrated.set(true)
     }
 
+    pub fn set_scram_challenge(&self, challenge: ScramChallenge) {
+        self.scram_challenge.replace(Some(challenge));
+    }
+
+    pub fn take_scram_challenge(&self) -> Option<ScramChallenge> {
+        self.scram_challenge.take()
+    }
+
+    /// Returns the name of the user whose SCRAM exchange is in progress, if any.
+    pub fn scram_username(&self) -> Option<String> {
+        self.scram_challenge
+            .borrow()
+            .as_ref()
+            .map(|challenge| challenge.username.clone())
+    }
+
     pub fn clear_user_id(&self) {
         self.set_user_id(u32::MAX);
     }
@@ -0,0 +1,145 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::SystemConfig;
+use crate::streaming::partitions::storage::persist_offset;
+use crate::streaming::persistence::persister::FileWithSyncPersister;
+use crate::streaming::transactions::PartitionTransactions;
+use compio::fs::create_dir_all;
+use iggy_common::{ConsumerKind, IggyError};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tracing::{error, info, warn};
+
+/// Commit decision of a transaction, persisted before any of its partitions is completed
+/// and removed once all of them, along with the consumer offsets, have been applied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingCommit {
+    pub transaction_id: u64,
+    /// Partitions touched by the transaction as `(stream_id, topic_id, partition_id)`.
+    pub partitions: Vec<(usize, usize, usize)>,
+    pub offsets: Vec<PendingOffset>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingOffset {
+    pub stream_id: usize,
+    pub topic_id: usize,
+    pub partition_id: usize,
+    pub kind: ConsumerKind,
+    pub consumer_id: usize,
+    pub offset: u64,
+}
+
+impl PendingOffset {
+    fn path(&self, config: &SystemConfig) -> String {
+        let dir_path = match self.kind {
+            ConsumerKind::Consumer => {
+                config.get_consumer_offsets_path(self.stream_id, self.topic_id, self.partition_id)
+            }
+            ConsumerKind::ConsumerGroup => config.get_consumer_group_offsets_path(
+                self.stream_id,
+                self.topic_id,
+                self.partition_id,
+            ),
+        };
+        format!("{dir_path}/{}", self.consumer_id)
+    }
+}
+
+impl PendingCommit {
+    fn path(config: &SystemConfig, transaction_id: u64) -> String {
+        format!("{}/{transaction_id}", config.get_state_transactions_path())
+    }
+
+    pub async fn persist(&self, config: &SystemConfig) -> Result<(), IggyError> {
+        let bytes = rmp_serde::to_vec(self).map_err(|_| IggyError::CannotSerializeResource)?;
+        FileWithSyncPersister
+            .overwrite(&Self::path(config, self.transaction_id), bytes)
+            .await
+    }
+
+    pub async fn remove(config: &SystemConfig, transaction_id: u64) -> Result<(), IggyError> {
+        FileWithSyncPersister
+            .delete(&Self::path(config, transaction_id))
+            .await
+    }
+}
+
+/// Applies the commit decisions left behind by a server which stopped in the middle of a commit.
+/// Must be invoked before the partitions are loaded, as they abort all the transactions still open.
+pub async fn recover_pending_commits(config: &SystemConfig) -> Result<usize, IggyError> {
+    let path = config.get_state_transactions_path();
+    if !Path::new(&path).exists() {
+        create_dir_all(&path).await.map_err(|e| {
+            error!("Cannot create transactions directory: {path}, error: {e}");
+            IggyError::CannotCreateBaseDirectory(path.clone())
+        })?;
+        return Ok(0);
+    }
+
+    let entries = std::fs::read_dir(&path).map_err(|e| {
+        error!("Cannot read transactions directory: {path}, error: {e}");
+        IggyError::CannotReadFile
+    })?;
+
+    let mut recovered = 0;
+    for entry in entries.flatten() {
+        let file_path = entry.path().to_string_lossy().to_string();
+        let bytes = std::fs::read(&file_path).map_err(|e| {
+            error!("Cannot read transaction commit file: {file_path}, error: {e}");
+            IggyError::CannotReadFile
+        })?;
+        let commit: PendingCommit = match rmp_serde::from_slice(&bytes) {
+            Ok(commit) => commit,
+            Err(e) => {
+                // The decision was not fully written, so none of the partitions was completed.
+                warn!("Discarding incomplete transaction commit file: {file_path}, error: {e}");
+                FileWithSyncPersister.delete(&file_path).await?;
+                continue;
+            }
+        };
+
+        for &(stream_id, topic_id, partition_id) in &commit.partitions {
+            let transactions_path =
+                config.get_partition_transactions_path(stream_id, topic_id, partition_id);
+            if !Path::new(&transactions_path).exists() {
+                continue;
+            }
+            let mut transactions = PartitionTransactions::load(&transactions_path)?;
+            if transactions.complete(commit.transaction_id, true, None) {
+                transactions.persist(&transactions_path).await?;
+            }
+        }
+
+        for offset in &commit.offsets {
+            let path = offset.path(config);
+            if Path::new(&path).parent().is_some_and(|dir| dir.exists()) {
+                persist_offset(&path, offset.offset).await?;
+            }
+        }
+
+        FileWithSyncPersister.delete(&file_path).await?;
+        info!(
+            "Recovered commit of transaction with ID: {}",
+            commit.transaction_id
+        );
+        recovered += 1;
+    }
+    Ok(recovered)
+}
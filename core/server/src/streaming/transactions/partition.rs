@@ -0,0 +1,381 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::persistence::persister::FileWithSyncPersister;
+use iggy_common::{IggyError, IsolationLevel};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::error;
+
+/// Transactional context of an append handled by the message pump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionAppend {
+    /// Messages produced within the open transaction.
+    Messages(u64),
+    /// Control marker completing the transaction.
+    Marker {
+        transaction_id: u64,
+        committed: bool,
+    },
+}
+
+/// Transactional state of a single partition, persisted next to its segments.
+///
+/// Offsets written by open transactions stay invisible to `read_committed` consumers until the
+/// transaction completes. Ranges of aborted transactions remain hidden from them for good, while
+/// the control markers appended on completion are never returned to any consumer.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartitionTransactions {
+    open: BTreeMap<u64, Vec<(u64, u64)>>,
+    aborted: Vec<(u64, u64)>,
+    markers: Vec<u64>,
+    /// Sorted offsets hidden from the consumers, shared with the polls instead of being
+    /// collected on every one of them.
+    #[serde(skip)]
+    hidden: HiddenOffsets,
+}
+
+/// Sorted, non-overlapping, inclusive ranges of the hidden offsets for each isolation level.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct HiddenOffsets {
+    read_committed: Arc<Vec<(u64, u64)>>,
+    read_uncommitted: Arc<Vec<(u64, u64)>>,
+}
+
+/// Snapshot of the offsets hidden from a consumer, taken before polling a partition.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TransactionVisibility {
+    /// First offset which must not be returned, if any transaction is still open.
+    pub stable_offset: Option<u64>,
+    /// Sorted, non-overlapping, inclusive ranges of offsets which must be skipped.
+    hidden: Arc<Vec<(u64, u64)>>,
+    /// First offset to be polled.
+    from: u64,
+}
+
+impl TransactionVisibility {
+    pub fn is_hidden(&self, offset: u64) -> bool {
+        let index = self.hidden.partition_point(|(_, last)| *last < offset);
+        self.hidden
+            .get(index)
+            .is_some_and(|(first, _)| *first <= offset)
+    }
+
+    pub fn is_unrestricted(&self) -> bool {
+        self.stable_offset.is_none() && self.hidden.last().is_none_or(|(_, last)| *last < self.from)
+    }
+}
+
+/// Inserts the range keeping the ranges sorted, as the offsets of different transactions
+/// never overlap.
+fn insert_range(ranges: &mut Vec<(u64, u64)>, range: (u64, u64)) {
+    let index = ranges.partition_point(|existing| *existing < range);
+    ranges.insert(index, range);
+}
+
+/// Drops the sorted ranges ending below the offset.
+fn prune_ranges(ranges: &mut Vec<(u64, u64)>, first_offset: u64) {
+    let count = ranges.partition_point(|(_, last)| *last < first_offset);
+    ranges.drain(..count);
+}
+
+impl PartitionTransactions {
+    pub fn is_empty(&self) -> bool {
+        self.open.is_empty() && self.aborted.is_empty() && self.markers.is_empty()
+    }
+
+    pub fn is_open(&self, transaction_id: u64) -> bool {
+        self.open.contains_key(&transaction_id)
+    }
+
+    pub fn open_transactions(&self) -> impl Iterator<Item = u64> + '_ {
+        self.open.keys().copied()
+    }
+
+    /// Records the inclusive range of offsets appended by the transaction.
+    pub fn add_range(&mut self, transaction_id: u64, first: u64, last: u64) {
+        let ranges = self.open.entry(transaction_id).or_default();
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == first => *end = last,
+            _ => ranges.push((first, last)),
+        }
+    }
+
+    /// Completes the transaction, returns `false` if it was not open on this partition.
+    /// The marker offset is `None` only when recovering, as no control message is appended then.
+    pub fn complete(&mut self, transaction_id: u64, committed: bool, marker: Option<u64>) -> bool {
+        let Some(ranges) = self.open.remove(&transaction_id) else {
+            return false;
+        };
+
+        if !committed {
+            let read_committed = Arc::make_mut(&mut self.hidden.read_committed);
+            for range in ranges {
+                insert_range(&mut self.aborted, range);
+                insert_range(read_committed, range);
+            }
+        }
+        if let Some(marker) = marker {
+            let index = self.markers.partition_point(|existing| *existing < marker);
+            self.markers.insert(index, marker);
+            insert_range(
+                Arc::make_mut(&mut self.hidden.read_committed),
+                (marker, marker),
+            );
+            insert_range(
+                Arc::make_mut(&mut self.hidden.read_uncommitted),
+                (marker, marker),
+            );
+        }
+        true
+    }
+
+    /// Aborts all the transactions which are still open, used when loading the partition,
+    /// as the producers which began them are gone after the restart.
+    pub fn abort_open(&mut self) -> usize {
+        let open = std::mem::take(&mut self.open);
+        let count = open.len();
+        for (_, ranges) in open {
+            self.aborted.extend(ranges);
+        }
+        self.aborted.sort_unstable();
+        self.refresh_hidden();
+        count
+    }
+
+    /// Drops the state of offsets which were never persisted in the partition log.
+    pub fn clamp(&mut self, current_offset: Option<u64>) {
+        let Some(current_offset) = current_offset else {
+            *self = Self::default();
+            return;
+        };
+
+        let clamp_ranges = |ranges: &mut Vec<(u64, u64)>| {
+            ranges.retain(|(first, _)| *first <= current_offset);
+            for (_, last) in ranges.iter_mut() {
+                *last = (*last).min(current_offset);
+            }
+        };
+        for ranges in self.open.values_mut() {
+            clamp_ranges(ranges);
+        }
+        self.open.retain(|_, ranges| !ranges.is_empty());
+        clamp_ranges(&mut self.aborted);
+        self.markers.retain(|marker| *marker <= current_offset);
+        self.refresh_hidden();
+    }
+
+    /// Forgets completed ranges and markers below the first offset still stored in the partition.
+    pub fn prune(&mut self, first_offset: u64) {
+        prune_ranges(&mut self.aborted, first_offset);
+        let count = self
+            .markers
+            .partition_point(|marker| *marker < first_offset);
+        self.markers.drain(..count);
+        for hidden in [
+            &mut self.hidden.read_committed,
+            &mut self.hidden.read_uncommitted,
+        ] {
+            if hidden.first().is_some_and(|(_, last)| *last < first_offset) {
+                prune_ranges(Arc::make_mut(hidden), first_offset);
+            }
+        }
+    }
+
+    pub fn visibility(&self, isolation_level: IsolationLevel, from: u64) -> TransactionVisibility {
+        if isolation_level != IsolationLevel::ReadCommitted {
+            return TransactionVisibility {
+                stable_offset: None,
+                hidden: self.hidden.read_uncommitted.clone(),
+                from,
+            };
+        }
+
+        TransactionVisibility {
+            stable_offset: self
+                .open
+                .values()
+                .filter_map(|ranges| ranges.first().map(|(first, _)| *first))
+                .min(),
+            hidden: self.hidden.read_committed.clone(),
+            from,
+        }
+    }
+
+    /// Rebuilds the hidden offsets from the aborted ranges and the markers.
+    fn refresh_hidden(&mut self) {
+        let markers = self.markers.iter().map(|marker| (*marker, *marker));
+        let read_uncommitted: Vec<(u64, u64)> = markers.clone().collect();
+        let mut read_committed: Vec<(u64, u64)> =
+            self.aborted.iter().copied().chain(markers).collect();
+        read_committed.sort_unstable();
+        self.hidden = HiddenOffsets {
+            read_committed: Arc::new(read_committed),
+            read_uncommitted: Arc::new(read_uncommitted),
+        };
+    }
+
+    pub fn load(path: &str) -> Result<Self, IggyError> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                error!("Cannot read partition transactions file: {path}, error: {e}");
+                return Err(IggyError::CannotReadFile);
+            }
+        };
+        let mut transactions: Self = rmp_serde::from_slice(&bytes).map_err(|e| {
+            error!("Cannot deserialize partition transactions file: {path}, error: {e}");
+            IggyError::CannotDeserializeResource
+        })?;
+        transactions.aborted.sort_unstable();
+        transactions.markers.sort_unstable();
+        transactions.refresh_hidden();
+        Ok(transactions)
+    }
+
+    pub async fn persist(&self, path: &str) -> Result<(), IggyError> {
+        let bytes = rmp_serde::to_vec(self).map_err(|_| IggyError::CannotSerializeResource)?;
+        FileWithSyncPersister.overwrite(path, bytes).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_merge_contiguous_ranges_of_transaction() {
+        let mut transactions = PartitionTransactions::default();
+        transactions.add_range(1, 0, 4);
+        transactions.add_range(1, 5, 9);
+        transactions.add_range(1, 12, 13);
+
+        assert_eq!(transactions.open[&1], vec![(0, 9), (12, 13)]);
+    }
+
+    #[test]
+    fn read_committed_should_stop_at_first_open_transaction() {
+        let mut transactions = PartitionTransactions::default();
+        transactions.add_range(1, 10, 19);
+        transactions.add_range(2, 5, 7);
+
+        let visibility = transactions.visibility(IsolationLevel::ReadCommitted, 0);
+        assert_eq!(visibility.stable_offset, Some(5));
+
+        let visibility = transactions.visibility(IsolationLevel::ReadUncommitted, 0);
+        assert!(visibility.is_unrestricted());
+    }
+
+    #[test]
+    fn aborted_ranges_should_be_hidden_only_for_read_committed() {
+        let mut transactions = PartitionTransactions::default();
+        transactions.add_range(1, 0, 4);
+        transactions.add_range(2, 5, 9);
+        assert!(transactions.complete(1, false, Some(10)));
+        assert!(transactions.complete(2, true, Some(11)));
+        assert!(!transactions.complete(2, true, Some(12)));
+
+        let visibility = transactions.visibility(IsolationLevel::ReadCommitted, 0);
+        assert_eq!(visibility.stable_offset, None);
+        assert!(visibility.is_hidden(3));
+        assert!(!visibility.is_hidden(7));
+        assert!(visibility.is_hidden(10));
+        assert!(visibility.is_hidden(11));
+
+        let visibility = transactions.visibility(IsolationLevel::ReadUncommitted, 0);
+        assert!(!visibility.is_hidden(3));
+        assert!(visibility.is_hidden(10));
+    }
+
+    #[test]
+    fn should_clamp_state_beyond_current_offset() {
+        let mut transactions = PartitionTransactions::default();
+        transactions.add_range(1, 0, 9);
+        transactions.add_range(2, 10, 19);
+        transactions.clamp(Some(5));
+
+        assert_eq!(transactions.open[&1], vec![(0, 5)]);
+        assert!(!transactions.is_open(2));
+
+        transactions.clamp(None);
+        assert!(transactions.is_empty());
+    }
+
+    #[test]
+    fn should_abort_open_transactions_and_prune_completed_state() {
+        let mut transactions = PartitionTransactions::default();
+        transactions.add_range(1, 0, 4);
+        transactions.add_range(2, 6, 8);
+        transactions.complete(1, true, Some(5));
+
+        assert_eq!(transactions.abort_open(), 1);
+        assert_eq!(transactions.aborted, vec![(6, 8)]);
+
+        transactions.prune(6);
+        assert!(transactions.markers.is_empty());
+        transactions.prune(9);
+        assert!(transactions.is_empty());
+    }
+
+    #[test]
+    fn hidden_offsets_should_be_kept_sorted_and_shared_between_polls() {
+        let mut transactions = PartitionTransactions::default();
+        transactions.add_range(1, 0, 1);
+        transactions.add_range(2, 2, 3);
+        transactions.add_range(3, 4, 5);
+        transactions.complete(3, false, Some(6));
+        transactions.complete(1, false, Some(7));
+        transactions.complete(2, true, Some(8));
+
+        let first = transactions.visibility(IsolationLevel::ReadCommitted, 0);
+        let second = transactions.visibility(IsolationLevel::ReadCommitted, 3);
+        assert!(Arc::ptr_eq(&first.hidden, &second.hidden));
+        assert_eq!(*first.hidden, vec![(0, 1), (4, 5), (6, 6), (7, 7), (8, 8)]);
+        let hidden: Vec<u64> = (0..10).filter(|offset| first.is_hidden(*offset)).collect();
+        assert_eq!(hidden, vec![0, 1, 4, 5, 6, 7, 8]);
+        assert!(
+            transactions
+                .visibility(IsolationLevel::ReadUncommitted, 9)
+                .is_unrestricted()
+        );
+
+        transactions.prune(5);
+        let visibility = transactions.visibility(IsolationLevel::ReadCommitted, 0);
+        assert_eq!(*visibility.hidden, vec![(4, 5), (6, 6), (7, 7), (8, 8)]);
+        assert_eq!(transactions.aborted, vec![(4, 5)]);
+    }
+
+    #[test]
+    fn hidden_offsets_should_be_restored_when_loading() {
+        let mut transactions = PartitionTransactions::default();
+        transactions.add_range(1, 0, 4);
+        transactions.complete(1, false, Some(5));
+        let bytes = rmp_serde::to_vec(&transactions).unwrap();
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("transactions");
+        std::fs::write(&path, bytes).unwrap();
+
+        let loaded = PartitionTransactions::load(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded, transactions);
+        let visibility = loaded.visibility(IsolationLevel::ReadCommitted, 0);
+        assert!(visibility.is_hidden(2));
+        assert!(visibility.is_hidden(5));
+    }
+}
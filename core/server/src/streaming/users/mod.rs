@@ -16,4 +16,6 @@
  * under the License.
  */
 
+pub mod quota_manager;
+pub mod token_authenticator;
 pub mod user;
@@ -0,0 +1,396 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::utils::ptr::EternalPtr;
+use dashmap::DashMap;
+use iggy_common::{Quota, UserId, UserQuotas};
+use std::time::{Duration, Instant};
+
+/// What a request counts against, besides the request rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaUsage {
+    /// Any request other than sending or polling messages.
+    Request,
+    /// A request sending the given number of bytes.
+    Produce(u64),
+    /// A request polling messages, the polled bytes are charged once known.
+    Fetch,
+}
+
+/// Enforces user quotas with token buckets.
+///
+/// The buckets of the `User` scope are shared by all connections of a user, on any shard,
+/// while the buckets of the `Client` scope are kept for each connection.
+/// Byte buckets may go into debt: a request is let through as long as the bucket isn't in debt,
+/// and then charged in full, so the following requests wait until the debt is paid off.
+pub struct QuotaManager {
+    users: EternalPtr<DashMap<UserId, QuotaBuckets>>,
+    clients: EternalPtr<DashMap<u32, QuotaBuckets>>,
+}
+
+impl QuotaManager {
+    pub fn new(
+        users: EternalPtr<DashMap<UserId, QuotaBuckets>>,
+        clients: EternalPtr<DashMap<u32, QuotaBuckets>>,
+    ) -> Self {
+        Self { users, clients }
+    }
+}
+
+impl Clone for QuotaManager {
+    fn clone(&self) -> Self {
+        Self {
+            users: self.users.clone(),
+            clients: self.clients.clone(),
+        }
+    }
+}
+
+impl QuotaManager {
+    /// Charges the request against the quotas, or returns how long the client has to wait
+    /// before sending it again. Nothing is charged when the request is throttled.
+    pub fn acquire(
+        &self,
+        quotas: &UserQuotas,
+        client_id: u32,
+        usage: QuotaUsage,
+    ) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut user_buckets = quotas
+            .user
+            .as_ref()
+            .map(|quota| Self::buckets(&self.users, quotas.user_id, quota));
+        let mut client_buckets = quotas
+            .client
+            .as_ref()
+            .map(|quota| Self::buckets(&self.clients, client_id, quota));
+
+        let throttle_time = user_buckets
+            .as_mut()
+            .map_or(Duration::ZERO, |buckets| buckets.throttle_time(usage, now))
+            .max(
+                client_buckets
+                    .as_mut()
+                    .map_or(Duration::ZERO, |buckets| buckets.throttle_time(usage, now)),
+            );
+        if !throttle_time.is_zero() {
+            return Err(throttle_time);
+        }
+
+        if let Some(buckets) = user_buckets.as_mut() {
+            buckets.consume(usage);
+        }
+        if let Some(buckets) = client_buckets.as_mut() {
+            buckets.consume(usage);
+        }
+        Ok(())
+    }
+
+    /// Charges the bytes returned by a poll against the fetch quotas.
+    pub fn record_fetch(&self, quotas: &UserQuotas, client_id: u32, bytes: u64) {
+        if let Some(quota) = quotas.user.as_ref() {
+            Self::buckets(&self.users, quotas.user_id, quota).consume_fetch(bytes);
+        }
+        if let Some(quota) = quotas.client.as_ref() {
+            Self::buckets(&self.clients, client_id, quota).consume_fetch(bytes);
+        }
+    }
+
+    pub fn delete_client(&self, client_id: u32) {
+        self.clients.remove(&client_id);
+    }
+
+    pub fn delete_user(&self, user_id: UserId) {
+        self.users.remove(&user_id);
+    }
+
+    fn buckets<'a, K: std::hash::Hash + Eq + Copy>(
+        map: &'a DashMap<K, QuotaBuckets>,
+        key: K,
+        quota: &Quota,
+    ) -> dashmap::mapref::one::RefMut<'a, K, QuotaBuckets> {
+        let mut buckets = map.entry(key).or_default();
+        buckets.sync(quota);
+        buckets
+    }
+}
+
+/// Token buckets matching the limits of a single quota.
+#[derive(Debug, Default)]
+pub struct QuotaBuckets {
+    produce: Option<TokenBucket>,
+    fetch: Option<TokenBucket>,
+    requests: Option<TokenBucket>,
+}
+
+impl QuotaBuckets {
+    /// Recreates the buckets whose limits were changed since they were created.
+    fn sync(&mut self, quota: &Quota) {
+        sync_bucket(
+            &mut self.produce,
+            quota
+                .produce_bytes_per_second
+                .map(|size| size.as_bytes_u64()),
+        );
+        sync_bucket(
+            &mut self.fetch,
+            quota.fetch_bytes_per_second.map(|size| size.as_bytes_u64()),
+        );
+        sync_bucket(&mut self.requests, quota.requests_per_second.map(u64::from));
+    }
+
+    fn throttle_time(&mut self, usage: QuotaUsage, now: Instant) -> Duration {
+        let bytes_bucket = match usage {
+            QuotaUsage::Request => None,
+            QuotaUsage::Produce(_) => self.produce.as_mut(),
+            QuotaUsage::Fetch => self.fetch.as_mut(),
+        };
+        let bytes_throttle_time =
+            bytes_bucket.map_or(Duration::ZERO, |bucket| bucket.throttle_time(0.0, now));
+        let request_throttle_time = self
+            .requests
+            .as_mut()
+            .map_or(Duration::ZERO, |bucket| bucket.throttle_time(1.0, now));
+        bytes_throttle_time.max(request_throttle_time)
+    }
+
+    fn consume(&mut self, usage: QuotaUsage) {
+        if let Some(bucket) = self.requests.as_mut() {
+            bucket.consume(1.0);
+        }
+        if let QuotaUsage::Produce(bytes) = usage
+            && let Some(bucket) = self.produce.as_mut()
+        {
+            bucket.consume(bytes as f64);
+        }
+    }
+
+    fn consume_fetch(&mut self, bytes: u64) {
+        if let Some(bucket) = self.fetch.as_mut() {
+            bucket.consume(bytes as f64);
+        }
+    }
+}
+
+fn sync_bucket(bucket: &mut Option<TokenBucket>, rate_per_second: Option<u64>) {
+    match rate_per_second {
+        None => *bucket = None,
+        Some(rate)
+            if bucket
+                .as_ref()
+                .is_some_and(|bucket| bucket.rate_per_second == rate) => {}
+        Some(rate) => *bucket = Some(TokenBucket::new(rate, Instant::now())),
+    }
+}
+
+/// Token bucket refilled at a constant rate, holding up to one second worth of tokens.
+#[derive(Debug)]
+pub struct TokenBucket {
+    rate_per_second: u64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(rate_per_second: u64, now: Instant) -> Self {
+        Self {
+            rate_per_second,
+            tokens: rate_per_second as f64,
+            updated_at: now,
+        }
+    }
+
+    /// Returns how long it takes until the bucket holds the required tokens, zero if it already does.
+    pub fn throttle_time(&mut self, required: f64, now: Instant) -> Duration {
+        self.refill(now);
+        if self.tokens >= required {
+            return Duration::ZERO;
+        }
+
+        let missing = required - self.tokens;
+        Duration::from_secs_f64(missing / self.rate_per_second as f64).max(Duration::from_millis(1))
+    }
+
+    /// Takes the tokens out of the bucket, going into debt if there are not enough of them.
+    pub fn consume(&mut self, tokens: f64) {
+        self.tokens -= tokens;
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        let capacity = self.rate_per_second as f64;
+        self.tokens = (self.tokens + elapsed * capacity).min(capacity);
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::IggyByteSize;
+
+    #[test]
+    fn bucket_should_allow_burst_up_to_rate_and_then_throttle() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(10, now);
+        for _ in 0..10 {
+            assert!(bucket.throttle_time(1.0, now).is_zero());
+            bucket.consume(1.0);
+        }
+
+        assert_eq!(bucket.throttle_time(1.0, now), Duration::from_millis(100));
+        assert!(
+            bucket
+                .throttle_time(1.0, now + Duration::from_millis(100))
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn bucket_in_debt_should_report_time_to_pay_it_off() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(1000, now);
+        assert!(bucket.throttle_time(0.0, now).is_zero());
+        bucket.consume(3000.0);
+
+        assert_eq!(bucket.throttle_time(0.0, now), Duration::from_secs(2));
+        assert!(
+            bucket
+                .throttle_time(0.0, now + Duration::from_secs(2))
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn bucket_should_not_refill_above_capacity() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(5, now);
+        bucket.throttle_time(1.0, now + Duration::from_secs(60));
+        for _ in 0..5 {
+            bucket.consume(1.0);
+        }
+        assert!(
+            !bucket
+                .throttle_time(1.0, now + Duration::from_secs(60))
+                .is_zero()
+        );
+    }
+
+    fn manager() -> QuotaManager {
+        let users: &'static DashMap<UserId, QuotaBuckets> = Box::leak(Box::default());
+        let clients: &'static DashMap<u32, QuotaBuckets> = Box::leak(Box::default());
+        QuotaManager::new(users.into(), clients.into())
+    }
+
+    #[test]
+    fn user_quota_should_be_shared_by_clients() {
+        let manager = manager();
+        let quotas = UserQuotas {
+            user_id: 1,
+            user: Some(Quota {
+                requests_per_second: Some(2),
+                ..Default::default()
+            }),
+            client: None,
+        };
+
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Request).is_ok());
+        assert!(manager.acquire(&quotas, 20, QuotaUsage::Request).is_ok());
+        assert!(manager.acquire(&quotas, 30, QuotaUsage::Request).is_err());
+    }
+
+    #[test]
+    fn client_quota_should_apply_to_each_client() {
+        let manager = manager();
+        let quotas = UserQuotas {
+            user_id: 1,
+            user: None,
+            client: Some(Quota {
+                requests_per_second: Some(1),
+                ..Default::default()
+            }),
+        };
+
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Request).is_ok());
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Request).is_err());
+        assert!(manager.acquire(&quotas, 20, QuotaUsage::Request).is_ok());
+    }
+
+    #[test]
+    fn produce_debt_should_throttle_only_sends() {
+        let manager = manager();
+        let quotas = UserQuotas {
+            user_id: 1,
+            user: Some(Quota {
+                produce_bytes_per_second: Some(IggyByteSize::from(1000)),
+                ..Default::default()
+            }),
+            client: None,
+        };
+
+        assert!(
+            manager
+                .acquire(&quotas, 10, QuotaUsage::Produce(5000))
+                .is_ok()
+        );
+        let throttle_time = manager
+            .acquire(&quotas, 10, QuotaUsage::Produce(1))
+            .unwrap_err();
+        assert!(throttle_time > Duration::from_secs(3));
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Fetch).is_ok());
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Request).is_ok());
+    }
+
+    #[test]
+    fn fetched_bytes_should_throttle_next_poll() {
+        let manager = manager();
+        let quotas = UserQuotas {
+            user_id: 1,
+            user: None,
+            client: Some(Quota {
+                fetch_bytes_per_second: Some(IggyByteSize::from(100)),
+                ..Default::default()
+            }),
+        };
+
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Fetch).is_ok());
+        manager.record_fetch(&quotas, 10, 1000);
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Fetch).is_err());
+    }
+
+    #[test]
+    fn changed_limit_should_reset_bucket() {
+        let manager = manager();
+        let mut quotas = UserQuotas {
+            user_id: 1,
+            user: Some(Quota {
+                requests_per_second: Some(1),
+                ..Default::default()
+            }),
+            client: None,
+        };
+
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Request).is_ok());
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Request).is_err());
+        quotas.user = Some(Quota {
+            requests_per_second: Some(100),
+            ..Default::default()
+        });
+        assert!(manager.acquire(&quotas, 10, QuotaUsage::Request).is_ok());
+    }
+}
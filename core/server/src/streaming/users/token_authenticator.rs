@@ -0,0 +1,272 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::http::{HttpJwtConfig, TrustedIssuerConfig};
+use crate::http::jwt::jwks::JwksClient;
+use crate::http::jwt::jwt_manager::normalize_issuer_url;
+use iggy_common::{GlobalPermissions, IggyError, Permissions, UserId};
+use jsonwebtoken::{TokenData, Validation};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use tracing::{debug, warn};
+
+type Claims = Map<String, Value>;
+
+#[derive(Debug, Deserialize)]
+struct IssuerClaim {
+    iss: String,
+}
+
+/// The user an access token is mapped to.
+#[derive(Debug, PartialEq)]
+pub enum TokenPrincipal {
+    /// The trusted issuer maps all of its tokens to the single user.
+    UserId(UserId),
+    /// The username is taken from the token claims, the user might be created on the first login.
+    Username {
+        username: String,
+        auto_provision: bool,
+        permissions: Option<Permissions>,
+    },
+}
+
+/// Validates the OAuth2/OIDC access tokens of the binary token login against the trusted
+/// issuers from `http.jwt.trusted_issuers`, fetching their signing keys from the JWKS endpoints.
+pub struct TokenAuthenticator {
+    jwks_client: JwksClient,
+    trusted_issuers: HashMap<String, TrustedIssuerConfig>,
+}
+
+impl TokenAuthenticator {
+    pub fn new(config: &HttpJwtConfig) -> Self {
+        let trusted_issuers = config
+            .trusted_issuers
+            .iter()
+            .flatten()
+            .map(|issuer| (normalize_issuer_url(&issuer.issuer), issuer.clone()))
+            .collect();
+        Self {
+            jwks_client: JwksClient::default(),
+            trusted_issuers,
+        }
+    }
+
+    pub async fn authenticate(&self, token: &str) -> Result<TokenPrincipal, IggyError> {
+        let header = jsonwebtoken::decode_header(token).map_err(|error| {
+            debug!("Failed to decode the access token header. {error}");
+            IggyError::InvalidAccessToken
+        })?;
+        let Some(kid) = header.kid.as_deref() else {
+            warn!("Access token has no key ID.");
+            return Err(IggyError::InvalidAccessToken);
+        };
+        let issuer = jsonwebtoken::dangerous::insecure_decode::<IssuerClaim>(token)
+            .map_err(|_| IggyError::InvalidAccessToken)?
+            .claims
+            .iss;
+        let Some(config) = self.trusted_issuers.get(&normalize_issuer_url(&issuer)) else {
+            warn!("Access token issuer: {issuer} is not trusted.");
+            return Err(IggyError::Unauthenticated);
+        };
+
+        let Some(key) = self
+            .jwks_client
+            .get_key(&config.issuer, &config.jwks_url, kid)
+            .await
+        else {
+            warn!(
+                "Failed to get the signing key: {kid} of issuer: {} from JWKS.",
+                config.issuer
+            );
+            return Err(IggyError::Unauthenticated);
+        };
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(std::slice::from_ref(&config.issuer));
+        validation.set_audience(std::slice::from_ref(&config.audience));
+        let TokenData { claims, .. } = jsonwebtoken::decode::<Claims>(token, &key, &validation)
+            .map_err(|error| {
+                warn!("Invalid access token of issuer: {}. {error}", config.issuer);
+                IggyError::Unauthenticated
+            })?;
+
+        map_claims(config, &claims)
+    }
+}
+
+fn map_claims(config: &TrustedIssuerConfig, claims: &Claims) -> Result<TokenPrincipal, IggyError> {
+    if config.user_id != 0 {
+        return Ok(TokenPrincipal::UserId(config.user_id));
+    }
+
+    let Some(username_claim) = config.username_claim.as_deref() else {
+        warn!(
+            "Trusted issuer: {} maps neither to a user ID nor a username claim.",
+            config.issuer
+        );
+        return Err(IggyError::Unauthenticated);
+    };
+    let Some(username) = claims.get(username_claim).and_then(Value::as_str) else {
+        warn!(
+            "Access token of issuer: {} has no: {username_claim} claim.",
+            config.issuer
+        );
+        return Err(IggyError::InvalidAccessToken);
+    };
+
+    let permissions = config
+        .permissions_claim
+        .as_deref()
+        .and_then(|claim| claims.get(claim))
+        .and_then(permissions_from_claim);
+    Ok(TokenPrincipal::Username {
+        username: username.to_owned(),
+        auto_provision: config.auto_provision,
+        permissions,
+    })
+}
+
+fn permissions_from_claim(claim: &Value) -> Option<Permissions> {
+    let names: Vec<&str> = match claim {
+        Value::String(names) => names.split_whitespace().collect(),
+        Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+        _ => return None,
+    };
+
+    let mut global = GlobalPermissions::default();
+    for name in names {
+        let permission = match name {
+            "manage_servers" => &mut global.manage_servers,
+            "read_servers" => &mut global.read_servers,
+            "manage_users" => &mut global.manage_users,
+            "read_users" => &mut global.read_users,
+            "manage_streams" => &mut global.manage_streams,
+            "read_streams" => &mut global.read_streams,
+            "manage_topics" => &mut global.manage_topics,
+            "read_topics" => &mut global.read_topics,
+            "poll_messages" => &mut global.poll_messages,
+            "send_messages" => &mut global.send_messages,
+            _ => continue,
+        };
+        *permission = true;
+    }
+
+    if global == GlobalPermissions::default() {
+        return None;
+    }
+
+    Some(Permissions {
+        global,
+        streams: None,
+        stream_patterns: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issuer_config(user_id: u32) -> TrustedIssuerConfig {
+        TrustedIssuerConfig {
+            issuer: "https://issuer.example.com".to_string(),
+            audience: "iggy".to_string(),
+            jwks_url: "https://issuer.example.com/.well-known/jwks.json".to_string(),
+            user_id,
+            username_claim: Some("preferred_username".to_string()),
+            auto_provision: true,
+            permissions_claim: Some("roles".to_string()),
+        }
+    }
+
+    fn claims(value: Value) -> Claims {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn should_map_token_to_configured_user_id() {
+        let principal = map_claims(
+            &issuer_config(5),
+            &claims(json!({"preferred_username": "alice"})),
+        )
+        .unwrap();
+
+        assert_eq!(principal, TokenPrincipal::UserId(5));
+    }
+
+    #[test]
+    fn should_map_token_to_username_and_permissions_from_claims() {
+        let principal = map_claims(
+            &issuer_config(0),
+            &claims(json!({
+                "preferred_username": "alice",
+                "roles": ["read_streams", "poll_messages", "unknown"]
+            })),
+        )
+        .unwrap();
+
+        let TokenPrincipal::Username {
+            username,
+            auto_provision,
+            permissions,
+        } = principal
+        else {
+            panic!("expected username principal");
+        };
+        assert_eq!(username, "alice");
+        assert!(auto_provision);
+        let global = permissions.unwrap().global;
+        assert!(global.read_streams);
+        assert!(global.poll_messages);
+        assert!(!global.send_messages);
+        assert!(!global.manage_servers);
+    }
+
+    #[test]
+    fn should_parse_space_separated_permissions() {
+        let permissions = permissions_from_claim(&json!("openid send_messages read_topics"));
+
+        let global = permissions.unwrap().global;
+        assert!(global.send_messages);
+        assert!(global.read_topics);
+        assert!(!global.read_streams);
+    }
+
+    #[test]
+    fn should_not_grant_permissions_without_known_names() {
+        assert!(permissions_from_claim(&json!("openid profile")).is_none());
+        assert!(permissions_from_claim(&json!(42)).is_none());
+    }
+
+    #[test]
+    fn should_reject_token_without_username_claim() {
+        let result = map_claims(&issuer_config(0), &claims(json!({"sub": "alice"})));
+
+        assert!(matches!(result, Err(IggyError::InvalidAccessToken)));
+    }
+
+    #[test]
+    fn should_reject_issuer_without_user_mapping() {
+        let mut config = issuer_config(0);
+        config.username_claim = None;
+
+        let result = map_claims(&config, &claims(json!({"preferred_username": "alice"})));
+
+        assert!(matches!(result, Err(IggyError::Unauthenticated)));
+    }
+}
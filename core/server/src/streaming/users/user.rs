@@ -21,6 +21,7 @@ use iggy_common::IggyTimestamp;
 use iggy_common::PersonalAccessToken;
 use iggy_common::UserStatus;
 use iggy_common::defaults::*;
+use iggy_common::scram::ScramCredentials;
 use iggy_common::{Permissions, UserId};
 use std::sync::Arc;
 
@@ -30,6 +31,7 @@ pub struct User {
     pub status: UserStatus,
     pub username: String,
     pub password: String,
+    pub scram_credentials: Option<ScramCredentials>,
     pub created_at: IggyTimestamp,
     pub permissions: Option<Permissions>,
     pub personal_access_tokens: DashMap<Arc<str>, PersonalAccessToken>,
@@ -42,6 +44,7 @@ impl Default for User {
             status: UserStatus::Active,
             username: "user".to_string(),
             password: "secret".to_string(),
+            scram_credentials: None,
             created_at: IggyTimestamp::now(),
             permissions: None,
             personal_access_tokens: DashMap::new(),
@@ -64,13 +67,16 @@ impl User {
         status: UserStatus,
         permissions: Option<Permissions>,
     ) -> Self {
-        Self::with_password(
-            id,
-            username,
-            crypto::hash_password(password),
-            status,
-            permissions,
-        )
+        Self {
+            scram_credentials: Some(ScramCredentials::new(password)),
+            ..Self::with_password(
+                id,
+                username,
+                crypto::hash_password(password),
+                status,
+                permissions,
+            )
+        }
     }
 
     pub fn with_password(
@@ -84,6 +90,7 @@ impl User {
             id,
             username: username.into(),
             password,
+            scram_credentials: None,
             created_at: IggyTimestamp::now(),
             status,
             permissions,
@@ -124,6 +131,7 @@ mod tests {
             DEFAULT_ROOT_PASSWORD,
             &user.password
         ));
+        assert!(user.scram_credentials.is_some());
         assert_eq!(user.status, UserStatus::Active);
         assert!(user.created_at.as_micros() > 0);
     }
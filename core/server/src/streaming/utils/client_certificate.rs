@@ -0,0 +1,199 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::audit::AuditEvent;
+use crate::configs::tls::{ClientCertificatePrincipal, TlsClientAuthConfig};
+use crate::shard::IggyShard;
+use crate::streaming::session::Session;
+use iggy_common::IggyError;
+use rustls::RootCertStore;
+use rustls::pki_types::CertificateDer;
+use rustls::server::WebPkiClientVerifier;
+use rustls::server::danger::ClientCertVerifier;
+use rustls_pemfile::certs;
+use std::io::BufReader;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+use x509_parser::extensions::GeneralName;
+use x509_parser::parse_x509_certificate;
+
+/// The audit log operation of the login with the client certificate, which has no command code.
+pub const CERTIFICATE_LOGIN_OPERATION: &str = "user.login_with_certificate";
+
+/// Builds the client certificate verifier for a TLS listener. When client
+/// authentication is disabled, clients are not asked for a certificate.
+pub fn client_cert_verifier(
+    config: &TlsClientAuthConfig,
+) -> Result<Arc<dyn ClientCertVerifier>, Box<dyn std::error::Error>> {
+    if !config.enabled {
+        return Ok(WebPkiClientVerifier::no_client_auth());
+    }
+
+    let ca_file = std::fs::File::open(&config.ca_file)?;
+    let mut ca_reader = BufReader::new(ca_file);
+    let mut roots = RootCertStore::empty();
+    for cert in certs(&mut ca_reader) {
+        roots.add(cert?)?;
+    }
+
+    if roots.is_empty() {
+        return Err("No CA certificates found in client auth CA file".into());
+    }
+
+    let builder = WebPkiClientVerifier::builder(Arc::new(roots));
+    let verifier = if config.required {
+        builder.build()?
+    } else {
+        builder.allow_unauthenticated().build()?
+    };
+    Ok(verifier)
+}
+
+/// Extracts the username from a DER-encoded client certificate.
+pub fn certificate_principal(
+    certificate: &CertificateDer<'_>,
+    principal: ClientCertificatePrincipal,
+) -> Option<String> {
+    let (_, certificate) = parse_x509_certificate(certificate.as_ref()).ok()?;
+    match principal {
+        ClientCertificatePrincipal::CommonName => certificate
+            .subject()
+            .iter_common_name()
+            .next()
+            .and_then(|common_name| common_name.as_str().ok())
+            .map(ToOwned::to_owned),
+        ClientCertificatePrincipal::SubjectAltName => certificate
+            .subject_alternative_name()
+            .ok()
+            .flatten()?
+            .value
+            .general_names
+            .iter()
+            .find_map(|name| match name {
+                GeneralName::DNSName(name)
+                | GeneralName::RFC822Name(name)
+                | GeneralName::URI(name) => Some((*name).to_owned()),
+                _ => None,
+            }),
+    }
+}
+
+/// Authenticates the session as the user identified by the verified client
+/// certificate. Connections without a certificate are left unauthenticated,
+/// so the client can still log in with credentials or a token.
+pub fn authenticate_client_certificate(
+    shard: &IggyShard,
+    session: &Session,
+    config: &TlsClientAuthConfig,
+    peer_certificates: Option<&[CertificateDer<'_>]>,
+) -> Result<(), IggyError> {
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let Some(certificate) = peer_certificates.and_then(|certificates| certificates.first()) else {
+        debug!("No client certificate presented for session: {session}");
+        return Err(IggyError::Unauthenticated);
+    };
+
+    let username = certificate_principal(certificate, config.principal);
+    let result = match &username {
+        None => {
+            warn!(
+                "Client certificate for session: {session} has no {} to map to a user.",
+                config.principal
+            );
+            Err(IggyError::InvalidCredentials)
+        }
+        Some(username) => match shard.login_user_with_credentials(username, None, Some(session)) {
+            Ok(user) => {
+                info!(
+                    "Authenticated user: {} with ID: {} using client certificate for session: {session}",
+                    user.username, user.id
+                );
+                Ok(user.id)
+            }
+            Err(error) => {
+                warn!(
+                    "Client certificate principal: {username} could not be mapped to a user for session: {session}. {error}"
+                );
+                Err(error)
+            }
+        },
+    };
+
+    shard.audit_log.record(AuditEvent {
+        user_id: result.as_ref().ok().copied(),
+        username,
+        client_id: (session.client_id > 0).then_some(session.client_id),
+        address: Some(session.ip_address.to_string()),
+        ..AuditEvent::new(CERTIFICATE_LOGIN_OPERATION, result.as_ref().map(|_| ()))
+    });
+    result.map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_certificate() -> CertificateDer<'static> {
+        let mut reader = BufReader::new(&include_bytes!("../../../../certs/iggy_cert.pem")[..]);
+        certs(&mut reader).next().unwrap().unwrap()
+    }
+
+    #[test]
+    fn should_extract_common_name_as_principal() {
+        let principal = certificate_principal(
+            &server_certificate(),
+            ClientCertificatePrincipal::CommonName,
+        );
+
+        assert_eq!(principal.as_deref(), Some("localhost"));
+    }
+
+    #[test]
+    fn should_extract_subject_alt_name_as_principal() {
+        let principal = certificate_principal(
+            &server_certificate(),
+            ClientCertificatePrincipal::SubjectAltName,
+        );
+
+        assert_eq!(principal.as_deref(), Some("localhost"));
+    }
+
+    #[test]
+    fn should_not_extract_principal_from_invalid_certificate() {
+        let certificate = CertificateDer::from(vec![1, 2, 3]);
+
+        assert!(
+            certificate_principal(&certificate, ClientCertificatePrincipal::CommonName).is_none()
+        );
+    }
+
+    #[test]
+    fn should_not_require_ca_file_when_client_auth_is_disabled() {
+        let config = TlsClientAuthConfig {
+            enabled: false,
+            required: false,
+            ca_file: String::new(),
+            principal: ClientCertificatePrincipal::CommonName,
+        };
+
+        assert!(client_cert_verifier(&config).is_ok());
+    }
+}
@@ -17,7 +17,9 @@
  */
 
 pub mod address;
+pub mod client_certificate;
 pub mod crypto;
 pub mod file;
 pub mod ptr;
+pub mod timestamp_id;
 pub use iggy_common::random_id;
@@ -130,6 +130,12 @@ pub(crate) async fn handle_connection(
                     );
                     sender.send_error_response(error).await?;
                     debug!("TCP error response was sent to: {session}.");
+                } else if matches!(error, IggyError::Throttled(_)) {
+                    debug!(
+                        "Command with code {code} ({cmd_name}) was throttled, session: {session}, error: {error}."
+                    );
+                    sender.send_error_response(error).await?;
+                    debug!("TCP error response was sent to: {session}.");
                 } else {
                     error!(
                         "Command with code {code} ({cmd_name}) was not handled successfully, session: {session}, error: {error}."
@@ -20,11 +20,16 @@ use crate::configs::tcp::TcpSocketConfig;
 use crate::shard::IggyShard;
 use crate::shard::task_registry::ShutdownToken;
 use crate::shard::transmission::event::ShardEvent;
+use crate::streaming::utils::client_certificate::{
+    authenticate_client_certificate, client_cert_verifier,
+};
 use crate::tcp::connection_handler::{handle_connection, handle_error};
+use compio::io::compat::AsyncStream;
 use compio::net::{SocketOpts, TcpListener};
-use compio::tls::TlsAcceptor;
+use compio::tls::TlsStream;
 use err_trail::ErrContext;
 use futures::FutureExt;
+use futures_rustls::TlsAcceptor;
 use iggy_common::{IggyError, SenderKind, TransportProtocol};
 use rustls::ServerConfig;
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
@@ -109,8 +114,11 @@ pub(crate) async fn start(
                 .unwrap_or_else(|e| panic!("Failed to load certificates: {e}"))
         };
 
+    let client_cert_verifier = client_cert_verifier(&tls_config.client_auth)
+        .unwrap_or_else(|e| panic!("Failed to load client auth CA certificates: {e}"));
+
     let server_config = ServerConfig::builder()
-        .with_no_client_auth()
+        .with_client_cert_verifier(client_cert_verifier)
         .with_single_cert(certs, key)
         .unwrap_or_else(|e| panic!("Unable to create TLS server config: {e}"));
 
@@ -182,14 +190,25 @@ async fn accept_loop(
                         let registry = shard.task_registry.clone();
                         let registry_clone = registry.clone();
                         registry.spawn_connection(async move {
-                            match acceptor.accept(stream).await {
+                            match acceptor.accept(AsyncStream::new(stream)).await {
                                 Ok(tls_stream) => {
                                     // TLS handshake successful, now create session
                                     info!("TLS handshake successful, adding TCP client: {}", address);
+                                    let peer_certificates = tls_stream.get_ref().1.peer_certificates().map(<[_]>::to_vec);
+                                    let tls_stream = TlsStream::from(tls_stream);
                                     let transport = TransportProtocol::Tcp;
                                     let session = shard_clone.add_client(&address, transport);
                                     info!("Added {} client with session: {} for IP address: {}", transport, session, address);
 
+                                    let client_auth = &shard_clone.config.tcp.tls.client_auth;
+                                    if let Err(error) = authenticate_client_certificate(&shard_clone, &session, client_auth, peer_certificates.as_deref())
+                                        && client_auth.required
+                                    {
+                                        warn!("Rejecting TCP TLS client: {} without a valid client certificate user. {}", address, error);
+                                        shard_clone.delete_client(session.client_id).await;
+                                        return;
+                                    }
+
                                     let client_id = session.client_id;
                                     info!("Created new session: {}", session);
 
@@ -118,7 +118,11 @@ pub(crate) async fn handle_connection(
                     return Err(ConnectionError::from(error));
                 }
                 _ => {
-                    error!("Command failed for session: {session}, error: {error}.");
+                    if matches!(error, IggyError::Throttled(_)) {
+                        debug!("Command throttled for session: {session}, error: {error}.");
+                    } else {
+                        error!("Command failed for session: {session}, error: {error}.");
+                    }
                     match sender.send_error_response(error).await {
                         Ok(_) => {
                             debug!("WebSocket error response was sent to: {session}.");
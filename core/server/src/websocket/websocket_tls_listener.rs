@@ -20,12 +20,17 @@ use crate::configs::websocket::WebSocketConfig;
 use crate::shard::IggyShard;
 use crate::shard::task_registry::ShutdownToken;
 use crate::shard::transmission::event::ShardEvent;
+use crate::streaming::utils::client_certificate::{
+    authenticate_client_certificate, client_cert_verifier,
+};
 use crate::websocket::connection_handler::{handle_connection, handle_error};
+use compio::io::compat::AsyncStream;
 use compio::net::{SocketOpts, TcpListener};
-use compio::tls::TlsAcceptor;
+use compio::tls::TlsStream;
 use compio::ws::accept_async_with_config;
 use err_trail::ErrContext;
 use futures::FutureExt;
+use futures_rustls::TlsAcceptor;
 use iggy_common::{IggyError, SenderKind, TransportProtocol, WebSocketTlsSender};
 use rustls::ServerConfig;
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
@@ -129,8 +134,11 @@ pub async fn start(
                 .unwrap_or_else(|e| panic!("Failed to load certificates: {e}"))
         };
 
+    let client_cert_verifier = client_cert_verifier(&tls_config.client_auth)
+        .unwrap_or_else(|e| panic!("Failed to load client auth CA certificates: {e}"));
+
     let server_config = ServerConfig::builder()
-        .with_no_client_auth()
+        .with_client_cert_verifier(client_cert_verifier)
         .with_single_cert(certs, key)
         .unwrap_or_else(|e| panic!("Unable to create TLS server config: {e}"));
 
@@ -190,9 +198,11 @@ async fn accept_loop(
                         let registry_clone = registry.clone();
 
                         registry.spawn_connection(async move {
-                            match acceptor.accept(tcp_stream).await {
+                            match acceptor.accept(AsyncStream::new(tcp_stream)).await {
                                 Ok(tls_stream) => {
                                     info!("TLS handshake successful for {}, performing WebSocket upgrade...", remote_addr);
+                                    let peer_certificates = tls_stream.get_ref().1.peer_certificates().map(<[_]>::to_vec);
+                                    let tls_stream = TlsStream::from(tls_stream);
 
                                     match accept_async_with_config(tls_stream, Some(ws_config_clone)).await {
                                         Ok(websocket) => {
@@ -201,6 +211,15 @@ async fn accept_loop(
                                             let session = shard_clone.add_client(&remote_addr, TransportProtocol::WebSocket);
                                             let client_id = session.client_id;
 
+                                            let client_auth = &shard_clone.config.websocket.tls.client_auth;
+                                            if let Err(error) = authenticate_client_certificate(&shard_clone, &session, client_auth, peer_certificates.as_deref())
+                                                && client_auth.required
+                                            {
+                                                warn!("Rejecting WebSocket TLS client: {} without a valid client certificate user. {}", remote_addr, error);
+                                                shard_clone.delete_client(client_id).await;
+                                                return;
+                                            }
+
                                             let sender = WebSocketTlsSender::new(websocket);
                                             let mut sender_kind = SenderKind::WebSocketTls(sender);
                                             let client_stop_receiver = registry_clone.add_connection(client_id);
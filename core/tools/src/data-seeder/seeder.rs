@@ -57,6 +57,7 @@ async fn create_topics(client: &IggyClient, streams: &[(String, u32)]) -> Result
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await?;
 
@@ -69,6 +70,7 @@ async fn create_topics(client: &IggyClient, streams: &[(String, u32)]) -> Result
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await?;
 
@@ -81,6 +83,7 @@ async fn create_topics(client: &IggyClient, streams: &[(String, u32)]) -> Result
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await?;
 
@@ -93,6 +96,7 @@ async fn create_topics(client: &IggyClient, streams: &[(String, u32)]) -> Result
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await?;
 
@@ -105,6 +109,7 @@ async fn create_topics(client: &IggyClient, streams: &[(String, u32)]) -> Result
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
             )
             .await?;
     }
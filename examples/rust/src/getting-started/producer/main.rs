@@ -79,6 +79,7 @@ async fn init_system(client: &IggyClient) -> (u32, u32) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await
     {
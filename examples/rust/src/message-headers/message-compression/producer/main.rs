@@ -50,6 +50,7 @@ async fn main() -> Result<(), IggyError> {
             None,                       // Replication factor.
             IggyExpiry::NeverExpire,    // Time until messages expire on the server.
             MaxTopicSize::ServerDefault, // Defined in server/config.toml. Defaults to "unlimited".
+            CleanupPolicy::Delete,      // Delete old segments instead of compacting them by key.
         )
         .await
         .expect("Topic was NOT created! Start a fresh server to run this example.");
@@ -92,6 +92,7 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Ig
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
         )
         .await?;
     Ok(())
@@ -17,7 +17,7 @@
 
 use crate::{RUNTIME, ffi};
 use iggy::prelude::{
-    Client as IggyConnectionClient, CompressionAlgorithm as RustCompressionAlgorithm, Consumer,
+    CleanupPolicy as RustCleanupPolicy, Client as IggyConnectionClient, CompressionAlgorithm as RustCompressionAlgorithm, Consumer,
     ConsumerGroupClient, Identifier as RustIdentifier, IggyClient as RustIggyClient,
     IggyClientBuilder as RustIggyClientBuilder, IggyError, IggyExpiry as RustIggyExpiry,
     IggyMessage, IggyTimestamp, MaxTopicSize as RustMaxTopicSize, MessageClient, PartitionClient,
@@ -353,6 +353,7 @@ impl Client {
                     rust_replication_factor,
                     rust_message_expiry,
                     rust_max_topic_size,
+                    RustCleanupPolicy::Delete,
                 )
                 .await
                 .map_err(|error| {
@@ -80,6 +80,11 @@ public sealed class TopicResponse
     /// </summary>
     public required byte? ReplicationFactor { get; init; }
 
+    /// <summary>
+    ///     Cleanup policy of the topic.
+    /// </summary>
+    public CleanupPolicy CleanupPolicy { get; set; } = CleanupPolicy.Delete;
+
     /// <summary>
     ///     List of partitions in the topic.
     /// </summary>
@@ -573,15 +573,7 @@ internal static class BinaryMapper
     internal static StreamResponse MapStream(ReadOnlySpan<byte> payload)
     {
         var (stream, position) = MapToStream(payload, 0);
-        List<TopicResponse> topics = new();
-        var length = payload.Length;
-
-        while (position < length)
-        {
-            var (topic, readBytes) = MapToTopic(payload, position);
-            topics.Add(topic);
-            position += readBytes;
-        }
+        var topics = MapToTopics(payload, position);
 
         return new StreamResponse
         {
@@ -620,19 +612,34 @@ internal static class BinaryMapper
     }
 
     internal static IReadOnlyList<TopicResponse> MapTopics(ReadOnlySpan<byte> payload)
+    {
+        return MapToTopics(payload, 0).AsReadOnly();
+    }
+
+    // Topic headers are followed by an optional section with one cleanup policy byte per topic,
+    // which servers that predate cleanup policies do not send.
+    private static List<TopicResponse> MapToTopics(ReadOnlySpan<byte> payload, int position)
     {
         List<TopicResponse> topics = new();
         var length = payload.Length;
-        var position = 0;
 
-        while (position < length)
+        while (position < length && length - position != topics.Count)
         {
             var (topic, readBytes) = MapToTopic(payload, position);
             topics.Add(topic);
             position += readBytes;
         }
 
-        return topics.AsReadOnly();
+        if (position < length)
+        {
+            foreach (var topic in topics)
+            {
+                topic.CleanupPolicy = (CleanupPolicy)payload[position];
+                position++;
+            }
+        }
+
+        return topics;
     }
 
     internal static TopicResponse MapTopic(ReadOnlySpan<byte> payload)
@@ -641,13 +648,15 @@ internal static class BinaryMapper
         List<PartitionResponse> partitions = new();
         var length = payload.Length;
 
-        while (position < length)
+        while (position < length && length - position != 1)
         {
             var (partition, readBytes) = MapToPartition(payload, position);
             partitions.Add(partition);
             position += readBytes;
         }
 
+        var cleanupPolicy = position < length ? (CleanupPolicy)payload[position] : topic.CleanupPolicy;
+
         return new TopicResponse
         {
             Id = topic.Id,
@@ -659,6 +668,7 @@ internal static class BinaryMapper
             MessagesCount = topic.MessagesCount,
             Size = topic.Size,
             ReplicationFactor = topic.ReplicationFactor,
+            CleanupPolicy = cleanupPolicy,
             MaxTopicSize = topic.MaxTopicSize,
             Partitions = partitions
         };
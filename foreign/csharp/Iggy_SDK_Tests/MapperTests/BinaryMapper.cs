@@ -182,6 +182,7 @@ public sealed class BinaryMapper
         Assert.Equal(messagesCountTopic1, topicResponse.MessagesCount);
         Assert.Equal(topicName1, topicResponse.Name);
         Assert.Equal(CompressionAlgorithm.None, topicResponse.CompressionAlgorithm);
+        Assert.Equal(CleanupPolicy.Delete, topicResponse.CleanupPolicy);
     }
 
     [Fact]
@@ -199,9 +200,11 @@ public sealed class BinaryMapper
         var payload2 = BinaryFactory.CreateTopicPayload(id2, partitionsCount2, messageExpiry2, name2,
             sizeBytesTopic2, messagesCountTopic2, createdAt2, replicationFactor2, maxTopicSize2, 2);
 
-        var combinedPayload = new byte[payload1.Length + payload2.Length];
+        var combinedPayload = new byte[payload1.Length + payload2.Length + 2];
         payload1.CopyTo(combinedPayload.AsSpan());
         payload2.CopyTo(combinedPayload.AsSpan(payload1.Length));
+        combinedPayload[^2] = (byte)CleanupPolicy.Delete;
+        combinedPayload[^1] = (byte)CleanupPolicy.Compact;
 
         // Act
         IReadOnlyList<TopicResponse> responses = Mappers.BinaryMapper.MapTopics(combinedPayload);
@@ -217,6 +220,7 @@ public sealed class BinaryMapper
         Assert.Equal(messagesCountTopic1, response1.MessagesCount);
         Assert.Equal(name1, response1.Name);
         Assert.Equal(CompressionAlgorithm.None, response1.CompressionAlgorithm);
+        Assert.Equal(CleanupPolicy.Delete, response1.CleanupPolicy);
 
         var response2 = responses.ElementAt(1);
         Assert.Equal(id2, response2.Id);
@@ -225,6 +229,7 @@ public sealed class BinaryMapper
         Assert.Equal(partitionsCount2, response2.PartitionsCount);
         Assert.Equal(name2, response2.Name);
         Assert.Equal(CompressionAlgorithm.Gzip, response2.CompressionAlgorithm);
+        Assert.Equal(CleanupPolicy.Compact, response2.CleanupPolicy);
     }
 
     [Fact]
@@ -236,8 +241,9 @@ public sealed class BinaryMapper
         var topicPayload = BinaryFactory.CreateTopicPayload(topicId, partitionsCount, messageExpiry, topicName,
             sizeBytes, messagesCount, createdAt2, replicationFactor, maxTopicSize, 1);
 
-        var combinedPayload = new byte[topicPayload.Length];
+        var combinedPayload = new byte[topicPayload.Length + 1];
         topicPayload.CopyTo(combinedPayload.AsSpan());
+        combinedPayload[^1] = (byte)CleanupPolicy.Compact;
 
         // Act
         var response = Mappers.BinaryMapper.MapTopic(combinedPayload);
@@ -250,6 +256,7 @@ public sealed class BinaryMapper
         Assert.Equal(topicId, response.Id);
         Assert.Equal(topicName, response.Name);
         Assert.Equal(CompressionAlgorithm.None, response.CompressionAlgorithm);
+        Assert.Equal(CleanupPolicy.Compact, response.CleanupPolicy);
     }
 
     [Fact]
@@ -55,7 +55,7 @@ func DeserializeOffset(payload []byte) *iggcon.ConsumerOffsetInfo {
 func DeserializeStream(payload []byte) (*iggcon.StreamDetails, error) {
 	stream, pos := DeserializeToStream(payload, 0)
 	topics := make([]iggcon.Topic, 0)
-	for pos < len(payload) {
+	for pos < len(payload) && len(topics) < int(stream.TopicsCount) {
 		topic, readBytes, err := DeserializeToTopic(payload, pos)
 		if err != nil {
 			return nil, err
@@ -63,6 +63,9 @@ func DeserializeStream(payload []byte) (*iggcon.StreamDetails, error) {
 		topics = append(topics, topic)
 		pos += readBytes
 	}
+	if err := deserializeCleanupPolicies(payload, pos, topics); err != nil {
+		return nil, err
+	}
 
 	sort.Slice(topics, func(i, j int) bool {
 		return topics[i].Id < topics[j].Id
@@ -182,7 +185,8 @@ func DeserializeTopics(payload []byte) ([]iggcon.Topic, error) {
 	length := len(payload)
 	position := 0
 
-	for position < length {
+	// The topics are followed by their cleanup policies, one byte per topic.
+	for position < length && length-position != len(topics) {
 		topic, readBytes, err := DeserializeToTopic(payload, position)
 		if err != nil {
 			return nil, err
@@ -190,6 +194,9 @@ func DeserializeTopics(payload []byte) ([]iggcon.Topic, error) {
 		topics = append(topics, topic)
 		position += readBytes
 	}
+	if err := deserializeCleanupPolicies(payload, position, topics); err != nil {
+		return nil, err
+	}
 
 	return topics, nil
 }
@@ -203,11 +210,16 @@ func DeserializeTopic(payload []byte) (*iggcon.TopicDetails, error) {
 	partitions := make([]iggcon.PartitionContract, 0)
 	length := len(payload)
 
-	for position < length {
+	for position < length && len(partitions) < int(topic.PartitionsCount) {
 		partition, readBytes := DeserializePartition(payload, position)
 		partitions = append(partitions, partition)
 		position += readBytes
 	}
+	topics := []iggcon.Topic{topic}
+	if err := deserializeCleanupPolicies(payload, position, topics); err != nil {
+		return &iggcon.TopicDetails{}, err
+	}
+	topic = topics[0]
 	return &iggcon.TopicDetails{
 		Topic:      topic,
 		Partitions: partitions,
@@ -223,6 +235,7 @@ func DeserializeToTopic(payload []byte, position int) (iggcon.Topic, int, error)
 	topic.CompressionAlgorithm = payload[position+24]
 	topic.MaxTopicSize = binary.LittleEndian.Uint64(payload[position+25 : position+33])
 	topic.ReplicationFactor = payload[position+33]
+	topic.CleanupPolicy = defaultCleanupPolicy
 	topic.Size = binary.LittleEndian.Uint64(payload[position+34 : position+42])
 	topic.MessagesCount = binary.LittleEndian.Uint64(payload[position+42 : position+50])
 
@@ -233,6 +246,25 @@ func DeserializeToTopic(payload []byte, position int) (iggcon.Topic, int, error)
 	return topic, readBytes, nil
 }
 
+// defaultCleanupPolicy is the cleanup policy of the topics in responses sent by the servers
+// which don't support it.
+const defaultCleanupPolicy uint8 = 1
+
+// deserializeCleanupPolicies reads the cleanup policies trailing the topics of a response, one
+// byte per topic. They are optional, so the topics keep the default cleanup policy without them.
+func deserializeCleanupPolicies(payload []byte, position int, topics []iggcon.Topic) error {
+	if position == len(payload) {
+		return nil
+	}
+	if len(payload)-position != len(topics) {
+		return fmt.Errorf("expected %d topic cleanup policies, got %d bytes", len(topics), len(payload)-position)
+	}
+	for i := range topics {
+		topics[i].CleanupPolicy = payload[position+i]
+	}
+	return nil
+}
+
 func DeserializePartition(payload []byte, position int) (iggcon.PartitionContract, int) {
 	id := binary.LittleEndian.Uint32(payload[position : position+4])
 	createdAt := binary.LittleEndian.Uint64(payload[position+4 : position+12])
@@ -86,3 +86,64 @@ func TestDeserializeFetchMessages_EmptyPayload(t *testing.T) {
 		t.Fatalf("expected 0 messages, got %d", len(result.Messages))
 	}
 }
+
+func buildTopicPayload(id uint32, name string) []byte {
+	buf := make([]byte, 51+len(name))
+	binary.LittleEndian.PutUint32(buf[0:4], id)
+	binary.LittleEndian.PutUint64(buf[4:12], 1_710_000_000_000)
+	binary.LittleEndian.PutUint32(buf[12:16], 3)
+	binary.LittleEndian.PutUint64(buf[16:24], 0)
+	buf[24] = 1
+	binary.LittleEndian.PutUint64(buf[25:33], 0)
+	buf[33] = 1
+	binary.LittleEndian.PutUint64(buf[34:42], 1024)
+	binary.LittleEndian.PutUint64(buf[42:50], 100)
+	buf[50] = byte(len(name))
+	copy(buf[51:], name)
+	return buf
+}
+
+func TestDeserializeTopics_ReadsTrailingCleanupPolicies(t *testing.T) {
+	payload := append(buildTopicPayload(1, "topic-a"), buildTopicPayload(2, "topic-b")...)
+	payload = append(payload, 1, 2)
+
+	topics, err := DeserializeTopics(payload)
+
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if len(topics) != 2 {
+		t.Fatalf("expected 2 topics, got %d", len(topics))
+	}
+	for i, want := range []struct {
+		id            uint32
+		cleanupPolicy uint8
+		name          string
+	}{{1, 1, "topic-a"}, {2, 2, "topic-b"}} {
+		topic := topics[i]
+		if topic.Id != want.id || topic.CleanupPolicy != want.cleanupPolicy || topic.Name != want.name {
+			t.Errorf("topic %d = %+v, want id %d, cleanup policy %d, name %q", i, topic, want.id, want.cleanupPolicy, want.name)
+		}
+		if topic.Size != 1024 || topic.MessagesCount != 100 {
+			t.Errorf("topic %d size = %d, messages count = %d, want 1024 and 100", i, topic.Size, topic.MessagesCount)
+		}
+	}
+}
+
+func TestDeserializeTopics_WithoutCleanupPoliciesUsesDefault(t *testing.T) {
+	payload := append(buildTopicPayload(1, "topic-a"), buildTopicPayload(2, "topic-b")...)
+
+	topics, err := DeserializeTopics(payload)
+
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if len(topics) != 2 {
+		t.Fatalf("expected 2 topics, got %d", len(topics))
+	}
+	for i, topic := range topics {
+		if topic.CleanupPolicy != defaultCleanupPolicy {
+			t.Errorf("topic %d cleanup policy = %d, want %d", i, topic.CleanupPolicy, defaultCleanupPolicy)
+		}
+	}
+}
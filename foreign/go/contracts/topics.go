@@ -49,6 +49,7 @@ type Topic struct {
 	CompressionAlgorithm uint8    `json:"compressionAlgorithm"`
 	MaxTopicSize         uint64   `json:"maxTopicSize"`
 	ReplicationFactor    uint8    `json:"replicationFactor"`
+	CleanupPolicy        uint8    `json:"cleanupPolicy"`
 	MessagesCount        uint64   `json:"messagesCount"`
 	PartitionsCount      uint32   `json:"partitionsCount"`
 }
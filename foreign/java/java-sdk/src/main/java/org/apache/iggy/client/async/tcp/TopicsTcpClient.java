@@ -31,7 +31,6 @@ import org.apache.iggy.topic.Topic;
 import org.apache.iggy.topic.TopicDetails;
 
 import java.math.BigInteger;
-import java.util.ArrayList;
 import java.util.List;
 import java.util.Optional;
 import java.util.concurrent.CompletableFuture;
@@ -74,11 +73,7 @@ public class TopicsTcpClient implements TopicsClient {
 
         return connection.send(CommandCode.Topic.GET_ALL.getValue(), payload).thenApply(response -> {
             try {
-                List<Topic> topics = new ArrayList<>();
-                while (response.isReadable()) {
-                    topics.add(BytesDeserializer.readTopic(response));
-                }
-                return topics;
+                return BytesDeserializer.readTopics(response);
             } finally {
                 response.release();
             }
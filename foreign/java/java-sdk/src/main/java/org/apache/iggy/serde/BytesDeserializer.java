@@ -43,6 +43,7 @@ import org.apache.iggy.system.ClientInfo;
 import org.apache.iggy.system.ClientInfoDetails;
 import org.apache.iggy.system.ConsumerGroupInfo;
 import org.apache.iggy.system.Stats;
+import org.apache.iggy.topic.CleanupPolicy;
 import org.apache.iggy.topic.CompressionAlgorithm;
 import org.apache.iggy.topic.Topic;
 import org.apache.iggy.topic.TopicDetails;
@@ -85,21 +86,40 @@ public final class BytesDeserializer {
     public static StreamDetails readStreamDetails(ByteBuf response) {
         var streamBase = readStreamBase(response);
 
+        var topics = readTopics(response);
+        return new StreamDetails(streamBase, topics);
+    }
+
+    /**
+     * Reads topic headers followed by the optional trailing section with one cleanup policy byte per topic.
+     * Servers that predate cleanup policies do not send the section, in which case the default policy is kept.
+     */
+    public static List<Topic> readTopics(ByteBuf response) {
         List<Topic> topics = new ArrayList<>();
-        while (response.isReadable()) {
+        while (response.isReadable() && response.readableBytes() != topics.size()) {
             topics.add(readTopic(response));
         }
+        if (!response.isReadable()) {
+            return topics;
+        }
 
-        return new StreamDetails(streamBase, topics);
+        List<Topic> withPolicies = new ArrayList<>(topics.size());
+        for (Topic topic : topics) {
+            withPolicies.add(withCleanupPolicy(topic, CleanupPolicy.fromCode(response.readByte())));
+        }
+        return withPolicies;
     }
 
     public static TopicDetails readTopicDetails(ByteBuf response) {
         var topic = readTopic(response);
 
         List<Partition> partitions = new ArrayList<>();
-        while (response.isReadable()) {
+        while (response.isReadable() && response.readableBytes() != 1) {
             partitions.add(readPartition(response));
         }
+        if (response.isReadable()) {
+            topic = withCleanupPolicy(topic, CleanupPolicy.fromCode(response.readByte()));
+        }
 
         return new TopicDetails(topic, partitions);
     }
@@ -135,10 +155,26 @@ public final class BytesDeserializer {
                 CompressionAlgorithm.fromCode(compressionAlgorithmCode),
                 maxTopicSize,
                 (short) replicationFactor,
+                CleanupPolicy.Delete,
                 messagesCount,
                 partitionsCount);
     }
 
+    private static Topic withCleanupPolicy(Topic topic, CleanupPolicy cleanupPolicy) {
+        return new Topic(
+                topic.id(),
+                topic.createdAt(),
+                topic.name(),
+                topic.size(),
+                topic.messageExpiry(),
+                topic.compressionAlgorithm(),
+                topic.maxTopicSize(),
+                topic.replicationFactor(),
+                cleanupPolicy,
+                topic.messagesCount(),
+                topic.partitionsCount());
+    }
+
     public static ConsumerGroupDetails readConsumerGroupDetails(ByteBuf response) {
         var consumerGroup = readConsumerGroup(response);
 
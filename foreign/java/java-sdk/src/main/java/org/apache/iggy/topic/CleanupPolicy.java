@@ -0,0 +1,45 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+
+package org.apache.iggy.topic;
+
+import org.apache.iggy.exception.IggyInvalidArgumentException;
+
+public enum CleanupPolicy {
+    Delete(1),
+    Compact(2);
+
+    private final Integer code;
+
+    CleanupPolicy(Integer code) {
+        this.code = code;
+    }
+
+    public static CleanupPolicy fromCode(int code) {
+        for (CleanupPolicy policy : values()) {
+            if (policy.code == code) {
+                return policy;
+            }
+        }
+        throw new IggyInvalidArgumentException("Unknown cleanup policy code: " + code);
+    }
+
+    public Integer asCode() {
+        return code;
+    }
+}
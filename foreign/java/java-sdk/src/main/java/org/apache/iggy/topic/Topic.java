@@ -30,5 +30,6 @@ public record Topic(
         CompressionAlgorithm compressionAlgorithm,
         BigInteger maxTopicSize,
         Short replicationFactor,
+        CleanupPolicy cleanupPolicy,
         BigInteger messagesCount,
         Long partitionsCount) {}
@@ -33,6 +33,7 @@ public record TopicDetails(
         CompressionAlgorithm compressionAlgorithm,
         BigInteger maxTopicSize,
         Short replicationFactor,
+        CleanupPolicy cleanupPolicy,
         BigInteger messagesCount,
         Long partitionsCount,
         List<Partition> partitions) {
@@ -46,6 +47,7 @@ public record TopicDetails(
                 topic.compressionAlgorithm(),
                 topic.maxTopicSize(),
                 topic.replicationFactor(),
+                topic.cleanupPolicy(),
                 topic.messagesCount(),
                 topic.partitionsCount(),
                 partitions);
@@ -25,6 +25,7 @@ import org.apache.commons.lang3.ArrayUtils;
 import org.apache.iggy.message.HeaderKey;
 import org.apache.iggy.message.HeaderKind;
 import org.apache.iggy.system.CacheMetricsKey;
+import org.apache.iggy.topic.CleanupPolicy;
 import org.apache.iggy.topic.CompressionAlgorithm;
 import org.apache.iggy.user.UserStatus;
 import org.junit.jupiter.api.Nested;
@@ -54,6 +55,7 @@ import static org.apache.iggy.serde.BytesDeserializer.readStreamDetails;
 import static org.apache.iggy.serde.BytesDeserializer.readStreamPermissions;
 import static org.apache.iggy.serde.BytesDeserializer.readTopic;
 import static org.apache.iggy.serde.BytesDeserializer.readTopicDetails;
+import static org.apache.iggy.serde.BytesDeserializer.readTopics;
 import static org.apache.iggy.serde.BytesDeserializer.readTopicPermissions;
 import static org.apache.iggy.serde.BytesDeserializer.readU64AsBigInteger;
 import static org.apache.iggy.serde.BytesDeserializer.readUserInfo;
@@ -187,6 +189,32 @@ class BytesDeserializerTest {
             // then
             assertThat(streamDetails.id()).isEqualTo(1L);
             assertThat(streamDetails.topics()).hasSize(1);
+            assertThat(streamDetails.topics().get(0).cleanupPolicy()).isEqualTo(CleanupPolicy.Delete);
+        }
+
+        @Test
+        void shouldDeserializeStreamDetailsWithTrailingCleanupPolicies() {
+            // given
+            ByteBuf buffer = Unpooled.buffer();
+            buffer.writeIntLE(1); // stream ID
+            writeU64(buffer, BigInteger.valueOf(1000));
+            buffer.writeIntLE(2); // topics count
+            writeU64(buffer, BigInteger.valueOf(5000));
+            writeU64(buffer, BigInteger.valueOf(100));
+            buffer.writeByte(6);
+            buffer.writeBytes("stream".getBytes());
+            writeTopicData(buffer);
+            writeTopicData(buffer);
+            buffer.writeByte(CleanupPolicy.Compact.asCode());
+            buffer.writeByte(CleanupPolicy.Delete.asCode());
+
+            // when
+            var streamDetails = readStreamDetails(buffer);
+
+            // then
+            assertThat(streamDetails.topics()).hasSize(2);
+            assertThat(streamDetails.topics().get(0).cleanupPolicy()).isEqualTo(CleanupPolicy.Compact);
+            assertThat(streamDetails.topics().get(1).cleanupPolicy()).isEqualTo(CleanupPolicy.Delete);
         }
     }
 
@@ -206,6 +234,9 @@ class BytesDeserializerTest {
             assertThat(topic.id()).isEqualTo(10L);
             assertThat(topic.name()).isEqualTo("test");
             assertThat(topic.partitionsCount()).isEqualTo(4L);
+            assertThat(topic.cleanupPolicy()).isEqualTo(CleanupPolicy.Delete);
+            assertThat(topic.size()).isEqualTo("500");
+            assertThat(topic.messagesCount()).isEqualTo(BigInteger.valueOf(50));
         }
 
         @Test
@@ -222,6 +253,41 @@ class BytesDeserializerTest {
             // then
             assertThat(topicDetails.id()).isEqualTo(10L);
             assertThat(topicDetails.partitions()).hasSize(1);
+            assertThat(topicDetails.cleanupPolicy()).isEqualTo(CleanupPolicy.Delete);
+        }
+
+        @Test
+        void shouldDeserializeTopicDetailsWithTrailingCleanupPolicy() {
+            // given
+            ByteBuf buffer = Unpooled.buffer();
+            writeTopicData(buffer);
+            writePartitionData(buffer);
+            buffer.writeByte(CleanupPolicy.Compact.asCode());
+
+            // when
+            var topicDetails = readTopicDetails(buffer);
+
+            // then
+            assertThat(topicDetails.partitions()).hasSize(1);
+            assertThat(topicDetails.cleanupPolicy()).isEqualTo(CleanupPolicy.Compact);
+        }
+
+        @Test
+        void shouldDeserializeTopicsWithTrailingCleanupPolicies() {
+            // given
+            ByteBuf buffer = Unpooled.buffer();
+            writeTopicData(buffer);
+            writeTopicData(buffer);
+            buffer.writeByte(CleanupPolicy.Delete.asCode());
+            buffer.writeByte(CleanupPolicy.Compact.asCode());
+
+            // when
+            var topics = readTopics(buffer);
+
+            // then
+            assertThat(topics).hasSize(2);
+            assertThat(topics.get(0).cleanupPolicy()).isEqualTo(CleanupPolicy.Delete);
+            assertThat(topics.get(1).cleanupPolicy()).isEqualTo(CleanupPolicy.Compact);
         }
     }
 
@@ -19,6 +19,7 @@
 
 package org.apache.iggy.stream;
 
+import org.apache.iggy.topic.CleanupPolicy;
 import org.apache.iggy.topic.CompressionAlgorithm;
 import org.apache.iggy.topic.Topic;
 import org.junit.jupiter.api.Test;
@@ -41,6 +42,7 @@ class StreamDetailsTest {
                 CompressionAlgorithm.None,
                 BigInteger.ONE,
                 (short) 2,
+                CleanupPolicy.Delete,
                 BigInteger.ZERO,
                 2L));
 
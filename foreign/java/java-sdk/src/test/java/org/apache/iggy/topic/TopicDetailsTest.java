@@ -39,6 +39,7 @@ class TopicDetailsTest {
                 CompressionAlgorithm.Gzip,
                 BigInteger.TWO,
                 (short) 12,
+                CleanupPolicy.Compact,
                 BigInteger.ZERO,
                 1L);
         var partitions = List.of(new Partition(1L, BigInteger.TEN, 2L, BigInteger.ZERO, "size", BigInteger.ONE));
@@ -53,6 +54,7 @@ class TopicDetailsTest {
         assertThat(topicDetails.compressionAlgorithm()).isEqualTo(CompressionAlgorithm.Gzip);
         assertThat(topicDetails.maxTopicSize()).isEqualTo(BigInteger.TWO);
         assertThat(topicDetails.replicationFactor()).isEqualTo((short) 12);
+        assertThat(topicDetails.cleanupPolicy()).isEqualTo(CleanupPolicy.Compact);
         assertThat(topicDetails.messagesCount()).isEqualTo(BigInteger.ZERO);
         assertThat(topicDetails.partitionsCount()).isEqualTo(1L);
         assertThat(topicDetails.partitions()).isEqualTo(partitions);
@@ -0,0 +1,91 @@
+/**
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+
+import { describe, it } from 'node:test';
+import assert from 'node:assert/strict';
+import {
+  DEFAULT_CLEANUP_POLICY,
+  deserializeTopic,
+  deserializeTopics
+} from './topic.utils.js';
+
+const serializeTopicHeader = (id: number, name: string, partitionsCount = 3) => {
+  const b = Buffer.alloc(51 + name.length);
+  b.writeUInt32LE(id, 0);
+  b.writeBigUInt64LE(1_710_000_000_000n, 4);
+  b.writeUInt32LE(partitionsCount, 12);
+  b.writeUInt8(1, 33);
+  b.writeBigUInt64LE(1024n, 34);
+  b.writeBigUInt64LE(100n, 42);
+  b.writeUInt8(name.length, 50);
+  b.write(name, 51);
+  return b;
+};
+
+describe('deserializeTopics', () => {
+
+  it('reads the trailing cleanup policies of the topics', () => {
+    const b = Buffer.concat([
+      serializeTopicHeader(1, 'topic-a'),
+      serializeTopicHeader(2, 'topic-b'),
+      Buffer.from([2, 1])
+    ]);
+
+    const [first, second] = deserializeTopics(b);
+    assert.deepEqual(first.cleanupPolicy, 2);
+    assert.deepEqual(first.sizeBytes, 1024n);
+    assert.deepEqual(first.messagesCount, 100n);
+    assert.deepEqual(first.name, 'topic-a');
+    assert.deepEqual(second.id, 2);
+    assert.deepEqual(second.cleanupPolicy, 1);
+    assert.deepEqual(second.name, 'topic-b');
+  });
+
+  it('uses the default cleanup policy without the trailing ones', () => {
+    const b = Buffer.concat([
+      serializeTopicHeader(1, 'topic-a'),
+      serializeTopicHeader(2, 'topic-b')
+    ]);
+
+    const topics = deserializeTopics(b);
+    assert.deepEqual(topics.length, 2);
+    assert.deepEqual(topics.map(t => t.cleanupPolicy), [
+      DEFAULT_CLEANUP_POLICY, DEFAULT_CLEANUP_POLICY
+    ]);
+  });
+
+});
+
+describe('deserializeTopic', () => {
+
+  it('reads the cleanup policy following the partitions', () => {
+    const b = Buffer.concat([
+      serializeTopicHeader(1, 'topic-a', 1),
+      Buffer.alloc(40),
+      Buffer.from([2])
+    ]);
+
+    const { bytesRead, data } = deserializeTopic(b);
+    assert.deepEqual(bytesRead, b.length);
+    assert.deepEqual(data.partitions.length, 1);
+    assert.deepEqual(data.cleanupPolicy, 2);
+  });
+
+});
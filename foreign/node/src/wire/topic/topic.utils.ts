@@ -41,6 +41,8 @@ export type BaseTopic = {
   maxTopicSize: bigint,
   /** Replication factor */
   replicationFactor: number
+  /** Cleanup policy (1 = delete, 2 = compact) */
+  cleanupPolicy: number,
   /** Total size of the topic in bytes */
   sizeBytes: bigint,
   /** Total number of messages in the topic */
@@ -114,6 +116,31 @@ export type CompressionAlgorithm = CompressionAlgorithmNone | CompressionAlgorit
 export const isValidCompressionAlgorithm = (ca: number): ca is CompressionAlgorithm =>
   Object.values(CompressionAlgorithm).includes(ca);
 
+/** Cleanup policy of the topics in responses sent by the servers which don't support it. */
+export const DEFAULT_CLEANUP_POLICY = 1;
+
+/**
+ * Reads the optional cleanup policies trailing the topics of a response, one byte per topic.
+ *
+ * @param p - Buffer containing the response
+ * @param pos - Position of the cleanup policies in the buffer
+ * @param topics - Topics to update, keeping the default cleanup policy if missing
+ * @returns Number of bytes read
+ * @throws Error if the cleanup policies don't match the topics
+ */
+export const deserializeCleanupPolicies = (
+  p: Buffer,
+  pos: number,
+  topics: BaseTopic[]
+): number => {
+  if (pos === p.length)
+    return 0;
+  if (p.length - pos !== topics.length)
+    throw new Error(`Expected ${topics.length} topic cleanup policies, got ${p.length - pos} bytes`);
+  topics.forEach((topic, i) => { topic.cleanupPolicy = p.readUInt8(pos + i); });
+  return topics.length;
+};
+
 /**
  * Deserializes a base topic from a buffer.
  *
@@ -145,6 +172,7 @@ export const deserializeBaseTopic = (p: Buffer, pos = 0): BaseTopicSerialized =>
       compressionAlgorithm,
       maxTopicSize,
       replicationFactor,
+      cleanupPolicy: DEFAULT_CLEANUP_POLICY,
       messageExpiry,
       messagesCount,
       sizeBytes,
@@ -192,11 +220,12 @@ export const deserializeTopic = (p: Buffer, pos = 0): TopicSerialized => {
   pos += bytesRead;
   const partitions = [];
   const end = p.length;
-  while (pos < end) {
+  while (pos < end && partitions.length < data.partitionsCount) {
     const { bytesRead, data } = deserializePartition(p, pos);
     partitions.push(data);
     pos += bytesRead;
   }
+  pos += deserializeCleanupPolicies(p, pos, [data]);
   return { bytesRead: pos - start, data: { ...data, partitions } };
 };
 
@@ -209,12 +238,14 @@ export const deserializeTopic = (p: Buffer, pos = 0): TopicSerialized => {
  * @returns Array of deserialized topics
  */
 export const deserializeTopics = (p: Buffer, pos = 0): Topic[] => {
-  const topics = [];
+  const topics: Topic[] = [];
   const len = p.length;
-  while (pos < len) {
-    const { bytesRead, data } = deserializeTopic(p, pos);
-    topics.push(data);
+  // The topics are followed by their cleanup policies, one byte per topic.
+  while (pos < len && len - pos !== topics.length) {
+    const { bytesRead, data } = deserializeBaseTopic(p, pos);
+    topics.push({ ...data, partitions: [] });
     pos += bytesRead;
   }
+  deserializeCleanupPolicies(p, pos, topics);
   return topics;
 };
@@ -216,6 +216,7 @@ impl IggyClient {
                     replication_factor,
                     expiry,
                     max_size,
+                    CleanupPolicy::Delete,
                 )
                 .await
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{e:?}")))?;
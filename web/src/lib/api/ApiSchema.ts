@@ -120,7 +120,7 @@ type Topics =
       method: 'POST';
       path: `/streams/${number}/topics`;
       body: {
-        compression_algorithm: 'none' | 'gzip';
+        compression_algorithm: 'none' | 'gzip' | 'lz4' | 'zstd' | 'snappy';
         max_topic_size: number;
         message_expiry: number;
         name: string;